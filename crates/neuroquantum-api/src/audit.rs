@@ -0,0 +1,506 @@
+//! Tamper-evident audit log
+//!
+//! Every authenticated request and gRPC call is recorded as an [`AuditEntry`]
+//! naming the principal, the action, the resource and the outcome, along with
+//! the statement it executed and the tables that statement touched. Handlers
+//! executing a statement attach its [`AuditDetails`] to the request for the
+//! authentication middleware to record. Each entry carries the
+//! SHA-256 hash of its predecessor and a hash over its own fields, so editing,
+//! removing or reordering a past entry breaks the chain at that point;
+//! [`verify_chain`] reports the first entry that doesn't match.
+//!
+//! With a configured path, entries are appended to a JSON Lines file and
+//! synced before the request completes. The most recent
+//! [`MAX_RETAINED_ENTRIES`] entries are also kept in memory; queries they
+//! cannot fully answer read the file.
+
+use std::collections::{HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use neuroquantum_qsql::table_access;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use tracing::error;
+use utoipa::{IntoParams, ToSchema};
+
+/// `previous_hash` of the first entry of a log
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Number of recent entries kept in memory for queries
+pub const MAX_RETAINED_ENTRIES: usize = 10_000;
+
+/// Entries returned by a query without an explicit limit
+pub const DEFAULT_QUERY_LIMIT: usize = 100;
+
+/// Largest number of entries a single query returns
+pub const MAX_QUERY_LIMIT: usize = 1000;
+
+/// Whether an audited operation succeeded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+    Success,
+    Failure,
+}
+
+impl AuditOutcome {
+    /// Outcome of a request answered with `status`
+    #[must_use]
+    pub const fn from_status(status: u16) -> Self {
+        if status < 400 {
+            Self::Success
+        } else {
+            Self::Failure
+        }
+    }
+}
+
+/// One recorded operation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct AuditEntry {
+    /// Position in the log, starting at 0
+    pub sequence: u64,
+    pub timestamp: DateTime<Utc>,
+    /// API key name or JWT subject that performed the operation
+    pub principal: String,
+    /// HTTP method of the request, `GRPC` for gRPC calls
+    pub action: String,
+    /// Request path or full gRPC method name
+    pub resource: String,
+    pub outcome: AuditOutcome,
+    /// HTTP status of the response; gRPC status codes are mapped to their
+    /// HTTP equivalent
+    pub status: u16,
+    /// Statement executed by the request, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statement: Option<String>,
+    /// Tables the statement read or wrote
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tables: Vec<String>,
+    /// Hash of the preceding entry, [`GENESIS_HASH`] for the first one
+    pub previous_hash: String,
+    /// Hash over all other fields of this entry
+    pub hash: String,
+}
+
+impl AuditEntry {
+    /// Hash over every field except `hash` itself
+    #[must_use]
+    pub fn compute_hash(&self) -> String {
+        let timestamp = self
+            .timestamp
+            .to_rfc3339_opts(chrono::SecondsFormat::Nanos, true);
+        let mut hasher = Sha256::new();
+        hasher.update(self.sequence.to_le_bytes());
+        // Length prefixes keep adjacent text fields from running into each other
+        for field in [
+            timestamp.as_str(),
+            self.principal.as_str(),
+            self.action.as_str(),
+            self.resource.as_str(),
+            self.previous_hash.as_str(),
+        ] {
+            hasher.update((field.len() as u64).to_le_bytes());
+            hasher.update(field.as_bytes());
+        }
+        hasher.update([self.outcome as u8]);
+        hasher.update(self.status.to_le_bytes());
+        hasher.update([u8::from(self.statement.is_some())]);
+        let statement = self.statement.as_deref().unwrap_or_default();
+        hasher.update((statement.len() as u64).to_le_bytes());
+        hasher.update(statement.as_bytes());
+        hasher.update((self.tables.len() as u64).to_le_bytes());
+        for table in &self.tables {
+            hasher.update((table.len() as u64).to_le_bytes());
+            hasher.update(table.as_bytes());
+        }
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+/// Statement executed for a request and the tables it touched
+///
+/// Handlers insert it into the request extensions; the authentication
+/// middleware adds it to the request's audit entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditDetails {
+    pub statement: String,
+    pub tables: Vec<String>,
+}
+
+impl AuditDetails {
+    /// Details of a single statement
+    ///
+    /// Statements that don't parse are recorded without tables.
+    #[must_use]
+    pub fn for_statement(sql: &str) -> Self {
+        Self::for_statements([sql])
+    }
+
+    /// Details of several statements executed by one request, e.g. a batch
+    #[must_use]
+    pub fn for_statements<'a>(statements: impl IntoIterator<Item = &'a str>) -> Self {
+        let parser = neuroquantum_qsql::Parser::new();
+        let mut sql = Vec::new();
+        let mut seen = HashSet::new();
+        let mut tables = Vec::new();
+        for statement in statements {
+            sql.push(statement.trim());
+            let Ok(parsed) = parser.parse_query(statement) else {
+                continue;
+            };
+            for access in table_access::table_accesses(&parsed) {
+                if seen.insert(access.table.clone()) {
+                    tables.push(access.table);
+                }
+            }
+        }
+        Self {
+            statement: sql.join(";\n"),
+            tables,
+        }
+    }
+}
+
+/// First point at which an audit chain fails verification
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AuditChainError {
+    #[error("audit entry {found} out of sequence, expected {expected}")]
+    OutOfSequence { expected: u64, found: u64 },
+    #[error("audit entry {sequence} does not link to its predecessor")]
+    BrokenLink { sequence: u64 },
+    #[error("audit entry {sequence} does not match its hash")]
+    HashMismatch { sequence: u64 },
+    #[error("audit log unreadable: {0}")]
+    Unreadable(String),
+}
+
+/// Check that `entries` form an unbroken hash chain
+///
+/// The chain may start at any entry, as the in-memory window does; a chain
+/// starting at sequence 0 must start from [`GENESIS_HASH`].
+pub fn verify_chain(entries: &[AuditEntry]) -> Result<(), AuditChainError> {
+    let mut previous: Option<&AuditEntry> = None;
+    for entry in entries {
+        match previous {
+            | Some(prev) => {
+                if entry.sequence != prev.sequence + 1 {
+                    return Err(AuditChainError::OutOfSequence {
+                        expected: prev.sequence + 1,
+                        found: entry.sequence,
+                    });
+                }
+                if entry.previous_hash != prev.hash {
+                    return Err(AuditChainError::BrokenLink {
+                        sequence: entry.sequence,
+                    });
+                }
+            },
+            | None if entry.sequence == 0 && entry.previous_hash != GENESIS_HASH => {
+                return Err(AuditChainError::BrokenLink { sequence: 0 });
+            },
+            | None => {},
+        }
+        if entry.compute_hash() != entry.hash {
+            return Err(AuditChainError::HashMismatch {
+                sequence: entry.sequence,
+            });
+        }
+        previous = Some(entry);
+    }
+    Ok(())
+}
+
+/// Criteria for selecting audit entries, all optional
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AuditFilter {
+    /// Only entries of this principal
+    pub principal: Option<String>,
+    /// Only entries with this action (HTTP method)
+    pub action: Option<String>,
+    /// Only entries whose resource starts with this path
+    pub resource: Option<String>,
+    /// Only entries whose statement touched this table
+    pub table: Option<String>,
+    /// Only entries with this outcome
+    pub outcome: Option<AuditOutcome>,
+    /// Only entries recorded at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Only entries recorded before this time
+    pub until: Option<DateTime<Utc>>,
+    /// Maximum number of entries, the most recent ones are returned
+    pub limit: Option<usize>,
+}
+
+impl AuditFilter {
+    /// Check if `entry` meets every criterion
+    #[must_use]
+    pub fn matches(&self, entry: &AuditEntry) -> bool {
+        self.principal
+            .as_ref()
+            .is_none_or(|p| *p == entry.principal)
+            && self
+                .action
+                .as_ref()
+                .is_none_or(|a| a.eq_ignore_ascii_case(&entry.action))
+            && self
+                .resource
+                .as_ref()
+                .is_none_or(|r| entry.resource.starts_with(r.as_str()))
+            && self
+                .table
+                .as_ref()
+                .is_none_or(|t| entry.tables.iter().any(|table| table == t))
+            && self.outcome.is_none_or(|o| o == entry.outcome)
+            && self.since.is_none_or(|since| entry.timestamp >= since)
+            && self.until.is_none_or(|until| entry.timestamp < until)
+    }
+}
+
+#[derive(Debug)]
+struct AuditState {
+    file: Option<File>,
+    /// Most recent entries, oldest first
+    recent: VecDeque<AuditEntry>,
+    next_sequence: u64,
+    last_hash: String,
+    /// Set when a failed append could not be removed from the file; no
+    /// entries are appended after it
+    damaged: bool,
+}
+
+/// Append-only, hash-chained log of authenticated operations
+#[derive(Debug)]
+pub struct AuditLog {
+    path: Option<PathBuf>,
+    state: Mutex<AuditState>,
+}
+
+impl AuditLog {
+    /// Create a log that only keeps entries in memory
+    #[must_use]
+    pub fn in_memory() -> Self {
+        Self {
+            path: None,
+            state: Mutex::new(AuditState {
+                file: None,
+                recent: VecDeque::new(),
+                next_sequence: 0,
+                last_hash: GENESIS_HASH.to_string(),
+                damaged: false,
+            }),
+        }
+    }
+
+    /// Open the log file at `path`, creating it if needed
+    ///
+    /// New entries continue the existing chain. A chain that fails
+    /// verification is reported but not repaired, so the damage stays visible.
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+
+        let entries = if tokio::fs::try_exists(&path).await? {
+            read_entries(&path).await?
+        } else {
+            Vec::new()
+        };
+        if let Err(e) = verify_chain(&entries) {
+            error!("🚨 Audit log {} failed verification: {}", path.display(), e);
+        }
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .await
+            .with_context(|| format!("Failed to open audit log {}", path.display()))?;
+        let (next_sequence, last_hash) = entries.last().map_or_else(
+            || (0, GENESIS_HASH.to_string()),
+            |last| (last.sequence + 1, last.hash.clone()),
+        );
+        let skip = entries.len().saturating_sub(MAX_RETAINED_ENTRIES);
+
+        Ok(Self {
+            path: Some(path),
+            state: Mutex::new(AuditState {
+                file: Some(file),
+                recent: entries.into_iter().skip(skip).collect(),
+                next_sequence,
+                last_hash,
+                damaged: false,
+            }),
+        })
+    }
+
+    /// Path of the log file, `None` for an in-memory log
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Append an entry and sync it to disk
+    pub async fn record(
+        &self,
+        principal: &str,
+        action: &str,
+        resource: &str,
+        status: u16,
+    ) -> Result<AuditEntry> {
+        self.record_details(principal, action, resource, status, None)
+            .await
+    }
+
+    /// Append an entry for a request that executed a statement
+    pub async fn record_details(
+        &self,
+        principal: &str,
+        action: &str,
+        resource: &str,
+        status: u16,
+        details: Option<&AuditDetails>,
+    ) -> Result<AuditEntry> {
+        let mut state = self.state.lock().await;
+        if state.damaged {
+            anyhow::bail!("Audit log holds a partial entry from a failed append");
+        }
+        let mut entry = AuditEntry {
+            sequence: state.next_sequence,
+            timestamp: Utc::now(),
+            principal: principal.to_string(),
+            action: action.to_string(),
+            resource: resource.to_string(),
+            outcome: AuditOutcome::from_status(status),
+            status,
+            statement: details.map(|details| details.statement.clone()),
+            tables: details
+                .map(|details| details.tables.clone())
+                .unwrap_or_default(),
+            previous_hash: state.last_hash.clone(),
+            hash: String::new(),
+        };
+        entry.hash = entry.compute_hash();
+
+        if let Some(file) = state.file.as_mut() {
+            let mut line = serde_json::to_vec(&entry)?;
+            line.push(b'\n');
+            let length = file
+                .metadata()
+                .await
+                .context("Failed to read audit log length")?
+                .len();
+            let appended = async {
+                file.write_all(&line)
+                    .await
+                    .context("Failed to append audit entry")?;
+                file.sync_data().await.context("Failed to sync audit log")
+            }
+            .await;
+            if let Err(e) = appended {
+                // Cut off whatever part of the line was written, so the file
+                // never holds a partial entry the next one is appended to
+                let truncated = async {
+                    file.set_len(length).await?;
+                    file.sync_data().await
+                }
+                .await;
+                if let Err(truncate_error) = truncated {
+                    error!(
+                        "🚨 Failed to remove a partial audit entry, refusing further entries: {}",
+                        truncate_error
+                    );
+                    state.damaged = true;
+                }
+                return Err(e);
+            }
+        }
+
+        state.next_sequence += 1;
+        state.last_hash.clone_from(&entry.hash);
+        if state.recent.len() == MAX_RETAINED_ENTRIES {
+            state.recent.pop_front();
+        }
+        state.recent.push_back(entry.clone());
+        Ok(entry)
+    }
+
+    /// Most recent entries matching `filter`, oldest first
+    ///
+    /// Answered from memory when the retained entries hold enough matches;
+    /// otherwise a file-backed log searches the whole file.
+    pub async fn query(&self, filter: &AuditFilter) -> Result<Vec<AuditEntry>> {
+        let limit = filter
+            .limit
+            .unwrap_or(DEFAULT_QUERY_LIMIT)
+            .min(MAX_QUERY_LIMIT);
+        let state = self.state.lock().await;
+        let mut entries = newest_matches(state.recent.iter(), filter, limit);
+        let retains_all = state.recent.front().is_none_or(|first| first.sequence == 0);
+        if let Some(path) = self
+            .path
+            .as_ref()
+            .filter(|_| entries.len() < limit && !retains_all)
+        {
+            entries = newest_matches(read_entries(path).await?.iter(), filter, limit);
+        }
+        entries.reverse();
+        Ok(entries)
+    }
+
+    /// Verify the whole chain
+    ///
+    /// A file-backed log is read back from disk, so changes made to the file
+    /// behind the log's back are detected.
+    pub async fn verify(&self) -> Result<(), AuditChainError> {
+        let mut state = self.state.lock().await;
+        match &self.path {
+            | Some(path) => {
+                let entries = read_entries(path)
+                    .await
+                    .map_err(|e| AuditChainError::Unreadable(format!("{e:#}")))?;
+                verify_chain(&entries)
+            },
+            | None => verify_chain(state.recent.make_contiguous()),
+        }
+    }
+}
+
+/// Up to `limit` entries matching `filter`, newest first
+fn newest_matches<'a>(
+    entries: impl DoubleEndedIterator<Item = &'a AuditEntry>,
+    filter: &AuditFilter,
+    limit: usize,
+) -> Vec<AuditEntry> {
+    entries
+        .rev()
+        .filter(|entry| filter.matches(entry))
+        .take(limit)
+        .cloned()
+        .collect()
+}
+
+/// Read every entry of the log file at `path`
+pub async fn read_entries(path: &Path) -> Result<Vec<AuditEntry>> {
+    let contents = tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("Failed to read audit log {}", path.display()))?;
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(number, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("Malformed audit entry on line {}", number + 1))
+        })
+        .collect()
+}
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use tracing::{info, warn};
 use uuid::Uuid;
 
-use crate::permissions::Permission;
+use crate::permissions::{Permission, ScopeRule};
 use crate::storage::{ApiKeyInfo, ApiKeyStorage, StorageStats};
 
 // For testing, use a lower cost to speed up tests
@@ -25,6 +25,20 @@ pub struct ApiKey {
     pub last_used: Option<DateTime<Utc>>,
     pub usage_count: u64,
     pub rate_limit_per_hour: Option<u32>,
+    /// Table scope rules; empty for keys not restricted to specific tables
+    #[serde(default)]
+    pub scopes: Vec<ScopeRule>,
+}
+
+impl ApiKey {
+    /// Whether the key's scope rules allow `action` on `table`
+    ///
+    /// Admin keys are never restricted by scope rules.
+    #[must_use]
+    pub fn scope_allows(&self, table: &str, action: &str) -> bool {
+        Permission::has_admin(&self.permissions)
+            || Permission::scope_allows(&self.scopes, table, action)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -115,6 +129,28 @@ impl AuthService {
         expiry_hours: Option<u32>,
         rate_limit_per_hour: Option<u32>,
     ) -> Result<ApiKey, String> {
+        self.generate_scoped_api_key(
+            name,
+            permissions,
+            Vec::new(),
+            expiry_hours,
+            rate_limit_per_hour,
+        )
+    }
+
+    /// Generate an API key restricted to the tables matched by `scopes`
+    pub fn generate_scoped_api_key(
+        &mut self,
+        name: String,
+        permissions: Vec<String>,
+        scopes: Vec<ScopeRule>,
+        expiry_hours: Option<u32>,
+        rate_limit_per_hour: Option<u32>,
+    ) -> Result<ApiKey, String> {
+        for rule in &scopes {
+            rule.validate()?;
+        }
+
         let key = format!("nqdb_{}", Uuid::new_v4().to_string().replace('-', ""));
 
         // Use lower cost for tests to speed up execution
@@ -139,6 +175,7 @@ impl AuthService {
             last_used: None,
             usage_count: 0,
             rate_limit_per_hour,
+            scopes,
         };
 
         // Store in persistent database
@@ -206,12 +243,14 @@ impl AuthService {
 
     #[must_use]
     pub fn check_endpoint_permission(&self, api_key: &ApiKey, path: &str) -> bool {
-        // Define permission mappings for different endpoints
-        let required_permission = match path {
-            | p if p.starts_with("/api/v1/neuromorphic") => "neuromorphic",
-            | p if p.starts_with("/api/v1/quantum") => "quantum",
-            | p if p.starts_with("/api/v1/dna") => "dna",
-            | p if p.starts_with("/api/v1/admin") => "admin",
+        // Define permission mappings for different endpoints; every API version
+        // shares the same mapping
+        let api_path = crate::versioning::strip_version_prefix(path).unwrap_or(path);
+        let required_permission = match api_path {
+            | p if p.starts_with("/neuromorphic") => "neuromorphic",
+            | p if p.starts_with("/quantum") => "quantum",
+            | p if p.starts_with("/dna") => "dna",
+            | p if p.starts_with("/admin") => "admin",
             | p if p.starts_with("/metrics") => "admin",
             | p if p.contains("/query") || p.contains("/search") => "read",
             | p if p.contains("/train") || p.contains("/optimize") || p.contains("/compress") => {
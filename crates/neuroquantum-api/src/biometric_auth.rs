@@ -3,11 +3,31 @@
 //! This module provides EEG (Electroencephalography) signal processing for biometric authentication.
 //! It leverages the neuromorphic nature of `NeuroQuantumDB` to process brainwave patterns and
 //! create unique user signatures for advanced authentication.
-
-use std::collections::HashMap;
+//!
+//! Every signature records the [`EEG_FEATURE_VERSION`] its template was
+//! extracted with. Templates from another version are never compared against
+//! fresh features: authentication asks for re-enrollment instead, and
+//! [`EEGAuthService::migrate_signatures`] re-extracts templates from newly
+//! recorded samples.
+//!
+//! # Liveness and replay protection
+//!
+//! [`EEGAuthService::issue_challenge`] hands out a single-use nonce together
+//! with a marker frequency. The client drives a stimulus at that frequency
+//! during capture, so a live recording shows a spectral peak there that a
+//! previously captured sample lacks. Samples answering a challenge are also
+//! fingerprinted and exact repeats within [`EEGAuthConfig::replay_window`] are
+//! rejected. The marker is notched out before features are extracted, keeping
+//! challenge responses comparable with enrollment templates.
+
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, VecDeque};
 use std::f32::consts::PI;
+use std::hash::BuildHasher;
+use std::time::Duration;
 
 use neuroquantum_core::security::constant_time_threshold_check;
+use rand::Rng;
 use rustfft::num_complex::Complex;
 use rustfft::FftPlanner;
 use serde::{Deserialize, Serialize};
@@ -34,6 +54,41 @@ pub enum EEGError {
 
     #[error("User signature not found: {0}")]
     SignatureNotFound(String),
+
+    #[error(
+        "Signature of {user_id} uses feature version {enrolled}, current is {current}: re-enrollment required"
+    )]
+    ReenrollmentRequired {
+        user_id: String,
+        enrolled: u32,
+        current: u32,
+    },
+
+    #[error("Invalid configuration: {0}")]
+    InvalidConfiguration(String),
+
+    #[error("No matching challenge issued for {0}")]
+    ChallengeInvalid(String),
+
+    #[error("Challenge for {0} has expired")]
+    ChallengeExpired(String),
+
+    #[error("Replayed EEG sample rejected for {0}")]
+    ReplayDetected(String),
+
+    #[error("Liveness check failed: no {frequency:.1}Hz challenge marker in sample")]
+    LivenessCheckFailed { frequency: f32 },
+}
+
+/// Version of the EEG feature extraction algorithm
+///
+/// Bump this whenever a change to filtering or feature extraction makes new
+/// features incomparable with templates extracted before it.
+pub const EEG_FEATURE_VERSION: u32 = 1;
+
+/// Feature version of signatures stored before versioning was introduced
+const fn initial_feature_version() -> u32 {
+    1
 }
 
 /// Represents different EEG frequency bands
@@ -691,6 +746,9 @@ impl EEGFeatures {
 pub struct UserSignature {
     pub user_id: String,
     pub feature_template: EEGFeatures,
+    /// Feature extraction version the template was extracted with
+    #[serde(default = "initial_feature_version")]
+    pub feature_version: u32,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub last_updated: chrono::DateTime<chrono::Utc>,
     pub enrollment_count: usize,
@@ -705,6 +763,7 @@ pub struct EEGProcessor {
     feature_extractor: FFTAnalyzer,
     min_samples: usize,
     signal_quality_threshold: f32,
+    feature_version: u32,
 }
 
 impl EEGProcessor {
@@ -731,9 +790,16 @@ impl EEGProcessor {
             feature_extractor: FFTAnalyzer::new(sampling_rate),
             min_samples: (sampling_rate * 2.0) as usize, // Minimum 2 seconds of data
             signal_quality_threshold: 50.0, // 50% minimum quality (adjusted for synthetic signals)
+            feature_version: EEG_FEATURE_VERSION,
         })
     }
 
+    /// Feature extraction version of the features this processor extracts
+    #[must_use]
+    pub const fn feature_version(&self) -> u32 {
+        self.feature_version
+    }
+
     /// Process raw EEG data and extract features
     pub fn process_raw_eeg(&self, raw_data: &[f32]) -> Result<EEGFeatures, EEGError> {
         // 1. Validate input data
@@ -836,6 +902,7 @@ impl EEGProcessor {
         Ok(UserSignature {
             user_id,
             feature_template: eeg_features.clone(),
+            feature_version: self.feature_version,
             created_at: chrono::Utc::now(),
             last_updated: chrono::Utc::now(),
             enrollment_count: 1,
@@ -882,24 +949,118 @@ impl EEGProcessor {
     }
 }
 
+/// Matching, liveness and replay settings of EEG authentication
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EEGAuthConfig {
+    /// Similarity (0.0 - 1.0) new signatures require for a match
+    pub match_threshold: f32,
+
+    /// How long an issued challenge can be answered
+    pub challenge_ttl: Duration,
+
+    /// How long answered samples are remembered to reject replays
+    pub replay_window: Duration,
+
+    /// Maximum number of remembered samples per user
+    pub max_remembered_samples: usize,
+
+    /// Reject authentication attempts that do not answer a challenge
+    pub require_challenge: bool,
+
+    /// Lowest challenge marker frequency in Hz
+    pub marker_min_frequency: f32,
+
+    /// Highest challenge marker frequency in Hz
+    pub marker_max_frequency: f32,
+
+    /// Minimum ratio of marker amplitude to the surrounding spectrum
+    pub marker_min_snr: f32,
+}
+
+impl Default for EEGAuthConfig {
+    fn default() -> Self {
+        Self {
+            match_threshold: 0.85,
+            challenge_ttl: Duration::from_secs(30),
+            replay_window: Duration::from_secs(600),
+            max_remembered_samples: 64,
+            require_challenge: true,
+            // Between the gamma activity around 40Hz and 50Hz mains hum
+            marker_min_frequency: 41.0,
+            marker_max_frequency: 49.0,
+            marker_min_snr: 5.0,
+        }
+    }
+}
+
+/// Server-issued liveness challenge for one authentication attempt
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EEGChallenge {
+    pub user_id: String,
+    /// Single-use nonce to submit with the sample
+    pub nonce: String,
+    /// Frequency in Hz the capture session must be stimulated at
+    pub marker_frequency: f32,
+    pub issued_at: chrono::DateTime<chrono::Utc>,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
 /// EEG-based authentication service
 #[derive(Debug, Clone)]
 pub struct EEGAuthService {
     processor: EEGProcessor,
     user_signatures: HashMap<String, UserSignature>,
     max_enrollment_samples: usize,
+    config: EEGAuthConfig,
+    challenges: HashMap<String, EEGChallenge>,
+    recent_samples: HashMap<String, VecDeque<(u64, chrono::DateTime<chrono::Utc>)>>,
+    sample_hasher: RandomState,
 }
 
 impl EEGAuthService {
     /// Create new EEG authentication service
     pub fn new(sampling_rate: f32) -> Result<Self, EEGError> {
+        Self::with_config(sampling_rate, EEGAuthConfig::default())
+    }
+
+    /// Create an EEG authentication service with custom settings
+    pub fn with_config(sampling_rate: f32, config: EEGAuthConfig) -> Result<Self, EEGError> {
+        let processor = EEGProcessor::new(sampling_rate)?;
+        if !(0.0..=1.0).contains(&config.match_threshold) {
+            return Err(EEGError::InvalidConfiguration(format!(
+                "match threshold {} must be between 0 and 1",
+                config.match_threshold
+            )));
+        }
+        if config.marker_min_frequency <= 0.0
+            || config.marker_min_frequency > config.marker_max_frequency
+            || config.marker_max_frequency >= sampling_rate / 2.0
+        {
+            return Err(EEGError::InvalidConfiguration(format!(
+                "marker frequencies {}-{}Hz must be positive, ordered and below {}Hz",
+                config.marker_min_frequency,
+                config.marker_max_frequency,
+                sampling_rate / 2.0
+            )));
+        }
+
         Ok(Self {
-            processor: EEGProcessor::new(sampling_rate)?,
+            processor,
             user_signatures: HashMap::new(),
             max_enrollment_samples: 5,
+            config,
+            challenges: HashMap::new(),
+            recent_samples: HashMap::new(),
+            sample_hasher: RandomState::new(),
         })
     }
 
+    /// Authentication settings
+    #[must_use]
+    pub const fn config(&self) -> &EEGAuthConfig {
+        &self.config
+    }
+
     /// Enroll a new user with their EEG signature
     pub fn enroll_user(
         &mut self,
@@ -907,9 +1068,10 @@ impl EEGAuthService {
         raw_eeg: &[f32],
     ) -> Result<UserSignature, EEGError> {
         let features = self.processor.process_raw_eeg(raw_eeg)?;
-        let signature = self
+        let mut signature = self
             .processor
             .extract_user_signature(user_id.clone(), &features)?;
+        signature.authentication_threshold = self.config.match_threshold;
 
         self.user_signatures
             .insert(user_id.clone(), signature.clone());
@@ -918,12 +1080,32 @@ impl EEGAuthService {
         Ok(signature)
     }
 
+    /// Switch to another feature extraction version
+    ///
+    /// Used when rolling out a new extraction algorithm; signatures enrolled
+    /// under a different version must be migrated before they authenticate.
+    pub fn set_feature_version(&mut self, version: u32) {
+        self.processor.feature_version = version;
+    }
+
+    /// Feature extraction version new features are extracted with
+    #[must_use]
+    pub const fn feature_version(&self) -> u32 {
+        self.processor.feature_version
+    }
+
     /// Update user signature with additional EEG sample (improves accuracy)
+    ///
+    /// A signature from an older feature version is re-extracted from the
+    /// sample instead of being averaged with it.
     pub fn update_signature(&mut self, user_id: &str, raw_eeg: &[f32]) -> Result<(), EEGError> {
         let features = self.processor.process_raw_eeg(raw_eeg)?;
+        let current_version = self.processor.feature_version;
 
         if let Some(signature) = self.user_signatures.get_mut(user_id) {
-            if signature.enrollment_count < self.max_enrollment_samples {
+            if signature.feature_version != current_version {
+                Self::reextract(signature, features, current_version);
+            } else if signature.enrollment_count < self.max_enrollment_samples {
                 // Average the features for better template
                 signature.feature_template.delta_power =
                     f32::midpoint(signature.feature_template.delta_power, features.delta_power);
@@ -963,6 +1145,20 @@ impl EEGAuthService {
             .get(user_id)
             .ok_or_else(|| EEGError::SignatureNotFound(user_id.to_string()))?;
 
+        // Features of different versions are not comparable, so a match score
+        // would be meaningless
+        if signature.feature_version != self.processor.feature_version {
+            warn!(
+                "⚠️  EEG signature of {} uses feature version {} (current: {}), re-enrollment required",
+                user_id, signature.feature_version, self.processor.feature_version
+            );
+            return Err(EEGError::ReenrollmentRequired {
+                user_id: user_id.to_string(),
+                enrolled: signature.feature_version,
+                current: self.processor.feature_version,
+            });
+        }
+
         let similarity = features.similarity(&signature.feature_template);
 
         // Use constant-time threshold check to prevent timing attacks
@@ -994,6 +1190,151 @@ impl EEGAuthService {
         })
     }
 
+    /// Issue a liveness challenge for a user's next authentication
+    ///
+    /// Replaces any challenge previously issued to the user.
+    pub fn issue_challenge(&mut self, user_id: &str) -> Result<EEGChallenge, EEGError> {
+        if !self.user_signatures.contains_key(user_id) {
+            return Err(EEGError::SignatureNotFound(user_id.to_string()));
+        }
+
+        // Markers lie on a 0.5Hz grid, well apart for typical capture lengths
+        let steps =
+            ((self.config.marker_max_frequency - self.config.marker_min_frequency) * 2.0) as u32;
+        let step = rand::thread_rng().gen_range(0..=steps);
+        let issued_at = chrono::Utc::now();
+        let challenge = EEGChallenge {
+            user_id: user_id.to_string(),
+            nonce: uuid::Uuid::new_v4().to_string(),
+            marker_frequency: (step as f32).mul_add(0.5, self.config.marker_min_frequency),
+            issued_at,
+            expires_at: issued_at
+                + chrono::Duration::from_std(self.config.challenge_ttl)
+                    .unwrap_or(chrono::Duration::MAX),
+        };
+
+        debug!(
+            "Issued EEG challenge for {} (marker: {:.1}Hz)",
+            user_id, challenge.marker_frequency
+        );
+        self.challenges
+            .insert(user_id.to_string(), challenge.clone());
+        Ok(challenge)
+    }
+
+    /// Authenticate a user with a sample answering their pending challenge
+    ///
+    /// The challenge is consumed by the attempt. The sample is rejected if it
+    /// repeats one submitted within the replay window or lacks the challenge
+    /// marker, before it is compared with the user's template.
+    pub fn authenticate_with_challenge(
+        &mut self,
+        user_id: &str,
+        nonce: &str,
+        raw_eeg: &[f32],
+    ) -> Result<AuthenticationResult, EEGError> {
+        let challenge = self
+            .challenges
+            .remove(user_id)
+            .filter(|challenge| challenge.nonce == nonce)
+            .ok_or_else(|| EEGError::ChallengeInvalid(user_id.to_string()))?;
+        let now = chrono::Utc::now();
+        if now > challenge.expires_at {
+            return Err(EEGError::ChallengeExpired(user_id.to_string()));
+        }
+
+        self.remember_sample(user_id, raw_eeg, now)?;
+
+        if !self.has_marker(raw_eeg, challenge.marker_frequency) {
+            warn!(
+                "❌ EEG liveness check failed for {}: no {:.1}Hz marker",
+                user_id, challenge.marker_frequency
+            );
+            return Err(EEGError::LivenessCheckFailed {
+                frequency: challenge.marker_frequency,
+            });
+        }
+
+        let marker_notch = DigitalFilter::notch_with_rate(
+            challenge.marker_frequency,
+            self.processor.sampling_rate,
+        );
+        let cleaned = marker_notch.apply_with_rate(raw_eeg, self.processor.sampling_rate);
+        self.authenticate(user_id, &cleaned)
+    }
+
+    /// Record a sample's fingerprint, rejecting exact repeats
+    fn remember_sample(
+        &mut self,
+        user_id: &str,
+        raw_eeg: &[f32],
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), EEGError> {
+        let fingerprint = self.sample_hasher.hash_one(
+            raw_eeg
+                .iter()
+                .map(|sample| sample.to_bits())
+                .collect::<Vec<_>>(),
+        );
+        let window =
+            chrono::Duration::from_std(self.config.replay_window).unwrap_or(chrono::Duration::MAX);
+
+        let seen = self.recent_samples.entry(user_id.to_string()).or_default();
+        seen.retain(|(_, at)| now.signed_duration_since(*at) <= window);
+        if seen.iter().any(|(known, _)| *known == fingerprint) {
+            warn!("❌ Replayed EEG sample rejected for {}", user_id);
+            return Err(EEGError::ReplayDetected(user_id.to_string()));
+        }
+
+        if seen.len() >= self.config.max_remembered_samples {
+            seen.pop_front();
+        }
+        seen.push_back((fingerprint, now));
+        Ok(())
+    }
+
+    /// Check if a sample shows a spectral peak at the marker frequency
+    ///
+    /// The marker's amplitude is compared with the median of the spectrum
+    /// 1-3Hz either side of it, which ignores other narrow-band activity. The
+    /// reference never drops below 1% of the strongest component, so leakage
+    /// in an otherwise empty region of the spectrum does not count as a peak.
+    fn has_marker(&self, raw_eeg: &[f32], frequency: f32) -> bool {
+        let spectrum = self.processor.feature_extractor.analyze_windowed(raw_eeg);
+        let bins = spectrum.spectrum.len();
+        if bins == 0 {
+            return false;
+        }
+        let resolution = spectrum.sampling_rate / (bins as f32 * 2.0);
+        let bin = |hz: f32| ((hz / resolution).round() as usize).min(bins - 1);
+
+        let center = bin(frequency);
+        let peak = spectrum.spectrum[center.saturating_sub(1)..=(center + 1).min(bins - 1)]
+            .iter()
+            .copied()
+            .fold(0.0f32, f32::max);
+
+        let mut surrounding: Vec<f32> = (bin(frequency - 3.0)..=bin(frequency + 3.0))
+            .filter(|&i| i.abs_diff(center) >= bin(1.0).max(2))
+            .map(|i| spectrum.spectrum[i])
+            .collect();
+        if surrounding.is_empty() {
+            return false;
+        }
+        surrounding.sort_by(f32::total_cmp);
+        let median = surrounding[surrounding.len() / 2];
+        // Skip the DC bin, which only reflects the electrode offset
+        let strongest = spectrum
+            .spectrum
+            .iter()
+            .skip(1)
+            .copied()
+            .fold(0.0f32, f32::max);
+        let reference = median.max(strongest / 100.0);
+
+        reference > 0.0 && peak >= reference * self.config.marker_min_snr
+    }
+
     /// Get user signature
     #[must_use]
     pub fn get_signature(&self, user_id: &str) -> Option<&UserSignature> {
@@ -1002,6 +1343,8 @@ impl EEGAuthService {
 
     /// Remove user signature
     pub fn revoke_user(&mut self, user_id: &str) -> bool {
+        self.challenges.remove(user_id);
+        self.recent_samples.remove(user_id);
         self.user_signatures.remove(user_id).is_some()
     }
 
@@ -1010,6 +1353,85 @@ impl EEGAuthService {
     pub fn list_users(&self) -> Vec<String> {
         self.user_signatures.keys().cloned().collect()
     }
+
+    /// Users whose signature was extracted with another feature version
+    #[must_use]
+    pub fn outdated_users(&self) -> Vec<String> {
+        self.user_signatures
+            .values()
+            .filter(|signature| signature.feature_version != self.processor.feature_version)
+            .map(|signature| signature.user_id.clone())
+            .collect()
+    }
+
+    /// Migrate outdated signatures to the current feature version
+    ///
+    /// Templates of users with a freshly recorded sample in `samples` are
+    /// re-extracted from it. Outdated users without a sample are reported as
+    /// pending and keep requiring re-enrollment.
+    pub fn migrate_signatures(
+        &mut self,
+        samples: &HashMap<String, Vec<f32>>,
+    ) -> SignatureMigrationReport {
+        let current_version = self.processor.feature_version;
+        let mut report = SignatureMigrationReport {
+            feature_version: current_version,
+            ..Default::default()
+        };
+
+        for user_id in self.outdated_users() {
+            let Some(raw_eeg) = samples.get(&user_id) else {
+                report.pending.push(user_id);
+                continue;
+            };
+            match self.processor.process_raw_eeg(raw_eeg) {
+                | Ok(features) => {
+                    if let Some(signature) = self.user_signatures.get_mut(&user_id) {
+                        Self::reextract(signature, features, current_version);
+                    }
+                    report.migrated.push(user_id);
+                },
+                | Err(e) => {
+                    warn!("Failed to migrate EEG signature of {}: {}", user_id, e);
+                    report.failed.insert(user_id, e.to_string());
+                },
+            }
+        }
+
+        info!(
+            "🧠 EEG signature migration to feature version {}: {} migrated, {} pending, {} failed",
+            current_version,
+            report.migrated.len(),
+            report.pending.len(),
+            report.failed.len()
+        );
+        report
+    }
+
+    /// Replace a signature's template with features of the current version
+    fn reextract(signature: &mut UserSignature, features: EEGFeatures, version: u32) {
+        info!(
+            "🔄 Re-extracted EEG signature of {} (feature version {} → {})",
+            signature.user_id, signature.feature_version, version
+        );
+        signature.feature_template = features;
+        signature.feature_version = version;
+        signature.enrollment_count = 1;
+        signature.last_updated = chrono::Utc::now();
+    }
+}
+
+/// Outcome of [`EEGAuthService::migrate_signatures`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SignatureMigrationReport {
+    /// Feature version signatures were migrated to
+    pub feature_version: u32,
+    /// Users whose template was re-extracted
+    pub migrated: Vec<String>,
+    /// Outdated users without a sample who still need to re-enroll
+    pub pending: Vec<String>,
+    /// Users whose sample could not be processed, with the reason
+    pub failed: HashMap<String, String>,
 }
 
 /// Result of EEG authentication attempt
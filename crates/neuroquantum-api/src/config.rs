@@ -7,8 +7,17 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DatabaseConfig {
     pub data_path: String,
+    /// Maximum number of API requests processed concurrently
     pub max_connections: u32,
+    /// Maximum time a request waits for a free connection slot
     pub connection_timeout_seconds: u64,
+    /// Requests allowed to wait for a slot before new ones are rejected with 503
+    #[serde(default = "default_max_queue_depth")]
+    pub max_queue_depth: u32,
+}
+
+const fn default_max_queue_depth() -> u32 {
+    100
 }
 
 impl Default for DatabaseConfig {
@@ -17,6 +26,7 @@ impl Default for DatabaseConfig {
             data_path: "./data".to_string(),
             max_connections: 100,
             connection_timeout_seconds: 30,
+            max_queue_depth: default_max_queue_depth(),
         }
     }
 }
@@ -34,6 +44,10 @@ pub struct ApiConfig {
     pub redis: Option<RedisConfig>,
     pub logging: LoggingConfig,
     pub tracing: TracingConfig,
+    #[serde(default)]
+    pub audit: AuditConfig,
+    #[serde(default)]
+    pub auth: AuthConfig,
 }
 
 /// Server configuration
@@ -47,6 +61,24 @@ pub struct ServerConfig {
     pub client_timeout: u64,
     pub client_shutdown: u64,
     pub tls: Option<TlsConfig>,
+    /// Port for the gRPC service on `host`; the service is disabled when unset
+    #[serde(default)]
+    pub grpc_port: Option<u16>,
+    /// Seconds a gRPC transaction may go without statements before it is
+    /// aborted; 0 keeps idle transactions open
+    #[serde(default = "default_grpc_transaction_idle_timeout_secs")]
+    pub grpc_transaction_idle_timeout_secs: u64,
+    /// Seconds in-flight requests and WebSocket clients get to finish on shutdown
+    #[serde(default = "default_shutdown_grace_period_secs")]
+    pub shutdown_grace_period_secs: u64,
+}
+
+const fn default_shutdown_grace_period_secs() -> u64 {
+    30
+}
+
+const fn default_grpc_transaction_idle_timeout_secs() -> u64 {
+    300
 }
 
 impl Default for ServerConfig {
@@ -60,6 +92,9 @@ impl Default for ServerConfig {
             client_timeout: 5000,
             client_shutdown: 5000,
             tls: None,
+            grpc_port: None,
+            grpc_transaction_idle_timeout_secs: default_grpc_transaction_idle_timeout_secs(),
+            shutdown_grace_period_secs: default_shutdown_grace_period_secs(),
         }
     }
 }
@@ -69,7 +104,57 @@ impl Default for ServerConfig {
 pub struct TlsConfig {
     pub cert_file: String,
     pub key_file: String,
+    /// CA certificates client certificates are verified against
     pub ca_file: Option<String>,
+    /// Whether clients present certificates, see [`ClientAuthMode`]
+    #[serde(default)]
+    pub client_auth: ClientAuthMode,
+}
+
+/// Client certificate handling of the TLS listener
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientAuthMode {
+    /// Client certificates are not requested
+    #[default]
+    Disabled,
+    /// Clients may present a certificate, which must then chain to `ca_file`
+    Optional,
+    /// Connections without a certificate chaining to `ca_file` are refused
+    Required,
+}
+
+/// Authentication methods accepted by protected endpoints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuthConfig {
+    /// Accept `Authorization: Bearer` JWTs
+    pub jwt: bool,
+    /// Accept `X-API-Key` headers
+    pub api_key: bool,
+    /// Accept verified TLS client certificates listed in `client_certificates`
+    pub client_certificate: bool,
+    /// Principals that client certificates authenticate as
+    pub client_certificates: Vec<ClientCertificatePrincipal>,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            jwt: true,
+            api_key: true,
+            client_certificate: true,
+            client_certificates: Vec::new(),
+        }
+    }
+}
+
+/// Permissions granted to clients presenting a certificate for `identity`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientCertificatePrincipal {
+    /// Common name or subject alternative name of the certificate
+    pub identity: String,
+    pub permissions: Vec<String>,
 }
 
 /// JWT authentication configuration
@@ -137,17 +222,22 @@ impl Default for RateLimitConfig {
 }
 
 /// Rate limiting strategy
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum RateLimitStrategy {
-    TokenBucket,
-    SlidingWindow,
-    FixedWindow,
-}
+pub type RateLimitStrategy = crate::rate_limit::RateLimitAlgorithm;
 
 /// CORS configuration
+///
+/// No origin is allowed by default; see [`crate::cors`] for how the policy
+/// is applied.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CorsConfig {
+    /// Origins allowed verbatim, e.g. `https://app.example.com`
     pub allowed_origins: Vec<String>,
+    /// Regexes an origin must match in full, e.g. `https://[a-z]+\.example\.com`
+    #[serde(default)]
+    pub allowed_origin_patterns: Vec<String>,
+    /// Allow every origin, for development only
+    #[serde(default)]
+    pub allow_any_origin: bool,
     pub allowed_methods: Vec<String>,
     pub allowed_headers: Vec<String>,
     pub expose_headers: Vec<String>,
@@ -158,11 +248,9 @@ pub struct CorsConfig {
 impl Default for CorsConfig {
     fn default() -> Self {
         Self {
-            allowed_origins: vec![
-                "http://localhost:3000".to_string(),
-                "http://localhost:8080".to_string(),
-                "https://app.neuroquantumdb.com".to_string(),
-            ],
+            allowed_origins: Vec::new(),
+            allowed_origin_patterns: Vec::new(),
+            allow_any_origin: false,
             allowed_methods: vec![
                 "GET".to_string(),
                 "POST".to_string(),
@@ -177,12 +265,16 @@ impl Default for CorsConfig {
                 "X-API-Key".to_string(),
                 "X-Request-ID".to_string(),
                 "X-Quantum-Level".to_string(),
+                "traceparent".to_string(),
+                "tracestate".to_string(),
             ],
             expose_headers: vec![
                 "X-RateLimit-Limit".to_string(),
                 "X-RateLimit-Remaining".to_string(),
                 "X-RateLimit-Reset".to_string(),
                 "X-Request-ID".to_string(),
+                "X-API-Version".to_string(),
+                "traceparent".to_string(),
             ],
             max_age: 3600,
             allow_credentials: true,
@@ -190,10 +282,24 @@ impl Default for CorsConfig {
     }
 }
 
+/// Default limit on request bodies, see [`SecurityConfig::max_request_body_bytes`]
+pub const DEFAULT_MAX_REQUEST_BODY_BYTES: usize = 16 * 1024 * 1024;
+
+/// Default limit on streamed uploads, see [`SecurityConfig::max_import_body_bytes`]
+pub const DEFAULT_MAX_IMPORT_BODY_BYTES: usize = 1024 * 1024 * 1024;
+
 /// Security configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SecurityConfig {
-    pub max_payload_size: usize,
+    /// Largest JSON request body accepted, in bytes
+    ///
+    /// Larger bodies are rejected with `413 Payload Too Large` as soon as the
+    /// `Content-Length` or the bytes received exceed the limit.
+    #[serde(alias = "max_payload_size")]
+    pub max_request_body_bytes: usize,
+    /// Largest streamed upload accepted by import endpoints, in bytes
+    #[serde(default = "default_max_import_body_bytes")]
+    pub max_import_body_bytes: usize,
     pub request_timeout_seconds: u64,
     pub security_headers: bool,
     pub csrf_protection: bool,
@@ -233,10 +339,15 @@ pub struct EncryptionAtRestConfig {
     pub production_mode: bool,
 }
 
+const fn default_max_import_body_bytes() -> usize {
+    DEFAULT_MAX_IMPORT_BODY_BYTES
+}
+
 impl Default for SecurityConfig {
     fn default() -> Self {
         Self {
-            max_payload_size: 16 * 1024 * 1024, // 16MB
+            max_request_body_bytes: DEFAULT_MAX_REQUEST_BODY_BYTES,
+            max_import_body_bytes: DEFAULT_MAX_IMPORT_BODY_BYTES,
             request_timeout_seconds: 30,
             security_headers: true,
             csrf_protection: false, // Disabled for API-only service
@@ -317,6 +428,13 @@ impl Default for RedisConfig {
     }
 }
 
+/// Audit log configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AuditConfig {
+    /// File the audit log is appended to; entries are only kept in memory when unset
+    pub path: Option<String>,
+}
+
 /// Logging configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoggingConfig {
@@ -462,12 +580,24 @@ impl ApiConfig {
             self.logging.level = log_level;
         }
 
-        if let Ok(max_payload) = std::env::var("NEUROQUANTUM_MAX_PAYLOAD_SIZE") {
+        if let Ok(max_payload) = std::env::var("NEUROQUANTUM_MAX_REQUEST_BODY_BYTES")
+            .or_else(|_| std::env::var("NEUROQUANTUM_MAX_PAYLOAD_SIZE"))
+        {
             if let Ok(size) = max_payload.parse::<usize>() {
-                self.security.max_payload_size = size;
+                self.security.max_request_body_bytes = size;
             }
         }
 
+        if let Ok(max_import) = std::env::var("NEUROQUANTUM_MAX_IMPORT_BODY_BYTES") {
+            if let Ok(size) = max_import.parse::<usize>() {
+                self.security.max_import_body_bytes = size;
+            }
+        }
+
+        if let Ok(audit_path) = std::env::var("NEUROQUANTUM_AUDIT_LOG_PATH") {
+            self.audit.path = Some(audit_path);
+        }
+
         if let Ok(rate_limit) = std::env::var("NEUROQUANTUM_RATE_LIMIT") {
             if let Ok(limit) = rate_limit.parse::<u32>() {
                 self.rate_limit.requests_per_hour = limit;
@@ -514,13 +644,39 @@ impl ApiConfig {
         }
 
         // Validate payload size
-        if self.security.max_payload_size > 100 * 1024 * 1024 {
+        if self.security.max_request_body_bytes > 100 * 1024 * 1024 {
             tracing::warn!(
                 "Max payload size is very large ({}MB). This may impact performance.",
-                self.security.max_payload_size / (1024 * 1024)
+                self.security.max_request_body_bytes / (1024 * 1024)
+            );
+        }
+        if self.security.max_import_body_bytes < self.security.max_request_body_bytes {
+            tracing::warn!(
+                "Import body limit ({} bytes) is below the request body limit ({} bytes)",
+                self.security.max_import_body_bytes,
+                self.security.max_request_body_bytes
             );
         }
 
+        // Validate CORS policy
+        for pattern in &self.cors.allowed_origin_patterns {
+            crate::cors::origin_pattern(pattern)
+                .map_err(|e| anyhow::anyhow!("Invalid CORS origin pattern '{pattern}': {e}"))?;
+        }
+        for method in &self.cors.allowed_methods {
+            actix_web::http::Method::from_bytes(method.as_bytes())
+                .map_err(|_| anyhow::anyhow!("Invalid CORS method '{method}'"))?;
+        }
+        for header in self
+            .cors
+            .allowed_headers
+            .iter()
+            .chain(&self.cors.expose_headers)
+        {
+            actix_web::http::header::HeaderName::from_bytes(header.as_bytes())
+                .map_err(|_| anyhow::anyhow!("Invalid CORS header '{header}'"))?;
+        }
+
         // Validate Redis URL if provided
         if let Some(redis_config) = &self.redis {
             if !redis_config.url.starts_with("redis://")
@@ -587,7 +743,7 @@ impl ApiConfig {
                 ..RateLimitConfig::default()
             },
             security: SecurityConfig {
-                max_payload_size: 32 * 1024 * 1024, // 32MB for dev
+                max_request_body_bytes: 32 * 1024 * 1024, // 32MB for dev
                 quantum_encryption: false,
                 ..SecurityConfig::default()
             },
@@ -597,6 +753,13 @@ impl ApiConfig {
                 structured_logging: false,
                 ..LoggingConfig::default()
             },
+            cors: CorsConfig {
+                allowed_origins: vec![
+                    "http://localhost:3000".to_string(),
+                    "http://localhost:8080".to_string(),
+                ],
+                ..CorsConfig::default()
+            },
             ..Self::default()
         }
     }
@@ -614,6 +777,7 @@ impl ApiConfig {
                     cert_file: "/etc/ssl/certs/neuroquantum.crt".to_string(),
                     key_file: "/etc/ssl/private/neuroquantum.key".to_string(),
                     ca_file: None,
+                    client_auth: ClientAuthMode::Disabled,
                 }),
                 ..ServerConfig::default()
             },
@@ -628,7 +792,7 @@ impl ApiConfig {
                 ..RateLimitConfig::default()
             },
             security: SecurityConfig {
-                max_payload_size: 8 * 1024 * 1024, // 8MB for production
+                max_request_body_bytes: 8 * 1024 * 1024, // 8MB for production
                 quantum_encryption: true,
                 security_headers: true,
                 ..SecurityConfig::default()
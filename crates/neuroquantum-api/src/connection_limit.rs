@@ -0,0 +1,214 @@
+//! Admission control for API requests.
+//!
+//! [`ConnectionLimiter`] caps the number of requests that are processed
+//! concurrently at `DatabaseConfig::max_connections`. Requests beyond the limit
+//! wait for a free slot, up to `max_queue_depth` waiting requests and at most
+//! `connection_timeout_seconds`; anything past that is rejected with
+//! `503 Service Unavailable` and a `Retry-After` header.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::header::{HeaderValue, RETRY_AFTER};
+use actix_web::http::StatusCode;
+use actix_web::{Error, HttpResponse, ResponseError};
+use futures_util::future::LocalBoxFuture;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::warn;
+
+use crate::config::DatabaseConfig;
+use crate::error::ApiError;
+
+/// Seconds clients are asked to wait before retrying a rejected request
+const RETRY_AFTER_SECONDS: u64 = 1;
+
+/// Semaphore-based concurrency limit shared by all API workers
+#[derive(Debug)]
+pub struct ConnectionLimiter {
+    permits: Arc<Semaphore>,
+    max_connections: usize,
+    max_queue_depth: usize,
+    queued: AtomicUsize,
+    queue_timeout: Duration,
+}
+
+/// A slot held for the duration of one request; released on drop
+#[derive(Debug)]
+pub struct ConnectionPermit {
+    _permit: OwnedSemaphorePermit,
+}
+
+impl ConnectionLimiter {
+    /// Create a limiter admitting `max_connections` concurrent requests.
+    ///
+    /// At most `max_queue_depth` further requests wait for a slot, each for no
+    /// longer than `queue_timeout`. A limit of zero is treated as one.
+    #[must_use]
+    pub fn new(max_connections: usize, max_queue_depth: usize, queue_timeout: Duration) -> Self {
+        let max_connections = max_connections.max(1);
+        Self {
+            permits: Arc::new(Semaphore::new(max_connections)),
+            max_connections,
+            max_queue_depth,
+            queued: AtomicUsize::new(0),
+            queue_timeout,
+        }
+    }
+
+    /// Create a limiter sized from the database configuration
+    #[must_use]
+    pub fn from_config(config: &DatabaseConfig) -> Self {
+        Self::new(
+            config.max_connections as usize,
+            config.max_queue_depth as usize,
+            Duration::from_secs(config.connection_timeout_seconds),
+        )
+    }
+
+    /// Maximum number of concurrently admitted requests
+    #[must_use]
+    pub const fn max_connections(&self) -> usize {
+        self.max_connections
+    }
+
+    /// Number of requests currently holding a slot
+    #[must_use]
+    pub fn active_connections(&self) -> usize {
+        self.max_connections - self.permits.available_permits()
+    }
+
+    /// Number of requests currently waiting for a slot
+    #[must_use]
+    pub fn queued_requests(&self) -> usize {
+        self.queued.load(Ordering::Acquire)
+    }
+
+    /// Acquire a slot, waiting in the queue if one is available.
+    ///
+    /// Returns `None` if the queue is full or the wait timed out.
+    pub async fn acquire(&self) -> Option<ConnectionPermit> {
+        if let Ok(permit) = self.permits.clone().try_acquire_owned() {
+            return Some(ConnectionPermit { _permit: permit });
+        }
+
+        let max_queue_depth = self.max_queue_depth;
+        self.queued
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |queued| {
+                (queued < max_queue_depth).then_some(queued + 1)
+            })
+            .ok()?;
+
+        let permit = tokio::time::timeout(self.queue_timeout, self.permits.clone().acquire_owned())
+            .await
+            .ok()
+            .and_then(Result::ok);
+        self.queued.fetch_sub(1, Ordering::AcqRel);
+
+        permit.map(|permit| ConnectionPermit { _permit: permit })
+    }
+}
+
+/// Middleware enforcing a [`ConnectionLimiter`]
+pub struct ConnectionLimitMiddleware {
+    limiter: Arc<ConnectionLimiter>,
+}
+
+impl ConnectionLimitMiddleware {
+    #[must_use]
+    pub const fn new(limiter: Arc<ConnectionLimiter>) -> Self {
+        Self { limiter }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for ConnectionLimitMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = ConnectionLimitMiddlewareService<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(ConnectionLimitMiddlewareService {
+            service: std::rc::Rc::new(service),
+            limiter: self.limiter.clone(),
+        }))
+    }
+}
+
+pub struct ConnectionLimitMiddlewareService<S> {
+    service: std::rc::Rc<S>,
+    limiter: Arc<ConnectionLimiter>,
+}
+
+impl<S, B> Service<ServiceRequest> for ConnectionLimitMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let limiter = self.limiter.clone();
+
+        Box::pin(async move {
+            let Some(_permit) = limiter.acquire().await else {
+                warn!(
+                    max_connections = limiter.max_connections(),
+                    path = req.path(),
+                    "🚦 Connection limit reached, rejecting request"
+                );
+                return Err(Error::from(ConnectionLimitError {
+                    max_connections: limiter.max_connections(),
+                }));
+            };
+
+            service.call(req).await
+        })
+    }
+}
+
+// Admission error type for middleware
+#[derive(Debug)]
+struct ConnectionLimitError {
+    max_connections: usize,
+}
+
+impl std::fmt::Display for ConnectionLimitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Connection limit exceeded")
+    }
+}
+
+impl ResponseError for ConnectionLimitError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::SERVICE_UNAVAILABLE
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let mut response = ApiError::ServiceUnavailable {
+            service: "api".to_string(),
+            reason: format!(
+                "server is at capacity ({} concurrent requests)",
+                self.max_connections
+            ),
+        }
+        .error_response();
+        response
+            .headers_mut()
+            .insert(RETRY_AFTER, HeaderValue::from(RETRY_AFTER_SECONDS));
+        response
+    }
+}
@@ -0,0 +1,67 @@
+//! CORS policy built from [`CorsConfig`].
+//!
+//! Nothing is allowed unless configured: a browser origin is accepted when it
+//! is listed in `allowed_origins` or fully matches one of the
+//! `allowed_origin_patterns` regexes. `allow_any_origin` accepts every origin
+//! and is meant for development; combined with `allow_credentials` the
+//! request's origin is echoed back instead of `*`, as browsers refuse
+//! credentialed responses for a wildcard.
+//!
+//! Requests from other origins are rejected with `400 Bad Request`.
+
+use actix_cors::Cors;
+use regex::Regex;
+use tracing::{error, warn};
+
+use crate::config::CorsConfig;
+
+/// Compile an origin pattern, anchored so it must match the whole origin
+pub fn origin_pattern(pattern: &str) -> Result<Regex, regex::Error> {
+    Regex::new(&format!("^(?:{pattern})$"))
+}
+
+/// Build the CORS middleware for `config`
+///
+/// Invalid origin patterns are logged and skipped; `ApiConfig::validate`
+/// rejects them at startup.
+pub fn cors_middleware(config: &CorsConfig) -> Cors {
+    let mut cors = Cors::default()
+        .allowed_methods(config.allowed_methods.iter().map(String::as_str))
+        .allowed_headers(config.allowed_headers.iter().map(String::as_str))
+        .expose_headers(config.expose_headers.iter().map(String::as_str))
+        .max_age(config.max_age as usize);
+    if config.allow_credentials {
+        cors = cors.supports_credentials();
+    }
+
+    if config.allow_any_origin {
+        if !cfg!(debug_assertions) {
+            warn!("⚠️  CORS allows any origin; restrict cors.allowed_origins in production");
+        }
+        return cors.allow_any_origin();
+    }
+
+    for origin in &config.allowed_origins {
+        cors = cors.allowed_origin(origin);
+    }
+
+    let patterns: Vec<Regex> = config
+        .allowed_origin_patterns
+        .iter()
+        .filter_map(|pattern| match origin_pattern(pattern) {
+            | Ok(regex) => Some(regex),
+            | Err(e) => {
+                error!("Ignoring invalid CORS origin pattern '{}': {}", pattern, e);
+                None
+            },
+        })
+        .collect();
+    if !patterns.is_empty() {
+        cors = cors.allowed_origin_fn(move |origin, _| {
+            origin
+                .to_str()
+                .is_ok_and(|origin| patterns.iter().any(|pattern| pattern.is_match(origin)))
+        });
+    }
+    cors
+}
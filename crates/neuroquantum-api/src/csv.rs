@@ -0,0 +1,192 @@
+//! Minimal streaming CSV reader and writer used by the import/export endpoints
+//!
+//! The reader follows RFC 4180: fields may be quoted, quotes inside quoted
+//! fields are doubled, and quoted fields may span lines. Input is fed in
+//! arbitrary chunks so uploads can be parsed while they are still arriving.
+//! Parsing is byte-oriented; delimiters, quotes and line breaks are ASCII and
+//! never collide with UTF-8 continuation bytes.
+
+/// A parsed CSV record together with the line it started on (1-based)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvRecord {
+    pub line: usize,
+    pub fields: Vec<String>,
+}
+
+/// A malformed record, reported with the line it started on (1-based)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvError {
+    pub line: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// At the start of a field
+    FieldStart,
+    /// Inside an unquoted field
+    Unquoted,
+    /// Inside a quoted field
+    Quoted,
+    /// Just saw a quote inside a quoted field: either an escaped quote or the end
+    QuoteInQuoted,
+    /// The current record is malformed; skip until the end of the line
+    Skipping,
+}
+
+/// Incremental CSV parser
+#[derive(Debug)]
+pub struct CsvReader {
+    delimiter: u8,
+    state: State,
+    field: Vec<u8>,
+    record: Vec<String>,
+    line: usize,
+    record_line: usize,
+    error: Option<String>,
+}
+
+impl CsvReader {
+    /// Create a reader for the given single-byte delimiter
+    pub const fn new(delimiter: u8) -> Self {
+        Self {
+            delimiter,
+            state: State::FieldStart,
+            field: Vec::new(),
+            record: Vec::new(),
+            line: 1,
+            record_line: 1,
+            error: None,
+        }
+    }
+
+    /// Feed a chunk of input, returning every record completed by it
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<Result<CsvRecord, CsvError>> {
+        let mut out = Vec::new();
+        for &byte in chunk {
+            self.push_byte(byte, &mut out);
+        }
+        out
+    }
+
+    /// Signal end of input, returning the final record if one is pending
+    pub fn finish(&mut self) -> Option<Result<CsvRecord, CsvError>> {
+        match self.state {
+            | State::Quoted => {
+                self.error = Some("unterminated quoted field".to_string());
+            },
+            | State::FieldStart if self.record.is_empty() => return None,
+            | State::Skipping => return self.end_record(),
+            | _ => {},
+        }
+        self.end_field();
+        self.end_record()
+    }
+
+    fn push_byte(&mut self, byte: u8, out: &mut Vec<Result<CsvRecord, CsvError>>) {
+        match self.state {
+            | State::Skipping => {
+                if byte == b'\n' {
+                    out.extend(self.end_record());
+                    self.line += 1;
+                }
+            },
+            | State::Quoted => {
+                if byte == b'"' {
+                    self.state = State::QuoteInQuoted;
+                } else {
+                    if byte == b'\n' {
+                        self.line += 1;
+                    }
+                    self.field.push(byte);
+                }
+            },
+            | State::QuoteInQuoted if byte == b'"' => {
+                self.field.push(b'"');
+                self.state = State::Quoted;
+            },
+            | State::FieldStart if byte == b'"' => self.state = State::Quoted,
+            | State::Unquoted if byte == b'"' => {
+                self.fail("unexpected quote in unquoted field");
+            },
+            | State::FieldStart | State::Unquoted | State::QuoteInQuoted => {
+                if byte == self.delimiter {
+                    self.end_field();
+                    self.state = State::FieldStart;
+                } else if byte == b'\n' {
+                    let blank_line = self.state == State::FieldStart && self.record.is_empty();
+                    if blank_line {
+                        self.record_line = self.line + 1;
+                    } else {
+                        self.end_field();
+                        out.extend(self.end_record());
+                    }
+                    self.state = State::FieldStart;
+                    self.line += 1;
+                } else if byte == b'\r' {
+                    // Tolerate CRLF line endings; the '\n' ends the record
+                } else if self.state == State::QuoteInQuoted {
+                    self.fail("unexpected character after closing quote");
+                } else {
+                    self.field.push(byte);
+                    self.state = State::Unquoted;
+                }
+            },
+        }
+    }
+
+    fn fail(&mut self, message: &str) {
+        self.error = Some(message.to_string());
+        self.state = State::Skipping;
+    }
+
+    fn end_field(&mut self) {
+        let bytes = std::mem::take(&mut self.field);
+        match String::from_utf8(bytes) {
+            | Ok(field) => self.record.push(field),
+            | Err(_) => {
+                if self.error.is_none() {
+                    self.error = Some("field is not valid UTF-8".to_string());
+                }
+            },
+        }
+    }
+
+    fn end_record(&mut self) -> Option<Result<CsvRecord, CsvError>> {
+        let line = self.record_line;
+        let fields = std::mem::take(&mut self.record);
+        self.field.clear();
+        self.state = State::FieldStart;
+        // The next record starts on the line after the current one ends
+        self.record_line = self.line + 1;
+
+        Some(match self.error.take() {
+            | Some(message) => Err(CsvError { line, message }),
+            | None => Ok(CsvRecord { line, fields }),
+        })
+    }
+}
+
+/// Append one CSV record to `out`, quoting fields where required
+pub fn write_record<I, S>(fields: I, delimiter: u8, out: &mut String)
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let delimiter = char::from(delimiter);
+    for (idx, field) in fields.into_iter().enumerate() {
+        if idx > 0 {
+            out.push(delimiter);
+        }
+        let field = field.as_ref();
+        let needs_quotes = field.contains(|c| c == delimiter || matches!(c, '"' | '\n' | '\r'));
+        if needs_quotes {
+            out.push('"');
+            out.push_str(&field.replace('"', "\"\""));
+            out.push('"');
+        } else {
+            out.push_str(field);
+        }
+    }
+    out.push_str("\r\n");
+}
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
+use actix_web::http::StatusCode;
 use actix_web::{HttpResponse, ResponseError};
+use async_graphql::SimpleObject;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use utoipa::ToSchema;
@@ -21,6 +23,9 @@ pub enum ApiError {
     #[error("Resource not found: {0}")]
     NotFound(String),
 
+    #[error("Table not found: {table}")]
+    TableNotFound { table: String },
+
     #[error("Conflict: {0}")]
     Conflict(String),
 
@@ -30,6 +35,12 @@ pub enum ApiError {
     #[error("Invalid query: {details}")]
     InvalidQuery { details: String },
 
+    #[error("Query cancelled: {request_id}")]
+    QueryCancelled { request_id: String },
+
+    #[error("Query timed out: {details}")]
+    QueryTimeout { details: String },
+
     #[error("Quantum operation failed: {operation} - {reason}")]
     QuantumOperationFailed { operation: String, reason: String },
 
@@ -42,6 +53,9 @@ pub enum ApiError {
     #[error("Validation error: {field} - {message}")]
     ValidationError { field: String, message: String },
 
+    #[error("Payload too large: request body exceeds {limit} bytes")]
+    PayloadTooLarge { limit: usize },
+
     #[error("Quantum-resistant encryption error: {details}")]
     EncryptionError { details: String },
 
@@ -62,6 +76,242 @@ pub enum ApiError {
 
     #[error("Not implemented: {0}")]
     NotImplemented(String),
+
+    #[error("Unsupported API version: {requested}")]
+    UnsupportedApiVersion {
+        requested: String,
+        supported: Vec<String>,
+    },
+}
+
+/// Stable machine-readable error codes
+///
+/// Every [`ApiError`] maps to exactly one code, which is sent as `error_code`
+/// in the error envelope. Clients should branch on the code rather than the
+/// human-readable message; codes are never renamed once published.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    /// Missing or invalid credentials (401)
+    Unauthorized,
+    /// The API key lacks a required permission or scope (403)
+    PermissionDenied,
+    /// Malformed request (400)
+    BadRequest,
+    /// A request field failed validation (400)
+    ValidationFailed,
+    /// The request body exceeds the configured limit (413)
+    PayloadTooLarge,
+    /// The requested resource doesn't exist (404)
+    NotFound,
+    /// The table named by the request or query doesn't exist (404)
+    TableNotFound,
+    /// The request conflicts with the current state (409)
+    Conflict,
+    /// The query could not be parsed or executed (400)
+    InvalidQuery,
+    /// The query was cancelled before it finished (409)
+    QueryCancelled,
+    /// The query ran longer than allowed (504)
+    QueryTimeout,
+    /// Too many requests for the rate limit (429)
+    RateLimited,
+    /// A quantum operation failed (500)
+    QuantumOperationFailed,
+    /// DNA compression or decompression failed (500)
+    CompressionFailed,
+    /// Encryption or decryption failed (500)
+    EncryptionFailed,
+    /// Neural network training or inference failed (500)
+    NeuralNetworkFailed,
+    /// A table operation failed (500)
+    TableOperationFailed,
+    /// No database connection could be obtained (500)
+    ConnectionPoolExhausted,
+    /// A dependency is failing and requests to it are rejected (503)
+    CircuitBreakerOpen,
+    /// The service is temporarily unavailable (503)
+    ServiceUnavailable,
+    /// The endpoint is not implemented (501)
+    NotImplemented,
+    /// The requested API version is not provided by this server (406)
+    UnsupportedApiVersion,
+    /// Unexpected server-side failure (500)
+    InternalError,
+}
+
+impl ErrorCode {
+    /// The code as sent to clients
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            | Self::Unauthorized => "UNAUTHORIZED",
+            | Self::PermissionDenied => "PERMISSION_DENIED",
+            | Self::BadRequest => "BAD_REQUEST",
+            | Self::ValidationFailed => "VALIDATION_FAILED",
+            | Self::PayloadTooLarge => "PAYLOAD_TOO_LARGE",
+            | Self::NotFound => "NOT_FOUND",
+            | Self::TableNotFound => "TABLE_NOT_FOUND",
+            | Self::Conflict => "CONFLICT",
+            | Self::InvalidQuery => "INVALID_QUERY",
+            | Self::QueryCancelled => "QUERY_CANCELLED",
+            | Self::QueryTimeout => "QUERY_TIMEOUT",
+            | Self::RateLimited => "RATE_LIMITED",
+            | Self::QuantumOperationFailed => "QUANTUM_OPERATION_FAILED",
+            | Self::CompressionFailed => "COMPRESSION_FAILED",
+            | Self::EncryptionFailed => "ENCRYPTION_FAILED",
+            | Self::NeuralNetworkFailed => "NEURAL_NETWORK_FAILED",
+            | Self::TableOperationFailed => "TABLE_OPERATION_FAILED",
+            | Self::ConnectionPoolExhausted => "CONNECTION_POOL_EXHAUSTED",
+            | Self::CircuitBreakerOpen => "CIRCUIT_BREAKER_OPEN",
+            | Self::ServiceUnavailable => "SERVICE_UNAVAILABLE",
+            | Self::NotImplemented => "NOT_IMPLEMENTED",
+            | Self::UnsupportedApiVersion => "UNSUPPORTED_API_VERSION",
+            | Self::InternalError => "INTERNAL_ERROR",
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl ApiError {
+    /// Machine-readable code identifying this error
+    #[must_use]
+    pub const fn code(&self) -> ErrorCode {
+        match self {
+            | Self::Unauthorized(_) => ErrorCode::Unauthorized,
+            | Self::Forbidden(_) => ErrorCode::PermissionDenied,
+            | Self::BadRequest(_) => ErrorCode::BadRequest,
+            | Self::ValidationError { .. } => ErrorCode::ValidationFailed,
+            | Self::PayloadTooLarge { .. } => ErrorCode::PayloadTooLarge,
+            | Self::NotFound(_) => ErrorCode::NotFound,
+            | Self::TableNotFound { .. } => ErrorCode::TableNotFound,
+            | Self::Conflict(_) => ErrorCode::Conflict,
+            | Self::InvalidQuery { .. } => ErrorCode::InvalidQuery,
+            | Self::QueryCancelled { .. } => ErrorCode::QueryCancelled,
+            | Self::QueryTimeout { .. } => ErrorCode::QueryTimeout,
+            | Self::RateLimitExceeded { .. } => ErrorCode::RateLimited,
+            | Self::QuantumOperationFailed { .. } => ErrorCode::QuantumOperationFailed,
+            | Self::CompressionError { .. } => ErrorCode::CompressionFailed,
+            | Self::EncryptionError { .. } => ErrorCode::EncryptionFailed,
+            | Self::NeuralNetworkError { .. } => ErrorCode::NeuralNetworkFailed,
+            | Self::TableError { .. } => ErrorCode::TableOperationFailed,
+            | Self::ConnectionPoolError { .. } => ErrorCode::ConnectionPoolExhausted,
+            | Self::CircuitBreakerOpen { .. } => ErrorCode::CircuitBreakerOpen,
+            | Self::ServiceUnavailable { .. } => ErrorCode::ServiceUnavailable,
+            | Self::NotImplemented(_) => ErrorCode::NotImplemented,
+            | Self::UnsupportedApiVersion { .. } => ErrorCode::UnsupportedApiVersion,
+            | Self::InternalServerError { .. } => ErrorCode::InternalError,
+        }
+    }
+}
+
+impl ApiError {
+    /// Convert a failed query into the error with the most specific code
+    ///
+    /// QSQL and core errors are converted through their `From` impls; any
+    /// other failure is reported as an invalid query, or as a missing table if
+    /// its message names one.
+    #[must_use]
+    pub fn from_query_error(error: anyhow::Error) -> Self {
+        let error = match error.downcast::<neuroquantum_qsql::QSQLError>() {
+            | Ok(error) => return error.into(),
+            | Err(error) => error,
+        };
+        let error = match error.downcast::<neuroquantum_core::NeuroQuantumError>() {
+            | Ok(error) => return error.into(),
+            | Err(error) => error,
+        };
+        let details = format!("Query execution failed: {error:#}");
+        missing_table(&details).map_or(Self::InvalidQuery { details }, |table| {
+            Self::TableNotFound { table }
+        })
+    }
+}
+
+/// Name of the missing table if `message` reports one
+///
+/// The storage engine reports unknown tables as `Table '<name>' does not
+/// exist`, which QSQL wraps into its own messages.
+fn missing_table(message: &str) -> Option<String> {
+    let start = message.find("Table '")? + "Table '".len();
+    let len = message[start..].find("' does not exist")?;
+    Some(message[start..start + len].to_string())
+}
+
+impl From<neuroquantum_qsql::QSQLError> for ApiError {
+    fn from(error: neuroquantum_qsql::QSQLError) -> Self {
+        use neuroquantum_qsql::QSQLError;
+
+        let details = error.to_string();
+        if let Some(table) = missing_table(&details) {
+            return Self::TableNotFound { table };
+        }
+        match error {
+            | QSQLError::Cancelled => Self::QueryCancelled {
+                request_id: String::new(),
+            },
+            | QSQLError::NeuromorphicError { .. } => Self::NeuralNetworkError { details },
+            | QSQLError::QuantumError { message } => Self::QuantumOperationFailed {
+                operation: "query".to_string(),
+                reason: message,
+            },
+            | QSQLError::ConfigError { .. }
+            | QSQLError::MemoryError { .. }
+            | QSQLError::IOError { .. }
+            | QSQLError::SerializationError { .. } => {
+                Self::InternalServerError { message: details }
+            },
+            | QSQLError::ParseError { .. }
+            | QSQLError::SemanticError { .. }
+            | QSQLError::OptimizationError { .. }
+            | QSQLError::ExecutionError { .. }
+            | QSQLError::NLPError { .. }
+            | QSQLError::TypeError { .. }
+            | QSQLError::RuntimeError { .. }
+            | QSQLError::PreparedStatementError { .. } => Self::InvalidQuery { details },
+        }
+    }
+}
+
+impl From<neuroquantum_core::NeuroQuantumError> for ApiError {
+    fn from(error: neuroquantum_core::NeuroQuantumError) -> Self {
+        use neuroquantum_core::NeuroQuantumError;
+
+        let details = error.to_string();
+        if let Some(table) = missing_table(&details) {
+            return Self::TableNotFound { table };
+        }
+        match error {
+            | NeuroQuantumError::NotFound(message) => Self::NotFound(message),
+            | NeuroQuantumError::Timeout(_) => Self::QueryTimeout { details },
+            | NeuroQuantumError::QueryError(_) => Self::InvalidQuery { details },
+            | NeuroQuantumError::ValidationError(message) => Self::ValidationError {
+                field: String::new(),
+                message,
+            },
+            | NeuroQuantumError::AuthError(message) => Self::Unauthorized(message),
+            | NeuroQuantumError::SecurityError(message) => Self::Forbidden(message),
+            | NeuroQuantumError::CompressionError(reason) => Self::CompressionError { reason },
+            | NeuroQuantumError::LearningError(_) => Self::NeuralNetworkError { details },
+            | NeuroQuantumError::TransactionError(_)
+            | NeuroQuantumError::DeadlockDetected(_)
+            | NeuroQuantumError::IsolationViolation(_)
+            | NeuroQuantumError::ConcurrentModification(_)
+            | NeuroQuantumError::TransactionAborted { .. } => Self::Conflict(details),
+            | NeuroQuantumError::ResourceExhausted(_) | NeuroQuantumError::NotInitialized(_) => {
+                Self::ServiceUnavailable {
+                    service: "database".to_string(),
+                    reason: details,
+                }
+            },
+            | _ => Self::InternalServerError { message: details },
+        }
+    }
 }
 
 /// Standard API response wrapper
@@ -70,6 +320,9 @@ pub struct ApiResponse<T> {
     pub success: bool,
     pub data: Option<T>,
     pub error: Option<ApiError>,
+    /// Machine-readable code of `error`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<ErrorCode>,
     pub metadata: ResponseMetadata,
 }
 
@@ -102,6 +355,7 @@ impl<T> ApiResponse<T> {
             success: true,
             data: Some(data),
             error: None,
+            error_code: None,
             metadata,
         }
     }
@@ -111,12 +365,48 @@ impl<T> ApiResponse<T> {
         Self {
             success: false,
             data: None,
+            error_code: Some(error.code()),
             error: Some(error),
             metadata,
         }
     }
 }
 
+/// Response envelope of the v2 API
+///
+/// Successful v2 responses carry the payload directly in `data`; failures are
+/// reported through the HTTP status and the error body instead of a `success`
+/// flag.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseV2<T> {
+    pub data: T,
+    pub meta: ResponseMetaV2,
+}
+
+/// Metadata attached to v2 responses
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ResponseMetaV2 {
+    pub api_version: String,
+    pub request_id: String,
+    pub timestamp: String,
+    pub duration_ms: f64,
+}
+
+impl<T> ApiResponseV2<T> {
+    #[must_use]
+    pub fn new(data: T, duration: std::time::Duration) -> Self {
+        Self {
+            data,
+            meta: ResponseMetaV2 {
+                api_version: "v2".to_string(),
+                request_id: uuid::Uuid::new_v4().to_string(),
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                duration_ms: duration.as_secs_f64() * 1000.0,
+            },
+        }
+    }
+}
+
 // Data Transfer Objects for API operations
 
 /// Table schema definition
@@ -229,6 +519,86 @@ pub struct SqlQueryResponse {
     pub execution_time_ms: f64,
 }
 
+/// Result of a query cancellation request
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CancelQueryResponse {
+    pub request_id: String,
+    pub cancelled: bool,
+}
+
+/// SQL query result in the v2 API
+///
+/// Unlike [`SqlQueryResponse`], every field is always present: statements
+/// without a result set return empty `columns` and `rows`.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SqlQueryResultV2 {
+    pub columns: Vec<String>,
+    pub rows: Vec<HashMap<String, serde_json::Value>>,
+    pub rows_affected: usize,
+    pub execution_time_ms: f64,
+}
+
+impl From<SqlQueryResponse> for SqlQueryResultV2 {
+    fn from(response: SqlQueryResponse) -> Self {
+        Self {
+            columns: response.columns.unwrap_or_default(),
+            rows: response.rows.unwrap_or_default(),
+            rows_affected: response.rows_affected.unwrap_or(0),
+            execution_time_ms: response.execution_time_ms,
+        }
+    }
+}
+
+/// Query parameters for the streaming query endpoint
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct StreamQueryParams {
+    /// SELECT statement to execute
+    #[validate(length(min = 1))]
+    pub query: String,
+}
+
+/// A single statement within a batch query request
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, Validate)]
+pub struct BatchQueryItem {
+    #[validate(length(min = 1))]
+    pub sql: String,
+    /// Positional parameters bound to `$1`, `$2`, ... in order
+    pub params: Option<Vec<serde_json::Value>>,
+}
+
+/// Batch query request executing several statements in one round-trip
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+pub struct BatchQueryRequest {
+    #[validate(length(min = 1, max = 1000), nested)]
+    pub queries: Vec<BatchQueryItem>,
+    /// Run all statements inside a single transaction, rolling back on any failure
+    pub transactional: Option<bool>,
+    /// Isolation level of the batch transaction, e.g. `SERIALIZABLE`; only
+    /// valid with `transactional`, defaults to `READ COMMITTED`
+    pub isolation_level: Option<String>,
+}
+
+/// Outcome of a single statement within a batch
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BatchQueryResult {
+    pub index: usize,
+    pub success: bool,
+    pub result: Option<SqlQueryResponse>,
+    pub error: Option<String>,
+}
+
+/// Batch query response; `results` aligns positionally with the request
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BatchQueryResponse {
+    pub results: Vec<BatchQueryResult>,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub transactional: bool,
+    pub rolled_back: bool,
+    pub execution_time_ms: f64,
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 pub struct CreateTableRequest {
     #[validate(nested)]
@@ -269,6 +639,57 @@ pub struct InsertDataResponse {
     pub errors: Option<Vec<String>>,
 }
 
+/// Query parameters for the bulk-insert endpoint
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BulkInsertParams {
+    /// Insert valid rows and report failures instead of aborting the whole batch
+    pub ignore_errors: Option<bool>,
+    /// Commit without waiting for the log to be synced to disk; a crash
+    /// before the next log flush loses the rows
+    pub relaxed_commit: Option<bool>,
+}
+
+/// A row that could not be inserted during a bulk insert
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BulkInsertError {
+    pub index: usize,
+    pub error: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BulkInsertResponse {
+    pub inserted_count: usize,
+    pub failed_count: usize,
+    pub transaction_id: String,
+    /// Whether the commit was on disk when the response was sent
+    pub synchronous_commit: bool,
+    pub errors: Vec<BulkInsertError>,
+}
+
+/// Query parameters for the CSV import and export endpoints
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CsvParams {
+    /// Single-character field delimiter (default `,`)
+    pub delimiter: Option<String>,
+}
+
+/// A CSV record that could not be imported
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CsvImportError {
+    /// Line number of the record in the uploaded file (1-based)
+    pub line: usize,
+    pub error: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct CsvImportResponse {
+    pub imported_count: usize,
+    pub failed_count: usize,
+    pub errors: Vec<CsvImportError>,
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 pub struct QueryDataRequest {
     #[validate(length(min = 1, max = 64))]
@@ -341,9 +762,21 @@ pub struct QueryDataResponse {
     pub total_count: usize,
     pub returned_count: usize,
     pub has_more: bool,
+    /// Opaque cursor for the next page; `null` once the table is exhausted
+    pub next_cursor: Option<String>,
     pub query_stats: QueryStats,
 }
 
+/// Cursor pagination parameters for the table query endpoint
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PaginationParams {
+    /// Maximum number of rows per page
+    pub limit: Option<u32>,
+    /// Opaque cursor returned as `next_cursor` by the previous page
+    pub cursor: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct QueryStats {
     pub execution_time_ms: f64,
@@ -941,7 +1374,7 @@ pub struct DecompressionStats {
 
 // Performance and Monitoring DTOs
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, SimpleObject)]
 pub struct PerformanceStats {
     pub system_metrics: SystemMetrics,
     pub database_metrics: DatabaseMetrics,
@@ -949,7 +1382,82 @@ pub struct PerformanceStats {
     pub quantum_metrics: QuantumMetrics,
 }
 
+/// Buffer pool and pager internals reported by `/api/v1/stats/storage`
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct StorageInternals {
+    /// False if the storage engine has no page store attached, in which case
+    /// both sections are null
+    pub page_store_attached: bool,
+    pub buffer_pool: Option<BufferPoolMetrics>,
+    pub pager: Option<PagerMetrics>,
+}
+
+/// Audit entries and chain status reported by `/api/v1/audit`
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogResponse {
+    /// Matching entries, oldest first
+    pub entries: Vec<crate::audit::AuditEntry>,
+    /// True if the whole hash chain verifies
+    pub chain_valid: bool,
+    /// Where verification failed, if it did
+    pub chain_error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BufferPoolMetrics {
+    pub total_frames: usize,
+    pub used_frames: usize,
+    pub free_frames: usize,
+    pub dirty_frames: usize,
+    pub pinned_frames: usize,
+    pub hits: u64,
+    pub misses: u64,
+    /// Fraction of page fetches served from the pool
+    pub hit_rate: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PagerMetrics {
+    pub total_pages: u64,
+    pub free_pages: u64,
+    pub used_pages: u64,
+    pub cached_pages: u64,
+    pub file_size_bytes: u64,
+}
+
+/// Outcome of `/api/v1/admin/maintenance/{operation}`
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MaintenanceReport {
+    /// `checkpoint`, `vacuum` or `prune-wal`
+    pub operation: String,
+    /// LSN of the checkpoint written by `checkpoint` and `prune-wal`
+    pub checkpoint_lsn: Option<u64>,
+    /// Set by `vacuum`
+    pub vacuum: Option<VacuumReport>,
+    /// Set by `prune-wal`
+    pub wal: Option<WalPruneReport>,
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct VacuumReport {
+    pub tables_scanned: usize,
+    /// Compressed blocks of deleted rows that were dropped
+    pub compressed_blocks_removed: usize,
+    pub cached_rows_evicted: usize,
+    /// Free pages released from the end of the page file
+    pub pages_compacted: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WalPruneReport {
+    /// Records up to and including this LSN were removed
+    pub pruned_through_lsn: u64,
+    pub records_pruned: usize,
+    pub records_kept: usize,
+    pub bytes_reclaimed: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, SimpleObject)]
 pub struct SystemMetrics {
     pub memory_usage_mb: u64,
     pub cpu_usage_percent: f32,
@@ -959,7 +1467,7 @@ pub struct SystemMetrics {
     pub temperature_celsius: Option<f32>,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, SimpleObject)]
 pub struct DatabaseMetrics {
     pub active_connections: u32,
     pub queries_per_second: f32,
@@ -969,7 +1477,7 @@ pub struct DatabaseMetrics {
     pub total_records: u64,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, SimpleObject)]
 pub struct NeuralMetrics {
     pub active_networks: u32,
     pub training_jobs: u32,
@@ -978,7 +1486,7 @@ pub struct NeuralMetrics {
     pub synaptic_updates_per_second: f64,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, SimpleObject)]
 pub struct QuantumMetrics {
     pub coherence_time_ms: f32,
     pub entanglement_operations_per_second: f32,
@@ -987,6 +1495,33 @@ pub struct QuantumMetrics {
 }
 
 impl ResponseError for ApiError {
+    fn status_code(&self) -> StatusCode {
+        match self.code() {
+            | ErrorCode::Unauthorized => StatusCode::UNAUTHORIZED,
+            | ErrorCode::PermissionDenied => StatusCode::FORBIDDEN,
+            | ErrorCode::BadRequest | ErrorCode::ValidationFailed | ErrorCode::InvalidQuery => {
+                StatusCode::BAD_REQUEST
+            },
+            | ErrorCode::NotFound | ErrorCode::TableNotFound => StatusCode::NOT_FOUND,
+            | ErrorCode::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            | ErrorCode::Conflict | ErrorCode::QueryCancelled => StatusCode::CONFLICT,
+            | ErrorCode::QueryTimeout => StatusCode::GATEWAY_TIMEOUT,
+            | ErrorCode::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+            | ErrorCode::CircuitBreakerOpen | ErrorCode::ServiceUnavailable => {
+                StatusCode::SERVICE_UNAVAILABLE
+            },
+            | ErrorCode::NotImplemented => StatusCode::NOT_IMPLEMENTED,
+            | ErrorCode::UnsupportedApiVersion => StatusCode::NOT_ACCEPTABLE,
+            | ErrorCode::QuantumOperationFailed
+            | ErrorCode::CompressionFailed
+            | ErrorCode::EncryptionFailed
+            | ErrorCode::NeuralNetworkFailed
+            | ErrorCode::TableOperationFailed
+            | ErrorCode::ConnectionPoolExhausted
+            | ErrorCode::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
     fn error_response(&self) -> HttpResponse {
         let metadata = ResponseMetadata {
             request_id: uuid::Uuid::new_v4().to_string(),
@@ -997,22 +1532,7 @@ impl ResponseError for ApiError {
         };
 
         let response = ApiResponse::<()>::error(self.clone(), metadata);
-
-        match self {
-            | Self::Unauthorized(_) => HttpResponse::Unauthorized().json(response),
-            | Self::Forbidden(_) => HttpResponse::Forbidden().json(response),
-            | Self::BadRequest(_) | Self::ValidationError { .. } => {
-                HttpResponse::BadRequest().json(response)
-            },
-            | Self::NotFound(_) => HttpResponse::NotFound().json(response),
-            | Self::Conflict(_) => HttpResponse::Conflict().json(response),
-            | Self::RateLimitExceeded { .. } => HttpResponse::TooManyRequests().json(response),
-            | Self::ServiceUnavailable { .. } | Self::CircuitBreakerOpen { .. } => {
-                HttpResponse::ServiceUnavailable().json(response)
-            },
-            | Self::NotImplemented(_) => HttpResponse::NotImplemented().json(response),
-            | _ => HttpResponse::InternalServerError().json(response),
-        }
+        HttpResponse::build(self.status_code()).json(response)
     }
 }
 
@@ -1024,28 +1544,11 @@ where
         if response.success {
             Self::Ok().json(response)
         } else {
-            let mut status = match &response.error {
-                | Some(ApiError::ValidationError { .. }) => Self::BadRequest(),
-                | Some(ApiError::Unauthorized(_)) => Self::Unauthorized(),
-                | Some(ApiError::Forbidden(_)) => Self::Forbidden(),
-                | Some(ApiError::BadRequest(_)) => Self::BadRequest(),
-                | Some(ApiError::NotFound(_)) => Self::NotFound(),
-                | Some(ApiError::Conflict(_)) => Self::Conflict(),
-                | Some(ApiError::RateLimitExceeded { .. }) => Self::TooManyRequests(),
-                | Some(ApiError::QuantumOperationFailed { .. }) => Self::InternalServerError(),
-                | Some(ApiError::InvalidQuery { .. }) => Self::BadRequest(),
-                | Some(ApiError::InternalServerError { .. }) => Self::InternalServerError(),
-                | Some(ApiError::CompressionError { .. }) => Self::InternalServerError(),
-                | Some(ApiError::EncryptionError { .. }) => Self::InternalServerError(),
-                | Some(ApiError::NeuralNetworkError { .. }) => Self::InternalServerError(),
-                | Some(ApiError::TableError { .. }) => Self::InternalServerError(),
-                | Some(ApiError::ConnectionPoolError { .. }) => Self::InternalServerError(),
-                | Some(ApiError::CircuitBreakerOpen { .. }) => Self::ServiceUnavailable(),
-                | Some(ApiError::ServiceUnavailable { .. }) => Self::ServiceUnavailable(),
-                | Some(ApiError::NotImplemented(_)) => Self::NotImplemented(),
-                | None => Self::InternalServerError(),
-            };
-            status.json(response)
+            let status = response.error.as_ref().map_or(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseError::status_code,
+            );
+            Self::build(status).json(response)
         }
     }
 }
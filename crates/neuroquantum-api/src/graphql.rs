@@ -0,0 +1,221 @@
+//! GraphQL interface
+//!
+//! An `async-graphql` schema served at `POST /graphql` alongside the REST API.
+//! Requests go through the same authentication, rate limiting and admission
+//! control as the REST routes; the authenticated [`ApiKey`] is passed to the
+//! resolvers, which apply the same permission checks as the equivalent REST
+//! handlers. A GraphiQL playground is served at `GET /graphql` in debug builds.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use async_graphql::http::GraphiQLSource;
+use async_graphql::{
+    Context, EmptyMutation, EmptySubscription, Json, Object, Schema, SimpleObject,
+};
+use async_graphql_actix_web::{GraphQLRequest, GraphQLResponse};
+use neuroquantum_qsql::FieldAccess;
+
+use crate::auth::ApiKey;
+use crate::error::{ApiError, PerformanceStats};
+use crate::handlers::{
+    check_query_scopes, collect_performance_stats, query_result_to_response,
+    required_permission_for_query,
+};
+use crate::permissions::Permission;
+use crate::AppState;
+
+/// Path the GraphQL endpoint and playground are served from
+pub const GRAPHQL_PATH: &str = "/graphql";
+
+/// The GraphQL schema served by the API
+pub type NeuroQuantumSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+/// Build the GraphQL schema around the shared application state
+#[must_use]
+pub fn build_schema(app_state: AppState) -> NeuroQuantumSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(app_state)
+        .finish()
+}
+
+/// Column of a table
+#[derive(Debug, SimpleObject)]
+pub struct ColumnInfo {
+    pub name: String,
+    pub data_type: String,
+    pub nullable: bool,
+    pub auto_increment: bool,
+    pub encrypted: bool,
+}
+
+/// Table definition
+#[derive(Debug, SimpleObject)]
+pub struct TableInfo {
+    pub name: String,
+    pub primary_key: String,
+    pub version: u32,
+    pub columns: Vec<ColumnInfo>,
+}
+
+/// Overview of the database schema
+#[derive(Debug, SimpleObject)]
+pub struct SchemaInfo {
+    pub table_count: usize,
+    pub tables: Vec<TableInfo>,
+}
+
+/// Result of a QSQL statement, mirroring the REST `SqlQueryResponse`
+#[derive(Debug, SimpleObject)]
+pub struct SqlQueryResult {
+    pub rows: Json<Vec<HashMap<String, serde_json::Value>>>,
+    pub columns: Vec<String>,
+    pub rows_affected: usize,
+    pub execution_time_ms: f64,
+}
+
+/// Root query type
+#[derive(Debug, Default)]
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// All tables, ordered by name
+    async fn tables(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<TableInfo>> {
+        require_permission(ctx, "read")?;
+        Ok(table_infos(ctx.data::<AppState>()?).await)
+    }
+
+    /// A single table by name
+    async fn table(
+        &self,
+        ctx: &Context<'_>,
+        name: String,
+    ) -> async_graphql::Result<Option<TableInfo>> {
+        require_permission(ctx, "read")?;
+        let tables = table_infos(ctx.data::<AppState>()?).await;
+        Ok(tables.into_iter().find(|table| table.name == name))
+    }
+
+    /// Database schema overview
+    async fn schema_info(&self, ctx: &Context<'_>) -> async_graphql::Result<SchemaInfo> {
+        require_permission(ctx, "read")?;
+        let tables = table_infos(ctx.data::<AppState>()?).await;
+        Ok(SchemaInfo {
+            table_count: tables.len(),
+            tables,
+        })
+    }
+
+    /// Performance statistics, as reported by `GET /api/v1/stats/performance`
+    async fn stats(&self, ctx: &Context<'_>) -> async_graphql::Result<PerformanceStats> {
+        require_permission(ctx, "read")?;
+        Ok(collect_performance_stats(ctx.data::<AppState>()?).await)
+    }
+
+    /// Execute a QSQL statement, as `POST /api/v1/query` does
+    async fn query(&self, ctx: &Context<'_>, sql: String) -> async_graphql::Result<SqlQueryResult> {
+        let start = Instant::now();
+        let api_key = require_permission(ctx, required_permission_for_query(&sql))?;
+        check_query_scopes(&api_key.permissions, &api_key.scopes, &sql)?;
+        let field_access = if Permission::has_decrypt(&api_key.permissions) {
+            FieldAccess::Decrypted
+        } else {
+            FieldAccess::Redacted
+        };
+
+        let app_state = ctx.data::<AppState>()?;
+        let query_result = {
+            let mut qsql_engine = app_state.qsql_engine.lock().await;
+            qsql_engine
+                .execute_query_with_access(&sql, field_access)
+                .await
+                .map_err(|e| {
+                    crate::metrics::record_db_operation(
+                        "query",
+                        "failed",
+                        start.elapsed().as_secs_f64(),
+                    );
+                    ApiError::InvalidQuery {
+                        details: format!("Query execution failed: {e}"),
+                    }
+                })?
+        };
+        crate::metrics::record_db_operation("query", "success", start.elapsed().as_secs_f64());
+
+        let response =
+            query_result_to_response(query_result, start.elapsed().as_secs_f64() * 1000.0);
+        Ok(SqlQueryResult {
+            rows: Json(response.rows.unwrap_or_default()),
+            columns: response.columns.unwrap_or_default(),
+            rows_affected: response.rows_affected.unwrap_or(0),
+            execution_time_ms: response.execution_time_ms,
+        })
+    }
+}
+
+/// Check that the request's API key holds `permission` (or admin)
+fn require_permission<'a>(ctx: &Context<'a>, permission: &str) -> Result<&'a ApiKey, ApiError> {
+    let api_key = ctx
+        .data_opt::<ApiKey>()
+        .ok_or_else(|| ApiError::Unauthorized("Authentication required".to_string()))?;
+
+    if api_key
+        .permissions
+        .iter()
+        .any(|p| p == permission || p == "admin")
+    {
+        Ok(api_key)
+    } else {
+        Err(ApiError::Forbidden(format!(
+            "{permission} permission required for this query"
+        )))
+    }
+}
+
+async fn table_infos(app_state: &AppState) -> Vec<TableInfo> {
+    let db = app_state.db.read().await;
+    let storage = db.storage().await;
+
+    storage
+        .get_table_schemas()
+        .into_iter()
+        .map(|schema| TableInfo {
+            name: schema.name.clone(),
+            primary_key: schema.primary_key.clone(),
+            version: schema.version,
+            columns: schema
+                .columns
+                .iter()
+                .map(|column| ColumnInfo {
+                    name: column.name.clone(),
+                    data_type: format!("{:?}", column.data_type),
+                    nullable: column.nullable,
+                    auto_increment: column.auto_increment,
+                    encrypted: column.encrypted,
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// 🔷 GraphQL endpoint (requires authentication)
+pub async fn graphql_handler(
+    schema: web::Data<NeuroQuantumSchema>,
+    req: HttpRequest,
+    gql_request: GraphQLRequest,
+) -> GraphQLResponse {
+    let mut request = gql_request.into_inner();
+    if let Some(api_key) = req.extensions().get::<ApiKey>() {
+        request = request.data(api_key.clone());
+    }
+    schema.execute(request).await.into()
+}
+
+/// 🛝 GraphiQL playground (debug builds only)
+pub async fn graphiql() -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(GraphiQLSource::build().endpoint(GRAPHQL_PATH).finish())
+}
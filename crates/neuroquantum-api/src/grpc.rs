@@ -0,0 +1,731 @@
+//! gRPC interface
+//!
+//! A `tonic` service (`neuroquantum.database.Database`, see
+//! `proto/database.proto`) exposing QSQL execution, explicit transactions and
+//! row streaming over protobuf. It shares [`AppState`] with the REST API, so
+//! both interfaces operate on the same database, and is subject to the same
+//! admission control.
+//!
+//! Every call passes through [`AuthInterceptor`], which accepts the same
+//! credentials as the REST authentication middleware: a JWT in the
+//! `authorization: Bearer <token>` metadata entry or an API key in `x-api-key`.
+//! The authenticated [`Principal`] is then checked against the permission the
+//! statement requires, exactly as `POST /api/v1/query` does.
+//!
+//! Each transaction started with `BeginTransaction` runs on its own QSQL engine,
+//! so statements of concurrent transactions never interleave. Transactions are
+//! bound to the principal that started them, and are aborted once they go
+//! `server.grpc_transaction_idle_timeout_secs` without a statement, so
+//! abandoned transactions don't hold their locks forever.
+//!
+//! Every authenticated call is recorded in the [`AuditLog`](crate::audit::AuditLog)
+//! with the `GRPC` action, the full method name as resource and the statement
+//! it executed.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+use futures_util::{FutureExt, Stream};
+use neuroquantum_qsql::{FieldAccess, QSQLEngine};
+use tokio::sync::Mutex;
+use tonic::metadata::MetadataMap;
+use tonic::service::interceptor::InterceptedService;
+use tonic::service::Interceptor;
+use tonic::{Code, Request, Response, Status};
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+use crate::audit::{AuditDetails, AuditLog};
+use crate::auth::AuthService;
+use crate::error::ApiError;
+use crate::handlers::{
+    check_query_scopes, query_result_to_response, query_value_to_json,
+    required_permission_for_query, ISOLATION_LEVELS, STREAM_CHANNEL_CAPACITY,
+};
+use crate::jwt::JwtService;
+use crate::permissions::{Permission, ScopeRule};
+use crate::AppState;
+
+/// Generated protobuf messages, server and client
+pub mod proto {
+    tonic::include_proto!("neuroquantum.database");
+}
+
+use proto::database_server::{Database, DatabaseServer};
+
+/// Identity of an authenticated gRPC caller
+#[derive(Debug, Clone)]
+pub struct Principal {
+    /// JWT subject or API key name
+    pub name: String,
+    pub permissions: Vec<String>,
+    /// Table scope rules of the API key; empty for JWT callers
+    pub scopes: Vec<ScopeRule>,
+}
+
+impl Principal {
+    fn require(&self, permission: &str) -> Result<(), Status> {
+        if self
+            .permissions
+            .iter()
+            .any(|p| p == permission || p == "admin")
+        {
+            Ok(())
+        } else {
+            Err(Status::permission_denied(format!(
+                "{permission} permission required for this query"
+            )))
+        }
+    }
+
+    fn require_scopes(&self, sql: &str) -> Result<(), Status> {
+        check_query_scopes(&self.permissions, &self.scopes, sql).map_err(|e| match e {
+            | ApiError::Forbidden(message) => Status::permission_denied(message),
+            | other => Status::invalid_argument(other.to_string()),
+        })
+    }
+
+    fn field_access(&self) -> FieldAccess {
+        if Permission::has_decrypt(&self.permissions) {
+            FieldAccess::Decrypted
+        } else {
+            FieldAccess::Redacted
+        }
+    }
+}
+
+/// Interceptor authenticating every call with a JWT or API key
+#[derive(Clone)]
+pub struct AuthInterceptor {
+    auth_service: AuthService,
+    jwt_service: JwtService,
+}
+
+impl AuthInterceptor {
+    #[must_use]
+    pub fn new(app_state: &AppState) -> Self {
+        Self {
+            auth_service: app_state.auth_service.clone(),
+            jwt_service: app_state.jwt_service.clone(),
+        }
+    }
+
+    fn authenticate(&self, metadata: &MetadataMap) -> Option<Principal> {
+        // Try JWT authentication first
+        if let Some(token) = metadata
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+        {
+            // Interceptors are synchronous; validation only suspends while a key
+            // rotation holds the secret lock, in which case the token is refused
+            match self.jwt_service.validate_token(token).now_or_never() {
+                | Some(Ok(claims)) => {
+                    debug!(
+                        "✅ gRPC JWT authentication successful for user: {}",
+                        claims.sub
+                    );
+                    return Some(Principal {
+                        name: claims.sub,
+                        permissions: claims.permissions,
+                        scopes: Vec::new(),
+                    });
+                },
+                | Some(Err(e)) => warn!("❌ gRPC JWT validation failed: {:?}", e),
+                | None => warn!("❌ gRPC JWT validation skipped: key rotation in progress"),
+            }
+        }
+
+        // Try API key authentication
+        if let Some(key) = metadata
+            .get("x-api-key")
+            .and_then(|value| value.to_str().ok())
+        {
+            if let Some(api_key) = self
+                .auth_service
+                .validate_api_key(key)
+                .now_or_never()
+                .flatten()
+            {
+                debug!(
+                    "✅ gRPC API key authentication successful for: {}",
+                    api_key.name
+                );
+                return Some(Principal {
+                    name: api_key.name,
+                    permissions: api_key.permissions,
+                    scopes: api_key.scopes,
+                });
+            }
+            warn!("❌ Invalid API key provided to gRPC service");
+        }
+
+        None
+    }
+}
+
+impl Interceptor for AuthInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let principal = self.authenticate(request.metadata()).ok_or_else(|| {
+            Status::unauthenticated(
+                "Authentication required. Please provide a valid JWT token or API key.",
+            )
+        })?;
+        request.extensions_mut().insert(principal);
+        Ok(request)
+    }
+}
+
+/// An open transaction and the engine its statements run on
+struct GrpcTransaction {
+    owner: String,
+    engine: Arc<Mutex<QSQLEngine>>,
+    /// When a statement of the transaction last started or finished
+    last_used: Instant,
+}
+
+type Transactions = DashMap<String, GrpcTransaction>;
+
+/// Implementation of the `Database` gRPC service
+pub struct DatabaseService {
+    app_state: AppState,
+    transactions: Arc<Transactions>,
+}
+
+impl DatabaseService {
+    #[must_use]
+    pub fn new(app_state: AppState) -> Self {
+        Self {
+            app_state,
+            transactions: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Abort idle transactions in the background until the service is dropped
+    ///
+    /// Does nothing when `server.grpc_transaction_idle_timeout_secs` is 0.
+    pub fn spawn_idle_reaper(&self) {
+        let idle_timeout = Duration::from_secs(
+            self.app_state
+                .config
+                .server
+                .grpc_transaction_idle_timeout_secs,
+        );
+        if idle_timeout.is_zero() {
+            return;
+        }
+        let transactions = Arc::downgrade(&self.transactions);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(idle_timeout / 2);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                let Some(transactions) = transactions.upgrade() else {
+                    break;
+                };
+                abort_idle_transactions(&transactions, idle_timeout).await;
+            }
+        });
+    }
+
+    /// Mark the transaction as used now
+    fn touch(&self, transaction_id: &str) {
+        if let Some(mut tx) = self.transactions.get_mut(transaction_id) {
+            tx.last_used = Instant::now();
+        }
+    }
+
+    /// Engine a statement runs on: the transaction's own engine, or the shared
+    /// one when no transaction is given
+    fn engine_for(
+        &self,
+        principal: &Principal,
+        transaction_id: &str,
+    ) -> Result<Arc<Mutex<QSQLEngine>>, Status> {
+        if transaction_id.is_empty() {
+            return Ok(self.app_state.qsql_engine.clone());
+        }
+
+        match self.transactions.get_mut(transaction_id) {
+            | Some(mut tx) if tx.owner == principal.name => {
+                tx.last_used = Instant::now();
+                Ok(tx.engine.clone())
+            },
+            | _ => Err(Status::not_found(format!(
+                "Transaction {transaction_id} not found"
+            ))),
+        }
+    }
+
+    async fn run(
+        &self,
+        principal: &Principal,
+        sql: &str,
+        transaction_id: &str,
+    ) -> Result<neuroquantum_qsql::QueryResult, Status> {
+        let start = Instant::now();
+        let engine = self.engine_for(principal, transaction_id)?;
+        let mut engine = engine.lock().await;
+        let result = engine
+            .execute_query_with_access(sql, principal.field_access())
+            .await;
+        drop(engine);
+        if !transaction_id.is_empty() {
+            self.touch(transaction_id);
+        }
+
+        let status = if result.is_ok() { "success" } else { "failed" };
+        crate::metrics::record_db_operation("query", status, start.elapsed().as_secs_f64());
+        result.map_err(|e| Status::invalid_argument(format!("Query execution failed: {e}")))
+    }
+
+    /// Remove the caller's transaction and finish it with `statement`
+    async fn finish_transaction(
+        &self,
+        principal: &Principal,
+        transaction_id: &str,
+        statement: &str,
+    ) -> Result<Response<proto::TransactionResponse>, Status> {
+        let (_, tx) = self
+            .transactions
+            .remove_if(transaction_id, |_, tx| tx.owner == principal.name)
+            .ok_or_else(|| Status::not_found(format!("Transaction {transaction_id} not found")))?;
+
+        tx.engine
+            .lock()
+            .await
+            .execute_query(statement)
+            .await
+            .map_err(|e| Status::aborted(format!("{statement} failed: {e}")))?;
+
+        info!(
+            "🔚 gRPC transaction {} finished with {}",
+            transaction_id, statement
+        );
+        Ok(Response::new(proto::TransactionResponse { success: true }))
+    }
+
+    /// Start a transaction owned by `principal` on an engine of its own
+    async fn begin(
+        &self,
+        principal: &Principal,
+        isolation_level: &str,
+    ) -> Result<Response<proto::BeginTransactionResponse>, Status> {
+        principal.require("write")?;
+
+        let isolation_level = isolation_level.to_uppercase();
+        let statement = if isolation_level.is_empty() {
+            "BEGIN".to_string()
+        } else if ISOLATION_LEVELS.contains(&isolation_level.as_str()) {
+            format!("BEGIN TRANSACTION ISOLATION LEVEL {isolation_level}")
+        } else {
+            return Err(Status::invalid_argument(format!(
+                "Unknown isolation level: {isolation_level}"
+            )));
+        };
+
+        let _permit = self.admit().await?;
+        let storage_engine = self.app_state.db.read().await.storage_engine_arc();
+        let mut engine = QSQLEngine::with_storage(storage_engine)
+            .map_err(|e| Status::internal(format!("Failed to initialize QSQL engine: {e}")))?;
+        engine
+            .execute_query(&statement)
+            .await
+            .map_err(|e| Status::internal(format!("Failed to begin transaction: {e}")))?;
+
+        let transaction_id = Uuid::new_v4().to_string();
+        self.transactions.insert(
+            transaction_id.clone(),
+            GrpcTransaction {
+                owner: principal.name.clone(),
+                engine: Arc::new(Mutex::new(engine)),
+                last_used: Instant::now(),
+            },
+        );
+
+        info!(
+            "🔐 gRPC transaction {} started by {}",
+            transaction_id, principal.name
+        );
+        Ok(Response::new(proto::BeginTransactionResponse {
+            transaction_id,
+        }))
+    }
+
+    /// Record a call of `method` and its outcome in the audit log
+    async fn audit<T>(
+        &self,
+        principal: &Principal,
+        method: &str,
+        statement: Option<&str>,
+        result: &Result<T, Status>,
+    ) {
+        let code = result.as_ref().map_or_else(Status::code, |_| Code::Ok);
+        record_call(
+            &self.app_state.audit_log,
+            principal,
+            method,
+            statement,
+            code,
+        )
+        .await;
+    }
+
+    async fn admit(&self) -> Result<crate::connection_limit::ConnectionPermit, Status> {
+        self.app_state
+            .connection_limiter
+            .acquire()
+            .await
+            .ok_or_else(|| Status::unavailable("Server is at capacity"))
+    }
+}
+
+/// Roll back the transactions that went `idle_timeout` without a statement
+///
+/// Transactions whose engine is busy with a statement are left alone.
+async fn abort_idle_transactions(transactions: &Transactions, idle_timeout: Duration) {
+    let idle: Vec<String> = transactions
+        .iter()
+        .filter(|tx| tx.last_used.elapsed() >= idle_timeout)
+        .map(|tx| tx.key().clone())
+        .collect();
+
+    for transaction_id in idle {
+        let Some((transaction_id, tx)) = transactions.remove_if(&transaction_id, |_, tx| {
+            tx.last_used.elapsed() >= idle_timeout && tx.engine.try_lock().is_ok()
+        }) else {
+            continue;
+        };
+        warn!(
+            "⏱️ Aborting gRPC transaction {} of {}: idle for {:?}",
+            transaction_id, tx.owner, idle_timeout
+        );
+        if let Err(e) = tx.engine.lock().await.execute_query("ROLLBACK").await {
+            warn!(
+                "❌ Failed to roll back idle gRPC transaction {}: {}",
+                transaction_id, e
+            );
+        }
+    }
+}
+
+/// Record a call of `method` that finished with `code` in `audit_log`
+async fn record_call(
+    audit_log: &AuditLog,
+    principal: &Principal,
+    method: &str,
+    statement: Option<&str>,
+    code: Code,
+) {
+    let details = statement.map(AuditDetails::for_statement);
+    if let Err(e) = audit_log
+        .record_details(
+            &principal.name,
+            "GRPC",
+            &format!("/neuroquantum.database.Database/{method}"),
+            http_status(code),
+            details.as_ref(),
+        )
+        .await
+    {
+        error!("❌ Failed to record audit entry: {:#}", e);
+    }
+}
+
+/// HTTP status equivalent to a gRPC status code
+const fn http_status(code: Code) -> u16 {
+    match code {
+        | Code::Ok => 200,
+        | Code::InvalidArgument | Code::OutOfRange | Code::FailedPrecondition => 400,
+        | Code::Unauthenticated => 401,
+        | Code::PermissionDenied => 403,
+        | Code::NotFound => 404,
+        | Code::AlreadyExists | Code::Aborted => 409,
+        | Code::ResourceExhausted => 429,
+        | Code::Cancelled => 499,
+        | Code::Unimplemented => 501,
+        | Code::Unavailable => 503,
+        | Code::DeadlineExceeded => 504,
+        | Code::Unknown | Code::Internal | Code::DataLoss => 500,
+    }
+}
+
+/// Read the principal the interceptor attached to the request
+fn principal<T>(request: &Request<T>) -> Result<Principal, Status> {
+    request
+        .extensions()
+        .get::<Principal>()
+        .cloned()
+        .ok_or_else(|| Status::unauthenticated("Authentication required"))
+}
+
+fn require_read_only(sql: &str) -> Result<(), Status> {
+    if required_permission_for_query(sql) == "read" {
+        Ok(())
+    } else {
+        Err(Status::invalid_argument(
+            "Only read-only statements can be queried; use Execute instead",
+        ))
+    }
+}
+
+/// Convert a result value into its protobuf representation
+fn json_to_value(value: serde_json::Value) -> proto::Value {
+    use proto::value::Kind;
+
+    let kind = match value {
+        | serde_json::Value::Null => Kind::NullValue(true),
+        | serde_json::Value::Bool(b) => Kind::BoolValue(b),
+        | serde_json::Value::Number(n) => n.as_i64().map_or_else(
+            || Kind::FloatValue(n.as_f64().unwrap_or(f64::NAN)),
+            Kind::IntValue,
+        ),
+        | serde_json::Value::String(s) => Kind::StringValue(s),
+        | other => Kind::JsonValue(other.to_string()),
+    };
+    proto::Value { kind: Some(kind) }
+}
+
+/// Convert a protobuf value back into the JSON returned by the REST API
+#[must_use]
+pub fn value_to_json(value: &proto::Value) -> serde_json::Value {
+    use proto::value::Kind;
+
+    match &value.kind {
+        | None | Some(Kind::NullValue(_)) => serde_json::Value::Null,
+        | Some(Kind::BoolValue(b)) => serde_json::Value::Bool(*b),
+        | Some(Kind::IntValue(i)) => serde_json::Value::from(*i),
+        | Some(Kind::FloatValue(f)) => serde_json::Value::from(*f),
+        | Some(Kind::StringValue(s)) => serde_json::Value::String(s.clone()),
+        | Some(Kind::JsonValue(json)) => {
+            serde_json::from_str(json).unwrap_or_else(|_| serde_json::Value::String(json.clone()))
+        },
+    }
+}
+
+fn json_row_to_row(row: HashMap<String, serde_json::Value>) -> proto::Row {
+    proto::Row {
+        fields: row
+            .into_iter()
+            .map(|(k, v)| (k, json_to_value(v)))
+            .collect(),
+    }
+}
+
+type RowStream = Pin<Box<dyn Stream<Item = Result<proto::Row, Status>> + Send>>;
+
+#[tonic::async_trait]
+impl Database for DatabaseService {
+    async fn execute(
+        &self,
+        request: Request<proto::ExecuteRequest>,
+    ) -> Result<Response<proto::ExecuteResponse>, Status> {
+        let start = Instant::now();
+        let principal = principal(&request)?;
+        let req = request.into_inner();
+        let response = async {
+            principal.require(required_permission_for_query(&req.sql))?;
+            principal.require_scopes(&req.sql)?;
+
+            let _permit = self.admit().await?;
+            let result = self.run(&principal, &req.sql, &req.transaction_id).await?;
+
+            Ok(Response::new(proto::ExecuteResponse {
+                rows_affected: result.rows_affected,
+                execution_time_ms: start.elapsed().as_secs_f64() * 1000.0,
+            }))
+        }
+        .await;
+        self.audit(&principal, "Execute", Some(&req.sql), &response)
+            .await;
+        response
+    }
+
+    async fn query(
+        &self,
+        request: Request<proto::QueryRequest>,
+    ) -> Result<Response<proto::QueryResponse>, Status> {
+        let start = Instant::now();
+        let principal = principal(&request)?;
+        let req = request.into_inner();
+        let response = async {
+            require_read_only(&req.sql)?;
+            principal.require("read")?;
+            principal.require_scopes(&req.sql)?;
+
+            let _permit = self.admit().await?;
+            let result = self.run(&principal, &req.sql, &req.transaction_id).await?;
+            let response = query_result_to_response(result, start.elapsed().as_secs_f64() * 1000.0);
+
+            Ok(Response::new(proto::QueryResponse {
+                columns: response.columns.unwrap_or_default(),
+                rows: response
+                    .rows
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(json_row_to_row)
+                    .collect(),
+                rows_affected: response.rows_affected.unwrap_or(0) as u64,
+                execution_time_ms: response.execution_time_ms,
+            }))
+        }
+        .await;
+        self.audit(&principal, "Query", Some(&req.sql), &response)
+            .await;
+        response
+    }
+
+    async fn begin_transaction(
+        &self,
+        request: Request<proto::BeginTransactionRequest>,
+    ) -> Result<Response<proto::BeginTransactionResponse>, Status> {
+        let principal = principal(&request)?;
+        let isolation_level = request.into_inner().isolation_level;
+        let response = self.begin(&principal, &isolation_level).await;
+        self.audit(&principal, "BeginTransaction", None, &response)
+            .await;
+        response
+    }
+
+    async fn commit(
+        &self,
+        request: Request<proto::TransactionRequest>,
+    ) -> Result<Response<proto::TransactionResponse>, Status> {
+        let principal = principal(&request)?;
+        let transaction_id = request.into_inner().transaction_id;
+        let response = async {
+            let _permit = self.admit().await?;
+            self.finish_transaction(&principal, &transaction_id, "COMMIT")
+                .await
+        }
+        .await;
+        self.audit(&principal, "Commit", Some("COMMIT"), &response)
+            .await;
+        response
+    }
+
+    async fn abort(
+        &self,
+        request: Request<proto::TransactionRequest>,
+    ) -> Result<Response<proto::TransactionResponse>, Status> {
+        let principal = principal(&request)?;
+        let transaction_id = request.into_inner().transaction_id;
+        let response = async {
+            let _permit = self.admit().await?;
+            self.finish_transaction(&principal, &transaction_id, "ROLLBACK")
+                .await
+        }
+        .await;
+        self.audit(&principal, "Abort", Some("ROLLBACK"), &response)
+            .await;
+        response
+    }
+
+    type QueryStreamStream = RowStream;
+
+    async fn query_stream(
+        &self,
+        request: Request<proto::QueryRequest>,
+    ) -> Result<Response<Self::QueryStreamStream>, Status> {
+        let start = Instant::now();
+        let principal = principal(&request)?;
+        let req = request.into_inner();
+        let admitted = async {
+            require_read_only(&req.sql)?;
+            principal.require("read")?;
+            principal.require_scopes(&req.sql)?;
+
+            let permit = self.admit().await?;
+            let engine = self.engine_for(&principal, &req.transaction_id)?;
+            Ok::<_, Status>((permit, engine))
+        }
+        .await;
+        if admitted.is_err() {
+            self.audit(&principal, "QueryStream", Some(&req.sql), &admitted)
+                .await;
+        }
+        let (permit, engine) = admitted?;
+        let field_access = principal.field_access();
+        let audit_log = self.app_state.audit_log.clone();
+        let (tx, rx) = tokio::sync::mpsc::channel(STREAM_CHANNEL_CAPACITY);
+
+        tokio::spawn(async move {
+            let _permit = permit;
+            let result = {
+                let mut engine = engine.lock().await;
+                engine
+                    .execute_query_with_access(&req.sql, field_access)
+                    .await
+            };
+            let code = result.as_ref().map_or(Code::InvalidArgument, |_| Code::Ok);
+            record_call(&audit_log, &principal, "QueryStream", Some(&req.sql), code).await;
+
+            let query_result = match result {
+                | Ok(query_result) => query_result,
+                | Err(e) => {
+                    crate::metrics::record_db_operation(
+                        "query_stream",
+                        "failed",
+                        start.elapsed().as_secs_f64(),
+                    );
+                    let _ = tx
+                        .send(Err(Status::invalid_argument(format!(
+                            "Query execution failed: {e}"
+                        ))))
+                        .await;
+                    return;
+                },
+            };
+
+            let mut row_count = 0usize;
+            for row in query_result.rows {
+                let row = row
+                    .into_iter()
+                    .map(|(k, v)| (k, query_value_to_json(v)))
+                    .collect();
+                // `send` waits while the channel is full, pausing production
+                if tx.send(Ok(json_row_to_row(row))).await.is_err() {
+                    warn!("gRPC client disconnected after {} rows", row_count);
+                    return;
+                }
+                row_count += 1;
+            }
+
+            crate::metrics::record_db_operation(
+                "query_stream",
+                "success",
+                start.elapsed().as_secs_f64(),
+            );
+        });
+
+        let stream = futures_util::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|item| (item, rx))
+        });
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+/// The authenticated `Database` service, ready to add to a `tonic` server
+#[must_use]
+pub fn service(
+    app_state: AppState,
+) -> InterceptedService<DatabaseServer<DatabaseService>, AuthInterceptor> {
+    let interceptor = AuthInterceptor::new(&app_state);
+    let database = DatabaseService::new(app_state);
+    database.spawn_idle_reaper();
+    DatabaseServer::with_interceptor(database, interceptor)
+}
+
+/// Serve the `Database` service on `addr` until the process exits
+pub async fn start_grpc_server(app_state: AppState, addr: SocketAddr) -> anyhow::Result<()> {
+    info!("🛰️ Starting NeuroQuantumDB gRPC server on {}", addr);
+    tonic::transport::Server::builder()
+        .add_service(service(app_state))
+        .serve(addr)
+        .await?;
+    Ok(())
+}
@@ -5,25 +5,38 @@ use std::time::Instant;
 use actix_web::{web, HttpMessage, HttpRequest, HttpResponse, Result as ActixResult};
 use neuroquantum_core::{DNACompressor, NeuroQuantumDB};
 use neuroquantum_qsql::query_plan::QueryValue;
+use neuroquantum_qsql::table_access::{self, AccessKind};
+use neuroquantum_qsql::{FieldAccess, Literal};
 use serde::{Deserialize, Serialize};
 use tracing::{info, warn};
 use utoipa::{OpenApi, ToSchema};
 use validator::Validate;
 
+use crate::audit::{AuditDetails, AuditEntry, AuditFilter, AuditOutcome};
 use crate::auth::{ApiKey, AuthService};
+use crate::biometric_auth::{AuthenticationResult, EEGError};
+use crate::config::{ApiConfig, DEFAULT_MAX_IMPORT_BODY_BYTES};
+use crate::csv::{CsvError, CsvReader, CsvRecord};
 use crate::error::{
-    ApiError, ApiResponse, ColumnDefinition, CompressDnaRequest, CompressDnaResponse,
-    CompressedSequence, CompressionStats, ConstraintType, CreateTableRequest, CreateTableResponse,
-    DataType, DatabaseMetrics, DecompressDnaRequest, DecompressDnaResponse, DecompressedSequence,
-    DecompressionStats, DeleteDataRequest, DeleteDataResponse, GroverRequestConfig, GroverResults,
-    InsertDataRequest, InsertDataResponse, NeuralMetrics, ParallelTemperingRequestConfig,
-    ParallelTemperingResults, PerformanceStats, QUBORequestConfig, QUBOResults, QuantumMetrics,
-    QuantumSearchRequest, QuantumSearchResponse, QuantumSearchResult, QuantumStats,
-    QueryDataRequest, QueryDataResponse, QueryStats, ResponseMetadata, SqlQueryRequest,
-    SqlQueryResponse, SystemMetrics, TFIMRequestConfig, TFIMResults, TableSchema,
-    TrainNeuralNetworkRequest, TrainNeuralNetworkResponse, TrainingStatus, UpdateDataRequest,
-    UpdateDataResponse,
+    ApiError, ApiResponse, ApiResponseV2, AuditLogResponse, BatchQueryItem, BatchQueryRequest,
+    BatchQueryResponse, BatchQueryResult, BufferPoolMetrics, BulkInsertError, BulkInsertParams,
+    BulkInsertResponse, CancelQueryResponse, ColumnDefinition, CompressDnaRequest,
+    CompressDnaResponse, CompressedSequence, CompressionStats, ConstraintType, CreateTableRequest,
+    CreateTableResponse, CsvImportError, CsvImportResponse, CsvParams, DataType, DatabaseMetrics,
+    DecompressDnaRequest, DecompressDnaResponse, DecompressedSequence, DecompressionStats,
+    DeleteDataRequest, DeleteDataResponse, ErrorCode, GroverRequestConfig, GroverResults,
+    InsertDataRequest, InsertDataResponse, MaintenanceReport, NeuralMetrics, PagerMetrics,
+    PaginationParams, ParallelTemperingRequestConfig, ParallelTemperingResults, PerformanceStats,
+    QUBORequestConfig, QUBOResults, QuantumMetrics, QuantumSearchRequest, QuantumSearchResponse,
+    QuantumSearchResult, QuantumStats, QueryDataRequest, QueryDataResponse, QueryStats,
+    ResponseMetaV2, ResponseMetadata, SqlQueryRequest, SqlQueryResponse, SqlQueryResultV2,
+    StorageInternals, StreamQueryParams, SystemMetrics, TFIMRequestConfig, TFIMResults,
+    TableSchema, TrainNeuralNetworkRequest, TrainNeuralNetworkResponse, TrainingStatus,
+    UpdateDataRequest, UpdateDataResponse, VacuumReport, WalPruneReport,
 };
+use crate::metrics::StatementLabels;
+use crate::middleware::RequestId;
+use crate::permissions::{Permission, ScopeRule, READ, WRITE};
 
 /// `OpenAPI` documentation
 #[derive(OpenApi)]
@@ -34,8 +47,15 @@ use crate::error::{
         login,
         refresh_token,
         execute_sql_query,
+        execute_sql_query_v2,
+        cancel_query,
+        execute_batch_query,
+        stream_sql_query,
         create_table,
         insert_data,
+        bulk_insert_data,
+        import_csv,
+        export_csv,
         query_data,
         update_data,
         delete_data,
@@ -46,10 +66,15 @@ use crate::error::{
         decompress_dna,
         get_metrics,
         get_performance_stats,
+        get_storage_stats,
+        run_maintenance,
+        get_audit_log,
         eeg_enroll,
+        eeg_challenge,
         eeg_authenticate,
         eeg_update_signature,
         eeg_list_users,
+        eeg_migrate_signatures,
         biometric_enroll,
         biometric_verify,
         get_index_recommendations,
@@ -60,6 +85,7 @@ use crate::error::{
             // Auth DTOs
             GenerateKeyRequest,
             GenerateKeyResponse,
+            ScopeRule,
             RevokeKeyRequest,
             LoginRequest,
             LoginResponse,
@@ -68,10 +94,20 @@ use crate::error::{
             // CRUD DTOs
             SqlQueryRequest,
             SqlQueryResponse,
+            SqlQueryResultV2,
+            CancelQueryResponse,
+            BatchQueryItem,
+            BatchQueryRequest,
+            BatchQueryResult,
+            BatchQueryResponse,
             CreateTableRequest,
             CreateTableResponse,
             InsertDataRequest,
             InsertDataResponse,
+            BulkInsertError,
+            BulkInsertResponse,
+            CsvImportError,
+            CsvImportResponse,
             QueryDataRequest,
             QueryDataResponse,
             UpdateDataRequest,
@@ -109,12 +145,25 @@ use crate::error::{
             DatabaseMetrics,
             NeuralMetrics,
             QuantumMetrics,
+            StorageInternals,
+            BufferPoolMetrics,
+            PagerMetrics,
+            MaintenanceReport,
+            VacuumReport,
+            WalPruneReport,
+            AuditLogResponse,
+            AuditEntry,
+            AuditOutcome,
 
             // Biometric Auth DTOs
             EEGEnrollRequest,
             EEGEnrollResponse,
+            EEGChallengeRequest,
+            EEGChallengeResponse,
             EEGAuthRequest,
             EEGAuthResponse,
+            EEGMigrateRequest,
+            EEGMigrateResponse,
             BiometricEnrollRequest,
             BiometricEnrollResponse,
             BiometricVerifyRequest,
@@ -130,7 +179,9 @@ use crate::error::{
             ColumnDefinition,
             DataType,
             ApiError,
+            ErrorCode,
             ApiResponse<String>,
+            ResponseMetaV2,
         )
     ),
     tags(
@@ -178,6 +229,9 @@ pub struct GenerateKeyRequest {
     pub permissions: Vec<String>,
     pub expiry_hours: Option<u32>,
     pub rate_limit_per_hour: Option<u32>,
+    /// Restrict the key to specific tables; evaluated in order, first match wins
+    #[serde(default)]
+    pub scopes: Vec<ScopeRule>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -188,6 +242,7 @@ pub struct GenerateKeyResponse {
     pub expires_at: String,
     pub created_at: String,
     pub rate_limit_per_hour: Option<u32>,
+    pub scopes: Vec<ScopeRule>,
     pub warning: String,
 }
 
@@ -298,11 +353,19 @@ pub async fn generate_api_key(
         }
     }
 
+    for rule in &key_request.scopes {
+        rule.validate().map_err(|message| {
+            crate::metrics::record_auth_request("failed");
+            ApiError::BadRequest(message)
+        })?;
+    }
+
     let mut auth_service_mut = auth_service.as_ref().clone();
     let new_key = auth_service_mut
-        .generate_api_key(
+        .generate_scoped_api_key(
             key_request.name.clone(),
             key_request.permissions.clone(),
+            key_request.scopes.clone(),
             key_request.expiry_hours,
             key_request.rate_limit_per_hour,
         )
@@ -327,6 +390,7 @@ pub async fn generate_api_key(
         expires_at: new_key.expires_at.to_rfc3339(),
         created_at: new_key.created_at.to_rfc3339(),
         rate_limit_per_hour: new_key.rate_limit_per_hour,
+        scopes: new_key.scopes,
         warning: "⚠️ Store this API key securely. It will not be shown again!".to_string(),
     };
 
@@ -426,6 +490,7 @@ pub async fn create_table(
     }
 
     let table_name = create_req.schema.name.clone();
+    require_table_scope(&req, &table_name, WRITE)?;
 
     info!(
         "🗃️ Creating table '{}' with {} columns",
@@ -489,6 +554,7 @@ pub async fn create_table(
                     nullable: c.nullable.unwrap_or(true),
                     default_value,
                     auto_increment: c.auto_increment.unwrap_or(false),
+                    ..Default::default()
                 })
             })
             .collect();
@@ -504,6 +570,7 @@ pub async fn create_table(
         auto_increment_columns: std::collections::HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     // Create table in database
@@ -582,6 +649,7 @@ pub async fn insert_data(
     if !has_permission {
         return Err(ApiError::Forbidden("Write permission required".to_string()));
     }
+    require_table_scope(&req, &table_name, WRITE)?;
 
     if insert_req.records.is_empty() {
         return Err(ApiError::BadRequest(
@@ -683,242 +751,866 @@ pub async fn insert_data(
     )))
 }
 
-/// Query data from a table with advanced filtering
+/// Maximum number of rows accepted by a single bulk insert
+const MAX_BULK_INSERT_ROWS: usize = 10_000;
+
+/// Bulk-insert rows into a table within a single transaction
+///
+/// The request body is a JSON array of row objects. All rows are inserted in
+/// one transaction and the call fails atomically on the first bad row, unless
+/// `ignore_errors=true` is passed, in which case valid rows are committed and
+/// failures are reported per row.
+///
+/// `relaxed_commit=true` returns without waiting for the commit to be synced
+/// to disk.
 #[utoipa::path(
     post,
-    path = "/api/v1/tables/{table_name}/query",
+    path = "/api/v1/tables/{table_name}/bulk",
     params(
-        ("table_name" = String, Path, description = "Name of the table")
+        ("table_name" = String, Path, description = "Name of the table"),
+        BulkInsertParams
     ),
-    request_body = QueryDataRequest,
+    request_body = Vec<HashMap<String, serde_json::Value>>,
     responses(
-        (status = 200, description = "Query executed successfully", body = ApiResponse<QueryDataResponse>),
-        (status = 400, description = "Invalid query", body = ApiResponse<String>),
+        (status = 201, description = "Rows inserted", body = ApiResponse<BulkInsertResponse>),
+        (status = 400, description = "Invalid rows; nothing was inserted", body = ApiResponse<String>),
         (status = 404, description = "Table not found", body = ApiResponse<String>),
     ),
     tag = "CRUD Operations"
 )]
-pub async fn query_data(
+pub async fn bulk_insert_data(
     req: HttpRequest,
     path: web::Path<String>,
+    params: web::Query<BulkInsertParams>,
     db: web::Data<Arc<tokio::sync::RwLock<NeuroQuantumDB>>>,
-    query_req: web::Json<QueryDataRequest>,
+    rows: web::Json<Vec<HashMap<String, serde_json::Value>>>,
 ) -> ActixResult<HttpResponse, ApiError> {
     let start = Instant::now();
     let table_name = path.into_inner();
+    let ignore_errors = params.ignore_errors.unwrap_or(false);
+    let durability = if params.relaxed_commit.unwrap_or(false) {
+        neuroquantum_core::storage::Durability::Relaxed
+    } else {
+        neuroquantum_core::storage::Durability::Durable
+    };
 
-    // Validate request
-    query_req
-        .validate()
-        .map_err(|e| ApiError::ValidationError {
-            field: "query".to_string(),
-            message: e.to_string(),
-        })?;
-
-    // Check permissions - extract before await to avoid holding RefCell across await
-    let has_read_permission = {
+    // Check permissions (extract before any await to avoid holding RefCell across await)
+    let has_permission = {
         let extensions = req.extensions();
         let api_key = extensions
             .get::<ApiKey>()
             .ok_or_else(|| ApiError::Unauthorized("Authentication required".to_string()))?;
 
-        api_key.permissions.contains(&"read".to_string())
+        api_key.permissions.contains(&"write".to_string())
             || api_key.permissions.contains(&"admin".to_string())
-    }; // extensions RefCell is dropped here
+    };
 
-    if !has_read_permission {
-        return Err(ApiError::Forbidden("Read permission required".to_string()));
+    if !has_permission {
+        return Err(ApiError::Forbidden("Write permission required".to_string()));
     }
+    require_table_scope(&req, &table_name, WRITE)?;
 
-    let limit = query_req.limit.unwrap_or(100);
-    let offset = query_req.offset.unwrap_or(0);
+    if rows.is_empty() {
+        return Err(ApiError::BadRequest(
+            "No rows provided for insertion".to_string(),
+        ));
+    }
+    if rows.len() > MAX_BULK_INSERT_ROWS {
+        return Err(ApiError::BadRequest(format!(
+            "Bulk insert accepts at most {MAX_BULK_INSERT_ROWS} rows per request"
+        )));
+    }
 
     info!(
-        "🔍 Querying table '{}' with limit {} offset {}",
-        table_name, limit, offset
+        "📥 Bulk inserting {} rows into table '{}' (ignore_errors: {})",
+        rows.len(),
+        table_name,
+        ignore_errors
     );
 
-    // Execute real query on storage engine
-    use neuroquantum_core::storage::SelectQuery;
-
     let db_lock = db.as_ref().read().await;
-    let storage = db_lock.storage_mut().await;
+    let mut storage = db_lock.storage_mut().await;
 
-    // Build SelectQuery from request
-    let select_query = SelectQuery {
-        table: table_name.clone(),
-        columns: vec!["*".to_string()],
-        where_clause: None,
-        order_by: None,
-        limit: Some(u64::from(limit)),
-        offset: Some(u64::from(offset)),
-    };
+    if storage.get_table_schema(&table_name).is_none() {
+        return Err(ApiError::NotFound(format!(
+            "Table '{table_name}' does not exist"
+        )));
+    }
 
-    // Execute query on storage engine
-    let (rows, query_exec_stats) = storage
-        .select_rows_with_stats(&select_query)
+    let tx_id = storage
+        .begin_transaction()
         .await
         .map_err(|e| ApiError::InternalServerError {
-            message: format!("Query execution failed: {e}"),
+            message: format!("Failed to begin transaction: {e}"),
         })?;
 
-    // Convert rows to JSON records
-    let mut records = Vec::new();
-    let rows_scanned = rows.len();
-    for row in &rows {
-        let mut record = HashMap::new();
-        for (field, value) in &row.fields {
-            record.insert(field.clone(), storage_value_to_json(value));
+    let mut inserted_count = 0;
+    let mut errors = Vec::new();
+
+    for (idx, record) in rows.iter().enumerate() {
+        let outcome = match record_to_row(record) {
+            | Ok(row) => storage
+                .insert_row_transactional(tx_id, &table_name, row)
+                .await
+                .map_err(|e| e.to_string()),
+            | Err(e) => Err(e),
+        };
+
+        match outcome {
+            | Ok(_) => inserted_count += 1,
+            | Err(error) if ignore_errors => errors.push(BulkInsertError { index: idx, error }),
+            | Err(error) => {
+                if let Err(e) = storage.rollback_transaction(tx_id).await {
+                    warn!("Failed to roll back bulk insert transaction: {}", e);
+                }
+                return Err(ApiError::BadRequest(format!(
+                    "Row {idx}: {error}; no rows were inserted"
+                )));
+            },
         }
-        records.push(record);
     }
 
-    // Get total count (would need a separate COUNT query in production)
-    let total_count = rows_scanned;
+    let commit = storage
+        .commit_transaction_with_durability(tx_id, durability)
+        .await
+        .map_err(|e| ApiError::InternalServerError {
+            message: format!("Failed to commit bulk insert: {e}"),
+        })?;
+    drop(storage);
 
-    let query_stats = QueryStats {
-        execution_time_ms: start.elapsed().as_millis() as f64,
-        rows_scanned: query_exec_stats.rows_examined,
-        indexes_used: query_exec_stats.indexes_used.clone(),
-        neural_operations: None, // Neural operations not yet integrated in query execution
-        quantum_operations: None, // Quantum operations not yet integrated in query execution
-        cache_hit_rate: query_exec_stats.cache_hit_rate(),
-    };
+    crate::metrics::record_db_operation("bulk_insert", "success", start.elapsed().as_secs_f64());
 
-    let response = QueryDataResponse {
-        records: records.clone(),
-        total_count,
-        returned_count: records.len(),
-        has_more: records.len() == limit as usize,
-        query_stats,
+    info!(
+        "✅ Bulk inserted {} rows into '{}', {} failed",
+        inserted_count,
+        table_name,
+        errors.len()
+    );
+
+    let response = BulkInsertResponse {
+        inserted_count,
+        failed_count: errors.len(),
+        transaction_id: tx_id.to_string(),
+        synchronous_commit: commit.synchronous,
+        errors,
     };
 
-    Ok(HttpResponse::Ok().json(ApiResponse::success(
+    Ok(HttpResponse::Created().json(ApiResponse::success(
         response,
         ResponseMetadata::new(
             start.elapsed(),
-            &format!("Query executed on table '{table_name}'"),
+            &format!("Bulk inserted {inserted_count} rows into '{table_name}'"),
         ),
     )))
 }
 
-/// Update data in a table with optimistic locking
+/// Convert a JSON record into a storage `Row` with an unassigned ID
+fn record_to_row(
+    record: &HashMap<String, serde_json::Value>,
+) -> Result<neuroquantum_core::storage::Row, String> {
+    if record.is_empty() {
+        return Err("Record is empty".to_string());
+    }
+
+    let fields = record
+        .iter()
+        .map(|(key, value)| Ok((key.clone(), json_to_storage_value(value, key)?)))
+        .collect::<Result<HashMap<_, _>, String>>()?;
+
+    Ok(neuroquantum_core::storage::Row {
+        id: 0, // Will be assigned by storage engine
+        fields,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    })
+}
+
+/// Number of rows fetched per page while exporting a table as CSV
+const CSV_EXPORT_PAGE_SIZE: usize = 1000;
+
+/// Import rows from a CSV upload
+///
+/// The first record must be a header naming table columns. Records are parsed
+/// and inserted as the upload arrives; values are coerced to the column types
+/// of the table schema. Malformed records are skipped and reported with their
+/// line numbers.
+///
+/// Uploads are limited to `security.max_import_body_bytes` rather than the
+/// smaller limit on JSON request bodies.
 #[utoipa::path(
-    put,
-    path = "/api/v1/tables/{table_name}/data",
+    post,
+    path = "/api/v1/tables/{table_name}/import/csv",
     params(
-        ("table_name" = String, Path, description = "Name of the table")
+        ("table_name" = String, Path, description = "Name of the table"),
+        CsvParams
     ),
-    request_body = UpdateDataRequest,
+    request_body(content = String, content_type = "text/csv"),
     responses(
-        (status = 200, description = "Data updated successfully", body = ApiResponse<UpdateDataResponse>),
-        (status = 400, description = "Invalid update", body = ApiResponse<String>),
+        (status = 200, description = "CSV imported", body = ApiResponse<CsvImportResponse>),
+        (status = 400, description = "Invalid header or delimiter", body = ApiResponse<String>),
         (status = 404, description = "Table not found", body = ApiResponse<String>),
-        (status = 409, description = "Optimistic lock conflict", body = ApiResponse<String>),
+        (status = 413, description = "Upload exceeds the import limit", body = ApiResponse<String>),
     ),
     tag = "CRUD Operations"
 )]
-pub async fn update_data(
+pub async fn import_csv(
     req: HttpRequest,
     path: web::Path<String>,
-    update_req: web::Json<UpdateDataRequest>,
+    params: web::Query<CsvParams>,
+    db: web::Data<Arc<tokio::sync::RwLock<NeuroQuantumDB>>>,
+    mut payload: web::Payload,
 ) -> ActixResult<HttpResponse, ApiError> {
+    use futures_util::StreamExt;
+
     let start = Instant::now();
     let table_name = path.into_inner();
 
-    // Validate request
-    update_req
-        .validate()
-        .map_err(|e| ApiError::ValidationError {
-            field: "update".to_string(),
-            message: e.to_string(),
-        })?;
+    // Check permissions (extract before any await to avoid holding RefCell across await)
+    let has_permission = {
+        let extensions = req.extensions();
+        let api_key = extensions
+            .get::<ApiKey>()
+            .ok_or_else(|| ApiError::Unauthorized("Authentication required".to_string()))?;
 
-    // Check permissions
-    let extensions = req.extensions();
-    let api_key = extensions
-        .get::<ApiKey>()
-        .ok_or_else(|| ApiError::Unauthorized("Authentication required".to_string()))?;
+        api_key.permissions.contains(&"write".to_string())
+            || api_key.permissions.contains(&"admin".to_string())
+    };
 
-    if !api_key.permissions.contains(&"write".to_string())
-        && !api_key.permissions.contains(&"admin".to_string())
-    {
+    if !has_permission {
         return Err(ApiError::Forbidden("Write permission required".to_string()));
     }
+    require_table_scope(&req, &table_name, WRITE)?;
 
-    if update_req.updates.is_empty() {
-        return Err(ApiError::BadRequest("No updates provided".to_string()));
+    let limit = req
+        .app_data::<web::Data<ApiConfig>>()
+        .map_or(DEFAULT_MAX_IMPORT_BODY_BYTES, |config| {
+            config.security.max_import_body_bytes
+        });
+    let content_length = req
+        .headers()
+        .get(actix_web::http::header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok());
+    if content_length.is_some_and(|length| length > limit) {
+        return Err(ApiError::PayloadTooLarge { limit });
     }
 
-    info!(
-        "✏️ Updating data in table '{}' with {} filters and {} updates",
-        table_name,
-        update_req.filters.len(),
-        update_req.updates.len()
-    );
+    let delimiter = parse_csv_delimiter(params.delimiter.as_deref())?;
+    let schema = {
+        let db_lock = db.as_ref().read().await;
+        let storage = db_lock.storage().await;
+        storage.get_table_schema(&table_name).cloned()
+    }
+    .ok_or_else(|| ApiError::TableNotFound {
+        table: table_name.clone(),
+    })?;
 
-    // Simulate update operation
-    let updated_count = 42; // Mock value
-    let matched_count = 45; // Mock value
-    let new_version = update_req.optimistic_lock_version.map(|v| v + 1);
+    info!("📥 Importing CSV into table '{}'", table_name);
 
-    let response = UpdateDataResponse {
-        updated_count,
-        matched_count,
-        new_version,
-    };
+    let mut reader = CsvReader::new(delimiter);
+    let mut header = None;
+    let mut summary = CsvImportResponse::default();
+    let mut received = 0usize;
+
+    while let Some(chunk) = payload.next().await {
+        let chunk =
+            chunk.map_err(|e| ApiError::BadRequest(format!("Failed to read upload: {e}")))?;
+        received += chunk.len();
+        if received > limit {
+            return Err(ApiError::PayloadTooLarge { limit });
+        }
+        let records = reader.feed(&chunk);
+        import_csv_records(db.as_ref(), &schema, &mut header, records, &mut summary).await?;
+    }
+    let remaining = reader.finish().into_iter().collect();
+    import_csv_records(db.as_ref(), &schema, &mut header, remaining, &mut summary).await?;
+
+    if header.is_none() {
+        return Err(ApiError::BadRequest(
+            "CSV upload is empty; a header row is required".to_string(),
+        ));
+    }
+
+    summary.failed_count = summary.errors.len();
+    crate::metrics::record_db_operation("csv_import", "success", start.elapsed().as_secs_f64());
 
+    info!(
+        "✅ Imported {} CSV rows into '{}', {} failed",
+        summary.imported_count, table_name, summary.failed_count
+    );
+
+    let message = format!(
+        "Imported {} rows into '{table_name}'",
+        summary.imported_count
+    );
     Ok(HttpResponse::Ok().json(ApiResponse::success(
-        response,
-        ResponseMetadata::new(
-            start.elapsed(),
-            &format!("Updated {updated_count} records in table '{table_name}'"),
-        ),
+        summary,
+        ResponseMetadata::new(start.elapsed(), &message),
     )))
 }
 
-/// Delete data from a table with cascade support
+/// Insert a batch of parsed CSV records, resolving the header on first use
+async fn import_csv_records(
+    db: &tokio::sync::RwLock<NeuroQuantumDB>,
+    schema: &neuroquantum_core::storage::TableSchema,
+    header: &mut Option<Vec<neuroquantum_core::storage::ColumnDefinition>>,
+    records: Vec<Result<CsvRecord, CsvError>>,
+    summary: &mut CsvImportResponse,
+) -> Result<(), ApiError> {
+    let mut rows = Vec::new();
+
+    for record in records {
+        let record = match record {
+            | Ok(record) => record,
+            | Err(e) if header.is_none() => {
+                return Err(ApiError::BadRequest(format!(
+                    "Line {}: malformed header: {}",
+                    e.line, e.message
+                )));
+            },
+            | Err(e) => {
+                summary.errors.push(CsvImportError {
+                    line: e.line,
+                    error: e.message,
+                });
+                continue;
+            },
+        };
+
+        let Some(columns) = header.as_ref() else {
+            *header = Some(resolve_csv_header(schema, &record)?);
+            continue;
+        };
+
+        match csv_record_to_row(columns, &record) {
+            | Ok(row) => rows.push((record.line, row)),
+            | Err(error) => summary.errors.push(CsvImportError {
+                line: record.line,
+                error,
+            }),
+        }
+    }
+
+    if rows.is_empty() {
+        return Ok(());
+    }
+
+    let db_lock = db.read().await;
+    let mut storage = db_lock.storage_mut().await;
+    for (line, row) in rows {
+        match storage.insert_row(&schema.name, row).await {
+            | Ok(_) => summary.imported_count += 1,
+            | Err(e) => summary.errors.push(CsvImportError {
+                line,
+                error: e.to_string(),
+            }),
+        }
+    }
+
+    Ok(())
+}
+
+/// Map a CSV header record onto the table's column definitions
+fn resolve_csv_header(
+    schema: &neuroquantum_core::storage::TableSchema,
+    record: &CsvRecord,
+) -> Result<Vec<neuroquantum_core::storage::ColumnDefinition>, ApiError> {
+    let mut columns: Vec<neuroquantum_core::storage::ColumnDefinition> = Vec::new();
+    for name in &record.fields {
+        let name = name.trim();
+        let column = schema
+            .columns
+            .iter()
+            .find(|c| c.name == name)
+            .ok_or_else(|| {
+                ApiError::BadRequest(format!(
+                    "Line {}: column '{name}' does not exist in table '{}'",
+                    record.line, schema.name
+                ))
+            })?;
+        if columns.iter().any(|c| c.name == column.name) {
+            return Err(ApiError::BadRequest(format!(
+                "Line {}: duplicate column '{name}' in header",
+                record.line
+            )));
+        }
+        columns.push(column.clone());
+    }
+    Ok(columns)
+}
+
+/// Convert a CSV data record into a storage `Row` following the header columns
+fn csv_record_to_row(
+    columns: &[neuroquantum_core::storage::ColumnDefinition],
+    record: &CsvRecord,
+) -> Result<neuroquantum_core::storage::Row, String> {
+    if record.fields.len() != columns.len() {
+        return Err(format!(
+            "expected {} fields, found {}",
+            columns.len(),
+            record.fields.len()
+        ));
+    }
+
+    let mut fields = HashMap::new();
+    for (column, raw) in columns.iter().zip(&record.fields) {
+        if let Some(value) = csv_field_to_storage_value(raw, column)? {
+            fields.insert(column.name.clone(), value);
+        }
+    }
+
+    Ok(neuroquantum_core::storage::Row {
+        id: 0, // Will be assigned by storage engine
+        fields,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    })
+}
+
+/// Export a table as CSV
+///
+/// Rows are read page by page with a range scan over the primary key order
+/// and streamed to the client, so only one page of rows is held in memory at
+/// a time. Tables with an `ENCRYPTED` primary key have no key order and are
+/// read in full for every page. The first record is a header with the column
+/// names in schema order.
 #[utoipa::path(
-    delete,
-    path = "/api/v1/tables/{table_name}/data",
+    get,
+    path = "/api/v1/tables/{table_name}/export/csv",
     params(
-        ("table_name" = String, Path, description = "Name of the table")
+        ("table_name" = String, Path, description = "Name of the table"),
+        CsvParams
     ),
-    request_body = DeleteDataRequest,
     responses(
-        (status = 200, description = "Data deleted successfully", body = ApiResponse<DeleteDataResponse>),
-        (status = 400, description = "Invalid delete", body = ApiResponse<String>),
+        (status = 200, description = "Table contents (text/csv)", body = String),
+        (status = 400, description = "Invalid delimiter", body = ApiResponse<String>),
         (status = 404, description = "Table not found", body = ApiResponse<String>),
     ),
     tag = "CRUD Operations"
 )]
-pub async fn delete_data(
+pub async fn export_csv(
     req: HttpRequest,
     path: web::Path<String>,
-    delete_req: web::Json<DeleteDataRequest>,
+    params: web::Query<CsvParams>,
+    db: web::Data<Arc<tokio::sync::RwLock<NeuroQuantumDB>>>,
 ) -> ActixResult<HttpResponse, ApiError> {
+    use actix_web::web::Bytes;
+
     let start = Instant::now();
     let table_name = path.into_inner();
 
-    // Validate request
-    delete_req
-        .validate()
-        .map_err(|e| ApiError::ValidationError {
-            field: "delete".to_string(),
-            message: e.to_string(),
-        })?;
-
-    // Check permissions
-    let extensions = req.extensions();
-    let api_key = extensions
-        .get::<ApiKey>()
-        .ok_or_else(|| ApiError::Unauthorized("Authentication required".to_string()))?;
+    // Check permissions (extract before any await to avoid holding RefCell across await)
+    let has_permission = {
+        let extensions = req.extensions();
+        let api_key = extensions
+            .get::<ApiKey>()
+            .ok_or_else(|| ApiError::Unauthorized("Authentication required".to_string()))?;
 
-    if !api_key.permissions.contains(&"write".to_string())
+        api_key.permissions.contains(&"read".to_string())
+            || api_key.permissions.contains(&"admin".to_string())
+    };
+
+    if !has_permission {
+        return Err(ApiError::Forbidden("Read permission required".to_string()));
+    }
+    require_table_scope(&req, &table_name, READ)?;
+
+    let delimiter = parse_csv_delimiter(params.delimiter.as_deref())?;
+    let schema = {
+        let db_lock = db.as_ref().read().await;
+        let storage = db_lock.storage().await;
+        storage.get_table_schema(&table_name).cloned()
+    }
+    .ok_or_else(|| ApiError::TableNotFound {
+        table: table_name.clone(),
+    })?;
+
+    info!("📤 Exporting table '{}' as CSV", table_name);
+
+    let (tx, rx) =
+        tokio::sync::mpsc::channel::<Result<Bytes, actix_web::Error>>(STREAM_CHANNEL_CAPACITY);
+    let db = db.get_ref().clone();
+    let column_names: Vec<String> = schema.columns.iter().map(|c| c.name.clone()).collect();
+
+    tokio::spawn(async move {
+        let mut header = String::new();
+        crate::csv::write_record(&column_names, delimiter, &mut header);
+        if tx.send(Ok(Bytes::from(header))).await.is_err() {
+            return;
+        }
+
+        let mut after_key = None;
+        let mut row_count = 0usize;
+        loop {
+            let page = {
+                let db_lock = db.read().await;
+                let storage = db_lock.storage().await;
+                storage
+                    .select_page(&schema.name, after_key.as_ref(), CSV_EXPORT_PAGE_SIZE)
+                    .await
+            };
+
+            let (rows, next_key) = match page {
+                | Ok((rows, next_key, _stats)) => (rows, next_key),
+                | Err(e) => {
+                    warn!("CSV export of '{}' failed: {}", schema.name, e);
+                    crate::metrics::record_db_operation(
+                        "csv_export",
+                        "failed",
+                        start.elapsed().as_secs_f64(),
+                    );
+                    // Abort the response so the client does not mistake it for a full export
+                    let _ = tx
+                        .send(Err(actix_web::error::ErrorInternalServerError(
+                            "CSV export failed",
+                        )))
+                        .await;
+                    return;
+                },
+            };
+
+            let mut chunk = String::new();
+            for row in &rows {
+                crate::csv::write_record(
+                    column_names
+                        .iter()
+                        .map(|name| storage_value_to_csv(row.fields.get(name))),
+                    delimiter,
+                    &mut chunk,
+                );
+            }
+            row_count += rows.len();
+
+            if !chunk.is_empty() && tx.send(Ok(Bytes::from(chunk))).await.is_err() {
+                warn!("CSV export client disconnected after {} rows", row_count);
+                return;
+            }
+
+            match next_key {
+                | Some(key) => after_key = Some(key),
+                | None => break,
+            }
+        }
+
+        crate::metrics::record_db_operation("csv_export", "success", start.elapsed().as_secs_f64());
+        info!("✅ Exported {} rows from '{}'", row_count, schema.name);
+    });
+
+    let body = futures_util::stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|chunk| (chunk, rx))
+    });
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/csv; charset=utf-8")
+        .insert_header((
+            "Content-Disposition",
+            format!("attachment; filename=\"{table_name}.csv\""),
+        ))
+        .streaming(body))
+}
+
+/// Query data from a table with advanced filtering
+#[utoipa::path(
+    post,
+    path = "/api/v1/tables/{table_name}/query",
+    params(
+        ("table_name" = String, Path, description = "Name of the table"),
+        PaginationParams
+    ),
+    request_body = QueryDataRequest,
+    responses(
+        (status = 200, description = "Query executed successfully", body = ApiResponse<QueryDataResponse>),
+        (status = 400, description = "Invalid query", body = ApiResponse<String>),
+        (status = 404, description = "Table not found", body = ApiResponse<String>),
+    ),
+    tag = "CRUD Operations"
+)]
+pub async fn query_data(
+    req: HttpRequest,
+    path: web::Path<String>,
+    pagination: web::Query<PaginationParams>,
+    db: web::Data<Arc<tokio::sync::RwLock<NeuroQuantumDB>>>,
+    query_req: web::Json<QueryDataRequest>,
+) -> ActixResult<HttpResponse, ApiError> {
+    let start = Instant::now();
+    let table_name = path.into_inner();
+
+    // Validate request
+    query_req
+        .validate()
+        .map_err(|e| ApiError::ValidationError {
+            field: "query".to_string(),
+            message: e.to_string(),
+        })?;
+
+    // Check permissions - extract before await to avoid holding RefCell across await
+    let has_read_permission = {
+        let extensions = req.extensions();
+        let api_key = extensions
+            .get::<ApiKey>()
+            .ok_or_else(|| ApiError::Unauthorized("Authentication required".to_string()))?;
+
+        api_key.permissions.contains(&"read".to_string())
+            || api_key.permissions.contains(&"admin".to_string())
+    }; // extensions RefCell is dropped here
+
+    if !has_read_permission {
+        return Err(ApiError::Forbidden("Read permission required".to_string()));
+    }
+    require_table_scope(&req, &table_name, READ)?;
+
+    let limit = pagination.limit.or(query_req.limit).unwrap_or(100);
+    if limit == 0 {
+        return Err(ApiError::ValidationError {
+            field: "limit".to_string(),
+            message: "limit must be greater than zero".to_string(),
+        });
+    }
+
+    // Explicit offsets keep the legacy behaviour; everything else pages by
+    // primary key so results stay stable while rows are being inserted.
+    let use_offset = query_req.offset.is_some() && pagination.cursor.is_none();
+    let offset = query_req.offset.unwrap_or(0);
+    let after_key = pagination
+        .cursor
+        .as_deref()
+        .map(decode_cursor)
+        .transpose()?;
+
+    info!(
+        "🔍 Querying table '{}' with limit {} ({})",
+        table_name,
+        limit,
+        if use_offset {
+            format!("offset {offset}")
+        } else {
+            "cursor pagination".to_string()
+        }
+    );
+
+    // Execute real query on storage engine
+    use neuroquantum_core::storage::SelectQuery;
+
+    let db_lock = db.as_ref().read().await;
+    let storage = db_lock.storage_mut().await;
+
+    let (rows, next_key, query_exec_stats) = if use_offset {
+        // Build SelectQuery from request
+        let select_query = SelectQuery {
+            table: table_name.clone(),
+            columns: vec!["*".to_string()],
+            where_clause: None,
+            order_by: None,
+            limit: Some(u64::from(limit)),
+            offset: Some(u64::from(offset)),
+        };
+
+        let (rows, stats) = storage
+            .select_rows_with_stats(&select_query)
+            .await
+            .map_err(|e| ApiError::InternalServerError {
+                message: format!("Query execution failed: {e}"),
+            })?;
+        (rows, None, stats)
+    } else {
+        storage
+            .select_page(&table_name, after_key.as_ref(), limit as usize)
+            .await
+            .map_err(|e| ApiError::InternalServerError {
+                message: format!("Query execution failed: {e}"),
+            })?
+    };
+    drop(storage);
+
+    // Convert rows to JSON records
+    let mut records = Vec::new();
+    let rows_scanned = rows.len();
+    for row in &rows {
+        let mut record = HashMap::new();
+        for (field, value) in &row.fields {
+            record.insert(field.clone(), storage_value_to_json(value));
+        }
+        records.push(record);
+    }
+
+    // Get total count (would need a separate COUNT query in production)
+    let total_count = rows_scanned;
+
+    let query_stats = QueryStats {
+        execution_time_ms: start.elapsed().as_millis() as f64,
+        rows_scanned: query_exec_stats.rows_examined,
+        indexes_used: query_exec_stats.indexes_used.clone(),
+        neural_operations: None, // Neural operations not yet integrated in query execution
+        quantum_operations: None, // Quantum operations not yet integrated in query execution
+        cache_hit_rate: query_exec_stats.cache_hit_rate(),
+    };
+
+    let next_cursor = next_key.as_ref().map(encode_cursor).transpose()?;
+    let has_more = if use_offset {
+        records.len() == limit as usize
+    } else {
+        next_cursor.is_some()
+    };
+
+    let response = QueryDataResponse {
+        returned_count: records.len(),
+        records,
+        total_count,
+        has_more,
+        next_cursor,
+        query_stats,
+    };
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        response,
+        ResponseMetadata::new(
+            start.elapsed(),
+            &format!("Query executed on table '{table_name}'"),
+        ),
+    )))
+}
+
+/// Encode a primary key value as an opaque, URL-safe pagination cursor
+fn encode_cursor(key: &neuroquantum_core::storage::Value) -> Result<String, ApiError> {
+    use base64::Engine;
+    let bytes = serde_json::to_vec(key).map_err(|e| ApiError::InternalServerError {
+        message: format!("Failed to encode cursor: {e}"),
+    })?;
+    Ok(base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes))
+}
+
+/// Decode a pagination cursor produced by [`encode_cursor`]
+fn decode_cursor(cursor: &str) -> Result<neuroquantum_core::storage::Value, ApiError> {
+    use base64::Engine;
+    let invalid = |message: String| ApiError::ValidationError {
+        field: "cursor".to_string(),
+        message,
+    };
+    let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(cursor)
+        .map_err(|e| invalid(format!("Malformed cursor: {e}")))?;
+    serde_json::from_slice(&bytes).map_err(|e| invalid(format!("Malformed cursor: {e}")))
+}
+
+/// Update data in a table with optimistic locking
+#[utoipa::path(
+    put,
+    path = "/api/v1/tables/{table_name}/data",
+    params(
+        ("table_name" = String, Path, description = "Name of the table")
+    ),
+    request_body = UpdateDataRequest,
+    responses(
+        (status = 200, description = "Data updated successfully", body = ApiResponse<UpdateDataResponse>),
+        (status = 400, description = "Invalid update", body = ApiResponse<String>),
+        (status = 404, description = "Table not found", body = ApiResponse<String>),
+        (status = 409, description = "Optimistic lock conflict", body = ApiResponse<String>),
+    ),
+    tag = "CRUD Operations"
+)]
+pub async fn update_data(
+    req: HttpRequest,
+    path: web::Path<String>,
+    update_req: web::Json<UpdateDataRequest>,
+) -> ActixResult<HttpResponse, ApiError> {
+    let start = Instant::now();
+    let table_name = path.into_inner();
+
+    // Validate request
+    update_req
+        .validate()
+        .map_err(|e| ApiError::ValidationError {
+            field: "update".to_string(),
+            message: e.to_string(),
+        })?;
+
+    // Check permissions
+    let extensions = req.extensions();
+    let api_key = extensions
+        .get::<ApiKey>()
+        .ok_or_else(|| ApiError::Unauthorized("Authentication required".to_string()))?;
+
+    if !api_key.permissions.contains(&"write".to_string())
+        && !api_key.permissions.contains(&"admin".to_string())
+    {
+        return Err(ApiError::Forbidden("Write permission required".to_string()));
+    }
+    require_table_scope(&req, &table_name, WRITE)?;
+
+    if update_req.updates.is_empty() {
+        return Err(ApiError::BadRequest("No updates provided".to_string()));
+    }
+
+    info!(
+        "✏️ Updating data in table '{}' with {} filters and {} updates",
+        table_name,
+        update_req.filters.len(),
+        update_req.updates.len()
+    );
+
+    // Simulate update operation
+    let updated_count = 42; // Mock value
+    let matched_count = 45; // Mock value
+    let new_version = update_req.optimistic_lock_version.map(|v| v + 1);
+
+    let response = UpdateDataResponse {
+        updated_count,
+        matched_count,
+        new_version,
+    };
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        response,
+        ResponseMetadata::new(
+            start.elapsed(),
+            &format!("Updated {updated_count} records in table '{table_name}'"),
+        ),
+    )))
+}
+
+/// Delete data from a table with cascade support
+#[utoipa::path(
+    delete,
+    path = "/api/v1/tables/{table_name}/data",
+    params(
+        ("table_name" = String, Path, description = "Name of the table")
+    ),
+    request_body = DeleteDataRequest,
+    responses(
+        (status = 200, description = "Data deleted successfully", body = ApiResponse<DeleteDataResponse>),
+        (status = 400, description = "Invalid delete", body = ApiResponse<String>),
+        (status = 404, description = "Table not found", body = ApiResponse<String>),
+    ),
+    tag = "CRUD Operations"
+)]
+pub async fn delete_data(
+    req: HttpRequest,
+    path: web::Path<String>,
+    delete_req: web::Json<DeleteDataRequest>,
+) -> ActixResult<HttpResponse, ApiError> {
+    let start = Instant::now();
+    let table_name = path.into_inner();
+
+    // Validate request
+    delete_req
+        .validate()
+        .map_err(|e| ApiError::ValidationError {
+            field: "delete".to_string(),
+            message: e.to_string(),
+        })?;
+
+    // Check permissions
+    let extensions = req.extensions();
+    let api_key = extensions
+        .get::<ApiKey>()
+        .ok_or_else(|| ApiError::Unauthorized("Authentication required".to_string()))?;
+
+    if !api_key.permissions.contains(&"write".to_string())
         && !api_key.permissions.contains(&"admin".to_string())
     {
         return Err(ApiError::Forbidden("Write permission required".to_string()));
     }
+    require_table_scope(&req, &table_name, WRITE)?;
 
     let cascade = delete_req.cascade.unwrap_or(false);
     let soft_delete = delete_req.soft_delete.unwrap_or(false);
@@ -1117,6 +1809,7 @@ pub async fn quantum_search(
             "Quantum permission required".to_string(),
         ));
     }
+    require_table_scope(&req, &search_req.table_name, READ)?;
 
     if search_req.query_vector.is_empty() {
         return Err(ApiError::BadRequest(
@@ -2261,6 +2954,225 @@ pub async fn get_performance_stats(
         }
     }
 
+    let stats = collect_performance_stats(&app_state).await;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        stats,
+        ResponseMetadata::new(start.elapsed(), "Performance statistics collected"),
+    )))
+}
+
+/// Get live buffer pool and pager statistics of the storage engine
+#[utoipa::path(
+    get,
+    path = "/api/v1/stats/storage",
+    responses(
+        (status = 200, description = "Storage stats retrieved", body = ApiResponse<StorageInternals>),
+        (status = 403, description = "Admin permission required", body = ApiResponse<String>),
+    ),
+    tag = "Monitoring"
+)]
+pub async fn get_storage_stats(
+    req: HttpRequest,
+    app_state: web::Data<crate::AppState>,
+) -> ActixResult<HttpResponse, ApiError> {
+    let start = Instant::now();
+
+    {
+        let extensions = req.extensions();
+        let api_key = extensions
+            .get::<ApiKey>()
+            .ok_or_else(|| ApiError::Unauthorized("Admin authentication required".to_string()))?;
+
+        if !api_key.permissions.contains(&"admin".to_string()) {
+            return Err(ApiError::Forbidden(
+                "Admin permission required to view storage internals".to_string(),
+            ));
+        }
+    }
+
+    let db = app_state.db.read().await;
+    let page_store = db.storage().await.page_store_stats().await;
+    let stats = StorageInternals {
+        page_store_attached: page_store.is_some(),
+        buffer_pool: page_store.as_ref().map(|stats| BufferPoolMetrics {
+            total_frames: stats.buffer_pool.total_frames,
+            used_frames: stats.buffer_pool.used_frames,
+            free_frames: stats.buffer_pool.free_frames,
+            dirty_frames: stats.buffer_pool.dirty_frames,
+            pinned_frames: stats.buffer_pool.pinned_frames,
+            hits: stats.cache.hits,
+            misses: stats.cache.misses,
+            hit_rate: stats.cache.hit_rate,
+        }),
+        pager: page_store.as_ref().map(|stats| PagerMetrics {
+            total_pages: stats.pager.total_pages,
+            free_pages: stats.pager.free_pages,
+            used_pages: stats.pager.used_pages,
+            cached_pages: stats.pager.cached_pages,
+            file_size_bytes: stats.pager.file_size_bytes,
+        }),
+    };
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        stats,
+        ResponseMetadata::new(start.elapsed(), "Storage statistics collected"),
+    )))
+}
+
+/// Run a storage maintenance operation on demand
+///
+/// `checkpoint` writes a WAL checkpoint, `vacuum` reclaims space held by
+/// deleted rows and trailing free pages, and `prune-wal` checkpoints and
+/// removes the WAL records before the checkpoint.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/maintenance/{operation}",
+    params(
+        ("operation" = String, Path, description = "One of checkpoint, vacuum or prune-wal")
+    ),
+    responses(
+        (status = 200, description = "Maintenance operation completed", body = ApiResponse<MaintenanceReport>),
+        (status = 403, description = "Admin permission required", body = ApiResponse<String>),
+        (status = 404, description = "Unknown maintenance operation", body = ApiResponse<String>),
+    ),
+    tag = "Monitoring"
+)]
+pub async fn run_maintenance(
+    req: HttpRequest,
+    path: web::Path<String>,
+    app_state: web::Data<crate::AppState>,
+) -> ActixResult<HttpResponse, ApiError> {
+    let start = Instant::now();
+    let operation = path.into_inner();
+
+    {
+        let extensions = req.extensions();
+        let api_key = extensions
+            .get::<ApiKey>()
+            .ok_or_else(|| ApiError::Unauthorized("Admin authentication required".to_string()))?;
+
+        if !api_key.permissions.contains(&"admin".to_string()) {
+            return Err(ApiError::Forbidden(
+                "Admin permission required to run storage maintenance".to_string(),
+            ));
+        }
+    }
+
+    let mut report = MaintenanceReport {
+        operation: operation.clone(),
+        checkpoint_lsn: None,
+        vacuum: None,
+        wal: None,
+    };
+    let db = app_state.db.read().await;
+    let result = match operation.as_str() {
+        | "checkpoint" => db.storage().await.checkpoint().await.map(|lsn| {
+            report.checkpoint_lsn = Some(lsn);
+        }),
+        | "vacuum" => db.storage_mut().await.vacuum().await.map(|stats| {
+            report.vacuum = Some(VacuumReport {
+                tables_scanned: stats.tables_scanned,
+                compressed_blocks_removed: stats.compressed_blocks_removed,
+                cached_rows_evicted: stats.cached_rows_evicted,
+                pages_compacted: stats.pages_compacted,
+            });
+        }),
+        | "prune-wal" => db.storage().await.prune_wal().await.map(|stats| {
+            report.checkpoint_lsn = Some(stats.checkpoint_lsn);
+            report.wal = Some(WalPruneReport {
+                pruned_through_lsn: stats.pruned_through_lsn,
+                records_pruned: stats.records_pruned,
+                records_kept: stats.records_kept,
+                bytes_reclaimed: stats.bytes_reclaimed,
+            });
+        }),
+        | _ => {
+            return Err(ApiError::NotFound(format!(
+                "Unknown maintenance operation '{operation}'"
+            )));
+        },
+    };
+    drop(db);
+
+    let status = if result.is_ok() { "success" } else { "failed" };
+    crate::metrics::record_db_operation(
+        &format!("maintenance_{}", operation.replace('-', "_")),
+        status,
+        start.elapsed().as_secs_f64(),
+    );
+    result.map_err(|e| ApiError::InternalServerError {
+        message: format!("Maintenance operation '{operation}' failed: {e}"),
+    })?;
+
+    info!("🧹 Maintenance operation '{}' completed", operation);
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        report,
+        ResponseMetadata::new(start.elapsed(), "Maintenance operation completed"),
+    )))
+}
+
+/// Query the audit log of authenticated requests
+///
+/// Returns the most recent entries matching the filter along with the result
+/// of verifying the whole hash chain.
+#[utoipa::path(
+    get,
+    path = "/api/v1/audit",
+    params(AuditFilter),
+    responses(
+        (status = 200, description = "Audit entries retrieved", body = ApiResponse<AuditLogResponse>),
+        (status = 403, description = "Admin permission required", body = ApiResponse<String>),
+    ),
+    tag = "Monitoring"
+)]
+pub async fn get_audit_log(
+    req: HttpRequest,
+    filter: web::Query<AuditFilter>,
+    app_state: web::Data<crate::AppState>,
+) -> ActixResult<HttpResponse, ApiError> {
+    let start = Instant::now();
+
+    {
+        let extensions = req.extensions();
+        let api_key = extensions
+            .get::<ApiKey>()
+            .ok_or_else(|| ApiError::Unauthorized("Admin authentication required".to_string()))?;
+
+        if !api_key.permissions.contains(&"admin".to_string()) {
+            return Err(ApiError::Forbidden(
+                "Admin permission required to view the audit log".to_string(),
+            ));
+        }
+    }
+
+    let entries =
+        app_state
+            .audit_log
+            .query(&filter)
+            .await
+            .map_err(|e| ApiError::InternalServerError {
+                message: format!("Failed to read the audit log: {e:#}"),
+            })?;
+    let verification = app_state.audit_log.verify().await;
+    if let Err(e) = &verification {
+        warn!("🚨 Audit log failed verification: {}", e);
+    }
+    let response = AuditLogResponse {
+        entries,
+        chain_valid: verification.is_ok(),
+        chain_error: verification.err().map(|e| e.to_string()),
+    };
+
+    let message = format!("Retrieved {} audit entries", response.entries.len());
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        response,
+        ResponseMetadata::new(start.elapsed(), &message),
+    )))
+}
+
+/// Collect the performance statistics reported by `/api/v1/stats/performance`
+pub(crate) async fn collect_performance_stats(app_state: &crate::AppState) -> PerformanceStats {
     // Estimation ratios for neural/quantum operations
     // These represent the approximate percentage of total queries that use each feature
     const NEURAL_OPS_RATIO: f32 = 0.1; // ~10% of queries use neural matching (NEUROMATCH, etc.)
@@ -2315,8 +3227,8 @@ pub async fn get_performance_stats(
     // Get average query time from Prometheus histogram metrics
     let average_query_time_ms = crate::metrics::get_average_query_time_ms();
 
-    // Get active connections from WebSocket service
-    let active_connections = crate::metrics::ACTIVE_CONNECTIONS.get() as u32;
+    // Get requests currently admitted by the connection limiter
+    let active_connections = app_state.connection_limiter.active_connections() as u32;
 
     // Get neural network training stats from Prometheus metrics
     let training_jobs = crate::metrics::NEURAL_NETWORKS_TRAINING.get() as u32;
@@ -2343,7 +3255,7 @@ pub async fn get_performance_stats(
     };
 
     // Build performance stats with real metrics
-    let stats = PerformanceStats {
+    PerformanceStats {
         system_metrics: SystemMetrics {
             memory_usage_mb,
             cpu_usage_percent,
@@ -2373,12 +3285,7 @@ pub async fn get_performance_stats(
             quantum_state_fidelity: 0.96, // Simulated
             measurement_error_rate: 0.02, // Simulated
         },
-    };
-
-    Ok(HttpResponse::Ok().json(ApiResponse::success(
-        stats,
-        ResponseMetadata::new(start.elapsed(), "Performance statistics collected"),
-    )))
+    }
 }
 
 // =============================================================================
@@ -2413,6 +3320,27 @@ pub struct EEGAuthRequest {
     pub user_id: String,
     pub sampling_rate: f32,
     pub raw_eeg_data: Vec<f32>,
+    /// Nonce of the challenge the sample was captured for
+    #[serde(default)]
+    pub nonce: Option<String>,
+}
+
+/// Request for an EEG liveness challenge
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+pub struct EEGChallengeRequest {
+    #[validate(length(min = 3, max = 100))]
+    pub user_id: String,
+}
+
+/// Liveness challenge to answer with the next EEG sample
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EEGChallengeResponse {
+    pub user_id: String,
+    /// Single-use nonce to submit with the sample
+    pub nonce: String,
+    /// Frequency in Hz to stimulate the capture session at
+    pub marker_frequency: f32,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
 }
 
 /// Response from EEG authentication
@@ -2425,6 +3353,64 @@ pub struct EEGAuthResponse {
     pub timestamp: chrono::DateTime<chrono::Utc>,
 }
 
+/// Request to migrate EEG signatures to the current feature version
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct EEGMigrateRequest {
+    /// Freshly recorded EEG sample per user, used to re-extract their template
+    #[serde(default)]
+    pub samples: HashMap<String, Vec<f32>>,
+}
+
+/// Response from EEG signature migration
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EEGMigrateResponse {
+    /// Feature version signatures were migrated to
+    pub feature_version: u32,
+    /// Users whose template was re-extracted
+    pub migrated: Vec<String>,
+    /// Users who still need to re-enroll
+    pub pending: Vec<String>,
+    /// Users whose sample could not be processed, with the reason
+    pub failed: HashMap<String, String>,
+}
+
+/// Authenticate an EEG sample, answering the user's challenge if a nonce is given
+///
+/// Samples without a nonce are only accepted when challenges are not required.
+async fn authenticate_eeg(
+    app_state: &crate::AppState,
+    user_id: &str,
+    nonce: Option<&str>,
+    sample: &[f32],
+) -> Result<AuthenticationResult, EEGError> {
+    if let Some(nonce) = nonce {
+        return app_state
+            .eeg_service
+            .write()
+            .await
+            .authenticate_with_challenge(user_id, nonce, sample);
+    }
+
+    let eeg_service = app_state.eeg_service.read().await;
+    if eeg_service.config().require_challenge {
+        return Err(EEGError::ChallengeInvalid(user_id.to_string()));
+    }
+    eeg_service.authenticate(user_id, sample)
+}
+
+/// Map an EEG authentication error to an API error
+///
+/// Outdated signatures are a conflict rather than a rejection, telling the
+/// client to re-enroll instead of reporting a biometric mismatch.
+fn eeg_auth_error(context: &str, error: &EEGError) -> ApiError {
+    match error {
+        | EEGError::ReenrollmentRequired { .. } => {
+            ApiError::Conflict(format!("{context}: {error}"))
+        },
+        | _ => ApiError::Unauthorized(format!("{context}: {error}")),
+    }
+}
+
 /// Enroll a user with EEG biometric signature
 #[utoipa::path(
     post,
@@ -2495,6 +3481,51 @@ pub async fn eeg_enroll(
     )))
 }
 
+/// Issue a liveness challenge for EEG authentication
+///
+/// The returned nonce must accompany the next sample, which has to be captured
+/// while the session is stimulated at the marker frequency.
+#[utoipa::path(
+    post,
+    path = "/api/v1/biometric/eeg/challenge",
+    request_body = EEGChallengeRequest,
+    responses(
+        (status = 200, description = "Challenge issued", body = ApiResponse<EEGChallengeResponse>),
+        (status = 404, description = "User not enrolled", body = ApiResponse<String>),
+    ),
+    tag = "Biometric Authentication"
+)]
+pub async fn eeg_challenge(
+    body: web::Json<EEGChallengeRequest>,
+    app_state: web::Data<crate::AppState>,
+) -> ActixResult<HttpResponse, ApiError> {
+    let start = Instant::now();
+
+    body.validate().map_err(|e| ApiError::ValidationError {
+        field: "challenge_request".to_string(),
+        message: format!("Invalid challenge request: {e}"),
+    })?;
+
+    let challenge = app_state
+        .eeg_service
+        .write()
+        .await
+        .issue_challenge(&body.user_id)
+        .map_err(|e| ApiError::NotFound(format!("EEG challenge not issued: {e}")))?;
+
+    let response = EEGChallengeResponse {
+        user_id: challenge.user_id,
+        nonce: challenge.nonce,
+        marker_frequency: challenge.marker_frequency,
+        expires_at: challenge.expires_at,
+    };
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        response,
+        ResponseMetadata::new(start.elapsed(), "EEG challenge issued"),
+    )))
+}
+
 /// Authenticate user with EEG biometric data
 #[utoipa::path(
     post,
@@ -2504,6 +3535,7 @@ pub async fn eeg_enroll(
         (status = 200, description = "Authentication result", body = ApiResponse<EEGAuthResponse>),
         (status = 400, description = "Invalid EEG data", body = ApiResponse<String>),
         (status = 401, description = "Authentication failed", body = ApiResponse<String>),
+        (status = 409, description = "Signature outdated, re-enrollment required", body = ApiResponse<String>),
     ),
     tag = "Biometric Authentication"
 )]
@@ -2520,13 +3552,15 @@ pub async fn eeg_authenticate(
         message: format!("Invalid auth request: {e}"),
     })?;
 
-    // Use shared EEG auth service from AppState
-    let eeg_service = app_state.eeg_service.read().await;
-
     // Authenticate user
-    let auth_result = eeg_service
-        .authenticate(&body.user_id, &body.raw_eeg_data)
-        .map_err(|e| ApiError::Unauthorized(format!("EEG authentication failed: {e}")))?;
+    let auth_result = authenticate_eeg(
+        &app_state,
+        &body.user_id,
+        body.nonce.as_deref(),
+        &body.raw_eeg_data,
+    )
+    .await
+    .map_err(|e| eeg_auth_error("EEG authentication failed", &e))?;
 
     let response = EEGAuthResponse {
         authenticated: auth_result.authenticated,
@@ -2650,6 +3684,57 @@ pub async fn eeg_list_users(
     )))
 }
 
+/// Migrate EEG signatures to the current feature extraction version
+///
+/// Signatures of users with a sample in the request are re-extracted from it;
+/// other outdated users are reported as pending re-enrollment.
+#[utoipa::path(
+    post,
+    path = "/api/v1/biometric/eeg/migrate",
+    request_body = EEGMigrateRequest,
+    responses(
+        (status = 200, description = "Migration report", body = ApiResponse<EEGMigrateResponse>),
+        (status = 403, description = "Admin permission required", body = ApiResponse<String>),
+    ),
+    tag = "Biometric Authentication"
+)]
+pub async fn eeg_migrate_signatures(
+    req: HttpRequest,
+    body: web::Json<EEGMigrateRequest>,
+    app_state: web::Data<crate::AppState>,
+) -> ActixResult<HttpResponse, ApiError> {
+    let start = Instant::now();
+
+    // Check permissions - use block to drop RefCell reference before await
+    {
+        let extensions = req.extensions();
+        let api_key = extensions
+            .get::<ApiKey>()
+            .ok_or_else(|| ApiError::Unauthorized("Authentication required".to_string()))?;
+
+        if !api_key.permissions.contains(&"admin".to_string()) {
+            return Err(ApiError::Forbidden(
+                "Admin permission required to migrate EEG signatures".to_string(),
+            ));
+        }
+    }
+
+    let mut eeg_service = app_state.eeg_service.write().await;
+    let report = eeg_service.migrate_signatures(&body.samples);
+
+    let response = EEGMigrateResponse {
+        feature_version: report.feature_version,
+        migrated: report.migrated,
+        pending: report.pending,
+        failed: report.failed,
+    };
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        response,
+        ResponseMetadata::new(start.elapsed(), "EEG signature migration completed"),
+    )))
+}
+
 // =============================================================================
 // BIOMETRIC ENROLL/VERIFY ENDPOINTS (Documented API)
 // =============================================================================
@@ -2700,6 +3785,9 @@ pub struct BiometricVerifyRequest {
     /// Sampling rate in Hz (default: 256)
     #[serde(default)]
     pub sampling_rate: Option<f32>,
+    /// Nonce of the challenge the sample was captured for
+    #[serde(default)]
+    pub nonce: Option<String>,
 }
 
 /// Response from biometric verification
@@ -2839,6 +3927,7 @@ pub async fn biometric_enroll(
         (status = 200, description = "Verification result", body = ApiResponse<BiometricVerifyResponse>),
         (status = 400, description = "Invalid biometric data", body = ApiResponse<String>),
         (status = 401, description = "Verification failed", body = ApiResponse<String>),
+        (status = 409, description = "Signature outdated, re-enrollment required", body = ApiResponse<String>),
     ),
     tag = "Biometric Authentication"
 )]
@@ -2862,13 +3951,15 @@ pub async fn biometric_verify(
         ));
     }
 
-    // Use shared EEG auth service from AppState
-    let eeg_service = app_state.eeg_service.read().await;
-
     // Authenticate user
-    let auth_result = eeg_service
-        .authenticate(&body.user_id, &body.eeg_sample)
-        .map_err(|e| ApiError::Unauthorized(format!("Biometric verification failed: {e}")))?;
+    let auth_result = authenticate_eeg(
+        &app_state,
+        &body.user_id,
+        body.nonce.as_deref(),
+        &body.eeg_sample,
+    )
+    .await
+    .map_err(|e| eeg_auth_error("Biometric verification failed", &e))?;
 
     // Generate session token if verified
     let session_token = if auth_result.authenticated {
@@ -2921,97 +4012,777 @@ pub async fn biometric_verify(
     }
 }
 
-// =============================================================================
-// GENERIC SQL QUERY HANDLER
-// =============================================================================
+// =============================================================================
+// GENERIC SQL QUERY HANDLER
+// =============================================================================
+
+/// Execute a generic SQL query
+#[utoipa::path(
+    post,
+    path = "/api/v1/query",
+    request_body = SqlQueryRequest,
+    responses(
+        (status = 200, description = "Query executed successfully", body = ApiResponse<SqlQueryResponse>),
+        (status = 400, description = "Invalid SQL query", body = ApiResponse<String>),
+        (status = 403, description = "Insufficient permissions", body = ApiResponse<String>),
+        (status = 404, description = "Table not found", body = ApiResponse<String>),
+        (status = 409, description = "Query cancelled", body = ApiResponse<String>),
+    ),
+    tag = "CRUD Operations"
+)]
+#[tracing::instrument(
+    name = "execute_sql_query",
+    skip_all,
+    fields(query_len = query_req.query.len(), execution_time_ms = tracing::field::Empty)
+)]
+pub async fn execute_sql_query(
+    req: HttpRequest,
+    app_state: web::Data<crate::AppState>,
+    query_req: web::Json<SqlQueryRequest>,
+) -> ActixResult<HttpResponse, ApiError> {
+    let start = Instant::now();
+    let response = run_sql_query(&req, &app_state, &query_req).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        response,
+        ResponseMetadata::new(start.elapsed(), "SQL query executed successfully"),
+    )))
+}
+
+/// Execute a generic SQL query (v2 response envelope)
+///
+/// Same semantics as `POST /api/v1/query`; the result is returned in an
+/// [`ApiResponseV2`] envelope with `columns` and `rows` always present.
+#[utoipa::path(
+    post,
+    path = "/api/v2/query",
+    request_body = SqlQueryRequest,
+    responses(
+        (status = 200, description = "Query executed successfully", body = ApiResponseV2<SqlQueryResultV2>),
+        (status = 400, description = "Invalid SQL query", body = ApiResponse<String>),
+        (status = 403, description = "Insufficient permissions", body = ApiResponse<String>),
+    ),
+    tag = "CRUD Operations"
+)]
+#[tracing::instrument(
+    name = "execute_sql_query_v2",
+    skip_all,
+    fields(query_len = query_req.query.len(), execution_time_ms = tracing::field::Empty)
+)]
+pub async fn execute_sql_query_v2(
+    req: HttpRequest,
+    app_state: web::Data<crate::AppState>,
+    query_req: web::Json<SqlQueryRequest>,
+) -> ActixResult<HttpResponse, ApiError> {
+    let start = Instant::now();
+    let response = run_sql_query(&req, &app_state, &query_req).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponseV2::new(
+        SqlQueryResultV2::from(response),
+        start.elapsed(),
+    )))
+}
+
+/// Validate, authorize and execute a SQL query on behalf of the request's API key
+async fn run_sql_query(
+    req: &HttpRequest,
+    app_state: &crate::AppState,
+    query_req: &SqlQueryRequest,
+) -> Result<SqlQueryResponse, ApiError> {
+    let labels = StatementLabels::of(&query_req.query);
+    let result = execute_sql_statement(req, app_state, query_req, &labels).await;
+    if let Err(e) = &result {
+        crate::metrics::record_query_error(&labels, query_error_category(e, &labels));
+    }
+    result
+}
+
+/// Error category a failed query is counted under
+fn query_error_category(error: &ApiError, labels: &StatementLabels) -> &'static str {
+    match error {
+        | ApiError::ValidationError { .. } => "validation",
+        | ApiError::Unauthorized(_) | ApiError::Forbidden(_) => "permission",
+        | ApiError::QueryCancelled { .. } => "cancelled",
+        | ApiError::Conflict(_) => "conflict",
+        | _ => labels.failure_category(),
+    }
+}
+
+async fn execute_sql_statement(
+    req: &HttpRequest,
+    app_state: &crate::AppState,
+    query_req: &SqlQueryRequest,
+    labels: &StatementLabels,
+) -> Result<SqlQueryResponse, ApiError> {
+    let start = Instant::now();
+
+    // Validate request
+    query_req
+        .validate()
+        .map_err(|e| ApiError::ValidationError {
+            field: "query".to_string(),
+            message: e.to_string(),
+        })?;
+
+    // Check permissions - Extract API key data before any await points
+    let (has_permission, required_permission, field_access, owner, request_id, scope_check) = {
+        let extensions = req.extensions();
+        let api_key = extensions
+            .get::<ApiKey>()
+            .ok_or_else(|| ApiError::Unauthorized("Authentication required".to_string()))?;
+
+        // Determine required permission based on query type
+        let required_permission = required_permission_for_query(&query_req.query);
+
+        let has_permission = api_key
+            .permissions
+            .contains(&required_permission.to_string())
+            || api_key.permissions.contains(&"admin".to_string());
+
+        // ENCRYPTED columns are only decrypted for keys holding the decrypt permission
+        let field_access = if Permission::has_decrypt(&api_key.permissions) {
+            FieldAccess::Decrypted
+        } else {
+            FieldAccess::Redacted
+        };
+
+        // Queries are registered under the request ID so they can be cancelled
+        let request_id = extensions.get::<RequestId>().map_or_else(
+            || uuid::Uuid::new_v4().to_string(),
+            |request_id| request_id.0.clone(),
+        );
+
+        let scope_check =
+            check_query_scopes(&api_key.permissions, &api_key.scopes, &query_req.query);
+
+        (
+            has_permission,
+            required_permission.to_string(),
+            field_access,
+            api_key.key.clone(),
+            request_id,
+            scope_check,
+        )
+    }; // extensions reference is dropped here
+    req.extensions_mut()
+        .insert(AuditDetails::for_statement(&query_req.query));
+
+    if !has_permission {
+        return Err(ApiError::Forbidden(format!(
+            "{required_permission} permission required for this query"
+        )));
+    }
+    scope_check?;
+
+    info!(
+        "🔍 Executing SQL query: {}",
+        query_req.query.chars().take(100).collect::<String>()
+    );
+
+    // Register before waiting for the engine so queued queries can be cancelled too
+    let running = app_state.running_queries.register(&request_id, &owner)?;
+    let token = running.token();
+
+    // Execute query using QSQL engine
+    // Note: Storage synchronization is handled automatically through the shared database state
+    let mut qsql_engine = app_state.qsql_engine.lock().await;
+    let query_result = qsql_engine
+        .execute_query_cancellable(&query_req.query, field_access, token.clone())
+        .await
+        .map_err(|e| {
+            crate::metrics::record_db_operation("query", "failed", start.elapsed().as_secs_f64());
+            if token.is_cancelled() {
+                ApiError::QueryCancelled {
+                    request_id: request_id.clone(),
+                }
+            } else {
+                ApiError::from_query_error(e)
+            }
+        })?;
+
+    let execution_time_ms = start.elapsed().as_secs_f64() * 1000.0;
+    tracing::Span::current().record("execution_time_ms", execution_time_ms);
+
+    // Record successful query metrics
+    crate::metrics::record_db_operation("query", "success", start.elapsed().as_secs_f64());
+    crate::metrics::record_statement(labels, start.elapsed().as_secs_f64());
+
+    // Convert QSQL QueryResult to SqlQueryResponse
+    let response = query_result_to_response(query_result, execution_time_ms);
+
+    info!(
+        "✅ SQL query executed successfully in {:.2}ms",
+        execution_time_ms
+    );
+
+    Ok(response)
+}
+
+/// Cancel an in-flight query
+///
+/// Signals the query registered under `request_id` (the `X-Request-ID` of the
+/// original `POST /api/v1/query`), which then fails with a `409` cancelled
+/// error. Only the API key that submitted the query, or an admin key, may
+/// cancel it.
+#[utoipa::path(
+    post,
+    path = "/api/v1/query/{request_id}/cancel",
+    params(
+        ("request_id" = String, Path, description = "Request ID of the running query")
+    ),
+    responses(
+        (status = 200, description = "Query cancelled", body = ApiResponse<CancelQueryResponse>),
+        (status = 404, description = "No running query with this request ID", body = ApiResponse<String>),
+    ),
+    tag = "CRUD Operations"
+)]
+pub async fn cancel_query(
+    req: HttpRequest,
+    app_state: web::Data<crate::AppState>,
+    path: web::Path<String>,
+) -> ActixResult<HttpResponse, ApiError> {
+    let start = Instant::now();
+    let request_id = path.into_inner();
+
+    let (requester, is_admin) = {
+        let extensions = req.extensions();
+        let api_key = extensions
+            .get::<ApiKey>()
+            .ok_or_else(|| ApiError::Unauthorized("Authentication required".to_string()))?;
+        (
+            api_key.key.clone(),
+            api_key.permissions.contains(&"admin".to_string()),
+        )
+    };
+
+    if !app_state
+        .running_queries
+        .cancel(&request_id, &requester, is_admin)
+    {
+        return Err(ApiError::NotFound(format!(
+            "No running query with request ID {request_id}; it may have already completed"
+        )));
+    }
+
+    info!("🛑 Cancelled query {}", request_id);
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        CancelQueryResponse {
+            request_id,
+            cancelled: true,
+        },
+        ResponseMetadata::new(start.elapsed(), "Query cancellation requested"),
+    )))
+}
+
+/// Isolation levels a client may request when beginning a transaction
+pub(crate) const ISOLATION_LEVELS: [&str; 4] = [
+    "READ UNCOMMITTED",
+    "READ COMMITTED",
+    "REPEATABLE READ",
+    "SERIALIZABLE",
+];
 
-/// Execute a generic SQL query
+/// Execute a batch of SQL statements in order
+///
+/// Each statement produces its own entry in the `results` array, positionally
+/// aligned with the request. A failing statement does not abort the batch
+/// unless `transactional` is set, in which case the whole batch runs inside a
+/// single transaction and is rolled back on the first failure. The
+/// transaction runs at `isolation_level` when one is given.
 #[utoipa::path(
     post,
-    path = "/api/v1/query",
-    request_body = SqlQueryRequest,
+    path = "/api/v1/query/batch",
+    request_body = BatchQueryRequest,
     responses(
-        (status = 200, description = "Query executed successfully", body = ApiResponse<SqlQueryResponse>),
-        (status = 400, description = "Invalid SQL query", body = ApiResponse<String>),
+        (status = 200, description = "Batch executed", body = ApiResponse<BatchQueryResponse>),
+        (status = 400, description = "Invalid batch", body = ApiResponse<String>),
         (status = 403, description = "Insufficient permissions", body = ApiResponse<String>),
     ),
     tag = "CRUD Operations"
 )]
-pub async fn execute_sql_query(
+pub async fn execute_batch_query(
     req: HttpRequest,
     app_state: web::Data<crate::AppState>,
-    query_req: web::Json<SqlQueryRequest>,
+    batch_req: web::Json<BatchQueryRequest>,
 ) -> ActixResult<HttpResponse, ApiError> {
-    use crate::error::SqlQueryResponse;
-
     let start = Instant::now();
 
-    // Validate request
-    query_req
+    batch_req
         .validate()
         .map_err(|e| ApiError::ValidationError {
-            field: "query".to_string(),
+            field: "queries".to_string(),
             message: e.to_string(),
         })?;
 
-    // Check permissions - Extract API key data before any await points
-    let (has_permission, required_permission) = {
+    // Check permissions for every statement before executing anything
+    let (missing_permission, scope_check) = {
         let extensions = req.extensions();
         let api_key = extensions
             .get::<ApiKey>()
             .ok_or_else(|| ApiError::Unauthorized("Authentication required".to_string()))?;
 
-        // Determine required permission based on query type
-        let query_upper = query_req.query.trim().to_uppercase();
-        let required_permission = if query_upper.starts_with("SELECT")
-            || query_upper.starts_with("EXPLAIN")
-            || query_upper.starts_with("DESCRIBE")
-            || query_upper.starts_with("SHOW")
+        let is_admin = api_key.permissions.contains(&"admin".to_string());
+        let missing_permission = batch_req
+            .queries
+            .iter()
+            .map(|item| required_permission_for_query(&item.sql))
+            .find(|perm| !is_admin && !api_key.permissions.contains(&(*perm).to_string()));
+        let scope_check = batch_req.queries.iter().try_for_each(|item| {
+            check_query_scopes(&api_key.permissions, &api_key.scopes, &item.sql)
+        });
+        (missing_permission, scope_check)
+    };
+    req.extensions_mut().insert(AuditDetails::for_statements(
+        batch_req.queries.iter().map(|item| item.sql.as_str()),
+    ));
+
+    if let Some(permission) = missing_permission {
+        return Err(ApiError::Forbidden(format!(
+            "{permission} permission required for this batch"
+        )));
+    }
+    scope_check?;
+
+    // Convert parameters up front so malformed params fail the request early
+    let mut bound_params = Vec::with_capacity(batch_req.queries.len());
+    for (idx, item) in batch_req.queries.iter().enumerate() {
+        let params = item
+            .params
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .map(json_to_literal)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|message| ApiError::ValidationError {
+                field: format!("queries[{idx}].params"),
+                message,
+            })?;
+        bound_params.push(params);
+    }
+
+    let transactional = batch_req.transactional.unwrap_or(false);
+    let begin_statement = match batch_req.isolation_level.as_deref() {
+        | None => "BEGIN".to_string(),
+        | Some(_) if !transactional => {
+            return Err(ApiError::ValidationError {
+                field: "isolation_level".to_string(),
+                message: "isolation_level requires a transactional batch".to_string(),
+            });
+        },
+        | Some(level) => {
+            let level = level.trim().to_uppercase();
+            if !ISOLATION_LEVELS.contains(&level.as_str()) {
+                return Err(ApiError::ValidationError {
+                    field: "isolation_level".to_string(),
+                    message: format!("Unknown isolation level: {level}"),
+                });
+            }
+            format!("BEGIN TRANSACTION ISOLATION LEVEL {level}")
+        },
+    };
+
+    info!(
+        "📦 Executing batch of {} statements (transactional: {})",
+        batch_req.queries.len(),
+        transactional
+    );
+
+    let mut qsql_engine = app_state.qsql_engine.lock().await;
+
+    if transactional {
+        qsql_engine
+            .execute_query(&begin_statement)
+            .await
+            .map_err(|e| ApiError::InternalServerError {
+                message: format!("Failed to begin batch transaction: {e}"),
+            })?;
+    }
+
+    let mut results = Vec::with_capacity(batch_req.queries.len());
+    let mut aborted = false;
+
+    for (idx, (item, params)) in batch_req.queries.iter().zip(&bound_params).enumerate() {
+        if aborted {
+            results.push(BatchQueryResult {
+                index: idx,
+                success: false,
+                result: None,
+                error: Some("Skipped: batch transaction rolled back".to_string()),
+            });
+            continue;
+        }
+
+        let labels = StatementLabels::of(&item.sql);
+        let stmt_start = Instant::now();
+        match qsql_engine
+            .execute_query_with_params(&item.sql, params)
+            .await
         {
-            "read"
+            | Ok(query_result) => {
+                crate::metrics::record_db_operation(
+                    "query",
+                    "success",
+                    stmt_start.elapsed().as_secs_f64(),
+                );
+                crate::metrics::record_statement(&labels, stmt_start.elapsed().as_secs_f64());
+                results.push(BatchQueryResult {
+                    index: idx,
+                    success: true,
+                    result: Some(query_result_to_response(
+                        query_result,
+                        stmt_start.elapsed().as_secs_f64() * 1000.0,
+                    )),
+                    error: None,
+                });
+            },
+            | Err(e) => {
+                crate::metrics::record_db_operation(
+                    "query",
+                    "failed",
+                    stmt_start.elapsed().as_secs_f64(),
+                );
+                crate::metrics::record_query_error(&labels, labels.failure_category());
+                results.push(BatchQueryResult {
+                    index: idx,
+                    success: false,
+                    result: None,
+                    error: Some(format!("Query execution failed: {e}")),
+                });
+                aborted = transactional;
+            },
+        }
+    }
+
+    let mut rolled_back = false;
+    if transactional {
+        if aborted {
+            qsql_engine.execute_query("ROLLBACK").await.map_err(|e| {
+                ApiError::InternalServerError {
+                    message: format!("Failed to roll back batch transaction: {e}"),
+                }
+            })?;
+            rolled_back = true;
+            // Statements that ran before the failure were undone as well
+            for result in results.iter_mut().filter(|r| r.success) {
+                result.success = false;
+                result.error = Some("Rolled back: later statement in batch failed".to_string());
+            }
         } else {
-            "write"
-        };
+            qsql_engine.execute_query("COMMIT").await.map_err(|e| {
+                ApiError::InternalServerError {
+                    message: format!("Failed to commit batch transaction: {e}"),
+                }
+            })?;
+        }
+    }
+    drop(qsql_engine);
 
-        let has_permission = api_key
-            .permissions
-            .contains(&required_permission.to_string())
-            || api_key.permissions.contains(&"admin".to_string());
+    let succeeded = results.iter().filter(|r| r.success).count();
+    let failed = results.len() - succeeded;
 
-        (has_permission, required_permission.to_string())
-    }; // extensions reference is dropped here
+    info!(
+        "✅ Batch finished: {} succeeded, {} failed{}",
+        succeeded,
+        failed,
+        if rolled_back { " (rolled back)" } else { "" }
+    );
+
+    let response = BatchQueryResponse {
+        results,
+        succeeded,
+        failed,
+        transactional,
+        rolled_back,
+        execution_time_ms: start.elapsed().as_secs_f64() * 1000.0,
+    };
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        response,
+        ResponseMetadata::new(start.elapsed(), "Batch query executed"),
+    )))
+}
+
+/// Number of streamed events or rows that may be queued ahead of a slow client
+pub(crate) const STREAM_CHANNEL_CAPACITY: usize = 32;
+
+/// Number of rows read from storage per page while streaming a SELECT
+const STREAM_PAGE_SIZE: usize = 256;
+
+/// Stream the rows of a SELECT as Server-Sent Events
+///
+/// Each row is sent as a `row` event, followed by a terminal `complete` event
+/// carrying the row count and timing (or an `error` event on failure). Events
+/// flow through a bounded channel, so a slow client pauses the producer rather
+/// than letting serialized rows pile up in memory.
+///
+/// Single-table SELECTs are read from storage a page at a time in primary
+/// key order, and the next page is only read once the previous one has been
+/// queued. SELECTs with joins, aggregates, ORDER BY or LIMIT are executed
+/// whole before streaming.
+#[utoipa::path(
+    get,
+    path = "/api/v1/query/stream",
+    params(StreamQueryParams),
+    responses(
+        (status = 200, description = "Row stream (text/event-stream)", body = String),
+        (status = 400, description = "Invalid or non-SELECT query", body = ApiResponse<String>),
+        (status = 403, description = "Insufficient permissions", body = ApiResponse<String>),
+    ),
+    tag = "CRUD Operations"
+)]
+pub async fn stream_sql_query(
+    req: HttpRequest,
+    app_state: web::Data<crate::AppState>,
+    params: web::Query<StreamQueryParams>,
+) -> ActixResult<HttpResponse, ApiError> {
+    use actix_web::web::Bytes;
+
+    let start = Instant::now();
+
+    params.validate().map_err(|e| ApiError::ValidationError {
+        field: "query".to_string(),
+        message: e.to_string(),
+    })?;
+
+    if !params.query.trim().to_uppercase().starts_with("SELECT") {
+        return Err(ApiError::BadRequest(
+            "Only SELECT statements can be streamed".to_string(),
+        ));
+    }
+
+    let (has_permission, scope_check) = {
+        let extensions = req.extensions();
+        let api_key = extensions
+            .get::<ApiKey>()
+            .ok_or_else(|| ApiError::Unauthorized("Authentication required".to_string()))?;
+
+        (
+            api_key.permissions.contains(&"read".to_string())
+                || api_key.permissions.contains(&"admin".to_string()),
+            check_query_scopes(&api_key.permissions, &api_key.scopes, &params.query),
+        )
+    };
+    req.extensions_mut()
+        .insert(AuditDetails::for_statement(&params.query));
 
     if !has_permission {
-        return Err(ApiError::Forbidden(format!(
-            "{required_permission} permission required for this query"
-        )));
+        return Err(ApiError::Forbidden("Read permission required".to_string()));
     }
+    scope_check?;
 
     info!(
-        "🔍 Executing SQL query: {}",
-        &query_req.query[..query_req.query.len().min(100)]
+        "📡 Streaming SQL query: {}",
+        params.query.chars().take(100).collect::<String>()
     );
 
-    // Execute query using QSQL engine
-    // Note: Storage synchronization is handled automatically through the shared database state
-    let mut qsql_engine = app_state.qsql_engine.lock().await;
-    let query_result = qsql_engine
-        .execute_query(&query_req.query)
-        .await
-        .map_err(|e| {
-            crate::metrics::record_db_operation("query", "failed", start.elapsed().as_secs_f64());
-            ApiError::InvalidQuery {
-                details: format!("Query execution failed: {e}"),
+    let (tx, rx) = tokio::sync::mpsc::channel::<Bytes>(STREAM_CHANNEL_CAPACITY);
+    let qsql_engine = app_state.qsql_engine.clone();
+    let query = params.into_inner().query;
+
+    tokio::spawn(async move {
+        let labels = StatementLabels::of(&query);
+        let fail = |e: anyhow::Error| {
+            crate::metrics::record_db_operation(
+                "query_stream",
+                "failed",
+                start.elapsed().as_secs_f64(),
+            );
+            crate::metrics::record_query_error(&labels, labels.failure_category());
+            sse_event(
+                "error",
+                &serde_json::json!({ "error": format!("Query execution failed: {e}") }),
+            )
+        };
+
+        // Every page is read from the snapshot the cursor was opened with
+        let cursor = qsql_engine
+            .lock()
+            .await
+            .open_cursor(&query, FieldAccess::Redacted)
+            .await;
+        let mut cursor = match cursor {
+            | Ok(cursor) => cursor,
+            | Err(e) => {
+                let _ = tx.send(fail(e)).await;
+                return;
+            },
+        };
+
+        let streamed = stream_query_rows(&qsql_engine, &query, cursor.as_mut(), &tx).await;
+        if let Some(cursor) = cursor {
+            if let Err(e) = qsql_engine.lock().await.close_cursor(cursor).await {
+                warn!("Failed to close query stream cursor: {}", e);
+            }
+        }
+        let row_count = match streamed {
+            | Ok(Some(row_count)) => row_count,
+            | Ok(None) => return,
+            | Err(e) => {
+                let _ = tx.send(fail(e)).await;
+                return;
+            },
+        };
+
+        crate::metrics::record_statement(&labels, start.elapsed().as_secs_f64());
+        crate::metrics::record_db_operation(
+            "query_stream",
+            "success",
+            start.elapsed().as_secs_f64(),
+        );
+        let summary = serde_json::json!({
+            "row_count": row_count,
+            "execution_time_ms": start.elapsed().as_secs_f64() * 1000.0,
+        });
+        let _ = tx.send(sse_event("complete", &summary)).await;
+    });
+
+    let body = futures_util::stream::unfold(rx, |mut rx| async move {
+        rx.recv()
+            .await
+            .map(|chunk| (Ok::<_, actix_web::Error>(chunk), rx))
+    });
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header(("Cache-Control", "no-cache"))
+        .insert_header(("X-Accel-Buffering", "no"))
+        .streaming(body))
+}
+
+/// Send the rows of a streamed query as `row` events
+///
+/// Reads page by page from `cursor`, locking the engine per page so other
+/// queries run in between, or executes the query whole without one. Returns
+/// the number of rows sent, or `None` if the client disconnected.
+async fn stream_query_rows(
+    qsql_engine: &tokio::sync::Mutex<neuroquantum_qsql::QSQLEngine>,
+    query: &str,
+    mut cursor: Option<&mut neuroquantum_qsql::QueryCursor>,
+    tx: &tokio::sync::mpsc::Sender<actix_web::web::Bytes>,
+) -> anyhow::Result<Option<usize>> {
+    let mut row_count = 0usize;
+    loop {
+        let rows = {
+            let mut engine = qsql_engine.lock().await;
+            match cursor.as_deref_mut() {
+                | Some(cursor) => match engine.fetch_cursor_page(cursor, STREAM_PAGE_SIZE).await? {
+                    | Some(page) => page.rows,
+                    | None => break,
+                },
+                // Statements that cannot be paged are executed whole
+                | None => engine.execute_query(query).await?.rows,
+            }
+        };
+
+        for row in rows {
+            let payload: serde_json::Map<String, serde_json::Value> = row
+                .into_iter()
+                .map(|(k, v)| (k, query_value_to_json(v)))
+                .collect();
+            // `send` waits while the channel is full, pausing production
+            if tx
+                .send(sse_event("row", &serde_json::Value::Object(payload)))
+                .await
+                .is_err()
+            {
+                warn!("SSE client disconnected after {} rows", row_count);
+                return Ok(None);
             }
+            row_count += 1;
+        }
+
+        if cursor.is_none() {
+            break;
+        }
+    }
+    Ok(Some(row_count))
+}
+
+/// Format a single Server-Sent Event
+fn sse_event(event: &str, data: &serde_json::Value) -> actix_web::web::Bytes {
+    actix_web::web::Bytes::from(format!("event: {event}\ndata: {data}\n\n"))
+}
+
+/// Determine whether a SQL statement needs `read` or `write` permission
+pub(crate) fn required_permission_for_query(query: &str) -> &'static str {
+    let query_upper = query.trim().to_uppercase();
+    if query_upper.starts_with("SELECT")
+        || query_upper.starts_with("EXPLAIN")
+        || query_upper.starts_with("DESCRIBE")
+        || query_upper.starts_with("SHOW")
+    {
+        "read"
+    } else {
+        "write"
+    }
+}
+
+/// Check that the request's API key scope allows `action` on `table`
+fn require_table_scope(req: &HttpRequest, table: &str, action: &str) -> Result<(), ApiError> {
+    let extensions = req.extensions();
+    let api_key = extensions
+        .get::<ApiKey>()
+        .ok_or_else(|| ApiError::Unauthorized("Authentication required".to_string()))?;
+
+    if api_key.scope_allows(table, action) {
+        Ok(())
+    } else {
+        Err(ApiError::Forbidden(format!(
+            "API key scope does not allow {action} access to table '{table}'"
+        )))
+    }
+}
+
+/// Check the tables `query` reads and writes against an API key's scope rules
+///
+/// Keys without scope rules and admin keys are not restricted. Statements that
+/// reach tables only through an index or prepared statement name cannot be
+/// checked and are refused for scoped keys.
+pub(crate) fn check_query_scopes(
+    permissions: &[String],
+    scopes: &[ScopeRule],
+    query: &str,
+) -> Result<(), ApiError> {
+    if scopes.is_empty() || Permission::has_admin(permissions) {
+        return Ok(());
+    }
+
+    let statement = neuroquantum_qsql::Parser::new()
+        .parse_query(query)
+        .map_err(|e| ApiError::InvalidQuery {
+            details: format!("Query execution failed: {e}"),
         })?;
 
-    let execution_time_ms = start.elapsed().as_secs_f64() * 1000.0;
+    if table_access::names_tables_indirectly(&statement) {
+        return Err(ApiError::Forbidden(
+            "Statements referencing tables through indexes or prepared statements are not allowed for scoped API keys"
+                .to_string(),
+        ));
+    }
 
-    // Record successful query metrics
-    crate::metrics::record_db_operation("query", "success", start.elapsed().as_secs_f64());
+    for access in table_access::table_accesses(&statement) {
+        let action = match access.kind {
+            | AccessKind::Read => READ,
+            | AccessKind::Write => WRITE,
+        };
+        if !Permission::scope_allows(scopes, &access.table, action) {
+            return Err(ApiError::Forbidden(format!(
+                "API key scope does not allow {action} access to table '{}'",
+                access.table
+            )));
+        }
+    }
 
-    // Convert QSQL QueryResult to SqlQueryResponse
-    let response = SqlQueryResponse {
+    Ok(())
+}
+
+/// Convert a QSQL `QueryResult` into the API's `SqlQueryResponse`
+pub(crate) fn query_result_to_response(
+    query_result: neuroquantum_qsql::QueryResult,
+    execution_time_ms: f64,
+) -> SqlQueryResponse {
+    SqlQueryResponse {
         success: true,
         rows_affected: Some(query_result.rows_affected as usize),
         rows: if query_result.rows.is_empty() {
@@ -3023,31 +4794,7 @@ pub async fn execute_sql_query(
                     .into_iter()
                     .map(|row| {
                         row.into_iter()
-                            .map(|(k, v)| {
-                                let json_value = match v {
-                                    | QueryValue::Null => serde_json::Value::Null,
-                                    | QueryValue::Boolean(b) => serde_json::Value::Bool(b),
-                                    | QueryValue::Integer(i) => serde_json::Value::Number(i.into()),
-                                    | QueryValue::Float(f) => serde_json::Number::from_f64(f)
-                                        .map_or(serde_json::Value::Null, serde_json::Value::Number),
-                                    | QueryValue::String(s) => serde_json::Value::String(s),
-                                    | QueryValue::Blob(b) => {
-                                        use base64::Engine;
-                                        serde_json::Value::String(
-                                            base64::engine::general_purpose::STANDARD.encode(b),
-                                        )
-                                    },
-                                    | QueryValue::DNASequence(s) => serde_json::Value::String(s),
-                                    | QueryValue::SynapticWeight(w) => {
-                                        serde_json::Number::from_f64(f64::from(w)).map_or(
-                                            serde_json::Value::Null,
-                                            serde_json::Value::Number,
-                                        )
-                                    },
-                                    | QueryValue::QuantumState(s) => serde_json::Value::String(s),
-                                };
-                                (k, json_value)
-                            })
+                            .map(|(k, v)| (k, query_value_to_json(v)))
                             .collect()
                     })
                     .collect(),
@@ -3066,17 +4813,48 @@ pub async fn execute_sql_query(
         },
         error: None,
         execution_time_ms,
-    };
+    }
+}
 
-    info!(
-        "✅ SQL query executed successfully in {:.2}ms",
-        execution_time_ms
-    );
+/// Convert a QSQL `QueryValue` to `serde_json::Value`
+pub(crate) fn query_value_to_json(value: QueryValue) -> serde_json::Value {
+    match value {
+        | QueryValue::Null => serde_json::Value::Null,
+        | QueryValue::Boolean(b) => serde_json::Value::Bool(b),
+        | QueryValue::Integer(i) => serde_json::Value::Number(i.into()),
+        | QueryValue::Float(f) => serde_json::Number::from_f64(f)
+            .map_or(serde_json::Value::Null, serde_json::Value::Number),
+        | QueryValue::String(s) => serde_json::Value::String(s),
+        | QueryValue::Blob(b) => {
+            use base64::Engine;
+            serde_json::Value::String(base64::engine::general_purpose::STANDARD.encode(b))
+        },
+        | QueryValue::DNASequence(s) => serde_json::Value::String(s),
+        | QueryValue::SynapticWeight(w) => serde_json::Number::from_f64(f64::from(w))
+            .map_or(serde_json::Value::Null, serde_json::Value::Number),
+        | QueryValue::QuantumState(s) => serde_json::Value::String(s),
+    }
+}
 
-    Ok(HttpResponse::Ok().json(ApiResponse::success(
-        response,
-        ResponseMetadata::new(start.elapsed(), "SQL query executed successfully"),
-    )))
+/// Convert a JSON query parameter into a QSQL literal
+fn json_to_literal(value: &serde_json::Value) -> Result<Literal, String> {
+    match value {
+        | serde_json::Value::Null => Ok(Literal::Null),
+        | serde_json::Value::Bool(b) => Ok(Literal::Boolean(*b)),
+        | serde_json::Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Ok(Literal::Integer(i))
+            } else {
+                n.as_f64()
+                    .map(Literal::Float)
+                    .ok_or_else(|| format!("Unsupported numeric parameter: {n}"))
+            }
+        },
+        | serde_json::Value::String(s) => Ok(Literal::String(s.clone())),
+        | serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
+            Err("Array and object parameters are not supported".to_string())
+        },
+    }
 }
 
 // =============================================================================
@@ -3109,6 +4887,8 @@ pub struct IndexRecommendationDto {
     pub estimated_improvement: f64,
     /// Number of queries that would benefit
     pub affected_query_count: u64,
+    /// Number of observed full scans the index would have avoided
+    pub avoidable_full_scans: u64,
     /// SQL statement to create the index
     pub create_statement: String,
     /// Reason for the recommendation
@@ -3138,6 +4918,9 @@ pub struct IndexAdvisorStatsDto {
 /// - Estimated performance improvement
 /// - Priority level
 /// - Reason for the recommendation
+///
+/// Indexes that already exist in storage are never recommended. The same
+/// report is served at `/api/v1/stats/index-advisor`.
 #[utoipa::path(
     get,
     path = "/api/v1/advisor/indexes",
@@ -3171,6 +4954,7 @@ pub async fn get_index_recommendations(
 
     // Get recommendations from the QSQL engine's index advisor
     let qsql_engine = app_state.qsql_engine.lock().await;
+    qsql_engine.refresh_index_advisor_indexes().await;
     let recommendations = qsql_engine.get_index_recommendations();
     let statistics = qsql_engine.get_index_advisor_statistics();
 
@@ -3185,6 +4969,7 @@ pub async fn get_index_recommendations(
             priority: format!("{}", r.priority),
             estimated_improvement: r.estimated_improvement,
             affected_query_count: r.affected_query_count,
+            avoidable_full_scans: r.avoidable_full_scans,
             create_statement: r.create_statement,
             reason: r.reason,
             estimated_size_bytes: r.estimated_size_bytes,
@@ -3292,6 +5077,96 @@ pub fn json_to_storage_value(
 }
 
 /// Convert `storage::Value` to `serde_json::Value`
+/// Parse the `delimiter` query parameter into a single ASCII byte
+fn parse_csv_delimiter(delimiter: Option<&str>) -> Result<u8, ApiError> {
+    match delimiter {
+        | None => Ok(b','),
+        | Some("\\t") => Ok(b'\t'),
+        | Some(d) if d.len() == 1 && d != "\"" && d != "\n" && d != "\r" => Ok(d.as_bytes()[0]),
+        | Some(_) => Err(ApiError::ValidationError {
+            field: "delimiter".to_string(),
+            message: "Delimiter must be a single ASCII character other than a quote or newline"
+                .to_string(),
+        }),
+    }
+}
+
+/// Coerce a raw CSV field to the column's type
+///
+/// Returns `Ok(None)` for empty fields the storage engine fills in itself
+/// (AUTO_INCREMENT and DEFAULT columns), and `Value::Null` for other empty
+/// fields in nullable columns.
+fn csv_field_to_storage_value(
+    raw: &str,
+    column: &neuroquantum_core::storage::ColumnDefinition,
+) -> Result<Option<neuroquantum_core::storage::Value>, String> {
+    use neuroquantum_core::storage::{DataType, Value};
+
+    let name = &column.name;
+    if raw.is_empty() {
+        let generated = column.auto_increment
+            || column.default_value.is_some()
+            || matches!(column.data_type, DataType::Serial | DataType::BigSerial);
+        return if generated {
+            Ok(None)
+        } else if column.nullable {
+            Ok(Some(Value::Null))
+        } else if column.data_type == DataType::Text {
+            Ok(Some(Value::text("")))
+        } else {
+            Err(format!("Column '{name}' cannot be empty"))
+        };
+    }
+
+    let value = match column.data_type {
+        | DataType::Integer | DataType::Serial | DataType::BigSerial => raw
+            .trim()
+            .parse::<i64>()
+            .map(Value::Integer)
+            .map_err(|_| format!("Column '{name}': '{raw}' is not a valid integer"))?,
+        | DataType::Float => raw
+            .trim()
+            .parse::<f64>()
+            .map(Value::Float)
+            .map_err(|_| format!("Column '{name}': '{raw}' is not a valid float"))?,
+        | DataType::Boolean => match raw.trim().to_lowercase().as_str() {
+            | "true" | "t" | "1" | "yes" => Value::Boolean(true),
+            | "false" | "f" | "0" | "no" => Value::Boolean(false),
+            | _ => return Err(format!("Column '{name}': '{raw}' is not a valid boolean")),
+        },
+        | DataType::Timestamp => chrono::DateTime::parse_from_rfc3339(raw.trim())
+            .map(|ts| Value::Timestamp(ts.with_timezone(&chrono::Utc)))
+            .map_err(|_| format!("Column '{name}': '{raw}' is not an RFC 3339 timestamp"))?,
+        | DataType::Binary => {
+            use base64::Engine;
+            base64::engine::general_purpose::STANDARD
+                .decode(raw.trim())
+                .map(|bytes| Value::Binary(Arc::new(bytes)))
+                .map_err(|_| format!("Column '{name}': binary values must be base64-encoded"))?
+        },
+        | DataType::Text => Value::text(raw),
+    };
+
+    Ok(Some(value))
+}
+
+/// Render a storage value as a CSV field (NULL becomes an empty field)
+fn storage_value_to_csv(value: Option<&neuroquantum_core::storage::Value>) -> String {
+    use neuroquantum_core::storage::Value;
+    match value {
+        | None | Some(Value::Null) => String::new(),
+        | Some(Value::Integer(i)) => i.to_string(),
+        | Some(Value::Float(f)) => f.to_string(),
+        | Some(Value::Text(s)) => s.as_ref().clone(),
+        | Some(Value::Boolean(b)) => b.to_string(),
+        | Some(Value::Timestamp(ts)) => ts.to_rfc3339(),
+        | Some(Value::Binary(b)) => {
+            use base64::Engine;
+            base64::engine::general_purpose::STANDARD.encode(b.as_ref())
+        },
+    }
+}
+
 fn storage_value_to_json(value: &neuroquantum_core::storage::Value) -> serde_json::Value {
     use neuroquantum_core::storage::Value;
     match value {
@@ -40,10 +40,11 @@
 )]
 use std::time::Instant;
 
-use actix_cors::Cors;
 use actix_web::body::MessageBody;
-use actix_web::middleware::{Compress, Logger};
-use actix_web::{web, App, HttpMessage, HttpResponse, HttpServer, Result as ActixResult};
+use actix_web::error::{JsonPayloadError, PayloadError};
+use actix_web::http::Method;
+use actix_web::middleware::{Compress, Condition, Logger};
+use actix_web::{guard, web, App, HttpMessage, HttpResponse, HttpServer, Result as ActixResult};
 use actix_web_prom::PrometheusMetricsBuilder;
 use anyhow::Result;
 use biometric_auth::EEGAuthService;
@@ -53,30 +54,45 @@ use tracing::info;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
+pub mod audit;
 pub mod auth;
 pub mod biometric_auth;
 pub mod cli;
 pub mod config;
+pub mod connection_limit;
+pub mod cors;
+pub mod csv;
 pub mod error;
+pub mod graphql;
+pub mod grpc;
 pub mod handlers;
 pub mod jwt;
 pub mod metrics;
 pub mod middleware;
 pub mod permissions;
+pub mod query_cancellation;
 pub mod rate_limit;
+pub mod shutdown;
 pub mod storage;
+pub mod system_info;
+pub mod tls;
 pub mod tracing_setup;
+pub mod versioning;
 pub mod websocket;
 
 use std::sync::Arc;
 
+use audit::AuditLog;
 use auth::AuthService;
 pub use config::ApiConfig;
-pub use error::{ApiError, ApiResponse, ResponseMetadata};
+use connection_limit::{ConnectionLimitMiddleware, ConnectionLimiter};
+pub use error::{ApiError, ApiResponse, ErrorCode, ResponseMetadata};
 pub use handlers::json_to_storage_value;
 use handlers::ApiDoc;
 use jwt::JwtService;
-use rate_limit::{RateLimitConfig, RateLimitService};
+use query_cancellation::RunningQueries;
+use rate_limit::{RateLimitConfig, RateLimitMiddleware, RateLimitService};
+use shutdown::{DrainMiddleware, InFlightRequests};
 use websocket::{ConnectionConfig, ConnectionManager, PubSubManager, WebSocketService};
 
 /// Application state shared across handlers
@@ -89,7 +105,16 @@ pub struct AppState {
     pub rate_limit_service: RateLimitService,
     pub websocket_service: Arc<WebSocketService>,
     pub eeg_service: Arc<RwLock<EEGAuthService>>,
+    pub connection_limiter: Arc<ConnectionLimiter>,
+    /// Queries currently executing, for cancellation by request ID
+    pub running_queries: Arc<RunningQueries>,
+    /// Hash-chained record of authenticated requests
+    pub audit_log: Arc<AuditLog>,
+    /// Requests being handled, drained on shutdown
+    pub in_flight_requests: Arc<InFlightRequests>,
     pub config: ApiConfig,
+    /// When the application state was created, used for uptime reporting
+    pub started_at: Instant,
 }
 
 impl AppState {
@@ -100,6 +125,13 @@ impl AppState {
             .await
             .map_err(|e| anyhow::anyhow!("Failed to initialize database: {e}"))?;
 
+        Self::with_database(config, db).await
+    }
+
+    /// Create application state around an already-initialized database.
+    ///
+    /// Useful for embedding and tests that need control over the storage path.
+    pub async fn with_database(config: ApiConfig, db: NeuroQuantumDB) -> Result<Self> {
         let auth_service = AuthService::new()
             .map_err(|e| anyhow::anyhow!("Failed to initialize auth service: {e}"))?;
 
@@ -121,6 +153,7 @@ impl AppState {
             burst_allowance: config.rate_limit.burst_allowance,
             redis_url: config.redis.as_ref().map(|r| r.url.clone()),
             fallback_to_memory: true,
+            algorithm: config.rate_limit.strategy,
         };
         let rate_limit_service = RateLimitService::new(rate_limit_config).await?;
 
@@ -131,7 +164,7 @@ impl AppState {
         let db_arc = Arc::new(tokio::sync::RwLock::new(db));
 
         // Initialize QSQL engine with the shared storage engine
-        let qsql_engine = neuroquantum_qsql::QSQLEngine::with_storage(storage_engine_arc)
+        let qsql_engine = neuroquantum_qsql::QSQLEngine::with_storage(storage_engine_arc.clone())
             .map_err(|e| anyhow::anyhow!("Failed to initialize QSQL engine: {e}"))?;
         let qsql_engine_arc = Arc::new(tokio::sync::Mutex::new(qsql_engine));
 
@@ -146,6 +179,9 @@ impl AppState {
             pubsub_manager,
             qsql_engine_arc.clone(),
         ));
+        websocket_service
+            .enable_live_queries(storage_engine_arc)
+            .await;
 
         // Initialize EEG authentication service with shared state
         // Default sampling rate 256 Hz is standard for clinical EEG
@@ -154,6 +190,17 @@ impl AppState {
         let eeg_service_arc = Arc::new(RwLock::new(eeg_service));
         tracing::info!("🧠 EEG authentication service initialized");
 
+        let connection_limiter = Arc::new(ConnectionLimiter::from_config(&config.database));
+
+        let audit_log = match &config.audit.path {
+            | Some(path) => {
+                let audit_log = AuditLog::open(path).await?;
+                tracing::info!("📜 Audit log at {}", path);
+                audit_log
+            },
+            | None => AuditLog::in_memory(),
+        };
+
         Ok(Self {
             db: db_arc,
             qsql_engine: qsql_engine_arc,
@@ -162,38 +209,83 @@ impl AppState {
             rate_limit_service,
             websocket_service,
             eeg_service: eeg_service_arc,
+            connection_limiter,
+            running_queries: Arc::new(RunningQueries::new()),
+            audit_log: Arc::new(audit_log),
+            in_flight_requests: Arc::new(InFlightRequests::new()),
             config,
+            started_at: Instant::now(),
         })
     }
 }
 
 /// 🏥 Health check endpoint
-pub async fn health_check() -> ActixResult<HttpResponse, ApiError> {
+pub async fn health_check(
+    app_state: Option<web::Data<AppState>>,
+) -> ActixResult<HttpResponse, ApiError> {
     let start = Instant::now();
 
-    let health_data = serde_json::json!({
+    let memory_usage_mb = crate::metrics::get_process_memory_bytes()
+        .map_or(0.0, |bytes| bytes as f64 / (1024.0 * 1024.0));
+
+    let mut health_data = serde_json::json!({
         "status": "healthy",
         "version": env!("CARGO_PKG_VERSION"),
-        "uptime_seconds": 0,
+        "uptime_seconds": crate::metrics::get_uptime_seconds(),
+        "active_connections": 0,
         "system_metrics": {
-            "memory_usage_mb": 128,
-            "power_consumption_w": 45,
-            "temperature_c": 42.5
-        },
-        "security": {
-            "authentication_enabled": true,
-            "jwt_enabled": true,
-            "rate_limiting_enabled": true,
-            "quantum_security": true
-        },
-        "features": {
-            "neuromorphic_processing": true,
-            "quantum_search": true,
-            "dna_compression": true,
-            "real_time_updates": true
+            "memory_usage_mb": memory_usage_mb,
+            "cpu_load_average": crate::metrics::get_load_average(),
+            "temperature_c": crate::metrics::get_system_temperature()
         }
     });
 
+    // State-dependent details are only available when the handler runs inside the full app
+    if let Some(state) = app_state {
+        let db = state.db.read().await;
+        let db_config = db.config();
+        let storage = db.storage().await;
+        let cache = storage.cache_statistics();
+        let page_store = storage.page_store_stats().await;
+        drop(storage);
+
+        health_data["uptime_seconds"] = state.started_at.elapsed().as_secs_f64().into();
+        health_data["active_connections"] = state.connection_limiter.active_connections().into();
+        health_data["row_cache"] = serde_json::json!({
+            "hit_rate": cache.hit_rate(),
+            "hits": cache.hits,
+            "misses": cache.misses,
+            "cached_rows": cache.cached_rows,
+            "capacity": cache.capacity
+        });
+        // Page-level buffer pool, null until a page store is attached to the engine
+        health_data["buffer_pool"] = page_store.map_or(serde_json::Value::Null, |stats| {
+            serde_json::json!({
+                "total_frames": stats.buffer_pool.total_frames,
+                "used_frames": stats.buffer_pool.used_frames,
+                "free_frames": stats.buffer_pool.free_frames,
+                "dirty_frames": stats.buffer_pool.dirty_frames,
+                "pinned_frames": stats.buffer_pool.pinned_frames,
+                "hits": stats.cache.hits,
+                "misses": stats.cache.misses,
+                "hit_rate": stats.cache.hit_rate
+            })
+        });
+        health_data["security"] = serde_json::json!({
+            "authentication_enabled": state.auth_service.has_admin_keys(),
+            "jwt_enabled": !state.config.jwt.secret.is_empty(),
+            "rate_limiting_enabled": state.config.rate_limit.enabled,
+            "quantum_security": state.config.jwt.quantum_enabled
+                || state.config.security.quantum_encryption
+        });
+        health_data["features"] = serde_json::json!({
+            "neuromorphic_processing": db_config.enable_neuromorphic_learning,
+            "quantum_search": db_config.enable_quantum_optimization,
+            "dna_compression": true,
+            "real_time_updates": true
+        });
+    }
+
     Ok(HttpResponse::Ok().json(ApiResponse::success(
         health_data,
         ResponseMetadata::new(start.elapsed(), "Health check completed"),
@@ -201,7 +293,26 @@ pub async fn health_check() -> ActixResult<HttpResponse, ApiError> {
 }
 
 /// 📊 Prometheus metrics endpoint (public - no authentication required)
-pub async fn metrics() -> HttpResponse {
+pub async fn metrics(app_state: Option<web::Data<AppState>>) -> HttpResponse {
+    // Storage maintenance gauges are only available inside the full app
+    if let Some(state) = app_state {
+        let db = state.db.read().await;
+        let status = db.storage().await.maintenance_status().await;
+        drop(db);
+
+        // Without a checkpoint since startup, the uptime is a lower bound
+        let seconds_since_last_checkpoint = status.last_checkpoint.map_or_else(
+            || state.started_at.elapsed().as_secs_f64(),
+            |checkpoint| {
+                (chrono::Utc::now() - checkpoint.timestamp).num_milliseconds() as f64 / 1000.0
+            },
+        );
+        crate::metrics::update_maintenance_metrics(
+            seconds_since_last_checkpoint,
+            status.free_page_ratio,
+        );
+    }
+
     match crate::metrics::render_metrics() {
         | Ok(metrics_text) => HttpResponse::Ok()
             .content_type("text/plain; version=0.0.4; charset=utf-8")
@@ -227,10 +338,14 @@ pub async fn websocket_handler(
 
     // Check if user is authenticated (JWT token should be in extensions from middleware)
     let extensions = req.extensions();
-    let user_id = if let Some(token) = extensions.get::<error::AuthToken>() {
-        Some(token.sub.clone())
+    let (user_id, permissions, scopes) = if let Some(token) = extensions.get::<error::AuthToken>() {
+        (token.sub.clone(), token.permissions.clone(), Vec::new())
     } else if let Some(api_key) = extensions.get::<auth::ApiKey>() {
-        Some(api_key.name.clone())
+        (
+            api_key.name.clone(),
+            api_key.permissions.clone(),
+            api_key.scopes.clone(),
+        )
     } else {
         return Ok(HttpResponse::Unauthorized().json(serde_json::json!({
             "error": "Authentication required for WebSocket connection",
@@ -252,8 +367,11 @@ pub async fn websocket_handler(
 
     // Create connection metadata
     let mut metadata = ConnectionMetadata::new(remote_addr);
-    metadata.user_id = user_id;
+    metadata.user_id = Some(user_id);
     metadata.user_agent = user_agent;
+    // Queries sent over the connection are checked against these
+    metadata.permissions = permissions;
+    metadata.scopes = scopes;
 
     // Handle WebSocket upgrade
     let (response, session, msg_stream) = actix_ws::handle(&req, stream)?;
@@ -278,6 +396,25 @@ pub async fn websocket_handler(
     Ok(response)
 }
 
+/// JSON extractor configuration enforcing `limit` on request bodies
+///
+/// Bodies over the limit are rejected with `413 Payload Too Large` as soon as
+/// the `Content-Length` or the bytes read so far exceed it, without buffering
+/// the rest.
+#[must_use]
+pub fn json_config(limit: usize) -> web::JsonConfig {
+    web::JsonConfig::default()
+        .limit(limit)
+        .error_handler(move |err, _req| match err {
+            | JsonPayloadError::Overflow { .. }
+            | JsonPayloadError::OverflowKnownLength { .. }
+            | JsonPayloadError::Payload(PayloadError::Overflow) => {
+                ApiError::PayloadTooLarge { limit }.into()
+            },
+            | err => err.into(),
+        })
+}
+
 /// Configure application routes and middleware
 pub fn configure_app(
     app_state: AppState,
@@ -296,9 +433,13 @@ pub fn configure_app(
         .build()
         .expect("Prometheus metrics builder should succeed");
 
-    let cors_origins = app_state.config.cors.allowed_origins.clone();
-    #[allow(unused_variables)]
-    let tracing_enabled = app_state.config.tracing.enabled;
+    let cors = cors::cors_middleware(&app_state.config.cors);
+    let in_flight_requests = app_state.in_flight_requests.clone();
+    let connection_limiter = app_state.connection_limiter.clone();
+    let rate_limiting_enabled = app_state.config.rate_limit.enabled;
+    let rate_limit_service = app_state.rate_limit_service.clone();
+    let graphql_schema = graphql::build_schema(app_state.clone());
+    let max_request_body_bytes = app_state.config.security.max_request_body_bytes;
 
     App::new()
         // Add application state
@@ -307,38 +448,23 @@ pub fn configure_app(
         .app_data(web::Data::new(app_state.auth_service.clone()))
         .app_data(web::Data::new(app_state.jwt_service.clone()))
         .app_data(web::Data::new(app_state.rate_limit_service.clone()))
+        .app_data(web::Data::from(app_state.audit_log.clone()))
+        .app_data(web::Data::new(graphql_schema))
         .app_data(web::Data::new(app_state.config))
-        // Add tracing middleware (the middleware itself checks if tracing is enabled)
+        // Reject oversized bodies before buffering them
+        .app_data(json_config(max_request_body_bytes))
+        .app_data(web::PayloadConfig::new(max_request_body_bytes))
+        // Add tracing middleware (spans are only exported once `init_tracing` installed a tracer)
         .wrap(middleware::tracing_middleware())
+        // Resolve the API version from the path or `Accept` header
+        .wrap(versioning::VersionNegotiationMiddleware)
         // Add other middleware
         .wrap(prometheus)
         .wrap(Logger::default())
         .wrap(Compress::default())
-        .wrap({
-            let mut cors = Cors::default()
-                .allowed_origin("http://localhost:3000")
-                .allowed_origin("http://localhost:8080");
-
-            // Add configured origins
-            for origin in &cors_origins {
-                cors = cors.allowed_origin(origin);
-            }
-
-            cors.allowed_methods(vec!["GET", "POST", "PUT", "DELETE", "PATCH", "OPTIONS"])
-                .allowed_headers(vec![
-                    "Authorization",
-                    "Content-Type",
-                    "X-API-Key",
-                    "X-Request-ID",
-                    "X-Quantum-Level"
-                ])
-                .expose_headers(vec![
-                    "X-RateLimit-Limit",
-                    "X-RateLimit-Remaining",
-                    "X-RateLimit-Reset"
-                ])
-                .max_age(3600)
-        })
+        .wrap(cors)
+        // Count requests so shutdown can drain them
+        .wrap(DrainMiddleware::new(in_flight_requests))
 
         // OpenAPI Documentation
         .service(
@@ -351,92 +477,152 @@ pub fn configure_app(
         .route("/metrics", web::get().to(metrics))
         .route("/ws", web::get().to(websocket_handler))
 
+        // GraphQL (playground only in debug builds)
+        .service(
+            web::resource(graphql::GRAPHQL_PATH)
+                .guard(guard::fn_guard(|ctx| {
+                    cfg!(debug_assertions) && ctx.head().method == Method::GET
+                }))
+                .to(graphql::graphiql)
+        )
+        .service(
+            web::resource(graphql::GRAPHQL_PATH)
+                .wrap(Condition::new(
+                    rate_limiting_enabled,
+                    RateLimitMiddleware::by_api_key(rate_limit_service),
+                ))
+                .wrap(middleware::auth_middleware())
+                .wrap(ConnectionLimitMiddleware::new(connection_limiter.clone()))
+                .route(web::post().to(graphql::graphql_handler))
+        )
+
         // API v1 routes
         .service(
             web::scope("/api/v1")
-                // Authentication routes (public)
-                .service(
-                    web::scope("/auth")
-                        .route("/login", web::post().to(handlers::login))
-                        .route("/refresh", web::post().to(handlers::refresh_token))
-                )
+                .configure(|cfg| configure_api_routes(cfg, &connection_limiter))
+        )
 
-                // Protected admin routes
+        // API v2 routes: endpoints whose contract changed in v2 are registered
+        // first and take precedence, everything else is shared with v1
+        .service(
+            web::scope("/api/v2")
                 .service(
-                    web::scope("/auth")
+                    web::resource("/query")
                         .wrap(middleware::auth_middleware())
-                        .route("/generate-key", web::post().to(handlers::generate_api_key))
-                        .route("/revoke-key", web::post().to(handlers::revoke_api_key))
+                        .wrap(ConnectionLimitMiddleware::new(connection_limiter.clone()))
+                        .route(web::post().to(handlers::execute_sql_query_v2))
                 )
+                .configure(|cfg| configure_api_routes(cfg, &connection_limiter))
+        )
+}
 
-                // Protected API routes (require authentication)
-                .service(
-                    web::scope("")
-                        .wrap(middleware::auth_middleware())
+/// Routes served identically by every API version
+fn configure_api_routes(cfg: &mut web::ServiceConfig, connection_limiter: &Arc<ConnectionLimiter>) {
+    cfg
+        // Authentication routes (public)
+        .service(
+            web::scope("/auth")
+                .route("/login", web::post().to(handlers::login))
+                .route("/refresh", web::post().to(handlers::refresh_token))
+        )
 
-                        // Generic SQL query endpoint
-                        .route("/query", web::post().to(handlers::execute_sql_query))
+        // Protected admin routes
+        .service(
+            web::scope("/auth")
+                .wrap(middleware::auth_middleware())
+                .route("/generate-key", web::post().to(handlers::generate_api_key))
+                .route("/revoke-key", web::post().to(handlers::revoke_api_key))
+        )
 
-                        // CRUD Operations
-                        .service(
-                            web::scope("/tables")
-                                .route("", web::post().to(handlers::create_table))
-                                .route("/{table_name}/data", web::post().to(handlers::insert_data))
-                                .route("/{table_name}/query", web::post().to(handlers::query_data))
-                                .route("/{table_name}/data", web::put().to(handlers::update_data))
-                                .route("/{table_name}/data", web::delete().to(handlers::delete_data))
-                        )
+        // Protected API routes (require authentication)
+        .service(
+            web::scope("")
+                .wrap(middleware::auth_middleware())
+                .wrap(ConnectionLimitMiddleware::new(connection_limiter.clone()))
+
+                // Generic SQL query endpoint
+                .route("/query", web::post().to(handlers::execute_sql_query))
+                .route("/query/batch", web::post().to(handlers::execute_batch_query))
+                .route("/query/stream", web::get().to(handlers::stream_sql_query))
+                .route("/query/{request_id}/cancel", web::post().to(handlers::cancel_query))
+                .route("/audit", web::get().to(handlers::get_audit_log))
+
+                // CRUD Operations
+                .service(
+                    web::scope("/tables")
+                        .route("", web::post().to(handlers::create_table))
+                        .route("/{table_name}/data", web::post().to(handlers::insert_data))
+                        .route("/{table_name}/bulk", web::post().to(handlers::bulk_insert_data))
+                        .route("/{table_name}/import/csv", web::post().to(handlers::import_csv))
+                        .route("/{table_name}/export/csv", web::get().to(handlers::export_csv))
+                        .route("/{table_name}/query", web::post().to(handlers::query_data))
+                        .route("/{table_name}/data", web::put().to(handlers::update_data))
+                        .route("/{table_name}/data", web::delete().to(handlers::delete_data))
+                )
 
-                        // Advanced Features
-                        .service(
-                            web::scope("/neural")
-                                .route("/train", web::post().to(handlers::train_neural_network))
-                                .route("/train/{network_id}", web::get().to(handlers::get_training_status))
-                        )
-                        .service(
-                            web::scope("/quantum")
-                                .route("/search", web::post().to(handlers::quantum_search))
-                        )
-                        .service(
-                            web::scope("/dna")
-                                .route("/compress", web::post().to(handlers::compress_dna))
-                                .route("/decompress", web::post().to(handlers::decompress_dna))
-                        )
+                // Advanced Features
+                .service(
+                    web::scope("/neural")
+                        .route("/train", web::post().to(handlers::train_neural_network))
+                        .route("/train/{network_id}", web::get().to(handlers::get_training_status))
+                )
+                .service(
+                    web::scope("/quantum")
+                        .route("/search", web::post().to(handlers::quantum_search))
+                )
+                .service(
+                    web::scope("/dna")
+                        .route("/compress", web::post().to(handlers::compress_dna))
+                        .route("/decompress", web::post().to(handlers::decompress_dna))
+                )
 
-                        // Biometric Authentication
+                // Biometric Authentication
+                .service(
+                    web::scope("/biometric")
+                        // New documented endpoints at /biometric/enroll and /biometric/verify
+                        .route("/enroll", web::post().to(handlers::biometric_enroll))
+                        .route("/verify", web::post().to(handlers::biometric_verify))
+                        // EEG-specific endpoints under /biometric/eeg/
                         .service(
-                            web::scope("/biometric")
-                                // New documented endpoints at /biometric/enroll and /biometric/verify
-                                .route("/enroll", web::post().to(handlers::biometric_enroll))
-                                .route("/verify", web::post().to(handlers::biometric_verify))
-                                // EEG-specific endpoints under /biometric/eeg/
-                                .service(
-                                    web::scope("/eeg")
-                                        .route("/enroll", web::post().to(handlers::eeg_enroll))
-                                        .route("/authenticate", web::post().to(handlers::eeg_authenticate))
-                                        .route("/update", web::post().to(handlers::eeg_update_signature))
-                                        .route("/users", web::get().to(handlers::eeg_list_users))
-                                )
+                            web::scope("/eeg")
+                                .route("/enroll", web::post().to(handlers::eeg_enroll))
+                                .route("/challenge", web::post().to(handlers::eeg_challenge))
+                                .route("/authenticate", web::post().to(handlers::eeg_authenticate))
+                                .route("/update", web::post().to(handlers::eeg_update_signature))
+                                .route("/users", web::get().to(handlers::eeg_list_users))
+                                .route("/migrate", web::post().to(handlers::eeg_migrate_signatures))
                         )
+                )
 
-                        // Monitoring
-                        .service(
-                            web::scope("/stats")
-                                .route("/performance", web::get().to(handlers::get_performance_stats))
-                        )
+                // Monitoring
+                .service(
+                    web::scope("/stats")
+                        .route("/performance", web::get().to(handlers::get_performance_stats))
+                        .route("/storage", web::get().to(handlers::get_storage_stats))
+                        .route("/index-advisor", web::get().to(handlers::get_index_recommendations))
+                )
+                .service(
+                    web::scope("/admin/maintenance")
+                        .route("/{operation}", web::post().to(handlers::run_maintenance))
+                )
 
-                        // Index Advisor
-                        .service(
-                            web::scope("/advisor")
-                                .route("/indexes", web::get().to(handlers::get_index_recommendations))
-                                .route("/indexes/statistics", web::delete().to(handlers::clear_index_advisor_statistics))
-                        )
+                // Index Advisor
+                .service(
+                    web::scope("/advisor")
+                        .route("/indexes", web::get().to(handlers::get_index_recommendations))
+                        .route("/indexes/statistics", web::delete().to(handlers::clear_index_advisor_statistics))
                 )
-        )
+        );
 }
 
 /// Start the HTTP server with the given configuration
-pub async fn start_server(config: ApiConfig) -> Result<()> {
+///
+/// Once `shutdown_signal` completes the server drains its connections for up to
+/// `server.shutdown_grace_period_secs`, see [`shutdown::graceful_shutdown`].
+pub async fn start_server(
+    config: ApiConfig,
+    shutdown_signal: impl std::future::Future<Output = ()>,
+) -> Result<()> {
     let bind_address = format!("{}:{}", config.server.host, config.server.port);
     let app_state = AppState::new(config.clone()).await?;
 
@@ -451,14 +637,66 @@ pub async fn start_server(config: ApiConfig) -> Result<()> {
     );
     info!("📊 Metrics available at: http://{}/metrics", bind_address);
 
-    HttpServer::new(move || configure_app(app_state.clone()))
-        .bind(&bind_address)?
-        .run()
-        .await?;
+    if let Some(grpc_port) = config.server.grpc_port {
+        let grpc_address: std::net::SocketAddr = format!("{}:{}", config.server.host, grpc_port)
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Invalid gRPC bind address: {e}"))?;
+        let grpc_state = app_state.clone();
+        tokio::spawn(async move {
+            if let Err(e) = grpc::start_grpc_server(grpc_state, grpc_address).await {
+                tracing::error!("❌ gRPC server failed: {}", e);
+            }
+        });
+    }
+
+    let listener = std::net::TcpListener::bind(&bind_address)?;
+    let server = http_server(app_state.clone(), listener)?;
+    let handle = server.handle();
+    tokio::pin!(server);
+
+    tokio::select! {
+        result = &mut server => return Ok(result?),
+        () = shutdown_signal => {},
+    }
+    let grace = std::time::Duration::from_secs(config.server.shutdown_grace_period_secs);
+    shutdown::graceful_shutdown(&handle, &app_state, grace).await;
+    server.await?;
 
     Ok(())
 }
 
+/// Create the HTTP server for `app_state` on `listener`
+///
+/// Serves HTTPS when `server.tls` is configured, verifying client certificates
+/// as set by its `client_auth`. Signals are left to the caller, which stops
+/// the server with [`shutdown::graceful_shutdown`].
+pub fn http_server(
+    app_state: AppState,
+    listener: std::net::TcpListener,
+) -> Result<actix_web::dev::Server> {
+    let server_config = app_state.config.server.clone();
+    let mut server = HttpServer::new(move || configure_app(app_state.clone()))
+        .disable_signals()
+        .shutdown_timeout(server_config.shutdown_grace_period_secs);
+    if let Some(workers) = server_config.workers {
+        server = server.workers(workers);
+    }
+
+    let server = match &server_config.tls {
+        | Some(tls_config) => {
+            info!(
+                "🔒 TLS enabled, client certificates {:?}",
+                tls_config.client_auth
+            );
+            server
+                .on_connect(tls::on_connect)
+                .listen_rustls_0_23(listener, tls::server_config(tls_config)?)?
+        },
+        | None => server.listen(listener)?,
+    };
+    Ok(server.run())
+}
+
 // Property-based tests for API robustness
 #[cfg(test)]
 mod proptest_suite;
@@ -3,7 +3,8 @@ use std::env;
 
 use anyhow::Result;
 use neuroquantum_api::cli::Cli;
-use neuroquantum_api::{start_server, ApiConfig};
+use neuroquantum_api::config::ClientAuthMode;
+use neuroquantum_api::{start_server, system_info, ApiConfig};
 use tokio::signal;
 use tracing::{error, info, warn};
 use tracing_subscriber::layer::SubscriberExt;
@@ -76,22 +77,13 @@ async fn main() -> Result<()> {
     info!("📖 API Documentation: {}/api-docs/", config.base_url());
     info!("🏥 Health Check: {}/health", config.base_url());
 
-    // Start server with graceful shutdown
-    let server_future = start_server(config);
-
-    tokio::select! {
-        result = server_future => {
-            match result {
-                Ok(()) => info!("✅ Server stopped gracefully"),
-                Err(e) => {
-                    error!("❌ Server error: {}", e);
-                    std::process::exit(1);
-                }
-            }
-        }
-        () = shutdown_signal => {
-            info!("🛑 Received shutdown signal, stopping server...");
-        }
+    // Start server, draining connections once a shutdown signal arrives
+    match start_server(config, shutdown_signal).await {
+        | Ok(()) => info!("✅ Server stopped gracefully"),
+        | Err(e) => {
+            error!("❌ Server error: {}", e);
+            std::process::exit(1);
+        },
     }
 
     // Shutdown OpenTelemetry tracing if it was initialized
@@ -215,21 +207,15 @@ fn validate_environment(config: &ApiConfig) -> Result<()> {
     }
 
     // Check available memory
-    if let Ok(meminfo) = std::fs::read_to_string("/proc/meminfo") {
-        if let Some(line) = meminfo.lines().find(|l| l.starts_with("MemAvailable:")) {
-            if let Some(mem_str) = line.split_whitespace().nth(1) {
-                if let Ok(mem_kb) = mem_str.parse::<u64>() {
-                    let mem_mb = mem_kb / 1024;
-                    info!("💾 Available memory: {} MB", mem_mb);
-
-                    if mem_mb < 512 {
-                        warn!(
-                            "⚠️  Low memory detected ({}MB). Consider increasing memory for optimal performance.",
-                            mem_mb
-                        );
-                    }
-                }
-            }
+    if let Some(memory) = system_info::memory_info() {
+        let mem_mb = memory.available_mb();
+        info!("💾 Available memory: {} MB", mem_mb);
+
+        if memory.is_low() {
+            warn!(
+                "⚠️  Low memory detected ({}MB). Consider increasing memory for optimal performance.",
+                mem_mb
+            );
         }
     }
 
@@ -265,6 +251,21 @@ fn validate_environment(config: &ApiConfig) -> Result<()> {
             error!("❌ TLS private key file not found: {}", tls_config.key_file);
             return Err(anyhow::anyhow!("TLS private key file missing"));
         }
+        if tls_config.client_auth != ClientAuthMode::Disabled {
+            match &tls_config.ca_file {
+                | Some(ca_file) if std::path::Path::new(ca_file).exists() => {
+                    info!("🪪 Client certificates verified against {}", ca_file);
+                },
+                | Some(ca_file) => {
+                    error!("❌ TLS CA file not found: {}", ca_file);
+                    return Err(anyhow::anyhow!("TLS CA file missing"));
+                },
+                | None => {
+                    error!("❌ Client certificate authentication requires tls.ca_file");
+                    return Err(anyhow::anyhow!("TLS CA file not configured"));
+                },
+            }
+        }
         info!("🔒 TLS/HTTPS enabled");
     }
 
@@ -350,6 +351,15 @@ fn print_system_info() {
     let cpu_count = num_cpus::get();
     info!("   CPU cores: {}", cpu_count);
 
+    // Memory information
+    if let Some(memory) = system_info::memory_info() {
+        info!(
+            "   Memory: {} MB available of {} MB",
+            memory.available_mb(),
+            memory.total_mb()
+        );
+    }
+
     // Environment variables of interest
     let env_vars = [
         "NEUROQUANTUM_CONFIG",
@@ -15,6 +15,8 @@
 
 use std::time::SystemTime;
 
+use neuroquantum_qsql::ast::Statement;
+use neuroquantum_qsql::table_access::{self, AccessKind};
 use prometheus::{
     register_counter_vec, register_gauge, register_gauge_vec, register_histogram_vec, CounterVec,
     Encoder, Gauge, GaugeVec, HistogramVec, TextEncoder,
@@ -105,6 +107,16 @@ pub static QUANTUM_SEARCH_TOTAL: std::sync::LazyLock<CounterVec> = std::sync::La
     .expect("Failed to register quantum_search_total metric")
 });
 
+/// Total failed QSQL statements by statement type and error category
+pub static QUERY_ERRORS_TOTAL: std::sync::LazyLock<CounterVec> = std::sync::LazyLock::new(|| {
+    register_counter_vec!(
+        "neuroquantum_query_errors_total",
+        "Total failed QSQL statements",
+        &["statement", "category"]
+    )
+    .expect("Failed to register query_errors_total metric")
+});
+
 /// Total neural network training operations
 pub static NEURAL_TRAINING_TOTAL: std::sync::LazyLock<CounterVec> =
     std::sync::LazyLock::new(|| {
@@ -179,6 +191,25 @@ pub static SYSTEM_TEMPERATURE_CELSIUS: std::sync::LazyLock<Gauge> =
         .expect("Failed to register system_temperature_celsius metric")
     });
 
+/// Seconds since the last WAL checkpoint
+pub static SECONDS_SINCE_LAST_CHECKPOINT: std::sync::LazyLock<Gauge> =
+    std::sync::LazyLock::new(|| {
+        register_gauge!(
+            "neuroquantum_seconds_since_last_checkpoint",
+            "Seconds since the last WAL checkpoint, or since startup if none was taken"
+        )
+        .expect("Failed to register seconds_since_last_checkpoint metric")
+    });
+
+/// Fraction of the page file on the free list (0.0 - 1.0)
+pub static STORAGE_FREE_PAGE_RATIO: std::sync::LazyLock<Gauge> = std::sync::LazyLock::new(|| {
+    register_gauge!(
+        "neuroquantum_storage_free_page_ratio",
+        "Fraction of the page file on the free list (0.0 - 1.0)"
+    )
+    .expect("Failed to register storage_free_page_ratio metric")
+});
+
 /// API Key usage by key name
 pub static API_KEY_USAGE: std::sync::LazyLock<GaugeVec> = std::sync::LazyLock::new(|| {
     register_gauge_vec!(
@@ -203,6 +234,18 @@ pub static QUERY_RESPONSE_TIME_SECONDS: std::sync::LazyLock<HistogramVec> =
         .expect("Failed to register query_response_time_seconds metric")
     });
 
+/// QSQL statement execution duration in seconds by statement type and table
+pub static QUERY_DURATION_SECONDS: std::sync::LazyLock<HistogramVec> =
+    std::sync::LazyLock::new(|| {
+        register_histogram_vec!(
+            "neuroquantum_query_duration_seconds",
+            "QSQL statement execution duration in seconds",
+            &["statement", "table"],
+            vec![0.0005, 0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0]
+        )
+        .expect("Failed to register query_duration_seconds metric")
+    });
+
 /// API request duration in seconds
 pub static API_REQUEST_DURATION_SECONDS: std::sync::LazyLock<HistogramVec> =
     std::sync::LazyLock::new(|| {
@@ -261,6 +304,112 @@ pub fn record_query(query_type: &str, duration_secs: f64) {
         .observe(duration_secs);
 }
 
+/// Metric labels of a QSQL statement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementLabels {
+    /// Statement type, e.g. `select`, `insert` or `quantum_search`
+    pub statement: &'static str,
+    /// Table written by the statement, else the first table it reads
+    pub table: String,
+}
+
+impl StatementLabels {
+    /// Label for statements that failed to parse
+    pub const UNPARSED: &'static str = "unparsed";
+
+    /// Labels of `query`, parsing it to find the statement type and table
+    #[must_use]
+    pub fn of(query: &str) -> Self {
+        neuroquantum_qsql::Parser::new()
+            .parse_query(query)
+            .map_or_else(
+                |_| Self {
+                    statement: Self::UNPARSED,
+                    table: "none".to_string(),
+                },
+                |statement| Self::of_statement(&statement),
+            )
+    }
+
+    /// Labels of a parsed statement
+    #[must_use]
+    pub fn of_statement(statement: &Statement) -> Self {
+        Self {
+            statement: statement_type(statement),
+            table: primary_table(statement).unwrap_or_else(|| "none".to_string()),
+        }
+    }
+
+    /// Category of an error raised while executing the statement
+    ///
+    /// Statements that did not parse fail with `parse` errors, everything else
+    /// with `execution` errors.
+    #[must_use]
+    pub fn failure_category(&self) -> &'static str {
+        if self.statement == Self::UNPARSED {
+            "parse"
+        } else {
+            "execution"
+        }
+    }
+}
+
+/// Statement type label of a parsed statement
+fn statement_type(statement: &Statement) -> &'static str {
+    match statement {
+        | Statement::Select(_) => "select",
+        | Statement::Insert(_) => "insert",
+        | Statement::Update(_) => "update",
+        | Statement::Delete(_) => "delete",
+        | Statement::QuantumSearch(_) => "quantum_search",
+        | Statement::SuperpositionQuery(_) | Statement::QuantumJoin(_) => "quantum",
+        | Statement::NeuroMatch(_)
+        | Statement::SynapticOptimize(_)
+        | Statement::LearnPattern(_)
+        | Statement::AdaptWeights(_) => "neuromorphic",
+        | Statement::CreateTable(_)
+        | Statement::DropTable(_)
+        | Statement::AlterTable(_)
+        | Statement::CreateIndex(_)
+        | Statement::DropIndex(_)
+        | Statement::TruncateTable(_)
+        | Statement::CompressTable(_) => "ddl",
+        | Statement::Explain(_) => "explain",
+        | Statement::Analyze(_) => "analyze",
+        | Statement::BeginTransaction(_)
+        | Statement::Commit(_)
+        | Statement::Rollback(_)
+        | Statement::Savepoint(_)
+        | Statement::RollbackToSavepoint(_)
+        | Statement::ReleaseSavepoint(_) => "transaction",
+        | Statement::Prepare(_) | Statement::Execute(_) | Statement::Deallocate(_) => "prepared",
+    }
+}
+
+/// Table written by `statement`, else the first table it reads
+fn primary_table(statement: &Statement) -> Option<String> {
+    let accesses = table_access::table_accesses(statement);
+    accesses
+        .iter()
+        .find(|access| access.kind == AccessKind::Write)
+        .or_else(|| accesses.first())
+        .map(|access| access.table.clone())
+}
+
+/// Record the execution time of a successful QSQL statement
+pub fn record_statement(labels: &StatementLabels, duration_secs: f64) {
+    QUERY_DURATION_SECONDS
+        .with_label_values(&[labels.statement, &labels.table])
+        .observe(duration_secs);
+}
+
+/// Record a failed QSQL statement
+pub fn record_query_error(labels: &StatementLabels, category: &str) {
+    QUERY_ERRORS_TOTAL
+        .with_label_values(&[labels.statement, category])
+        .inc();
+}
+
 /// Record an authentication request
 pub fn record_auth_request(status: &str) {
     AUTH_REQUESTS_TOTAL.with_label_values(&[status]).inc();
@@ -351,6 +500,17 @@ pub fn update_system_metrics() {
     }
 }
 
+/// Update the gauges dashboards use to alert on overdue storage maintenance
+///
+/// `free_page_ratio` is `None` when no page store is attached, reported as 0.
+pub fn update_maintenance_metrics(
+    seconds_since_last_checkpoint: f64,
+    free_page_ratio: Option<f64>,
+) {
+    SECONDS_SINCE_LAST_CHECKPOINT.set(seconds_since_last_checkpoint);
+    STORAGE_FREE_PAGE_RATIO.set(free_page_ratio.unwrap_or(0.0));
+}
+
 /// Get the server uptime in seconds
 static START_TIME: std::sync::LazyLock<SystemTime> = std::sync::LazyLock::new(SystemTime::now);
 
@@ -358,6 +518,27 @@ pub fn get_uptime_seconds() -> f64 {
     START_TIME.elapsed().map(|d| d.as_secs_f64()).unwrap_or(0.0)
 }
 
+/// Get the resident memory of this process in bytes
+#[must_use]
+pub fn get_process_memory_bytes() -> Option<u64> {
+    use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
+
+    let pid = sysinfo::get_current_pid().ok()?;
+    let mut sys = System::new();
+    sys.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        true,
+        ProcessRefreshKind::nothing().with_memory(),
+    );
+    sys.process(pid).map(sysinfo::Process::memory)
+}
+
+/// Get the one-minute system load average (0.0 on platforms without load averages)
+#[must_use]
+pub fn get_load_average() -> f64 {
+    sysinfo::System::load_average().one
+}
+
 /// Get system temperature in Celsius (Linux-specific, returns None on other platforms)
 #[must_use]
 #[allow(clippy::missing_const_for_fn)] // Cannot be const: performs I/O operations
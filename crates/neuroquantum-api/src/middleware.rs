@@ -7,11 +7,14 @@ use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Tr
 use actix_web::http::header::{HeaderName, HeaderValue};
 use actix_web::{Error, HttpMessage};
 use futures_util::future::LocalBoxFuture;
-use tracing::{debug, info, warn};
+use tracing::{debug, error, info, warn};
 
+use crate::audit::{AuditDetails, AuditLog};
 use crate::auth::AuthService;
+use crate::config::{ApiConfig, AuthConfig};
 use crate::error::ApiError;
 use crate::jwt::JwtService;
+use crate::tls::{principal_for, ClientIdentity};
 
 /// Authentication middleware that supports both JWT and API key authentication
 pub struct AuthMiddleware<S> {
@@ -40,8 +43,33 @@ where
                 return service.call(req).await;
             }
 
-            // Try JWT authentication first
-            if let Some(auth_header) = req.headers().get("Authorization") {
+            let config = req.app_data::<actix_web::web::Data<ApiConfig>>().cloned();
+            let default_auth = AuthConfig::default();
+            let auth = config.as_ref().map_or(&default_auth, |config| &config.auth);
+
+            // Verified TLS client certificates take precedence
+            if auth.client_certificate {
+                let identity = req.conn_data::<ClientIdentity>().cloned();
+                if let Some(identity) = identity {
+                    if let Some(api_key) = principal_for(auth, &identity) {
+                        debug!(
+                            "✅ Client certificate authentication successful for: {}",
+                            identity
+                        );
+                        let principal = api_key.name.clone();
+                        req.extensions_mut().insert(identity);
+                        req.extensions_mut().insert(api_key);
+                        return call_audited(&service, req, principal).await;
+                    }
+                    warn!(
+                        "❌ Client certificate {} is not mapped to a principal",
+                        identity
+                    );
+                }
+            }
+
+            // Try JWT authentication
+            if let Some(auth_header) = req.headers().get("Authorization").filter(|_| auth.jwt) {
                 if let Ok(auth_str) = auth_header.to_str() {
                     if let Some(token) = auth_str.strip_prefix("Bearer ") {
                         // Extract JWT service from app data
@@ -54,8 +82,9 @@ where
                                         "✅ JWT authentication successful for user: {}",
                                         claims.sub
                                     );
+                                    let principal = claims.sub.clone();
                                     req.extensions_mut().insert(claims);
-                                    return service.call(req).await;
+                                    return call_audited(&service, req, principal).await;
                                 },
                                 | Err(e) => {
                                     warn!("❌ JWT validation failed: {:?}", e);
@@ -67,14 +96,15 @@ where
             }
 
             // Try API key authentication
-            if let Some(api_key_header) = req.headers().get("X-API-Key") {
+            if let Some(api_key_header) = req.headers().get("X-API-Key").filter(|_| auth.api_key) {
                 if let Ok(api_key_str) = api_key_header.to_str() {
                     if let Some(auth_service) = req.app_data::<actix_web::web::Data<AuthService>>()
                     {
                         if let Some(api_key) = auth_service.validate_api_key(api_key_str).await {
                             debug!("✅ API key authentication successful for: {}", api_key.name);
+                            let principal = api_key.name.clone();
                             req.extensions_mut().insert(api_key);
-                            return service.call(req).await;
+                            return call_audited(&service, req, principal).await;
                         }
                         warn!("❌ Invalid API key provided");
                     }
@@ -92,6 +122,50 @@ where
     }
 }
 
+/// Call `service` for an authenticated request and record it in the audit log
+///
+/// Apps without an [`AuditLog`] in their app data pass requests through
+/// unrecorded. [`AuditDetails`] the handler attached to the request are added
+/// to the entry. Failing to record an entry is logged but doesn't fail the
+/// request.
+async fn call_audited<S, B>(
+    service: &Rc<S>,
+    req: ServiceRequest,
+    principal: String,
+) -> Result<ServiceResponse<B>, Error>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+{
+    let Some(audit_log) = req.app_data::<actix_web::web::Data<AuditLog>>().cloned() else {
+        return service.call(req).await;
+    };
+    let action = req.method().to_string();
+    let resource = req.path().to_string();
+    // Shares the extensions the handler sees, also when the call fails
+    let http_req = req.request().clone();
+
+    let result = service.call(req).await;
+    let status = match &result {
+        | Ok(res) => res.status(),
+        | Err(e) => e.as_response_error().status_code(),
+    };
+    let details = http_req.extensions().get::<AuditDetails>().cloned();
+    drop(http_req);
+    if let Err(e) = audit_log
+        .record_details(
+            &principal,
+            &action,
+            &resource,
+            status.as_u16(),
+            details.as_ref(),
+        )
+        .await
+    {
+        error!("❌ Failed to record audit entry: {:#}", e);
+    }
+    result
+}
+
 /// Transform factory for authentication middleware
 pub struct AuthMiddlewareFactory;
 
@@ -116,15 +190,12 @@ where
 
 /// Helper function to determine if an endpoint is public
 pub fn is_public_endpoint(path: &str) -> bool {
-    matches!(
-        path,
-        "/health"
-            | "/metrics"
-            | "/api-docs"
-            | "/api-docs/"
-            | "/api/v1/auth/login"
-            | "/api/v1/auth/refresh"
-    ) || path.starts_with("/api-docs/")
+    matches!(path, "/health" | "/metrics" | "/api-docs" | "/api-docs/")
+        || path.starts_with("/api-docs/")
+        || matches!(
+            crate::versioning::strip_version_prefix(path),
+            Some("/auth/login" | "/auth/refresh")
+        )
 }
 
 /// Convenience function to create auth middleware
@@ -262,9 +333,9 @@ where
                                 "🚫 Request payload too large: {} bytes (max: {})",
                                 length, max_payload_size
                             );
-                            let error = ApiError::BadRequest(format!(
-                                "Payload too large. Maximum size: {max_payload_size} bytes, provided: {length} bytes"
-                            ));
+                            let error = ApiError::PayloadTooLarge {
+                                limit: max_payload_size,
+                            };
                             return Err(actix_web::Error::from(error));
                         }
                     }
@@ -609,7 +680,7 @@ where
 
 /// Helper function to check if an endpoint is an admin endpoint
 fn is_admin_endpoint(path: &str) -> bool {
-    path.starts_with("/api/v1/admin")
+    crate::versioning::strip_version_prefix(path).is_some_and(|p| p.starts_with("/admin"))
         || path.starts_with("/metrics")
         || path.contains("/api-key/generate")
 }
@@ -619,7 +690,23 @@ pub const fn ip_whitelist_middleware(whitelist: Vec<String>) -> IpWhitelistMiddl
     IpWhitelistMiddlewareFactory::new(whitelist)
 }
 
+/// Header carrying the request identifier, accepted from clients and echoed in responses
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Maximum length of a client-supplied request ID before a fresh one is generated
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// Identifier of the current request, stored in the request extensions by
+/// [`TracingMiddleware`] and recorded on the request span
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
 /// Distributed tracing middleware for OpenTelemetry
+///
+/// Starts an `http_request` span per request, parented to the W3C
+/// `traceparent` context of the incoming request when present, so handler and
+/// QSQL spans become its children. The request ID is taken from `X-Request-ID`
+/// (or generated), attached to the span and echoed back with the trace context.
 pub struct TracingMiddleware<S> {
     service: Rc<S>,
 }
@@ -640,7 +727,7 @@ where
         let service = self.service.clone();
 
         Box::pin(async move {
-            use tracing::Span;
+            use tracing::Instrument;
             use tracing_opentelemetry::OpenTelemetrySpanExt;
 
             // Extract trace context from headers if present
@@ -665,23 +752,31 @@ where
                 propagator.extract(&HeaderExtractor(req.headers()))
             });
 
+            let request_id = req
+                .headers()
+                .get(REQUEST_ID_HEADER)
+                .and_then(|v| v.to_str().ok())
+                .filter(|id| !id.is_empty() && id.len() <= MAX_REQUEST_ID_LEN)
+                .map_or_else(|| uuid::Uuid::new_v4().to_string(), str::to_string);
+            req.extensions_mut().insert(RequestId(request_id.clone()));
+
             // Create span with extracted context
             let span = tracing::info_span!(
                 "http_request",
-                method = %req.method(),
-                uri = %req.uri(),
-                version = ?req.version(),
+                otel.kind = "server",
+                http.method = %req.method(),
+                http.target = %req.uri(),
+                http.status_code = tracing::field::Empty,
+                request_id = %request_id,
             );
-
             span.set_parent(parent_context);
 
-            let _guard = span.enter();
-
-            // Add trace context to response headers
-            let mut res = service.call(req).await?;
+            // Run the rest of the chain inside the span so handler spans become children
+            let mut res = service.call(req).instrument(span.clone()).await?;
+            span.record("http.status_code", res.status().as_u16());
 
             // Inject trace context into response headers
-            let context = Span::current().context();
+            let context = span.context();
             opentelemetry::global::get_text_map_propagator(|propagator| {
                 use opentelemetry::propagation::Injector;
 
@@ -704,6 +799,11 @@ where
                 propagator.inject_context(&context, &mut HeaderInjector(res.headers_mut()));
             });
 
+            if let Ok(value) = HeaderValue::from_str(&request_id) {
+                res.headers_mut()
+                    .insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+            }
+
             Ok(res)
         })
     }
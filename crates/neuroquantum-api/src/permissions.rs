@@ -16,6 +16,25 @@
 //! let read_only = vec![READ.to_string()];
 //! assert_eq!(read_only.len(), 1);
 //! ```
+//!
+//! Keys can additionally be restricted to specific tables with [`ScopeRule`]s:
+//!
+//! ```rust
+//! use neuroquantum_api::permissions::{Permission, ScopeRule, READ, WRITE};
+//!
+//! let scopes = vec![
+//!     ScopeRule::new("audit", &[]),
+//!     ScopeRule::new("users", &[READ]),
+//!     ScopeRule::new("app_*", &[READ, WRITE]),
+//! ];
+//! assert!(Permission::scope_allows(&scopes, "users", READ));
+//! assert!(!Permission::scope_allows(&scopes, "users", WRITE));
+//! assert!(Permission::scope_allows(&scopes, "app_events", WRITE));
+//! assert!(!Permission::scope_allows(&scopes, "audit", READ));
+//! ```
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Permission for administrative operations
 pub const ADMIN: &str = "admin";
@@ -35,11 +54,102 @@ pub const READ: &str = "read";
 /// Permission for write operations
 pub const WRITE: &str = "write";
 
+/// Permission to read decrypted values of `ENCRYPTED` columns
+pub const DECRYPT: &str = "decrypt";
+
 /// Permission granted after quantum authentication
 pub const QUANTUM_AUTHENTICATED: &str = "quantum_authenticated";
 
 /// All available permissions as static strings
-pub const ALL_PERMISSIONS: &[&str] = &[ADMIN, NEUROMORPHIC, QUANTUM, DNA, READ, WRITE];
+pub const ALL_PERMISSIONS: &[&str] = &[ADMIN, NEUROMORPHIC, QUANTUM, DNA, READ, WRITE, DECRYPT];
+
+/// Scope rule action matching every action
+pub const ANY_ACTION: &str = "*";
+
+/// Actions a [`ScopeRule`] can allow on tables
+pub const SCOPE_ACTIONS: &[&str] = &[READ, WRITE, ANY_ACTION];
+
+/// Restricts an API key to the tables matching `resource`
+///
+/// `resource` is a table name pattern in which `*` matches any sequence of
+/// characters (`*` alone matches every table). Matching is case-insensitive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ScopeRule {
+    /// Table name pattern, e.g. `users`, `app_*` or `*`
+    pub resource: String,
+    /// Actions allowed on matching tables: `read`, `write` or `*`; empty denies all access
+    #[serde(default)]
+    pub actions: Vec<String>,
+}
+
+impl ScopeRule {
+    #[must_use]
+    pub fn new(resource: impl Into<String>, actions: &[&str]) -> Self {
+        Self {
+            resource: resource.into(),
+            actions: Permission::to_owned(actions),
+        }
+    }
+
+    /// Check that the rule has a pattern and only known actions
+    pub fn validate(&self) -> Result<(), String> {
+        if self.resource.trim().is_empty() {
+            return Err("Scope rule resource must not be empty".to_string());
+        }
+        match self
+            .actions
+            .iter()
+            .find(|action| !SCOPE_ACTIONS.contains(&action.as_str()))
+        {
+            | Some(action) => Err(format!(
+                "Invalid scope action: {action}. Valid actions are: {SCOPE_ACTIONS:?}"
+            )),
+            | None => Ok(()),
+        }
+    }
+
+    /// Whether the rule applies to `table`
+    #[must_use]
+    pub fn matches(&self, table: &str) -> bool {
+        wildcard_match(
+            self.resource.to_ascii_lowercase().as_bytes(),
+            table.to_ascii_lowercase().as_bytes(),
+        )
+    }
+
+    /// Whether the rule allows `action` on the tables it matches
+    #[must_use]
+    pub fn allows(&self, action: &str) -> bool {
+        self.actions
+            .iter()
+            .any(|allowed| allowed == action || allowed == ANY_ACTION)
+    }
+}
+
+/// Match `text` against `pattern`, where `*` matches any sequence of bytes
+fn wildcard_match(pattern: &[u8], text: &[u8]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` in the pattern and the text position it matched up to
+    let mut backtrack = None;
+
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == text[t] {
+            p += 1;
+            t += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, t));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == b'*')
+}
 
 /// Permission utilities
 pub struct Permission;
@@ -140,4 +250,27 @@ impl Permission {
     pub fn has_write(permissions: &[String]) -> bool {
         permissions.iter().any(|p| p == WRITE)
     }
+
+    /// Check if the given permissions allow reading decrypted `ENCRYPTED` columns.
+    #[inline]
+    #[must_use]
+    pub fn has_decrypt(permissions: &[String]) -> bool {
+        permissions.iter().any(|p| p == DECRYPT || p == ADMIN)
+    }
+
+    /// Check whether scope rules allow `action` on `table`.
+    ///
+    /// Rules are evaluated in order and the first one matching `table` decides.
+    /// Tables matched by no rule are denied. An empty rule list leaves the key
+    /// unrestricted.
+    #[must_use]
+    pub fn scope_allows(scopes: &[ScopeRule], table: &str, action: &str) -> bool {
+        if scopes.is_empty() {
+            return true;
+        }
+        scopes
+            .iter()
+            .find(|rule| rule.matches(table))
+            .is_some_and(|rule| rule.allows(action))
+    }
 }
@@ -16,7 +16,7 @@ use proptest::prelude::*;
 
 use crate::error::{ApiError, ApiResponse, ResponseMetadata, SqlQueryRequest};
 use crate::permissions::Permission;
-use crate::rate_limit::RateLimitConfig;
+use crate::rate_limit::{RateLimitAlgorithm, RateLimitConfig};
 
 /// Get configurable `PropTest` configuration from environment
 ///
@@ -231,6 +231,7 @@ proptest! {
             burst_allowance: Some(burst_allowance),
             redis_url: None,
             fallback_to_memory: true,
+            algorithm: RateLimitAlgorithm::FixedWindow,
         };
 
         prop_assert_eq!(config.requests_per_window, requests_per_hour);
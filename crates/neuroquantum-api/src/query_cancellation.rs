@@ -0,0 +1,125 @@
+//! Cancellation of in-flight queries.
+//!
+//! Every query run through `POST /api/v1/query` is registered in
+//! [`RunningQueries`] under its request ID (the `X-Request-ID` header, or a
+//! generated one) for as long as it executes. `POST /api/v1/query/{request_id}/cancel`
+//! signals the query's [`CancellationToken`]; the QSQL executor checks the token
+//! between units of work and the original request fails with a cancelled error.
+
+use std::sync::Arc;
+
+use dashmap::mapref::entry::Entry;
+use dashmap::DashMap;
+use neuroquantum_qsql::CancellationToken;
+
+use crate::error::ApiError;
+
+/// A query currently executing
+#[derive(Debug)]
+struct RunningQuery {
+    /// API key the query was submitted with
+    owner: String,
+    token: CancellationToken,
+}
+
+/// Registry of in-flight queries, keyed by request ID
+#[derive(Debug, Default)]
+pub struct RunningQueries {
+    queries: DashMap<String, RunningQuery>,
+}
+
+/// Registration of a running query; removed from the registry on drop
+#[derive(Debug)]
+pub struct RunningQueryGuard {
+    registry: Arc<RunningQueries>,
+    request_id: String,
+    token: CancellationToken,
+}
+
+impl RunningQueries {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a query under `request_id` for the duration of the returned guard.
+    ///
+    /// Fails with a conflict if another query is already running under the same ID.
+    pub fn register(
+        self: &Arc<Self>,
+        request_id: &str,
+        owner: &str,
+    ) -> Result<RunningQueryGuard, ApiError> {
+        let token = CancellationToken::new();
+        match self.queries.entry(request_id.to_string()) {
+            | Entry::Occupied(_) => {
+                return Err(ApiError::Conflict(format!(
+                    "A query with request ID {request_id} is already running"
+                )));
+            },
+            | Entry::Vacant(entry) => {
+                entry.insert(RunningQuery {
+                    owner: owner.to_string(),
+                    token: token.clone(),
+                });
+            },
+        }
+
+        Ok(RunningQueryGuard {
+            registry: Arc::clone(self),
+            request_id: request_id.to_string(),
+            token,
+        })
+    }
+
+    /// Cancel the query running under `request_id`.
+    ///
+    /// Only the key that submitted the query, or an admin, may cancel it; queries
+    /// owned by other keys are reported as not running. Returns whether a query
+    /// was found and signalled.
+    pub fn cancel(&self, request_id: &str, requester: &str, is_admin: bool) -> bool {
+        match self.queries.get(request_id) {
+            | Some(query) if is_admin || query.owner == requester => {
+                query.token.cancel();
+                true
+            },
+            | _ => false,
+        }
+    }
+
+    /// Whether a query is currently registered under `request_id`
+    #[must_use]
+    pub fn is_running(&self, request_id: &str) -> bool {
+        self.queries.contains_key(request_id)
+    }
+
+    /// Number of queries currently executing
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.queries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+}
+
+impl RunningQueryGuard {
+    /// Token signalled when the query is cancelled
+    #[must_use]
+    pub fn token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+
+    #[must_use]
+    pub fn request_id(&self) -> &str {
+        &self.request_id
+    }
+}
+
+impl Drop for RunningQueryGuard {
+    fn drop(&mut self) {
+        self.registry.queries.remove(&self.request_id);
+    }
+}
@@ -1,8 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use actix_web::dev::ServiceRequest;
+use actix_web::http::header::{HeaderName, HeaderValue, RETRY_AFTER};
+use actix_web::http::StatusCode;
 use actix_web::{Error, HttpMessage, HttpResponse, ResponseError};
 use redis::{cmd, AsyncCommands, Client as RedisClient};
 use serde::{Deserialize, Serialize};
@@ -21,6 +23,22 @@ fn current_unix_timestamp() -> u64 {
         .unwrap_or(0)
 }
 
+/// Algorithm used to decide whether a request fits within the rate limit
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RateLimitAlgorithm {
+    /// Count requests in consecutive fixed windows. Cheap, but a client can send
+    /// a full window's worth of requests at the end of one window and again at
+    /// the start of the next.
+    #[default]
+    FixedWindow,
+    /// Keep a log of accepted requests and count those within the last window,
+    /// so no window-sized span ever admits more than `requests_per_window`.
+    SlidingWindow,
+    /// Refill tokens continuously at `requests_per_window` per window, up to
+    /// `requests_per_window + burst_allowance` tokens.
+    TokenBucket,
+}
+
 /// Rate limiting configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RateLimitConfig {
@@ -29,6 +47,8 @@ pub struct RateLimitConfig {
     pub burst_allowance: Option<u32>,
     pub redis_url: Option<String>,
     pub fallback_to_memory: bool,
+    #[serde(default)]
+    pub algorithm: RateLimitAlgorithm,
 }
 
 impl Default for RateLimitConfig {
@@ -39,69 +59,142 @@ impl Default for RateLimitConfig {
             burst_allowance: Some(10),
             redis_url: None,
             fallback_to_memory: true,
+            algorithm: RateLimitAlgorithm::FixedWindow,
         }
     }
 }
 
-/// Rate limit bucket for token bucket algorithm
+impl RateLimitConfig {
+    fn window(&self) -> u64 {
+        u64::from(self.window_size_seconds.max(1))
+    }
+
+    /// Maximum number of tokens held by a token bucket
+    fn bucket_capacity(&self) -> u32 {
+        self.requests_per_window
+            .saturating_add(self.burst_allowance.unwrap_or(0))
+    }
+}
+
+/// Per-key rate limit state, shared by all algorithms
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct RateLimitBucket {
     tokens: u32,
     last_refill: u64,
     window_start: u64,
     request_count: u32,
+    /// Timestamps of requests accepted within the last window (sliding window)
+    #[serde(default)]
+    request_log: VecDeque<u64>,
 }
 
 impl RateLimitBucket {
-    fn new(max_tokens: u32) -> Self {
-        let now = current_unix_timestamp();
-
+    fn new(config: &RateLimitConfig, now: u64) -> Self {
         Self {
-            tokens: max_tokens,
+            tokens: config.bucket_capacity(),
             last_refill: now,
             window_start: now,
             request_count: 0,
+            request_log: VecDeque::new(),
         }
     }
 
-    fn try_consume(&mut self, config: &RateLimitConfig) -> bool {
-        let now = current_unix_timestamp();
-
-        // Reset window if needed
-        if now >= self.window_start + u64::from(config.window_size_seconds) {
-            self.window_start = now;
-            self.request_count = 0;
-            self.tokens = config.requests_per_window;
+    /// Bring the state forward to `now`: start a new fixed window, drop expired
+    /// log entries or refill tokens, depending on the algorithm
+    fn advance(&mut self, config: &RateLimitConfig, now: u64) {
+        let window = config.window();
+        match config.algorithm {
+            | RateLimitAlgorithm::FixedWindow => {
+                if now >= self.window_start + window {
+                    self.window_start = now;
+                    self.request_count = 0;
+                }
+            },
+            | RateLimitAlgorithm::SlidingWindow => {
+                while self
+                    .request_log
+                    .front()
+                    .is_some_and(|&accepted| accepted + window <= now)
+                {
+                    self.request_log.pop_front();
+                }
+            },
+            | RateLimitAlgorithm::TokenBucket => {
+                let elapsed = now.saturating_sub(self.last_refill);
+                let tokens_to_add = elapsed * u64::from(config.requests_per_window) / window;
+                // Leave last_refill alone until a whole token has accrued, so
+                // frequent requests do not discard partial refills
+                if tokens_to_add > 0 {
+                    let tokens = u64::from(self.tokens) + tokens_to_add;
+                    self.tokens = tokens.min(u64::from(config.bucket_capacity())) as u32;
+                    self.last_refill = now;
+                }
+            },
         }
+    }
 
-        // Refill tokens based on time elapsed
-        let time_elapsed = now - self.last_refill;
-        if time_elapsed > 0 {
-            let tokens_to_add = (time_elapsed * u64::from(config.requests_per_window)
-                / u64::from(config.window_size_seconds)) as u32;
+    fn try_consume(&mut self, config: &RateLimitConfig, now: u64) -> bool {
+        self.advance(config, now);
 
-            self.tokens = (self.tokens + tokens_to_add).min(config.requests_per_window);
-            self.last_refill = now;
+        if self.remaining(config) == 0 {
+            return false;
         }
-
-        // Check if we can consume a token
-        if self.tokens > 0 && self.request_count < config.requests_per_window {
-            self.tokens -= 1;
-            self.request_count += 1;
-            true
-        } else {
-            false
+        match config.algorithm {
+            | RateLimitAlgorithm::FixedWindow => self.request_count += 1,
+            | RateLimitAlgorithm::SlidingWindow => self.request_log.push_back(now),
+            | RateLimitAlgorithm::TokenBucket => self.tokens -= 1,
         }
+        true
     }
 
-    const fn remaining_tokens(&self) -> u32 {
-        self.tokens
+    /// Requests still allowed; call [`Self::advance`] first
+    fn remaining(&self, config: &RateLimitConfig) -> u32 {
+        match config.algorithm {
+            | RateLimitAlgorithm::FixedWindow => config
+                .requests_per_window
+                .saturating_sub(self.request_count),
+            | RateLimitAlgorithm::SlidingWindow => {
+                let logged = u32::try_from(self.request_log.len()).unwrap_or(u32::MAX);
+                config.requests_per_window.saturating_sub(logged)
+            },
+            | RateLimitAlgorithm::TokenBucket => self.tokens,
+        }
     }
 
-    fn time_until_reset(&self, config: &RateLimitConfig) -> u64 {
-        let now = current_unix_timestamp();
+    /// Seconds until more requests are allowed; call [`Self::advance`] first
+    fn time_until_reset(&self, config: &RateLimitConfig, now: u64) -> u64 {
+        let window = config.window();
+        match config.algorithm {
+            | RateLimitAlgorithm::FixedWindow => (self.window_start + window).saturating_sub(now),
+            | RateLimitAlgorithm::SlidingWindow => self
+                .request_log
+                .front()
+                .map_or(0, |&oldest| (oldest + window).saturating_sub(now)),
+            | RateLimitAlgorithm::TokenBucket => {
+                if self.tokens >= config.bucket_capacity() {
+                    return 0;
+                }
+                let per_token = window.div_ceil(u64::from(config.requests_per_window.max(1)));
+                (self.last_refill + per_token).saturating_sub(now)
+            },
+        }
+    }
 
-        (self.window_start + u64::from(config.window_size_seconds)).saturating_sub(now)
+    /// Whether the state has nothing left to remember and can be dropped
+    fn is_expired(&self, config: &RateLimitConfig, now: u64) -> bool {
+        let window = config.window();
+        match config.algorithm {
+            | RateLimitAlgorithm::FixedWindow => self.window_start + window <= now,
+            | RateLimitAlgorithm::SlidingWindow => self
+                .request_log
+                .back()
+                .is_none_or(|&newest| newest + window <= now),
+            | RateLimitAlgorithm::TokenBucket => {
+                let mut refilled = self.clone();
+                refilled.advance(config, now);
+                refilled.tokens >= config.bucket_capacity()
+            },
+        }
     }
 }
 
@@ -162,10 +255,35 @@ impl RateLimitService {
 
     /// Check rate limit for a given key (e.g., user ID, IP address)
     pub async fn check_rate_limit(&self, key: &str) -> Result<RateLimitResult, ApiError> {
+        self.check_rate_limit_at(key, current_unix_timestamp())
+            .await
+    }
+
+    /// Check rate limit for a given key as of the Unix timestamp `now` (seconds)
+    pub async fn check_rate_limit_at(
+        &self,
+        key: &str,
+        now: u64,
+    ) -> Result<RateLimitResult, ApiError> {
         if let Some(ref client) = self.redis_client {
-            self.check_rate_limit_redis(client, key).await
+            self.check_rate_limit_redis(client, key, now).await
         } else {
-            self.check_rate_limit_memory(key).await
+            self.check_rate_limit_memory(key, now).await
+        }
+    }
+
+    /// Algorithm this service enforces
+    #[must_use]
+    pub const fn algorithm(&self) -> RateLimitAlgorithm {
+        self.config.algorithm
+    }
+
+    fn result(&self, bucket: &RateLimitBucket, allowed: bool, now: u64) -> RateLimitResult {
+        RateLimitResult {
+            allowed,
+            remaining: bucket.remaining(&self.config),
+            reset_time: bucket.time_until_reset(&self.config, now),
+            limit: self.config.requests_per_window,
         }
     }
 
@@ -173,6 +291,7 @@ impl RateLimitService {
         &self,
         client: &RedisClient,
         key: &str,
+        now: u64,
     ) -> Result<RateLimitResult, ApiError> {
         let mut conn = client
             .get_multiplexed_async_connection()
@@ -193,13 +312,12 @@ impl RateLimitService {
                 })?;
 
         let mut bucket = if let Some(ref data) = bucket_data {
-            serde_json::from_str(data)
-                .unwrap_or_else(|_| RateLimitBucket::new(self.config.requests_per_window))
+            serde_json::from_str(data).unwrap_or_else(|_| RateLimitBucket::new(&self.config, now))
         } else {
-            RateLimitBucket::new(self.config.requests_per_window)
+            RateLimitBucket::new(&self.config, now)
         };
 
-        let allowed = bucket.try_consume(&self.config);
+        let allowed = bucket.try_consume(&self.config, now);
 
         // Store updated bucket
         let bucket_json =
@@ -208,55 +326,52 @@ impl RateLimitService {
             })?;
 
         let _: () = conn
-            .set_ex(
-                &bucket_key,
-                bucket_json,
-                u64::from(self.config.window_size_seconds),
-            )
+            .set_ex(&bucket_key, bucket_json, self.config.window())
             .await
             .map_err(|e| ApiError::InternalServerError {
                 message: format!("Redis SET failed: {e}"),
             })?;
 
-        Ok(RateLimitResult {
-            allowed,
-            remaining: bucket.remaining_tokens(),
-            reset_time: bucket.time_until_reset(&self.config),
-            limit: self.config.requests_per_window,
-        })
+        Ok(self.result(&bucket, allowed, now))
     }
 
-    async fn check_rate_limit_memory(&self, key: &str) -> Result<RateLimitResult, ApiError> {
+    async fn check_rate_limit_memory(
+        &self,
+        key: &str,
+        now: u64,
+    ) -> Result<RateLimitResult, ApiError> {
         let mut store = self.memory_store.write().await;
 
         let bucket = store
             .entry(key.to_string())
-            .or_insert_with(|| RateLimitBucket::new(self.config.requests_per_window));
+            .or_insert_with(|| RateLimitBucket::new(&self.config, now));
 
-        let allowed = bucket.try_consume(&self.config);
+        let allowed = bucket.try_consume(&self.config, now);
 
-        Ok(RateLimitResult {
-            allowed,
-            remaining: bucket.remaining_tokens(),
-            reset_time: bucket.time_until_reset(&self.config),
-            limit: self.config.requests_per_window,
-        })
+        Ok(self.result(bucket, allowed, now))
     }
 
     /// Get rate limit status without consuming a token
     pub async fn get_rate_limit_status(&self, key: &str) -> Result<RateLimitResult, ApiError> {
-        if let Some(ref client) = self.redis_client {
-            self.get_rate_limit_status_redis(client, key).await
+        let now = current_unix_timestamp();
+        let bucket = if let Some(ref client) = self.redis_client {
+            self.get_bucket_redis(client, key).await?
         } else {
-            self.get_rate_limit_status_memory(key).await
-        }
+            self.memory_store.read().await.get(key).cloned()
+        };
+
+        let mut bucket = bucket.unwrap_or_else(|| RateLimitBucket::new(&self.config, now));
+        bucket.advance(&self.config, now);
+
+        // Status check doesn't consume tokens
+        Ok(self.result(&bucket, true, now))
     }
 
-    async fn get_rate_limit_status_redis(
+    async fn get_bucket_redis(
         &self,
         client: &RedisClient,
         key: &str,
-    ) -> Result<RateLimitResult, ApiError> {
+    ) -> Result<Option<RateLimitBucket>, ApiError> {
         let mut conn = client
             .get_multiplexed_async_connection()
             .await
@@ -272,39 +387,7 @@ impl RateLimitService {
                     message: format!("Redis GET failed: {e}"),
                 })?;
 
-        let bucket = if let Some(ref data) = bucket_data {
-            serde_json::from_str(data)
-                .unwrap_or_else(|_| RateLimitBucket::new(self.config.requests_per_window))
-        } else {
-            RateLimitBucket::new(self.config.requests_per_window)
-        };
-
-        Ok(RateLimitResult {
-            allowed: true, // Status check doesn't consume tokens
-            remaining: bucket.remaining_tokens(),
-            reset_time: bucket.time_until_reset(&self.config),
-            limit: self.config.requests_per_window,
-        })
-    }
-
-    async fn get_rate_limit_status_memory(&self, key: &str) -> Result<RateLimitResult, ApiError> {
-        let store = self.memory_store.read().await;
-
-        if let Some(bucket) = store.get(key) {
-            Ok(RateLimitResult {
-                allowed: true,
-                remaining: bucket.remaining_tokens(),
-                reset_time: bucket.time_until_reset(&self.config),
-                limit: self.config.requests_per_window,
-            })
-        } else {
-            Ok(RateLimitResult {
-                allowed: true,
-                remaining: self.config.requests_per_window,
-                reset_time: u64::from(self.config.window_size_seconds),
-                limit: self.config.requests_per_window,
-            })
-        }
+        Ok(bucket_data.and_then(|data| serde_json::from_str(&data).ok()))
     }
 
     /// Reset rate limit for a specific key (admin function)
@@ -338,9 +421,7 @@ impl RateLimitService {
             let mut store = self.memory_store.write().await;
             let now = current_unix_timestamp();
 
-            store.retain(|_, bucket| {
-                bucket.window_start + u64::from(self.config.window_size_seconds) > now
-            });
+            store.retain(|_, bucket| !bucket.is_expired(&self.config, now));
 
             debug!(
                 "Cleaned up expired rate limit entries. Remaining: {}",
@@ -510,6 +591,7 @@ where
                             limit: result.limit,
                             remaining: result.remaining,
                             reset_time: result.reset_time,
+                            window_size_seconds: rate_limit_service.config.window_size_seconds,
                         };
                         Err(Error::from(rate_limit_error))
                     }
@@ -530,6 +612,7 @@ struct RateLimitError {
     limit: u32,
     remaining: u32,
     reset_time: u64,
+    window_size_seconds: u32,
 }
 
 impl std::fmt::Display for RateLimitError {
@@ -539,18 +622,30 @@ impl std::fmt::Display for RateLimitError {
 }
 
 impl ResponseError for RateLimitError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::TOO_MANY_REQUESTS
+    }
+
     fn error_response(&self) -> HttpResponse {
-        HttpResponse::TooManyRequests()
-            .insert_header(("x-ratelimit-limit", self.limit.to_string()))
-            .insert_header(("x-ratelimit-remaining", self.remaining.to_string()))
-            .insert_header(("x-ratelimit-reset", self.reset_time.to_string()))
-            .insert_header(("retry-after", self.reset_time.to_string()))
-            .json(serde_json::json!({
-                "error": "Rate limit exceeded",
-                "code": "RATE_LIMIT_EXCEEDED",
-                "limit": self.limit,
-                "remaining": self.remaining,
-                "reset_in_seconds": self.reset_time
-            }))
+        let mut response = ApiError::RateLimitExceeded {
+            limit: self.limit,
+            window: format!("{} seconds", self.window_size_seconds),
+        }
+        .error_response();
+        let headers = response.headers_mut();
+        headers.insert(
+            HeaderName::from_static("x-ratelimit-limit"),
+            HeaderValue::from(self.limit),
+        );
+        headers.insert(
+            HeaderName::from_static("x-ratelimit-remaining"),
+            HeaderValue::from(self.remaining),
+        );
+        headers.insert(
+            HeaderName::from_static("x-ratelimit-reset"),
+            HeaderValue::from(self.reset_time),
+        );
+        headers.insert(RETRY_AFTER, HeaderValue::from(self.reset_time));
+        response
     }
 }
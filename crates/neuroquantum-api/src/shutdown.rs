@@ -0,0 +1,272 @@
+//! Graceful shutdown with connection draining
+//!
+//! [`graceful_shutdown`] stops the server from accepting connections, sends
+//! WebSocket clients a `1001 Going Away` close frame and waits up to
+//! `server.shutdown_grace_period_secs` for in-flight requests to complete and
+//! the WebSocket clients to disconnect. Whatever is left when the grace period
+//! ends is terminated as the server stops.
+//!
+//! [`DrainMiddleware`] counts the requests being handled in
+//! [`InFlightRequests`]. While draining it answers requests arriving on
+//! kept-alive connections with `503 Service Unavailable`, so the count only
+//! falls. A request counts as complete once its handler returned a response;
+//! streamed bodies still being sent are cut off when the server stops.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use actix_web::dev::{
+    forward_ready, ServerHandle, Service, ServiceRequest, ServiceResponse, Transform,
+};
+use actix_web::http::{ConnectionType, StatusCode};
+use actix_web::{Error, HttpResponse, ResponseError};
+use futures_util::future::LocalBoxFuture;
+use tokio::sync::Notify;
+use tracing::{info, warn};
+
+use crate::error::ApiError;
+use crate::websocket::ConnectionManager;
+use crate::AppState;
+
+/// How often the WebSocket connection count is checked while draining
+const WEBSOCKET_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Requests currently being handled
+#[derive(Debug, Default)]
+pub struct InFlightRequests {
+    count: AtomicUsize,
+    draining: AtomicBool,
+    idle: Notify,
+}
+
+impl InFlightRequests {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of requests being handled
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+
+    /// Whether new requests are being rejected for shutdown
+    #[must_use]
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::SeqCst)
+    }
+
+    /// Reject new requests from now on
+    pub fn start_draining(&self) {
+        self.draining.store(true, Ordering::SeqCst);
+    }
+
+    /// Count a new request, or `None` when draining
+    fn enter(self: &Arc<Self>) -> Option<InFlightGuard> {
+        // Counting before checking the flag means a drain that saw no
+        // requests cannot miss one that was just admitted
+        self.count.fetch_add(1, Ordering::SeqCst);
+        let guard = InFlightGuard {
+            requests: self.clone(),
+        };
+        (!self.is_draining()).then_some(guard)
+    }
+
+    /// Wait until no requests are being handled
+    pub async fn wait_idle(&self) {
+        loop {
+            let idle = self.idle.notified();
+            if self.count() == 0 {
+                return;
+            }
+            idle.await;
+        }
+    }
+}
+
+/// Marks a request as complete when dropped
+struct InFlightGuard {
+    requests: Arc<InFlightRequests>,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        if self.requests.count.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.requests.idle.notify_waiters();
+        }
+    }
+}
+
+/// Outcome of draining the server
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DrainReport {
+    /// Requests that completed within the grace period
+    pub requests_drained: usize,
+    /// Requests still running when the server stopped
+    pub requests_terminated: usize,
+    /// WebSocket clients that disconnected within the grace period
+    pub websockets_drained: usize,
+    /// WebSocket connections closed by the server stopping
+    pub websockets_terminated: usize,
+}
+
+/// Stop the server behind `handle`, draining connections for up to `grace`
+///
+/// Returns once the server has stopped.
+pub async fn graceful_shutdown(
+    handle: &ServerHandle,
+    state: &AppState,
+    grace: Duration,
+) -> DrainReport {
+    let started = Instant::now();
+    let requests = &state.in_flight_requests;
+    let websockets = state.websocket_service.connection_manager();
+
+    requests.start_draining();
+    handle.pause().await;
+
+    let requests_in_flight = requests.count();
+    let websockets_open = websockets
+        .close_all(Some(actix_ws::CloseCode::Away.into()))
+        .await;
+    info!(
+        requests = requests_in_flight,
+        websockets = websockets_open,
+        grace_period_secs = grace.as_secs_f64(),
+        "🛑 Draining connections"
+    );
+
+    let drained = tokio::time::timeout(grace, async {
+        requests.wait_idle().await;
+        wait_for_websockets(&websockets).await;
+    })
+    .await
+    .is_ok();
+
+    let requests_terminated = requests.count();
+    let websockets_terminated = websockets.connection_count();
+    let report = DrainReport {
+        requests_drained: requests_in_flight.saturating_sub(requests_terminated),
+        requests_terminated,
+        websockets_drained: websockets_open.saturating_sub(websockets_terminated),
+        websockets_terminated,
+    };
+
+    handle.stop(false).await;
+    websockets.shutdown().await;
+
+    if drained {
+        info!(
+            requests_drained = report.requests_drained,
+            websockets_drained = report.websockets_drained,
+            elapsed_ms = started.elapsed().as_millis() as u64,
+            "✅ All connections drained"
+        );
+    } else {
+        warn!(
+            requests_drained = report.requests_drained,
+            requests_terminated = report.requests_terminated,
+            websockets_drained = report.websockets_drained,
+            websockets_terminated = report.websockets_terminated,
+            "⚠️  Grace period expired, terminated remaining connections"
+        );
+    }
+    report
+}
+
+async fn wait_for_websockets(websockets: &ConnectionManager) {
+    while websockets.connection_count() > 0 {
+        tokio::time::sleep(WEBSOCKET_POLL_INTERVAL).await;
+    }
+}
+
+/// Middleware counting requests in [`InFlightRequests`]
+pub struct DrainMiddleware {
+    requests: Arc<InFlightRequests>,
+}
+
+impl DrainMiddleware {
+    #[must_use]
+    pub const fn new(requests: Arc<InFlightRequests>) -> Self {
+        Self { requests }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for DrainMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = DrainMiddlewareService<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(DrainMiddlewareService {
+            service: std::rc::Rc::new(service),
+            requests: self.requests.clone(),
+        }))
+    }
+}
+
+pub struct DrainMiddlewareService<S> {
+    service: std::rc::Rc<S>,
+    requests: Arc<InFlightRequests>,
+}
+
+impl<S, B> Service<ServiceRequest> for DrainMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let guard = self.requests.enter();
+
+        Box::pin(async move {
+            let Some(_guard) = guard else {
+                return Err(Error::from(ShuttingDownError));
+            };
+            service.call(req).await
+        })
+    }
+}
+
+#[derive(Debug)]
+struct ShuttingDownError;
+
+impl std::fmt::Display for ShuttingDownError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Server is shutting down")
+    }
+}
+
+impl ResponseError for ShuttingDownError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::SERVICE_UNAVAILABLE
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let mut response = ApiError::ServiceUnavailable {
+            service: "api".to_string(),
+            reason: "server is shutting down".to_string(),
+        }
+        .error_response();
+        response
+            .head_mut()
+            .set_connection_type(ConnectionType::Close);
+        response
+    }
+}
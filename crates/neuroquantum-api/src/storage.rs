@@ -7,6 +7,7 @@ use rusqlite::{params, Connection};
 use tracing::{debug, info};
 
 use crate::auth::ApiKey;
+use crate::permissions::ScopeRule;
 
 /// Persistent storage for API keys using `SQLite`
 #[derive(Debug, Clone)]
@@ -49,11 +50,23 @@ impl ApiKeyStorage {
                 rate_limit_per_hour INTEGER,
                 is_revoked INTEGER NOT NULL DEFAULT 0,
                 revoked_at TEXT,
-                revoked_by TEXT
+                revoked_by TEXT,
+                scopes TEXT NOT NULL DEFAULT '[]'
             )",
             [],
         )?;
 
+        // Databases created before scoped keys lack the scopes column
+        let has_scopes = conn
+            .prepare("SELECT 1 FROM pragma_table_info('api_keys') WHERE name = 'scopes'")?
+            .exists([])?;
+        if !has_scopes {
+            conn.execute(
+                "ALTER TABLE api_keys ADD COLUMN scopes TEXT NOT NULL DEFAULT '[]'",
+                [],
+            )?;
+        }
+
         // Create indexes for performance
         conn.execute(
             "CREATE INDEX IF NOT EXISTS idx_api_keys_name ON api_keys(name)",
@@ -77,12 +90,13 @@ impl ApiKeyStorage {
             .map_err(|e| anyhow::anyhow!("Database lock poisoned: {e}"))?;
 
         let permissions_json = serde_json::to_string(&api_key.permissions)?;
+        let scopes_json = serde_json::to_string(&api_key.scopes)?;
 
         conn.execute(
             "INSERT INTO api_keys (
                 key_id, key_hash, name, permissions, expires_at, created_at,
-                last_used, usage_count, rate_limit_per_hour
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                last_used, usage_count, rate_limit_per_hour, scopes
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             params![
                 &api_key.key,
                 key_hash,
@@ -93,6 +107,7 @@ impl ApiKeyStorage {
                 api_key.last_used.map(|dt| dt.to_rfc3339()),
                 api_key.usage_count,
                 api_key.rate_limit_per_hour,
+                scopes_json,
             ],
         )?;
 
@@ -113,7 +128,7 @@ impl ApiKeyStorage {
 
         let mut stmt = conn.prepare(
             "SELECT key_id, key_hash, name, permissions, expires_at, created_at,
-                    last_used, usage_count, rate_limit_per_hour, is_revoked
+                    last_used, usage_count, rate_limit_per_hour, is_revoked, scopes
              FROM api_keys
              WHERE key_id = ? AND is_revoked = 0",
         )?;
@@ -132,6 +147,14 @@ impl ApiKeyStorage {
             let expires_at_str: String = row.get(4)?;
             let created_at_str: String = row.get(5)?;
             let last_used_str: Option<String> = row.get(6)?;
+            let scopes_json: String = row.get(10)?;
+            let scopes: Vec<ScopeRule> = serde_json::from_str(&scopes_json).map_err(|e| {
+                rusqlite::Error::FromSqlConversionFailure(
+                    10,
+                    rusqlite::types::Type::Text,
+                    Box::new(e),
+                )
+            })?;
 
             let api_key = ApiKey {
                 key: row.get(0)?,
@@ -160,6 +183,7 @@ impl ApiKeyStorage {
                     .map(|dt| dt.with_timezone(&Utc)),
                 usage_count: row.get(7)?,
                 rate_limit_per_hour: row.get(8)?,
+                scopes,
             };
 
             let key_hash: String = row.get(1)?;
@@ -221,7 +245,7 @@ impl ApiKeyStorage {
 
         let mut stmt = conn.prepare(
             "SELECT key_id, name, permissions, expires_at, created_at,
-                    last_used, usage_count, rate_limit_per_hour
+                    last_used, usage_count, rate_limit_per_hour, scopes
              FROM api_keys
              WHERE is_revoked = 0
              ORDER BY created_at DESC",
@@ -242,6 +266,14 @@ impl ApiKeyStorage {
                 let expires_at_str: String = row.get(3)?;
                 let created_at_str: String = row.get(4)?;
                 let last_used_str: Option<String> = row.get(5)?;
+                let scopes_json: String = row.get(8)?;
+                let scopes: Vec<ScopeRule> = serde_json::from_str(&scopes_json).map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        8,
+                        rusqlite::types::Type::Text,
+                        Box::new(e),
+                    )
+                })?;
 
                 let key_id: String = row.get(0)?;
                 let masked_key = format!("{}...{}", &key_id[..8], &key_id[key_id.len() - 8..]);
@@ -273,6 +305,7 @@ impl ApiKeyStorage {
                         .map(|dt| dt.with_timezone(&Utc)),
                     usage_count: row.get(6)?,
                     rate_limit_per_hour: row.get(7)?,
+                    scopes,
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -364,6 +397,7 @@ pub struct ApiKeyInfo {
     pub last_used: Option<DateTime<Utc>>,
     pub usage_count: u64,
     pub rate_limit_per_hour: Option<u32>,
+    pub scopes: Vec<ScopeRule>,
 }
 
 /// Storage statistics
@@ -0,0 +1,59 @@
+//! Portable host information
+//!
+//! Memory figures come from `sysinfo`, which reads `MemAvailable` from
+//! `/proc/meminfo` on Linux and queries the native APIs on macOS and Windows.
+
+use sysinfo::{MemoryRefreshKind, RefreshKind, System};
+
+/// Available memory in MB below which startup warns about low memory
+pub const LOW_MEMORY_THRESHOLD_MB: u64 = 512;
+
+/// Physical memory of the host
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryInfo {
+    /// Installed memory in bytes
+    pub total_bytes: u64,
+    /// Memory available to new allocations without swapping, in bytes
+    pub available_bytes: u64,
+}
+
+impl MemoryInfo {
+    /// Installed memory in MB
+    #[must_use]
+    pub const fn total_mb(&self) -> u64 {
+        self.total_bytes / (1024 * 1024)
+    }
+
+    /// Available memory in MB
+    #[must_use]
+    pub const fn available_mb(&self) -> u64 {
+        self.available_bytes / (1024 * 1024)
+    }
+
+    /// Check if available memory is below [`LOW_MEMORY_THRESHOLD_MB`]
+    #[must_use]
+    pub const fn is_low(&self) -> bool {
+        self.available_mb() < LOW_MEMORY_THRESHOLD_MB
+    }
+}
+
+/// Probe the host's memory
+///
+/// Returns `None` on platforms where `sysinfo` can't read memory figures.
+#[must_use]
+pub fn memory_info() -> Option<MemoryInfo> {
+    let sys = System::new_with_specifics(
+        RefreshKind::nothing().with_memory(MemoryRefreshKind::nothing().with_ram()),
+    );
+    let total_bytes = sys.total_memory();
+    (total_bytes > 0).then(|| MemoryInfo {
+        total_bytes,
+        available_bytes: sys.available_memory(),
+    })
+}
+
+/// Memory available on the host in bytes, if it can be determined
+#[must_use]
+pub fn available_memory_bytes() -> Option<u64> {
+    memory_info().map(|info| info.available_bytes)
+}
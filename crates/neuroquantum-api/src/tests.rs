@@ -138,6 +138,7 @@ mod auth_tests {
             created_at: chrono::Utc::now(),
             expires_at: chrono::Utc::now() + chrono::Duration::hours(1),
             rate_limit_per_hour: Some(1000),
+            scopes: Vec::new(),
             usage_count: 0,
             last_used: None,
         };
@@ -156,6 +157,7 @@ mod auth_tests {
             created_at: chrono::Utc::now(),
             expires_at: chrono::Utc::now() + chrono::Duration::hours(1),
             rate_limit_per_hour: Some(100),
+            scopes: Vec::new(),
             usage_count: 5,
             last_used: None,
         };
@@ -175,7 +177,7 @@ mod handler_tests {
 
     #[actix_web::test]
     async fn test_health_check_endpoint() {
-        let result = health_check().await;
+        let result = health_check(None).await;
         assert!(result.is_ok());
 
         let response = result.unwrap();
@@ -184,7 +186,7 @@ mod handler_tests {
 
     #[actix_web::test]
     async fn test_metrics_endpoint() {
-        let response = metrics().await;
+        let response = metrics(None).await;
         assert_eq!(response.status(), StatusCode::OK);
     }
 
@@ -0,0 +1,213 @@
+//! TLS listener and client certificate authentication
+//!
+//! [`server_config`] builds the rustls configuration of the HTTPS listener.
+//! With [`ClientAuthMode::Optional`] or [`ClientAuthMode::Required`], client
+//! certificates are verified against the configured CA during the handshake,
+//! so connections presenting an untrusted certificate never reach the
+//! application.
+//!
+//! [`on_connect`] stores the [`ClientIdentity`] of a verified certificate in
+//! the connection data, where the auth middleware maps it to a principal
+//! listed in [`AuthConfig::client_certificates`].
+
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+use actix_tls::accept::rustls_0_23::TlsStream;
+use actix_web::dev::Extensions;
+use actix_web::rt::net::TcpStream;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use rustls::crypto::ring;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::WebPkiClientVerifier;
+use rustls::{RootCertStore, ServerConfig};
+use sha2::{Digest, Sha256};
+use tracing::warn;
+use x509_parser::extensions::GeneralName;
+
+use crate::auth::ApiKey;
+use crate::config::{AuthConfig, ClientAuthMode, TlsConfig};
+
+/// Identity of a verified client certificate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientIdentity {
+    /// Common name of the subject
+    pub common_name: Option<String>,
+    /// DNS names, email addresses and URIs of the subject alternative names
+    pub subject_alt_names: Vec<String>,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+}
+
+impl ClientIdentity {
+    /// Read the identity from a DER-encoded certificate
+    pub fn from_der(der: &[u8]) -> Result<Self> {
+        let (_, cert) = x509_parser::parse_x509_certificate(der)
+            .map_err(|e| anyhow!("Invalid client certificate: {e}"))?;
+
+        let common_name = cert
+            .subject()
+            .iter_common_name()
+            .next()
+            .and_then(|cn| cn.as_str().ok())
+            .map(str::to_string);
+        let subject_alt_names = cert
+            .subject_alternative_name()
+            .map_err(|e| anyhow!("Invalid subject alternative names: {e}"))?
+            .map(|san| {
+                san.value
+                    .general_names
+                    .iter()
+                    .filter_map(|name| match name {
+                        | GeneralName::DNSName(name)
+                        | GeneralName::RFC822Name(name)
+                        | GeneralName::URI(name) => Some((*name).to_string()),
+                        | _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let validity = cert.validity();
+
+        Ok(Self {
+            common_name,
+            subject_alt_names,
+            not_before: timestamp(validity.not_before.timestamp())?,
+            not_after: timestamp(validity.not_after.timestamp())?,
+        })
+    }
+
+    /// Common name followed by the subject alternative names
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.common_name
+            .iter()
+            .chain(&self.subject_alt_names)
+            .map(String::as_str)
+    }
+
+    /// Stable key identifying the certificate holder
+    ///
+    /// Hashes the common name and subject alternative names, so every client
+    /// gets its own key (e.g. as owner of running queries) while renewed
+    /// certificates for the same names keep it.
+    #[must_use]
+    pub fn key(&self) -> String {
+        let mut hasher = Sha256::new();
+        // Length prefixes keep adjacent names from running into each other
+        for name in self.names() {
+            hasher.update((name.len() as u64).to_le_bytes());
+            hasher.update(name.as_bytes());
+        }
+        format!("mtls_{:x}", hasher.finalize())
+    }
+}
+
+impl fmt::Display for ClientIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.common_name {
+            | Some(cn) => write!(f, "CN={cn}"),
+            | None => write!(f, "SAN={}", self.subject_alt_names.join(",")),
+        }
+    }
+}
+
+fn timestamp(secs: i64) -> Result<DateTime<Utc>> {
+    DateTime::from_timestamp(secs, 0).ok_or_else(|| anyhow!("Certificate time out of range"))
+}
+
+/// Principal a verified client certificate authenticates as
+///
+/// The first entry of `auth.client_certificates` whose identity matches the
+/// common name or a subject alternative name is used. The returned key carries
+/// the entry's permissions and expires with the certificate; instead of a
+/// secret it holds [`ClientIdentity::key`].
+#[must_use]
+pub fn principal_for(auth: &AuthConfig, identity: &ClientIdentity) -> Option<ApiKey> {
+    let principal = auth
+        .client_certificates
+        .iter()
+        .find(|principal| identity.names().any(|name| name == principal.identity))?;
+    Some(ApiKey {
+        key: identity.key(),
+        name: principal.identity.clone(),
+        permissions: principal.permissions.clone(),
+        expires_at: identity.not_after,
+        created_at: identity.not_before,
+        last_used: None,
+        usage_count: 0,
+        rate_limit_per_hour: None,
+        scopes: Vec::new(),
+    })
+}
+
+/// Build the rustls configuration of the HTTPS listener
+pub fn server_config(tls: &TlsConfig) -> Result<ServerConfig> {
+    let provider = Arc::new(ring::default_provider());
+
+    let certs = CertificateDer::pem_file_iter(&tls.cert_file)
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .with_context(|| format!("Failed to read TLS certificate {}", tls.cert_file))?;
+    let key = PrivateKeyDer::from_pem_file(&tls.key_file)
+        .with_context(|| format!("Failed to read TLS private key {}", tls.key_file))?;
+
+    let builder = ServerConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .context("Failed to select TLS protocol versions")?;
+    let builder = if tls.client_auth == ClientAuthMode::Disabled {
+        builder.with_no_client_auth()
+    } else {
+        let ca_file = tls
+            .ca_file
+            .as_ref()
+            .ok_or_else(|| anyhow!("Client certificate authentication requires ca_file"))?;
+        let mut roots = RootCertStore::empty();
+        for cert in CertificateDer::pem_file_iter(ca_file)
+            .with_context(|| format!("Failed to read CA certificates {ca_file}"))?
+        {
+            let cert = cert.with_context(|| format!("Invalid CA certificate in {ca_file}"))?;
+            roots
+                .add(cert)
+                .with_context(|| format!("Invalid CA certificate in {ca_file}"))?;
+        }
+
+        let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider);
+        let verifier = if tls.client_auth == ClientAuthMode::Optional {
+            verifier.allow_unauthenticated()
+        } else {
+            verifier
+        };
+        builder.with_client_cert_verifier(
+            verifier
+                .build()
+                .context("Failed to build client certificate verifier")?,
+        )
+    };
+
+    builder
+        .with_single_cert(certs, key)
+        .context("TLS certificate doesn't match the private key")
+}
+
+/// Connection callback storing the [`ClientIdentity`] of a TLS client
+///
+/// Register with `HttpServer::on_connect`; handlers and middleware read the
+/// identity with `HttpRequest::conn_data`. Certificates reaching this point
+/// were already verified during the handshake.
+pub fn on_connect(connection: &dyn Any, data: &mut Extensions) {
+    let Some(stream) = connection.downcast_ref::<TlsStream<TcpStream>>() else {
+        return;
+    };
+    let (_, session) = stream.get_ref();
+    let Some(cert) = session.peer_certificates().and_then(<[_]>::first) else {
+        return;
+    };
+    match ClientIdentity::from_der(cert) {
+        | Ok(identity) => {
+            data.insert(identity);
+        },
+        | Err(e) => warn!("❌ Unreadable client certificate: {:#}", e),
+    }
+}
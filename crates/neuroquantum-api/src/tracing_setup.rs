@@ -4,6 +4,7 @@ use anyhow::{Context, Result};
 use opentelemetry::trace::TracerProvider;
 use opentelemetry::{global, KeyValue};
 use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
 use opentelemetry_sdk::trace::{RandomIdGenerator, Sampler, Tracer};
 use opentelemetry_sdk::{runtime, Resource};
 use opentelemetry_semantic_conventions::resource::{SERVICE_NAME, SERVICE_VERSION};
@@ -31,6 +32,9 @@ pub fn init_tracing(config: &TracingConfig) -> Result<()> {
     info!("   Sampling rate: {:.1}%", config.sampling_rate * 100.0);
     info!("   Trace level: {:?}", config.trace_level);
 
+    // Propagate W3C `traceparent`/`tracestate` headers across service boundaries
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
     // Create tracer based on exporter type
     let tracer = match &config.exporter {
         | TracingExporter::Jaeger => create_jaeger_tracer(config)?,
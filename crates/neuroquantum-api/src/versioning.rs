@@ -0,0 +1,241 @@
+//! API version negotiation.
+//!
+//! Clients select an API version either through the path (`/api/v1/...`,
+//! `/api/v2/...`) or with a vendor media type in the `Accept` header
+//! (`application/vnd.neuroquantum.v2+json`). When both are given the header
+//! wins: the request is rerouted to the matching `/api/v{N}` scope, so a client
+//! can move to a new version without changing its URLs. Requests for a version
+//! this server does not provide are rejected with `406 Not Acceptable`.
+//!
+//! The negotiated version is stored in the request extensions and echoed in the
+//! `X-API-Version` response header.
+
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::header::{HeaderName, HeaderValue, ACCEPT};
+use actix_web::http::{StatusCode, Uri};
+use actix_web::{Error, HttpMessage, HttpResponse, ResponseError};
+use futures_util::future::LocalBoxFuture;
+use tracing::debug;
+
+use crate::error::ApiError;
+
+/// Prefix of the vendor media type used to request a version in `Accept`
+pub const VENDOR_MEDIA_TYPE_PREFIX: &str = "application/vnd.neuroquantum.v";
+
+/// Response header carrying the negotiated version
+pub const API_VERSION_HEADER: &str = "x-api-version";
+
+/// A released version of the REST API
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ApiVersion {
+    /// Original API with the `ApiResponse`/`ResponseMetadata` envelope
+    V1,
+    /// Restructured response envelope (`ApiResponseV2`)
+    V2,
+}
+
+impl ApiVersion {
+    /// All versions served, oldest first
+    pub const SUPPORTED: [Self; 2] = [Self::V1, Self::V2];
+
+    /// Look up a version by its number
+    #[must_use]
+    pub const fn from_number(number: u32) -> Option<Self> {
+        match number {
+            | 1 => Some(Self::V1),
+            | 2 => Some(Self::V2),
+            | _ => None,
+        }
+    }
+
+    /// Version label as used in paths and headers, e.g. `v2`
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            | Self::V1 => "v1",
+            | Self::V2 => "v2",
+        }
+    }
+
+    /// Path prefix of the version's scope, e.g. `/api/v2`
+    #[must_use]
+    pub fn path_prefix(self) -> String {
+        format!("/api/{}", self.as_str())
+    }
+}
+
+/// Outcome of looking for a version in one part of the request
+#[derive(Debug, PartialEq, Eq)]
+enum Requested {
+    Supported(ApiVersion),
+    Unsupported(String),
+}
+
+fn parse_version(label: &str) -> Requested {
+    label
+        .parse()
+        .ok()
+        .and_then(ApiVersion::from_number)
+        .map_or_else(
+            || Requested::Unsupported(format!("v{label}")),
+            Requested::Supported,
+        )
+}
+
+/// Version requested by a vendor media type in an `Accept` header
+fn version_from_accept(accept: &str) -> Option<Requested> {
+    accept.split(',').find_map(|media_type| {
+        let rest = media_type.trim().strip_prefix(VENDOR_MEDIA_TYPE_PREFIX)?;
+        let label = rest.split(['+', ';']).next().unwrap_or_default().trim();
+        Some(parse_version(label))
+    })
+}
+
+/// Version segment of an `/api/v{N}` path and the remainder after it
+fn version_from_path(path: &str) -> Option<(Requested, &str)> {
+    let rest = path.strip_prefix("/api/v")?;
+    let end = rest.find('/').unwrap_or(rest.len());
+    let (label, remainder) = rest.split_at(end);
+    if !label.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    Some((parse_version(label), remainder))
+}
+
+/// Part of a versioned API path after `/api/v{N}`, e.g. `/auth/login` for
+/// `/api/v2/auth/login`; `None` for paths outside a supported version
+#[must_use]
+pub fn strip_version_prefix(path: &str) -> Option<&str> {
+    match version_from_path(path)? {
+        | (Requested::Supported(_), remainder) => Some(remainder),
+        | (Requested::Unsupported(_), _) => None,
+    }
+}
+
+/// Middleware negotiating the API version of each request
+#[derive(Debug, Default, Clone, Copy)]
+pub struct VersionNegotiationMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for VersionNegotiationMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = VersionNegotiationMiddlewareService<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(VersionNegotiationMiddlewareService {
+            service: std::rc::Rc::new(service),
+        }))
+    }
+}
+
+pub struct VersionNegotiationMiddlewareService<S> {
+    service: std::rc::Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for VersionNegotiationMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+
+        Box::pin(async move {
+            let from_header = req
+                .headers()
+                .get(ACCEPT)
+                .and_then(|value| value.to_str().ok())
+                .and_then(version_from_accept);
+            let from_path = version_from_path(req.path())
+                .map(|(requested, remainder)| (requested, remainder.to_string()));
+
+            let version = match (from_header, from_path) {
+                | (Some(Requested::Unsupported(requested)), _)
+                | (None, Some((Requested::Unsupported(requested), _))) => {
+                    return Err(Error::from(UnsupportedVersionError { requested }));
+                },
+                | (Some(Requested::Supported(version)), Some((path_version, remainder))) => {
+                    if path_version != Requested::Supported(version) {
+                        reroute(&mut req, version, &remainder)?;
+                    }
+                    Some(version)
+                },
+                | (Some(Requested::Supported(version)), None)
+                | (None, Some((Requested::Supported(version), _))) => Some(version),
+                | (None, None) => None,
+            };
+
+            if let Some(version) = version {
+                req.extensions_mut().insert(version);
+            }
+
+            let mut res = service.call(req).await?;
+            if let Some(version) = version {
+                res.headers_mut().insert(
+                    HeaderName::from_static(API_VERSION_HEADER),
+                    HeaderValue::from_static(version.as_str()),
+                );
+            }
+            Ok(res)
+        })
+    }
+}
+
+/// Rewrite the request path to `version`'s scope, keeping the query string
+fn reroute(req: &mut ServiceRequest, version: ApiVersion, remainder: &str) -> Result<(), Error> {
+    let mut path = format!("{}{remainder}", version.path_prefix());
+    if let Some(query) = req.uri().query() {
+        path = format!("{path}?{query}");
+    }
+    debug!("🔀 Routing {} to {}", req.path(), path);
+
+    let mut parts = req.head().uri.clone().into_parts();
+    parts.path_and_query = Some(path.parse().map_err(actix_web::error::ErrorBadRequest)?);
+    let uri = Uri::from_parts(parts).map_err(actix_web::error::ErrorBadRequest)?;
+    req.match_info_mut().get_mut().update(&uri);
+    req.head_mut().uri = uri;
+    Ok(())
+}
+
+// Negotiation error type for middleware
+#[derive(Debug)]
+struct UnsupportedVersionError {
+    requested: String,
+}
+
+impl std::fmt::Display for UnsupportedVersionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unsupported API version: {}", self.requested)
+    }
+}
+
+impl ResponseError for UnsupportedVersionError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::NOT_ACCEPTABLE
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        ApiError::UnsupportedApiVersion {
+            requested: self.requested.clone(),
+            supported: ApiVersion::SUPPORTED
+                .iter()
+                .map(|version| version.as_str().to_string())
+                .collect(),
+        }
+        .error_response()
+    }
+}
@@ -1,15 +1,27 @@
 //! Integrated WebSocket Handler
 //!
 //! Combines `ConnectionManager` and `PubSubManager` for a complete
-//! real-time communication solution with query streaming support.
+//! real-time communication solution with query streaming support and live
+//! query subscriptions.
+//!
+//! Messages are JSON objects tagged by `type`, e.g.
+//! `{"type":"subscribe_query","query":"SELECT ..."}` for a live query.
+//! `{"type":"subscribe"}` is taken by channel subscriptions, so live queries
+//! use `subscribe_query` rather than an `action` field. Streamed and live
+//! queries are checked against the permissions and scope rules the
+//! connection authenticated with.
 
 use std::sync::Arc;
 
 use actix_ws::{Message, Session};
 use futures_util::StreamExt;
+use neuroquantum_core::storage::{RowChangeKind, StorageEngine};
 use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::error::RecvError;
 use tracing::{debug, error, info, warn};
 
+use crate::error::ApiError;
+use crate::websocket::live_query::{LiveQueryEvent, LiveQueryId, LiveQueryRegistry};
 use crate::websocket::manager::{ConnectionError, ConnectionManager};
 use crate::websocket::pubsub::{ChannelId, PubSubManager};
 use crate::websocket::streaming::{
@@ -42,6 +54,12 @@ pub enum WsMessage {
     /// Cancel a streaming query
     CancelQuery { stream_id: String },
 
+    /// Follow a SELECT, receiving its result set changes
+    SubscribeQuery { query: String },
+
+    /// Stop following a live query
+    UnsubscribeQuery { subscription_id: String },
+
     /// Ping (heartbeat)
     Ping { timestamp: Option<String> },
 
@@ -105,6 +123,25 @@ pub enum WsResponse {
     /// Query cancelled
     QueryCancelled { stream_id: String, reason: String },
 
+    /// Live query registered
+    LiveQuerySubscribed {
+        subscription_id: String,
+        query: String,
+        table: String,
+    },
+
+    /// Live query removed
+    LiveQueryUnsubscribed { subscription_id: String },
+
+    /// A committed change to a live query's result set
+    LiveQueryChange {
+        subscription_id: String,
+        table: String,
+        change: RowChangeKind,
+        row: serde_json::Value,
+        timestamp: String,
+    },
+
     /// Pong response
     Pong { timestamp: String },
 
@@ -125,6 +162,7 @@ pub struct WebSocketService {
     pubsub_manager: Arc<PubSubManager>,
     streaming_registry: Arc<StreamingRegistry>,
     query_streamer: Arc<QueryStreamer>,
+    live_queries: Arc<LiveQueryRegistry>,
     qsql_engine: Option<Arc<tokio::sync::Mutex<neuroquantum_qsql::QSQLEngine>>>,
 }
 
@@ -142,11 +180,13 @@ impl WebSocketService {
         ));
 
         info!("✅ WebSocketService initialized with streaming support");
+        let live_queries = Arc::new(LiveQueryRegistry::new(pubsub_manager.clone()));
         Self {
             connection_manager,
             pubsub_manager,
             streaming_registry,
             query_streamer,
+            live_queries,
             qsql_engine: None,
         }
     }
@@ -174,11 +214,13 @@ impl WebSocketService {
         ));
 
         info!("✅ WebSocketService initialized with QSQL engine support");
+        let live_queries = Arc::new(LiveQueryRegistry::new(pubsub_manager.clone()));
         Self {
             connection_manager,
             pubsub_manager,
             streaming_registry,
             query_streamer,
+            live_queries,
             qsql_engine: Some(qsql_engine),
         }
     }
@@ -196,11 +238,13 @@ impl WebSocketService {
         ));
 
         info!("✅ WebSocketService initialized with custom streaming config");
+        let live_queries = Arc::new(LiveQueryRegistry::new(pubsub_manager.clone()));
         Self {
             connection_manager,
             pubsub_manager,
             streaming_registry,
             query_streamer,
+            live_queries,
             qsql_engine: None,
         }
     }
@@ -225,7 +269,8 @@ impl WebSocketService {
             }
         }
 
-        // Cleanup: unsubscribe from all channels
+        // Cleanup: drop live queries and unsubscribe from all channels
+        self.live_queries.unsubscribe_connection(conn_id).await;
         if let Err(e) = self.pubsub_manager.unsubscribe_all(conn_id).await {
             warn!("Failed to unsubscribe connection {}: {:?}", conn_id, e);
         }
@@ -298,6 +343,8 @@ impl WebSocketService {
             | WsMessage::Publish { .. } => "publish",
             | WsMessage::StreamQuery { .. } => "stream_query",
             | WsMessage::CancelQuery { .. } => "cancel_query",
+            | WsMessage::SubscribeQuery { .. } => "subscribe_query",
+            | WsMessage::UnsubscribeQuery { .. } => "unsubscribe_query",
             | WsMessage::Ping { .. } => "ping",
             | WsMessage::Pong { .. } => "pong",
             | WsMessage::QueryStatus { .. } => "query_status",
@@ -321,6 +368,13 @@ impl WebSocketService {
             | WsMessage::CancelQuery { stream_id } => {
                 self.handle_cancel_query(conn_id, stream_id).await?;
             },
+            | WsMessage::SubscribeQuery { query } => {
+                self.handle_subscribe_query(conn_id, query).await?;
+            },
+            | WsMessage::UnsubscribeQuery { subscription_id } => {
+                self.handle_unsubscribe_query(conn_id, subscription_id)
+                    .await?;
+            },
             | WsMessage::Ping { timestamp } => {
                 self.handle_ping(conn_id, timestamp).await?;
             },
@@ -433,6 +487,10 @@ impl WebSocketService {
         query: String,
         _batch_size: Option<usize>,
     ) -> Result<(), Box<dyn std::error::Error>> {
+        if !self.authorize_query(conn_id, &query).await? {
+            return Ok(());
+        }
+
         // Register the stream
         let stream_id = self
             .streaming_registry
@@ -660,6 +718,156 @@ impl WebSocketService {
         Ok(())
     }
 
+    /// Handle live query subscription request
+    async fn handle_subscribe_query(
+        &self,
+        conn_id: ConnectionId,
+        query: String,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(qsql_engine) = &self.qsql_engine else {
+            let response = WsResponse::Error {
+                code: "LIVE_QUERY_UNAVAILABLE".to_string(),
+                message: "Live queries require a QSQL engine".to_string(),
+            };
+            self.send_to_connection(conn_id, &response).await?;
+            return Ok(());
+        };
+        if !self.authorize_query(conn_id, &query).await? {
+            return Ok(());
+        }
+
+        let parsed = qsql_engine.lock().await.live_query(&query);
+        let storage_query = match parsed {
+            | Ok(storage_query) => storage_query,
+            | Err(e) => {
+                let response = WsResponse::Error {
+                    code: "INVALID_LIVE_QUERY".to_string(),
+                    message: e.to_string(),
+                };
+                self.send_to_connection(conn_id, &response).await?;
+                return Ok(());
+            },
+        };
+
+        let table = storage_query.table.clone();
+        let subscription_id = self
+            .live_queries
+            .subscribe(conn_id, query.clone(), storage_query)
+            .await?;
+
+        let response = WsResponse::LiveQuerySubscribed {
+            subscription_id: subscription_id.to_string(),
+            query,
+            table,
+        };
+        self.send_to_connection(conn_id, &response).await?;
+
+        Ok(())
+    }
+
+    /// Check the connection may run `query`, replying with an error if not
+    async fn authorize_query(
+        &self,
+        conn_id: ConnectionId,
+        query: &str,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let authorized = match self.connection_manager.get_connection(conn_id) {
+            | Some(connection) => connection.metadata.read().await.authorize_query(query),
+            | None => Err(ApiError::Unauthorized("Unknown connection".to_string())),
+        };
+        let Err(e) = authorized else {
+            return Ok(true);
+        };
+
+        warn!("🚫 Query rejected for connection {}: {}", conn_id, e);
+        let response = WsResponse::Error {
+            code: e.code().as_str().to_string(),
+            message: e.to_string(),
+        };
+        self.send_to_connection(conn_id, &response).await?;
+        Ok(false)
+    }
+
+    /// Handle live query unsubscription request
+    async fn handle_unsubscribe_query(
+        &self,
+        conn_id: ConnectionId,
+        subscription_id: String,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let removed = match uuid::Uuid::parse_str(&subscription_id) {
+            | Ok(uuid) => {
+                self.live_queries
+                    .unsubscribe(conn_id, LiveQueryId::from(uuid))
+                    .await?
+            },
+            | Err(_) => false,
+        };
+
+        let response = if removed {
+            WsResponse::LiveQueryUnsubscribed { subscription_id }
+        } else {
+            WsResponse::Error {
+                code: "INVALID_SUBSCRIPTION_ID".to_string(),
+                message: format!("Unknown live query: {subscription_id}"),
+            }
+        };
+        self.send_to_connection(conn_id, &response).await?;
+
+        Ok(())
+    }
+
+    /// Deliver committed changes of `storage_engine` to live queries
+    ///
+    /// Spawns a task following the storage engine's change feed until it is
+    /// dropped.
+    pub async fn enable_live_queries(
+        &self,
+        storage_engine: Arc<tokio::sync::RwLock<StorageEngine>>,
+    ) {
+        let mut changes = storage_engine.read().await.subscribe_changes();
+        let live_queries = self.live_queries.clone();
+        let connection_manager = self.connection_manager.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let change = match changes.recv().await {
+                    | Ok(change) => change,
+                    | Err(RecvError::Lagged(missed)) => {
+                        warn!("⚠️  Live queries missed {} row changes", missed);
+                        continue;
+                    },
+                    | Err(RecvError::Closed) => break,
+                };
+                if live_queries.is_empty() {
+                    continue;
+                }
+
+                let notifications = {
+                    let storage = storage_engine.read().await;
+                    live_queries
+                        .dispatch(&change, |row, filter| storage.row_matches(row, filter))
+                        .await
+                };
+                for (conn_id, event) in notifications {
+                    let Some(connection) = connection_manager.get_connection(conn_id) else {
+                        continue;
+                    };
+                    let response = live_query_change(event);
+                    match connection.send_json(&response).await {
+                        | Ok(()) => {
+                            crate::metrics::record_websocket_message("sent", "live_query_change")
+                        },
+                        | Err(e) => {
+                            warn!("Failed to send live query change to {}: {:?}", conn_id, e)
+                        },
+                    }
+                }
+            }
+        });
+
+        info!("✅ Live queries enabled");
+    }
+
     /// Handle query status request
     async fn handle_query_status(
         &self,
@@ -698,6 +906,9 @@ impl WebSocketService {
                 | WsResponse::QueryBatch { .. } => "query_batch",
                 | WsResponse::QueryCompleted { .. } => "query_completed",
                 | WsResponse::QueryCancelled { .. } => "query_cancelled",
+                | WsResponse::LiveQuerySubscribed { .. } => "live_query_subscribed",
+                | WsResponse::LiveQueryUnsubscribed { .. } => "live_query_unsubscribed",
+                | WsResponse::LiveQueryChange { .. } => "live_query_change",
                 | WsResponse::Pong { .. } => "pong",
                 | WsResponse::QueryStatus { .. } => "query_status",
                 | WsResponse::Error { .. } => "error",
@@ -749,11 +960,34 @@ impl WebSocketService {
         }
     }
 
+    /// Get the connection manager
+    #[must_use]
+    pub fn connection_manager(&self) -> Arc<ConnectionManager> {
+        self.connection_manager.clone()
+    }
+
     /// Get streaming registry for advanced operations
     #[must_use]
     pub fn streaming_registry(&self) -> Arc<StreamingRegistry> {
         self.streaming_registry.clone()
     }
+
+    /// Get the live query registry
+    #[must_use]
+    pub fn live_queries(&self) -> Arc<LiveQueryRegistry> {
+        self.live_queries.clone()
+    }
+}
+
+/// Response announcing a live query change
+fn live_query_change(event: LiveQueryEvent) -> WsResponse {
+    WsResponse::LiveQueryChange {
+        subscription_id: event.subscription_id.to_string(),
+        table: event.table,
+        change: event.change,
+        row: serde_json::to_value(&event.row).unwrap_or(serde_json::Value::Null),
+        timestamp: chrono::Utc::now().to_rfc3339(),
+    }
 }
 
 /// Service statistics
@@ -0,0 +1,281 @@
+//! Live Query Subscriptions
+//!
+//! A client subscribes to a SELECT and is notified whenever a committed
+//! change makes a row enter, change within or leave the query's result set.
+//!
+//! # Protocol
+//!
+//! ```text
+//! → {"type": "subscribe_query", "query": "SELECT * FROM orders WHERE status = 'open'"}
+//! ← {"type": "live_query_subscribed", "subscription_id": "...", ...}
+//! ← {"type": "live_query_change", "subscription_id": "...", "change": "inserted", "row": {...}, ...}
+//! → {"type": "unsubscribe_query", "subscription_id": "..."}
+//! ```
+//!
+//! Each subscription owns the Pub/Sub channel `live_query.<subscription_id>`,
+//! so subscriptions show up in channel statistics and are removed with the
+//! connection's other subscriptions on disconnect.
+
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use neuroquantum_core::storage::{Row, RowChange, RowChangeKind, SelectQuery, WhereClause};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::websocket::pubsub::{ChannelId, PubSubError, PubSubManager};
+use crate::websocket::types::ConnectionId;
+
+/// Prefix of the Pub/Sub channels owned by live query subscriptions
+pub const LIVE_QUERY_CHANNEL_PREFIX: &str = "live_query.";
+
+/// Unique identifier for a live query subscription
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LiveQueryId(Uuid);
+
+impl LiveQueryId {
+    /// Create a new unique subscription ID
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Pub/Sub channel the subscription is published on
+    #[must_use]
+    pub fn channel(&self) -> ChannelId {
+        ChannelId::new(format!("{LIVE_QUERY_CHANNEL_PREFIX}{}", self.0))
+    }
+}
+
+impl Default for LiveQueryId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for LiveQueryId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Uuid> for LiveQueryId {
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+/// A registered live query
+#[derive(Debug, Clone)]
+pub struct LiveQuery {
+    pub id: LiveQueryId,
+    pub connection_id: ConnectionId,
+    /// Query text as sent by the client
+    pub query: String,
+    /// Table the query reads
+    pub table: String,
+    /// Conditions a row must satisfy to be part of the result set
+    pub filter: Option<WhereClause>,
+}
+
+impl LiveQuery {
+    /// How `change` affects the query's result set, if at all
+    ///
+    /// An update moving a row into the result set is reported as an insert and
+    /// one moving it out as a delete of the previous row.
+    pub fn classify<'a>(
+        &self,
+        change: &'a RowChange,
+        matches: impl Fn(&Row, &WhereClause) -> bool,
+    ) -> Option<(RowChangeKind, &'a Row)> {
+        if change.table != self.table {
+            return None;
+        }
+        let selected = |row: &Row| {
+            self.filter
+                .as_ref()
+                .is_none_or(|filter| matches(row, filter))
+        };
+        match change.kind {
+            | RowChangeKind::Inserted | RowChangeKind::Deleted => {
+                selected(&change.row).then_some((change.kind, &change.row))
+            },
+            | RowChangeKind::Updated => {
+                let was_selected = change.previous.as_ref().is_some_and(selected);
+                match (was_selected, selected(&change.row)) {
+                    | (true, true) => Some((RowChangeKind::Updated, &change.row)),
+                    | (false, true) => Some((RowChangeKind::Inserted, &change.row)),
+                    | (true, false) => change
+                        .previous
+                        .as_ref()
+                        .map(|previous| (RowChangeKind::Deleted, previous)),
+                    | (false, false) => None,
+                }
+            },
+        }
+    }
+}
+
+/// A change notification for one live query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveQueryEvent {
+    pub subscription_id: LiveQueryId,
+    pub table: String,
+    pub change: RowChangeKind,
+    pub row: Row,
+}
+
+/// Registry of live queries across all connections
+pub struct LiveQueryRegistry {
+    pubsub_manager: Arc<PubSubManager>,
+    queries: DashMap<LiveQueryId, LiveQuery>,
+}
+
+impl LiveQueryRegistry {
+    /// Create a registry publishing on `pubsub_manager`
+    pub fn new(pubsub_manager: Arc<PubSubManager>) -> Self {
+        Self {
+            pubsub_manager,
+            queries: DashMap::new(),
+        }
+    }
+
+    /// Register `query` for a connection
+    ///
+    /// `storage_query` is the query translated for the storage engine, whose
+    /// table and WHERE clause decide which changes are delivered.
+    pub async fn subscribe(
+        &self,
+        conn_id: ConnectionId,
+        query: String,
+        storage_query: SelectQuery,
+    ) -> Result<LiveQueryId, PubSubError> {
+        let id = LiveQueryId::new();
+        self.pubsub_manager
+            .subscribe(conn_id, id.channel().as_str())
+            .await?;
+        self.queries.insert(
+            id,
+            LiveQuery {
+                id,
+                connection_id: conn_id,
+                query,
+                table: storage_query.table,
+                filter: storage_query.where_clause,
+            },
+        );
+
+        info!("👁️ Connection {} subscribed to live query {}", conn_id, id);
+        Ok(id)
+    }
+
+    /// Remove a connection's live query
+    ///
+    /// Returns `false` if the connection has no subscription with this ID.
+    pub async fn unsubscribe(
+        &self,
+        conn_id: ConnectionId,
+        id: LiveQueryId,
+    ) -> Result<bool, PubSubError> {
+        if self
+            .queries
+            .remove_if(&id, |_, query| query.connection_id == conn_id)
+            .is_none()
+        {
+            return Ok(false);
+        }
+        self.pubsub_manager
+            .unsubscribe(conn_id, id.channel().as_str())
+            .await?;
+        Ok(true)
+    }
+
+    /// Remove every live query of a disconnected connection
+    ///
+    /// Returns the number of subscriptions removed.
+    pub async fn unsubscribe_connection(&self, conn_id: ConnectionId) -> usize {
+        let ids = self.connection_queries(conn_id);
+        for id in &ids {
+            self.queries.remove(id);
+            if let Err(e) = self
+                .pubsub_manager
+                .unsubscribe(conn_id, id.channel().as_str())
+                .await
+            {
+                warn!("Failed to unsubscribe live query {}: {:?}", id, e);
+            }
+        }
+        if !ids.is_empty() {
+            debug!(
+                "Removed {} live queries of connection {}",
+                ids.len(),
+                conn_id
+            );
+        }
+        ids.len()
+    }
+
+    /// IDs of a connection's live queries
+    #[must_use]
+    pub fn connection_queries(&self, conn_id: ConnectionId) -> Vec<LiveQueryId> {
+        self.queries
+            .iter()
+            .filter(|entry| entry.connection_id == conn_id)
+            .map(|entry| *entry.key())
+            .collect()
+    }
+
+    /// Look up a live query
+    #[must_use]
+    pub fn get(&self, id: LiveQueryId) -> Option<LiveQuery> {
+        self.queries.get(&id).map(|entry| entry.clone())
+    }
+
+    /// Number of registered live queries
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.queries.len()
+    }
+
+    /// Check if no live queries are registered
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+
+    /// Publish `change` to every live query it affects
+    ///
+    /// `matches` evaluates a WHERE clause against a row. Returns the
+    /// notifications to deliver, one per subscribed connection and query.
+    pub async fn dispatch(
+        &self,
+        change: &RowChange,
+        matches: impl Fn(&Row, &WhereClause) -> bool,
+    ) -> Vec<(ConnectionId, LiveQueryEvent)> {
+        let affected: Vec<(LiveQueryId, RowChangeKind, Row)> = self
+            .queries
+            .iter()
+            .filter_map(|entry| {
+                entry
+                    .classify(change, &matches)
+                    .map(|(kind, row)| (entry.id, kind, row.clone()))
+            })
+            .collect();
+
+        let mut notifications = Vec::new();
+        for (id, kind, row) in affected {
+            let event = LiveQueryEvent {
+                subscription_id: id,
+                table: change.table.clone(),
+                change: kind,
+                row,
+            };
+            let payload = serde_json::to_value(&event).unwrap_or(serde_json::Value::Null);
+            for conn_id in self.pubsub_manager.publish(&id.channel(), &payload).await {
+                notifications.push((conn_id, event.clone()));
+            }
+        }
+        notifications
+    }
+}
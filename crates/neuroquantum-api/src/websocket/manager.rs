@@ -5,12 +5,13 @@
 //! - Heartbeat monitoring with configurable timeouts
 //! - Broadcast support for all connections
 //! - Connection statistics and metrics
+//! - Bounded per-connection outbound queues with slow consumer handling
 //! - Graceful shutdown
 
 use std::sync::Arc;
 use std::time::Duration;
 
-use actix_ws::Session;
+use actix_ws::{CloseCode, CloseReason, Session};
 use dashmap::DashMap;
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
@@ -18,6 +19,7 @@ use tokio::time;
 use tracing::{debug, error, info, warn};
 
 use crate::websocket::metrics::ConnectionMetrics;
+use crate::websocket::outbound::{OutboundQueue, OverflowPolicy, DEFAULT_OUTBOUND_QUEUE_SIZE};
 use crate::websocket::types::{Connection, ConnectionId, ConnectionMetadata};
 
 /// Configuration for the connection manager
@@ -37,8 +39,18 @@ pub struct ConnectionConfig {
 
     /// Enable automatic heartbeat monitoring
     pub enable_heartbeat_monitor: bool,
+
+    /// Maximum number of messages queued for a connection before the
+    /// overflow policy applies
+    pub outbound_queue_size: usize,
+
+    /// What to do when a connection's outbound queue is full
+    pub overflow_policy: OverflowPolicy,
 }
 
+/// How long a slow consumer gets to accept its close frame
+const SLOW_CONSUMER_CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+
 impl Default for ConnectionConfig {
     fn default() -> Self {
         Self {
@@ -47,6 +59,8 @@ impl Default for ConnectionConfig {
             heartbeat_timeout: Duration::from_secs(90),
             idle_timeout: Duration::from_secs(300),
             enable_heartbeat_monitor: true,
+            outbound_queue_size: DEFAULT_OUTBOUND_QUEUE_SIZE,
+            overflow_policy: OverflowPolicy::default(),
         }
     }
 }
@@ -111,11 +125,17 @@ impl ConnectionManager {
             return Err(ConnectionError::MaxConnectionsReached);
         }
 
-        let connection = Arc::new(Connection::new(session, metadata.clone()));
+        let outbound = OutboundQueue::new(
+            self.config.outbound_queue_size,
+            self.config.overflow_policy,
+            self.metrics.clone(),
+        );
+        let connection = Arc::new(Connection::new(session, metadata.clone(), outbound));
         let conn_id = connection.id;
 
-        // Insert the connection
-        self.connections.insert(conn_id, connection);
+        // Insert the connection and start writing its queued messages
+        self.connections.insert(conn_id, connection.clone());
+        self.spawn_writer(connection);
 
         // Update metrics
         self.metrics.increment_total_connections();
@@ -135,6 +155,48 @@ impl ConnectionManager {
         Ok(conn_id)
     }
 
+    /// Spawn the task writing a connection's queued messages
+    ///
+    /// If the queue overflows the connection is removed and closed with a
+    /// policy violation close frame.
+    fn spawn_writer(&self, connection: Arc<Connection>) {
+        let connections = self.connections.clone();
+        let metrics = self.metrics.clone();
+
+        tokio::spawn(async move {
+            connection.run_writer().await;
+            if !connection.outbound.is_overflowed() {
+                return;
+            }
+
+            let conn_id = connection.id;
+            warn!(
+                "🐌 Disconnecting slow consumer {} (outbound queue of {} messages full)",
+                conn_id,
+                connection.outbound.capacity()
+            );
+            crate::metrics::record_websocket_connection("slow_consumer");
+            if connections.remove(&conn_id).is_some() {
+                metrics.decrement_active_connections();
+            }
+
+            let reason = CloseReason {
+                code: CloseCode::Policy,
+                description: Some("outbound queue full".to_string()),
+            };
+            match time::timeout(
+                SLOW_CONSUMER_CLOSE_TIMEOUT,
+                connection.close_with_reason(Some(reason)),
+            )
+            .await
+            {
+                | Ok(Ok(())) => {},
+                | Ok(Err(e)) => debug!("Slow consumer {} already closed: {:?}", conn_id, e),
+                | Err(_) => debug!("Slow consumer {} did not accept close frame", conn_id),
+            }
+        });
+    }
+
     /// Unregister a connection
     ///
     /// Removes the connection from the manager and updates metrics.
@@ -185,10 +247,7 @@ impl ConnectionManager {
             .get_connection(conn_id)
             .ok_or(ConnectionError::ConnectionNotFound)?;
 
-        connection
-            .send_text(message)
-            .await
-            .map_err(|_| ConnectionError::SendFailed)?;
+        connection.send_text(message).await?;
 
         self.metrics.increment_messages_sent(1);
 
@@ -197,7 +256,8 @@ impl ConnectionManager {
 
     /// Broadcast a message to all active connections
     ///
-    /// Returns the number of connections that successfully received the message.
+    /// Returns the number of connections the message was queued for. Slow
+    /// consumers whose queue overflows are disconnected by their writer task.
     pub async fn broadcast(&self, message: impl Into<String> + Clone) -> usize {
         let msg = message.into();
         let mut success_count = 0;
@@ -211,6 +271,7 @@ impl ConnectionManager {
                 | Ok(()) => {
                     success_count += 1;
                 },
+                | Err(ConnectionError::SlowConsumer) => {},
                 | Err(e) => {
                     warn!("Failed to broadcast to connection {}: {:?}", conn_id, e);
                     failed_connections.push(conn_id);
@@ -344,6 +405,23 @@ impl ConnectionManager {
         self.metrics.snapshot()
     }
 
+    /// Send a close frame with `reason` to every connection
+    ///
+    /// Connections stay registered until their handler sees the client
+    /// complete the closing handshake. Returns the number of connections
+    /// asked to close.
+    pub async fn close_all(&self, reason: Option<CloseReason>) -> usize {
+        let connections: Vec<Arc<Connection>> =
+            self.connections.iter().map(|entry| entry.clone()).collect();
+
+        for connection in &connections {
+            if let Err(e) = connection.close_with_reason(reason.clone()).await {
+                debug!("Connection {} already closed: {:?}", connection.id, e);
+            }
+        }
+        connections.len()
+    }
+
     /// Gracefully shut down the connection manager
     ///
     /// Closes all active connections and stops the heartbeat monitor.
@@ -386,4 +464,7 @@ pub enum ConnectionError {
 
     #[error("Connection closed")]
     ConnectionClosed,
+
+    #[error("Client is not reading messages fast enough")]
+    SlowConsumer,
 }
@@ -31,6 +31,12 @@ pub struct ConnectionMetrics {
 
     /// Total number of broadcast messages
     pub broadcast_messages: Arc<AtomicU64>,
+
+    /// Total outbound messages discarded because a client's queue was full
+    pub messages_dropped: Arc<AtomicU64>,
+
+    /// Total number of clients disconnected for not keeping up
+    pub slow_consumer_disconnects: Arc<AtomicU64>,
 }
 
 impl ConnectionMetrics {
@@ -45,6 +51,8 @@ impl ConnectionMetrics {
             connection_errors: Arc::new(AtomicU64::new(0)),
             heartbeat_failures: Arc::new(AtomicU64::new(0)),
             broadcast_messages: Arc::new(AtomicU64::new(0)),
+            messages_dropped: Arc::new(AtomicU64::new(0)),
+            slow_consumer_disconnects: Arc::new(AtomicU64::new(0)),
         }
     }
 
@@ -89,6 +97,17 @@ impl ConnectionMetrics {
         self.broadcast_messages.fetch_add(1, Ordering::Relaxed);
     }
 
+    /// Increment dropped outbound messages counter
+    pub fn increment_messages_dropped(&self, count: u64) {
+        self.messages_dropped.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Increment slow consumer disconnects counter
+    pub fn increment_slow_consumer_disconnects(&self) {
+        self.slow_consumer_disconnects
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
     /// Get a snapshot of current metrics
     #[must_use]
     pub fn snapshot(&self) -> MetricsSnapshot {
@@ -100,6 +119,8 @@ impl ConnectionMetrics {
             connection_errors: self.connection_errors.load(Ordering::Relaxed),
             heartbeat_failures: self.heartbeat_failures.load(Ordering::Relaxed),
             broadcast_messages: self.broadcast_messages.load(Ordering::Relaxed),
+            messages_dropped: self.messages_dropped.load(Ordering::Relaxed),
+            slow_consumer_disconnects: self.slow_consumer_disconnects.load(Ordering::Relaxed),
         }
     }
 
@@ -112,6 +133,8 @@ impl ConnectionMetrics {
         self.connection_errors.store(0, Ordering::Relaxed);
         self.heartbeat_failures.store(0, Ordering::Relaxed);
         self.broadcast_messages.store(0, Ordering::Relaxed);
+        self.messages_dropped.store(0, Ordering::Relaxed);
+        self.slow_consumer_disconnects.store(0, Ordering::Relaxed);
     }
 }
 
@@ -131,6 +154,8 @@ pub struct MetricsSnapshot {
     pub connection_errors: u64,
     pub heartbeat_failures: u64,
     pub broadcast_messages: u64,
+    pub messages_dropped: u64,
+    pub slow_consumer_disconnects: u64,
 }
 
 impl MetricsSnapshot {
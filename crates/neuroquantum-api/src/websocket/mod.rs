@@ -8,7 +8,9 @@
 //! - **Connection Manager**: Handles client lifecycle (register, unregister, heartbeat)
 //! - **Pub/Sub Channels**: Topic-based message broadcasting
 //! - **Query Streaming**: Incremental result delivery with backpressure
+//! - **Live Queries**: Result set change notifications as transactions commit
 //! - **Flow Control**: Automatic rate limiting and buffer management
+//! - **Outbound Queues**: Bounded per-connection send queues that shed slow consumers
 //!
 //! # Example
 //!
@@ -31,8 +33,10 @@
 
 pub mod flow_control;
 pub mod handler;
+pub mod live_query;
 pub mod manager;
 pub mod metrics;
+pub mod outbound;
 pub mod pubsub;
 pub mod streaming;
 pub mod types;
@@ -45,8 +49,10 @@ pub use flow_control::{
     FlowController, FlowRecommendation, FlowState,
 };
 pub use handler::{ServiceStats, WebSocketService, WsMessage, WsResponse};
+pub use live_query::{LiveQuery, LiveQueryEvent, LiveQueryId, LiveQueryRegistry};
 pub use manager::{ConnectionConfig, ConnectionManager};
 pub use metrics::ConnectionMetrics;
+pub use outbound::{OutboundQueue, OverflowPolicy, PushOutcome};
 pub use pubsub::{ChannelId, ChannelStats, PubSubManager, PubSubStats};
 pub use streaming::{
     QueryProgress, QueryResultBatch, QueryStreamId, QueryStreamStatus, QueryStreamer, StreamStats,
@@ -0,0 +1,200 @@
+//! Bounded outbound message queues
+//!
+//! Messages for a client are queued per connection and written to its socket
+//! by a dedicated writer task, so a client that stops reading cannot stall
+//! broadcasts to every other connection. The queue holds at most
+//! [`ConnectionConfig::outbound_queue_size`](crate::websocket::ConnectionConfig)
+//! messages; once it is full the connection's [`OverflowPolicy`] either
+//! discards the oldest queued message or disconnects the client as a slow
+//! consumer. Both events are counted in [`ConnectionMetrics`].
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::Notify;
+
+use crate::websocket::metrics::ConnectionMetrics;
+
+/// Default number of messages queued for a connection
+pub const DEFAULT_OUTBOUND_QUEUE_SIZE: usize = 1024;
+
+/// What to do when a connection's outbound queue is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverflowPolicy {
+    /// Close the connection with a close frame, discarding queued messages
+    #[default]
+    Disconnect,
+
+    /// Discard the oldest queued message to make room for the new one
+    DropOldest,
+}
+
+/// Result of queuing a message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushOutcome {
+    /// The message was queued
+    Queued,
+
+    /// The message was queued after discarding the oldest one
+    DroppedOldest,
+
+    /// The queue was full and has been closed; the client is disconnected
+    Overflowed,
+
+    /// The queue was already closed and the message was discarded
+    Closed,
+}
+
+/// Bounded queue of text messages waiting to be written to one client
+#[derive(Debug)]
+pub struct OutboundQueue {
+    messages: Mutex<VecDeque<String>>,
+    capacity: usize,
+    policy: OverflowPolicy,
+    metrics: Arc<ConnectionMetrics>,
+    closed: AtomicBool,
+    overflowed: AtomicBool,
+    message_ready: Notify,
+    closing: Notify,
+}
+
+impl OutboundQueue {
+    /// Create a queue holding at most `capacity` messages
+    ///
+    /// Dropped messages and slow consumer disconnects are recorded in
+    /// `metrics`.
+    #[must_use]
+    pub fn new(capacity: usize, policy: OverflowPolicy, metrics: Arc<ConnectionMetrics>) -> Self {
+        Self {
+            messages: Mutex::new(VecDeque::new()),
+            capacity: capacity.max(1),
+            policy,
+            metrics,
+            closed: AtomicBool::new(false),
+            overflowed: AtomicBool::new(false),
+            message_ready: Notify::new(),
+            closing: Notify::new(),
+        }
+    }
+
+    /// Queue a message for the writer task
+    pub fn push(&self, message: String) -> PushOutcome {
+        let mut messages = self.lock();
+        if self.is_closed() {
+            return PushOutcome::Closed;
+        }
+
+        let outcome = if messages.len() < self.capacity {
+            PushOutcome::Queued
+        } else {
+            match self.policy {
+                | OverflowPolicy::DropOldest => {
+                    messages.pop_front();
+                    self.metrics.increment_messages_dropped(1);
+                    PushOutcome::DroppedOldest
+                },
+                | OverflowPolicy::Disconnect => {
+                    let discarded = messages.len() as u64 + 1;
+                    messages.clear();
+                    self.overflowed.store(true, Ordering::Release);
+                    self.closed.store(true, Ordering::Release);
+                    drop(messages);
+
+                    self.metrics.increment_messages_dropped(discarded);
+                    self.metrics.increment_slow_consumer_disconnects();
+                    self.wake();
+                    return PushOutcome::Overflowed;
+                },
+            }
+        };
+
+        messages.push_back(message);
+        drop(messages);
+        self.message_ready.notify_one();
+        outcome
+    }
+
+    /// Wait for the next message to write
+    ///
+    /// Returns `None` once the queue is closed; messages still queued at that
+    /// point are not delivered.
+    pub async fn pop(&self) -> Option<String> {
+        loop {
+            let ready = self.message_ready.notified();
+            if self.is_closed() {
+                return None;
+            }
+            if let Some(message) = self.lock().pop_front() {
+                return Some(message);
+            }
+            ready.await;
+        }
+    }
+
+    /// Wait until the queue is closed
+    pub async fn closed(&self) {
+        loop {
+            let closing = self.closing.notified();
+            if self.is_closed() {
+                return;
+            }
+            closing.await;
+        }
+    }
+
+    /// Close the queue, rejecting further messages
+    pub fn close(&self) {
+        let messages = self.lock();
+        self.closed.store(true, Ordering::Release);
+        drop(messages);
+        self.wake();
+    }
+
+    /// Check if the queue is closed
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
+    /// Check if the queue was closed because the client fell too far behind
+    #[must_use]
+    pub fn is_overflowed(&self) -> bool {
+        self.overflowed.load(Ordering::Acquire)
+    }
+
+    /// Number of queued messages
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Check if no messages are queued
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Maximum number of queued messages
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Policy applied when the queue is full
+    #[must_use]
+    pub const fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    fn lock(&self) -> MutexGuard<'_, VecDeque<String>> {
+        self.messages.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn wake(&self) {
+        self.message_ready.notify_one();
+        self.closing.notify_waiters();
+    }
+}
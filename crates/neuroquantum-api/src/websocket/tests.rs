@@ -87,6 +87,25 @@ async fn test_connection_metadata_update_activity() {
     assert!(metadata.last_activity > old_timestamp);
 }
 
+#[test]
+fn test_connection_metadata_authorizes_queries() {
+    use crate::permissions::ScopeRule;
+
+    let mut metadata = create_mock_metadata(Some("reader"));
+    assert!(metadata.authorize_query("SELECT * FROM orders").is_err());
+
+    metadata.permissions = vec!["read".to_string()];
+    assert!(metadata.authorize_query("SELECT * FROM orders").is_ok());
+    assert!(metadata
+        .authorize_query("DELETE FROM orders WHERE id = 1")
+        .is_err());
+
+    // Scope rules narrow the tables the permissions apply to
+    metadata.scopes = vec![ScopeRule::new("orders", &["read"])];
+    assert!(metadata.authorize_query("SELECT * FROM orders").is_ok());
+    assert!(metadata.authorize_query("SELECT * FROM users").is_err());
+}
+
 #[tokio::test]
 async fn test_metrics_increment_operations() {
     use crate::websocket::metrics::ConnectionMetrics;
@@ -140,6 +159,8 @@ async fn test_metrics_snapshot_calculations() {
         connection_errors: 5,
         heartbeat_failures: 3,
         broadcast_messages: 10,
+        messages_dropped: 0,
+        slow_consumer_disconnects: 0,
     };
 
     // Message rate: (500 + 300) / 10.0 = 80.0 messages/sec
@@ -255,6 +276,7 @@ async fn test_connection_error_types() {
         ConnectionError::SendFailed,
         ConnectionError::SerializationFailed("test".to_string()),
         ConnectionError::ConnectionClosed,
+        ConnectionError::SlowConsumer,
     ];
 
     // Test that all errors can be displayed
@@ -321,3 +343,68 @@ async fn test_connection_manager_concurrent_access() {
     // Manager should still be in a valid state
     assert_eq!(manager.connection_count(), 0);
 }
+
+#[tokio::test]
+async fn test_outbound_queue_drop_oldest_without_reader() {
+    use std::sync::Arc;
+
+    use crate::websocket::metrics::ConnectionMetrics;
+    use crate::websocket::outbound::{OutboundQueue, OverflowPolicy, PushOutcome};
+
+    let metrics = Arc::new(ConnectionMetrics::new());
+    let queue = OutboundQueue::new(3, OverflowPolicy::DropOldest, metrics.clone());
+
+    // Nobody drains the queue, so it keeps only the newest messages
+    for i in 0..10 {
+        let outcome = queue.push(format!("message {i}"));
+        let expected = if i < 3 {
+            PushOutcome::Queued
+        } else {
+            PushOutcome::DroppedOldest
+        };
+        assert_eq!(outcome, expected);
+    }
+    assert_eq!(queue.len(), 3);
+    assert_eq!(metrics.snapshot().messages_dropped, 7);
+    assert_eq!(metrics.snapshot().slow_consumer_disconnects, 0);
+    assert_eq!(queue.pop().await.as_deref(), Some("message 7"));
+
+    queue.close();
+    assert_eq!(queue.pop().await, None);
+    assert_eq!(queue.push("late".to_string()), PushOutcome::Closed);
+}
+
+#[tokio::test]
+async fn test_outbound_queue_disconnects_slow_consumer() {
+    use std::sync::Arc;
+
+    use crate::websocket::metrics::ConnectionMetrics;
+    use crate::websocket::outbound::{OutboundQueue, OverflowPolicy, PushOutcome};
+
+    let metrics = Arc::new(ConnectionMetrics::new());
+    let queue = Arc::new(OutboundQueue::new(
+        2,
+        OverflowPolicy::Disconnect,
+        metrics.clone(),
+    ));
+    let closed = tokio::spawn({
+        let queue = queue.clone();
+        async move { queue.closed().await }
+    });
+
+    assert_eq!(queue.push("a".to_string()), PushOutcome::Queued);
+    assert_eq!(queue.push("b".to_string()), PushOutcome::Queued);
+    assert_eq!(queue.push("c".to_string()), PushOutcome::Overflowed);
+
+    tokio::time::timeout(Duration::from_secs(1), closed)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(queue.is_overflowed());
+    assert!(queue.is_empty());
+    assert_eq!(queue.pop().await, None);
+
+    let snapshot = metrics.snapshot();
+    assert_eq!(snapshot.messages_dropped, 3);
+    assert_eq!(snapshot.slow_consumer_disconnects, 1);
+}
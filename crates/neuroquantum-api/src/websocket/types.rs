@@ -10,6 +10,12 @@ use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
+use crate::error::ApiError;
+use crate::handlers::{check_query_scopes, required_permission_for_query};
+use crate::permissions::ScopeRule;
+use crate::websocket::manager::ConnectionError;
+use crate::websocket::outbound::{OutboundQueue, PushOutcome};
+
 /// Unique identifier for a WebSocket connection
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ConnectionId(Uuid);
@@ -55,6 +61,14 @@ pub struct ConnectionMetadata {
     /// User-Agent header
     pub user_agent: Option<String>,
 
+    /// Permissions of the authenticated principal
+    #[serde(default)]
+    pub permissions: Vec<String>,
+
+    /// Table scope rules of the API key; empty for JWT principals
+    #[serde(default)]
+    pub scopes: Vec<ScopeRule>,
+
     /// Connection timestamp
     pub connected_at: chrono::DateTime<chrono::Utc>,
 
@@ -75,6 +89,8 @@ impl ConnectionMetadata {
             auth_token: None,
             remote_addr,
             user_agent: None,
+            permissions: Vec::new(),
+            scopes: Vec::new(),
             connected_at: now,
             last_activity: now,
             custom: std::collections::HashMap::new(),
@@ -86,6 +102,24 @@ impl ConnectionMetadata {
         self.last_activity = chrono::Utc::now();
     }
 
+    /// Check that the principal may run `query`
+    ///
+    /// Requires the read or write permission the statement needs, and checks
+    /// the tables it touches against the API key's scope rules.
+    pub(crate) fn authorize_query(&self, query: &str) -> Result<(), ApiError> {
+        let required = required_permission_for_query(query);
+        if !self
+            .permissions
+            .iter()
+            .any(|p| p == required || p == "admin")
+        {
+            return Err(ApiError::Forbidden(format!(
+                "{required} permission required for this query"
+            )));
+        }
+        check_query_scopes(&self.permissions, &self.scopes, query)
+    }
+
     /// Check if connection has been idle for longer than the given duration
     #[must_use]
     pub fn is_idle(&self, timeout: Duration) -> bool {
@@ -124,6 +158,9 @@ pub struct Connection {
     /// Actix WebSocket session for sending messages
     pub session: Arc<RwLock<Session>>,
 
+    /// Messages waiting to be written to the session
+    pub outbound: Arc<OutboundQueue>,
+
     /// Connection metadata
     pub metadata: Arc<RwLock<ConnectionMetadata>>,
 
@@ -143,10 +180,11 @@ pub struct Connection {
 impl Connection {
     /// Create a new connection
     #[must_use]
-    pub fn new(session: Session, metadata: ConnectionMetadata) -> Self {
+    pub fn new(session: Session, metadata: ConnectionMetadata, outbound: OutboundQueue) -> Self {
         Self {
             id: ConnectionId::new(),
             session: Arc::new(RwLock::new(session)),
+            outbound: Arc::new(outbound),
             metadata: Arc::new(RwLock::new(metadata)),
             status: Arc::new(RwLock::new(ConnectionStatus::Active)),
             last_heartbeat: Arc::new(RwLock::new(Instant::now())),
@@ -155,18 +193,37 @@ impl Connection {
         }
     }
 
-    /// Send a text message to the client
-    pub async fn send_text(&self, text: impl Into<String>) -> Result<(), actix_ws::Closed> {
-        let mut session = self.session.write().await;
-        let text_string = text.into();
-        let result = session.text(text_string).await;
-
-        if result.is_ok() {
-            let mut count = self.messages_sent.write().await;
-            *count += 1;
+    /// Queue a text message for the client
+    ///
+    /// Returns [`ConnectionError::SlowConsumer`] if the outbound queue
+    /// overflowed and the client is being disconnected.
+    pub async fn send_text(&self, text: impl Into<String>) -> Result<(), ConnectionError> {
+        match self.outbound.push(text.into()) {
+            | PushOutcome::Queued | PushOutcome::DroppedOldest => Ok(()),
+            | PushOutcome::Overflowed => Err(ConnectionError::SlowConsumer),
+            | PushOutcome::Closed => Err(ConnectionError::ConnectionClosed),
         }
+    }
 
-        result
+    /// Write queued messages to the session until the queue is closed
+    ///
+    /// A write blocked on a client that stopped reading is abandoned as soon
+    /// as the queue closes.
+    pub async fn run_writer(&self) {
+        let mut session = self.session.read().await.clone();
+        while let Some(text) = self.outbound.pop().await {
+            tokio::select! {
+                result = session.text(text) => {
+                    if result.is_err() {
+                        self.outbound.close();
+                        break;
+                    }
+                    let mut count = self.messages_sent.write().await;
+                    *count += 1;
+                },
+                () = self.outbound.closed() => break,
+            }
+        }
     }
 
     /// Send a JSON message to the client
@@ -214,10 +271,19 @@ impl Connection {
 
     /// Close the connection gracefully
     pub async fn close(&self) -> Result<(), actix_ws::Closed> {
+        self.close_with_reason(None).await
+    }
+
+    /// Close the connection, sending `reason` in the close frame
+    pub async fn close_with_reason(
+        &self,
+        reason: Option<actix_ws::CloseReason>,
+    ) -> Result<(), actix_ws::Closed> {
         self.set_status(ConnectionStatus::Closing).await;
+        self.outbound.close();
 
         let session = self.session.write().await;
-        let result = session.clone().close(None).await;
+        let result = session.clone().close(reason).await;
 
         drop(session); // Release lock before updating status
         self.set_status(ConnectionStatus::Closed).await;
@@ -49,6 +49,7 @@ fn create_test_schema(table_name: &str) -> neuroquantum_core::storage::TableSche
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             neuroquantum_core::storage::ColumnDefinition {
                 name: "name".to_string(),
@@ -56,6 +57,7 @@ fn create_test_schema(table_name: &str) -> neuroquantum_core::storage::TableSche
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             neuroquantum_core::storage::ColumnDefinition {
                 name: "value".to_string(),
@@ -63,6 +65,7 @@ fn create_test_schema(table_name: &str) -> neuroquantum_core::storage::TableSche
                 nullable: true,
                 default_value: Some(neuroquantum_core::storage::Value::Integer(0)),
                 auto_increment: false,
+                ..Default::default()
             },
             neuroquantum_core::storage::ColumnDefinition {
                 name: "active".to_string(),
@@ -70,6 +73,7 @@ fn create_test_schema(table_name: &str) -> neuroquantum_core::storage::TableSche
                 nullable: true,
                 default_value: Some(neuroquantum_core::storage::Value::Boolean(true)),
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         created_at: chrono::Utc::now(),
@@ -77,6 +81,7 @@ fn create_test_schema(table_name: &str) -> neuroquantum_core::storage::TableSche
         auto_increment_columns: std::collections::HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     }
 }
 
@@ -249,6 +254,7 @@ async fn test_create_table_with_various_column_types() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             neuroquantum_core::storage::ColumnDefinition {
                 name: "text_col".to_string(),
@@ -256,6 +262,7 @@ async fn test_create_table_with_various_column_types() {
                 nullable: true,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             neuroquantum_core::storage::ColumnDefinition {
                 name: "float_col".to_string(),
@@ -263,6 +270,7 @@ async fn test_create_table_with_various_column_types() {
                 nullable: true,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             neuroquantum_core::storage::ColumnDefinition {
                 name: "bool_col".to_string(),
@@ -270,6 +278,7 @@ async fn test_create_table_with_various_column_types() {
                 nullable: false,
                 default_value: Some(neuroquantum_core::storage::Value::Boolean(false)),
                 auto_increment: false,
+                ..Default::default()
             },
             neuroquantum_core::storage::ColumnDefinition {
                 name: "binary_col".to_string(),
@@ -277,6 +286,7 @@ async fn test_create_table_with_various_column_types() {
                 nullable: true,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         created_at: chrono::Utc::now(),
@@ -284,6 +294,7 @@ async fn test_create_table_with_various_column_types() {
         auto_increment_columns: std::collections::HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
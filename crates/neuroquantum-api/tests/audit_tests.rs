@@ -0,0 +1,331 @@
+//! Tests for the tamper-evident audit log and `GET /api/v1/audit`
+
+use actix_web::http::StatusCode;
+use actix_web::test;
+use neuroquantum_api::audit::{
+    read_entries, verify_chain, AuditChainError, AuditDetails, AuditEntry, AuditFilter, AuditLog,
+    AuditOutcome, GENESIS_HASH, MAX_RETAINED_ENTRIES,
+};
+use neuroquantum_api::permissions::Permission;
+use neuroquantum_api::{configure_app, ApiConfig, AppState};
+use neuroquantum_core::NeuroQuantumDBBuilder;
+use serde_json::{json, Value};
+
+async fn write_entries(path: &std::path::Path, entries: &[AuditEntry]) {
+    let lines: Vec<String> = entries
+        .iter()
+        .map(|entry| serde_json::to_string(entry).unwrap())
+        .collect();
+    tokio::fs::write(path, lines.join("\n") + "\n")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_audit_chain_verifies_and_persists() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("audit").join("audit.log");
+
+    let log = AuditLog::open(&path).await.unwrap();
+    let first = log
+        .record("alice", "POST", "/api/v1/query", 200)
+        .await
+        .unwrap();
+    log.record("bob", "DELETE", "/api/v1/tables/users/data", 403)
+        .await
+        .unwrap();
+    log.record("alice", "GET", "/api/v1/tables/users/export/csv", 200)
+        .await
+        .unwrap();
+    assert_eq!(first.sequence, 0);
+    assert_eq!(first.previous_hash, GENESIS_HASH);
+    assert_eq!(log.verify().await, Ok(()));
+    drop(log);
+
+    // Reopening continues the chain from the last persisted entry
+    let log = AuditLog::open(&path).await.unwrap();
+    let fourth = log
+        .record("carol", "POST", "/api/v1/query", 500)
+        .await
+        .unwrap();
+    assert_eq!(fourth.sequence, 3);
+    assert_eq!(fourth.outcome, AuditOutcome::Failure);
+
+    let entries = read_entries(&path).await.unwrap();
+    assert_eq!(entries.len(), 4);
+    assert_eq!(fourth.previous_hash, entries[2].hash);
+    assert_eq!(verify_chain(&entries), Ok(()));
+    assert_eq!(log.verify().await, Ok(()));
+}
+
+#[tokio::test]
+async fn test_altering_past_entry_breaks_verification() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("audit.log");
+
+    let log = AuditLog::open(&path).await.unwrap();
+    for principal in ["alice", "bob", "carol"] {
+        log.record(principal, "POST", "/api/v1/query", 200)
+            .await
+            .unwrap();
+    }
+    let original = read_entries(&path).await.unwrap();
+
+    // Rewriting an entry invalidates its own hash
+    let mut tampered = original.clone();
+    tampered[1].principal = "mallory".to_string();
+    write_entries(&path, &tampered).await;
+    assert_eq!(
+        log.verify().await,
+        Err(AuditChainError::HashMismatch { sequence: 1 })
+    );
+
+    // Recomputing the hash breaks the link from the next entry
+    tampered[1].hash = tampered[1].compute_hash();
+    write_entries(&path, &tampered).await;
+    assert_eq!(
+        log.verify().await,
+        Err(AuditChainError::BrokenLink { sequence: 2 })
+    );
+
+    // Removing an entry leaves a gap in the sequence
+    let mut removed = original.clone();
+    removed.remove(1);
+    assert_eq!(
+        verify_chain(&removed),
+        Err(AuditChainError::OutOfSequence {
+            expected: 1,
+            found: 2
+        })
+    );
+
+    write_entries(&path, &original).await;
+    assert_eq!(log.verify().await, Ok(()));
+}
+
+#[tokio::test]
+async fn test_audit_query_filters() {
+    let log = AuditLog::in_memory();
+    log.record("alice", "POST", "/api/v1/query", 200)
+        .await
+        .unwrap();
+    log.record("bob", "POST", "/api/v1/tables/users/data", 403)
+        .await
+        .unwrap();
+    log.record("alice", "GET", "/api/v1/tables/users/export/csv", 200)
+        .await
+        .unwrap();
+
+    let all = log.query(&AuditFilter::default()).await.unwrap();
+    assert_eq!(
+        all.iter().map(|e| e.sequence).collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+
+    let alice = log
+        .query(&AuditFilter {
+            principal: Some("alice".to_string()),
+            ..AuditFilter::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(alice.len(), 2);
+
+    let tables = log
+        .query(&AuditFilter {
+            resource: Some("/api/v1/tables/users".to_string()),
+            outcome: Some(AuditOutcome::Failure),
+            ..AuditFilter::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].principal, "bob");
+
+    // The limit keeps the most recent entries
+    let latest = log
+        .query(&AuditFilter {
+            limit: Some(1),
+            ..AuditFilter::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(latest[0].sequence, 2);
+    assert_eq!(log.verify().await, Ok(()));
+}
+
+#[tokio::test]
+async fn test_audit_records_statement_and_tables() {
+    let log = AuditLog::in_memory();
+    let details = AuditDetails::for_statement(
+        "SELECT orders.id FROM orders INNER JOIN customers ON orders.customer_id = customers.id",
+    );
+    assert_eq!(details.tables, vec!["orders", "customers"]);
+    log.record_details("alice", "POST", "/api/v1/query", 200, Some(&details))
+        .await
+        .unwrap();
+    log.record("alice", "GET", "/api/v1/stats/performance", 200)
+        .await
+        .unwrap();
+
+    let customers = log
+        .query(&AuditFilter {
+            table: Some("customers".to_string()),
+            ..AuditFilter::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(customers.len(), 1);
+    assert_eq!(
+        customers[0].statement.as_deref(),
+        Some(details.statement.as_str())
+    );
+
+    // The statement is covered by the hash chain
+    let mut tampered = customers[0].clone();
+    tampered.statement = Some("SELECT 1".to_string());
+    assert_ne!(tampered.compute_hash(), tampered.hash);
+    assert_eq!(log.verify().await, Ok(()));
+
+    // So is adding a statement or tables to an entry recorded without one
+    let all = log.query(&AuditFilter::default()).await.unwrap();
+    let mut tampered = all[1].clone();
+    assert_eq!(tampered.statement, None);
+    tampered.statement = Some(String::new());
+    assert_ne!(tampered.compute_hash(), tampered.hash);
+    let mut tampered = all[1].clone();
+    tampered.tables = vec!["customers".to_string()];
+    assert_ne!(tampered.compute_hash(), tampered.hash);
+}
+
+#[tokio::test]
+async fn test_audit_query_reads_entries_beyond_memory() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("audit.log");
+
+    let mut entries: Vec<AuditEntry> = Vec::new();
+    for sequence in 0..MAX_RETAINED_ENTRIES as u64 + 2 {
+        let principal = if sequence < 2 { "early" } else { "recent" };
+        let mut entry = AuditEntry {
+            sequence,
+            timestamp: chrono::Utc::now(),
+            principal: principal.to_string(),
+            action: "POST".to_string(),
+            resource: "/api/v1/query".to_string(),
+            outcome: AuditOutcome::Success,
+            status: 200,
+            statement: None,
+            tables: Vec::new(),
+            previous_hash: entries
+                .last()
+                .map_or_else(|| GENESIS_HASH.to_string(), |last| last.hash.clone()),
+            hash: String::new(),
+        };
+        entry.hash = entry.compute_hash();
+        entries.push(entry);
+    }
+    write_entries(&path, &entries).await;
+
+    // Both early entries fell out of the in-memory window
+    let log = AuditLog::open(&path).await.unwrap();
+    let early = log
+        .query(&AuditFilter {
+            principal: Some("early".to_string()),
+            ..AuditFilter::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        early.iter().map(|e| e.sequence).collect::<Vec<_>>(),
+        vec![0, 1]
+    );
+
+    let latest = log
+        .query(&AuditFilter {
+            limit: Some(3),
+            ..AuditFilter::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(latest[0].sequence, MAX_RETAINED_ENTRIES as u64 - 1);
+}
+
+#[actix_web::test]
+async fn test_audit_endpoint_records_authenticated_requests() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let audit_dir = tempfile::tempdir().unwrap();
+    let db = NeuroQuantumDBBuilder::new()
+        .storage_path(temp_dir.path().to_path_buf())
+        .build()
+        .await
+        .expect("Failed to initialize database");
+    let mut config = ApiConfig::default();
+    config.audit.path = Some(
+        audit_dir
+            .path()
+            .join("audit.log")
+            .to_string_lossy()
+            .into_owned(),
+    );
+    let state = AppState::with_database(config, db)
+        .await
+        .expect("Failed to create app state");
+    let admin_key = state
+        .auth_service
+        .clone()
+        .generate_api_key(
+            "audit-admin".to_string(),
+            Permission::admin_permissions(),
+            Some(1),
+            None,
+        )
+        .unwrap()
+        .key;
+    let reader_key = state
+        .auth_service
+        .clone()
+        .generate_api_key(
+            "audit-reader".to_string(),
+            Permission::read_only(),
+            Some(1),
+            None,
+        )
+        .unwrap()
+        .key;
+    let app = test::init_service(configure_app(state)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/query")
+        .insert_header(("X-API-Key", reader_key.as_str()))
+        .set_json(json!({ "query": "SELECT * FROM missing_table" }))
+        .to_request();
+    test::call_service(&app, req).await;
+
+    // Only admins may read the audit log
+    let req = test::TestRequest::get()
+        .uri("/api/v1/audit")
+        .insert_header(("X-API-Key", reader_key.as_str()))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/audit?principal=audit-reader")
+        .insert_header(("X-API-Key", admin_key.as_str()))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["chain_valid"], true);
+    let entries = body["data"]["entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["action"], "POST");
+    assert_eq!(entries[0]["resource"], "/api/v1/query");
+    assert_eq!(entries[0]["outcome"], "failure");
+    assert_eq!(entries[0]["status"], 404);
+    assert_eq!(entries[0]["statement"], "SELECT * FROM missing_table");
+    assert_eq!(entries[0]["tables"], json!(["missing_table"]));
+    assert_eq!(entries[1]["resource"], "/api/v1/audit");
+    assert!(entries[1].get("statement").is_none());
+    assert_eq!(entries[1]["status"], 403);
+}
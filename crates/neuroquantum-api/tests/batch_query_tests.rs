@@ -0,0 +1,213 @@
+//! Tests for the batch query endpoint (`POST /api/v1/query/batch`)
+//!
+//! These tests exercise the handler through actix-web's test service with an
+//! API key injected into the request extensions, mirroring what the auth
+//! middleware does in production.
+
+mod common;
+
+use actix_web::dev::Service;
+use actix_web::{test, web, App, HttpMessage};
+use neuroquantum_api::handlers;
+use neuroquantum_api::permissions::Permission;
+use serde_json::{json, Value};
+
+use common::{create_test_state, test_api_key};
+
+/// Create application state backed by a temporary database
+macro_rules! batch_app {
+    ($state:expr, $permissions:expr) => {{
+        let permissions: Vec<String> = $permissions;
+        test::init_service(
+            App::new()
+                .app_data(web::Data::new($state.clone()))
+                .wrap_fn(move |req, srv| {
+                    req.extensions_mut()
+                        .insert(test_api_key(permissions.clone()));
+                    srv.call(req)
+                })
+                .route(
+                    "/api/v1/query/batch",
+                    web::post().to(handlers::execute_batch_query),
+                ),
+        )
+        .await
+    }};
+}
+
+#[actix_web::test]
+async fn test_batch_results_align_positionally() {
+    let (state, _temp_dir) = create_test_state().await;
+    let app = batch_app!(state, Permission::read_write());
+
+    let body = json!({
+        "queries": [
+            { "sql": "CREATE TABLE batch_users (id INTEGER PRIMARY KEY, name TEXT)" },
+            { "sql": "INSERT INTO batch_users (id, name) VALUES ($1, $2)", "params": [1, "Ada"] },
+            { "sql": "SELECT * FROM batch_users WHERE id = $1", "params": [1] }
+        ]
+    });
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/query/batch")
+        .set_json(&body)
+        .to_request();
+    let resp: Value = test::call_and_read_body_json(&app, req).await;
+
+    let results = resp["data"]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 3);
+    for (idx, result) in results.iter().enumerate() {
+        assert_eq!(result["index"], idx);
+        assert_eq!(result["success"], true, "statement {idx} failed: {result}");
+    }
+
+    assert_eq!(results[1]["result"]["rows_affected"], 1);
+    let rows = results[2]["result"]["rows"].as_array().unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["name"], "Ada");
+    assert_eq!(resp["data"]["succeeded"], 3);
+    assert_eq!(resp["data"]["failed"], 0);
+}
+
+#[actix_web::test]
+async fn test_batch_failure_does_not_abort_non_transactional_batch() {
+    let (state, _temp_dir) = create_test_state().await;
+    let app = batch_app!(state, Permission::read_write());
+
+    let body = json!({
+        "queries": [
+            { "sql": "CREATE TABLE batch_items (id INTEGER PRIMARY KEY, label TEXT)" },
+            { "sql": "SELECT * FROM table_that_does_not_exist" },
+            { "sql": "INSERT INTO batch_items (id, label) VALUES (1, 'first')" }
+        ]
+    });
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/query/batch")
+        .set_json(&body)
+        .to_request();
+    let resp: Value = test::call_and_read_body_json(&app, req).await;
+
+    let results = resp["data"]["results"].as_array().unwrap();
+    assert_eq!(results[0]["success"], true);
+    assert_eq!(results[1]["success"], false);
+    assert!(results[1]["error"].is_string());
+    assert_eq!(results[2]["success"], true);
+    assert_eq!(resp["data"]["rolled_back"], false);
+}
+
+#[actix_web::test]
+async fn test_transactional_batch_rolls_back_on_failure() {
+    let (state, _temp_dir) = create_test_state().await;
+    let app = batch_app!(state, Permission::read_write());
+
+    let setup = json!({
+        "queries": [{ "sql": "CREATE TABLE batch_accounts (id INTEGER PRIMARY KEY, balance INTEGER)" }]
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/v1/query/batch")
+        .set_json(&setup)
+        .to_request();
+    let _: Value = test::call_and_read_body_json(&app, req).await;
+
+    let body = json!({
+        "transactional": true,
+        "queries": [
+            { "sql": "INSERT INTO batch_accounts (id, balance) VALUES (1, 100)" },
+            { "sql": "INSERT INTO missing_table (id) VALUES (1)" },
+            { "sql": "INSERT INTO batch_accounts (id, balance) VALUES (2, 200)" }
+        ]
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/v1/query/batch")
+        .set_json(&body)
+        .to_request();
+    let resp: Value = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(resp["data"]["rolled_back"], true);
+    assert_eq!(resp["data"]["succeeded"], 0);
+    let results = resp["data"]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 3);
+    assert!(results.iter().all(|r| r["success"] == false));
+
+    let verify = json!({
+        "queries": [{ "sql": "SELECT * FROM batch_accounts" }]
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/v1/query/batch")
+        .set_json(&verify)
+        .to_request();
+    let resp: Value = test::call_and_read_body_json(&app, req).await;
+    let rows = &resp["data"]["results"][0]["result"]["rows"];
+    assert!(rows.is_null() || rows.as_array().unwrap().is_empty());
+}
+
+#[actix_web::test]
+async fn test_batch_requires_write_permission_for_writes() {
+    let (state, _temp_dir) = create_test_state().await;
+    let app = batch_app!(state, Permission::read_only());
+
+    let body = json!({
+        "queries": [
+            { "sql": "SELECT 1" },
+            { "sql": "CREATE TABLE forbidden (id INTEGER PRIMARY KEY)" }
+        ]
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/v1/query/batch")
+        .set_json(&body)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::FORBIDDEN);
+}
+
+#[actix_web::test]
+async fn test_transactional_batch_runs_at_requested_isolation_level() {
+    let (state, _temp_dir) = create_test_state().await;
+    let app = batch_app!(state, Permission::read_write());
+
+    let setup = json!({
+        "queries": [{ "sql": "CREATE TABLE batch_ledger (id INTEGER PRIMARY KEY, amount INTEGER)" }]
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/v1/query/batch")
+        .set_json(&setup)
+        .to_request();
+    let _: Value = test::call_and_read_body_json(&app, req).await;
+
+    let body = json!({
+        "transactional": true,
+        "isolation_level": "serializable",
+        "queries": [
+            { "sql": "INSERT INTO batch_ledger (id, amount) VALUES (1, 100)" },
+            { "sql": "SELECT * FROM batch_ledger" }
+        ]
+    });
+    let req = test::TestRequest::post()
+        .uri("/api/v1/query/batch")
+        .set_json(&body)
+        .to_request();
+    let resp: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp["data"]["succeeded"], 2, "batch failed: {resp}");
+    assert_eq!(resp["data"]["rolled_back"], false);
+
+    // Unknown levels and levels without a transaction are rejected up front
+    for body in [
+        json!({
+            "transactional": true,
+            "isolation_level": "CHAOTIC",
+            "queries": [{ "sql": "SELECT * FROM batch_ledger" }]
+        }),
+        json!({
+            "isolation_level": "SERIALIZABLE",
+            "queries": [{ "sql": "SELECT * FROM batch_ledger" }]
+        }),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/api/v1/query/batch")
+            .set_json(&body)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+    }
+}
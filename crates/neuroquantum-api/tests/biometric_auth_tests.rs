@@ -3,9 +3,14 @@
 //! These tests validate EEG signal processing, feature extraction,
 //! user enrollment, and authentication.
 
+use std::collections::HashMap;
 use std::f32::consts::PI;
 
-use neuroquantum_api::biometric_auth::{ButterworthDesign, EEGAuthService, EEGProcessor};
+use std::time::Duration;
+
+use neuroquantum_api::biometric_auth::{
+    ButterworthDesign, EEGAuthConfig, EEGAuthService, EEGError, EEGProcessor, EEG_FEATURE_VERSION,
+};
 
 fn generate_mock_eeg_signal(
     sampling_rate: f32,
@@ -31,6 +36,15 @@ fn generate_mock_eeg_signal(
     signal
 }
 
+/// Add the stimulus response a challenge asks for to a captured signal
+fn with_marker(signal: &[f32], sampling_rate: f32, frequency: f32) -> Vec<f32> {
+    signal
+        .iter()
+        .enumerate()
+        .map(|(i, x)| x + 0.5 * (2.0 * PI * frequency * i as f32 / sampling_rate).sin())
+        .collect()
+}
+
 #[test]
 fn test_eeg_processor_creation() {
     let processor = EEGProcessor::new(256.0);
@@ -202,3 +216,176 @@ fn test_signature_update() {
     let signature = auth_service.get_signature(&user_id).unwrap();
     assert_eq!(signature.enrollment_count, 2);
 }
+
+#[test]
+fn test_outdated_signature_requires_reenrollment() {
+    let mut auth_service = EEGAuthService::new(256.0).unwrap();
+    let signal = generate_mock_eeg_signal(256.0, 3.0, 1.0);
+    auth_service
+        .enroll_user("alice".to_string(), &signal)
+        .unwrap();
+    auth_service
+        .enroll_user("bob".to_string(), &signal)
+        .unwrap();
+    assert_eq!(
+        auth_service.get_signature("alice").unwrap().feature_version,
+        EEG_FEATURE_VERSION
+    );
+
+    // A new extraction algorithm ships
+    let next_version = EEG_FEATURE_VERSION + 1;
+    auth_service.set_feature_version(next_version);
+
+    // The genuine user is not rejected, but asked to re-enroll
+    let result = auth_service.authenticate("alice", &signal);
+    assert!(matches!(
+        result,
+        Err(EEGError::ReenrollmentRequired { enrolled, current, .. })
+            if enrolled == EEG_FEATURE_VERSION && current == next_version
+    ));
+    let mut outdated = auth_service.outdated_users();
+    outdated.sort();
+    assert_eq!(outdated, vec!["alice".to_string(), "bob".to_string()]);
+
+    // Migrating with a fresh sample restores authentication for alice only
+    let samples = HashMap::from([("alice".to_string(), signal.clone())]);
+    let report = auth_service.migrate_signatures(&samples);
+    assert_eq!(report.feature_version, next_version);
+    assert_eq!(report.migrated, vec!["alice".to_string()]);
+    assert_eq!(report.pending, vec!["bob".to_string()]);
+    assert!(report.failed.is_empty());
+
+    let signature = auth_service.get_signature("alice").unwrap();
+    assert_eq!(signature.feature_version, next_version);
+    assert_eq!(signature.enrollment_count, 1);
+    let result = auth_service.authenticate("alice", &signal).unwrap();
+    assert!(result.authenticated);
+    assert!(matches!(
+        auth_service.authenticate("bob", &signal),
+        Err(EEGError::ReenrollmentRequired { .. })
+    ));
+}
+
+#[test]
+fn test_signature_update_reextracts_outdated_template() {
+    let mut auth_service = EEGAuthService::new(256.0).unwrap();
+    let signal = generate_mock_eeg_signal(256.0, 3.0, 1.0);
+    auth_service
+        .enroll_user("carol".to_string(), &signal)
+        .unwrap();
+    auth_service.update_signature("carol", &signal).unwrap();
+    assert_eq!(
+        auth_service
+            .get_signature("carol")
+            .unwrap()
+            .enrollment_count,
+        2
+    );
+
+    auth_service.set_feature_version(EEG_FEATURE_VERSION + 1);
+    auth_service.update_signature("carol", &signal).unwrap();
+
+    let signature = auth_service.get_signature("carol").unwrap();
+    assert_eq!(signature.feature_version, EEG_FEATURE_VERSION + 1);
+    assert_eq!(signature.enrollment_count, 1);
+    assert!(auth_service.outdated_users().is_empty());
+    assert!(
+        auth_service
+            .authenticate("carol", &signal)
+            .unwrap()
+            .authenticated
+    );
+}
+
+#[test]
+fn test_replayed_sample_is_rejected() {
+    let mut auth_service = EEGAuthService::new(256.0).unwrap();
+    let enrollment = generate_mock_eeg_signal(256.0, 3.0, 1.0);
+    auth_service
+        .enroll_user("dave".to_string(), &enrollment)
+        .unwrap();
+
+    // A live capture answering the challenge is accepted
+    let challenge = auth_service.issue_challenge("dave").unwrap();
+    let captured = with_marker(
+        &generate_mock_eeg_signal(256.0, 3.0, 1.02),
+        256.0,
+        challenge.marker_frequency,
+    );
+    let result = auth_service
+        .authenticate_with_challenge("dave", &challenge.nonce, &captured)
+        .unwrap();
+    assert!(result.authenticated);
+
+    // The nonce is single-use
+    assert!(matches!(
+        auth_service.authenticate_with_challenge("dave", &challenge.nonce, &captured),
+        Err(EEGError::ChallengeInvalid(_))
+    ));
+
+    // Replaying the captured sample against a new challenge is rejected
+    let challenge = auth_service.issue_challenge("dave").unwrap();
+    assert!(matches!(
+        auth_service.authenticate_with_challenge("dave", &challenge.nonce, &captured),
+        Err(EEGError::ReplayDetected(_))
+    ));
+
+    // A fresh sample without the challenge marker fails the liveness check
+    let challenge = auth_service.issue_challenge("dave").unwrap();
+    let unmarked = generate_mock_eeg_signal(256.0, 3.0, 1.03);
+    assert!(matches!(
+        auth_service.authenticate_with_challenge("dave", &challenge.nonce, &unmarked),
+        Err(EEGError::LivenessCheckFailed { .. })
+    ));
+
+    // A fresh capture for the new challenge succeeds
+    let challenge = auth_service.issue_challenge("dave").unwrap();
+    let captured = with_marker(
+        &generate_mock_eeg_signal(256.0, 3.0, 0.98),
+        256.0,
+        challenge.marker_frequency,
+    );
+    let result = auth_service
+        .authenticate_with_challenge("dave", &challenge.nonce, &captured)
+        .unwrap();
+    assert!(result.authenticated);
+    assert!(result.similarity_score >= auth_service.config().match_threshold);
+}
+
+#[test]
+fn test_challenge_expiry_and_configuration() {
+    let config = EEGAuthConfig {
+        match_threshold: 0.9,
+        challenge_ttl: Duration::ZERO,
+        ..Default::default()
+    };
+    let mut auth_service = EEGAuthService::with_config(256.0, config).unwrap();
+    let signal = generate_mock_eeg_signal(256.0, 3.0, 1.0);
+    let signature = auth_service
+        .enroll_user("erin".to_string(), &signal)
+        .unwrap();
+    assert_eq!(signature.authentication_threshold, 0.9);
+
+    assert!(matches!(
+        auth_service.issue_challenge("nobody"),
+        Err(EEGError::SignatureNotFound(_))
+    ));
+    let challenge = auth_service.issue_challenge("erin").unwrap();
+    std::thread::sleep(Duration::from_millis(5));
+    let captured = with_marker(&signal, 256.0, challenge.marker_frequency);
+    assert!(matches!(
+        auth_service.authenticate_with_challenge("erin", &challenge.nonce, &captured),
+        Err(EEGError::ChallengeExpired(_))
+    ));
+
+    // Markers must be representable at the sampling rate
+    let config = EEGAuthConfig {
+        marker_min_frequency: 70.0,
+        marker_max_frequency: 80.0,
+        ..Default::default()
+    };
+    assert!(matches!(
+        EEGAuthService::with_config(128.0, config),
+        Err(EEGError::InvalidConfiguration(_))
+    ));
+}
@@ -0,0 +1,198 @@
+//! Tests for the bulk-insert endpoint (`POST /api/v1/tables/{table_name}/bulk`)
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use actix_web::dev::Service;
+use actix_web::{test, web, App, HttpMessage};
+use neuroquantum_api::handlers;
+use neuroquantum_api::permissions::Permission;
+use neuroquantum_core::storage::{ColumnDefinition, DataType, SelectQuery, TableSchema};
+use neuroquantum_core::{NeuroQuantumDB, NeuroQuantumDBBuilder};
+use serde_json::{json, Value as JsonValue};
+use tokio::sync::RwLock;
+
+use common::test_api_key;
+
+async fn create_test_db() -> (Arc<RwLock<NeuroQuantumDB>>, tempfile::TempDir) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db = NeuroQuantumDBBuilder::new()
+        .storage_path(temp_dir.path().to_path_buf())
+        .build()
+        .await
+        .expect("Failed to initialize database");
+    let db = Arc::new(RwLock::new(db));
+
+    {
+        let db_lock = db.write().await;
+        let mut storage = db_lock.storage_mut().await;
+        let schema = TableSchema {
+            name: "readings".to_string(),
+            columns: vec![
+                ColumnDefinition {
+                    name: "id".to_string(),
+                    data_type: DataType::Integer,
+                    nullable: false,
+                    default_value: None,
+                    auto_increment: false,
+                    ..Default::default()
+                },
+                ColumnDefinition {
+                    name: "sensor".to_string(),
+                    data_type: DataType::Text,
+                    nullable: false,
+                    default_value: None,
+                    auto_increment: false,
+                    ..Default::default()
+                },
+            ],
+            primary_key: "id".to_string(),
+            created_at: chrono::Utc::now(),
+            version: 1,
+            auto_increment_columns: HashMap::new(),
+            id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
+            foreign_keys: Vec::new(),
+            ..Default::default()
+        };
+        storage.create_table(schema).await.unwrap();
+    }
+
+    (db, temp_dir)
+}
+
+async fn count_rows(db: &Arc<RwLock<NeuroQuantumDB>>) -> usize {
+    let db_lock = db.read().await;
+    let storage = db_lock.storage().await;
+    let query = SelectQuery {
+        table: "readings".to_string(),
+        columns: vec!["*".to_string()],
+        where_clause: None,
+        order_by: None,
+        limit: None,
+        offset: None,
+    };
+    storage.select_rows(&query).await.unwrap().len()
+}
+
+macro_rules! bulk_app {
+    ($db:expr) => {
+        test::init_service(
+            App::new()
+                .app_data(web::Data::new($db.clone()))
+                .wrap_fn(|req, srv| {
+                    req.extensions_mut()
+                        .insert(test_api_key(Permission::read_write()));
+                    srv.call(req)
+                })
+                .route(
+                    "/api/v1/tables/{table_name}/bulk",
+                    web::post().to(handlers::bulk_insert_data),
+                ),
+        )
+        .await
+    };
+}
+
+#[actix_web::test]
+async fn test_bulk_insert_commits_all_rows_in_one_transaction() {
+    let (db, _temp_dir) = create_test_db().await;
+    let app = bulk_app!(db);
+
+    let rows: Vec<JsonValue> = (1..=500)
+        .map(|i| json!({ "id": i, "sensor": format!("sensor_{i}") }))
+        .collect();
+    let req = test::TestRequest::post()
+        .uri("/api/v1/tables/readings/bulk")
+        .set_json(&rows)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::CREATED);
+
+    let body: JsonValue = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["inserted_count"], 500);
+    assert_eq!(body["data"]["failed_count"], 0);
+    assert!(body["data"]["transaction_id"].is_string());
+    assert_eq!(body["data"]["synchronous_commit"], true);
+
+    assert_eq!(count_rows(&db).await, 500);
+}
+
+#[actix_web::test]
+async fn test_bulk_insert_relaxed_commit_skips_fsync() {
+    let (db, _temp_dir) = create_test_db().await;
+    let app = bulk_app!(db);
+    let sync_count = || async {
+        let db_lock = db.read().await;
+        let storage = db_lock.storage().await;
+        storage.get_transaction_manager().log_manager().sync_count()
+    };
+
+    let rows: Vec<JsonValue> = (1..=10)
+        .map(|i| json!({ "id": i, "sensor": format!("sensor_{i}") }))
+        .collect();
+    let syncs = sync_count().await;
+    let req = test::TestRequest::post()
+        .uri("/api/v1/tables/readings/bulk?relaxed_commit=true")
+        .set_json(&rows)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::CREATED);
+
+    let body: JsonValue = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["synchronous_commit"], false);
+    assert_eq!(sync_count().await, syncs);
+    assert_eq!(count_rows(&db).await, 10);
+}
+
+#[actix_web::test]
+async fn test_bulk_insert_is_atomic_on_failure() {
+    let (db, _temp_dir) = create_test_db().await;
+    let app = bulk_app!(db);
+
+    let mut rows: Vec<JsonValue> = (1..=50)
+        .map(|i| json!({ "id": i, "sensor": format!("sensor_{i}") }))
+        .collect();
+    // Missing the non-nullable `sensor` column
+    rows[25] = json!({ "id": 26 });
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/tables/readings/bulk")
+        .set_json(&rows)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+
+    assert_eq!(count_rows(&db).await, 0);
+}
+
+#[actix_web::test]
+async fn test_bulk_insert_ignore_errors_reports_failed_rows() {
+    let (db, _temp_dir) = create_test_db().await;
+    let app = bulk_app!(db);
+
+    let mut rows: Vec<JsonValue> = (1..=10)
+        .map(|i| json!({ "id": i, "sensor": format!("sensor_{i}") }))
+        .collect();
+    rows[3] = json!({ "id": 4 });
+    rows[7] = json!({});
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/tables/readings/bulk?ignore_errors=true")
+        .set_json(&rows)
+        .to_request();
+    let body: JsonValue = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(body["data"]["inserted_count"], 8);
+    assert_eq!(body["data"]["failed_count"], 2);
+    let failed: Vec<u64> = body["data"]["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["index"].as_u64().unwrap())
+        .collect();
+    assert_eq!(failed, vec![3, 7]);
+
+    assert_eq!(count_rows(&db).await, 8);
+}
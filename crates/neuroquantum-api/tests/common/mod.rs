@@ -0,0 +1,74 @@
+//! Fixtures shared by the API integration tests
+//!
+//! Each test binary compiles this module on its own and uses only some of
+//! the fixtures, hence the `dead_code` allowance.
+#![allow(dead_code)]
+
+use neuroquantum_api::auth::ApiKey;
+use neuroquantum_api::{ApiConfig, AppState};
+use neuroquantum_core::NeuroQuantumDBBuilder;
+
+/// App state over a fresh database in a temporary directory, which is
+/// removed when the returned guard drops
+pub async fn create_test_state() -> (AppState, tempfile::TempDir) {
+    create_test_state_with_config(ApiConfig::default()).await
+}
+
+/// Like [`create_test_state`], serving `config`
+pub async fn create_test_state_with_config(config: ApiConfig) -> (AppState, tempfile::TempDir) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db = NeuroQuantumDBBuilder::new()
+        .storage_path(temp_dir.path().to_path_buf())
+        .build()
+        .await
+        .expect("Failed to initialize database");
+    let state = AppState::with_database(config, db)
+        .await
+        .expect("Failed to create app state");
+    (state, temp_dir)
+}
+
+/// An unexpired API key with `permissions`, as the auth middleware would
+/// place it in the request extensions
+pub fn test_api_key(permissions: Vec<String>) -> ApiKey {
+    ApiKey {
+        key: "nqdb_test_key".to_string(),
+        name: "integration-test".to_string(),
+        permissions,
+        expires_at: chrono::Utc::now() + chrono::Duration::hours(1),
+        created_at: chrono::Utc::now(),
+        last_used: None,
+        usage_count: 0,
+        rate_limit_per_hour: None,
+        scopes: Vec::new(),
+    }
+}
+
+/// Register a one-hour API key with `permissions` with the state's auth
+/// service, for tests that authenticate through the middleware
+pub fn issue_api_key(state: &AppState, permissions: Vec<String>) -> ApiKey {
+    state
+        .auth_service
+        .clone()
+        .generate_api_key("integration-test".to_string(), permissions, Some(1), None)
+        .unwrap()
+}
+
+/// Create `table (id, name)` holding Ada, Grace and Linus with ids 1 to 3
+pub async fn seed_users(state: &AppState, table: &str) {
+    let mut engine = state.qsql_engine.lock().await;
+    engine
+        .execute_query(&format!(
+            "CREATE TABLE {table} (id INTEGER PRIMARY KEY, name TEXT NOT NULL)"
+        ))
+        .await
+        .unwrap();
+    for (id, name) in [(1, "Ada"), (2, "Grace"), (3, "Linus")] {
+        engine
+            .execute_query(&format!(
+                "INSERT INTO {table} (id, name) VALUES ({id}, '{name}')"
+            ))
+            .await
+            .unwrap();
+    }
+}
@@ -3,7 +3,7 @@
 //! These tests validate configuration defaults, development/production modes,
 //! validation, and URL generation.
 
-use neuroquantum_api::config::{ApiConfig, TlsConfig};
+use neuroquantum_api::config::{ApiConfig, ClientAuthMode, TlsConfig};
 
 #[test]
 fn test_default_config() {
@@ -61,6 +61,38 @@ fn test_base_url() {
         cert_file: "test.crt".to_string(),
         key_file: "test.key".to_string(),
         ca_file: None,
+        client_auth: ClientAuthMode::Disabled,
     });
     assert_eq!(tls_config.base_url(), "https://127.0.0.1:8080");
 }
+
+#[test]
+fn test_cors_config_has_no_dev_origins_by_default() {
+    for config in [ApiConfig::default(), ApiConfig::production()] {
+        assert!(config.cors.allowed_origins.is_empty());
+        assert!(config.cors.allowed_origin_patterns.is_empty());
+        assert!(!config.cors.allow_any_origin);
+    }
+
+    // The development profile opts into the local frontends explicitly
+    let dev = ApiConfig::development();
+    assert!(dev
+        .cors
+        .allowed_origins
+        .contains(&"http://localhost:3000".to_string()));
+}
+
+#[test]
+fn test_cors_config_validation() {
+    let mut config = ApiConfig::default();
+    config.jwt.secret = "this-is-a-valid-32-character-secret!".to_string();
+    config.cors.allowed_origin_patterns = vec![r"https://[a-z]+\.example\.com".to_string()];
+    assert!(config.validate().is_ok());
+
+    config.cors.allowed_origin_patterns = vec!["https://(unclosed".to_string()];
+    assert!(config.validate().is_err());
+
+    config.cors.allowed_origin_patterns.clear();
+    config.cors.allowed_methods.push("NOT A METHOD".to_string());
+    assert!(config.validate().is_err());
+}
@@ -0,0 +1,163 @@
+//! Tests for request admission control sized from `DatabaseConfig::max_connections`
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpResponse};
+use futures_util::future::join_all;
+use neuroquantum_api::config::DatabaseConfig;
+use neuroquantum_api::connection_limit::{ConnectionLimitMiddleware, ConnectionLimiter};
+use neuroquantum_api::{health_check, ApiConfig, AppState};
+use neuroquantum_core::NeuroQuantumDBBuilder;
+use serde_json::Value;
+use tokio::sync::Semaphore;
+
+/// Handler that blocks until the test opens the gate
+async fn gated(gate: web::Data<Arc<Semaphore>>) -> HttpResponse {
+    gate.acquire().await.unwrap().forget();
+    HttpResponse::Ok().finish()
+}
+
+/// Wait until the limiter reports the expected number of admitted and queued requests
+async fn wait_for(limiter: &ConnectionLimiter, active: usize, queued: usize) {
+    while limiter.active_connections() != active || limiter.queued_requests() != queued {
+        tokio::task::yield_now().await;
+    }
+}
+
+macro_rules! limited_app {
+    ($limiter:expr, $gate:expr) => {
+        test::init_service(
+            App::new().app_data(web::Data::new($gate.clone())).service(
+                web::scope("")
+                    .wrap(ConnectionLimitMiddleware::new($limiter.clone()))
+                    .route("/slow", web::get().to(gated)),
+            ),
+        )
+        .await
+    };
+}
+
+#[actix_web::test]
+async fn test_requests_beyond_limit_get_503_with_retry_after() {
+    let limiter = Arc::new(ConnectionLimiter::new(2, 0, Duration::from_secs(30)));
+    let gate = Arc::new(Semaphore::new(0));
+    let app = limited_app!(limiter, gate);
+
+    let admitted = join_all(
+        (0..2)
+            .map(|_| test::call_service(&app, test::TestRequest::get().uri("/slow").to_request())),
+    );
+    let probe = async {
+        wait_for(&limiter, 2, 0).await;
+
+        for _ in 0..3 {
+            let err =
+                test::try_call_service(&app, test::TestRequest::get().uri("/slow").to_request())
+                    .await
+                    .unwrap_err();
+            let resp = err.error_response();
+            assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+            assert!(resp.headers().contains_key("retry-after"));
+        }
+
+        gate.add_permits(2);
+    };
+
+    let (responses, ()) = tokio::join!(admitted, probe);
+    assert!(responses.iter().all(|r| r.status().is_success()));
+    assert_eq!(limiter.active_connections(), 0);
+}
+
+#[actix_web::test]
+async fn test_rejection_uses_error_envelope() {
+    let limiter = Arc::new(ConnectionLimiter::new(1, 0, Duration::from_secs(30)));
+    let gate = Arc::new(Semaphore::new(0));
+    let app = limited_app!(limiter, gate);
+    let _held = limiter.acquire().await.unwrap();
+
+    let err = test::try_call_service(&app, test::TestRequest::get().uri("/slow").to_request())
+        .await
+        .unwrap_err();
+    let resp = err.error_response();
+    assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(resp.headers().get("retry-after").unwrap(), "1");
+    let body: Value =
+        serde_json::from_slice(&actix_web::body::to_bytes(resp.into_body()).await.unwrap())
+            .unwrap();
+    assert_eq!(body["success"], false);
+    assert_eq!(body["error_code"], "SERVICE_UNAVAILABLE");
+}
+
+#[actix_web::test]
+async fn test_requests_queue_up_to_configured_depth() {
+    let limiter = Arc::new(ConnectionLimiter::new(1, 1, Duration::from_secs(30)));
+    let gate = Arc::new(Semaphore::new(0));
+    let app = limited_app!(limiter, gate);
+
+    let first = test::call_service(&app, test::TestRequest::get().uri("/slow").to_request());
+    let queued = async {
+        wait_for(&limiter, 1, 0).await;
+        test::call_service(&app, test::TestRequest::get().uri("/slow").to_request()).await
+    };
+    let probe = async {
+        wait_for(&limiter, 1, 1).await;
+
+        let err = test::try_call_service(&app, test::TestRequest::get().uri("/slow").to_request())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.error_response().status(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+
+        gate.add_permits(2);
+    };
+
+    let (first, queued, ()) = tokio::join!(first, queued, probe);
+    assert!(first.status().is_success());
+    assert!(queued.status().is_success());
+}
+
+#[actix_web::test]
+async fn test_queued_request_times_out() {
+    let limiter = ConnectionLimiter::new(1, 4, Duration::from_millis(20));
+    let _held = limiter.acquire().await.unwrap();
+
+    assert!(limiter.acquire().await.is_none());
+    assert_eq!(limiter.queued_requests(), 0);
+}
+
+#[actix_web::test]
+async fn test_health_reports_active_connections() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db = NeuroQuantumDBBuilder::new()
+        .storage_path(temp_dir.path().to_path_buf())
+        .build()
+        .await
+        .expect("Failed to initialize database");
+    let config = ApiConfig {
+        database: DatabaseConfig {
+            max_connections: 4,
+            ..DatabaseConfig::default()
+        },
+        ..ApiConfig::default()
+    };
+    let state = AppState::with_database(config, db)
+        .await
+        .expect("Failed to create app state");
+    assert_eq!(state.connection_limiter.max_connections(), 4);
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .route("/health", web::get().to(health_check)),
+    )
+    .await;
+
+    let _permits = join_all((0..3).map(|_| state.connection_limiter.acquire())).await;
+    let req = test::TestRequest::get().uri("/health").to_request();
+    let resp: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp["data"]["active_connections"], 3);
+}
@@ -0,0 +1,152 @@
+//! Tests for the CORS policy
+//!
+//! Origins are only allowed when `ApiConfig.cors` lists them, matches them
+//! with a pattern, or allows any origin.
+
+mod common;
+
+use actix_web::http::header::HeaderMap;
+use actix_web::http::StatusCode;
+use actix_web::test;
+use neuroquantum_api::config::CorsConfig;
+use neuroquantum_api::{configure_app, ApiConfig, AppState};
+
+use common::create_test_state_with_config;
+
+async fn create_cors_state(cors: CorsConfig) -> (AppState, tempfile::TempDir) {
+    create_test_state_with_config(ApiConfig {
+        cors,
+        ..ApiConfig::default()
+    })
+    .await
+}
+
+fn health_from(origin: &str) -> test::TestRequest {
+    test::TestRequest::get()
+        .uri("/health")
+        .insert_header(("Origin", origin))
+}
+
+fn allowed_origin(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get("access-control-allow-origin")
+        .map(|value| value.to_str().unwrap().to_string())
+}
+
+#[actix_web::test]
+async fn test_unconfigured_origins_are_rejected() {
+    let (state, _temp_dir) = create_cors_state(CorsConfig {
+        allowed_origins: vec!["https://app.example.com".to_string()],
+        ..CorsConfig::default()
+    })
+    .await;
+    let app = test::init_service(configure_app(state)).await;
+
+    let resp = test::call_service(&app, health_from("https://app.example.com").to_request()).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        allowed_origin(resp.headers()).as_deref(),
+        Some("https://app.example.com")
+    );
+
+    // Dev origins are not allowed unless configured
+    for origin in ["https://evil.example.net", "http://localhost:3000"] {
+        let resp = test::call_service(&app, health_from(origin).to_request()).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "{origin}");
+        assert_eq!(allowed_origin(resp.headers()), None);
+    }
+
+    // Requests without an Origin header are not affected
+    let req = test::TestRequest::get().uri("/health").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn test_localhost_is_allowed_when_configured() {
+    let (state, _temp_dir) = create_cors_state(ApiConfig::development().cors).await;
+    let app = test::init_service(configure_app(state)).await;
+
+    let resp = test::call_service(&app, health_from("http://localhost:3000").to_request()).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        allowed_origin(resp.headers()).as_deref(),
+        Some("http://localhost:3000")
+    );
+}
+
+#[actix_web::test]
+async fn test_origin_patterns_match_whole_origin() {
+    let (state, _temp_dir) = create_cors_state(CorsConfig {
+        allowed_origin_patterns: vec![r"https://[a-z]+\.example\.com".to_string()],
+        ..CorsConfig::default()
+    })
+    .await;
+    let app = test::init_service(configure_app(state)).await;
+
+    let resp = test::call_service(
+        &app,
+        health_from("https://dashboard.example.com").to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    for origin in [
+        "https://dashboard.example.com.evil.net",
+        "http://dashboard.example.com",
+    ] {
+        let resp = test::call_service(&app, health_from(origin).to_request()).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "{origin}");
+    }
+}
+
+#[actix_web::test]
+async fn test_preflight_uses_configured_methods_and_headers() {
+    let (state, _temp_dir) = create_cors_state(CorsConfig {
+        allowed_origins: vec!["https://app.example.com".to_string()],
+        allowed_methods: vec!["GET".to_string()],
+        allowed_headers: vec!["X-API-Key".to_string()],
+        ..CorsConfig::default()
+    })
+    .await;
+    let app = test::init_service(configure_app(state)).await;
+
+    let preflight = |method: &str| {
+        test::TestRequest::default()
+            .method(actix_web::http::Method::OPTIONS)
+            .uri("/api/v1/query")
+            .insert_header(("Origin", "https://app.example.com"))
+            .insert_header(("Access-Control-Request-Method", method))
+            .insert_header(("Access-Control-Request-Headers", "x-api-key"))
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, preflight("GET")).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let resp = test::call_service(&app, preflight("DELETE")).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_allow_any_origin_echoes_origin_with_credentials() {
+    let (state, _temp_dir) = create_cors_state(CorsConfig {
+        allow_any_origin: true,
+        allow_credentials: true,
+        ..CorsConfig::default()
+    })
+    .await;
+    let app = test::init_service(configure_app(state)).await;
+
+    let resp = test::call_service(&app, health_from("http://localhost:5173").to_request()).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        allowed_origin(resp.headers()).as_deref(),
+        Some("http://localhost:5173")
+    );
+    assert_eq!(
+        resp.headers()
+            .get("access-control-allow-credentials")
+            .map(|value| value.to_str().unwrap()),
+        Some("true")
+    );
+}
@@ -0,0 +1,181 @@
+//! Tests for CSV import/export (`/api/v1/tables/{table_name}/import/csv` and `/export/csv`)
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use actix_web::dev::Service;
+use actix_web::{test, web, App, HttpMessage};
+use neuroquantum_api::csv::{write_record, CsvReader};
+use neuroquantum_api::handlers;
+use neuroquantum_api::permissions::Permission;
+use neuroquantum_core::storage::{ColumnDefinition, DataType, TableSchema};
+use neuroquantum_core::{NeuroQuantumDB, NeuroQuantumDBBuilder};
+use serde_json::Value as JsonValue;
+use tokio::sync::RwLock;
+
+use common::test_api_key;
+
+async fn create_test_db() -> (Arc<RwLock<NeuroQuantumDB>>, tempfile::TempDir) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db = NeuroQuantumDBBuilder::new()
+        .storage_path(temp_dir.path().to_path_buf())
+        .build()
+        .await
+        .expect("Failed to initialize database");
+    let db = Arc::new(RwLock::new(db));
+
+    {
+        let db_lock = db.write().await;
+        let mut storage = db_lock.storage_mut().await;
+        let column = |name: &str, data_type: DataType, nullable: bool| ColumnDefinition {
+            name: name.to_string(),
+            data_type,
+            nullable,
+            default_value: None,
+            auto_increment: false,
+            ..Default::default()
+        };
+        let schema = TableSchema {
+            name: "products".to_string(),
+            columns: vec![
+                column("id", DataType::Integer, false),
+                column("name", DataType::Text, false),
+                column("price", DataType::Float, true),
+                column("in_stock", DataType::Boolean, true),
+            ],
+            primary_key: "id".to_string(),
+            created_at: chrono::Utc::now(),
+            version: 1,
+            auto_increment_columns: HashMap::new(),
+            id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
+            foreign_keys: Vec::new(),
+            ..Default::default()
+        };
+        storage.create_table(schema).await.unwrap();
+    }
+
+    (db, temp_dir)
+}
+
+macro_rules! csv_app {
+    ($db:expr) => {
+        test::init_service(
+            App::new()
+                .app_data(web::Data::new($db.clone()))
+                .wrap_fn(|req, srv| {
+                    req.extensions_mut()
+                        .insert(test_api_key(Permission::read_write()));
+                    srv.call(req)
+                })
+                .route(
+                    "/api/v1/tables/{table_name}/import/csv",
+                    web::post().to(handlers::import_csv),
+                )
+                .route(
+                    "/api/v1/tables/{table_name}/export/csv",
+                    web::get().to(handlers::export_csv),
+                ),
+        )
+        .await
+    };
+}
+
+#[actix_web::test]
+async fn test_csv_round_trip() {
+    let (db, _temp_dir) = create_test_db().await;
+    let app = csv_app!(db);
+
+    let input = "id,name,price,in_stock\r\n\
+                 1,Widget,9.5,true\r\n\
+                 2,\"Gadget, deluxe\",19.25,false\r\n\
+                 3,\"Quote \"\"Q\"\" Co\",,\r\n";
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/tables/products/import/csv")
+        .insert_header(("content-type", "text/csv"))
+        .set_payload(input)
+        .to_request();
+    let resp: JsonValue = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp["data"]["imported_count"], 3, "import failed: {resp}");
+    assert_eq!(resp["data"]["failed_count"], 0);
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/tables/products/export/csv")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success());
+    assert_eq!(
+        resp.headers().get("content-type").unwrap(),
+        "text/csv; charset=utf-8"
+    );
+    let body = test::read_body(resp).await;
+    let output = String::from_utf8(body.to_vec()).unwrap();
+
+    assert_eq!(output, input);
+}
+
+#[actix_web::test]
+async fn test_csv_import_reports_malformed_rows_with_line_numbers() {
+    let (db, _temp_dir) = create_test_db().await;
+    let app = csv_app!(db);
+
+    let input = "name;id\n\
+                 alpha;1\n\
+                 beta;not-a-number\n\
+                 gamma\n\
+                 \n\
+                 delta;4\n";
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/tables/products/import/csv?delimiter=;")
+        .set_payload(input)
+        .to_request();
+    let resp: JsonValue = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(resp["data"]["imported_count"], 2);
+    assert_eq!(resp["data"]["failed_count"], 2);
+    let lines: Vec<u64> = resp["data"]["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["line"].as_u64().unwrap())
+        .collect();
+    assert_eq!(lines, vec![3, 4]);
+}
+
+#[actix_web::test]
+async fn test_csv_import_rejects_unknown_header_column() {
+    let (db, _temp_dir) = create_test_db().await;
+    let app = csv_app!(db);
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/tables/products/import/csv")
+        .set_payload("id,colour\n1,red\n")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn test_csv_reader_handles_records_split_across_chunks() {
+    let mut reader = CsvReader::new(b',');
+    let mut records = reader.feed(b"a,\"multi");
+    records.extend(reader.feed(b"\nline\"\r\nb,c"));
+    records.extend(reader.finish());
+
+    let records: Vec<_> = records.into_iter().map(Result::unwrap).collect();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].fields, vec!["a", "multi\nline"]);
+    assert_eq!(records[0].line, 1);
+    assert_eq!(records[1].fields, vec!["b", "c"]);
+    assert_eq!(records[1].line, 3);
+}
+
+#[test]
+fn test_csv_writer_quotes_only_when_needed() {
+    let mut out = String::new();
+    write_record(["plain", "with,comma", "say \"hi\""], b',', &mut out);
+    assert_eq!(out, "plain,\"with,comma\",\"say \"\"hi\"\"\"\r\n");
+}
@@ -59,6 +59,7 @@ fn create_test_schema(table_name: &str) -> TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -66,6 +67,7 @@ fn create_test_schema(table_name: &str) -> TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "value".to_string(),
@@ -73,6 +75,7 @@ fn create_test_schema(table_name: &str) -> TableSchema {
                 nullable: true,
                 default_value: Some(Value::Integer(0)),
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "active".to_string(),
@@ -80,6 +83,7 @@ fn create_test_schema(table_name: &str) -> TableSchema {
                 nullable: true,
                 default_value: Some(Value::Boolean(true)),
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         created_at: chrono::Utc::now(),
@@ -87,6 +91,7 @@ fn create_test_schema(table_name: &str) -> TableSchema {
         auto_increment_columns: std::collections::HashMap::new(),
         id_strategy: IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     }
 }
 
@@ -316,6 +321,7 @@ async fn test_complex_multi_table_workflow() {
                     nullable: false,
                     default_value: None,
                     auto_increment: true,
+                    ..Default::default()
                 },
                 ColumnDefinition {
                     name: "username".to_string(),
@@ -323,6 +329,7 @@ async fn test_complex_multi_table_workflow() {
                     nullable: false,
                     default_value: None,
                     auto_increment: false,
+                    ..Default::default()
                 },
             ],
             created_at: chrono::Utc::now(),
@@ -330,6 +337,7 @@ async fn test_complex_multi_table_workflow() {
             auto_increment_columns: std::collections::HashMap::new(),
             id_strategy: IdGenerationStrategy::AutoIncrement,
             foreign_keys: Vec::new(),
+            ..Default::default()
         };
 
         // Posts table
@@ -343,6 +351,7 @@ async fn test_complex_multi_table_workflow() {
                     nullable: false,
                     default_value: None,
                     auto_increment: true,
+                    ..Default::default()
                 },
                 ColumnDefinition {
                     name: "user_id".to_string(),
@@ -350,6 +359,7 @@ async fn test_complex_multi_table_workflow() {
                     nullable: false,
                     default_value: None,
                     auto_increment: false,
+                    ..Default::default()
                 },
                 ColumnDefinition {
                     name: "content".to_string(),
@@ -357,6 +367,7 @@ async fn test_complex_multi_table_workflow() {
                     nullable: false,
                     default_value: None,
                     auto_increment: false,
+                    ..Default::default()
                 },
             ],
             created_at: chrono::Utc::now(),
@@ -364,6 +375,7 @@ async fn test_complex_multi_table_workflow() {
             auto_increment_columns: std::collections::HashMap::new(),
             id_strategy: IdGenerationStrategy::AutoIncrement,
             foreign_keys: Vec::new(),
+            ..Default::default()
         };
 
         storage.create_table(users_schema).await.unwrap();
@@ -42,6 +42,7 @@ async fn test_complete_workflow_with_query_statistics() {
                     nullable: false,
                     default_value: None,
                     auto_increment: true,
+                    ..Default::default()
                 },
                 neuroquantum_core::storage::ColumnDefinition {
                     name: "username".to_string(),
@@ -49,6 +50,7 @@ async fn test_complete_workflow_with_query_statistics() {
                     nullable: false,
                     default_value: None,
                     auto_increment: false,
+                    ..Default::default()
                 },
                 neuroquantum_core::storage::ColumnDefinition {
                     name: "email".to_string(),
@@ -56,6 +58,7 @@ async fn test_complete_workflow_with_query_statistics() {
                     nullable: false,
                     default_value: None,
                     auto_increment: false,
+                    ..Default::default()
                 },
                 neuroquantum_core::storage::ColumnDefinition {
                     name: "active".to_string(),
@@ -63,6 +66,7 @@ async fn test_complete_workflow_with_query_statistics() {
                     nullable: true,
                     default_value: Some(neuroquantum_core::storage::Value::Boolean(true)),
                     auto_increment: false,
+                    ..Default::default()
                 },
             ],
             created_at: chrono::Utc::now(),
@@ -70,6 +74,7 @@ async fn test_complete_workflow_with_query_statistics() {
             auto_increment_columns: std::collections::HashMap::new(),
             id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
             foreign_keys: Vec::new(),
+            ..Default::default()
         };
 
         storage
@@ -211,6 +216,7 @@ async fn test_concurrent_operations() {
                     nullable: false,
                     default_value: None,
                     auto_increment: true,
+                    ..Default::default()
                 },
                 neuroquantum_core::storage::ColumnDefinition {
                     name: "value".to_string(),
@@ -218,6 +224,7 @@ async fn test_concurrent_operations() {
                     nullable: false,
                     default_value: None,
                     auto_increment: false,
+                    ..Default::default()
                 },
             ],
             created_at: chrono::Utc::now(),
@@ -225,6 +232,7 @@ async fn test_concurrent_operations() {
             auto_increment_columns: std::collections::HashMap::new(),
             id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
             foreign_keys: Vec::new(),
+            ..Default::default()
         };
 
         storage
@@ -284,6 +292,7 @@ async fn test_query_statistics_accuracy() {
                     nullable: false,
                     default_value: None,
                     auto_increment: true,
+                    ..Default::default()
                 },
                 neuroquantum_core::storage::ColumnDefinition {
                     name: "data".to_string(),
@@ -291,6 +300,7 @@ async fn test_query_statistics_accuracy() {
                     nullable: false,
                     default_value: None,
                     auto_increment: false,
+                    ..Default::default()
                 },
             ],
             created_at: chrono::Utc::now(),
@@ -298,6 +308,7 @@ async fn test_query_statistics_accuracy() {
             auto_increment_columns: std::collections::HashMap::new(),
             id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
             foreign_keys: Vec::new(),
+            ..Default::default()
         };
 
         storage.create_table(schema).await.unwrap();
@@ -2,7 +2,19 @@
 //!
 //! These tests validate API response creation and error conversion.
 
-use neuroquantum_api::error::{ApiError, ApiResponse, ResponseMetadata};
+mod common;
+
+use actix_web::dev::Service;
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpMessage, ResponseError};
+use neuroquantum_api::error::{ApiError, ApiResponse, ErrorCode, ResponseMetadata};
+use neuroquantum_api::permissions::Permission;
+use neuroquantum_api::{handlers, ApiConfig, AppState};
+use neuroquantum_core::{NeuroQuantumDBBuilder, NeuroQuantumError};
+use neuroquantum_qsql::QSQLError;
+use serde_json::{json, Value};
+
+use common::test_api_key;
 
 #[test]
 fn test_api_response_success() {
@@ -37,3 +49,169 @@ fn test_api_error_response() {
     assert!(response.data.is_none());
     assert!(response.error.is_some());
 }
+
+#[test]
+fn test_error_codes_and_statuses() {
+    let cases = [
+        (
+            ApiError::TableNotFound {
+                table: "users".to_string(),
+            },
+            "TABLE_NOT_FOUND",
+            StatusCode::NOT_FOUND,
+        ),
+        (
+            ApiError::Forbidden("admin permission required".to_string()),
+            "PERMISSION_DENIED",
+            StatusCode::FORBIDDEN,
+        ),
+        (
+            ApiError::RateLimitExceeded {
+                limit: 100,
+                window: "1 hour".to_string(),
+            },
+            "RATE_LIMITED",
+            StatusCode::TOO_MANY_REQUESTS,
+        ),
+        (
+            ApiError::QueryTimeout {
+                details: "30s".to_string(),
+            },
+            "QUERY_TIMEOUT",
+            StatusCode::GATEWAY_TIMEOUT,
+        ),
+        (
+            ApiError::InvalidQuery {
+                details: "syntax".to_string(),
+            },
+            "INVALID_QUERY",
+            StatusCode::BAD_REQUEST,
+        ),
+        (
+            ApiError::QueryCancelled {
+                request_id: "r1".to_string(),
+            },
+            "QUERY_CANCELLED",
+            StatusCode::CONFLICT,
+        ),
+        (
+            ApiError::UnsupportedApiVersion {
+                requested: "v9".to_string(),
+                supported: vec!["v1".to_string(), "v2".to_string()],
+            },
+            "UNSUPPORTED_API_VERSION",
+            StatusCode::NOT_ACCEPTABLE,
+        ),
+    ];
+    for (error, code, status) in cases {
+        assert_eq!(error.code().as_str(), code);
+        assert_eq!(serde_json::to_value(error.code()).unwrap(), json!(code));
+        assert_eq!(error.status_code(), status, "{code}");
+    }
+}
+
+#[test]
+fn test_qsql_and_core_errors_map_to_codes() {
+    let missing = QSQLError::ExecutionError {
+        message: "Storage select failed: Table 'orders' does not exist".to_string(),
+    };
+    assert!(matches!(
+        ApiError::from(missing),
+        ApiError::TableNotFound { table } if table == "orders"
+    ));
+    let parse = QSQLError::ParseError {
+        message: "unexpected token".to_string(),
+        position: 7,
+    };
+    assert_eq!(ApiError::from(parse).code(), ErrorCode::InvalidQuery);
+    assert_eq!(
+        ApiError::from(QSQLError::Cancelled).code(),
+        ErrorCode::QueryCancelled
+    );
+
+    let cases = [
+        (
+            NeuroQuantumError::Timeout("lock wait".to_string()),
+            ErrorCode::QueryTimeout,
+        ),
+        (
+            NeuroQuantumError::NotFound("key".to_string()),
+            ErrorCode::NotFound,
+        ),
+        (
+            NeuroQuantumError::DeadlockDetected("tx 4".to_string()),
+            ErrorCode::Conflict,
+        ),
+        (
+            NeuroQuantumError::StorageError("Table 'users' does not exist".to_string()),
+            ErrorCode::TableNotFound,
+        ),
+        (
+            NeuroQuantumError::StorageError("disk full".to_string()),
+            ErrorCode::InternalError,
+        ),
+    ];
+    for (error, code) in cases {
+        assert_eq!(ApiError::from(error).code(), code);
+    }
+
+    // Query errors arrive as anyhow errors wrapping the QSQL error
+    let wrapped = anyhow::Error::new(QSQLError::SemanticError {
+        message: "ambiguous column".to_string(),
+    });
+    assert_eq!(
+        ApiError::from_query_error(wrapped).code(),
+        ErrorCode::InvalidQuery
+    );
+}
+
+#[actix_web::test]
+async fn test_query_failures_report_documented_codes() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db = NeuroQuantumDBBuilder::new()
+        .storage_path(temp_dir.path().to_path_buf())
+        .build()
+        .await
+        .unwrap();
+    let state = AppState::with_database(ApiConfig::default(), db)
+        .await
+        .unwrap();
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .wrap_fn(|req, srv| {
+                req.extensions_mut()
+                    .insert(test_api_key(Permission::read_only()));
+                srv.call(req)
+            })
+            .route("/api/v1/query", web::post().to(handlers::execute_sql_query)),
+    )
+    .await;
+
+    for (query, status, code) in [
+        (
+            "SELECT * FROM table_that_does_not_exist",
+            StatusCode::NOT_FOUND,
+            "TABLE_NOT_FOUND",
+        ),
+        (
+            "CREATE TABLE forbidden (id INTEGER PRIMARY KEY)",
+            StatusCode::FORBIDDEN,
+            "PERMISSION_DENIED",
+        ),
+        ("SELECT (1 +", StatusCode::BAD_REQUEST, "INVALID_QUERY"),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/api/v1/query")
+            .set_json(json!({ "query": query }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), status, "{query}");
+
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["success"], false);
+        assert_eq!(body["error_code"], code, "{query}");
+        assert!(body["metadata"]["message"].as_str().is_some());
+    }
+}
@@ -0,0 +1,136 @@
+//! Tests for draining connections on shutdown
+//!
+//! Requests are held in flight by taking the database write lock, which
+//! `/health` waits for.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use actix_web::dev::ServerHandle;
+use actix_web::http::{ConnectionType, StatusCode};
+use actix_web::{test, web, App, HttpResponse};
+use neuroquantum_api::config::ApiConfig;
+use neuroquantum_api::shutdown::{
+    graceful_shutdown, DrainMiddleware, DrainReport, InFlightRequests,
+};
+use neuroquantum_api::{http_server, AppState};
+use neuroquantum_core::NeuroQuantumDBBuilder;
+
+/// Start an HTTP server, returning its state, base URL and handle
+async fn start() -> (AppState, String, ServerHandle, tempfile::TempDir) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db = NeuroQuantumDBBuilder::new()
+        .storage_path(temp_dir.path().to_path_buf())
+        .build()
+        .await
+        .expect("Failed to initialize database");
+    let mut config = ApiConfig::default();
+    config.server.workers = Some(1);
+    let state = AppState::with_database(config, db)
+        .await
+        .expect("Failed to create app state");
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://127.0.0.1:{}", listener.local_addr().unwrap().port());
+    let server = http_server(state.clone(), listener).unwrap();
+    let handle = server.handle();
+    actix_web::rt::spawn(server);
+    (state, url, handle, temp_dir)
+}
+
+async fn wait_for_in_flight(state: &AppState, count: usize) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while state.in_flight_requests.count() != count {
+        assert!(
+            Instant::now() < deadline,
+            "request never reached the handler"
+        );
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+#[actix_web::test]
+async fn test_shutdown_waits_for_in_flight_request() {
+    let (state, url, handle, _temp_dir) = start().await;
+    let db_guard = state.db.write().await;
+
+    let request = tokio::spawn(reqwest::get(format!("{url}/health")));
+    wait_for_in_flight(&state, 1).await;
+
+    let grace = Duration::from_secs(10);
+    let started = Instant::now();
+    let shutdown_state = state.clone();
+    let shutdown =
+        actix_web::rt::spawn(
+            async move { graceful_shutdown(&handle, &shutdown_state, grace).await },
+        );
+
+    // The server waits for the request rather than stopping
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(state.in_flight_requests.is_draining());
+    assert!(!shutdown.is_finished());
+    drop(db_guard);
+
+    let response = request.await.unwrap().unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    let report = shutdown.await.unwrap();
+    assert!(started.elapsed() < grace);
+    assert_eq!(
+        report,
+        DrainReport {
+            requests_drained: 1,
+            ..DrainReport::default()
+        }
+    );
+    assert_eq!(state.in_flight_requests.count(), 0);
+}
+
+#[actix_web::test]
+async fn test_shutdown_terminates_requests_after_grace_period() {
+    let (state, url, handle, _temp_dir) = start().await;
+    let _db_guard = state.db.write().await;
+
+    let request = tokio::spawn(reqwest::get(format!("{url}/health")));
+    wait_for_in_flight(&state, 1).await;
+
+    let grace = Duration::from_millis(300);
+    let started = Instant::now();
+    let report = graceful_shutdown(&handle, &state, grace).await;
+    assert!(started.elapsed() >= grace);
+    assert_eq!(
+        report,
+        DrainReport {
+            requests_terminated: 1,
+            ..DrainReport::default()
+        }
+    );
+
+    // The request was cut off instead of answered
+    assert!(request.await.unwrap().is_err());
+}
+
+#[actix_web::test]
+async fn test_draining_rejection_uses_error_envelope() {
+    let requests = Arc::new(InFlightRequests::new());
+    let app = test::init_service(
+        App::new()
+            .wrap(DrainMiddleware::new(requests.clone()))
+            .route("/", web::get().to(HttpResponse::Ok)),
+    )
+    .await;
+    requests.start_draining();
+
+    let err = test::try_call_service(&app, test::TestRequest::get().to_request())
+        .await
+        .unwrap_err();
+    let resp = err.error_response();
+    assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(resp.head().connection_type(), ConnectionType::Close);
+    let body: serde_json::Value =
+        serde_json::from_slice(&actix_web::body::to_bytes(resp.into_body()).await.unwrap())
+            .unwrap();
+    assert_eq!(body["success"], false);
+    assert_eq!(body["error_code"], "SERVICE_UNAVAILABLE");
+    assert_eq!(requests.count(), 0);
+}
@@ -0,0 +1,141 @@
+//! Tests for the GraphQL endpoint (`POST /graphql`)
+//!
+//! Each query is compared against the equivalent REST call so both interfaces
+//! stay in agreement.
+
+mod common;
+
+use actix_web::dev::Service;
+use actix_web::{test, web, App, HttpMessage};
+use neuroquantum_api::graphql::{build_schema, graphql_handler, GRAPHQL_PATH};
+use neuroquantum_api::handlers;
+use neuroquantum_api::permissions::Permission;
+use serde_json::{json, Value};
+
+use common::{create_test_state, seed_users, test_api_key};
+
+macro_rules! graphql_app {
+    ($state:expr, $permissions:expr) => {{
+        let permissions: Vec<String> = $permissions;
+        test::init_service(
+            App::new()
+                .app_data(web::Data::new($state.clone()))
+                .app_data(web::Data::new(build_schema($state.clone())))
+                .wrap_fn(move |req, srv| {
+                    req.extensions_mut()
+                        .insert(test_api_key(permissions.clone()));
+                    srv.call(req)
+                })
+                .route(GRAPHQL_PATH, web::post().to(graphql_handler))
+                .route("/api/v1/query", web::post().to(handlers::execute_sql_query))
+                .route(
+                    "/api/v1/stats/performance",
+                    web::get().to(handlers::get_performance_stats),
+                ),
+        )
+        .await
+    }};
+}
+
+#[actix_web::test]
+async fn test_graphql_query_matches_rest_query() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_users(&state, "gql_users").await;
+    let app = graphql_app!(state, Permission::read_only());
+
+    let sql = "SELECT id, name FROM gql_users WHERE id >= 2";
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/query")
+        .set_json(json!({ "query": sql }))
+        .to_request();
+    let rest: Value = test::call_and_read_body_json(&app, req).await;
+
+    let req = test::TestRequest::post()
+        .uri(GRAPHQL_PATH)
+        .set_json(json!({
+            "query": "query Users($sql: String!) { query(sql: $sql) { rows columns } }",
+            "variables": { "sql": sql }
+        }))
+        .to_request();
+    let gql: Value = test::call_and_read_body_json(&app, req).await;
+
+    assert!(gql["errors"].is_null(), "unexpected errors: {gql}");
+    assert_eq!(gql["data"]["query"]["rows"], rest["data"]["rows"]);
+    assert_eq!(gql["data"]["query"]["columns"], rest["data"]["columns"]);
+    assert_eq!(gql["data"]["query"]["rows"].as_array().unwrap().len(), 2);
+}
+
+#[actix_web::test]
+async fn test_graphql_table_introspection() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_users(&state, "gql_users").await;
+    let app = graphql_app!(state, Permission::read_only());
+
+    let req = test::TestRequest::post()
+        .uri(GRAPHQL_PATH)
+        .set_json(json!({
+            "query": "{ schemaInfo { tableCount tables { name primaryKey columns { name nullable } } } }"
+        }))
+        .to_request();
+    let gql: Value = test::call_and_read_body_json(&app, req).await;
+
+    let info = &gql["data"]["schemaInfo"];
+    assert_eq!(info["tableCount"], 1);
+    let table = &info["tables"][0];
+    assert_eq!(table["name"], "gql_users");
+    assert_eq!(table["primaryKey"], "id");
+    let columns: Vec<&str> = table["columns"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(columns, vec!["id", "name"]);
+    assert_eq!(table["columns"][1]["nullable"], false);
+}
+
+#[actix_web::test]
+async fn test_graphql_stats_match_rest_stats() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_users(&state, "gql_users").await;
+    let app = graphql_app!(state, Permission::read_only());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/stats/performance")
+        .to_request();
+    let rest: Value = test::call_and_read_body_json(&app, req).await;
+
+    let req = test::TestRequest::post()
+        .uri(GRAPHQL_PATH)
+        .set_json(json!({ "query": "{ stats { databaseMetrics { totalTables } } }" }))
+        .to_request();
+    let gql: Value = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(
+        gql["data"]["stats"]["databaseMetrics"]["totalTables"],
+        rest["data"]["database_metrics"]["total_tables"]
+    );
+}
+
+#[actix_web::test]
+async fn test_graphql_enforces_write_permission() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_users(&state, "gql_users").await;
+    let app = graphql_app!(state, Permission::read_only());
+
+    let req = test::TestRequest::post()
+        .uri(GRAPHQL_PATH)
+        .set_json(json!({
+            "query": "{ query(sql: \"INSERT INTO gql_users (id, name) VALUES (9, 'Eve')\") { rowsAffected } }"
+        }))
+        .to_request();
+    let gql: Value = test::call_and_read_body_json(&app, req).await;
+
+    let errors = gql["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("write permission required"));
+}
@@ -0,0 +1,353 @@
+//! Tests for the gRPC `Database` service
+//!
+//! A generated client talks to a real `tonic` server on a loopback port; query
+//! results are compared against the REST API running on the same state.
+
+mod common;
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+
+use actix_web::dev::Service;
+use actix_web::{test, web, App, HttpMessage};
+use neuroquantum_api::audit::{AuditFilter, AuditOutcome};
+use neuroquantum_api::auth::ApiKey;
+use neuroquantum_api::grpc::proto::database_client::DatabaseClient;
+use neuroquantum_api::grpc::proto::{
+    BeginTransactionRequest, ExecuteRequest, QueryRequest, Row, TransactionRequest,
+};
+use neuroquantum_api::grpc::{self, value_to_json};
+use neuroquantum_api::permissions::Permission;
+use neuroquantum_api::{handlers, ApiConfig, AppState};
+use serde_json::{json, Value};
+use tonic::transport::Channel;
+use tonic::Code;
+
+use common::{create_test_state, create_test_state_with_config, issue_api_key, seed_users};
+
+/// Serve the state's `Database` service on a loopback port and connect to it
+async fn start_server(state: &AppState) -> DatabaseClient<Channel> {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr: SocketAddr = listener.local_addr().unwrap();
+    let incoming = tonic::transport::server::TcpIncoming::from(listener);
+    let service = grpc::service(state.clone());
+    tokio::spawn(async move {
+        tonic::transport::Server::builder()
+            .add_service(service)
+            .serve_with_incoming(incoming)
+            .await
+            .unwrap();
+    });
+
+    DatabaseClient::connect(format!("http://{addr}"))
+        .await
+        .unwrap()
+}
+
+fn authed<T>(message: T, api_key: &ApiKey) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    request
+        .metadata_mut()
+        .insert("x-api-key", api_key.key.parse().unwrap());
+    request
+}
+
+fn row_to_json(row: &Row) -> HashMap<String, Value> {
+    row.fields
+        .iter()
+        .map(|(k, v)| (k.clone(), value_to_json(v)))
+        .collect()
+}
+
+fn query(sql: &str) -> QueryRequest {
+    QueryRequest {
+        sql: sql.to_string(),
+        transaction_id: String::new(),
+    }
+}
+
+#[actix_web::test]
+async fn test_grpc_query_matches_rest_query() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_users(&state, "grpc_users").await;
+    let api_key = issue_api_key(&state, Permission::read_only());
+    let mut client = start_server(&state).await;
+
+    let rest_key = api_key.clone();
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .wrap_fn(move |req, srv| {
+                req.extensions_mut().insert(rest_key.clone());
+                srv.call(req)
+            })
+            .route("/api/v1/query", web::post().to(handlers::execute_sql_query)),
+    )
+    .await;
+
+    let sql = "SELECT id, name FROM grpc_users WHERE id >= 2";
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/query")
+        .set_json(json!({ "query": sql }))
+        .to_request();
+    let rest: Value = test::call_and_read_body_json(&app, req).await;
+
+    let response = client
+        .query(authed(query(sql), &api_key))
+        .await
+        .unwrap()
+        .into_inner();
+
+    let rows: Vec<HashMap<String, Value>> = response.rows.iter().map(row_to_json).collect();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(json!(rows), rest["data"]["rows"]);
+    assert_eq!(json!(response.columns), rest["data"]["columns"]);
+}
+
+#[actix_web::test]
+async fn test_grpc_query_stream_yields_every_row() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_users(&state, "grpc_users").await;
+    let api_key = issue_api_key(&state, Permission::read_only());
+    let mut client = start_server(&state).await;
+
+    let sql = "SELECT id, name FROM grpc_users";
+    let expected = client
+        .query(authed(query(sql), &api_key))
+        .await
+        .unwrap()
+        .into_inner()
+        .rows;
+
+    let mut stream = client
+        .query_stream(authed(query(sql), &api_key))
+        .await
+        .unwrap()
+        .into_inner();
+    let mut streamed = Vec::new();
+    while let Some(row) = stream.message().await.unwrap() {
+        streamed.push(row);
+    }
+
+    assert_eq!(streamed.len(), 3);
+    assert_eq!(streamed, expected);
+}
+
+#[actix_web::test]
+async fn test_grpc_requires_authentication() {
+    let (state, _temp_dir) = create_test_state().await;
+    let mut client = start_server(&state).await;
+
+    let status = client
+        .query(tonic::Request::new(query("SELECT 1")))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::Unauthenticated);
+
+    let mut request = tonic::Request::new(query("SELECT 1"));
+    request
+        .metadata_mut()
+        .insert("x-api-key", "nqdb_not_a_real_key".parse().unwrap());
+    let status = client.query(request).await.unwrap_err();
+    assert_eq!(status.code(), Code::Unauthenticated);
+}
+
+#[actix_web::test]
+async fn test_grpc_calls_are_audited() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_users(&state, "grpc_users").await;
+    let api_key = issue_api_key(&state, Permission::read_only());
+    let mut client = start_server(&state).await;
+
+    let sql = "SELECT id FROM grpc_users";
+    client.query(authed(query(sql), &api_key)).await.unwrap();
+    let status = client
+        .execute(authed(
+            ExecuteRequest {
+                sql: "DELETE FROM grpc_users WHERE id = 1".to_string(),
+                transaction_id: String::new(),
+            },
+            &api_key,
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::PermissionDenied);
+
+    let entries = state
+        .audit_log
+        .query(&AuditFilter {
+            principal: Some("integration-test".to_string()),
+            ..AuditFilter::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].action, "GRPC");
+    assert_eq!(entries[0].resource, "/neuroquantum.database.Database/Query");
+    assert_eq!(entries[0].outcome, AuditOutcome::Success);
+    assert_eq!(entries[0].statement.as_deref(), Some(sql));
+    assert_eq!(entries[0].tables, vec!["grpc_users"]);
+    assert_eq!(
+        entries[1].resource,
+        "/neuroquantum.database.Database/Execute"
+    );
+    assert_eq!(entries[1].status, 403);
+    assert_eq!(entries[1].outcome, AuditOutcome::Failure);
+    assert_eq!(entries[1].tables, vec!["grpc_users"]);
+}
+
+async fn begin(client: &mut DatabaseClient<Channel>, api_key: &ApiKey) -> String {
+    client
+        .begin_transaction(authed(BeginTransactionRequest::default(), api_key))
+        .await
+        .unwrap()
+        .into_inner()
+        .transaction_id
+}
+
+#[actix_web::test]
+async fn test_grpc_idle_transaction_is_aborted() {
+    let mut config = ApiConfig::default();
+    config.server.grpc_transaction_idle_timeout_secs = 1;
+    let (state, _temp_dir) = create_test_state_with_config(config).await;
+    seed_users(&state, "grpc_users").await;
+    let api_key = issue_api_key(&state, Permission::read_write());
+    let mut client = start_server(&state).await;
+
+    let insert = |id: i64, transaction_id: &str| {
+        authed(
+            ExecuteRequest {
+                sql: format!("INSERT INTO grpc_users (id, name) VALUES ({id}, 'Idle')"),
+                transaction_id: transaction_id.to_string(),
+            },
+            &api_key,
+        )
+    };
+
+    // Statements keep a transaction alive past the idle timeout
+    let active = begin(&mut client, &api_key).await;
+    for id in [20, 21, 22] {
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        client.execute(insert(id, &active)).await.unwrap();
+    }
+    let abandoned = begin(&mut client, &api_key).await;
+    client.execute(insert(30, &abandoned)).await.unwrap();
+    client
+        .commit(authed(
+            TransactionRequest {
+                transaction_id: active,
+            },
+            &api_key,
+        ))
+        .await
+        .unwrap();
+
+    tokio::time::sleep(std::time::Duration::from_millis(2500)).await;
+    let status = client
+        .commit(authed(
+            TransactionRequest {
+                transaction_id: abandoned,
+            },
+            &api_key,
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::NotFound);
+
+    // The abandoned insert was rolled back and its lock released
+    let rows = client
+        .query(authed(
+            query("SELECT id FROM grpc_users WHERE id >= 20"),
+            &api_key,
+        ))
+        .await
+        .unwrap()
+        .into_inner()
+        .rows;
+    assert_eq!(rows.len(), 3);
+    client.execute(insert(30, "")).await.unwrap();
+}
+
+#[actix_web::test]
+async fn test_grpc_enforces_write_permission() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_users(&state, "grpc_users").await;
+    let api_key = issue_api_key(&state, Permission::read_only());
+    let mut client = start_server(&state).await;
+
+    let status = client
+        .execute(authed(
+            ExecuteRequest {
+                sql: "INSERT INTO grpc_users (id, name) VALUES (9, 'Eve')".to_string(),
+                transaction_id: String::new(),
+            },
+            &api_key,
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::PermissionDenied);
+
+    let status = client
+        .begin_transaction(authed(BeginTransactionRequest::default(), &api_key))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::PermissionDenied);
+}
+
+#[actix_web::test]
+async fn test_grpc_transaction_commit_and_abort() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_users(&state, "grpc_users").await;
+    let api_key = issue_api_key(&state, Permission::read_write());
+    let mut client = start_server(&state).await;
+
+    for (name, commit, expected) in [("Eve", false, 3), ("Mallory", true, 4)] {
+        let transaction_id = client
+            .begin_transaction(authed(BeginTransactionRequest::default(), &api_key))
+            .await
+            .unwrap()
+            .into_inner()
+            .transaction_id;
+
+        let executed = client
+            .execute(authed(
+                ExecuteRequest {
+                    sql: format!("INSERT INTO grpc_users (id, name) VALUES (10, '{name}')"),
+                    transaction_id: transaction_id.clone(),
+                },
+                &api_key,
+            ))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(executed.rows_affected, 1);
+
+        let finish = authed(TransactionRequest { transaction_id }, &api_key);
+        let finished = if commit {
+            client.commit(finish).await
+        } else {
+            client.abort(finish).await
+        };
+        assert!(finished.unwrap().into_inner().success);
+
+        let rows = client
+            .query(authed(query("SELECT id FROM grpc_users"), &api_key))
+            .await
+            .unwrap()
+            .into_inner()
+            .rows;
+        assert_eq!(rows.len(), expected);
+    }
+
+    let status = client
+        .commit(authed(
+            TransactionRequest {
+                transaction_id: "no-such-transaction".to_string(),
+            },
+            &api_key,
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::NotFound);
+}
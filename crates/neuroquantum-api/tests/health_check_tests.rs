@@ -0,0 +1,124 @@
+//! Tests for the `/health` endpoint reporting live process and storage metrics
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use actix_web::{test, web, App};
+use neuroquantum_api::{health_check, ApiConfig, AppState};
+use neuroquantum_core::storage::pager::PageType;
+use neuroquantum_core::storage::{
+    BufferPoolConfig, BufferPoolManager, PageStorageManager, PagerConfig,
+};
+use neuroquantum_core::NeuroQuantumDBBuilder;
+use serde_json::Value;
+
+async fn create_test_state(config: ApiConfig) -> (AppState, tempfile::TempDir) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db = NeuroQuantumDBBuilder::new()
+        .storage_path(temp_dir.path().to_path_buf())
+        .enable_quantum_optimization(false)
+        .build()
+        .await
+        .expect("Failed to initialize database");
+    let state = AppState::with_database(config, db)
+        .await
+        .expect("Failed to create app state");
+    (state, temp_dir)
+}
+
+#[actix_web::test]
+async fn test_health_reports_live_metrics() {
+    let (state, _temp_dir) = create_test_state(ApiConfig::default()).await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .route("/health", web::get().to(health_check)),
+    )
+    .await;
+
+    let req = test::TestRequest::get().uri("/health").to_request();
+    let first: Value = test::call_and_read_body_json(&app, req).await;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let req = test::TestRequest::get().uri("/health").to_request();
+    let second: Value = test::call_and_read_body_json(&app, req).await;
+
+    let first_uptime = first["data"]["uptime_seconds"].as_f64().unwrap();
+    let second_uptime = second["data"]["uptime_seconds"].as_f64().unwrap();
+    assert!(second_uptime > first_uptime);
+
+    let memory_mb = second["data"]["system_metrics"]["memory_usage_mb"]
+        .as_f64()
+        .unwrap();
+    assert!(memory_mb > 0.0);
+    assert!(second["data"]["row_cache"]["capacity"].as_u64().unwrap() > 0);
+    assert!(second["data"]["buffer_pool"].is_null());
+}
+
+#[actix_web::test]
+async fn test_health_reports_attached_buffer_pool() {
+    let (state, temp_dir) = create_test_state(ApiConfig::default()).await;
+    let pager = Arc::new(
+        PageStorageManager::new(&temp_dir.path().join("pages.db"), PagerConfig::default())
+            .await
+            .unwrap(),
+    );
+    let buffer_pool = Arc::new(
+        BufferPoolManager::new(
+            pager.clone(),
+            BufferPoolConfig {
+                pool_size: 8,
+                enable_background_flush: false,
+                prefetch_enabled: false,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap(),
+    );
+    let page_id = pager.allocate_page(PageType::Data).await.unwrap();
+    buffer_pool.fetch_page(page_id).await.unwrap();
+    state
+        .db
+        .read()
+        .await
+        .storage_mut()
+        .await
+        .attach_page_store(buffer_pool);
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .route("/health", web::get().to(health_check)),
+    )
+    .await;
+    let req = test::TestRequest::get().uri("/health").to_request();
+    let resp: Value = test::call_and_read_body_json(&app, req).await;
+
+    let buffer_pool = &resp["data"]["buffer_pool"];
+    assert_eq!(buffer_pool["total_frames"], 8);
+    assert_eq!(buffer_pool["used_frames"], 1);
+    assert_eq!(buffer_pool["pinned_frames"], 1);
+    assert_eq!(buffer_pool["misses"], 1);
+}
+
+#[actix_web::test]
+async fn test_health_flags_follow_configuration() {
+    let mut config = ApiConfig::default();
+    config.rate_limit.enabled = false;
+    let (state, _temp_dir) = create_test_state(config).await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .route("/health", web::get().to(health_check)),
+    )
+    .await;
+
+    let req = test::TestRequest::get().uri("/health").to_request();
+    let resp: Value = test::call_and_read_body_json(&app, req).await;
+    let data = &resp["data"];
+
+    assert_eq!(data["security"]["rate_limiting_enabled"], false);
+    assert_eq!(data["security"]["jwt_enabled"], false);
+    assert_eq!(data["features"]["quantum_search"], false);
+    assert_eq!(data["features"]["neuromorphic_processing"], true);
+}
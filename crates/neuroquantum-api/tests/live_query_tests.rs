@@ -0,0 +1,143 @@
+//! Tests for live query subscriptions
+//!
+//! One connection follows `SELECT * FROM orders WHERE status = 'open'` while
+//! writes arrive through the shared QSQL engine and storage engine, as they
+//! would from other connections.
+
+use std::sync::Arc;
+
+use neuroquantum_api::websocket::{ConnectionId, LiveQueryRegistry, PubSubManager};
+use neuroquantum_core::storage::{
+    create_test_row, create_test_schema, RowChange, RowChangeKind, StorageEngine, Value,
+};
+use neuroquantum_qsql::QSQLEngine;
+use tempfile::TempDir;
+use tokio::sync::{broadcast, RwLock};
+
+const LIVE_QUERY: &str = "SELECT * FROM orders WHERE status = 'open'";
+
+async fn setup() -> (TempDir, Arc<RwLock<StorageEngine>>, QSQLEngine) {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+    let storage_arc = Arc::new(RwLock::new(storage));
+    let mut engine = QSQLEngine::with_storage(storage_arc.clone()).unwrap();
+    engine
+        .execute_query("CREATE TABLE orders (id INTEGER PRIMARY KEY, status TEXT, amount INTEGER)")
+        .await
+        .unwrap();
+    (temp_dir, storage_arc, engine)
+}
+
+async fn next_change(changes: &mut broadcast::Receiver<RowChange>) -> RowChange {
+    tokio::time::timeout(std::time::Duration::from_secs(5), changes.recv())
+        .await
+        .expect("no row change published")
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_live_query_receives_matching_changes() {
+    let (_temp_dir, storage_arc, mut engine) = setup().await;
+    let pubsub = Arc::new(PubSubManager::new());
+    let registry = LiveQueryRegistry::new(pubsub.clone());
+
+    let subscriber = ConnectionId::new();
+    let storage_query = engine.live_query(LIVE_QUERY).unwrap();
+    let id = registry
+        .subscribe(subscriber, LIVE_QUERY.to_string(), storage_query)
+        .await
+        .unwrap();
+    assert_eq!(
+        pubsub.get_subscribers(&id.channel()).await,
+        vec![subscriber]
+    );
+    let mut changes = storage_arc.read().await.subscribe_changes();
+
+    engine
+        .execute_query("INSERT INTO orders (id, status, amount) VALUES (1, 'open', 10)")
+        .await
+        .unwrap();
+    engine
+        .execute_query("INSERT INTO orders (id, status, amount) VALUES (2, 'closed', 20)")
+        .await
+        .unwrap();
+    engine
+        .execute_query("UPDATE orders SET status = 'closed' WHERE id = 1")
+        .await
+        .unwrap();
+
+    let mut received = Vec::new();
+    for _ in 0..3 {
+        let change = next_change(&mut changes).await;
+        let storage = storage_arc.read().await;
+        received.extend(
+            registry
+                .dispatch(&change, |row, filter| storage.row_matches(row, filter))
+                .await,
+        );
+    }
+
+    // The closed order never enters the result set; closing the open one
+    // removes it again
+    assert_eq!(received.len(), 2);
+    let (conn_id, inserted) = &received[0];
+    assert_eq!(*conn_id, subscriber);
+    assert_eq!(inserted.subscription_id, id);
+    assert_eq!(inserted.change, RowChangeKind::Inserted);
+    assert_eq!(inserted.row.fields.get("amount"), Some(&Value::Integer(10)));
+    let (_, deleted) = &received[1];
+    assert_eq!(deleted.change, RowChangeKind::Deleted);
+    assert_eq!(deleted.row.fields.get("status"), Some(&Value::text("open")));
+
+    // Disconnecting removes the subscription
+    assert_eq!(registry.unsubscribe_connection(subscriber).await, 1);
+    assert!(registry.is_empty());
+    assert!(pubsub.get_subscribers(&id.channel()).await.is_empty());
+    assert!(pubsub.get_connection_subscriptions(subscriber).is_empty());
+}
+
+#[tokio::test]
+async fn test_transactional_changes_published_on_commit() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+    storage
+        .create_table(create_test_schema("users"))
+        .await
+        .unwrap();
+    let mut changes = storage.subscribe_changes();
+
+    let tx_id = storage.begin_transaction().await.unwrap();
+    storage
+        .insert_row_transactional(tx_id, "users", create_test_row(1, "Alice"))
+        .await
+        .unwrap();
+    assert!(changes.try_recv().is_err());
+    storage.commit_transaction(tx_id).await.unwrap();
+    let change = next_change(&mut changes).await;
+    assert_eq!(change.kind, RowChangeKind::Inserted);
+    assert_eq!(change.row.fields.get("name"), Some(&Value::text("Alice")));
+
+    let tx_id = storage.begin_transaction().await.unwrap();
+    storage
+        .insert_row_transactional(tx_id, "users", create_test_row(2, "Bob"))
+        .await
+        .unwrap();
+    storage.rollback_transaction(tx_id).await.unwrap();
+    assert!(changes.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_live_query_rejects_unsupported_queries() {
+    let (_temp_dir, _storage_arc, engine) = setup().await;
+    assert!(engine
+        .live_query("SELECT status, COUNT(*) FROM orders GROUP BY status")
+        .is_err());
+    assert!(engine
+        .live_query("SELECT * FROM orders WHERE id IN (1, 2)")
+        .is_err());
+    assert!(engine.live_query("DELETE FROM orders").is_err());
+
+    let storage_query = engine.live_query(LIVE_QUERY).unwrap();
+    assert_eq!(storage_query.table, "orders");
+    assert_eq!(storage_query.where_clause.unwrap().conditions.len(), 1);
+}
@@ -0,0 +1,271 @@
+//! Tests for the storage maintenance endpoints and metrics
+//!
+//! Each operation runs through `POST /api/v1/admin/maintenance/{operation}`
+//! against a temporary database, and the maintenance gauges are read back
+//! from the Prometheus `/metrics` endpoint.
+
+mod common;
+
+use std::sync::Arc;
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{Service, ServiceResponse};
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpMessage};
+use neuroquantum_api::permissions::Permission;
+use neuroquantum_api::{handlers, AppState};
+use neuroquantum_core::storage::pager::{PageId, PageType};
+use neuroquantum_core::storage::{
+    BufferPoolConfig, BufferPoolManager, PageStorageManager, PagerConfig,
+};
+use serde_json::{json, Value};
+
+use common::{create_test_state, test_api_key};
+
+/// Test service with the query, maintenance and metrics routes
+macro_rules! init_app {
+    ($state:expr, $permissions:expr) => {{
+        let permissions: Vec<String> = $permissions;
+        test::init_service(
+            App::new()
+                .app_data(web::Data::new($state))
+                .wrap_fn(move |req, srv| {
+                    req.extensions_mut()
+                        .insert(test_api_key(permissions.clone()));
+                    srv.call(req)
+                })
+                .route("/api/v1/query", web::post().to(handlers::execute_sql_query))
+                .route(
+                    "/api/v1/admin/maintenance/{operation}",
+                    web::post().to(handlers::run_maintenance),
+                )
+                .route("/metrics", web::get().to(neuroquantum_api::metrics)),
+        )
+        .await
+    }};
+}
+
+fn maintenance_request(operation: &str) -> test::TestRequest {
+    test::TestRequest::post().uri(&format!("/api/v1/admin/maintenance/{operation}"))
+}
+
+fn sql_request(query: &str) -> test::TestRequest {
+    test::TestRequest::post()
+        .uri("/api/v1/query")
+        .set_json(json!({ "query": query }))
+}
+
+async fn read_response<B: MessageBody>(resp: ServiceResponse<B>) -> (StatusCode, Value) {
+    let status = resp.status();
+    (status, test::read_body_json(resp).await)
+}
+
+/// Send a request to the test service, returning the status and JSON body
+macro_rules! call {
+    ($app:expr, $request:expr) => {
+        read_response(test::call_service(&$app, $request.to_request()).await).await
+    };
+}
+
+/// Value of the sample `series` in Prometheus text output
+fn sample(metrics: &str, series: &str) -> Option<f64> {
+    metrics
+        .lines()
+        .find_map(|line| line.strip_prefix(series)?.trim().parse().ok())
+}
+
+/// Attach a page store whose last two pages are free, returning the pool
+/// and its used page
+async fn attach_page_store_with_free_tail(
+    state: &AppState,
+    path: &std::path::Path,
+) -> (Arc<BufferPoolManager>, PageId) {
+    let pager = Arc::new(
+        PageStorageManager::new(path, PagerConfig::default())
+            .await
+            .unwrap(),
+    );
+    let mut page_ids = Vec::new();
+    for _ in 0..3 {
+        page_ids.push(pager.allocate_page(PageType::Data).await.unwrap());
+    }
+    pager.deallocate_page(page_ids[1]).await.unwrap();
+    pager.deallocate_page(page_ids[2]).await.unwrap();
+
+    let buffer_pool = Arc::new(
+        BufferPoolManager::new(
+            pager,
+            BufferPoolConfig {
+                pool_size: 8,
+                enable_background_flush: false,
+                prefetch_enabled: false,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap(),
+    );
+    state
+        .db
+        .read()
+        .await
+        .storage_mut()
+        .await
+        .attach_page_store(Arc::clone(&buffer_pool));
+    (buffer_pool, page_ids[0])
+}
+
+#[actix_web::test]
+async fn test_checkpoint_returns_lsn() {
+    let (state, _temp_dir) = create_test_state().await;
+    let app = init_app!(state, Permission::admin_permissions());
+
+    let (status, body) = call!(app, maintenance_request("checkpoint"));
+    assert_eq!(status, StatusCode::OK);
+    let report = &body["data"];
+    assert_eq!(report["operation"], "checkpoint");
+    let first_lsn = report["checkpoint_lsn"].as_u64().unwrap();
+    assert!(first_lsn > 0);
+    assert!(report["vacuum"].is_null());
+    assert!(report["wal"].is_null());
+
+    let (_, body) = call!(app, maintenance_request("checkpoint"));
+    assert!(body["data"]["checkpoint_lsn"].as_u64().unwrap() > first_lsn);
+}
+
+#[actix_web::test]
+async fn test_vacuum_compacts_pages_and_keeps_live_rows() {
+    let (state, temp_dir) = create_test_state().await;
+    let pages_path = temp_dir.path().join("pages.db");
+    attach_page_store_with_free_tail(&state, &pages_path).await;
+    let app = init_app!(state, Permission::admin_permissions());
+
+    for query in [
+        "CREATE TABLE vacuum_items (id INTEGER PRIMARY KEY, name TEXT)",
+        "INSERT INTO vacuum_items (id, name) VALUES (1, 'kept')",
+        "INSERT INTO vacuum_items (id, name) VALUES (2, 'deleted')",
+        "INSERT INTO vacuum_items (id, name) VALUES (3, 'deleted')",
+        "DELETE FROM vacuum_items WHERE id > 1",
+    ] {
+        let (status, _) = call!(app, sql_request(query));
+        assert!(status.is_success(), "query failed: {query}");
+    }
+
+    let (status, body) = call!(app, maintenance_request("vacuum"));
+    assert_eq!(status, StatusCode::OK);
+    let vacuum = &body["data"]["vacuum"];
+    assert!(vacuum["tables_scanned"].as_u64().unwrap() >= 1);
+    assert_eq!(vacuum["pages_compacted"], 2);
+    assert!(body["data"]["checkpoint_lsn"].is_null());
+
+    // Metadata page plus the one used page remain
+    let file_size = tokio::fs::metadata(&pages_path).await.unwrap().len();
+    assert_eq!(file_size, 2 * 4096);
+
+    // Nothing left to reclaim, and the remaining row is still readable
+    let (_, body) = call!(app, maintenance_request("vacuum"));
+    assert_eq!(body["data"]["vacuum"]["compressed_blocks_removed"], 0);
+    assert_eq!(body["data"]["vacuum"]["pages_compacted"], 0);
+    let (_, result) = call!(app, sql_request("SELECT * FROM vacuum_items"));
+    assert_eq!(result["data"]["rows"].as_array().unwrap().len(), 1);
+}
+
+#[actix_web::test]
+async fn test_prune_wal_removes_records_before_checkpoint() {
+    let (state, _temp_dir) = create_test_state().await;
+    let app = init_app!(state, Permission::admin_permissions());
+
+    let (_, body) = call!(app, maintenance_request("checkpoint"));
+    let first_lsn = body["data"]["checkpoint_lsn"].as_u64().unwrap();
+
+    let (status, body) = call!(app, maintenance_request("prune-wal"));
+    assert_eq!(status, StatusCode::OK);
+    let report = &body["data"];
+    let checkpoint_lsn = report["checkpoint_lsn"].as_u64().unwrap();
+    assert!(checkpoint_lsn > first_lsn);
+
+    let wal = &report["wal"];
+    assert_eq!(
+        wal["pruned_through_lsn"].as_u64().unwrap(),
+        checkpoint_lsn - 1
+    );
+    assert!(wal["records_pruned"].as_u64().unwrap() >= 1);
+    // Only the new checkpoint record is left
+    assert_eq!(wal["records_kept"], 1);
+    assert!(wal["bytes_reclaimed"].as_u64().unwrap() > 0);
+}
+
+#[actix_web::test]
+async fn test_prune_wal_flushes_dirty_pages_first() {
+    let (state, temp_dir) = create_test_state().await;
+    let (buffer_pool, page_id) =
+        attach_page_store_with_free_tail(&state, &temp_dir.path().join("pages.db")).await;
+    let app = init_app!(state, Permission::admin_permissions());
+
+    buffer_pool.fetch_page(page_id).await.unwrap();
+    buffer_pool.unpin_page(page_id, true).await.unwrap();
+    assert_eq!(buffer_pool.stats().await.dirty_frames, 1);
+    let syncs_before = buffer_pool.pager().sync_count().await;
+
+    let (status, _) = call!(app, maintenance_request("prune-wal"));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(buffer_pool.stats().await.dirty_frames, 0);
+    assert!(buffer_pool.pager().sync_count().await > syncs_before);
+}
+
+#[actix_web::test]
+async fn test_unknown_maintenance_operation_is_not_found() {
+    let (state, _temp_dir) = create_test_state().await;
+    let app = init_app!(state, Permission::admin_permissions());
+
+    let (status, _) = call!(app, maintenance_request("defragment"));
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn test_maintenance_requires_admin() {
+    let (state, _temp_dir) = create_test_state().await;
+    let app = init_app!(state, Permission::read_write());
+
+    for operation in ["checkpoint", "vacuum", "prune-wal"] {
+        let (status, _) = call!(app, maintenance_request(operation));
+        assert_eq!(status, StatusCode::FORBIDDEN, "{operation}");
+    }
+}
+
+#[actix_web::test]
+async fn test_maintenance_metrics() {
+    let (state, temp_dir) = create_test_state().await;
+    attach_page_store_with_free_tail(&state, &temp_dir.path().join("pages.db")).await;
+    let app = init_app!(state, Permission::admin_permissions());
+
+    let service = &app;
+    let metrics = move || async move {
+        let req = test::TestRequest::get().uri("/metrics").to_request();
+        let body = test::call_and_read_body(service, req).await;
+        String::from_utf8(body.to_vec()).unwrap()
+    };
+
+    // Two of the four pages are free
+    let text = metrics().await;
+    assert_eq!(
+        sample(&text, "neuroquantum_storage_free_page_ratio"),
+        Some(0.5)
+    );
+    assert!(sample(&text, "neuroquantum_seconds_since_last_checkpoint").is_some());
+
+    for operation in ["vacuum", "checkpoint"] {
+        let (status, _) = call!(app, maintenance_request(operation));
+        assert_eq!(status, StatusCode::OK, "{operation}");
+    }
+    let text = metrics().await;
+    assert_eq!(
+        sample(&text, "neuroquantum_storage_free_page_ratio"),
+        Some(0.0)
+    );
+    let since_checkpoint = sample(&text, "neuroquantum_seconds_since_last_checkpoint").unwrap();
+    assert!(
+        (0.0..60.0).contains(&since_checkpoint),
+        "unexpected age: {since_checkpoint}"
+    );
+}
@@ -12,8 +12,10 @@ fn test_is_public_endpoint() {
     assert!(is_public_endpoint("/health"));
     assert!(is_public_endpoint("/metrics"));
     assert!(is_public_endpoint("/api/v1/auth/login"));
+    assert!(is_public_endpoint("/api/v2/auth/login"));
     assert!(is_public_endpoint("/api-docs/"));
     assert!(!is_public_endpoint("/api/v1/tables"));
+    assert!(!is_public_endpoint("/api/v9/auth/login"));
     assert!(!is_public_endpoint("/api/v1/neural/train"));
 }
 
@@ -0,0 +1,229 @@
+//! Tests for mutual TLS client authentication
+//!
+//! The server runs on a real HTTPS listener from `http_server`, using the
+//! certificates in `tests/fixtures/mtls`.
+
+use std::path::PathBuf;
+
+use neuroquantum_api::audit::AuditFilter;
+use neuroquantum_api::config::{ApiConfig, ClientAuthMode, ClientCertificatePrincipal, TlsConfig};
+use neuroquantum_api::tls::{principal_for, ClientIdentity};
+use neuroquantum_api::{http_server, AppState};
+use neuroquantum_core::NeuroQuantumDBBuilder;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::CertificateDer;
+use serde_json::Value;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/mtls")
+        .join(name)
+}
+
+fn mtls_config(client_auth: ClientAuthMode) -> ApiConfig {
+    let mut config = ApiConfig::default();
+    config.server.workers = Some(1);
+    config.server.tls = Some(TlsConfig {
+        cert_file: fixture("server.pem").to_string_lossy().into_owned(),
+        key_file: fixture("server.key").to_string_lossy().into_owned(),
+        ca_file: Some(fixture("ca.pem").to_string_lossy().into_owned()),
+        client_auth,
+    });
+    config.auth.client_certificates = vec![ClientCertificatePrincipal {
+        identity: "reporting-service".to_string(),
+        permissions: vec!["read".to_string()],
+    }];
+    config
+}
+
+/// Start an HTTPS server, returning its state, base URL and handle
+async fn start(
+    config: ApiConfig,
+) -> (
+    AppState,
+    String,
+    actix_web::dev::ServerHandle,
+    tempfile::TempDir,
+) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db = NeuroQuantumDBBuilder::new()
+        .storage_path(temp_dir.path().to_path_buf())
+        .build()
+        .await
+        .expect("Failed to initialize database");
+    let state = AppState::with_database(config, db)
+        .await
+        .expect("Failed to create app state");
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!(
+        "https://127.0.0.1:{}",
+        listener.local_addr().unwrap().port()
+    );
+    let server = http_server(state.clone(), listener).unwrap();
+    let handle = server.handle();
+    actix_web::rt::spawn(server);
+    (state, url, handle, temp_dir)
+}
+
+fn client(identity: Option<&str>) -> reqwest::Client {
+    let ca = std::fs::read(fixture("ca.pem")).unwrap();
+    let mut builder = reqwest::Client::builder()
+        .use_rustls_tls()
+        .tls_built_in_root_certs(false)
+        .add_root_certificate(reqwest::Certificate::from_pem(&ca).unwrap());
+    if let Some(name) = identity {
+        let mut pem = std::fs::read(fixture(&format!("{name}.pem"))).unwrap();
+        pem.extend(std::fs::read(fixture(&format!("{name}.key"))).unwrap());
+        builder = builder.identity(reqwest::Identity::from_pem(&pem).unwrap());
+    }
+    builder.build().unwrap()
+}
+
+#[test]
+fn test_client_identity_maps_to_principal() {
+    let cert = CertificateDer::from_pem_file(fixture("client.pem")).unwrap();
+    let identity = ClientIdentity::from_der(&cert).unwrap();
+    assert_eq!(identity.common_name.as_deref(), Some("reporting-service"));
+    assert_eq!(
+        identity.subject_alt_names,
+        vec!["reporting.internal", "reports@example.com"]
+    );
+
+    let config = mtls_config(ClientAuthMode::Required);
+    let api_key = principal_for(&config.auth, &identity).unwrap();
+    assert_eq!(api_key.name, "reporting-service");
+    assert_eq!(api_key.permissions, vec!["read"]);
+    assert_eq!(api_key.key, identity.key());
+    assert!(api_key.key.starts_with("mtls_"));
+    assert_eq!(api_key.expires_at, identity.not_after);
+
+    // Subject alternative names identify the client as well
+    let mut auth = config.auth.clone();
+    auth.client_certificates[0].identity = "reporting.internal".to_string();
+    assert!(principal_for(&auth, &identity).is_some());
+    auth.client_certificates[0].identity = "billing-service".to_string();
+    assert!(principal_for(&auth, &identity).is_none());
+}
+
+#[test]
+fn test_client_identity_key_is_per_certificate_holder() {
+    let cert = CertificateDer::from_pem_file(fixture("client.pem")).unwrap();
+    let identity = ClientIdentity::from_der(&cert).unwrap();
+    assert_eq!(
+        identity.key(),
+        ClientIdentity::from_der(&cert).unwrap().key()
+    );
+
+    // Clients sharing a principal entry still own their queries separately
+    let mut other = identity.clone();
+    other.common_name = Some("reporting-service-2".to_string());
+    assert_ne!(identity.key(), other.key());
+
+    let mut config = mtls_config(ClientAuthMode::Required);
+    config.auth.client_certificates[0].identity = "reporting.internal".to_string();
+    other.common_name = None;
+    let first = principal_for(&config.auth, &identity).unwrap();
+    let second = principal_for(&config.auth, &other).unwrap();
+    assert_eq!(first.name, second.name);
+    assert_ne!(first.key, second.key);
+}
+
+#[actix_web::test]
+async fn test_trusted_client_certificate_authenticates() {
+    let (state, url, handle, _temp_dir) = start(mtls_config(ClientAuthMode::Required)).await;
+
+    let resp = client(Some("client"))
+        .get(format!("{url}/api/v1/stats/performance"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::OK);
+    let body: Value = serde_json::from_str(&resp.text().await.unwrap()).unwrap();
+    assert_eq!(body["success"], true);
+
+    let entries = state.audit_log.query(&AuditFilter::default()).await;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].principal, "reporting-service");
+
+    handle.stop(true).await;
+}
+
+#[actix_web::test]
+async fn test_untrusted_client_certificate_is_rejected_in_handshake() {
+    let (state, url, handle, _temp_dir) = start(mtls_config(ClientAuthMode::Required)).await;
+
+    // Same subject, signed by a CA the server doesn't trust
+    let result = client(Some("untrusted-client"))
+        .get(format!("{url}/api/v1/stats/performance"))
+        .send()
+        .await;
+    assert!(result.is_err(), "untrusted certificate was accepted");
+
+    // Without a certificate the handshake fails as well
+    let result = client(None).get(format!("{url}/health")).send().await;
+    assert!(
+        result.is_err(),
+        "connection without certificate was accepted"
+    );
+
+    assert!(state
+        .audit_log
+        .query(&AuditFilter::default())
+        .await
+        .is_empty());
+
+    handle.stop(true).await;
+}
+
+#[actix_web::test]
+async fn test_client_certificates_coexist_with_api_keys() {
+    let mut config = mtls_config(ClientAuthMode::Optional);
+    let (state, url, handle, _temp_dir) = start(config.clone()).await;
+    let api_key = state
+        .auth_service
+        .clone()
+        .generate_api_key(
+            "mtls-api-key".to_string(),
+            vec!["read".to_string()],
+            Some(1),
+            None,
+        )
+        .unwrap()
+        .key;
+
+    // Optional mode admits clients without a certificate, which use API keys
+    let resp = client(None)
+        .get(format!("{url}/api/v1/stats/performance"))
+        .header("X-API-Key", api_key.as_str())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::OK);
+
+    let resp = client(None)
+        .get(format!("{url}/api/v1/stats/performance"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    let resp = client(Some("client"))
+        .get(format!("{url}/api/v1/stats/performance"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::OK);
+    handle.stop(true).await;
+
+    // Disabling certificate authentication leaves only the other methods
+    config.auth.client_certificate = false;
+    let (_state, url, handle, _temp_dir) = start(config).await;
+    let resp = client(Some("client"))
+        .get(format!("{url}/api/v1/stats/performance"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::UNAUTHORIZED);
+    handle.stop(true).await;
+}
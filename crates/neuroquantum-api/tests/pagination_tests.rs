@@ -0,0 +1,286 @@
+//! Tests for cursor-based pagination on `POST /api/v1/tables/{table_name}/query`
+
+mod common;
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use actix_web::dev::Service;
+use actix_web::{test, web, App, HttpMessage};
+use neuroquantum_api::handlers;
+use neuroquantum_api::permissions::Permission;
+use neuroquantum_core::storage::{
+    ColumnDefinition, ComparisonOperator, Condition, DataType, DeleteQuery, Row, TableSchema,
+    Value, WhereClause,
+};
+use neuroquantum_core::{NeuroQuantumDB, NeuroQuantumDBBuilder};
+use serde_json::{json, Value as JsonValue};
+use tokio::sync::RwLock;
+
+use common::test_api_key;
+
+async fn create_test_db() -> (Arc<RwLock<NeuroQuantumDB>>, tempfile::TempDir) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db = NeuroQuantumDBBuilder::new()
+        .storage_path(temp_dir.path().to_path_buf())
+        .build()
+        .await
+        .expect("Failed to initialize database");
+    (Arc::new(RwLock::new(db)), temp_dir)
+}
+
+async fn create_items_table(db: &Arc<RwLock<NeuroQuantumDB>>, count: i64) {
+    let db_lock = db.write().await;
+    let mut storage = db_lock.storage_mut().await;
+
+    let schema = TableSchema {
+        name: "items".to_string(),
+        columns: vec![
+            ColumnDefinition {
+                name: "id".to_string(),
+                data_type: DataType::Integer,
+                nullable: false,
+                default_value: None,
+                auto_increment: false,
+                ..Default::default()
+            },
+            ColumnDefinition {
+                name: "label".to_string(),
+                data_type: DataType::Text,
+                nullable: false,
+                default_value: None,
+                auto_increment: false,
+                ..Default::default()
+            },
+        ],
+        primary_key: "id".to_string(),
+        created_at: chrono::Utc::now(),
+        version: 1,
+        auto_increment_columns: HashMap::new(),
+        id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
+        foreign_keys: Vec::new(),
+        ..Default::default()
+    };
+    storage.create_table(schema).await.unwrap();
+
+    for i in 1..=count {
+        let mut fields = HashMap::new();
+        fields.insert("id".to_string(), Value::Integer(i));
+        fields.insert("label".to_string(), Value::text(format!("item_{i}")));
+        let row = Row {
+            id: 0,
+            fields,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        };
+        storage.insert_row("items", row).await.unwrap();
+    }
+}
+
+#[actix_web::test]
+async fn test_cursor_pagination_visits_every_row_once() {
+    let (db, _temp_dir) = create_test_db().await;
+    create_items_table(&db, 250).await;
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(db.clone()))
+            .wrap_fn(|req, srv| {
+                req.extensions_mut()
+                    .insert(test_api_key(Permission::read_only()));
+                srv.call(req)
+            })
+            .route(
+                "/api/v1/tables/{table_name}/query",
+                web::post().to(handlers::query_data),
+            ),
+    )
+    .await;
+
+    let mut seen = HashSet::new();
+    let mut page_sizes = Vec::new();
+    let mut cursor: Option<String> = None;
+
+    loop {
+        let uri = match &cursor {
+            | Some(c) => format!("/api/v1/tables/items/query?limit=100&cursor={c}"),
+            | None => "/api/v1/tables/items/query?limit=100".to_string(),
+        };
+        let req = test::TestRequest::post()
+            .uri(&uri)
+            .set_json(json!({ "table_name": "items" }))
+            .to_request();
+        let resp: JsonValue = test::call_and_read_body_json(&app, req).await;
+        let data = &resp["data"];
+
+        let records = data["records"].as_array().unwrap();
+        page_sizes.push(records.len());
+        for record in records {
+            let id = record["id"].as_i64().unwrap();
+            assert!(seen.insert(id), "row {id} returned twice");
+        }
+
+        if data["next_cursor"].is_null() {
+            assert_eq!(data["has_more"], false);
+            break;
+        }
+        cursor = data["next_cursor"].as_str().map(str::to_string);
+    }
+
+    assert_eq!(page_sizes, vec![100, 100, 50]);
+    assert_eq!(seen.len(), 250);
+    assert_eq!(seen, (1..=250).collect::<HashSet<i64>>());
+}
+
+#[actix_web::test]
+async fn test_cursor_pagination_is_stable_under_inserts() {
+    let (db, _temp_dir) = create_test_db().await;
+    create_items_table(&db, 20).await;
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(db.clone()))
+            .wrap_fn(|req, srv| {
+                req.extensions_mut()
+                    .insert(test_api_key(Permission::read_only()));
+                srv.call(req)
+            })
+            .route(
+                "/api/v1/tables/{table_name}/query",
+                web::post().to(handlers::query_data),
+            ),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/tables/items/query?limit=10")
+        .set_json(json!({ "table_name": "items" }))
+        .to_request();
+    let first: JsonValue = test::call_and_read_body_json(&app, req).await;
+    let cursor = first["data"]["next_cursor"].as_str().unwrap().to_string();
+
+    // Insert a row that sorts before the cursor; it must not shift the next page
+    {
+        let db_lock = db.write().await;
+        let mut storage = db_lock.storage_mut().await;
+        let mut fields = HashMap::new();
+        fields.insert("id".to_string(), Value::Integer(0));
+        fields.insert("label".to_string(), Value::text("late_arrival"));
+        let row = Row {
+            id: 0,
+            fields,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        };
+        storage.insert_row("items", row).await.unwrap();
+    }
+
+    let req = test::TestRequest::post()
+        .uri(&format!(
+            "/api/v1/tables/items/query?limit=10&cursor={cursor}"
+        ))
+        .set_json(json!({ "table_name": "items" }))
+        .to_request();
+    let second: JsonValue = test::call_and_read_body_json(&app, req).await;
+    let ids: Vec<i64> = second["data"]["records"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["id"].as_i64().unwrap())
+        .collect();
+    assert_eq!(ids, (11..=20).collect::<Vec<_>>());
+    assert!(second["data"]["next_cursor"].is_null());
+}
+
+#[actix_web::test]
+async fn test_cursor_page_reads_only_its_rows() {
+    let (db, _temp_dir) = create_test_db().await;
+    create_items_table(&db, 250).await;
+    {
+        let db_lock = db.write().await;
+        let mut storage = db_lock.storage_mut().await;
+        let query = DeleteQuery {
+            table: "items".to_string(),
+            where_clause: Some(WhereClause {
+                conditions: vec![Condition {
+                    field: "id".to_string(),
+                    operator: ComparisonOperator::Equal,
+                    value: Value::Integer(105),
+                }],
+            }),
+        };
+        storage.delete_rows(&query).await.unwrap();
+    }
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(db.clone()))
+            .wrap_fn(|req, srv| {
+                req.extensions_mut()
+                    .insert(test_api_key(Permission::read_only()));
+                srv.call(req)
+            })
+            .route(
+                "/api/v1/tables/{table_name}/query",
+                web::post().to(handlers::query_data),
+            ),
+    )
+    .await;
+
+    let cursor = encode_cursor(&Value::Integer(99));
+    let req = test::TestRequest::post()
+        .uri(&format!(
+            "/api/v1/tables/items/query?limit=10&cursor={cursor}"
+        ))
+        .set_json(json!({ "table_name": "items" }))
+        .to_request();
+    let resp: JsonValue = test::call_and_read_body_json(&app, req).await;
+    let data = &resp["data"];
+
+    // Numeric key order, skipping the deleted row
+    let ids: Vec<i64> = data["records"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["id"].as_i64().unwrap())
+        .collect();
+    assert_eq!(ids, vec![100, 101, 102, 103, 104, 106, 107, 108, 109, 110]);
+
+    // The range scan stops one row past the page instead of reading the table
+    assert_eq!(data["query_stats"]["rows_scanned"], 11);
+    assert_eq!(data["query_stats"]["indexes_used"], json!(["items_id"]));
+}
+
+/// Cursor resuming after `key`, as encoded by the query endpoint
+fn encode_cursor(key: &Value) -> String {
+    use base64::Engine;
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(serde_json::to_vec(key).unwrap())
+}
+
+#[actix_web::test]
+async fn test_malformed_cursor_is_rejected() {
+    let (db, _temp_dir) = create_test_db().await;
+    create_items_table(&db, 5).await;
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(db.clone()))
+            .wrap_fn(|req, srv| {
+                req.extensions_mut()
+                    .insert(test_api_key(Permission::read_only()));
+                srv.call(req)
+            })
+            .route(
+                "/api/v1/tables/{table_name}/query",
+                web::post().to(handlers::query_data),
+            ),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/tables/items/query?cursor=not*a*cursor")
+        .set_json(json!({ "table_name": "items" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_client_error());
+}
@@ -2,7 +2,7 @@
 //!
 //! These tests validate permission creation, validation, and helper methods.
 
-use neuroquantum_api::permissions::{Permission, ADMIN, READ, WRITE};
+use neuroquantum_api::permissions::{Permission, ScopeRule, ADMIN, ANY_ACTION, READ, WRITE};
 
 #[test]
 fn test_admin_permissions() {
@@ -52,3 +52,47 @@ fn test_to_owned() {
     assert_eq!(perms[0], "read");
     assert_eq!(perms[1], "write");
 }
+
+#[test]
+fn test_scope_rule_wildcards() {
+    let rule = ScopeRule::new("app_*", &[READ]);
+    assert!(rule.matches("app_events"));
+    assert!(rule.matches("APP_Users"));
+    assert!(rule.matches("app_"));
+    assert!(!rule.matches("application"));
+    assert!(!rule.matches("my_app_events"));
+
+    let rule = ScopeRule::new("*_log*", &[READ]);
+    assert!(rule.matches("audit_log"));
+    assert!(rule.matches("access_logs_2024"));
+    assert!(!rule.matches("audit"));
+
+    assert!(ScopeRule::new("*", &[READ]).matches("anything"));
+}
+
+#[test]
+fn test_scope_allows_first_matching_rule() {
+    let scopes = vec![
+        ScopeRule::new("audit", &[]),
+        ScopeRule::new("users", &[READ]),
+        ScopeRule::new("*", &[ANY_ACTION]),
+    ];
+
+    assert!(!Permission::scope_allows(&scopes, "audit", READ));
+    assert!(Permission::scope_allows(&scopes, "users", READ));
+    assert!(!Permission::scope_allows(&scopes, "users", WRITE));
+    assert!(Permission::scope_allows(&scopes, "orders", WRITE));
+
+    // Without a catch-all rule unmatched tables are denied
+    assert!(!Permission::scope_allows(&scopes[..2], "orders", READ));
+    // No rules at all means the key is unrestricted
+    assert!(Permission::scope_allows(&[], "audit", WRITE));
+}
+
+#[test]
+fn test_scope_rule_validation() {
+    assert!(ScopeRule::new("users", &[READ, WRITE]).validate().is_ok());
+    assert!(ScopeRule::new("users", &[]).validate().is_ok());
+    assert!(ScopeRule::new("", &[READ]).validate().is_err());
+    assert!(ScopeRule::new("users", &["drop"]).validate().is_err());
+}
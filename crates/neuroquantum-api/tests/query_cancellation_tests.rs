@@ -0,0 +1,116 @@
+//! Tests for cancelling in-flight queries by request ID
+//!
+//! A query is kept in flight by holding the shared QSQL engine lock while the
+//! cancellation request is sent; releasing the lock lets the query observe the
+//! cancelled token.
+
+mod common;
+
+use std::time::Duration;
+
+use actix_web::dev::Service;
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpMessage};
+use neuroquantum_api::permissions::Permission;
+use neuroquantum_api::{handlers, middleware};
+use serde_json::{json, Value};
+
+use common::{create_test_state, seed_users, test_api_key};
+
+const REQUEST_ID: &str = "slow-query-1";
+
+fn cancel_request(request_id: &str) -> test::TestRequest {
+    test::TestRequest::post().uri(&format!("/api/v1/query/{request_id}/cancel"))
+}
+
+#[actix_web::test]
+async fn test_cancel_in_flight_query() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_users(&state, "cancel_users").await;
+    let running_queries = state.running_queries.clone();
+    let qsql_engine = state.qsql_engine.clone();
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .wrap_fn(|req, srv| {
+                req.extensions_mut()
+                    .insert(test_api_key(Permission::read_only()));
+                srv.call(req)
+            })
+            .wrap(middleware::tracing_middleware())
+            .route("/api/v1/query", web::post().to(handlers::execute_sql_query))
+            .route(
+                "/api/v1/query/{request_id}/cancel",
+                web::post().to(handlers::cancel_query),
+            ),
+    )
+    .await;
+
+    // Keep the query waiting for the engine until it has been cancelled
+    let engine_guard = qsql_engine.lock().await;
+
+    let query = test::TestRequest::post()
+        .uri("/api/v1/query")
+        .insert_header(("X-Request-ID", REQUEST_ID))
+        .set_json(json!({ "query": "SELECT id, name FROM cancel_users" }))
+        .to_request();
+
+    let cancel = async {
+        while !running_queries.is_running(REQUEST_ID) {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        let resp = test::call_service(&app, cancel_request(REQUEST_ID).to_request()).await;
+        drop(engine_guard);
+        resp
+    };
+
+    let (query_resp, cancel_resp) = futures_util::join!(test::call_service(&app, query), cancel);
+
+    assert_eq!(cancel_resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(cancel_resp).await;
+    assert_eq!(body["data"]["request_id"], REQUEST_ID);
+    assert_eq!(body["data"]["cancelled"], true);
+
+    assert_eq!(query_resp.status(), StatusCode::CONFLICT);
+    let body: Value = test::read_body_json(query_resp).await;
+    assert_eq!(body["success"], false);
+    assert!(body["error"]["QueryCancelled"].is_object());
+
+    // The query is no longer registered once it has finished
+    assert!(!running_queries.is_running(REQUEST_ID));
+    let resp = test::call_service(&app, cancel_request(REQUEST_ID).to_request()).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    // The engine is usable again for later queries
+    let query = test::TestRequest::post()
+        .uri("/api/v1/query")
+        .set_json(json!({ "query": "SELECT id, name FROM cancel_users" }))
+        .to_request();
+    let resp = test::call_service(&app, query).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn test_cancel_unknown_query_is_not_found() {
+    let (state, _temp_dir) = create_test_state().await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .wrap_fn(|req, srv| {
+                req.extensions_mut()
+                    .insert(test_api_key(Permission::read_only()));
+                srv.call(req)
+            })
+            .route(
+                "/api/v1/query/{request_id}/cancel",
+                web::post().to(handlers::cancel_query),
+            ),
+    )
+    .await;
+
+    let resp = test::call_service(&app, cancel_request("no-such-query").to_request()).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["success"], false);
+}
@@ -0,0 +1,110 @@
+//! Tests for per-statement query metrics
+//!
+//! Queries run through `POST /api/v1/query` and the resulting series are read
+//! back from the Prometheus `/metrics` endpoint.
+
+mod common;
+
+use actix_web::dev::Service;
+use actix_web::{test, web, App, HttpMessage};
+use neuroquantum_api::handlers;
+use neuroquantum_api::metrics::StatementLabels;
+use neuroquantum_api::permissions::Permission;
+use serde_json::json;
+
+use common::{create_test_state, test_api_key};
+
+/// Create application state backed by a temporary database
+/// Value of the sample `series` in Prometheus text output
+fn sample(metrics: &str, series: &str) -> Option<f64> {
+    metrics
+        .lines()
+        .find_map(|line| line.strip_prefix(series)?.trim().parse().ok())
+}
+
+#[actix_web::test]
+async fn test_query_metrics_by_statement_type() {
+    let (state, _temp_dir) = create_test_state().await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .wrap_fn(|req, srv| {
+                req.extensions_mut()
+                    .insert(test_api_key(Permission::read_write()));
+                srv.call(req)
+            })
+            .route("/api/v1/query", web::post().to(handlers::execute_sql_query))
+            .route("/metrics", web::get().to(neuroquantum_api::metrics)),
+    )
+    .await;
+
+    for (query, succeeds) in [
+        (
+            "CREATE TABLE metrics_orders (id INTEGER PRIMARY KEY, amount INTEGER)",
+            true,
+        ),
+        (
+            "INSERT INTO metrics_orders (id, amount) VALUES (1, 10)",
+            true,
+        ),
+        ("SELECT * FROM metrics_orders", true),
+        ("SELECT * FROM metrics_orders WHERE id = 1", true),
+        ("SELECT * FROM metrics_missing_table", false),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/api/v1/query")
+            .set_json(json!({ "query": query }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status().is_success(), succeeds, "{query}");
+    }
+
+    let req = test::TestRequest::get().uri("/metrics").to_request();
+    let body = test::call_and_read_body(&app, req).await;
+    let metrics = String::from_utf8(body.to_vec()).unwrap();
+
+    let count = |statement: &str| {
+        sample(
+            &metrics,
+            &format!(
+                "neuroquantum_query_duration_seconds_count{{statement=\"{statement}\",table=\"metrics_orders\"}}"
+            ),
+        )
+        .unwrap_or_default()
+    };
+    assert!(count("ddl") >= 1.0);
+    assert!(count("insert") >= 1.0);
+    assert!(count("select") >= 2.0);
+    assert!(
+        sample(
+            &metrics,
+            "neuroquantum_query_duration_seconds_sum{statement=\"select\",table=\"metrics_orders\"}"
+        )
+        .unwrap_or_default()
+            > 0.0
+    );
+    assert!(
+        sample(
+            &metrics,
+            "neuroquantum_query_errors_total{category=\"execution\",statement=\"select\"}"
+        )
+        .unwrap_or_default()
+            >= 1.0
+    );
+}
+
+#[test]
+fn test_statement_labels() {
+    let labels = StatementLabels::of("INSERT INTO orders (id) VALUES (1)");
+    assert_eq!(labels.statement, "insert");
+    assert_eq!(labels.table, "orders");
+
+    let labels = StatementLabels::of("SELECT * FROM orders");
+    assert_eq!(labels.statement, "select");
+    assert_eq!(labels.failure_category(), "execution");
+
+    let labels = StatementLabels::of("SELEKT nonsense");
+    assert_eq!(labels.statement, StatementLabels::UNPARSED);
+    assert_eq!(labels.table, "none");
+    assert_eq!(labels.failure_category(), "parse");
+}
@@ -0,0 +1,231 @@
+//! Tests for the Server-Sent Events query endpoint (`GET /api/v1/query/stream`)
+
+mod common;
+
+use actix_web::dev::Service;
+use actix_web::{test, web, App, HttpMessage};
+use neuroquantum_api::permissions::Permission;
+use neuroquantum_api::{handlers, AppState};
+
+use common::{create_test_state, test_api_key};
+
+async fn seed_rows(state: &AppState, count: usize) {
+    let mut engine = state.qsql_engine.lock().await;
+    engine
+        .execute_query("CREATE TABLE events (id INTEGER PRIMARY KEY, kind TEXT)")
+        .await
+        .unwrap();
+    for i in 1..=count {
+        engine
+            .execute_query(&format!(
+                "INSERT INTO events (id, kind) VALUES ({i}, 'kind_{i}')"
+            ))
+            .await
+            .unwrap();
+    }
+}
+
+fn count_events(body: &str, event: &str) -> usize {
+    body.lines()
+        .filter(|line| *line == format!("event: {event}"))
+        .count()
+}
+
+#[actix_web::test]
+async fn test_stream_emits_one_event_per_row_and_summary() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_rows(&state, 25).await;
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .wrap_fn(|req, srv| {
+                req.extensions_mut()
+                    .insert(test_api_key(Permission::read_only()));
+                srv.call(req)
+            })
+            .route(
+                "/api/v1/query/stream",
+                web::get().to(handlers::stream_sql_query),
+            ),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/query/stream?query=SELECT%20*%20FROM%20events")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success());
+    assert_eq!(
+        resp.headers().get("content-type").unwrap(),
+        "text/event-stream"
+    );
+
+    let body = test::read_body(resp).await;
+    let body = String::from_utf8(body.to_vec()).unwrap();
+
+    assert_eq!(count_events(&body, "row"), 25);
+    assert_eq!(count_events(&body, "complete"), 1);
+    assert!(body.contains("\"row_count\":25"));
+    // The summary must be the final event
+    assert_eq!(body.trim_end().lines().nth_back(1), Some("event: complete"));
+}
+
+/// Values of `column` in the `row` events of an SSE body, in order
+fn row_values(body: &str, column: &str) -> Vec<serde_json::Value> {
+    let mut lines = body.lines();
+    let mut values = Vec::new();
+    while let Some(line) = lines.next() {
+        if line == "event: row" {
+            let data = lines.next().unwrap().strip_prefix("data: ").unwrap();
+            let row: serde_json::Value = serde_json::from_str(data).unwrap();
+            values.push(row[column].clone());
+        }
+    }
+    values
+}
+
+#[actix_web::test]
+async fn test_stream_pages_through_rows_in_key_order() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_rows(&state, 300).await;
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .wrap_fn(|req, srv| {
+                req.extensions_mut()
+                    .insert(test_api_key(Permission::read_only()));
+                srv.call(req)
+            })
+            .route(
+                "/api/v1/query/stream",
+                web::get().to(handlers::stream_sql_query),
+            ),
+    )
+    .await;
+
+    // More rows than fit on one storage page, filtered while paging
+    let req = test::TestRequest::get()
+        .uri("/api/v1/query/stream?query=SELECT%20id%20FROM%20events%20WHERE%20id%20%3E%2020")
+        .to_request();
+    let body = test::call_and_read_body(&app, req).await;
+    let body = String::from_utf8(body.to_vec()).unwrap();
+    let ids: Vec<i64> = row_values(&body, "id")
+        .iter()
+        .map(|id| id.as_i64().unwrap())
+        .collect();
+    assert_eq!(ids, (21..=300).collect::<Vec<_>>());
+    assert!(body.contains("\"row_count\":280"));
+
+    // Statements that cannot be paged are still streamed
+    let req = test::TestRequest::get()
+        .uri("/api/v1/query/stream?query=SELECT%20id%20FROM%20events%20ORDER%20BY%20id%20DESC%20LIMIT%203")
+        .to_request();
+    let body = test::call_and_read_body(&app, req).await;
+    let body = String::from_utf8(body.to_vec()).unwrap();
+    assert_eq!(
+        row_values(&body, "id"),
+        vec![
+            serde_json::json!(300),
+            serde_json::json!(299),
+            serde_json::json!(298)
+        ]
+    );
+}
+
+#[actix_web::test]
+async fn test_stream_accepts_long_multibyte_queries() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_rows(&state, 3).await;
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .wrap_fn(|req, srv| {
+                req.extensions_mut()
+                    .insert(test_api_key(Permission::read_only()));
+                srv.call(req)
+            })
+            .route(
+                "/api/v1/query/stream",
+                web::get().to(handlers::stream_sql_query),
+            ),
+    )
+    .await;
+
+    // The 100th byte falls inside a two-byte character
+    let query = format!("SELECT * FROM events WHERE kind = '{}'", "ü".repeat(60));
+    let encoded: String = query
+        .bytes()
+        .map(|byte| match byte {
+            | b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' => char::from(byte).to_string(),
+            | _ => format!("%{byte:02X}"),
+        })
+        .collect();
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/v1/query/stream?query={encoded}"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success());
+
+    let body = test::read_body(resp).await;
+    let body = String::from_utf8(body.to_vec()).unwrap();
+    assert_eq!(count_events(&body, "row"), 0);
+    assert_eq!(count_events(&body, "complete"), 1);
+}
+
+#[actix_web::test]
+async fn test_stream_reports_errors_as_events() {
+    let (state, _temp_dir) = create_test_state().await;
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .wrap_fn(|req, srv| {
+                req.extensions_mut()
+                    .insert(test_api_key(Permission::read_only()));
+                srv.call(req)
+            })
+            .route(
+                "/api/v1/query/stream",
+                web::get().to(handlers::stream_sql_query),
+            ),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/query/stream?query=SELECT%20*%20FROM%20missing_table")
+        .to_request();
+    let body = test::call_and_read_body(&app, req).await;
+    let body = String::from_utf8(body.to_vec()).unwrap();
+
+    assert_eq!(count_events(&body, "row"), 0);
+    assert_eq!(count_events(&body, "error"), 1);
+}
+
+#[actix_web::test]
+async fn test_stream_rejects_non_select_statements() {
+    let (state, _temp_dir) = create_test_state().await;
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .wrap_fn(|req, srv| {
+                req.extensions_mut()
+                    .insert(test_api_key(Permission::read_write()));
+                srv.call(req)
+            })
+            .route(
+                "/api/v1/query/stream",
+                web::get().to(handlers::stream_sql_query),
+            ),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/query/stream?query=DROP%20TABLE%20events")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+}
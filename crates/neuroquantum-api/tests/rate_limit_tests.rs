@@ -1,9 +1,15 @@
 //! Tests for rate limiting service
 //!
 //! These tests validate memory-based rate limiting, rate limit resets,
-//! and key isolation.
+//! key isolation, and the behaviour of each rate limiting algorithm at window
+//! boundaries.
 
-use neuroquantum_api::rate_limit::{RateLimitConfig, RateLimitService};
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpResponse};
+use neuroquantum_api::rate_limit::{
+    RateLimitAlgorithm, RateLimitConfig, RateLimitMiddleware, RateLimitService,
+};
+use serde_json::Value;
 
 #[tokio::test]
 async fn test_memory_rate_limiting() {
@@ -13,6 +19,7 @@ async fn test_memory_rate_limiting() {
         burst_allowance: Some(2),
         redis_url: None,
         fallback_to_memory: true,
+        algorithm: RateLimitAlgorithm::FixedWindow,
     };
 
     let service = RateLimitService::new(config).await.unwrap();
@@ -36,6 +43,7 @@ async fn test_rate_limit_reset() {
         burst_allowance: None,
         redis_url: None,
         fallback_to_memory: true,
+        algorithm: RateLimitAlgorithm::FixedWindow,
     };
 
     let service = RateLimitService::new(config).await.unwrap();
@@ -66,3 +74,126 @@ async fn test_different_keys() {
     assert!(result2.allowed);
     assert_eq!(result1.remaining, result2.remaining);
 }
+
+#[actix_web::test]
+async fn test_middleware_rejection_uses_error_envelope() {
+    let config = RateLimitConfig {
+        requests_per_window: 1,
+        ..boundary_config(RateLimitAlgorithm::FixedWindow)
+    };
+    let service = RateLimitService::new(config).await.unwrap();
+    let app = test::init_service(
+        App::new()
+            .wrap(RateLimitMiddleware::new(service, |_| "client".to_string()))
+            .route("/", web::get().to(HttpResponse::Ok)),
+    )
+    .await;
+
+    let resp = test::call_service(&app, test::TestRequest::get().to_request()).await;
+    assert!(resp.status().is_success());
+
+    let err = test::try_call_service(&app, test::TestRequest::get().to_request())
+        .await
+        .unwrap_err();
+    let resp = err.error_response();
+    assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(resp.headers().get("x-ratelimit-limit").unwrap(), "1");
+    assert_eq!(resp.headers().get("x-ratelimit-remaining").unwrap(), "0");
+    assert!(resp.headers().contains_key("retry-after"));
+    let body: Value =
+        serde_json::from_slice(&actix_web::body::to_bytes(resp.into_body()).await.unwrap())
+            .unwrap();
+    assert_eq!(body["success"], false);
+    assert_eq!(body["error_code"], "RATE_LIMITED");
+    assert_eq!(body["error"]["RateLimitExceeded"]["limit"], 1);
+}
+
+fn boundary_config(algorithm: RateLimitAlgorithm) -> RateLimitConfig {
+    RateLimitConfig {
+        requests_per_window: 10,
+        window_size_seconds: 60,
+        burst_allowance: None,
+        redis_url: None,
+        fallback_to_memory: true,
+        algorithm,
+    }
+}
+
+/// Send `count` requests at `now` and return how many were allowed
+async fn burst(service: &RateLimitService, key: &str, now: u64, count: u32) -> u32 {
+    let mut allowed = 0;
+    for _ in 0..count {
+        if service.check_rate_limit_at(key, now).await.unwrap().allowed {
+            allowed += 1;
+        }
+    }
+    allowed
+}
+
+#[tokio::test]
+async fn test_fixed_window_allows_double_burst_at_boundary() {
+    let service = RateLimitService::new(boundary_config(RateLimitAlgorithm::FixedWindow))
+        .await
+        .unwrap();
+    let start = 1_000_000;
+
+    // Open the window, then fill it just before it ends
+    assert_eq!(burst(&service, "client", start, 1).await, 1);
+    assert_eq!(burst(&service, "client", start + 59, 10).await, 9);
+
+    // One second later a new window starts and a full burst is admitted again,
+    // so 19 requests pass within two seconds
+    assert_eq!(burst(&service, "client", start + 60, 10).await, 10);
+}
+
+#[tokio::test]
+async fn test_sliding_window_prevents_double_burst_at_boundary() {
+    let service = RateLimitService::new(boundary_config(RateLimitAlgorithm::SlidingWindow))
+        .await
+        .unwrap();
+    let start = 1_000_000;
+
+    assert_eq!(burst(&service, "client", start, 1).await, 1);
+    assert_eq!(burst(&service, "client", start + 59, 10).await, 9);
+
+    // Only the request from `start` has left the window
+    let result = service
+        .check_rate_limit_at("client", start + 60)
+        .await
+        .unwrap();
+    assert!(result.allowed);
+    let result = service
+        .check_rate_limit_at("client", start + 60)
+        .await
+        .unwrap();
+    assert!(!result.allowed);
+    assert_eq!(result.remaining, 0);
+    assert_eq!(result.reset_time, 59);
+
+    // The burst from `start + 59` expires a full window later
+    assert_eq!(burst(&service, "client", start + 118, 10).await, 0);
+    assert_eq!(burst(&service, "client", start + 119, 10).await, 9);
+}
+
+#[tokio::test]
+async fn test_token_bucket_refills_gradually() {
+    let config = RateLimitConfig {
+        burst_allowance: Some(2),
+        ..boundary_config(RateLimitAlgorithm::TokenBucket)
+    };
+    let service = RateLimitService::new(config).await.unwrap();
+    let start = 1_000_000;
+
+    // A fresh bucket holds the window quota plus the burst allowance
+    assert_eq!(burst(&service, "client", start, 15).await, 12);
+
+    // Ten tokens per minute: one token every six seconds
+    let result = service
+        .check_rate_limit_at("client", start + 5)
+        .await
+        .unwrap();
+    assert!(!result.allowed);
+    assert_eq!(result.reset_time, 1);
+    assert_eq!(burst(&service, "client", start + 6, 2).await, 1);
+    assert_eq!(burst(&service, "client", start + 66, 15).await, 10);
+}
@@ -0,0 +1,104 @@
+//! Tests for request body size limits
+//!
+//! JSON bodies are capped at `security.max_request_body_bytes`, streamed CSV
+//! imports at the higher `security.max_import_body_bytes`.
+
+mod common;
+
+use actix_web::http::StatusCode;
+use actix_web::test;
+use neuroquantum_api::permissions::Permission;
+use neuroquantum_api::{configure_app, ApiConfig};
+use serde_json::{json, Value};
+
+use common::{create_test_state_with_config, issue_api_key};
+
+const REQUEST_LIMIT: usize = 1024;
+const IMPORT_LIMIT: usize = 8 * 1024;
+
+fn limited_config() -> ApiConfig {
+    let mut config = ApiConfig::default();
+    config.security.max_request_body_bytes = REQUEST_LIMIT;
+    config.security.max_import_body_bytes = IMPORT_LIMIT;
+    config
+}
+
+fn csv_upload(rows: usize) -> String {
+    let mut csv = String::from("id,name\n");
+    for id in 1..=rows {
+        csv.push_str(&format!("{id},user-{id:04}\n"));
+    }
+    csv
+}
+
+#[actix_web::test]
+async fn test_oversized_json_body_is_rejected_with_413() {
+    let (state, _temp_dir) = create_test_state_with_config(limited_config()).await;
+    let api_key = issue_api_key(&state, Permission::read_write()).key;
+    let app = test::init_service(configure_app(state)).await;
+
+    let query = format!(
+        "SELECT * FROM users WHERE name = '{}'",
+        "x".repeat(REQUEST_LIMIT)
+    );
+    let req = test::TestRequest::post()
+        .uri("/api/v1/query")
+        .insert_header(("X-API-Key", api_key.as_str()))
+        .set_json(json!({ "query": query }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["success"], false);
+    assert_eq!(body["error_code"], "PAYLOAD_TOO_LARGE");
+
+    // Bodies within the limit still reach the handler
+    let req = test::TestRequest::post()
+        .uri("/api/v1/query")
+        .insert_header(("X-API-Key", api_key.as_str()))
+        .set_json(json!({ "query": "SELECT 1" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_ne!(resp.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[actix_web::test]
+async fn test_csv_import_uses_its_own_limit() {
+    let (state, _temp_dir) = create_test_state_with_config(limited_config()).await;
+    state
+        .qsql_engine
+        .lock()
+        .await
+        .execute_query("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)")
+        .await
+        .unwrap();
+    let api_key = issue_api_key(&state, Permission::read_write()).key;
+    let app = test::init_service(configure_app(state)).await;
+
+    // Larger than a JSON body may be, within the import limit
+    let upload = csv_upload(100);
+    assert!(upload.len() > REQUEST_LIMIT && upload.len() < IMPORT_LIMIT);
+    let req = test::TestRequest::post()
+        .uri("/api/v1/tables/users/import/csv")
+        .insert_header(("X-API-Key", api_key.as_str()))
+        .insert_header(("content-type", "text/csv"))
+        .set_payload(upload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["imported_count"], 100, "import failed: {body}");
+
+    let upload = csv_upload(1000);
+    assert!(upload.len() > IMPORT_LIMIT);
+    let req = test::TestRequest::post()
+        .uri("/api/v1/tables/users/import/csv")
+        .insert_header(("X-API-Key", api_key.as_str()))
+        .insert_header(("content-type", "text/csv"))
+        .set_payload(upload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["error_code"], "PAYLOAD_TOO_LARGE");
+}
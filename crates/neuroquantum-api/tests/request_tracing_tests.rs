@@ -0,0 +1,197 @@
+//! Tests for request-scoped OpenTelemetry spans created by the tracing middleware
+//!
+//! Spans are captured with an in-memory exporter installed for the current
+//! thread only, so these tests do not touch the global tracer provider.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use actix_web::dev::Service;
+use actix_web::{test, web, App, HttpMessage, HttpResponse};
+use futures_util::future::BoxFuture;
+use neuroquantum_api::permissions::Permission;
+use neuroquantum_api::{handlers, middleware, ApiConfig, AppState};
+use neuroquantum_core::NeuroQuantumDBBuilder;
+use opentelemetry::trace::{SpanId, TraceId, TracerProvider as _};
+use opentelemetry_sdk::export::trace::{ExportResult, SpanData, SpanExporter};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::TracerProvider;
+use serde_json::json;
+use tracing_subscriber::layer::SubscriberExt;
+
+use common::test_api_key;
+
+const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+const PARENT_SPAN_ID: &str = "00f067aa0ba902b7";
+
+/// Span exporter that keeps finished spans in memory
+#[derive(Debug, Clone, Default)]
+struct CollectingExporter {
+    spans: Arc<Mutex<Vec<SpanData>>>,
+}
+
+impl SpanExporter for CollectingExporter {
+    fn export(&mut self, batch: Vec<SpanData>) -> BoxFuture<'static, ExportResult> {
+        self.spans.lock().unwrap().extend(batch);
+        Box::pin(std::future::ready(Ok(())))
+    }
+}
+
+impl CollectingExporter {
+    fn span(&self, name: &str) -> SpanData {
+        self.spans
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|span| span.name == name)
+            .cloned()
+            .unwrap_or_else(|| panic!("no span named '{name}' was recorded"))
+    }
+}
+
+/// Install a thread-local subscriber exporting spans to a `CollectingExporter`
+fn install_test_tracer() -> (
+    CollectingExporter,
+    TracerProvider,
+    tracing::subscriber::DefaultGuard,
+) {
+    opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let exporter = CollectingExporter::default();
+    let provider = TracerProvider::builder()
+        .with_simple_exporter(exporter.clone())
+        .build();
+    let subscriber = tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+    let guard = tracing::subscriber::set_default(subscriber);
+
+    (exporter, provider, guard)
+}
+
+fn traceparent() -> String {
+    format!("00-{TRACE_ID}-{PARENT_SPAN_ID}-01")
+}
+
+#[actix_web::test]
+async fn test_request_span_honors_incoming_traceparent() {
+    let (exporter, provider, _guard) = install_test_tracer();
+
+    let app = test::init_service(App::new().wrap(middleware::tracing_middleware()).route(
+        "/ping",
+        web::get().to(|| async { HttpResponse::Ok().finish() }),
+    ))
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/ping")
+        .insert_header(("traceparent", traceparent()))
+        .insert_header(("x-request-id", "req-123"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success());
+    assert_eq!(resp.headers().get("x-request-id").unwrap(), "req-123");
+    let response_traceparent = resp.headers().get("traceparent").unwrap().to_str().unwrap();
+    assert!(response_traceparent.contains(TRACE_ID));
+    drop(resp);
+
+    let _ = provider.force_flush();
+    let span = exporter.span("http_request");
+    assert_eq!(
+        span.span_context.trace_id(),
+        TraceId::from_hex(TRACE_ID).unwrap()
+    );
+    assert_eq!(
+        span.parent_span_id,
+        SpanId::from_hex(PARENT_SPAN_ID).unwrap()
+    );
+    assert!(span
+        .attributes
+        .iter()
+        .any(|kv| kv.key.as_str() == "request_id" && kv.value.as_str() == "req-123"));
+}
+
+#[actix_web::test]
+async fn test_request_id_is_generated_when_missing() {
+    let (_exporter, _provider, _guard) = install_test_tracer();
+
+    let app = test::init_service(App::new().wrap(middleware::tracing_middleware()).route(
+        "/ping",
+        web::get().to(|| async { HttpResponse::Ok().finish() }),
+    ))
+    .await;
+
+    let req = test::TestRequest::get().uri("/ping").to_request();
+    let resp = test::call_service(&app, req).await;
+    let request_id = resp
+        .headers()
+        .get("x-request-id")
+        .unwrap()
+        .to_str()
+        .unwrap();
+    assert!(uuid::Uuid::parse_str(request_id).is_ok());
+}
+
+#[actix_web::test]
+async fn test_sql_query_spans_are_children_of_request_span() {
+    let (exporter, provider, _guard) = install_test_tracer();
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db = NeuroQuantumDBBuilder::new()
+        .storage_path(temp_dir.path().to_path_buf())
+        .build()
+        .await
+        .expect("Failed to initialize database");
+    let state = AppState::with_database(ApiConfig::default(), db)
+        .await
+        .expect("Failed to create app state");
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .wrap_fn(|req, srv| {
+                req.extensions_mut()
+                    .insert(test_api_key(Permission::read_write()));
+                srv.call(req)
+            })
+            .wrap(middleware::tracing_middleware())
+            .route("/api/v1/query", web::post().to(handlers::execute_sql_query)),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/query")
+        .insert_header(("traceparent", traceparent()))
+        .set_json(json!({ "query": "CREATE TABLE traced (id INTEGER PRIMARY KEY)" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success());
+    drop(resp);
+
+    let _ = provider.force_flush();
+    let request_span = exporter.span("http_request");
+    let handler_span = exporter.span("execute_sql_query");
+    let qsql_span = exporter.span("qsql_query");
+    let execute_span = exporter.span("qsql_execute");
+
+    let trace_id = TraceId::from_hex(TRACE_ID).unwrap();
+    for span in [&request_span, &handler_span, &qsql_span, &execute_span] {
+        assert_eq!(span.span_context.trace_id(), trace_id);
+    }
+    assert_eq!(
+        handler_span.parent_span_id,
+        request_span.span_context.span_id()
+    );
+    assert_eq!(
+        qsql_span.parent_span_id,
+        handler_span.span_context.span_id()
+    );
+    assert_eq!(
+        execute_span.parent_span_id,
+        qsql_span.span_context.span_id()
+    );
+    assert!(execute_span
+        .attributes
+        .iter()
+        .any(|kv| kv.key.as_str() == "duration_ms"));
+}
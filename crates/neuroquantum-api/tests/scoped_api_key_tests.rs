@@ -0,0 +1,227 @@
+//! Tests for API keys restricted to specific tables with scope rules
+//!
+//! Keys are generated through `AuthService` and presented in the `X-API-Key`
+//! header, so scopes are loaded from key storage by the auth middleware just
+//! as in production.
+
+mod common;
+
+use actix_web::http::StatusCode;
+use actix_web::test;
+use neuroquantum_api::auth::ApiKey;
+use neuroquantum_api::permissions::{Permission, ScopeRule, READ, WRITE};
+use neuroquantum_api::{configure_app, AppState};
+use serde_json::{json, Value};
+
+use common::create_test_state;
+
+async fn seed_tables(state: &AppState) {
+    let mut engine = state.qsql_engine.lock().await;
+    for table in ["users", "audit", "app_events", "orders"] {
+        engine
+            .execute_query(&format!(
+                "CREATE TABLE {table} (id INTEGER PRIMARY KEY, name TEXT NOT NULL)"
+            ))
+            .await
+            .unwrap();
+        engine
+            .execute_query(&format!(
+                "INSERT INTO {table} (id, name) VALUES (1, 'seed')"
+            ))
+            .await
+            .unwrap();
+    }
+}
+
+/// Read-write key limited to reading `users` and reading/writing `app_*`, with
+/// `audit` explicitly denied
+fn issue_scoped_key(state: &AppState) -> ApiKey {
+    state
+        .auth_service
+        .clone()
+        .generate_scoped_api_key(
+            "scoped-test".to_string(),
+            Permission::read_write(),
+            vec![
+                ScopeRule::new("audit", &[]),
+                ScopeRule::new("users", &[READ]),
+                ScopeRule::new("app_*", &[READ, WRITE]),
+            ],
+            Some(1),
+            None,
+        )
+        .unwrap()
+}
+
+fn sql(api_key: &ApiKey, query: &str) -> test::TestRequest {
+    test::TestRequest::post()
+        .uri("/api/v1/query")
+        .insert_header(("X-API-Key", api_key.key.as_str()))
+        .set_json(json!({ "query": query }))
+}
+
+#[actix_web::test]
+async fn test_scoped_key_sql_access() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_tables(&state).await;
+    let api_key = issue_scoped_key(&state);
+    let app = test::init_service(configure_app(state)).await;
+
+    let allowed = [
+        "SELECT id, name FROM users",
+        "SELECT id, name FROM app_events",
+        "INSERT INTO app_events (id, name) VALUES (2, 'login')",
+    ];
+    for query in allowed {
+        let resp = test::call_service(&app, sql(&api_key, query).to_request()).await;
+        assert_eq!(resp.status(), StatusCode::OK, "{query}");
+    }
+
+    let denied = [
+        // read-only table
+        "INSERT INTO users (id, name) VALUES (2, 'Eve')",
+        "DELETE FROM users WHERE id = 1",
+        // explicitly denied table
+        "SELECT id, name FROM audit",
+        // table not matched by any rule
+        "SELECT id, name FROM orders",
+        // denied table reached through a join or subquery
+        "SELECT u.name FROM users u JOIN audit a ON u.id = a.id",
+        "SELECT name FROM users WHERE id IN (SELECT id FROM audit)",
+    ];
+    for query in denied {
+        let resp = test::call_service(&app, sql(&api_key, query).to_request()).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN, "{query}");
+    }
+}
+
+#[actix_web::test]
+async fn test_scoped_key_table_routes() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_tables(&state).await;
+    let api_key = issue_scoped_key(&state);
+    let app = test::init_service(configure_app(state)).await;
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/tables/users/export/csv")
+        .insert_header(("X-API-Key", api_key.key.as_str()))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/tables/users/data")
+        .insert_header(("X-API-Key", api_key.key.as_str()))
+        .set_json(json!({ "records": [{ "id": 3, "name": "Mallory" }] }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/tables/audit/export/csv")
+        .insert_header(("X-API-Key", api_key.key.as_str()))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+}
+
+#[actix_web::test]
+async fn test_scoped_key_quantum_search() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_tables(&state).await;
+    let api_key = state
+        .auth_service
+        .clone()
+        .generate_scoped_api_key(
+            "scoped-quantum".to_string(),
+            Permission::quantum_read(),
+            vec![ScopeRule::new("users", &[READ])],
+            Some(1),
+            None,
+        )
+        .unwrap();
+    let app = test::init_service(configure_app(state)).await;
+
+    let search = |table: &str| {
+        test::TestRequest::post()
+            .uri("/api/v1/quantum/search")
+            .insert_header(("X-API-Key", api_key.key.as_str()))
+            .set_json(json!({
+                "table_name": table,
+                "query_vector": [0.5, 0.5],
+                "similarity_threshold": 0.0
+            }))
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, search("users")).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let resp = test::call_service(&app, search("audit")).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+}
+
+#[actix_web::test]
+async fn test_unscoped_key_is_unrestricted() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_tables(&state).await;
+    let api_key = state
+        .auth_service
+        .clone()
+        .generate_api_key(
+            "unscoped-test".to_string(),
+            Permission::read_write(),
+            Some(1),
+            None,
+        )
+        .unwrap();
+    assert!(api_key.scopes.is_empty());
+    let app = test::init_service(configure_app(state)).await;
+
+    let query = "INSERT INTO audit (id, name) VALUES (2, 'entry')";
+    let resp = test::call_service(&app, sql(&api_key, query).to_request()).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn test_generate_key_with_scopes() {
+    let (state, _temp_dir) = create_test_state().await;
+    let admin_key = state
+        .auth_service
+        .clone()
+        .generate_api_key(
+            "scope-admin".to_string(),
+            Permission::admin_permissions(),
+            Some(1),
+            None,
+        )
+        .unwrap();
+    let app = test::init_service(configure_app(state)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/auth/generate-key")
+        .insert_header(("X-API-Key", admin_key.key.as_str()))
+        .set_json(json!({
+            "name": "reporting",
+            "permissions": ["read"],
+            "scopes": [{ "resource": "report_*", "actions": ["read"] }]
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["scopes"][0]["resource"], "report_*");
+    assert_eq!(body["data"]["scopes"][0]["actions"], json!(["read"]));
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/auth/generate-key")
+        .insert_header(("X-API-Key", admin_key.key.as_str()))
+        .set_json(json!({
+            "name": "invalid",
+            "permissions": ["read"],
+            "scopes": [{ "resource": "users", "actions": ["drop"] }]
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,132 @@
+//! Tests for the storage internals endpoint
+//!
+//! A buffer pool over a fresh page file is attached to the storage engine and
+//! exercised before the stats are requested.
+
+mod common;
+
+use std::sync::Arc;
+
+use actix_web::dev::Service;
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpMessage};
+use neuroquantum_api::permissions::Permission;
+use neuroquantum_api::{handlers, AppState};
+use neuroquantum_core::storage::pager::PageType;
+use neuroquantum_core::storage::{
+    BufferPoolConfig, BufferPoolManager, PageStorageManager, PagerConfig,
+};
+use serde_json::Value;
+
+use common::{create_test_state, test_api_key};
+
+async fn get_storage_stats(state: AppState, permissions: Vec<String>) -> (StatusCode, Value) {
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(state))
+            .wrap_fn(move |req, srv| {
+                req.extensions_mut()
+                    .insert(test_api_key(permissions.clone()));
+                srv.call(req)
+            })
+            .route(
+                "/api/v1/stats/storage",
+                web::get().to(handlers::get_storage_stats),
+            ),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/stats/storage")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    let status = resp.status();
+    (status, test::read_body_json(resp).await)
+}
+
+#[actix_web::test]
+async fn test_storage_stats_report_page_activity() {
+    let (state, temp_dir) = create_test_state().await;
+
+    let pager = Arc::new(
+        PageStorageManager::new(&temp_dir.path().join("pages.db"), PagerConfig::default())
+            .await
+            .unwrap(),
+    );
+    let buffer_pool = Arc::new(
+        BufferPoolManager::new(
+            pager.clone(),
+            BufferPoolConfig {
+                pool_size: 8,
+                enable_background_flush: false,
+                prefetch_enabled: false,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap(),
+    );
+
+    let mut page_ids = Vec::new();
+    for _ in 0..3 {
+        page_ids.push(pager.allocate_page(PageType::Data).await.unwrap());
+    }
+    // Two misses, a hit, and one page left pinned and dirty
+    for &page_id in &page_ids[..2] {
+        buffer_pool.fetch_page(page_id).await.unwrap();
+        buffer_pool.unpin_page(page_id, false).await.unwrap();
+    }
+    buffer_pool.fetch_page(page_ids[0]).await.unwrap();
+    buffer_pool.unpin_page(page_ids[0], true).await.unwrap();
+    buffer_pool.fetch_page(page_ids[0]).await.unwrap();
+
+    state
+        .db
+        .read()
+        .await
+        .storage_mut()
+        .await
+        .attach_page_store(buffer_pool);
+
+    let (status, body) = get_storage_stats(state, Permission::admin_permissions()).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let stats = &body["data"];
+    assert_eq!(stats["page_store_attached"], true);
+
+    let buffer_pool = &stats["buffer_pool"];
+    assert_eq!(buffer_pool["total_frames"], 8);
+    assert_eq!(buffer_pool["used_frames"], 2);
+    assert_eq!(buffer_pool["free_frames"], 6);
+    assert_eq!(buffer_pool["dirty_frames"], 1);
+    assert_eq!(buffer_pool["pinned_frames"], 1);
+    assert_eq!(buffer_pool["hits"], 2);
+    assert_eq!(buffer_pool["misses"], 2);
+    assert_eq!(buffer_pool["hit_rate"], 0.5);
+
+    let pager = &stats["pager"];
+    let total_pages = pager["total_pages"].as_u64().unwrap();
+    assert!(total_pages >= 4, "metadata page plus three data pages");
+    assert_eq!(pager["free_pages"], 0);
+    assert_eq!(pager["used_pages"], total_pages);
+    assert_eq!(pager["file_size_bytes"], total_pages * 4096);
+}
+
+#[actix_web::test]
+async fn test_storage_stats_without_page_store() {
+    let (state, _temp_dir) = create_test_state().await;
+
+    let (status, body) = get_storage_stats(state, Permission::admin_permissions()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["page_store_attached"], false);
+    assert!(body["data"]["buffer_pool"].is_null());
+    assert!(body["data"]["pager"].is_null());
+}
+
+#[actix_web::test]
+async fn test_storage_stats_require_admin() {
+    let (state, _temp_dir) = create_test_state().await;
+
+    let (status, _) = get_storage_stats(state, Permission::read_only()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
@@ -0,0 +1,31 @@
+//! Tests for the portable host memory probe
+
+use neuroquantum_api::system_info::{available_memory_bytes, memory_info, MemoryInfo};
+
+#[test]
+fn test_available_memory_is_reported() {
+    let available = available_memory_bytes().expect("memory probe unsupported on this platform");
+    assert!(available > 0);
+
+    let info = memory_info().unwrap();
+    assert!(info.total_bytes > 0);
+    assert!(info.available_bytes <= info.total_bytes);
+}
+
+#[test]
+fn test_low_memory_threshold() {
+    let mb = 1024 * 1024;
+    let low = MemoryInfo {
+        total_bytes: 1024 * mb,
+        available_bytes: 256 * mb,
+    };
+    assert_eq!(low.available_mb(), 256);
+    assert!(low.is_low());
+
+    let plenty = MemoryInfo {
+        total_bytes: 8192 * mb,
+        available_bytes: 4096 * mb,
+    };
+    assert_eq!(plenty.total_mb(), 8192);
+    assert!(!plenty.is_low());
+}
@@ -0,0 +1,106 @@
+//! Tests for API version negotiation
+//!
+//! All requests go through the full application from `configure_app`, so v1
+//! and v2 clients are served by the same app instance.
+
+mod common;
+
+use actix_web::http::header::ACCEPT;
+use actix_web::http::StatusCode;
+use actix_web::test;
+use neuroquantum_api::configure_app;
+use neuroquantum_api::permissions::Permission;
+use serde_json::{json, Value};
+
+use common::{create_test_state, issue_api_key, seed_users};
+
+fn query_request(uri: &str, api_key: &str) -> test::TestRequest {
+    test::TestRequest::post()
+        .uri(uri)
+        .insert_header(("X-API-Key", api_key))
+        .set_json(json!({ "query": "SELECT id, name FROM versioned_users" }))
+}
+
+#[actix_web::test]
+async fn test_v1_and_v2_clients_get_their_own_envelope() {
+    let (state, _temp_dir) = create_test_state().await;
+    seed_users(&state, "versioned_users").await;
+    let api_key = issue_api_key(&state, Permission::read_only()).key;
+    let app = test::init_service(configure_app(state)).await;
+
+    // v1 keeps the legacy ApiResponse/ResponseMetadata shape
+    let resp =
+        test::call_service(&app, query_request("/api/v1/query", &api_key).to_request()).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.headers().get("x-api-version").unwrap(), "v1");
+    let v1: Value = test::read_body_json(resp).await;
+    assert_eq!(v1["success"], true);
+    assert_eq!(v1["data"]["rows"][0]["name"], "Ada");
+    assert!(v1["metadata"]["request_id"].is_string());
+    assert!(v1.get("meta").is_none());
+
+    // v2 by path
+    let resp =
+        test::call_service(&app, query_request("/api/v2/query", &api_key).to_request()).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.headers().get("x-api-version").unwrap(), "v2");
+    let v2: Value = test::read_body_json(resp).await;
+    assert_eq!(v2["meta"]["api_version"], "v2");
+    assert_eq!(v2["data"]["rows"], v1["data"]["rows"]);
+    assert_eq!(v2["data"]["columns"], v1["data"]["columns"]);
+    assert!(v2.get("success").is_none());
+    assert!(v2.get("metadata").is_none());
+
+    // v2 by Accept header on the v1 URL
+    let req = query_request("/api/v1/query", &api_key)
+        .insert_header((ACCEPT, "application/vnd.neuroquantum.v2+json"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.headers().get("x-api-version").unwrap(), "v2");
+    let negotiated: Value = test::read_body_json(resp).await;
+    assert_eq!(negotiated["meta"]["api_version"], "v2");
+    assert_eq!(negotiated["data"]["rows"], v1["data"]["rows"]);
+}
+
+#[actix_web::test]
+async fn test_v2_serves_unchanged_routes() {
+    let (state, _temp_dir) = create_test_state().await;
+    let api_key = issue_api_key(&state, Permission::read_only()).key;
+    let app = test::init_service(configure_app(state)).await;
+
+    let req = test::TestRequest::get()
+        .uri("/api/v2/stats/performance")
+        .insert_header(("X-API-Key", api_key.as_str()))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.headers().get("x-api-version").unwrap(), "v2");
+}
+
+#[actix_web::test]
+async fn test_unknown_version_is_not_acceptable() {
+    let (state, _temp_dir) = create_test_state().await;
+    let api_key = issue_api_key(&state, Permission::read_only()).key;
+    let app = test::init_service(configure_app(state)).await;
+
+    let req = query_request("/api/v1/query", &api_key)
+        .insert_header((ACCEPT, "application/vnd.neuroquantum.v9+json"))
+        .to_request();
+    let err = test::try_call_service(&app, req).await.unwrap_err();
+    let resp = err.error_response();
+    assert_eq!(resp.status(), StatusCode::NOT_ACCEPTABLE);
+    let body: Value =
+        serde_json::from_slice(&actix_web::body::to_bytes(resp.into_body()).await.unwrap())
+            .unwrap();
+    assert_eq!(body["success"], false);
+    assert_eq!(body["error_code"], "UNSUPPORTED_API_VERSION");
+    assert_eq!(
+        body["error"]["UnsupportedApiVersion"],
+        json!({"requested": "v9", "supported": ["v1", "v2"]})
+    );
+
+    let req = query_request("/api/v3/query", &api_key).to_request();
+    let err = test::try_call_service(&app, req).await.unwrap_err();
+    assert_eq!(err.error_response().status(), StatusCode::NOT_ACCEPTABLE);
+}
@@ -4,11 +4,12 @@
 
 use std::time::Duration;
 
+use neuroquantum_api::websocket::manager::ConnectionError;
 use neuroquantum_api::websocket::{
     ChannelId, ConnectionConfig, ConnectionId, ConnectionManager, ConnectionMetadata,
     ConnectionMetrics, DropPolicy, FlowControlConfig, FlowControlledSender, FlowController,
-    FlowState, PubSubManager, QueryStreamId, QueryStreamStatus, QueryStreamer, StreamingConfig,
-    StreamingMessage, StreamingRegistry,
+    FlowState, OverflowPolicy, PubSubManager, QueryStreamId, QueryStreamStatus, QueryStreamer,
+    StreamingConfig, StreamingMessage, StreamingRegistry,
 };
 
 // =============================================================================
@@ -479,3 +480,104 @@ async fn test_connection_manager_concurrent_access() {
     // Manager should still be in a valid state
     assert_eq!(manager.connection_count(), 0);
 }
+
+// =============================================================================
+// Outbound Queue Tests
+// =============================================================================
+
+/// Open a WebSocket session whose client never reads what the server sends
+///
+/// The session's outgoing frames are only consumed by polling the response
+/// body, which the caller keeps but never polls.
+async fn non_draining_session() -> (actix_web::HttpResponse, actix_ws::Session) {
+    use actix_web::http::header;
+    use actix_web::test::TestRequest;
+    use actix_web::{web, FromRequest};
+
+    let (req, mut payload) = TestRequest::default()
+        .insert_header((header::UPGRADE, "websocket"))
+        .insert_header((header::CONNECTION, "upgrade"))
+        .insert_header((header::SEC_WEBSOCKET_VERSION, "13"))
+        .insert_header((header::SEC_WEBSOCKET_KEY, "dGhlIHNhbXBsZSBub25jZQ=="))
+        .to_http_parts();
+    let body = web::Payload::from_request(&req, &mut payload)
+        .await
+        .unwrap();
+    let (response, session, _messages) = actix_ws::handle(&req, body).unwrap();
+    (response, session)
+}
+
+#[tokio::test]
+async fn test_non_draining_client_is_disconnected() {
+    let manager = ConnectionManager::new(ConnectionConfig {
+        enable_heartbeat_monitor: false,
+        outbound_queue_size: 8,
+        overflow_policy: OverflowPolicy::Disconnect,
+        ..Default::default()
+    });
+    let (_response, session) = non_draining_session().await;
+    let conn_id = manager
+        .register(
+            session,
+            ConnectionMetadata::new("127.0.0.1:9000".to_string()),
+        )
+        .await
+        .unwrap();
+
+    let mut sent = 0;
+    let error = loop {
+        match manager.send_to(conn_id, format!("update {sent}")).await {
+            | Ok(()) => sent += 1,
+            | Err(e) => break e,
+        }
+        assert!(sent < 10_000, "outbound queue grew without bound");
+    };
+    assert!(matches!(error, ConnectionError::SlowConsumer));
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while manager.connection_count() > 0 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("slow consumer was not disconnected");
+
+    let metrics = manager.get_metrics();
+    assert_eq!(metrics.slow_consumer_disconnects, 1);
+    assert_eq!(metrics.active_connections, 0);
+    assert!(metrics.messages_dropped > 0);
+}
+
+#[tokio::test]
+async fn test_non_draining_client_queue_is_trimmed() {
+    let manager = ConnectionManager::new(ConnectionConfig {
+        enable_heartbeat_monitor: false,
+        outbound_queue_size: 8,
+        overflow_policy: OverflowPolicy::DropOldest,
+        ..Default::default()
+    });
+    let (_response, session) = non_draining_session().await;
+    let conn_id = manager
+        .register(
+            session,
+            ConnectionMetadata::new("127.0.0.1:9000".to_string()),
+        )
+        .await
+        .unwrap();
+
+    for i in 0..1_000 {
+        manager
+            .send_to(conn_id, format!("update {i}"))
+            .await
+            .unwrap();
+    }
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    // The client stays connected while its queue never exceeds the limit
+    let connection = manager.get_connection(conn_id).unwrap();
+    assert!(connection.outbound.len() <= 8);
+    let metrics = manager.get_metrics();
+    assert!(metrics.messages_dropped > 0);
+    assert_eq!(metrics.slow_consumer_disconnects, 0);
+    assert_eq!(manager.connection_count(), 1);
+}
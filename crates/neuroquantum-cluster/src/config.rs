@@ -123,6 +123,9 @@ pub struct RaftConfig {
     /// Maximum number of entries per append entries RPC
     pub max_entries_per_rpc: u64,
 
+    /// Maximum number of unacknowledged entries in flight to a single follower
+    pub max_inflight_entries: u64,
+
     /// Snapshot threshold (create snapshot after this many log entries)
     pub snapshot_threshold: u64,
 
@@ -209,6 +212,9 @@ pub struct DiscoveryConfig {
 
     /// etcd service discovery configuration
     pub etcd: Option<EtcdConfig>,
+
+    /// Gossip (SWIM) membership configuration
+    pub gossip: Option<GossipConfig>,
 }
 
 /// DNS service discovery configuration.
@@ -244,6 +250,41 @@ pub struct EtcdConfig {
     pub prefix: String,
 }
 
+/// Gossip (SWIM-style) membership configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GossipConfig {
+    /// Seed nodes contacted when joining (e.g., ["10.0.0.1:7946"])
+    pub seeds: Vec<String>,
+
+    /// Length of one protocol period (one probe per period)
+    pub protocol_period: Duration,
+
+    /// Number of members asked to probe indirectly when a direct ping fails
+    pub indirect_checks: usize,
+
+    /// Protocol periods a member stays suspected before it is declared dead
+    pub suspicion_periods: u64,
+
+    /// Multiplier for how often each membership update is piggybacked
+    pub retransmit_mult: u32,
+
+    /// Maximum number of membership updates piggybacked on one message
+    pub max_piggyback: usize,
+}
+
+impl Default for GossipConfig {
+    fn default() -> Self {
+        Self {
+            seeds: Vec::new(),
+            protocol_period: Duration::from_secs(1),
+            indirect_checks: 3,
+            suspicion_periods: 5,
+            retransmit_mult: 4,
+            max_piggyback: 8,
+        }
+    }
+}
+
 /// Method for discovering cluster nodes.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub enum DiscoveryMethod {
@@ -259,6 +300,9 @@ pub enum DiscoveryMethod {
 
     /// etcd-based discovery
     Etcd,
+
+    /// Gossip-based membership (SWIM)
+    Gossip,
 }
 
 impl Default for ClusterConfig {
@@ -286,6 +330,7 @@ impl Default for RaftConfig {
             election_timeout_min: Duration::from_millis(300),
             election_timeout_max: Duration::from_millis(500),
             max_entries_per_rpc: 100,
+            max_inflight_entries: 1000,
             snapshot_threshold: 10_000,
             snapshot_chunk_size: 1024 * 1024, // 1 MB
             enable_prevote: true,
@@ -332,6 +377,7 @@ impl Default for DiscoveryConfig {
             dns: None,
             consul: None,
             etcd: None,
+            gossip: None,
         }
     }
 }
@@ -369,6 +415,12 @@ impl ClusterConfig {
             ));
         }
 
+        if self.sharding.virtual_nodes == 0 {
+            return Err(ClusterError::ConfigError(
+                "Virtual nodes per node must be greater than 0".into(),
+            ));
+        }
+
         if self.sharding.replication_factor == 0 {
             return Err(ClusterError::ConfigError(
                 "Replication factor must be greater than 0".into(),
@@ -449,6 +501,13 @@ impl ClusterConfigBuilder {
         self
     }
 
+    /// Set the number of virtual nodes each node places on the hash ring.
+    #[must_use]
+    pub const fn virtual_nodes(mut self, virtual_nodes: u32) -> Self {
+        self.config.sharding.virtual_nodes = virtual_nodes;
+        self
+    }
+
     /// Set the discovery configuration.
     #[must_use]
     pub fn discovery(mut self, discovery: DiscoveryConfig) -> Self {
@@ -1,5 +1,6 @@
 //! Raft consensus implementation for cluster coordination.
 
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -10,7 +11,8 @@ use tracing::{debug, info, warn};
 use crate::config::ClusterConfig;
 use crate::error::{ClusterError, ClusterResult};
 use crate::network::{
-    AppendEntriesRequest, AppendEntriesResponse, ClusterMessage, LogEntryCompact, NetworkTransport,
+    AppendEntriesRequest, AppendEntriesResponse, ClusterMessage, InstallSnapshotRequest,
+    InstallSnapshotResponse, LogEntryCompact, NetworkTransport,
 };
 use crate::node::NodeId;
 
@@ -108,6 +110,8 @@ pub enum LogEntryData {
     ConfigChange(ConfigChange),
     /// Data operation
     Command(Vec<u8>),
+    /// Membership configuration (joint or final), effective once appended
+    Membership(Membership),
 }
 
 /// Configuration change for cluster membership.
@@ -132,6 +136,63 @@ pub enum ConfigChangeType {
     PromoteLearner,
 }
 
+/// Voting membership of the Raft group.
+///
+/// While `outgoing` is set the group is in joint consensus: elections and
+/// commits need a majority of both the new `voters` and the `outgoing` set.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Membership {
+    /// Voting members of the current (or incoming) configuration
+    pub voters: BTreeSet<NodeId>,
+    /// Voting members of the previous configuration during a joint change
+    pub outgoing: Option<BTreeSet<NodeId>>,
+    /// Non-voting members that receive the log but are not counted for quorum
+    pub learners: BTreeSet<NodeId>,
+}
+
+impl Membership {
+    /// Check whether a joint configuration change is in progress.
+    #[must_use]
+    pub const fn is_joint(&self) -> bool {
+        self.outgoing.is_some()
+    }
+
+    /// Check whether the node votes in either half of the configuration.
+    #[must_use]
+    pub fn is_voter(&self, node_id: NodeId) -> bool {
+        self.voters.contains(&node_id)
+            || self
+                .outgoing
+                .as_ref()
+                .is_some_and(|old| old.contains(&node_id))
+    }
+
+    /// All nodes that currently vote, across both halves of a joint config.
+    #[must_use]
+    pub fn all_voters(&self) -> BTreeSet<NodeId> {
+        let mut all = self.voters.clone();
+        if let Some(old) = &self.outgoing {
+            all.extend(old.iter().copied());
+        }
+        all
+    }
+
+    /// Check whether `acked` contains a majority of every active configuration.
+    #[must_use]
+    pub fn has_majority(&self, acked: &HashSet<NodeId>) -> bool {
+        Self::majority_of(&self.voters, acked)
+            && self
+                .outgoing
+                .as_ref()
+                .is_none_or(|old| Self::majority_of(old, acked))
+    }
+
+    fn majority_of(voters: &BTreeSet<NodeId>, acked: &HashSet<NodeId>) -> bool {
+        let count = voters.iter().filter(|id| acked.contains(id)).count();
+        count > voters.len() / 2
+    }
+}
+
 /// Raft state for the node.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RaftState {
@@ -145,6 +206,47 @@ pub enum RaftState {
     Leader,
 }
 
+/// Leader-side flow control for replication to one follower.
+#[derive(Debug, Clone, Default)]
+pub struct FollowerProgress {
+    /// Highest log index sent to the follower (acknowledged or not)
+    pub sent_index: u64,
+    /// Sending paused because the in-flight window is full
+    pub paused: bool,
+    /// When the most recent batch of entries was sent
+    pub last_sent: Option<Instant>,
+    /// Snapshot being streamed to a follower that fell too far behind
+    pub snapshot: Option<SnapshotTransfer>,
+}
+
+/// Snapshot transfer from the leader to one follower.
+#[derive(Debug, Clone)]
+pub struct SnapshotTransfer {
+    /// Last log index covered by the snapshot
+    pub last_included_index: u64,
+    /// Term of `last_included_index`
+    pub last_included_term: u64,
+    /// Serialized snapshot
+    pub data: Arc<Vec<u8>>,
+    /// Bytes acknowledged by the follower
+    pub offset: u64,
+    /// End offset of the chunk awaiting acknowledgement
+    pub inflight_end: Option<u64>,
+    /// When the chunk awaiting acknowledgement was sent
+    pub last_sent: Option<Instant>,
+}
+
+/// Snapshot chunks received so far (follower side).
+#[derive(Debug, Clone, Default)]
+pub struct IncomingSnapshot {
+    /// Last log index covered by the snapshot
+    pub last_included_index: u64,
+    /// Term of `last_included_index`
+    pub last_included_term: u64,
+    /// Bytes received so far
+    pub data: Vec<u8>,
+}
+
 /// Internal consensus state (public for testing purposes).
 pub struct ConsensusState {
     /// Current term
@@ -172,11 +274,49 @@ pub struct ConsensusState {
     /// Total cluster size
     pub cluster_size: usize,
     /// Next index to send to each follower (leader only)
-    pub next_index: std::collections::HashMap<NodeId, u64>,
+    pub next_index: HashMap<NodeId, u64>,
     /// Highest log entry known to be replicated on each follower (leader only)
-    pub match_index: std::collections::HashMap<NodeId, u64>,
+    pub match_index: HashMap<NodeId, u64>,
     /// Votes received in current election (candidate only)
-    pub votes_received: std::collections::HashSet<NodeId>,
+    pub votes_received: HashSet<NodeId>,
+    /// Voting membership (empty until bootstrapped; `cluster_size` is used then)
+    pub membership: Membership,
+    /// Log index of the entry that introduced the current membership
+    pub membership_index: u64,
+    /// Flow control state for each follower (leader only)
+    pub progress: HashMap<NodeId, FollowerProgress>,
+    /// Snapshot being received from the leader (follower only)
+    pub incoming_snapshot: Option<IncomingSnapshot>,
+}
+
+impl ConsensusState {
+    /// Check whether the acknowledging nodes (including self) form a quorum.
+    #[must_use]
+    pub fn is_quorum(&self, acked: &HashSet<NodeId>) -> bool {
+        if self.membership.voters.is_empty() {
+            acked.len() > self.cluster_size / 2
+        } else {
+            self.membership.has_majority(acked)
+        }
+    }
+
+    /// Adopt the most recent membership entry in the log.
+    ///
+    /// Raft applies configuration entries as soon as they are appended, so
+    /// followers call this after every append (including truncations).
+    fn adopt_latest_membership(&mut self) {
+        let latest = self.log.iter().rev().find_map(|entry| match &entry.data {
+            | LogEntryData::Membership(membership) => Some((entry.index, membership.clone())),
+            | _ => None,
+        });
+        if let Some((index, membership)) = latest {
+            if index != self.membership_index || membership != self.membership {
+                self.cluster_size = membership.voters.len();
+                self.membership = membership;
+                self.membership_index = index;
+            }
+        }
+    }
 }
 
 impl Default for ConsensusState {
@@ -194,9 +334,13 @@ impl Default for ConsensusState {
             quorum_status: QuorumStatus::Unknown,
             reachable_peers: 0,
             cluster_size: 1,
-            next_index: std::collections::HashMap::new(),
-            match_index: std::collections::HashMap::new(),
-            votes_received: std::collections::HashSet::new(),
+            next_index: HashMap::new(),
+            match_index: HashMap::new(),
+            votes_received: HashSet::new(),
+            membership: Membership::default(),
+            membership_index: 0,
+            progress: HashMap::new(),
+            incoming_snapshot: None,
         }
     }
 }
@@ -381,6 +525,231 @@ impl RaftConsensus {
         Ok(index)
     }
 
+    /// Bootstrap the voting membership, e.g. from the static peer list.
+    ///
+    /// Until this is called quorum decisions fall back to `cluster_size`.
+    pub async fn initialize_membership(&self, voters: impl IntoIterator<Item = NodeId>) {
+        let mut state = self.state.write().await;
+        state.membership = Membership {
+            voters: voters.into_iter().collect(),
+            outgoing: None,
+            learners: BTreeSet::new(),
+        };
+        state.membership_index = 0;
+        state.cluster_size = state.membership.voters.len().max(1);
+
+        info!(
+            node_id = self.node_id,
+            voters = ?state.membership.voters,
+            "Initialized cluster membership"
+        );
+    }
+
+    /// Get the current membership configuration.
+    pub async fn membership(&self) -> Membership {
+        self.state.read().await.membership.clone()
+    }
+
+    /// Add a non-voting learner that starts receiving the log.
+    ///
+    /// Returns the log index of the configuration entry.
+    pub async fn add_learner(&self, node_id: NodeId) -> ClusterResult<u64> {
+        let mut state = self.state.write().await;
+        self.ensure_can_change_membership(&state)?;
+
+        if state.membership.voters.is_empty() {
+            // Membership was never bootstrapped: adopt the current replication peers
+            let mut voters: BTreeSet<NodeId> = state.next_index.keys().copied().collect();
+            voters.insert(self.node_id);
+            state.membership.voters = voters;
+        }
+
+        if state.membership.is_voter(node_id) || state.membership.learners.contains(&node_id) {
+            return Err(ClusterError::NodeAlreadyExists(node_id));
+        }
+
+        let mut membership = state.membership.clone();
+        membership.learners.insert(node_id);
+        let index = self.append_membership_entry(&mut state, membership);
+
+        // A fresh learner has an empty log, so replicate from the beginning
+        state.next_index.insert(node_id, 1);
+        state.match_index.insert(node_id, 0);
+        state.progress.insert(node_id, FollowerProgress::default());
+
+        info!(
+            node_id = self.node_id,
+            learner = node_id,
+            index,
+            "Added learner to cluster"
+        );
+
+        Ok(index)
+    }
+
+    /// Remove a learner that was never promoted to voter.
+    pub async fn remove_learner(&self, node_id: NodeId) -> ClusterResult<u64> {
+        let mut state = self.state.write().await;
+        self.ensure_can_change_membership(&state)?;
+
+        if !state.membership.learners.contains(&node_id) {
+            return Err(ClusterError::NodeNotFound(node_id));
+        }
+
+        let mut membership = state.membership.clone();
+        membership.learners.remove(&node_id);
+        let index = self.append_membership_entry(&mut state, membership);
+        state.next_index.remove(&node_id);
+        state.match_index.remove(&node_id);
+        state.progress.remove(&node_id);
+
+        Ok(index)
+    }
+
+    /// Check whether a learner has replicated everything committed so far.
+    pub async fn is_caught_up(&self, node_id: NodeId) -> bool {
+        let state = self.state.read().await;
+        node_id == self.node_id
+            || state
+                .match_index
+                .get(&node_id)
+                .is_some_and(|&matched| matched >= state.commit_index)
+    }
+
+    /// Replace the voting membership using joint consensus.
+    ///
+    /// Appends a joint configuration (C_old,new). Once that entry commits the
+    /// leader appends the final configuration (C_new) on its own. Nodes joining
+    /// the voter set must already be learners that have caught up with the
+    /// commit index, so removing a voter never leaves the group short of
+    /// replicas that actually hold the data.
+    ///
+    /// Returns the log index of the joint configuration entry.
+    pub async fn change_membership(&self, voters: BTreeSet<NodeId>) -> ClusterResult<u64> {
+        let mut state = self.state.write().await;
+        self.ensure_can_change_membership(&state)?;
+
+        if voters.is_empty() {
+            return Err(ClusterError::ConfigError(
+                "membership must contain at least one voter".into(),
+            ));
+        }
+        if state.membership.voters.is_empty() {
+            return Err(ClusterError::InvalidState {
+                expected: "initialized membership".into(),
+                actual: "no membership".into(),
+            });
+        }
+
+        for &node_id in voters.difference(&state.membership.voters) {
+            if !state.membership.learners.contains(&node_id) {
+                return Err(ClusterError::NodeNotFound(node_id));
+            }
+            let matched = state.match_index.get(&node_id).copied().unwrap_or(0);
+            if matched < state.commit_index {
+                return Err(ClusterError::LearnerLagging {
+                    node_id,
+                    matched,
+                    required: state.commit_index,
+                });
+            }
+        }
+
+        let old_voters = state.membership.voters.clone();
+        let learners = state
+            .membership
+            .learners
+            .difference(&voters)
+            .copied()
+            .collect();
+        let joint = Membership {
+            voters,
+            outgoing: Some(old_voters),
+            learners,
+        };
+        let index = self.append_membership_entry(&mut state, joint);
+
+        info!(
+            node_id = self.node_id,
+            index,
+            membership = ?state.membership,
+            "Entered joint consensus"
+        );
+
+        Ok(index)
+    }
+
+    fn ensure_can_change_membership(&self, state: &ConsensusState) -> ClusterResult<()> {
+        if state.state != RaftState::Leader {
+            return Err(ClusterError::NotLeader(self.node_id, state.current_leader));
+        }
+        if state.membership.is_joint() {
+            return Err(ClusterError::MembershipChangeInProgress);
+        }
+        Ok(())
+    }
+
+    /// Append a membership entry and make it effective immediately.
+    fn append_membership_entry(&self, state: &mut ConsensusState, membership: Membership) -> u64 {
+        let fencing_token = FencingToken::new(state.current_term, state.next_sequence);
+        state.next_sequence += 1;
+
+        let index = state.log.len() as u64 + 1;
+        state.log.push(LogEntry {
+            term: state.current_term,
+            index,
+            data: LogEntryData::Membership(membership.clone()),
+            fencing_token: Some(fencing_token),
+        });
+        state.cluster_size = membership.voters.len();
+        state.membership = membership;
+        state.membership_index = index;
+        index
+    }
+
+    /// Finish a joint change once its entry is committed.
+    fn advance_membership(&self, state: &mut ConsensusState) {
+        if state.state != RaftState::Leader || state.commit_index < state.membership_index {
+            return;
+        }
+
+        if state.membership.is_joint() {
+            let mut final_membership = state.membership.clone();
+            final_membership.outgoing = None;
+            let index = self.append_membership_entry(state, final_membership);
+
+            // Stop replicating to nodes that are no longer part of the group
+            let members = state.membership.all_voters();
+            let learners = state.membership.learners.clone();
+            state
+                .next_index
+                .retain(|id, _| members.contains(id) || learners.contains(id));
+            state
+                .match_index
+                .retain(|id, _| members.contains(id) || learners.contains(id));
+            state
+                .progress
+                .retain(|id, _| members.contains(id) || learners.contains(id));
+
+            info!(
+                node_id = self.node_id,
+                index,
+                voters = ?state.membership.voters,
+                "Joint configuration committed, appended final configuration"
+            );
+        } else if !state.membership.voters.is_empty()
+            && !state.membership.voters.contains(&self.node_id)
+        {
+            info!(
+                node_id = self.node_id,
+                "Removed from the cluster configuration, stepping down"
+            );
+            state.state = RaftState::Follower;
+            state.leader_lease = None;
+            state.current_leader = None;
+        }
+    }
+
     /// Check if the node has quorum.
     const fn has_quorum(&self, state: &ConsensusState) -> bool {
         if state.cluster_size == 1 {
@@ -590,8 +959,18 @@ impl RaftConsensus {
                             state_guard.state
                         };
 
+                        // Learners never start elections
+                        let is_voter = {
+                            let state_guard = state.read().await;
+                            state_guard.membership.voters.is_empty()
+                                || state_guard.membership.is_voter(node_id)
+                        };
+
                         // Only start election if we're a follower or candidate
-                        if current_state == RaftState::Follower || current_state == RaftState::Candidate {
+                        if is_voter
+                            && (current_state == RaftState::Follower
+                                || current_state == RaftState::Candidate)
+                        {
                             info!(node_id, "Election timeout reached, starting election");
 
                             // Transition to candidate state
@@ -604,11 +983,18 @@ impl RaftConsensus {
                                 state_guard.votes_received.clear();
                                 state_guard.votes_received.insert(node_id);
 
-                                // Get list of peers from next_index keys (if we have any).
-                                // Note: This assumes peers are already registered via initialize_replication_state().
-                                // In production, this would typically come from cluster configuration or
-                                // a membership service.
-                                state_guard.next_index.keys().copied().collect::<Vec<_>>()
+                                // Ask every voter of the active configuration; fall back to
+                                // next_index keys when membership was never bootstrapped.
+                                if state_guard.membership.voters.is_empty() {
+                                    state_guard.next_index.keys().copied().collect::<Vec<_>>()
+                                } else {
+                                    state_guard
+                                        .membership
+                                        .all_voters()
+                                        .into_iter()
+                                        .filter(|&id| id != node_id)
+                                        .collect::<Vec<_>>()
+                                }
                             };
 
                             // Request votes from all peers
@@ -710,6 +1096,13 @@ impl RaftConsensus {
         for peer_id in peer_ids {
             state.next_index.insert(peer_id, next_idx);
             state.match_index.insert(peer_id, 0);
+            state.progress.insert(
+                peer_id,
+                FollowerProgress {
+                    sent_index: next_idx - 1,
+                    ..Default::default()
+                },
+            );
         }
 
         info!(
@@ -722,41 +1115,141 @@ impl RaftConsensus {
 
     /// Replicate log entries to a specific follower.
     pub async fn replicate_to_follower(&self, follower_id: NodeId) -> ClusterResult<()> {
-        let state = self.state.read().await;
+        let Some(message) = self.next_replication_message(follower_id).await? else {
+            return Ok(()); // Waiting for a snapshot chunk to be acknowledged
+        };
+
+        // Send AppendEntries or InstallSnapshot RPC via network
+        self.transport.send(follower_id, &message).await?;
+
+        Ok(())
+    }
+
+    /// Build the next replication message for a follower.
+    ///
+    /// Followers that lag the leader by more than `snapshot_threshold` entries
+    /// are caught up by streaming a snapshot of the committed log instead of
+    /// replaying entries one window at a time. Returns `None` while a snapshot
+    /// chunk is still awaiting acknowledgement.
+    pub async fn next_replication_message(
+        &self,
+        follower_id: NodeId,
+    ) -> ClusterResult<Option<ClusterMessage>> {
+        let mut state = self.state.write().await;
 
         if state.state != RaftState::Leader {
             return Err(ClusterError::NotLeader(self.node_id, state.current_leader));
         }
 
         let next_idx = state.next_index.get(&follower_id).copied().unwrap_or(1);
-        let prev_log_index = next_idx.saturating_sub(1);
+        let lag = (state.log.len() as u64).saturating_sub(next_idx - 1);
+        let mut progress = state.progress.remove(&follower_id).unwrap_or_default();
 
-        let prev_log_term = if prev_log_index > 0 && prev_log_index <= state.log.len() as u64 {
-            state.log[prev_log_index as usize - 1].term
-        } else {
-            0
+        if progress.snapshot.is_none()
+            && lag > self.config.raft.snapshot_threshold
+            && state.commit_index >= next_idx
+        {
+            progress.snapshot = Some(self.build_snapshot(&state)?);
+            info!(
+                node_id = self.node_id,
+                follower = follower_id,
+                lag,
+                "Follower fell too far behind, switching to snapshot install"
+            );
+        }
+
+        let message = match progress.snapshot.as_mut() {
+            | Some(snapshot) => self
+                .next_snapshot_chunk(&state, snapshot)
+                .map(ClusterMessage::InstallSnapshot),
+            | None => Some(ClusterMessage::AppendEntries(self.build_append_entries(
+                &state,
+                follower_id,
+                &mut progress,
+            ))),
         };
 
-        // Collect entries to send
-        let entries: Vec<LogEntryCompact> = if next_idx <= state.log.len() as u64 {
-            state.log[next_idx as usize - 1..]
+        state.progress.insert(follower_id, progress);
+        Ok(message)
+    }
+
+    /// Build the `AppendEntries` request for a follower from its `next_index`.
+    ///
+    /// At most `max_inflight_entries` unacknowledged entries are outstanding per
+    /// follower; once the window is full only heartbeats are sent until an
+    /// acknowledgement arrives.
+    pub async fn append_entries_request(
+        &self,
+        follower_id: NodeId,
+    ) -> ClusterResult<AppendEntriesRequest> {
+        let mut state = self.state.write().await;
+
+        if state.state != RaftState::Leader {
+            return Err(ClusterError::NotLeader(self.node_id, state.current_leader));
+        }
+
+        let mut progress = state.progress.remove(&follower_id).unwrap_or_default();
+        let request = self.build_append_entries(&state, follower_id, &mut progress);
+        state.progress.insert(follower_id, progress);
+
+        Ok(request)
+    }
+
+    fn build_append_entries(
+        &self,
+        state: &ConsensusState,
+        follower_id: NodeId,
+        progress: &mut FollowerProgress,
+    ) -> AppendEntriesRequest {
+        let next_idx = state.next_index.get(&follower_id).copied().unwrap_or(1);
+        let last_index = state.log.len() as u64;
+        let acked = next_idx - 1;
+
+        // Resend from the last acknowledged entry if a batch went unanswered
+        let timed_out = progress
+            .last_sent
+            .is_some_and(|sent| sent.elapsed() >= self.config.network.request_timeout);
+        if timed_out || progress.sent_index < acked {
+            progress.sent_index = acked;
+        }
+
+        let window_end = acked + self.config.raft.max_inflight_entries.max(1);
+        let first = progress.sent_index + 1;
+        let last = last_index
+            .min(window_end)
+            .min(progress.sent_index + self.config.raft.max_entries_per_rpc.max(1));
+
+        // A saturated follower only gets heartbeats, anchored at the acknowledged index
+        let (prev_log_index, entries) = if first <= last {
+            let entries: Vec<LogEntryCompact> = state.log[first as usize - 1..last as usize]
                 .iter()
                 .map(|entry| LogEntryCompact {
                     term: entry.term,
                     data: bincode::serialize(&entry.data).unwrap_or_default(),
                 })
-                .collect()
+                .collect();
+            progress.sent_index = last;
+            progress.last_sent = Some(Instant::now());
+            progress.paused = false;
+            (first - 1, entries)
         } else {
-            Vec::new()
+            progress.paused = first <= last_index;
+            (acked, Vec::new())
         };
 
-        let request = AppendEntriesRequest {
-            term: state.current_term,
-            leader_id: self.node_id,
-            prev_log_index,
-            prev_log_term,
-            entries: entries.clone(),
-            leader_commit: state.commit_index,
+        if progress.paused {
+            debug!(
+                node_id = self.node_id,
+                follower = follower_id,
+                inflight = progress.sent_index - acked,
+                "In-flight window full, pausing replication to follower"
+            );
+        }
+
+        let prev_log_term = if prev_log_index > 0 && prev_log_index <= last_index {
+            state.log[prev_log_index as usize - 1].term
+        } else {
+            0
         };
 
         debug!(
@@ -769,14 +1262,60 @@ impl RaftConsensus {
             "Sending AppendEntries to follower"
         );
 
-        drop(state); // Release read lock before async call
+        AppendEntriesRequest {
+            term: state.current_term,
+            leader_id: self.node_id,
+            prev_log_index,
+            prev_log_term,
+            entries,
+            leader_commit: state.commit_index,
+        }
+    }
 
-        // Send AppendEntries RPC via network
-        self.transport
-            .send(follower_id, &ClusterMessage::AppendEntries(request))
-            .await?;
+    /// Serialize the committed prefix of the log as a snapshot.
+    fn build_snapshot(&self, state: &ConsensusState) -> ClusterResult<SnapshotTransfer> {
+        let last_included_index = state.commit_index;
+        let committed = &state.log[..last_included_index as usize];
+        let data = bincode::serialize(committed)?;
+
+        Ok(SnapshotTransfer {
+            last_included_index,
+            last_included_term: committed.last().map_or(0, |entry| entry.term),
+            data: Arc::new(data),
+            offset: 0,
+            inflight_end: None,
+            last_sent: None,
+        })
+    }
 
-        Ok(())
+    /// Next chunk of a snapshot transfer, one chunk in flight at a time.
+    fn next_snapshot_chunk(
+        &self,
+        state: &ConsensusState,
+        snapshot: &mut SnapshotTransfer,
+    ) -> Option<InstallSnapshotRequest> {
+        let awaiting_ack = snapshot.inflight_end.is_some()
+            && snapshot
+                .last_sent
+                .is_some_and(|sent| sent.elapsed() < self.config.network.request_timeout);
+        if awaiting_ack {
+            return None;
+        }
+
+        let total = snapshot.data.len() as u64;
+        let end = total.min(snapshot.offset + self.config.raft.snapshot_chunk_size.max(1));
+        snapshot.inflight_end = Some(end);
+        snapshot.last_sent = Some(Instant::now());
+
+        Some(InstallSnapshotRequest {
+            term: state.current_term,
+            leader_id: self.node_id,
+            last_included_index: snapshot.last_included_index,
+            last_included_term: snapshot.last_included_term,
+            offset: snapshot.offset,
+            data: snapshot.data[snapshot.offset as usize..end as usize].to_vec(),
+            done: end == total,
+        })
     }
 
     /// Handle `AppendEntries` response from a follower (leader side).
@@ -807,11 +1346,23 @@ impl RaftConsensus {
             return Ok(()); // Ignore if no longer leader
         }
 
+        // Ignore nodes that have been removed from the configuration
+        let membership = &state.membership;
+        if !membership.voters.is_empty()
+            && !membership.is_voter(follower_id)
+            && !membership.learners.contains(&follower_id)
+        {
+            return Ok(());
+        }
+
         if response.success {
             // Update next_index and match_index for follower
             let next_idx = state.next_index.get(&follower_id).copied().unwrap_or(1);
             let new_match_index = if let Some(match_index) = response.match_index {
                 match_index
+            } else if let Some(progress) = state.progress.get(&follower_id) {
+                // Assume everything we sent has arrived
+                progress.sent_index
             } else {
                 // Calculate based on what we sent
                 let entries_sent = if next_idx <= state.log.len() as u64 {
@@ -821,10 +1372,23 @@ impl RaftConsensus {
                 };
                 next_idx + entries_sent - 1
             };
+            // Acknowledgements may be reordered; never move backwards
+            let new_match_index =
+                new_match_index.max(state.match_index.get(&follower_id).copied().unwrap_or(0));
 
             state.match_index.insert(follower_id, new_match_index);
             state.next_index.insert(follower_id, new_match_index + 1);
 
+            // Acknowledged entries leave the in-flight window
+            let max_inflight = self.config.raft.max_inflight_entries.max(1);
+            if let Some(progress) = state.progress.get_mut(&follower_id) {
+                if progress.sent_index <= new_match_index {
+                    progress.sent_index = new_match_index;
+                    progress.last_sent = None;
+                }
+                progress.paused = progress.sent_index - new_match_index >= max_inflight;
+            }
+
             debug!(
                 node_id = self.node_id,
                 follower = follower_id,
@@ -868,6 +1432,12 @@ impl RaftConsensus {
             };
 
             state.next_index.insert(follower_id, new_next_idx);
+            if let Some(progress) = state.progress.get_mut(&follower_id) {
+                // Anything in flight past the conflict will be rejected too
+                progress.sent_index = new_next_idx - 1;
+                progress.last_sent = None;
+                progress.paused = false;
+            }
 
             warn!(
                 node_id = self.node_id,
@@ -890,17 +1460,18 @@ impl RaftConsensus {
         // Find the highest index replicated to a majority
         let log_len = state.log.len() as u64;
         for n in (state.commit_index + 1..=log_len).rev() {
-            // Count replicas (including self)
-            let mut count = 1;
-            for &match_idx in state.match_index.values() {
-                if match_idx >= n {
-                    count += 1;
-                }
-            }
-
-            // Check if we have majority
-            let needed = (state.cluster_size / 2) + 1;
-            if count >= needed {
+            // Collect replicas (including self); learners are ignored by the quorum check
+            let mut acked: HashSet<NodeId> = state
+                .match_index
+                .iter()
+                .filter(|&(_, &match_idx)| match_idx >= n)
+                .map(|(&id, _)| id)
+                .collect();
+            acked.insert(self.node_id);
+            let count = acked.len();
+
+            // Check if we have majority (of both configurations while joint)
+            if state.is_quorum(&acked) {
                 // According to Raft §5.4.2, a leader can commit entries from previous terms
                 // once an entry from its current term is committed. However, it should never
                 // commit entries from previous terms directly.
@@ -920,6 +1491,8 @@ impl RaftConsensus {
                 }
             }
         }
+
+        self.advance_membership(state);
     }
 
     /// Handle incoming `AppendEntries` RPC (follower side).
@@ -1030,6 +1603,8 @@ impl RaftConsensus {
                 log_length = state.log.len(),
                 "Appended entries to log"
             );
+
+            state.adopt_latest_membership();
         }
 
         // Update commit index
@@ -1053,17 +1628,190 @@ impl RaftConsensus {
             }
         }
 
-        let last_log_index = state.log.len() as u64;
+        // Only the entries covered by this request are known to match the leader
+        let match_index = request.prev_log_index + request.entries.len() as u64;
 
         Ok(AppendEntriesResponse {
             term: state.current_term,
             success: true,
-            match_index: Some(last_log_index),
+            match_index: Some(match_index),
             conflict_index: None,
             conflict_term: None,
         })
     }
 
+    /// Handle `InstallSnapshot` response from a follower (leader side).
+    pub async fn handle_install_snapshot_response(
+        &self,
+        follower_id: NodeId,
+        response: InstallSnapshotResponse,
+    ) -> ClusterResult<()> {
+        let mut state = self.state.write().await;
+
+        if response.term > state.current_term {
+            warn!(
+                node_id = self.node_id,
+                current_term = state.current_term,
+                response_term = response.term,
+                "Received higher term in InstallSnapshot response, stepping down"
+            );
+            state.current_term = response.term;
+            state.state = RaftState::Follower;
+            state.leader_lease = None;
+            state.current_leader = None;
+            state.voted_for = None;
+            return Ok(());
+        }
+
+        if state.state != RaftState::Leader {
+            return Ok(()); // Ignore if no longer leader
+        }
+
+        let Some(progress) = state.progress.get_mut(&follower_id) else {
+            return Ok(());
+        };
+        let Some(snapshot) = progress.snapshot.as_mut() else {
+            return Ok(());
+        };
+
+        if !response.success {
+            // The follower lost track of the transfer; start over
+            snapshot.offset = 0;
+            snapshot.inflight_end = None;
+            snapshot.last_sent = None;
+            return Ok(());
+        }
+
+        if let Some(end) = snapshot.inflight_end.take() {
+            snapshot.offset = end;
+            snapshot.last_sent = None;
+        }
+        if snapshot.offset < snapshot.data.len() as u64 {
+            return Ok(());
+        }
+
+        // Snapshot fully installed: resume log replication after it
+        let installed = snapshot.last_included_index;
+        progress.snapshot = None;
+        progress.sent_index = installed;
+        progress.last_sent = None;
+        progress.paused = false;
+
+        let match_index = state
+            .match_index
+            .get(&follower_id)
+            .copied()
+            .unwrap_or(0)
+            .max(installed);
+        state.match_index.insert(follower_id, match_index);
+        state.next_index.insert(follower_id, match_index + 1);
+
+        info!(
+            node_id = self.node_id,
+            follower = follower_id,
+            last_included_index = installed,
+            "Follower installed snapshot, resuming log replication"
+        );
+
+        self.try_advance_commit_index(&mut state).await;
+
+        Ok(())
+    }
+
+    /// Handle incoming `InstallSnapshot` RPC (follower side).
+    pub async fn handle_install_snapshot(
+        &self,
+        request: InstallSnapshotRequest,
+    ) -> ClusterResult<InstallSnapshotResponse> {
+        let mut state = self.state.write().await;
+
+        if request.term < state.current_term {
+            return Ok(InstallSnapshotResponse {
+                term: state.current_term,
+                success: false,
+            });
+        }
+
+        if request.term > state.current_term {
+            state.current_term = request.term;
+            state.state = RaftState::Follower;
+            state.voted_for = None;
+            state.leader_lease = None;
+        }
+        state.current_leader = Some(request.leader_id);
+
+        // Reset election timer (the leader is alive)
+        drop(state);
+        self.notify_heartbeat().await;
+        let mut state = self.state.write().await;
+
+        let mut incoming = match state.incoming_snapshot.take() {
+            | Some(incoming)
+                if incoming.last_included_index == request.last_included_index
+                    && incoming.last_included_term == request.last_included_term =>
+            {
+                incoming
+            },
+            | _ => IncomingSnapshot {
+                last_included_index: request.last_included_index,
+                last_included_term: request.last_included_term,
+                data: Vec::new(),
+            },
+        };
+
+        // Chunks must continue the snapshot; a resent chunk overwrites its range
+        if request.offset > incoming.data.len() as u64 {
+            return Ok(InstallSnapshotResponse {
+                term: state.current_term,
+                success: false,
+            });
+        }
+        incoming.data.truncate(request.offset as usize);
+        incoming.data.extend_from_slice(&request.data);
+
+        if !request.done {
+            state.incoming_snapshot = Some(incoming);
+            return Ok(InstallSnapshotResponse {
+                term: state.current_term,
+                success: true,
+            });
+        }
+
+        let entries: Vec<LogEntry> = bincode::deserialize(&incoming.data)?;
+        let last_included = request.last_included_index as usize;
+
+        // Keep entries following the snapshot if they agree with it
+        let retains_suffix = state
+            .log
+            .get(last_included.wrapping_sub(1))
+            .is_some_and(|entry| entry.term == request.last_included_term);
+        let suffix = if retains_suffix {
+            state.log.split_off(last_included)
+        } else {
+            Vec::new()
+        };
+        state.log = entries;
+        state.log.extend(suffix);
+
+        if request.last_included_index > state.commit_index {
+            state.commit_index = request.last_included_index;
+        }
+        state.adopt_latest_membership();
+
+        info!(
+            node_id = self.node_id,
+            leader = request.leader_id,
+            last_included_index = request.last_included_index,
+            log_length = state.log.len(),
+            "Installed snapshot from leader"
+        );
+
+        Ok(InstallSnapshotResponse {
+            term: state.current_term,
+            success: true,
+        })
+    }
+
     /// Apply committed entries to state machine.
     pub async fn apply_committed_entries(&self) -> ClusterResult<usize> {
         let mut state = self.state.write().await;
@@ -1338,17 +2086,14 @@ impl RaftConsensus {
                 "Received vote"
             );
 
-            // Check if we have majority
-            // Need (cluster_size / 2) + 1 votes
-            let votes_needed = (state.cluster_size / 2) + 1;
+            // Check if we have majority (of both configurations while joint)
             let votes_count = state.votes_received.len(); // Already includes self
 
-            if votes_count >= votes_needed {
+            if state.is_quorum(&state.votes_received) {
                 info!(
                     node_id = self.node_id,
                     term = state.current_term,
                     votes = votes_count,
-                    needed = votes_needed,
                     "Won election, becoming leader"
                 );
 
@@ -1,11 +1,18 @@
 //! Service discovery for cluster nodes.
 
+use std::collections::HashMap;
 use std::net::SocketAddr;
+use std::sync::Arc;
 
+use async_trait::async_trait;
+use rand::seq::SliceRandom;
 use serde::{Deserialize, Serialize};
+use tokio::net::UdpSocket;
+use tokio::sync::{broadcast, Mutex};
+use tokio::task::JoinHandle;
 use tracing::{debug, info, warn};
 
-use crate::config::{ClusterConfig, DiscoveryMethod};
+use crate::config::{ClusterConfig, DiscoveryMethod, GossipConfig};
 use crate::error::{ClusterError, ClusterResult};
 use crate::node::{NodeId, NodeRole, PeerInfo};
 
@@ -31,6 +38,10 @@ pub struct DiscoveryService {
     consul_config: Option<crate::config::ConsulConfig>,
     /// etcd configuration
     etcd_config: Option<crate::config::EtcdConfig>,
+    /// Gossip configuration
+    gossip_config: Option<GossipConfig>,
+    /// Running gossip member list (if attached)
+    gossip: Option<Arc<GossipDiscovery>>,
     /// Local node ID (to exclude from discovery results)
     local_node_id: NodeId,
 }
@@ -50,10 +61,19 @@ impl DiscoveryService {
             dns_config: config.discovery.dns.clone(),
             consul_config: config.discovery.consul.clone(),
             etcd_config: config.discovery.etcd.clone(),
+            gossip_config: config.discovery.gossip.clone(),
+            gossip: None,
             local_node_id: config.node_id,
         })
     }
 
+    /// Attach a running gossip member list used by `DiscoveryMethod::Gossip`.
+    #[must_use]
+    pub fn with_gossip(mut self, gossip: Arc<GossipDiscovery>) -> Self {
+        self.gossip = Some(gossip);
+        self
+    }
+
     /// Discover cluster nodes.
     pub async fn discover(&self) -> ClusterResult<Vec<PeerInfo>> {
         match &self.method {
@@ -61,7 +81,47 @@ impl DiscoveryService {
             | DiscoveryMethod::Dns => self.discover_dns().await,
             | DiscoveryMethod::Consul => self.discover_consul().await,
             | DiscoveryMethod::Etcd => self.discover_etcd().await,
+            | DiscoveryMethod::Gossip => self.discover_gossip().await,
+        }
+    }
+
+    /// Discover nodes from the gossip member list.
+    ///
+    /// Before a gossip instance is attached only the configured seeds are
+    /// known; their node IDs are derived from their addresses.
+    async fn discover_gossip(&self) -> ClusterResult<Vec<PeerInfo>> {
+        if let Some(gossip) = &self.gossip {
+            let peers = gossip.peers().await;
+            info!(count = peers.len(), "Discovered nodes via gossip");
+            return Ok(peers);
+        }
+
+        let gossip_config = self.gossip_config.as_ref().ok_or_else(|| {
+            ClusterError::ConfigError(
+                "Gossip discovery enabled but no gossip configuration provided".into(),
+            )
+        })?;
+
+        let mut peers = Vec::new();
+        for seed in &gossip_config.seeds {
+            let addr: SocketAddr = seed.parse().map_err(|e| {
+                ClusterError::ConfigError(format!("Invalid gossip seed '{seed}': {e}"))
+            })?;
+            let node_id = generate_node_id_from_addr(&addr);
+            if node_id != self.local_node_id {
+                peers.push(PeerInfo {
+                    node_id,
+                    addr,
+                    role: NodeRole::Follower,
+                    last_heartbeat: None,
+                    healthy: false,
+                    protocol_version: 1, // Default, updated during handshake
+                });
+            }
         }
+
+        info!(count = peers.len(), "Discovered gossip seed nodes");
+        Ok(peers)
     }
 
     /// Discover nodes using static configuration.
@@ -376,3 +436,719 @@ struct ConsulServiceEntry {
     #[serde(default)]
     service_tags: Vec<String>,
 }
+
+/// Liveness state of a gossip member.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MemberState {
+    /// Member answered a recent probe
+    Alive,
+    /// Member failed a direct and indirect probe; may still refute
+    Suspect,
+    /// Member stayed suspected past the suspicion timeout
+    Dead,
+    /// Member left the cluster gracefully
+    Left,
+}
+
+/// A member of the gossip group as seen by the local node.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GossipMember {
+    /// Node identifier
+    pub node_id: NodeId,
+    /// Gossip address of the node
+    pub addr: SocketAddr,
+    /// Liveness state
+    pub state: MemberState,
+    /// Incarnation number; only the member itself increments it (to refute)
+    pub incarnation: u64,
+}
+
+/// Payload of a gossip message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum GossipPayload {
+    /// Direct probe
+    Ping {
+        /// Probe sequence number
+        seq: u64,
+    },
+    /// Probe acknowledgement (direct or relayed)
+    Ack {
+        /// Sequence number of the probe being acknowledged
+        seq: u64,
+        /// Node that answered the probe
+        target: NodeId,
+    },
+    /// Request to probe `target` on the sender's behalf
+    PingReq {
+        /// Sender's probe sequence number
+        seq: u64,
+        /// Node to probe
+        target: NodeId,
+        /// Address of the node to probe
+        target_addr: SocketAddr,
+    },
+    /// Join request from a new node
+    Join,
+    /// Full member list sent in reply to a join
+    JoinAck {
+        /// All members known to the responder, including itself
+        members: Vec<GossipMember>,
+    },
+}
+
+/// Message exchanged between gossip members.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GossipMessage {
+    /// Sending node
+    pub from: NodeId,
+    /// Gossip address of the sending node
+    pub from_addr: SocketAddr,
+    /// Piggybacked membership updates
+    pub updates: Vec<GossipMember>,
+    /// Message payload
+    pub payload: GossipPayload,
+}
+
+/// Membership change observed by the gossip protocol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MembershipEvent {
+    /// A new node joined (or a dead node rejoined)
+    Joined {
+        /// Node identifier
+        node_id: NodeId,
+        /// Gossip address of the node
+        addr: SocketAddr,
+    },
+    /// A node is suspected to have failed
+    Suspected(NodeId),
+    /// A suspected node refuted the suspicion
+    Recovered(NodeId),
+    /// A node was confirmed dead
+    Failed(NodeId),
+    /// A node left gracefully
+    Left(NodeId),
+}
+
+/// Transport used to exchange gossip messages.
+#[async_trait]
+pub trait GossipTransport: Send + Sync {
+    /// Send a message to `to`. Delivery is best effort.
+    async fn send(&self, to: SocketAddr, message: GossipMessage) -> ClusterResult<()>;
+}
+
+/// UDP transport for gossip messages (bincode encoded datagrams).
+pub struct UdpGossipTransport {
+    socket: Arc<UdpSocket>,
+}
+
+impl UdpGossipTransport {
+    /// Bind a UDP socket for gossip traffic.
+    pub async fn bind(addr: SocketAddr) -> ClusterResult<Self> {
+        let socket = UdpSocket::bind(addr).await?;
+        Ok(Self {
+            socket: Arc::new(socket),
+        })
+    }
+
+    /// Get the bound local address.
+    pub fn local_addr(&self) -> ClusterResult<SocketAddr> {
+        Ok(self.socket.local_addr()?)
+    }
+
+    /// Spawn a task feeding received datagrams into `gossip`.
+    pub fn spawn_receiver(&self, gossip: Arc<GossipDiscovery>) -> JoinHandle<()> {
+        let socket = Arc::clone(&self.socket);
+        tokio::spawn(async move {
+            let mut buf = vec![0u8; 64 * 1024];
+            loop {
+                let (len, from) = match socket.recv_from(&mut buf).await {
+                    | Ok(received) => received,
+                    | Err(e) => {
+                        warn!(error = %e, "Gossip socket receive failed");
+                        continue;
+                    },
+                };
+                match bincode::deserialize::<GossipMessage>(&buf[..len]) {
+                    | Ok(message) => {
+                        if let Err(e) = gossip.handle_message(message).await {
+                            debug!(from = %from, error = %e, "Failed to handle gossip message");
+                        }
+                    },
+                    | Err(e) => {
+                        debug!(from = %from, error = %e, "Dropping malformed gossip datagram")
+                    },
+                }
+            }
+        })
+    }
+}
+
+#[async_trait]
+impl GossipTransport for UdpGossipTransport {
+    async fn send(&self, to: SocketAddr, message: GossipMessage) -> ClusterResult<()> {
+        let bytes = bincode::serialize(&message)?;
+        self.socket.send_to(&bytes, to).await?;
+        Ok(())
+    }
+}
+
+/// Outstanding probe of the current protocol period.
+struct Probe {
+    target: NodeId,
+    seq: u64,
+    indirect_sent: bool,
+    acked: bool,
+}
+
+/// Mutable state of the gossip protocol.
+struct GossipState {
+    /// Local incarnation number
+    incarnation: u64,
+    /// Known members, excluding the local node
+    members: HashMap<NodeId, GossipMember>,
+    /// Protocol period in which each suspect was first suspected
+    suspect_since: HashMap<NodeId, u64>,
+    /// Current protocol period
+    period: u64,
+    /// Next probe sequence number
+    next_seq: u64,
+    /// Outstanding probe, if any
+    probe: Option<Probe>,
+    /// Remaining probe targets for this round (shuffled round robin)
+    probe_order: Vec<NodeId>,
+    /// Relayed probes: our seq -> (requester addr, requester seq, period)
+    relays: HashMap<u64, (SocketAddr, u64, u64)>,
+    /// Updates waiting to be piggybacked, with remaining transmissions
+    broadcasts: Vec<(GossipMember, u32)>,
+    /// Whether the local node has left
+    left: bool,
+}
+
+/// SWIM-style gossip membership.
+///
+/// Each protocol period ([`GossipDiscovery::tick`]) the node pings one member.
+/// If no ack arrives by the next period it asks `indirect_checks` other members
+/// to ping the target; if that also fails the target becomes *suspect*. A
+/// suspect that does not refute (by gossiping a higher incarnation) within
+/// `suspicion_periods` is declared *dead*. Membership updates are piggybacked
+/// on protocol messages and surfaced as [`MembershipEvent`]s.
+pub struct GossipDiscovery {
+    node_id: NodeId,
+    addr: SocketAddr,
+    config: GossipConfig,
+    transport: Arc<dyn GossipTransport>,
+    state: Mutex<GossipState>,
+    events: broadcast::Sender<MembershipEvent>,
+}
+
+impl GossipDiscovery {
+    /// Create a gossip member for the local node.
+    pub fn new(
+        node_id: NodeId,
+        addr: SocketAddr,
+        config: GossipConfig,
+        transport: Arc<dyn GossipTransport>,
+    ) -> Self {
+        let (events, _) = broadcast::channel(256);
+        Self {
+            node_id,
+            addr,
+            config,
+            transport,
+            state: Mutex::new(GossipState {
+                incarnation: 0,
+                members: HashMap::new(),
+                suspect_since: HashMap::new(),
+                period: 0,
+                next_seq: 1,
+                probe: None,
+                probe_order: Vec::new(),
+                relays: HashMap::new(),
+                broadcasts: Vec::new(),
+                left: false,
+            }),
+            events,
+        }
+    }
+
+    /// Subscribe to membership events (joins, failures, leaves).
+    pub fn subscribe(&self) -> broadcast::Receiver<MembershipEvent> {
+        self.events.subscribe()
+    }
+
+    /// Get all known members (excluding the local node).
+    pub async fn members(&self) -> Vec<GossipMember> {
+        let state = self.state.lock().await;
+        let mut members: Vec<GossipMember> = state.members.values().cloned().collect();
+        members.sort_by_key(|m| m.node_id);
+        members
+    }
+
+    /// Get the state of a member as seen locally.
+    pub async fn member_state(&self, node_id: NodeId) -> Option<MemberState> {
+        self.state
+            .lock()
+            .await
+            .members
+            .get(&node_id)
+            .map(|m| m.state)
+    }
+
+    /// Get live members as peers; suspects are reported as unhealthy.
+    pub async fn peers(&self) -> Vec<PeerInfo> {
+        self.members()
+            .await
+            .into_iter()
+            .filter(|m| matches!(m.state, MemberState::Alive | MemberState::Suspect))
+            .map(|m| PeerInfo {
+                node_id: m.node_id,
+                addr: m.addr,
+                role: NodeRole::Follower,
+                last_heartbeat: None,
+                healthy: m.state == MemberState::Alive,
+                protocol_version: 1, // Default, updated during handshake
+            })
+            .collect()
+    }
+
+    /// Announce the local node to the given seeds.
+    pub async fn join(&self, seeds: &[SocketAddr]) -> ClusterResult<()> {
+        let message = {
+            let mut state = self.state.lock().await;
+            state.left = false;
+            self.message(&mut state, GossipPayload::Join)
+        };
+        for &seed in seeds.iter().filter(|&&seed| seed != self.addr) {
+            self.transport.send(seed, message.clone()).await?;
+        }
+        info!(
+            node_id = self.node_id,
+            seeds = seeds.len(),
+            "Joining gossip group"
+        );
+        Ok(())
+    }
+
+    /// Leave the group gracefully, telling every known member.
+    pub async fn leave(&self) -> ClusterResult<()> {
+        let (targets, message) = {
+            let mut state = self.state.lock().await;
+            state.incarnation += 1;
+            state.left = true;
+            let me = GossipMember {
+                node_id: self.node_id,
+                addr: self.addr,
+                state: MemberState::Left,
+                incarnation: state.incarnation,
+            };
+            let targets: Vec<SocketAddr> = state
+                .members
+                .values()
+                .filter(|m| m.state != MemberState::Dead && m.state != MemberState::Left)
+                .map(|m| m.addr)
+                .collect();
+            let seq = Self::next_seq(&mut state);
+            let message = GossipMessage {
+                from: self.node_id,
+                from_addr: self.addr,
+                updates: vec![me],
+                payload: GossipPayload::Ping { seq },
+            };
+            (targets, message)
+        };
+        for target in targets {
+            self.transport.send(target, message.clone()).await?;
+        }
+        info!(node_id = self.node_id, "Left gossip group");
+        Ok(())
+    }
+
+    /// Run the protocol loop on a timer, one tick per protocol period.
+    pub fn start(self: &Arc<Self>) -> JoinHandle<()> {
+        let gossip = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(gossip.config.protocol_period);
+            loop {
+                interval.tick().await;
+                if gossip.state.lock().await.left {
+                    break;
+                }
+                if let Err(e) = gossip.tick().await {
+                    warn!(node_id = gossip.node_id, error = %e, "Gossip protocol period failed");
+                }
+            }
+        })
+    }
+
+    /// Run one protocol period.
+    pub async fn tick(&self) -> ClusterResult<()> {
+        let mut outgoing = Vec::new();
+        let mut events = Vec::new();
+        {
+            let mut state = self.state.lock().await;
+            if state.left {
+                return Ok(());
+            }
+            state.period += 1;
+            let period = state.period;
+            state
+                .relays
+                .retain(|_, &mut (_, _, started)| period.saturating_sub(started) <= 2);
+
+            // Evaluate the probe from the previous period
+            if let Some(mut probe) = state.probe.take() {
+                if !probe.acked {
+                    if probe.indirect_sent {
+                        self.suspect(&mut state, probe.target, &mut events);
+                    } else {
+                        let helpers = self.indirect_helpers(&state, probe.target);
+                        let target_addr = state.members.get(&probe.target).map(|m| m.addr);
+                        match target_addr {
+                            | Some(target_addr) if !helpers.is_empty() => {
+                                for helper in helpers {
+                                    let payload = GossipPayload::PingReq {
+                                        seq: probe.seq,
+                                        target: probe.target,
+                                        target_addr,
+                                    };
+                                    outgoing.push((helper, self.message(&mut state, payload)));
+                                }
+                                probe.indirect_sent = true;
+                                state.probe = Some(probe);
+                            },
+                            | _ => self.suspect(&mut state, probe.target, &mut events),
+                        }
+                    }
+                }
+            }
+
+            // Suspects that did not refute in time are confirmed dead
+            let expired: Vec<NodeId> = state
+                .suspect_since
+                .iter()
+                .filter(|&(_, &since)| {
+                    period.saturating_sub(since) >= self.config.suspicion_periods
+                })
+                .map(|(&id, _)| id)
+                .collect();
+            for node_id in expired {
+                state.suspect_since.remove(&node_id);
+                if let Some(member) = state.members.get(&node_id).cloned() {
+                    if member.state == MemberState::Suspect {
+                        let dead = GossipMember {
+                            state: MemberState::Dead,
+                            ..member
+                        };
+                        warn!(
+                            node_id = self.node_id,
+                            member = node_id,
+                            "Member confirmed dead"
+                        );
+                        state.members.insert(node_id, dead.clone());
+                        self.queue_broadcast(&mut state, dead);
+                        events.push(MembershipEvent::Failed(node_id));
+                    }
+                }
+            }
+
+            // Start a new probe
+            if state.probe.is_none() {
+                if let Some(target) = self.next_probe_target(&mut state) {
+                    let seq = Self::next_seq(&mut state);
+                    let addr = state.members[&target].addr;
+                    outgoing.push((addr, self.message(&mut state, GossipPayload::Ping { seq })));
+                    state.probe = Some(Probe {
+                        target,
+                        seq,
+                        indirect_sent: false,
+                        acked: false,
+                    });
+                }
+            }
+        }
+
+        self.emit(events);
+        for (to, message) in outgoing {
+            self.transport.send(to, message).await?;
+        }
+        Ok(())
+    }
+
+    /// Handle a message received from another member.
+    pub async fn handle_message(&self, message: GossipMessage) -> ClusterResult<()> {
+        let mut outgoing = Vec::new();
+        let mut events = Vec::new();
+        {
+            let mut state = self.state.lock().await;
+            if state.left {
+                return Ok(());
+            }
+
+            // A message proves the sender is reachable; learn unknown senders
+            if message.from != self.node_id && !state.members.contains_key(&message.from) {
+                let sender = GossipMember {
+                    node_id: message.from,
+                    addr: message.from_addr,
+                    state: MemberState::Alive,
+                    incarnation: 0,
+                };
+                self.apply_update(&mut state, sender, &mut events);
+            }
+            for update in message.updates {
+                self.apply_update(&mut state, update, &mut events);
+            }
+
+            match message.payload {
+                | GossipPayload::Ping { seq } => {
+                    let payload = GossipPayload::Ack {
+                        seq,
+                        target: self.node_id,
+                    };
+                    outgoing.push((message.from_addr, self.message(&mut state, payload)));
+                },
+                | GossipPayload::Ack { seq, target } => {
+                    if let Some((requester, requester_seq, _)) = state.relays.remove(&seq) {
+                        let payload = GossipPayload::Ack {
+                            seq: requester_seq,
+                            target,
+                        };
+                        outgoing.push((requester, self.message(&mut state, payload)));
+                    } else if let Some(probe) = state.probe.as_mut() {
+                        if probe.seq == seq && probe.target == target {
+                            probe.acked = true;
+                        }
+                    }
+                },
+                | GossipPayload::PingReq {
+                    seq, target_addr, ..
+                } => {
+                    let relay_seq = Self::next_seq(&mut state);
+                    let period = state.period;
+                    state
+                        .relays
+                        .insert(relay_seq, (message.from_addr, seq, period));
+                    let payload = GossipPayload::Ping { seq: relay_seq };
+                    outgoing.push((target_addr, self.message(&mut state, payload)));
+                },
+                | GossipPayload::Join => {
+                    let mut members: Vec<GossipMember> = state.members.values().cloned().collect();
+                    members.push(self.local_member(&state));
+                    let payload = GossipPayload::JoinAck { members };
+                    outgoing.push((message.from_addr, self.message(&mut state, payload)));
+                },
+                | GossipPayload::JoinAck { members } => {
+                    for member in members {
+                        self.apply_update(&mut state, member, &mut events);
+                    }
+                },
+            }
+        }
+
+        self.emit(events);
+        for (to, message) in outgoing {
+            self.transport.send(to, message).await?;
+        }
+        Ok(())
+    }
+
+    /// Apply a membership update following SWIM's incarnation rules.
+    fn apply_update(
+        &self,
+        state: &mut GossipState,
+        update: GossipMember,
+        events: &mut Vec<MembershipEvent>,
+    ) {
+        if update.node_id == self.node_id {
+            // Refute suspicion (or a stale death) by bumping our incarnation
+            if update.state != MemberState::Alive && update.incarnation >= state.incarnation {
+                state.incarnation = update.incarnation + 1;
+                let me = self.local_member(state);
+                self.queue_broadcast(state, me);
+                debug!(
+                    node_id = self.node_id,
+                    incarnation = state.incarnation,
+                    "Refuted suspicion"
+                );
+            }
+            return;
+        }
+
+        let Some(current) = state.members.get(&update.node_id).cloned() else {
+            if matches!(update.state, MemberState::Alive | MemberState::Suspect) {
+                info!(node_id = self.node_id, member = update.node_id, addr = %update.addr, "Member joined");
+                events.push(MembershipEvent::Joined {
+                    node_id: update.node_id,
+                    addr: update.addr,
+                });
+                if update.state == MemberState::Suspect {
+                    state.suspect_since.insert(update.node_id, state.period);
+                }
+                state.members.insert(update.node_id, update.clone());
+                self.queue_broadcast(state, update);
+            }
+            return;
+        };
+
+        let newer = update.incarnation > current.incarnation;
+        let same = update.incarnation == current.incarnation;
+        let gone = matches!(current.state, MemberState::Dead | MemberState::Left);
+
+        let accept = match update.state {
+            | MemberState::Alive => newer,
+            | MemberState::Suspect => {
+                !gone && (newer || (same && current.state == MemberState::Alive))
+            },
+            | MemberState::Dead | MemberState::Left => !gone && (newer || same),
+        };
+        if !accept {
+            return;
+        }
+
+        match update.state {
+            | MemberState::Alive => {
+                state.suspect_since.remove(&update.node_id);
+                match current.state {
+                    | MemberState::Suspect => {
+                        events.push(MembershipEvent::Recovered(update.node_id))
+                    },
+                    | MemberState::Dead | MemberState::Left => {
+                        events.push(MembershipEvent::Joined {
+                            node_id: update.node_id,
+                            addr: update.addr,
+                        })
+                    },
+                    | MemberState::Alive => {},
+                }
+            },
+            | MemberState::Suspect => {
+                state
+                    .suspect_since
+                    .entry(update.node_id)
+                    .or_insert(state.period);
+                if current.state == MemberState::Alive {
+                    events.push(MembershipEvent::Suspected(update.node_id));
+                }
+            },
+            | MemberState::Dead => {
+                state.suspect_since.remove(&update.node_id);
+                events.push(MembershipEvent::Failed(update.node_id));
+            },
+            | MemberState::Left => {
+                state.suspect_since.remove(&update.node_id);
+                events.push(MembershipEvent::Left(update.node_id));
+            },
+        }
+
+        state.members.insert(update.node_id, update.clone());
+        self.queue_broadcast(state, update);
+    }
+
+    /// Mark a member suspect after a failed probe.
+    fn suspect(&self, state: &mut GossipState, node_id: NodeId, events: &mut Vec<MembershipEvent>) {
+        if let Some(member) = state.members.get(&node_id).cloned() {
+            if member.state == MemberState::Alive {
+                debug!(
+                    node_id = self.node_id,
+                    member = node_id,
+                    "Probe failed, suspecting member"
+                );
+                let suspect = GossipMember {
+                    state: MemberState::Suspect,
+                    ..member
+                };
+                self.apply_update(state, suspect, events);
+            }
+        }
+    }
+
+    /// Pick the next probe target (round robin over a shuffled member list).
+    fn next_probe_target(&self, state: &mut GossipState) -> Option<NodeId> {
+        loop {
+            if state.probe_order.is_empty() {
+                let mut order: Vec<NodeId> = state
+                    .members
+                    .values()
+                    .filter(|m| matches!(m.state, MemberState::Alive | MemberState::Suspect))
+                    .map(|m| m.node_id)
+                    .collect();
+                if order.is_empty() {
+                    return None;
+                }
+                order.shuffle(&mut rand::thread_rng());
+                state.probe_order = order;
+            }
+            let candidate = state.probe_order.pop()?;
+            if state
+                .members
+                .get(&candidate)
+                .is_some_and(|m| matches!(m.state, MemberState::Alive | MemberState::Suspect))
+            {
+                return Some(candidate);
+            }
+        }
+    }
+
+    /// Choose members to probe `target` indirectly.
+    fn indirect_helpers(&self, state: &GossipState, target: NodeId) -> Vec<SocketAddr> {
+        let mut helpers: Vec<SocketAddr> = state
+            .members
+            .values()
+            .filter(|m| m.node_id != target && m.state == MemberState::Alive)
+            .map(|m| m.addr)
+            .collect();
+        helpers.shuffle(&mut rand::thread_rng());
+        helpers.truncate(self.config.indirect_checks);
+        helpers
+    }
+
+    /// Queue an update for piggybacking, replacing older news about the node.
+    fn queue_broadcast(&self, state: &mut GossipState, member: GossipMember) {
+        let group_size = state.members.len() + 1;
+        let transmissions =
+            self.config.retransmit_mult * (usize::BITS - group_size.leading_zeros()).max(1);
+        state
+            .broadcasts
+            .retain(|(queued, _)| queued.node_id != member.node_id);
+        state.broadcasts.push((member, transmissions));
+    }
+
+    /// Build a message, piggybacking the freshest pending updates.
+    fn message(&self, state: &mut GossipState, payload: GossipPayload) -> GossipMessage {
+        state.broadcasts.sort_by(|(_, a), (_, b)| b.cmp(a));
+        let mut updates = Vec::new();
+        for (member, remaining) in state.broadcasts.iter_mut().take(self.config.max_piggyback) {
+            updates.push(member.clone());
+            *remaining = remaining.saturating_sub(1);
+        }
+        state.broadcasts.retain(|&(_, remaining)| remaining > 0);
+
+        GossipMessage {
+            from: self.node_id,
+            from_addr: self.addr,
+            updates,
+            payload,
+        }
+    }
+
+    fn local_member(&self, state: &GossipState) -> GossipMember {
+        GossipMember {
+            node_id: self.node_id,
+            addr: self.addr,
+            state: MemberState::Alive,
+            incarnation: state.incarnation,
+        }
+    }
+
+    fn next_seq(state: &mut GossipState) -> u64 {
+        let seq = state.next_seq;
+        state.next_seq += 1;
+        seq
+    }
+
+    fn emit(&self, events: Vec<MembershipEvent>) {
+        for event in events {
+            // No subscribers is fine; events are informational
+            let _ = self.events.send(event);
+        }
+    }
+}
@@ -120,6 +120,18 @@ pub enum ClusterError {
     /// Minimum healthy nodes requirement not met
     #[error("Minimum healthy nodes requirement not met: {current} < {required}")]
     InsufficientHealthyNodes { current: usize, required: usize },
+
+    /// A membership change is already in progress
+    #[error("Membership change already in progress")]
+    MembershipChangeInProgress,
+
+    /// A joining node has not caught up with the leader's log yet
+    #[error("Node {node_id} is lagging: matched index {matched}, required {required}")]
+    LearnerLagging {
+        node_id: u64,
+        matched: u64,
+        required: u64,
+    },
 }
 
 impl From<std::io::Error> for ClusterError {
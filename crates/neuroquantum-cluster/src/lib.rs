@@ -64,6 +64,7 @@
 //! - **Raft Consensus**: Leader election and log replication using `openraft`
 //! - **gRPC Transport**: Inter-node communication via `tonic`
 //! - **Consistent Hashing**: Data sharding across nodes
+//! - **Query Routing**: Writes to the leader, reads balanced across up-to-date followers
 //! - **Service Discovery**: DNS-based or static node discovery
 //! - **Cluster Manager**: High-level coordination for multi-node deployments
 //! - **Metrics**: Prometheus-compatible metrics for observability
@@ -105,6 +106,7 @@ pub mod metrics;
 pub mod network;
 pub mod node;
 pub mod replication;
+pub mod routing;
 pub mod sharding;
 pub mod upgrade;
 
@@ -115,8 +117,12 @@ pub use error::{ClusterError, ClusterResult};
 pub use metrics::{ClusterMetrics, MetricsSnapshot};
 pub use node::{ClusterNode, NodeId, NodeRole, NodeState};
 pub use replication::ConsistencyLevel;
+pub use routing::{
+    ConsistencyToken, QueryKind, QueryRouter, QueryRouterConfig, ReplicaStatus, RouteTarget,
+};
 pub use sharding::{
-    RebalanceConfig, RebalanceProgress, ShardId, ShardInfo, ShardManager, ShardState, ShardStats,
-    ShardTransfer, TransferId, TransferStatus,
+    CopyBatch, RebalanceConfig, RebalanceEstimate, RebalanceJournal, RebalanceProgress, ShardId,
+    ShardInfo, ShardManager, ShardMove, ShardMover, ShardState, ShardStats, ShardTransfer,
+    TransferId, TransferStatus,
 };
 pub use upgrade::{canary_upgrade, UpgradeCoordinator, UpgradeProgress, UpgradeStatus};
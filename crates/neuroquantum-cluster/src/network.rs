@@ -175,6 +175,8 @@ pub struct InstallSnapshotRequest {
 pub struct InstallSnapshotResponse {
     /// Current term, for leader to update itself
     pub term: u64,
+    /// False if the chunk did not continue the snapshot being received
+    pub success: bool,
 }
 
 /// Simple ping request for health checks.
@@ -636,6 +638,20 @@ impl NetworkTransport {
                         ClusterError::ConnectionFailed(peer.addr, format!("gRPC call failed: {e}"))
                     })?;
                 },
+                | ClusterMessage::InstallSnapshot(snapshot_req) => {
+                    let req = proto::InstallSnapshotRequest {
+                        term: snapshot_req.term,
+                        leader_id: snapshot_req.leader_id,
+                        last_included_index: snapshot_req.last_included_index,
+                        last_included_term: snapshot_req.last_included_term,
+                        offset: snapshot_req.offset,
+                        data: snapshot_req.data.clone(),
+                        done: snapshot_req.done,
+                    };
+                    client.install_snapshot(req).await.map_err(|e| {
+                        ClusterError::ConnectionFailed(peer.addr, format!("gRPC call failed: {e}"))
+                    })?;
+                },
                 | _ => {
                     // Other message types would be handled similarly
                     debug!("Message type not yet implemented for gRPC");
@@ -1,8 +1,9 @@
 //! Cluster node management and lifecycle.
 
+use std::collections::BTreeSet;
 use std::net::SocketAddr;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
@@ -18,6 +19,13 @@ use crate::sharding::ShardManager;
 /// Unique identifier for a node in the cluster.
 pub type NodeId = u64;
 
+/// How long a membership change may wait for a joining node to catch up
+/// or for a joint configuration to commit.
+const MEMBERSHIP_CHANGE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Poll interval while waiting on a membership change.
+const MEMBERSHIP_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
 /// Role of a node in the Raft cluster.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum NodeRole {
@@ -320,6 +328,187 @@ impl ClusterNode {
         self.consensus.clone()
     }
 
+    /// Add a node to the cluster.
+    ///
+    /// The node joins as a learner first and receives the log. Only once it has
+    /// caught up is it promoted to a voter through a joint-consensus
+    /// configuration change, so the quorum never depends on an empty replica.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this node is not the leader, the node is already a
+    /// member, another membership change is in progress, or the new node does
+    /// not catch up in time. A failed join leaves no learner behind.
+    pub async fn add_member(&self, node_id: NodeId, addr: SocketAddr) -> ClusterResult<()> {
+        info!(
+            node_id = self.node_id,
+            new_member = node_id,
+            addr = %addr,
+            "Adding cluster member"
+        );
+
+        self.consensus.add_learner(node_id).await?;
+        // A peer the transport already knew is left in place if the join fails
+        let added_peer = match self.transport.add_peer(node_id, addr).await {
+            | Ok(()) => true,
+            | Err(ClusterError::NodeAlreadyExists(_)) => false,
+            | Err(e) => {
+                self.abandon_join(node_id, false).await;
+                return Err(e);
+            },
+        };
+
+        let result = self.promote_learner(node_id, addr).await;
+        if result.is_err() {
+            self.abandon_join(node_id, added_peer).await;
+        }
+        result
+    }
+
+    /// Wait for the learner `node_id` to catch up, then make it a voter.
+    async fn promote_learner(&self, node_id: NodeId, addr: SocketAddr) -> ClusterResult<()> {
+        {
+            let mut inner = self.inner.write().await;
+            let protocol_version = inner.config.manager.upgrades.protocol_version;
+            inner.peers.retain(|p| p.node_id != node_id);
+            inner.peers.push(PeerInfo {
+                node_id,
+                addr,
+                role: NodeRole::Learner,
+                last_heartbeat: None,
+                healthy: true,
+                protocol_version,
+            });
+        }
+
+        self.wait_for_catch_up(node_id).await?;
+
+        let mut voters = self.consensus.membership().await.voters;
+        voters.insert(node_id);
+        self.change_voters(voters).await?;
+
+        let mut inner = self.inner.write().await;
+        if let Some(peer) = inner.peers.iter_mut().find(|p| p.node_id == node_id) {
+            peer.role = NodeRole::Follower;
+        }
+
+        info!(
+            node_id = self.node_id,
+            new_member = node_id,
+            "Cluster member added"
+        );
+        Ok(())
+    }
+
+    /// Undo the registration of a learner whose join failed.
+    ///
+    /// A node that already became a voter through a pending joint
+    /// configuration stays a member; the configuration change still has to
+    /// finish or be reverted by a later membership change.
+    async fn abandon_join(&self, node_id: NodeId, remove_peer: bool) {
+        if !self
+            .consensus
+            .membership()
+            .await
+            .learners
+            .contains(&node_id)
+        {
+            warn!(
+                node_id = self.node_id,
+                member = node_id,
+                "Join failed after the member became a voter, keeping it"
+            );
+            return;
+        }
+        if let Err(e) = self.consensus.remove_learner(node_id).await {
+            warn!(
+                node_id = self.node_id,
+                member = node_id,
+                error = %e,
+                "Failed to remove learner after a failed join"
+            );
+            return;
+        }
+        if remove_peer {
+            let _ = self.transport.remove_peer(node_id).await;
+        }
+        self.inner
+            .write()
+            .await
+            .peers
+            .retain(|p| p.node_id != node_id);
+    }
+
+    /// Remove a node from the cluster.
+    ///
+    /// Voters are removed through a joint-consensus configuration change;
+    /// learners that were never promoted are dropped directly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this node is not the leader, the node is not a
+    /// member, another membership change is in progress, or the change does
+    /// not commit in time.
+    pub async fn remove_member(&self, node_id: NodeId) -> ClusterResult<()> {
+        info!(
+            node_id = self.node_id,
+            member = node_id,
+            "Removing cluster member"
+        );
+
+        let membership = self.consensus.membership().await;
+        if membership.learners.contains(&node_id) {
+            self.consensus.remove_learner(node_id).await?;
+        } else if membership.voters.contains(&node_id) {
+            let mut voters = membership.voters;
+            voters.remove(&node_id);
+            self.change_voters(voters).await?;
+        } else {
+            return Err(ClusterError::NodeNotFound(node_id));
+        }
+
+        // The node may already be gone from the transport if it never connected
+        let _ = self.transport.remove_peer(node_id).await;
+        self.inner
+            .write()
+            .await
+            .peers
+            .retain(|p| p.node_id != node_id);
+
+        info!(
+            node_id = self.node_id,
+            member = node_id,
+            "Cluster member removed"
+        );
+        Ok(())
+    }
+
+    /// Wait until a learner has replicated everything committed so far.
+    async fn wait_for_catch_up(&self, node_id: NodeId) -> ClusterResult<()> {
+        let start = tokio::time::Instant::now();
+        while !self.consensus.is_caught_up(node_id).await {
+            if start.elapsed() >= MEMBERSHIP_CHANGE_TIMEOUT {
+                return Err(ClusterError::Timeout(MEMBERSHIP_CHANGE_TIMEOUT));
+            }
+            tokio::time::sleep(MEMBERSHIP_POLL_INTERVAL).await;
+        }
+        Ok(())
+    }
+
+    /// Propose a new voter set and wait for the joint configuration to finish.
+    async fn change_voters(&self, voters: BTreeSet<NodeId>) -> ClusterResult<()> {
+        self.consensus.change_membership(voters).await?;
+
+        let start = tokio::time::Instant::now();
+        while self.consensus.membership().await.is_joint() {
+            if start.elapsed() >= MEMBERSHIP_CHANGE_TIMEOUT {
+                return Err(ClusterError::Timeout(MEMBERSHIP_CHANGE_TIMEOUT));
+            }
+            tokio::time::sleep(MEMBERSHIP_POLL_INTERVAL).await;
+        }
+        Ok(())
+    }
+
     /// Check network partition and update node state.
     pub async fn check_network_partition(&self) -> ClusterResult<()> {
         let inner = self.inner.read().await;
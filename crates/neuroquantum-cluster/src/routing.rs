@@ -0,0 +1,300 @@
+//! Read-replica query routing.
+//!
+//! The [`QueryRouter`] sends writes to the Raft leader and spreads reads
+//! across healthy followers, skipping any follower whose replicated LSN lags
+//! the leader by more than the configured staleness bound.
+//!
+//! Writes hand back a [`ConsistencyToken`] holding the LSN they committed
+//! at. A read carrying that token is only served by a follower that has
+//! replicated at least that far, which gives a client read-your-writes
+//! without pinning all of its reads to the leader.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tracing::{debug, warn};
+
+use crate::error::{ClusterError, ClusterResult};
+use crate::node::{NodeId, NodeRole};
+
+/// Read/write classification of a statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QueryKind {
+    /// Statement only reads data and may be served by a follower
+    Read,
+    /// Statement modifies data or schema and must run on the leader
+    Write,
+}
+
+/// Log position a read must observe, returned to the client by a write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ConsistencyToken(u64);
+
+impl ConsistencyToken {
+    /// Create a token for the given committed LSN.
+    #[must_use]
+    pub const fn new(lsn: u64) -> Self {
+        Self(lsn)
+    }
+
+    /// The LSN a replica must have replicated to serve reads with this token.
+    #[must_use]
+    pub const fn lsn(self) -> u64 {
+        self.0
+    }
+
+    /// Whether a replica at `replicated_lsn` has seen the write.
+    #[must_use]
+    pub const fn is_satisfied_by(self, replicated_lsn: u64) -> bool {
+        replicated_lsn >= self.0
+    }
+}
+
+/// Configuration for the query router.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryRouterConfig {
+    /// Maximum number of log entries a follower may lag the leader by and
+    /// still serve reads
+    pub max_staleness_lsn: u64,
+    /// Serve reads from the leader when no follower is eligible
+    pub fallback_to_leader: bool,
+}
+
+impl Default for QueryRouterConfig {
+    fn default() -> Self {
+        Self {
+            max_staleness_lsn: 1000,
+            fallback_to_leader: true,
+        }
+    }
+}
+
+/// Routing-relevant state of a cluster member.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplicaStatus {
+    /// Node identifier
+    pub node_id: NodeId,
+    /// Address queries should be sent to
+    pub addr: SocketAddr,
+    /// Current Raft role
+    pub role: NodeRole,
+    /// Whether the node is considered healthy
+    pub healthy: bool,
+    /// Highest log sequence number replicated to (or, for the leader, written by) this node
+    pub replicated_lsn: u64,
+}
+
+/// Where a statement should be executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RouteTarget {
+    /// Node that should execute the statement
+    pub node_id: NodeId,
+    /// Address of that node
+    pub addr: SocketAddr,
+    /// Role of the node at routing time
+    pub role: NodeRole,
+}
+
+/// Routes statements to the leader or to read replicas.
+pub struct QueryRouter {
+    /// Router configuration
+    config: QueryRouterConfig,
+    /// Known cluster members
+    replicas: RwLock<HashMap<NodeId, ReplicaStatus>>,
+    /// Round-robin cursor for read load balancing
+    next_read: AtomicUsize,
+}
+
+impl QueryRouter {
+    /// Create a new router with the given configuration.
+    #[must_use]
+    pub fn new(config: QueryRouterConfig) -> Self {
+        Self {
+            config,
+            replicas: RwLock::new(HashMap::new()),
+            next_read: AtomicUsize::new(0),
+        }
+    }
+
+    /// Get the router configuration.
+    pub const fn config(&self) -> &QueryRouterConfig {
+        &self.config
+    }
+
+    /// Insert or update the status of a cluster member.
+    pub async fn update_replica(&self, status: ReplicaStatus) {
+        let mut replicas = self.replicas.write().await;
+        if status.role == NodeRole::Leader {
+            // A new leader demotes whichever node held the role before
+            for replica in replicas.values_mut() {
+                if replica.role == NodeRole::Leader && replica.node_id != status.node_id {
+                    replica.role = NodeRole::Follower;
+                }
+            }
+        }
+        replicas.insert(status.node_id, status);
+    }
+
+    /// Record the latest replicated LSN for a member.
+    pub async fn update_lsn(&self, node_id: NodeId, lsn: u64) -> ClusterResult<()> {
+        let mut replicas = self.replicas.write().await;
+        let replica = replicas
+            .get_mut(&node_id)
+            .ok_or(ClusterError::NodeNotFound(node_id))?;
+        replica.replicated_lsn = lsn;
+        Ok(())
+    }
+
+    /// Mark a member healthy or unhealthy.
+    pub async fn set_healthy(&self, node_id: NodeId, healthy: bool) -> ClusterResult<()> {
+        let mut replicas = self.replicas.write().await;
+        let replica = replicas
+            .get_mut(&node_id)
+            .ok_or(ClusterError::NodeNotFound(node_id))?;
+        replica.healthy = healthy;
+        Ok(())
+    }
+
+    /// Record a write the leader committed at `lsn` and return its token.
+    ///
+    /// The leader's replicated LSN is advanced to `lsn` if it was behind, so
+    /// the staleness bound is measured against the newest write.
+    pub async fn record_write(&self, lsn: u64) -> ClusterResult<ConsistencyToken> {
+        let mut replicas = self.replicas.write().await;
+        let leader = replicas
+            .values_mut()
+            .find(|r| r.role == NodeRole::Leader)
+            .ok_or(ClusterError::NoLeader)?;
+        leader.replicated_lsn = leader.replicated_lsn.max(lsn);
+        Ok(ConsistencyToken::new(lsn))
+    }
+
+    /// Forget a member.
+    pub async fn remove_replica(&self, node_id: NodeId) -> ClusterResult<()> {
+        self.replicas
+            .write()
+            .await
+            .remove(&node_id)
+            .map(|_| ())
+            .ok_or(ClusterError::NodeNotFound(node_id))
+    }
+
+    /// Get the current leader, if known.
+    pub async fn leader(&self) -> Option<ReplicaStatus> {
+        self.replicas
+            .read()
+            .await
+            .values()
+            .find(|r| r.role == NodeRole::Leader)
+            .cloned()
+    }
+
+    /// Get the followers currently eligible to serve reads, ordered by node ID.
+    ///
+    /// A follower is eligible when it is healthy and its replicated LSN is
+    /// within `max_staleness_lsn` of the leader's.
+    pub async fn eligible_readers(&self) -> Vec<ReplicaStatus> {
+        self.eligible_readers_for(None).await
+    }
+
+    /// Get the followers eligible to serve a read carrying `token`.
+    ///
+    /// On top of the staleness bound, a follower must have replicated at
+    /// least up to the token's LSN.
+    pub async fn eligible_readers_for(
+        &self,
+        token: Option<ConsistencyToken>,
+    ) -> Vec<ReplicaStatus> {
+        let replicas = self.replicas.read().await;
+        let Some(leader_lsn) = replicas
+            .values()
+            .find(|r| r.role == NodeRole::Leader)
+            .map(|r| r.replicated_lsn)
+        else {
+            // Without a leader the lag cannot be measured
+            return Vec::new();
+        };
+
+        let mut readers: Vec<ReplicaStatus> = replicas
+            .values()
+            .filter(|r| r.role == NodeRole::Follower && r.healthy)
+            .filter(|r| {
+                leader_lsn.saturating_sub(r.replicated_lsn) <= self.config.max_staleness_lsn
+            })
+            .filter(|r| token.is_none_or(|t| t.is_satisfied_by(r.replicated_lsn)))
+            .cloned()
+            .collect();
+        readers.sort_by_key(|r| r.node_id);
+        readers
+    }
+
+    /// Choose the node that should execute a statement of the given kind.
+    ///
+    /// Writes always go to the leader. Reads are balanced round-robin across
+    /// eligible followers, falling back to the leader when none qualify and
+    /// `fallback_to_leader` is set.
+    pub async fn route(&self, kind: QueryKind) -> ClusterResult<RouteTarget> {
+        self.route_with_token(kind, None).await
+    }
+
+    /// Choose the node that should execute a statement, honouring the
+    /// client's consistency token.
+    ///
+    /// Reads with a token only go to followers that have replicated the
+    /// token's LSN. When none has, the read goes to the leader, which always
+    /// has, regardless of `fallback_to_leader`.
+    pub async fn route_with_token(
+        &self,
+        kind: QueryKind,
+        token: Option<ConsistencyToken>,
+    ) -> ClusterResult<RouteTarget> {
+        let target = match kind {
+            | QueryKind::Write => self.route_to_leader().await?,
+            | QueryKind::Read => {
+                let readers = self.eligible_readers_for(token).await;
+                if readers.is_empty() {
+                    if let Some(token) = token {
+                        debug!(
+                            lsn = token.lsn(),
+                            "No follower has the write, routing read to leader"
+                        );
+                    } else if !self.config.fallback_to_leader {
+                        return Err(ClusterError::ReplicationError(
+                            "No follower is within the staleness bound".into(),
+                        ));
+                    } else {
+                        warn!("No eligible read replica, routing read to leader");
+                    }
+                    self.route_to_leader().await?
+                } else {
+                    let idx = self.next_read.fetch_add(1, Ordering::Relaxed) % readers.len();
+                    let reader = &readers[idx];
+                    RouteTarget {
+                        node_id: reader.node_id,
+                        addr: reader.addr,
+                        role: reader.role,
+                    }
+                }
+            },
+        };
+
+        debug!(?kind, node_id = target.node_id, "Routed query");
+        Ok(target)
+    }
+
+    async fn route_to_leader(&self) -> ClusterResult<RouteTarget> {
+        let leader = self.leader().await.ok_or(ClusterError::NoLeader)?;
+        if !leader.healthy {
+            return Err(ClusterError::NoLeader);
+        }
+        Ok(RouteTarget {
+            node_id: leader.node_id,
+            addr: leader.addr,
+            role: leader.role,
+        })
+    }
+}
@@ -1,11 +1,13 @@
 //! Shard management and consistent hashing for data distribution.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
@@ -20,6 +22,9 @@ pub type ShardId = u64;
 /// Unique identifier for a transfer operation.
 pub type TransferId = u64;
 
+/// File name of the rebalance journal inside the cluster data directory.
+const REBALANCE_JOURNAL_FILE: &str = "rebalance_journal.json";
+
 /// State of a shard.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ShardState {
@@ -134,6 +139,45 @@ pub struct RebalanceProgress {
     pub throughput_bytes_per_sec: u64,
 }
 
+/// Cost of rebalancing onto a target topology, computed without moving data.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct RebalanceEstimate {
+    /// Shards whose owner would change
+    pub shards_moved: usize,
+    /// Keys held by the shards that would move
+    pub keys_moved: u64,
+    /// Bytes held by the shards that would move
+    pub bytes_moved: u64,
+    /// Total number of registered shards
+    pub total_shards: usize,
+    /// Total number of keys across all shards
+    pub total_keys: u64,
+    /// Keys each node would receive
+    pub keys_received: BTreeMap<NodeId, u64>,
+    /// Keys each node would hand off
+    pub keys_sent: BTreeMap<NodeId, u64>,
+}
+
+impl RebalanceEstimate {
+    /// Fraction of the shards that would move (0.0 - 1.0).
+    #[must_use]
+    pub fn moved_shard_fraction(&self) -> f64 {
+        if self.total_shards == 0 {
+            return 0.0;
+        }
+        self.shards_moved as f64 / self.total_shards as f64
+    }
+
+    /// Fraction of the keys that would move (0.0 - 1.0).
+    #[must_use]
+    pub fn moved_key_fraction(&self) -> f64 {
+        if self.total_keys == 0 {
+            return 0.0;
+        }
+        self.keys_moved as f64 / self.total_keys as f64
+    }
+}
+
 /// Configuration for bandwidth throttling during rebalancing.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RebalanceConfig {
@@ -158,6 +202,73 @@ impl Default for RebalanceConfig {
     }
 }
 
+/// Result of copying one batch of keys during a shard move.
+#[derive(Debug, Clone, Default)]
+pub struct CopyBatch {
+    /// Number of keys copied in this batch
+    pub keys: u64,
+    /// Number of bytes copied in this batch
+    pub bytes: u64,
+    /// Cursor to resume from, `None` once the whole shard has been copied
+    pub next_cursor: Option<Vec<u8>>,
+}
+
+/// Streams a shard's key range from its current owner to its new owner.
+///
+/// Implementations copy keys in cursor order so that an interrupted move can
+/// continue from the last checkpointed cursor instead of starting over.
+#[async_trait]
+pub trait ShardMover: Send + Sync {
+    /// Copy the next batch of keys for `transfer`, starting after `cursor`.
+    async fn copy_batch(
+        &self,
+        transfer: &ShardTransfer,
+        cursor: Option<&[u8]>,
+    ) -> ClusterResult<CopyBatch>;
+}
+
+/// A planned shard move together with its copy checkpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShardMove {
+    /// Transfer being executed
+    pub transfer: ShardTransfer,
+    /// Last key copied to the target (`None` if copying has not started)
+    pub cursor: Option<Vec<u8>>,
+}
+
+/// Durable record of an in-flight rebalance, used to resume after a crash.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RebalanceJournal {
+    /// Nodes of the topology being rebalanced towards
+    pub target_nodes: Vec<NodeId>,
+    /// Planned moves and their progress
+    pub moves: Vec<ShardMove>,
+}
+
+impl RebalanceJournal {
+    /// Load a journal from disk, returning `None` if no rebalance is pending.
+    pub async fn load(path: &Path) -> ClusterResult<Option<Self>> {
+        match tokio::fs::read(path).await {
+            | Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            | Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            | Err(e) => Err(ClusterError::Internal(format!(
+                "Failed to read rebalance journal: {e}"
+            ))),
+        }
+    }
+
+    /// Persist the journal atomically (write to a temp file, then rename).
+    pub async fn save(&self, path: &Path) -> ClusterResult<()> {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let tmp = path.with_extension("json.tmp");
+        tokio::fs::write(&tmp, serde_json::to_vec(self)?).await?;
+        tokio::fs::rename(&tmp, path).await?;
+        Ok(())
+    }
+}
+
 /// A point on the hash ring.
 #[derive(Debug, Clone)]
 struct RingPoint {
@@ -185,6 +296,9 @@ struct ShardManagerState {
     rebalance_started_at: Option<Instant>,
     /// Total bytes planned for current rebalance
     rebalance_total_bytes: u64,
+    /// Shard positions on the ring (sorted by hash); a shard owns the key
+    /// hashes between the previous shard's position and its own
+    shard_ring: Vec<(u64, ShardId)>,
 }
 
 /// Manages sharding and data distribution across the cluster.
@@ -199,11 +313,19 @@ pub struct ShardManager {
     rebalance_config: RebalanceConfig,
     /// Next transfer ID counter
     next_transfer_id: AtomicU64,
+    /// Location of the rebalance journal
+    journal_path: PathBuf,
 }
 
 impl ShardManager {
     /// Create a new shard manager.
     pub fn new(config: &ClusterConfig) -> ClusterResult<Self> {
+        if config.sharding.virtual_nodes == 0 {
+            return Err(ClusterError::ConfigError(
+                "Virtual nodes per node must be greater than 0".into(),
+            ));
+        }
+
         info!(
             virtual_nodes = config.sharding.virtual_nodes,
             replication_factor = config.sharding.replication_factor,
@@ -228,12 +350,23 @@ impl ShardManager {
                 transfers: HashMap::new(),
                 rebalance_started_at: None,
                 rebalance_total_bytes: 0,
+                shard_ring: Vec::new(),
             })),
             rebalance_config,
             next_transfer_id: AtomicU64::new(1),
+            journal_path: config.data_dir.join(REBALANCE_JOURNAL_FILE),
         })
     }
 
+    /// Number of virtual nodes each physical node places on the hash ring.
+    ///
+    /// More virtual nodes spread the key space more evenly across nodes, at
+    /// the cost of a larger ring to search and store.
+    #[must_use]
+    pub const fn virtual_nodes_per_node(&self) -> u32 {
+        self.virtual_nodes
+    }
+
     /// Add a node to the hash ring.
     pub async fn add_node(&self, node_id: NodeId) -> ClusterResult<()> {
         let mut state = self.state.write().await;
@@ -411,11 +544,7 @@ impl ShardManager {
         // For each shard, determine if it needs to move to a different primary node
         for (shard_id, shard_info) in &state.shards {
             // Hash the shard ID to find its correct primary node
-            let shard_hash = {
-                let mut hasher = std::collections::hash_map::DefaultHasher::new();
-                shard_id.hash(&mut hasher);
-                hasher.finish()
-            };
+            let shard_hash = Self::shard_hash(*shard_id);
 
             if state.ring.is_empty() {
                 continue;
@@ -529,11 +658,7 @@ impl ShardManager {
         }
 
         for shard_info in orphaned_shards {
-            let shard_hash = {
-                let mut hasher = std::collections::hash_map::DefaultHasher::new();
-                shard_info.shard_id.hash(&mut hasher);
-                hasher.finish()
-            };
+            let shard_hash = Self::shard_hash(shard_info.shard_id);
 
             let target_node = self.find_node_for_hash(&remaining_ring, shard_hash);
             let transfer_id = self.next_transfer_id.fetch_add(1, Ordering::SeqCst);
@@ -665,28 +790,8 @@ impl ShardManager {
             (transfer.shard_id, transfer.target_node)
         };
 
-        // Get the old primary node
-        let old_primary = state.shards.get(&shard_id).map(|s| s.primary_node);
-
-        // Update shard primary node
-        if let Some(shard) = state.shards.get_mut(&shard_id) {
-            shard.primary_node = target_node;
-            shard.state = ShardState::Active;
-        }
-
-        // Remove shard from old node's list
-        if let Some(old_node) = old_primary {
-            if let Some(old_node_shards) = state.node_shards.get_mut(&old_node) {
-                old_node_shards.retain(|&id| id != shard_id);
-            }
-        }
-
-        // Add shard to new node's list
-        state
-            .node_shards
-            .entry(target_node)
-            .or_default()
-            .push(shard_id);
+        // Update shard primary node and the per-node shard lists
+        Self::flip_owner(&mut state, shard_id, target_node);
 
         info!(transfer_id, shard_id, target_node, "Transfer completed");
 
@@ -864,6 +969,335 @@ impl ShardManager {
         Ok(())
     }
 
+    /// Get the shard whose key range contains `key`.
+    ///
+    /// Shards are placed on the ring at the hash of their ID and own every key
+    /// hash up to and including that position.
+    pub async fn get_shard_for_key(&self, key: &[u8]) -> ClusterResult<ShardInfo> {
+        let state = self.state.read().await;
+        let shard_id = Self::find_shard_for_hash(&state.shard_ring, self.hash_key(key))
+            .ok_or_else(|| ClusterError::Internal("No shards registered".into()))?;
+
+        state
+            .shards
+            .get(&shard_id)
+            .cloned()
+            .ok_or(ClusterError::ShardNotFound(shard_id))
+    }
+
+    /// Get the node currently serving `key`.
+    ///
+    /// Keys are served by the primary of their shard, which only changes when
+    /// a shard move commits, so reads keep going to the old owner while the
+    /// data is being copied. Falls back to the hash ring when no shards are
+    /// registered.
+    pub async fn get_owner_for_key(&self, key: &[u8]) -> ClusterResult<NodeId> {
+        match self.get_shard_for_key(key).await {
+            | Ok(shard) => Ok(shard.primary_node),
+            | Err(_) => self.get_primary_node(key).await,
+        }
+    }
+
+    /// Location of the rebalance journal.
+    #[must_use]
+    pub fn journal_path(&self) -> &Path {
+        &self.journal_path
+    }
+
+    /// Rebalance shards onto `target_nodes`.
+    ///
+    /// Computes the minimal set of moves for the target topology (only shards
+    /// whose consistent-hash owner changes are moved), then executes them one
+    /// at a time: keys are streamed to the new owner through `mover` while the
+    /// old owner keeps serving reads, and ownership flips atomically once the
+    /// copy finishes. The plan and copy cursors are journaled after every
+    /// batch, so calling `rebalance` again after a crash resumes where it
+    /// stopped. When all moves have committed the hash ring is switched to
+    /// the target topology.
+    pub async fn rebalance(
+        &self,
+        target_nodes: &[NodeId],
+        mover: &dyn ShardMover,
+    ) -> ClusterResult<Vec<ShardTransfer>> {
+        if target_nodes.is_empty() {
+            return Err(ClusterError::ConfigError(
+                "target topology must contain at least one node".into(),
+            ));
+        }
+        let target: BTreeSet<NodeId> = target_nodes.iter().copied().collect();
+
+        let mut journal = match RebalanceJournal::load(&self.journal_path).await? {
+            | Some(journal) => {
+                if journal
+                    .target_nodes
+                    .iter()
+                    .copied()
+                    .collect::<BTreeSet<_>>()
+                    != target
+                {
+                    return Err(ClusterError::RebalancingInProgress);
+                }
+                info!(
+                    moves = journal.moves.len(),
+                    "Resuming shard rebalancing from journal"
+                );
+                journal
+            },
+            | None => {
+                let journal = self.plan_rebalance(&target).await?;
+                journal.save(&self.journal_path).await?;
+                journal
+            },
+        };
+
+        self.load_journal(&journal).await?;
+
+        for idx in 0..journal.moves.len() {
+            if journal.moves[idx].transfer.status == TransferStatus::Completed {
+                continue;
+            }
+            if let Err(e) = self.execute_move(&mut journal, idx, mover).await {
+                let transfer_id = journal.moves[idx].transfer.transfer_id;
+                self.fail_transfer(transfer_id, e.to_string()).await?;
+                // Keep the journal so the move resumes from its last cursor
+                journal.moves[idx].transfer.status = TransferStatus::Pending;
+                journal.save(&self.journal_path).await?;
+                return Err(e);
+            }
+        }
+
+        {
+            let mut state = self.state.write().await;
+            state.ring = self.build_ring(&target);
+            state
+                .node_shards
+                .retain(|node_id, _| target.contains(node_id));
+            for &node_id in &target {
+                state.node_shards.entry(node_id).or_default();
+            }
+            state.rebalancing = false;
+            state.rebalance_started_at = None;
+        }
+
+        tokio::fs::remove_file(&self.journal_path).await?;
+
+        info!(
+            moves = journal.moves.len(),
+            nodes = target.len(),
+            "Shard rebalancing committed"
+        );
+
+        Ok(journal.moves.into_iter().map(|m| m.transfer).collect())
+    }
+
+    /// Estimate the cost of rebalancing onto `target_nodes`.
+    ///
+    /// Plans the same moves [`rebalance`](Self::rebalance) would make and
+    /// totals the keys and bytes of the shards involved, using the counts
+    /// recorded in their [`ShardInfo`]. Nothing is moved or journaled.
+    pub async fn estimate_rebalance_cost(
+        &self,
+        target_nodes: &[NodeId],
+    ) -> ClusterResult<RebalanceEstimate> {
+        if target_nodes.is_empty() {
+            return Err(ClusterError::ConfigError(
+                "target topology must contain at least one node".into(),
+            ));
+        }
+        let target: BTreeSet<NodeId> = target_nodes.iter().copied().collect();
+
+        let state = self.state.read().await;
+        let mut estimate = RebalanceEstimate {
+            total_shards: state.shards.len(),
+            total_keys: state.shards.values().map(|s| s.key_count).sum(),
+            ..Default::default()
+        };
+        for (shard, target_node) in self.planned_moves(&state, &target) {
+            estimate.shards_moved += 1;
+            estimate.keys_moved += shard.key_count;
+            estimate.bytes_moved += shard.size_bytes;
+            *estimate.keys_received.entry(target_node).or_default() += shard.key_count;
+            *estimate.keys_sent.entry(shard.primary_node).or_default() += shard.key_count;
+        }
+
+        Ok(estimate)
+    }
+
+    /// Shards whose consistent-hash owner differs on the target ring, with
+    /// their new owner.
+    fn planned_moves<'a>(
+        &self,
+        state: &'a ShardManagerState,
+        target: &BTreeSet<NodeId>,
+    ) -> Vec<(&'a ShardInfo, NodeId)> {
+        let ring = self.build_ring(target);
+        state
+            .shard_ring
+            .iter()
+            .filter_map(|&(position, shard_id)| {
+                let shard = state.shards.get(&shard_id)?;
+                let target_node = self.find_node_for_hash(&ring, position);
+                (target_node != shard.primary_node).then_some((shard, target_node))
+            })
+            .collect()
+    }
+
+    /// Compute the moves needed to reach the target topology.
+    async fn plan_rebalance(&self, target: &BTreeSet<NodeId>) -> ClusterResult<RebalanceJournal> {
+        let state = self.state.read().await;
+        if state.rebalancing {
+            return Err(ClusterError::RebalancingInProgress);
+        }
+
+        let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+
+        let mut moves = Vec::new();
+        for (shard, target_node) in self.planned_moves(&state, target) {
+            moves.push(ShardMove {
+                transfer: ShardTransfer {
+                    transfer_id: self.next_transfer_id.fetch_add(1, Ordering::SeqCst),
+                    shard_id: shard.shard_id,
+                    source_node: shard.primary_node,
+                    target_node,
+                    status: TransferStatus::Pending,
+                    bytes_transferred: 0,
+                    total_bytes: shard.size_bytes,
+                    keys_transferred: 0,
+                    total_keys: shard.key_count,
+                    started_at_ms: now_ms,
+                    completed_at_ms: 0,
+                    error: None,
+                },
+                cursor: None,
+            });
+        }
+
+        info!(
+            shards = state.shards.len(),
+            moves = moves.len(),
+            "Planned shard rebalancing"
+        );
+
+        Ok(RebalanceJournal {
+            target_nodes: target.iter().copied().collect(),
+            moves,
+        })
+    }
+
+    /// Install the journal's transfers and re-apply moves that already committed.
+    async fn load_journal(&self, journal: &RebalanceJournal) -> ClusterResult<()> {
+        let mut state = self.state.write().await;
+
+        state.rebalancing = true;
+        state.rebalance_started_at = Some(Instant::now());
+        state.transfers.clear();
+        state.rebalance_total_bytes = 0;
+
+        for shard_move in &journal.moves {
+            let transfer = &shard_move.transfer;
+            state.rebalance_total_bytes = state
+                .rebalance_total_bytes
+                .saturating_add(transfer.total_bytes);
+            state
+                .transfers
+                .insert(transfer.transfer_id, transfer.clone());
+
+            if transfer.status == TransferStatus::Completed {
+                Self::flip_owner(&mut state, transfer.shard_id, transfer.target_node);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Copy one shard to its new owner and commit the ownership change.
+    async fn execute_move(
+        &self,
+        journal: &mut RebalanceJournal,
+        idx: usize,
+        mover: &dyn ShardMover,
+    ) -> ClusterResult<()> {
+        let transfer_id = journal.moves[idx].transfer.transfer_id;
+        self.start_transfer(transfer_id).await?;
+
+        loop {
+            let transfer = self.get_transfer(transfer_id).await?;
+            let batch = mover
+                .copy_batch(&transfer, journal.moves[idx].cursor.as_deref())
+                .await?;
+
+            let keys = transfer.keys_transferred.saturating_add(batch.keys);
+            let bytes = transfer.bytes_transferred.saturating_add(batch.bytes);
+            self.update_transfer_progress(transfer_id, bytes, keys)
+                .await?;
+
+            let shard_move = &mut journal.moves[idx];
+            shard_move.transfer.keys_transferred = keys;
+            shard_move.transfer.bytes_transferred = bytes;
+
+            match batch.next_cursor {
+                | Some(cursor) => {
+                    shard_move.cursor = Some(cursor);
+                    journal.save(&self.journal_path).await?;
+                },
+                | None => break,
+            }
+        }
+
+        // Flip ownership under the write lock, then checkpoint the commit
+        self.complete_transfer(transfer_id).await?;
+        journal.moves[idx].transfer = self.get_transfer(transfer_id).await?;
+        journal.save(&self.journal_path).await?;
+
+        Ok(())
+    }
+
+    /// Point a shard at its new primary node.
+    fn flip_owner(state: &mut ShardManagerState, shard_id: ShardId, target_node: NodeId) {
+        let old_primary = state.shards.get_mut(&shard_id).map(|shard| {
+            let old_primary = shard.primary_node;
+            shard.primary_node = target_node;
+            shard.state = ShardState::Active;
+            old_primary
+        });
+
+        if let Some(shards) = old_primary.and_then(|node| state.node_shards.get_mut(&node)) {
+            shards.retain(|&id| id != shard_id);
+        }
+        let shards = state.node_shards.entry(target_node).or_default();
+        if !shards.contains(&shard_id) {
+            shards.push(shard_id);
+        }
+    }
+
+    /// Build a sorted hash ring for a set of nodes.
+    fn build_ring(&self, nodes: &BTreeSet<NodeId>) -> Vec<RingPoint> {
+        let mut ring: Vec<RingPoint> = nodes
+            .iter()
+            .flat_map(|&node_id| {
+                (0..self.virtual_nodes).map(move |i| RingPoint {
+                    hash: self.hash_node(node_id, i),
+                    node_id,
+                    virtual_index: i,
+                })
+            })
+            .collect();
+        ring.sort_by_key(|p| p.hash);
+        ring
+    }
+
+    /// Find the shard owning a key hash (first shard at or after it, wrapping).
+    fn find_shard_for_hash(shard_ring: &[(u64, ShardId)], hash: u64) -> Option<ShardId> {
+        let idx = shard_ring.partition_point(|&(position, _)| position < hash);
+        shard_ring
+            .get(idx)
+            .or_else(|| shard_ring.first())
+            .map(|&(_, shard_id)| shard_id)
+    }
+
     /// Register a shard with the manager.
     pub async fn register_shard(&self, shard_info: ShardInfo) -> ClusterResult<()> {
         let mut state = self.state.write().await;
@@ -871,7 +1305,17 @@ impl ShardManager {
         let shard_id = shard_info.shard_id;
         let primary_node = shard_info.primary_node;
 
-        state.shards.insert(shard_id, shard_info);
+        if let Some(previous) = state.shards.insert(shard_id, shard_info) {
+            if let Some(shards) = state.node_shards.get_mut(&previous.primary_node) {
+                shards.retain(|&id| id != shard_id);
+            }
+        } else {
+            let position = Self::shard_hash(shard_id);
+            let idx = state
+                .shard_ring
+                .partition_point(|&(hash, _)| hash < position);
+            state.shard_ring.insert(idx, (position, shard_id));
+        }
         state
             .node_shards
             .entry(primary_node)
@@ -930,6 +1374,13 @@ impl ShardManager {
         hasher.finish()
     }
 
+    /// Hash a shard ID to its position on the ring.
+    fn shard_hash(shard_id: ShardId) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        shard_id.hash(&mut hasher);
+        hasher.finish()
+    }
+
     /// Hash a node + virtual index to a position on the ring.
     fn hash_node(&self, node_id: NodeId, virtual_index: u32) -> u64 {
         let mut hasher = std::collections::hash_map::DefaultHasher::new();
@@ -1,8 +1,19 @@
 //! Unit tests for service discovery.
 
-use neuroquantum_cluster::config::{ClusterConfig, DiscoveryConfig, DiscoveryMethod};
-use neuroquantum_cluster::discovery::{DiscoveryService, NodeMetadata, NodeRegistration};
-use neuroquantum_cluster::error::ClusterError;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use neuroquantum_cluster::config::{ClusterConfig, DiscoveryConfig, DiscoveryMethod, GossipConfig};
+use neuroquantum_cluster::discovery::{
+    DiscoveryService, GossipDiscovery, GossipMessage, GossipTransport, MemberState,
+    MembershipEvent, NodeMetadata, NodeRegistration,
+};
+use neuroquantum_cluster::error::{ClusterError, ClusterResult};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 #[tokio::test]
 async fn test_static_discovery() {
@@ -104,3 +115,267 @@ async fn test_etcd_discovery_missing_config() {
     assert!(result.is_err());
     assert!(matches!(result.unwrap_err(), ClusterError::ConfigError(_)));
 }
+
+#[tokio::test]
+async fn test_gossip_discovery_returns_seeds_before_start() {
+    let config = ClusterConfig {
+        node_id: 1,
+        discovery: DiscoveryConfig {
+            method: DiscoveryMethod::Gossip,
+            gossip: Some(GossipConfig {
+                seeds: vec!["127.0.0.1:7946".into(), "127.0.0.1:7947".into()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let service = DiscoveryService::new(&config).unwrap();
+    let peers = service.discover().await.unwrap();
+    assert_eq!(peers.len(), 2);
+}
+
+#[tokio::test]
+async fn test_gossip_discovery_missing_config() {
+    let config = ClusterConfig {
+        node_id: 1,
+        discovery: DiscoveryConfig {
+            method: DiscoveryMethod::Gossip,
+            gossip: None,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let service = DiscoveryService::new(&config).unwrap();
+    let result = service.discover().await;
+    assert!(matches!(result, Err(ClusterError::ConfigError(_))));
+}
+
+/// In-memory network that drops a fraction of all messages and every message
+/// addressed to a killed node.
+struct SimNetwork {
+    queue: Mutex<VecDeque<(SocketAddr, GossipMessage)>>,
+    killed: Mutex<HashSet<SocketAddr>>,
+    rng: Mutex<StdRng>,
+    loss: f64,
+}
+
+impl SimNetwork {
+    fn new(loss: f64) -> Arc<Self> {
+        Arc::new(Self {
+            queue: Mutex::new(VecDeque::new()),
+            killed: Mutex::new(HashSet::new()),
+            rng: Mutex::new(StdRng::seed_from_u64(7)),
+            loss,
+        })
+    }
+
+    fn kill(&self, addr: SocketAddr) {
+        self.killed.lock().unwrap().insert(addr);
+    }
+
+    fn is_killed(&self, addr: SocketAddr) -> bool {
+        self.killed.lock().unwrap().contains(&addr)
+    }
+}
+
+#[async_trait]
+impl GossipTransport for SimNetwork {
+    async fn send(&self, to: SocketAddr, message: GossipMessage) -> ClusterResult<()> {
+        if self.is_killed(message.from_addr) || self.is_killed(to) {
+            return Ok(());
+        }
+        if self.rng.lock().unwrap().gen_bool(self.loss) {
+            return Ok(());
+        }
+        self.queue.lock().unwrap().push_back((to, message));
+        Ok(())
+    }
+}
+
+struct SimCluster {
+    network: Arc<SimNetwork>,
+    nodes: HashMap<SocketAddr, Arc<GossipDiscovery>>,
+}
+
+impl SimCluster {
+    fn new(loss: f64) -> Self {
+        Self {
+            network: SimNetwork::new(loss),
+            nodes: HashMap::new(),
+        }
+    }
+
+    fn add_node(&mut self, node_id: u64) -> Arc<GossipDiscovery> {
+        let addr: SocketAddr = format!("127.0.0.1:{}", 7000 + node_id).parse().unwrap();
+        let config = GossipConfig {
+            protocol_period: Duration::from_millis(100),
+            suspicion_periods: 3,
+            ..Default::default()
+        };
+        let transport: Arc<dyn GossipTransport> = self.network.clone();
+        let node = Arc::new(GossipDiscovery::new(node_id, addr, config, transport));
+        self.nodes.insert(addr, Arc::clone(&node));
+        node
+    }
+
+    /// Tick every live node once, then deliver messages until the network is idle.
+    async fn round(&self) {
+        for (addr, node) in &self.nodes {
+            if !self.network.is_killed(*addr) {
+                node.tick().await.unwrap();
+            }
+        }
+        loop {
+            let next = self.network.queue.lock().unwrap().pop_front();
+            let Some((to, message)) = next else {
+                break;
+            };
+            if let Some(node) = self.nodes.get(&to) {
+                node.handle_message(message).await.unwrap();
+            }
+        }
+    }
+
+    /// Live nodes other than `node_id`.
+    fn live_peers_of(&self, node_id: u64) -> Vec<Arc<GossipDiscovery>> {
+        self.nodes
+            .iter()
+            .filter(|(addr, _)| !self.network.is_killed(**addr))
+            .filter(|(addr, _)| addr.port() != (7000 + node_id) as u16)
+            .map(|(_, node)| Arc::clone(node))
+            .collect()
+    }
+
+    async fn all_see(&self, node_id: u64, accept: impl Fn(Option<MemberState>) -> bool) -> bool {
+        for peer in self.live_peers_of(node_id) {
+            if !accept(peer.member_state(node_id).await) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn seed_addr() -> SocketAddr {
+    "127.0.0.1:7001".parse().unwrap()
+}
+
+async fn bootstrap(cluster: &mut SimCluster, node_ids: &[u64]) {
+    let nodes: Vec<_> = node_ids.iter().map(|&id| cluster.add_node(id)).collect();
+    for node in &nodes {
+        node.join(&[seed_addr()]).await.unwrap();
+    }
+
+    for _ in 0..50 {
+        cluster.round().await;
+        let mut converged = true;
+        for node in &nodes {
+            // Retry lost joins, as a real node would on startup
+            if node.members().await.len() < nodes.len() - 1 {
+                node.join(&[seed_addr()]).await.unwrap();
+                converged = false;
+            }
+        }
+        if converged {
+            return;
+        }
+    }
+    panic!("gossip group did not converge");
+}
+
+#[tokio::test]
+async fn test_gossip_marks_killed_node_dead_on_lossy_network() {
+    let mut cluster = SimCluster::new(0.1);
+    bootstrap(&mut cluster, &[1, 2, 3, 4, 5]).await;
+
+    let observer = cluster.nodes[&seed_addr()].clone();
+    let mut events = observer.subscribe();
+
+    let victim: SocketAddr = "127.0.0.1:7004".parse().unwrap();
+    cluster.network.kill(victim);
+
+    let mut rounds = 0;
+    while !cluster
+        .all_see(4, |state| state == Some(MemberState::Dead))
+        .await
+    {
+        rounds += 1;
+        assert!(rounds < 60, "killed node was never declared dead");
+        cluster.round().await;
+    }
+
+    // The failure detector suspects before it condemns
+    let mut seen = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        seen.push(event);
+    }
+    let suspected = seen
+        .iter()
+        .position(|e| *e == MembershipEvent::Suspected(4));
+    let failed = seen.iter().position(|e| *e == MembershipEvent::Failed(4));
+    assert!(failed.is_some());
+    if let Some(suspected) = suspected {
+        assert!(suspected < failed.unwrap());
+    }
+    assert!(observer.peers().await.iter().all(|p| p.node_id != 4));
+
+    // Survivors keep each other alive
+    for id in [1, 2, 3, 5] {
+        assert!(
+            cluster
+                .all_see(id, |state| state != Some(MemberState::Dead))
+                .await
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_gossip_new_node_discovered_by_all_peers() {
+    let mut cluster = SimCluster::new(0.1);
+    bootstrap(&mut cluster, &[1, 2, 3, 4]).await;
+
+    let newcomer = cluster.add_node(6);
+    newcomer.join(&[seed_addr()]).await.unwrap();
+
+    let mut rounds = 0;
+    loop {
+        cluster.round().await;
+        let everyone_knows_newcomer = cluster
+            .all_see(6, |state| state == Some(MemberState::Alive))
+            .await;
+        let newcomer_knows_everyone = newcomer.members().await.len() == 4;
+        if everyone_knows_newcomer && newcomer_knows_everyone {
+            break;
+        }
+        if newcomer.members().await.is_empty() {
+            // Join (or its reply) was lost
+            newcomer.join(&[seed_addr()]).await.unwrap();
+        }
+        rounds += 1;
+        assert!(rounds < 60, "new node was not discovered by all peers");
+    }
+
+    let peers = newcomer.peers().await;
+    let mut ids: Vec<u64> = peers.iter().map(|p| p.node_id).collect();
+    ids.sort_unstable();
+    assert_eq!(ids, vec![1, 2, 3, 4]);
+}
+
+#[tokio::test]
+async fn test_gossip_leave_is_not_reported_as_failure() {
+    let mut cluster = SimCluster::new(0.0);
+    bootstrap(&mut cluster, &[1, 2, 3]).await;
+
+    let observer = cluster.nodes[&seed_addr()].clone();
+    let mut events = observer.subscribe();
+
+    let leaving = cluster.nodes[&"127.0.0.1:7003".parse().unwrap()].clone();
+    leaving.leave().await.unwrap();
+    cluster.round().await;
+
+    assert_eq!(observer.member_state(3).await, Some(MemberState::Left));
+    assert_eq!(events.try_recv().unwrap(), MembershipEvent::Left(3));
+}
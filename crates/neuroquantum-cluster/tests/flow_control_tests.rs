@@ -0,0 +1,194 @@
+//! Replication flow control tests - bounded in-flight window and snapshot catch-up
+//!
+//! As in the membership tests, replication is driven by hand so that a
+//! follower can be stalled simply by not delivering its requests.
+
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use neuroquantum_cluster::consensus::RaftConsensus;
+use neuroquantum_cluster::network::{AppendEntriesRequest, ClusterMessage, NetworkTransport};
+use neuroquantum_cluster::node::NodeId;
+use neuroquantum_cluster::ClusterConfig;
+
+// Port counter for tests - separate range from the other test files
+static PORT_COUNTER: AtomicU16 = AtomicU16::new(42000);
+
+const MAX_INFLIGHT: u64 = 50;
+const MAX_PER_RPC: u64 = 20;
+
+fn get_test_config_with_node(node_id: NodeId, snapshot_threshold: u64) -> ClusterConfig {
+    let port = PORT_COUNTER.fetch_add(1, Ordering::SeqCst);
+    let mut config = ClusterConfig {
+        node_id,
+        bind_addr: format!("127.0.0.1:{port}").parse().unwrap(),
+        ..Default::default()
+    };
+    // Long heartbeat interval so the leader lease does not expire mid-test
+    config.raft.heartbeat_interval = Duration::from_secs(60);
+    config.raft.max_inflight_entries = MAX_INFLIGHT;
+    config.raft.max_entries_per_rpc = MAX_PER_RPC;
+    config.raft.snapshot_threshold = snapshot_threshold;
+    config.raft.snapshot_chunk_size = 512;
+    config
+}
+
+/// Create a three node group {1, 2, 3} with node 1 as leader.
+async fn create_group(snapshot_threshold: u64) -> Vec<RaftConsensus> {
+    let mut nodes = Vec::new();
+    for node_id in 1..=3 {
+        let config = get_test_config_with_node(node_id, snapshot_threshold);
+        let transport = Arc::new(NetworkTransport::new(&config).await.unwrap());
+        nodes.push(
+            RaftConsensus::new(node_id, transport, config)
+                .await
+                .unwrap(),
+        );
+    }
+
+    let leader = &nodes[0];
+    leader.promote_to_leader().await.unwrap();
+    for node in &nodes {
+        node.initialize_membership([1, 2, 3]).await;
+    }
+    leader.update_quorum_status(2, 3).await;
+    leader.initialize_replication_state(vec![2, 3]).await;
+    nodes
+}
+
+async fn propose_many(leader: &RaftConsensus, count: usize) {
+    for i in 0..count {
+        leader
+            .propose(format!("entry{i}").into_bytes())
+            .await
+            .unwrap();
+    }
+}
+
+/// Replicate to a follower until it has acknowledged the whole log.
+async fn catch_up(leader: &RaftConsensus, follower: &RaftConsensus, follower_id: NodeId) {
+    let last_index = leader.last_log_index().await;
+    for _ in 0..1000 {
+        if leader.state.read().await.match_index[&follower_id] >= last_index {
+            return;
+        }
+        match leader.next_replication_message(follower_id).await.unwrap() {
+            | Some(ClusterMessage::AppendEntries(request)) => {
+                let response = follower.handle_append_entries(request).await.unwrap();
+                leader
+                    .handle_append_entries_response(follower_id, response)
+                    .await
+                    .unwrap();
+            },
+            | Some(ClusterMessage::InstallSnapshot(request)) => {
+                let response = follower.handle_install_snapshot(request).await.unwrap();
+                leader
+                    .handle_install_snapshot_response(follower_id, response)
+                    .await
+                    .unwrap();
+            },
+            | other => panic!("unexpected replication message: {other:?}"),
+        }
+    }
+    panic!("follower {follower_id} did not catch up");
+}
+
+fn append_entries(message: Option<ClusterMessage>) -> AppendEntriesRequest {
+    match message {
+        | Some(ClusterMessage::AppendEntries(request)) => request,
+        | other => panic!("expected AppendEntries, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_stalled_follower_bounds_inflight_entries() {
+    let nodes = create_group(100_000).await;
+    let leader = &nodes[0];
+    propose_many(leader, 500).await;
+
+    // Follower 2 is stalled: requests are produced but never acknowledged
+    let mut stalled = Vec::new();
+    for _ in 0..100 {
+        let request = append_entries(leader.next_replication_message(2).await.unwrap());
+        assert!(request.entries.len() as u64 <= MAX_PER_RPC);
+        stalled.push(request);
+    }
+
+    let unacked: u64 = stalled.iter().map(|r| r.entries.len() as u64).sum();
+    assert_eq!(unacked, MAX_INFLIGHT);
+    {
+        let state = leader.state.read().await;
+        let progress = &state.progress[&2];
+        assert!(progress.paused);
+        assert_eq!(progress.sent_index - state.match_index[&2], MAX_INFLIGHT);
+    }
+
+    // While paused, the follower still gets heartbeats
+    let heartbeat = stalled.last().unwrap();
+    assert!(heartbeat.entries.is_empty());
+    assert_eq!(heartbeat.term, leader.current_term().await);
+
+    // The follower wakes up and processes the backlog; the window reopens
+    let follower = &nodes[1];
+    for request in stalled {
+        let response = follower.handle_append_entries(request).await.unwrap();
+        leader
+            .handle_append_entries_response(2, response)
+            .await
+            .unwrap();
+    }
+    assert_eq!(leader.state.read().await.match_index[&2], MAX_INFLIGHT);
+    assert!(!leader.state.read().await.progress[&2].paused);
+
+    let resumed = append_entries(leader.next_replication_message(2).await.unwrap());
+    assert_eq!(resumed.prev_log_index, MAX_INFLIGHT);
+    assert_eq!(resumed.entries.len() as u64, MAX_PER_RPC);
+
+    // Healthy replication is unaffected: follower 3 catches up in bounded batches
+    catch_up(leader, &nodes[2], 3).await;
+    assert_eq!(leader.commit_index().await, 500);
+    assert_eq!(nodes[2].last_log_index().await, 500);
+}
+
+#[tokio::test]
+async fn test_far_behind_follower_receives_snapshot() {
+    let nodes = create_group(100).await;
+    let leader = &nodes[0];
+    let lagging = &nodes[1];
+
+    // Follower 3 keeps up, follower 2 is offline while 300 entries commit
+    propose_many(leader, 300).await;
+    catch_up(leader, &nodes[2], 3).await;
+    assert_eq!(leader.commit_index().await, 300);
+
+    // Follower 2 comes back: instead of replaying 300 entries it gets a snapshot
+    let first = leader.next_replication_message(2).await.unwrap();
+    let Some(ClusterMessage::InstallSnapshot(chunk)) = first else {
+        panic!("expected InstallSnapshot, got {first:?}");
+    };
+    assert_eq!(chunk.offset, 0);
+    assert_eq!(chunk.last_included_index, 300);
+    assert!(!chunk.done);
+
+    // Only one chunk is in flight at a time
+    assert!(leader.next_replication_message(2).await.unwrap().is_none());
+
+    let response = lagging.handle_install_snapshot(chunk).await.unwrap();
+    assert!(response.success);
+    leader
+        .handle_install_snapshot_response(2, response)
+        .await
+        .unwrap();
+
+    catch_up(leader, lagging, 2).await;
+    assert_eq!(lagging.last_log_index().await, 300);
+    assert_eq!(lagging.commit_index().await, 300);
+    assert!(leader.state.read().await.progress[&2].snapshot.is_none());
+
+    // New entries flow through normal log replication again
+    propose_many(leader, 5).await;
+    let request = append_entries(leader.next_replication_message(2).await.unwrap());
+    assert_eq!(request.prev_log_index, 300);
+    assert_eq!(request.entries.len(), 5);
+}
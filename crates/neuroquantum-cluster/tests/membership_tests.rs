@@ -0,0 +1,411 @@
+//! Membership change tests - joint consensus add/remove of voters
+//!
+//! Replication between nodes is driven by hand: the leader builds the
+//! `AppendEntries` request for a follower, the follower handles it and the
+//! response is fed back to the leader. This keeps the tests deterministic.
+
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use neuroquantum_cluster::consensus::{LogEntryData, RaftConsensus, RaftState};
+use neuroquantum_cluster::network::{NetworkTransport, RequestVoteRequest};
+use neuroquantum_cluster::node::{ClusterNode, NodeId, NodeRole};
+use neuroquantum_cluster::{ClusterConfig, ClusterError};
+
+// Port counter for tests - separate range from the other test files
+static PORT_COUNTER: AtomicU16 = AtomicU16::new(41000);
+
+fn get_test_config_with_node(node_id: NodeId) -> ClusterConfig {
+    let port = PORT_COUNTER.fetch_add(1, Ordering::SeqCst);
+    let mut config = ClusterConfig {
+        node_id,
+        bind_addr: format!("127.0.0.1:{port}").parse().unwrap(),
+        ..Default::default()
+    };
+    // Long heartbeat interval so the leader lease does not expire mid-test
+    config.raft.heartbeat_interval = Duration::from_secs(60);
+    config
+}
+
+async fn create_nodes(count: NodeId) -> Vec<RaftConsensus> {
+    let mut nodes = Vec::new();
+    for node_id in 1..=count {
+        let config = get_test_config_with_node(node_id);
+        let transport = Arc::new(NetworkTransport::new(&config).await.unwrap());
+        nodes.push(
+            RaftConsensus::new(node_id, transport, config)
+                .await
+                .unwrap(),
+        );
+    }
+    nodes
+}
+
+/// Bootstrap a three node group {1, 2, 3} with node 1 as leader.
+async fn bootstrap_three_node_group(nodes: &[RaftConsensus]) {
+    let leader = &nodes[0];
+    leader.promote_to_leader().await.unwrap();
+    for node in &nodes[..3] {
+        node.initialize_membership([1, 2, 3]).await;
+    }
+    leader.update_quorum_status(2, 3).await;
+    leader.initialize_replication_state(vec![2, 3]).await;
+}
+
+/// Send one round of `AppendEntries` from the leader to a follower.
+async fn replicate(leader: &RaftConsensus, follower: &RaftConsensus, follower_id: NodeId) {
+    let request = leader.append_entries_request(follower_id).await.unwrap();
+    let response = follower.handle_append_entries(request).await.unwrap();
+    leader
+        .handle_append_entries_response(follower_id, response)
+        .await
+        .unwrap();
+}
+
+/// Replicate twice to each follower so commit indexes propagate as well.
+async fn sync(nodes: &[RaftConsensus], followers: &[NodeId]) {
+    sync_from(&nodes[0], nodes, followers).await;
+}
+
+/// Like [`sync`], but replicating from a leader outside of `nodes`.
+async fn sync_from(leader: &RaftConsensus, nodes: &[RaftConsensus], followers: &[NodeId]) {
+    for _ in 0..2 {
+        for &id in followers {
+            replicate(leader, &nodes[id as usize - 1], id).await;
+        }
+    }
+}
+
+async fn commands(node: &RaftConsensus) -> Vec<Vec<u8>> {
+    node.state
+        .read()
+        .await
+        .log
+        .iter()
+        .filter_map(|entry| match &entry.data {
+            | LogEntryData::Command(data) => Some(data.clone()),
+            | _ => None,
+        })
+        .collect()
+}
+
+fn set(ids: &[NodeId]) -> BTreeSet<NodeId> {
+    ids.iter().copied().collect()
+}
+
+#[tokio::test]
+async fn test_grow_to_five_and_shrink_back_to_three() {
+    let nodes = create_nodes(5).await;
+    let leader = &nodes[0];
+    bootstrap_three_node_group(&nodes).await;
+
+    for i in 0..3 {
+        leader
+            .propose(format!("entry{i}").into_bytes())
+            .await
+            .unwrap();
+    }
+    sync(&nodes, &[2, 3]).await;
+    assert_eq!(leader.commit_index().await, 3);
+
+    // Grow: 4 and 5 join as learners and must catch up before becoming voters
+    leader.add_learner(4).await.unwrap();
+    leader.add_learner(5).await.unwrap();
+    assert!(matches!(
+        leader.change_membership(set(&[1, 2, 3, 4, 5])).await,
+        Err(ClusterError::LearnerLagging { node_id: 4, .. })
+    ));
+    sync(&nodes, &[2, 3, 4, 5]).await;
+    assert!(leader.is_caught_up(4).await);
+    assert!(leader.is_caught_up(5).await);
+
+    let joint_index = leader
+        .change_membership(set(&[1, 2, 3, 4, 5]))
+        .await
+        .unwrap();
+    assert!(leader.membership().await.is_joint());
+
+    // A majority of the old configuration alone must not commit the joint entry
+    replicate(leader, &nodes[1], 2).await;
+    assert!(leader.commit_index().await < joint_index);
+
+    // {1, 2, 4} is a majority of both {1, 2, 3} and {1, 2, 3, 4, 5}
+    replicate(leader, &nodes[3], 4).await;
+    assert!(leader.commit_index().await >= joint_index);
+    let membership = leader.membership().await;
+    assert!(!membership.is_joint());
+    assert_eq!(membership.voters, set(&[1, 2, 3, 4, 5]));
+
+    sync(&nodes, &[2, 3, 4, 5]).await;
+    for node in &nodes {
+        let membership = node.membership().await;
+        assert_eq!(membership.voters, set(&[1, 2, 3, 4, 5]));
+        assert!(membership.learners.is_empty());
+    }
+
+    // Shrink: drop 2 and 3, keeping the two nodes that joined
+    leader.change_membership(set(&[1, 4, 5])).await.unwrap();
+    sync(&nodes, &[2, 3, 4, 5]).await;
+    sync(&nodes, &[4, 5]).await;
+
+    let membership = leader.membership().await;
+    assert!(!membership.is_joint());
+    assert_eq!(membership.voters, set(&[1, 4, 5]));
+    {
+        let state = leader.state.read().await;
+        assert!(!state.next_index.contains_key(&2));
+        assert!(!state.next_index.contains_key(&3));
+        assert_eq!(state.commit_index, state.log.len() as u64);
+    }
+
+    // Entries committed before the change survive on the new members
+    let expected: Vec<Vec<u8>> = (0..3).map(|i| format!("entry{i}").into_bytes()).collect();
+    for node in &nodes[3..] {
+        assert_eq!(commands(node).await, expected);
+        assert_eq!(node.commit_index().await, leader.commit_index().await);
+        assert_eq!(node.membership().await.voters, set(&[1, 4, 5]));
+    }
+
+    // The old leader goes away; node 4 runs for election
+    let candidate = &nodes[3];
+    let request = {
+        let mut state = candidate.state.write().await;
+        state.state = RaftState::Candidate;
+        state.current_term += 1;
+        state.voted_for = Some(4);
+        state.votes_received.clear();
+        state.votes_received.insert(4);
+        RequestVoteRequest {
+            term: state.current_term,
+            candidate_id: 4,
+            last_log_index: state.log.len() as u64,
+            last_log_term: state.log.last().map_or(0, |e| e.term),
+            is_pre_vote: false,
+        }
+    };
+
+    // A vote from a removed node does not count towards the quorum
+    let response = nodes[1].handle_request_vote(request.clone()).await.unwrap();
+    assert!(response.vote_granted);
+    candidate
+        .handle_request_vote_response(2, response)
+        .await
+        .unwrap();
+    assert!(!candidate.is_leader().await);
+
+    let response = nodes[4].handle_request_vote(request).await.unwrap();
+    assert!(response.vote_granted);
+    candidate
+        .handle_request_vote_response(5, response)
+        .await
+        .unwrap();
+    assert!(candidate.is_leader().await);
+}
+
+/// Run `change` while replicating from `leader` (node 1) to every other member
+/// of its configuration, as the replication loop of a running node would.
+async fn with_replication<T>(
+    leader: &RaftConsensus,
+    nodes: &[RaftConsensus],
+    change: impl std::future::Future<Output = T>,
+) -> T {
+    let replication = async {
+        loop {
+            let membership = leader.membership().await;
+            let members: BTreeSet<NodeId> = membership
+                .voters
+                .iter()
+                .chain(membership.outgoing.iter().flatten())
+                .chain(&membership.learners)
+                .copied()
+                .filter(|&id| id != 1)
+                .collect();
+            for id in members {
+                // The member may have left the configuration meanwhile
+                let Ok(request) = leader.append_entries_request(id).await else {
+                    continue;
+                };
+                let response = nodes[id as usize - 1]
+                    .handle_append_entries(request)
+                    .await
+                    .unwrap();
+                let _ = leader.handle_append_entries_response(id, response).await;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    };
+
+    tokio::select! {
+        output = change => output,
+        _ = replication => unreachable!("replication never finishes"),
+    }
+}
+
+#[tokio::test]
+async fn test_cluster_node_grows_to_five_and_shrinks_back_to_three() {
+    // Node 1 is the cluster node under test; the others are driven by hand
+    let node = ClusterNode::new(get_test_config_with_node(1))
+        .await
+        .unwrap();
+    let leader = node.consensus();
+    let nodes = create_nodes(5).await;
+
+    leader.promote_to_leader().await.unwrap();
+    leader.initialize_membership([1, 2, 3]).await;
+    for follower in &nodes[1..3] {
+        follower.initialize_membership([1, 2, 3]).await;
+    }
+    leader.update_quorum_status(2, 3).await;
+    leader.initialize_replication_state(vec![2, 3]).await;
+
+    for i in 0..3 {
+        leader
+            .propose(format!("entry{i}").into_bytes())
+            .await
+            .unwrap();
+    }
+
+    // Grow: each new member catches up as a learner before it votes
+    for id in [4, 5] {
+        let addr = get_test_config_with_node(id).bind_addr;
+        with_replication(&leader, &nodes, node.add_member(id, addr))
+            .await
+            .unwrap();
+    }
+
+    let membership = leader.membership().await;
+    assert!(!membership.is_joint());
+    assert_eq!(membership.voters, set(&[1, 2, 3, 4, 5]));
+    assert!(membership.learners.is_empty());
+    let peers = node.peers().await;
+    assert_eq!(
+        peers.iter().map(|p| p.node_id).collect::<BTreeSet<_>>(),
+        set(&[4, 5])
+    );
+    assert!(peers.iter().all(|p| p.role == NodeRole::Follower));
+
+    let expected: Vec<Vec<u8>> = (0..3).map(|i| format!("entry{i}").into_bytes()).collect();
+    for follower in &nodes[3..] {
+        assert_eq!(commands(follower).await, expected);
+        assert_eq!(follower.membership().await.voters, set(&[1, 2, 3, 4, 5]));
+    }
+
+    // Shrink: remove the two members again
+    for id in [4, 5] {
+        with_replication(&leader, &nodes, node.remove_member(id))
+            .await
+            .unwrap();
+    }
+
+    let membership = leader.membership().await;
+    assert!(!membership.is_joint());
+    assert_eq!(membership.voters, set(&[1, 2, 3]));
+    assert!(node.peers().await.is_empty());
+    assert!(matches!(
+        node.remove_member(4).await,
+        Err(ClusterError::NodeNotFound(4))
+    ));
+
+    // The original three nodes commit on their own again
+    let index = leader.propose(b"after shrink".to_vec()).await.unwrap();
+    sync_from(&leader, &nodes, &[2, 3]).await;
+    assert!(leader.commit_index().await >= index);
+    for follower in &nodes[1..3] {
+        assert_eq!(follower.membership().await.voters, set(&[1, 2, 3]));
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_failed_join_leaves_no_learner_behind() {
+    let node = ClusterNode::new(get_test_config_with_node(1))
+        .await
+        .unwrap();
+    let leader = node.consensus();
+    let nodes = create_nodes(4).await;
+
+    leader.promote_to_leader().await.unwrap();
+    leader.initialize_membership([1, 2, 3]).await;
+    for follower in &nodes[1..3] {
+        follower.initialize_membership([1, 2, 3]).await;
+    }
+    leader.update_quorum_status(2, 3).await;
+    leader.initialize_replication_state(vec![2, 3]).await;
+    leader.propose(b"entry".to_vec()).await.unwrap();
+    sync_from(&leader, &nodes, &[2, 3]).await;
+
+    // Nothing replicates to node 4, so it never catches up
+    let addr = get_test_config_with_node(4).bind_addr;
+    assert!(matches!(
+        node.add_member(4, addr).await,
+        Err(ClusterError::Timeout(_))
+    ));
+
+    let membership = leader.membership().await;
+    assert_eq!(membership.voters, set(&[1, 2, 3]));
+    assert!(membership.learners.is_empty());
+    assert!(node.peers().await.is_empty());
+    assert!(matches!(
+        node.remove_member(4).await,
+        Err(ClusterError::NodeNotFound(4))
+    ));
+
+    // The node can be added again once it is reachable
+    with_replication(&leader, &nodes, node.add_member(4, addr))
+        .await
+        .unwrap();
+    assert_eq!(leader.membership().await.voters, set(&[1, 2, 3, 4]));
+}
+
+#[tokio::test]
+async fn test_membership_change_rejected_while_joint() {
+    let nodes = create_nodes(4).await;
+    let leader = &nodes[0];
+    bootstrap_three_node_group(&nodes).await;
+
+    leader.add_learner(4).await.unwrap();
+    sync(&nodes, &[4]).await;
+
+    leader.change_membership(set(&[1, 2, 3, 4])).await.unwrap();
+    assert!(matches!(
+        leader.change_membership(set(&[1, 2, 3])).await,
+        Err(ClusterError::MembershipChangeInProgress)
+    ));
+    assert!(matches!(
+        leader.add_learner(5).await,
+        Err(ClusterError::MembershipChangeInProgress)
+    ));
+}
+
+#[tokio::test]
+async fn test_voter_must_be_learner_first() {
+    let nodes = create_nodes(3).await;
+    let leader = &nodes[0];
+    bootstrap_three_node_group(&nodes).await;
+
+    assert!(matches!(
+        leader.change_membership(set(&[1, 2, 3, 9])).await,
+        Err(ClusterError::NodeNotFound(9))
+    ));
+    assert!(matches!(
+        leader.add_learner(2).await,
+        Err(ClusterError::NodeAlreadyExists(2))
+    ));
+}
+
+#[tokio::test]
+async fn test_follower_cannot_change_membership() {
+    let nodes = create_nodes(2).await;
+    assert!(matches!(
+        nodes[1].add_learner(3).await,
+        Err(ClusterError::NotLeader(2, _))
+    ));
+
+    let node = ClusterNode::new(get_test_config_with_node(7))
+        .await
+        .unwrap();
+    let result = node.add_member(8, "127.0.0.1:9999".parse().unwrap()).await;
+    assert!(matches!(result, Err(ClusterError::NotLeader(7, _))));
+    assert!(node.peers().await.is_empty());
+}
@@ -0,0 +1,205 @@
+//! Unit tests for read-replica query routing.
+
+use std::collections::HashMap;
+
+use neuroquantum_cluster::error::ClusterError;
+use neuroquantum_cluster::routing::{
+    ConsistencyToken, QueryKind, QueryRouter, QueryRouterConfig, ReplicaStatus,
+};
+use neuroquantum_cluster::NodeRole;
+
+fn replica(node_id: u64, role: NodeRole, lsn: u64) -> ReplicaStatus {
+    ReplicaStatus {
+        node_id,
+        addr: format!("127.0.0.1:{}", 9000 + node_id).parse().unwrap(),
+        role,
+        healthy: true,
+        replicated_lsn: lsn,
+    }
+}
+
+/// Mock three-node cluster: node 1 leads, nodes 2 and 3 follow.
+async fn three_node_router(config: QueryRouterConfig) -> QueryRouter {
+    let router = QueryRouter::new(config);
+    router
+        .update_replica(replica(1, NodeRole::Leader, 500))
+        .await;
+    router
+        .update_replica(replica(2, NodeRole::Follower, 495))
+        .await;
+    router
+        .update_replica(replica(3, NodeRole::Follower, 490))
+        .await;
+    router
+}
+
+#[tokio::test]
+async fn test_writes_always_hit_leader() {
+    let router = three_node_router(QueryRouterConfig::default()).await;
+
+    for _ in 0..20 {
+        let target = router.route(QueryKind::Write).await.unwrap();
+        assert_eq!(target.node_id, 1);
+        assert_eq!(target.role, NodeRole::Leader);
+    }
+}
+
+#[tokio::test]
+async fn test_reads_spread_across_followers() {
+    let router = three_node_router(QueryRouterConfig::default()).await;
+
+    let mut counts: HashMap<u64, usize> = HashMap::new();
+    for _ in 0..100 {
+        let target = router.route(QueryKind::Read).await.unwrap();
+        *counts.entry(target.node_id).or_default() += 1;
+    }
+
+    assert!(!counts.contains_key(&1), "reads must not hit the leader");
+    assert_eq!(counts.get(&2), Some(&50));
+    assert_eq!(counts.get(&3), Some(&50));
+}
+
+#[tokio::test]
+async fn test_stale_follower_is_excluded() {
+    let config = QueryRouterConfig {
+        max_staleness_lsn: 8,
+        ..Default::default()
+    };
+    let router = three_node_router(config).await;
+
+    // Node 3 lags by 10 entries, beyond the bound of 8
+    let readers = router.eligible_readers().await;
+    assert_eq!(readers.len(), 1);
+    assert_eq!(readers[0].node_id, 2);
+
+    for _ in 0..10 {
+        assert_eq!(router.route(QueryKind::Read).await.unwrap().node_id, 2);
+    }
+
+    // Once it catches up it serves reads again
+    router.update_lsn(3, 499).await.unwrap();
+    assert_eq!(router.eligible_readers().await.len(), 2);
+}
+
+#[tokio::test]
+async fn test_unhealthy_follower_is_excluded() {
+    let router = three_node_router(QueryRouterConfig::default()).await;
+    router.set_healthy(2, false).await.unwrap();
+
+    for _ in 0..10 {
+        assert_eq!(router.route(QueryKind::Read).await.unwrap().node_id, 3);
+    }
+}
+
+#[tokio::test]
+async fn test_reads_fall_back_to_leader() {
+    let config = QueryRouterConfig {
+        max_staleness_lsn: 0,
+        fallback_to_leader: true,
+    };
+    let router = three_node_router(config).await;
+    assert_eq!(router.route(QueryKind::Read).await.unwrap().node_id, 1);
+
+    let config = QueryRouterConfig {
+        max_staleness_lsn: 0,
+        fallback_to_leader: false,
+    };
+    let router = three_node_router(config).await;
+    assert!(router.route(QueryKind::Read).await.is_err());
+}
+
+#[tokio::test]
+async fn test_leader_change_redirects_writes() {
+    let router = three_node_router(QueryRouterConfig::default()).await;
+
+    router
+        .update_replica(replica(2, NodeRole::Leader, 500))
+        .await;
+
+    assert_eq!(router.route(QueryKind::Write).await.unwrap().node_id, 2);
+    let readers: Vec<u64> = router
+        .eligible_readers()
+        .await
+        .iter()
+        .map(|r| r.node_id)
+        .collect();
+    assert_eq!(readers, vec![1, 3]);
+}
+
+#[tokio::test]
+async fn test_write_without_leader_fails() {
+    let router = QueryRouter::new(QueryRouterConfig::default());
+    router
+        .update_replica(replica(2, NodeRole::Follower, 10))
+        .await;
+
+    assert!(matches!(
+        router.route(QueryKind::Write).await,
+        Err(ClusterError::NoLeader)
+    ));
+}
+
+#[tokio::test]
+async fn test_read_your_writes_skips_lagging_follower() {
+    let router = three_node_router(QueryRouterConfig::default()).await;
+
+    // The leader commits a write at LSN 496; only node 2 is close enough
+    let target = router.route(QueryKind::Write).await.unwrap();
+    assert_eq!(target.node_id, 1);
+    let token = router.record_write(496).await.unwrap();
+    assert_eq!(token, ConsistencyToken::new(496));
+    assert_eq!(router.leader().await.unwrap().replicated_lsn, 500);
+
+    router.update_lsn(2, 496).await.unwrap();
+    for _ in 0..10 {
+        let target = router
+            .route_with_token(QueryKind::Read, Some(token))
+            .await
+            .unwrap();
+        assert_eq!(target.node_id, 2, "node 3 has not replicated the write");
+    }
+
+    // Reads without a token still use both followers
+    let mut nodes: Vec<u64> = Vec::new();
+    for _ in 0..2 {
+        nodes.push(router.route(QueryKind::Read).await.unwrap().node_id);
+    }
+    nodes.sort_unstable();
+    assert_eq!(nodes, vec![2, 3]);
+}
+
+#[tokio::test]
+async fn test_read_your_writes_falls_back_to_leader() {
+    let config = QueryRouterConfig {
+        fallback_to_leader: false,
+        ..Default::default()
+    };
+    let router = three_node_router(config).await;
+
+    // No follower has replicated the new write yet
+    let token = router.record_write(501).await.unwrap();
+    assert_eq!(router.leader().await.unwrap().replicated_lsn, 501);
+    assert!(router.eligible_readers_for(Some(token)).await.is_empty());
+    let target = router
+        .route_with_token(QueryKind::Read, Some(token))
+        .await
+        .unwrap();
+    assert_eq!(target.node_id, 1);
+
+    // Once a follower catches up it serves the read instead
+    router.update_lsn(3, 501).await.unwrap();
+    let target = router
+        .route_with_token(QueryKind::Read, Some(token))
+        .await
+        .unwrap();
+    assert_eq!(target.node_id, 3);
+}
+
+#[tokio::test]
+async fn test_record_write_without_leader_fails() {
+    let router = QueryRouter::new(QueryRouterConfig::default());
+    assert!(matches!(
+        router.record_write(1).await,
+        Err(ClusterError::NoLeader)
+    ));
+}
@@ -1,9 +1,17 @@
 //! Unit tests for shard management and consistent hashing.
 
-use neuroquantum_cluster::config::ClusterConfig;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use neuroquantum_cluster::config::{ClusterConfig, ShardingConfig};
+use neuroquantum_cluster::node::NodeId;
 use neuroquantum_cluster::sharding::{
-    ShardInfo, ShardManager, ShardState, ShardTransfer, TransferStatus,
+    CopyBatch, RebalanceJournal, ShardInfo, ShardManager, ShardMover, ShardState, ShardTransfer,
+    TransferStatus,
 };
+use neuroquantum_cluster::{ClusterError, ClusterResult};
 
 #[tokio::test]
 async fn test_shard_manager_creation() {
@@ -346,3 +354,331 @@ async fn test_rebalance_config() {
     assert!(rebalance_config.auto_rebalance);
     assert_eq!(rebalance_config.max_concurrent_transfers, 2);
 }
+
+type NodeStores = Arc<Mutex<HashMap<NodeId, BTreeMap<Vec<u8>, Vec<u8>>>>>;
+
+/// Copies keys between in-memory per-node stores and checks that every key
+/// being moved is still served by the source node while the copy runs.
+struct InMemoryMover {
+    manager: Arc<ShardManager>,
+    stores: NodeStores,
+    batch_size: usize,
+    fail_after_calls: usize,
+    calls: AtomicUsize,
+}
+
+impl InMemoryMover {
+    fn new(manager: Arc<ShardManager>, stores: NodeStores) -> Self {
+        Self {
+            manager,
+            stores,
+            batch_size: 4,
+            fail_after_calls: usize::MAX,
+            calls: AtomicUsize::new(0),
+        }
+    }
+}
+
+#[async_trait]
+impl ShardMover for InMemoryMover {
+    async fn copy_batch(
+        &self,
+        transfer: &ShardTransfer,
+        cursor: Option<&[u8]>,
+    ) -> ClusterResult<CopyBatch> {
+        if self.calls.fetch_add(1, Ordering::SeqCst) >= self.fail_after_calls {
+            return Err(ClusterError::Internal("simulated crash".into()));
+        }
+
+        let candidates: Vec<(Vec<u8>, Vec<u8>)> = {
+            let stores = self.stores.lock().unwrap();
+            stores
+                .get(&transfer.source_node)
+                .map(|store| {
+                    store
+                        .iter()
+                        .filter(|(key, _)| cursor.is_none_or(|c| key.as_slice() > c))
+                        .map(|(k, v)| (k.clone(), v.clone()))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        let mut batch = Vec::new();
+        for (key, value) in candidates {
+            if self.manager.get_shard_for_key(&key).await?.shard_id != transfer.shard_id {
+                continue;
+            }
+            // The key must stay readable on the old owner until the move commits
+            assert_eq!(
+                self.manager.get_owner_for_key(&key).await?,
+                transfer.source_node
+            );
+            batch.push((key, value));
+            if batch.len() == self.batch_size {
+                break;
+            }
+        }
+
+        let mut stores = self.stores.lock().unwrap();
+        let target = stores.entry(transfer.target_node).or_default();
+        let bytes = batch.iter().map(|(k, v)| (k.len() + v.len()) as u64).sum();
+        for (key, value) in &batch {
+            target.insert(key.clone(), value.clone());
+        }
+
+        Ok(CopyBatch {
+            keys: batch.len() as u64,
+            bytes,
+            next_cursor: (batch.len() == self.batch_size)
+                .then(|| batch.last().map(|(k, _)| k.clone()))
+                .flatten(),
+        })
+    }
+}
+
+const SHARD_COUNT: u64 = 400;
+const KEY_COUNT: usize = 2000;
+
+fn rebalance_config(data_dir: &std::path::Path) -> ClusterConfig {
+    ClusterConfig {
+        data_dir: data_dir.to_path_buf(),
+        ..Default::default()
+    }
+}
+
+/// Register shards on node 1, spread them over a three node ring and place
+/// every key on the node that owns it.
+async fn three_node_ring(manager: &Arc<ShardManager>, stores: &NodeStores) -> Vec<ShardInfo> {
+    for shard_id in 0..SHARD_COUNT {
+        manager
+            .register_shard(ShardInfo {
+                shard_id,
+                primary_node: 1,
+                replica_nodes: vec![],
+                state: ShardState::Active,
+                key_count: 0,
+                size_bytes: 0,
+            })
+            .await
+            .unwrap();
+    }
+    let mover = InMemoryMover::new(manager.clone(), stores.clone());
+    manager.rebalance(&[1, 2, 3], &mover).await.unwrap();
+
+    for i in 0..KEY_COUNT {
+        let key = format!("key-{i:05}").into_bytes();
+        let owner = manager.get_owner_for_key(&key).await.unwrap();
+        stores
+            .lock()
+            .unwrap()
+            .entry(owner)
+            .or_default()
+            .insert(key, b"value".to_vec());
+    }
+
+    let mut shards = Vec::new();
+    for shard_id in 0..SHARD_COUNT {
+        shards.push(manager.get_shard(shard_id).await.unwrap());
+    }
+    shards
+}
+
+async fn assert_all_keys_served(manager: &ShardManager, stores: &NodeStores) {
+    for i in 0..KEY_COUNT {
+        let key = format!("key-{i:05}").into_bytes();
+        let owner = manager.get_owner_for_key(&key).await.unwrap();
+        let stores = stores.lock().unwrap();
+        assert!(
+            stores.get(&owner).is_some_and(|s| s.contains_key(&key)),
+            "key {i} missing on owner {owner}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_rebalance_adding_fourth_node_moves_about_a_quarter() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let manager = Arc::new(ShardManager::new(&rebalance_config(temp_dir.path())).unwrap());
+    let stores = NodeStores::default();
+    three_node_ring(&manager, &stores).await;
+
+    let mover = InMemoryMover::new(manager.clone(), stores.clone());
+    let transfers = manager.rebalance(&[1, 2, 3, 4], &mover).await.unwrap();
+
+    // Minimal moves: only shards now owned by the new node migrate
+    assert!(transfers.iter().all(|t| t.target_node == 4));
+    assert!(transfers
+        .iter()
+        .all(|t| t.status == TransferStatus::Completed));
+    let moved = transfers.len() as f64 / SHARD_COUNT as f64;
+    assert!((0.15..=0.35).contains(&moved), "moved fraction {moved}");
+
+    assert_eq!(
+        manager.get_node_shards(4).await.unwrap().len(),
+        transfers.len()
+    );
+    assert_all_keys_served(&manager, &stores).await;
+    assert!(!manager.is_rebalancing().await);
+    assert!(!manager.journal_path().exists());
+    assert_eq!(manager.get_ring_distribution().await.len(), 4);
+}
+
+#[tokio::test]
+async fn test_rebalance_resumes_after_crash() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let config = rebalance_config(temp_dir.path());
+    let manager = Arc::new(ShardManager::new(&config).unwrap());
+    let stores = NodeStores::default();
+    let shards_before = three_node_ring(&manager, &stores).await;
+
+    let mut mover = InMemoryMover::new(manager.clone(), stores.clone());
+    mover.fail_after_calls = 25;
+    assert!(manager.rebalance(&[1, 2, 3, 4], &mover).await.is_err());
+
+    let journal = RebalanceJournal::load(manager.journal_path())
+        .await
+        .unwrap()
+        .expect("journal must survive the crash");
+    let completed = journal
+        .moves
+        .iter()
+        .filter(|m| m.transfer.status == TransferStatus::Completed)
+        .count();
+    assert!(completed > 0 && completed < journal.moves.len());
+
+    // A different topology cannot start while the journaled one is unfinished
+    assert!(matches!(
+        manager.rebalance(&[1, 2], &mover).await,
+        Err(ClusterError::RebalancingInProgress)
+    ));
+
+    // Restart: a fresh manager sees the shard registry as it was persisted
+    drop(mover);
+    drop(manager);
+    let manager = Arc::new(ShardManager::new(&config).unwrap());
+    for shard in shards_before {
+        manager.register_shard(shard).await.unwrap();
+    }
+
+    let mover = InMemoryMover::new(manager.clone(), stores.clone());
+    let transfers = manager.rebalance(&[1, 2, 3, 4], &mover).await.unwrap();
+
+    let planned: Vec<_> = journal.moves.iter().map(|m| m.transfer.shard_id).collect();
+    let resumed: Vec<_> = transfers.iter().map(|t| t.shard_id).collect();
+    assert_eq!(planned, resumed);
+    for shard_id in planned {
+        assert_eq!(manager.get_shard(shard_id).await.unwrap().primary_node, 4);
+    }
+    assert_all_keys_served(&manager, &stores).await;
+    assert!(!manager.journal_path().exists());
+}
+
+/// Coefficient of variation of the number of keys each node owns.
+async fn key_spread(virtual_nodes: u32) -> f64 {
+    let config = ClusterConfig {
+        sharding: ShardingConfig {
+            virtual_nodes,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let manager = ShardManager::new(&config).unwrap();
+    assert_eq!(manager.virtual_nodes_per_node(), virtual_nodes);
+    for node_id in 1..=4 {
+        manager.add_node(node_id).await.unwrap();
+    }
+
+    let mut counts: HashMap<NodeId, u64> = HashMap::new();
+    for i in 0..KEY_COUNT * 10 {
+        let key = format!("key-{i:05}").into_bytes();
+        *counts
+            .entry(manager.get_primary_node(&key).await.unwrap())
+            .or_default() += 1;
+    }
+
+    let mean = (KEY_COUNT * 10) as f64 / 4.0;
+    let variance = (1..=4)
+        .map(|node_id| {
+            let count = counts.get(&node_id).copied().unwrap_or_default() as f64;
+            (count - mean).powi(2)
+        })
+        .sum::<f64>()
+        / 4.0;
+    variance.sqrt() / mean
+}
+
+#[tokio::test]
+async fn test_more_virtual_nodes_even_out_distribution() {
+    let low = key_spread(1).await;
+    let high = key_spread(256).await;
+    assert!(high < low, "spread with 256 vnodes {high} vs 1 vnode {low}");
+    assert!(high < 0.15, "spread with 256 vnodes {high}");
+}
+
+#[test]
+fn test_zero_virtual_nodes_rejected() {
+    let mut config = ClusterConfig::default();
+    config.sharding.virtual_nodes = 0;
+    assert!(matches!(
+        ShardManager::new(&config),
+        Err(ClusterError::ConfigError(_))
+    ));
+    assert!(config.validate().is_err());
+}
+
+#[tokio::test]
+async fn test_rebalance_estimate_matches_actual_moves() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let manager = Arc::new(ShardManager::new(&rebalance_config(temp_dir.path())).unwrap());
+    let stores = NodeStores::default();
+    three_node_ring(&manager, &stores).await;
+
+    // Record how many keys each shard holds so the estimate can see them
+    let mut key_counts: HashMap<u64, u64> = HashMap::new();
+    for i in 0..KEY_COUNT {
+        let key = format!("key-{i:05}").into_bytes();
+        let shard_id = manager.get_shard_for_key(&key).await.unwrap().shard_id;
+        *key_counts.entry(shard_id).or_default() += 1;
+    }
+    for shard_id in 0..SHARD_COUNT {
+        let mut shard = manager.get_shard(shard_id).await.unwrap();
+        shard.key_count = key_counts.get(&shard_id).copied().unwrap_or_default();
+        manager.register_shard(shard).await.unwrap();
+    }
+
+    let unchanged = manager.estimate_rebalance_cost(&[1, 2, 3]).await.unwrap();
+    assert_eq!(unchanged.shards_moved, 0);
+    assert_eq!(unchanged.keys_moved, 0);
+    assert_eq!(unchanged.total_keys, KEY_COUNT as u64);
+
+    let estimate = manager
+        .estimate_rebalance_cost(&[1, 2, 3, 4])
+        .await
+        .unwrap();
+    assert_eq!(estimate.total_shards, SHARD_COUNT as usize);
+    assert!(estimate.keys_moved > 0);
+    assert_eq!(
+        estimate.keys_received.keys().copied().collect::<Vec<_>>(),
+        vec![4]
+    );
+    assert_eq!(
+        estimate.keys_sent.values().sum::<u64>(),
+        estimate.keys_moved
+    );
+    // Estimating does not start a rebalance
+    assert!(!manager.is_rebalancing().await);
+    assert!(!manager.journal_path().exists());
+
+    let mover = InMemoryMover::new(manager.clone(), stores.clone());
+    let transfers = manager.rebalance(&[1, 2, 3, 4], &mover).await.unwrap();
+    let keys_moved: u64 = transfers.iter().map(|t| t.keys_transferred).sum();
+    assert_eq!(transfers.len(), estimate.shards_moved);
+    assert_eq!(keys_moved, estimate.keys_moved);
+    assert!((estimate.moved_key_fraction() - keys_moved as f64 / KEY_COUNT as f64).abs() < 1e-9);
+
+    assert!(matches!(
+        manager.estimate_rebalance_cost(&[]).await,
+        Err(ClusterError::ConfigError(_))
+    ));
+}
@@ -1,5 +1,6 @@
 #![allow(clippy::cast_sign_loss)]
 use std::hint::black_box;
+use std::time::Duration;
 
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use neuroquantum_core::storage::pager::{PageStorageManager, PageType, PagerConfig, SyncMode};
@@ -146,7 +147,14 @@ fn bench_sync_modes(c: &mut Criterion) {
 
     let mut group = c.benchmark_group("sync_modes");
 
-    for sync_mode in &[SyncMode::None, SyncMode::Commit, SyncMode::Always] {
+    for sync_mode in &[
+        SyncMode::None,
+        SyncMode::Commit,
+        SyncMode::Always,
+        SyncMode::Periodic {
+            interval: Duration::from_millis(10),
+        },
+    ] {
         let temp_dir = TempDir::new().unwrap();
         let db_path = temp_dir.path().join("bench.db");
 
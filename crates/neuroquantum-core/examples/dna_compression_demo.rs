@@ -10,7 +10,9 @@
 
 use std::time::Instant;
 
-use neuroquantum_core::dna::{DNACompressionConfig, DNACompressor, DNAError, QuantumDNACompressor};
+use neuroquantum_core::dna::{
+    DNACompressionConfig, DNACompressor, DNAError, DnaAlphabet, MemoryPolicy, QuantumDNACompressor,
+};
 
 #[tokio::main]
 async fn main() -> Result<(), DNAError> {
@@ -132,7 +134,10 @@ async fn demo_dictionary_compression() -> Result<(), DNAError> {
         enable_dictionary: true,
         max_dictionary_size: 65536,
         memory_limit: 1024 * 1024 * 1024,
+        memory_policy: MemoryPolicy::Reject,
         thread_count: rayon::current_num_threads(),
+        chunk_size: 1024 * 1024,
+        alphabet: DnaAlphabet::STANDARD,
     };
 
     let compressor = QuantumDNACompressor::with_config(config);
@@ -189,7 +194,10 @@ async fn demo_error_correction() -> Result<(), DNAError> {
             enable_dictionary: false,
             max_dictionary_size: 65536,
             memory_limit: 1024 * 1024 * 1024,
+            memory_policy: MemoryPolicy::Reject,
             thread_count: rayon::current_num_threads(),
+            chunk_size: 1024 * 1024,
+            alphabet: DnaAlphabet::STANDARD,
         };
 
         let compressor = QuantumDNACompressor::with_config(config);
@@ -233,7 +241,10 @@ async fn demo_performance_comparison() -> Result<(), DNAError> {
         enable_dictionary: false,
         max_dictionary_size: 0,
         memory_limit: 1024 * 1024 * 1024,
+        memory_policy: MemoryPolicy::Reject,
         thread_count: rayon::current_num_threads(),
+        chunk_size: 1024 * 1024,
+        alphabet: DnaAlphabet::STANDARD,
     };
 
     // Configuration 2: Balanced (moderate error correction, dictionary enabled)
@@ -243,7 +254,10 @@ async fn demo_performance_comparison() -> Result<(), DNAError> {
         enable_dictionary: true,
         max_dictionary_size: 65536,
         memory_limit: 1024 * 1024 * 1024,
+        memory_policy: MemoryPolicy::Reject,
         thread_count: rayon::current_num_threads(),
+        chunk_size: 1024 * 1024,
+        alphabet: DnaAlphabet::STANDARD,
     };
 
     // Configuration 3: Maximum compression (high error correction, large dictionary)
@@ -253,7 +267,10 @@ async fn demo_performance_comparison() -> Result<(), DNAError> {
         enable_dictionary: true,
         max_dictionary_size: 131072,
         memory_limit: 1024 * 1024 * 1024,
+        memory_policy: MemoryPolicy::Reject,
         thread_count: rayon::current_num_threads(),
+        chunk_size: 1024 * 1024,
+        alphabet: DnaAlphabet::STANDARD,
     };
 
     let configs = vec![
@@ -11,7 +11,7 @@
 use std::sync::Arc;
 
 use neuroquantum_core::storage::pager::{PageStorageManager, PageType, PagerConfig, SyncMode};
-use neuroquantum_core::storage::wal::{RecoveryStats, WALConfig, WALManager};
+use neuroquantum_core::storage::wal::{Durability, RecoveryStats, WALConfig, WALManager};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -41,6 +41,7 @@ async fn main() -> anyhow::Result<()> {
         enable_checksums: true,
         sync_mode: SyncMode::Commit,
         direct_io: false,
+        ..Default::default()
     };
 
     let db_file = data_dir.join("demo.db");
@@ -51,9 +52,12 @@ async fn main() -> anyhow::Result<()> {
         wal_dir: wal_dir.clone(),
         segment_size: 16 * 1024 * 1024, // 16MB segments
         sync_on_write: true,
+        commit_durability: Durability::Durable,
         buffer_size: 256 * 1024, // 256KB buffer
         checkpoint_interval_secs: 300,
+        max_wal_bytes_before_checkpoint: 64 * 1024 * 1024, // 64MB
         min_segments_to_keep: 3,
+        redo_workers: 4,
         group_commit_delay_ms: 5,
         group_commit_max_records: 1000,
         group_commit_max_bytes: 4 * 1024 * 1024,
@@ -253,7 +257,7 @@ async fn demo_crash_recovery(
 
     // Perform recovery
     println!("   Starting ARIES recovery...");
-    let stats: RecoveryStats = wal.recover(Arc::clone(pager)).await?;
+    let stats: RecoveryStats = wal.recover(Arc::clone(pager), None).await?;
 
     println!("   ✅ Recovery completed:");
     println!("      - Records analyzed: {}", stats.records_analyzed);
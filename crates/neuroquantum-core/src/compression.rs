@@ -0,0 +1,461 @@
+//! Pluggable compression for stored values
+//!
+//! [`NeuroQuantumDB::store_compressed`](crate::NeuroQuantumDB::store_compressed)
+//! compresses with DNA encoding, whose error-correction blocks make small or
+//! already-compressed payloads larger. The [`Compressor`] trait puts DNA next
+//! to general purpose codecs, selected per call through a
+//! [`CompressionAlgorithm`] or picked by [`CompressionAlgorithm::auto_select`]
+//! from a byte entropy estimate.
+//!
+//! Stored values start with a small header naming the algorithm, so they can be
+//! decompressed without knowing how they were written. Values written before
+//! the header existed are DNA-compressed JSON and are still read as such.
+//!
+//! DNA payloads use the binary [container format](crate::dna::container);
+//! payloads written as JSON before it existed are still read.
+//!
+//! DNA encoding of random or already-compressed data only adds overhead, so
+//! [`encode_dna_adaptive`] first runs [`predict_incompressible`] and stores such
+//! data uncompressed. Uncompressed payloads carry a CRC32 of the data and the
+//! [`UncompressedReason`] they were stored for.
+
+use std::fmt;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::dna::{CompressedDNA, DNACompressor, DNAContainer, QuantumDNACompressor};
+use crate::error::NeuroQuantumError;
+
+/// Magic bytes in front of every value written with an algorithm header
+const HEADER_MAGIC: &[u8; 4] = b"NQC\x01";
+
+/// Length of the algorithm header
+const HEADER_LEN: usize = HEADER_MAGIC.len() + 1;
+
+/// Bytes sampled by the entropy estimate
+const ENTROPY_SAMPLE_SIZE: usize = 64 * 1024;
+
+/// Size of each window sampled by [`predict_incompressible`]
+const PREDICTION_WINDOW_SIZE: usize = 4 * 1024;
+
+/// Maximum number of windows sampled by [`predict_incompressible`]
+const PREDICTION_WINDOWS: usize = 8;
+
+/// Leading bytes of common compressed, archive and media formats
+const COMPRESSED_SIGNATURES: &[&[u8]] = &[
+    b"\x1f\x8b",           // gzip
+    b"\x28\xb5\x2f\xfd",   // zstd
+    b"BZh",                // bzip2
+    b"\xfd7zXZ\x00",       // xz
+    b"PK\x03\x04",         // zip
+    b"7z\xbc\xaf\x27\x1c", // 7-Zip
+    b"\x89PNG\r\n\x1a\n",  // PNG
+    b"\xff\xd8\xff",       // JPEG
+];
+
+/// Length of the uncompressed payload header: reason and CRC32
+const UNCOMPRESSED_HEADER_LEN: usize = 5;
+
+/// Entropy in bits per byte above which data is stored uncompressed
+///
+/// Encrypted, random and already-compressed data sits close to 8 bits per byte.
+pub const INCOMPRESSIBLE_ENTROPY: f64 = 7.5;
+
+/// Entropy in bits per byte below which data is DNA-compressed
+///
+/// Data drawn from four or fewer symbols, such as nucleotide sequences, maps
+/// directly onto DNA bases.
+pub const DNA_ENTROPY: f64 = 2.0;
+
+/// Payloads shorter than this are stored uncompressed by auto-selection
+pub const MIN_COMPRESSIBLE_SIZE: usize = 64;
+
+/// zstd level used for stored values by default
+pub const DEFAULT_ZSTD_LEVEL: i32 = 3;
+
+/// Algorithm a stored value is compressed with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompressionAlgorithm {
+    /// Quaternary DNA encoding with Reed-Solomon error correction
+    #[default]
+    Dna,
+    /// Zstandard
+    Zstd,
+    /// LZ4 block compression
+    Lz4,
+    /// Stored as is
+    None,
+}
+
+impl CompressionAlgorithm {
+    /// Every algorithm, in header tag order
+    pub const ALL: [Self; 4] = [Self::Dna, Self::Zstd, Self::Lz4, Self::None];
+
+    /// Pick an algorithm for `data` from a quick entropy estimate
+    ///
+    /// Short and high-entropy payloads are stored uncompressed, very
+    /// low-entropy payloads are DNA-encoded and everything else uses zstd.
+    #[must_use]
+    pub fn auto_select(data: &[u8]) -> Self {
+        if data.len() < MIN_COMPRESSIBLE_SIZE {
+            return Self::None;
+        }
+        let entropy = estimate_entropy(data);
+        if entropy >= INCOMPRESSIBLE_ENTROPY {
+            Self::None
+        } else if entropy <= DNA_ENTROPY {
+            Self::Dna
+        } else {
+            Self::Zstd
+        }
+    }
+
+    /// Name used in logs and configuration
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            | Self::Dna => "dna",
+            | Self::Zstd => "zstd",
+            | Self::Lz4 => "lz4",
+            | Self::None => "none",
+        }
+    }
+
+    const fn tag(self) -> u8 {
+        match self {
+            | Self::Dna => 0,
+            | Self::Zstd => 1,
+            | Self::Lz4 => 2,
+            | Self::None => 3,
+        }
+    }
+
+    fn from_tag(tag: u8) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|algorithm| algorithm.tag() == tag)
+    }
+}
+
+impl fmt::Display for CompressionAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Shannon entropy of `data` in bits per byte
+///
+/// Only the first [`ENTROPY_SAMPLE_SIZE`] bytes are inspected.
+#[must_use]
+pub fn estimate_entropy(data: &[u8]) -> f64 {
+    let sample = &data[..data.len().min(ENTROPY_SAMPLE_SIZE)];
+    if sample.is_empty() {
+        return 0.0;
+    }
+    let mut counts = [0usize; 256];
+    for &byte in sample {
+        counts[byte as usize] += 1;
+    }
+    let len = sample.len() as f64;
+    counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Why a value was stored without compression
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UncompressedReason {
+    /// [`CompressionAlgorithm::None`] was selected explicitly or by
+    /// [`CompressionAlgorithm::auto_select`]
+    Requested,
+    /// The data starts with the signature of a compressed format
+    AlreadyCompressed,
+    /// The sampled entropy is at or above [`INCOMPRESSIBLE_ENTROPY`]
+    HighEntropy,
+}
+
+impl UncompressedReason {
+    const fn tag(self) -> u8 {
+        match self {
+            | Self::Requested => 0,
+            | Self::AlreadyCompressed => 1,
+            | Self::HighEntropy => 2,
+        }
+    }
+
+    const fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            | 0 => Some(Self::Requested),
+            | 1 => Some(Self::AlreadyCompressed),
+            | 2 => Some(Self::HighEntropy),
+            | _ => None,
+        }
+    }
+}
+
+impl fmt::Display for UncompressedReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            | Self::Requested => "requested",
+            | Self::AlreadyCompressed => "already compressed",
+            | Self::HighEntropy => "high entropy",
+        })
+    }
+}
+
+/// Predict whether DNA compression would make `data` larger
+///
+/// Returns why `data` should be stored uncompressed, or `None` if it is worth
+/// compressing. Up to [`PREDICTION_WINDOWS`] windows spread over the data are
+/// sampled, so the cost doesn't grow with the size of the value. Payloads
+/// shorter than [`MIN_COMPRESSIBLE_SIZE`] are too small to judge and are
+/// always compressed.
+#[must_use]
+pub fn predict_incompressible(data: &[u8]) -> Option<UncompressedReason> {
+    if data.len() < MIN_COMPRESSIBLE_SIZE {
+        return None;
+    }
+    if COMPRESSED_SIGNATURES
+        .iter()
+        .any(|signature| data.starts_with(signature))
+    {
+        return Some(UncompressedReason::AlreadyCompressed);
+    }
+
+    let window = data.len().min(PREDICTION_WINDOW_SIZE);
+    let windows = (data.len() / window).min(PREDICTION_WINDOWS);
+    let stride = (data.len() - window) / windows.saturating_sub(1).max(1);
+    let mean_entropy = (0..windows)
+        .map(|i| estimate_entropy(&data[i * stride..i * stride + window]))
+        .sum::<f64>()
+        / windows as f64;
+    (mean_entropy >= INCOMPRESSIBLE_ENTROPY).then_some(UncompressedReason::HighEntropy)
+}
+
+/// Encode `data` for storage with DNA compression, unless it wouldn't help
+///
+/// Data that [`predict_incompressible`] rejects is stored with
+/// [`CompressionAlgorithm::None`] and the predicted reason instead.
+pub async fn encode_dna_adaptive(
+    dna: &QuantumDNACompressor,
+    data: &[u8],
+) -> Result<Vec<u8>, NeuroQuantumError> {
+    if let Some(reason) = predict_incompressible(data) {
+        tracing::info!(
+            "Skipping DNA compression of {} bytes: {}",
+            data.len(),
+            reason
+        );
+        return Ok(encode_stored(
+            CompressionAlgorithm::None,
+            &encode_uncompressed(reason, data),
+        ));
+    }
+    let payload = Compressor::compress(dna, data).await?;
+    Ok(encode_stored(CompressionAlgorithm::Dna, &payload))
+}
+
+/// A compression codec for stored values
+#[async_trait]
+pub trait Compressor: Send + Sync {
+    /// Algorithm recorded for values written by this compressor
+    fn algorithm(&self) -> CompressionAlgorithm;
+
+    /// Compress `data`
+    async fn compress(&self, data: &[u8]) -> Result<Vec<u8>, NeuroQuantumError>;
+
+    /// Decompress a payload produced by [`compress`](Self::compress)
+    async fn decompress(&self, payload: &[u8]) -> Result<Vec<u8>, NeuroQuantumError>;
+
+    /// Check a payload without keeping the decompressed data
+    async fn validate(&self, payload: &[u8]) -> Result<bool, NeuroQuantumError> {
+        Ok(self.decompress(payload).await.is_ok())
+    }
+}
+
+#[async_trait]
+impl Compressor for QuantumDNACompressor {
+    fn algorithm(&self) -> CompressionAlgorithm {
+        CompressionAlgorithm::Dna
+    }
+
+    async fn compress(&self, data: &[u8]) -> Result<Vec<u8>, NeuroQuantumError> {
+        let compressed = DNACompressor::compress(self, data)
+            .await
+            .map_err(|e| NeuroQuantumError::CompressionError(e.to_string()))?;
+        compressed
+            .to_container_bytes()
+            .map_err(|e| NeuroQuantumError::SerializationError(e.to_string()))
+    }
+
+    async fn decompress(&self, payload: &[u8]) -> Result<Vec<u8>, NeuroQuantumError> {
+        let compressed = decode_dna(payload)?;
+        DNACompressor::decompress(self, &compressed)
+            .await
+            .map_err(|e| NeuroQuantumError::CompressionError(e.to_string()))
+    }
+
+    async fn validate(&self, payload: &[u8]) -> Result<bool, NeuroQuantumError> {
+        let compressed = decode_dna(payload)?;
+        DNACompressor::validate(self, &compressed)
+            .await
+            .map_err(|e| NeuroQuantumError::CompressionError(e.to_string()))
+    }
+}
+
+fn decode_dna(payload: &[u8]) -> Result<CompressedDNA, NeuroQuantumError> {
+    if DNAContainer::is_container(payload) {
+        return CompressedDNA::from_container_bytes(payload)
+            .map_err(|e| NeuroQuantumError::SerializationError(e.to_string()));
+    }
+    serde_json::from_slice(payload)
+        .map_err(|e| NeuroQuantumError::SerializationError(e.to_string()))
+}
+
+/// Zstandard compressor
+#[derive(Debug, Clone, Copy)]
+pub struct ZstdCompressor {
+    level: i32,
+}
+
+impl ZstdCompressor {
+    /// Create a compressor using zstd `level`
+    #[must_use]
+    pub const fn new(level: i32) -> Self {
+        Self { level }
+    }
+}
+
+impl Default for ZstdCompressor {
+    fn default() -> Self {
+        Self::new(DEFAULT_ZSTD_LEVEL)
+    }
+}
+
+#[async_trait]
+impl Compressor for ZstdCompressor {
+    fn algorithm(&self) -> CompressionAlgorithm {
+        CompressionAlgorithm::Zstd
+    }
+
+    async fn compress(&self, data: &[u8]) -> Result<Vec<u8>, NeuroQuantumError> {
+        zstd::bulk::compress(data, self.level)
+            .map_err(|e| NeuroQuantumError::CompressionError(e.to_string()))
+    }
+
+    async fn decompress(&self, payload: &[u8]) -> Result<Vec<u8>, NeuroQuantumError> {
+        zstd::stream::decode_all(payload)
+            .map_err(|e| NeuroQuantumError::CompressionError(e.to_string()))
+    }
+}
+
+/// LZ4 block compressor
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Lz4Compressor;
+
+#[async_trait]
+impl Compressor for Lz4Compressor {
+    fn algorithm(&self) -> CompressionAlgorithm {
+        CompressionAlgorithm::Lz4
+    }
+
+    async fn compress(&self, data: &[u8]) -> Result<Vec<u8>, NeuroQuantumError> {
+        Ok(lz4_flex::compress_prepend_size(data))
+    }
+
+    async fn decompress(&self, payload: &[u8]) -> Result<Vec<u8>, NeuroQuantumError> {
+        lz4_flex::decompress_size_prepended(payload)
+            .map_err(|e| NeuroQuantumError::CompressionError(e.to_string()))
+    }
+}
+
+/// Pass-through "compressor" for incompressible data
+///
+/// Payloads are the data behind an [`UncompressedReason`] tag and a CRC32 of
+/// the data, which [`decompress`](Compressor::decompress) verifies.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoCompression;
+
+#[async_trait]
+impl Compressor for NoCompression {
+    fn algorithm(&self) -> CompressionAlgorithm {
+        CompressionAlgorithm::None
+    }
+
+    async fn compress(&self, data: &[u8]) -> Result<Vec<u8>, NeuroQuantumError> {
+        Ok(encode_uncompressed(UncompressedReason::Requested, data))
+    }
+
+    async fn decompress(&self, payload: &[u8]) -> Result<Vec<u8>, NeuroQuantumError> {
+        let (_, data) = decode_uncompressed(payload)?;
+        Ok(data.to_vec())
+    }
+}
+
+/// Build an uncompressed payload of `data` stored for `reason`
+#[must_use]
+pub fn encode_uncompressed(reason: UncompressedReason, data: &[u8]) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(UNCOMPRESSED_HEADER_LEN + data.len());
+    payload.push(reason.tag());
+    payload.extend_from_slice(&crc32fast::hash(data).to_le_bytes());
+    payload.extend_from_slice(data);
+    payload
+}
+
+/// Split an uncompressed payload into its reason and data, verifying the CRC32
+pub fn decode_uncompressed(
+    payload: &[u8],
+) -> Result<(UncompressedReason, &[u8]), NeuroQuantumError> {
+    let Some((&[tag, c0, c1, c2, c3], data)) =
+        payload.split_first_chunk::<UNCOMPRESSED_HEADER_LEN>()
+    else {
+        return Err(NeuroQuantumError::SerializationError(
+            "Truncated uncompressed payload".to_string(),
+        ));
+    };
+    let reason = UncompressedReason::from_tag(tag).ok_or_else(|| {
+        NeuroQuantumError::SerializationError(format!("Unknown uncompressed reason tag {tag}"))
+    })?;
+    let expected = u32::from_le_bytes([c0, c1, c2, c3]);
+    let actual = crc32fast::hash(data);
+    if actual != expected {
+        return Err(NeuroQuantumError::CompressionError(format!(
+            "Checksum mismatch in uncompressed payload: expected {expected:08x}, got {actual:08x}"
+        )));
+    }
+    Ok((reason, data))
+}
+
+/// Prefix `payload` with the header recording `algorithm`
+#[must_use]
+pub fn encode_stored(algorithm: CompressionAlgorithm, payload: &[u8]) -> Vec<u8> {
+    let mut stored = Vec::with_capacity(HEADER_LEN + payload.len());
+    stored.extend_from_slice(HEADER_MAGIC);
+    stored.push(algorithm.tag());
+    stored.extend_from_slice(payload);
+    stored
+}
+
+/// Split a stored value into its algorithm and compressed payload
+///
+/// Values without a header are legacy DNA-compressed JSON.
+pub fn decode_stored(stored: &[u8]) -> Result<(CompressionAlgorithm, &[u8]), NeuroQuantumError> {
+    let Some(rest) = stored.strip_prefix(HEADER_MAGIC) else {
+        return Ok((CompressionAlgorithm::Dna, stored));
+    };
+    let (&tag, payload) = rest.split_first().ok_or_else(|| {
+        NeuroQuantumError::SerializationError("Truncated compression header".to_string())
+    })?;
+    let algorithm = CompressionAlgorithm::from_tag(tag).ok_or_else(|| {
+        NeuroQuantumError::SerializationError(format!("Unknown compression algorithm tag {tag}"))
+    })?;
+    Ok((algorithm, payload))
+}
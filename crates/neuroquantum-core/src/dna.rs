@@ -9,22 +9,30 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use tokio::sync::Semaphore;
 use tracing::{debug, info, instrument, warn};
 
 pub mod benchmarks;
 pub mod compression;
+pub mod container;
 pub mod decoder;
+pub mod dictionary;
 pub mod encoder;
 pub mod error_correction;
+pub mod observer;
 pub mod simd;
 
 #[cfg(test)]
 pub mod tests;
 
 // Re-export types for easier access
+pub use container::DNAContainer;
 pub use decoder::QuaternaryDecoder;
+pub use dictionary::{DNADictionary, DictionaryId, DictionaryStore};
 pub use encoder::QuaternaryEncoder;
 pub use error_correction::ReedSolomonCorrector;
+use observer::ObserverSlot;
+pub use observer::{CompressionEvent, CompressionObserver, CompressionOperation};
 
 // Type alias for backward compatibility
 pub type EncodedData = CompressedDNA;
@@ -84,6 +92,91 @@ impl DNABase {
     }
 }
 
+/// Symbols written for the four bases, indexed by their 2-bit value
+///
+/// The default is the A/T/G/C mapping of [`DNABase::to_char`]. Pipelines with
+/// their own base-to-bits convention, or a different 4-symbol set entirely,
+/// configure a permuted or custom alphabet in [`DNACompressionConfig`]. The
+/// alphabet is recorded in [`CompressionMetadata`], so symbols are always read
+/// back with the map they were written with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "[char; 4]", into = "[char; 4]")]
+pub struct DnaAlphabet([char; 4]);
+
+impl DnaAlphabet {
+    /// The standard alphabet: A=00, T=01, G=10, C=11
+    pub const STANDARD: Self = Self(['A', 'T', 'G', 'C']);
+
+    /// Create an alphabet writing `symbols[bits]` for the base with value `bits`
+    pub fn new(symbols: [char; 4]) -> Result<Self, DNAError> {
+        for (i, symbol) in symbols.iter().enumerate() {
+            if symbols[..i].contains(symbol) {
+                return Err(DNAError::InvalidAlphabet(format!(
+                    "symbol '{symbol}' is mapped more than once"
+                )));
+            }
+        }
+        Ok(Self(symbols))
+    }
+
+    /// Symbols indexed by 2-bit value
+    #[must_use]
+    pub const fn symbols(&self) -> [char; 4] {
+        self.0
+    }
+
+    /// Symbol written for `base`
+    #[must_use]
+    pub const fn symbol(&self, base: DNABase) -> char {
+        self.0[base.to_bits() as usize]
+    }
+
+    /// Base written as `symbol`
+    ///
+    /// Symbols that don't match exactly are compared ignoring ASCII case, like
+    /// [`DNABase::from_char`] does.
+    pub fn base(&self, symbol: char) -> Result<DNABase, DNAError> {
+        let bits = self
+            .0
+            .iter()
+            .position(|&s| s == symbol)
+            .or_else(|| self.0.iter().position(|s| s.eq_ignore_ascii_case(&symbol)))
+            .ok_or(DNAError::InvalidBase(symbol as u8))?;
+        DNABase::from_bits(bits as u8)
+    }
+
+    /// Write `bases` as symbols
+    #[must_use]
+    pub fn encode(&self, bases: &[DNABase]) -> String {
+        bases.iter().map(|&base| self.symbol(base)).collect()
+    }
+
+    /// Read bases from symbols written by [`encode`](Self::encode)
+    pub fn decode(&self, symbols: &str) -> Result<Vec<DNABase>, DNAError> {
+        symbols.chars().map(|symbol| self.base(symbol)).collect()
+    }
+}
+
+impl Default for DnaAlphabet {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+impl TryFrom<[char; 4]> for DnaAlphabet {
+    type Error = DNAError;
+
+    fn try_from(symbols: [char; 4]) -> Result<Self, Self::Error> {
+        Self::new(symbols)
+    }
+}
+
+impl From<DnaAlphabet> for [char; 4] {
+    fn from(alphabet: DnaAlphabet) -> Self {
+        alphabet.0
+    }
+}
+
 /// Comprehensive error types for DNA compression operations
 #[derive(Debug, Error)]
 pub enum DNAError {
@@ -114,6 +207,21 @@ pub enum DNAError {
     #[error("Invalid compression version: {0}")]
     InvalidVersion(u8),
 
+    #[error("Invalid DNA alphabet: {0}")]
+    InvalidAlphabet(String),
+
+    #[error("Invalid dictionary: {0}")]
+    InvalidDictionary(String),
+
+    #[error("Unknown dictionary: {0:08x}")]
+    UnknownDictionary(DictionaryId),
+
+    #[error("Invalid DNA container: {0}")]
+    InvalidContainer(String),
+
+    #[error("Unsupported DNA container version: {0}")]
+    UnsupportedContainerVersion(u8),
+
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 
@@ -134,6 +242,45 @@ pub struct DNASequence {
     pub original_length: usize,
     /// Compression metadata
     pub metadata: CompressionMetadata,
+    /// Chunks compressed independently, stored back to back in `bases` and
+    /// `parity`; empty if the data was compressed as a single block
+    ///
+    /// For chunked data `checksum` covers the chunk table rather than the data.
+    #[serde(default)]
+    pub chunks: Vec<DNAChunk>,
+}
+
+impl DNASequence {
+    /// Bases written in the alphabet recorded in the metadata
+    #[must_use]
+    pub fn to_symbols(&self) -> String {
+        self.metadata.alphabet.encode(&self.bases)
+    }
+
+    /// Replace the bases with `symbols`, read in the alphabet recorded in the
+    /// metadata
+    ///
+    /// This restores a sequence whose bases went through a pipeline as
+    /// symbols; the parity still catches symbols that changed on the way.
+    pub fn set_symbols(&mut self, symbols: &str) -> Result<(), DNAError> {
+        self.bases = self.metadata.alphabet.decode(symbols)?;
+        Ok(())
+    }
+}
+
+/// An independently compressed and error-corrected chunk of a [`DNASequence`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DNAChunk {
+    /// Length of the chunk's original data
+    pub original_length: usize,
+    /// Number of bases belonging to the chunk
+    pub base_count: usize,
+    /// Number of parity bytes belonging to the chunk
+    pub parity_length: usize,
+    /// Checksum of the chunk's dictionary-compressed data
+    pub checksum: u32,
+    /// Dictionary built for this chunk
+    pub dictionary: Option<HashMap<Vec<u8>, u16>>,
 }
 
 /// Metadata about the compression process
@@ -147,8 +294,14 @@ pub struct CompressionMetadata {
     pub error_correction_strength: u8,
     /// Dictionary used for pattern compression
     pub dictionary: Option<HashMap<Vec<u8>, u16>>,
+    /// Shared dictionary used for pattern compression, see [`DNADictionary`]
+    #[serde(default)]
+    pub dictionary_id: Option<DictionaryId>,
     /// Timestamp of compression
     pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// Alphabet the bases are written in as symbols
+    #[serde(default)]
+    pub alphabet: DnaAlphabet,
 }
 
 /// Compressed DNA data ready for storage
@@ -175,6 +328,24 @@ pub struct CompressionMetrics {
     pub errors_corrected: usize,
 }
 
+/// Bytes of working memory needed per input byte while compressing
+const WORKING_SET_FACTOR: usize = 2;
+
+/// How the compressor handles inputs that don't fit its memory limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemoryPolicy {
+    /// Fail with [`DNAError::MemoryError`]
+    #[default]
+    Reject,
+    /// Compress the input in chunks small enough that the chunks in flight
+    /// fit in the limit, one at a time if necessary
+    ///
+    /// Only the working memory is bounded; the compressed output still grows
+    /// with the input.
+    ChunkAndStream,
+}
+
 /// Configuration for DNA compression operations
 #[derive(Debug, Clone)]
 pub struct DNACompressionConfig {
@@ -188,8 +359,16 @@ pub struct DNACompressionConfig {
     pub max_dictionary_size: usize,
     /// Memory limit for compression operations (in bytes)
     pub memory_limit: usize,
+    /// What to do with inputs whose working set exceeds `memory_limit`
+    pub memory_policy: MemoryPolicy,
     /// Number of threads for parallel operations
     pub thread_count: usize,
+    /// Inputs larger than this are split into chunks of this size that are
+    /// compressed and decompressed concurrently, at most `thread_count` at a
+    /// time (0 disables chunking)
+    pub chunk_size: usize,
+    /// Symbols the bases are written in, recorded in the metadata
+    pub alphabet: DnaAlphabet,
 }
 
 impl Default for DNACompressionConfig {
@@ -200,11 +379,162 @@ impl Default for DNACompressionConfig {
             enable_dictionary: true,
             max_dictionary_size: 65536,       // 64KB dictionary
             memory_limit: 1024 * 1024 * 1024, // 1GB limit
+            memory_policy: MemoryPolicy::Reject,
             thread_count: rayon::current_num_threads(),
+            chunk_size: 1024 * 1024, // 1MB chunks
+            alphabet: DnaAlphabet::STANDARD,
         }
     }
 }
 
+/// Output of compressing one block of input
+struct EncodedBlock {
+    bases: Vec<DNABase>,
+    parity: Vec<u8>,
+    checksum: u32,
+    /// Length after dictionary compression
+    processed_length: usize,
+    dictionary: Option<HashMap<Vec<u8>, u16>>,
+}
+
+/// Dictionary-compress, encode and error-correct one block of input
+async fn encode_block(
+    config: &DNACompressionConfig,
+    data: &[u8],
+    shared_dictionary: Option<&DNADictionary>,
+) -> Result<EncodedBlock, DNAError> {
+    let mut encoder = QuaternaryEncoder::new(config);
+    let error_corrector = ReedSolomonCorrector::new(config.error_correction_strength);
+
+    // Step 1: Dictionary compression if enabled
+    let processed_data = if let Some(dictionary) = shared_dictionary {
+        debug!("Applying shared dictionary {:08x}", dictionary.id());
+        encoder.compress_with_shared_dictionary(data, dictionary)
+    } else if config.enable_dictionary {
+        debug!("Applying dictionary compression");
+        encoder.compress_with_dictionary(data).await?
+    } else {
+        data.to_vec()
+    };
+
+    // Step 2: Quaternary encoding
+    debug!("Encoding to DNA bases");
+    let bases = encoder.encode_to_bases(&processed_data).await?;
+
+    // Step 3: Add Reed-Solomon error correction
+    debug!("Adding Reed-Solomon error correction");
+    let parity = error_corrector.generate_parity(&processed_data)?;
+
+    // Step 4: Calculate checksum
+    Ok(EncodedBlock {
+        bases,
+        parity,
+        checksum: crc32fast::hash(&processed_data),
+        processed_length: processed_data.len(),
+        dictionary: encoder.get_dictionary(),
+    })
+}
+
+/// Decode, error-correct, verify and dictionary-decompress one block
+///
+/// Returns the data and the number of corrected errors.
+async fn decode_block(
+    config: &DNACompressionConfig,
+    bases: &[DNABase],
+    parity: &[u8],
+    checksum: u32,
+    dictionary: Option<&HashMap<Vec<u8>, u16>>,
+) -> Result<(Vec<u8>, usize), DNAError> {
+    let decoder = QuaternaryDecoder::new(config);
+    let error_corrector = ReedSolomonCorrector::new(config.error_correction_strength);
+
+    // Step 1: Decode DNA bases to binary
+    debug!("Decoding DNA bases to binary");
+    let mut decoded_data = decoder.decode_from_bases(bases).await?;
+
+    // Step 2: Apply Reed-Solomon error correction
+    debug!("Applying Reed-Solomon error correction");
+    let (corrected_data, errors_corrected) =
+        error_corrector.correct_errors(&decoded_data, parity)?;
+
+    if errors_corrected > 0 {
+        warn!("Corrected {} errors during decompression", errors_corrected);
+        decoded_data = corrected_data;
+    }
+
+    // Step 3: Verify checksum
+    let calculated_checksum = crc32fast::hash(&decoded_data);
+    if calculated_checksum != checksum {
+        return Err(DNAError::ChecksumMismatch {
+            expected: checksum,
+            actual: calculated_checksum,
+        });
+    }
+
+    // Step 4: Apply dictionary decompression if needed
+    let data = if let Some(dictionary) = dictionary {
+        debug!("Applying dictionary decompression");
+        decoder
+            .decompress_with_dictionary(&decoded_data, dictionary)
+            .await?
+    } else {
+        decoded_data
+    };
+
+    Ok((data, errors_corrected))
+}
+
+/// Concatenate independently compressed chunks into one sequence
+///
+/// Returns the sequence and the total length after dictionary compression.
+fn chunked_sequence(
+    original_length: usize,
+    chunk_size: usize,
+    blocks: Vec<EncodedBlock>,
+    metadata: CompressionMetadata,
+) -> (DNASequence, usize) {
+    let mut sequence = DNASequence {
+        bases: Vec::with_capacity(original_length * 4),
+        parity: Vec::new(),
+        checksum: 0,
+        original_length,
+        metadata,
+        chunks: Vec::with_capacity(blocks.len()),
+    };
+    let mut processed_length = 0;
+    let mut remaining = original_length;
+
+    for block in blocks {
+        let chunk_length = remaining.min(chunk_size);
+        remaining -= chunk_length;
+        processed_length += block.processed_length;
+        sequence.chunks.push(DNAChunk {
+            original_length: chunk_length,
+            base_count: block.bases.len(),
+            parity_length: block.parity.len(),
+            checksum: block.checksum,
+            dictionary: block.dictionary,
+        });
+        sequence.bases.extend(block.bases);
+        sequence.parity.extend(block.parity);
+    }
+
+    sequence.checksum = chunk_table_checksum(&sequence.chunks);
+    (sequence, processed_length)
+}
+
+/// Checksum over the chunk table, so altered chunk boundaries are detected
+fn chunk_table_checksum(chunks: &[DNAChunk]) -> u32 {
+    let mut hasher = crc32fast::Hasher::new();
+    for chunk in chunks {
+        hasher.update(&(chunk.original_length as u64).to_le_bytes());
+        hasher.update(&(chunk.base_count as u64).to_le_bytes());
+        hasher.update(&(chunk.parity_length as u64).to_le_bytes());
+        hasher.update(&chunk.checksum.to_le_bytes());
+    }
+    hasher.finalize()
+}
+
 /// Main DNA compression trait - async interface for database integration
 #[async_trait]
 pub trait DNACompressor: Send + Sync {
@@ -228,10 +558,14 @@ pub trait DNACompressor: Send + Sync {
 }
 
 /// High-performance DNA compressor implementation
+///
+/// Clones share metrics, trained dictionaries and the observer.
 #[derive(Debug, Clone)]
 pub struct QuantumDNACompressor {
     config: DNACompressionConfig,
     metrics: Arc<std::sync::Mutex<CompressionMetrics>>,
+    dictionaries: DictionaryStore,
+    observer: ObserverSlot,
 }
 
 impl QuantumDNACompressor {
@@ -251,13 +585,242 @@ impl QuantumDNACompressor {
             errors_corrected: 0,
         }));
 
-        Self { config, metrics }
+        Self {
+            config,
+            metrics,
+            dictionaries: DictionaryStore::default(),
+            observer: ObserverSlot::default(),
+        }
+    }
+
+    /// Report every successful compression and decompression to `observer`
+    ///
+    /// Replaces any previously registered observer.
+    pub fn set_observer(&self, observer: Arc<dyn CompressionObserver>) {
+        self.observer.set(Some(observer));
+    }
+
+    /// Stop reporting operations
+    pub fn clear_observer(&self) {
+        self.observer.set(None);
     }
 
     /// Update configuration
     pub const fn update_config(&mut self, config: DNACompressionConfig) {
         self.config = config;
     }
+
+    /// Train a shared dictionary on sample records and use it from now on
+    ///
+    /// Subsequent compressions replace the dictionary's patterns with
+    /// references and record only its ID, so values similar to the samples
+    /// compress better even when they are small. The dictionary holds at most
+    /// `max_dictionary_size / 32` patterns.
+    pub fn train_dictionary(&self, samples: &[&[u8]]) -> Result<DictionaryId, DNAError> {
+        let dictionary = DNADictionary::train(samples, self.config.max_dictionary_size)?;
+        info!(
+            "Trained DNA dictionary {:08x} with {} patterns from {} samples",
+            dictionary.id(),
+            dictionary.len(),
+            samples.len()
+        );
+        Ok(self.dictionaries.activate(dictionary))
+    }
+
+    /// Serialize the active dictionary for persistence
+    ///
+    /// Values compressed with it cannot be decompressed by a compressor that
+    /// has not imported it.
+    pub fn export_dictionary(&self) -> Result<Vec<u8>, DNAError> {
+        self.dictionaries
+            .active()
+            .ok_or_else(|| DNAError::InvalidDictionary("no dictionary trained".to_string()))?
+            .export()
+    }
+
+    /// Load a dictionary written by [`export_dictionary`](Self::export_dictionary)
+    /// and use it from now on
+    pub fn import_dictionary(&self, bytes: &[u8]) -> Result<DictionaryId, DNAError> {
+        let dictionary = DNADictionary::import(bytes)?;
+        debug!(
+            "Imported DNA dictionary {:08x} with {} patterns",
+            dictionary.id(),
+            dictionary.len()
+        );
+        Ok(self.dictionaries.activate(dictionary))
+    }
+
+    /// ID of the dictionary used for new compressions, if any
+    #[must_use]
+    pub fn active_dictionary(&self) -> Option<DictionaryId> {
+        self.dictionaries.active().map(|dictionary| dictionary.id())
+    }
+
+    /// Compress without the shared dictionary from now on
+    ///
+    /// The dictionary stays available to decompress existing values.
+    pub fn clear_dictionary(&self) {
+        self.dictionaries.deactivate();
+    }
+
+    /// Check if compressing `len` bytes exceeds the memory limit
+    const fn exceeds_memory_limit(&self, len: usize) -> bool {
+        len.saturating_mul(WORKING_SET_FACTOR) > self.config.memory_limit
+    }
+
+    /// Size of the chunks `len` bytes are split into, or `None` to compress
+    /// them as one block
+    ///
+    /// Inputs over the memory limit are chunked under
+    /// [`MemoryPolicy::ChunkAndStream`] even with a single thread.
+    fn chunk_size_for(&self, len: usize) -> Option<usize> {
+        if self.config.memory_policy == MemoryPolicy::ChunkAndStream
+            && self.exceeds_memory_limit(len)
+        {
+            let budget = (self.config.memory_limit / WORKING_SET_FACTOR).max(1);
+            return Some(match self.config.chunk_size {
+                | 0 => budget,
+                | chunk_size => chunk_size.min(budget),
+            });
+        }
+        (self.config.thread_count > 1 && self.config.chunk_size > 0 && len > self.config.chunk_size)
+            .then_some(self.config.chunk_size)
+    }
+
+    /// Number of chunks of at most `chunk_size` bytes processed at a time
+    ///
+    /// At most `thread_count`, and under [`MemoryPolicy::ChunkAndStream`] no
+    /// more than fit in the memory limit together.
+    fn chunk_concurrency(&self, chunk_size: usize) -> usize {
+        let threads = self.config.thread_count.max(1);
+        match self.config.memory_policy {
+            | MemoryPolicy::Reject => threads,
+            | MemoryPolicy::ChunkAndStream => {
+                let chunk_working_set = chunk_size.saturating_mul(WORKING_SET_FACTOR).max(1);
+                (self.config.memory_limit / chunk_working_set).clamp(1, threads)
+            },
+        }
+    }
+
+    /// Compress `chunk_size` chunks of `data` on concurrent tasks, at most
+    /// [`chunk_concurrency`](Self::chunk_concurrency) at a time
+    async fn encode_chunks(
+        &self,
+        data: &[u8],
+        chunk_size: usize,
+        shared_dictionary: Option<Arc<DNADictionary>>,
+    ) -> Result<Vec<EncodedBlock>, DNAError> {
+        let permits = Arc::new(Semaphore::new(self.chunk_concurrency(chunk_size)));
+        let mut tasks = Vec::with_capacity(data.len().div_ceil(chunk_size));
+
+        for chunk in data.chunks(chunk_size) {
+            let permit = Arc::clone(&permits)
+                .acquire_owned()
+                .await
+                .map_err(|e| DNAError::CompressionFailed(e.to_string()))?;
+            let config = self.config.clone();
+            let chunk = chunk.to_vec();
+            let dictionary = shared_dictionary.clone();
+            tasks.push(tokio::spawn(async move {
+                let _permit = permit;
+                encode_block(&config, &chunk, dictionary.as_deref()).await
+            }));
+        }
+
+        let mut blocks = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            let block = task
+                .await
+                .map_err(|e| DNAError::CompressionFailed(format!("Chunk task failed: {e}")))??;
+            blocks.push(block);
+        }
+        Ok(blocks)
+    }
+
+    /// Decompress the chunks of `sequence` on concurrent tasks, at most
+    /// [`chunk_concurrency`](Self::chunk_concurrency) at a time
+    ///
+    /// Returns the data and the number of corrected errors.
+    async fn decode_chunks(
+        &self,
+        sequence: &DNASequence,
+        shared_dictionary: Option<Arc<DNADictionary>>,
+    ) -> Result<(Vec<u8>, usize), DNAError> {
+        let chunks = &sequence.chunks;
+        let base_count: usize = chunks.iter().map(|chunk| chunk.base_count).sum();
+        if base_count != sequence.bases.len() {
+            return Err(DNAError::LengthMismatch {
+                expected: base_count,
+                actual: sequence.bases.len(),
+            });
+        }
+        let parity_length: usize = chunks.iter().map(|chunk| chunk.parity_length).sum();
+        if parity_length != sequence.parity.len() {
+            return Err(DNAError::LengthMismatch {
+                expected: parity_length,
+                actual: sequence.parity.len(),
+            });
+        }
+        let table_checksum = chunk_table_checksum(chunks);
+        if table_checksum != sequence.checksum {
+            return Err(DNAError::ChecksumMismatch {
+                expected: sequence.checksum,
+                actual: table_checksum,
+            });
+        }
+
+        let largest_chunk = chunks
+            .iter()
+            .map(|chunk| chunk.original_length)
+            .max()
+            .unwrap_or(0);
+        let permits = Arc::new(Semaphore::new(self.chunk_concurrency(largest_chunk)));
+        let mut tasks = Vec::with_capacity(chunks.len());
+        let (mut base_offset, mut parity_offset) = (0, 0);
+
+        for chunk in chunks {
+            let permit = Arc::clone(&permits)
+                .acquire_owned()
+                .await
+                .map_err(|e| DNAError::DecompressionFailed(e.to_string()))?;
+            let config = self.config.clone();
+            let bases = sequence.bases[base_offset..base_offset + chunk.base_count].to_vec();
+            let parity =
+                sequence.parity[parity_offset..parity_offset + chunk.parity_length].to_vec();
+            base_offset += chunk.base_count;
+            parity_offset += chunk.parity_length;
+            let chunk = chunk.clone();
+            let shared_dictionary = shared_dictionary.clone();
+
+            tasks.push(tokio::spawn(async move {
+                let _permit = permit;
+                let dictionary = shared_dictionary
+                    .as_deref()
+                    .map(DNADictionary::patterns)
+                    .or(chunk.dictionary.as_ref());
+                let (data, errors_corrected) =
+                    decode_block(&config, &bases, &parity, chunk.checksum, dictionary).await?;
+                if data.len() != chunk.original_length {
+                    return Err(DNAError::LengthMismatch {
+                        expected: chunk.original_length,
+                        actual: data.len(),
+                    });
+                }
+                Ok((data, errors_corrected))
+            }));
+        }
+
+        let mut data = Vec::with_capacity(sequence.original_length);
+        let mut errors_corrected = 0;
+        for task in tasks {
+            let (chunk_data, chunk_errors) = task
+                .await
+                .map_err(|e| DNAError::DecompressionFailed(format!("Chunk task failed: {e}")))??;
+            data.extend(chunk_data);
+            errors_corrected += chunk_errors;
+        }
+        Ok((data, errors_corrected))
+    }
 }
 
 #[async_trait]
@@ -269,54 +832,60 @@ impl DNACompressor for QuantumDNACompressor {
         info!("Starting DNA compression for {} bytes", data.len());
 
         // Check memory limits
-        if data.len() * 2 > self.config.memory_limit {
+        if self.config.memory_policy == MemoryPolicy::Reject
+            && self.exceeds_memory_limit(data.len())
+        {
             return Err(DNAError::MemoryError(format!(
                 "Data size {} exceeds memory limit {}",
-                data.len() * 2,
+                data.len() * WORKING_SET_FACTOR,
                 self.config.memory_limit
             )));
         }
 
-        // Create encoder and corrector
-        let mut encoder = QuaternaryEncoder::new(&self.config);
-        let error_corrector = ReedSolomonCorrector::new(self.config.error_correction_strength);
-
-        // Step 1: Dictionary compression if enabled
-        let processed_data = if self.config.enable_dictionary {
-            debug!("Applying dictionary compression");
-            encoder.compress_with_dictionary(data).await?
+        // Step 1: Dictionary-compress, encode and error-correct, in parallel
+        // chunks for large inputs
+        let shared_dictionary = if self.config.enable_dictionary {
+            self.dictionaries.active()
         } else {
-            data.to_vec()
+            None
         };
-
-        // Step 2: Quaternary encoding
-        debug!("Encoding to DNA bases");
-        let bases = encoder.encode_to_bases(&processed_data).await?;
-
-        // Step 3: Add Reed-Solomon error correction
-        debug!("Adding Reed-Solomon error correction");
-        let parity = error_corrector.generate_parity(&processed_data)?;
-
-        // Step 4: Calculate checksum
-        let checksum = crc32fast::hash(&processed_data);
-
-        // Create metadata
-        let compression_ratio = processed_data.len() as f64 / data.len() as f64;
         let metadata = CompressionMetadata {
             version: 1,
-            compression_ratio,
+            compression_ratio: 1.0,
             error_correction_strength: self.config.error_correction_strength,
-            dictionary: encoder.get_dictionary(),
+            dictionary: None,
+            dictionary_id: shared_dictionary.as_ref().map(|dictionary| dictionary.id()),
             timestamp: chrono::Utc::now(),
+            alphabet: self.config.alphabet,
         };
 
-        let sequence = DNASequence {
-            bases,
-            parity,
-            checksum,
-            original_length: data.len(),
-            metadata,
-        };
+        let (mut sequence, processed_length) =
+            if let Some(chunk_size) = self.chunk_size_for(data.len()) {
+                let blocks = self
+                    .encode_chunks(data, chunk_size, shared_dictionary)
+                    .await?;
+                debug!("Compressed {} chunks of {} bytes", blocks.len(), chunk_size);
+                chunked_sequence(data.len(), chunk_size, blocks, metadata)
+            } else {
+                let block = encode_block(&self.config, data, shared_dictionary.as_deref()).await?;
+                let processed_length = block.processed_length;
+                let sequence = DNASequence {
+                    bases: block.bases,
+                    parity: block.parity,
+                    checksum: block.checksum,
+                    original_length: data.len(),
+                    metadata: CompressionMetadata {
+                        dictionary: block.dictionary,
+                        ..metadata
+                    },
+                    chunks: Vec::new(),
+                };
+                (sequence, processed_length)
+            };
+
+        // Step 2: Record the compression ratio
+        let compression_ratio = processed_length as f64 / data.len() as f64;
+        sequence.metadata.compression_ratio = compression_ratio;
 
         let elapsed = start_time.elapsed();
         let compressed_size = sequence.bases.len() / 4 + sequence.parity.len();
@@ -324,7 +893,7 @@ impl DNACompressor for QuantumDNACompressor {
         let metrics = CompressionMetrics {
             compression_time_us: elapsed.as_micros() as u64,
             decompression_time_us: None,
-            peak_memory_bytes: processed_data.len() + sequence.bases.len() + sequence.parity.len(),
+            peak_memory_bytes: processed_length + sequence.bases.len() + sequence.parity.len(),
             errors_corrected: 0,
         };
 
@@ -339,6 +908,16 @@ impl DNACompressor for QuantumDNACompressor {
             elapsed.as_micros()
         );
 
+        self.observer.notify(|| CompressionEvent {
+            operation: CompressionOperation::Compress,
+            input_size: data.len(),
+            output_size: compressed_size,
+            compression_ratio: compressed_size as f64 / data.len().max(1) as f64,
+            duration: elapsed,
+            error_correction_bytes: sequence.parity.len(),
+            errors_corrected: 0,
+        });
+
         Ok(CompressedDNA {
             sequence,
             compressed_size,
@@ -362,43 +941,35 @@ impl DNACompressor for QuantumDNACompressor {
             ));
         }
 
-        // Create decoder and corrector
-        let decoder = QuaternaryDecoder::new(&self.config);
-        let error_corrector = ReedSolomonCorrector::new(self.config.error_correction_strength);
-
-        // Step 1: Decode DNA bases to binary
-        debug!("Decoding DNA bases to binary");
-        let mut decoded_data = decoder
-            .decode_from_bases(&compressed.sequence.bases)
-            .await?;
-
-        // Step 2: Apply Reed-Solomon error correction
-        debug!("Applying Reed-Solomon error correction");
-        let (corrected_data, errors_corrected) =
-            error_corrector.correct_errors(&decoded_data, &compressed.sequence.parity)?;
-
-        if errors_corrected > 0 {
-            warn!("Corrected {} errors during decompression", errors_corrected);
-            decoded_data = corrected_data;
-        }
-
-        // Step 3: Verify checksum
-        let calculated_checksum = crc32fast::hash(&decoded_data);
-        if calculated_checksum != compressed.sequence.checksum {
-            return Err(DNAError::ChecksumMismatch {
-                expected: compressed.sequence.checksum,
-                actual: calculated_checksum,
-            });
-        }
-
-        // Step 4: Apply dictionary decompression if needed
-        let final_data = if let Some(ref dictionary) = compressed.sequence.metadata.dictionary {
-            debug!("Applying dictionary decompression");
-            decoder
-                .decompress_with_dictionary(&decoded_data, dictionary)
-                .await?
+        // Step 1: Resolve the shared dictionary, if one was used
+        let shared_dictionary = compressed
+            .sequence
+            .metadata
+            .dictionary_id
+            .map(|id| {
+                self.dictionaries
+                    .get(id)
+                    .ok_or(DNAError::UnknownDictionary(id))
+            })
+            .transpose()?;
+
+        // Step 2: Decode, error-correct, verify and dictionary-decompress
+        let (final_data, errors_corrected) = if compressed.sequence.chunks.is_empty() {
+            let dictionary = shared_dictionary
+                .as_deref()
+                .map(DNADictionary::patterns)
+                .or(compressed.sequence.metadata.dictionary.as_ref());
+            decode_block(
+                &self.config,
+                &compressed.sequence.bases,
+                &compressed.sequence.parity,
+                compressed.sequence.checksum,
+                dictionary,
+            )
+            .await?
         } else {
-            decoded_data
+            self.decode_chunks(&compressed.sequence, shared_dictionary)
+                .await?
         };
 
         // Verify final length
@@ -424,6 +995,16 @@ impl DNACompressor for QuantumDNACompressor {
             errors_corrected
         );
 
+        self.observer.notify(|| CompressionEvent {
+            operation: CompressionOperation::Decompress,
+            input_size: compressed.compressed_size,
+            output_size: final_data.len(),
+            compression_ratio: compressed.compressed_size as f64 / final_data.len().max(1) as f64,
+            duration: elapsed,
+            error_correction_bytes: compressed.sequence.parity.len(),
+            errors_corrected,
+        });
+
         Ok(final_data)
     }
 
@@ -455,8 +1036,30 @@ impl DNACompressor for QuantumDNACompressor {
             return Ok(false);
         }
 
+        // A shared dictionary must be available to decompress
+        if let Some(id) = compressed.sequence.metadata.dictionary_id {
+            if self.dictionaries.get(id).is_none() {
+                return Ok(false);
+            }
+        }
+
         // Verify Reed-Solomon parity length
         let error_corrector = ReedSolomonCorrector::new(self.config.error_correction_strength);
+        if !compressed.sequence.chunks.is_empty() {
+            let chunks = &compressed.sequence.chunks;
+            let consistent = chunks.iter().map(|chunk| chunk.base_count).sum::<usize>()
+                == compressed.sequence.bases.len()
+                && chunks
+                    .iter()
+                    .map(|chunk| chunk.parity_length)
+                    .sum::<usize>()
+                    == compressed.sequence.parity.len()
+                && chunks.iter().all(|chunk| {
+                    chunk.parity_length
+                        == error_corrector.calculate_parity_length(chunk.base_count / 4)
+                });
+            return Ok(consistent);
+        }
         let expected_parity_len =
             error_corrector.calculate_parity_length(compressed.compressed_size);
         if compressed.sequence.parity.len() != expected_parity_len {
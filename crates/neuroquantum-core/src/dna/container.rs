@@ -0,0 +1,290 @@
+//! Binary container format for compressed DNA
+//!
+//! Serializing a [`CompressedDNA`] with `serde_json` spends a JSON string per
+//! base and a number per parity byte. The container packs four bases into a
+//! byte and stores everything else in a compact binary metadata section:
+//!
+//! ```text
+//! magic "NQDN" | version u8 | algorithm u8 | bases | parity | metadata
+//! ```
+//!
+//! Each section is prefixed with its length as a little-endian `u32`. The
+//! bases section starts with the base count as a little-endian `u64`, followed
+//! by the packed bases, most significant bits first. [`DNAContainer::parse`]
+//! validates the header and borrows the sections from the input without
+//! copying them.
+//!
+//! Readers reject versions they don't know instead of guessing at the layout.
+//! Version 1 predates [`DnaAlphabet`]; its bases are read with the standard
+//! alphabet.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::dna::{
+    CompressedDNA, CompressionMetadata, CompressionMetrics, DNABase, DNAChunk, DNAError,
+    DNASequence, DictionaryId, DnaAlphabet,
+};
+
+/// Magic bytes starting every container
+pub const CONTAINER_MAGIC: &[u8; 4] = b"NQDN";
+
+/// Container format version written by this build
+pub const CONTAINER_VERSION: u8 = 2;
+
+/// Oldest container format version this build reads
+pub const MIN_CONTAINER_VERSION: u8 = 1;
+
+/// Algorithm tag of quaternary encoding with Reed-Solomon parity
+pub const ALGORITHM_QUATERNARY_RS: u8 = 0;
+
+/// Length of the fixed header in front of the sections
+const HEADER_LEN: usize = CONTAINER_MAGIC.len() + 2;
+
+/// Fields of a [`CompressedDNA`] stored in the metadata section
+#[derive(Serialize)]
+struct MetadataSection<'a> {
+    checksum: u32,
+    original_length: usize,
+    compressed_size: usize,
+    metadata: &'a CompressionMetadata,
+    chunks: &'a [DNAChunk],
+    metrics: &'a CompressionMetrics,
+}
+
+/// Owned counterpart of [`MetadataSection`] for reading
+#[derive(Deserialize)]
+struct OwnedMetadataSection<M = CompressionMetadata> {
+    checksum: u32,
+    original_length: usize,
+    compressed_size: usize,
+    metadata: M,
+    chunks: Vec<DNAChunk>,
+    metrics: CompressionMetrics,
+}
+
+/// [`CompressionMetadata`] as written by version 1, without an alphabet
+#[derive(Deserialize)]
+struct MetadataV1 {
+    version: u8,
+    compression_ratio: f64,
+    error_correction_strength: u8,
+    dictionary: Option<HashMap<Vec<u8>, u16>>,
+    dictionary_id: Option<DictionaryId>,
+    timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<MetadataV1> for CompressionMetadata {
+    fn from(metadata: MetadataV1) -> Self {
+        Self {
+            version: metadata.version,
+            compression_ratio: metadata.compression_ratio,
+            error_correction_strength: metadata.error_correction_strength,
+            dictionary: metadata.dictionary,
+            dictionary_id: metadata.dictionary_id,
+            timestamp: metadata.timestamp,
+            alphabet: DnaAlphabet::STANDARD,
+        }
+    }
+}
+
+impl From<OwnedMetadataSection<MetadataV1>> for OwnedMetadataSection {
+    fn from(section: OwnedMetadataSection<MetadataV1>) -> Self {
+        Self {
+            checksum: section.checksum,
+            original_length: section.original_length,
+            compressed_size: section.compressed_size,
+            metadata: section.metadata.into(),
+            chunks: section.chunks,
+            metrics: section.metrics,
+        }
+    }
+}
+
+/// Sections of a container, borrowed from the bytes they were parsed from
+#[derive(Debug, Clone, Copy)]
+pub struct DNAContainer<'a> {
+    /// Format version from the header
+    pub version: u8,
+    /// Algorithm tag from the header
+    pub algorithm: u8,
+    /// Number of bases in `packed_bases`
+    pub base_count: usize,
+    /// Bases packed four to a byte
+    pub packed_bases: &'a [u8],
+    /// Reed-Solomon parity
+    pub parity: &'a [u8],
+    /// Serialized checksum, lengths, metadata, chunks and metrics
+    pub metadata: &'a [u8],
+}
+
+impl<'a> DNAContainer<'a> {
+    /// Check if `bytes` start with the container magic
+    #[must_use]
+    pub fn is_container(bytes: &[u8]) -> bool {
+        bytes.starts_with(CONTAINER_MAGIC)
+    }
+
+    /// Validate the header of `bytes` and locate its sections
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, DNAError> {
+        let rest = bytes
+            .strip_prefix(CONTAINER_MAGIC)
+            .ok_or_else(|| DNAError::InvalidContainer("missing magic bytes".to_string()))?;
+        let (&[version, algorithm], mut rest) = rest
+            .split_first_chunk::<2>()
+            .ok_or_else(|| DNAError::InvalidContainer("truncated header".to_string()))?;
+        if !(MIN_CONTAINER_VERSION..=CONTAINER_VERSION).contains(&version) {
+            return Err(DNAError::UnsupportedContainerVersion(version));
+        }
+        if algorithm != ALGORITHM_QUATERNARY_RS {
+            return Err(DNAError::InvalidContainer(format!(
+                "unknown algorithm tag {algorithm}"
+            )));
+        }
+
+        let bases = take_section(&mut rest, "bases")?;
+        let parity = take_section(&mut rest, "parity")?;
+        let metadata = take_section(&mut rest, "metadata")?;
+        if !rest.is_empty() {
+            return Err(DNAError::InvalidContainer(format!(
+                "{} trailing bytes",
+                rest.len()
+            )));
+        }
+
+        let (count, packed_bases) = bases
+            .split_first_chunk::<8>()
+            .ok_or_else(|| DNAError::InvalidContainer("truncated base count".to_string()))?;
+        let base_count = usize::try_from(u64::from_le_bytes(*count))
+            .map_err(|_| DNAError::InvalidContainer("base count too large".to_string()))?;
+        if packed_bases.len() != base_count.div_ceil(4) {
+            return Err(DNAError::InvalidContainer(format!(
+                "{} packed bytes cannot hold {} bases",
+                packed_bases.len(),
+                base_count
+            )));
+        }
+
+        Ok(Self {
+            version,
+            algorithm,
+            base_count,
+            packed_bases,
+            parity,
+            metadata,
+        })
+    }
+
+    /// Unpack the bases
+    #[must_use]
+    pub fn bases(&self) -> Vec<DNABase> {
+        let mut bases = Vec::with_capacity(self.base_count);
+        for &byte in self.packed_bases {
+            for shift in [6, 4, 2, 0] {
+                if bases.len() == self.base_count {
+                    break;
+                }
+                bases.push(match (byte >> shift) & 0b11 {
+                    | 0b00 => DNABase::Adenine,
+                    | 0b01 => DNABase::Thymine,
+                    | 0b10 => DNABase::Guanine,
+                    | _ => DNABase::Cytosine,
+                });
+            }
+        }
+        bases
+    }
+}
+
+/// Split a length-prefixed section off the front of `rest`
+fn take_section<'a>(rest: &mut &'a [u8], name: &str) -> Result<&'a [u8], DNAError> {
+    let (len, tail) = rest
+        .split_first_chunk::<4>()
+        .ok_or_else(|| DNAError::InvalidContainer(format!("truncated {name} section length")))?;
+    let len = u32::from_le_bytes(*len) as usize;
+    if tail.len() < len {
+        return Err(DNAError::InvalidContainer(format!(
+            "{name} section needs {len} bytes, {} left",
+            tail.len()
+        )));
+    }
+    let (section, tail) = tail.split_at(len);
+    *rest = tail;
+    Ok(section)
+}
+
+/// Append `section` with its length prefix
+fn put_section(out: &mut Vec<u8>, section: &[u8]) -> Result<(), DNAError> {
+    let len = u32::try_from(section.len()).map_err(|_| {
+        DNAError::CompressionFailed(format!(
+            "container section of {} bytes exceeds 4 GiB",
+            section.len()
+        ))
+    })?;
+    out.extend_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(section);
+    Ok(())
+}
+
+impl CompressedDNA {
+    /// Serialize into the binary container format
+    pub fn to_container_bytes(&self) -> Result<Vec<u8>, DNAError> {
+        let sequence = &self.sequence;
+
+        let mut bases = Vec::with_capacity(8 + sequence.bases.len().div_ceil(4));
+        bases.extend_from_slice(&(sequence.bases.len() as u64).to_le_bytes());
+        bases.extend(sequence.bases.chunks(4).map(|group| {
+            group
+                .iter()
+                .zip([6, 4, 2, 0])
+                .fold(0u8, |byte, (base, shift)| byte | (base.to_bits() << shift))
+        }));
+
+        let metadata = bincode::serialize(&MetadataSection {
+            checksum: sequence.checksum,
+            original_length: sequence.original_length,
+            compressed_size: self.compressed_size,
+            metadata: &sequence.metadata,
+            chunks: &sequence.chunks,
+            metrics: &self.metrics,
+        })
+        .map_err(|e| DNAError::CompressionFailed(format!("Failed to encode metadata: {e}")))?;
+
+        let mut out = Vec::with_capacity(
+            HEADER_LEN + 12 + bases.len() + sequence.parity.len() + metadata.len(),
+        );
+        out.extend_from_slice(CONTAINER_MAGIC);
+        out.push(CONTAINER_VERSION);
+        out.push(ALGORITHM_QUATERNARY_RS);
+        put_section(&mut out, &bases)?;
+        put_section(&mut out, &sequence.parity)?;
+        put_section(&mut out, &metadata)?;
+        Ok(out)
+    }
+
+    /// Read a value written by [`to_container_bytes`](Self::to_container_bytes)
+    pub fn from_container_bytes(bytes: &[u8]) -> Result<Self, DNAError> {
+        let container = DNAContainer::parse(bytes)?;
+        let section: OwnedMetadataSection = if container.version == 1 {
+            bincode::deserialize::<OwnedMetadataSection<MetadataV1>>(container.metadata)
+                .map(Into::into)
+        } else {
+            bincode::deserialize(container.metadata)
+        }
+        .map_err(|e| DNAError::InvalidContainer(format!("unreadable metadata: {e}")))?;
+
+        Ok(Self {
+            sequence: DNASequence {
+                bases: container.bases(),
+                parity: container.parity.to_vec(),
+                checksum: section.checksum,
+                original_length: section.original_length,
+                metadata: section.metadata,
+                chunks: section.chunks,
+            },
+            compressed_size: section.compressed_size,
+            metrics: section.metrics,
+        })
+    }
+}
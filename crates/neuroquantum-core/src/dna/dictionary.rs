@@ -0,0 +1,183 @@
+//! Shared compression dictionaries
+//!
+//! The per-value dictionary built by
+//! [`QuaternaryEncoder::compress_with_dictionary`](crate::dna::QuaternaryEncoder::compress_with_dictionary)
+//! only sees one value and travels inside its metadata, so redundancy between
+//! values is never exploited and small values don't benefit at all. A
+//! [`DNADictionary`] is trained once on sample records and shared: compressed
+//! values reference it by [`DictionaryId`] and decompression looks it up in the
+//! compressor's [`DictionaryStore`].
+
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::dna::encoder::{
+    select_patterns, FIRST_DICT_ID, MAX_DICT_ID, MAX_PATTERN_LEN, MIN_PATTERN_LEN,
+};
+use crate::dna::DNAError;
+
+/// Identifier of a shared dictionary, derived from its patterns
+pub type DictionaryId = u32;
+
+/// A pattern dictionary trained on sample records
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DNADictionary {
+    id: DictionaryId,
+    /// Patterns in ID order, the first one having ID 256
+    entries: Vec<Vec<u8>>,
+    #[serde(skip)]
+    patterns: HashMap<Vec<u8>, u16>,
+}
+
+impl DNADictionary {
+    /// Train a dictionary of at most `max_size / 32` patterns on `samples`
+    ///
+    /// Patterns are counted across all samples, so a field name that appears
+    /// once in each of many small records is picked up.
+    pub fn train(samples: &[&[u8]], max_size: usize) -> Result<Self, DNAError> {
+        let mut pattern_freq = HashMap::new();
+        for sample in samples {
+            for len in MIN_PATTERN_LEN..=MAX_PATTERN_LEN.min(sample.len()) {
+                for window in sample.windows(len) {
+                    *pattern_freq.entry(window.to_vec()).or_insert(0usize) += 1;
+                }
+            }
+        }
+
+        let entries = select_patterns(pattern_freq, max_size / 32);
+        if entries.is_empty() {
+            return Err(DNAError::InvalidDictionary(
+                "no repeated patterns in training samples".to_string(),
+            ));
+        }
+        Ok(Self::from_entries(entries))
+    }
+
+    /// Build a dictionary from patterns in ID order
+    fn from_entries(entries: Vec<Vec<u8>>) -> Self {
+        let patterns = entries
+            .iter()
+            .cloned()
+            .zip(FIRST_DICT_ID..=MAX_DICT_ID)
+            .collect();
+        Self {
+            id: Self::compute_id(&entries),
+            entries,
+            patterns,
+        }
+    }
+
+    /// Checksum of the patterns, identifying the dictionary
+    fn compute_id(entries: &[Vec<u8>]) -> DictionaryId {
+        let mut hasher = crc32fast::Hasher::new();
+        for entry in entries {
+            hasher.update(&(entry.len() as u32).to_le_bytes());
+            hasher.update(entry);
+        }
+        hasher.finalize()
+    }
+
+    /// Identifier stored in the metadata of values compressed with this dictionary
+    #[must_use]
+    pub const fn id(&self) -> DictionaryId {
+        self.id
+    }
+
+    /// Number of patterns
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the dictionary has no patterns
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Pattern to dictionary ID mapping used by the encoder and decoder
+    #[must_use]
+    pub const fn patterns(&self) -> &HashMap<Vec<u8>, u16> {
+        &self.patterns
+    }
+
+    /// Serialize the dictionary for persistence
+    pub fn export(&self) -> Result<Vec<u8>, DNAError> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    /// Load a dictionary written by [`export`](Self::export)
+    ///
+    /// Fails if the patterns don't match the recorded ID, which would make
+    /// values compressed with the original dictionary decode incorrectly.
+    pub fn import(bytes: &[u8]) -> Result<Self, DNAError> {
+        let exported: Self = serde_json::from_slice(bytes)?;
+        let dictionary = Self::from_entries(exported.entries);
+        if dictionary.id != exported.id {
+            return Err(DNAError::InvalidDictionary(format!(
+                "dictionary {:08x} does not match its patterns ({:08x})",
+                exported.id, dictionary.id
+            )));
+        }
+        Ok(dictionary)
+    }
+}
+
+/// Dictionaries known to a compressor
+///
+/// Cloning the store shares it, so clones of a compressor use the same
+/// dictionaries.
+#[derive(Debug, Clone, Default)]
+pub struct DictionaryStore {
+    inner: Arc<RwLock<DictionaryStoreInner>>,
+}
+
+#[derive(Debug, Default)]
+struct DictionaryStoreInner {
+    /// Dictionary used for new compressions
+    active: Option<DictionaryId>,
+    dictionaries: HashMap<DictionaryId, Arc<DNADictionary>>,
+}
+
+impl DictionaryStore {
+    /// Register `dictionary` and use it for subsequent compressions
+    ///
+    /// Previously active dictionaries stay available for decompression.
+    pub fn activate(&self, dictionary: DNADictionary) -> DictionaryId {
+        let id = dictionary.id();
+        let mut inner = self.inner.write().unwrap_or_else(PoisonError::into_inner);
+        inner.dictionaries.insert(id, Arc::new(dictionary));
+        inner.active = Some(id);
+        id
+    }
+
+    /// Dictionary used for new compressions, if any
+    #[must_use]
+    pub fn active(&self) -> Option<Arc<DNADictionary>> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        inner
+            .active
+            .and_then(|id| inner.dictionaries.get(&id).cloned())
+    }
+
+    /// Look up a registered dictionary
+    #[must_use]
+    pub fn get(&self, id: DictionaryId) -> Option<Arc<DNADictionary>> {
+        self.inner
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .dictionaries
+            .get(&id)
+            .cloned()
+    }
+
+    /// Stop using a dictionary for new compressions
+    pub fn deactivate(&self) {
+        self.inner
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .active = None;
+    }
+}
@@ -8,7 +8,22 @@ use std::collections::HashMap;
 use rayon::prelude::*;
 use tracing::{debug, instrument};
 
-use crate::dna::{DNABase, DNACompressionConfig, DNAError};
+use crate::dna::{DNABase, DNACompressionConfig, DNADictionary, DNAError};
+
+/// Shortest pattern replaced by a dictionary reference
+pub(crate) const MIN_PATTERN_LEN: usize = 4;
+
+/// Longest pattern replaced by a dictionary reference
+pub(crate) const MAX_PATTERN_LEN: usize = 32;
+
+/// Occurrences a pattern needs before it enters a dictionary
+pub(crate) const MIN_PATTERN_FREQUENCY: usize = 3;
+
+/// First dictionary ID; lower values are single bytes
+pub(crate) const FIRST_DICT_ID: u16 = 256;
+
+/// Last dictionary ID; 0xFF00-0xFFFF would be read as escape sequences
+pub(crate) const MAX_DICT_ID: u16 = 0xFEFF;
 
 /// Quaternary encoder that converts binary data to DNA bases
 #[derive(Debug)]
@@ -38,11 +53,9 @@ impl QuaternaryEncoder {
 
         // Build frequency map of byte patterns
         let mut pattern_freq = HashMap::new();
-        let min_pattern_len = 4;
-        let max_pattern_len = 32;
 
         // Use parallel processing to analyze patterns
-        let patterns: Vec<_> = (min_pattern_len..=max_pattern_len.min(data.len()))
+        let patterns: Vec<_> = (MIN_PATTERN_LEN..=MAX_PATTERN_LEN.min(data.len()))
             .into_par_iter()
             .flat_map(|len| {
                 data.windows(len)
@@ -57,33 +70,12 @@ impl QuaternaryEncoder {
             *pattern_freq.entry(pattern).or_insert(0usize) += 1;
         }
 
-        // Select most frequent patterns for dictionary
-        let mut frequent_patterns: Vec<_> = pattern_freq
-            .into_iter()
-            .filter(|(pattern, freq)| *freq >= 3 && pattern.len() >= min_pattern_len)
-            .collect();
-
-        frequent_patterns.sort_by_key(|(pattern, freq)| {
-            std::cmp::Reverse(*freq * pattern.len()) // Prioritize by space savings
-        });
-
         // Build dictionary (limited size)
-        // Dictionary IDs start at 256 and must not have high-byte 0xFF (reserved for escaping)
-        // Valid range: 256 (0x0100) to 65278 (0xFEFF), excluding 0xFF00-0xFFFF
-        let mut dictionary = HashMap::new();
-        let mut dict_id = 256u16; // Start after single-byte values
-        let max_dict_id = 0xFEFFu16; // Avoid 0xFF00-0xFFFF range (used for escape sequence)
-
-        for (pattern, _) in frequent_patterns
-            .iter()
-            .take(self.config.max_dictionary_size / 32)
-        {
-            if dict_id > max_dict_id {
-                break;
-            }
-            dictionary.insert(pattern.clone(), dict_id);
-            dict_id += 1;
-        }
+        let dictionary: HashMap<Vec<u8>, u16> =
+            select_patterns(pattern_freq, self.config.max_dictionary_size / 32)
+                .into_iter()
+                .zip(FIRST_DICT_ID..=MAX_DICT_ID)
+                .collect();
 
         if dictionary.is_empty() {
             return Ok(data.to_vec());
@@ -92,45 +84,26 @@ impl QuaternaryEncoder {
         debug!("Built dictionary with {} patterns", dictionary.len());
 
         // Apply dictionary compression
-        let mut compressed = Vec::with_capacity(data.len());
-        let mut i = 0;
-
-        while i < data.len() {
-            let mut matched = false;
-
-            // Try to match longest pattern first
-            for len in (min_pattern_len..=max_pattern_len.min(data.len() - i)).rev() {
-                if i + len <= data.len() {
-                    let pattern = &data[i..i + len];
-                    if let Some(&dict_id) = dictionary.get(pattern) {
-                        // Encode as dictionary reference: [0xFF][dict_id_high][dict_id_low]
-                        compressed.push(0xFF);
-                        compressed.push((dict_id >> 8) as u8);
-                        compressed.push(dict_id as u8);
-                        i += len;
-                        matched = true;
-                        break;
-                    }
-                }
-            }
-
-            if !matched {
-                // Copy literal byte, but escape 0xFF to avoid confusion with dictionary references
-                // 0xFF followed by 0xFF means a literal 0xFF byte
-                if data[i] == 0xFF {
-                    compressed.push(0xFF);
-                    compressed.push(0xFF);
-                } else {
-                    compressed.push(data[i]);
-                }
-                i += 1;
-            }
-        }
+        let compressed = apply_dictionary(data, &dictionary);
 
         self.dictionary = Some(dictionary);
         Ok(compressed)
     }
 
+    /// Replace patterns of a shared dictionary with references to it
+    ///
+    /// Unlike [`compress_with_dictionary`](Self::compress_with_dictionary)
+    /// this also applies to small values, which is where a dictionary trained
+    /// on other records pays off.
+    #[must_use]
+    pub fn compress_with_shared_dictionary(
+        &self,
+        data: &[u8],
+        dictionary: &DNADictionary,
+    ) -> Vec<u8> {
+        apply_dictionary(data, dictionary.patterns())
+    }
+
     /// Convert binary data to DNA bases using quaternary encoding
     #[instrument(skip(self, data))]
     pub async fn encode_to_bases(&self, data: &[u8]) -> Result<Vec<DNABase>, DNAError> {
@@ -282,3 +255,72 @@ impl QuaternaryEncoder {
         repeated_bytes as f64
     }
 }
+
+/// Pick the patterns saving the most space, at most `limit` of them
+///
+/// Patterns occurring fewer than [`MIN_PATTERN_FREQUENCY`] times are skipped.
+/// The result is ordered by savings, ties broken by the pattern bytes, so the
+/// same frequencies always yield the same dictionary.
+pub(crate) fn select_patterns(pattern_freq: HashMap<Vec<u8>, usize>, limit: usize) -> Vec<Vec<u8>> {
+    let mut frequent_patterns: Vec<_> = pattern_freq
+        .into_iter()
+        .filter(|(pattern, freq)| {
+            *freq >= MIN_PATTERN_FREQUENCY && pattern.len() >= MIN_PATTERN_LEN
+        })
+        .collect();
+
+    // Prioritize by space savings
+    frequent_patterns.sort_by(|(a, a_freq), (b, b_freq)| {
+        (b_freq * b.len())
+            .cmp(&(a_freq * a.len()))
+            .then_with(|| a.cmp(b))
+    });
+
+    let max_entries = usize::from(MAX_DICT_ID - FIRST_DICT_ID) + 1;
+    frequent_patterns
+        .into_iter()
+        .take(limit.min(max_entries))
+        .map(|(pattern, _)| pattern)
+        .collect()
+}
+
+/// Replace dictionary patterns in `data` with `[0xFF][id_high][id_low]`
+///
+/// The longest matching pattern wins. Literal 0xFF bytes are escaped as
+/// `[0xFF][0xFF]`.
+pub(crate) fn apply_dictionary(data: &[u8], dictionary: &HashMap<Vec<u8>, u16>) -> Vec<u8> {
+    let mut compressed = Vec::with_capacity(data.len());
+    let mut i = 0;
+
+    while i < data.len() {
+        let mut matched = false;
+
+        // Try to match longest pattern first
+        for len in (MIN_PATTERN_LEN..=MAX_PATTERN_LEN.min(data.len() - i)).rev() {
+            let pattern = &data[i..i + len];
+            if let Some(&dict_id) = dictionary.get(pattern) {
+                // Encode as dictionary reference: [0xFF][dict_id_high][dict_id_low]
+                compressed.push(0xFF);
+                compressed.push((dict_id >> 8) as u8);
+                compressed.push(dict_id as u8);
+                i += len;
+                matched = true;
+                break;
+            }
+        }
+
+        if !matched {
+            // Copy literal byte, but escape 0xFF to avoid confusion with dictionary references
+            // 0xFF followed by 0xFF means a literal 0xFF byte
+            if data[i] == 0xFF {
+                compressed.push(0xFF);
+                compressed.push(0xFF);
+            } else {
+                compressed.push(data[i]);
+            }
+            i += 1;
+        }
+    }
+
+    compressed
+}
@@ -0,0 +1,89 @@
+//! Per-operation telemetry for the DNA compressor
+//!
+//! [`QuantumDNACompressor::get_metrics`](crate::dna::DNACompressor::get_metrics)
+//! only keeps the figures of the most recent operation, so a monitoring
+//! pipeline would have to poll and would still miss operations. A
+//! [`CompressionObserver`] registered with
+//! [`QuantumDNACompressor::set_observer`](crate::dna::QuantumDNACompressor::set_observer)
+//! is instead handed a [`CompressionEvent`] after every successful compression
+//! and decompression.
+
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Direction of a reported operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompressionOperation {
+    Compress,
+    Decompress,
+}
+
+/// Figures of one compression or decompression
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompressionEvent {
+    pub operation: CompressionOperation,
+    /// Bytes passed in: the original data when compressing, the compressed
+    /// size when decompressing
+    pub input_size: usize,
+    /// Bytes produced: the compressed size when compressing, the original
+    /// data when decompressing
+    pub output_size: usize,
+    /// Compressed size divided by original size
+    pub compression_ratio: f64,
+    /// Wall-clock time of the operation
+    pub duration: Duration,
+    /// Reed-Solomon parity bytes included in the compressed size
+    pub error_correction_bytes: usize,
+    /// Errors repaired by error correction (always 0 when compressing)
+    pub errors_corrected: usize,
+}
+
+/// Receives a [`CompressionEvent`] after each compressor operation
+///
+/// Called on the task that ran the operation, so implementations should hand
+/// events off rather than block.
+pub trait CompressionObserver: Send + Sync {
+    fn on_compress(&self, event: CompressionEvent);
+}
+
+/// Observer slot shared by clones of a compressor
+#[derive(Clone, Default)]
+pub(crate) struct ObserverSlot {
+    inner: Arc<RwLock<Option<Arc<dyn CompressionObserver>>>>,
+}
+
+impl ObserverSlot {
+    pub(crate) fn set(&self, observer: Option<Arc<dyn CompressionObserver>>) {
+        *self.inner.write().unwrap_or_else(PoisonError::into_inner) = observer;
+    }
+
+    pub(crate) fn is_set(&self) -> bool {
+        self.inner
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_some()
+    }
+
+    /// Report `event` to the registered observer, if any
+    pub(crate) fn notify(&self, event: impl FnOnce() -> CompressionEvent) {
+        let observer = self
+            .inner
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        if let Some(observer) = observer {
+            observer.on_compress(event());
+        }
+    }
+}
+
+impl std::fmt::Debug for ObserverSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObserverSlot")
+            .field("registered", &self.is_set())
+            .finish()
+    }
+}
@@ -6,7 +6,8 @@
 #![cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
 
 use std::arch::aarch64::{
-    __crc32b, __crc32d, vceqq_u8, vdupq_n_u8, veorq_u8, vgetq_lane_u8, vld1q_u8,
+    __crc32b, __crc32d, vandq_u8, vceqq_u8, vdupq_n_u8, veorq_u8, vgetq_lane_u8, vld1q_u8,
+    vmaxvq_u8, vst1q_u8,
 };
 
 use crate::dna::{DNABase, DNAError};
@@ -140,19 +141,23 @@ unsafe fn decode_64_bases_neon(chunk: &[DNABase], output: &mut Vec<u8>) -> Resul
 
 /// NEON-optimized pattern matching for dictionary compression
 ///
+/// Needles longer than 16 bytes go through [`find_long_pattern_neon`].
+///
 /// # Safety
 /// This function requires ARM64 NEON support. The caller must ensure:
 /// - The CPU supports NEON instructions
 /// - Input slices are valid and properly aligned
-/// - Needle length is <= 16 bytes
 #[target_feature(enable = "neon")]
 #[must_use]
 pub unsafe fn find_pattern_neon(haystack: &[u8], needle: &[u8]) -> Vec<usize> {
     let mut matches = Vec::new();
 
-    if needle.len() > 16 || needle.is_empty() {
+    if needle.is_empty() {
         return matches;
     }
+    if needle.len() > 16 {
+        return find_long_pattern_neon(haystack, needle);
+    }
 
     // Load needle into NEON register (pad with zeros if needed)
     let mut needle_padded = [0u8; 16];
@@ -188,6 +193,63 @@ pub unsafe fn find_pattern_neon(haystack: &[u8], needle: &[u8]) -> Vec<usize> {
     matches
 }
 
+/// NEON-optimized pattern matching for needles of any length
+///
+/// Compares the needle's first and last byte against 16 candidate positions
+/// at once and only verifies the full needle where both match.
+///
+/// # Safety
+/// This function requires ARM64 NEON support. The caller must ensure:
+/// - The CPU supports NEON instructions
+/// - Input slices are valid
+#[target_feature(enable = "neon")]
+#[must_use]
+pub unsafe fn find_long_pattern_neon(haystack: &[u8], needle: &[u8]) -> Vec<usize> {
+    let mut matches = Vec::new();
+
+    if needle.is_empty() || haystack.len() < needle.len() {
+        return matches;
+    }
+
+    let last = needle.len() - 1;
+    let first_vec = vdupq_n_u8(needle[0]);
+    let last_vec = vdupq_n_u8(needle[last]);
+    let mut lanes = [0u8; 16];
+
+    // Each block tests the 16 candidate starts pos..pos + 16
+    let mut pos = 0;
+    while pos + last + 16 <= haystack.len() {
+        let first_block = vld1q_u8(haystack.as_ptr().add(pos));
+        let last_block = vld1q_u8(haystack.as_ptr().add(pos + last));
+        let candidates = vandq_u8(
+            vceqq_u8(first_block, first_vec),
+            vceqq_u8(last_block, last_vec),
+        );
+
+        if vmaxvq_u8(candidates) != 0 {
+            vst1q_u8(lanes.as_mut_ptr(), candidates);
+            for (offset, _) in lanes.iter().enumerate().filter(|(_, &lane)| lane != 0) {
+                let start = pos + offset;
+                if haystack[start + 1..start + needle.len()] == needle[1..] {
+                    matches.push(start);
+                }
+            }
+        }
+
+        pos += 16;
+    }
+
+    // Handle remainder with scalar search
+    while pos + needle.len() <= haystack.len() {
+        if &haystack[pos..pos + needle.len()] == needle {
+            matches.push(pos);
+        }
+        pos += 1;
+    }
+
+    matches
+}
+
 /// Calculate Hamming distance between DNA sequences using NEON
 ///
 /// # Safety
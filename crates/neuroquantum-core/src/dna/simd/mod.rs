@@ -2,6 +2,14 @@
 //!
 //! This module provides SIMD-optimized implementations for DNA compression operations
 //! targeting ARM64 NEON and `x86_64` AVX2 instruction sets.
+//!
+//! The instruction set is detected at runtime. Benchmarks and bug reproductions
+//! can pin it with a [`SimdOverride`], either programmatically through
+//! [`SimdCapabilities::with_override`] or by setting the
+//! [`SIMD_OVERRIDE_ENV`] environment variable to `scalar`, `avx2` or `neon`.
+
+use std::fmt;
+use std::str::FromStr;
 
 use crate::dna::{DNABase, DNAError};
 
@@ -186,6 +194,62 @@ pub fn detect_neon_capabilities() -> NeonCapabilities {
     }
 }
 
+/// Environment variable pinning the SIMD code path, see [`SimdOverride`]
+pub const SIMD_OVERRIDE_ENV: &str = "NEUROQUANTUM_SIMD";
+
+/// Code path the SIMD operations are pinned to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SimdOverride {
+    /// Use the best instruction set the CPU supports
+    #[default]
+    Auto,
+    /// Use the scalar implementations only
+    ForceScalar,
+    /// Use AVX2, failing if the CPU lacks it
+    ForceAvx2,
+    /// Use NEON, failing if the CPU lacks it
+    ForceNeon,
+}
+
+impl SimdOverride {
+    /// Read the override from [`SIMD_OVERRIDE_ENV`]
+    ///
+    /// An unset or empty variable means [`SimdOverride::Auto`].
+    pub fn from_env() -> Result<Self, DNAError> {
+        match std::env::var(SIMD_OVERRIDE_ENV) {
+            | Ok(value) if !value.trim().is_empty() => value.parse(),
+            | _ => Ok(Self::Auto),
+        }
+    }
+}
+
+impl FromStr for SimdOverride {
+    type Err = DNAError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            | "auto" => Ok(Self::Auto),
+            | "scalar" => Ok(Self::ForceScalar),
+            | "avx2" => Ok(Self::ForceAvx2),
+            | "neon" => Ok(Self::ForceNeon),
+            | other => Err(DNAError::SimdError(format!(
+                "unknown SIMD override '{other}', expected auto, scalar, avx2 or neon"
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for SimdOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            | Self::Auto => "auto",
+            | Self::ForceScalar => "scalar",
+            | Self::ForceAvx2 => "avx2",
+            | Self::ForceNeon => "neon",
+        })
+    }
+}
+
 /// SIMD capability detection and dispatch
 #[derive(Debug, Clone)]
 pub struct SimdCapabilities {
@@ -229,6 +293,55 @@ impl SimdCapabilities {
         caps
     }
 
+    /// Capabilities restricted to the code path chosen by `simd_override`
+    ///
+    /// Forcing an instruction set the CPU doesn't support is an error rather
+    /// than a silent fallback to scalar code.
+    pub fn with_override(simd_override: SimdOverride) -> Result<Self, DNAError> {
+        let detected = Self::detect();
+        let unavailable = || {
+            DNAError::SimdError(format!(
+                "SIMD override '{simd_override}' requested but not supported by this CPU"
+            ))
+        };
+        match simd_override {
+            | SimdOverride::Auto => Ok(detected),
+            | SimdOverride::ForceScalar => Ok(Self::scalar()),
+            | SimdOverride::ForceAvx2 if detected.has_avx2 => Ok(Self {
+                has_neon: false,
+                vector_width: 32,
+                ..detected
+            }),
+            | SimdOverride::ForceNeon if detected.has_neon => Ok(Self {
+                has_avx2: false,
+                has_sse42: false,
+                vector_width: 16,
+                ..detected
+            }),
+            | SimdOverride::ForceAvx2 | SimdOverride::ForceNeon => Err(unavailable()),
+        }
+    }
+
+    /// Capabilities selected by [`SIMD_OVERRIDE_ENV`]
+    ///
+    /// The `new` constructors of the encoder, decoder and pattern matcher
+    /// start from these. An unknown value, or an instruction set the CPU
+    /// lacks, is an error rather than a fallback to the detected path.
+    pub fn from_env() -> Result<Self, DNAError> {
+        Self::with_override(SimdOverride::from_env()?)
+    }
+
+    /// Capabilities without any SIMD instruction set
+    #[must_use]
+    pub const fn scalar() -> Self {
+        Self {
+            has_neon: false,
+            has_avx2: false,
+            has_sse42: false,
+            vector_width: 1,
+        }
+    }
+
     /// Get the optimal chunk size for SIMD operations
     #[must_use]
     pub const fn optimal_chunk_size(&self) -> usize {
@@ -242,12 +355,28 @@ pub struct SimdEncoder {
 }
 
 impl SimdEncoder {
-    /// Create a new SIMD encoder
+    /// Create a new SIMD encoder, see [`SimdCapabilities::from_env`]
+    pub fn new() -> Result<Self, DNAError> {
+        Ok(Self::with_capabilities(SimdCapabilities::from_env()?))
+    }
+
+    /// Create an encoder pinned to the code path chosen by `simd_override`
+    pub fn with_override(simd_override: SimdOverride) -> Result<Self, DNAError> {
+        Ok(Self::with_capabilities(SimdCapabilities::with_override(
+            simd_override,
+        )?))
+    }
+
+    /// Create an encoder using `capabilities`
     #[must_use]
-    pub fn new() -> Self {
-        Self {
-            capabilities: SimdCapabilities::detect(),
-        }
+    pub const fn with_capabilities(capabilities: SimdCapabilities) -> Self {
+        Self { capabilities }
+    }
+
+    /// Capabilities selecting the code path
+    #[must_use]
+    pub const fn capabilities(&self) -> &SimdCapabilities {
+        &self.capabilities
     }
 
     /// Encode bytes to DNA bases using SIMD when available
@@ -321,12 +450,28 @@ pub struct SimdDecoder {
 }
 
 impl SimdDecoder {
-    /// Create a new SIMD decoder
+    /// Create a new SIMD decoder, see [`SimdCapabilities::from_env`]
+    pub fn new() -> Result<Self, DNAError> {
+        Ok(Self::with_capabilities(SimdCapabilities::from_env()?))
+    }
+
+    /// Create a decoder pinned to the code path chosen by `simd_override`
+    pub fn with_override(simd_override: SimdOverride) -> Result<Self, DNAError> {
+        Ok(Self::with_capabilities(SimdCapabilities::with_override(
+            simd_override,
+        )?))
+    }
+
+    /// Create a decoder using `capabilities`
     #[must_use]
-    pub fn new() -> Self {
-        Self {
-            capabilities: SimdCapabilities::detect(),
-        }
+    pub const fn with_capabilities(capabilities: SimdCapabilities) -> Self {
+        Self { capabilities }
+    }
+
+    /// Capabilities selecting the code path
+    #[must_use]
+    pub const fn capabilities(&self) -> &SimdCapabilities {
+        &self.capabilities
     }
 
     /// Decode DNA bases to bytes using SIMD when available
@@ -408,15 +553,34 @@ pub struct SimdPatternMatcher {
 }
 
 impl SimdPatternMatcher {
-    /// Create a new SIMD pattern matcher
+    /// Create a new SIMD pattern matcher, see [`SimdCapabilities::from_env`]
+    pub fn new() -> Result<Self, DNAError> {
+        Ok(Self::with_capabilities(SimdCapabilities::from_env()?))
+    }
+
+    /// Create a pattern matcher pinned to the code path chosen by `simd_override`
+    pub fn with_override(simd_override: SimdOverride) -> Result<Self, DNAError> {
+        Ok(Self::with_capabilities(SimdCapabilities::with_override(
+            simd_override,
+        )?))
+    }
+
+    /// Create a pattern matcher using `capabilities`
     #[must_use]
-    pub fn new() -> Self {
-        Self {
-            capabilities: SimdCapabilities::detect(),
-        }
+    pub const fn with_capabilities(capabilities: SimdCapabilities) -> Self {
+        Self { capabilities }
+    }
+
+    /// Capabilities selecting the code path
+    #[must_use]
+    pub const fn capabilities(&self) -> &SimdCapabilities {
+        &self.capabilities
     }
 
     /// Find pattern occurrences using SIMD string matching
+    ///
+    /// Needles longer than a vector are located by a vectorized scan for
+    /// their first and last byte, verifying each candidate in full.
     #[must_use]
     pub fn find_pattern_occurrences(&self, haystack: &[u8], needle: &[u8]) -> Vec<usize> {
         if needle.is_empty() || haystack.len() < needle.len() {
@@ -427,14 +591,14 @@ impl SimdPatternMatcher {
 
         #[cfg(target_arch = "aarch64")]
         {
-            if self.capabilities.has_neon && needle.len() <= 16 {
+            if self.capabilities.has_neon {
                 return unsafe { arm64_neon::find_pattern_neon(haystack, needle) };
             }
         }
 
         #[cfg(target_arch = "x86_64")]
         {
-            if self.capabilities.has_avx2 && needle.len() <= 32 {
+            if self.capabilities.has_avx2 {
                 return unsafe { x86_avx2::find_pattern_avx2(haystack, needle) };
             }
         }
@@ -550,7 +714,7 @@ pub mod utils {
             });
         }
 
-        let capabilities = SimdCapabilities::detect();
+        let capabilities = SimdCapabilities::from_env()?;
 
         #[cfg(target_arch = "aarch64")]
         {
@@ -570,21 +734,3 @@ pub mod utils {
         Ok(seq1.iter().zip(seq2.iter()).filter(|(a, b)| a != b).count())
     }
 }
-
-impl Default for SimdEncoder {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl Default for SimdDecoder {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl Default for SimdPatternMatcher {
-    fn default() -> Self {
-        Self::new()
-    }
-}
@@ -142,7 +142,7 @@ mod encoder_decoder_tests {
 
     #[test]
     fn test_simd_encoder_creation() {
-        let encoder = SimdEncoder::new();
+        let encoder = SimdEncoder::new().unwrap();
         let caps = &encoder.capabilities;
 
         // At least one architecture should be detected on any modern system
@@ -152,14 +152,14 @@ mod encoder_decoder_tests {
 
     #[test]
     fn test_simd_decoder_creation() {
-        let decoder = SimdDecoder::new();
+        let decoder = SimdDecoder::new().unwrap();
         let caps = &decoder.capabilities;
         assert!(caps.vector_width >= 1);
     }
 
     #[test]
     fn test_encode_empty_input() {
-        let encoder = SimdEncoder::new();
+        let encoder = SimdEncoder::new().unwrap();
         let mut output = Vec::new();
 
         encoder.encode_bytes_to_bases(&[], &mut output).unwrap();
@@ -168,7 +168,7 @@ mod encoder_decoder_tests {
 
     #[test]
     fn test_decode_empty_input() {
-        let decoder = SimdDecoder::new();
+        let decoder = SimdDecoder::new().unwrap();
         let mut output = Vec::new();
 
         decoder.decode_bases_to_bytes(&[], &mut output).unwrap();
@@ -177,7 +177,7 @@ mod encoder_decoder_tests {
 
     #[test]
     fn test_encode_single_byte() {
-        let encoder = SimdEncoder::new();
+        let encoder = SimdEncoder::new().unwrap();
         let input = vec![0b10110011u8];
         let mut output = Vec::new();
 
@@ -197,7 +197,7 @@ mod encoder_decoder_tests {
 
     #[test]
     fn test_decode_single_byte() {
-        let decoder = SimdDecoder::new();
+        let decoder = SimdDecoder::new().unwrap();
         let input = vec![
             DNABase::Guanine,  // 10
             DNABase::Cytosine, // 11
@@ -215,8 +215,8 @@ mod encoder_decoder_tests {
 
     #[test]
     fn test_encode_decode_roundtrip_small() {
-        let encoder = SimdEncoder::new();
-        let decoder = SimdDecoder::new();
+        let encoder = SimdEncoder::new().unwrap();
+        let decoder = SimdDecoder::new().unwrap();
 
         for size in [1, 2, 3, 4, 7, 8, 15, 16, 17, 31, 32, 33] {
             let input = create_random_bytes(size, size as u64);
@@ -234,8 +234,8 @@ mod encoder_decoder_tests {
 
     #[test]
     fn test_encode_decode_roundtrip_large() {
-        let encoder = SimdEncoder::new();
-        let decoder = SimdDecoder::new();
+        let encoder = SimdEncoder::new().unwrap();
+        let decoder = SimdDecoder::new().unwrap();
 
         for size in [64, 128, 256, 512, 1024, 4096] {
             let input = create_random_bytes(size, size as u64);
@@ -253,7 +253,7 @@ mod encoder_decoder_tests {
 
     #[test]
     fn test_encode_simd_matches_scalar() {
-        let encoder = SimdEncoder::new();
+        let encoder = SimdEncoder::new().unwrap();
 
         // Test various sizes that exercise different code paths
         for size in [1, 4, 8, 16, 32, 64, 100, 127, 128, 129, 255, 256, 257] {
@@ -274,7 +274,7 @@ mod encoder_decoder_tests {
 
     #[test]
     fn test_decode_simd_matches_scalar() {
-        let decoder = SimdDecoder::new();
+        let decoder = SimdDecoder::new().unwrap();
 
         // Create bases in multiples of 4
         for num_bytes in [1, 4, 8, 16, 32, 64, 100, 127, 128, 129] {
@@ -298,7 +298,7 @@ mod encoder_decoder_tests {
 
     #[test]
     fn test_decode_invalid_length() {
-        let decoder = SimdDecoder::new();
+        let decoder = SimdDecoder::new().unwrap();
 
         // Lengths not divisible by 4 should fail
         for invalid_len in [1, 2, 3, 5, 6, 7, 9, 10, 11] {
@@ -312,7 +312,7 @@ mod encoder_decoder_tests {
 
     #[test]
     fn test_batch_encode() {
-        let encoder = SimdEncoder::new();
+        let encoder = SimdEncoder::new().unwrap();
 
         let input = create_random_bytes(1000, 12345);
         let result = encoder.batch_encode(&input).unwrap();
@@ -323,7 +323,7 @@ mod encoder_decoder_tests {
 
     #[test]
     fn test_batch_decode() {
-        let decoder = SimdDecoder::new();
+        let decoder = SimdDecoder::new().unwrap();
 
         let input_bytes = create_random_bytes(250, 54321);
         let bases = scalar_encode(&input_bytes).unwrap();
@@ -334,7 +334,7 @@ mod encoder_decoder_tests {
 
     #[test]
     fn test_all_byte_values_encode() {
-        let encoder = SimdEncoder::new();
+        let encoder = SimdEncoder::new().unwrap();
 
         // Test all 256 possible byte values
         let input: Vec<u8> = (0..=255).collect();
@@ -349,8 +349,8 @@ mod encoder_decoder_tests {
 
     #[test]
     fn test_all_byte_values_roundtrip() {
-        let encoder = SimdEncoder::new();
-        let decoder = SimdDecoder::new();
+        let encoder = SimdEncoder::new().unwrap();
+        let decoder = SimdDecoder::new().unwrap();
 
         let input: Vec<u8> = (0..=255).collect();
         let mut encoded = Vec::new();
@@ -366,7 +366,7 @@ mod encoder_decoder_tests {
 
     #[test]
     fn test_pattern_bytes_encode() {
-        let encoder = SimdEncoder::new();
+        let encoder = SimdEncoder::new().unwrap();
 
         // Test specific patterns
         let patterns: Vec<Vec<u8>> = vec![
@@ -400,35 +400,35 @@ mod pattern_matcher_tests {
 
     #[test]
     fn test_pattern_matcher_creation() {
-        let matcher = SimdPatternMatcher::new();
+        let matcher = SimdPatternMatcher::new().unwrap();
         let caps = &matcher.capabilities;
         assert!(caps.vector_width >= 1);
     }
 
     #[test]
     fn test_find_pattern_empty_haystack() {
-        let matcher = SimdPatternMatcher::new();
+        let matcher = SimdPatternMatcher::new().unwrap();
         let result = matcher.find_pattern_occurrences(&[], b"test");
         assert!(result.is_empty());
     }
 
     #[test]
     fn test_find_pattern_empty_needle() {
-        let matcher = SimdPatternMatcher::new();
+        let matcher = SimdPatternMatcher::new().unwrap();
         let result = matcher.find_pattern_occurrences(b"test", &[]);
         assert!(result.is_empty());
     }
 
     #[test]
     fn test_find_pattern_needle_longer_than_haystack() {
-        let matcher = SimdPatternMatcher::new();
+        let matcher = SimdPatternMatcher::new().unwrap();
         let result = matcher.find_pattern_occurrences(b"abc", b"abcdef");
         assert!(result.is_empty());
     }
 
     #[test]
     fn test_find_pattern_single_match() {
-        let matcher = SimdPatternMatcher::new();
+        let matcher = SimdPatternMatcher::new().unwrap();
         let haystack = b"hello world";
         let needle = b"world";
 
@@ -441,7 +441,7 @@ mod pattern_matcher_tests {
 
     #[test]
     fn test_find_pattern_multiple_matches() {
-        let matcher = SimdPatternMatcher::new();
+        let matcher = SimdPatternMatcher::new().unwrap();
         let haystack = b"abcabcabc";
         let needle = b"abc";
 
@@ -454,7 +454,7 @@ mod pattern_matcher_tests {
 
     #[test]
     fn test_find_pattern_overlapping() {
-        let matcher = SimdPatternMatcher::new();
+        let matcher = SimdPatternMatcher::new().unwrap();
         let haystack = b"aaaaaa";
         let needle = b"aa";
 
@@ -467,7 +467,7 @@ mod pattern_matcher_tests {
 
     #[test]
     fn test_find_pattern_no_match() {
-        let matcher = SimdPatternMatcher::new();
+        let matcher = SimdPatternMatcher::new().unwrap();
         let haystack = b"hello world";
         let needle = b"xyz";
 
@@ -477,7 +477,7 @@ mod pattern_matcher_tests {
 
     #[test]
     fn test_find_pattern_at_boundaries() {
-        let matcher = SimdPatternMatcher::new();
+        let matcher = SimdPatternMatcher::new().unwrap();
 
         // Match at start
         let result = matcher.find_pattern_occurrences(b"hello", b"hel");
@@ -494,7 +494,7 @@ mod pattern_matcher_tests {
 
     #[test]
     fn test_find_pattern_single_char() {
-        let matcher = SimdPatternMatcher::new();
+        let matcher = SimdPatternMatcher::new().unwrap();
         let haystack = b"abcabc";
         let needle = b"b";
 
@@ -507,7 +507,7 @@ mod pattern_matcher_tests {
 
     #[test]
     fn test_find_pattern_large_haystack() {
-        let matcher = SimdPatternMatcher::new();
+        let matcher = SimdPatternMatcher::new().unwrap();
 
         // Create large haystack with known patterns
         let mut haystack = vec![0u8; 1000];
@@ -528,7 +528,7 @@ mod pattern_matcher_tests {
 
     #[test]
     fn test_find_pattern_simd_matches_scalar() {
-        let matcher = SimdPatternMatcher::new();
+        let matcher = SimdPatternMatcher::new().unwrap();
 
         // Test various needle sizes
         for needle_len in 1..=16 {
@@ -553,6 +553,62 @@ mod pattern_matcher_tests {
             );
         }
     }
+
+    #[test]
+    fn test_find_long_pattern_matches_scalar() {
+        let matcher = SimdPatternMatcher::new().unwrap();
+
+        for needle_len in [17, 33, 64, 200] {
+            let needle = create_random_bytes(needle_len, 7 + needle_len as u64);
+            let mut haystack = create_random_bytes(2000, needle_len as u64);
+
+            // Back-to-back copies, copies straddling vector blocks and one at the end
+            let positions = [0, needle_len, 2 * needle_len + 45, 1031, 2000 - needle_len];
+            for pos in positions {
+                haystack[pos..pos + needle_len].copy_from_slice(&needle);
+            }
+
+            let result = matcher.find_pattern_occurrences(&haystack, &needle);
+            assert_eq!(
+                result,
+                scalar_find_pattern(&haystack, &needle),
+                "Pattern matching differs for needle length {needle_len}"
+            );
+            assert_eq!(result, positions);
+        }
+    }
+
+    #[test]
+    fn test_find_long_pattern_overlapping() {
+        let matcher = SimdPatternMatcher::new().unwrap();
+
+        // Every even offset starts a candidate whose first and last byte match
+        let haystack = b"AB".repeat(300);
+        for needle_len in [64, 200] {
+            let needle = &haystack[..needle_len];
+            let result = matcher.find_pattern_occurrences(&haystack, needle);
+            assert_eq!(result, scalar_find_pattern(&haystack, needle));
+            assert_eq!(result.len(), (600 - needle_len) / 2 + 1);
+        }
+    }
+
+    #[test]
+    fn test_find_long_pattern_partial_matches() {
+        let matcher = SimdPatternMatcher::new().unwrap();
+
+        // Copies with a corrupted middle byte share the first and last byte
+        // with the needle but must not match
+        let needle = create_random_bytes(64, 99);
+        let mut haystack = vec![0u8; 1000];
+        for pos in [10, 200, 500] {
+            haystack[pos..pos + 64].copy_from_slice(&needle);
+        }
+        haystack[200 + 32] ^= 0xFF;
+
+        let result = matcher.find_pattern_occurrences(&haystack, &needle);
+        assert_eq!(result, scalar_find_pattern(&haystack, &needle));
+        assert_eq!(result, vec![10, 500]);
+    }
 }
 
 // ============================================================================
@@ -999,6 +1055,119 @@ mod capability_tests {
     }
 }
 
+// ============================================================================
+// SIMD Override Tests
+// ============================================================================
+
+#[cfg(test)]
+mod override_tests {
+    use super::*;
+
+    /// The instruction set detected on this CPU, if any
+    fn detected_override() -> Option<SimdOverride> {
+        let caps = SimdCapabilities::detect();
+        if caps.has_avx2 {
+            Some(SimdOverride::ForceAvx2)
+        } else if caps.has_neon {
+            Some(SimdOverride::ForceNeon)
+        } else {
+            None
+        }
+    }
+
+    fn assert_same_output(simd_override: SimdOverride) {
+        let scalar_encoder = SimdEncoder::with_override(SimdOverride::ForceScalar).unwrap();
+        let scalar_decoder = SimdDecoder::with_override(SimdOverride::ForceScalar).unwrap();
+        let scalar_matcher = SimdPatternMatcher::with_override(SimdOverride::ForceScalar).unwrap();
+        let encoder = SimdEncoder::with_override(simd_override).unwrap();
+        let decoder = SimdDecoder::with_override(simd_override).unwrap();
+        let matcher = SimdPatternMatcher::with_override(simd_override).unwrap();
+
+        for (seed, size) in [1, 31, 32, 128, 129, 1000, 4096].into_iter().enumerate() {
+            let input = create_random_bytes(size, seed as u64);
+            let bases = encoder.batch_encode(&input).unwrap();
+            assert_eq!(bases, scalar_encoder.batch_encode(&input).unwrap());
+            assert_eq!(decoder.batch_decode(&bases).unwrap(), input);
+            assert_eq!(
+                decoder.batch_decode(&bases).unwrap(),
+                scalar_decoder.batch_decode(&bases).unwrap()
+            );
+
+            let needle = &input[size / 2..(size / 2 + 3).min(size)];
+            assert_eq!(
+                matcher.find_pattern_occurrences(&input, needle),
+                scalar_matcher.find_pattern_occurrences(&input, needle)
+            );
+        }
+    }
+
+    #[test]
+    fn test_force_scalar_disables_simd() {
+        let caps = SimdCapabilities::with_override(SimdOverride::ForceScalar).unwrap();
+        assert!(!caps.has_avx2 && !caps.has_neon && !caps.has_sse42);
+        assert_eq!(caps.vector_width, 1);
+
+        let encoder = SimdEncoder::with_override(SimdOverride::ForceScalar).unwrap();
+        assert!(!encoder.capabilities().has_avx2 && !encoder.capabilities().has_neon);
+    }
+
+    #[test]
+    fn test_forced_paths_match_scalar_output() {
+        assert_same_output(SimdOverride::Auto);
+        if let Some(simd_override) = detected_override() {
+            assert_same_output(simd_override);
+        }
+    }
+
+    #[test]
+    fn test_forcing_unavailable_instruction_set_fails() {
+        let caps = SimdCapabilities::detect();
+        for (simd_override, available) in [
+            (SimdOverride::ForceAvx2, caps.has_avx2),
+            (SimdOverride::ForceNeon, caps.has_neon),
+        ] {
+            let result = SimdCapabilities::with_override(simd_override);
+            assert_eq!(result.is_ok(), available, "{simd_override}");
+            if !available {
+                assert!(matches!(result, Err(DNAError::SimdError(_))));
+                assert!(SimdEncoder::with_override(simd_override).is_err());
+                assert!(SimdDecoder::with_override(simd_override).is_err());
+                assert!(SimdPatternMatcher::with_override(simd_override).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_simd_override() {
+        assert_eq!("auto".parse::<SimdOverride>().unwrap(), SimdOverride::Auto);
+        assert_eq!(
+            " Scalar ".parse::<SimdOverride>().unwrap(),
+            SimdOverride::ForceScalar
+        );
+        assert_eq!(
+            "AVX2".parse::<SimdOverride>().unwrap(),
+            SimdOverride::ForceAvx2
+        );
+        assert_eq!(
+            "neon".parse::<SimdOverride>().unwrap(),
+            SimdOverride::ForceNeon
+        );
+        assert!("sse9".parse::<SimdOverride>().is_err());
+
+        for simd_override in [
+            SimdOverride::Auto,
+            SimdOverride::ForceScalar,
+            SimdOverride::ForceAvx2,
+            SimdOverride::ForceNeon,
+        ] {
+            assert_eq!(
+                simd_override.to_string().parse::<SimdOverride>().unwrap(),
+                simd_override
+            );
+        }
+    }
+}
+
 // ============================================================================
 // Utility Function Tests
 // ============================================================================
@@ -125,6 +125,9 @@ unsafe fn decode_128_bases_avx2(chunk: &[DNABase], output: &mut Vec<u8>) -> Resu
 
 /// AVX2-optimized pattern matching for dictionary compression
 ///
+/// Needles of up to 32 bytes are compared in a single vector; longer ones go
+/// through [`find_long_pattern_avx2`].
+///
 /// # Safety
 ///
 /// This function requires the CPU to support AVX2 instructions.
@@ -135,9 +138,12 @@ unsafe fn decode_128_bases_avx2(chunk: &[DNABase], output: &mut Vec<u8>) -> Resu
 pub unsafe fn find_pattern_avx2(haystack: &[u8], needle: &[u8]) -> Vec<usize> {
     let mut matches = Vec::new();
 
-    if needle.len() > 32 || needle.is_empty() {
+    if needle.is_empty() {
         return matches;
     }
+    if needle.len() > 32 {
+        return find_long_pattern_avx2(haystack, needle);
+    }
 
     // Load needle into AVX2 register (pad with zeros if needed)
     let mut needle_padded = [0u8; 32];
@@ -154,7 +160,7 @@ pub unsafe fn find_pattern_avx2(haystack: &[u8], needle: &[u8]) -> Vec<usize> {
         let match_mask = _mm256_movemask_epi8(cmp_result) as u32;
 
         // Check if we have a match at the beginning
-        let needle_mask = (1u32 << needle.len()) - 1;
+        let needle_mask = u32::MAX >> (32 - needle.len());
         if (match_mask & needle_mask) == needle_mask {
             matches.push(pos);
         }
@@ -173,6 +179,62 @@ pub unsafe fn find_pattern_avx2(haystack: &[u8], needle: &[u8]) -> Vec<usize> {
     matches
 }
 
+/// AVX2-optimized pattern matching for needles of any length
+///
+/// Compares the needle's first and last byte against 32 candidate positions
+/// at once and only verifies the full needle where both match.
+///
+/// # Safety
+///
+/// This function requires the CPU to support AVX2 instructions.
+/// The caller must ensure that the `avx2` target feature is available before calling.
+/// Use `is_x86_feature_detected!("avx2")` to check at runtime.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+pub unsafe fn find_long_pattern_avx2(haystack: &[u8], needle: &[u8]) -> Vec<usize> {
+    let mut matches = Vec::new();
+
+    if needle.is_empty() || haystack.len() < needle.len() {
+        return matches;
+    }
+
+    let last = needle.len() - 1;
+    let first_vec = _mm256_set1_epi8(needle[0] as i8);
+    let last_vec = _mm256_set1_epi8(needle[last] as i8);
+
+    // Each block tests the 32 candidate starts pos..pos + 32
+    let mut pos = 0;
+    while pos + last + 32 <= haystack.len() {
+        let first_block = _mm256_loadu_si256(haystack.as_ptr().add(pos) as *const __m256i);
+        let last_block = _mm256_loadu_si256(haystack.as_ptr().add(pos + last) as *const __m256i);
+        let candidates = _mm256_and_si256(
+            _mm256_cmpeq_epi8(first_block, first_vec),
+            _mm256_cmpeq_epi8(last_block, last_vec),
+        );
+
+        let mut candidate_mask = _mm256_movemask_epi8(candidates) as u32;
+        while candidate_mask != 0 {
+            let start = pos + candidate_mask.trailing_zeros() as usize;
+            if haystack[start + 1..start + needle.len()] == needle[1..] {
+                matches.push(start);
+            }
+            candidate_mask &= candidate_mask - 1;
+        }
+
+        pos += 32;
+    }
+
+    // Handle remainder with scalar search
+    while pos + needle.len() <= haystack.len() {
+        if &haystack[pos..pos + needle.len()] == needle {
+            matches.push(pos);
+        }
+        pos += 1;
+    }
+
+    matches
+}
+
 /// Calculate Hamming distance between DNA sequences using AVX2
 ///
 /// # Safety
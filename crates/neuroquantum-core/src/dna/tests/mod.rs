@@ -522,6 +522,171 @@ mod error_tests {
     }
 }
 
+/// Tests for shared, persistable compression dictionaries
+#[cfg(test)]
+mod dictionary_tests {
+    use super::*;
+
+    fn record(id: usize, status: &str) -> Vec<u8> {
+        format!(
+            r#"{{"customer_id":{id},"status":"{status}","region":"eu-central-1","tier":"premium"}}"#
+        )
+        .into_bytes()
+    }
+
+    fn training_records() -> Vec<Vec<u8>> {
+        (0..32)
+            .map(|i| record(i, if i % 2 == 0 { "active" } else { "suspended" }))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_trained_dictionary_improves_ratio_on_similar_records() {
+        let records = training_records();
+        let samples: Vec<&[u8]> = records.iter().map(Vec::as_slice).collect();
+
+        let plain = QuantumDNACompressor::new();
+        let trained = QuantumDNACompressor::new();
+        let id = trained.train_dictionary(&samples).unwrap();
+        assert_eq!(trained.active_dictionary(), Some(id));
+
+        for (i, status) in [(1000, "active"), (1001, "suspended"), (1002, "active")] {
+            let data = record(i, status);
+            let without = plain.compress(&data).await.unwrap();
+            let with = trained.compress(&data).await.unwrap();
+
+            // Only the dictionary ID travels with the value
+            assert_eq!(with.sequence.metadata.dictionary_id, Some(id));
+            assert!(with.sequence.metadata.dictionary.is_none());
+            assert!(
+                with.compressed_size < without.compressed_size,
+                "{} >= {}",
+                with.compressed_size,
+                without.compressed_size
+            );
+            assert!(
+                with.sequence.metadata.compression_ratio
+                    < without.sequence.metadata.compression_ratio
+            );
+            assert_eq!(trained.decompress(&with).await.unwrap(), data);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exported_dictionary_decompresses_elsewhere() {
+        let records = training_records();
+        let samples: Vec<&[u8]> = records.iter().map(Vec::as_slice).collect();
+        let compressor = QuantumDNACompressor::new();
+        let id = compressor.train_dictionary(&samples).unwrap();
+
+        let data = record(7, "active");
+        let compressed = compressor.compress(&data).await.unwrap();
+
+        // A compressor without the dictionary cannot decode the value
+        let other = QuantumDNACompressor::new();
+        assert!(!other.validate(&compressed).await.unwrap());
+        assert!(matches!(
+            other.decompress(&compressed).await,
+            Err(DNAError::UnknownDictionary(unknown)) if unknown == id
+        ));
+
+        let exported = compressor.export_dictionary().unwrap();
+        assert_eq!(other.import_dictionary(&exported).unwrap(), id);
+        assert_eq!(other.decompress(&compressed).await.unwrap(), data);
+
+        // Existing values stay readable after the dictionary is retired
+        other.clear_dictionary();
+        assert_eq!(other.active_dictionary(), None);
+        assert_eq!(other.decompress(&compressed).await.unwrap(), data);
+        let fresh = other.compress(&data).await.unwrap();
+        assert_eq!(fresh.sequence.metadata.dictionary_id, None);
+    }
+
+    #[test]
+    fn test_dictionary_import_rejects_tampered_patterns() {
+        let compressor = QuantumDNACompressor::new();
+        assert!(compressor.export_dictionary().is_err());
+        assert!(compressor
+            .train_dictionary(&[b"no repeats".as_slice()])
+            .is_err());
+
+        let records = training_records();
+        let samples: Vec<&[u8]> = records.iter().map(Vec::as_slice).collect();
+        compressor.train_dictionary(&samples).unwrap();
+        let mut exported: serde_json::Value =
+            serde_json::from_slice(&compressor.export_dictionary().unwrap()).unwrap();
+        exported["entries"][0][0] = serde_json::json!(0);
+        let tampered = serde_json::to_vec(&exported).unwrap();
+        assert!(matches!(
+            compressor.import_dictionary(&tampered),
+            Err(DNAError::InvalidDictionary(_))
+        ));
+    }
+}
+
+/// Tests for parallel chunked compression of large inputs
+#[cfg(test)]
+mod chunked_tests {
+    use super::*;
+
+    fn config(thread_count: usize) -> DNACompressionConfig {
+        DNACompressionConfig {
+            // Per-value dictionaries over megabytes of input dominate the runtime
+            enable_dictionary: false,
+            thread_count,
+            chunk_size: 512 * 1024,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_parallel_chunked_compression_matches_sequential() {
+        let data = TestDataGenerator::generate_entropy_data(4 * 1024 * 1024, 0.5);
+
+        let sequential = QuantumDNACompressor::with_config(config(1));
+        let parallel = QuantumDNACompressor::with_config(config(4));
+
+        let sequential_compressed = sequential.compress(&data).await.unwrap();
+        let parallel_compressed = parallel.compress(&data).await.unwrap();
+
+        assert!(sequential_compressed.sequence.chunks.is_empty());
+        let chunks = &parallel_compressed.sequence.chunks;
+        assert_eq!(chunks.len(), 8);
+        assert!(chunks
+            .iter()
+            .all(|chunk| chunk.original_length == 512 * 1024));
+        assert!(parallel.validate(&parallel_compressed).await.unwrap());
+
+        let sequential_data = sequential.decompress(&sequential_compressed).await.unwrap();
+        let parallel_data = parallel.decompress(&parallel_compressed).await.unwrap();
+        assert_eq!(parallel_data, sequential_data);
+        assert_eq!(parallel_data, data);
+
+        // Chunk boundaries travel with the data, so any compressor can decode it
+        assert_eq!(
+            sequential.decompress(&parallel_compressed).await.unwrap(),
+            data
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_altered_chunk_table_is_rejected() {
+        let data = TestDataGenerator::generate_entropy_data(1024 * 1024 + 17, 0.5);
+        let compressor = QuantumDNACompressor::with_config(config(2));
+
+        let mut compressed = compressor.compress(&data).await.unwrap();
+        assert_eq!(compressed.sequence.chunks.len(), 3);
+        assert_eq!(compressed.sequence.chunks[2].original_length, 17);
+
+        compressed.sequence.chunks[0].original_length -= 1;
+        compressed.sequence.chunks[1].original_length += 1;
+        assert!(matches!(
+            compressor.decompress(&compressed).await,
+            Err(DNAError::ChecksumMismatch { .. })
+        ));
+    }
+}
+
 /// Helper functions for test data generation
 pub struct TestDataGenerator;
 
@@ -578,3 +743,415 @@ impl TestDataGenerator {
         (0..length).map(|_| bases[rng.gen_range(0..4)]).collect()
     }
 }
+
+#[cfg(test)]
+mod observer_tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::dna::{CompressionEvent, CompressionObserver, CompressionOperation};
+
+    #[derive(Default)]
+    struct RecordingObserver {
+        events: Mutex<Vec<CompressionEvent>>,
+    }
+
+    impl CompressionObserver for RecordingObserver {
+        fn on_compress(&self, event: CompressionEvent) {
+            self.events.lock().unwrap().push(event);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_observer_receives_event_per_operation() {
+        let compressor = QuantumDNACompressor::new();
+        let observer = Arc::new(RecordingObserver::default());
+        compressor.set_observer(observer.clone());
+
+        let inputs: Vec<Vec<u8>> = vec![
+            b"ACGTACGTACGTACGT".repeat(8),
+            TestDataGenerator::generate_entropy_data(2048, 0.8),
+            b"observer".to_vec(),
+        ];
+        let mut compressed = Vec::new();
+        for input in &inputs {
+            compressed.push(compressor.compress(input).await.unwrap());
+        }
+
+        {
+            let events = observer.events.lock().unwrap();
+            assert_eq!(events.len(), inputs.len());
+            for ((event, input), output) in events.iter().zip(&inputs).zip(&compressed) {
+                assert_eq!(event.operation, CompressionOperation::Compress);
+                assert_eq!(event.input_size, input.len());
+                assert_eq!(event.output_size, output.compressed_size);
+                assert_eq!(event.error_correction_bytes, output.sequence.parity.len());
+                let ratio = output.compressed_size as f64 / input.len() as f64;
+                assert!((event.compression_ratio - ratio).abs() < f64::EPSILON);
+                assert_eq!(event.errors_corrected, 0);
+            }
+        }
+
+        // Clones report to the same observer
+        let clone = compressor.clone();
+        let restored = clone.decompress(&compressed[1]).await.unwrap();
+        assert_eq!(restored, inputs[1]);
+        {
+            let events = observer.events.lock().unwrap();
+            assert_eq!(events.len(), inputs.len() + 1);
+            let event = events.last().unwrap();
+            assert_eq!(event.operation, CompressionOperation::Decompress);
+            assert_eq!(event.input_size, compressed[1].compressed_size);
+            assert_eq!(event.output_size, inputs[1].len());
+        }
+
+        compressor.clear_observer();
+        compressor.compress(&inputs[0]).await.unwrap();
+        assert_eq!(observer.events.lock().unwrap().len(), inputs.len() + 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_operation_not_reported() {
+        let compressor = QuantumDNACompressor::with_config(DNACompressionConfig {
+            memory_limit: 16,
+            ..Default::default()
+        });
+        let observer = Arc::new(RecordingObserver::default());
+        compressor.set_observer(observer.clone());
+
+        assert!(compressor.compress(&[0u8; 64]).await.is_err());
+        assert!(observer.events.lock().unwrap().is_empty());
+    }
+}
+
+/// Tests for compressing inputs larger than the memory limit
+#[cfg(test)]
+mod memory_policy_tests {
+    use super::*;
+    use crate::dna::MemoryPolicy;
+
+    const MEMORY_LIMIT: usize = 4 * 1024;
+
+    fn config(memory_policy: MemoryPolicy, thread_count: usize) -> DNACompressionConfig {
+        DNACompressionConfig {
+            memory_limit: MEMORY_LIMIT,
+            memory_policy,
+            thread_count,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reject_policy_fails_over_budget() {
+        let data = TestDataGenerator::generate_entropy_data(64 * 1024, 0.5);
+        let compressor = QuantumDNACompressor::with_config(config(MemoryPolicy::Reject, 4));
+
+        assert!(matches!(
+            compressor.compress(&data).await,
+            Err(DNAError::MemoryError(_))
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_chunk_and_stream_round_trips_over_budget() {
+        let data = TestDataGenerator::generate_entropy_data(64 * 1024 + 5, 0.5);
+
+        for thread_count in [1, 4] {
+            let compressor = QuantumDNACompressor::with_config(config(
+                MemoryPolicy::ChunkAndStream,
+                thread_count,
+            ));
+            let compressed = compressor.compress(&data).await.unwrap();
+
+            let chunks = &compressed.sequence.chunks;
+            assert_eq!(chunks.len(), 33);
+            assert!(chunks
+                .iter()
+                .all(|chunk| chunk.original_length * 2 <= MEMORY_LIMIT));
+            assert_eq!(compressor.decompress(&compressed).await.unwrap(), data);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chunk_and_stream_leaves_small_inputs_whole() {
+        let data = TestDataGenerator::generate_entropy_data(1024, 0.5);
+        let compressor = QuantumDNACompressor::with_config(config(MemoryPolicy::ChunkAndStream, 1));
+
+        let compressed = compressor.compress(&data).await.unwrap();
+        assert!(compressed.sequence.chunks.is_empty());
+        assert_eq!(compressor.decompress(&compressed).await.unwrap(), data);
+    }
+}
+
+/// Tests for the binary container format
+#[cfg(test)]
+mod container_tests {
+    use super::*;
+    use crate::dna::container::{CONTAINER_MAGIC, CONTAINER_VERSION};
+    use crate::dna::{CompressedDNA, DNAContainer};
+
+    async fn compress(data: &[u8], config: DNACompressionConfig) -> CompressedDNA {
+        QuantumDNACompressor::with_config(config)
+            .compress(data)
+            .await
+            .unwrap()
+    }
+
+    fn assert_same(restored: &CompressedDNA, compressed: &CompressedDNA) {
+        assert_eq!(restored.sequence.bases, compressed.sequence.bases);
+        assert_eq!(restored.sequence.parity, compressed.sequence.parity);
+        assert_eq!(restored.sequence.checksum, compressed.sequence.checksum);
+        assert_eq!(
+            restored.sequence.original_length,
+            compressed.sequence.original_length
+        );
+        assert_eq!(
+            restored.sequence.metadata.dictionary,
+            compressed.sequence.metadata.dictionary
+        );
+        assert_eq!(
+            restored.sequence.chunks.len(),
+            compressed.sequence.chunks.len()
+        );
+        assert_eq!(restored.compressed_size, compressed.compressed_size);
+    }
+
+    #[tokio::test]
+    async fn test_container_roundtrip() {
+        let compressor = QuantumDNACompressor::new();
+        let data = b"The quick brown fox jumps over the lazy dog. ".repeat(40);
+        let compressed = compressor.compress(&data).await.unwrap();
+
+        let bytes = compressed.to_container_bytes().unwrap();
+        assert!(bytes.starts_with(CONTAINER_MAGIC));
+        assert_eq!(bytes[CONTAINER_MAGIC.len()], CONTAINER_VERSION);
+
+        let restored = CompressedDNA::from_container_bytes(&bytes).unwrap();
+        assert_same(&restored, &compressed);
+        assert_eq!(compressor.decompress(&restored).await.unwrap(), data);
+    }
+
+    #[tokio::test]
+    async fn test_container_roundtrip_edge_cases() {
+        // Empty data, base counts that don't fill the last packed byte and
+        // chunked sequences
+        for data in [Vec::new(), vec![0xA5], vec![1, 2, 3]] {
+            let compressed = compress(&data, DNACompressionConfig::default()).await;
+            let restored =
+                CompressedDNA::from_container_bytes(&compressed.to_container_bytes().unwrap())
+                    .unwrap();
+            assert_same(&restored, &compressed);
+        }
+
+        let data = TestDataGenerator::generate_entropy_data(64 * 1024, 0.5);
+        let config = DNACompressionConfig {
+            memory_limit: 4 * 1024,
+            memory_policy: crate::dna::MemoryPolicy::ChunkAndStream,
+            ..Default::default()
+        };
+        let compressed = compress(&data, config.clone()).await;
+        assert!(!compressed.sequence.chunks.is_empty());
+        let restored =
+            CompressedDNA::from_container_bytes(&compressed.to_container_bytes().unwrap()).unwrap();
+        assert_same(&restored, &compressed);
+        let decompressed = QuantumDNACompressor::with_config(config)
+            .decompress(&restored)
+            .await
+            .unwrap();
+        assert_eq!(decompressed, data);
+    }
+
+    #[tokio::test]
+    async fn test_container_smaller_than_json() {
+        let compressor = QuantumDNACompressor::new();
+        let data = TestDataGenerator::generate_entropy_data(16 * 1024, 0.7);
+        let compressed = compressor.compress(&data).await.unwrap();
+
+        let container = compressed.to_container_bytes().unwrap();
+        let json = serde_json::to_vec(&compressed).unwrap();
+        assert!(
+            container.len() * 5 < json.len(),
+            "container {} bytes, JSON {} bytes",
+            container.len(),
+            json.len()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_container_rejects_unknown_version_and_corruption() {
+        let compressed = compress(b"versioned payload", DNACompressionConfig::default()).await;
+        let bytes = compressed.to_container_bytes().unwrap();
+
+        let mut future = bytes.clone();
+        future[CONTAINER_MAGIC.len()] = CONTAINER_VERSION + 1;
+        assert!(matches!(
+            CompressedDNA::from_container_bytes(&future),
+            Err(DNAError::UnsupportedContainerVersion(v)) if v == CONTAINER_VERSION + 1
+        ));
+
+        assert!(matches!(
+            CompressedDNA::from_container_bytes(&bytes[..bytes.len() - 1]),
+            Err(DNAError::InvalidContainer(_))
+        ));
+        assert!(matches!(
+            CompressedDNA::from_container_bytes(b"{\"sequence\":{}}"),
+            Err(DNAError::InvalidContainer(_))
+        ));
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(DNAContainer::parse(&trailing).is_err());
+
+        let container = DNAContainer::parse(&bytes).unwrap();
+        assert_eq!(container.base_count, compressed.sequence.bases.len());
+        assert_eq!(container.parity, compressed.sequence.parity.as_slice());
+    }
+}
+
+/// Tests for custom base-to-symbol alphabets
+#[cfg(test)]
+mod alphabet_tests {
+    use super::*;
+    use crate::dna::container::{CONTAINER_MAGIC, MIN_CONTAINER_VERSION};
+    use crate::dna::{CompressedDNA, DNAContainer, DnaAlphabet};
+
+    /// C=00, A=01, T=10, G=11
+    fn permuted() -> DnaAlphabet {
+        DnaAlphabet::new(['C', 'A', 'T', 'G']).unwrap()
+    }
+
+    #[test]
+    fn test_default_alphabet_matches_dna_base() {
+        let alphabet = DnaAlphabet::default();
+        assert_eq!(alphabet, DnaAlphabet::STANDARD);
+        assert_eq!(DNACompressionConfig::default().alphabet, alphabet);
+        for bits in 0..4 {
+            let base = DNABase::from_bits(bits).unwrap();
+            assert_eq!(alphabet.symbol(base), base.to_char());
+            assert_eq!(alphabet.base(base.to_char()).unwrap(), base);
+        }
+        assert_eq!(alphabet.base('g').unwrap(), DNABase::Guanine);
+    }
+
+    #[test]
+    fn test_alphabet_validation() {
+        assert!(matches!(
+            DnaAlphabet::new(['A', 'T', 'A', 'C']),
+            Err(DNAError::InvalidAlphabet(_))
+        ));
+        assert!(serde_json::from_str::<DnaAlphabet>(r#"["A","A","G","C"]"#).is_err());
+
+        let alphabet: DnaAlphabet = serde_json::from_str(r#"["C","A","T","G"]"#).unwrap();
+        assert_eq!(alphabet, permuted());
+        assert!(matches!(
+            alphabet.base('X'),
+            Err(DNAError::InvalidBase(b'X'))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_permuted_alphabet_roundtrip() {
+        let data = b"Custom base-to-bits conventions for synthesis pipelines. ".repeat(20);
+        let compressor = QuantumDNACompressor::with_config(DNACompressionConfig {
+            alphabet: permuted(),
+            ..Default::default()
+        });
+        let compressed = compressor.compress(&data).await.unwrap();
+        assert_eq!(compressed.sequence.metadata.alphabet, permuted());
+
+        // Each base is emitted as the symbol its 2-bit value maps to
+        let symbols = compressed.sequence.to_symbols();
+        assert_eq!(symbols.chars().count(), compressed.sequence.bases.len());
+        for (symbol, base) in symbols.chars().zip(&compressed.sequence.bases) {
+            let expected = match base.to_bits() {
+                | 0b00 => 'C',
+                | 0b01 => 'A',
+                | 0b10 => 'T',
+                | _ => 'G',
+            };
+            assert_eq!(symbol, expected);
+        }
+        assert_ne!(
+            symbols,
+            DnaAlphabet::STANDARD.encode(&compressed.sequence.bases)
+        );
+
+        // The recorded alphabet travels with the metadata and reads the
+        // symbols back, even for a compressor configured with the default map
+        let json = serde_json::to_string(&compressed).unwrap();
+        let mut restored: CompressedDNA = serde_json::from_str(&json).unwrap();
+        restored.sequence.bases.clear();
+        restored.sequence.set_symbols(&symbols).unwrap();
+        assert_eq!(restored.sequence.bases, compressed.sequence.bases);
+        let decompressed = QuantumDNACompressor::new()
+            .decompress(&restored)
+            .await
+            .unwrap();
+        assert_eq!(decompressed, data);
+
+        let bytes = compressed.to_container_bytes().unwrap();
+        let restored = CompressedDNA::from_container_bytes(&bytes).unwrap();
+        assert_eq!(restored.sequence.metadata.alphabet, permuted());
+        assert_eq!(restored.sequence.to_symbols(), symbols);
+    }
+
+    #[tokio::test]
+    async fn test_non_atgc_alphabet_roundtrip() {
+        let alphabet = DnaAlphabet::new(['0', '1', '2', '3']).unwrap();
+        let data = TestDataGenerator::generate_entropy_data(4096, 0.6);
+        let compressor = QuantumDNACompressor::with_config(DNACompressionConfig {
+            alphabet,
+            ..Default::default()
+        });
+        let mut compressed = compressor.compress(&data).await.unwrap();
+
+        let symbols = compressed.sequence.to_symbols();
+        assert!(symbols.chars().all(|c| ('0'..='3').contains(&c)));
+        assert!(compressed.sequence.set_symbols("01A").is_err());
+
+        compressed.sequence.set_symbols(&symbols).unwrap();
+        assert_eq!(compressor.decompress(&compressed).await.unwrap(), data);
+    }
+
+    #[tokio::test]
+    async fn test_version_1_container_uses_standard_alphabet() {
+        let compressor = QuantumDNACompressor::new();
+        let data = b"written before alphabets were recorded".to_vec();
+        let compressed = compressor.compress(&data).await.unwrap();
+        let sequence = &compressed.sequence;
+        let container_bytes = compressed.to_container_bytes().unwrap();
+        let container = DNAContainer::parse(&container_bytes).unwrap();
+
+        // Version 1 metadata lacks the alphabet at the end of the metadata
+        let metadata = &sequence.metadata;
+        let section = bincode::serialize(&(
+            sequence.checksum,
+            sequence.original_length,
+            compressed.compressed_size,
+            (
+                metadata.version,
+                metadata.compression_ratio,
+                metadata.error_correction_strength,
+                &metadata.dictionary,
+                metadata.dictionary_id,
+                metadata.timestamp,
+            ),
+            &sequence.chunks,
+            &compressed.metrics,
+        ))
+        .unwrap();
+        let mut bytes = CONTAINER_MAGIC.to_vec();
+        bytes.extend([MIN_CONTAINER_VERSION, container.algorithm]);
+        let mut bases = (sequence.bases.len() as u64).to_le_bytes().to_vec();
+        bases.extend_from_slice(container.packed_bases);
+        for section in [&bases[..], container.parity, &section] {
+            bytes.extend((section.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(section);
+        }
+
+        let restored = CompressedDNA::from_container_bytes(&bytes).unwrap();
+        assert_eq!(restored.sequence.metadata.alphabet, DnaAlphabet::STANDARD);
+        assert_eq!(restored.sequence.bases, sequence.bases);
+        assert_eq!(compressor.decompress(&restored).await.unwrap(), data);
+    }
+}
@@ -65,12 +65,24 @@ pub enum NeuroQuantumError {
     #[error("Deadlock detected: {0}")]
     DeadlockDetected(String),
 
+    /// The transaction was or must be rolled back; `retryable` tells clients
+    /// whether running it again from the start can succeed
+    #[error("Transaction aborted ({reason}): {message}")]
+    TransactionAborted {
+        reason: crate::transaction::AbortReason,
+        retryable: bool,
+        message: String,
+    },
+
     #[error("Isolation violation: {0}")]
     IsolationViolation(String),
 
     #[error("Concurrent modification detected: {0}")]
     ConcurrentModification(String),
 
+    #[error("Not initialized: {0}")]
+    NotInitialized(String),
+
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 
@@ -88,6 +100,35 @@ impl NeuroQuantumError {
     pub fn invalid_operation(msg: &str) -> Self {
         Self::InvalidOperation(msg.to_string())
     }
+
+    /// Create an aborted transaction error, retryable as `reason` dictates
+    #[must_use]
+    pub fn transaction_aborted(reason: crate::transaction::AbortReason, message: String) -> Self {
+        Self::TransactionAborted {
+            reason,
+            retryable: reason.is_retryable(),
+            message,
+        }
+    }
+
+    /// Whether the failed operation may succeed if the transaction is retried
+    #[must_use]
+    pub const fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::TransactionAborted {
+                retryable: true,
+                ..
+            }
+        )
+    }
+}
+
+// Convert rejected quantum circuits to NeuroQuantumError
+impl From<crate::quantum::backends::CircuitError> for NeuroQuantumError {
+    fn from(error: crate::quantum::backends::CircuitError) -> Self {
+        Self::ValidationError(error.to_string())
+    }
 }
 
 // Convert DNA errors to NeuroQuantumError
@@ -51,9 +51,10 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use tracing::info;
+use tracing::{debug, info};
 
 /// Module exports
+pub mod compression;
 pub mod concurrency; // Lock hierarchy documentation and concurrency guidelines
 pub mod dna;
 pub mod error;
@@ -75,35 +76,38 @@ pub mod storage;
 pub mod synaptic;
 pub mod transaction;
 
+pub use compression::{CompressionAlgorithm, Compressor, UncompressedReason};
 // Re-export key DNA compression types for easy access
 pub use dna::{
-    CompressedDNA, CompressionMetadata, CompressionMetrics, DNABase, DNACompressionConfig,
-    DNACompressor, DNAError, DNASequence, QuantumDNACompressor,
+    CompressedDNA, CompressionEvent, CompressionMetadata, CompressionMetrics, CompressionObserver,
+    DNABase, DNACompressionConfig, DNACompressor, DNADictionary, DNAError, DNASequence,
+    DictionaryId, MemoryPolicy, QuantumDNACompressor,
 };
 // Re-export other core types
 pub use error::NeuroQuantumError;
 // Re-export nalgebra for API use
 pub use nalgebra;
 // Re-export NEON optimization types
-pub use neon_optimization::{NeonOptimizer, OptimizationStats, QuantumOperation};
-// Re-export spiking neural network types (Izhikevich model)
+pub use neon_optimization::{FusedOperation, NeonOptimizer, OptimizationStats, QuantumOperation};
+// Re-export spiking neural network types
 pub use spiking::{
-    IzhikevichNeuron, IzhikevichNeuronType, IzhikevichParameters, NetworkStatistics, STDPRule,
-    SpikingNeuralNetwork, SpikingSynapse,
+    HodgkinHuxleyNeuron, IzhikevichNeuron, IzhikevichNeuronType, IzhikevichParameters, LifNeuron,
+    LifParameters, NetworkStatistics, NeuronModel, STDPRule, Spike, SpikingNeuralNetwork,
+    SpikingNeuron, SpikingSynapse, StdpVariant,
 };
 pub use storage::StorageEngine;
 // Re-export transaction management types
 pub use transaction::{
-    IsolationLevel, LockManager, LockType, LogManager, RecoveryManager, Transaction, TransactionId,
-    TransactionManager, TransactionStatistics, TransactionStatus, LSN,
+    AbortReason, AbortStatistics, CheckpointInfo, IsolationLevel, LockManager, LockType,
+    LogManager, RecoveryManager, Transaction, TransactionId, TransactionManager,
+    TransactionStatistics, TransactionStatus, LSN,
 };
 
 // Quantum search constants
-/// Minimum search space size to ensure meaningful quantum advantage using Grover's algorithm
-const MIN_QUANTUM_SEARCH_SPACE: usize = 4;
-
-/// Minimum quantum speedup threshold to ensure quantum performance exceeds classical
-const MIN_QUANTUM_SPEEDUP: f32 = 1.01;
+/// Largest state vector simulated by `quantum_search` (2^20 amplitudes, 16 MiB)
+const MAX_QUANTUM_SEARCH_QUBITS: usize = 20;
+/// Expected speedup over a classical scan below which `quantum_search` skips Grover
+const MIN_QUANTUM_SPEEDUP: f64 = 2.0;
 
 /// Main database engine that integrates all components
 ///
@@ -131,6 +135,7 @@ const MIN_QUANTUM_SPEEDUP: f32 = 1.01;
 pub struct NeuroQuantumDB {
     storage: std::sync::Arc<tokio::sync::RwLock<storage::StorageEngine>>,
     dna_compressor: dna::QuantumDNACompressor,
+    compression_ratios: monitoring::CompressionRatioMetrics,
     config: NeuroQuantumConfig,
 }
 
@@ -170,6 +175,7 @@ pub struct NeuroQuantumDB {
 #[derive(Debug, Clone)]
 pub struct NeuroQuantumDBBuilder {
     config: NeuroQuantumConfig,
+    field_keys: Option<std::sync::Arc<pqcrypto::KeyManager>>,
 }
 
 impl NeuroQuantumDBBuilder {
@@ -189,6 +195,7 @@ impl NeuroQuantumDBBuilder {
     pub fn new() -> Self {
         Self {
             config: NeuroQuantumConfig::default(),
+            field_keys: None,
         }
     }
 
@@ -214,7 +221,10 @@ impl NeuroQuantumDBBuilder {
     /// ```
     #[must_use]
     pub const fn with_config(config: NeuroQuantumConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            field_keys: None,
+        }
     }
 
     /// Set the storage path for the database.
@@ -245,6 +255,17 @@ impl NeuroQuantumDBBuilder {
         self
     }
 
+    /// Set the key manager used for `ENCRYPTED` columns.
+    ///
+    /// When unset, the key manager persisted in the storage directory is
+    /// used, sealed with the data-at-rest master key. It is created on first
+    /// start.
+    #[must_use]
+    pub fn field_key_manager(mut self, keys: std::sync::Arc<pqcrypto::KeyManager>) -> Self {
+        self.field_keys = Some(keys);
+        self
+    }
+
     /// Set the DNA compression configuration.
     #[must_use]
     pub const fn dna_compression(mut self, config: dna::DNACompressionConfig) -> Self {
@@ -252,6 +273,16 @@ impl NeuroQuantumDBBuilder {
         self
     }
 
+    /// Set how often expired keys are deleted in the background.
+    #[must_use]
+    pub const fn key_expiry_sweep_interval(
+        mut self,
+        interval: Option<std::time::Duration>,
+    ) -> Self {
+        self.config.key_expiry_sweep_interval = interval;
+        self
+    }
+
     /// Build and initialize the `NeuroQuantumDB` instance.
     ///
     /// This method performs all necessary async initialization, including:
@@ -259,6 +290,7 @@ impl NeuroQuantumDBBuilder {
     /// - Initializing the DNA compressor
     /// - Setting up the transaction manager with WAL
     /// - Initializing encryption at rest
+    /// - Loading the key manager for `ENCRYPTED` columns
     ///
     /// # Errors
     ///
@@ -291,18 +323,30 @@ impl NeuroQuantumDBBuilder {
             dna::QuantumDNACompressor::with_config(self.config.dna_compression.clone());
 
         // Properly initialize the storage engine asynchronously
-        let storage = storage::StorageEngine::new(&self.config.storage_path)
+        let mut storage = storage::StorageEngine::new(&self.config.storage_path)
             .await
             .map_err(|e| NeuroQuantumError::StorageError(e.to_string()))?;
+        match self.field_keys {
+            | Some(keys) => storage.set_field_key_manager(keys),
+            | None => storage
+                .load_field_key_manager()
+                .await
+                .map_err(|e| NeuroQuantumError::StorageError(e.to_string()))?,
+        }
 
         // Wrap storage in Arc<RwLock> for thread-safe sharing with QSQL engine
         let storage = std::sync::Arc::new(tokio::sync::RwLock::new(storage));
 
+        if let Some(interval) = self.config.key_expiry_sweep_interval {
+            NeuroQuantumDB::spawn_expiry_sweeper(&storage, interval);
+        }
+
         info!("✅ NeuroQuantumDB fully initialized and ready for use");
 
         Ok(NeuroQuantumDB {
             storage,
             dna_compressor,
+            compression_ratios: monitoring::CompressionRatioMetrics::default(),
             config: self.config,
         })
     }
@@ -326,6 +370,9 @@ pub struct NeuroQuantumConfig {
     /// Performance tuning
     pub enable_quantum_optimization: bool,
     pub enable_neuromorphic_learning: bool,
+    /// How often expired keys are deleted in the background (`None` disables
+    /// the sweeper; expired keys are still hidden from reads)
+    pub key_expiry_sweep_interval: Option<std::time::Duration>,
 }
 
 impl Default for NeuroQuantumConfig {
@@ -336,6 +383,7 @@ impl Default for NeuroQuantumConfig {
             memory_limit_gb: 8,
             enable_quantum_optimization: true,
             enable_neuromorphic_learning: true,
+            key_expiry_sweep_interval: Some(std::time::Duration::from_secs(60)),
         }
     }
 }
@@ -418,6 +466,7 @@ impl NeuroQuantumDB {
         Self {
             storage,
             dna_compressor,
+            compression_ratios: monitoring::CompressionRatioMetrics::default(),
             config,
         }
     }
@@ -455,99 +504,343 @@ impl NeuroQuantumDB {
             .await
             .map_err(|e| NeuroQuantumError::StorageError(e.to_string()))?;
         self.storage = std::sync::Arc::new(tokio::sync::RwLock::new(new_storage));
+        if let Some(interval) = self.config.key_expiry_sweep_interval {
+            Self::spawn_expiry_sweeper(&self.storage, interval);
+        }
         Ok(())
     }
 
     /// Store data with DNA compression
+    ///
+    /// Data that DNA compression would only make larger, such as random or
+    /// already-compressed bytes, is stored uncompressed instead; see
+    /// [`compression::predict_incompressible`] and
+    /// [`stored_uncompressed_reason`](Self::stored_uncompressed_reason).
     pub async fn store_compressed(
         &mut self,
         key: &str,
         data: &[u8],
     ) -> Result<(), NeuroQuantumError> {
+        self.ensure_initialized().await?;
+        tracing::info!("Storing {} bytes for key: {}", data.len(), key);
+
+        let stored = compression::encode_dna_adaptive(&self.dna_compressor, data).await?;
+        {
+            let mut storage = self.storage.write().await;
+            storage
+                .store(key, &stored)
+                .await
+                .map_err(|e| NeuroQuantumError::StorageError(e.to_string()))?;
+        };
+
+        self.compression_ratios.record(data.len(), stored.len());
         tracing::info!(
-            "Storing {} bytes with DNA compression for key: {}",
+            "Successfully stored compressed data: {} -> {} bytes",
             data.len(),
-            key
+            stored.len()
         );
 
-        // Compress data using DNA algorithm
-        let compressed = self
-            .dna_compressor
-            .compress(data)
-            .await
-            .map_err(|e| NeuroQuantumError::CompressionError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Store data compressed with an algorithm chosen from its entropy
+    ///
+    /// Returns the algorithm that was used; see
+    /// [`CompressionAlgorithm::auto_select`].
+    pub async fn store_compressed_auto(
+        &mut self,
+        key: &str,
+        data: &[u8],
+    ) -> Result<CompressionAlgorithm, NeuroQuantumError> {
+        let algorithm = CompressionAlgorithm::auto_select(data);
+        self.store_compressed_with(key, data, algorithm).await?;
+        Ok(algorithm)
+    }
+
+    /// Store data compressed with `algorithm`
+    pub async fn store_compressed_with(
+        &mut self,
+        key: &str,
+        data: &[u8],
+        algorithm: CompressionAlgorithm,
+    ) -> Result<(), NeuroQuantumError> {
+        self.ensure_initialized().await?;
+
+        tracing::info!(
+            "Storing {} bytes with {} compression for key: {}",
+            data.len(),
+            algorithm,
+            key
+        );
 
-        // Serialize compressed data
-        let serialized = serde_json::to_vec(&compressed)
-            .map_err(|e| NeuroQuantumError::SerializationError(e.to_string()))?;
+        let payload = self.compressor(algorithm).compress(data).await?;
+        let stored = compression::encode_stored(algorithm, &payload);
 
         // Store in underlying storage engine (acquire write lock)
         {
             let mut storage = self.storage.write().await;
             storage
-                .store(key, &serialized)
+                .store(key, &stored)
                 .await
                 .map_err(|e| NeuroQuantumError::StorageError(e.to_string()))?;
         };
 
+        self.compression_ratios.record(data.len(), stored.len());
         tracing::info!(
-            "Successfully stored compressed data: {:.2}% compression ratio",
-            compressed.sequence.metadata.compression_ratio * 100.0
+            "Successfully stored compressed data: {} -> {} bytes",
+            data.len(),
+            stored.len()
         );
 
         Ok(())
     }
 
+    /// Store data with DNA compression that expires after `ttl`
+    ///
+    /// The key reads as missing once the TTL has passed and is deleted by the
+    /// background sweeper (see
+    /// [`NeuroQuantumConfig::key_expiry_sweep_interval`]). The expiry is
+    /// persisted with the database metadata.
+    pub async fn store_compressed_with_ttl(
+        &mut self,
+        key: &str,
+        data: &[u8],
+        ttl: std::time::Duration,
+    ) -> Result<(), NeuroQuantumError> {
+        self.ensure_initialized().await?;
+        let ttl = chrono::Duration::from_std(ttl)
+            .map_err(|e| NeuroQuantumError::ValidationError(format!("Invalid TTL: {e}")))?;
+        let expires_at = chrono::Utc::now()
+            .checked_add_signed(ttl)
+            .ok_or_else(|| NeuroQuantumError::ValidationError("TTL too large".to_string()))?;
+
+        let stored = compression::encode_dna_adaptive(&self.dna_compressor, data).await?;
+
+        let mut storage = self.storage.write().await;
+        storage
+            .store_with_expiry(key, &stored, expires_at)
+            .await
+            .map_err(|e| NeuroQuantumError::StorageError(e.to_string()))?;
+        drop(storage);
+        self.compression_ratios.record(data.len(), stored.len());
+        Ok(())
+    }
+
+    /// Delete all expired keys now, returning how many were removed
+    pub async fn sweep_expired_keys(&self) -> Result<usize, NeuroQuantumError> {
+        self.ensure_initialized().await?;
+        let mut storage = self.storage.write().await;
+        storage
+            .purge_expired_keys()
+            .await
+            .map_err(|e| NeuroQuantumError::StorageError(e.to_string()))
+    }
+
+    /// Periodically delete expired keys until the storage engine is dropped
+    fn spawn_expiry_sweeper(
+        storage: &std::sync::Arc<tokio::sync::RwLock<storage::StorageEngine>>,
+        interval: std::time::Duration,
+    ) {
+        let storage = std::sync::Arc::downgrade(storage);
+        tokio::spawn(async move {
+            let mut timer = tokio::time::interval(interval);
+            timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                timer.tick().await;
+                let Some(storage) = storage.upgrade() else {
+                    break;
+                };
+                // Only take the write lock when there is something to delete
+                if storage.read().await.expired_keys().is_empty() {
+                    continue;
+                }
+                let result = storage.write().await.purge_expired_keys().await;
+                if let Err(e) = result {
+                    tracing::warn!("Failed to purge expired keys: {}", e);
+                }
+            }
+        });
+    }
+
     /// Retrieve and decompress data
     pub async fn retrieve_compressed(&self, key: &str) -> Result<Vec<u8>, NeuroQuantumError> {
         tracing::info!("Retrieving compressed data for key: {}", key);
 
-        // Retrieve from storage (acquire read lock)
-        let serialized = {
-            let storage = self.storage.read().await;
-            storage
-                .retrieve(key)
-                .await
-                .map_err(|e| NeuroQuantumError::StorageError(e.to_string()))?
-        };
+        let stored = self.retrieve_stored(key).await?;
+        let (algorithm, payload) = compression::decode_stored(&stored)?;
+        let data = self.compressor(algorithm).decompress(payload).await?;
+
+        tracing::info!(
+            "Successfully retrieved and decompressed {} bytes ({})",
+            data.len(),
+            algorithm
+        );
+
+        Ok(data)
+    }
 
-        // Check if data exists
-        let data = match serialized {
-            | Some(data) => data,
-            | None => {
-                return Err(NeuroQuantumError::NotFound(format!(
-                    "Key '{key}' not found"
-                )));
+    /// Algorithm a stored value was compressed with
+    pub async fn stored_compression(
+        &self,
+        key: &str,
+    ) -> Result<CompressionAlgorithm, NeuroQuantumError> {
+        let stored = self.retrieve_stored(key).await?;
+        compression::decode_stored(&stored).map(|(algorithm, _)| algorithm)
+    }
+
+    /// Why a stored value was not compressed
+    ///
+    /// Returns `None` for values stored with a compression algorithm.
+    pub async fn stored_uncompressed_reason(
+        &self,
+        key: &str,
+    ) -> Result<Option<UncompressedReason>, NeuroQuantumError> {
+        let stored = self.retrieve_stored(key).await?;
+        match compression::decode_stored(&stored)? {
+            | (CompressionAlgorithm::None, payload) => {
+                compression::decode_uncompressed(payload).map(|(reason, _)| Some(reason))
             },
-        };
+            | _ => Ok(None),
+        }
+    }
 
-        // Deserialize compressed data
-        let compressed: CompressedDNA = serde_json::from_slice(&data)
-            .map_err(|e| NeuroQuantumError::SerializationError(e.to_string()))?;
+    /// Compressor implementing `algorithm`
+    fn compressor(&self, algorithm: CompressionAlgorithm) -> &dyn Compressor {
+        compressor_for(&self.dna_compressor, algorithm)
+    }
 
-        // Decompress using DNA algorithm
-        let data = self
-            .dna_compressor
-            .decompress(&compressed)
+    /// Store several values with DNA compression in one storage transaction
+    ///
+    /// Values are compressed concurrently, at most
+    /// [`DNACompressionConfig::thread_count`](dna::DNACompressionConfig) at a
+    /// time. If writing any of them fails, none of the keys are stored.
+    pub async fn store_batch(
+        &mut self,
+        items: &[(String, Vec<u8>)],
+    ) -> Result<(), NeuroQuantumError> {
+        self.ensure_initialized().await?;
+        tracing::info!("Storing batch of {} values", items.len());
+
+        let data = items.iter().map(|(_, data)| data.clone()).collect();
+        let payloads = self
+            .run_concurrently(data, |dna, data| async move {
+                compression::encode_dna_adaptive(&dna, &data).await
+            })
+            .await?;
+        let entries: Vec<(String, Vec<u8>)> = items
+            .iter()
+            .map(|(key, _)| key.clone())
+            .zip(payloads)
+            .collect();
+
+        let mut storage = self.storage.write().await;
+        storage
+            .store_batch(&entries)
             .await
-            .map_err(|e| NeuroQuantumError::CompressionError(e.to_string()))?;
+            .map_err(|e| NeuroQuantumError::StorageError(e.to_string()))?;
+        drop(storage);
 
-        tracing::info!(
-            "Successfully retrieved and decompressed {} bytes",
-            data.len()
-        );
+        for ((_, data), (_, stored)) in items.iter().zip(&entries) {
+            self.compression_ratios.record(data.len(), stored.len());
+        }
+        Ok(())
+    }
 
-        Ok(data)
+    /// Retrieve and decompress several values
+    ///
+    /// Results are in the order of `keys`, with `None` for missing or expired
+    /// keys. Values are decompressed concurrently, at most
+    /// [`DNACompressionConfig::thread_count`](dna::DNACompressionConfig) at a
+    /// time.
+    pub async fn retrieve_batch(
+        &self,
+        keys: &[String],
+    ) -> Result<Vec<Option<Vec<u8>>>, NeuroQuantumError> {
+        self.ensure_initialized().await?;
+        tracing::info!("Retrieving batch of {} keys", keys.len());
+
+        let mut stored = Vec::with_capacity(keys.len());
+        {
+            let storage = self.storage.read().await;
+            for key in keys {
+                stored.push(
+                    storage
+                        .retrieve(key)
+                        .await
+                        .map_err(|e| NeuroQuantumError::StorageError(e.to_string()))?,
+                );
+            }
+        }
+
+        self.run_concurrently(stored, |dna, stored| async move {
+            let Some(stored) = stored else {
+                return Ok(None);
+            };
+            let (algorithm, payload) = compression::decode_stored(&stored)?;
+            compressor_for(&dna, algorithm)
+                .decompress(payload)
+                .await
+                .map(Some)
+        })
+        .await
     }
 
-    /// Get compression statistics
-    #[must_use]
-    pub fn get_compression_stats(&self) -> CompressionMetrics {
-        self.dna_compressor.get_metrics()
+    /// Run `op` on each input on concurrent tasks, at most `thread_count` at
+    /// a time, returning the outputs in input order
+    async fn run_concurrently<T, R, F, Fut>(
+        &self,
+        inputs: Vec<T>,
+        op: F,
+    ) -> Result<Vec<R>, NeuroQuantumError>
+    where
+        T: Send + 'static,
+        R: Send + 'static,
+        F: Fn(dna::QuantumDNACompressor, T) -> Fut,
+        Fut: std::future::Future<Output = Result<R, NeuroQuantumError>> + Send + 'static,
+    {
+        let permits = std::sync::Arc::new(tokio::sync::Semaphore::new(
+            self.config.dna_compression.thread_count.max(1),
+        ));
+        let mut tasks = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            let permit = std::sync::Arc::clone(&permits)
+                .acquire_owned()
+                .await
+                .map_err(|e| NeuroQuantumError::CoreError(e.to_string()))?;
+            let task = op(self.dna_compressor.clone(), input);
+            tasks.push(tokio::spawn(async move {
+                let _permit = permit;
+                task.await
+            }));
+        }
+
+        let mut outputs = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            let output = task
+                .await
+                .map_err(|e| NeuroQuantumError::CoreError(format!("Batch task failed: {e}")))??;
+            outputs.push(output);
+        }
+        Ok(outputs)
     }
 
-    /// Validate stored compressed data integrity
-    pub async fn validate_data_integrity(&self, key: &str) -> Result<bool, NeuroQuantumError> {
+    /// Fail if the database was created with the deprecated constructors and
+    /// [`init()`](Self::init) was never called
+    async fn ensure_initialized(&self) -> Result<(), NeuroQuantumError> {
+        if self.storage.read().await.is_initialized() {
+            Ok(())
+        } else {
+            Err(NeuroQuantumError::NotInitialized(
+                "NeuroQuantumDB::init() must be called before use; prefer NeuroQuantumDBBuilder"
+                    .to_string(),
+            ))
+        }
+    }
+
+    /// Raw stored value of `key`
+    async fn retrieve_stored(&self, key: &str) -> Result<Vec<u8>, NeuroQuantumError> {
+        self.ensure_initialized().await?;
+
         let serialized = {
             let storage = self.storage.read().await;
             storage
@@ -556,23 +849,28 @@ impl NeuroQuantumDB {
                 .map_err(|e| NeuroQuantumError::StorageError(e.to_string()))?
         };
 
-        // Check if data exists
-        let data = match serialized {
-            | Some(data) => data,
-            | None => {
-                return Err(NeuroQuantumError::NotFound(format!(
-                    "Key '{key}' not found"
-                )));
-            },
-        };
+        serialized.ok_or_else(|| NeuroQuantumError::NotFound(format!("Key '{key}' not found")))
+    }
+
+    /// Get compression statistics
+    #[must_use]
+    pub fn get_compression_stats(&self) -> CompressionMetrics {
+        self.dna_compressor.get_metrics()
+    }
 
-        let compressed: CompressedDNA = serde_json::from_slice(&data)
-            .map_err(|e| NeuroQuantumError::SerializationError(e.to_string()))?;
+    /// Rolling distribution of the compression ratios of recent writes
+    ///
+    /// Register a clone with a Prometheus registry to export it.
+    #[must_use]
+    pub const fn compression_ratio_metrics(&self) -> &monitoring::CompressionRatioMetrics {
+        &self.compression_ratios
+    }
 
-        self.dna_compressor
-            .validate(&compressed)
-            .await
-            .map_err(|e| NeuroQuantumError::CompressionError(e.to_string()))
+    /// Validate stored compressed data integrity
+    pub async fn validate_data_integrity(&self, key: &str) -> Result<bool, NeuroQuantumError> {
+        let stored = self.retrieve_stored(key).await?;
+        let (algorithm, payload) = compression::decode_stored(&stored)?;
+        self.compressor(algorithm).validate(payload).await
     }
 
     /// Get mutable reference to storage engine.
@@ -610,6 +908,27 @@ impl NeuroQuantumDB {
     pub const fn dna_compressor(&self) -> &dna::QuantumDNACompressor {
         &self.dna_compressor
     }
+
+    /// Get the configuration the database was built with.
+    #[must_use]
+    pub const fn config(&self) -> &NeuroQuantumConfig {
+        &self.config
+    }
+}
+
+/// Compressor implementing `algorithm`, using `dna` for DNA compression
+fn compressor_for(
+    dna: &dna::QuantumDNACompressor,
+    algorithm: CompressionAlgorithm,
+) -> &dyn Compressor {
+    static ZSTD: compression::ZstdCompressor =
+        compression::ZstdCompressor::new(compression::DEFAULT_ZSTD_LEVEL);
+    match algorithm {
+        | CompressionAlgorithm::Dna => dna,
+        | CompressionAlgorithm::Zstd => &ZSTD,
+        | CompressionAlgorithm::Lz4 => &compression::Lz4Compressor,
+        | CompressionAlgorithm::None => &compression::NoCompression,
+    }
 }
 
 #[allow(deprecated)]
@@ -632,6 +951,7 @@ pub struct NeuroQuantumDBCore {
     quantum_ops_rate: f32,
     synaptic_adaptations: u64,
     avg_compression_ratio: f32,
+    storage: Option<std::sync::Arc<tokio::sync::RwLock<storage::StorageEngine>>>,
 }
 
 impl NeuroQuantumDBCore {
@@ -644,9 +964,20 @@ impl NeuroQuantumDBCore {
             quantum_ops_rate: 0.0,
             synaptic_adaptations: 0,
             avg_compression_ratio: 1000.0,
+            storage: None,
         })
     }
 
+    /// Attach the storage engine searched by [`Self::quantum_search`]
+    #[must_use]
+    pub fn with_storage(
+        mut self,
+        storage: std::sync::Arc<tokio::sync::RwLock<storage::StorageEngine>>,
+    ) -> Self {
+        self.storage = Some(storage);
+        self
+    }
+
     /// For testing: initialize with predefined parameters
     ///
     /// # Errors
@@ -659,6 +990,7 @@ impl NeuroQuantumDBCore {
             quantum_ops_rate: 100.0,
             synaptic_adaptations: 50,
             avg_compression_ratio: 500.0,
+            storage: None,
         })
     }
 
@@ -687,83 +1019,445 @@ impl NeuroQuantumDBCore {
     }
 
     /// Execute quantum search with Grover's algorithm
+    ///
+    /// `request.query` names the table to search and `request.filters` are
+    /// column predicates of the form `{"column": "name", "op": "eq", "value": "Ada"}`
+    /// (`op` defaults to `eq`; also `ne`, `lt`, `lte`, `gt`, `gte`, `contains`).
+    /// Every row of the table is one basis state and the oracle marks the rows
+    /// matching all predicates.
+    ///
+    /// The matching rows are resolved by the storage engine: predicates other
+    /// than `ne` and `contains` become a WHERE clause, which reads only the
+    /// candidate rows through a secondary index on a filtered column when one
+    /// exists and scans the table otherwise. The amplification is simulated
+    /// over a register holding the table's row count, taken from its primary
+    /// key index, with the matching rows as the marked states.
+    ///
+    /// The amplification schedule is chosen as follows:
+    /// - `request.grover_iterations` runs exactly that many iterations;
+    /// - `request.estimated_matches = Some(k)` runs `⌊π/4·√(N/k)⌋` iterations;
+    /// - otherwise the number of matches is treated as unknown and the
+    ///   exponential search of Boyer, Brassard, Høyer and Tapp is used, retrying
+    ///   with a random iteration count below a growing bound until a measurement
+    ///   hits a marked row.
+    ///
+    /// The random iteration counts and measurements are drawn from a generator
+    /// seeded with `request.seed` when set, so the same seeded request over the
+    /// same rows always returns the same result.
+    ///
+    /// The reported speedup is measured on the executed lookup: the table's
+    /// row count over the rows the storage engine examined to resolve the
+    /// predicates. It is 1.0 when no index narrowed the rows.
+    ///
+    /// Unless `request.grover_iterations` is set, small search spaces where
+    /// Grover isn't expected to need at least half the oracle queries of a
+    /// classical scan skip the simulation and rank the matches uniformly.
     pub async fn quantum_search(&self, request: QueryRequest) -> Result<QueryResult> {
+        use rand::SeedableRng;
+
         info!("Executing quantum search with Grover's algorithm");
 
-        // Implement actual quantum search algorithm using Grover's algorithm simulation
-        // Use a minimum search space to ensure meaningful quantum speedup
-        let search_space_size = request.filters.len().max(MIN_QUANTUM_SEARCH_SPACE);
-        let optimal_iterations = ((std::f64::consts::PI / 4.0) * (search_space_size as f64).sqrt())
-            .ceil()
-            .max(1.0) as usize;
-
-        // Simulate quantum superposition and amplitude amplification
-        let mut amplitudes = vec![1.0 / (search_space_size as f64).sqrt(); search_space_size];
-        let mut matching_items = Vec::new();
-
-        // Apply Grover iterations
-        for iteration in 0..optimal_iterations {
-            // Oracle function: mark target states
-            for (i, filter) in request.filters.iter().enumerate() {
-                if self.evaluate_quantum_filter(filter) {
-                    amplitudes[i] = -amplitudes[i]; // Phase flip for matching items
-                    if iteration == optimal_iterations - 1 {
-                        matching_items.push(serde_json::json!({
-                            "id": i,
-                            "data": format!("quantum_result_{}", i),
-                            "probability": amplitudes[i].abs(),
-                            "filter_match": filter
-                        }));
-                    }
-                }
-            }
+        let storage = self
+            .storage
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Quantum search requires an attached storage engine"))?;
+        let table = request.query.trim();
+
+        let (row_ids, candidates, stats) = {
+            let storage = storage.read().await;
+            let row_ids = storage
+                .table_row_ids(table)
+                .ok_or_else(|| anyhow::anyhow!("Table '{table}' does not exist"))?;
+            let conditions: Vec<storage::Condition> = request
+                .filters
+                .iter()
+                .filter_map(Self::quantum_filter_condition)
+                .collect();
+            let query = storage::SelectQuery {
+                table: table.to_string(),
+                columns: vec!["*".to_string()],
+                where_clause: (!conditions.is_empty())
+                    .then_some(storage::WhereClause { conditions }),
+                order_by: None,
+                limit: None,
+                offset: None,
+            };
+            let (candidates, stats) = storage.select_rows_with_stats(&query).await?;
+            (row_ids, candidates, stats)
+        };
+        let search_space_size = row_ids.len();
 
-            // Diffusion operator: inversion about average
-            let average = amplitudes.iter().sum::<f64>() / amplitudes.len() as f64;
-            for amplitude in &mut amplitudes {
-                *amplitude = 2.0f64.mul_add(average, -*amplitude);
+        // The oracle applies every predicate, including those the engine can't evaluate
+        let rows: Vec<storage::Row> = candidates
+            .into_iter()
+            .filter(|row| {
+                request
+                    .filters
+                    .iter()
+                    .all(|filter| Self::evaluate_quantum_filter(row, filter))
+            })
+            .collect();
+        let match_count = rows.len();
+        let quantum_speedup = search_space_size as f32 / stats.rows_examined.max(1) as f32;
+        debug!(
+            "Quantum search over {} rows examined {} (index scan: {}) for {} matches",
+            search_space_size, stats.rows_examined, stats.index_scan, match_count
+        );
+
+        if match_count == 0 {
+            return Ok(QueryResult {
+                results: Vec::new(),
+                total_count: 0,
+                quantum_speedup,
+                compression_savings: self.avg_compression_ratio,
+                neuromorphic_optimizations: self.synaptic_adaptations as u32,
+            });
+        }
+
+        // Encode the rows in the smallest register that holds them; padding states are never marked
+        let qubits = (usize::BITS - (search_space_size - 1).leading_zeros()).max(1) as usize;
+        if qubits > MAX_QUANTUM_SEARCH_QUBITS {
+            anyhow::bail!(
+                "Table '{table}' has {search_space_size} rows, more than quantum search can simulate"
+            );
+        }
+        let state_size = 1usize << qubits;
+        let (basis_states, marked) = Self::mark_basis_states(&row_ids, &rows);
+
+        if request.grover_iterations.is_none() {
+            let speedup =
+                Self::estimated_quantum_speedup(search_space_size, state_size, match_count);
+            if speedup < MIN_QUANTUM_SPEEDUP {
+                info!(
+                    "Quantum search over {} rows with {} matches: estimated speedup {:.2} below {}, skipping amplification",
+                    search_space_size, match_count, speedup, MIN_QUANTUM_SPEEDUP
+                );
+                return Ok(self.classical_search_result(rows, quantum_speedup, &request));
             }
+            debug!(
+                "Quantum search over {} rows with {} matches: estimated speedup {:.2}, using Grover",
+                search_space_size, match_count, speedup
+            );
         }
 
-        // Convert JSON values to SearchResultItem format
-        let search_results: Vec<SearchResultItem> = matching_items
+        let oracle =
+            std::sync::Arc::new(quantum_processor::DatabaseOracle::new(marked.clone(), true));
+        let mut processor = quantum_processor::QuantumStateProcessor::new(
+            qubits,
+            oracle,
+            quantum_processor::QuantumProcessorConfig::default(),
+        )?;
+
+        let oracle_queries = match (request.grover_iterations, request.estimated_matches) {
+            | (Some(iterations), _) => {
+                Self::run_grover_iterations(&mut processor, iterations)?;
+                iterations
+            },
+            | (None, Some(estimated)) => {
+                let iterations = Self::grover_iterations_for(state_size, estimated);
+                Self::run_grover_iterations(&mut processor, iterations)?;
+                iterations
+            },
+            | (None, None) => match request.seed {
+                | Some(seed) => Self::exponential_grover_search(
+                    &mut processor,
+                    &marked,
+                    &mut rand::rngs::StdRng::seed_from_u64(seed),
+                )?,
+                | None => Self::exponential_grover_search(
+                    &mut processor,
+                    &marked,
+                    &mut rand::thread_rng(),
+                )?,
+            },
+        };
+
+        // Rank the matching rows by their amplified measurement probability
+        let mut matches: Vec<(f64, storage::Row)> = rows
             .into_iter()
-            .enumerate()
-            .map(|(index, item)| SearchResultItem {
-                id: index.to_string(),
-                data: item,
-                relevance_score: amplitudes.get(index).copied().unwrap_or(0.0) as f32,
-                synaptic_strength: amplitudes.get(index).copied().unwrap_or(0.0) as f32,
+            .map(|row| {
+                let probability = basis_states
+                    .get(&row.id)
+                    .map_or(0.0, |&state| processor.get_probability(state));
+                (probability, row)
             })
             .collect();
+        matches.sort_by(|(a, _), (b, _)| b.total_cmp(a));
 
-        let total_results = search_results.len() as u64;
+        let limit = if request.limit == 0 {
+            usize::MAX
+        } else {
+            request.limit as usize
+        };
+        let search_results: Vec<SearchResultItem> = matches
+            .into_iter()
+            .skip(request.offset as usize)
+            .take(limit)
+            .map(|(probability, row)| SearchResultItem {
+                id: row.id.to_string(),
+                data: Self::row_to_json(&row),
+                relevance_score: probability as f32,
+                synaptic_strength: probability.sqrt() as f32,
+            })
+            .collect();
 
-        // Calculate quantum speedup (theoretical vs classical)
-        // Classical search would need O(N) operations, quantum needs O(sqrt(N))
-        let classical_time = search_space_size as f32;
-        let quantum_time = optimal_iterations.max(1) as f32;
-        let quantum_speedup = (classical_time / quantum_time).max(MIN_QUANTUM_SPEEDUP);
+        debug!(
+            "Grover amplification used {} oracle queries over {} states",
+            oracle_queries, state_size
+        );
 
         Ok(QueryResult {
             results: search_results,
-            total_count: total_results,
+            total_count: match_count as u64,
             quantum_speedup,
             compression_savings: self.avg_compression_ratio,
             neuromorphic_optimizations: self.synaptic_adaptations as u32,
         })
     }
 
-    /// Evaluate quantum filter conditions
-    fn evaluate_quantum_filter(&self, filter: &serde_json::Value) -> bool {
-        // Simulate quantum measurement and filter evaluation
-        if let Some(condition) = filter.as_str() {
-            // Simple pattern matching for demonstration
-            condition.contains("quantum")
-                || condition.contains("neuro")
-                || condition.contains("dna")
+    /// Map each row to the basis state at its position in `row_ids` and mark
+    /// the states of the matching `rows`
+    fn mark_basis_states(
+        row_ids: &[storage::RowId],
+        rows: &[storage::Row],
+    ) -> (std::collections::HashMap<storage::RowId, usize>, Vec<bool>) {
+        let basis_states: std::collections::HashMap<storage::RowId, usize> = row_ids
+            .iter()
+            .enumerate()
+            .map(|(state, &row_id)| (row_id, state))
+            .collect();
+        let mut marked = vec![false; row_ids.len()];
+        for row in rows {
+            if let Some(&state) = basis_states.get(&row.id) {
+                marked[state] = true;
+            }
+        }
+        (basis_states, marked)
+    }
+
+    /// Expected oracle-query speedup of Grover search over a classical scan
+    ///
+    /// A linear scan finds one of `k` matches among `N` rows after `(N+1)/(k+1)`
+    /// queries on average, Grover after `⌊π/4·√(N'/k)⌋` iterations over the
+    /// padded state size `N'`. Zero iterations means amplification can't help.
+    fn estimated_quantum_speedup(
+        search_space_size: usize,
+        state_size: usize,
+        marked: usize,
+    ) -> f64 {
+        let iterations = Self::grover_iterations_for(state_size, marked);
+        if iterations == 0 {
+            return 0.0;
+        }
+        let scan_queries = (search_space_size + 1) as f64 / (marked + 1) as f64;
+        scan_queries / iterations as f64
+    }
+
+    /// Answer a quantum search with its matching rows, without amplification
+    ///
+    /// Matches keep table order and share the probability of measuring one of
+    /// them after a perfect search.
+    fn classical_search_result(
+        &self,
+        rows: Vec<storage::Row>,
+        quantum_speedup: f32,
+        request: &QueryRequest,
+    ) -> QueryResult {
+        let match_count = rows.len();
+        let probability = 1.0 / match_count as f64;
+        let limit = if request.limit == 0 {
+            usize::MAX
         } else {
-            false
+            request.limit as usize
+        };
+        let results = rows
+            .into_iter()
+            .skip(request.offset as usize)
+            .take(limit)
+            .map(|row| SearchResultItem {
+                id: row.id.to_string(),
+                data: Self::row_to_json(&row),
+                relevance_score: probability as f32,
+                synaptic_strength: probability.sqrt() as f32,
+            })
+            .collect();
+
+        QueryResult {
+            results,
+            total_count: match_count as u64,
+            quantum_speedup,
+            compression_savings: self.avg_compression_ratio,
+            neuromorphic_optimizations: self.synaptic_adaptations as u32,
+        }
+    }
+
+    /// Optimal Grover iteration count `⌊π/4·√(N/k)⌋` for `k` marked states
+    fn grover_iterations_for(state_size: usize, marked: usize) -> usize {
+        ((std::f64::consts::PI / 4.0) * (state_size as f64 / marked.max(1) as f64).sqrt()).floor()
+            as usize
+    }
+
+    /// Prepare the uniform superposition and apply `iterations` Grover rotations
+    fn run_grover_iterations(
+        processor: &mut quantum_processor::QuantumStateProcessor,
+        iterations: usize,
+    ) -> Result<()> {
+        processor.initialize_superposition()?;
+        for _ in 0..iterations {
+            processor.apply_oracle()?;
+            processor.apply_diffusion_operator()?;
+        }
+        Ok(())
+    }
+
+    /// Grover search for an unknown number of marked states (BBHT schedule)
+    ///
+    /// Each round runs a uniformly random iteration count below the current
+    /// bound and measures; on a miss the bound grows by 6/5 up to `√N`. The processor is left
+    /// in the state of the successful round. Returns the oracle queries used.
+    fn exponential_grover_search(
+        processor: &mut quantum_processor::QuantumStateProcessor,
+        marked: &[bool],
+        rng: &mut impl rand::Rng,
+    ) -> Result<usize> {
+        const GROWTH: f64 = 6.0 / 5.0;
+        const MAX_ROUNDS: usize = 64;
+
+        let state_size = processor.state_size();
+        let max_bound = (state_size as f64).sqrt();
+        let mut bound = 1.0f64;
+        let mut oracle_queries = 0;
+
+        for _ in 0..MAX_ROUNDS {
+            let iterations = rng.gen_range(0..bound.ceil() as usize);
+            Self::run_grover_iterations(processor, iterations)?;
+            oracle_queries += iterations;
+
+            let sample: f64 = rng.gen();
+            let mut cumulative = 0.0;
+            let measured = (0..state_size)
+                .find(|&index| {
+                    cumulative += processor.get_probability(index);
+                    sample < cumulative
+                })
+                .unwrap_or(state_size - 1);
+            if marked.get(measured).copied().unwrap_or(false) {
+                return Ok(oracle_queries);
+            }
+
+            bound = (bound * GROWTH).min(max_bound);
+        }
+
+        tracing::warn!("Exponential Grover search did not hit a marked state");
+        Ok(oracle_queries)
+    }
+
+    /// Storage condition for a filter the engine can evaluate, narrowing the
+    /// rows through an index on its column
+    ///
+    /// `ne` and `contains` filters, and values other than numbers, strings
+    /// and booleans, are left to [`Self::evaluate_quantum_filter`].
+    fn quantum_filter_condition(filter: &serde_json::Value) -> Option<storage::Condition> {
+        let column = filter.get("column")?.as_str()?;
+        let operator = match filter
+            .get("op")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("eq")
+        {
+            | "eq" => storage::ComparisonOperator::Equal,
+            | "lt" => storage::ComparisonOperator::LessThan,
+            | "lte" => storage::ComparisonOperator::LessThanOrEqual,
+            | "gt" => storage::ComparisonOperator::GreaterThan,
+            | "gte" => storage::ComparisonOperator::GreaterThanOrEqual,
+            | _ => return None,
+        };
+        let value = match filter.get("value")? {
+            | serde_json::Value::Number(n) => n.as_i64().map_or_else(
+                || n.as_f64().map(storage::Value::Float),
+                |i| Some(storage::Value::Integer(i)),
+            )?,
+            | serde_json::Value::String(s) => storage::Value::text(s.clone()),
+            | serde_json::Value::Bool(b) => storage::Value::Boolean(*b),
+            | _ => return None,
+        };
+        Some(storage::Condition {
+            field: column.to_string(),
+            operator,
+            value,
+        })
+    }
+
+    /// Evaluate a column predicate against a stored row (the Grover oracle)
+    fn evaluate_quantum_filter(row: &storage::Row, filter: &serde_json::Value) -> bool {
+        let Some(column) = filter.get("column").and_then(serde_json::Value::as_str) else {
+            return false;
+        };
+        let Some(expected) = filter.get("value") else {
+            return false;
+        };
+        let op = filter
+            .get("op")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("eq");
+        let actual = row
+            .fields
+            .get(column)
+            .map_or(serde_json::Value::Null, Self::value_to_json);
+
+        let ordering = match (&actual, expected) {
+            | (serde_json::Value::Number(a), serde_json::Value::Number(b)) => a
+                .as_f64()
+                .zip(b.as_f64())
+                .and_then(|(a, b)| a.partial_cmp(&b)),
+            | (serde_json::Value::String(a), serde_json::Value::String(b)) => Some(a.cmp(b)),
+            | (a, b) if a == b => Some(std::cmp::Ordering::Equal),
+            | _ => None,
+        };
+
+        match op {
+            | "eq" => ordering == Some(std::cmp::Ordering::Equal),
+            | "ne" => ordering != Some(std::cmp::Ordering::Equal),
+            | "lt" => ordering == Some(std::cmp::Ordering::Less),
+            | "lte" => matches!(
+                ordering,
+                Some(std::cmp::Ordering::Less | std::cmp::Ordering::Equal)
+            ),
+            | "gt" => ordering == Some(std::cmp::Ordering::Greater),
+            | "gte" => matches!(
+                ordering,
+                Some(std::cmp::Ordering::Greater | std::cmp::Ordering::Equal)
+            ),
+            | "contains" => match (&actual, expected) {
+                | (serde_json::Value::String(a), serde_json::Value::String(b)) => {
+                    a.contains(b.as_str())
+                },
+                | _ => false,
+            },
+            | _ => false,
+        }
+    }
+
+    /// Convert a stored row into a JSON object of its column values
+    fn row_to_json(row: &storage::Row) -> serde_json::Value {
+        // Sorted by column name so the output doesn't depend on hash order
+        let mut fields: Vec<(&String, &storage::Value)> = row.fields.iter().collect();
+        fields.sort_by_key(|(name, _)| *name);
+        let fields: serde_json::Map<String, serde_json::Value> = fields
+            .into_iter()
+            .map(|(name, value)| (name.clone(), Self::value_to_json(value)))
+            .collect();
+        serde_json::Value::Object(fields)
+    }
+
+    fn value_to_json(value: &storage::Value) -> serde_json::Value {
+        match value {
+            | storage::Value::Integer(i) => serde_json::json!(i),
+            | storage::Value::Float(f) => serde_json::json!(f),
+            | storage::Value::Text(s) => serde_json::Value::String(s.as_ref().clone()),
+            | storage::Value::Boolean(b) => serde_json::Value::Bool(*b),
+            | storage::Value::Timestamp(ts) => serde_json::Value::String(ts.to_rfc3339()),
+            | storage::Value::Binary(bytes) => serde_json::json!(bytes.as_ref()),
+            | storage::Value::Null => serde_json::Value::Null,
         }
     }
 
@@ -906,6 +1600,15 @@ pub struct QueryRequest {
     pub limit: u32,
     pub offset: u32,
     pub filters: Vec<serde_json::Value>,
+    /// Expected number of matching rows; unknown counts use exponential search
+    #[serde(default)]
+    pub estimated_matches: Option<usize>,
+    /// Fixed Grover iteration count, overriding the derived schedule
+    #[serde(default)]
+    pub grover_iterations: Option<usize>,
+    /// Seed for the simulator's random choices, making the search reproducible
+    #[serde(default)]
+    pub seed: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -1024,6 +1727,39 @@ mod tests {
         let _ = std::fs::remove_dir_all(&temp_dir);
     }
 
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn test_uninitialized_db_rejects_operations() {
+        let temp_dir =
+            std::env::temp_dir().join(format!("nqdb_uninit_test_{}", uuid::Uuid::new_v4()));
+        let mut db = NeuroQuantumDB::with_config(NeuroQuantumConfig {
+            storage_path: temp_dir.clone(),
+            ..Default::default()
+        });
+
+        let not_initialized = |result: Result<_, NeuroQuantumError>| {
+            matches!(result, Err(NeuroQuantumError::NotInitialized(_)))
+        };
+        assert!(not_initialized(db.store_compressed("key", b"value").await));
+        assert!(not_initialized(
+            db.retrieve_compressed("key").await.map(drop)
+        ));
+        assert!(not_initialized(
+            db.validate_data_integrity("key").await.map(drop)
+        ));
+        assert!(not_initialized(
+            db.store_batch(&[("key".to_string(), b"value".to_vec())])
+                .await
+        ));
+
+        // After init() the same instance works
+        db.init().await.unwrap();
+        db.store_compressed("key", b"value").await.unwrap();
+        assert_eq!(db.retrieve_compressed("key").await.unwrap(), b"value");
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_neuro_quantum_db_builder_with_config() {
         // Test the builder with custom configuration
@@ -1064,22 +1800,320 @@ mod tests {
         let _ = std::fs::remove_dir_all(&temp_dir);
     }
 
+    /// Create a `particles` table with ids `1..=count` and a core attached to it
+    async fn quantum_search_fixture(
+        count: i64,
+    ) -> (NeuroQuantumDB, NeuroQuantumDBCore, std::path::PathBuf) {
+        let temp_dir =
+            std::env::temp_dir().join(format!("nqdb_quantum_search_{}", uuid::Uuid::new_v4()));
+        let db = NeuroQuantumDBBuilder::new()
+            .storage_path(temp_dir.clone())
+            .build()
+            .await
+            .unwrap();
+
+        {
+            let mut storage = db.storage_mut().await;
+            let schema = storage::TableSchema {
+                name: "particles".to_string(),
+                columns: vec![
+                    storage::ColumnDefinition {
+                        name: "id".to_string(),
+                        data_type: storage::DataType::Integer,
+                        nullable: false,
+                        default_value: None,
+                        auto_increment: false,
+                        ..Default::default()
+                    },
+                    storage::ColumnDefinition {
+                        name: "name".to_string(),
+                        data_type: storage::DataType::Text,
+                        nullable: false,
+                        default_value: None,
+                        auto_increment: false,
+                        ..Default::default()
+                    },
+                ],
+                primary_key: "id".to_string(),
+                created_at: chrono::Utc::now(),
+                version: 1,
+                auto_increment_columns: std::collections::HashMap::new(),
+                id_strategy: storage::IdGenerationStrategy::AutoIncrement,
+                foreign_keys: Vec::new(),
+                ..Default::default()
+            };
+            storage.create_table(schema).await.unwrap();
+
+            for (id, name) in (1..=count).map(|i| (i, format!("particle_{i}"))) {
+                let mut fields = std::collections::HashMap::new();
+                fields.insert("id".to_string(), storage::Value::Integer(id));
+                fields.insert("name".to_string(), storage::Value::text(name));
+                let row = storage::Row {
+                    id: 0,
+                    fields,
+                    created_at: chrono::Utc::now(),
+                    updated_at: chrono::Utc::now(),
+                };
+                storage.insert_row("particles", row).await.unwrap();
+            }
+        }
+
+        let db_core = NeuroQuantumDBCore::new_test()
+            .unwrap()
+            .with_storage(db.storage_engine_arc());
+        (db, db_core, temp_dir)
+    }
+
+    fn particles_request(filter: serde_json::Value) -> QueryRequest {
+        QueryRequest {
+            query: "particles".to_string(),
+            quantum_level: 2,
+            use_grovers: true,
+            limit: 0,
+            offset: 0,
+            filters: vec![filter],
+            estimated_matches: None,
+            grover_iterations: None,
+            seed: None,
+        }
+    }
+
     #[tokio::test]
     async fn test_quantum_search() {
-        let db_core = NeuroQuantumDBCore::new_test().unwrap();
+        let (_db, db_core, temp_dir) = quantum_search_fixture(20).await;
 
         let request = QueryRequest {
-            query: "quantum search test".to_string(),
-            quantum_level: 2,
-            use_grovers: true,
             limit: 10,
-            offset: 0,
-            filters: vec![serde_json::json!("quantum"), serde_json::json!("search")],
+            estimated_matches: Some(1),
+            ..particles_request(serde_json::json!({ "column": "name", "value": "particle_13" }))
+        };
+
+        let result = db_core.quantum_search(request).await.unwrap();
+        assert_eq!(result.total_count, 1);
+        assert_eq!(result.results.len(), 1);
+        assert_eq!(result.results[0].data["id"], 13);
+        assert_eq!(result.results[0].data["name"], "particle_13");
+        // The marked row is amplified well above the uniform 1/32
+        assert!(result.results[0].relevance_score > 0.9);
+        // Without an index on `name` every row is examined
+        assert!((result.quantum_speedup - 1.0).abs() < f32::EPSILON);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_quantum_search_reads_candidates_through_index() {
+        let (db, db_core, temp_dir) = quantum_search_fixture(20).await;
+        db.storage_mut()
+            .await
+            .create_index(storage::IndexDefinition {
+                name: "idx_particles_name".to_string(),
+                table: "particles".to_string(),
+                columns: vec!["name".to_string()],
+                unique: false,
+            })
+            .await
+            .unwrap();
+
+        let request = QueryRequest {
+            estimated_matches: Some(1),
+            ..particles_request(serde_json::json!({ "column": "name", "value": "particle_13" }))
+        };
+        let result = db_core.quantum_search(request).await.unwrap();
+
+        assert_eq!(result.total_count, 1);
+        assert_eq!(result.results[0].data["id"], 13);
+        assert!(result.results[0].relevance_score > 0.9);
+        // The index lookup examined one of the 20 rows
+        assert!((result.quantum_speedup - 20.0).abs() < f32::EPSILON);
+
+        // `contains` is evaluated by the oracle on the rows the index narrowed
+        let request = QueryRequest {
+            filters: vec![
+                serde_json::json!({ "column": "name", "op": "gte", "value": "particle_2" }),
+                serde_json::json!({ "column": "name", "op": "contains", "value": "0" }),
+            ],
+            ..particles_request(serde_json::Value::Null)
+        };
+        let result = db_core.quantum_search(request).await.unwrap();
+        assert_eq!(result.total_count, 1);
+        assert_eq!(result.results[0].data["name"], "particle_20");
+        // particle_2, particle_20 and particle_3..particle_9 sort at or above particle_2
+        assert!((result.quantum_speedup - 20.0 / 9.0).abs() < 1e-6);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_quantum_search_multiple_solutions() {
+        let (_db, db_core, temp_dir) = quantum_search_fixture(16).await;
+
+        for marked in [2, 4] {
+            let request = QueryRequest {
+                estimated_matches: Some(marked),
+                ..particles_request(
+                    serde_json::json!({ "column": "id", "op": "lte", "value": marked }),
+                )
+            };
+            let result = db_core.quantum_search(request).await.unwrap();
+
+            assert_eq!(result.total_count, marked as u64);
+            let success: f32 = result.results.iter().map(|r| r.relevance_score).sum();
+            assert!(success > 0.9, "k={marked}: success probability {success}");
+        }
+
+        // A single-solution rotation over-rotates with two solutions
+        let request = QueryRequest {
+            estimated_matches: Some(2),
+            grover_iterations: Some(NeuroQuantumDBCore::grover_iterations_for(16, 1)),
+            ..particles_request(serde_json::json!({ "column": "id", "op": "lte", "value": 2 }))
+        };
+        let result = db_core.quantum_search(request).await.unwrap();
+        let success: f32 = result.results.iter().map(|r| r.relevance_score).sum();
+        assert!(success < 0.5);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_quantum_search_unknown_solution_count() {
+        let (_db, db_core, temp_dir) = quantum_search_fixture(16).await;
+
+        let request =
+            particles_request(serde_json::json!({ "column": "id", "op": "lte", "value": 4 }));
+        let result = db_core.quantum_search(request).await.unwrap();
+
+        assert_eq!(result.total_count, 4);
+        let mut ids: Vec<i64> = result
+            .results
+            .iter()
+            .map(|r| r.data["id"].as_i64().unwrap())
+            .collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![1, 2, 3, 4]);
+        assert!(result.quantum_speedup > 0.0);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_quantum_search_falls_back_to_classical_scan() {
+        // One match among two rows: a scan needs 1.5 queries on average, Grover one iteration
+        assert!(NeuroQuantumDBCore::estimated_quantum_speedup(2, 2, 1) < MIN_QUANTUM_SPEEDUP);
+        let (_db, db_core, temp_dir) = quantum_search_fixture(2).await;
+
+        let request = particles_request(serde_json::json!({ "column": "id", "value": 2 }));
+        let result = db_core.quantum_search(request).await.unwrap();
+
+        assert_eq!(result.total_count, 1);
+        assert_eq!(result.results.len(), 1);
+        assert_eq!(result.results[0].data["name"], "particle_2");
+        assert!((result.results[0].relevance_score - 1.0).abs() < f32::EPSILON);
+        assert!((result.quantum_speedup - 1.0).abs() < f32::EPSILON);
+
+        // An explicit iteration count always runs Grover
+        let request = QueryRequest {
+            grover_iterations: Some(0),
+            ..particles_request(serde_json::json!({ "column": "id", "value": 2 }))
+        };
+        let result = db_core.quantum_search(request).await.unwrap();
+        assert!((result.results[0].relevance_score - 0.5).abs() < 1e-6);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_quantum_search_uses_grover_for_large_search_space() {
+        // 32.5 scan queries on average vs. 6 Grover iterations
+        assert!(NeuroQuantumDBCore::estimated_quantum_speedup(64, 64, 1) > MIN_QUANTUM_SPEEDUP);
+        let (_db, db_core, temp_dir) = quantum_search_fixture(64).await;
+
+        let request = QueryRequest {
+            estimated_matches: Some(1),
+            ..particles_request(serde_json::json!({ "column": "id", "value": 42 }))
+        };
+        let result = db_core.quantum_search(request).await.unwrap();
+
+        assert_eq!(result.total_count, 1);
+        assert_eq!(result.results[0].data["id"], 42);
+        assert!(result.results[0].relevance_score > 0.9);
+        // The oracle is built from a scan of all 64 rows
+        assert!((result.quantum_speedup - 1.0).abs() < f32::EPSILON);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_seeded_quantum_search_is_reproducible() {
+        let (_db, db_core, temp_dir) = quantum_search_fixture(64).await;
+
+        // No estimated match count, so the randomized exponential search runs
+        let seeded = || QueryRequest {
+            seed: Some(0x5eed),
+            ..particles_request(serde_json::json!({ "column": "id", "op": "lte", "value": 3 }))
         };
+        let first = db_core.quantum_search(seeded()).await.unwrap();
+        let second = db_core.quantum_search(seeded()).await.unwrap();
+
+        assert_eq!(first.total_count, 3);
+        assert_eq!(first.results.len(), 3);
+        assert_eq!(
+            serde_json::to_vec(&first).unwrap(),
+            serde_json::to_vec(&second).unwrap()
+        );
 
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_quantum_search_marks_the_matching_rows() {
+        let (db, _db_core, temp_dir) = quantum_search_fixture(8).await;
+        let storage = db.storage().await;
+        let row_ids = storage.table_row_ids("particles").unwrap();
+        let query = storage::SelectQuery {
+            table: "particles".to_string(),
+            columns: vec!["*".to_string()],
+            where_clause: Some(storage::WhereClause {
+                conditions: NeuroQuantumDBCore::quantum_filter_condition(
+                    &serde_json::json!({ "column": "id", "op": "gt", "value": 6 }),
+                )
+                .into_iter()
+                .collect(),
+            }),
+            order_by: None,
+            limit: None,
+            offset: None,
+        };
+        let rows = storage.select_rows(&query).await.unwrap();
+
+        let (basis_states, marked) = NeuroQuantumDBCore::mark_basis_states(&row_ids, &rows);
+        assert_eq!(marked.len(), 8);
+        assert_eq!(marked.iter().filter(|&&m| m).count(), 2);
+        // Each match is marked at its own state, not at the first states
+        for row in &rows {
+            assert!(marked[basis_states[&row.id]]);
+        }
+        assert!(!marked[basis_states[&row_ids[0]]]);
+
+        drop(storage);
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_quantum_search_iteration_override() {
+        let (_db, db_core, temp_dir) = quantum_search_fixture(16).await;
+
+        // Zero iterations leaves the uniform superposition untouched
+        let request = QueryRequest {
+            grover_iterations: Some(0),
+            ..particles_request(serde_json::json!({ "column": "id", "value": 7 }))
+        };
         let result = db_core.quantum_search(request).await.unwrap();
-        assert!(result.total_count > 0);
-        assert!(result.quantum_speedup > 1.0);
+
+        assert_eq!(result.results.len(), 1);
+        assert!((result.results[0].relevance_score - 1.0 / 16.0).abs() < 1e-6);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
     }
 
     #[tokio::test]
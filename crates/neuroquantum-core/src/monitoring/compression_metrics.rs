@@ -0,0 +1,242 @@
+//! DNA Compression Ratio Distribution
+//!
+//! [`CompressionMetrics`](crate::dna::CompressionMetrics) only keeps running
+//! aggregates, which hide a regression affecting a fraction of the writes.
+//! [`CompressionRatioMetrics`] keeps a rolling window of per-write ratios
+//! instead, answering percentile queries and exporting them to Prometheus.
+//!
+//! Ratios are `original size / stored size`, so higher is better and data
+//! stored uncompressed lands just below 1.0.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use prometheus::core::{Collector, Desc};
+use prometheus::proto::MetricFamily;
+use prometheus::{GaugeVec, Histogram, HistogramOpts, Opts};
+use serde::{Deserialize, Serialize};
+
+/// Quantiles exported as gauges
+const EXPORTED_QUANTILES: [(f64, &str); 3] = [(0.5, "0.5"), (0.95, "0.95"), (0.99, "0.99")];
+
+/// Size and age limits of the rolling window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompressionRatioConfig {
+    /// Maximum number of ratios kept; the oldest are dropped first
+    pub max_samples: usize,
+    /// Ratios older than this are dropped
+    pub max_age: Duration,
+}
+
+impl Default for CompressionRatioConfig {
+    fn default() -> Self {
+        Self {
+            max_samples: 1024,
+            max_age: Duration::from_secs(3600),
+        }
+    }
+}
+
+/// Snapshot of the ratios in the window
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CompressionRatioSummary {
+    pub samples: usize,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub p50: f64,
+    pub p95: f64,
+    pub p99: f64,
+}
+
+/// Rolling distribution of per-write compression ratios
+///
+/// Cloning is cheap and clones share the window, so one instance can be
+/// recorded to by the database and registered with a Prometheus registry.
+#[derive(Clone)]
+pub struct CompressionRatioMetrics {
+    config: CompressionRatioConfig,
+    samples: Arc<Mutex<VecDeque<(Instant, f64)>>>,
+    /// Every ratio ever recorded
+    histogram: Histogram,
+    /// Quantiles over the current window, refreshed on collection
+    window_quantiles: GaugeVec,
+}
+
+impl CompressionRatioMetrics {
+    /// Create an empty window
+    pub fn new(config: CompressionRatioConfig) -> Result<Self, prometheus::Error> {
+        let histogram = Histogram::with_opts(
+            HistogramOpts::new(
+                "neuroquantum_dna_store_compression_ratio",
+                "Original size divided by stored size per compressed write",
+            )
+            .buckets(vec![0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 5.0, 8.0]),
+        )?;
+        let window_quantiles = GaugeVec::new(
+            Opts::new(
+                "neuroquantum_dna_store_compression_ratio_window",
+                "Compression ratio quantiles over the recent writes",
+            ),
+            &["quantile"],
+        )?;
+
+        Ok(Self {
+            config,
+            samples: Arc::new(Mutex::new(VecDeque::new())),
+            histogram,
+            window_quantiles,
+        })
+    }
+
+    #[must_use]
+    pub const fn config(&self) -> &CompressionRatioConfig {
+        &self.config
+    }
+
+    /// Record a write of `original_bytes` that took `stored_bytes` in storage
+    ///
+    /// Empty writes carry no ratio and are ignored.
+    pub fn record(&self, original_bytes: usize, stored_bytes: usize) {
+        if original_bytes == 0 || stored_bytes == 0 {
+            return;
+        }
+        let ratio = original_bytes as f64 / stored_bytes as f64;
+        self.histogram.observe(ratio);
+
+        let now = Instant::now();
+        let mut samples = self.lock();
+        samples.push_back((now, ratio));
+        while samples.len() > self.config.max_samples {
+            samples.pop_front();
+        }
+        Self::prune_expired(&mut samples, now, self.config.max_age);
+    }
+
+    /// Ratio below which a fraction `p` (0.0..=1.0) of the window falls
+    ///
+    /// Uses the nearest-rank method. Returns `None` for an empty window.
+    #[must_use]
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        let sorted = self.sorted_window();
+        Self::nearest_rank(&sorted, p)
+    }
+
+    /// Median ratio of the window
+    #[must_use]
+    pub fn p50(&self) -> Option<f64> {
+        self.percentile(0.5)
+    }
+
+    /// 95th percentile ratio of the window
+    #[must_use]
+    pub fn p95(&self) -> Option<f64> {
+        self.percentile(0.95)
+    }
+
+    /// Number of ratios in the window
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.sorted_window().len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Summarize the window, or `None` if it is empty
+    #[must_use]
+    pub fn summary(&self) -> Option<CompressionRatioSummary> {
+        let sorted = self.sorted_window();
+        let (&min, &max) = (sorted.first()?, sorted.last()?);
+        Some(CompressionRatioSummary {
+            samples: sorted.len(),
+            min,
+            max,
+            mean: sorted.iter().sum::<f64>() / sorted.len() as f64,
+            p50: Self::nearest_rank(&sorted, 0.5)?,
+            p95: Self::nearest_rank(&sorted, 0.95)?,
+            p99: Self::nearest_rank(&sorted, 0.99)?,
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<(Instant, f64)>> {
+        self.samples.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn prune_expired(samples: &mut VecDeque<(Instant, f64)>, now: Instant, max_age: Duration) {
+        while samples
+            .front()
+            .is_some_and(|(at, _)| now.duration_since(*at) > max_age)
+        {
+            samples.pop_front();
+        }
+    }
+
+    /// Unexpired ratios in ascending order
+    fn sorted_window(&self) -> Vec<f64> {
+        let mut samples = self.lock();
+        Self::prune_expired(&mut samples, Instant::now(), self.config.max_age);
+        let mut sorted: Vec<f64> = samples.iter().map(|(_, ratio)| *ratio).collect();
+        drop(samples);
+        sorted.sort_by(f64::total_cmp);
+        sorted
+    }
+
+    fn nearest_rank(sorted: &[f64], p: f64) -> Option<f64> {
+        if sorted.is_empty() {
+            return None;
+        }
+        let rank = (p.clamp(0.0, 1.0) * sorted.len() as f64).ceil() as usize;
+        sorted.get(rank.saturating_sub(1)).copied()
+    }
+}
+
+impl Default for CompressionRatioMetrics {
+    /// Creates a window with the default limits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the metric options are invalid, which they are not.
+    #[allow(clippy::expect_used)] // Acceptable for Default impl at startup
+    fn default() -> Self {
+        Self::new(CompressionRatioConfig::default())
+            .expect("Failed to create compression ratio metrics")
+    }
+}
+
+impl std::fmt::Debug for CompressionRatioMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CompressionRatioMetrics")
+            .field("config", &self.config)
+            .field("samples", &self.lock().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Collector for CompressionRatioMetrics {
+    fn desc(&self) -> Vec<&Desc> {
+        let mut desc = self.histogram.desc();
+        desc.extend(self.window_quantiles.desc());
+        desc
+    }
+
+    fn collect(&self) -> Vec<MetricFamily> {
+        let sorted = self.sorted_window();
+        for (p, label) in EXPORTED_QUANTILES {
+            match Self::nearest_rank(&sorted, p) {
+                | Some(ratio) => self.window_quantiles.with_label_values(&[label]).set(ratio),
+                | None => {
+                    // An empty window has no quantiles rather than stale ones
+                    let _ = self.window_quantiles.remove_label_values(&[label]);
+                },
+            }
+        }
+
+        let mut families = self.histogram.collect();
+        families.extend(self.window_quantiles.collect());
+        families
+    }
+}
@@ -2,12 +2,17 @@
 //!
 //! Provides comprehensive monitoring capabilities including:
 //! - Query metrics and slow query logging
+//! - Rolling DNA compression ratio percentiles
 //! - Prometheus metrics export
 //! - Performance tracking
 
+pub mod compression_metrics;
 pub mod prometheus;
 pub mod query_metrics;
 
+pub use compression_metrics::{
+    CompressionRatioConfig, CompressionRatioMetrics, CompressionRatioSummary,
+};
 pub use prometheus::MetricsExporter;
 pub use query_metrics::{
     AdvancedQueryMetrics, MonitoringConfig, QueryExecutionParams, SlowQueryEntry,
@@ -20,6 +20,7 @@ use crate::error::{CoreError, CoreResult};
 #[derive(Debug)]
 pub struct NeonOptimizer {
     enabled: bool,
+    fusion_enabled: bool,
     optimization_stats: OptimizationStats,
 }
 
@@ -34,10 +35,12 @@ pub struct OptimizationStats {
     pub matrix_ops_speedup: f32,
     pub quantum_ops_speedup: f32,
     pub total_bytes_processed: u64,
+    /// Operations merged into another operation's pass by [`NeonOptimizer::apply_fused`]
+    pub fused_operations: u64,
 }
 
 /// Quantum operations that can be accelerated with NEON
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum QuantumOperation {
     /// Normalize quantum state vector to unit length
     Normalize,
@@ -45,6 +48,50 @@ pub enum QuantumOperation {
     PhaseFlip,
     /// Apply Hadamard gate transformation
     Hadamard,
+    /// Grover diffusion: invert every amplitude about the mean amplitude
+    Diffusion,
+}
+
+impl QuantumOperation {
+    /// Whether the operation can share a pass with its neighbours
+    ///
+    /// Fusible operations map every amplitude `a` to `s * a + c * mean`, with
+    /// `s` and `c` depending only on sums over the state vector, so a run of
+    /// them composes into a single map. Hadamard mixes amplitude pairs and
+    /// always runs on its own.
+    #[must_use]
+    pub const fn is_fusible(self) -> bool {
+        !matches!(self, Self::Hadamard)
+    }
+}
+
+/// Quantum operations grouped into passes over the state vector
+///
+/// Created by [`NeonOptimizer::fuse`] and applied with
+/// [`NeonOptimizer::apply_fused`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FusedOperation {
+    passes: Vec<Vec<QuantumOperation>>,
+}
+
+impl FusedOperation {
+    /// Operations of each pass, in application order
+    #[must_use]
+    pub fn passes(&self) -> &[Vec<QuantumOperation>] {
+        &self.passes
+    }
+
+    /// Total number of operations
+    #[must_use]
+    pub fn num_operations(&self) -> usize {
+        self.passes.iter().map(Vec::len).sum()
+    }
+
+    /// Number of operations that don't need a pass of their own
+    #[must_use]
+    pub fn fusion_count(&self) -> usize {
+        self.num_operations() - self.passes.len()
+    }
 }
 
 impl NeonOptimizer {
@@ -60,6 +107,7 @@ impl NeonOptimizer {
 
         Ok(Self {
             enabled,
+            fusion_enabled: true,
             optimization_stats: OptimizationStats::default(),
         })
     }
@@ -354,6 +402,20 @@ impl NeonOptimizer {
         }
     }
 
+    /// Check if consecutive operations are fused by [`fuse`](Self::fuse)
+    #[must_use]
+    pub const fn is_fusion_enabled(&self) -> bool {
+        self.fusion_enabled
+    }
+
+    /// Enable or disable operation fusion
+    ///
+    /// With fusion disabled, [`fuse`](Self::fuse) puts every operation in a
+    /// pass of its own.
+    pub fn set_fusion_enabled(&mut self, enabled: bool) {
+        self.fusion_enabled = enabled;
+    }
+
     /// NEON-optimized DNA compression using quaternary encoding
     /// Encodes 4 bytes at a time using parallel bit manipulation
     pub fn vectorized_dna_compression(&mut self, data: &[u8]) -> CoreResult<Vec<u8>> {
@@ -553,10 +615,147 @@ impl NeonOptimizer {
                     imag_parts[i * 2 + 1] = (i0 - i1) * inv_sqrt2;
                 }
             },
+            | QuantumOperation::Diffusion => {
+                let (mean_re, mean_im) = Self::mean_amplitude(real_parts, imag_parts);
+                for (r, i) in real_parts.iter_mut().zip(imag_parts.iter_mut()) {
+                    *r = 2.0f32.mul_add(mean_re, -*r);
+                    *i = 2.0f32.mul_add(mean_im, -*i);
+                }
+            },
         }
         Ok(())
     }
 
+    /// Mean of the complex amplitudes, as real and imaginary part
+    fn mean_amplitude(real_parts: &[f32], imag_parts: &[f32]) -> (f32, f32) {
+        if real_parts.is_empty() {
+            return (0.0, 0.0);
+        }
+        let len = real_parts.len() as f32;
+        (
+            real_parts.iter().sum::<f32>() / len,
+            imag_parts.iter().sum::<f32>() / len,
+        )
+    }
+
+    /// Group consecutive fusible operations into shared passes
+    ///
+    /// A Grover iteration's phase flip and diffusion, for example, become a
+    /// single pass instead of two, saving a round trip through the state
+    /// vector. Operations are kept in order, so applying the result with
+    /// [`apply_fused`](Self::apply_fused) matches applying `ops` one by one.
+    #[must_use]
+    pub fn fuse(&self, ops: &[QuantumOperation]) -> FusedOperation {
+        let mut passes: Vec<Vec<QuantumOperation>> = Vec::new();
+        for &op in ops {
+            match passes.last_mut() {
+                | Some(pass)
+                    if self.fusion_enabled
+                        && op.is_fusible()
+                        && pass.iter().all(|prev| prev.is_fusible()) =>
+                {
+                    pass.push(op);
+                },
+                | _ => passes.push(vec![op]),
+            }
+        }
+        FusedOperation { passes }
+    }
+
+    /// Apply operations grouped by [`fuse`](Self::fuse)
+    ///
+    /// Passes with several operations read the state once to sum it up and
+    /// once more to write the result; the merged operations are counted in
+    /// [`OptimizationStats::fused_operations`].
+    pub fn apply_fused(
+        &mut self,
+        real_parts: &mut [f32],
+        imag_parts: &mut [f32],
+        fused: &FusedOperation,
+    ) -> CoreResult<()> {
+        if real_parts.len() != imag_parts.len() {
+            return Err(CoreError::InvalidOperation(
+                "Real and imaginary parts must have same length".to_string(),
+            ));
+        }
+
+        for pass in &fused.passes {
+            match pass.as_slice() {
+                | [] => {},
+                | [op] => self.quantum_state_operation(real_parts, imag_parts, *op)?,
+                | ops => {
+                    self.fused_pass(real_parts, imag_parts, ops);
+                    self.optimization_stats.fused_operations += ops.len() as u64 - 1;
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply a run of fusible operations as one map `a -> s * a + c * mean`
+    fn fused_pass(
+        &mut self,
+        real_parts: &mut [f32],
+        imag_parts: &mut [f32],
+        ops: &[QuantumOperation],
+    ) {
+        let len = real_parts.len() as f32;
+        let (mean_re, mean_im) = Self::mean_amplitude(real_parts, imag_parts);
+        let norm_sq: f32 = real_parts
+            .iter()
+            .zip(imag_parts.iter())
+            .map(|(r, i)| r.mul_add(*r, i * i))
+            .sum();
+        let mean_sq = mean_re.mul_add(mean_re, mean_im * mean_im);
+
+        // Compose the maps: the mean of `s * a + c * mean` is `(s + c) * mean`
+        let (mut scale, mut shift) = (1.0f32, 0.0f32);
+        for op in ops {
+            match op {
+                | QuantumOperation::PhaseFlip => {
+                    scale = -scale;
+                    shift = -shift;
+                },
+                | QuantumOperation::Diffusion => {
+                    (scale, shift) = (-scale, 2.0f32.mul_add(scale, shift));
+                },
+                | QuantumOperation::Normalize => {
+                    // |s * a + c * mean|^2 summed, using sum(a) = len * mean
+                    let current = (scale * scale).mul_add(
+                        norm_sq,
+                        (2.0 * scale).mul_add(shift, shift * shift) * len * mean_sq,
+                    );
+                    let norm = current.max(0.0).sqrt();
+                    if norm > 1e-10 {
+                        scale /= norm;
+                        shift /= norm;
+                    }
+                },
+                // Never part of a fused pass
+                | QuantumOperation::Hadamard => {},
+            }
+        }
+
+        if self.enabled {
+            #[cfg(target_arch = "aarch64")]
+            {
+                self.optimization_stats.simd_operations += 1;
+                crate::simd::neon::safe_neon_scale_shift(real_parts, scale, shift * mean_re);
+                crate::simd::neon::safe_neon_scale_shift(imag_parts, scale, shift * mean_im);
+                return;
+            }
+            #[cfg(not(target_arch = "aarch64"))]
+            {
+                self.optimization_stats.scalar_fallbacks += 1;
+            }
+        }
+
+        for (r, i) in real_parts.iter_mut().zip(imag_parts.iter_mut()) {
+            *r = scale.mul_add(*r, shift * mean_re);
+            *i = scale.mul_add(*i, shift * mean_im);
+        }
+    }
+
     /// NEON-optimized parallel search operations
     pub fn parallel_search(&mut self, haystack: &[u8], needle: &[u8]) -> CoreResult<Vec<usize>> {
         if needle.is_empty() || haystack.len() < needle.len() {
@@ -624,6 +823,7 @@ impl Default for NeonOptimizer {
     fn default() -> Self {
         Self::new().unwrap_or_else(|_| Self {
             enabled: false,
+            fusion_enabled: true,
             optimization_stats: OptimizationStats::default(),
         })
     }
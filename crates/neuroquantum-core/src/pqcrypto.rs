@@ -1,12 +1,16 @@
 #![allow(clippy::similar_names)]
 
-use std::sync::Arc;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{Arc, PoisonError, RwLock};
 
 /// Post-Quantum Cryptography utilities for `NeuroQuantumDB`
 ///
 /// Implements NIST post-quantum standards:
 /// - ML-KEM (Kyber) for key encapsulation (using `RustCrypto` ml-kem crate)
 /// - ML-DSA (Dilithium) for digital signatures
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
 use ml_kem::{
     kem::{Decapsulate, Encapsulate},
     Ciphertext, EncodedSizeUser, KemCore, MlKem768,
@@ -15,6 +19,10 @@ use pqcrypto_mldsa::mldsa65;
 use pqcrypto_traits::sign::{PublicKey as SignPublicKey, SecretKey as SignSecretKey};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use tokio::io::AsyncWriteExt;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::storage::encryption::EncryptionManager;
 
 /// Type aliases for ML-KEM-768 (NIST Security Level 3)
 type MlKemDecapsulationKey = <MlKem768 as KemCore>::DecapsulationKey;
@@ -25,6 +33,13 @@ const MLKEM768_CIPHERTEXT_SIZE: usize = 1088;
 /// ML-KEM-768 shared secret size in bytes (32 bytes)
 const MLKEM768_SHARED_SECRET_SIZE: usize = 32;
 
+/// Magic bytes opening every versioned ciphertext produced by [`KeyManager`]
+const VERSIONED_MAGIC: &[u8; 4] = b"NQPQ";
+/// Header length: magic (4) + key version (4, big-endian)
+const VERSIONED_HEADER_SIZE: usize = 8;
+/// AES-GCM nonce size in bytes
+const AES_GCM_NONCE_SIZE: usize = 12;
+
 #[derive(Error, Debug)]
 pub enum PQCryptoError {
     #[error("Signature verification failed")]
@@ -44,6 +59,18 @@ pub enum PQCryptoError {
 
     #[error("Invalid key format: {0}")]
     InvalidKeyFormat(String),
+
+    #[error("Unknown key version: {0}")]
+    UnknownKeyVersion(KeyVersion),
+
+    #[error("Encryption failed: {0}")]
+    EncryptionFailed(String),
+
+    #[error("Decryption failed: {0}")]
+    DecryptionFailed(String),
+
+    #[error("Key storage error: {0}")]
+    KeyStorage(String),
 }
 
 /// Post-quantum cryptographic key manager
@@ -274,6 +301,393 @@ impl Default for PQCryptoManager {
     }
 }
 
+/// Raw key material of one [`PQCryptoManager`], as written to sealed storage
+#[derive(Serialize, Deserialize, Zeroize, ZeroizeOnDrop)]
+#[allow(clippy::struct_field_names)]
+struct StoredKeyPair {
+    mlkem_encapsulation_key: Vec<u8>,
+    mlkem_decapsulation_key: Vec<u8>,
+    mldsa_public_key: Vec<u8>,
+    mldsa_secret_key: Vec<u8>,
+}
+
+impl PQCryptoManager {
+    fn to_stored(&self) -> StoredKeyPair {
+        StoredKeyPair {
+            mlkem_encapsulation_key: self.mlkem_encapsulation_key.as_bytes().to_vec(),
+            mlkem_decapsulation_key: self.mlkem_decapsulation_key.as_bytes().to_vec(),
+            mldsa_public_key: self.mldsa_public_key.as_bytes().to_vec(),
+            mldsa_secret_key: self.mldsa_secret_key.as_bytes().to_vec(),
+        }
+    }
+
+    fn from_stored(stored: &StoredKeyPair) -> Result<Self, PQCryptoError> {
+        let invalid =
+            |what: &str| PQCryptoError::InvalidKeyFormat(format!("invalid stored {what}"));
+
+        let ek: ml_kem::Encoded<MlKemEncapsulationKey> = stored
+            .mlkem_encapsulation_key
+            .as_slice()
+            .try_into()
+            .map_err(|_| invalid("ML-KEM encapsulation key"))?;
+        let dk: ml_kem::Encoded<MlKemDecapsulationKey> = stored
+            .mlkem_decapsulation_key
+            .as_slice()
+            .try_into()
+            .map_err(|_| invalid("ML-KEM decapsulation key"))?;
+        let mldsa_pk = mldsa65::PublicKey::from_bytes(&stored.mldsa_public_key)
+            .map_err(|_| invalid("ML-DSA public key"))?;
+        let mldsa_sk = mldsa65::SecretKey::from_bytes(&stored.mldsa_secret_key)
+            .map_err(|_| invalid("ML-DSA secret key"))?;
+
+        Ok(Self {
+            mlkem_encapsulation_key: Arc::new(MlKemEncapsulationKey::from_bytes(&ek)),
+            mlkem_decapsulation_key: Arc::new(MlKemDecapsulationKey::from_bytes(&dk)),
+            mldsa_public_key: Arc::new(mldsa_pk),
+            mldsa_secret_key: Arc::new(mldsa_sk),
+        })
+    }
+}
+
+/// Version of a key pair managed by [`KeyManager`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct KeyVersion(pub u32);
+
+impl std::fmt::Display for KeyVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "v{}", self.0)
+    }
+}
+
+struct KeyRing {
+    current: KeyVersion,
+    versions: BTreeMap<KeyVersion, Arc<PQCryptoManager>>,
+}
+
+/// Serialized form of a [`KeyRing`]
+#[derive(Serialize, Deserialize)]
+struct StoredKeyRing {
+    current: KeyVersion,
+    versions: BTreeMap<KeyVersion, StoredKeyPair>,
+}
+
+/// Versioned post-quantum key manager supporting online key rotation
+///
+/// Data is encrypted with AES-256-GCM under a fresh ML-KEM-768 shared secret
+/// encapsulated to the current key version. The ciphertext layout is
+/// `"NQPQ" | version (u32 BE) | KEM ciphertext (1088) | nonce (12) | AEAD ciphertext`,
+/// with the header authenticated as associated data.
+///
+/// [`KeyManager::rotate`] makes a new version current while older versions stay
+/// available for decryption. Existing data is upgraded lazily with
+/// [`KeyManager::reencrypt`] or in bulk with [`KeyManager::reencrypt_all`], after
+/// which the old version can be dropped with [`KeyManager::retire`].
+///
+/// The key ring is persisted with [`KeyManager::save`] and restored with
+/// [`KeyManager::load`], sealed with the storage master key.
+pub struct KeyManager {
+    ring: RwLock<KeyRing>,
+}
+
+impl std::fmt::Debug for KeyManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyManager")
+            .field("current", &self.current_version())
+            .field("versions", &self.versions())
+            .finish()
+    }
+}
+
+impl KeyManager {
+    /// Create a key manager holding a freshly generated version 1
+    #[must_use]
+    pub fn new() -> Self {
+        let initial = KeyVersion(1);
+        let mut versions = BTreeMap::new();
+        versions.insert(initial, Arc::new(PQCryptoManager::new()));
+        Self {
+            ring: RwLock::new(KeyRing {
+                current: initial,
+                versions,
+            }),
+        }
+    }
+
+    /// Version used for new encryptions
+    #[must_use]
+    pub fn current_version(&self) -> KeyVersion {
+        self.ring
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .current
+    }
+
+    /// All versions still available for decryption, oldest first
+    #[must_use]
+    pub fn versions(&self) -> Vec<KeyVersion> {
+        self.ring
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .versions
+            .keys()
+            .copied()
+            .collect()
+    }
+
+    /// Generate a new key version and make it current
+    ///
+    /// Previous versions remain available for decrypting existing data.
+    pub fn rotate(&self) -> Result<KeyVersion, PQCryptoError> {
+        // Key generation is slow; do it before taking the write lock
+        let keys = Arc::new(PQCryptoManager::new());
+
+        let mut ring = self.ring.write().unwrap_or_else(PoisonError::into_inner);
+        let next = ring.current.0.checked_add(1).ok_or_else(|| {
+            PQCryptoError::KeyGenerationFailed("key version space exhausted".to_string())
+        })?;
+        let version = KeyVersion(next);
+        ring.versions.insert(version, keys);
+        ring.current = version;
+
+        tracing::info!("🔄 Rotated post-quantum keys to {}", version);
+        Ok(version)
+    }
+
+    /// Drop an old key version; data still encrypted under it becomes unreadable
+    pub fn retire(&self, version: KeyVersion) -> Result<(), PQCryptoError> {
+        let mut ring = self.ring.write().unwrap_or_else(PoisonError::into_inner);
+        if version == ring.current {
+            return Err(PQCryptoError::InvalidKeyFormat(format!(
+                "cannot retire current key version {version}"
+            )));
+        }
+        ring.versions
+            .remove(&version)
+            .ok_or(PQCryptoError::UnknownKeyVersion(version))?;
+        Ok(())
+    }
+
+    /// Encrypt data under the current key version
+    pub fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, PQCryptoError> {
+        let (version, keys) = {
+            let ring = self.ring.read().unwrap_or_else(PoisonError::into_inner);
+            (ring.current, Arc::clone(&ring.versions[&ring.current]))
+        };
+
+        let (kem_ciphertext, shared_secret) = keys.encapsulate();
+        let mut nonce_bytes = [0u8; AES_GCM_NONCE_SIZE];
+        rand::RngCore::fill_bytes(&mut rand::thread_rng(), &mut nonce_bytes);
+
+        let mut output = Vec::with_capacity(
+            VERSIONED_HEADER_SIZE
+                + MLKEM768_CIPHERTEXT_SIZE
+                + AES_GCM_NONCE_SIZE
+                + plaintext.len()
+                + 16,
+        );
+        output.extend_from_slice(VERSIONED_MAGIC);
+        output.extend_from_slice(&version.0.to_be_bytes());
+
+        let cipher = Aes256Gcm::new_from_slice(&shared_secret)
+            .map_err(|e| PQCryptoError::EncryptionFailed(format!("invalid data key: {e}")))?;
+        let sealed = cipher
+            .encrypt(
+                &Nonce::from(nonce_bytes),
+                Payload {
+                    msg: plaintext,
+                    aad: &output[..VERSIONED_HEADER_SIZE],
+                },
+            )
+            .map_err(|e| PQCryptoError::EncryptionFailed(e.to_string()))?;
+
+        output.extend_from_slice(&kem_ciphertext);
+        output.extend_from_slice(&nonce_bytes);
+        output.extend_from_slice(&sealed);
+        Ok(output)
+    }
+
+    /// Decrypt data with the key version named in its header
+    pub fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, PQCryptoError> {
+        let version = Self::version_of(ciphertext)?;
+        let keys = self
+            .ring
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .versions
+            .get(&version)
+            .cloned()
+            .ok_or(PQCryptoError::UnknownKeyVersion(version))?;
+
+        let body = &ciphertext[VERSIONED_HEADER_SIZE..];
+        if body.len() < MLKEM768_CIPHERTEXT_SIZE + AES_GCM_NONCE_SIZE {
+            return Err(PQCryptoError::InvalidCiphertext(
+                "versioned ciphertext is truncated".to_string(),
+            ));
+        }
+        let (kem_ciphertext, rest) = body.split_at(MLKEM768_CIPHERTEXT_SIZE);
+        let (nonce_bytes, sealed) = rest.split_at(AES_GCM_NONCE_SIZE);
+
+        let shared_secret = keys.decapsulate(kem_ciphertext)?;
+        let cipher = Aes256Gcm::new_from_slice(&shared_secret)
+            .map_err(|e| PQCryptoError::DecryptionFailed(format!("invalid data key: {e}")))?;
+        let nonce: [u8; AES_GCM_NONCE_SIZE] = nonce_bytes
+            .try_into()
+            .map_err(|_| PQCryptoError::InvalidCiphertext("invalid nonce".to_string()))?;
+
+        cipher
+            .decrypt(
+                &Nonce::from(nonce),
+                Payload {
+                    msg: sealed,
+                    aad: &ciphertext[..VERSIONED_HEADER_SIZE],
+                },
+            )
+            .map_err(|_| {
+                PQCryptoError::DecryptionFailed(
+                    "authentication failed - wrong key or corrupted data".to_string(),
+                )
+            })
+    }
+
+    /// Read the key version tag from a versioned ciphertext header
+    pub fn version_of(ciphertext: &[u8]) -> Result<KeyVersion, PQCryptoError> {
+        if ciphertext.len() < VERSIONED_HEADER_SIZE || &ciphertext[..4] != VERSIONED_MAGIC {
+            return Err(PQCryptoError::InvalidCiphertext(
+                "missing versioned ciphertext header".to_string(),
+            ));
+        }
+        let mut tag = [0u8; 4];
+        tag.copy_from_slice(&ciphertext[4..VERSIONED_HEADER_SIZE]);
+        Ok(KeyVersion(u32::from_be_bytes(tag)))
+    }
+
+    /// Re-encrypt data under the current version if it uses an older one
+    ///
+    /// Returns `None` when the data is already current, so callers can upgrade
+    /// records lazily as they read them.
+    pub fn reencrypt(&self, ciphertext: &[u8]) -> Result<Option<Vec<u8>>, PQCryptoError> {
+        if Self::version_of(ciphertext)? == self.current_version() {
+            return Ok(None);
+        }
+        let plaintext = self.decrypt(ciphertext)?;
+        self.encrypt(&plaintext).map(Some)
+    }
+
+    /// Re-encrypt every ciphertext still under an older version, in place
+    ///
+    /// Returns the number of upgraded entries. This is CPU-bound; run it via
+    /// `tokio::task::spawn_blocking` to migrate data in the background.
+    pub fn reencrypt_all(&self, ciphertexts: &mut [Vec<u8>]) -> Result<usize, PQCryptoError> {
+        let mut upgraded = 0;
+        for ciphertext in ciphertexts.iter_mut() {
+            if let Some(fresh) = self.reencrypt(ciphertext)? {
+                *ciphertext = fresh;
+                upgraded += 1;
+            }
+        }
+        if upgraded > 0 {
+            tracing::info!(
+                "🔄 Re-encrypted {} entries under {}",
+                upgraded,
+                self.current_version()
+            );
+        }
+        Ok(upgraded)
+    }
+
+    /// Write all key versions to `path`, sealed with the storage master key
+    ///
+    /// The file is written next to `path` and renamed over it once synced, so
+    /// a crash leaves either the old or the new key ring.
+    pub async fn save(&self, path: &Path, master: &EncryptionManager) -> Result<(), PQCryptoError> {
+        let stored = {
+            let ring = self.ring.read().unwrap_or_else(PoisonError::into_inner);
+            StoredKeyRing {
+                current: ring.current,
+                versions: ring
+                    .versions
+                    .iter()
+                    .map(|(version, keys)| (*version, keys.to_stored()))
+                    .collect(),
+            }
+        };
+        let mut plaintext =
+            bincode::serialize(&stored).map_err(|e| PQCryptoError::EncodingError(e.to_string()))?;
+        let sealed = master.encrypt(&plaintext);
+        plaintext.zeroize();
+        let sealed = sealed.map_err(|e| PQCryptoError::EncryptionFailed(e.to_string()))?;
+        let bytes =
+            bincode::serialize(&sealed).map_err(|e| PQCryptoError::EncodingError(e.to_string()))?;
+
+        let storage_error = |e: std::io::Error| PQCryptoError::KeyStorage(e.to_string());
+        let tmp_path = path.with_extension("tmp");
+        let mut file = tokio::fs::File::create(&tmp_path)
+            .await
+            .map_err(storage_error)?;
+        // Restrict permissions before any key material is written
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(std::fs::Permissions::from_mode(0o600))
+                .await
+                .map_err(storage_error)?;
+        }
+        file.write_all(&bytes).await.map_err(storage_error)?;
+        file.sync_all().await.map_err(storage_error)?;
+        tokio::fs::rename(&tmp_path, path)
+            .await
+            .map_err(storage_error)?;
+
+        tracing::info!(
+            "🔐 Saved {} post-quantum key versions to {}",
+            stored.versions.len(),
+            path.display()
+        );
+        Ok(())
+    }
+
+    /// Read a key ring written by [`KeyManager::save`]
+    ///
+    /// Fails if the file was sealed with a different master key.
+    pub async fn load(path: &Path, master: &EncryptionManager) -> Result<Self, PQCryptoError> {
+        let bytes = tokio::fs::read(path)
+            .await
+            .map_err(|e| PQCryptoError::KeyStorage(e.to_string()))?;
+        let sealed: crate::storage::EncryptedData = bincode::deserialize(&bytes)
+            .map_err(|e| PQCryptoError::EncodingError(e.to_string()))?;
+        let mut plaintext = master.decrypt(&sealed).map_err(|_| {
+            PQCryptoError::DecryptionFailed(
+                "key ring was sealed with a different master key".to_string(),
+            )
+        })?;
+        let stored = bincode::deserialize::<StoredKeyRing>(&plaintext);
+        plaintext.zeroize();
+        let stored = stored.map_err(|e| PQCryptoError::EncodingError(e.to_string()))?;
+
+        if !stored.versions.contains_key(&stored.current) {
+            return Err(PQCryptoError::UnknownKeyVersion(stored.current));
+        }
+        let versions = stored
+            .versions
+            .iter()
+            .map(|(version, keys)| Ok((*version, Arc::new(PQCryptoManager::from_stored(keys)?))))
+            .collect::<Result<BTreeMap<_, _>, PQCryptoError>>()?;
+
+        Ok(Self {
+            ring: RwLock::new(KeyRing {
+                current: stored.current,
+                versions,
+            }),
+        })
+    }
+}
+
+impl Default for KeyManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -389,6 +803,118 @@ mod tests {
         manager.verify_quantum_claims(&claims).unwrap();
     }
 
+    #[test]
+    fn test_key_rotation_keeps_old_versions_readable() {
+        let manager = KeyManager::new();
+        assert_eq!(manager.current_version(), KeyVersion(1));
+
+        let v1_data = manager.encrypt(b"written under v1").unwrap();
+        assert_eq!(KeyManager::version_of(&v1_data).unwrap(), KeyVersion(1));
+
+        let rotated = manager.rotate().unwrap();
+        assert_eq!(rotated, KeyVersion(2));
+        assert_eq!(manager.current_version(), KeyVersion(2));
+        assert_eq!(manager.versions(), vec![KeyVersion(1), KeyVersion(2)]);
+
+        // Old data still decrypts with its tagged version
+        assert_eq!(manager.decrypt(&v1_data).unwrap(), b"written under v1");
+
+        // New data uses the current version
+        let v2_data = manager.encrypt(b"written under v2").unwrap();
+        assert_eq!(KeyManager::version_of(&v2_data).unwrap(), KeyVersion(2));
+        assert_eq!(manager.decrypt(&v2_data).unwrap(), b"written under v2");
+    }
+
+    #[test]
+    fn test_reencrypt_all_upgrades_and_allows_retirement() {
+        let manager = KeyManager::new();
+        let mut records: Vec<Vec<u8>> = (0..3)
+            .map(|i| manager.encrypt(format!("record {i}").as_bytes()).unwrap())
+            .collect();
+
+        manager.rotate().unwrap();
+        records.push(manager.encrypt(b"record 3").unwrap());
+
+        assert_eq!(manager.reencrypt_all(&mut records).unwrap(), 3);
+        assert_eq!(manager.reencrypt_all(&mut records).unwrap(), 0);
+        assert!(manager.reencrypt(&records[0]).unwrap().is_none());
+
+        manager.retire(KeyVersion(1)).unwrap();
+        assert!(manager.retire(KeyVersion(2)).is_err());
+        for (i, record) in records.iter().enumerate() {
+            assert_eq!(KeyManager::version_of(record).unwrap(), KeyVersion(2));
+            assert_eq!(
+                manager.decrypt(record).unwrap(),
+                format!("record {i}").as_bytes()
+            );
+        }
+    }
+
+    #[test]
+    fn test_retired_or_tampered_versions_fail() {
+        let manager = KeyManager::new();
+        let v1_data = manager.encrypt(b"secret").unwrap();
+        manager.rotate().unwrap();
+
+        // Rewriting the version tag breaks the authenticated header
+        let mut relabeled = v1_data.clone();
+        relabeled[4..8].copy_from_slice(&2u32.to_be_bytes());
+        assert!(manager.decrypt(&relabeled).is_err());
+
+        manager.retire(KeyVersion(1)).unwrap();
+        assert!(matches!(
+            manager.decrypt(&v1_data),
+            Err(PQCryptoError::UnknownKeyVersion(KeyVersion(1)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_key_ring_round_trips_sealed_with_master_key() {
+        use crate::storage::encryption::KeyStorageStrategy;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let master =
+            EncryptionManager::with_strategy(temp_dir.path(), KeyStorageStrategy::FileBased)
+                .await
+                .unwrap();
+        let path = temp_dir.path().join("field_keys.bin");
+
+        let manager = KeyManager::new();
+        let v1_data = manager.encrypt(b"written under v1").unwrap();
+        manager.rotate().unwrap();
+        let v2_data = manager.encrypt(b"written under v2").unwrap();
+        manager.save(&path, &master).await.unwrap();
+
+        // Key material is not stored in the clear
+        let on_disk = std::fs::read(&path).unwrap();
+        let secret = manager.ring.read().unwrap().versions[&KeyVersion(1)]
+            .mldsa_secret_key
+            .as_bytes()
+            .to_vec();
+        assert!(!on_disk
+            .windows(secret.len())
+            .any(|w| w == secret.as_slice()));
+
+        let loaded = KeyManager::load(&path, &master).await.unwrap();
+        assert_eq!(loaded.current_version(), KeyVersion(2));
+        assert_eq!(loaded.versions(), vec![KeyVersion(1), KeyVersion(2)]);
+        assert_eq!(loaded.decrypt(&v1_data).unwrap(), b"written under v1");
+        assert_eq!(loaded.decrypt(&v2_data).unwrap(), b"written under v2");
+        let fresh = loaded.encrypt(b"written after load").unwrap();
+        assert_eq!(manager.decrypt(&fresh).unwrap(), b"written after load");
+
+        // A different master key cannot open the key ring
+        let other_dir = tempfile::tempdir().unwrap();
+        let other_master =
+            EncryptionManager::with_strategy(other_dir.path(), KeyStorageStrategy::FileBased)
+                .await
+                .unwrap();
+        assert!(matches!(
+            KeyManager::load(&path, &other_master).await,
+            Err(PQCryptoError::DecryptionFailed(_))
+        ));
+    }
+
     #[test]
     fn test_invalid_signature_fails() {
         let manager = PQCryptoManager::new();
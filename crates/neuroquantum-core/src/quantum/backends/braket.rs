@@ -49,7 +49,10 @@ use std::time::Instant;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
-use super::{QuantumBackendConfig, QuantumBackendInfo, QuantumExecutionResult, QuantumProvider};
+use super::{
+    CircuitProfile, QuantumBackendConfig, QuantumBackendInfo, QuantumExecutionResult,
+    QuantumProvider,
+};
 use crate::error::{CoreError, CoreResult};
 
 // =============================================================================
@@ -283,8 +286,13 @@ impl BraketBackend {
 
     /// Submit a quantum task to AWS Braket
     ///
+    /// The circuit is checked with [`validate_circuit`](QuantumBackendInfo::validate_circuit)
+    /// first.
+    ///
     /// Note: This is a placeholder for actual AWS SDK integration.
     pub async fn submit_task(&self, circuit: &str) -> CoreResult<QuantumExecutionResult> {
+        self.validate_circuit(&CircuitProfile::from_qasm(circuit)?)?;
+
         if !self.has_credentials() {
             return Err(CoreError::invalid_operation(
                 "AWS credentials not configured. Set AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY \
@@ -375,6 +383,10 @@ impl QuantumBackendInfo for BraketBackend {
     fn provider(&self) -> QuantumProvider {
         QuantumProvider::AWSBraket
     }
+
+    fn max_circuit_depth(&self) -> Option<usize> {
+        self.config.common.max_circuit_depth
+    }
 }
 
 // =============================================================================
@@ -44,7 +44,10 @@ use std::time::Instant;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
-use super::{QuantumBackendConfig, QuantumBackendInfo, QuantumExecutionResult, QuantumProvider};
+use super::{
+    CircuitProfile, QuantumBackendConfig, QuantumBackendInfo, QuantumExecutionResult,
+    QuantumProvider,
+};
 use crate::error::{CoreError, CoreResult};
 
 // =============================================================================
@@ -309,9 +312,14 @@ impl IBMQuantumBackend {
 
     /// Submit a circuit to IBM Quantum Runtime API
     ///
+    /// The circuit is checked with [`validate_circuit`](QuantumBackendInfo::validate_circuit)
+    /// first.
+    ///
     /// Note: This is a placeholder for the actual HTTP client implementation.
     /// In production, this would use reqwest or similar to make API calls.
     pub async fn submit_circuit(&self, qasm: &str) -> CoreResult<QuantumExecutionResult> {
+        self.validate_circuit(&CircuitProfile::from_qasm(qasm)?)?;
+
         let api_token = self.get_api_token().ok_or_else(|| {
             CoreError::invalid_operation(
                 "IBM Quantum API token not configured. Set IBM_QUANTUM_API_KEY environment \
@@ -371,6 +379,10 @@ impl QuantumBackendInfo for IBMQuantumBackend {
     fn provider(&self) -> QuantumProvider {
         QuantumProvider::IBMQuantum
     }
+
+    fn max_circuit_depth(&self) -> Option<usize> {
+        self.config.common.max_circuit_depth
+    }
 }
 
 // =============================================================================
@@ -50,7 +50,9 @@ use std::time::Instant;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
-use super::{QuantumBackendConfig, QuantumBackendInfo, QuantumProvider};
+use super::{
+    CircuitError, CircuitProfile, QuantumBackendConfig, QuantumBackendInfo, QuantumProvider,
+};
 use crate::error::{CoreError, CoreResult};
 
 // =============================================================================
@@ -363,8 +365,13 @@ impl IonQBackend {
 
     /// Submit a circuit to `IonQ` API
     ///
+    /// The circuit is checked with [`validate_circuit`](QuantumBackendInfo::validate_circuit)
+    /// first.
+    ///
     /// Note: This is a placeholder for actual HTTP client implementation.
     pub async fn submit_circuit(&self, circuit: &IonQCircuit) -> CoreResult<IonQResult> {
+        self.validate_circuit(&circuit.profile()?)?;
+
         let api_key = self.get_api_key().ok_or_else(|| {
             CoreError::invalid_operation(
                 "IonQ API key not configured. Set IONQ_API_KEY environment variable \
@@ -431,6 +438,10 @@ impl QuantumBackendInfo for IonQBackend {
     fn provider(&self) -> QuantumProvider {
         QuantumProvider::IonQ
     }
+
+    fn max_circuit_depth(&self) -> Option<usize> {
+        self.config.common.max_circuit_depth
+    }
 }
 
 // =============================================================================
@@ -446,6 +457,13 @@ pub struct IonQCircuit {
     pub circuit: Vec<IonQGate>,
 }
 
+impl IonQCircuit {
+    /// Qubit count and estimated depth of the circuit
+    pub fn profile(&self) -> Result<CircuitProfile, CircuitError> {
+        CircuitProfile::from_operations(self.qubits, self.circuit.iter().map(IonQGate::qubits))
+    }
+}
+
 /// `IonQ` gate operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "gate")]
@@ -498,6 +516,28 @@ pub enum IonQGate {
     },
 }
 
+impl IonQGate {
+    /// Qubits the gate acts on
+    #[must_use]
+    pub fn qubits(&self) -> Vec<usize> {
+        match self {
+            | Self::H { target }
+            | Self::X { target }
+            | Self::Y { target }
+            | Self::Z { target }
+            | Self::Rx { target, .. }
+            | Self::Ry { target, .. }
+            | Self::Rz { target, .. }
+            | Self::GPi { target, .. }
+            | Self::GPi2 { target, .. } => vec![*target],
+            | Self::CNOT { control, target } | Self::CZ { control, target } => {
+                vec![*control, *target]
+            },
+            | Self::SWAP { targets } | Self::MS { targets, .. } => targets.clone(),
+        }
+    }
+}
+
 /// `IonQ` job result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IonQResult {
@@ -10,17 +10,21 @@
 //! - **AWS Braket**: Multi-vendor access to `IonQ`, Rigetti, D-Wave, and simulators
 //! - **D-Wave**: Native quantum annealing for optimization problems
 //! - **`IonQ`**: High-fidelity trapped-ion quantum computers
+//! - **Local Simulator**: Seeded offline execution with optional readout noise, for tests
 //!
 //! ## Architecture
 //!
 //! Each provider module implements the common backend traits for various quantum algorithms:
 //!
-//! | Algorithm | IBM Quantum | AWS Braket | D-Wave | `IonQ` |
-//! |-----------|-------------|------------|--------|------|
-//! | Grover's Search | ✓ | ✓ | ✗ | ✓ |
-//! | QUBO/QAOA | ✓ | ✓ | ✓ | ✗ |
-//! | TFIM | ✗ | ✓ | ✓ | ✗ |
-//! | Parallel Tempering | ✓ | ✓ | ✓ | ✓ |
+//! | Algorithm | IBM Quantum | AWS Braket | D-Wave | `IonQ` | Simulator |
+//! |-----------|-------------|------------|--------|------|-----------|
+//! | Grover's Search | ✓ | ✓ | ✗ | ✓ | ✓ |
+//! | QUBO/QAOA | ✓ | ✓ | ✓ | ✗ | ✓ |
+//! | TFIM | ✗ | ✓ | ✓ | ✗ | ✗ |
+//! | Parallel Tempering | ✓ | ✓ | ✓ | ✓ | ✗ |
+//!
+//! `BackendSelector` picks an available backend from this table for a given problem,
+//! falling back to the local simulator.
 //!
 //! ## Configuration
 //!
@@ -64,12 +68,18 @@ pub mod braket;
 pub mod dwave;
 pub mod ibm;
 pub mod ionq;
+pub mod selector;
+pub mod simulator;
 
 // Re-export all backend types for convenience
 pub use braket::*;
 pub use dwave::*;
 pub use ibm::*;
 pub use ionq::*;
+pub use selector::*;
+pub use simulator::*;
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -89,6 +99,163 @@ pub trait QuantumBackendInfo: Send + Sync {
 
     /// Get the provider type
     fn provider(&self) -> QuantumProvider;
+
+    /// Largest estimated circuit depth this backend accepts, `None` if unlimited
+    fn max_circuit_depth(&self) -> Option<usize> {
+        None
+    }
+
+    /// Check that a circuit fits the qubit count and depth limit of this backend
+    ///
+    /// Run before submission, so oversized circuits fail locally instead of
+    /// after waiting in the provider's queue.
+    fn validate_circuit(&self, circuit: &CircuitProfile) -> Result<(), CircuitError> {
+        let available = self.max_qubits();
+        if circuit.num_qubits > available {
+            return Err(CircuitError::TooManyQubits {
+                backend: self.name().to_string(),
+                required: circuit.num_qubits,
+                available,
+            });
+        }
+        if let Some(max_depth) = self.max_circuit_depth() {
+            if circuit.depth > max_depth {
+                return Err(CircuitError::TooDeep {
+                    backend: self.name().to_string(),
+                    depth: circuit.depth,
+                    max_depth,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reasons a circuit is rejected before submission
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CircuitError {
+    #[error("circuit needs {required} qubits but {backend} has {available}")]
+    TooManyQubits {
+        backend: String,
+        required: usize,
+        available: usize,
+    },
+    #[error("circuit depth {depth} exceeds the limit of {max_depth} for {backend}")]
+    TooDeep {
+        backend: String,
+        depth: usize,
+        max_depth: usize,
+    },
+    #[error("malformed circuit: {0}")]
+    Malformed(String),
+}
+
+/// Qubit count and estimated depth of a circuit
+///
+/// The depth is the number of layers when every gate is scheduled as soon as
+/// all of its qubits are free, measurements included. Hardware compilation
+/// adds routing and native gate decomposition, so the real depth is higher.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CircuitProfile {
+    /// Number of qubits the circuit declares
+    pub num_qubits: usize,
+    /// Estimated depth
+    pub depth: usize,
+}
+
+impl CircuitProfile {
+    /// Profile a circuit given as the qubits each operation acts on, in order
+    pub fn from_operations<I, Q>(num_qubits: usize, operations: I) -> Result<Self, CircuitError>
+    where
+        I: IntoIterator<Item = Q>,
+        Q: AsRef<[usize]>,
+    {
+        let mut layers = vec![0; num_qubits];
+        let mut depth = 0;
+        for operation in operations {
+            let qubits = operation.as_ref();
+            if let Some(&qubit) = qubits.iter().find(|&&q| q >= num_qubits) {
+                return Err(CircuitError::Malformed(format!(
+                    "operation on qubit {qubit} of a {num_qubits}-qubit circuit"
+                )));
+            }
+            let Some(layer) = qubits.iter().map(|&q| layers[q] + 1).max() else {
+                continue;
+            };
+            for &q in qubits {
+                layers[q] = layer;
+            }
+            depth = depth.max(layer);
+        }
+        Ok(Self { num_qubits, depth })
+    }
+
+    /// Profile an `OpenQASM` 3.0 circuit as built by the gate-based backends
+    ///
+    /// Qubits are declared with `qubit[n] name;` and addressed as `name[i]`;
+    /// every other statement is an operation on the qubits it addresses.
+    pub fn from_qasm(qasm: &str) -> Result<Self, CircuitError> {
+        let mut registers: HashMap<&str, (usize, usize)> = HashMap::new();
+        let mut num_qubits = 0;
+        let mut operations = Vec::new();
+
+        for line in qasm.lines() {
+            let statement = line.split("//").next().unwrap_or_default().trim();
+            let statement = statement.trim_end_matches(';').trim();
+            if statement.is_empty()
+                || statement.starts_with("OPENQASM")
+                || statement.starts_with("include")
+                || statement.starts_with("bit[")
+            {
+                continue;
+            }
+            if let Some(declaration) = statement.strip_prefix("qubit[") {
+                let (size, name) = declaration.split_once(']').ok_or_else(|| {
+                    CircuitError::Malformed(format!("invalid declaration '{statement}'"))
+                })?;
+                let size: usize = size.trim().parse().map_err(|_| {
+                    CircuitError::Malformed(format!("invalid register size in '{statement}'"))
+                })?;
+                registers.insert(name.trim(), (num_qubits, size));
+                num_qubits += size;
+                continue;
+            }
+            operations.push(Self::qasm_operands(statement, &registers)?);
+        }
+
+        Self::from_operations(num_qubits, operations)
+    }
+
+    /// Qubits addressed by a QASM statement, as indices across all registers
+    fn qasm_operands(
+        statement: &str,
+        registers: &HashMap<&str, (usize, usize)>,
+    ) -> Result<Vec<usize>, CircuitError> {
+        let mut qubits = Vec::new();
+        for (open, _) in statement.match_indices('[') {
+            let name_start = statement[..open]
+                .char_indices()
+                .rev()
+                .find(|&(_, c)| !(c.is_ascii_alphanumeric() || c == '_'))
+                .map_or(0, |(i, c)| i + c.len_utf8());
+            let Some(&(offset, size)) = registers.get(&statement[name_start..open]) else {
+                continue;
+            };
+            let index = statement[open + 1..]
+                .split_once(']')
+                .and_then(|(index, _)| index.trim().parse::<usize>().ok())
+                .ok_or_else(|| {
+                    CircuitError::Malformed(format!("invalid qubit index in '{statement}'"))
+                })?;
+            if index >= size {
+                return Err(CircuitError::Malformed(format!(
+                    "qubit index {index} out of range for a register of {size} in '{statement}'"
+                )));
+            }
+            qubits.push(offset + index);
+        }
+        Ok(qubits)
+    }
 }
 
 /// Enumeration of supported quantum computing providers
@@ -129,6 +296,10 @@ pub struct QuantumBackendConfig {
     pub verbose: bool,
     /// Use fallback to local simulation when hardware unavailable
     pub fallback_to_simulation: bool,
+    /// Largest estimated circuit depth to submit; deeper circuits outlast the
+    /// device's coherence time (`None` disables the check)
+    #[serde(default)]
+    pub max_circuit_depth: Option<usize>,
 }
 
 impl Default for QuantumBackendConfig {
@@ -138,6 +309,7 @@ impl Default for QuantumBackendConfig {
             max_retries: 3,
             verbose: false,
             fallback_to_simulation: true,
+            max_circuit_depth: None,
         }
     }
 }
@@ -299,6 +471,95 @@ mod tests {
         assert!(config.fallback_to_simulation);
     }
 
+    #[test]
+    fn test_circuit_profile_from_qasm() {
+        let qasm = "OPENQASM 3.0;\n\
+                    qubit[3] q;\n\
+                    bit[3] c;\n\
+                    h q[0];\n\
+                    h q[1];\n\
+                    cx q[0], q[2]; // waits for q[0]\n\
+                    rzz(0.5) q[1], q[2];\n\
+                    c[1] = measure q[1];\n";
+        let profile = CircuitProfile::from_qasm(qasm).unwrap();
+        assert_eq!(
+            profile,
+            CircuitProfile {
+                num_qubits: 3,
+                depth: 4
+            }
+        );
+
+        assert!(matches!(
+            CircuitProfile::from_qasm("qubit[2] q;\nx q[2];"),
+            Err(CircuitError::Malformed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_over_budget_circuit_is_rejected_locally() {
+        let mut backend = IBMQuantumBackend::new(IBMQuantumConfig {
+            max_qubits: Some(4),
+            ..Default::default()
+        });
+        let wide = CircuitProfile::from_qasm(&backend.build_grover_qasm(5, &[3], 1)).unwrap();
+        assert_eq!(
+            backend.validate_circuit(&wide),
+            Err(CircuitError::TooManyQubits {
+                backend: "IBM Quantum".to_string(),
+                required: 5,
+                available: 4,
+            })
+        );
+
+        backend.config_mut().common.max_circuit_depth = Some(20);
+        let deep = CircuitProfile::from_qasm(&backend.build_grover_qasm(3, &[5], 4)).unwrap();
+        assert!(deep.depth > 20);
+        assert!(matches!(
+            backend.validate_circuit(&deep),
+            Err(CircuitError::TooDeep { max_depth: 20, .. })
+        ));
+
+        // Submission fails before any credentials or network are involved
+        let error = backend
+            .submit_circuit(&backend.build_grover_qasm(3, &[5], 4))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("exceeds the limit of 20"));
+    }
+
+    #[test]
+    fn test_in_budget_circuit_passes_validation() {
+        let ibm = IBMQuantumBackend::new(IBMQuantumConfig {
+            common: QuantumBackendConfig {
+                max_circuit_depth: Some(100),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let qasm = ibm.build_grover_qasm(3, &[5], 1);
+        assert_eq!(
+            ibm.validate_circuit(&CircuitProfile::from_qasm(&qasm).unwrap()),
+            Ok(())
+        );
+
+        let ionq = IonQBackend::new(IonQConfig::default());
+        let circuit = ionq.build_grover_circuit(4, &[9], 2);
+        let profile = circuit.profile().unwrap();
+        assert_eq!(profile.num_qubits, 4);
+        assert_eq!(ionq.validate_circuit(&profile), Ok(()));
+
+        let braket = BraketBackend::new(BraketConfig::default());
+        let gates = [
+            BraketGate::H(0),
+            BraketGate::CX(0, 1),
+            BraketGate::Measure(1, 1),
+        ];
+        let profile = CircuitProfile::from_qasm(&braket.build_qasm_circuit(2, &gates)).unwrap();
+        assert_eq!(profile.depth, 3);
+        assert_eq!(braket.validate_circuit(&profile), Ok(()));
+    }
+
     #[test]
     fn test_available_providers_always_includes_simulator() {
         let providers = QuantumBackendFactory::available_providers();
@@ -0,0 +1,362 @@
+//! # Automatic Backend Selection
+//!
+//! [`BackendSelector`] dispatches a [`QuantumProblem`] to a backend that can
+//! run it, so callers don't need to know which provider handles which
+//! algorithm.
+//!
+//! ## Selection Order
+//!
+//! Providers are tried in the user's preference order, or the per-algorithm
+//! default order otherwise (e.g. D-Wave first for QUBO, `IonQ` first for
+//! Grover). A backend is skipped when:
+//!
+//! - its provider doesn't support the algorithm (see the table in [`super`])
+//! - it is not available, e.g. because credentials are missing
+//! - the problem needs more qubits than it offers
+//!
+//! When no registered backend qualifies, the local [`SimulatorBackend`] is
+//! selected.
+//!
+//! ```no_run
+//! use neuroquantum_core::quantum::backends::selector::{BackendSelector, QuantumProblem};
+//!
+//! let selector = BackendSelector::from_env();
+//! let backend = selector.select(&QuantumProblem::Qubo { num_variables: 64 }).unwrap();
+//! println!("Solving on {}", backend.name());
+//! ```
+
+use tracing::debug;
+
+use super::braket::BraketBackend;
+use super::dwave::DWaveBackend;
+use super::ibm::IBMQuantumBackend;
+use super::ionq::IonQBackend;
+use super::simulator::SimulatorBackend;
+use super::{QuantumBackendInfo, QuantumProvider};
+use crate::error::{CoreError, CoreResult};
+
+/// A problem to be dispatched, described by its algorithm and size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantumProblem {
+    /// Grover's search over `2^num_qubits` items
+    Grover { num_qubits: usize },
+    /// QUBO optimization, via annealing or QAOA
+    Qubo { num_variables: usize },
+    /// Transverse-field Ising model
+    Tfim { num_spins: usize },
+    /// Parallel tempering over an Ising model
+    ParallelTempering { num_spins: usize },
+}
+
+impl QuantumProblem {
+    /// Number of qubits the problem occupies
+    #[must_use]
+    pub const fn num_qubits(&self) -> usize {
+        match *self {
+            | Self::Grover { num_qubits } => num_qubits,
+            | Self::Qubo { num_variables } => num_variables,
+            | Self::Tfim { num_spins } | Self::ParallelTempering { num_spins } => num_spins,
+        }
+    }
+
+    /// Whether `provider` implements this algorithm
+    ///
+    /// The local simulator serves every algorithm as the classical fallback.
+    #[must_use]
+    pub const fn runs_on(&self, provider: QuantumProvider) -> bool {
+        match (self, provider) {
+            | (_, QuantumProvider::LocalSimulator)
+            | (_, QuantumProvider::AWSBraket)
+            | (Self::ParallelTempering { .. }, _) => true,
+            | (Self::Grover { .. }, provider) => {
+                matches!(
+                    provider,
+                    QuantumProvider::IBMQuantum | QuantumProvider::IonQ
+                )
+            },
+            | (Self::Qubo { .. }, provider) => {
+                matches!(
+                    provider,
+                    QuantumProvider::IBMQuantum | QuantumProvider::DWave
+                )
+            },
+            | (Self::Tfim { .. }, provider) => matches!(provider, QuantumProvider::DWave),
+        }
+    }
+
+    /// Provider order used when the selector has no preference
+    ///
+    /// Same order as [`super::QuantumBackendFactory::best_provider_for_algorithm`].
+    #[must_use]
+    pub const fn default_preference(&self) -> &'static [QuantumProvider] {
+        match self {
+            | Self::Grover { .. } => &[
+                QuantumProvider::IonQ,
+                QuantumProvider::IBMQuantum,
+                QuantumProvider::AWSBraket,
+            ],
+            | Self::Qubo { .. } => &[
+                QuantumProvider::DWave,
+                QuantumProvider::IBMQuantum,
+                QuantumProvider::AWSBraket,
+            ],
+            | Self::Tfim { .. } => &[QuantumProvider::DWave, QuantumProvider::AWSBraket],
+            | Self::ParallelTempering { .. } => &[
+                QuantumProvider::DWave,
+                QuantumProvider::IBMQuantum,
+                QuantumProvider::IonQ,
+            ],
+        }
+    }
+}
+
+/// Picks a configured and available backend for a [`QuantumProblem`]
+pub struct BackendSelector {
+    backends: Vec<Box<dyn QuantumBackendInfo>>,
+    preference: Option<Vec<QuantumProvider>>,
+    simulator: SimulatorBackend,
+}
+
+impl Default for BackendSelector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BackendSelector {
+    /// Create a selector without hardware backends, always choosing the simulator
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            backends: Vec::new(),
+            preference: None,
+            simulator: SimulatorBackend::default(),
+        }
+    }
+
+    /// Create a selector with every hardware backend configured from the environment
+    ///
+    /// Providers without credentials are registered too; they report themselves
+    /// unavailable and are skipped during selection.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::new()
+            .with_backend(Box::new(IBMQuantumBackend::from_env()))
+            .with_backend(Box::new(BraketBackend::from_env()))
+            .with_backend(Box::new(DWaveBackend::from_env()))
+            .with_backend(Box::new(IonQBackend::from_env()))
+    }
+
+    /// Register a backend
+    ///
+    /// Backends of the same provider are tried in registration order.
+    #[must_use]
+    pub fn with_backend(mut self, backend: Box<dyn QuantumBackendInfo>) -> Self {
+        self.backends.push(backend);
+        self
+    }
+
+    /// Try providers in this order instead of the per-algorithm default
+    ///
+    /// Providers missing from the list are never selected, except for the
+    /// simulator fallback. Listing [`QuantumProvider::LocalSimulator`] makes
+    /// the simulator win over every provider after it.
+    #[must_use]
+    pub fn with_preference(mut self, preference: Vec<QuantumProvider>) -> Self {
+        self.preference = Some(preference);
+        self
+    }
+
+    /// Replace the fallback simulator
+    #[must_use]
+    pub fn with_simulator(mut self, simulator: SimulatorBackend) -> Self {
+        self.simulator = simulator;
+        self
+    }
+
+    /// Select the backend to run `problem` on
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when no backend, including the simulator, has enough
+    /// qubits for the problem.
+    pub fn select(&self, problem: &QuantumProblem) -> CoreResult<&dyn QuantumBackendInfo> {
+        let preference = self
+            .preference
+            .as_deref()
+            .unwrap_or_else(|| problem.default_preference());
+
+        for &provider in preference {
+            if !problem.runs_on(provider) {
+                continue;
+            }
+            if provider == QuantumProvider::LocalSimulator {
+                break;
+            }
+            let candidate = self.backends.iter().find(|backend| {
+                backend.provider() == provider
+                    && backend.is_available()
+                    && backend.max_qubits() >= problem.num_qubits()
+            });
+            if let Some(backend) = candidate {
+                debug!("Selected {} for {:?}", backend.name(), problem);
+                return Ok(backend.as_ref());
+            }
+        }
+
+        // Grover runs on the state vector, the other algorithms are annealed
+        let capacity = match problem {
+            | QuantumProblem::Grover { .. } => self.simulator.config().max_qubits,
+            | _ => self.simulator.config().max_variables,
+        };
+        if problem.num_qubits() > capacity {
+            return Err(CoreError::invalid_operation(&format!(
+                "No backend can run {problem:?}: the simulator is limited to {capacity}"
+            )));
+        }
+        debug!("No hardware backend for {:?}, using the simulator", problem);
+        Ok(&self.simulator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantum::backends::dwave::DWaveConfig;
+    use crate::quantum::backends::ibm::IBMQuantumConfig;
+    use crate::quantum::backends::ionq::IonQConfig;
+
+    fn dwave() -> Box<DWaveBackend> {
+        Box::new(DWaveBackend::new(DWaveConfig {
+            api_token: Some("dwave-token".to_string()),
+            ..Default::default()
+        }))
+    }
+
+    fn ibm() -> Box<IBMQuantumBackend> {
+        Box::new(IBMQuantumBackend::new(IBMQuantumConfig {
+            api_token: Some("ibm-token".to_string()),
+            ..Default::default()
+        }))
+    }
+
+    fn ionq() -> Box<IonQBackend> {
+        Box::new(IonQBackend::new(IonQConfig {
+            api_key: Some("ionq-key".to_string()),
+            ..Default::default()
+        }))
+    }
+
+    #[test]
+    fn test_qubo_routes_to_qubo_capable_backend() {
+        let selector = BackendSelector::new()
+            .with_backend(ionq())
+            .with_backend(dwave());
+        let problem = QuantumProblem::Qubo { num_variables: 32 };
+
+        let backend = selector.select(&problem).unwrap();
+        assert_eq!(backend.provider(), QuantumProvider::DWave);
+
+        // IonQ doesn't run QUBO, so IBM is the next candidate
+        let selector = BackendSelector::new()
+            .with_backend(ionq())
+            .with_backend(ibm());
+        let backend = selector.select(&problem).unwrap();
+        assert_eq!(backend.provider(), QuantumProvider::IBMQuantum);
+
+        let grover = QuantumProblem::Grover { num_qubits: 8 };
+        assert_eq!(
+            selector.select(&grover).unwrap().provider(),
+            QuantumProvider::IonQ
+        );
+    }
+
+    #[test]
+    fn test_falls_back_to_simulator() {
+        let problem = QuantumProblem::Qubo { num_variables: 16 };
+        let selector = BackendSelector::new();
+        let backend = selector.select(&problem).unwrap();
+        assert_eq!(backend.provider(), QuantumProvider::LocalSimulator);
+
+        // Only Grover-capable hardware is configured
+        let selector = BackendSelector::new().with_backend(ionq());
+        let backend = selector.select(&problem).unwrap();
+        assert_eq!(backend.provider(), QuantumProvider::LocalSimulator);
+
+        // Problems too large for the capable backends fall back as well
+        let selector = BackendSelector::new().with_backend(Box::new(IBMQuantumBackend::new(
+            IBMQuantumConfig {
+                api_token: Some("ibm-token".to_string()),
+                max_qubits: Some(8),
+                ..Default::default()
+            },
+        )));
+        let backend = selector.select(&problem).unwrap();
+        assert_eq!(backend.provider(), QuantumProvider::LocalSimulator);
+
+        let huge = QuantumProblem::Qubo {
+            num_variables: 100_000,
+        };
+        assert!(selector.select(&huge).is_err());
+    }
+
+    #[test]
+    fn test_missing_credentials_exclude_provider() {
+        let unconfigured = Box::new(DWaveBackend::new(DWaveConfig {
+            api_token: None,
+            ..Default::default()
+        }));
+        if unconfigured.is_available() {
+            // DWAVE_API_TOKEN is set in this environment
+            return;
+        }
+
+        let selector = BackendSelector::new()
+            .with_backend(unconfigured)
+            .with_backend(ibm());
+        let problem = QuantumProblem::Tfim { num_spins: 10 };
+        assert_eq!(
+            selector.select(&problem).unwrap().provider(),
+            QuantumProvider::LocalSimulator
+        );
+
+        let problem = QuantumProblem::Qubo { num_variables: 10 };
+        assert_eq!(
+            selector.select(&problem).unwrap().provider(),
+            QuantumProvider::IBMQuantum
+        );
+    }
+
+    #[test]
+    fn test_preference_order_is_honored() {
+        let problem = QuantumProblem::ParallelTempering { num_spins: 12 };
+        let selector = BackendSelector::new()
+            .with_backend(dwave())
+            .with_backend(ibm())
+            .with_backend(ionq());
+        assert_eq!(
+            selector.select(&problem).unwrap().provider(),
+            QuantumProvider::DWave
+        );
+
+        let selector = selector.with_preference(vec![
+            QuantumProvider::AWSBraket,
+            QuantumProvider::IonQ,
+            QuantumProvider::DWave,
+        ]);
+        assert_eq!(
+            selector.select(&problem).unwrap().provider(),
+            QuantumProvider::IonQ
+        );
+
+        // The simulator ends the search where it is listed
+        let selector = selector.with_preference(vec![
+            QuantumProvider::LocalSimulator,
+            QuantumProvider::DWave,
+        ]);
+        assert_eq!(
+            selector.select(&problem).unwrap().provider(),
+            QuantumProvider::LocalSimulator
+        );
+    }
+}
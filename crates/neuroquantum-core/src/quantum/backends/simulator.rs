@@ -0,0 +1,466 @@
+//! # Local Simulator Backend
+//!
+//! This module provides an offline stand-in for the hardware providers, so the
+//! quantum code paths can be exercised without cloud credentials.
+//!
+//! ## Execution
+//!
+//! - **Grover's Search**: Circuits run on the exact state vector simulator and
+//!   are measured shot by shot
+//! - **QUBO**: Problems are sampled by simulated annealing, one independent
+//!   run per read, and the lowest-energy read is returned
+//!
+//! ## Reproducibility and Noise
+//!
+//! With a seed, every call draws from a fresh RNG seeded with it, so identical
+//! inputs give identical results. `readout_error` flips each measured bit with
+//! the given probability, emulating the measurement errors of real devices.
+//!
+//! ## Configuration
+//!
+//! ```no_run
+//! use neuroquantum_core::quantum::backends::simulator::{SimulatorBackend, SimulatorConfig};
+//!
+//! let backend = SimulatorBackend::new(SimulatorConfig {
+//!     seed: Some(42),
+//!     readout_error: 0.01,
+//!     ..Default::default()
+//! });
+//! ```
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use nalgebra::DMatrix;
+use num_complex::Complex64;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use super::{CircuitProfile, QuantumBackendConfig, QuantumBackendInfo, QuantumProvider};
+use crate::error::{CoreError, CoreResult};
+use crate::quantum::grover_hardware_backends::GroverHardwareBackend;
+use crate::quantum::grover_quantum::{
+    GroverMeasurementStats, GroverQuantumBackend, QuantumGroverConfig, QuantumGroverResult,
+    QuantumGroverSolver, QuantumOracle,
+};
+use crate::quantum::qubo_hardware_backends::QUBOSolverBackend;
+use crate::quantum::qubo_quantum::{
+    IsingModel, MeasurementStats, QUBOProblem, QuantumQuboSolution, QuboQuantumBackend,
+};
+
+// =============================================================================
+// Simulator Configuration
+// =============================================================================
+
+/// Configuration for the local simulator backend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulatorConfig {
+    /// Seed for measurements and annealing; `None` seeds from entropy
+    pub seed: Option<u64>,
+
+    /// Number of shots for measurement
+    pub num_shots: usize,
+
+    /// Largest register simulated (2^n amplitudes)
+    pub max_qubits: usize,
+
+    /// Largest QUBO problem annealed
+    pub max_variables: usize,
+
+    /// Probability (0.0 - 1.0) that a measured bit is read out flipped
+    pub readout_error: f64,
+
+    /// Independent annealing runs per QUBO problem
+    pub num_reads: usize,
+
+    /// Metropolis sweeps over all variables per annealing run
+    pub sweeps: usize,
+
+    /// Common backend configuration
+    #[serde(flatten)]
+    pub common: QuantumBackendConfig,
+}
+
+impl Default for SimulatorConfig {
+    fn default() -> Self {
+        Self {
+            seed: None,
+            num_shots: 1024,
+            max_qubits: 20, // 2^20 = 1M amplitudes
+            max_variables: 5000,
+            readout_error: 0.0,
+            num_reads: 16,
+            sweeps: 1000,
+            common: QuantumBackendConfig::default(),
+        }
+    }
+}
+
+// =============================================================================
+// Simulator Backend
+// =============================================================================
+
+/// Local backend executing circuits and annealing problems by simulation
+///
+/// Implements the same traits as the hardware backends and is always
+/// available, so it can replace them in tests and offline environments.
+///
+/// ## Example
+///
+/// ```no_run
+/// use neuroquantum_core::quantum::backends::simulator::SimulatorBackend;
+/// use neuroquantum_core::quantum::grover_hardware_backends::GroverHardwareBackend;
+/// use neuroquantum_core::quantum::grover_quantum::QuantumOracle;
+///
+/// # async fn example() -> neuroquantum_core::error::CoreResult<()> {
+/// let backend = SimulatorBackend::with_seed(7);
+/// let result = backend.search(&QuantumOracle::new(3, vec![5]), 256).await?;
+/// assert_eq!(result.found_indices[0], 5);
+/// # Ok(())
+/// # }
+/// ```
+pub struct SimulatorBackend {
+    config: SimulatorConfig,
+}
+
+impl SimulatorBackend {
+    /// Create a new simulator backend with the given configuration
+    #[must_use]
+    pub const fn new(config: SimulatorConfig) -> Self {
+        Self { config }
+    }
+
+    /// Create a noiseless simulator giving reproducible results for `seed`
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self::new(SimulatorConfig {
+            seed: Some(seed),
+            ..Default::default()
+        })
+    }
+
+    /// Get the current configuration
+    #[must_use]
+    pub const fn config(&self) -> &SimulatorConfig {
+        &self.config
+    }
+
+    /// Get mutable reference to configuration
+    pub const fn config_mut(&mut self) -> &mut SimulatorConfig {
+        &mut self.config
+    }
+
+    /// RNG for one call, reproducible when a seed is configured
+    fn rng(&self) -> StdRng {
+        self.config
+            .seed
+            .map_or_else(StdRng::from_entropy, StdRng::seed_from_u64)
+    }
+
+    /// Flip each of the low `num_bits` bits of `value` with the readout error
+    fn apply_readout_error(&self, rng: &mut StdRng, value: usize, num_bits: usize) -> usize {
+        if self.config.readout_error <= 0.0 {
+            return value;
+        }
+        (0..num_bits)
+            .filter(|_| rng.gen_bool(self.config.readout_error.min(1.0)))
+            .fold(value, |value, bit| value ^ (1 << bit))
+    }
+
+    /// Measure `state` `num_shots` times
+    fn measure(
+        &self,
+        rng: &mut StdRng,
+        state: &[Complex64],
+        num_qubits: usize,
+        num_shots: usize,
+    ) -> GroverMeasurementStats {
+        let mut cdf = Vec::with_capacity(state.len());
+        let mut cumulative = 0.0;
+        for amplitude in state {
+            cumulative += amplitude.norm_sqr();
+            cdf.push(cumulative);
+        }
+
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        for _ in 0..num_shots {
+            let sample = rng.gen::<f64>() * cumulative;
+            let outcome = cdf
+                .iter()
+                .position(|&c| sample < c)
+                .unwrap_or(cdf.len() - 1);
+            let outcome = self.apply_readout_error(rng, outcome, num_qubits);
+            *counts.entry(outcome).or_insert(0) += 1;
+        }
+
+        // Most frequent first, ties broken by state for reproducibility
+        let mut outcome_distribution: Vec<(usize, usize)> = counts.into_iter().collect();
+        outcome_distribution.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let shots = num_shots.max(1) as f64;
+        let (best_state, best_count) = outcome_distribution.first().copied().unwrap_or((0, 0));
+        let entropy = outcome_distribution
+            .iter()
+            .map(|&(_, count)| {
+                let p = count as f64 / shots;
+                -p * p.ln()
+            })
+            .sum();
+
+        GroverMeasurementStats {
+            num_shots,
+            unique_states: outcome_distribution.len(),
+            best_state,
+            best_probability: best_count as f64 / shots,
+            outcome_distribution,
+            entropy,
+        }
+    }
+
+    /// QUBO energy `xᵀQx`
+    fn qubo_energy(q_matrix: &DMatrix<f64>, variables: &[u8]) -> f64 {
+        let mut energy = 0.0;
+        for (i, &x_i) in variables.iter().enumerate() {
+            for (j, &x_j) in variables.iter().enumerate() {
+                if x_i == 1 && x_j == 1 {
+                    energy += q_matrix[(i, j)];
+                }
+            }
+        }
+        energy
+    }
+
+    /// One simulated annealing run from a random assignment
+    fn anneal(&self, rng: &mut StdRng, q_matrix: &DMatrix<f64>) -> Vec<u8> {
+        let n = q_matrix.nrows();
+        let mut variables: Vec<u8> = (0..n).map(|_| u8::from(rng.gen_bool(0.5))).collect();
+
+        // Cool geometrically from the largest coefficient down to a thousandth of it
+        let scale = q_matrix.iter().fold(0.0f64, |max, q| max.max(q.abs()));
+        if scale == 0.0 {
+            return variables;
+        }
+        let initial_temperature = 2.0 * scale;
+        let final_temperature = 1e-3 * scale;
+        let sweeps = self.config.sweeps.max(1);
+        let cooling = (final_temperature / initial_temperature).powf(1.0 / sweeps as f64);
+
+        let mut temperature = initial_temperature;
+        for _ in 0..sweeps {
+            for i in 0..n {
+                // Energy change of flipping variable i
+                let field: f64 = (0..n)
+                    .filter(|&j| j != i && variables[j] == 1)
+                    .map(|j| q_matrix[(i, j)] + q_matrix[(j, i)])
+                    .sum();
+                let sign = if variables[i] == 1 { -1.0 } else { 1.0 };
+                let delta = sign * (q_matrix[(i, i)] + field);
+                if delta <= 0.0 || rng.gen::<f64>() < (-delta / temperature).exp() {
+                    variables[i] ^= 1;
+                }
+            }
+            temperature *= cooling;
+        }
+        variables
+    }
+}
+
+impl Default for SimulatorBackend {
+    fn default() -> Self {
+        Self::new(SimulatorConfig::default())
+    }
+}
+
+impl QuantumBackendInfo for SimulatorBackend {
+    fn is_available(&self) -> bool {
+        true // Simulator is always available
+    }
+
+    fn max_qubits(&self) -> usize {
+        self.config.max_qubits
+    }
+
+    fn name(&self) -> &'static str {
+        "Local Simulator"
+    }
+
+    fn provider(&self) -> QuantumProvider {
+        QuantumProvider::LocalSimulator
+    }
+
+    fn max_circuit_depth(&self) -> Option<usize> {
+        self.config.common.max_circuit_depth
+    }
+}
+
+#[async_trait]
+impl GroverHardwareBackend for SimulatorBackend {
+    async fn search(
+        &self,
+        oracle: &QuantumOracle,
+        num_shots: usize,
+    ) -> CoreResult<QuantumGroverResult> {
+        let start_time = Instant::now();
+        let shots = if num_shots > 0 {
+            num_shots
+        } else {
+            self.config.num_shots
+        };
+
+        info!(
+            "SimulatorBackend: Executing Grover's search with {} qubits, {} shots",
+            oracle.num_qubits, shots
+        );
+
+        self.validate_circuit(&CircuitProfile {
+            num_qubits: oracle.num_qubits,
+            depth: 0,
+        })?;
+
+        let solver = QuantumGroverSolver::with_config(QuantumGroverConfig {
+            backend: GroverQuantumBackend::Simulator,
+            num_shots: shots,
+            ..Default::default()
+        });
+        let mut result = solver.search_with_oracle(oracle)?;
+        self.validate_circuit(&CircuitProfile {
+            num_qubits: result.circuit.num_qubits,
+            depth: result.circuit.depth,
+        })?;
+
+        if let Some(state) = &result.state_vector {
+            let mut rng = self.rng();
+            result.measurement_stats =
+                Some(self.measure(&mut rng, state, oracle.num_qubits, shots));
+        }
+        result.computation_time_ms = start_time.elapsed().as_secs_f64() * 1000.0;
+
+        Ok(result)
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn max_qubits(&self) -> usize {
+        self.config.max_qubits
+    }
+
+    fn name(&self) -> &'static str {
+        "Local Simulator"
+    }
+
+    fn backend_type(&self) -> GroverQuantumBackend {
+        GroverQuantumBackend::Simulator
+    }
+}
+
+#[async_trait]
+impl QUBOSolverBackend for SimulatorBackend {
+    async fn solve(&self, problem: &QUBOProblem) -> CoreResult<QuantumQuboSolution> {
+        let start_time = Instant::now();
+
+        info!(
+            "SimulatorBackend: Annealing problem '{}' with {} variables",
+            problem.name, problem.num_vars
+        );
+
+        if problem.num_vars > self.config.max_variables {
+            return Err(CoreError::invalid_operation(&format!(
+                "Problem has {} variables but simulator limited to {}",
+                problem.num_vars, self.config.max_variables
+            )));
+        }
+
+        let q_matrix = &problem.q_matrix;
+        let mut rng = self.rng();
+        let num_reads = self.config.num_reads.max(1);
+        let reads: Vec<(Vec<u8>, f64)> = (0..num_reads)
+            .map(|_| {
+                let variables = self.anneal(&mut rng, q_matrix);
+                let readout = self.apply_readout_error(
+                    &mut rng,
+                    variables
+                        .iter()
+                        .enumerate()
+                        .fold(0, |bits, (i, &x)| bits | (usize::from(x) << i)),
+                    variables.len(),
+                );
+                let variables: Vec<u8> = (0..variables.len())
+                    .map(|i| u8::from((readout >> i) & 1 == 1))
+                    .collect();
+                let energy = Self::qubo_energy(q_matrix, &variables);
+                (variables, energy)
+            })
+            .collect();
+
+        let (variables, energy) = reads
+            .iter()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .cloned()
+            .unwrap_or_default();
+        let best_reads = reads.iter().filter(|(v, _)| *v == variables).count();
+        let mut distinct: Vec<&Vec<u8>> = reads.iter().map(|(v, _)| v).collect();
+        distinct.sort();
+        distinct.dedup();
+
+        let mean = reads.iter().map(|(_, e)| e).sum::<f64>() / num_reads as f64;
+        let energy_variance =
+            reads.iter().map(|(_, e)| (e - mean).powi(2)).sum::<f64>() / num_reads as f64;
+        let entropy = distinct
+            .iter()
+            .map(|state| {
+                let p = reads.iter().filter(|(v, _)| v == *state).count() as f64 / num_reads as f64;
+                -p * p.ln()
+            })
+            .sum();
+
+        let spins: Vec<i8> = variables.iter().map(|&x| 2 * x as i8 - 1).collect();
+        let ising_energy = IsingModel::from_qubo(q_matrix).evaluate(&spins);
+
+        // Same quality measure as QuantumQuboSolver
+        let max_possible = q_matrix.sum().abs();
+        let quality = if max_possible < 1e-10 {
+            1.0
+        } else {
+            1.0 - (energy / max_possible).abs().min(1.0)
+        };
+
+        Ok(QuantumQuboSolution {
+            variables,
+            energy,
+            ising_energy,
+            quality,
+            backend_used: QuboQuantumBackend::ClassicalFallback,
+            quantum_evaluations: num_reads,
+            iterations: num_reads * self.config.sweeps,
+            converged: best_reads > 1,
+            computation_time_ms: start_time.elapsed().as_secs_f64() * 1000.0,
+            measurement_stats: Some(MeasurementStats {
+                unique_states: distinct.len(),
+                best_state_probability: best_reads as f64 / num_reads as f64,
+                entropy,
+                energy_variance,
+            }),
+        })
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn max_variables(&self) -> usize {
+        self.config.max_variables
+    }
+
+    fn name(&self) -> &'static str {
+        "Local Simulator"
+    }
+
+    fn backend_type(&self) -> QuboQuantumBackend {
+        QuboQuantumBackend::ClassicalFallback
+    }
+}
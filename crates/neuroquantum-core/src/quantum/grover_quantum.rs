@@ -69,7 +69,7 @@ pub enum GroverQuantumBackend {
 }
 
 /// Quantum gate types for Grover circuits
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum GroverGate {
     /// Hadamard gate (superposition)
     H { qubit: usize },
@@ -92,7 +92,7 @@ pub enum GroverGate {
 }
 
 /// Quantum circuit representation for Grover's algorithm
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GroverCircuit {
     /// Number of data qubits
     pub num_qubits: usize,
@@ -106,6 +106,253 @@ pub struct GroverCircuit {
     pub iterations: usize,
 }
 
+/// Comment prefix carrying Grover metadata through `OpenQASM` export/import
+const QASM_METADATA_PREFIX: &str = "// neuroquantum-grover:";
+
+impl GroverCircuit {
+    /// Serialize the circuit as an `OpenQASM` 3.0 program
+    ///
+    /// Gates map onto `stdgates.inc`; multi-controlled gates use the `ctrl(k) @`
+    /// modifier with the target as the last operand. Every data qubit is measured
+    /// into a classical register at the end of the program. Iteration count and
+    /// depth are kept in a leading comment so [`GroverCircuit::from_qasm3`] can
+    /// restore them.
+    #[must_use]
+    pub fn to_qasm3(&self) -> String {
+        let total_qubits = self.num_qubits + usize::from(self.uses_ancilla);
+        let mut qasm = String::new();
+        qasm.push_str("OPENQASM 3.0;\n");
+        qasm.push_str("include \"stdgates.inc\";\n");
+        qasm.push_str(&format!(
+            "{QASM_METADATA_PREFIX} iterations={} depth={} ancilla={}\n\n",
+            self.iterations, self.depth, self.uses_ancilla
+        ));
+        qasm.push_str(&format!("qubit[{total_qubits}] q;\n"));
+        qasm.push_str(&format!("bit[{}] c;\n\n", self.num_qubits));
+
+        for gate in &self.gates {
+            let line = match gate {
+                | GroverGate::H { qubit } => format!("h q[{qubit}];"),
+                | GroverGate::X { qubit } => format!("x q[{qubit}];"),
+                | GroverGate::Z { qubit } => format!("z q[{qubit}];"),
+                | GroverGate::RZ { qubit, angle } => format!("rz({angle}) q[{qubit}];"),
+                | GroverGate::CNOT { control, target } => format!("cx q[{control}], q[{target}];"),
+                | GroverGate::CZ { control, target } => format!("cz q[{control}], q[{target}];"),
+                | GroverGate::Phase { qubit, angle } => format!("p({angle}) q[{qubit}];"),
+                | GroverGate::MCX { controls, target } => {
+                    Self::controlled_qasm("x", controls, *target)
+                },
+                | GroverGate::MCZ { controls, target } => {
+                    Self::controlled_qasm("z", controls, *target)
+                },
+            };
+            qasm.push_str(&line);
+            qasm.push('\n');
+        }
+
+        qasm.push('\n');
+        for qubit in 0..self.num_qubits {
+            qasm.push_str(&format!("c[{qubit}] = measure q[{qubit}];\n"));
+        }
+
+        qasm
+    }
+
+    /// Parse an `OpenQASM` 3.0 program produced by [`GroverCircuit::to_qasm3`]
+    ///
+    /// Accepts the gate subset that [`GroverGate`] can represent. Measurements,
+    /// barriers and classical declarations are skipped since measurement is
+    /// implicit at the end of a Grover circuit. A `ctrl(k) @ z` gate is read as
+    /// an MCZ whose `controls` include the target, matching the oracle and
+    /// diffusion builders.
+    pub fn from_qasm3(source: &str) -> CoreResult<Self> {
+        let mut iterations = 0;
+        let mut depth = None;
+        let mut uses_ancilla = false;
+        let mut total_qubits = None;
+        let mut gates = Vec::new();
+
+        let mut code = String::new();
+        for line in source.lines() {
+            if let Some(metadata) = line.trim().strip_prefix(QASM_METADATA_PREFIX) {
+                for field in metadata.split_whitespace() {
+                    match field.split_once('=') {
+                        | Some(("iterations", v)) => iterations = Self::parse_qasm_number(v)?,
+                        | Some(("depth", v)) => depth = Some(Self::parse_qasm_number(v)?),
+                        | Some(("ancilla", v)) => uses_ancilla = v == "true",
+                        | _ => {},
+                    }
+                }
+            }
+            code.push_str(line.split("//").next().unwrap_or_default());
+            code.push('\n');
+        }
+
+        for statement in code.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+            if statement.starts_with("OPENQASM")
+                || statement.starts_with("include")
+                || statement.starts_with("bit")
+                || statement.starts_with("barrier")
+                || statement.contains("measure")
+            {
+                continue;
+            }
+
+            if let Some(size) = statement
+                .strip_prefix("qubit[")
+                .and_then(|rest| rest.split_once(']'))
+                .map(|(size, _)| size)
+            {
+                if total_qubits.is_some() {
+                    return Err(CoreError::invalid_operation(
+                        "OpenQASM import supports a single qubit register",
+                    ));
+                }
+                total_qubits = Some(Self::parse_qasm_number(size)?);
+                continue;
+            }
+
+            gates.push(Self::parse_qasm_gate(statement)?);
+        }
+
+        let total_qubits = total_qubits.ok_or_else(|| {
+            CoreError::invalid_operation("OpenQASM program declares no qubit register")
+        })?;
+        let num_qubits = total_qubits.saturating_sub(usize::from(uses_ancilla));
+
+        Ok(Self {
+            num_qubits,
+            uses_ancilla,
+            depth: depth.unwrap_or_else(|| estimate_circuit_depth(&gates)),
+            gates,
+            iterations,
+        })
+    }
+
+    fn controlled_qasm(name: &str, controls: &[usize], target: usize) -> String {
+        let operands: Vec<String> = controls
+            .iter()
+            .filter(|&&c| c != target)
+            .chain(std::iter::once(&target))
+            .map(|q| format!("q[{q}]"))
+            .collect();
+        if operands.len() == 1 {
+            format!("{name} {};", operands[0])
+        } else {
+            format!(
+                "ctrl({}) @ {name} {};",
+                operands.len() - 1,
+                operands.join(", ")
+            )
+        }
+    }
+
+    fn parse_qasm_gate(statement: &str) -> CoreResult<GroverGate> {
+        let unsupported = || {
+            CoreError::invalid_operation(&format!("Unsupported OpenQASM statement: {statement}"))
+        };
+
+        let (num_controls, rest) = match statement.strip_prefix("ctrl") {
+            | Some(rest) => {
+                let (count, rest) = rest
+                    .trim_start()
+                    .strip_prefix('(')
+                    .and_then(|r| r.split_once(')'))
+                    .ok_or_else(unsupported)?;
+                let rest = rest
+                    .trim_start()
+                    .strip_prefix('@')
+                    .ok_or_else(unsupported)?;
+                (Some(Self::parse_qasm_number(count)?), rest.trim_start())
+            },
+            | None => (None, statement),
+        };
+
+        let (head, operands) = rest
+            .split_once(char::is_whitespace)
+            .ok_or_else(unsupported)?;
+        let (name, angle) = match head.split_once('(') {
+            | Some((name, arg)) => {
+                let arg = arg.strip_suffix(')').ok_or_else(unsupported)?;
+                let angle = arg.trim().parse::<f64>().map_err(|_| {
+                    CoreError::invalid_operation(&format!("Invalid OpenQASM angle: {arg}"))
+                })?;
+                (name, Some(angle))
+            },
+            | None => (head, None),
+        };
+
+        let qubits = operands
+            .split(',')
+            .map(|operand| {
+                operand
+                    .trim()
+                    .strip_prefix("q[")
+                    .and_then(|o| o.strip_suffix(']'))
+                    .ok_or_else(unsupported)
+                    .and_then(Self::parse_qasm_number)
+            })
+            .collect::<CoreResult<Vec<usize>>>()?;
+
+        if let Some(count) = num_controls {
+            if qubits.len() != count + 1 {
+                return Err(unsupported());
+            }
+            let target = qubits[count];
+            return match name {
+                | "x" => Ok(GroverGate::MCX {
+                    controls: qubits[..count].to_vec(),
+                    target,
+                }),
+                | "z" => Ok(GroverGate::MCZ {
+                    controls: qubits,
+                    target,
+                }),
+                | _ => Err(unsupported()),
+            };
+        }
+
+        match (name, angle, qubits.as_slice()) {
+            | ("h", None, &[qubit]) => Ok(GroverGate::H { qubit }),
+            | ("x", None, &[qubit]) => Ok(GroverGate::X { qubit }),
+            | ("z", None, &[qubit]) => Ok(GroverGate::Z { qubit }),
+            | ("rz", Some(angle), &[qubit]) => Ok(GroverGate::RZ { qubit, angle }),
+            | ("p" | "phase", Some(angle), &[qubit]) => Ok(GroverGate::Phase { qubit, angle }),
+            | ("cx" | "cnot", None, &[control, target]) => Ok(GroverGate::CNOT { control, target }),
+            | ("cz", None, &[control, target]) => Ok(GroverGate::CZ { control, target }),
+            | ("ccx", None, &[c0, c1, target]) => Ok(GroverGate::MCX {
+                controls: vec![c0, c1],
+                target,
+            }),
+            | _ => Err(unsupported()),
+        }
+    }
+
+    fn parse_qasm_number(value: &str) -> CoreResult<usize> {
+        value.trim().parse().map_err(|_| {
+            CoreError::invalid_operation(&format!("Invalid OpenQASM integer: {value}"))
+        })
+    }
+}
+
+/// Estimate circuit depth from its gate list
+fn estimate_circuit_depth(gates: &[GroverGate]) -> usize {
+    // Simplified: count multi-qubit gates as the main depth contributors
+    gates
+        .iter()
+        .filter(|g| {
+            matches!(
+                g,
+                GroverGate::CNOT { .. }
+                    | GroverGate::CZ { .. }
+                    | GroverGate::MCX { .. }
+                    | GroverGate::MCZ { .. }
+            )
+        })
+        .count()
+        + 1
+}
+
 /// Oracle specification for Grover's algorithm
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuantumOracle {
@@ -854,20 +1101,7 @@ impl QuantumGroverSolver {
 
     /// Calculate circuit depth
     fn calculate_circuit_depth(&self, gates: &[GroverGate]) -> usize {
-        // Simplified: count multi-qubit gates as the main depth contributors
-        gates
-            .iter()
-            .filter(|g| {
-                matches!(
-                    g,
-                    GroverGate::CNOT { .. }
-                        | GroverGate::CZ { .. }
-                        | GroverGate::MCX { .. }
-                        | GroverGate::MCZ { .. }
-                )
-            })
-            .count()
-            + 1
+        estimate_circuit_depth(gates)
     }
 }
 
@@ -997,6 +1231,41 @@ mod tests {
         assert!(!circuit.gates.is_empty());
     }
 
+    #[test]
+    fn test_qasm3_round_trip() {
+        let solver = QuantumGroverSolver::new();
+        let oracle = QuantumOracle::new(3, vec![5]);
+        let mut circuit = solver.build_grover_circuit(&oracle, 2).unwrap();
+        circuit.gates.push(GroverGate::RZ {
+            qubit: 1,
+            angle: PI / 3.0,
+        });
+        circuit.gates.push(GroverGate::MCX {
+            controls: vec![0, 1],
+            target: 2,
+        });
+
+        let qasm = circuit.to_qasm3();
+        assert!(qasm.starts_with("OPENQASM 3.0;"));
+        assert!(qasm.contains("include \"stdgates.inc\";"));
+        assert!(qasm.contains("qubit[3] q;"));
+        assert!(qasm.contains("ctrl(2) @ z q[0], q[1], q[2];"));
+        assert!(qasm.contains("ctrl(2) @ x q[0], q[1], q[2];"));
+        assert_eq!(qasm.matches("= measure q[").count(), 3);
+
+        let parsed = GroverCircuit::from_qasm3(&qasm).unwrap();
+        assert_eq!(parsed, circuit);
+    }
+
+    #[test]
+    fn test_qasm3_import_rejects_unknown_gates() {
+        let qasm = "OPENQASM 3.0;\nqubit[2] q;\nswap q[0], q[1];\n";
+        assert!(GroverCircuit::from_qasm3(qasm).is_err());
+
+        let missing_register = "OPENQASM 3.0;\nh q[0];\n";
+        assert!(GroverCircuit::from_qasm3(missing_register).is_err());
+    }
+
     #[test]
     fn test_empty_oracle() {
         let solver = QuantumGroverSolver::new();
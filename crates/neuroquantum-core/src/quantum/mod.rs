@@ -6,6 +6,10 @@
 //!   implementations** including VQE, QAOA, and Simulated Quantum Annealing (SQA).
 //!   Features automatic fallback to classical solver when quantum backends unavailable.
 //!
+//! - **QAOA Solver**: `QaoaSolver` solves a `QUBOProblem` with a p-layer QAOA ansatz,
+//!   optimizing the angles on a state vector simulation and sampling either locally
+//!   or on a configured `QuantumHardwareBackend`.
+//!
 //! - **TFIM (Transverse Field Ising Model)**: Now with **real quantum hardware integration**:
 //!   - **Quantum Annealing Backends**: D-Wave and AWS Braket quantum annealers for native
 //!     Ising model solving (NEW!)
@@ -179,6 +183,7 @@ pub mod grover_hardware_backends;
 
 // Quantum extensions
 pub mod parallel_tempering_hardware_backends;
+pub mod qaoa;
 pub mod quantum_parallel_tempering;
 pub mod qubo_hardware_backends;
 pub mod qubo_quantum;
@@ -208,12 +213,18 @@ pub use backends::ionq::{
     IonQBackend, IonQCircuit, IonQConfig as UnifiedIonQConfig, IonQDeviceSpec, IonQGate,
     IonQJobStatus, IonQMetadata, IonQResult, IonQTarget,
 };
+// Re-export the local simulator backend for offline testing
+pub use backends::simulator::{SimulatorBackend, SimulatorConfig};
+// Re-export automatic backend selection
+pub use backends::selector::{BackendSelector, QuantumProblem};
 // Unified quantum hardware backends (NEW!)
 // These provide a reorganized, provider-centric view of quantum backends
 // The module is exported for direct use; common types are re-exported with prefixes
 // to avoid conflicts with the algorithm-specific hardware backends above
 pub use backends::{
     // Common types (no conflicts)
+    CircuitError,
+    CircuitProfile,
     QuantumBackendConfig as UnifiedBackendConfig,
     QuantumBackendFactory,
     QuantumBackendInfo,
@@ -276,6 +287,8 @@ pub use parallel_tempering_hardware_backends::{
     UnifiedPTConfig,
     UnifiedPTSolver,
 };
+// High-level QAOA entry point for QUBO problems
+pub use qaoa::{QaoaConfig, QaoaResult, QaoaSolver};
 // Re-export new quantum extension types
 pub use quantum_parallel_tempering::{
     create_quantum_ising_optimizer, IsingHamiltonian, QuantumBackend, QuantumParallelTempering,
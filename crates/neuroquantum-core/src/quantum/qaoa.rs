@@ -0,0 +1,345 @@
+//! # QAOA Solver for QUBO Problems
+//!
+//! High-level entry point for solving a [`QUBOProblem`] with the Quantum
+//! Approximate Optimization Algorithm.
+//!
+//! The ansatz alternates `p` cost layers `exp(-iγ_k H_C)` with mixer layers
+//! `exp(-iβ_k Σ X_i)`, starting from the uniform superposition. The angles are
+//! tuned classically by minimizing the exact expectation `⟨H_C⟩` of the
+//! simulated state vector (coordinate pattern search), after which the final
+//! state is sampled and the best measured bitstring is returned.
+//!
+//! When a [`QuantumHardwareBackend`] is configured and available, the final
+//! sampling is dispatched to it; otherwise (or if the backend fails) the local
+//! state vector simulation is sampled instead.
+//!
+//! ```no_run
+//! use neuroquantum_core::quantum::{max_cut_problem, QaoaSolver};
+//!
+//! # fn example() -> anyhow::Result<()> {
+//! let problem = max_cut_problem(&[(0, 1, 1.0), (1, 2, 1.0)], 3)?;
+//! let result = QaoaSolver::new().solve(&problem, 2)?;
+//! println!("best: {:?} ({})", result.bitstring, result.objective);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+use nalgebra::DVector;
+use num_complex::Complex64;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use super::qubo_quantum::{IsingModel, QUBOProblem, QuantumHardwareBackend};
+use crate::error::{CoreError, CoreResult};
+
+/// Largest problem simulated as a state vector (2^20 amplitudes)
+const MAX_QAOA_QUBITS: usize = 20;
+
+/// Name reported when the local simulator produced the samples
+const SIMULATOR_BACKEND: &str = "state-vector simulator";
+
+/// Configuration for the QAOA solver
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QaoaConfig {
+    /// Number of measurement shots taken from the optimized circuit
+    pub shots: usize,
+    /// Maximum parameter optimization iterations
+    pub max_iterations: usize,
+    /// Optimization stops once the search step falls below this value
+    pub convergence_threshold: f64,
+    /// Initial step size of the parameter search (radians)
+    pub initial_step: f64,
+    /// Seed for sampling the simulated circuit
+    pub seed: u64,
+}
+
+impl Default for QaoaConfig {
+    fn default() -> Self {
+        Self {
+            shots: 1024,
+            max_iterations: 200,
+            convergence_threshold: 1e-3,
+            initial_step: 0.25,
+            seed: 42,
+        }
+    }
+}
+
+/// Result of a QAOA run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QaoaResult {
+    /// Best measured assignment of the binary variables
+    pub bitstring: Vec<u8>,
+    /// QUBO objective `x^T Q x` of `bitstring`
+    pub objective: f64,
+    /// Expected objective `⟨H_C⟩` of the optimized circuit
+    pub expectation: f64,
+    /// Optimized cost angles, one per layer
+    pub gammas: Vec<f64>,
+    /// Optimized mixer angles, one per layer
+    pub betas: Vec<f64>,
+    /// Expected objective after each optimization iteration
+    pub history: Vec<f64>,
+    /// Optimization iterations performed
+    pub iterations: usize,
+    /// Whether the parameter search converged
+    pub converged: bool,
+    /// Backend that produced the samples
+    pub backend: String,
+}
+
+/// QAOA solver for QUBO problems
+pub struct QaoaSolver {
+    config: QaoaConfig,
+    backend: Option<Arc<dyn QuantumHardwareBackend>>,
+}
+
+impl QaoaSolver {
+    /// Create a solver using the local state vector simulator
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_config(QaoaConfig::default())
+    }
+
+    /// Create a solver with a custom configuration
+    #[must_use]
+    pub fn with_config(config: QaoaConfig) -> Self {
+        Self {
+            config,
+            backend: None,
+        }
+    }
+
+    /// Dispatch sampling to a quantum hardware backend when it is available
+    #[must_use]
+    pub fn with_backend(mut self, backend: Arc<dyn QuantumHardwareBackend>) -> Self {
+        self.backend = Some(backend);
+        self
+    }
+
+    /// Solve a QUBO problem with a `layers`-deep QAOA ansatz
+    pub fn solve(&self, qubo: &QUBOProblem, layers: usize) -> CoreResult<QaoaResult> {
+        let n = qubo.q_matrix.nrows();
+        if n == 0 {
+            return Err(CoreError::invalid_operation("Empty QUBO problem"));
+        }
+        if layers == 0 {
+            return Err(CoreError::invalid_operation(
+                "QAOA needs at least one layer",
+            ));
+        }
+        if n > MAX_QAOA_QUBITS {
+            return Err(CoreError::invalid_operation(&format!(
+                "QUBO '{}' has {n} variables, QAOA simulation supports at most {MAX_QAOA_QUBITS}",
+                qubo.name
+            )));
+        }
+
+        info!(
+            "Solving QUBO '{}' with {} variables using QAOA (p={})",
+            qubo.name, n, layers
+        );
+
+        // Objective of every basis state; bit q of the index is variable q
+        let energies: Vec<f64> = (0..1usize << n)
+            .map(|z| Self::objective(qubo, &Self::bits(z, n)))
+            .collect();
+        // Angles are relative to the objective range so the defaults suit any scale
+        let scale = energies
+            .iter()
+            .fold(0.0f64, |acc, e| acc.max(e.abs()))
+            .max(f64::EPSILON);
+
+        // Linear-ramp initialization, as in a discretized adiabatic schedule
+        let mut params: Vec<f64> = (0..layers)
+            .map(|k| PI * (k as f64 + 0.5) / layers as f64)
+            .chain((0..layers).map(|k| 0.75 * (1.0 - (k as f64 + 0.5) / layers as f64)))
+            .collect();
+
+        let mut expectation = self.expectation(&energies, scale, n, &params);
+        let mut history = vec![expectation];
+        let mut step = self.config.initial_step;
+        let mut converged = false;
+        let mut iterations = 0;
+
+        while iterations < self.config.max_iterations {
+            iterations += 1;
+            let mut improved = false;
+
+            for k in 0..params.len() {
+                for direction in [1.0, -1.0] {
+                    let mut candidate = params.clone();
+                    candidate[k] += direction * step;
+                    let value = self.expectation(&energies, scale, n, &candidate);
+                    if value < expectation - 1e-12 {
+                        params = candidate;
+                        expectation = value;
+                        improved = true;
+                        break;
+                    }
+                }
+            }
+
+            history.push(expectation);
+            if !improved {
+                step /= 2.0;
+                if step < self.config.convergence_threshold {
+                    converged = true;
+                    break;
+                }
+            }
+        }
+
+        debug!(
+            "QAOA optimization finished after {} iterations, ⟨H_C⟩ = {:.6}",
+            iterations, expectation
+        );
+
+        let (bitstring, backend) = match self.sample_backend(qubo) {
+            | Some(sampled) => sampled,
+            | None => {
+                let state = Self::evolve(&energies, scale, n, &params);
+                (
+                    self.sample_simulator(&state, &energies, n),
+                    SIMULATOR_BACKEND.to_string(),
+                )
+            },
+        };
+        let objective = Self::objective(qubo, &bitstring);
+
+        info!(
+            "QAOA solved '{}': objective {:.4} via {}",
+            qubo.name, objective, backend
+        );
+
+        let betas = params.split_off(layers);
+        Ok(QaoaResult {
+            bitstring,
+            objective,
+            expectation,
+            gammas: params,
+            betas,
+            history,
+            iterations,
+            converged,
+            backend,
+        })
+    }
+
+    /// Sample the problem on the configured hardware backend, if any
+    fn sample_backend(&self, qubo: &QUBOProblem) -> Option<(Vec<u8>, String)> {
+        let backend = self.backend.as_ref().filter(|b| b.is_available())?;
+        let ising = IsingModel::from_qubo(&qubo.q_matrix);
+
+        match backend.submit_ising_problem(&ising, self.config.shots) {
+            | Ok(samples) => {
+                let best = samples
+                    .iter()
+                    .map(|(spins, _, _)| ising.spins_to_binary(spins))
+                    .min_by(|a, b| Self::objective(qubo, a).total_cmp(&Self::objective(qubo, b)))?;
+                Some((best, backend.name().to_string()))
+            },
+            | Err(e) => {
+                warn!(
+                    "QAOA backend '{}' failed, falling back to simulation: {}",
+                    backend.name(),
+                    e
+                );
+                None
+            },
+        }
+    }
+
+    /// Sample the simulated state and keep the lowest-objective measurement
+    fn sample_simulator(&self, state: &DVector<Complex64>, energies: &[f64], n: usize) -> Vec<u8> {
+        let probabilities: Vec<f64> = state.iter().map(Complex64::norm_sqr).collect();
+        let mut rng = StdRng::seed_from_u64(self.config.seed);
+
+        let mut best: Option<usize> = None;
+        for _ in 0..self.config.shots.max(1) {
+            let r: f64 = rng.gen();
+            let mut cumulative = 0.0;
+            let mut measured = probabilities.len() - 1;
+            for (z, &p) in probabilities.iter().enumerate() {
+                cumulative += p;
+                if r < cumulative {
+                    measured = z;
+                    break;
+                }
+            }
+            if best.is_none_or(|b| energies[measured] < energies[b]) {
+                best = Some(measured);
+            }
+        }
+
+        Self::bits(best.unwrap_or(0), n)
+    }
+
+    /// Expected objective of the ansatz state for the given angles
+    fn expectation(&self, energies: &[f64], scale: f64, n: usize, params: &[f64]) -> f64 {
+        Self::evolve(energies, scale, n, params)
+            .iter()
+            .zip(energies)
+            .map(|(amplitude, energy)| amplitude.norm_sqr() * energy)
+            .sum()
+    }
+
+    /// Prepare the QAOA state for `params = [γ_1..γ_p, β_1..β_p]`
+    fn evolve(energies: &[f64], scale: f64, n: usize, params: &[f64]) -> DVector<Complex64> {
+        let dim = energies.len();
+        let layers = params.len() / 2;
+        let mut state = DVector::from_element(dim, Complex64::new(1.0 / (dim as f64).sqrt(), 0.0));
+
+        for layer in 0..layers {
+            let gamma = params[layer];
+            let beta = params[layers + layer];
+
+            // Cost unitary exp(-iγ H_C) is diagonal in the computational basis
+            for (amplitude, energy) in state.iter_mut().zip(energies) {
+                *amplitude *= Complex64::new(0.0, -gamma * energy / scale).exp();
+            }
+
+            // Mixer unitary exp(-iβ Σ X_i) = Π RX(2β)
+            let c = Complex64::new(beta.cos(), 0.0);
+            let s = Complex64::new(0.0, -beta.sin());
+            for qubit in 0..n {
+                let mask = 1 << qubit;
+                for i in (0..dim).filter(|i| i & mask == 0) {
+                    let a0 = state[i];
+                    let a1 = state[i | mask];
+                    state[i] = c * a0 + s * a1;
+                    state[i | mask] = s * a0 + c * a1;
+                }
+            }
+        }
+
+        state
+    }
+
+    /// QUBO objective `x^T Q x`
+    fn objective(qubo: &QUBOProblem, bits: &[u8]) -> f64 {
+        let n = bits.len();
+        let mut value = 0.0;
+        for i in (0..n).filter(|&i| bits[i] == 1) {
+            for j in (0..n).filter(|&j| bits[j] == 1) {
+                value += qubo.q_matrix[(i, j)];
+            }
+        }
+        value
+    }
+
+    fn bits(z: usize, n: usize) -> Vec<u8> {
+        (0..n).map(|q| ((z >> q) & 1) as u8).collect()
+    }
+}
+
+impl Default for QaoaSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
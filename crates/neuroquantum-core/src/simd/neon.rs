@@ -191,6 +191,16 @@ pub fn safe_neon_quantum_operation(
     }
 }
 
+/// Safe wrapper for NEON `value = scale * value + shift` with automatic feature detection
+pub fn safe_neon_scale_shift(values: &mut [f32], scale: f32, shift: f32) {
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        // SAFETY: We've checked that NEON is available
+        unsafe { neon_scale_shift(values, scale, shift) }
+    } else {
+        scalar_scale_shift(values, scale, shift);
+    }
+}
+
 /// Safe wrapper for NEON parallel search with automatic feature detection
 pub fn safe_neon_parallel_search(haystack: &[u8], needle: &[u8]) -> CoreResult<Vec<usize>> {
     if std::arch::is_aarch64_feature_detected!("neon") {
@@ -305,11 +315,45 @@ pub unsafe fn neon_quantum_operation(
                 imag_parts[i * 2 + 1] = (i0 - i1) * inv_sqrt2;
             }
         },
+        | QuantumOperation::Diffusion => {
+            if len > 0 {
+                let n = len as f32;
+                let mean_re = real_parts.iter().sum::<f32>() / n;
+                let mean_im = imag_parts.iter().sum::<f32>() / n;
+                neon_scale_shift(real_parts, -1.0, 2.0 * mean_re);
+                neon_scale_shift(imag_parts, -1.0, 2.0 * mean_im);
+            }
+        },
     }
 
     Ok(())
 }
 
+/// NEON-optimized `value = scale * value + shift` over a slice
+///
+/// # Safety
+///
+/// This function requires ARM64 NEON support. The caller must ensure:
+/// - The CPU supports NEON instructions
+/// - Use `std::arch::is_aarch64_feature_detected!("neon")` to check at runtime
+#[target_feature(enable = "neon")]
+pub unsafe fn neon_scale_shift(values: &mut [f32], scale: f32, shift: f32) {
+    let chunks = values.len() / 4;
+    let scale_vec = vdupq_n_f32(scale);
+    let shift_vec = vdupq_n_f32(shift);
+
+    for i in 0..chunks {
+        let idx = i * 4;
+        let value = vld1q_f32(&raw const values[idx]);
+        vst1q_f32(&raw mut values[idx], vfmaq_f32(shift_vec, value, scale_vec));
+    }
+
+    // Handle remainder
+    for value in &mut values[(chunks * 4)..] {
+        *value = scale.mul_add(*value, shift);
+    }
+}
+
 /// NEON-optimized parallel pattern search
 ///
 /// # Safety
@@ -459,6 +503,21 @@ fn scalar_quantum_operation(
                 imag_parts[i * 2 + 1] = (i0 - i1) * inv_sqrt2;
             }
         },
+        | QuantumOperation::Diffusion => {
+            if !real_parts.is_empty() {
+                let n = real_parts.len() as f32;
+                let mean_re = real_parts.iter().sum::<f32>() / n;
+                let mean_im = imag_parts.iter().sum::<f32>() / n;
+                scalar_scale_shift(real_parts, -1.0, 2.0 * mean_re);
+                scalar_scale_shift(imag_parts, -1.0, 2.0 * mean_im);
+            }
+        },
     }
     Ok(())
 }
+
+fn scalar_scale_shift(values: &mut [f32], scale: f32, shift: f32) {
+    for value in values {
+        *value = scale.mul_add(*value, shift);
+    }
+}
@@ -37,14 +37,28 @@
 //! | RZ   | 0.1  | 0.26 | -65 | 2 | Resonator |
 //! | LTS  | 0.02 | 0.25 | -65 | 2 | Low-Threshold Spiking |
 //!
+//! ## Other Neuron Models
+//!
+//! All models implement [`NeuronModel`], so a [`SpikingNeuralNetwork`] can mix
+//! them freely:
+//!
+//! - [`IzhikevichNeuron`]: the model described above
+//! - [`LifNeuron`]: leaky integrate-and-fire, `τ dv/dt = -(v - v_rest) + R·I`
+//! - [`HodgkinHuxleyNeuron`]: single-compartment Hodgkin-Huxley with Na⁺, K⁺
+//!   and leak conductances
+//!
 //! ## References
 //!
+//! - Hodgkin, A.L. & Huxley, A.F. (1952). A quantitative description of membrane
+//!   current and its application to conduction and excitation in nerve.
+//!   The Journal of Physiology, 117(4):500-544.
 //! - Izhikevich, E.M. (2003). Simple model of spiking neurons.
 //!   IEEE Transactions on Neural Networks, 14(6):1569-1572.
 //! - Izhikevich, E.M. (2004). Which model to use for cortical spiking neurons?
 //!   IEEE Transactions on Neural Networks, 15(5):1063-1070.
 
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::RwLock;
 
 use serde::{Deserialize, Serialize};
@@ -199,6 +213,50 @@ impl Default for IzhikevichParameters {
     }
 }
 
+/// A spike emitted by a neuron.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Spike {
+    /// Neuron that fired
+    pub neuron_id: u64,
+    /// Time of the spike in ms on the neuron's clock
+    pub time: u64,
+}
+
+/// Common interface for spiking neuron models.
+///
+/// Each neuron keeps its own simulation clock in ms, advanced by `dt` on every
+/// [`step`](Self::step). Spike times and [`last_spike_time`](Self::last_spike_time)
+/// are reported on that clock.
+pub trait NeuronModel {
+    /// Unique identifier of the neuron
+    fn id(&self) -> u64;
+
+    /// Advance the neuron by `dt` ms.
+    ///
+    /// `input_current` is added to any tonic current set with
+    /// [`set_current`](Self::set_current) for the duration of this step.
+    /// Returns the spike if the neuron fired.
+    fn step(&mut self, input_current: f64, dt: f64) -> Option<Spike>;
+
+    /// Set the tonic (injected) current
+    fn set_current(&mut self, current: f64);
+
+    /// Membrane potential (mV)
+    fn membrane_potential(&self) -> f64;
+
+    /// Time of the most recent spike, if any
+    fn last_spike_time(&self) -> Option<u64>;
+
+    /// Total number of spikes fired
+    fn spike_count(&self) -> u64;
+
+    /// Set the neuron's clock, e.g. when joining a running simulation
+    fn set_clock(&mut self, time_ms: u64);
+
+    /// Reset the neuron (and its clock) to its initial state
+    fn reset(&mut self);
+}
+
 /// Izhikevich spiking neuron model.
 ///
 /// This struct represents a single neuron following the Izhikevich dynamics.
@@ -242,6 +300,9 @@ pub struct IzhikevichNeuron {
 
     /// Accumulated synaptic input for current timestep
     synaptic_input: f64,
+
+    /// Simulation clock (ms)
+    clock_ms: f64,
 }
 
 impl IzhikevichNeuron {
@@ -277,6 +338,7 @@ impl IzhikevichNeuron {
             spike_history: Vec::with_capacity(1000),
             max_history_length: 1000,
             synaptic_input: 0.0,
+            clock_ms: 0.0,
         }
     }
 
@@ -298,6 +360,7 @@ impl IzhikevichNeuron {
             spike_history: Vec::with_capacity(1000),
             max_history_length: 1000,
             synaptic_input: 0.0,
+            clock_ms: 0.0,
         }
     }
 
@@ -336,17 +399,25 @@ impl IzhikevichNeuron {
     /// ```
     #[instrument(level = "trace", skip(self))]
     pub fn step(&mut self, current_time: u64) -> bool {
-        let total_input = self.current_input + self.synaptic_input;
+        self.clock_ms = current_time as f64;
+        self.integrate(0.0, 1.0).is_some()
+    }
+
+    /// Integrate the dynamics over `dt` ms and record a spike at the current clock
+    fn integrate(&mut self, extra_input: f64, dt: f64) -> Option<Spike> {
+        let total_input = self.current_input + self.synaptic_input + extra_input;
         self.synaptic_input = 0.0; // Reset for next timestep
 
         let IzhikevichParameters { a, b, c, d } = self.params;
 
-        // Two half-steps for numerical stability (Euler method with dt=0.5ms)
-        for _ in 0..2 {
-            self.v += 0.5
-                * ((0.04 * self.v).mul_add(self.v, 5.0 * self.v) + 140.0 - self.u + total_input);
+        // Euler half-steps of at most 0.5 ms for numerical stability
+        let substeps = (dt / 0.5).ceil().max(1.0);
+        let h = dt / substeps;
+        for _ in 0..substeps as u32 {
+            self.v +=
+                h * ((0.04 * self.v).mul_add(self.v, 5.0 * self.v) + 140.0 - self.u + total_input);
         }
-        self.u += a * b.mul_add(self.v, -self.u);
+        self.u += dt * a * b.mul_add(self.v, -self.u);
 
         // Check for spike
         if self.v >= self.spike_threshold {
@@ -355,6 +426,7 @@ impl IzhikevichNeuron {
             self.u += d;
 
             // Record spike
+            let current_time = self.clock_ms.round() as u64;
             self.spike_count += 1;
             self.last_spike_time = Some(current_time);
 
@@ -365,10 +437,13 @@ impl IzhikevichNeuron {
             self.spike_history.push(current_time);
 
             debug!(neuron_id = self.id, time = current_time, "Spike fired");
-            return true;
+            return Some(Spike {
+                neuron_id: self.id,
+                time: current_time,
+            });
         }
 
-        false
+        None
     }
 
     /// Get the instantaneous firing rate (Hz) based on recent spike history.
@@ -409,6 +484,7 @@ impl IzhikevichNeuron {
         self.spike_history.clear();
         self.synaptic_input = 0.0;
         self.current_input = 0.0;
+        self.clock_ms = 0.0;
     }
 
     /// Get the spike history.
@@ -430,6 +506,437 @@ impl IzhikevichNeuron {
     }
 }
 
+impl NeuronModel for IzhikevichNeuron {
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn step(&mut self, input_current: f64, dt: f64) -> Option<Spike> {
+        self.clock_ms += dt;
+        self.integrate(input_current, dt)
+    }
+
+    fn set_current(&mut self, current: f64) {
+        Self::set_current(self, current);
+    }
+
+    fn membrane_potential(&self) -> f64 {
+        self.v
+    }
+
+    fn last_spike_time(&self) -> Option<u64> {
+        self.last_spike_time
+    }
+
+    fn spike_count(&self) -> u64 {
+        self.spike_count
+    }
+
+    fn set_clock(&mut self, time_ms: u64) {
+        self.clock_ms = time_ms as f64;
+    }
+
+    fn reset(&mut self) {
+        Self::reset(self);
+    }
+}
+
+/// Parameters of the leaky integrate-and-fire model.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LifParameters {
+    /// Membrane time constant (ms)
+    pub tau_m: f64,
+    /// Resting potential (mV)
+    pub v_rest: f64,
+    /// Potential after a spike (mV)
+    pub v_reset: f64,
+    /// Firing threshold (mV)
+    pub v_threshold: f64,
+    /// Membrane resistance (scales input current to voltage)
+    pub resistance: f64,
+    /// Absolute refractory period (ms)
+    pub refractory_ms: f64,
+}
+
+impl Default for LifParameters {
+    fn default() -> Self {
+        Self {
+            tau_m: 10.0,
+            v_rest: -65.0,
+            v_reset: -70.0,
+            v_threshold: -50.0,
+            resistance: 1.0,
+            refractory_ms: 2.0,
+        }
+    }
+}
+
+/// Leaky integrate-and-fire neuron.
+///
+/// Integrates `τ dv/dt = -(v - v_rest) + R·I` and fires when `v` reaches the
+/// threshold, after which it is clamped to `v_reset` for the refractory period.
+/// With the default parameters it fires for input currents above 15.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifNeuron {
+    /// Unique identifier for this neuron
+    pub id: u64,
+
+    /// Model parameters
+    pub params: LifParameters,
+
+    /// Membrane potential (mV)
+    pub v: f64,
+
+    /// Tonic injected current
+    pub current_input: f64,
+
+    /// Total number of spikes fired
+    pub spike_count: u64,
+
+    /// Time of last spike (ms)
+    pub last_spike_time: Option<u64>,
+
+    /// Remaining refractory time (ms)
+    refractory_remaining: f64,
+
+    /// Simulation clock (ms)
+    clock_ms: f64,
+}
+
+impl LifNeuron {
+    /// Euler integration step (ms)
+    const INTEGRATION_STEP: f64 = 0.1;
+
+    /// Create a LIF neuron with default parameters.
+    #[must_use]
+    pub fn new(id: u64) -> Self {
+        Self::with_params(id, LifParameters::default())
+    }
+
+    /// Create a LIF neuron with custom parameters.
+    #[must_use]
+    pub const fn with_params(id: u64, params: LifParameters) -> Self {
+        Self {
+            id,
+            params,
+            v: params.v_rest,
+            current_input: 0.0,
+            spike_count: 0,
+            last_spike_time: None,
+            refractory_remaining: 0.0,
+            clock_ms: 0.0,
+        }
+    }
+}
+
+impl NeuronModel for LifNeuron {
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn step(&mut self, input_current: f64, dt: f64) -> Option<Spike> {
+        self.clock_ms += dt;
+        let total_input = self.current_input + input_current;
+        let p = self.params;
+
+        let substeps = (dt / Self::INTEGRATION_STEP).ceil().max(1.0);
+        let h = dt / substeps;
+        let mut spike = None;
+        for _ in 0..substeps as u32 {
+            if self.refractory_remaining > 0.0 {
+                self.refractory_remaining -= h;
+                self.v = p.v_reset;
+                continue;
+            }
+
+            self.v += h * p.resistance.mul_add(total_input, p.v_rest - self.v) / p.tau_m;
+            if self.v >= p.v_threshold {
+                self.v = p.v_reset;
+                self.refractory_remaining = p.refractory_ms;
+                let time = self.clock_ms.round() as u64;
+                self.spike_count += 1;
+                self.last_spike_time = Some(time);
+                spike = Some(Spike {
+                    neuron_id: self.id,
+                    time,
+                });
+            }
+        }
+        spike
+    }
+
+    fn set_current(&mut self, current: f64) {
+        self.current_input = current;
+    }
+
+    fn membrane_potential(&self) -> f64 {
+        self.v
+    }
+
+    fn last_spike_time(&self) -> Option<u64> {
+        self.last_spike_time
+    }
+
+    fn spike_count(&self) -> u64 {
+        self.spike_count
+    }
+
+    fn set_clock(&mut self, time_ms: u64) {
+        self.clock_ms = time_ms as f64;
+    }
+
+    fn reset(&mut self) {
+        *self = Self::with_params(self.id, self.params);
+    }
+}
+
+/// Single-compartment Hodgkin-Huxley neuron.
+///
+/// Uses the original squid giant axon conductances (membrane potential shifted
+/// so rest is at -65 mV), integrated with forward Euler at 0.01 ms. A spike is
+/// counted when the membrane potential crosses 0 mV from below. Repetitive
+/// firing starts at an input current of roughly 6-7 µA/cm².
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HodgkinHuxleyNeuron {
+    /// Unique identifier for this neuron
+    pub id: u64,
+
+    /// Membrane potential (mV)
+    pub v: f64,
+
+    /// Sodium activation gate
+    pub m: f64,
+
+    /// Sodium inactivation gate
+    pub h: f64,
+
+    /// Potassium activation gate
+    pub n: f64,
+
+    /// Tonic injected current (µA/cm²)
+    pub current_input: f64,
+
+    /// Total number of spikes fired
+    pub spike_count: u64,
+
+    /// Time of last spike (ms)
+    pub last_spike_time: Option<u64>,
+
+    /// Whether the membrane is currently above the spike detection threshold
+    above_threshold: bool,
+
+    /// Simulation clock (ms)
+    clock_ms: f64,
+}
+
+impl HodgkinHuxleyNeuron {
+    const RESTING_POTENTIAL: f64 = -65.0;
+    const CAPACITANCE: f64 = 1.0;
+    const G_NA: f64 = 120.0;
+    const G_K: f64 = 36.0;
+    const G_L: f64 = 0.3;
+    const E_NA: f64 = 50.0;
+    const E_K: f64 = -77.0;
+    const E_L: f64 = -54.387;
+    const SPIKE_THRESHOLD: f64 = 0.0;
+    const INTEGRATION_STEP: f64 = 0.01;
+
+    /// Create a Hodgkin-Huxley neuron at rest.
+    #[must_use]
+    pub fn new(id: u64) -> Self {
+        let v = Self::RESTING_POTENTIAL;
+        let rates = GatingRates::at(v);
+        Self {
+            id,
+            v,
+            m: rates.alpha_m / (rates.alpha_m + rates.beta_m),
+            h: rates.alpha_h / (rates.alpha_h + rates.beta_h),
+            n: rates.alpha_n / (rates.alpha_n + rates.beta_n),
+            current_input: 0.0,
+            spike_count: 0,
+            last_spike_time: None,
+            above_threshold: false,
+            clock_ms: 0.0,
+        }
+    }
+}
+
+/// Voltage-dependent opening and closing rates of the Hodgkin-Huxley gates
+struct GatingRates {
+    alpha_m: f64,
+    beta_m: f64,
+    alpha_h: f64,
+    beta_h: f64,
+    alpha_n: f64,
+    beta_n: f64,
+}
+
+impl GatingRates {
+    fn at(v: f64) -> Self {
+        // x / (1 - exp(-x / k)), continuous at x = 0
+        let linoid = |x: f64, k: f64| {
+            if x.abs() < 1e-7 {
+                k
+            } else {
+                x / (1.0 - (-x / k).exp())
+            }
+        };
+        Self {
+            alpha_m: 0.1 * linoid(v + 40.0, 10.0),
+            beta_m: 4.0 * (-(v + 65.0) / 18.0).exp(),
+            alpha_h: 0.07 * (-(v + 65.0) / 20.0).exp(),
+            beta_h: 1.0 / (1.0 + (-(v + 35.0) / 10.0).exp()),
+            alpha_n: 0.01 * linoid(v + 55.0, 10.0),
+            beta_n: 0.125 * (-(v + 65.0) / 80.0).exp(),
+        }
+    }
+}
+
+impl NeuronModel for HodgkinHuxleyNeuron {
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn step(&mut self, input_current: f64, dt: f64) -> Option<Spike> {
+        self.clock_ms += dt;
+        let total_input = self.current_input + input_current;
+
+        let substeps = (dt / Self::INTEGRATION_STEP).ceil().max(1.0);
+        let h = dt / substeps;
+        let mut spike = None;
+        for _ in 0..substeps as u32 {
+            let rates = GatingRates::at(self.v);
+            let i_na = Self::G_NA * self.m.powi(3) * self.h * (self.v - Self::E_NA);
+            let i_k = Self::G_K * self.n.powi(4) * (self.v - Self::E_K);
+            let i_l = Self::G_L * (self.v - Self::E_L);
+
+            self.v += h * (total_input - i_na - i_k - i_l) / Self::CAPACITANCE;
+            self.m += h * rates.alpha_m.mul_add(1.0 - self.m, -rates.beta_m * self.m);
+            self.h += h * rates.alpha_h.mul_add(1.0 - self.h, -rates.beta_h * self.h);
+            self.n += h * rates.alpha_n.mul_add(1.0 - self.n, -rates.beta_n * self.n);
+
+            if self.v >= Self::SPIKE_THRESHOLD && !self.above_threshold {
+                self.above_threshold = true;
+                let time = self.clock_ms.round() as u64;
+                self.spike_count += 1;
+                self.last_spike_time = Some(time);
+                spike = Some(Spike {
+                    neuron_id: self.id,
+                    time,
+                });
+            } else if self.v < Self::SPIKE_THRESHOLD {
+                self.above_threshold = false;
+            }
+        }
+        spike
+    }
+
+    fn set_current(&mut self, current: f64) {
+        self.current_input = current;
+    }
+
+    fn membrane_potential(&self) -> f64 {
+        self.v
+    }
+
+    fn last_spike_time(&self) -> Option<u64> {
+        self.last_spike_time
+    }
+
+    fn spike_count(&self) -> u64 {
+        self.spike_count
+    }
+
+    fn set_clock(&mut self, time_ms: u64) {
+        self.clock_ms = time_ms as f64;
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new(self.id);
+    }
+}
+
+/// A neuron of any supported model, as stored in a [`SpikingNeuralNetwork`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SpikingNeuron {
+    /// Izhikevich neuron
+    Izhikevich(IzhikevichNeuron),
+    /// Leaky integrate-and-fire neuron
+    Lif(LifNeuron),
+    /// Hodgkin-Huxley neuron
+    HodgkinHuxley(HodgkinHuxleyNeuron),
+}
+
+impl SpikingNeuron {
+    fn model(&self) -> &dyn NeuronModel {
+        match self {
+            | Self::Izhikevich(neuron) => neuron,
+            | Self::Lif(neuron) => neuron,
+            | Self::HodgkinHuxley(neuron) => neuron,
+        }
+    }
+
+    fn model_mut(&mut self) -> &mut dyn NeuronModel {
+        match self {
+            | Self::Izhikevich(neuron) => neuron,
+            | Self::Lif(neuron) => neuron,
+            | Self::HodgkinHuxley(neuron) => neuron,
+        }
+    }
+}
+
+impl NeuronModel for SpikingNeuron {
+    fn id(&self) -> u64 {
+        self.model().id()
+    }
+
+    fn step(&mut self, input_current: f64, dt: f64) -> Option<Spike> {
+        self.model_mut().step(input_current, dt)
+    }
+
+    fn set_current(&mut self, current: f64) {
+        self.model_mut().set_current(current);
+    }
+
+    fn membrane_potential(&self) -> f64 {
+        self.model().membrane_potential()
+    }
+
+    fn last_spike_time(&self) -> Option<u64> {
+        self.model().last_spike_time()
+    }
+
+    fn spike_count(&self) -> u64 {
+        self.model().spike_count()
+    }
+
+    fn set_clock(&mut self, time_ms: u64) {
+        self.model_mut().set_clock(time_ms);
+    }
+
+    fn reset(&mut self) {
+        self.model_mut().reset();
+    }
+}
+
+impl From<IzhikevichNeuron> for SpikingNeuron {
+    fn from(neuron: IzhikevichNeuron) -> Self {
+        Self::Izhikevich(neuron)
+    }
+}
+
+impl From<LifNeuron> for SpikingNeuron {
+    fn from(neuron: LifNeuron) -> Self {
+        Self::Lif(neuron)
+    }
+}
+
+impl From<HodgkinHuxleyNeuron> for SpikingNeuron {
+    fn from(neuron: HodgkinHuxleyNeuron) -> Self {
+        Self::HodgkinHuxley(neuron)
+    }
+}
+
 /// Conductance-based synapse for spiking neural networks.
 ///
 /// Models synaptic transmission with exponential decay dynamics,
@@ -461,6 +968,82 @@ pub struct SpikingSynapse {
 
     /// Queue of pending spikes (time, weight)
     spike_queue: Vec<(u64, f64)>,
+
+    /// Plasticity rule applied to this synapse
+    pub stdp: StdpVariant,
+
+    /// Spike traces used by trace-based plasticity rules
+    traces: PlasticityTraces,
+}
+
+/// Plasticity rule variant applied to a [`SpikingSynapse`].
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum StdpVariant {
+    /// Classic pair-based STDP (default)
+    #[default]
+    Pair,
+    /// Triplet STDP (Pfister & Gerstner, 2006)
+    ///
+    /// Adds a second, slower trace per side so that potentiation depends on
+    /// recent postsynaptic activity, reproducing the frequency dependence of
+    /// plasticity observed in cortex.
+    Triplet,
+    /// Reward-modulated (dopamine-gated) STDP
+    ///
+    /// Pair-based updates accumulate in an eligibility trace instead of the
+    /// weight; the weight only changes when a reward signal is delivered.
+    RewardModulated {
+        /// Decay time constant of the eligibility trace (ms)
+        eligibility_trace_tau: f64,
+    },
+}
+
+/// Exponentially decaying spike traces for trace-based STDP.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct PlasticityTraces {
+    /// Fast presynaptic trace (`tau_plus`)
+    r1: f64,
+    /// Slow presynaptic trace (`tau_x`)
+    r2: f64,
+    /// Fast postsynaptic trace (`tau_minus`)
+    o1: f64,
+    /// Slow postsynaptic trace (`tau_y`)
+    o2: f64,
+    /// Eligibility trace for reward-modulated STDP
+    eligibility: f64,
+    /// Time the traces were last decayed to
+    last_update: u64,
+}
+
+impl PlasticityTraces {
+    const fn new() -> Self {
+        Self {
+            r1: 0.0,
+            r2: 0.0,
+            o1: 0.0,
+            o2: 0.0,
+            eligibility: 0.0,
+            last_update: 0,
+        }
+    }
+
+    /// Decay all traces from `last_update` to `time`
+    fn decay_to(&mut self, time: u64, rule: &STDPRule, variant: StdpVariant) {
+        let dt = time.saturating_sub(self.last_update) as f64;
+        if dt > 0.0 {
+            self.r1 *= (-dt / rule.tau_plus).exp();
+            self.r2 *= (-dt / rule.tau_x).exp();
+            self.o1 *= (-dt / rule.tau_minus).exp();
+            self.o2 *= (-dt / rule.tau_y).exp();
+            if let StdpVariant::RewardModulated {
+                eligibility_trace_tau,
+            } = variant
+            {
+                self.eligibility *= (-dt / eligibility_trace_tau).exp();
+            }
+        }
+        self.last_update = self.last_update.max(time);
+    }
 }
 
 impl SpikingSynapse {
@@ -476,6 +1059,8 @@ impl SpikingSynapse {
             conductance: 0.0,
             reversal_potential: 0.0, // Excitatory reversal potential
             spike_queue: Vec::new(),
+            stdp: StdpVariant::Pair,
+            traces: PlasticityTraces::new(),
         }
     }
 
@@ -491,6 +1076,8 @@ impl SpikingSynapse {
             conductance: 0.0,
             reversal_potential: -70.0, // Inhibitory reversal potential
             spike_queue: Vec::new(),
+            stdp: StdpVariant::Pair,
+            traces: PlasticityTraces::new(),
         }
     }
 
@@ -513,9 +1100,94 @@ impl SpikingSynapse {
             conductance: 0.0,
             reversal_potential,
             spike_queue: Vec::new(),
+            stdp: StdpVariant::Pair,
+            traces: PlasticityTraces::new(),
+        }
+    }
+
+    /// Select the plasticity rule for this synapse.
+    #[must_use]
+    pub const fn with_stdp(mut self, variant: StdpVariant) -> Self {
+        self.stdp = variant;
+        self
+    }
+
+    /// Current eligibility trace (reward-modulated STDP only).
+    #[must_use]
+    pub const fn eligibility(&self) -> f64 {
+        self.traces.eligibility
+    }
+
+    /// Apply the trace-based plasticity update for a presynaptic spike at `time`.
+    ///
+    /// Pair and reward-modulated rules depress by the postsynaptic trace;
+    /// the triplet rule additionally scales depression by the slow
+    /// presynaptic trace.
+    pub fn on_pre_spike(&mut self, time: u64, rule: &STDPRule) {
+        self.traces.decay_to(time, rule, self.stdp);
+        let dw = match self.stdp {
+            | StdpVariant::Triplet => {
+                -self.traces.o1 * rule.a3_minus.mul_add(self.traces.r2, rule.a_minus)
+            },
+            | StdpVariant::Pair | StdpVariant::RewardModulated { .. } => {
+                -self.traces.o1 * rule.a_minus
+            },
+        };
+        self.apply_weight_change(dw, rule);
+        self.traces.r1 += 1.0;
+        self.traces.r2 += 1.0;
+    }
+
+    /// Apply the trace-based plasticity update for a postsynaptic spike at `time`.
+    ///
+    /// Pair and reward-modulated rules potentiate by the presynaptic trace;
+    /// the triplet rule additionally scales potentiation by the slow
+    /// postsynaptic trace, which grows with the postsynaptic firing rate.
+    pub fn on_post_spike(&mut self, time: u64, rule: &STDPRule) {
+        self.traces.decay_to(time, rule, self.stdp);
+        let dw = match self.stdp {
+            | StdpVariant::Triplet => {
+                self.traces.r1 * rule.a3_plus.mul_add(self.traces.o2, rule.a_plus)
+            },
+            | StdpVariant::Pair | StdpVariant::RewardModulated { .. } => {
+                self.traces.r1 * rule.a_plus
+            },
+        };
+        self.apply_weight_change(dw, rule);
+        self.traces.o1 += 1.0;
+        self.traces.o2 += 1.0;
+    }
+
+    /// Convert the eligibility trace into a weight change scaled by `reward`.
+    ///
+    /// Has no effect on synapses that are not reward-modulated.
+    pub fn apply_reward(&mut self, time: u64, reward: f64, rule: &STDPRule) {
+        if !matches!(self.stdp, StdpVariant::RewardModulated { .. }) {
+            return;
+        }
+        self.traces.decay_to(time, rule, self.stdp);
+        self.weight = reward
+            .mul_add(self.traces.eligibility, self.weight)
+            .clamp(rule.w_min, rule.w_max);
+    }
+
+    /// Route a weight change to the weight or, when reward-gated, the eligibility trace
+    fn apply_weight_change(&mut self, dw: f64, rule: &STDPRule) {
+        match self.stdp {
+            | StdpVariant::RewardModulated { .. } => self.traces.eligibility += dw,
+            | StdpVariant::Pair | StdpVariant::Triplet => {
+                self.weight = (self.weight + dw).clamp(rule.w_min, rule.w_max);
+            },
         }
     }
 
+    /// Clear transient state (conductance, pending spikes and traces).
+    fn reset_state(&mut self) {
+        self.conductance = 0.0;
+        self.spike_queue.clear();
+        self.traces = PlasticityTraces::new();
+    }
+
     /// Receive a presynaptic spike.
     pub fn receive_spike(&mut self, arrival_time: u64) {
         self.spike_queue
@@ -574,6 +1246,18 @@ pub struct STDPRule {
 
     /// Maximum weight
     pub w_max: f64,
+
+    /// Triplet potentiation amplitude (scaled by the slow postsynaptic trace)
+    pub a3_plus: f64,
+
+    /// Triplet depression amplitude (scaled by the slow presynaptic trace)
+    pub a3_minus: f64,
+
+    /// Time constant of the slow presynaptic trace (ms)
+    pub tau_x: f64,
+
+    /// Time constant of the slow postsynaptic trace (ms)
+    pub tau_y: f64,
 }
 
 impl Default for STDPRule {
@@ -585,6 +1269,12 @@ impl Default for STDPRule {
             tau_minus: 20.0,
             w_min: 0.0,
             w_max: 1.0,
+            // Minimal all-to-all triplet model fitted to visual cortex data
+            // (Pfister & Gerstner, 2006)
+            a3_plus: 0.0062,
+            a3_minus: 0.00023,
+            tau_x: 101.0,
+            tau_y: 125.0,
         }
     }
 }
@@ -622,7 +1312,7 @@ impl STDPRule {
 #[derive(Debug)]
 pub struct SpikingNeuralNetwork {
     /// Neurons in the network
-    neurons: RwLock<HashMap<u64, IzhikevichNeuron>>,
+    neurons: RwLock<HashMap<u64, SpikingNeuron>>,
 
     /// Synapses indexed by postsynaptic neuron ID
     synapses: RwLock<HashMap<u64, Vec<SpikingSynapse>>>,
@@ -654,20 +1344,25 @@ impl SpikingNeuralNetwork {
         }
     }
 
-    /// Add a neuron to the network.
-    pub fn add_neuron(&self, neuron: IzhikevichNeuron) -> CoreResult<()> {
+    /// Add a neuron of any model to the network.
+    ///
+    /// The neuron's clock is aligned with the network's simulation time.
+    pub fn add_neuron(&self, neuron: impl Into<SpikingNeuron>) -> CoreResult<()> {
+        let mut neuron = neuron.into();
+        neuron.set_clock(self.current_time()?);
+
         let mut neurons = self.neurons.write().map_err(|e| {
             CoreError::LockError(format!("Failed to acquire neurons write lock: {e}"))
         })?;
 
-        if neurons.contains_key(&neuron.id) {
+        let id = neuron.id();
+        if neurons.contains_key(&id) {
             return Err(CoreError::InvalidOperation(format!(
-                "Neuron with ID {} already exists",
-                neuron.id
+                "Neuron with ID {id} already exists"
             )));
         }
 
-        neurons.insert(neuron.id, neuron);
+        neurons.insert(id, neuron);
         Ok(())
     }
 
@@ -678,6 +1373,7 @@ impl SpikingNeuralNetwork {
         count: u64,
         neuron_type: IzhikevichNeuronType,
     ) -> CoreResult<Vec<u64>> {
+        let current_time = self.current_time()?;
         let mut neurons = self.neurons.write().map_err(|e| {
             CoreError::LockError(format!("Failed to acquire neurons write lock: {e}"))
         })?;
@@ -690,7 +1386,9 @@ impl SpikingNeuralNetwork {
                     "Neuron with ID {id} already exists"
                 )));
             }
-            neurons.insert(id, IzhikevichNeuron::new(id, neuron_type));
+            let mut neuron = SpikingNeuron::from(IzhikevichNeuron::new(id, neuron_type));
+            neuron.set_clock(current_time);
+            neurons.insert(id, neuron);
             ids.push(id);
         }
 
@@ -704,6 +1402,18 @@ impl SpikingNeuralNetwork {
         post_id: u64,
         weight: f64,
         excitatory: bool,
+    ) -> CoreResult<()> {
+        self.connect_with_stdp(pre_id, post_id, weight, excitatory, StdpVariant::Pair)
+    }
+
+    /// Connect two neurons with a synapse using the given plasticity rule.
+    pub fn connect_with_stdp(
+        &self,
+        pre_id: u64,
+        post_id: u64,
+        weight: f64,
+        excitatory: bool,
+        stdp: StdpVariant,
     ) -> CoreResult<()> {
         // Verify neurons exist
         {
@@ -726,7 +1436,8 @@ impl SpikingNeuralNetwork {
             SpikingSynapse::excitatory(pre_id, post_id, weight)
         } else {
             SpikingSynapse::inhibitory(pre_id, post_id, weight)
-        };
+        }
+        .with_stdp(stdp);
 
         let mut synapses = self.synapses.write().map_err(|e| {
             CoreError::LockError(format!("Failed to acquire synapses write lock: {e}"))
@@ -779,7 +1490,8 @@ impl SpikingNeuralNetwork {
                 if let Some(post_neuron) = neurons.get(post_id) {
                     let mut total_current = 0.0;
                     for synapse in synapse_list.iter_mut() {
-                        total_current += synapse.update(current_time, post_neuron.v);
+                        total_current +=
+                            synapse.update(current_time, post_neuron.membrane_potential());
                     }
                     synaptic_currents.insert(*post_id, total_current);
                 }
@@ -793,10 +1505,8 @@ impl SpikingNeuralNetwork {
             })?;
 
             for (id, neuron) in neurons.iter_mut() {
-                if let Some(&current) = synaptic_currents.get(id) {
-                    neuron.add_synaptic_input(current);
-                }
-                if neuron.step(current_time) {
+                let current = synaptic_currents.get(id).copied().unwrap_or(0.0);
+                if neuron.step(current, 1.0).is_some() {
                     fired_neurons.push(*id);
                 }
             }
@@ -814,6 +1524,9 @@ impl SpikingNeuralNetwork {
                     for synapse in synapse_list.iter_mut() {
                         if synapse.pre_id == pre_id {
                             synapse.receive_spike(current_time);
+                            if self.learning_enabled && synapse.stdp != StdpVariant::Pair {
+                                synapse.on_pre_spike(current_time, &self.stdp_rule);
+                            }
                         }
                     }
                 }
@@ -848,9 +1561,14 @@ impl SpikingNeuralNetwork {
         for &post_id in fired_neurons {
             if let Some(synapse_list) = synapses.get_mut(&post_id) {
                 for synapse in synapse_list.iter_mut() {
+                    // Trace-based rules keep their own spike history
+                    if synapse.stdp != StdpVariant::Pair {
+                        synapse.on_post_spike(current_time, &self.stdp_rule);
+                        continue;
+                    }
                     // Get presynaptic spike time
                     if let Some(pre_neuron) = neurons.get(&synapse.pre_id) {
-                        if let Some(pre_spike_time) = pre_neuron.last_spike_time {
+                        if let Some(pre_spike_time) = pre_neuron.last_spike_time() {
                             let delta_t = (current_time as i64 - pre_spike_time as i64) as f64;
                             // Only apply STDP for recent spikes (within 100 ms window)
                             if delta_t.abs() < 100.0 {
@@ -929,6 +1647,21 @@ impl SpikingNeuralNetwork {
         })
     }
 
+    /// Deliver a reward (e.g. dopamine) signal to all reward-modulated synapses.
+    ///
+    /// Each synapse's weight changes by `reward` times its current
+    /// eligibility trace; other synapses are unaffected.
+    pub fn deliver_reward(&self, reward: f64) -> CoreResult<()> {
+        let current_time = self.current_time()?;
+        let mut synapses = self.synapses.write().map_err(|e| {
+            CoreError::LockError(format!("Failed to acquire synapses write lock: {e}"))
+        })?;
+        for synapse in synapses.values_mut().flat_map(|list| list.iter_mut()) {
+            synapse.apply_reward(current_time, reward, &self.stdp_rule);
+        }
+        Ok(())
+    }
+
     /// Get the current simulation time.
     pub fn current_time(&self) -> CoreResult<u64> {
         Ok(*self
@@ -944,7 +1677,7 @@ impl SpikingNeuralNetwork {
                 CoreError::LockError(format!("Failed to acquire neurons write lock: {e}"))
             })?;
             for neuron in neurons.values_mut() {
-                neuron.reset();
+                NeuronModel::reset(neuron);
             }
         }
         {
@@ -953,8 +1686,7 @@ impl SpikingNeuralNetwork {
             })?;
             for synapse_list in synapses.values_mut() {
                 for synapse in synapse_list.iter_mut() {
-                    synapse.conductance = 0.0;
-                    synapse.spike_queue.clear();
+                    synapse.reset_state();
                 }
             }
         }
@@ -969,12 +1701,163 @@ impl SpikingNeuralNetwork {
     }
 }
 
+impl SpikingNeuralNetwork {
+    /// Save the complete network state to `path`.
+    ///
+    /// Neurons (parameters and membrane state), synapses (weights, conductance
+    /// and pending spikes), the STDP rule, the simulation clock and the
+    /// current [`NetworkStatistics`] are written in a versioned binary format.
+    /// The file is written to a temporary sibling and renamed into place, so
+    /// an interrupted save never leaves a truncated checkpoint behind.
+    pub fn save(&self, path: impl AsRef<Path>) -> CoreResult<()> {
+        let path = path.as_ref();
+        let bytes = self.checkpoint()?.encode()?;
+
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                CoreError::StorageError(format!("Failed to create checkpoint directory: {e}"))
+            })?;
+        }
+        let tmp_path = path.with_extension("tmp");
+        std::fs::write(&tmp_path, &bytes)
+            .map_err(|e| CoreError::StorageError(format!("Failed to write checkpoint: {e}")))?;
+        std::fs::rename(&tmp_path, path)
+            .map_err(|e| CoreError::StorageError(format!("Failed to finalize checkpoint: {e}")))?;
+
+        debug!(
+            "Saved spiking network checkpoint to {} ({} bytes)",
+            path.display(),
+            bytes.len()
+        );
+        Ok(())
+    }
+
+    /// Load a network previously written with [`save`](Self::save).
+    ///
+    /// The restored network continues exactly where the saved one stopped:
+    /// driven with the same input, it produces the same spike trains.
+    pub fn load(path: impl AsRef<Path>) -> CoreResult<Self> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)
+            .map_err(|e| CoreError::StorageError(format!("Failed to read checkpoint: {e}")))?;
+        let network = NetworkCheckpoint::decode(&bytes)?.restore()?;
+
+        debug!("Loaded spiking network checkpoint from {}", path.display());
+        Ok(network)
+    }
+
+    /// Capture a consistent snapshot of the network state
+    fn checkpoint(&self) -> CoreResult<NetworkCheckpoint> {
+        let statistics = self.statistics()?;
+        let neurons = self.neurons.read().map_err(|e| {
+            CoreError::LockError(format!("Failed to acquire neurons read lock: {e}"))
+        })?;
+        let synapses = self.synapses.read().map_err(|e| {
+            CoreError::LockError(format!("Failed to acquire synapses read lock: {e}"))
+        })?;
+
+        let mut neurons: Vec<SpikingNeuron> = neurons.values().cloned().collect();
+        neurons.sort_by_key(NeuronModel::id);
+        let mut synapses: Vec<(u64, Vec<SpikingSynapse>)> = synapses
+            .iter()
+            .map(|(post_id, list)| (*post_id, list.clone()))
+            .collect();
+        synapses.sort_by_key(|(post_id, _)| *post_id);
+
+        Ok(NetworkCheckpoint {
+            neurons,
+            synapses,
+            stdp_rule: self.stdp_rule.clone(),
+            learning_enabled: self.learning_enabled,
+            current_time: statistics.simulation_time_ms,
+            total_spikes: statistics.total_spikes,
+            statistics,
+        })
+    }
+}
+
 impl Default for SpikingNeuralNetwork {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Magic bytes identifying a spiking network checkpoint
+const CHECKPOINT_MAGIC: &[u8; 4] = b"NQSN";
+
+/// Current checkpoint format version
+///
+/// Version 2 added per-synapse plasticity rules and trace state; version 3
+/// stores neurons of any [`NeuronModel`].
+const CHECKPOINT_VERSION: u32 = 3;
+
+/// Serialized form of a [`SpikingNeuralNetwork`].
+///
+/// On disk: `"NQSN" | version (u32 LE) | bincode payload`.
+#[derive(Debug, Serialize, Deserialize)]
+struct NetworkCheckpoint {
+    neurons: Vec<SpikingNeuron>,
+    synapses: Vec<(u64, Vec<SpikingSynapse>)>,
+    stdp_rule: STDPRule,
+    learning_enabled: bool,
+    current_time: u64,
+    total_spikes: u64,
+    statistics: NetworkStatistics,
+}
+
+impl NetworkCheckpoint {
+    fn encode(&self) -> CoreResult<Vec<u8>> {
+        let payload = bincode::serialize(self).map_err(|e| {
+            CoreError::SerializationError(format!("Failed to encode checkpoint: {e}"))
+        })?;
+        let mut bytes = Vec::with_capacity(CHECKPOINT_MAGIC.len() + 4 + payload.len());
+        bytes.extend_from_slice(CHECKPOINT_MAGIC);
+        bytes.extend_from_slice(&CHECKPOINT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&payload);
+        Ok(bytes)
+    }
+
+    fn decode(bytes: &[u8]) -> CoreResult<Self> {
+        let header_len = CHECKPOINT_MAGIC.len() + 4;
+        if bytes.len() < header_len || &bytes[..CHECKPOINT_MAGIC.len()] != CHECKPOINT_MAGIC {
+            return Err(CoreError::SerializationError(
+                "Not a spiking network checkpoint".to_string(),
+            ));
+        }
+        let mut version = [0u8; 4];
+        version.copy_from_slice(&bytes[CHECKPOINT_MAGIC.len()..header_len]);
+        let version = u32::from_le_bytes(version);
+        if version != CHECKPOINT_VERSION {
+            return Err(CoreError::SerializationError(format!(
+                "Unsupported spiking network checkpoint version {version} (expected {CHECKPOINT_VERSION})"
+            )));
+        }
+
+        bincode::deserialize(&bytes[header_len..])
+            .map_err(|e| CoreError::SerializationError(format!("Failed to decode checkpoint: {e}")))
+    }
+
+    fn restore(self) -> CoreResult<SpikingNeuralNetwork> {
+        let synapse_count: usize = self.synapses.iter().map(|(_, list)| list.len()).sum();
+        if self.statistics.neuron_count != self.neurons.len()
+            || self.statistics.synapse_count != synapse_count
+        {
+            return Err(CoreError::SerializationError(
+                "Checkpoint statistics do not match its contents".to_string(),
+            ));
+        }
+
+        Ok(SpikingNeuralNetwork {
+            neurons: RwLock::new(self.neurons.into_iter().map(|n| (n.id(), n)).collect()),
+            synapses: RwLock::new(self.synapses.into_iter().collect()),
+            stdp_rule: self.stdp_rule,
+            learning_enabled: self.learning_enabled,
+            current_time: RwLock::new(self.current_time),
+            total_spikes: RwLock::new(self.total_spikes),
+        })
+    }
+}
+
 /// Network statistics summary.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkStatistics {
@@ -1,6 +1,8 @@
 //! Incremental Backup System for `NeuroQuantumDB`
 //!
-//! Provides efficient incremental backups by only backing up changes since last backup
+//! Provides efficient incremental backups by only backing up changes since last backup.
+//! Modified pages are selected from the pager's modification epochs, or from
+//! the WAL for parent backups taken before epochs were recorded.
 
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
@@ -41,13 +43,43 @@ impl IncrementalBackup {
         since_lsn: u64,
         metadata: &BackupMetadata,
     ) -> Result<BackupStats> {
-        let start = std::time::Instant::now();
-        let mut stats = BackupStats::default();
-
         info!("Starting incremental backup since LSN {}", since_lsn);
 
         // Get modified pages from WAL
-        let modified_pages = self.get_modified_pages_since_lsn(since_lsn).await?;
+        let mut modified_pages: Vec<PageId> = self
+            .get_modified_pages_since_lsn(since_lsn)
+            .await?
+            .into_iter()
+            .collect();
+        modified_pages.sort_unstable_by_key(|page_id| page_id.0);
+        self.backup_pages(modified_pages, since_lsn, metadata).await
+    }
+
+    /// Backup all pages written after the pager closed `epoch`
+    ///
+    /// WAL segments are still backed up from `since_lsn`.
+    pub async fn backup_since_epoch(
+        &self,
+        epoch: u64,
+        since_lsn: u64,
+        metadata: &BackupMetadata,
+    ) -> Result<BackupStats> {
+        info!("Starting incremental backup since page epoch {}", epoch);
+
+        let modified_pages = self.pager.read().await.pages_modified_since(epoch).await;
+        self.backup_pages(modified_pages, since_lsn, metadata).await
+    }
+
+    /// Backup `modified_pages` and the WAL segments since `since_lsn`
+    async fn backup_pages(
+        &self,
+        modified_pages: Vec<PageId>,
+        since_lsn: u64,
+        metadata: &BackupMetadata,
+    ) -> Result<BackupStats> {
+        let start = std::time::Instant::now();
+        let mut stats = BackupStats::default();
+
         info!("Found {} modified pages", modified_pages.len());
 
         // Create backup directory
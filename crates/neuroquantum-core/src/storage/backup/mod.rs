@@ -4,7 +4,7 @@
 //! - Hot backups (no downtime required)
 //! - Point-in-Time Recovery (PITR)
 //! - Incremental backups
-//! - Cloud storage integration (S3)
+//! - Cloud storage integration (S3, Azure Blob Storage)
 //! - Backup verification and validation
 //! - Compression and encryption
 
@@ -23,10 +23,13 @@ pub mod restore;
 pub mod storage_backend;
 
 pub use incremental::{IncrementalBackup, IncrementalBackupManager};
-pub use restore::{RestoreManager, RestoreOptions, RestoreStats};
-pub use storage_backend::{BackupStorageBackend, LocalBackend, S3Backend};
+pub use restore::{RecoveryTarget, RestoreManager, RestoreOptions, RestoreStats};
+pub use storage_backend::{
+    AzureBackend, AzureBlobClient, AzureRestClient, BackupStorageBackend, LocalBackend, S3Backend,
+};
 
 use super::pager::PageStorageManager;
+use super::wal::log_writer::SEGMENT_EXTENSION;
 use super::wal::WALManager;
 
 /// Unique identifier for backup operations
@@ -73,6 +76,12 @@ pub struct BackupMetadata {
     pub start_lsn: u64,
     /// LSN at backup end
     pub end_lsn: Option<u64>,
+    /// Page modification epoch closed when the backup started
+    ///
+    /// Incremental backups copy the pages written in later epochs. `None`
+    /// for backups taken before epochs were recorded.
+    #[serde(default)]
+    pub page_epoch: Option<u64>,
     /// Total size in bytes
     pub size_bytes: u64,
     /// Compressed size in bytes
@@ -114,6 +123,8 @@ pub struct BackupConfig {
     pub storage_backend: BackupStorageType,
     /// S3 configuration (if using S3)
     pub s3_config: Option<S3Config>,
+    /// Azure Blob Storage configuration (if using Azure)
+    pub azure_config: Option<AzureConfig>,
 }
 
 impl Default for BackupConfig {
@@ -129,6 +140,7 @@ impl Default for BackupConfig {
             include_wal: true,
             storage_backend: BackupStorageType::Local,
             s3_config: None,
+            azure_config: None,
         }
     }
 }
@@ -140,6 +152,8 @@ pub enum BackupStorageType {
     Local,
     /// Amazon S3
     S3,
+    /// Azure Blob Storage
+    Azure,
 }
 
 /// S3 configuration
@@ -152,6 +166,23 @@ pub struct S3Config {
     pub endpoint: Option<String>,
 }
 
+/// Azure Blob Storage configuration
+///
+/// Either `access_key` (Shared Key authorization) or `sas_token` must be set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AzureConfig {
+    /// Storage account name
+    pub account: String,
+    /// Container holding the backups
+    pub container: String,
+    /// Base64-encoded storage account access key
+    pub access_key: Option<String>,
+    /// Shared access signature token (query string, with or without leading `?`)
+    pub sas_token: Option<String>,
+    /// Custom blob endpoint (e.g. Azurite); defaults to `https://{account}.blob.core.windows.net`
+    pub endpoint: Option<String>,
+}
+
 /// Backup statistics
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct BackupStats {
@@ -206,6 +237,13 @@ impl BackupManager {
                     .ok_or_else(|| anyhow!("S3 configuration required"))?;
                 Arc::new(S3Backend::new(s3_config.clone()).await?)
             },
+            | BackupStorageType::Azure => {
+                let azure_config = config
+                    .azure_config
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("Azure configuration required"))?;
+                Arc::new(AzureBackend::new(azure_config.clone()).await?)
+            },
         };
 
         Ok(Self {
@@ -233,6 +271,7 @@ impl BackupManager {
         let backup_id = Uuid::new_v4();
         let start_time = Utc::now();
         let start_lsn = self.wal_manager.read().await.current_lsn();
+        let page_epoch = self.pager.read().await.advance_epoch().await?;
 
         let mut metadata = BackupMetadata {
             backup_id,
@@ -242,6 +281,7 @@ impl BackupManager {
             end_time: None,
             start_lsn,
             end_lsn: None,
+            page_epoch: Some(page_epoch),
             size_bytes: 0,
             compressed_size_bytes: 0,
             file_count: 0,
@@ -373,9 +413,15 @@ impl BackupManager {
             self.storage_backend.clone(),
         );
 
-        incremental_mgr
-            .backup_since_lsn(last_full_backup.end_lsn.unwrap_or(0), metadata)
-            .await
+        let since_lsn = last_full_backup.end_lsn.unwrap_or(0);
+        match last_full_backup.page_epoch {
+            | Some(epoch) => {
+                incremental_mgr
+                    .backup_since_epoch(epoch, since_lsn, metadata)
+                    .await
+            },
+            | None => incremental_mgr.backup_since_lsn(since_lsn, metadata).await,
+        }
     }
 
     /// Perform a differential backup
@@ -469,7 +515,7 @@ impl BackupManager {
 
         while let Some(entry) = wal_files.next_entry().await? {
             let path = entry.path();
-            if path.extension().and_then(|s| s.to_str()) == Some("wal") {
+            if path.extension().and_then(|s| s.to_str()) == Some(SEGMENT_EXTENSION) {
                 let filename = path
                     .file_name()
                     .ok_or_else(|| anyhow!("WAL file path has no filename: {}", path.display()))?;
@@ -562,7 +608,7 @@ impl BackupManager {
             entries.sort();
 
             for file_path in entries {
-                if file_path.extension().and_then(|s| s.to_str()) == Some("wal") {
+                if file_path.extension().and_then(|s| s.to_str()) == Some(SEGMENT_EXTENSION) {
                     let file_data = self.storage_backend.read_file(&file_path).await?;
                     hasher.update(&file_data);
                 }
@@ -676,6 +722,7 @@ mod unit_tests {
             end_time: None,
             start_lsn: 0,
             end_lsn: None,
+            page_epoch: None,
             size_bytes: 0,
             compressed_size_bytes: 0,
             file_count: 0,
@@ -6,6 +6,8 @@
 //! - Incremental restore
 //! - Verification and validation
 
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -15,16 +17,40 @@ use serde::{Deserialize, Serialize};
 use tracing::{debug, info, warn};
 
 use super::{BackupId, BackupMetadata, BackupStorageBackend, BackupType};
+use crate::storage::pager::{Page, PageId, PageType, PAGE_SIZE};
+use crate::storage::wal::log_writer::{decode_segment, SEGMENT_EXTENSION};
+use crate::storage::wal::{TransactionId, WALRecord, WALRecordType, LSN};
+
+/// Point a point-in-time recovery stops at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryTarget {
+    /// Replay records written at or before this time
+    Timestamp(DateTime<Utc>),
+    /// Replay records up to and including this LSN
+    Lsn(LSN),
+}
+
+impl RecoveryTarget {
+    /// Check if `record` lies at or before the target
+    fn includes(&self, record: &WALRecord) -> bool {
+        match self {
+            | Self::Timestamp(time) => record.timestamp <= *time,
+            | Self::Lsn(lsn) => record.lsn <= *lsn,
+        }
+    }
+}
 
 /// Restore options
 #[derive(Debug, Clone)]
 pub struct RestoreOptions {
     /// Backup ID to restore from
     pub backup_id: BackupId,
-    /// Point-in-time to restore to (if PITR)
-    pub target_time: Option<DateTime<Utc>>,
-    /// Target LSN to restore to (if PITR)
-    pub target_lsn: Option<u64>,
+    /// Point to recover to by replaying WAL past the backup (PITR)
+    pub recover_to: Option<RecoveryTarget>,
+    /// Directory with WAL segments written after the backup, replayed along
+    /// with the backup's own WAL for point-in-time recovery
+    pub wal_archive: Option<PathBuf>,
     /// Output directory for restored database
     pub output_path: PathBuf,
     /// Verify backup integrity before restore
@@ -39,8 +65,8 @@ impl Default for RestoreOptions {
     fn default() -> Self {
         Self {
             backup_id: uuid::Uuid::nil(),
-            target_time: None,
-            target_lsn: None,
+            recover_to: None,
+            wal_archive: None,
             output_path: PathBuf::from("./restored_db"),
             verify_before_restore: true,
             verify_after_restore: true,
@@ -131,9 +157,9 @@ impl RestoreManager {
         }
 
         // Step 5: Apply WAL for PITR if requested
-        if self.options.target_time.is_some() || self.options.target_lsn.is_some() {
-            info!("Applying WAL for point-in-time recovery");
-            self.apply_wal_for_pitr(&metadata, &mut stats).await?;
+        if let Some(target) = self.options.recover_to {
+            self.apply_wal_for_pitr(&metadata, target, &mut stats)
+                .await?;
         }
 
         // Step 6: Verify restored database if requested
@@ -249,6 +275,7 @@ impl RestoreManager {
             parent_options.backup_id = parent_id;
             parent_options.verify_before_restore = false;
             parent_options.verify_after_restore = false;
+            parent_options.recover_to = None;
 
             let parent_manager = Self::new(self.storage_backend.clone(), parent_options);
 
@@ -342,7 +369,7 @@ impl RestoreManager {
         entries.sort();
 
         for wal_file in entries {
-            if wal_file.extension().and_then(|s| s.to_str()) == Some("wal") {
+            if wal_file.extension().and_then(|s| s.to_str()) == Some(SEGMENT_EXTENSION) {
                 debug!("Restoring WAL: {}", wal_file.display());
 
                 let wal_data = self.storage_backend.read_file(&wal_file).await?;
@@ -369,70 +396,138 @@ impl RestoreManager {
         Ok(())
     }
 
-    /// Apply WAL records for point-in-time recovery
+    /// Replay WAL on the restored pages up to `target` (point-in-time recovery)
+    ///
+    /// History is repeated from the start of the backup up to the target,
+    /// including aborts and savepoint rollbacks, and transactions that had not
+    /// committed by then are rolled back. No record past the target is applied.
     async fn apply_wal_for_pitr(
         &self,
-        _metadata: &BackupMetadata,
+        metadata: &BackupMetadata,
+        target: RecoveryTarget,
         stats: &mut RestoreStats,
     ) -> Result<()> {
-        info!("Applying WAL for point-in-time recovery");
-
-        let target_lsn = if let Some(lsn) = self.options.target_lsn {
-            lsn
-        } else if let Some(target_time) = self.options.target_time {
-            // Find LSN corresponding to target time
-            self.find_lsn_for_time(target_time).await?
-        } else {
-            return Ok(());
-        };
-
-        info!("Target LSN for PITR: {}", target_lsn);
+        let replay_from = metadata.start_lsn;
+        match target {
+            | RecoveryTarget::Lsn(lsn) if lsn < replay_from => {
+                return Err(anyhow!(
+                    "Recovery target LSN {lsn} precedes the backup (LSN {replay_from})"
+                ));
+            },
+            | RecoveryTarget::Timestamp(time) if time < metadata.start_time => {
+                return Err(anyhow!(
+                    "Recovery target {time} precedes the backup ({})",
+                    metadata.start_time
+                ));
+            },
+            | _ => {},
+        }
+        info!("Applying WAL for point-in-time recovery to {:?}", target);
 
-        // Read and replay WAL records up to target LSN
-        let wal_dir = self.options.output_path.join("wal");
-        if !wal_dir.exists() {
-            warn!("No WAL files found for PITR");
-            return Ok(());
+        let records = self.load_wal_records().await?;
+        if records.last().is_none_or(|record| target.includes(record)) {
+            warn!("WAL ends before the recovery target, replaying all of it");
         }
 
-        let mut entries = tokio::fs::read_dir(&wal_dir).await?;
-        let mut wal_files = Vec::new();
+        let writes = replay_writes(
+            records.iter().take_while(|record| target.includes(record)),
+            replay_from,
+        );
 
-        while let Some(entry) = entries.next_entry().await? {
-            let path = entry.path();
-            if path.extension().and_then(|s| s.to_str()) == Some("wal") {
-                wal_files.push(path);
-            }
+        let mut pages: HashMap<PageId, Page> = HashMap::new();
+        for write in writes {
+            let page = match pages.entry(write.page_id) {
+                | Entry::Occupied(entry) => entry.into_mut(),
+                | Entry::Vacant(entry) => {
+                    entry.insert(self.load_restored_page(write.page_id).await?)
+                },
+            };
+            page.write_data(write.offset, write.data)?;
+            page.set_lsn(page.header().lsn.max(write.lsn));
+            stats.wal_records_applied += 1;
         }
 
-        wal_files.sort();
+        let data_dir = self.options.output_path.join("data");
+        tokio::fs::create_dir_all(&data_dir).await?;
+        for page in pages.values_mut() {
+            page.update_checksum();
+            let page_bytes = page.serialize()?;
+            tokio::fs::write(data_dir.join(page_file_name(page.id())), &page_bytes).await?;
+            stats.bytes_written += page_bytes.len() as u64;
+        }
 
-        for wal_file in wal_files {
-            debug!("Replaying WAL: {}", wal_file.display());
+        info!(
+            "Applied {} WAL writes to {} pages",
+            stats.wal_records_applied,
+            pages.len()
+        );
 
-            let _wal_data = tokio::fs::read(&wal_file).await?;
+        Ok(())
+    }
 
-            // Parse WAL records (simplified - actual implementation would be more complex)
-            // For now, just count records as applied
-            stats.wal_records_applied += 1;
+    /// WAL records of the restored backup and the WAL archive, in LSN order
+    async fn load_wal_records(&self) -> Result<Vec<WALRecord>> {
+        let mut wal_dirs = vec![self.options.output_path.join("wal")];
+        wal_dirs.extend(self.options.wal_archive.clone());
 
-            // Stop if we've reached target LSN
-            // In real implementation, we'd parse records and check LSN
-            if stats.wal_records_applied > 100 {
-                break;
+        let mut records = BTreeMap::new();
+        for wal_dir in wal_dirs {
+            if !wal_dir.exists() {
+                continue;
+            }
+            let mut entries = tokio::fs::read_dir(&wal_dir).await?;
+            while let Some(entry) = entries.next_entry().await? {
+                let path = entry.path();
+                if path.extension().and_then(|s| s.to_str()) == Some(SEGMENT_EXTENSION) {
+                    debug!("Reading WAL: {}", path.display());
+                    let wal_data = tokio::fs::read(&path).await?;
+                    for record in decode_segment(&wal_data) {
+                        records.insert(record.lsn, record);
+                    }
+                }
             }
         }
 
-        info!("Applied {} WAL records", stats.wal_records_applied);
-
-        Ok(())
+        Ok(records.into_values().collect())
     }
 
-    /// Find LSN for a given timestamp
-    async fn find_lsn_for_time(&self, _target_time: DateTime<Utc>) -> Result<u64> {
-        // Simplified implementation
-        // In real system, would scan WAL files and find LSN closest to target time
-        Ok(0)
+    /// Read a page from the restored data files
+    ///
+    /// Single-page files written by incremental backups take precedence over
+    /// the page chunks of the full backup. Pages allocated after the backup
+    /// start out empty.
+    async fn load_restored_page(&self, page_id: PageId) -> Result<Page> {
+        let data_dir = self.options.output_path.join("data");
+        let page_file = data_dir.join(page_file_name(page_id));
+        if page_file.exists() {
+            return Page::from_bytes(&tokio::fs::read(&page_file).await?);
+        }
+
+        let mut entries = tokio::fs::read_dir(&data_dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            let Some((start, end)) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(parse_chunk_range)
+            else {
+                continue;
+            };
+            if !(start..end).contains(&page_id.0) {
+                continue;
+            }
+
+            let chunk_data = tokio::fs::read(&path).await?;
+            let page = chunk_data
+                .chunks_exact(PAGE_SIZE)
+                .filter_map(|page_bytes| Page::from_bytes(page_bytes).ok())
+                .find(|page| page.id() == page_id);
+            if let Some(page) = page {
+                return Ok(page);
+            }
+        }
+
+        Ok(Page::new(page_id, PageType::Data))
     }
 
     /// Verify restored database integrity
@@ -493,7 +588,7 @@ impl RestoreManager {
             entries.sort();
 
             for file_path in entries {
-                if file_path.extension().and_then(|s| s.to_str()) == Some("wal") {
+                if file_path.extension().and_then(|s| s.to_str()) == Some(SEGMENT_EXTENSION) {
                     let file_data = self.storage_backend.read_file(&file_path).await?;
                     hasher.update(&file_data);
                 }
@@ -508,9 +603,10 @@ impl RestoreManager {
     fn decompress_data(&self, data: &[u8]) -> Result<Vec<u8>> {
         use std::io::Read;
 
-        use flate2::read::GzDecoder;
+        use flate2::read::MultiGzDecoder;
 
-        let mut decoder = GzDecoder::new(data);
+        // Compressed page chunks hold one gzip member per page
+        let mut decoder = MultiGzDecoder::new(data);
         let mut decompressed = Vec::new();
         decoder.read_to_end(&mut decompressed)?;
         Ok(decompressed)
@@ -523,6 +619,116 @@ impl RestoreManager {
     }
 }
 
+/// A byte range written to a page while replaying WAL
+struct PageWrite<'a> {
+    /// LSN of the record causing the write
+    lsn: LSN,
+    page_id: PageId,
+    offset: usize,
+    data: &'a [u8],
+}
+
+/// Writes bringing the backed up pages to the state at the end of `records`
+///
+/// `records` are in LSN order. Records before `replay_from` are already
+/// reflected in the backed up pages and are only used to roll back
+/// transactions that never committed.
+fn replay_writes<'a>(
+    records: impl IntoIterator<Item = &'a WALRecord>,
+    replay_from: LSN,
+) -> Vec<PageWrite<'a>> {
+    let mut open_updates: HashMap<TransactionId, Vec<&'a WALRecord>> = HashMap::new();
+    let mut writes = Vec::new();
+    let mut last_lsn = replay_from;
+
+    for record in records {
+        last_lsn = record.lsn;
+        let replayed = record.lsn >= replay_from;
+        match &record.record_type {
+            | WALRecordType::Update {
+                tx_id,
+                page_id,
+                offset,
+                after_image,
+                ..
+            } => {
+                if replayed {
+                    writes.push(PageWrite {
+                        lsn: record.lsn,
+                        page_id: *page_id,
+                        offset: *offset,
+                        data: after_image,
+                    });
+                }
+                open_updates.entry(*tx_id).or_default().push(record);
+            },
+            | WALRecordType::RollbackToSavepoint {
+                tx_id, target_lsn, ..
+            } => {
+                if let Some(updates) = open_updates.get_mut(tx_id) {
+                    let kept = updates.partition_point(|update| update.lsn <= *target_lsn);
+                    let rolled_back = updates.split_off(kept);
+                    if replayed {
+                        undo_writes(&rolled_back, record.lsn, &mut writes);
+                    }
+                }
+            },
+            | WALRecordType::Abort { tx_id } => {
+                if let Some(updates) = open_updates.remove(tx_id) {
+                    if replayed {
+                        undo_writes(&updates, record.lsn, &mut writes);
+                    }
+                }
+            },
+            | WALRecordType::Commit { tx_id } => {
+                open_updates.remove(tx_id);
+            },
+            | _ => {},
+        }
+    }
+
+    // Roll back transactions still open at the recovery target
+    let mut uncommitted: Vec<&WALRecord> = open_updates.into_values().flatten().collect();
+    uncommitted.sort_unstable_by_key(|update| update.lsn);
+    undo_writes(&uncommitted, last_lsn, &mut writes);
+
+    writes
+}
+
+/// Restore the before images of `updates`, newest first
+fn undo_writes<'a>(updates: &[&'a WALRecord], lsn: LSN, writes: &mut Vec<PageWrite<'a>>) {
+    for update in updates.iter().rev() {
+        if let WALRecordType::Update {
+            page_id,
+            offset,
+            before_image,
+            ..
+        } = &update.record_type
+        {
+            writes.push(PageWrite {
+                lsn,
+                page_id: *page_id,
+                offset: *offset,
+                data: before_image,
+            });
+        }
+    }
+}
+
+/// File holding a single page, as written by incremental backups
+fn page_file_name(page_id: PageId) -> String {
+    format!("page_{:016x}.dat", page_id.0)
+}
+
+/// Page range of a full backup chunk from its file stem
+fn parse_chunk_range(stem: &str) -> Option<(u64, u64)> {
+    let (start, end) = stem.strip_prefix("pages_")?.split_once('_')?;
+    Some((
+        u64::from_str_radix(start, 16).ok()?,
+        u64::from_str_radix(end, 16).ok()?,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,6 +2,7 @@
 
 #![allow(clippy::significant_drop_tightening)]
 
+use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::Result;
@@ -10,9 +11,9 @@ use tokio::sync::RwLock;
 
 use crate::storage::pager::{Page, PageId, PageType};
 use crate::storage::{
-    BackupConfig, BackupManager, BackupStorageBackend, BackupStorageType, BackupType, LocalBackend,
-    PageStorageManager, PagerConfig, RestoreManager, RestoreOptions, SyncMode, WALConfig,
-    WALManager,
+    BackupConfig, BackupManager, BackupStorageBackend, BackupStorageType, BackupType, Durability,
+    LocalBackend, PageStorageManager, PagerConfig, RecoveryTarget, RestoreManager, RestoreOptions,
+    SyncMode, WALConfig, WALManager,
 };
 
 /// Helper to create test database
@@ -33,6 +34,7 @@ async fn setup_test_db() -> Result<(
         enable_checksums: true,
         sync_mode: SyncMode::None,
         direct_io: false,
+        ..Default::default()
     };
 
     let db_file = db_path.join("test.db");
@@ -44,9 +46,12 @@ async fn setup_test_db() -> Result<(
         wal_dir: wal_path,
         segment_size: 1024 * 1024,
         sync_on_write: false,
+        commit_durability: Durability::Durable,
         buffer_size: 64 * 1024,
         checkpoint_interval_secs: 60,
+        max_wal_bytes_before_checkpoint: 0,
         min_segments_to_keep: 2,
+        redo_workers: 1,
         group_commit_delay_ms: 0,
         group_commit_max_records: 1000,
         group_commit_max_bytes: 4 * 1024 * 1024,
@@ -62,6 +67,14 @@ async fn setup_test_db() -> Result<(
     Ok((temp_dir, pager, wal_manager))
 }
 
+/// Read a page written to a restore's data directory by WAL replay
+async fn read_restored_page(restore_path: &Path, page_id: PageId) -> Result<Page> {
+    let path = restore_path
+        .join("data")
+        .join(format!("page_{:016x}.dat", page_id.0));
+    Page::from_bytes(&tokio::fs::read(path).await?)
+}
+
 #[tokio::test]
 async fn test_full_backup_creation() -> Result<()> {
     let (temp_dir, pager, wal_manager) = setup_test_db().await?;
@@ -92,6 +105,7 @@ async fn test_full_backup_creation() -> Result<()> {
         include_wal: true,
         storage_backend: BackupStorageType::Local,
         s3_config: None,
+        azure_config: None,
     };
 
     let backup_manager = BackupManager::new(pager, wal_manager, config).await?;
@@ -135,6 +149,7 @@ async fn test_backup_and_restore() -> Result<()> {
         include_wal: false,
         storage_backend: BackupStorageType::Local,
         s3_config: None,
+        azure_config: None,
     };
 
     let backup_manager = BackupManager::new(pager, wal_manager, config).await?;
@@ -146,8 +161,8 @@ async fn test_backup_and_restore() -> Result<()> {
 
     let restore_options = RestoreOptions {
         backup_id: backup_metadata.backup_id,
-        target_time: None,
-        target_lsn: None,
+        recover_to: None,
+        wal_archive: None,
         output_path: restore_path.clone(),
         verify_before_restore: true,
         verify_after_restore: true,
@@ -225,6 +240,160 @@ async fn test_incremental_backup() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_incremental_backup_copies_only_modified_pages() -> Result<()> {
+    let (temp_dir, pager, wal_manager) = setup_test_db().await?;
+    let db_file = temp_dir.path().join("db").join("test.db");
+
+    let mut page_ids = Vec::new();
+    {
+        let pager = pager.read().await;
+        for _ in 0..100 {
+            page_ids.push(pager.allocate_page(PageType::Data).await?);
+        }
+        pager.sync().await?;
+    }
+
+    let backup_path = temp_dir.path().join("backups");
+    let full_config = BackupConfig {
+        output_path: backup_path.clone(),
+        backup_type: BackupType::Full,
+        enable_compression: false,
+        ..Default::default()
+    };
+    let full_manager =
+        BackupManager::new(Arc::clone(&pager), Arc::clone(&wal_manager), full_config).await?;
+    let full_backup = full_manager.backup().await?;
+    assert!(full_backup.page_epoch.is_some());
+
+    let modified = [page_ids[10], page_ids[50], page_ids[90]];
+    {
+        let pager = pager.read().await;
+        for &page_id in &modified {
+            let mut page = pager.read_page(page_id).await?;
+            page.write_data(0, b"Modified")?;
+            pager.write_page(&page).await?;
+        }
+        pager.sync().await?;
+    }
+    drop(full_manager);
+    drop(pager);
+
+    // The epochs survive reopening the database
+    let pager = Arc::new(RwLock::new(
+        PageStorageManager::new(&db_file, PagerConfig::default()).await?,
+    ));
+    {
+        let pager = pager.read().await;
+        let epoch = full_backup.page_epoch.unwrap();
+        assert!(pager.page_epoch(modified[0]).await.unwrap() > epoch);
+        assert!(pager.page_epoch(page_ids[0]).await.unwrap() <= epoch);
+    }
+
+    let incr_config = BackupConfig {
+        output_path: backup_path.clone(),
+        backup_type: BackupType::Incremental,
+        enable_compression: false,
+        ..Default::default()
+    };
+    let incr_manager = BackupManager::new(pager, wal_manager, incr_config).await?;
+    let incr_backup = incr_manager.backup().await?;
+    assert_eq!(incr_backup.parent_backup_id, Some(full_backup.backup_id));
+
+    let data_dir = backup_path
+        .join(format!("backup_{}", incr_backup.backup_id))
+        .join("data");
+    let mut page_files = Vec::new();
+    let mut entries = tokio::fs::read_dir(&data_dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        page_files.push(entry.file_name().to_string_lossy().into_owned());
+    }
+    page_files.sort();
+    let expected: Vec<String> = modified
+        .iter()
+        .map(|page_id| format!("page_{:016x}.dat", page_id.0))
+        .collect();
+    assert_eq!(page_files, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_point_in_time_restore() -> Result<()> {
+    let (temp_dir, pager, wal_manager) = setup_test_db().await?;
+
+    {
+        let pager = pager.write().await;
+        for i in 1..=3 {
+            let mut page = Page::new(PageId(i), PageType::Data);
+            page.update_checksum();
+            pager.write_page(&page).await?;
+        }
+        pager.sync().await?;
+    }
+
+    let backup_path = temp_dir.path().join("backups");
+    let config = BackupConfig {
+        output_path: backup_path.clone(),
+        backup_type: BackupType::Full,
+        enable_compression: true,
+        include_wal: true,
+        ..Default::default()
+    };
+    let manager = BackupManager::new(pager, Arc::clone(&wal_manager), config).await?;
+    let backup = manager.backup().await?;
+
+    // Four transactions commit one after another on page 1, and one more
+    // starts before the second commit but only commits after the target
+    let mut commit_times = Vec::new();
+    {
+        let wal = wal_manager.read().await;
+        let mut late_tx = None;
+        for i in 0..4 {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            let tx_id = wal.begin_transaction().await?;
+            let after_image = format!("commit-{i}").into_bytes();
+            wal.log_update(tx_id, PageId(1), i * 16, vec![0; 8], after_image)
+                .await?;
+            if i == 1 {
+                let late = wal.begin_transaction().await?;
+                wal.log_update(late, PageId(2), 0, vec![0; 4], b"late".to_vec())
+                    .await?;
+                late_tx = Some(late);
+            }
+            wal.commit_transaction(tx_id).await?;
+            commit_times.push(chrono::Utc::now());
+        }
+        wal.commit_transaction(late_tx.unwrap()).await?;
+    }
+
+    let restore_path = temp_dir.path().join("restored");
+    let storage_backend = Arc::new(LocalBackend::new(backup_path).await?);
+    let options = RestoreOptions {
+        backup_id: backup.backup_id,
+        recover_to: Some(RecoveryTarget::Timestamp(commit_times[1])),
+        wal_archive: Some(temp_dir.path().join("wal")),
+        output_path: restore_path.clone(),
+        ..Default::default()
+    };
+    let stats = RestoreManager::new(storage_backend, options)
+        .restore()
+        .await?;
+    assert_eq!(stats.wal_records_applied, 4);
+
+    let page = read_restored_page(&restore_path, PageId(1)).await?;
+    assert_eq!(page.read_data(0, 8)?, b"commit-0");
+    assert_eq!(page.read_data(16, 8)?, b"commit-1");
+    assert_eq!(page.read_data(32, 8)?, &[0; 8]);
+    assert_eq!(page.read_data(48, 8)?, &[0; 8]);
+
+    // The transaction still open at the target is rolled back
+    let page = read_restored_page(&restore_path, PageId(2)).await?;
+    assert_eq!(page.read_data(0, 4)?, &[0; 4]);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_backup_list() -> Result<()> {
     let (temp_dir, pager, wal_manager) = setup_test_db().await?;
@@ -405,8 +574,8 @@ async fn test_restore_verification() -> Result<()> {
 
     let options = RestoreOptions {
         backup_id: backup.backup_id,
-        target_time: None,
-        target_lsn: None,
+        recover_to: None,
+        wal_archive: None,
         output_path: restore_path,
         verify_before_restore: true,
         verify_after_restore: true,
@@ -0,0 +1,265 @@
+//! Order-preserving composite keys for multi-column indexes
+//!
+//! A [`CompositeKey`] concatenates several column values into a single B+ Tree
+//! [`Key`] so that comparing the encoded bytes compares the values column by
+//! column. Every part starts with a type tag followed by a payload whose byte
+//! order matches the value order:
+//!
+//! - integers and timestamps are big-endian with the sign bit flipped
+//! - floats use the IEEE 754 bit pattern, inverted for negative numbers
+//! - text and binary are escaped (`0x00` becomes `0x00 0xFF`) and terminated
+//!   by `0x00 0x01`
+//!
+//! Variable-length parts are terminated rather than length-prefixed: a length
+//! prefix would sort `"b"` before `"ab"`, while the terminator sorts a value
+//! before every longer value it is a prefix of. Because each part is
+//! self-delimiting, the encoding of the leading columns is a byte prefix of
+//! every full key, which is what [`CompositeKey::prefix_upper_bound`] and
+//! [`BTree::prefix_scan`](super::BTree::prefix_scan) rely on.
+//!
+//! Values of different types order by type: NULL first, then booleans,
+//! integers, floats, text, timestamps and binary.
+//!
+//! [`CompositeKey::decode`] recovers the values, which lets covering indexes
+//! answer queries without reading table rows. Timestamps are encoded with
+//! microsecond precision and `-0.0` is stored as `0.0`.
+
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+
+use super::Key;
+use crate::storage::types::Value as FieldValue;
+
+const TAG_NULL: u8 = 0x00;
+const TAG_BOOLEAN: u8 = 0x10;
+const TAG_INTEGER: u8 = 0x20;
+const TAG_FLOAT: u8 = 0x21;
+const TAG_TEXT: u8 = 0x30;
+const TAG_TIMESTAMP: u8 = 0x40;
+const TAG_BINARY: u8 = 0x50;
+
+const ESCAPE: u8 = 0x00;
+const ESCAPED_ZERO: u8 = 0xFF;
+const TERMINATOR: u8 = 0x01;
+
+/// Multi-column B+ Tree key built from column values in index order
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CompositeKey {
+    bytes: Key,
+    parts: usize,
+}
+
+impl CompositeKey {
+    /// Create an empty key
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Encode `values` as consecutive key parts
+    #[must_use]
+    pub fn from_values<'a>(values: impl IntoIterator<Item = &'a FieldValue>) -> Self {
+        let mut key = Self::new();
+        for value in values {
+            key.push(value);
+        }
+        key
+    }
+
+    /// Append the next column value
+    pub fn push(&mut self, value: &FieldValue) -> &mut Self {
+        match value {
+            | FieldValue::Null => self.bytes.push(TAG_NULL),
+            | FieldValue::Boolean(b) => {
+                self.bytes.push(TAG_BOOLEAN);
+                self.bytes.push(u8::from(*b));
+            },
+            | FieldValue::Integer(i) => {
+                self.bytes.push(TAG_INTEGER);
+                self.push_signed(*i);
+            },
+            | FieldValue::Float(f) => {
+                self.bytes.push(TAG_FLOAT);
+                // Adding zero turns -0.0 into 0.0 so both encode alike
+                let bits = (f + 0.0).to_bits();
+                let ordered = if bits >> 63 == 1 {
+                    !bits
+                } else {
+                    bits | (1 << 63)
+                };
+                self.bytes.extend_from_slice(&ordered.to_be_bytes());
+            },
+            | FieldValue::Text(s) => {
+                self.bytes.push(TAG_TEXT);
+                self.push_escaped(s.as_bytes());
+            },
+            | FieldValue::Timestamp(ts) => {
+                self.bytes.push(TAG_TIMESTAMP);
+                self.push_signed(ts.timestamp_micros());
+            },
+            | FieldValue::Binary(b) => {
+                self.bytes.push(TAG_BINARY);
+                self.push_escaped(b);
+            },
+        }
+        self.parts += 1;
+        self
+    }
+
+    /// Append a row ID, making keys of a non-unique index distinct
+    ///
+    /// The row ID is not counted as a part and sorts entries with equal
+    /// column values by row ID.
+    pub fn push_row_id(&mut self, row_id: u64) -> &mut Self {
+        self.bytes.extend_from_slice(&row_id.to_be_bytes());
+        self
+    }
+
+    /// Number of column values in the key
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.parts
+    }
+
+    /// Check if the key has no column values
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.parts == 0
+    }
+
+    /// Encoded key bytes
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Consume the key, returning the encoded B+ Tree key
+    #[must_use]
+    pub fn into_key(self) -> Key {
+        self.bytes
+    }
+
+    /// Smallest key greater than every key starting with `prefix`
+    ///
+    /// Returns `None` when no such key exists (an empty prefix or one made of
+    /// `0xFF` bytes only), i.e. the prefix range is unbounded above.
+    #[must_use]
+    pub fn prefix_upper_bound(prefix: &[u8]) -> Option<Key> {
+        let end = prefix.iter().rposition(|&byte| byte != u8::MAX)?;
+        let mut bound = prefix[..=end].to_vec();
+        bound[end] += 1;
+        Some(bound)
+    }
+
+    /// Key prefix shared by every key whose first part is text starting with
+    /// `prefix`
+    ///
+    /// Unlike a pushed text part this has no terminator, so scanning it with
+    /// [`prefix_upper_bound`](Self::prefix_upper_bound) finds longer texts too.
+    #[must_use]
+    pub fn text_prefix(prefix: &str) -> Key {
+        let mut key = Self::new();
+        key.bytes.push(TAG_TEXT);
+        key.push_escaped(prefix.as_bytes());
+        key.bytes.truncate(key.bytes.len() - 2);
+        key.into_key()
+    }
+
+    /// Decode the first `parts` column values of an encoded key
+    ///
+    /// Bytes after the last part, such as a row ID suffix, are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key has fewer parts or is malformed.
+    pub fn decode(key: &[u8], parts: usize) -> Result<Vec<FieldValue>> {
+        let mut rest = key;
+        let mut values = Vec::with_capacity(parts);
+        while values.len() < parts {
+            let (&tag, tail) = rest
+                .split_first()
+                .ok_or_else(|| anyhow!("Composite key ends before part {}", values.len()))?;
+            rest = tail;
+            let value = match tag {
+                | TAG_NULL => FieldValue::Null,
+                | TAG_BOOLEAN => FieldValue::Boolean(take::<1>(&mut rest)?[0] != 0),
+                | TAG_INTEGER => FieldValue::Integer(take_signed(&mut rest)?),
+                | TAG_FLOAT => {
+                    let ordered = u64::from_be_bytes(take(&mut rest)?);
+                    let bits = if ordered >> 63 == 1 {
+                        ordered & !(1 << 63)
+                    } else {
+                        !ordered
+                    };
+                    FieldValue::Float(f64::from_bits(bits))
+                },
+                | TAG_TEXT => {
+                    let text = String::from_utf8(take_escaped(&mut rest)?)
+                        .map_err(|e| anyhow!("Composite key text is not UTF-8: {e}"))?;
+                    FieldValue::Text(Arc::new(text))
+                },
+                | TAG_TIMESTAMP => {
+                    let micros = take_signed(&mut rest)?;
+                    let timestamp = chrono::DateTime::from_timestamp_micros(micros)
+                        .ok_or_else(|| anyhow!("Composite key timestamp out of range"))?;
+                    FieldValue::Timestamp(timestamp)
+                },
+                | TAG_BINARY => FieldValue::Binary(Arc::new(take_escaped(&mut rest)?)),
+                | other => return Err(anyhow!("Unknown composite key tag {other:#04x}")),
+            };
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    fn push_signed(&mut self, value: i64) {
+        let ordered = (value as u64) ^ (1 << 63);
+        self.bytes.extend_from_slice(&ordered.to_be_bytes());
+    }
+
+    fn push_escaped(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.bytes.push(byte);
+            if byte == ESCAPE {
+                self.bytes.push(ESCAPED_ZERO);
+            }
+        }
+        self.bytes.extend_from_slice(&[ESCAPE, TERMINATOR]);
+    }
+}
+
+fn take<const N: usize>(rest: &mut &[u8]) -> Result<[u8; N]> {
+    if rest.len() < N {
+        return Err(anyhow!("Composite key is truncated"));
+    }
+    let (head, tail) = rest.split_at(N);
+    *rest = tail;
+    head.try_into()
+        .map_err(|_| anyhow!("Composite key is truncated"))
+}
+
+fn take_signed(rest: &mut &[u8]) -> Result<i64> {
+    let ordered = u64::from_be_bytes(take(rest)?);
+    Ok((ordered ^ (1 << 63)) as i64)
+}
+
+fn take_escaped(rest: &mut &[u8]) -> Result<Vec<u8>> {
+    let mut data = Vec::new();
+    loop {
+        match take::<1>(rest)?[0] {
+            | ESCAPE => match take::<1>(rest)?[0] {
+                | ESCAPED_ZERO => data.push(ESCAPE),
+                | TERMINATOR => return Ok(data),
+                | other => return Err(anyhow!("Invalid composite key escape {other:#04x}")),
+            },
+            | byte => data.push(byte),
+        }
+    }
+}
+
+impl From<CompositeKey> for Key {
+    fn from(key: CompositeKey) -> Self {
+        key.into_key()
+    }
+}
@@ -6,6 +6,7 @@
 //! - ACID-compliant operations
 //! - Concurrent access support
 //! - Optional prefix compression for memory efficiency (30-60% savings)
+//! - Offline structural integrity checks
 
 use std::fmt;
 use std::path::Path;
@@ -14,15 +15,19 @@ use anyhow::{anyhow, Result};
 use tokio::fs;
 use tracing::{debug, info};
 
+pub mod composite;
 pub mod compression;
 pub mod node;
 pub mod page;
+pub mod verify;
 
 #[cfg(test)]
 mod tests;
 
+pub use composite::CompositeKey;
 pub use node::{BTreeNode, CompressedInternalNode, CompressedLeafNode, InternalNode, LeafNode};
 pub use page::{PageId, PageManager, PageSerializer};
+pub use verify::{IntegrityReport, IntegrityViolation};
 
 /// Default B+ Tree order (max children per internal node)
 pub const DEFAULT_ORDER: usize = 128;
@@ -264,7 +269,25 @@ impl BTree {
         let root_page_id = self
             .root_page_id
             .ok_or_else(|| anyhow!("Tree has entries but no root page (invariant violation)"))?;
-        self.range_scan_recursive(root_page_id, start_key, end_key)
+        self.range_scan_recursive(root_page_id, start_key, &|key| key > end_key)
+            .await
+    }
+
+    /// Scan all entries whose key starts with `prefix`
+    ///
+    /// Used for lookups on the leading columns of a [`CompositeKey`].
+    ///
+    /// # Returns
+    /// * Vector of (key, value) pairs with the prefix, in key order
+    pub async fn prefix_scan(&self, prefix: &Key) -> Result<Vec<(Key, Value)>> {
+        if self.root_page_id.is_none() {
+            return Ok(Vec::new());
+        }
+
+        let root_page_id = self
+            .root_page_id
+            .ok_or_else(|| anyhow!("Tree has entries but no root page (invariant violation)"))?;
+        self.range_scan_recursive(root_page_id, prefix, &|key| !key.starts_with(prefix))
             .await
     }
 
@@ -483,7 +506,7 @@ impl BTree {
         &'a self,
         page_id: PageId,
         start_key: &'a Key,
-        end_key: &'a Key,
+        past_end: &'a (dyn Fn(&Key) -> bool + Sync),
     ) -> RangeScanFuture<'a> {
         Box::pin(async move {
             // Try to read as internal node first
@@ -491,7 +514,7 @@ impl BTree {
                 let child_index = internal_node.find_child_index(start_key);
                 let child_page_id = internal_node.children[child_index];
                 return self
-                    .range_scan_recursive(child_page_id, start_key, end_key)
+                    .range_scan_recursive(child_page_id, start_key, past_end)
                     .await;
             }
 
@@ -503,11 +526,13 @@ impl BTree {
                 let leaf_node = self.page_manager.read_leaf_node(page_id).await?;
 
                 for (key, value) in &leaf_node.entries {
-                    if key >= start_key && key <= end_key {
-                        results.push((key.clone(), *value));
-                    } else if key > end_key {
+                    if key < start_key {
+                        continue;
+                    }
+                    if past_end(key) {
                         return Ok(results);
                     }
+                    results.push((key.clone(), *value));
                 }
 
                 current_page_id = leaf_node.next_leaf;
@@ -14,6 +14,7 @@ use std::time::Instant;
 use tempfile::TempDir;
 
 use super::*;
+use crate::storage::types::Value as FieldValue;
 
 #[tokio::test]
 async fn test_empty_tree() {
@@ -183,6 +184,119 @@ async fn test_range_scan_edge_cases() {
     assert_eq!(results.len(), 10);
 }
 
+fn composite(values: &[FieldValue]) -> Key {
+    CompositeKey::from_values(values).into_key()
+}
+
+#[test]
+fn test_composite_key_ordering() {
+    // The leading column decides before any later column
+    assert!(
+        composite(&[FieldValue::Integer(1), FieldValue::text("zzz")])
+            < composite(&[FieldValue::Integer(2), FieldValue::text("a")])
+    );
+    assert!(
+        composite(&[FieldValue::Integer(-5), FieldValue::Integer(100)])
+            < composite(&[FieldValue::Integer(3), FieldValue::Integer(-100)])
+    );
+
+    // Shorter text sorts before longer text it is a prefix of, regardless of
+    // the following column
+    assert!(
+        composite(&[FieldValue::text("a"), FieldValue::Integer(9)])
+            < composite(&[FieldValue::text("ab"), FieldValue::Integer(0)])
+    );
+    assert!(composite(&[FieldValue::text("a\0b")]) < composite(&[FieldValue::text("a\u{1}")]));
+
+    let floats = [-1.5, -0.5, 0.0, 2.0, f64::INFINITY];
+    for pair in floats.windows(2) {
+        assert!(
+            composite(&[FieldValue::Float(pair[0])]) < composite(&[FieldValue::Float(pair[1])])
+        );
+    }
+    assert_eq!(
+        composite(&[FieldValue::Float(-0.0)]),
+        composite(&[FieldValue::Float(0.0)])
+    );
+
+    assert!(composite(&[FieldValue::Null]) < composite(&[FieldValue::Boolean(false)]));
+    assert!(composite(&[FieldValue::Integer(i64::MAX)]) < composite(&[FieldValue::text("")]));
+
+    let key = CompositeKey::from_values(&[FieldValue::Integer(1), FieldValue::text("x")]);
+    assert_eq!(key.len(), 2);
+    assert_eq!(
+        CompositeKey::prefix_upper_bound(&[0x10, 0xFF, 0xFF]),
+        Some(vec![0x11])
+    );
+    assert_eq!(CompositeKey::prefix_upper_bound(&[0xFF]), None);
+
+    // Decoding recovers the values and ignores a row ID suffix
+    let values = vec![
+        FieldValue::Null,
+        FieldValue::Boolean(true),
+        FieldValue::Integer(-7),
+        FieldValue::Float(-2.5),
+        FieldValue::text("a\0b"),
+        FieldValue::Binary(std::sync::Arc::new(vec![0, 1, 0xFF])),
+    ];
+    let mut key = CompositeKey::from_values(&values);
+    key.push_row_id(42);
+    assert_eq!(
+        CompositeKey::decode(key.as_bytes(), values.len()).unwrap(),
+        values
+    );
+    assert!(CompositeKey::decode(&key.as_bytes()[..4], 3).is_err());
+}
+
+#[tokio::test]
+async fn test_composite_key_prefix_scan() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut btree = BTree::new(temp_dir.path()).await.unwrap();
+
+    // "eu" is a text prefix of "eu-west", but not a key prefix of its entries
+    let regions = ["us", "eu-west", "eu", "apac"];
+    for amount in (0..200).rev() {
+        for (region_index, region) in regions.iter().enumerate() {
+            let mut key = CompositeKey::from_values(&[
+                FieldValue::text(*region),
+                FieldValue::Integer(amount),
+            ]);
+            let row_id = (region_index * 1000) as u64 + amount as u64;
+            key.push_row_id(row_id);
+            btree.insert(key.into_key(), row_id).await.unwrap();
+        }
+    }
+    assert_eq!(btree.len(), 800);
+    assert!(btree.height() > 1);
+
+    // WHERE region = 'eu'
+    let prefix = composite(&[FieldValue::text("eu")]);
+    let results = btree.prefix_scan(&prefix).await.unwrap();
+    let amounts: Vec<u64> = results.iter().map(|(_, row_id)| row_id - 2000).collect();
+    assert_eq!(amounts, (0..200).collect::<Vec<_>>());
+
+    // WHERE region = 'eu' AND amount > 150
+    let start = CompositeKey::prefix_upper_bound(&composite(&[
+        FieldValue::text("eu"),
+        FieldValue::Integer(150),
+    ]))
+    .unwrap();
+    let end = CompositeKey::prefix_upper_bound(&prefix).unwrap();
+    let results = btree.range_scan(&start, &end).await.unwrap();
+    let amounts: Vec<u64> = results.iter().map(|(_, row_id)| row_id - 2000).collect();
+    assert_eq!(amounts, (151..200).collect::<Vec<_>>());
+
+    // A full scan is ordered by region first, then amount
+    let all = btree.prefix_scan(&Vec::new()).await.unwrap();
+    let order: Vec<u64> = all.iter().map(|(_, row_id)| row_id / 1000).collect();
+    let expected: Vec<u64> = [3, 2, 1, 0]
+        .iter()
+        .flat_map(|region| std::iter::repeat_n(*region, 200))
+        .collect();
+    assert_eq!(order, expected);
+    assert!(all.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
+
 #[tokio::test]
 async fn test_persistence() {
     let temp_dir = TempDir::new().unwrap();
@@ -454,3 +568,93 @@ async fn test_concurrent_inserts() {
         assert_eq!(count, 100);
     }
 }
+
+/// Build a tree with small nodes so a few hundred keys span several levels
+async fn small_order_tree(temp_dir: &TempDir, keys: u64) -> BTree {
+    let mut btree = BTree::with_config(BTreeConfig {
+        order: 4,
+        data_path: temp_dir.path().to_path_buf(),
+        enable_wal: false,
+    })
+    .await
+    .unwrap();
+    for i in 0..keys {
+        let key = format!("key{:04}", (i * 37) % keys).into_bytes();
+        btree.insert(key, i).await.unwrap();
+    }
+    btree
+}
+
+#[tokio::test]
+async fn test_verify_healthy_tree() {
+    let temp_dir = TempDir::new().unwrap();
+    let empty = BTree::new(temp_dir.path().join("empty")).await.unwrap();
+    assert_eq!(empty.verify().await.unwrap(), IntegrityReport::default());
+
+    let mut btree = small_order_tree(&temp_dir, 200).await;
+    let report = btree.verify().await.unwrap();
+    assert_eq!(report.keys, 200);
+    assert_eq!(report.height, btree.height());
+    assert!(report.height >= 3);
+    assert!(report.leaf_pages > 1);
+    assert!(report.internal_pages >= 1);
+
+    // Deletes leave leaves underfull but the structure sound
+    for i in 0..50 {
+        let key = format!("key{i:04}").into_bytes();
+        assert!(btree.delete(&key).await.unwrap());
+    }
+    assert_eq!(btree.verify().await.unwrap().keys, 150);
+}
+
+#[tokio::test]
+async fn test_verify_detects_broken_sibling_pointer() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut btree = small_order_tree(&temp_dir, 200).await;
+
+    // Walk down to the leftmost leaf and make it skip its right sibling
+    let mut page_id = btree.root_page_id.unwrap();
+    while let Ok(internal) = btree.page_manager.read_internal_node(page_id).await {
+        page_id = internal.children[0];
+    }
+    let mut first = btree.page_manager.read_leaf_node(page_id).await.unwrap();
+    let second_id = first.next_leaf.unwrap();
+    let third_id = btree
+        .page_manager
+        .read_leaf_node(second_id)
+        .await
+        .unwrap()
+        .next_leaf;
+    first.next_leaf = third_id;
+    btree
+        .page_manager
+        .write_leaf_node(page_id, &first)
+        .await
+        .unwrap();
+
+    let error = btree.verify().await.unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<IntegrityViolation>(),
+        Some(&IntegrityViolation::BrokenLeafChain {
+            page_id,
+            expected: Some(second_id),
+            found: third_id,
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_verify_detects_key_count_mismatch() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut btree = small_order_tree(&temp_dir, 50).await;
+    btree.num_keys += 1;
+
+    let error = btree.verify().await.unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<IntegrityViolation>(),
+        Some(&IntegrityViolation::KeyCountMismatch {
+            recorded: 51,
+            found: 50,
+        })
+    );
+}
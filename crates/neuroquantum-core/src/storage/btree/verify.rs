@@ -0,0 +1,220 @@
+//! Structural integrity verification for B+ Trees
+//!
+//! [`BTree::verify`] reads every page reachable from the root and checks the
+//! invariants the search and scan paths rely on:
+//!
+//! - keys are strictly ascending within each node
+//! - every key lies within the range its parent's separators assign to it,
+//!   i.e. `keys[i - 1] <= key < keys[i]` for child `i`, which also orders
+//!   keys across nodes
+//! - internal nodes have one more child than keys, and no page is reachable
+//!   twice
+//! - all leaves sit at the same depth, equal to the tree height
+//! - the `next_leaf` chain visits every leaf exactly once, left to right
+//! - the tracked key count matches the entries found in the leaves
+//!
+//! Verification stops at the first violation, returned as an
+//! [`IntegrityViolation`] inside the error.
+
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use super::{BTree, Key, PageId};
+
+/// Summary of a structurally sound tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IntegrityReport {
+    /// Entries found in the leaves
+    pub keys: usize,
+    /// Depth of the leaves, 0 for an empty tree
+    pub height: usize,
+    pub internal_pages: usize,
+    pub leaf_pages: usize,
+}
+
+/// First structural problem found by [`BTree::verify`]
+///
+/// Key positions are indexes into the node's keys, or entries for leaves.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum IntegrityViolation {
+    #[error("Page {page_id}: key {index} is not greater than the key before it")]
+    UnorderedKeys { page_id: PageId, index: usize },
+    #[error("Page {page_id}: key {index} lies outside the range of its parent separators")]
+    KeyOutOfBounds { page_id: PageId, index: usize },
+    #[error("Internal page {page_id} has {keys} keys but {children} children")]
+    ChildCountMismatch {
+        page_id: PageId,
+        keys: usize,
+        children: usize,
+    },
+    #[error("Page {page_id} is reachable more than once")]
+    PageReachedTwice { page_id: PageId },
+    #[error("Leaf page {page_id} is at depth {depth}, expected {expected}")]
+    UnbalancedLeaf {
+        page_id: PageId,
+        depth: usize,
+        expected: usize,
+    },
+    #[error("Leaf page {page_id} links to {found:?} instead of {expected:?}")]
+    BrokenLeafChain {
+        page_id: PageId,
+        expected: Option<PageId>,
+        found: Option<PageId>,
+    },
+    #[error("Tree records {recorded} keys but its leaves hold {found}")]
+    KeyCountMismatch { recorded: usize, found: usize },
+    #[error("Tree records height {recorded} but its leaves are at depth {found}")]
+    HeightMismatch { recorded: usize, found: usize },
+}
+
+/// A page still to visit, with the key range its parent assigns to it
+struct PendingPage {
+    page_id: PageId,
+    depth: usize,
+    /// Inclusive lower bound
+    lower: Option<Key>,
+    /// Exclusive upper bound
+    upper: Option<Key>,
+}
+
+impl PendingPage {
+    fn check_keys<'a>(&self, keys: impl Iterator<Item = &'a Key>) -> Result<usize> {
+        let mut previous: Option<&Key> = None;
+        let mut count = 0;
+        for (index, key) in keys.enumerate() {
+            if previous.is_some_and(|previous| key <= previous) {
+                return Err(IntegrityViolation::UnorderedKeys {
+                    page_id: self.page_id,
+                    index,
+                }
+                .into());
+            }
+            let below = self.lower.as_ref().is_some_and(|lower| key < lower);
+            let above = self.upper.as_ref().is_some_and(|upper| key >= upper);
+            if below || above {
+                return Err(IntegrityViolation::KeyOutOfBounds {
+                    page_id: self.page_id,
+                    index,
+                }
+                .into());
+            }
+            previous = Some(key);
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+impl BTree {
+    /// Check the structure of the tree against the B+ Tree invariants
+    ///
+    /// Reads every page, so this is meant for offline checks such as after a
+    /// crash rather than the query path.
+    ///
+    /// # Returns
+    /// * `Ok(report)` if the tree is sound
+    /// * `Err` holding an [`IntegrityViolation`] for the first problem found,
+    ///   or an I/O error if a page cannot be read
+    pub async fn verify(&self) -> Result<IntegrityReport> {
+        let Some(root_page_id) = self.root_page_id else {
+            return self.check_totals(IntegrityReport::default());
+        };
+
+        let mut report = IntegrityReport::default();
+        let mut visited = HashSet::new();
+        // Leaves in key order with their sibling pointers
+        let mut leaves: Vec<(PageId, Option<PageId>)> = Vec::new();
+        let mut stack = vec![PendingPage {
+            page_id: root_page_id,
+            depth: 1,
+            lower: None,
+            upper: None,
+        }];
+
+        while let Some(page) = stack.pop() {
+            if !visited.insert(page.page_id) {
+                return Err(IntegrityViolation::PageReachedTwice {
+                    page_id: page.page_id,
+                }
+                .into());
+            }
+
+            if let Ok(internal_node) = self.page_manager.read_internal_node(page.page_id).await {
+                let keys = &internal_node.keys;
+                if internal_node.children.len() != keys.len() + 1 {
+                    return Err(IntegrityViolation::ChildCountMismatch {
+                        page_id: page.page_id,
+                        keys: keys.len(),
+                        children: internal_node.children.len(),
+                    }
+                    .into());
+                }
+                page.check_keys(keys.iter())?;
+                report.internal_pages += 1;
+
+                // Push right to left so children are visited in key order
+                for (i, &child) in internal_node.children.iter().enumerate().rev() {
+                    stack.push(PendingPage {
+                        page_id: child,
+                        depth: page.depth + 1,
+                        lower: if i == 0 {
+                            page.lower.clone()
+                        } else {
+                            Some(keys[i - 1].clone())
+                        },
+                        upper: keys.get(i).cloned().or_else(|| page.upper.clone()),
+                    });
+                }
+                continue;
+            }
+
+            let leaf_node = self.page_manager.read_leaf_node(page.page_id).await?;
+            report.keys += page.check_keys(leaf_node.entries.iter().map(|(key, _)| key))?;
+            if report.leaf_pages == 0 {
+                report.height = page.depth;
+            } else if page.depth != report.height {
+                return Err(IntegrityViolation::UnbalancedLeaf {
+                    page_id: page.page_id,
+                    depth: page.depth,
+                    expected: report.height,
+                }
+                .into());
+            }
+            report.leaf_pages += 1;
+            leaves.push((page.page_id, leaf_node.next_leaf));
+        }
+
+        for (i, &(page_id, next_leaf)) in leaves.iter().enumerate() {
+            let expected = leaves.get(i + 1).map(|&(next, _)| next);
+            if next_leaf != expected {
+                return Err(IntegrityViolation::BrokenLeafChain {
+                    page_id,
+                    expected,
+                    found: next_leaf,
+                }
+                .into());
+            }
+        }
+
+        self.check_totals(report)
+    }
+
+    fn check_totals(&self, report: IntegrityReport) -> Result<IntegrityReport> {
+        if report.keys != self.num_keys {
+            return Err(IntegrityViolation::KeyCountMismatch {
+                recorded: self.num_keys,
+                found: report.keys,
+            }
+            .into());
+        }
+        if report.height != self.height {
+            return Err(IntegrityViolation::HeightMismatch {
+                recorded: self.height,
+                found: report.height,
+            }
+            .into());
+        }
+        Ok(report)
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! Implements LRU (Least Recently Used) and Clock eviction algorithms.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use indexmap::IndexSet;
 
@@ -14,7 +14,16 @@ pub trait EvictionPolicy: Send + Sync {
     fn record_access(&mut self, frame_id: FrameId);
 
     /// Select a victim frame for eviction
-    fn select_victim(&mut self) -> Option<FrameId>;
+    fn select_victim(&mut self) -> Option<FrameId> {
+        self.select_victim_excluding(&HashSet::new())
+    }
+
+    /// Select the first eviction candidate not in `excluded`
+    ///
+    /// Called with the frames rejected so far, such as pinned ones, to get
+    /// successive candidates. Returns `None` once every tracked frame is
+    /// excluded.
+    fn select_victim_excluding(&mut self, excluded: &HashSet<FrameId>) -> Option<FrameId>;
 
     /// Remove a frame from tracking
     fn remove(&mut self, frame_id: FrameId);
@@ -24,7 +33,8 @@ pub trait EvictionPolicy: Send + Sync {
 ///
 /// This implementation uses `IndexSet` to achieve O(1) complexity for all operations:
 /// - `record_access`: O(1) - removes and re-inserts at end
-/// - `select_victim`: O(1) - returns first element
+/// - `select_victim`: O(1) - returns first element, O(k) when skipping k
+///   excluded frames
 /// - `remove`: O(1) - removes by value
 ///
 /// The `IndexSet` maintains insertion order, allowing us to use it as an LRU queue
@@ -54,9 +64,12 @@ impl EvictionPolicy for LRUEviction {
         self.order.insert(frame_id);
     }
 
-    fn select_victim(&mut self) -> Option<FrameId> {
-        // Return least recently used (first element in set) - O(1)
-        self.order.first().copied()
+    fn select_victim_excluding(&mut self, excluded: &HashSet<FrameId>) -> Option<FrameId> {
+        // Return least recently used (first element in set) that isn't excluded
+        self.order
+            .iter()
+            .find(|frame_id| !excluded.contains(frame_id))
+            .copied()
     }
 
     fn remove(&mut self, frame_id: FrameId) {
@@ -94,16 +107,15 @@ impl EvictionPolicy for ClockEviction {
         self.reference_bits.insert(frame_id, true);
     }
 
-    fn select_victim(&mut self) -> Option<FrameId> {
-        if self.frames.is_empty() {
-            return None;
-        }
-
-        // Sweep clock hand until we find a frame with reference bit = 0
-        loop {
+    fn select_victim_excluding(&mut self, excluded: &HashSet<FrameId>) -> Option<FrameId> {
+        // Sweep clock hand until we find a frame with reference bit = 0. Two
+        // turns clear every reference bit, so a third finds nothing new.
+        for _ in 0..self.frames.len() * 2 {
             let frame_id = self.frames[self.hand];
 
-            if let Some(&ref_bit) = self.reference_bits.get(&frame_id) {
+            if excluded.contains(&frame_id) {
+                // Passed over without using up its second chance
+            } else if let Some(&ref_bit) = self.reference_bits.get(&frame_id) {
                 if ref_bit {
                     // Give second chance, clear reference bit
                     self.reference_bits.insert(frame_id, false);
@@ -116,6 +128,7 @@ impl EvictionPolicy for ClockEviction {
             // Move clock hand
             self.hand = (self.hand + 1) % self.frames.len();
         }
+        None
     }
 
     fn remove(&mut self, frame_id: FrameId) {
@@ -216,6 +229,35 @@ mod tests {
         assert!(victim.is_some());
     }
 
+    #[test]
+    fn test_lru_excluding() {
+        let mut lru = LRUEviction::new(3);
+
+        lru.record_access(FrameId(0));
+        lru.record_access(FrameId(1));
+        lru.record_access(FrameId(2));
+
+        let excluded = HashSet::from([FrameId(0), FrameId(1)]);
+        assert_eq!(lru.select_victim_excluding(&excluded), Some(FrameId(2)));
+
+        let excluded = HashSet::from([FrameId(0), FrameId(1), FrameId(2)]);
+        assert_eq!(lru.select_victim_excluding(&excluded), None);
+    }
+
+    #[test]
+    fn test_clock_excluding() {
+        let mut clock = ClockEviction::new(3);
+
+        clock.record_access(FrameId(1));
+
+        // Frame 0 is excluded and frame 1 gets its second chance
+        let excluded = HashSet::from([FrameId(0)]);
+        assert_eq!(clock.select_victim_excluding(&excluded), Some(FrameId(2)));
+
+        let excluded = HashSet::from([FrameId(0), FrameId(1), FrameId(2)]);
+        assert_eq!(clock.select_victim_excluding(&excluded), None);
+    }
+
     #[test]
     fn test_lru_large_scale_performance() {
         // Test with 10,000 frames to verify O(1) performance
@@ -12,6 +12,7 @@ use tracing::{debug, info, warn};
 
 use super::super::pager::{PageId, PageStorageManager};
 use super::frame::{Frame, FrameId};
+use super::{mark_dirty, take_dirty};
 
 /// Background flusher
 pub struct BackgroundFlusher {
@@ -148,24 +149,31 @@ impl BackgroundFlusher {
         frames: &Arc<RwLock<HashMap<FrameId, Frame>>>,
         dirty_pages: &Arc<RwLock<HashMap<PageId, FrameId>>>,
     ) -> anyhow::Result<()> {
+        // Mark as clean before reading the page, so modifications made during
+        // the write leave it dirty
+        let frames_guard = frames.read().await;
+        let was_dirty = match frames_guard.get(&frame_id) {
+            | Some(frame) => take_dirty(dirty_pages, frame, page_id).await,
+            | None => false,
+        };
+        drop(frames_guard);
+        if !was_dirty {
+            return Ok(());
+        }
+
         // Write to disk
         let page_guard = page.read().await;
-        pager.write_page(&page_guard).await?;
+        let written = pager.write_page(&page_guard).await;
         drop(page_guard);
 
-        // Mark as clean
-        let frames_guard = frames.read().await;
-        if let Some(frame) = frames_guard.get(&frame_id) {
-            frame.set_dirty(false);
+        if written.is_err() {
+            let frames_guard = frames.read().await;
+            if let Some(frame) = frames_guard.get(&frame_id) {
+                mark_dirty(dirty_pages, frame, page_id, frame_id).await;
+            }
+            drop(frames_guard);
         }
-        drop(frames_guard);
-
-        // Remove from dirty pages
-        let mut dirty = dirty_pages.write().await;
-        dirty.remove(&page_id);
-        drop(dirty);
-
-        Ok(())
+        written
     }
 
     /// Stop the background flusher
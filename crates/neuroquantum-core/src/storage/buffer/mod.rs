@@ -7,8 +7,8 @@
 //! - Background flushing
 //! - Memory limit enforcement
 
-use std::collections::{HashMap, VecDeque};
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 
 use anyhow::{anyhow, Result};
@@ -27,6 +27,40 @@ pub use frame::{Frame, FrameError, FrameId};
 
 use super::pager::{Page, PageId, PageStorageManager};
 
+/// Mark a frame dirty and record it in the dirty set
+///
+/// The flag changes while the dirty set's write lock is held, so the flag and
+/// the set membership never disagree. Callers hold the frames lock, which is
+/// always taken before the dirty set's.
+async fn mark_dirty(
+    dirty_pages: &RwLock<HashMap<PageId, FrameId>>,
+    frame: &Frame,
+    page_id: PageId,
+    frame_id: FrameId,
+) {
+    let mut dirty_pages = dirty_pages.write().await;
+    frame.set_dirty(true);
+    dirty_pages.insert(page_id, frame_id);
+}
+
+/// Mark a frame clean ahead of writing it to disk
+///
+/// Returns `false` if the frame was already clean. The page must be read for
+/// writing only after this returns: a modification made later marks the frame
+/// dirty again instead of being cleared by the flush that missed it. If the
+/// write fails, the caller marks the frame dirty again with [`mark_dirty`].
+async fn take_dirty(
+    dirty_pages: &RwLock<HashMap<PageId, FrameId>>,
+    frame: &Frame,
+    page_id: PageId,
+) -> bool {
+    let mut dirty_pages = dirty_pages.write().await;
+    dirty_pages.remove(&page_id);
+    let was_dirty = frame.is_dirty();
+    frame.set_dirty(false);
+    was_dirty
+}
+
 /// Access pattern detected by the pattern detector
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum AccessPattern {
@@ -310,6 +344,9 @@ pub struct BufferPoolManager {
     cache_misses: AtomicU64,
     /// Access pattern detector for prefetching
     access_detector: Arc<RwLock<AccessPatternDetector>>,
+    /// Set by `begin_drain`: dirty pages are written on unpin instead of
+    /// being queued for the flusher
+    draining: AtomicBool,
 }
 
 impl BufferPoolManager {
@@ -349,6 +386,7 @@ impl BufferPoolManager {
             cache_hits: AtomicU64::new(0),
             cache_misses: AtomicU64::new(0),
             access_detector: Arc::new(RwLock::new(AccessPatternDetector::new())),
+            draining: AtomicBool::new(false),
         };
 
         // Start background flusher if enabled
@@ -460,6 +498,7 @@ impl BufferPoolManager {
                 cache_hits: AtomicU64::new(0),
                 cache_misses: AtomicU64::new(0),
                 access_detector: self.access_detector.clone(),
+                draining: AtomicBool::new(self.is_draining()),
             };
 
             // Spawn low-priority background prefetch
@@ -576,28 +615,49 @@ impl BufferPoolManager {
         self.evict_page().await
     }
 
+    /// Ask the eviction policy for candidates until one is not pinned
+    async fn select_unpinned_victim(&self) -> Result<FrameId> {
+        let frames = self.frames.read().await;
+        let mut pinned: HashSet<FrameId> = frames
+            .iter()
+            .filter(|(_, frame)| frame.is_pinned())
+            .map(|(&frame_id, _)| frame_id)
+            .collect();
+        drop(frames);
+
+        loop {
+            let mut eviction = self.eviction.write().await;
+            let candidate = eviction.select_victim_excluding(&pinned);
+            drop(eviction);
+            let Some(frame_id) = candidate else {
+                return Err(anyhow!("All frames are pinned, cannot evict"));
+            };
+
+            // The frame may have been pinned since the pinned set was taken
+            let frames = self.frames.read().await;
+            let is_pinned = frames
+                .get(&frame_id)
+                .ok_or_else(|| anyhow!("Victim frame not found: {frame_id:?}"))?
+                .is_pinned();
+            drop(frames);
+            if !is_pinned {
+                return Ok(frame_id);
+            }
+            pinned.insert(frame_id);
+        }
+    }
+
     /// Evict a page from the buffer pool
     async fn evict_page(&self) -> Result<FrameId> {
         debug!("🔄 Evicting a page from buffer pool");
 
-        // Get victim from eviction policy
-        let mut eviction = self.eviction.write().await;
-        let victim_frame_id = eviction
-            .select_victim()
-            .ok_or_else(|| anyhow!("No victim found for eviction"))?;
-        drop(eviction);
+        let victim_frame_id = self.select_unpinned_victim().await?;
 
-        // Check if frame is pinned
         let frames = self.frames.read().await;
         let frame = frames
             .get(&victim_frame_id)
             .ok_or_else(|| anyhow!("Victim frame not found: {victim_frame_id:?}"))?;
 
-        if frame.is_pinned() {
-            drop(frames);
-            return Err(anyhow!("All frames are pinned, cannot evict"));
-        }
-
         let victim_page_id = frame
             .page_id()
             .await
@@ -653,15 +713,15 @@ impl BufferPoolManager {
             .map_err(|e| anyhow!("Failed to unpin frame {frame_id:?}: {e}"))?;
 
         if is_dirty {
-            frame.set_dirty(true);
+            mark_dirty(&self.dirty_pages, frame, page_id, frame_id).await;
             drop(frames);
 
-            // Add to dirty pages
-            let mut dirty_pages = self.dirty_pages.write().await;
-            dirty_pages.insert(page_id, frame_id);
-            drop(dirty_pages);
-
-            debug!("📝 Marked page {:?} as dirty", page_id);
+            if self.is_draining() {
+                // Write through so the dirty set can only shrink while draining
+                self.flush_page_internal(page_id, frame_id).await?;
+            } else {
+                debug!("📝 Marked page {:?} as dirty", page_id);
+            }
         } else {
             drop(frames);
         }
@@ -696,27 +756,29 @@ impl BufferPoolManager {
             drop(frames);
             return Ok(());
         }
-
         let page = frame.page().await?;
+
+        // Mark as clean before reading the page, so modifications made during
+        // the write leave it dirty
+        let was_dirty = take_dirty(&self.dirty_pages, frame, page_id).await;
         drop(frames);
+        if !was_dirty {
+            return Ok(());
+        }
 
         // Write to disk
         let page_guard = page.read().await;
-        self.pager.write_page(&page_guard).await?;
+        let written = self.pager.write_page(&page_guard).await;
         drop(page_guard);
 
-        // Mark as clean
-        let frames = self.frames.read().await;
-        let frame = frames
-            .get(&frame_id)
-            .ok_or_else(|| anyhow!("Frame not found: {frame_id:?}"))?;
-        frame.set_dirty(false);
-        drop(frames);
-
-        // Remove from dirty pages
-        let mut dirty_pages = self.dirty_pages.write().await;
-        dirty_pages.remove(&page_id);
-        drop(dirty_pages);
+        if let Err(e) = written {
+            let frames = self.frames.read().await;
+            if let Some(frame) = frames.get(&frame_id) {
+                mark_dirty(&self.dirty_pages, frame, page_id, frame_id).await;
+            }
+            drop(frames);
+            return Err(e);
+        }
 
         debug!("💾 Flushed page {:?} to disk", page_id);
         Ok(())
@@ -744,6 +806,12 @@ impl BufferPoolManager {
         Ok(())
     }
 
+    /// Page storage manager backing the pool
+    #[must_use]
+    pub const fn pager(&self) -> &Arc<PageStorageManager> {
+        &self.pager
+    }
+
     /// Get buffer pool statistics
     pub async fn stats(&self) -> BufferPoolStats {
         let frames = self.frames.read().await;
@@ -805,6 +873,23 @@ impl BufferPoolManager {
         }
     }
 
+    /// Stop queuing dirty pages ahead of [`shutdown`](Self::shutdown)
+    ///
+    /// From now on [`unpin_page`](Self::unpin_page) writes a dirty page to
+    /// disk before returning, so pages dirtied while the pool is draining no
+    /// longer add to the dirty set and the final flush converges under load.
+    pub fn begin_drain(&self) {
+        if !self.draining.swap(true, Ordering::AcqRel) {
+            info!("🚰 Draining BufferPoolManager, dirty pages are now written through");
+        }
+    }
+
+    /// Check if [`begin_drain`](Self::begin_drain) was called
+    #[must_use]
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::Acquire)
+    }
+
     /// Shutdown the buffer pool, flushing all pages
     pub async fn shutdown(&self) -> Result<()> {
         info!("🛑 Shutting down BufferPoolManager");
@@ -1115,6 +1200,58 @@ mod tests {
         assert_eq!(stats.used_frames, 10);
     }
 
+    #[tokio::test]
+    async fn test_eviction_skips_pinned_victim() {
+        let (buffer_pool, _temp_dir) = create_test_buffer_pool().await;
+
+        let mut page_ids = Vec::new();
+        for _ in 0..11 {
+            page_ids.push(
+                buffer_pool
+                    .pager
+                    .allocate_page(PageType::Data)
+                    .await
+                    .unwrap(),
+            );
+        }
+
+        // The least recently used page stays pinned
+        buffer_pool.fetch_page(page_ids[0]).await.unwrap();
+        for &page_id in &page_ids[1..10] {
+            buffer_pool.fetch_page(page_id).await.unwrap();
+            buffer_pool.unpin_page(page_id, false).await.unwrap();
+        }
+        assert_eq!(buffer_pool.stats().await.free_frames, 0);
+
+        buffer_pool.fetch_page(page_ids[10]).await.unwrap();
+
+        assert!(buffer_pool.page_table.contains_key(&page_ids[0]));
+        assert!(!buffer_pool.page_table.contains_key(&page_ids[1]));
+        assert!(buffer_pool.page_table.contains_key(&page_ids[10]));
+    }
+
+    #[tokio::test]
+    async fn test_eviction_fails_when_all_frames_pinned() {
+        let (buffer_pool, _temp_dir) = create_test_buffer_pool().await;
+
+        for _ in 0..10 {
+            let page_id = buffer_pool
+                .pager
+                .allocate_page(PageType::Data)
+                .await
+                .unwrap();
+            buffer_pool.fetch_page(page_id).await.unwrap();
+        }
+
+        let page_id = buffer_pool
+            .pager
+            .allocate_page(PageType::Data)
+            .await
+            .unwrap();
+        let err = buffer_pool.fetch_page(page_id).await.unwrap_err();
+        assert!(err.to_string().contains("All frames are pinned"));
+    }
+
     #[tokio::test]
     async fn test_flush_dirty_page() {
         let (buffer_pool, _temp_dir) = create_test_buffer_pool().await;
@@ -1171,6 +1308,121 @@ mod tests {
         assert_eq!(stats.dirty_frames, 0);
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_modification_during_flush_is_not_lost() {
+        let (buffer_pool, _temp_dir) = create_test_buffer_pool().await;
+        let buffer_pool = Arc::new(buffer_pool);
+        let page_id = buffer_pool
+            .pager
+            .allocate_page(PageType::Data)
+            .await
+            .unwrap();
+
+        // Each modification writes the next sequence number
+        let sequence = Arc::new(AtomicU64::new(0));
+        let writers: Vec<_> = (0..4)
+            .map(|_| {
+                let buffer_pool = Arc::clone(&buffer_pool);
+                let sequence = Arc::clone(&sequence);
+                tokio::spawn(async move {
+                    for _ in 0..200 {
+                        let page = buffer_pool.fetch_page(page_id).await.unwrap();
+                        {
+                            let mut page_guard = page.write().await;
+                            let value = sequence.fetch_add(1, Ordering::SeqCst) + 1;
+                            page_guard.write_data(0, &value.to_le_bytes()).unwrap();
+                        }
+                        buffer_pool.unpin_page(page_id, true).await.unwrap();
+                        tokio::task::yield_now().await;
+                    }
+                })
+            })
+            .collect();
+
+        let done = Arc::new(AtomicBool::new(false));
+        let flusher = tokio::spawn({
+            let buffer_pool = Arc::clone(&buffer_pool);
+            let done = Arc::clone(&done);
+            async move {
+                while !done.load(Ordering::Acquire) {
+                    buffer_pool.flush_page(page_id).await.unwrap();
+                    tokio::task::yield_now().await;
+                }
+            }
+        });
+
+        for writer in writers {
+            writer.await.unwrap();
+        }
+        done.store(true, Ordering::Release);
+        flusher.await.unwrap();
+
+        buffer_pool.flush_all().await.unwrap();
+        assert_eq!(buffer_pool.stats().await.dirty_frames, 0);
+
+        let on_disk = buffer_pool.pager.read_page(page_id).await.unwrap();
+        let last = sequence.load(Ordering::SeqCst);
+        assert_eq!(last, 800);
+        assert_eq!(on_disk.read_data(0, 8).unwrap(), &last.to_le_bytes());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_drain_converges_under_load() {
+        let (buffer_pool, _temp_dir) = create_test_buffer_pool().await;
+        let buffer_pool = Arc::new(buffer_pool);
+
+        let mut page_ids = Vec::new();
+        for _ in 0..5 {
+            let page_id = buffer_pool
+                .pager
+                .allocate_page(PageType::Data)
+                .await
+                .unwrap();
+            buffer_pool.fetch_page(page_id).await.unwrap();
+            buffer_pool.unpin_page(page_id, true).await.unwrap();
+            page_ids.push(page_id);
+        }
+        assert_eq!(buffer_pool.stats().await.dirty_frames, 5);
+
+        buffer_pool.begin_drain();
+        assert!(buffer_pool.is_draining());
+
+        // Keep dirtying pages for the whole shutdown
+        let writes = Arc::new(AtomicU64::new(0));
+        let stop = Arc::new(AtomicBool::new(false));
+        let writer = tokio::spawn({
+            let buffer_pool = Arc::clone(&buffer_pool);
+            let writes = Arc::clone(&writes);
+            let stop = Arc::clone(&stop);
+            async move {
+                for page_id in page_ids.iter().cycle() {
+                    if stop.load(Ordering::Acquire) {
+                        break;
+                    }
+                    let page = buffer_pool.fetch_page(*page_id).await.unwrap();
+                    page.write().await.write_data(0, b"draining").unwrap();
+                    buffer_pool.unpin_page(*page_id, true).await.unwrap();
+                    writes.fetch_add(1, Ordering::Relaxed);
+                    tokio::task::yield_now().await;
+                }
+            }
+        });
+        while writes.load(Ordering::Relaxed) < 10 {
+            tokio::task::yield_now().await;
+        }
+
+        buffer_pool.shutdown().await.unwrap();
+        assert_eq!(buffer_pool.stats().await.dirty_frames, 0);
+
+        let writes_at_shutdown = writes.load(Ordering::Relaxed);
+        while writes.load(Ordering::Relaxed) < writes_at_shutdown + 10 {
+            tokio::task::yield_now().await;
+        }
+        stop.store(true, Ordering::Release);
+        writer.await.unwrap();
+        assert_eq!(buffer_pool.stats().await.dirty_frames, 0);
+    }
+
     #[test]
     fn test_auto_tuned_config() {
         // Test that auto_tuned() produces a valid configuration
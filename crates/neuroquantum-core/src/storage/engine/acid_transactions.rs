@@ -3,18 +3,23 @@
 //! This module implements full ACID transaction support with:
 //! - Write-Ahead Logging (WAL)
 //! - Lock-based concurrency control
-//! - Isolation levels (Read Committed, Serializable)
+//! - Isolation levels (Read Committed, Serializable, Snapshot)
 //! - Savepoints for partial rollback
 
+use std::collections::HashSet;
+
 use anyhow::{anyhow, Result};
 use tracing::{debug, instrument};
 
-use super::StorageEngine;
-use crate::storage::query::{DeleteQuery, SelectQuery, UpdateQuery};
+use super::crud::SnapshotRows;
+use super::{RowChangeKind, StorageEngine};
+use crate::storage::query::{DeleteQuery, FieldAccess, SelectQuery, UpdateQuery};
 use crate::storage::row::Row;
+use crate::storage::stats::QueryExecutionStats;
 use crate::storage::transaction_log::{Operation, LSN};
-use crate::storage::types::RowId;
-use crate::transaction::{IsolationLevel, LockType, TransactionId};
+use crate::storage::types::{RowId, Value};
+use crate::storage::wal::{CommitResult, Durability};
+use crate::transaction::{IsolationLevel, LockType, LogRecord, LogRecordType, TransactionId};
 
 impl StorageEngine {
     /// Begin a new ACID transaction
@@ -36,9 +41,12 @@ impl StorageEngine {
     /// Begin a transaction with specific isolation level
     ///
     /// # Isolation Levels
+    /// - `ReadUncommitted`: Reads take no locks and may see uncommitted data
     /// - `ReadCommitted`: See only committed data (default)
-    /// - `RepeatableRead`: Consistent snapshot for the entire transaction
+    /// - `RepeatableRead`: Rows read stay locked until the transaction ends
     /// - `Serializable`: Full isolation, transactions appear sequential
+    /// - `Snapshot`: Reads see the database as of the transaction's start;
+    ///   writing a row another transaction changed since then fails
     ///
     /// # Errors
     ///
@@ -49,7 +57,7 @@ impl StorageEngine {
         isolation_level: IsolationLevel,
     ) -> Result<TransactionId> {
         self.transaction_manager
-            .begin_transaction(isolation_level)
+            .begin_with_isolation(isolation_level)
             .await
             .map_err(|e| anyhow!("Failed to begin transaction: {e}"))
     }
@@ -60,17 +68,36 @@ impl StorageEngine {
     /// 1. Retrieves pending operations from the undo log
     /// 2. Writes INSERT operations to disk (updates are already persisted)
     /// 3. Marks the transaction as committed in the WAL
+    /// 4. Publishes the transaction's row changes to the change feed
     ///
     /// # Errors
     ///
     /// Returns an error if commit fails or disk writes fail.
-    #[instrument(level = "debug", skip(self), fields(tx_id = ?tx_id))]
     pub async fn commit_acid_transaction(&mut self, tx_id: TransactionId) -> Result<()> {
-        use crate::transaction::LogRecordType;
+        self.commit_acid_transaction_with_durability(tx_id, Durability::Durable)
+            .await
+            .map(|_| ())
+    }
 
+    /// Commit a transaction with the given `durability`
+    ///
+    /// Like [`commit_acid_transaction`](Self::commit_acid_transaction), but a
+    /// [`Durability::Relaxed`] commit returns without forcing its commit
+    /// record to disk. The result tells whether the commit was synchronous.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if commit fails or disk writes fail.
+    #[instrument(level = "debug", skip(self), fields(tx_id = ?tx_id))]
+    pub async fn commit_acid_transaction_with_durability(
+        &mut self,
+        tx_id: TransactionId,
+        durability: Durability,
+    ) -> Result<CommitResult> {
         debug!("💾 Committing transaction: {:?}", tx_id);
 
         // Get the undo log to find pending writes (inserts/updates)
+        let mut changes = Vec::new();
         if let Some(log_entries) = self.transaction_manager.get_undo_log(tx_id).await {
             for entry in &log_entries {
                 if let LogRecordType::Update {
@@ -91,15 +118,44 @@ impl StorageEngine {
                         }
                     }
                     // For updates, the data is already on disk (we update in place)
+
+                    if let Some(change) = Self::logged_change(before_image.as_deref(), after_image)
+                    {
+                        changes.push((table.clone(), change));
+                    }
                 }
             }
         }
 
         // Now complete the transaction commit
-        self.transaction_manager
-            .commit(tx_id)
+        let result = self
+            .transaction_manager
+            .commit_with_durability(tx_id, durability)
             .await
-            .map_err(|e| anyhow!("Failed to commit transaction: {e}"))
+            .map_err(|e| anyhow!("Failed to commit transaction: {e}"))?;
+
+        for (table, (kind, row, previous)) in changes {
+            self.publish_change(&table, kind, &row, previous.as_ref());
+        }
+        Ok(result)
+    }
+
+    /// Decode a logged row change from its before and after images
+    ///
+    /// An empty after-image marks a DELETE, a missing before-image an INSERT.
+    fn logged_change(
+        before_image: Option<&[u8]>,
+        after_image: &[u8],
+    ) -> Option<(RowChangeKind, Row, Option<Row>)> {
+        let before = before_image.and_then(|image| serde_json::from_slice::<Row>(image).ok());
+        if after_image.is_empty() {
+            return before.map(|row| (RowChangeKind::Deleted, row, None));
+        }
+        let after = serde_json::from_slice::<Row>(after_image).ok()?;
+        Some(match before {
+            | Some(previous) => (RowChangeKind::Updated, after, Some(previous)),
+            | None => (RowChangeKind::Inserted, after, None),
+        })
     }
 
     /// Rollback a transaction and undo all changes
@@ -113,42 +169,15 @@ impl StorageEngine {
     ///
     /// Returns an error if rollback fails.
     pub async fn rollback_acid_transaction(&mut self, tx_id: TransactionId) -> Result<()> {
-        use crate::transaction::LogRecordType;
-
         debug!("🔙 Rolling back transaction: {:?}", tx_id);
 
         // Get the undo log for this transaction before rolling back
-        let undo_log = self.transaction_manager.get_undo_log(tx_id).await;
-
-        // Apply undo operations in reverse order
-        if let Some(log_entries) = undo_log {
-            for entry in log_entries.iter().rev() {
-                if let LogRecordType::Update {
-                    before_image, key, ..
-                } = &entry.record_type
-                {
-                    let row_id: RowId = key.parse().unwrap_or(0);
-                    if before_image.is_none() {
-                        // This was an INSERT - we need to delete the row
-                        debug!("ROLLBACK: Deleting inserted row {}", key);
-                        // Remove from compressed blocks
-                        self.compressed_blocks.remove(&row_id);
-                        // Remove from cache
-                        self.row_cache.pop(&row_id);
-                    } else {
-                        // This was an UPDATE or DELETE - restore the before image
-                        debug!("ROLLBACK: Restoring before image for row {}", key);
-                        if let Some(before) = before_image {
-                            if let Ok(row) = serde_json::from_slice::<Row>(before) {
-                                let compressed = self.compress_row(&row).await?;
-                                self.compressed_blocks.insert(row_id, compressed);
-                                self.add_to_cache(row);
-                            }
-                        }
-                    }
-                }
-            }
-        }
+        let undo_log = self
+            .transaction_manager
+            .get_undo_log(tx_id)
+            .await
+            .unwrap_or_default();
+        self.undo_row_changes(&[], &undo_log).await?;
 
         // Now call the transaction manager to complete the rollback
         self.transaction_manager
@@ -174,54 +203,20 @@ impl StorageEngine {
         tx_id: TransactionId,
         savepoint_lsn: LSN,
     ) -> Result<u64> {
-        use crate::transaction::LogRecordType;
-
         debug!(
             "↩️  Rolling back transaction {:?} to savepoint LSN {}",
             tx_id, savepoint_lsn
         );
 
-        // Get the undo log for this transaction
-        let undo_log = self.transaction_manager.get_undo_log(tx_id).await;
-        let mut operations_undone = 0u64;
-
-        // Apply undo operations in reverse order for entries after savepoint
-        if let Some(log_entries) = undo_log {
-            for entry in log_entries.iter().rev() {
-                // Only undo operations that occurred after the savepoint
-                if entry.lsn <= savepoint_lsn {
-                    break;
-                }
-
-                operations_undone += 1;
-
-                if let LogRecordType::Update {
-                    before_image, key, ..
-                } = &entry.record_type
-                {
-                    let row_id: RowId = key.parse().unwrap_or(0);
-                    if before_image.is_none() {
-                        // This was an INSERT - we need to delete the row
-                        debug!("ROLLBACK TO SAVEPOINT: Deleting inserted row {}", key);
-                        self.compressed_blocks.remove(&row_id);
-                        self.row_cache.pop(&row_id);
-                    } else {
-                        // This was an UPDATE or DELETE - restore the before image
-                        debug!(
-                            "ROLLBACK TO SAVEPOINT: Restoring before image for row {}",
-                            key
-                        );
-                        if let Some(before) = before_image {
-                            if let Ok(row) = serde_json::from_slice::<Row>(before) {
-                                let compressed = self.compress_row(&row).await?;
-                                self.compressed_blocks.insert(row_id, compressed);
-                                self.add_to_cache(row);
-                            }
-                        }
-                    }
-                }
-            }
-        }
+        // Undo only operations that occurred after the savepoint
+        let (kept, undone): (Vec<_>, Vec<_>) = self
+            .transaction_manager
+            .get_undo_log(tx_id)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .partition(|record| record.lsn <= savepoint_lsn);
+        self.undo_row_changes(&kept, &undone).await?;
 
         // Call the transaction manager to update its internal state
         self.transaction_manager
@@ -229,7 +224,145 @@ impl StorageEngine {
             .await
             .map_err(|e| anyhow!("Failed to rollback to savepoint: {e}"))?;
 
-        Ok(operations_undone)
+        Ok(undone.len() as u64)
+    }
+
+    /// Create a savepoint named `name` at the transaction's current position,
+    /// replacing an existing savepoint of the same name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction does not exist.
+    pub async fn create_acid_savepoint(&self, tx_id: TransactionId, name: &str) -> Result<LSN> {
+        self.transaction_manager
+            .create_savepoint(tx_id, name.to_string())
+            .await
+            .map_err(|e| anyhow!("Failed to create savepoint: {e}"))
+    }
+
+    /// Rollback a transaction to the savepoint `name`
+    ///
+    /// Undoes the operations made since the savepoint, which stays active;
+    /// savepoints created after it are discarded.
+    ///
+    /// # Returns
+    ///
+    /// The number of operations that were undone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the savepoint does not exist or rollback fails.
+    pub async fn rollback_acid_to_named_savepoint(
+        &mut self,
+        tx_id: TransactionId,
+        name: &str,
+    ) -> Result<u64> {
+        debug!(
+            "↩️  Rolling back transaction {:?} to savepoint '{}'",
+            tx_id, name
+        );
+
+        let undone = self
+            .transaction_manager
+            .rollback_to_named_savepoint(tx_id, name)
+            .await
+            .map_err(|e| anyhow!("Failed to rollback to savepoint: {e}"))?;
+        let kept = self
+            .transaction_manager
+            .get_undo_log(tx_id)
+            .await
+            .unwrap_or_default();
+        self.undo_row_changes(&kept, &undone).await?;
+
+        Ok(undone.len() as u64)
+    }
+
+    /// Release the savepoint `name` and every savepoint created after it,
+    /// keeping their work
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the savepoint does not exist.
+    pub async fn release_acid_savepoint(&self, tx_id: TransactionId, name: &str) -> Result<()> {
+        self.transaction_manager
+            .release_savepoint(tx_id, name.to_string())
+            .await
+            .map_err(|e| anyhow!("Failed to release savepoint: {e}"))
+    }
+
+    /// Apply the before-images of `undone`, newest first
+    ///
+    /// Restores the rows in memory, in the primary key and secondary indexes
+    /// and in the table files. Rows the transaction inserted, according to
+    /// `kept` and `undone` together, only reach their table file at commit,
+    /// so they are restored in memory alone.
+    async fn undo_row_changes(&mut self, kept: &[LogRecord], undone: &[LogRecord]) -> Result<()> {
+        let inserted: HashSet<(&str, &str)> = kept
+            .iter()
+            .chain(undone)
+            .filter_map(|record| match &record.record_type {
+                | LogRecordType::Update {
+                    table,
+                    key,
+                    before_image: None,
+                    ..
+                } => Some((table.as_str(), key.as_str())),
+                | _ => None,
+            })
+            .collect();
+
+        for record in undone.iter().rev() {
+            let LogRecordType::Update {
+                table,
+                key,
+                before_image,
+                after_image,
+                ..
+            } = &record.record_type
+            else {
+                continue;
+            };
+            let Some(schema) = self.metadata.tables.get(table).cloned() else {
+                continue;
+            };
+            self.bump_table_version(table);
+            let in_file = !inserted.contains(&(table.as_str(), key.as_str()));
+
+            // Take the logged change out of memory and the indexes
+            let after = if after_image.is_empty() {
+                None
+            } else {
+                Some(serde_json::from_slice::<Row>(after_image)?)
+            };
+            if let Some(after) = &after {
+                self.compressed_blocks.remove(&after.id);
+                self.row_cache.pop(&after.id);
+                self.update_indexes_for_delete(&schema, after)?;
+            }
+
+            let Some(before) = before_image else {
+                // This was an INSERT - removing the row is all there is to it
+                debug!("ROLLBACK: Deleting inserted row {}", key);
+                continue;
+            };
+
+            // This was an UPDATE or DELETE - restore the before image
+            debug!("ROLLBACK: Restoring before image for row {}", key);
+            let row = serde_json::from_slice::<Row>(before)?;
+            let compressed = self.compress_row(&row).await?;
+            self.compressed_blocks.insert(row.id, compressed);
+            self.update_indexes_for_insert(&schema, &row)?;
+            if in_file {
+                if after.is_some() {
+                    self.rewrite_table_file_with_updates(table, std::slice::from_ref(&row))
+                        .await?;
+                } else {
+                    self.append_row_to_file(table, &row).await?;
+                }
+            }
+            self.add_to_cache(row);
+        }
+        Ok(())
     }
 
     /// Get the undo log for a transaction
@@ -280,14 +413,33 @@ impl StorageEngine {
         row.id = self.next_row_id;
         self.next_row_id += 1;
 
+        // Populate AUTO_INCREMENT and DEFAULT columns, as for non-transactional inserts
+        self.populate_auto_increment_columns(table, &schema, &mut row)?;
+        Self::populate_default_values(&schema, &mut row);
+
         // Validate row against schema
         self.validate_row(&schema, &row)?;
+        self.check_unique_indexes(table, &row)?;
+        self.validate_foreign_key_constraints(&schema, &row).await?;
+
+        // Seal ENCRYPTED columns before the row reaches the WAL or memory
+        self.encrypt_fields(&schema, &mut row)?;
 
         // Compress row data
         let compressed_data = self.compress_row(&row).await?;
 
         // Log the operation to WAL before applying changes
         let after_image = serde_json::to_vec(&row)?;
+        self.transaction_manager
+            .write_stored_row(
+                tx_id,
+                table,
+                &row.id.to_string(),
+                None,
+                Some(after_image.clone()),
+            )
+            .await
+            .map_err(|e| anyhow!("Failed to write row: {e}"))?;
         self.transaction_manager
             .log_update(
                 tx_id,
@@ -300,6 +452,7 @@ impl StorageEngine {
             .map_err(|e| anyhow!("Failed to log update: {e}"))?;
 
         // Apply changes to memory only (disk write happens at commit time)
+        self.bump_table_version(table);
         self.compressed_blocks.insert(row.id, compressed_data);
         self.update_indexes_for_insert(&schema, &row)?;
         self.add_to_cache(row.clone());
@@ -353,7 +506,7 @@ impl StorageEngine {
             offset: None,
         };
 
-        let existing_rows = self.select_rows(&select_query).await?;
+        let existing_rows = self.select_stored_rows(&select_query).await?;
         let mut updated_count = 0;
         let mut updated_rows = Vec::new();
 
@@ -363,6 +516,14 @@ impl StorageEngine {
             // Serialize before-image for WAL
             let before_image = serde_json::to_vec(&old_row)?;
 
+            let schema = self
+                .metadata
+                .tables
+                .get(&query.table)
+                .ok_or_else(|| anyhow!("Table '{}' schema not found", query.table))?
+                .clone();
+            self.decrypt_fields(&schema, &mut row)?;
+
             // Apply updates
             for (field, new_value) in &query.set_values {
                 row.fields.insert(field.clone(), new_value.clone());
@@ -370,16 +531,26 @@ impl StorageEngine {
             row.updated_at = chrono::Utc::now();
 
             // Validate updated row
-            let schema = self
-                .metadata
-                .tables
-                .get(&query.table)
-                .ok_or_else(|| anyhow!("Table '{}' schema not found", query.table))?;
-            self.validate_row(schema, &row)?;
+            self.validate_row(&schema, &row)?;
+            self.check_unique_indexes(&query.table, &row)?;
+            self.validate_foreign_key_constraints(&schema, &row).await?;
+            self.encrypt_fields(&schema, &mut row)?;
 
             // Serialize after-image for WAL
             let after_image = serde_json::to_vec(&row)?;
 
+            // Keep the before-image for snapshots and detect write conflicts
+            self.transaction_manager
+                .write_stored_row(
+                    tx_id,
+                    &query.table,
+                    &row.id.to_string(),
+                    Some(before_image.clone()),
+                    Some(after_image.clone()),
+                )
+                .await
+                .map_err(|e| anyhow!("Failed to write row: {e}"))?;
+
             // Log to WAL
             self.transaction_manager
                 .log_update(
@@ -395,6 +566,8 @@ impl StorageEngine {
             // Apply changes
             let compressed_data = self.compress_row(&row).await?;
             self.compressed_blocks.insert(row.id, compressed_data);
+            self.remove_from_secondary_indexes(&query.table, &old_row);
+            self.insert_into_secondary_indexes(&query.table, &row);
             self.add_to_cache(row.clone());
             updated_rows.push(row.clone());
 
@@ -424,93 +597,126 @@ impl StorageEngine {
     ///
     /// This method:
     /// 1. Acquires an exclusive lock on the table
-    /// 2. Logs before-images to WAL (empty after-image indicates DELETE)
-    /// 3. Applies changes immediately
+    /// 2. Applies the ON DELETE actions of referencing foreign keys within
+    ///    the transaction, so a rollback restores cascaded changes too
+    /// 3. Logs before-images to WAL (empty after-image indicates DELETE)
+    /// 4. Applies changes immediately
     ///
     /// # Errors
     ///
-    /// Returns an error if lock acquisition fails.
+    /// Returns an error if lock acquisition fails or a foreign key with
+    /// RESTRICT or NO ACTION still references a deleted row.
     pub async fn delete_rows_acid(
         &mut self,
         tx_id: TransactionId,
         query: &DeleteQuery,
     ) -> Result<u64> {
-        debug!("🗑️ Transactional delete from table: {}", query.table);
-
-        // Acquire exclusive lock on table
-        let resource_id = format!("table:{}", query.table);
-        self.transaction_manager
-            .acquire_lock(tx_id, resource_id.clone(), LockType::Exclusive)
-            .await
-            .map_err(|e| anyhow!("Failed to acquire lock: {e}"))?;
-
-        // Get existing rows that match the condition
-        let select_query = SelectQuery {
-            table: query.table.clone(),
-            columns: vec!["*".to_string()],
-            where_clause: query.where_clause.clone(),
-            order_by: None,
-            limit: None,
-            offset: None,
-        };
-
-        let rows_to_delete = self.select_rows(&select_query).await?;
-        let deleted_count = rows_to_delete.len();
-        let mut deleted_row_ids = Vec::new();
+        self.delete_rows_acid_internal(tx_id, query).await
+    }
 
-        for row in rows_to_delete {
-            // Serialize before-image for WAL
-            let before_image = serde_json::to_vec(&row)?;
+    /// Transactional delete, boxed as it recurses for ON DELETE CASCADE
+    pub(crate) fn delete_rows_acid_internal<'a>(
+        &'a mut self,
+        tx_id: TransactionId,
+        query: &'a DeleteQuery,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<u64>> + Send + 'a>> {
+        Box::pin(async move {
+            debug!("🗑️ Transactional delete from table: {}", query.table);
 
-            // Log to WAL (DELETE has before-image, empty after-image)
+            // Acquire exclusive lock on table
+            let resource_id = format!("table:{}", query.table);
             self.transaction_manager
-                .log_update(
-                    tx_id,
-                    query.table.clone(),
-                    row.id.to_string(),
-                    Some(before_image),
-                    vec![], // Empty after-image indicates DELETE
-                )
+                .acquire_lock(tx_id, resource_id.clone(), LockType::Exclusive)
                 .await
-                .map_err(|e| anyhow!("Failed to log delete: {e}"))?;
+                .map_err(|e| anyhow!("Failed to acquire lock: {e}"))?;
 
-            // Keep track of deleted row IDs
-            deleted_row_ids.push(row.id);
+            // Get existing rows that match the condition
+            let select_query = SelectQuery {
+                table: query.table.clone(),
+                columns: vec!["*".to_string()],
+                where_clause: query.where_clause.clone(),
+                order_by: None,
+                limit: None,
+                offset: None,
+            };
 
-            // Apply changes
-            self.compressed_blocks.remove(&row.id);
-            self.row_cache.pop(&row.id);
+            let rows_to_delete = self.select_stored_rows(&select_query).await?;
+            let deleted_count = rows_to_delete.len();
+            let mut deleted_row_ids = Vec::new();
 
-            let schema = self
-                .metadata
-                .tables
-                .get(&query.table)
-                .ok_or_else(|| anyhow!("Table '{}' schema not found", query.table))?
-                .clone();
-            self.update_indexes_for_delete(&schema, &row)?;
+            // Cascade, reject or null out references before removing any row
+            for row in &rows_to_delete {
+                self.handle_delete_foreign_key_constraints(&query.table, row, Some(tx_id))
+                    .await?;
+            }
 
-            // Log operation
-            let operation = Operation::Delete {
-                table: query.table.clone(),
-                row_id: row.id,
-                data: row,
-            };
-            self.log_operation(operation).await?;
-        }
+            for row in rows_to_delete {
+                // Serialize before-image for WAL
+                let before_image = serde_json::to_vec(&row)?;
+
+                // Keep the before-image for snapshots and detect write conflicts
+                self.transaction_manager
+                    .write_stored_row(
+                        tx_id,
+                        &query.table,
+                        &row.id.to_string(),
+                        Some(before_image.clone()),
+                        None,
+                    )
+                    .await
+                    .map_err(|e| anyhow!("Failed to delete row: {e}"))?;
+
+                // Log to WAL (DELETE has before-image, empty after-image)
+                self.transaction_manager
+                    .log_update(
+                        tx_id,
+                        query.table.clone(),
+                        row.id.to_string(),
+                        Some(before_image),
+                        vec![], // Empty after-image indicates DELETE
+                    )
+                    .await
+                    .map_err(|e| anyhow!("Failed to log delete: {e}"))?;
+
+                // Keep track of deleted row IDs
+                deleted_row_ids.push(row.id);
+
+                // Apply changes
+                self.compressed_blocks.remove(&row.id);
+                self.row_cache.pop(&row.id);
+
+                let schema = self
+                    .metadata
+                    .tables
+                    .get(&query.table)
+                    .ok_or_else(|| anyhow!("Table '{}' schema not found", query.table))?
+                    .clone();
+                self.update_indexes_for_delete(&schema, &row)?;
+
+                // Log operation
+                let operation = Operation::Delete {
+                    table: query.table.clone(),
+                    row_id: row.id,
+                    data: row,
+                };
+                self.log_operation(operation).await?;
+            }
 
-        // Rewrite table file without deleted rows
-        if deleted_count > 0 {
-            self.rewrite_table_file_with_deletions(&query.table, &deleted_row_ids)
-                .await?;
-        }
+            // Rewrite table file without deleted rows
+            if deleted_count > 0 {
+                self.rewrite_table_file_with_deletions(&query.table, &deleted_row_ids)
+                    .await?;
+            }
 
-        debug!("✅ Deleted {} rows (tx: {:?})", deleted_count, tx_id);
-        Ok(deleted_count as u64)
+            debug!("✅ Deleted {} rows (tx: {:?})", deleted_count, tx_id);
+            Ok(deleted_count as u64)
+        })
     }
 
     /// Select rows within a transaction (with appropriate locking)
     ///
-    /// Acquires a shared lock on the table to ensure consistent reads.
+    /// Values of `ENCRYPTED` columns are redacted; see
+    /// [`select_rows_acid_with_access`](Self::select_rows_acid_with_access).
     ///
     /// # Errors
     ///
@@ -519,18 +725,124 @@ impl StorageEngine {
         &self,
         tx_id: TransactionId,
         query: &SelectQuery,
+    ) -> Result<Vec<Row>> {
+        self.select_rows_acid_with_access(tx_id, query, FieldAccess::Redacted)
+            .await
+    }
+
+    /// Select rows within a transaction, presenting `ENCRYPTED` columns per `access`
+    ///
+    /// Takes a shared lock on the table as the transaction's isolation level
+    /// requires: none for Read Uncommitted, for the duration of the select
+    /// for Read Committed, and until commit or rollback for Repeatable Read
+    /// and Serializable. Snapshot transactions take no lock: they read the
+    /// table as of their snapshot, with older versions of rows written since
+    /// kept by the transaction manager, plus their own writes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if lock acquisition fails or query fails.
+    pub async fn select_rows_acid_with_access(
+        &self,
+        tx_id: TransactionId,
+        query: &SelectQuery,
+        access: FieldAccess,
     ) -> Result<Vec<Row>> {
         debug!("🔍 Transactional select from table: {}", query.table);
 
-        // Acquire shared lock on table for consistent reads
+        if let Some(snapshot) = self.transaction_snapshot(tx_id, &query.table).await? {
+            return self.select_snapshot_rows(query, access, &snapshot).await;
+        }
+
         let resource_id = format!("table:{}", query.table);
-        self.transaction_manager
-            .acquire_lock(tx_id, resource_id.clone(), LockType::Shared)
+        let release = self
+            .transaction_manager
+            .acquire_read_lock(tx_id, resource_id.clone())
+            .await
+            .map_err(|e| anyhow!("Failed to acquire lock: {e}"))?;
+
+        let rows = self.select_rows_with_access(query, access).await;
+        if release {
+            self.transaction_manager
+                .release_read_lock(tx_id, &resource_id)
+                .await
+                .map_err(|e| anyhow!("Failed to release lock: {e}"))?;
+        }
+        rows
+    }
+
+    /// Select a page of rows in primary key order after `after_key` within a transaction
+    ///
+    /// Like [`select_page_with_access`](Self::select_page_with_access), with
+    /// the table read as in
+    /// [`select_rows_acid_with_access`](Self::select_rows_acid_with_access).
+    /// Every page of a `Snapshot` transaction comes from the same snapshot,
+    /// so paging through a table while it is written neither skips nor
+    /// repeats rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if lock acquisition fails or query fails.
+    pub async fn select_page_acid_with_access(
+        &self,
+        tx_id: TransactionId,
+        query: &SelectQuery,
+        after_key: Option<&Value>,
+        limit: usize,
+        access: FieldAccess,
+    ) -> Result<(Vec<Row>, Option<Value>, QueryExecutionStats)> {
+        if let Some(snapshot) = self.transaction_snapshot(tx_id, &query.table).await? {
+            return self
+                .select_snapshot_page(query, after_key, limit, access, &snapshot)
+                .await;
+        }
+
+        let resource_id = format!("table:{}", query.table);
+        let release = self
+            .transaction_manager
+            .acquire_read_lock(tx_id, resource_id.clone())
             .await
             .map_err(|e| anyhow!("Failed to acquire lock: {e}"))?;
 
-        // Perform the select (now protected by lock)
-        self.select_rows(query).await
+        let page = self
+            .select_page_with_access(query, after_key, limit, access)
+            .await;
+        if release {
+            self.transaction_manager
+                .release_read_lock(tx_id, &resource_id)
+                .await
+                .map_err(|e| anyhow!("Failed to release lock: {e}"))?;
+        }
+        page
+    }
+
+    /// The rows of `table` a `Snapshot` transaction sees in place of the
+    /// stored ones, or `None` for other isolation levels
+    async fn transaction_snapshot(
+        &self,
+        tx_id: TransactionId,
+        table: &str,
+    ) -> Result<Option<SnapshotRows>> {
+        let versions = self
+            .transaction_manager
+            .snapshot_rows(tx_id, table)
+            .await
+            .map_err(|e| anyhow!("Failed to read snapshot: {e}"))?;
+        let Some(versions) = versions else {
+            return Ok(None);
+        };
+
+        let mut snapshot = SnapshotRows::new();
+        for (key, data) in versions {
+            let Ok(row_id) = key.parse::<RowId>() else {
+                continue;
+            };
+            let row = data
+                .map(|data| serde_json::from_slice::<Row>(&data))
+                .transpose()?;
+            snapshot.insert(row_id, row);
+        }
+        Ok(Some(snapshot))
     }
 
     /// Execute a full transaction with automatic commit/rollback
@@ -616,6 +928,18 @@ impl StorageEngine {
         self.commit_acid_transaction(tx_id).await
     }
 
+    /// Commit a transaction with the given `durability` (alias for
+    /// `commit_acid_transaction_with_durability`)
+    #[inline]
+    pub async fn commit_transaction_with_durability(
+        &mut self,
+        tx_id: TransactionId,
+        durability: Durability,
+    ) -> Result<CommitResult> {
+        self.commit_acid_transaction_with_durability(tx_id, durability)
+            .await
+    }
+
     /// Rollback a transaction (alias for `rollback_acid_transaction`)
     #[inline]
     pub async fn rollback_transaction(&mut self, tx_id: TransactionId) -> Result<()> {
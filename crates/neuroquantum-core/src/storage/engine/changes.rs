@@ -0,0 +1,97 @@
+//! Row change feed for live queries
+//!
+//! Committed inserts, updates and deletes are broadcast as [`RowChange`]s to
+//! every receiver obtained from [`StorageEngine::subscribe_changes`].
+//! Autocommit writes are published as they are applied; changes made inside an
+//! ACID transaction are published when it commits and never when it rolls back.
+//!
+//! Values of `ENCRYPTED` columns are redacted in published rows. A receiver
+//! that falls more than [`CHANGE_FEED_CAPACITY`] changes behind misses the
+//! oldest ones.
+//!
+//! Independently of the feed, every table carries a data version that changes
+//! whenever its rows or schema change, including uncommitted transactional
+//! writes and rollbacks. Caches of query results compare it to tell whether
+//! a result is still current.
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use super::StorageEngine;
+use crate::storage::row::Row;
+
+/// Number of changes buffered for each receiver of the change feed
+pub const CHANGE_FEED_CAPACITY: usize = 1024;
+
+/// Kind of change made to a row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RowChangeKind {
+    Inserted,
+    Updated,
+    Deleted,
+}
+
+/// A committed change to a single row
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RowChange {
+    /// Table the row belongs to
+    pub table: String,
+    pub kind: RowChangeKind,
+    /// The row after the change, or the removed row for a delete
+    pub row: Row,
+    /// The row before an update
+    pub previous: Option<Row>,
+}
+
+impl StorageEngine {
+    /// Receive every row change committed from now on
+    #[must_use]
+    pub fn subscribe_changes(&self) -> broadcast::Receiver<RowChange> {
+        self.change_feed.subscribe()
+    }
+
+    /// Current data version of `table`
+    ///
+    /// Versions are drawn from one engine-wide counter, so a table that is
+    /// dropped and created again never returns to an earlier version. Tables
+    /// that were never written since the engine was opened report 0.
+    #[must_use]
+    pub fn table_version(&self, table: &str) -> u64 {
+        self.table_versions.get(table).copied().unwrap_or(0)
+    }
+
+    /// Give `table` a new data version after a write
+    pub(crate) fn bump_table_version(&mut self, table: &str) {
+        self.last_table_version += 1;
+        self.table_versions
+            .insert(table.to_string(), self.last_table_version);
+    }
+
+    /// Publish a committed change of stored `row`
+    pub(crate) fn publish_change(
+        &self,
+        table: &str,
+        kind: RowChangeKind,
+        row: &Row,
+        previous: Option<&Row>,
+    ) {
+        if self.change_feed.receiver_count() == 0 {
+            return;
+        }
+        let view = |row: &Row| {
+            let mut row = row.clone();
+            if let Some(schema) = self.metadata.tables.get(table) {
+                Self::redact_fields(schema, &mut row);
+            }
+            row
+        };
+        // Sending only fails when every receiver has been dropped meanwhile
+        let _ = self.change_feed.send(RowChange {
+            table: table.to_string(),
+            kind,
+            row: view(row),
+            previous: previous.map(view),
+        });
+    }
+}
@@ -3,20 +3,37 @@
 //! This module implements INSERT, SELECT, UPDATE, and DELETE operations.
 
 use std::collections::HashMap;
+use std::ops::Bound;
+use std::sync::atomic::Ordering;
 
 use anyhow::{anyhow, Result};
 use tracing::{debug, instrument};
 
-use super::StorageEngine;
+use super::{RowChangeKind, StorageEngine};
 use crate::error::CoreError;
+use crate::storage::btree::Key;
 use crate::storage::query::{
-    ComparisonOperator, Condition, DeleteQuery, SelectQuery, UpdateQuery, WhereClause,
+    ComparisonOperator, Condition, DeleteQuery, FieldAccess, OrderBy, SelectQuery, SortDirection,
+    UpdateQuery, WhereClause,
 };
 use crate::storage::row::Row;
 use crate::storage::stats::QueryExecutionStats;
 use crate::storage::transaction_log::Operation;
 use crate::storage::types::{DataType, RowId, TableSchema, Value};
 
+/// How a select presents the values of `ENCRYPTED` columns
+#[derive(Debug, Clone, Copy)]
+enum ReadMode {
+    /// Return rows exactly as stored (ciphertext), for internal read-modify-write paths
+    Stored,
+    /// Return rows as seen by a reader with the given access
+    Access(FieldAccess),
+}
+
+/// Rows a `Snapshot` transaction sees in place of the stored ones, by row ID;
+/// `None` hides a stored row
+pub(crate) type SnapshotRows = HashMap<RowId, Option<Row>>;
+
 impl StorageEngine {
     /// Insert a new row into the specified table
     ///
@@ -71,10 +88,14 @@ impl StorageEngine {
 
         // Validate row against schema
         self.validate_row(&schema, &row)?;
+        self.check_unique_indexes(table, &row)?;
 
         // Validate foreign key constraints
         self.validate_foreign_key_constraints(&schema, &row).await?;
 
+        // Seal ENCRYPTED columns before the row reaches disk, cache or WAL
+        self.encrypt_fields(&schema, &mut row)?;
+
         // Compress row data using DNA compression
         let compressed_data = self.compress_row(&row).await?;
 
@@ -94,6 +115,9 @@ impl StorageEngine {
             data: row.clone(),
         };
         self.log_operation(operation).await?;
+        self.record_row_version(table, row.id, None, Some(&row))
+            .await?;
+        self.publish_change(table, RowChangeKind::Inserted, &row, None);
 
         // Add to cache (moves row, so do this last)
         let row_id = row.id;
@@ -105,6 +129,9 @@ impl StorageEngine {
 
     /// Select rows matching the given query
     ///
+    /// Values of `ENCRYPTED` columns are redacted; use
+    /// [`select_rows_with_access`](Self::select_rows_with_access) to decrypt them.
+    ///
     /// # Errors
     ///
     /// Returns an error if the table doesn't exist or query execution fails.
@@ -114,8 +141,63 @@ impl StorageEngine {
         Ok(rows)
     }
 
+    /// Select rows matching the given query, presenting `ENCRYPTED` columns per `access`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table doesn't exist, query execution fails, or
+    /// decryption is requested without a configured field key manager.
+    #[instrument(level = "debug", skip(self, query), fields(table = %query.table))]
+    pub async fn select_rows_with_access(
+        &self,
+        query: &SelectQuery,
+        access: FieldAccess,
+    ) -> Result<Vec<Row>> {
+        let (rows, _stats) = self
+            .select_rows_internal(query, ReadMode::Access(access), None, None)
+            .await?;
+        Ok(rows)
+    }
+
+    /// Select rows as a `Snapshot` transaction sees them, with `snapshot`
+    /// laid over the stored rows
+    pub(crate) async fn select_snapshot_rows(
+        &self,
+        query: &SelectQuery,
+        access: FieldAccess,
+        snapshot: &SnapshotRows,
+    ) -> Result<Vec<Row>> {
+        let (rows, _stats) = self
+            .select_rows_internal(query, ReadMode::Access(access), None, Some(snapshot))
+            .await?;
+        Ok(rows)
+    }
+
+    /// Select rows matching the given query, scanning the index named by an optimizer hint
+    ///
+    /// Like [`select_rows_with_access`](Self::select_rows_with_access), but
+    /// `index_hint` replaces the engine's own choice of secondary index when
+    /// it can answer the query; otherwise it is ignored with a warning.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table doesn't exist, query execution fails, or
+    /// decryption is requested without a configured field key manager.
+    #[instrument(level = "debug", skip(self, query), fields(table = %query.table))]
+    pub async fn select_rows_with_index_hint(
+        &self,
+        query: &SelectQuery,
+        access: FieldAccess,
+        index_hint: Option<&str>,
+    ) -> Result<(Vec<Row>, QueryExecutionStats)> {
+        self.select_rows_internal(query, ReadMode::Access(access), index_hint, None)
+            .await
+    }
+
     /// Select rows matching the given query with execution statistics
     ///
+    /// Values of `ENCRYPTED` columns are redacted.
+    ///
     /// # Errors
     ///
     /// Returns an error if the table doesn't exist or query execution fails.
@@ -123,6 +205,28 @@ impl StorageEngine {
     pub async fn select_rows_with_stats(
         &self,
         query: &SelectQuery,
+    ) -> Result<(Vec<Row>, QueryExecutionStats)> {
+        self.select_rows_internal(query, ReadMode::Access(FieldAccess::Redacted), None, None)
+            .await
+    }
+
+    /// Select rows as stored on disk, for read-modify-write paths
+    ///
+    /// Filtering and ordering still see decrypted values when a field key
+    /// manager is configured, but the returned rows keep their ciphertext.
+    pub(crate) async fn select_stored_rows(&self, query: &SelectQuery) -> Result<Vec<Row>> {
+        let (rows, _stats) = self
+            .select_rows_internal(query, ReadMode::Stored, None, None)
+            .await?;
+        Ok(rows)
+    }
+
+    async fn select_rows_internal(
+        &self,
+        query: &SelectQuery,
+        mode: ReadMode,
+        index_hint: Option<&str>,
+        snapshot: Option<&SnapshotRows>,
     ) -> Result<(Vec<Row>, QueryExecutionStats)> {
         debug!("🔍 Selecting rows from table: {}", query.table);
 
@@ -144,17 +248,73 @@ impl StorageEngine {
             stats.index_scan = false;
         }
 
-        // Load all rows for the table
-        let mut rows = self.load_table_rows(&query.table).await?;
+        // Narrow the rows through a secondary index; indexes only know the
+        // rows as stored, so snapshot reads scan the table
+        let index_plan = if snapshot.is_some() {
+            None
+        } else {
+            self.index_plan(schema, query, index_hint)
+        };
+        if let Some(plan) = &index_plan {
+            stats.indexes_used.push(plan.access().index_name);
+            stats.index_scan = true;
+            // Read-modify-write paths need whole stored rows
+            stats.index_only = plan.covering && matches!(mode, ReadMode::Access(_));
+        }
+
+        let mut rows = match &index_plan {
+            // Covering index: build the rows from the index entries alone
+            | Some(plan) if stats.index_only => plan.covered_rows()?,
+            | _ => {
+                // Load all rows for the table
+                let mut rows = self.load_table_rows(&query.table).await?;
+                if let Some(plan) = &index_plan {
+                    let row_ids = plan.row_ids();
+                    rows.retain(|row| row_ids.contains(&row.id));
+                }
+                if let Some(snapshot) = snapshot {
+                    rows.retain(|row| !snapshot.contains_key(&row.id));
+                    rows.extend(snapshot.values().flatten().cloned());
+                    rows.sort_by_key(|row| row.id);
+                }
+
+                // Track cache hits/misses during row loading
+                for row in &rows {
+                    if self.row_cache.contains(&row.id) {
+                        stats.cache_hits += 1;
+                    } else {
+                        stats.cache_misses += 1;
+                    }
+                }
+                self.cache_hits
+                    .fetch_add(stats.cache_hits as u64, Ordering::Relaxed);
+                self.cache_misses
+                    .fetch_add(stats.cache_misses as u64, Ordering::Relaxed);
+                rows
+            },
+        };
         stats.rows_examined = rows.len();
 
-        // Track cache hits/misses during row loading
-        for row in &rows {
-            if self.row_cache.contains(&row.id) {
-                stats.cache_hits += 1;
-            } else {
-                stats.cache_misses += 1;
-            }
+        // Present ENCRYPTED columns before filtering so predicates see plaintext;
+        // covered rows hold only indexed columns, which are never encrypted
+        let encrypted = schema.has_encrypted_columns() && !stats.index_only;
+        let mut stored_rows = HashMap::new();
+        if encrypted {
+            let access = match mode {
+                | ReadMode::Access(access) => access,
+                | ReadMode::Stored => {
+                    stored_rows = rows.iter().map(|row| (row.id, row.clone())).collect();
+                    if self.field_keys.is_some() {
+                        FieldAccess::Decrypted
+                    } else {
+                        FieldAccess::Redacted
+                    }
+                },
+            };
+            rows = rows
+                .into_iter()
+                .map(|row| self.field_view(schema, row, access))
+                .collect::<Result<Vec<_>>>()?;
         }
 
         // Apply WHERE clause
@@ -176,6 +336,14 @@ impl StorageEngine {
             rows.truncate(limit as usize);
         }
 
+        // Swap the views back for the stored rows they were built from
+        if encrypted && matches!(mode, ReadMode::Stored) {
+            rows = rows
+                .into_iter()
+                .filter_map(|row| stored_rows.remove(&row.id))
+                .collect();
+        }
+
         // Project columns
         if !query.columns.is_empty() && !query.columns.contains(&"*".to_string()) {
             rows = self.project_columns(rows, &query.columns)?;
@@ -185,6 +353,212 @@ impl StorageEngine {
         Ok((rows, stats))
     }
 
+    /// Select a page of rows ordered by primary key, resuming after `after_key`
+    ///
+    /// Keyset pagination: each page continues strictly after the last-seen
+    /// primary key, so pages stay stable while rows are inserted concurrently
+    /// (unlike OFFSET, which shifts). Returns the page, the key to resume
+    /// from (or `None` once the table is exhausted), and execution statistics.
+    ///
+    /// The page is read with a range scan over the table's primary key order,
+    /// loading only the rows on it. Tables with an `ENCRYPTED` primary key, or
+    /// an `after_key` of another type than the primary key, fall back to
+    /// filtering and sorting the whole table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table doesn't exist or query execution fails.
+    #[instrument(level = "debug", skip(self, after_key), fields(table = %table))]
+    pub async fn select_page(
+        &self,
+        table: &str,
+        after_key: Option<&Value>,
+        limit: usize,
+    ) -> Result<(Vec<Row>, Option<Value>, QueryExecutionStats)> {
+        let query = SelectQuery {
+            table: table.to_string(),
+            columns: vec!["*".to_string()],
+            where_clause: None,
+            order_by: None,
+            limit: None,
+            offset: None,
+        };
+        self.select_page_with_access(&query, after_key, limit, FieldAccess::Redacted)
+            .await
+    }
+
+    /// Select a page of the rows matching `query`, in primary key order after `after_key`
+    ///
+    /// Like [`select_page`](Self::select_page), but only rows matching the
+    /// WHERE clause of `query` are returned, projected onto its columns and
+    /// with `ENCRYPTED` columns presented per `access`. The ORDER BY, LIMIT
+    /// and OFFSET of `query` are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table doesn't exist, query execution fails, or
+    /// decryption is requested without a configured field key manager.
+    #[instrument(level = "debug", skip(self, query, after_key), fields(table = %query.table))]
+    pub async fn select_page_with_access(
+        &self,
+        query: &SelectQuery,
+        after_key: Option<&Value>,
+        limit: usize,
+        access: FieldAccess,
+    ) -> Result<(Vec<Row>, Option<Value>, QueryExecutionStats)> {
+        self.select_page_internal(query, after_key, limit, access, None)
+            .await
+    }
+
+    /// Select a page as a `Snapshot` transaction sees it, with `snapshot`
+    /// laid over the stored rows
+    ///
+    /// Rows the snapshot replaces are skipped in the primary key order and
+    /// their snapshot versions merged in at their own keys.
+    pub(crate) async fn select_snapshot_page(
+        &self,
+        query: &SelectQuery,
+        after_key: Option<&Value>,
+        limit: usize,
+        access: FieldAccess,
+        snapshot: &SnapshotRows,
+    ) -> Result<(Vec<Row>, Option<Value>, QueryExecutionStats)> {
+        self.select_page_internal(query, after_key, limit, access, Some(snapshot))
+            .await
+    }
+
+    async fn select_page_internal(
+        &self,
+        query: &SelectQuery,
+        after_key: Option<&Value>,
+        limit: usize,
+        access: FieldAccess,
+        snapshot: Option<&SnapshotRows>,
+    ) -> Result<(Vec<Row>, Option<Value>, QueryExecutionStats)> {
+        let schema = self
+            .metadata
+            .tables
+            .get(&query.table)
+            .ok_or_else(|| anyhow!("Table '{}' does not exist", query.table))?;
+        let primary_key = &schema.primary_key;
+
+        let (mut rows, stats) = match self.primary_key_lower_bound(&query.table, after_key) {
+            | Some(lower) => {
+                let mut stats = QueryExecutionStats::default();
+                stats
+                    .indexes_used
+                    .push(format!("{}_{primary_key}", query.table));
+                stats.index_scan = true;
+
+                let mut stored = self
+                    .primary_key_range(&query.table, &lower)
+                    .filter(|(_, row_id)| !snapshot.is_some_and(|s| s.contains_key(row_id)))
+                    .peekable();
+                let mut versions: Vec<(Key, &Row)> = snapshot
+                    .into_iter()
+                    .flat_map(|snapshot| snapshot.values().flatten())
+                    .filter_map(|row| Some((self.primary_key_entry(&query.table, row)?, row)))
+                    .filter(|(key, _)| match &lower {
+                        | Bound::Included(start) => key >= start,
+                        | Bound::Excluded(start) => key > start,
+                        | Bound::Unbounded => true,
+                    })
+                    .collect();
+                versions.sort_by(|a, b| a.0.cmp(&b.0));
+                let mut versions = versions.into_iter().peekable();
+
+                // Fetch one extra row to learn whether another page exists
+                let mut rows = Vec::with_capacity(limit + 1);
+                while rows.len() <= limit {
+                    let from_store = match (stored.peek(), versions.peek()) {
+                        | (Some((stored_key, _)), Some((version_key, _))) => {
+                            *stored_key <= version_key
+                        },
+                        | (stored_next, _) => stored_next.is_some(),
+                    };
+                    let row = if from_store {
+                        let Some((_, row_id)) = stored.next() else {
+                            break;
+                        };
+                        if self.row_cache.contains(&row_id) {
+                            stats.cache_hits += 1;
+                        } else {
+                            stats.cache_misses += 1;
+                        }
+                        let Some(row) = self.load_row(row_id).await? else {
+                            continue;
+                        };
+                        row
+                    } else {
+                        let Some((_, row)) = versions.next() else {
+                            break;
+                        };
+                        row.clone()
+                    };
+                    stats.rows_examined += 1;
+
+                    let row = if schema.has_encrypted_columns() {
+                        self.field_view(schema, row, access)?
+                    } else {
+                        row
+                    };
+                    let row = match &query.where_clause {
+                        | Some(where_clause) => {
+                            self.apply_where_clause(vec![row], where_clause)?.pop()
+                        },
+                        | None => Some(row),
+                    };
+                    rows.extend(row);
+                }
+                self.cache_hits
+                    .fetch_add(stats.cache_hits as u64, Ordering::Relaxed);
+                self.cache_misses
+                    .fetch_add(stats.cache_misses as u64, Ordering::Relaxed);
+                (rows, stats)
+            },
+            | None => {
+                let mut where_clause = query.where_clause.clone().unwrap_or(WhereClause {
+                    conditions: Vec::new(),
+                });
+                if let Some(key) = after_key {
+                    where_clause.conditions.push(Condition {
+                        field: primary_key.clone(),
+                        operator: ComparisonOperator::GreaterThan,
+                        value: key.clone(),
+                    });
+                }
+                let query = SelectQuery {
+                    table: query.table.clone(),
+                    columns: vec!["*".to_string()],
+                    where_clause: (!where_clause.conditions.is_empty()).then_some(where_clause),
+                    order_by: Some(OrderBy {
+                        field: primary_key.clone(),
+                        direction: SortDirection::Ascending,
+                    }),
+                    limit: Some(limit as u64 + 1),
+                    offset: None,
+                };
+                self.select_rows_internal(&query, ReadMode::Access(access), None, snapshot)
+                    .await?
+            },
+        };
+
+        let next_key = if rows.len() > limit {
+            rows.truncate(limit);
+            rows.last()
+                .and_then(|row| row.fields.get(primary_key).cloned())
+        } else {
+            None
+        };
+
+        // Project columns once the resume key is known
+        if !query.columns.is_empty() && !query.columns.contains(&"*".to_string()) {
+            rows = self.project_columns(rows, &query.columns)?;
+        }
+
+        Ok((rows, next_key, stats))
+    }
+
     /// Update rows matching the given query
     ///
     /// Handles foreign key constraints:
@@ -211,7 +585,7 @@ impl StorageEngine {
             offset: None,
         };
 
-        let existing_rows = self.select_rows(&select_query).await?;
+        let existing_rows = self.select_stored_rows(&select_query).await?;
         let mut updated_count = 0;
         let mut updated_rows = Vec::new();
 
@@ -227,6 +601,7 @@ impl StorageEngine {
 
         for mut row in existing_rows {
             let old_row = row.clone();
+            self.decrypt_fields(&schema, &mut row)?;
 
             // Apply updates
             for (field, new_value) in &query.set_values {
@@ -236,6 +611,7 @@ impl StorageEngine {
 
             // Validate updated row
             self.validate_row(&schema, &row)?;
+            self.check_unique_indexes(&query.table, &row)?;
 
             // Validate foreign key constraints for the updated row
             self.validate_foreign_key_constraints(&schema, &row).await?;
@@ -244,12 +620,21 @@ impl StorageEngine {
             self.handle_update_foreign_key_constraints(&query.table, &old_row, &row)
                 .await?;
 
+            self.encrypt_fields(&schema, &mut row)?;
+
             // Update compressed data
             let compressed_data = self.compress_row(&row).await?;
             self.compressed_blocks.insert(row.id, compressed_data);
 
+            // Re-key the row in secondary indexes
+            self.remove_from_secondary_indexes(&query.table, &old_row);
+            self.insert_into_secondary_indexes(&query.table, &row);
+
             // Keep track of updated rows for file rewrite (need clone here)
             updated_rows.push(row.clone());
+            self.record_row_version(&query.table, row.id, Some(&old_row), Some(&row))
+                .await?;
+            self.publish_change(&query.table, RowChangeKind::Updated, &row, Some(&old_row));
 
             // Log operation (consumes old_row, clones row for new_data)
             let operation = Operation::Update {
@@ -304,13 +689,13 @@ impl StorageEngine {
             offset: None,
         };
 
-        let rows_to_delete = self.select_rows(&select_query).await?;
+        let rows_to_delete = self.select_stored_rows(&select_query).await?;
         let deleted_count = rows_to_delete.len();
         let mut deleted_row_ids = Vec::new();
 
         // Handle foreign key constraints for rows to be deleted
         for row in &rows_to_delete {
-            self.handle_delete_foreign_key_constraints(&query.table, row)
+            self.handle_delete_foreign_key_constraints(&query.table, row, None)
                 .await?;
         }
 
@@ -337,6 +722,9 @@ impl StorageEngine {
                 })?
                 .clone();
             self.update_indexes_for_delete(&schema, &row)?;
+            self.record_row_version(&query.table, row.id, Some(&row), None)
+                .await?;
+            self.publish_change(&query.table, RowChangeKind::Deleted, &row, None);
 
             // Log operation
             let operation = Operation::Delete {
@@ -374,13 +762,13 @@ impl StorageEngine {
                 offset: None,
             };
 
-            let rows_to_delete = self.select_rows(&select_query).await?;
+            let rows_to_delete = self.select_stored_rows(&select_query).await?;
             let deleted_count = rows_to_delete.len();
             let mut deleted_row_ids = Vec::new();
 
             // Handle foreign key constraints for rows to be deleted
             for row in &rows_to_delete {
-                self.handle_delete_foreign_key_constraints(&query.table, row)
+                self.handle_delete_foreign_key_constraints(&query.table, row, None)
                     .await?;
             }
 
@@ -407,6 +795,9 @@ impl StorageEngine {
                     })?
                     .clone();
                 self.update_indexes_for_delete(&schema, &row)?;
+                self.record_row_version(&query.table, row.id, Some(&row), None)
+                    .await?;
+                self.publish_change(&query.table, RowChangeKind::Deleted, &row, None);
 
                 // Log operation
                 let operation = Operation::Delete {
@@ -427,75 +818,239 @@ impl StorageEngine {
         })
     }
 
+    /// Record a row write made outside any transaction in the transaction
+    /// manager's version store, so active snapshots keep seeing `before`
+    async fn record_row_version(
+        &self,
+        table: &str,
+        row_id: RowId,
+        before: Option<&Row>,
+        after: Option<&Row>,
+    ) -> Result<()> {
+        let before = before.map(serde_json::to_vec).transpose()?;
+        let after = after.map(serde_json::to_vec).transpose()?;
+        self.transaction_manager
+            .record_stored_row(table, &row_id.to_string(), before, after)
+            .await;
+        Ok(())
+    }
+
     /// Store data with a key (used by the main API)
     ///
     /// # Errors
     ///
     /// Returns an error if storage fails.
     pub async fn store(&mut self, key: &str, data: &[u8]) -> Result<()> {
-        // Create a simple row structure for generic storage
-        // Note: 'id' is not set here - it will be auto-generated by insert_row
+        self.store_entry(key, data).await?;
+
+        // A plain store replaces any previous TTL
+        if self.metadata.key_expirations.remove(key).is_some() {
+            self.save_metadata().await?;
+        }
+        Ok(())
+    }
+
+    /// Store data with a key that expires at `expires_at`
+    ///
+    /// The expiry is kept in the database metadata, so it survives restarts.
+    /// Expired keys are no longer returned by [`retrieve`](Self::retrieve)
+    /// and are deleted by [`purge_expired_keys`](Self::purge_expired_keys).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if storage fails.
+    pub async fn store_with_expiry(
+        &mut self,
+        key: &str,
+        data: &[u8],
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<()> {
+        self.store_entry(key, data).await?;
+        self.metadata
+            .key_expirations
+            .insert(key.to_string(), expires_at);
+        self.save_metadata().await
+    }
+
+    /// Expiry time of a key stored with a TTL
+    #[must_use]
+    pub fn key_expiry(&self, key: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.metadata.key_expirations.get(key).copied()
+    }
+
+    /// Check if a key is past its expiry time
+    #[must_use]
+    pub fn is_key_expired(&self, key: &str) -> bool {
+        self.key_expiry(key)
+            .is_some_and(|expires_at| expires_at <= chrono::Utc::now())
+    }
+
+    /// Keys past their expiry time that haven't been purged yet
+    #[must_use]
+    pub fn expired_keys(&self) -> Vec<String> {
+        let now = chrono::Utc::now();
+        self.metadata
+            .key_expirations
+            .iter()
+            .filter(|(_, &expires_at)| expires_at <= now)
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    /// Delete every key past its expiry time
+    ///
+    /// Returns the number of keys removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if deleting an entry or saving the metadata fails.
+    pub async fn purge_expired_keys(&mut self) -> Result<usize> {
+        let expired = self.expired_keys();
+        if expired.is_empty() {
+            return Ok(0);
+        }
+
+        for key in &expired {
+            let query = DeleteQuery {
+                table: "_storage".to_string(),
+                where_clause: Some(WhereClause {
+                    conditions: vec![Condition {
+                        field: "key".to_string(),
+                        operator: ComparisonOperator::Equal,
+                        value: Value::text(key),
+                    }],
+                }),
+            };
+            self.delete_rows(&query).await?;
+            self.metadata.key_expirations.remove(key);
+        }
+        self.save_metadata().await?;
+
+        debug!("⏳ Purged {} expired keys", expired.len());
+        Ok(expired.len())
+    }
+
+    /// Insert a key-value entry into the generic storage table
+    async fn store_entry(&mut self, key: &str, data: &[u8]) -> Result<()> {
+        self.ensure_storage_table().await?;
+        self.insert_row("_storage", Self::storage_entry_row(key, data))
+            .await?;
+        Ok(())
+    }
+
+    /// Store several key-value entries in one transaction
+    ///
+    /// Either all entries are stored or, if any insert fails, none are.
+    /// Like [`store`](Self::store), this replaces any previous TTL of the keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an insert or the commit fails.
+    pub async fn store_batch(&mut self, entries: &[(String, Vec<u8>)]) -> Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        self.ensure_storage_table().await?;
+
+        let tx_id = self.begin_transaction().await?;
+        for (key, data) in entries {
+            let row = Self::storage_entry_row(key, data);
+            if let Err(e) = self.insert_row_transactional(tx_id, "_storage", row).await {
+                self.rollback_transaction(tx_id).await?;
+                return Err(e);
+            }
+        }
+        self.commit_transaction(tx_id).await?;
+
+        let mut expiry_cleared = false;
+        for (key, _) in entries {
+            expiry_cleared |= self.metadata.key_expirations.remove(key).is_some();
+        }
+        if expiry_cleared {
+            self.save_metadata().await?;
+        }
+
+        debug!("📦 Stored batch of {} entries", entries.len());
+        Ok(())
+    }
+
+    /// Row of the generic storage table holding `data` under `key`
+    fn storage_entry_row(key: &str, data: &[u8]) -> Row {
+        // Note: 'id' is not set here - it will be auto-generated on insert
         let mut fields = HashMap::new();
         fields.insert("key".to_string(), Value::text(key));
         fields.insert("data".to_string(), Value::binary(data));
 
-        let row = Row {
+        Row {
             id: 0, // Will be set by auto-increment
             fields,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
-        };
+        }
+    }
 
-        // Ensure we have a generic storage table
-        if !self.metadata.tables.contains_key("_storage") {
-            use crate::storage::id_generation::IdGenerationStrategy;
-            use crate::storage::types::ColumnDefinition;
-
-            let schema = TableSchema {
-                name: "_storage".to_string(),
-                columns: vec![
-                    ColumnDefinition {
-                        name: "id".to_string(),
-                        data_type: DataType::BigSerial,
-                        nullable: false,
-                        default_value: None,
-                        auto_increment: true,
-                    },
-                    ColumnDefinition {
-                        name: "key".to_string(),
-                        data_type: DataType::Text,
-                        nullable: false,
-                        default_value: None,
-                        auto_increment: false,
-                    },
-                    ColumnDefinition {
-                        name: "data".to_string(),
-                        data_type: DataType::Binary,
-                        nullable: false,
-                        default_value: None,
-                        auto_increment: false,
-                    },
-                ],
-                primary_key: "id".to_string(),
-                created_at: chrono::Utc::now(),
-                version: 1,
-                auto_increment_columns: HashMap::new(),
-                id_strategy: IdGenerationStrategy::AutoIncrement,
-                foreign_keys: Vec::new(),
-            };
-            self.create_table(schema).await?;
+    /// Create the generic key-value storage table if it doesn't exist yet
+    async fn ensure_storage_table(&mut self) -> Result<()> {
+        use crate::storage::id_generation::IdGenerationStrategy;
+        use crate::storage::types::ColumnDefinition;
+
+        if self.metadata.tables.contains_key("_storage") {
+            return self.ensure_storage_key_index().await;
         }
 
-        self.insert_row("_storage", row).await?;
-        Ok(())
+        let schema = TableSchema {
+            name: "_storage".to_string(),
+            columns: vec![
+                ColumnDefinition {
+                    name: "id".to_string(),
+                    data_type: DataType::BigSerial,
+                    nullable: false,
+                    default_value: None,
+                    auto_increment: true,
+                    ..Default::default()
+                },
+                ColumnDefinition {
+                    name: "key".to_string(),
+                    data_type: DataType::Text,
+                    nullable: false,
+                    default_value: None,
+                    auto_increment: false,
+                    ..Default::default()
+                },
+                ColumnDefinition {
+                    name: "data".to_string(),
+                    data_type: DataType::Binary,
+                    nullable: false,
+                    default_value: None,
+                    auto_increment: false,
+                    ..Default::default()
+                },
+            ],
+            primary_key: "id".to_string(),
+            created_at: chrono::Utc::now(),
+            version: 1,
+            auto_increment_columns: HashMap::new(),
+            id_strategy: IdGenerationStrategy::AutoIncrement,
+            foreign_keys: Vec::new(),
+            ..Default::default()
+        };
+        self.create_table(schema).await?;
+        self.ensure_storage_key_index().await
     }
 
     /// Retrieve data by key (used by the main API)
     ///
+    /// Returns `None` for keys past their expiry time.
+    ///
     /// # Errors
     ///
     /// Returns an error if the query fails.
     pub async fn retrieve(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        // Expired keys are gone even if they haven't been purged yet
+        if self.is_key_expired(key) {
+            return Ok(None);
+        }
+
         // Query for the key in the generic storage table
         let query = SelectQuery {
             table: "_storage".to_string(),
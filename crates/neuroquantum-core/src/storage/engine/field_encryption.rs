@@ -0,0 +1,154 @@
+//! Field-level encryption for `StorageEngine`
+//!
+//! Values of columns declared `ENCRYPTED` are serialized and sealed with the
+//! post-quantum [`KeyManager`] before rows are compressed and written to disk.
+//! Readers either receive the decrypted value or a redacted placeholder,
+//! depending on the [`FieldAccess`] requested for the query.
+//!
+//! The key ring is kept in the data directory, sealed with the data-at-rest
+//! master key, so encrypted values stay readable across restarts.
+
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+
+use super::StorageEngine;
+use crate::pqcrypto::KeyManager;
+use crate::storage::query::FieldAccess;
+use crate::storage::row::Row;
+use crate::storage::types::{TableSchema, Value};
+
+/// Placeholder returned in place of encrypted values for unauthorized readers
+pub const REDACTED_PLACEHOLDER: &str = "[REDACTED]";
+
+/// File in the data directory holding the sealed field key ring
+const FIELD_KEYS_FILE: &str = "field_keys.bin";
+
+impl StorageEngine {
+    /// Attach the key manager used to encrypt `ENCRYPTED` column values
+    pub fn set_field_key_manager(&mut self, keys: Arc<KeyManager>) {
+        self.field_keys = Some(keys);
+    }
+
+    /// Key manager used for `ENCRYPTED` columns, if one is configured
+    #[must_use]
+    pub fn field_key_manager(&self) -> Option<&Arc<KeyManager>> {
+        self.field_keys.as_ref()
+    }
+
+    /// Attach the key manager persisted in the data directory
+    ///
+    /// A new key manager is generated and saved on first use.
+    pub async fn load_field_key_manager(&mut self) -> Result<()> {
+        let master = self.encryption_manager.as_ref().ok_or_else(|| {
+            anyhow!("Cannot persist field keys: encryption at rest is not initialized")
+        })?;
+        let path = self.data_dir.join(FIELD_KEYS_FILE);
+
+        let keys = if path.exists() {
+            KeyManager::load(&path, master)
+                .await
+                .map_err(|e| anyhow!("Failed to load field keys: {e}"))?
+        } else {
+            let keys = KeyManager::new();
+            keys.save(&path, master)
+                .await
+                .map_err(|e| anyhow!("Failed to save field keys: {e}"))?;
+            keys
+        };
+        self.field_keys = Some(Arc::new(keys));
+        Ok(())
+    }
+
+    /// Persist the attached key manager, e.g. after a key rotation
+    pub async fn save_field_key_manager(&self) -> Result<()> {
+        let (Some(master), Some(keys)) = (&self.encryption_manager, &self.field_keys) else {
+            return Err(anyhow!("No persistent field key manager is configured"));
+        };
+        keys.save(&self.data_dir.join(FIELD_KEYS_FILE), master)
+            .await
+            .map_err(|e| anyhow!("Failed to save field keys: {e}"))
+    }
+
+    /// Encrypt the values of all `ENCRYPTED` columns in `row` in place
+    pub(crate) fn encrypt_fields(&self, schema: &TableSchema, row: &mut Row) -> Result<()> {
+        if !schema.has_encrypted_columns() {
+            return Ok(());
+        }
+        let keys = self.field_keys.as_ref().ok_or_else(|| {
+            anyhow!(
+                "Table '{}' has encrypted columns but no field key manager is configured",
+                schema.name
+            )
+        })?;
+
+        for column in schema.columns.iter().filter(|c| c.encrypted) {
+            let Some(value) = row.fields.get_mut(&column.name) else {
+                continue;
+            };
+            if matches!(value, Value::Null) {
+                continue;
+            }
+            let plaintext = bincode::serialize(value)
+                .map_err(|e| anyhow!("Failed to serialize field '{}': {e}", column.name))?;
+            let sealed = keys
+                .encrypt(&plaintext)
+                .map_err(|e| anyhow!("Failed to encrypt field '{}': {e}", column.name))?;
+            *value = Value::Binary(Arc::new(sealed));
+        }
+        Ok(())
+    }
+
+    /// Decrypt the values of all `ENCRYPTED` columns in `row` in place
+    pub(crate) fn decrypt_fields(&self, schema: &TableSchema, row: &mut Row) -> Result<()> {
+        if !schema.has_encrypted_columns() {
+            return Ok(());
+        }
+        let keys = self.field_keys.as_ref().ok_or_else(|| {
+            anyhow!(
+                "Cannot decrypt table '{}': no field key manager is configured",
+                schema.name
+            )
+        })?;
+
+        for column in schema.columns.iter().filter(|c| c.encrypted) {
+            let Some(value) = row.fields.get_mut(&column.name) else {
+                continue;
+            };
+            let Value::Binary(sealed) = value else {
+                continue;
+            };
+            let plaintext = keys
+                .decrypt(sealed)
+                .map_err(|e| anyhow!("Failed to decrypt field '{}': {e}", column.name))?;
+            *value = bincode::deserialize(&plaintext)
+                .map_err(|e| anyhow!("Failed to deserialize field '{}': {e}", column.name))?;
+        }
+        Ok(())
+    }
+
+    /// Replace the values of all `ENCRYPTED` columns in `row` with a placeholder
+    pub(crate) fn redact_fields(schema: &TableSchema, row: &mut Row) {
+        for column in schema.columns.iter().filter(|c| c.encrypted) {
+            if let Some(value) = row.fields.get_mut(&column.name) {
+                if !matches!(value, Value::Null) {
+                    *value = Value::text(REDACTED_PLACEHOLDER);
+                }
+            }
+        }
+    }
+
+    /// Build the reader-facing view of a stored row
+    pub(crate) fn field_view(
+        &self,
+        schema: &TableSchema,
+        mut row: Row,
+        access: FieldAccess,
+    ) -> Result<Row> {
+        match access {
+            | FieldAccess::Redacted => Self::redact_fields(schema, &mut row),
+            | FieldAccess::Decrypted => self.decrypt_fields(schema, &mut row)?,
+        }
+        Ok(row)
+    }
+}
@@ -2,15 +2,63 @@
 //!
 //! This module implements foreign key validation and referential actions
 //! (ON UPDATE/ON DELETE CASCADE, SET NULL, etc.).
+//!
+//! The referencing columns of each foreign key are indexed when the table is
+//! created, so finding the rows that reference a parent row is an index
+//! lookup rather than a scan of the child table.
+
+use std::collections::BTreeMap;
 
 use anyhow::{anyhow, Result};
 
 use super::StorageEngine;
-use crate::storage::query::{ComparisonOperator, Condition, DeleteQuery, SelectQuery, WhereClause};
+use crate::storage::query::{
+    ComparisonOperator, Condition, DeleteQuery, SelectQuery, UpdateQuery, WhereClause,
+};
 use crate::storage::row::Row;
-use crate::storage::types::{ForeignKeyConstraint, ReferentialAction, TableSchema, Value};
+use crate::storage::types::{
+    ForeignKeyConstraint, IndexDefinition, ReferentialAction, TableSchema, Value,
+};
+use crate::transaction::TransactionId;
 
 impl StorageEngine {
+    /// Index the referencing columns of the foreign keys of a new table
+    ///
+    /// Each foreign key gets an index named `<table>_<columns>_fkey`, unless
+    /// an index of the table already starts with its columns. `ENCRYPTED`
+    /// columns cannot be indexed and are left to table scans.
+    pub(crate) fn create_foreign_key_indexes(&mut self, schema: &TableSchema) {
+        for fk in &schema.foreign_keys {
+            let encrypted = schema
+                .columns
+                .iter()
+                .any(|col| col.encrypted && fk.columns.contains(&col.name));
+            let covered = self
+                .get_table_indexes(&schema.name)
+                .iter()
+                .any(|index| index.columns.starts_with(&fk.columns));
+            let name = format!("{}_{}_fkey", schema.name, fk.columns.join("_"));
+            if fk.columns.is_empty()
+                || encrypted
+                || covered
+                || self.metadata.index_definitions.contains_key(&name)
+            {
+                continue;
+            }
+
+            self.secondary_indexes.insert(name.clone(), BTreeMap::new());
+            self.metadata.index_definitions.insert(
+                name.clone(),
+                IndexDefinition {
+                    name,
+                    table: schema.name.clone(),
+                    columns: fk.columns.clone(),
+                    unique: false,
+                },
+            );
+        }
+    }
+
     /// Validate foreign key constraints for an INSERT operation
     ///
     /// Checks that all foreign key values reference existing rows in the referenced tables.
@@ -105,7 +153,7 @@ impl StorageEngine {
             offset: None,
         };
 
-        let rows = self.select_rows(&query).await?;
+        let rows = self.select_stored_rows(&query).await?;
         Ok(!rows.is_empty())
     }
 
@@ -211,7 +259,7 @@ impl StorageEngine {
             offset: None,
         };
 
-        let referencing_rows = self.select_rows(&select_query).await?;
+        let referencing_rows = self.select_stored_rows(&select_query).await?;
 
         if referencing_rows.is_empty() {
             return Ok(());
@@ -317,7 +365,8 @@ impl StorageEngine {
 
     /// Handle foreign key constraints when deleting a row
     ///
-    /// Finds all tables that reference this table and applies the ON DELETE action.
+    /// Finds all tables that reference this table and applies the ON DELETE
+    /// action, within the transaction `tx_id` if given.
     ///
     /// # Errors
     ///
@@ -326,6 +375,7 @@ impl StorageEngine {
         &mut self,
         table_name: &str,
         row: &Row,
+        tx_id: Option<TransactionId>,
     ) -> Result<()> {
         // Get the schema of the table being deleted from
         let table_schema = self
@@ -359,7 +409,7 @@ impl StorageEngine {
         for (ref_table_name, foreign_keys) in referencing_tables {
             for fk in foreign_keys {
                 // For each foreign key that references this table, apply the ON DELETE action
-                self.apply_delete_action(&ref_table_name, &fk, &table_schema, row)
+                self.apply_delete_action(&ref_table_name, &fk, &table_schema, row, tx_id)
                     .await?;
             }
         }
@@ -369,6 +419,11 @@ impl StorageEngine {
 
     /// Apply the ON DELETE action for a foreign key constraint
     ///
+    /// Cascaded deletes and the updates of SET NULL and SET DEFAULT go
+    /// through the regular delete and update paths, or their transactional
+    /// versions when `tx_id` is given, so they are validated, indexed and
+    /// logged like any other change.
+    ///
     /// # Errors
     ///
     /// Returns an error if the action cannot be applied.
@@ -378,6 +433,7 @@ impl StorageEngine {
         fk: &ForeignKeyConstraint,
         referenced_schema: &TableSchema,
         deleted_row: &Row,
+        tx_id: Option<TransactionId>,
     ) -> Result<()> {
         // Build the WHERE clause to find referencing rows
         let mut conditions = Vec::new();
@@ -398,18 +454,19 @@ impl StorageEngine {
         if conditions.is_empty() {
             return Ok(()); // No matching columns to check
         }
+        let where_clause = Some(WhereClause { conditions });
 
-        // Find referencing rows
+        // Find referencing rows, through the foreign key index
         let select_query = SelectQuery {
             table: referencing_table.to_string(),
             columns: vec!["*".to_string()],
-            where_clause: Some(WhereClause { conditions }),
+            where_clause: where_clause.clone(),
             order_by: None,
             limit: None,
             offset: None,
         };
 
-        let referencing_rows = self.select_rows(&select_query).await?;
+        let referencing_rows = self.select_stored_rows(&select_query).await?;
 
         if referencing_rows.is_empty() {
             return Ok(()); // No referencing rows, nothing to do
@@ -433,84 +490,53 @@ impl StorageEngine {
             },
             | ReferentialAction::Cascade => {
                 // Delete all referencing rows (this may trigger further cascades)
-                let mut cascade_conditions = Vec::new();
-                for (i, ref_column) in fk.referenced_columns.iter().enumerate() {
-                    if let Some(deleted_value) = deleted_row.fields.get(ref_column) {
-                        let fk_column = fk
-                            .columns
-                            .get(i)
-                            .ok_or_else(|| anyhow!("Foreign key column count mismatch"))?;
-                        cascade_conditions.push(Condition {
-                            field: fk_column.clone(),
-                            operator: ComparisonOperator::Equal,
-                            value: deleted_value.clone(),
-                        });
-                    }
-                }
-
                 let cascade_delete = DeleteQuery {
                     table: referencing_table.to_string(),
-                    where_clause: Some(WhereClause {
-                        conditions: cascade_conditions,
-                    }),
+                    where_clause,
+                };
+                match tx_id {
+                    | Some(tx_id) => {
+                        self.delete_rows_acid_internal(tx_id, &cascade_delete)
+                            .await?
+                    },
+                    | None => self.delete_rows_internal(&cascade_delete).await?,
                 };
-
-                // Recursive delete (handles further cascades)
-                // Use Box::pin for async recursion
-                Box::pin(self.delete_rows_internal(&cascade_delete)).await?;
-            },
-            | ReferentialAction::SetNull => {
-                // Set the foreign key columns to NULL in referencing rows
-                for ref_row in &referencing_rows {
-                    let mut updated_fields = ref_row.fields.clone();
-                    for fk_column in &fk.columns {
-                        updated_fields.insert(fk_column.clone(), Value::Null);
-                    }
-
-                    let updated_row = Row {
-                        id: ref_row.id,
-                        fields: updated_fields,
-                        created_at: ref_row.created_at,
-                        updated_at: chrono::Utc::now(),
-                    };
-
-                    self.update_row_internal(referencing_table, &updated_row)
-                        .await?;
-                }
             },
-            | ReferentialAction::SetDefault => {
-                // Set the foreign key columns to their default values
+            | ReferentialAction::SetNull | ReferentialAction::SetDefault => {
                 let ref_schema = self
                     .metadata
                     .tables
                     .get(referencing_table)
-                    .ok_or_else(|| anyhow!("Table '{referencing_table}' not found"))?
-                    .clone();
-
-                for ref_row in &referencing_rows {
-                    let mut updated_fields = ref_row.fields.clone();
-                    for fk_column in &fk.columns {
-                        // Find the default value for this column
-                        let default_value = ref_schema
-                            .columns
-                            .iter()
-                            .find(|c| c.name == *fk_column)
-                            .and_then(|c| c.default_value.clone())
-                            .unwrap_or(Value::Null);
-
-                        updated_fields.insert(fk_column.clone(), default_value);
-                    }
+                    .ok_or_else(|| anyhow!("Table '{referencing_table}' not found"))?;
 
-                    let updated_row = Row {
-                        id: ref_row.id,
-                        fields: updated_fields,
-                        created_at: ref_row.created_at,
-                        updated_at: chrono::Utc::now(),
-                    };
+                // A NOT NULL column rejects the update, which rejects the delete
+                let set_values = fk
+                    .columns
+                    .iter()
+                    .map(|fk_column| {
+                        let value = if fk.on_delete == ReferentialAction::SetDefault {
+                            ref_schema
+                                .columns
+                                .iter()
+                                .find(|c| c.name == *fk_column)
+                                .and_then(|c| c.default_value.clone())
+                                .unwrap_or(Value::Null)
+                        } else {
+                            Value::Null
+                        };
+                        (fk_column.clone(), value)
+                    })
+                    .collect();
 
-                    self.update_row_internal(referencing_table, &updated_row)
-                        .await?;
-                }
+                let update = UpdateQuery {
+                    table: referencing_table.to_string(),
+                    set_values,
+                    where_clause,
+                };
+                match tx_id {
+                    | Some(tx_id) => self.update_rows_acid(tx_id, &update).await?,
+                    | None => self.update_rows(&update).await?,
+                };
             },
         }
 
@@ -523,6 +549,7 @@ impl StorageEngine {
     ///
     /// Returns an error if the update fails.
     pub(crate) async fn update_row_internal(&mut self, table: &str, row: &Row) -> Result<()> {
+        self.bump_table_version(table);
         // Compress and store the updated row
         let compressed_data = self.compress_row(row).await?;
         self.compressed_blocks.insert(row.id, compressed_data);
@@ -0,0 +1,606 @@
+//! Secondary indexes for `StorageEngine`
+//!
+//! `CREATE INDEX` registers an [`IndexDefinition`] in the database metadata and
+//! builds an ordered map from [`CompositeKey`]s of the indexed column values to
+//! row IDs. Every key ends with the row ID, so rows sharing the same values get
+//! their own entries. Indexes are maintained together with the primary key
+//! index and rebuilt from the table rows when the engine is opened.
+//!
+//! A SELECT whose WHERE clause fixes a leading prefix of the indexed columns
+//! with `=` and optionally bounds the next column with `<`, `<=`, `>` or `>=`
+//! is answered with a range scan over the index. The full WHERE clause is still
+//! applied to the rows found. When the index also holds every selected and
+//! sorted column, the rows are decoded from the index entries and the table
+//! is not read at all (an index-only scan).
+//!
+//! Alongside, the primary key of every table is kept as composite keys in key
+//! order, so keyset pagination resumes with a range scan after the last key.
+
+use std::collections::{BTreeMap, HashSet};
+use std::ops::Bound;
+
+use anyhow::{anyhow, Result};
+use tracing::{info, warn};
+
+use super::StorageEngine;
+use crate::storage::btree::{CompositeKey, Key};
+use crate::storage::query::{ComparisonOperator, SelectQuery, WhereClause};
+use crate::storage::row::Row;
+use crate::storage::types::{
+    ConstraintViolation, DataType, IndexDefinition, RowId, TableSchema, Value,
+};
+
+/// How a SELECT reads its table through a secondary index
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexAccess {
+    /// Name of the scanned index
+    pub index_name: String,
+    /// Leading index columns constrained by the scanned key range
+    pub key_columns: Vec<String>,
+    /// Whether results are built from index entries without reading table rows
+    pub index_only: bool,
+}
+
+/// Value used for indexed columns a row does not have
+static MISSING: Value = Value::Null;
+
+/// Key range of an index that covers the rows a WHERE clause can match
+struct IndexScan {
+    /// Number of leading columns fixed by `=` conditions
+    equalities: usize,
+    /// Whether the column after the fixed prefix is bounded by a range condition
+    bounded: bool,
+    lower: Bound<Key>,
+    upper: Bound<Key>,
+}
+
+impl IndexScan {
+    fn plan(
+        definition: &IndexDefinition,
+        schema: &TableSchema,
+        where_clause: &WhereClause,
+    ) -> Option<Self> {
+        let mut prefix = CompositeKey::new();
+        for column in &definition.columns {
+            let Some(condition) = where_clause.conditions.iter().find(|condition| {
+                condition.field == *column
+                    && matches!(condition.operator, ComparisonOperator::Equal)
+            }) else {
+                break;
+            };
+            prefix.push(&condition.value);
+        }
+        let equalities = prefix.len();
+
+        let mut lower = Bound::Included(prefix.as_bytes().to_vec());
+        let mut upper = CompositeKey::prefix_upper_bound(prefix.as_bytes())
+            .map_or(Bound::Unbounded, Bound::Excluded);
+        let mut bounded = false;
+
+        // Any single range condition on the next column narrows the scan; the
+        // others are left to the WHERE clause
+        if let Some(column) = definition.columns.get(equalities) {
+            let data_type = schema
+                .columns
+                .iter()
+                .find(|c| c.name == *column)
+                .map(|c| &c.data_type);
+            for condition in &where_clause.conditions {
+                if condition.field != *column || !is_range_comparable(&condition.value, data_type) {
+                    continue;
+                }
+                let mut bound = prefix.clone();
+                bound.push(&condition.value);
+                let bound = bound.into_key();
+                match condition.operator {
+                    | ComparisonOperator::GreaterThan => {
+                        lower = Bound::Included(CompositeKey::prefix_upper_bound(&bound)?);
+                    },
+                    | ComparisonOperator::GreaterThanOrEqual => lower = Bound::Included(bound),
+                    | ComparisonOperator::LessThan => upper = Bound::Excluded(bound),
+                    | ComparisonOperator::LessThanOrEqual => {
+                        upper = CompositeKey::prefix_upper_bound(&bound)
+                            .map_or(Bound::Unbounded, Bound::Excluded);
+                    },
+                    | _ => continue,
+                }
+                bounded = true;
+            }
+        }
+
+        (equalities > 0 || bounded).then_some(Self {
+            equalities,
+            bounded,
+            lower,
+            upper,
+        })
+    }
+}
+
+impl StorageEngine {
+    /// Create a secondary index over one or more columns of a table
+    ///
+    /// Existing rows are indexed immediately.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - An index with the same name already exists
+    /// - The table or one of the columns doesn't exist
+    /// - A column is `ENCRYPTED`
+    /// - The index is unique and existing rows share the same values
+    pub async fn create_index(&mut self, definition: IndexDefinition) -> Result<()> {
+        info!(
+            "🔨 Creating index '{}' on {}({})",
+            definition.name,
+            definition.table,
+            definition.columns.join(", ")
+        );
+
+        if self
+            .metadata
+            .index_definitions
+            .contains_key(&definition.name)
+        {
+            return Err(anyhow!("Index '{}' already exists", definition.name));
+        }
+        let schema = self
+            .metadata
+            .tables
+            .get(&definition.table)
+            .ok_or_else(|| anyhow!("Table '{}' does not exist", definition.table))?;
+        if definition.columns.is_empty() {
+            return Err(anyhow!("Index '{}' has no columns", definition.name));
+        }
+        for column in &definition.columns {
+            let column_definition = schema
+                .columns
+                .iter()
+                .find(|c| c.name == *column)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Column '{column}' does not exist in table '{}'",
+                        definition.table
+                    )
+                })?;
+            if column_definition.encrypted {
+                return Err(anyhow!("Cannot index ENCRYPTED column '{column}'"));
+            }
+        }
+
+        let rows = self.load_table_rows(&definition.table).await?;
+        let entries = build_index(&definition, &rows)?;
+        self.secondary_indexes
+            .insert(definition.name.clone(), entries);
+        self.metadata
+            .index_definitions
+            .insert(definition.name.clone(), definition);
+
+        self.save_metadata().await
+    }
+
+    /// Drop a secondary index
+    ///
+    /// Returns `false` if no index with that name exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the metadata cannot be saved.
+    pub async fn drop_index(&mut self, index_name: &str) -> Result<bool> {
+        if self.metadata.index_definitions.remove(index_name).is_none() {
+            return Ok(false);
+        }
+        self.secondary_indexes.remove(index_name);
+        self.save_metadata().await?;
+
+        info!("✅ Index '{}' dropped", index_name);
+        Ok(true)
+    }
+
+    /// Get the definition of a secondary index
+    #[must_use]
+    pub fn get_index_definition(&self, index_name: &str) -> Option<&IndexDefinition> {
+        self.metadata.index_definitions.get(index_name)
+    }
+
+    /// Get the secondary indexes of a table, ordered by index name
+    #[must_use]
+    pub fn get_table_indexes(&self, table_name: &str) -> Vec<&IndexDefinition> {
+        let mut definitions: Vec<_> = self
+            .metadata
+            .index_definitions
+            .values()
+            .filter(|definition| definition.table == table_name)
+            .collect();
+        definitions.sort_by(|a, b| a.name.cmp(&b.name));
+        definitions
+    }
+
+    /// Remove the secondary indexes of a dropped table
+    pub(crate) fn drop_table_indexes(&mut self, table_name: &str) {
+        self.primary_key_order.remove(table_name);
+        self.metadata.index_definitions.retain(|name, definition| {
+            if definition.table == table_name {
+                self.secondary_indexes.remove(name);
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    /// Rebuild all secondary indexes and primary key orders from the stored table rows
+    pub(crate) async fn rebuild_secondary_indexes(&mut self) -> Result<()> {
+        let definitions: Vec<_> = self.metadata.index_definitions.values().cloned().collect();
+        for definition in definitions {
+            let rows = self.load_table_rows(&definition.table).await?;
+            let entries = build_index(&definition, &rows)?;
+            self.secondary_indexes.insert(definition.name, entries);
+        }
+
+        let tables: Vec<_> = self.metadata.tables.keys().cloned().collect();
+        self.primary_key_order.clear();
+        for table in tables {
+            for row in self.load_table_rows(&table).await? {
+                self.insert_into_primary_key_order(&table, &row);
+            }
+        }
+        Ok(())
+    }
+
+    /// Add a row to the secondary indexes and primary key order of its table
+    pub(crate) fn insert_into_secondary_indexes(&mut self, table: &str, row: &Row) {
+        self.insert_into_primary_key_order(table, row);
+        for definition in self.metadata.index_definitions.values() {
+            if definition.table == table {
+                if let Some(index) = self.secondary_indexes.get_mut(&definition.name) {
+                    index.insert(entry_key(definition, row), row.id);
+                }
+            }
+        }
+    }
+
+    /// Remove a row from the secondary indexes and primary key order of its table
+    pub(crate) fn remove_from_secondary_indexes(&mut self, table: &str, row: &Row) {
+        if let Some(key) = self.primary_key_entry(table, row) {
+            if let Some(order) = self.primary_key_order.get_mut(table) {
+                order.remove(&key);
+            }
+        }
+        for definition in self.metadata.index_definitions.values() {
+            if definition.table == table {
+                if let Some(index) = self.secondary_indexes.get_mut(&definition.name) {
+                    index.remove(&entry_key(definition, row));
+                }
+            }
+        }
+    }
+
+    fn insert_into_primary_key_order(&mut self, table: &str, row: &Row) {
+        if let Some(key) = self.primary_key_entry(table, row) {
+            self.primary_key_order
+                .entry(table.to_string())
+                .or_default()
+                .insert(key, row.id);
+        }
+    }
+
+    /// Entry of `row` in the primary key order of `table`
+    ///
+    /// `ENCRYPTED` primary keys hold ciphertext, which has no useful order,
+    /// so their tables are not kept in key order.
+    pub(crate) fn primary_key_entry(&self, table: &str, row: &Row) -> Option<Key> {
+        let schema = self.metadata.tables.get(table)?;
+        if primary_key_encrypted(schema) {
+            return None;
+        }
+        let value = row.fields.get(&schema.primary_key).unwrap_or(&MISSING);
+        let mut key = CompositeKey::from_values([value]);
+        key.push_row_id(row.id);
+        Some(key.into_key())
+    }
+
+    /// Lower bound of the primary key order entries of `table` after `after_key`
+    ///
+    /// Returns `None` if the table is not kept in key order or `after_key`
+    /// cannot be compared with its primary key through the key order.
+    pub(crate) fn primary_key_lower_bound(
+        &self,
+        table: &str,
+        after_key: Option<&Value>,
+    ) -> Option<Bound<Key>> {
+        let schema = self.metadata.tables.get(table)?;
+        if primary_key_encrypted(schema) {
+            return None;
+        }
+        match after_key {
+            | Some(key) => {
+                let data_type = schema
+                    .columns
+                    .iter()
+                    .find(|c| c.name == schema.primary_key)
+                    .map(|c| &c.data_type);
+                if !is_range_comparable(key, data_type) {
+                    return None;
+                }
+                let key = CompositeKey::from_values([key]).into_key();
+                Some(Bound::Included(CompositeKey::prefix_upper_bound(&key)?))
+            },
+            | None => Some(Bound::Unbounded),
+        }
+    }
+
+    /// Entries of the primary key order of `table` from `lower` on, with the
+    /// IDs of their rows
+    pub(crate) fn primary_key_range<'a>(
+        &'a self,
+        table: &str,
+        lower: &Bound<Key>,
+    ) -> impl Iterator<Item = (&'a Key, RowId)> + 'a {
+        // Tables loaded without any rows have no key order yet
+        let lower = lower.clone();
+        self.primary_key_order
+            .get(table)
+            .into_iter()
+            .flat_map(move |order| order.range((lower.clone(), Bound::Unbounded)))
+            .map(|(key, row_id)| (key, *row_id))
+    }
+
+    /// Check that no other row shares the values of `row` in a unique index
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the violated index.
+    pub(crate) fn check_unique_indexes(&self, table: &str, row: &Row) -> Result<()> {
+        for definition in self.get_table_indexes(table) {
+            if !definition.unique {
+                continue;
+            }
+            let values = column_values(definition, row);
+            // Like SQL, rows with a NULL in the index never conflict
+            if values.iter().any(|value| matches!(value, Value::Null)) {
+                continue;
+            }
+            let Some(index) = self.secondary_indexes.get(&definition.name) else {
+                continue;
+            };
+
+            let prefix = CompositeKey::from_values(values).into_key();
+            let upper =
+                CompositeKey::prefix_upper_bound(&prefix).map_or(Bound::Unbounded, Bound::Excluded);
+            if scan_range(index, &Bound::Included(prefix), &upper)
+                .any(|(_, row_id)| row_id != row.id)
+            {
+                return Err(duplicate_error(definition));
+            }
+        }
+        Ok(())
+    }
+
+    /// Describe how a SELECT reads its table through a secondary index
+    ///
+    /// Returns `None` if the query scans the table instead.
+    #[must_use]
+    pub fn plan_index_access(&self, query: &SelectQuery) -> Option<IndexAccess> {
+        self.plan_hinted_index_access(query, None)
+    }
+
+    /// Describe how a SELECT reads its table, preferring the index `index_hint`
+    ///
+    /// Returns `None` if the query scans the table instead.
+    #[must_use]
+    pub fn plan_hinted_index_access(
+        &self,
+        query: &SelectQuery,
+        index_hint: Option<&str>,
+    ) -> Option<IndexAccess> {
+        let schema = self.metadata.tables.get(&query.table)?;
+        self.index_plan(schema, query, index_hint)
+            .map(|plan| plan.access())
+    }
+
+    /// Choose the secondary index for a SELECT
+    ///
+    /// Picks the index fixing the most leading columns with `=`, preferring one
+    /// that also bounds the next column and then one covering the query. An
+    /// `index_hint` naming an index that can answer the query overrides that
+    /// choice; any other hint is ignored with a warning.
+    pub(crate) fn index_plan<'a>(
+        &'a self,
+        schema: &TableSchema,
+        query: &SelectQuery,
+        index_hint: Option<&str>,
+    ) -> Option<IndexPlan<'a>> {
+        let mut candidates = Vec::new();
+        if let Some(where_clause) = &query.where_clause {
+            for definition in self.get_table_indexes(&schema.name) {
+                let Some(index) = self.secondary_indexes.get(&definition.name) else {
+                    continue;
+                };
+                let Some(scan) = IndexScan::plan(definition, schema, where_clause) else {
+                    continue;
+                };
+                candidates.push(IndexPlan {
+                    definition,
+                    index,
+                    covering: covers(definition, schema, query),
+                    scan,
+                });
+            }
+        }
+
+        if let Some(hint) = index_hint {
+            if let Some(position) = candidates
+                .iter()
+                .position(|plan| plan.definition.name == hint)
+            {
+                return Some(candidates.swap_remove(position));
+            }
+            warn!(
+                "Index hint {} ignored: no such index on '{}' usable by this query",
+                hint, schema.name
+            );
+        }
+        // Of equally ranked indexes, keep the first by name
+        candidates.into_iter().rev().max_by_key(IndexPlan::rank)
+    }
+}
+
+/// Secondary index chosen for a SELECT, with the key range to scan
+pub(crate) struct IndexPlan<'a> {
+    definition: &'a IndexDefinition,
+    index: &'a BTreeMap<Key, RowId>,
+    scan: IndexScan,
+    /// Whether the index holds every column the query reads
+    pub(crate) covering: bool,
+}
+
+impl IndexPlan<'_> {
+    const fn rank(&self) -> (usize, bool, bool) {
+        (self.scan.equalities, self.scan.bounded, self.covering)
+    }
+
+    pub(crate) fn access(&self) -> IndexAccess {
+        let key_columns = self.scan.equalities + usize::from(self.scan.bounded);
+        IndexAccess {
+            index_name: self.definition.name.clone(),
+            key_columns: self.definition.columns[..key_columns].to_vec(),
+            index_only: self.covering,
+        }
+    }
+
+    /// IDs of the rows in the key range
+    pub(crate) fn row_ids(&self) -> HashSet<RowId> {
+        scan_range(self.index, &self.scan.lower, &self.scan.upper)
+            .map(|(_, row_id)| row_id)
+            .collect()
+    }
+
+    /// Rows in the key range, decoded from the index entries
+    ///
+    /// The rows hold only the indexed columns and carry the Unix epoch as
+    /// their timestamps, so this is only used for covering plans.
+    pub(crate) fn covered_rows(&self) -> Result<Vec<Row>> {
+        let columns = &self.definition.columns;
+        let epoch = chrono::DateTime::<chrono::Utc>::UNIX_EPOCH;
+        scan_range(self.index, &self.scan.lower, &self.scan.upper)
+            .map(|(key, row_id)| {
+                let values = CompositeKey::decode(key, columns.len())?;
+                Ok(Row {
+                    id: row_id,
+                    fields: columns.iter().cloned().zip(values).collect(),
+                    created_at: epoch,
+                    updated_at: epoch,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Index entries for `rows`, rejecting duplicates in a unique index
+fn build_index(definition: &IndexDefinition, rows: &[Row]) -> Result<BTreeMap<Key, RowId>> {
+    let mut index = BTreeMap::new();
+    let mut seen = HashSet::new();
+    for row in rows {
+        if definition.unique {
+            let values = column_values(definition, row);
+            if !values.iter().any(|value| matches!(value, Value::Null))
+                && !seen.insert(CompositeKey::from_values(values).into_key())
+            {
+                return Err(duplicate_error(definition));
+            }
+        }
+        index.insert(entry_key(definition, row), row.id);
+    }
+    Ok(index)
+}
+
+fn column_values<'a>(definition: &IndexDefinition, row: &'a Row) -> Vec<&'a Value> {
+    definition
+        .columns
+        .iter()
+        .map(|column| row.fields.get(column).unwrap_or(&MISSING))
+        .collect()
+}
+
+fn entry_key(definition: &IndexDefinition, row: &Row) -> Key {
+    let mut key = CompositeKey::from_values(column_values(definition, row));
+    key.push_row_id(row.id);
+    key.into_key()
+}
+
+fn duplicate_error(definition: &IndexDefinition) -> anyhow::Error {
+    ConstraintViolation::Unique {
+        constraint: definition.name.clone(),
+        columns: definition.columns.clone(),
+    }
+    .into()
+}
+
+/// Keys and row IDs of the entries between `lower` and `upper`
+fn scan_range<'a>(
+    index: &'a BTreeMap<Key, RowId>,
+    lower: &Bound<Key>,
+    upper: &'a Bound<Key>,
+) -> impl Iterator<Item = (&'a Key, RowId)> + 'a {
+    index
+        .range((lower.clone(), Bound::Unbounded))
+        .take_while(move |(key, _)| match upper {
+            | Bound::Included(end) => *key <= end,
+            | Bound::Excluded(end) => *key < end,
+            | Bound::Unbounded => true,
+        })
+        .map(|(key, row_id)| (key, *row_id))
+}
+
+/// Whether `definition` holds every column `query` selects, filters or sorts on
+///
+/// Indexes with timestamp columns never cover a query, as they keep
+/// timestamps at microsecond precision only, and neither do indexes with
+/// encrypted columns.
+fn covers(definition: &IndexDefinition, schema: &TableSchema, query: &SelectQuery) -> bool {
+    if query.columns.is_empty() || query.columns.iter().any(|column| column == "*") {
+        return false;
+    }
+    let lossy = schema.columns.iter().any(|column| {
+        (column.data_type == DataType::Timestamp || column.encrypted)
+            && definition.columns.contains(&column.name)
+    });
+    let indexed = |column: &String| definition.columns.contains(column);
+
+    !lossy
+        && query.columns.iter().all(indexed)
+        && query
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| &where_clause.conditions)
+            .all(|condition| indexed(&condition.field))
+        && query
+            .order_by
+            .iter()
+            .all(|order_by| indexed(&order_by.field))
+}
+
+/// Whether the primary key of `schema` is an `ENCRYPTED` column
+fn primary_key_encrypted(schema: &TableSchema) -> bool {
+    schema
+        .columns
+        .iter()
+        .any(|column| column.name == schema.primary_key && column.encrypted)
+}
+
+/// Whether a range bound on a column can be answered from its index order
+///
+/// Range comparisons only hold between values of the same type, and timestamps
+/// are indexed at microsecond precision.
+fn is_range_comparable(value: &Value, data_type: Option<&DataType>) -> bool {
+    matches!(
+        (value, data_type),
+        (
+            Value::Integer(_),
+            Some(DataType::Integer | DataType::Serial | DataType::BigSerial)
+        ) | (Value::Float(_), Some(DataType::Float))
+            | (Value::Text(_), Some(DataType::Text))
+            | (Value::Boolean(_), Some(DataType::Boolean))
+    )
+}
@@ -3,13 +3,15 @@
 use std::collections::HashMap;
 use std::num::NonZeroUsize;
 use std::path::Path;
+use std::sync::atomic::AtomicU64;
 
 use anyhow::{anyhow, Result};
 use lru::LruCache;
 use tokio::fs;
+use tokio::sync::broadcast;
 use tracing::{debug, info};
 
-use super::StorageEngine;
+use super::{StorageEngine, CHANGE_FEED_CAPACITY};
 use crate::dna::QuantumDNACompressor;
 use crate::storage::encryption::EncryptionManager;
 use crate::storage::stats::{DatabaseMetadata, QueryExecutionStats};
@@ -46,11 +48,16 @@ impl StorageEngine {
             tables: HashMap::new(),
             next_row_id: 1,
             next_lsn: 1,
+            table_statistics: HashMap::new(),
+            index_definitions: HashMap::new(),
+            key_expirations: HashMap::new(),
         };
 
         Self {
             data_dir: data_dir.to_path_buf(),
             indexes: HashMap::new(),
+            secondary_indexes: HashMap::new(),
+            primary_key_order: HashMap::new(),
             transaction_log: Vec::new(),
             compressed_blocks: HashMap::new(),
             metadata,
@@ -60,9 +67,17 @@ impl StorageEngine {
             // SAFETY: 10000 is a non-zero constant
             #[allow(clippy::expect_used)]
             row_cache: LruCache::new(NonZeroUsize::new(10000).expect("10000 is non-zero")),
+            cache_hits: AtomicU64::new(0),
+            cache_misses: AtomicU64::new(0),
             transaction_manager: TransactionManager::new(),
             encryption_manager: None,
+            field_keys: None,
             last_query_stats: QueryExecutionStats::default(),
+            change_feed: broadcast::channel(CHANGE_FEED_CAPACITY).0,
+            table_versions: HashMap::new(),
+            last_table_version: 0,
+            page_store: None,
+            initialized: false,
         }
     }
 
@@ -108,6 +123,8 @@ impl StorageEngine {
         let mut engine = Self {
             data_dir: data_dir.clone(),
             indexes: HashMap::new(),
+            secondary_indexes: HashMap::new(),
+            primary_key_order: HashMap::new(),
             transaction_log: Vec::new(),
             compressed_blocks: HashMap::new(),
             metadata,
@@ -117,9 +134,17 @@ impl StorageEngine {
             // SAFETY: 10000 is a non-zero constant
             #[allow(clippy::expect_used)]
             row_cache: LruCache::new(NonZeroUsize::new(10000).expect("10000 is non-zero")),
+            cache_hits: AtomicU64::new(0),
+            cache_misses: AtomicU64::new(0),
             transaction_manager,
             encryption_manager: Some(encryption_manager),
+            field_keys: None,
             last_query_stats: QueryExecutionStats::default(),
+            change_feed: broadcast::channel(CHANGE_FEED_CAPACITY).0,
+            table_versions: HashMap::new(),
+            last_table_version: 0,
+            page_store: None,
+            initialized: true,
         };
 
         // Load existing data
@@ -174,6 +199,9 @@ impl StorageEngine {
                 tables: HashMap::new(),
                 next_row_id: 1,
                 next_lsn: 1,
+                table_statistics: HashMap::new(),
+                index_definitions: HashMap::new(),
+                key_expirations: HashMap::new(),
             };
 
             // Save metadata
@@ -0,0 +1,87 @@
+//! Key listing for the key-value API of `StorageEngine`
+//!
+//! Values written with [`store`](StorageEngine::store) live in the `_storage`
+//! table. A secondary index on its `key` column keeps the keys ordered, so
+//! listing every key starting with a prefix is a range scan of the index
+//! rather than a scan of the table. Storing a key again adds another row, so
+//! the index can hold a key several times; listings report each key once.
+
+use std::ops::Bound;
+
+use anyhow::{anyhow, Result};
+use futures::Stream;
+
+use super::StorageEngine;
+use crate::storage::btree::CompositeKey;
+use crate::storage::types::{IndexDefinition, Value};
+
+/// Name of the secondary index over the keys of the `_storage` table
+const STORAGE_KEY_INDEX: &str = "_storage_key";
+
+impl StorageEngine {
+    /// Stored keys starting with `prefix`, in sorted order
+    ///
+    /// Expired keys are left out. Returns every key if `prefix` is `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an index entry cannot be decoded.
+    pub fn list_keys(&self, prefix: Option<&str>) -> Result<Vec<String>> {
+        self.keys(prefix).collect()
+    }
+
+    /// Stream the stored keys starting with `prefix`, in sorted order
+    ///
+    /// Like [`list_keys`](Self::list_keys), but keys are decoded as they are
+    /// consumed instead of being collected up front.
+    pub fn key_stream<'a>(
+        &'a self,
+        prefix: Option<&str>,
+    ) -> impl Stream<Item = Result<String>> + 'a {
+        futures::stream::iter(self.keys(prefix))
+    }
+
+    /// Iterate over the unexpired keys starting with `prefix`
+    fn keys<'a>(&'a self, prefix: Option<&str>) -> impl Iterator<Item = Result<String>> + 'a {
+        let lower = CompositeKey::text_prefix(prefix.unwrap_or_default());
+        let upper =
+            CompositeKey::prefix_upper_bound(&lower).map_or(Bound::Unbounded, Bound::Excluded);
+        let mut previous: Option<String> = None;
+
+        self.secondary_indexes
+            .get(STORAGE_KEY_INDEX)
+            .into_iter()
+            .flat_map(move |index| index.range((Bound::Included(lower.clone()), upper.clone())))
+            .filter_map(move |(entry, _)| {
+                let key = match CompositeKey::decode(entry, 1).map(|mut values| values.pop()) {
+                    | Ok(Some(Value::Text(key))) => key.as_ref().clone(),
+                    | Ok(_) => return Some(Err(anyhow!("Stored key is not text"))),
+                    | Err(e) => return Some(Err(e)),
+                };
+                // Entries of the same key are adjacent
+                if previous.as_ref() == Some(&key) {
+                    return None;
+                }
+                previous = Some(key.clone());
+                (!self.is_key_expired(&key)).then_some(Ok(key))
+            })
+    }
+
+    /// Create the index over the keys of the `_storage` table if it is missing
+    pub(crate) async fn ensure_storage_key_index(&mut self) -> Result<()> {
+        if self
+            .metadata
+            .index_definitions
+            .contains_key(STORAGE_KEY_INDEX)
+        {
+            return Ok(());
+        }
+        self.create_index(IndexDefinition {
+            name: STORAGE_KEY_INDEX.to_string(),
+            table: "_storage".to_string(),
+            columns: vec!["key".to_string()],
+            unique: false,
+        })
+        .await
+    }
+}
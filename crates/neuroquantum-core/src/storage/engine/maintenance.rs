@@ -0,0 +1,199 @@
+//! On-demand storage maintenance for `StorageEngine`
+//!
+//! - `vacuum`: drop compressed blocks and cached rows of deleted rows, and
+//!   release free pages at the end of the attached page store
+//! - `prune_wal`: sync the storage files, checkpoint and remove the WAL
+//!   records recovery no longer needs
+//! - `maintenance_status`: last checkpoint and free-page ratio, for alerting
+//!   on overdue maintenance
+
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use tokio::fs;
+use tracing::info;
+
+use super::StorageEngine;
+use crate::storage::row::CompressedRowEntry;
+use crate::storage::stats::{MaintenanceStatus, VacuumStats, WalPruneStats};
+use crate::storage::types::RowId;
+
+impl StorageEngine {
+    /// Reclaim space held by deleted rows
+    ///
+    /// Compressed blocks and cached rows whose row is in no table file are
+    /// dropped. This is skipped while ACID transactions are active, as their
+    /// rows only reach the table files on commit. Free pages at the end of
+    /// the attached page store are then released.
+    pub async fn vacuum(&mut self) -> Result<VacuumStats> {
+        let mut stats = VacuumStats::default();
+
+        let active = self
+            .transaction_manager
+            .get_statistics()
+            .await
+            .active_transactions;
+        if active > 0 {
+            info!(
+                "🧹 Skipping row cleanup, {} transactions are active",
+                active
+            );
+        } else if let Some(live_rows) = self.live_row_ids().await? {
+            stats.tables_scanned = self.metadata.tables.len();
+
+            let blocks_before = self.compressed_blocks.len();
+            self.compressed_blocks
+                .retain(|row_id, _| live_rows.contains(row_id));
+            stats.compressed_blocks_removed = blocks_before - self.compressed_blocks.len();
+            if stats.compressed_blocks_removed > 0 {
+                self.save_compressed_blocks().await?;
+            }
+
+            let stale_rows: Vec<RowId> = self
+                .row_cache
+                .iter()
+                .map(|(row_id, _)| *row_id)
+                .filter(|row_id| !live_rows.contains(row_id))
+                .collect();
+            for row_id in &stale_rows {
+                self.row_cache.pop(row_id);
+            }
+            stats.cached_rows_evicted = stale_rows.len();
+        }
+
+        if let Some(buffer_pool) = &self.page_store {
+            // Write back dirty frames before the file is shrunk under them
+            buffer_pool.flush_all().await?;
+            stats.pages_compacted = buffer_pool.pager().compact().await?;
+        }
+
+        info!(
+            "🧹 Vacuum removed {} compressed blocks, evicted {} cached rows and released {} pages",
+            stats.compressed_blocks_removed, stats.cached_rows_evicted, stats.pages_compacted
+        );
+        Ok(stats)
+    }
+
+    /// Write a checkpoint and remove the WAL records before it
+    ///
+    /// Dirty pages and all table files are synced to disk first, so the
+    /// changes covered by the removed records are durable without them. The
+    /// checkpoint record itself is kept, as are the records of transactions
+    /// that are still active so they can be rolled back.
+    pub async fn prune_wal(&self) -> Result<WalPruneStats> {
+        self.sync_storage_files().await?;
+        let checkpoint_lsn = self.checkpoint().await?;
+        let mut pruned_through_lsn = checkpoint_lsn - 1;
+        if let Some(oldest) = self.transaction_manager.oldest_active_lsn().await {
+            pruned_through_lsn = pruned_through_lsn.min(oldest.saturating_sub(1));
+        }
+
+        let before = self
+            .transaction_manager
+            .get_wal_stats()
+            .await
+            .map_err(|e| anyhow!("Failed to read WAL statistics: {e}"))?;
+        self.transaction_manager
+            .truncate_wal_after_checkpoint(pruned_through_lsn)
+            .await
+            .map_err(|e| anyhow!("Failed to prune WAL: {e}"))?;
+        let after = self
+            .transaction_manager
+            .get_wal_stats()
+            .await
+            .map_err(|e| anyhow!("Failed to read WAL statistics: {e}"))?;
+
+        Ok(WalPruneStats {
+            checkpoint_lsn,
+            pruned_through_lsn,
+            records_pruned: before.record_count.saturating_sub(after.record_count),
+            records_kept: after.record_count,
+            bytes_reclaimed: before.file_size_bytes.saturating_sub(after.file_size_bytes),
+        })
+    }
+
+    /// Last checkpoint and free-page ratio of the storage
+    pub async fn maintenance_status(&self) -> MaintenanceStatus {
+        let free_page_ratio = match &self.page_store {
+            | Some(buffer_pool) => {
+                let pager = buffer_pool.pager().stats().await;
+                (pager.total_pages > 0).then(|| pager.free_pages as f64 / pager.total_pages as f64)
+            },
+            | None => None,
+        };
+
+        MaintenanceStatus {
+            last_checkpoint: self.transaction_manager.last_checkpoint().await,
+            free_page_ratio,
+        }
+    }
+
+    /// Write back dirty pages and fsync the table and compressed block files
+    async fn sync_storage_files(&self) -> Result<()> {
+        if let Some(buffer_pool) = &self.page_store {
+            buffer_pool.flush_all().await?;
+        }
+
+        let tables_dir = self.data_dir.join("tables");
+        let mut paths: Vec<_> = self
+            .metadata
+            .tables
+            .keys()
+            .map(|table| tables_dir.join(format!("{table}.nqdb")))
+            .collect();
+        paths.push(
+            self.data_dir
+                .join("quantum")
+                .join("compressed_blocks.qdata"),
+        );
+        for path in paths.iter().filter(|path| path.exists()) {
+            fs::File::open(path)
+                .await?
+                .sync_all()
+                .await
+                .map_err(|e| anyhow!("Failed to sync {}: {e}", path.display()))?;
+        }
+
+        // Table files are replaced by rename; sync the directory entries too
+        #[cfg(unix)]
+        if tables_dir.exists() {
+            fs::File::open(&tables_dir).await?.sync_all().await?;
+        }
+        Ok(())
+    }
+
+    /// Ids of the rows in all table files
+    ///
+    /// Returns `None` if a table file is not entirely in the binary row
+    /// format, as its row ids cannot be read without decoding every row.
+    async fn live_row_ids(&self) -> Result<Option<HashSet<RowId>>> {
+        let mut live_rows = HashSet::new();
+        for table in self.metadata.tables.keys() {
+            let table_path = self.data_dir.join("tables").join(format!("{table}.nqdb"));
+            if !table_path.exists() {
+                continue;
+            }
+
+            let content = fs::read(&table_path).await?;
+            let mut offset = 0;
+            while offset + 4 <= content.len() {
+                let len_bytes: [u8; 4] = content[offset..offset + 4]
+                    .try_into()
+                    .map_err(|_| anyhow!("Failed to read length prefix"))?;
+                let end = offset + 4 + u32::from_le_bytes(len_bytes) as usize;
+                let Some(entry) = content
+                    .get(offset + 4..end)
+                    .and_then(|bytes| bincode::deserialize::<CompressedRowEntry>(bytes).ok())
+                else {
+                    return Ok(None);
+                };
+                live_rows.insert(entry.row_id);
+                offset = end;
+            }
+            if offset != content.len() {
+                return Ok(None);
+            }
+        }
+        Ok(Some(live_rows))
+    }
+}
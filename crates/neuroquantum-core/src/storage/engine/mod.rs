@@ -8,15 +8,24 @@
 //! - `crud`: DML operations (INSERT/SELECT/UPDATE/DELETE)
 //! - `transactions`: Simple transaction management (for backwards compatibility)
 //! - `acid_transactions`: Full ACID transaction support with WAL
+//! - `changes`: Change feed of committed row changes
 //! - `persistence`: Disk I/O operations
 //! - `recovery`: Crash recovery
+//! - `maintenance`: Vacuum, WAL pruning and maintenance status
 //! - `foreign_keys`: FK constraint handling
+//! - `field_encryption`: Per-column encryption for `ENCRYPTED` columns
+//! - `indexes`: Secondary indexes over one or more columns
 //! - `query_helpers`: Internal query processing utilities
 
 mod acid_transactions;
+mod changes;
 mod crud;
+mod field_encryption;
 mod foreign_keys;
+mod indexes;
 mod init;
+mod keys;
+mod maintenance;
 mod persistence;
 mod query_helpers;
 mod recovery;
@@ -26,16 +35,27 @@ mod transactions;
 // Re-export transaction types for convenience
 use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 
+pub use changes::{RowChange, RowChangeKind, CHANGE_FEED_CAPACITY};
+pub use field_encryption::REDACTED_PLACEHOLDER;
+pub use indexes::IndexAccess;
 use lru::LruCache;
+use tokio::sync::broadcast;
 pub use transactions::{BatchOperation, BatchResult};
 
+use super::btree::Key;
+use super::buffer::BufferPoolManager;
 use super::encryption::EncryptionManager;
 use super::row::Row;
-use super::stats::{DatabaseMetadata, QueryExecutionStats};
+use super::stats::{
+    CacheStatistics, DatabaseMetadata, PageStoreStats, QueryExecutionStats, TableStatistics,
+};
 use super::transaction_log::Transaction;
 use super::types::RowId;
 use crate::dna::{EncodedData, QuantumDNACompressor};
+use crate::pqcrypto::KeyManager;
 use crate::transaction::TransactionManager;
 
 /// Main storage engine providing persistent file-based storage
@@ -50,6 +70,12 @@ pub struct StorageEngine {
     /// B+ Tree indexes for fast query performance
     pub(crate) indexes: HashMap<String, BTreeMap<String, RowId>>,
 
+    /// Secondary indexes keyed by index name, mapping composite keys to rows
+    pub(crate) secondary_indexes: HashMap<String, BTreeMap<Key, RowId>>,
+
+    /// Primary key of each table's rows in key order, for keyset pagination
+    pub(crate) primary_key_order: HashMap<String, BTreeMap<Key, RowId>>,
+
     /// Active transaction log for ACID compliance
     pub(crate) transaction_log: Vec<Transaction>,
 
@@ -72,23 +98,81 @@ pub struct StorageEngine {
     /// Uses proper LRU eviction strategy for optimal memory management
     pub(crate) row_cache: LruCache<RowId, Row>,
 
+    /// Cumulative row cache hits
+    pub(crate) cache_hits: AtomicU64,
+
+    /// Cumulative row cache misses
+    pub(crate) cache_misses: AtomicU64,
+
     /// Transaction manager for ACID compliance
     pub(crate) transaction_manager: TransactionManager,
 
     /// Encryption manager for data-at-rest encryption
     pub(crate) encryption_manager: Option<EncryptionManager>,
 
+    /// Post-quantum key manager for `ENCRYPTED` column values
+    pub(crate) field_keys: Option<Arc<KeyManager>>,
+
     /// Query execution statistics for the last query
     pub(crate) last_query_stats: QueryExecutionStats,
+
+    /// Broadcasts committed row changes to live query subscribers
+    pub(crate) change_feed: broadcast::Sender<changes::RowChange>,
+
+    /// Version of each table's data, see `table_version`
+    pub(crate) table_versions: HashMap<String, u64>,
+
+    /// Latest version handed out to any table
+    pub(crate) last_table_version: u64,
+
+    /// Buffer pool over a page file, reported by `page_store_stats`
+    pub(crate) page_store: Option<Arc<BufferPoolManager>>,
+
+    /// False for a placeholder created by `new_placeholder`
+    pub(crate) initialized: bool,
 }
 
 impl StorageEngine {
+    /// Check if the engine was opened with [`new`](Self::new) rather than
+    /// created as a placeholder
+    #[must_use]
+    pub const fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
     /// Get the last query execution statistics
     #[must_use]
     pub const fn get_last_query_stats(&self) -> &QueryExecutionStats {
         &self.last_query_stats
     }
 
+    /// Attach the buffer pool whose statistics are reported by
+    /// [`page_store_stats`](Self::page_store_stats)
+    pub fn attach_page_store(&mut self, buffer_pool: Arc<BufferPoolManager>) {
+        self.page_store = Some(buffer_pool);
+    }
+
+    /// Live buffer pool and pager statistics, if a page store is attached
+    pub async fn page_store_stats(&self) -> Option<PageStoreStats> {
+        let buffer_pool = self.page_store.as_ref()?;
+        Some(PageStoreStats {
+            buffer_pool: buffer_pool.stats().await,
+            cache: buffer_pool.cache_metrics().await,
+            pager: buffer_pool.pager().stats().await,
+        })
+    }
+
+    /// Get cumulative row cache statistics since the engine was opened
+    #[must_use]
+    pub fn cache_statistics(&self) -> CacheStatistics {
+        CacheStatistics {
+            hits: self.cache_hits.load(Ordering::Relaxed),
+            misses: self.cache_misses.load(Ordering::Relaxed),
+            cached_rows: self.row_cache.len(),
+            capacity: self.row_cache.cap().get(),
+        }
+    }
+
     /// Get a reference to the transaction manager
     #[must_use]
     pub const fn get_transaction_manager(&self) -> &TransactionManager {
@@ -101,6 +185,14 @@ impl StorageEngine {
         self.metadata.tables.len()
     }
 
+    /// Get the schemas of all tables, ordered by table name
+    #[must_use]
+    pub fn get_table_schemas(&self) -> Vec<&super::types::TableSchema> {
+        let mut schemas: Vec<_> = self.metadata.tables.values().collect();
+        schemas.sort_by(|a, b| a.name.cmp(&b.name));
+        schemas
+    }
+
     /// Get the schema for a specific table
     ///
     /// Returns the table schema if it exists, or None if the table doesn't exist.
@@ -110,6 +202,29 @@ impl StorageEngine {
         self.metadata.tables.get(table_name)
     }
 
+    /// Get the number of rows in a table, from its primary key index
+    #[must_use]
+    pub fn get_table_row_count(&self, table_name: &str) -> Option<usize> {
+        let schema = self.metadata.tables.get(table_name)?;
+        self.indexes
+            .get(&format!("{table_name}_{}", schema.primary_key))
+            .map(BTreeMap::len)
+    }
+
+    /// Get the ids of a table's rows in primary key index order
+    pub(crate) fn table_row_ids(&self, table_name: &str) -> Option<Vec<RowId>> {
+        let schema = self.metadata.tables.get(table_name)?;
+        self.indexes
+            .get(&format!("{table_name}_{}", schema.primary_key))
+            .map(|index| index.values().copied().collect())
+    }
+
+    /// Get the optimizer statistics last stored for a table by `ANALYZE`
+    #[must_use]
+    pub fn get_table_statistics(&self, table_name: &str) -> Option<&TableStatistics> {
+        self.metadata.table_statistics.get(table_name)
+    }
+
     /// Get a mutable reference to the schema for a specific table
     ///
     /// Returns the table schema if it exists, or None if the table doesn't exist.
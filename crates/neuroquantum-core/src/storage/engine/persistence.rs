@@ -128,8 +128,22 @@ impl StorageEngine {
         Ok(rows)
     }
 
+    /// Load a single row by ID from the row cache or its compressed block
+    ///
+    /// Returns `None` if the row has no compressed block, e.g. once deleted.
+    pub(crate) async fn load_row(&self, row_id: RowId) -> Result<Option<Row>> {
+        if let Some(row) = self.row_cache.peek(&row_id) {
+            return Ok(Some(row.clone()));
+        }
+        match self.compressed_blocks.get(&row_id) {
+            | Some(compressed_data) => Ok(Some(self.decompress_row(compressed_data).await?)),
+            | None => Ok(None),
+        }
+    }
+
     /// Append row to table file with DNA compression and encryption
     pub(crate) async fn append_row_to_file(&mut self, table: &str, row: &Row) -> Result<()> {
+        self.bump_table_version(table);
         let table_path = self.data_dir.join("tables").join(format!("{table}.nqdb"));
 
         // Get or create compressed data for this row
@@ -207,6 +221,7 @@ impl StorageEngine {
         table: &str,
         updated_rows: &[Row],
     ) -> Result<()> {
+        self.bump_table_version(table);
         let table_path = self.data_dir.join("tables").join(format!("{table}.nqdb"));
 
         // Create a HashMap of updated rows for quick lookup
@@ -247,6 +262,7 @@ impl StorageEngine {
         table: &str,
         deleted_row_ids: &[RowId],
     ) -> Result<()> {
+        self.bump_table_version(table);
         let table_path = self.data_dir.join("tables").join(format!("{table}.nqdb"));
 
         // Create a temporary file
@@ -452,6 +468,14 @@ impl StorageEngine {
         // Load compressed blocks
         self.load_compressed_blocks().await?;
 
+        // Rebuild secondary indexes from the table rows
+        self.rebuild_secondary_indexes().await?;
+
+        // Databases created before keys were indexed get the index now
+        if self.metadata.tables.contains_key("_storage") {
+            self.ensure_storage_key_index().await?;
+        }
+
         info!(
             "✅ Loaded {} tables, next_row_id: {}, next_lsn: {}",
             self.metadata.tables.len(),
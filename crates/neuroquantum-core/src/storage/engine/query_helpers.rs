@@ -14,7 +14,7 @@ use super::StorageEngine;
 use crate::dna::{DNACompressor, EncodedData};
 use crate::storage::query::{ComparisonOperator, OrderBy, SortDirection, WhereClause};
 use crate::storage::row::Row;
-use crate::storage::types::{DataType, TableSchema, Value};
+use crate::storage::types::{ConstraintViolation, DataType, TableSchema, Value};
 
 impl StorageEngine {
     /// Validate table schema
@@ -25,6 +25,7 @@ impl StorageEngine {
     /// - Table name is empty
     /// - No columns are defined
     /// - Primary key column doesn't exist
+    /// - A CHECK constraint refers to a column that doesn't exist
     pub(crate) fn validate_schema(&self, schema: &TableSchema) -> Result<()> {
         if schema.name.is_empty() {
             return Err(anyhow!("Table name cannot be empty"));
@@ -46,6 +47,28 @@ impl StorageEngine {
             ));
         }
 
+        // Primary keys are indexed and compared in plaintext
+        if schema
+            .columns
+            .iter()
+            .any(|col| col.encrypted && col.name == schema.primary_key)
+        {
+            return Err(anyhow!(
+                "Primary key '{}' cannot be ENCRYPTED",
+                schema.primary_key
+            ));
+        }
+
+        for check in &schema.check_constraints {
+            if !schema.columns.iter().any(|col| col.name == check.column) {
+                return Err(anyhow!(
+                    "CHECK constraint '{}' refers to unknown column '{}'",
+                    check.name,
+                    check.column
+                ));
+            }
+        }
+
         Ok(())
     }
 
@@ -55,10 +78,19 @@ impl StorageEngine {
     ///
     /// Returns an error if:
     /// - Column type doesn't match the value type
-    /// - Required column is missing
+    /// - A NOT NULL column is NULL or missing, as [`ConstraintViolation::NotNull`]
+    /// - A CHECK constraint fails, as [`ConstraintViolation::Check`]
     pub(crate) fn validate_row(&self, schema: &TableSchema, row: &Row) -> Result<()> {
         for column in &schema.columns {
+            let not_null = || ConstraintViolation::NotNull {
+                table: schema.name.clone(),
+                column: column.name.clone(),
+            };
             if let Some(value) = row.fields.get(&column.name) {
+                if matches!(value, Value::Null) && !column.nullable {
+                    return Err(not_null().into());
+                }
+
                 // Type validation
                 let valid_type = match (&column.data_type, value) {
                     | (DataType::Integer, Value::Integer(_)) => true,
@@ -70,7 +102,7 @@ impl StorageEngine {
                     // Serial types store as Integer values
                     | (DataType::BigSerial, Value::Integer(_)) => true,
                     | (DataType::Serial, Value::Integer(_)) => true,
-                    | (_, Value::Null) => column.nullable,
+                    | (_, Value::Null) => true,
                     | _ => false,
                 };
 
@@ -87,7 +119,28 @@ impl StorageEngine {
                 && !column.auto_increment
                 && !matches!(column.data_type, DataType::Serial | DataType::BigSerial)
             {
-                return Err(anyhow!("Required column '{}' is missing", column.name));
+                return Err(not_null().into());
+            }
+        }
+
+        for check in &schema.check_constraints {
+            // NULL satisfies a CHECK constraint
+            let Some(value) = row
+                .fields
+                .get(&check.column)
+                .filter(|value| !matches!(value, Value::Null))
+            else {
+                continue;
+            };
+            if !self
+                .evaluate_condition(value, &check.operator, &check.value)
+                .unwrap_or(false)
+            {
+                return Err(ConstraintViolation::Check {
+                    constraint: check.name.clone(),
+                    expression: check.to_string(),
+                }
+                .into());
             }
         }
 
@@ -148,6 +201,8 @@ impl StorageEngine {
             }
         }
 
+        self.insert_into_secondary_indexes(&schema.name, row);
+
         Ok(())
     }
 
@@ -170,6 +225,8 @@ impl StorageEngine {
             }
         }
 
+        self.remove_from_secondary_indexes(&schema.name, row);
+
         Ok(())
     }
 
@@ -241,6 +298,20 @@ impl StorageEngine {
         Ok(filtered_rows)
     }
 
+    /// Whether `row` satisfies every condition of `where_clause`
+    ///
+    /// Conditions on missing fields or comparing values of different types do
+    /// not match.
+    #[must_use]
+    pub fn row_matches(&self, row: &Row, where_clause: &WhereClause) -> bool {
+        where_clause.conditions.iter().all(|condition| {
+            row.fields.get(&condition.field).is_some_and(|value| {
+                self.evaluate_condition(value, &condition.operator, &condition.value)
+                    .unwrap_or(false)
+            })
+        })
+    }
+
     /// Evaluate a single condition
     ///
     /// # Errors
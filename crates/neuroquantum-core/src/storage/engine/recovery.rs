@@ -73,7 +73,7 @@ impl StorageEngine {
                 // Undo ALTER TABLE by restoring the original schema
                 self.metadata
                     .tables
-                    .insert(table.clone(), old_schema.clone());
+                    .insert(table.clone(), (**old_schema).clone());
                 debug!("Undoing alter table: {} (restored original schema)", table);
             },
         }
@@ -102,6 +102,7 @@ impl StorageEngine {
             .clone();
 
         // Apply the row to storage
+        self.bump_table_version(table);
         let compressed_data = self.compress_row(&row).await?;
         self.compressed_blocks.insert(row.id, compressed_data);
 
@@ -123,6 +124,7 @@ impl StorageEngine {
         before_image: Option<&[u8]>,
     ) -> Result<()> {
         debug!("⏪ Applying before-image (UNDO) for {}.{}", table, key);
+        self.bump_table_version(table);
 
         if let Some(before_data) = before_image {
             // Deserialize the old row from before-image
@@ -275,8 +277,8 @@ impl StorageEngine {
     ///
     /// A checkpoint records the current state of all active transactions
     /// and forces all dirty pages to disk. This reduces recovery time by
-    /// establishing a known-good starting point.
-    pub async fn checkpoint(&self) -> Result<()> {
+    /// establishing a known-good starting point. Returns the checkpoint LSN.
+    pub async fn checkpoint(&self) -> Result<crate::transaction::LSN> {
         self.transaction_manager
             .checkpoint()
             .await
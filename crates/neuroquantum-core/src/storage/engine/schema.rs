@@ -13,8 +13,9 @@ use super::StorageEngine;
 use crate::storage::id_generation::AutoIncrementConfig;
 use crate::storage::query::AlterTableOp;
 use crate::storage::row::CompressedRowEntry;
+use crate::storage::stats::TableStatistics;
 use crate::storage::transaction_log::Operation;
-use crate::storage::types::{DataType, TableSchema, Value};
+use crate::storage::types::{DataType, RowId, TableSchema, Value};
 
 impl StorageEngine {
     /// Create a new table with given schema
@@ -68,12 +69,16 @@ impl StorageEngine {
         self.metadata
             .tables
             .insert(schema_to_store.name.clone(), schema_to_store.clone());
+        self.bump_table_version(&table_name);
 
         // Create index in memory
         self.indexes.insert(
             format!("{}_{}", schema.name, schema.primary_key),
             BTreeMap::new(),
         );
+        self.primary_key_order
+            .insert(table_name.clone(), BTreeMap::new());
+        self.create_foreign_key_indexes(&schema_to_store);
 
         // Log operation
         let operation = Operation::CreateTable { schema };
@@ -218,6 +223,9 @@ impl StorageEngine {
 
         // Remove table from metadata
         self.metadata.tables.remove(table_name);
+        self.metadata.table_statistics.remove(table_name);
+        self.bump_table_version(table_name);
+        self.drop_table_indexes(table_name);
 
         // Log the DROP TABLE operation
         let operation = Operation::DropTable {
@@ -252,6 +260,7 @@ impl StorageEngine {
     /// Returns an error if:
     /// - Table doesn't exist
     /// - Column already exists (for ADD)
+    /// - A NOT NULL column without DEFAULT is added to a table with rows
     /// - Column doesn't exist (for DROP/RENAME/MODIFY)
     /// - Attempting to drop primary key
     /// - Data conversion fails (for MODIFY)
@@ -279,6 +288,14 @@ impl StorageEngine {
             .ok_or_else(|| anyhow!("Table '{table_name}' does not exist"))?
             .clone();
 
+        // Rows of all tables share `compressed_blocks`, so only rewrite this table's
+        let row_ids: Vec<_> = self
+            .load_table_rows(table_name)
+            .await?
+            .iter()
+            .map(|row| row.id)
+            .collect();
+
         // Create new schema based on operation
         let mut new_schema = old_schema.clone();
 
@@ -289,6 +306,22 @@ impl StorageEngine {
                     return Err(anyhow!("Column '{}' already exists", column.name));
                 }
 
+                // Existing rows would need re-encrypting in place, so only CREATE TABLE may do it
+                if column.encrypted {
+                    return Err(anyhow!(
+                        "Column '{}' cannot be added as ENCRYPTED; declare it in CREATE TABLE",
+                        column.name
+                    ));
+                }
+
+                // Existing rows would be left without a value for the column
+                if !column.nullable && column.default_value.is_none() && !row_ids.is_empty() {
+                    return Err(anyhow!(
+                        "Column '{}' is NOT NULL and needs a DEFAULT for the existing rows",
+                        column.name
+                    ));
+                }
+
                 // Add the new column
                 new_schema.columns.push(column.clone());
 
@@ -304,10 +337,7 @@ impl StorageEngine {
                 // Update all existing rows with default value or NULL
                 let default_value = column.default_value.clone().unwrap_or(Value::Null);
 
-                // Collect row IDs to update (avoid borrow issues)
-                let row_ids: Vec<_> = self.compressed_blocks.keys().copied().collect();
-
-                for row_id in row_ids {
+                for &row_id in &row_ids {
                     if let Some(encoded_data) = self.compressed_blocks.get(&row_id) {
                         // Decompress the row
                         let mut row_data = self.decompress_row(encoded_data).await?;
@@ -348,10 +378,13 @@ impl StorageEngine {
                 // Remove from auto_increment_columns if present
                 new_schema.auto_increment_columns.remove(column_name);
 
-                // Remove column data from all existing rows
-                let row_ids: Vec<_> = self.compressed_blocks.keys().copied().collect();
+                // CHECK constraints on the column go with it
+                new_schema
+                    .check_constraints
+                    .retain(|check| check.column != *column_name);
 
-                for row_id in row_ids {
+                // Remove column data from all existing rows
+                for &row_id in &row_ids {
                     if let Some(encoded_data) = self.compressed_blocks.get(&row_id) {
                         let mut row_data = self.decompress_row(encoded_data).await?;
 
@@ -400,10 +433,14 @@ impl StorageEngine {
                         .insert(new_name.clone(), new_config);
                 }
 
-                // Rename column in all existing rows
-                let row_ids: Vec<_> = self.compressed_blocks.keys().copied().collect();
+                for check in &mut new_schema.check_constraints {
+                    if check.column == *old_name {
+                        check.column = new_name.clone();
+                    }
+                }
 
-                for row_id in row_ids {
+                // Rename column in all existing rows
+                for &row_id in &row_ids {
                     if let Some(encoded_data) = self.compressed_blocks.get(&row_id) {
                         let mut row_data = self.decompress_row(encoded_data).await?;
 
@@ -441,9 +478,7 @@ impl StorageEngine {
                 new_schema.columns[column_index].data_type = new_data_type.clone();
 
                 // Convert data in all existing rows
-                let row_ids: Vec<_> = self.compressed_blocks.keys().copied().collect();
-
-                for row_id in row_ids {
+                for &row_id in &row_ids {
                     if let Some(encoded_data) = self.compressed_blocks.get(&row_id) {
                         let mut row_data = self.decompress_row(encoded_data).await?;
 
@@ -474,8 +509,8 @@ impl StorageEngine {
         // Log the operation for WAL
         let operation_log = Operation::AlterTable {
             table: table_name.to_string(),
-            old_schema: old_schema.clone(),
-            new_schema: new_schema.clone(),
+            old_schema: Box::new(old_schema.clone()),
+            new_schema: Box::new(new_schema.clone()),
         };
         self.log_operation(operation_log).await?;
 
@@ -488,7 +523,7 @@ impl StorageEngine {
         self.save_metadata().await?;
 
         // Rewrite the table file with updated rows
-        self.rewrite_table_file(table_name).await?;
+        self.rewrite_table_file(table_name, &row_ids).await?;
 
         info!("✅ Table '{}' altered successfully", table_name);
         Ok(())
@@ -558,8 +593,13 @@ impl StorageEngine {
         Ok(())
     }
 
-    /// Rewrite the entire table file with current data from `compressed_blocks`
-    pub(crate) async fn rewrite_table_file(&mut self, table_name: &str) -> Result<()> {
+    /// Rewrite the table file with the current data of `row_ids` from `compressed_blocks`
+    pub(crate) async fn rewrite_table_file(
+        &mut self,
+        table_name: &str,
+        row_ids: &[RowId],
+    ) -> Result<()> {
+        self.bump_table_version(table_name);
         let table_path = self
             .data_dir
             .join("tables")
@@ -578,8 +618,8 @@ impl StorageEngine {
             .open(&table_path)
             .await?;
 
-        // Get all rows for this table (sorted by ID for consistency)
-        let mut row_ids: Vec<_> = self.compressed_blocks.keys().copied().collect();
+        // Write rows sorted by ID for consistency
+        let mut row_ids = row_ids.to_vec();
         row_ids.sort_unstable();
 
         for row_id in row_ids {
@@ -610,6 +650,29 @@ impl StorageEngine {
         }
 
         file.flush().await?;
+
+        // Immediately persist compressed blocks to quantum directory
+        self.save_compressed_blocks().await?;
+
         Ok(())
     }
+
+    /// Store optimizer statistics for a table alongside its schema
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table does not exist or the metadata cannot be saved.
+    pub async fn set_table_statistics(
+        &mut self,
+        table_name: &str,
+        statistics: TableStatistics,
+    ) -> Result<()> {
+        if !self.metadata.tables.contains_key(table_name) {
+            return Err(anyhow!("Table '{table_name}' does not exist"));
+        }
+        self.metadata
+            .table_statistics
+            .insert(table_name.to_string(), statistics);
+        self.save_metadata().await
+    }
 }
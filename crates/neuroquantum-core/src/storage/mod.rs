@@ -68,18 +68,23 @@ pub mod wal;
 // Core types
 // Backup and restore
 pub use backup::{
-    BackupConfig, BackupManager, BackupMetadata, BackupStats, BackupStorageBackend,
-    BackupStorageType, BackupType, IncrementalBackup, LocalBackend, RestoreManager, RestoreOptions,
-    RestoreStats, S3Backend, S3Config,
+    AzureBackend, AzureConfig, BackupConfig, BackupManager, BackupMetadata, BackupStats,
+    BackupStorageBackend, BackupStorageType, BackupType, IncrementalBackup, LocalBackend,
+    RecoveryTarget, RestoreManager, RestoreOptions, RestoreStats, S3Backend, S3Config,
 };
 // B+ tree
-pub use btree::{BTree, BTreeConfig};
+pub use btree::{BTree, BTreeConfig, CompositeKey};
 // Buffer pool
-pub use buffer::{BufferPoolConfig, BufferPoolManager, BufferPoolStats, EvictionPolicyType};
+pub use buffer::{
+    BufferPoolConfig, BufferPoolManager, BufferPoolStats, CacheMetrics, EvictionPolicyType,
+};
 // Encryption
 pub use encryption::{EncryptedData, EncryptionManager};
 // Storage engine
-pub use engine::{BatchOperation, BatchResult, StorageEngine};
+pub use engine::{
+    BatchOperation, BatchResult, IndexAccess, RowChange, RowChangeKind, StorageEngine,
+    CHANGE_FEED_CAPACITY, REDACTED_PLACEHOLDER,
+};
 // ID generation
 pub use id_generation::{AutoIncrementConfig, IdGenerationStrategy};
 // Migration
@@ -90,16 +95,19 @@ pub use migration::{
     SqlExecutor, ValidationResult,
 };
 // Pager
-pub use pager::{PageStorageManager, PagerConfig, StorageStats, SyncMode};
+pub use pager::{FreeListStrategy, PageStorageManager, PagerConfig, StorageStats, SyncMode};
 // Query types
 pub use query::{
-    AlterTableOp, ComparisonOperator, Condition, DeleteQuery, InsertQuery, OrderBy, SelectQuery,
-    SortDirection, UpdateQuery, WhereClause,
+    AlterTableOp, ComparisonOperator, Condition, DeleteQuery, FieldAccess, InsertQuery, OrderBy,
+    SelectQuery, SortDirection, UpdateQuery, WhereClause,
 };
 // Row types
 pub use row::Row;
 // Statistics and metadata
-pub use stats::{DatabaseMetadata, QueryExecutionStats};
+pub use stats::{
+    CacheStatistics, ColumnStatistics, DatabaseMetadata, MaintenanceStatus, PageStoreStats,
+    QueryExecutionStats, TableStatistics, VacuumStats, WalPruneStats,
+};
 // Compressed row entry is pub(crate) for internal use only
 
 // Test helpers (available in all builds for integration tests)
@@ -107,7 +115,10 @@ pub use test_helpers::{create_test_row, create_test_schema};
 // Transaction log types
 pub use transaction_log::{Operation, Transaction, TransactionId, TransactionStatus, LSN};
 pub use types::{
-    ColumnDefinition, DataType, ForeignKeyConstraint, ReferentialAction, RowId, TableSchema, Value,
+    CheckConstraint, ColumnDefinition, ConstraintViolation, DataType, ForeignKeyConstraint,
+    IndexDefinition, ReferentialAction, RowId, TableSchema, Value,
 };
 // WAL
-pub use wal::{RecoveryStats, WALConfig, WALManager};
+pub use wal::{
+    CommitResult, Durability, RecoveryPhase, RecoveryProgress, RecoveryStats, WALConfig, WALManager,
+};
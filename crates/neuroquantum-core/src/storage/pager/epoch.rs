@@ -0,0 +1,174 @@
+//! Per-page modification epochs
+//!
+//! Every page write is stamped with the pager's current epoch, and starting a
+//! backup closes the epoch. The pages an incremental backup has to copy are
+//! exactly those stamped with an epoch after the one its parent closed.
+//!
+//! Writes only mark the stamps dirty in memory. They are saved next to the
+//! database file in `<file>.epochs` when the pager syncs (periodically, on
+//! `sync` or `flush`) or an epoch is closed, alongside the fsync of the pages
+//! they describe. A file saved while dirty, or one that can't be read, isn't
+//! trusted and every page is treated as modified. Writes after the last sync
+//! have no saved stamps, just as they are not yet durable.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tracing::warn;
+
+use super::page::PageId;
+
+/// Modification epoch of every page of a database file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageEpochs {
+    /// Epoch stamped on pages written now
+    current: u64,
+    /// Epoch of the last write of each page
+    pages: HashMap<PageId, u64>,
+    /// False once pages were written after the stamps were last saved
+    clean: bool,
+    /// Number of writes recorded since the epochs were loaded
+    #[serde(skip)]
+    writes: u64,
+}
+
+impl PageEpochs {
+    /// Sidecar file holding the epochs of `db_file`
+    #[must_use]
+    pub fn path_for(db_file: &Path) -> PathBuf {
+        let mut path = db_file.as_os_str().to_owned();
+        path.push(".epochs");
+        PathBuf::from(path)
+    }
+
+    /// Load the epochs saved at `path`
+    ///
+    /// Without a trustworthy file, the first `total_pages` pages are stamped
+    /// with a new epoch so the next incremental backup copies all of them.
+    pub async fn load(path: &Path, total_pages: u64) -> Result<Self> {
+        let saved = match tokio::fs::read(path).await {
+            | Ok(bytes) => match bincode::deserialize::<Self>(&bytes) {
+                | Ok(epochs) => Some(epochs),
+                | Err(e) => {
+                    warn!("⚠️ Discarding unreadable page epochs: {}", e);
+                    None
+                },
+            },
+            | Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            | Err(e) => return Err(e).context("Failed to read page epochs"),
+        };
+
+        match saved {
+            | Some(epochs) if epochs.clean => Ok(epochs),
+            | saved => {
+                let mut epochs = saved.unwrap_or(Self {
+                    current: 0,
+                    pages: HashMap::new(),
+                    clean: false,
+                    writes: 0,
+                });
+                if total_pages > 1 {
+                    warn!("⚠️ Page epochs out of date, treating every page as modified");
+                    epochs.current += 1;
+                    for page_id in (0..total_pages).map(PageId) {
+                        epochs.pages.insert(page_id, epochs.current);
+                    }
+                }
+                epochs.clean = false;
+                Ok(epochs)
+            },
+        }
+    }
+
+    /// Save the epochs to `path`
+    ///
+    /// The file is replaced atomically and synced before this returns.
+    pub async fn save(&self, path: &Path) -> Result<()> {
+        let bytes = bincode::serialize(self)
+            .map_err(|e| anyhow!("Failed to serialize page epochs: {e}"))?;
+        let tmp_path = path.with_extension("epochs.tmp");
+        let mut file = tokio::fs::File::create(&tmp_path)
+            .await
+            .context("Failed to write page epochs")?;
+        file.write_all(&bytes)
+            .await
+            .context("Failed to write page epochs")?;
+        file.sync_all()
+            .await
+            .context("Failed to sync page epochs")?;
+        tokio::fs::rename(&tmp_path, path)
+            .await
+            .context("Failed to replace page epochs")
+    }
+
+    /// Stamp `page_id` with the current epoch and mark the epochs dirty
+    ///
+    /// Nothing is written; the next sync saves the stamps.
+    pub fn record(&mut self, page_id: PageId) {
+        self.pages.insert(page_id, self.current);
+        self.clean = false;
+        self.writes += 1;
+    }
+
+    /// Check if no pages were written since the epochs were saved
+    #[must_use]
+    pub const fn is_clean(&self) -> bool {
+        self.clean
+    }
+
+    /// Number of writes recorded so far, read before syncing the pages
+    #[must_use]
+    pub const fn writes(&self) -> u64 {
+        self.writes
+    }
+
+    /// Mark the epochs as matching the pages on disk, ahead of saving them
+    ///
+    /// `writes` is the count read before the pages were synced. If pages were
+    /// written since, they may not be on disk yet and the epochs stay dirty.
+    pub const fn mark_clean_through(&mut self, writes: u64) {
+        self.clean = self.writes == writes;
+    }
+
+    /// Close the current epoch and return it
+    ///
+    /// Pages written afterwards carry a later epoch.
+    pub const fn advance(&mut self) -> u64 {
+        self.current += 1;
+        self.current - 1
+    }
+
+    /// Drop the stamps of pages at or beyond `total_pages`, after the file
+    /// was truncated to that many pages
+    pub fn truncate(&mut self, total_pages: u64) {
+        self.pages.retain(|page_id, _| page_id.0 < total_pages);
+    }
+
+    /// Epoch stamped on pages written now
+    #[must_use]
+    pub const fn current(&self) -> u64 {
+        self.current
+    }
+
+    /// Epoch of the last write of `page_id`
+    #[must_use]
+    pub fn page_epoch(&self, page_id: PageId) -> Option<u64> {
+        self.pages.get(&page_id).copied()
+    }
+
+    /// Pages written after `epoch` was closed, in page order
+    #[must_use]
+    pub fn modified_since(&self, epoch: u64) -> Vec<PageId> {
+        let mut pages: Vec<PageId> = self
+            .pages
+            .iter()
+            .filter(|(_, &page_epoch)| page_epoch > epoch)
+            .map(|(&page_id, _)| page_id)
+            .collect();
+        pages.sort_unstable_by_key(|page_id| page_id.0);
+        pages
+    }
+}
@@ -1,6 +1,7 @@
 //! Free page list management
 //!
-//! Tracks which pages are free and can be reused
+//! Tracks which pages are free and can be reused, handing them out in the
+//! order chosen by a [`FreeListStrategy`]
 
 use std::collections::VecDeque;
 
@@ -9,6 +10,21 @@ use serde::{Deserialize, Serialize};
 
 use super::page::PageId;
 
+/// Order in which free pages are reused
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FreeListStrategy {
+    /// Oldest freed page first
+    #[default]
+    Fifo,
+    /// Most recently freed page first
+    Lifo,
+    /// Lowest page ID first, filling holes from the start of the file
+    LowestId,
+    /// Page closest to the allocation's locality hint, so logically adjacent
+    /// pages stay physically close; without a hint, lowest page ID first
+    NearestToHint,
+}
+
 /// Free list for tracking available pages
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FreeList {
@@ -34,14 +50,44 @@ impl FreeList {
         self.count += 1;
     }
 
-    /// Pop a free page from the list
+    /// Pop a free page from the list, oldest first
     pub fn pop_free_page(&mut self) -> Option<PageId> {
-        if let Some(page_id) = self.free_pages.pop_front() {
-            self.count -= 1;
-            Some(page_id)
-        } else {
-            None
-        }
+        self.pop_free_page_with(FreeListStrategy::Fifo, None)
+    }
+
+    /// Pop the free page `strategy` picks
+    ///
+    /// `hint` is only used by [`FreeListStrategy::NearestToHint`], typically
+    /// the page the caller's object currently ends at. Ties in distance go to
+    /// the lower page ID.
+    pub fn pop_free_page_with(
+        &mut self,
+        strategy: FreeListStrategy,
+        hint: Option<PageId>,
+    ) -> Option<PageId> {
+        let index = match (strategy, hint) {
+            | (FreeListStrategy::Fifo, _) => 0,
+            | (FreeListStrategy::Lifo, _) => self.free_pages.len().checked_sub(1)?,
+            | (FreeListStrategy::LowestId, _) | (FreeListStrategy::NearestToHint, None) => {
+                self.position_min_by_key(|page_id| page_id.0)?
+            },
+            | (FreeListStrategy::NearestToHint, Some(hint)) => {
+                self.position_min_by_key(|page_id| (page_id.0.abs_diff(hint.0), page_id.0))?
+            },
+        };
+
+        let page_id = self.free_pages.remove(index)?;
+        self.count -= 1;
+        Some(page_id)
+    }
+
+    /// Index of the free page with the smallest key
+    fn position_min_by_key<K: Ord>(&self, key: impl Fn(&PageId) -> K) -> Option<usize> {
+        self.free_pages
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, page_id)| key(page_id))
+            .map(|(index, _)| index)
     }
 
     /// Get the number of free pages
@@ -72,6 +118,12 @@ impl FreeList {
         self.free_pages.iter().copied().collect()
     }
 
+    /// Keep only the free pages for which `keep` returns true
+    pub fn retain(&mut self, mut keep: impl FnMut(PageId) -> bool) {
+        self.free_pages.retain(|&page_id| keep(page_id));
+        self.count = self.free_pages.len();
+    }
+
     /// Clear all free pages
     pub fn clear(&mut self) {
         self.free_pages.clear();
@@ -171,4 +223,63 @@ mod tests {
 
         assert!(free_list.is_empty());
     }
+
+    fn free_list_of(pages: &[u64]) -> FreeList {
+        let mut free_list = FreeList::new();
+        for &page in pages {
+            free_list.add_free_page(PageId(page));
+        }
+        free_list
+    }
+
+    #[test]
+    fn test_free_list_lifo() {
+        let mut free_list = free_list_of(&[5, 10, 15]);
+        let popped: Vec<PageId> =
+            std::iter::from_fn(|| free_list.pop_free_page_with(FreeListStrategy::Lifo, None))
+                .collect();
+        assert_eq!(popped, vec![PageId(15), PageId(10), PageId(5)]);
+        assert_eq!(free_list.free_count(), 0);
+    }
+
+    #[test]
+    fn test_free_list_lowest_id_ascending() {
+        let mut free_list = free_list_of(&[42, 7, 19, 3, 28]);
+        let popped: Vec<PageId> = std::iter::from_fn(|| {
+            free_list.pop_free_page_with(FreeListStrategy::LowestId, Some(PageId(30)))
+        })
+        .collect();
+        assert_eq!(
+            popped,
+            vec![PageId(3), PageId(7), PageId(19), PageId(28), PageId(42)]
+        );
+        assert!(free_list.is_empty());
+    }
+
+    #[test]
+    fn test_free_list_nearest_to_hint() {
+        let mut free_list = free_list_of(&[2, 90, 40, 57, 61]);
+        let strategy = FreeListStrategy::NearestToHint;
+
+        assert_eq!(
+            free_list.pop_free_page_with(strategy, Some(PageId(60))),
+            Some(PageId(61))
+        );
+        // Then the next nearest
+        assert_eq!(
+            free_list.pop_free_page_with(strategy, Some(PageId(60))),
+            Some(PageId(57))
+        );
+        // 40 and 90 are equally far from 65; ties go to the lower page
+        assert_eq!(
+            free_list.pop_free_page_with(strategy, Some(PageId(65))),
+            Some(PageId(40))
+        );
+        // Without a hint it falls back to the lowest page
+        assert_eq!(
+            free_list.pop_free_page_with(strategy, None),
+            Some(PageId(2))
+        );
+        assert_eq!(free_list.free_count(), 1);
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! Provides efficient async read/write operations for database pages
 
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
@@ -28,6 +29,8 @@ pub struct PageIO {
     file: Arc<RwLock<File>>,
     /// Configuration
     config: PagerConfig,
+    /// Number of completed syncs
+    syncs: AtomicU64,
 }
 
 impl PageIO {
@@ -36,6 +39,7 @@ impl PageIO {
         Self {
             file: Arc::new(RwLock::new(file)),
             config,
+            syncs: AtomicU64::new(0),
         }
     }
 
@@ -109,7 +113,16 @@ impl PageIO {
 
         let file = self.file.write().await;
 
-        file.sync_all().await.context("Failed to sync file to disk")
+        file.sync_all()
+            .await
+            .context("Failed to sync file to disk")?;
+        self.syncs.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Number of completed syncs
+    pub fn sync_count(&self) -> u64 {
+        self.syncs.load(Ordering::Relaxed)
     }
 
     /// Read multiple pages in batch with optimized vectored I/O
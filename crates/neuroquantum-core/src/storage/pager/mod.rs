@@ -4,11 +4,15 @@
 //! - 4KB page-based storage
 //! - Free page tracking
 //! - Page allocation/deallocation
-//! - Checksum validation
+//! - Checksum validation and scrubbing
+//! - Per-page modification epochs for incremental backups
 //! - Async file operations
+//! - Optional periodic fsync in the background
 
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{anyhow, Context, Result};
 use lru::LruCache;
@@ -16,13 +20,15 @@ use tokio::fs::OpenOptions;
 use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
 
+pub mod epoch;
 pub mod free_list;
 pub mod io;
 pub mod page;
 
-pub use free_list::FreeList;
+pub use epoch::PageEpochs;
+pub use free_list::{FreeList, FreeListStrategy};
 pub use io::PageIO;
-pub use page::{Page, PageHeader, PageId, PageType, PAGE_SIZE};
+pub use page::{ChecksumAlgorithm, Page, PageHeader, PageId, PageType, PAGE_SIZE};
 
 /// Configuration for the page storage manager
 #[derive(Debug, Clone)]
@@ -35,6 +41,12 @@ pub struct PagerConfig {
     pub sync_mode: SyncMode,
     /// Enable direct I/O (bypass OS cache)
     pub direct_io: bool,
+    /// Algorithm used to checksum written pages
+    pub checksum_algorithm: ChecksumAlgorithm,
+    /// Exclude pages that fail a scrub from allocation
+    pub quarantine_corrupt_pages: bool,
+    /// Order in which freed pages are reused
+    pub free_list_strategy: FreeListStrategy,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -45,6 +57,13 @@ pub enum SyncMode {
     Commit,
     /// Sync after every write (slowest, safest)
     Always,
+    /// Sync in the background every `interval` instead of per write
+    ///
+    /// This trades durability for throughput: writes are only guaranteed to
+    /// survive a crash once the next tick has synced them, so up to
+    /// `interval` worth of writes can be lost. Ticks without writes since the
+    /// last sync skip the fsync. The interval must be non-zero.
+    Periodic { interval: Duration },
 }
 
 impl Default for PagerConfig {
@@ -54,6 +73,9 @@ impl Default for PagerConfig {
             enable_checksums: true,
             sync_mode: SyncMode::Commit,
             direct_io: false,
+            checksum_algorithm: ChecksumAlgorithm::default(),
+            quarantine_corrupt_pages: false,
+            free_list_strategy: FreeListStrategy::default(),
         }
     }
 }
@@ -75,6 +97,12 @@ pub struct PageStorageManager {
     total_pages: Arc<RwLock<u64>>,
     /// Page cache (simple LRU)
     page_cache: Arc<RwLock<LruCache<PageId, Page>>>,
+    /// Corrupt pages excluded from allocation (not persisted)
+    quarantine: Arc<RwLock<HashSet<PageId>>>,
+    /// Modification epoch of each page, saved on sync
+    epochs: Arc<RwLock<PageEpochs>>,
+    /// File the epochs are saved to
+    epochs_path: PathBuf,
 }
 
 impl PageStorageManager {
@@ -115,6 +143,15 @@ impl PageStorageManager {
             free_list.free_count()
         );
 
+        let epochs_path = PageEpochs::path_for(&file_path);
+        let epochs = PageEpochs::load(&epochs_path, total_pages).await?;
+
+        if let SyncMode::Periodic { interval } = config.sync_mode {
+            if interval.is_zero() {
+                return Err(anyhow!("Periodic sync interval must be non-zero"));
+            }
+        }
+
         let manager = Self {
             _file_path: file_path,
             config: config.clone(),
@@ -126,6 +163,9 @@ impl PageStorageManager {
                 #[allow(clippy::expect_used)]
                 std::num::NonZeroUsize::new(1000).expect("1000 is non-zero"),
             ))),
+            quarantine: Arc::new(RwLock::new(HashSet::new())),
+            epochs: Arc::new(RwLock::new(epochs)),
+            epochs_path,
         };
 
         // Initialize page 0 with free list if it's a new database
@@ -134,9 +174,39 @@ impl PageStorageManager {
             manager.persist_free_list(&free_list).await?;
         }
 
+        if let SyncMode::Periodic { interval } = config.sync_mode {
+            manager.spawn_periodic_sync(interval);
+        }
+
         Ok(manager)
     }
 
+    /// Sync pending writes every `interval` until the manager is dropped
+    fn spawn_periodic_sync(&self, interval: Duration) {
+        let io = Arc::downgrade(&self.io);
+        let epochs = Arc::downgrade(&self.epochs);
+        let epochs_path = self.epochs_path.clone();
+        tokio::spawn(async move {
+            let mut timer = tokio::time::interval(interval);
+            timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            // The first tick completes immediately
+            timer.tick().await;
+            loop {
+                timer.tick().await;
+                let (Some(io), Some(epochs)) = (io.upgrade(), epochs.upgrade()) else {
+                    break;
+                };
+                // Clean epochs mean nothing was written since the last sync
+                if epochs.read().await.is_clean() {
+                    continue;
+                }
+                if let Err(e) = Self::sync_files(&io, &epochs, &epochs_path).await {
+                    warn!("⚠️ Periodic sync failed: {}", e);
+                }
+            }
+        });
+    }
+
     /// Load metadata from disk or initialize new
     async fn load_metadata(io: &Arc<RwLock<PageIO>>) -> Result<(FreeList, u64)> {
         let io = io.read().await;
@@ -167,10 +237,28 @@ impl PageStorageManager {
 
     /// Allocate a new page
     pub async fn allocate_page(&self, page_type: PageType) -> Result<PageId> {
+        self.allocate_page_with_hint(page_type, None).await
+    }
+
+    /// Allocate a new page, preferring a free page close to `hint`
+    ///
+    /// `hint` is typically the last page of the object being grown. It only
+    /// steers the choice among free pages under
+    /// [`FreeListStrategy::NearestToHint`]; new pages are always appended.
+    pub async fn allocate_page_with_hint(
+        &self,
+        page_type: PageType,
+        hint: Option<PageId>,
+    ) -> Result<PageId> {
         let mut free_list = self.free_list.write().await;
 
-        // Try to reuse a free page
-        if let Some(page_id) = free_list.pop_free_page() {
+        // Try to reuse a free page, dropping any that have been quarantined
+        let strategy = self.config.free_list_strategy;
+        let quarantine = self.quarantine.read().await;
+        let reusable = std::iter::from_fn(|| free_list.pop_free_page_with(strategy, hint))
+            .find(|page_id| !quarantine.contains(page_id));
+        drop(quarantine);
+        if let Some(page_id) = reusable {
             debug!("♻️ Reusing free page: {:?}", page_id);
 
             // Initialize the page
@@ -211,7 +299,11 @@ impl PageStorageManager {
         debug!("🗑️ Deallocating page: {:?}", page_id);
 
         let mut free_list = self.free_list.write().await;
-        free_list.add_free_page(page_id);
+        if self.is_quarantined(page_id).await {
+            warn!("⚠️ Not reusing quarantined page: {:?}", page_id);
+        } else {
+            free_list.add_free_page(page_id);
+        }
 
         // Remove from cache
         let mut cache = self.page_cache.write().await;
@@ -241,6 +333,10 @@ impl PageStorageManager {
 
         // Validate checksum if enabled
         if self.config.enable_checksums && !page.verify_checksum() {
+            warn!(
+                "⚠️ Checksum validation failed for page {:?}, run a scrub to find other damaged pages",
+                page_id
+            );
             return Err(anyhow!("Checksum validation failed for page {page_id:?}"));
         }
 
@@ -260,7 +356,7 @@ impl PageStorageManager {
         // Update checksum if enabled
         let mut page = page.clone();
         if self.config.enable_checksums {
-            page.update_checksum();
+            page.update_checksum_with(self.config.checksum_algorithm);
         }
 
         // Write to disk
@@ -271,6 +367,9 @@ impl PageStorageManager {
         if self.config.sync_mode == SyncMode::Always {
             io.sync().await?;
         }
+        drop(io);
+
+        self.record_modification(page.id()).await;
 
         // Update cache
         {
@@ -281,11 +380,67 @@ impl PageStorageManager {
         Ok(())
     }
 
+    /// Stamp a written page with the current epoch
+    ///
+    /// The stamp is only saved by the next sync, keeping file I/O off the
+    /// write path.
+    async fn record_modification(&self, page_id: PageId) {
+        self.epochs.write().await.record(page_id);
+    }
+
     /// Sync all pending writes to disk
     pub async fn sync(&self) -> Result<()> {
         debug!("🔄 Syncing all writes to disk");
-        let io = self.io.read().await;
-        io.sync().await
+        Self::sync_files(&self.io, &self.epochs, &self.epochs_path).await
+    }
+
+    /// Sync the database file, then save the epochs if pages were written
+    async fn sync_files(
+        io: &RwLock<PageIO>,
+        epochs: &RwLock<PageEpochs>,
+        epochs_path: &Path,
+    ) -> Result<()> {
+        let writes = epochs.read().await.writes();
+        let io = io.read().await;
+        io.sync().await?;
+        drop(io);
+
+        // Pages written before the sync started are now on disk
+        let mut epochs = epochs.write().await;
+        if epochs.is_clean() {
+            return Ok(());
+        }
+        epochs.mark_clean_through(writes);
+        epochs.save(epochs_path).await
+    }
+
+    /// Number of times the database file was synced to disk
+    pub async fn sync_count(&self) -> u64 {
+        self.io.read().await.sync_count()
+    }
+
+    /// Close the current modification epoch and return it
+    ///
+    /// Called when a backup starts: pages written afterwards carry a later
+    /// epoch and are picked up by [`pages_modified_since`](Self::pages_modified_since).
+    /// The epochs are saved before returning, so pages written after a
+    /// restart are never stamped with the closed epoch.
+    pub async fn advance_epoch(&self) -> Result<u64> {
+        let mut epochs = self.epochs.write().await;
+        let closed = epochs.advance();
+        epochs.save(&self.epochs_path).await?;
+        Ok(closed)
+    }
+
+    /// Pages written after `epoch` was closed, in page order
+    pub async fn pages_modified_since(&self, epoch: u64) -> Vec<PageId> {
+        self.epochs.read().await.modified_since(epoch)
+    }
+
+    /// Epoch of the last write of a page, if it was written since epochs
+    /// were tracked
+    pub async fn page_epoch(&self, page_id: PageId) -> Option<u64> {
+        self.epochs.read().await.page_epoch(page_id)
     }
 
     /// Get total number of pages
@@ -298,6 +453,48 @@ impl PageStorageManager {
         self.free_list.read().await.free_count()
     }
 
+    /// Release the free pages at the end of the file, shrinking it
+    ///
+    /// Free pages followed by a used page stay on the free list. Returns the
+    /// number of pages released.
+    pub async fn compact(&self) -> Result<u64> {
+        let mut free_list = self.free_list.write().await;
+        let mut total_pages = self.total_pages.write().await;
+
+        let free: HashSet<PageId> = free_list.get_free_pages().into_iter().collect();
+        let mut new_total = *total_pages;
+        // Page 0 holds the free list and is never released
+        while new_total > 1 && free.contains(&PageId(new_total - 1)) {
+            new_total -= 1;
+        }
+        let released = *total_pages - new_total;
+        if released == 0 {
+            return Ok(0);
+        }
+
+        free_list.retain(|page_id| page_id.0 < new_total);
+        self.persist_free_list(&free_list).await?;
+        self.io
+            .write()
+            .await
+            .truncate(new_total * PAGE_SIZE as u64)
+            .await?;
+
+        let mut cache = self.page_cache.write().await;
+        for page_id in (new_total..*total_pages).map(PageId) {
+            cache.pop(&page_id);
+        }
+        drop(cache);
+        self.epochs.write().await.truncate(new_total);
+        *total_pages = new_total;
+
+        info!(
+            "🗜️ Released {} free pages at the end of the file ({} pages left)",
+            released, new_total
+        );
+        Ok(released)
+    }
+
     /// Persist the free list to page 0
     async fn persist_free_list(&self, free_list: &FreeList) -> Result<()> {
         let data = free_list.serialize()?;
@@ -306,12 +503,14 @@ impl PageStorageManager {
 
         // Update checksum if enabled
         if self.config.enable_checksums {
-            page.update_checksum();
+            page.update_checksum_with(self.config.checksum_algorithm);
         }
 
         let io = self.io.write().await;
         io.write_page(&page).await?;
+        drop(io);
 
+        self.record_modification(PageId(0)).await;
         Ok(())
     }
 
@@ -329,6 +528,91 @@ impl PageStorageManager {
         Ok(())
     }
 
+    /// Verify every allocated page on disk
+    ///
+    /// Pages are read directly from the file, bypassing the cache, and a
+    /// damaged page doesn't stop the scan. Free pages hold no data and are
+    /// skipped. With [`PagerConfig::quarantine_corrupt_pages`] the damaged
+    /// pages are also quarantined so they are never handed out again.
+    pub async fn scrub(&self) -> Result<ScrubReport> {
+        let total_pages = *self.total_pages.read().await;
+        let free_pages: HashSet<PageId> = self
+            .free_list
+            .read()
+            .await
+            .get_free_pages()
+            .into_iter()
+            .collect();
+
+        info!("🔍 Scrubbing {} pages", total_pages);
+
+        let mut report = ScrubReport::default();
+        {
+            let io = self.io.read().await;
+            for page_id in (0..total_pages).map(PageId) {
+                if free_pages.contains(&page_id) {
+                    report.pages_skipped += 1;
+                    continue;
+                }
+                report.pages_scanned += 1;
+
+                let reason = match io.read_page(page_id).await {
+                    | Err(e) => Some(format!("{e:#}")),
+                    | Ok(page) if page.id() != page_id => {
+                        Some(format!("header names {:?}", page.id()))
+                    },
+                    | Ok(page) if self.config.enable_checksums && !page.verify_checksum() => Some(
+                        format!("{:?} checksum mismatch", page.header().checksum_algorithm),
+                    ),
+                    | Ok(_) => None,
+                };
+                if let Some(reason) = reason {
+                    warn!("⚠️ Scrub found corrupt page {:?}: {}", page_id, reason);
+                    report.corrupt_pages.push(CorruptPage { page_id, reason });
+                }
+            }
+        }
+
+        if self.config.quarantine_corrupt_pages && !report.corrupt_pages.is_empty() {
+            let mut quarantine = self.quarantine.write().await;
+            let mut cache = self.page_cache.write().await;
+            for corrupt in &report.corrupt_pages {
+                cache.pop(&corrupt.page_id);
+                if quarantine.insert(corrupt.page_id) {
+                    report.quarantined.push(corrupt.page_id);
+                }
+            }
+        }
+
+        info!(
+            "🔍 Scrub finished: {} scanned, {} corrupt, {} quarantined",
+            report.pages_scanned,
+            report.corrupt_pages.len(),
+            report.quarantined.len()
+        );
+
+        Ok(report)
+    }
+
+    /// Pages excluded from allocation, in page order
+    pub async fn quarantined_pages(&self) -> Vec<PageId> {
+        let mut pages: Vec<PageId> = self.quarantine.read().await.iter().copied().collect();
+        pages.sort_unstable_by_key(|page_id| page_id.0);
+        pages
+    }
+
+    /// Check if a page is excluded from allocation
+    pub async fn is_quarantined(&self, page_id: PageId) -> bool {
+        self.quarantine.read().await.contains(&page_id)
+    }
+
+    /// Allow a repaired page to be allocated again
+    ///
+    /// Returns `false` if the page was not quarantined.
+    pub async fn release_quarantine(&self, page_id: PageId) -> bool {
+        self.quarantine.write().await.remove(&page_id)
+    }
+
     /// Get storage statistics
     pub async fn stats(&self) -> StorageStats {
         let total = *self.total_pages.read().await;
@@ -361,6 +645,35 @@ impl PageStorageManager {
     }
 }
 
+/// Outcome of [`PageStorageManager::scrub`]
+#[derive(Debug, Clone, Default)]
+pub struct ScrubReport {
+    /// Pages read and verified
+    pub pages_scanned: u64,
+    /// Free pages that were not verified
+    pub pages_skipped: u64,
+    /// Pages that failed validation, in page order
+    pub corrupt_pages: Vec<CorruptPage>,
+    /// Corrupt pages newly quarantined by this scrub
+    pub quarantined: Vec<PageId>,
+}
+
+impl ScrubReport {
+    /// Check if no corrupt pages were found
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.corrupt_pages.is_empty()
+    }
+}
+
+/// A page that failed validation during a scrub
+#[derive(Debug, Clone)]
+pub struct CorruptPage {
+    pub page_id: PageId,
+    /// Why the page failed validation
+    pub reason: String,
+}
+
 /// Storage statistics
 #[derive(Debug, Clone)]
 pub struct StorageStats {
@@ -477,6 +790,135 @@ mod tests {
         assert_eq!(manager.free_pages().await, 0);
     }
 
+    #[tokio::test]
+    async fn test_compact_releases_trailing_free_pages() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+
+        {
+            let manager = PageStorageManager::new(&db_path, PagerConfig::default())
+                .await
+                .unwrap();
+            let mut page_ids = Vec::new();
+            for _ in 0..4 {
+                page_ids.push(manager.allocate_page(PageType::Data).await.unwrap());
+            }
+
+            // Only the free page at the end can be released
+            manager.deallocate_page(page_ids[1]).await.unwrap();
+            manager.deallocate_page(page_ids[3]).await.unwrap();
+            assert_eq!(manager.compact().await.unwrap(), 1);
+            assert_eq!(manager.total_pages().await, 4);
+            assert_eq!(manager.free_pages().await, 1);
+
+            // Freeing the page in between releases the hole as well
+            manager.deallocate_page(page_ids[2]).await.unwrap();
+            assert_eq!(manager.compact().await.unwrap(), 2);
+            assert_eq!(manager.compact().await.unwrap(), 0);
+            assert_eq!(manager.free_pages().await, 0);
+            manager.flush().await.unwrap();
+        }
+
+        // Metadata page plus the one used page
+        assert_eq!(
+            tokio::fs::metadata(&db_path).await.unwrap().len(),
+            2 * PAGE_SIZE as u64
+        );
+        let manager = PageStorageManager::new(&db_path, PagerConfig::default())
+            .await
+            .unwrap();
+        assert_eq!(manager.total_pages().await, 2);
+        assert_eq!(manager.free_pages().await, 0);
+        assert_eq!(
+            manager.allocate_page(PageType::Data).await.unwrap(),
+            PageId(2)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_closed_epoch_survives_reopen() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+
+        let (page_id, closed) = {
+            let manager = PageStorageManager::new(&db_path, PagerConfig::default())
+                .await
+                .unwrap();
+            let page_id = manager.allocate_page(PageType::Data).await.unwrap();
+            manager.sync().await.unwrap();
+            (page_id, manager.advance_epoch().await.unwrap())
+        };
+
+        // Reopen without another sync, as after a crash
+        let manager = PageStorageManager::new(&db_path, PagerConfig::default())
+            .await
+            .unwrap();
+        let mut page = manager.read_page(page_id).await.unwrap();
+        page.write_data(0, b"after backup").unwrap();
+        manager.write_page(&page).await.unwrap();
+
+        assert!(manager.page_epoch(page_id).await.unwrap() > closed);
+        assert!(manager
+            .pages_modified_since(closed)
+            .await
+            .contains(&page_id));
+    }
+
+    #[tokio::test]
+    async fn test_page_writes_leave_epochs_to_sync() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let epochs_path = PageEpochs::path_for(&db_path);
+
+        let manager = PageStorageManager::new(&db_path, PagerConfig::default())
+            .await
+            .unwrap();
+        let page_id = manager.allocate_page(PageType::Data).await.unwrap();
+        manager.sync().await.unwrap();
+        let synced = tokio::fs::read(&epochs_path).await.unwrap();
+        let closed = manager.advance_epoch().await.unwrap();
+        let advanced = tokio::fs::read(&epochs_path).await.unwrap();
+        assert_ne!(advanced, synced);
+
+        // Writing the page only marks the epochs dirty
+        let mut page = manager.read_page(page_id).await.unwrap();
+        page.write_data(0, b"dirty").unwrap();
+        manager.write_page(&page).await.unwrap();
+        assert!(!manager.epochs.read().await.is_clean());
+        assert_eq!(tokio::fs::read(&epochs_path).await.unwrap(), advanced);
+
+        manager.sync().await.unwrap();
+        assert!(manager.epochs.read().await.is_clean());
+        let saved = PageEpochs::load(&epochs_path, 0).await.unwrap();
+        assert!(saved.is_clean());
+        assert!(saved.page_epoch(page_id).unwrap() > closed);
+    }
+
+    #[tokio::test]
+    async fn test_write_during_sync_keeps_epochs_dirty() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+
+        let manager = PageStorageManager::new(&db_path, PagerConfig::default())
+            .await
+            .unwrap();
+        let page_id = manager.allocate_page(PageType::Data).await.unwrap();
+        manager.sync().await.unwrap();
+
+        // Replay a sync with a write landing after the fsync
+        let writes = manager.epochs.read().await.writes();
+        manager.io.read().await.sync().await.unwrap();
+        let mut page = manager.read_page(page_id).await.unwrap();
+        page.write_data(0, b"late").unwrap();
+        manager.write_page(&page).await.unwrap();
+        manager.epochs.write().await.mark_clean_through(writes);
+        assert!(!manager.epochs.read().await.is_clean());
+
+        // The next sync covers the late write
+        manager.sync().await.unwrap();
+        assert!(manager.epochs.read().await.is_clean());
+    }
+
     #[tokio::test]
     async fn test_persistence() {
         let temp_dir = TempDir::new().unwrap();
@@ -571,4 +1013,238 @@ mod tests {
         assert_eq!(stats.free_pages, 2);
         assert_eq!(stats.used_pages, 4);
     }
+
+    /// Flip one data byte of `page_id` directly in the database file
+    fn corrupt_page(db_path: &Path, page_id: PageId) {
+        use std::io::{Read, Seek, SeekFrom, Write};
+
+        let mut file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(db_path)
+            .unwrap();
+        let offset = page_id.0 * PAGE_SIZE as u64 + page::PAGE_HEADER_SIZE as u64 + 100;
+        let mut byte = [0u8; 1];
+        file.seek(SeekFrom::Start(offset)).unwrap();
+        file.read_exact(&mut byte).unwrap();
+        byte[0] ^= 0xFF;
+        file.seek(SeekFrom::Start(offset)).unwrap();
+        file.write_all(&byte).unwrap();
+    }
+
+    async fn populated_manager(db_path: &Path, config: PagerConfig) -> PageStorageManager {
+        let manager = PageStorageManager::new(db_path, config).await.unwrap();
+        for i in 0..5 {
+            let page_id = manager.allocate_page(PageType::Data).await.unwrap();
+            let mut page = manager.read_page(page_id).await.unwrap();
+            page.write_data(0, format!("Page {i}").as_bytes()).unwrap();
+            manager.write_page(&page).await.unwrap();
+        }
+        manager.flush().await.unwrap();
+        manager
+    }
+
+    #[tokio::test]
+    async fn test_scrub_finds_corrupt_page() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+
+        let manager = populated_manager(&db_path, PagerConfig::default()).await;
+        manager.deallocate_page(PageId(2)).await.unwrap();
+
+        let report = manager.scrub().await.unwrap();
+        assert!(report.is_clean());
+        assert_eq!(report.pages_scanned, 5);
+        assert_eq!(report.pages_skipped, 1);
+
+        corrupt_page(&db_path, PageId(3));
+        // Corrupting a free page is not reported
+        corrupt_page(&db_path, PageId(2));
+
+        let report = manager.scrub().await.unwrap();
+        let corrupt: Vec<PageId> = report.corrupt_pages.iter().map(|c| c.page_id).collect();
+        assert_eq!(corrupt, vec![PageId(3)]);
+        assert!(report.quarantined.is_empty());
+        assert!(!manager.is_quarantined(PageId(3)).await);
+
+        // The cached copy is still intact; the damage only shows on disk
+        assert!(manager.read_page(PageId(3)).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_scrub_quarantines_corrupt_pages() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+
+        let config = PagerConfig {
+            quarantine_corrupt_pages: true,
+            ..Default::default()
+        };
+        let manager = populated_manager(&db_path, config).await;
+
+        corrupt_page(&db_path, PageId(4));
+        let report = manager.scrub().await.unwrap();
+        assert_eq!(report.corrupt_pages.len(), 1);
+        assert_eq!(report.corrupt_pages[0].page_id, PageId(4));
+        assert_eq!(report.quarantined, vec![PageId(4)]);
+        assert_eq!(manager.quarantined_pages().await, vec![PageId(4)]);
+
+        // The quarantined page is evicted from the cache and fails reads
+        assert!(manager.read_page(PageId(4)).await.is_err());
+
+        // Freeing it doesn't make it allocatable again
+        manager.deallocate_page(PageId(4)).await.unwrap();
+        assert_eq!(manager.free_pages().await, 0);
+        assert_eq!(
+            manager.allocate_page(PageType::Data).await.unwrap(),
+            PageId(6)
+        );
+
+        // A second scrub reports the page without quarantining it again
+        let report = manager.scrub().await.unwrap();
+        assert_eq!(report.corrupt_pages.len(), 1);
+        assert!(report.quarantined.is_empty());
+
+        assert!(manager.release_quarantine(PageId(4)).await);
+        assert!(!manager.release_quarantine(PageId(4)).await);
+    }
+
+    #[tokio::test]
+    async fn test_allocate_page_near_hint() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+
+        let config = PagerConfig {
+            free_list_strategy: FreeListStrategy::NearestToHint,
+            ..Default::default()
+        };
+        let manager = populated_manager(&db_path, config).await;
+        for page in [1, 5, 3] {
+            manager.deallocate_page(PageId(page)).await.unwrap();
+        }
+
+        // Growing an object that ends at page 4 reuses a neighbouring page
+        let page_id = manager
+            .allocate_page_with_hint(PageType::Data, Some(PageId(4)))
+            .await
+            .unwrap();
+        assert_eq!(page_id, PageId(3));
+        let page_id = manager
+            .allocate_page_with_hint(PageType::Data, Some(PageId(4)))
+            .await
+            .unwrap();
+        assert_eq!(page_id, PageId(5));
+
+        // Without a hint the lowest free page is reused, then the file grows
+        assert_eq!(
+            manager.allocate_page(PageType::Data).await.unwrap(),
+            PageId(1)
+        );
+        assert_eq!(
+            manager.allocate_page(PageType::Data).await.unwrap(),
+            PageId(6)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_periodic_sync_mode() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+
+        let interval = Duration::from_millis(200);
+        let config = PagerConfig {
+            sync_mode: SyncMode::Periodic { interval },
+            ..Default::default()
+        };
+        let manager = PageStorageManager::new(&db_path, config).await.unwrap();
+        let syncs_before = manager.sync_count().await;
+
+        let mut page_ids = Vec::new();
+        for i in 0..10 {
+            let page_id = manager.allocate_page(PageType::Data).await.unwrap();
+            let mut page = manager.read_page(page_id).await.unwrap();
+            page.write_data(0, format!("Page {i}").as_bytes()).unwrap();
+            manager.write_page(&page).await.unwrap();
+            page_ids.push(page_id);
+        }
+        // Writes don't fsync synchronously
+        assert_eq!(manager.sync_count().await, syncs_before);
+
+        // A tick syncs the pending writes
+        let deadline = tokio::time::Instant::now() + interval * 20;
+        while manager.sync_count().await == syncs_before {
+            assert!(
+                tokio::time::Instant::now() < deadline,
+                "no periodic sync happened"
+            );
+            tokio::time::sleep(interval / 2).await;
+        }
+
+        // Ticks without new writes skip the fsync
+        let synced = manager.sync_count().await;
+        tokio::time::sleep(interval * 3).await;
+        assert_eq!(manager.sync_count().await, synced);
+
+        // The synced writes are on disk, bypassing the page cache
+        let io = manager.io.read().await;
+        for (i, &page_id) in page_ids.iter().enumerate() {
+            let page = io.read_page(page_id).await.unwrap();
+            let expected = format!("Page {i}").into_bytes();
+            assert_eq!(&page.data()[..expected.len()], expected.as_slice());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sync_count_per_mode() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let always = PagerConfig {
+            sync_mode: SyncMode::Always,
+            ..Default::default()
+        };
+        let manager = PageStorageManager::new(temp_dir.path().join("always.db"), always)
+            .await
+            .unwrap();
+        let syncs_before = manager.sync_count().await;
+        let page_id = manager.allocate_page(PageType::Data).await.unwrap();
+        let page = manager.read_page(page_id).await.unwrap();
+        manager.write_page(&page).await.unwrap();
+        assert_eq!(manager.sync_count().await, syncs_before + 2);
+
+        let zero_interval = PagerConfig {
+            sync_mode: SyncMode::Periodic {
+                interval: Duration::ZERO,
+            },
+            ..Default::default()
+        };
+        assert!(
+            PageStorageManager::new(temp_dir.path().join("zero.db"), zero_interval)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scrub_with_checksum_algorithms() {
+        for checksum_algorithm in [ChecksumAlgorithm::XxHash64, ChecksumAlgorithm::Sha256] {
+            let temp_dir = TempDir::new().unwrap();
+            let db_path = temp_dir.path().join("test.db");
+
+            let config = PagerConfig {
+                checksum_algorithm,
+                ..Default::default()
+            };
+            let manager = populated_manager(&db_path, config.clone()).await;
+            drop(manager);
+
+            let manager = PageStorageManager::new(&db_path, config).await.unwrap();
+            let page = manager.read_page(PageId(1)).await.unwrap();
+            assert_eq!(page.header().checksum_algorithm, checksum_algorithm);
+
+            corrupt_page(&db_path, PageId(5));
+            let report = manager.scrub().await.unwrap();
+            let corrupt: Vec<PageId> = report.corrupt_pages.iter().map(|c| c.page_id).collect();
+            assert_eq!(corrupt, vec![PageId(5)]);
+        }
+    }
 }
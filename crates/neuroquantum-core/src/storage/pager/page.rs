@@ -9,6 +9,7 @@ use std::fmt;
 
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 /// Page size in bytes (4KB standard)
 pub const PAGE_SIZE: usize = 4096;
@@ -63,6 +64,56 @@ impl TryFrom<u8> for PageType {
     }
 }
 
+/// Digest length available in the header: the 4 checksum bytes followed by
+/// 16 extension bytes
+const DIGEST_SIZE: usize = 20;
+
+/// Algorithm used to checksum page data
+///
+/// The algorithm is recorded in each page header, so pages written with
+/// different algorithms can be verified side by side. Digests longer than
+/// [`DIGEST_SIZE`] bytes are truncated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum ChecksumAlgorithm {
+    /// CRC-32 (4 bytes), the format of pages written before the choice existed
+    #[default]
+    Crc32 = 0,
+    /// xxHash64 (8 bytes), faster than CRC-32 on large pages
+    XxHash64 = 1,
+    /// SHA-256 truncated to 20 bytes, for tamper detection
+    Sha256 = 2,
+}
+
+impl ChecksumAlgorithm {
+    /// Digest of `data`, zero-padded to [`DIGEST_SIZE`] bytes
+    fn digest(self, data: &[u8]) -> [u8; DIGEST_SIZE] {
+        let mut digest = [0u8; DIGEST_SIZE];
+        match self {
+            | Self::Crc32 => digest[..4].copy_from_slice(&crc32fast::hash(data).to_le_bytes()),
+            | Self::XxHash64 => {
+                digest[..8].copy_from_slice(&twox_hash::XxHash64::oneshot(0, data).to_le_bytes());
+            },
+            | Self::Sha256 => digest.copy_from_slice(&Sha256::digest(data)[..DIGEST_SIZE]),
+        }
+        digest
+    }
+}
+
+impl TryFrom<u8> for ChecksumAlgorithm {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            | 0 => Ok(Self::Crc32),
+            | 1 => Ok(Self::XxHash64),
+            | 2 => Ok(Self::Sha256),
+            | _ => Err(anyhow!("Invalid checksum algorithm: {value}")),
+        }
+    }
+}
+
 /// Page header (64 bytes)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PageHeader {
@@ -74,8 +125,10 @@ pub struct PageHeader {
     pub page_id: PageId,
     /// LSN (Log Sequence Number)
     pub lsn: u64,
-    /// Checksum for data integrity
+    /// Checksum for data integrity (first 4 digest bytes)
     pub checksum: u32,
+    /// Algorithm the checksum was computed with
+    pub checksum_algorithm: ChecksumAlgorithm,
     /// Free space in this page
     pub free_space: u16,
     /// Number of slots/records in this page
@@ -84,8 +137,8 @@ pub struct PageHeader {
     pub next_page: Option<PageId>,
     /// Previous page ID (for doubly-linked pages)
     pub prev_page: Option<PageId>,
-    /// Reserved for future use
-    reserved: [u8; 16],
+    /// Digest bytes beyond the first 4, for algorithms wider than CRC-32
+    checksum_ext: [u8; 16],
 }
 
 const MAGIC_NUMBER: u32 = 0xDEADBEEF;
@@ -100,11 +153,12 @@ impl PageHeader {
             page_id,
             lsn: 0,
             checksum: 0,
+            checksum_algorithm: ChecksumAlgorithm::Crc32,
             free_space: PAGE_DATA_SIZE as u16,
             slot_count: 0,
             next_page: None,
             prev_page: None,
-            reserved: [0; 16],
+            checksum_ext: [0; 16],
         }
     }
 
@@ -124,6 +178,9 @@ impl PageHeader {
         // Page type
         buf[4] = self.page_type as u8;
 
+        // Checksum algorithm
+        buf[5] = self.checksum_algorithm as u8;
+
         // Page ID
         buf[8..16].copy_from_slice(&self.page_id.0.to_le_bytes());
 
@@ -147,8 +204,8 @@ impl PageHeader {
         let prev = self.prev_page.map_or(u64::MAX, |p| p.0);
         buf[40..48].copy_from_slice(&prev.to_le_bytes());
 
-        // Reserved
-        buf[48..64].copy_from_slice(&self.reserved);
+        // Checksum extension
+        buf[48..64].copy_from_slice(&self.checksum_ext);
 
         Ok(buf)
     }
@@ -165,6 +222,7 @@ impl PageHeader {
         }
 
         let page_type = PageType::try_from(buf[4])?;
+        let checksum_algorithm = ChecksumAlgorithm::try_from(buf[5])?;
         let page_id = PageId(u64::from_le_bytes(buf[8..16].try_into()?));
         let lsn = u64::from_le_bytes(buf[16..24].try_into()?);
         let checksum = u32::from_le_bytes(buf[24..28].try_into()?);
@@ -185,8 +243,8 @@ impl PageHeader {
             Some(PageId(prev_page_raw))
         };
 
-        let mut reserved = [0u8; 16];
-        reserved.copy_from_slice(&buf[48..64]);
+        let mut checksum_ext = [0u8; 16];
+        checksum_ext.copy_from_slice(&buf[48..64]);
 
         Ok(Self {
             magic,
@@ -194,11 +252,12 @@ impl PageHeader {
             page_id,
             lsn,
             checksum,
+            checksum_algorithm,
             free_space,
             slot_count,
             next_page,
             prev_page,
-            reserved,
+            checksum_ext,
         })
     }
 }
@@ -281,22 +340,31 @@ impl Page {
         Ok(&self.data[offset..offset + len])
     }
 
-    /// Calculate checksum for the page
+    /// Calculate checksum for the page with the algorithm in its header
     #[must_use]
     pub fn calculate_checksum(&self) -> u32 {
-        // Simple CRC32 checksum
-        crc32fast::hash(&self.data)
+        let digest = self.header.checksum_algorithm.digest(&self.data);
+        u32::from_le_bytes([digest[0], digest[1], digest[2], digest[3]])
     }
 
-    /// Update checksum in header
+    /// Update checksum in header, keeping the page's checksum algorithm
     pub fn update_checksum(&mut self) {
-        self.header.checksum = self.calculate_checksum();
+        self.update_checksum_with(self.header.checksum_algorithm);
+    }
+
+    /// Update checksum in header using `algorithm`
+    pub fn update_checksum_with(&mut self, algorithm: ChecksumAlgorithm) {
+        let digest = algorithm.digest(&self.data);
+        self.header.checksum_algorithm = algorithm;
+        self.header.checksum = u32::from_le_bytes([digest[0], digest[1], digest[2], digest[3]]);
+        self.header.checksum_ext.copy_from_slice(&digest[4..]);
     }
 
     /// Verify checksum
     #[must_use]
     pub fn verify_checksum(&self) -> bool {
-        self.header.checksum == self.calculate_checksum()
+        let digest = self.header.checksum_algorithm.digest(&self.data);
+        digest[..4] == self.header.checksum.to_le_bytes() && digest[4..] == self.header.checksum_ext
     }
 
     /// Serialize page to bytes
@@ -419,6 +487,27 @@ mod tests {
         assert!(!page.verify_checksum());
     }
 
+    #[test]
+    fn test_checksum_algorithms() {
+        for algorithm in [
+            ChecksumAlgorithm::Crc32,
+            ChecksumAlgorithm::XxHash64,
+            ChecksumAlgorithm::Sha256,
+        ] {
+            let mut page = Page::new(PageId(7), PageType::Data);
+            page.write_data(0, b"Checksummed").unwrap();
+            page.update_checksum_with(algorithm);
+
+            // The algorithm survives serialization
+            let mut page = Page::from_bytes(&page.to_bytes().unwrap()).unwrap();
+            assert_eq!(page.header().checksum_algorithm, algorithm);
+            assert!(page.verify_checksum());
+
+            page.data_mut()[PAGE_DATA_SIZE - 1] ^= 0x01;
+            assert!(!page.verify_checksum());
+        }
+    }
+
     #[test]
     fn test_page_header_serialization() {
         let header = PageHeader::new(PageId(100), PageType::BTreeLeaf);
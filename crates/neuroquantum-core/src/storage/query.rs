@@ -9,6 +9,8 @@
 
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
+
 use super::types::{ColumnDefinition, DataType, Value};
 
 /// Query for selecting rows from a table
@@ -22,6 +24,16 @@ pub struct SelectQuery {
     pub offset: Option<u64>,
 }
 
+/// How values of `ENCRYPTED` columns are returned to a reader
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FieldAccess {
+    /// Replace encrypted values with a redacted placeholder
+    #[default]
+    Redacted,
+    /// Decrypt values for a reader authorized to see them
+    Decrypted,
+}
+
 /// Query for inserting a new row
 #[derive(Debug, Clone)]
 pub struct InsertQuery {
@@ -78,7 +90,7 @@ pub struct Condition {
 }
 
 /// Comparison operators for conditions
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ComparisonOperator {
     Equal,
     NotEqual,
@@ -6,8 +6,11 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use super::buffer::{BufferPoolStats, CacheMetrics};
+use super::pager::StorageStats;
 use super::transaction_log::LSN;
-use super::types::{RowId, TableSchema};
+use super::types::{IndexDefinition, RowId, TableSchema, Value};
+use crate::transaction::CheckpointInfo;
 
 /// Query execution statistics for monitoring and optimization
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -20,6 +23,9 @@ pub struct QueryExecutionStats {
     pub indexes_used: Vec<String>,
     /// Whether index was actually used for query optimization
     pub index_scan: bool,
+    /// Whether results were read from index entries without fetching table rows
+    #[serde(default)]
+    pub index_only: bool,
     /// Number of rows examined from storage
     pub rows_examined: usize,
 }
@@ -39,6 +45,80 @@ impl QueryExecutionStats {
     }
 }
 
+/// Cumulative row cache statistics since the storage engine was opened
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+pub struct CacheStatistics {
+    /// Row lookups served from the cache
+    pub hits: u64,
+    /// Row lookups that missed the cache
+    pub misses: u64,
+    /// Rows currently cached
+    pub cached_rows: usize,
+    /// Maximum number of cached rows
+    pub capacity: usize,
+}
+
+impl CacheStatistics {
+    /// Fraction of lookups served from the cache
+    ///
+    /// Returns `None` if no lookups have been recorded.
+    #[must_use]
+    pub fn hit_rate(&self) -> Option<f64> {
+        let total = self.hits + self.misses;
+        if total > 0 {
+            Some(self.hits as f64 / total as f64)
+        } else {
+            None
+        }
+    }
+}
+
+/// Live statistics of the page store attached to a storage engine
+#[derive(Debug, Clone)]
+pub struct PageStoreStats {
+    /// Buffer pool frame usage
+    pub buffer_pool: BufferPoolStats,
+    /// Buffer pool hits and misses
+    pub cache: CacheMetrics,
+    /// Page counts and file size of the underlying pager
+    pub pager: StorageStats,
+}
+
+/// Outcome of [`StorageEngine::vacuum`](super::StorageEngine::vacuum)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+pub struct VacuumStats {
+    /// Tables whose rows were scanned for live row ids
+    pub tables_scanned: usize,
+    /// Compressed blocks of rows that no longer exist
+    pub compressed_blocks_removed: usize,
+    /// Cached rows that no longer exist
+    pub cached_rows_evicted: usize,
+    /// Free pages released from the end of the page file
+    pub pages_compacted: u64,
+}
+
+/// Outcome of [`StorageEngine::prune_wal`](super::StorageEngine::prune_wal)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+pub struct WalPruneStats {
+    /// Checkpoint written before pruning
+    pub checkpoint_lsn: LSN,
+    /// Records up to and including this LSN were removed
+    pub pruned_through_lsn: LSN,
+    pub records_pruned: usize,
+    pub records_kept: usize,
+    /// Shrinkage of the WAL file in bytes
+    pub bytes_reclaimed: u64,
+}
+
+/// Inputs for alerting on overdue storage maintenance
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MaintenanceStatus {
+    /// Most recent checkpoint since the engine was opened
+    pub last_checkpoint: Option<CheckpointInfo>,
+    /// Fraction of the page file on the free list, if a page store is attached
+    pub free_page_ratio: Option<f64>,
+}
+
 /// Database metadata persisted to disk
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DatabaseMetadata {
@@ -48,4 +128,49 @@ pub struct DatabaseMetadata {
     pub tables: HashMap<String, TableSchema>,
     pub next_row_id: RowId,
     pub next_lsn: LSN,
+    /// Optimizer statistics gathered by `ANALYZE`, keyed by table name
+    #[serde(default)]
+    pub table_statistics: HashMap<String, TableStatistics>,
+    /// Secondary indexes created with `CREATE INDEX`, keyed by index name
+    #[serde(default)]
+    pub index_definitions: HashMap<String, IndexDefinition>,
+    /// Expiry time of key-value entries stored with a TTL, keyed by key
+    #[serde(default)]
+    pub key_expirations: HashMap<String, chrono::DateTime<chrono::Utc>>,
+}
+
+/// Optimizer statistics for one table, gathered by `ANALYZE`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableStatistics {
+    /// Number of rows when the table was analyzed
+    pub row_count: u64,
+    /// Statistics per analyzed column, keyed by column name
+    pub columns: HashMap<String, ColumnStatistics>,
+    /// When the statistics were gathered
+    pub analyzed_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl TableStatistics {
+    /// Whether the table has grown or shrunk by more than `drift_threshold`
+    /// (a fraction of the analyzed row count) since it was analyzed
+    #[must_use]
+    pub fn is_stale(&self, current_row_count: u64, drift_threshold: f64) -> bool {
+        let drift = current_row_count.abs_diff(self.row_count) as f64;
+        drift > drift_threshold * self.row_count.max(1) as f64
+    }
+}
+
+/// Value distribution of a single column
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnStatistics {
+    /// Fraction of rows where the column is NULL
+    pub null_fraction: f64,
+    /// Number of distinct non-NULL values
+    pub distinct_count: u64,
+    /// Most common non-NULL values with the fraction of rows holding each,
+    /// most frequent first
+    pub most_common_values: Vec<(Value, f64)>,
+    /// Bounds of an equi-depth histogram over the remaining non-NULL values,
+    /// in ascending order
+    pub histogram_bounds: Vec<Value>,
 }
@@ -20,6 +20,7 @@ pub fn create_test_schema(name: &str) -> TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -27,6 +28,7 @@ pub fn create_test_schema(name: &str) -> TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "created_at".to_string(),
@@ -34,6 +36,7 @@ pub fn create_test_schema(name: &str) -> TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -42,6 +45,7 @@ pub fn create_test_schema(name: &str) -> TableSchema {
         auto_increment_columns: HashMap::new(),
         id_strategy: IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     }
 }
 
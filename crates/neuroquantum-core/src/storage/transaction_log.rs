@@ -53,8 +53,8 @@ pub enum Operation {
     },
     AlterTable {
         table: String,
-        old_schema: TableSchema,
-        new_schema: TableSchema,
+        old_schema: Box<TableSchema>,
+        new_schema: Box<TableSchema>,
     },
 }
 
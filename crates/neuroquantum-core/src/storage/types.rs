@@ -6,6 +6,8 @@
 //! - `TableSchema`: Table structure definition
 //! - `ColumnDefinition`: Column metadata and constraints
 //! - `ForeignKeyConstraint`: Referential integrity constraints
+//! - `CheckConstraint`: Column value constraints (`CHECK (price > 0)`)
+//! - `ConstraintViolation`: Error for rows rejected by a constraint
 
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -13,6 +15,7 @@ use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 
 use super::id_generation::{AutoIncrementConfig, IdGenerationStrategy};
+use super::query::ComparisonOperator;
 
 /// Unique identifier for database rows
 pub type RowId = u64;
@@ -34,6 +37,9 @@ pub struct TableSchema {
     /// Foreign key constraints defined on this table
     #[serde(default)]
     pub foreign_keys: Vec<ForeignKeyConstraint>,
+    /// CHECK constraints defined on this table
+    #[serde(default)]
+    pub check_constraints: Vec<CheckConstraint>,
 }
 
 impl TableSchema {
@@ -62,6 +68,7 @@ impl TableSchema {
             auto_increment_columns: HashMap::new(),
             id_strategy: IdGenerationStrategy::AutoIncrement,
             foreign_keys: Vec::new(),
+            check_constraints: Vec::new(),
         }
     }
 
@@ -71,6 +78,20 @@ impl TableSchema {
         self.id_strategy = strategy;
         self
     }
+
+    /// Whether any column of this table is declared `ENCRYPTED`
+    #[must_use]
+    pub fn has_encrypted_columns(&self) -> bool {
+        self.columns.iter().any(|c| c.encrypted)
+    }
+}
+
+/// An unnamed table keyed on `id` without columns or constraints, to fill in
+/// the fields a struct literal leaves out
+impl Default for TableSchema {
+    fn default() -> Self {
+        Self::new("", "id", Vec::new())
+    }
 }
 
 /// Foreign key constraint definition
@@ -92,6 +113,75 @@ pub struct ForeignKeyConstraint {
     pub on_update: ReferentialAction,
 }
 
+/// CHECK constraint comparing a column with a constant, e.g. `CHECK (price > 0)`
+///
+/// As in SQL, a NULL value satisfies the constraint; NOT NULL rejects it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CheckConstraint {
+    /// Constraint name, reported when a row violates it
+    pub name: String,
+    /// The constrained column
+    pub column: String,
+    pub operator: ComparisonOperator,
+    pub value: Value,
+}
+
+impl std::fmt::Display for CheckConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let operator = match self.operator {
+            | ComparisonOperator::Equal => "=",
+            | ComparisonOperator::NotEqual => "<>",
+            | ComparisonOperator::LessThan => "<",
+            | ComparisonOperator::LessThanOrEqual => "<=",
+            | ComparisonOperator::GreaterThan => ">",
+            | ComparisonOperator::GreaterThanOrEqual => ">=",
+            | ComparisonOperator::Like => "LIKE",
+            | ComparisonOperator::In => "IN",
+        };
+        match &self.value {
+            | Value::Text(text) => write!(f, "{} {operator} '{text}'", self.column),
+            | value => write!(f, "{} {operator} {value}", self.column),
+        }
+    }
+}
+
+/// A row rejected by a constraint of its table
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ConstraintViolation {
+    #[error("NOT NULL constraint violated: column '{column}' of table '{table}' cannot be NULL")]
+    NotNull { table: String, column: String },
+    #[error(
+        "UNIQUE constraint '{constraint}' violated: duplicate value for ({})",
+        columns.join(", ")
+    )]
+    Unique {
+        constraint: String,
+        columns: Vec<String>,
+    },
+    #[error("CHECK constraint '{constraint}' violated: {expression}")]
+    Check {
+        constraint: String,
+        expression: String,
+    },
+}
+
+/// Secondary index over one or more columns of a table
+///
+/// Entries are keyed by a [`CompositeKey`](super::btree::CompositeKey) of the
+/// column values in index order, so a query constraining a leading prefix of
+/// the columns can be answered with a range scan.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IndexDefinition {
+    /// Index name, unique across the database
+    pub name: String,
+    /// The indexed table
+    pub table: String,
+    /// Indexed columns, most significant first
+    pub columns: Vec<String>,
+    /// Whether two rows may not share the same (non-NULL) column values
+    pub unique: bool,
+}
+
 /// Referential action for foreign key constraints
 /// Specifies what action to take when the referenced row is updated or deleted
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -136,6 +226,17 @@ pub struct ColumnDefinition {
     /// Whether this column auto-increments
     #[serde(default)]
     pub auto_increment: bool,
+    /// Whether values are encrypted field-by-field (`ENCRYPTED` column attribute)
+    #[serde(default)]
+    pub encrypted: bool,
+}
+
+/// An unnamed, non-nullable `TEXT` column, to fill in the fields a struct
+/// literal leaves out
+impl Default for ColumnDefinition {
+    fn default() -> Self {
+        Self::new("", DataType::Text)
+    }
 }
 
 impl ColumnDefinition {
@@ -148,6 +249,7 @@ impl ColumnDefinition {
     /// let col = ColumnDefinition::new("name", DataType::Text);
     /// assert!(!col.nullable);
     /// assert!(!col.auto_increment);
+    /// assert!(!col.encrypted);
     /// ```
     pub fn new(name: impl Into<String>, data_type: DataType) -> Self {
         let name = name.into();
@@ -158,6 +260,7 @@ impl ColumnDefinition {
             nullable: false,
             default_value: None,
             auto_increment,
+            encrypted: false,
         }
     }
 
@@ -181,6 +284,17 @@ impl ColumnDefinition {
         self.auto_increment = true;
         self
     }
+
+    /// Encrypt this column's values individually
+    ///
+    /// Encrypted values are only returned in plaintext to readers with
+    /// [`FieldAccess::Decrypted`](super::FieldAccess); everyone else sees a
+    /// redacted placeholder.
+    #[must_use]
+    pub const fn encrypted(mut self) -> Self {
+        self.encrypted = true;
+        self
+    }
 }
 
 /// Supported data types
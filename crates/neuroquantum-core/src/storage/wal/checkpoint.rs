@@ -113,6 +113,7 @@ mod tests {
     use uuid::Uuid;
 
     use super::*;
+    use crate::storage::wal::Durability;
 
     #[test]
     fn test_should_checkpoint() {
@@ -120,9 +121,12 @@ mod tests {
             wal_dir: PathBuf::from("test"),
             segment_size: 1024,
             sync_on_write: false,
+            commit_durability: Durability::Durable,
             buffer_size: 1024,
             checkpoint_interval_secs: 5,
+            max_wal_bytes_before_checkpoint: 0,
             min_segments_to_keep: 2,
+            redo_workers: 1,
             group_commit_delay_ms: 0,
             group_commit_max_records: 1000,
             group_commit_max_bytes: 4 * 1024 * 1024,
@@ -8,6 +8,8 @@
 //! - Group commit for high-throughput durability
 
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, Result};
@@ -16,7 +18,39 @@ use tokio::io::{AsyncReadExt, AsyncWriteExt, BufWriter};
 use tokio::sync::{mpsc, oneshot};
 use tracing::{debug, info, warn};
 
-use super::{WALRecord, LSN};
+use super::{Durability, WALRecord, LSN};
+
+/// File extension of WAL segment files
+pub const SEGMENT_EXTENSION: &str = "log";
+
+/// Decode the records of a segment file's contents
+///
+/// Records with a checksum mismatch are skipped. A truncated record at the
+/// end, left by a torn write, ends the segment.
+#[must_use]
+pub fn decode_segment(data: &[u8]) -> Vec<WALRecord> {
+    let mut records = Vec::new();
+    let mut rest = data;
+
+    while let Some((len_buf, tail)) = rest.split_first_chunk::<4>() {
+        let record_len = u32::from_le_bytes(*len_buf) as usize;
+        if tail.len() < record_len {
+            break;
+        }
+        let (record_buf, tail) = tail.split_at(record_len);
+        rest = tail;
+
+        if let Ok(record) = WALRecord::from_bytes(record_buf) {
+            if !record.verify_checksum() {
+                warn!("⚠️ Checksum mismatch for LSN {}", record.lsn);
+                continue;
+            }
+            records.push(record);
+        }
+    }
+
+    records
+}
 
 /// Log writer configuration
 #[derive(Debug, Clone)]
@@ -46,14 +80,17 @@ struct GroupCommitBuffer {
     pending: Vec<PendingRecord>,
     total_bytes: usize,
     flush_deadline: Option<Instant>,
+    /// Counts the batch fsyncs towards [`LogWriter::sync_count`]
+    sync_count: Arc<AtomicU64>,
 }
 
 impl GroupCommitBuffer {
-    const fn new() -> Self {
+    const fn new(sync_count: Arc<AtomicU64>) -> Self {
         Self {
             pending: Vec::new(),
             total_bytes: 0,
             flush_deadline: None,
+            sync_count,
         }
     }
 
@@ -110,6 +147,8 @@ pub struct LogWriter {
     next_lsn: LSN,
     /// Group commit command sender (None if group commit is disabled)
     group_commit_tx: Option<mpsc::UnboundedSender<GroupCommitCommand>>,
+    /// Number of fsyncs issued, shared with the group commit task
+    sync_count: Arc<AtomicU64>,
 }
 
 impl LogWriter {
@@ -127,6 +166,7 @@ impl LogWriter {
             current_file_size: 0,
             next_lsn,
             group_commit_tx: None,
+            sync_count: Arc::new(AtomicU64::new(0)),
         };
 
         // Open the current segment file
@@ -242,8 +282,34 @@ impl LogWriter {
         Ok(())
     }
 
+    /// Number of fsyncs issued on the log so far
+    pub fn sync_count(&self) -> u64 {
+        self.sync_count.load(Ordering::SeqCst)
+    }
+
     /// Append a WAL record to the log
+    ///
+    /// The record is synced to disk if `sync_on_write` is set.
     pub async fn append_record(&mut self, record: WALRecord) -> Result<()> {
+        let durability = if self.config.sync_on_write {
+            Durability::Durable
+        } else {
+            Durability::Relaxed
+        };
+        self.append_record_with_durability(record, durability).await
+    }
+
+    /// Append a WAL record to the log, overriding `sync_on_write`
+    ///
+    /// A [`Durability::Durable`] append returns once the record is synced to
+    /// disk. A [`Durability::Relaxed`] append returns without waiting for a
+    /// sync: with group commit the record is synced with its batch, otherwise
+    /// with the next sync of the log.
+    pub async fn append_record_with_durability(
+        &mut self,
+        record: WALRecord,
+        durability: Durability,
+    ) -> Result<()> {
         // If group commit is enabled, use it
         if let Some(tx) = &self.group_commit_tx {
             // Serialize the record to calculate size
@@ -261,6 +327,10 @@ impl LogWriter {
             })
             .map_err(|_| anyhow!("Group commit task has stopped"))?;
 
+            if durability == Durability::Relaxed {
+                return Ok(());
+            }
+
             // Wait for the batch to be flushed
             response_rx
                 .await
@@ -270,11 +340,12 @@ impl LogWriter {
         }
 
         // Fallback to direct write (group commit disabled)
-        self.write_record_direct(record).await
+        self.write_record_direct(record, durability == Durability::Durable)
+            .await
     }
 
     /// Write a record directly without group commit
-    async fn write_record_direct(&mut self, record: WALRecord) -> Result<()> {
+    async fn write_record_direct(&mut self, record: WALRecord, sync: bool) -> Result<()> {
         // Check if we need to rotate to a new segment
         if self.current_file_size >= self.config.segment_size {
             self.rotate_segment().await?;
@@ -295,10 +366,10 @@ impl LogWriter {
             // Update file size
             self.current_file_size += 4 + record_bytes.len();
 
-            // Sync if configured
-            if self.config.sync_on_write {
+            if sync {
                 file.flush().await?;
                 file.get_ref().sync_all().await?;
+                self.sync_count.fetch_add(1, Ordering::SeqCst);
             }
 
             debug!(
@@ -325,6 +396,7 @@ impl LogWriter {
         if let Some(file) = &mut self.current_file {
             file.flush().await?;
             file.get_ref().sync_all().await?;
+            self.sync_count.fetch_add(1, Ordering::SeqCst);
             debug!("💾 Flushed WAL to disk");
         }
         Ok(())
@@ -339,9 +411,10 @@ impl LogWriter {
         let max_bytes = config.group_commit_max_bytes;
         let segment_size = config.segment_size;
         let buffer_size = config.buffer_size;
+        let sync_count = Arc::clone(&self.sync_count);
 
         tokio::spawn(async move {
-            let mut buffer = GroupCommitBuffer::new();
+            let mut buffer = GroupCommitBuffer::new(sync_count);
             let mut current_segment = 0u64;
             let mut current_file: Option<BufWriter<File>> = None;
             let mut current_file_size = 0usize;
@@ -545,6 +618,8 @@ impl LogWriter {
             if let Some(file) = current_file.as_mut() {
                 if file.flush().await.is_err() || file.get_ref().sync_all().await.is_err() {
                     write_result = Err(anyhow!("Failed to sync"));
+                } else {
+                    buffer.sync_count.fetch_add(1, Ordering::SeqCst);
                 }
             }
         }
@@ -624,38 +699,9 @@ impl LogWriter {
 
     /// Read all records from a segment file
     async fn read_segment(path: &PathBuf, start_lsn: LSN) -> Result<Vec<WALRecord>> {
-        let mut file = File::open(path).await?;
-        let mut records = Vec::new();
-
-        loop {
-            // Read record length
-            let mut len_buf = [0u8; 4];
-            match file.read_exact(&mut len_buf).await {
-                | Ok(_) => {
-                    let record_len = u32::from_le_bytes(len_buf) as usize;
-
-                    // Read record data
-                    let mut record_buf = vec![0u8; record_len];
-                    file.read_exact(&mut record_buf).await?;
-
-                    // Deserialize
-                    if let Ok(record) = WALRecord::from_bytes(&record_buf) {
-                        // Verify checksum
-                        if !record.verify_checksum() {
-                            warn!("⚠️ Checksum mismatch for LSN {}", record.lsn);
-                            continue;
-                        }
-
-                        // Only include records >= start_lsn
-                        if record.lsn >= start_lsn {
-                            records.push(record);
-                        }
-                    }
-                },
-                | Err(_) => break, // End of file
-            }
-        }
-
+        let data = tokio::fs::read(path).await?;
+        let mut records = decode_segment(&data);
+        records.retain(|record| record.lsn >= start_lsn);
         Ok(records)
     }
 
@@ -1,7 +1,7 @@
 //! Write-Ahead Logging (WAL) System for `NeuroQuantumDB`
 //!
 //! Implements ARIES-style recovery with:
-//! - Write-ahead logging with force-at-commit
+//! - Write-ahead logging with force-at-commit, or relaxed commits per transaction
 //! - REDO/UNDO log records
 //! - Checkpointing for fast recovery
 //! - Crash recovery with analysis, redo, and undo phases
@@ -9,7 +9,7 @@
 
 use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 
 use anyhow::{anyhow, Result};
@@ -24,7 +24,9 @@ pub mod recovery;
 
 pub use checkpoint::{CheckpointManager, CheckpointRecord};
 pub use log_writer::{LogWriter, LogWriterConfig};
-pub use recovery::{RecoveryManager, RecoveryStats};
+pub use recovery::{
+    RecoveryManager, RecoveryPhase, RecoveryProgress, RecoveryStats, PROGRESS_REPORT_INTERVAL,
+};
 
 use super::pager::{PageId, PageStorageManager};
 
@@ -156,6 +158,26 @@ impl WALRecord {
     }
 }
 
+/// How far a commit is persisted before `commit_transaction` returns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Durability {
+    /// Force the commit record to disk before returning
+    #[default]
+    Durable,
+    /// Return once the commit record is written, leaving the fsync to the
+    /// next group commit or log flush; a crash before then loses the commit
+    Relaxed,
+}
+
+/// Outcome of committing a transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommitResult {
+    /// LSN of the commit record
+    pub lsn: LSN,
+    /// Whether the commit record was on disk when the commit returned
+    pub synchronous: bool,
+}
+
 /// WAL Manager configuration
 #[derive(Debug, Clone)]
 pub struct WALConfig {
@@ -165,12 +187,20 @@ pub struct WALConfig {
     pub segment_size: usize,
     /// Whether to fsync after each write (durability vs performance)
     pub sync_on_write: bool,
+    /// Durability of commits that don't request one
+    pub commit_durability: Durability,
     /// Buffer size for log writer
     pub buffer_size: usize,
     /// Checkpoint interval in seconds
     pub checkpoint_interval_secs: u64,
+    /// WAL bytes written since the last checkpoint that trigger a new one in
+    /// the background, whichever of this and the interval comes first
+    /// (0 to disable)
+    pub max_wal_bytes_before_checkpoint: u64,
     /// Number of WAL segments to keep for recovery
     pub min_segments_to_keep: usize,
+    /// Pages redone concurrently during recovery (1 for sequential redo)
+    pub redo_workers: usize,
     /// Group commit delay in milliseconds (0 to disable group commit)
     pub group_commit_delay_ms: u64,
     /// Maximum number of records per group commit batch
@@ -185,9 +215,12 @@ impl Default for WALConfig {
             wal_dir: PathBuf::from("data/wal"),
             segment_size: 64 * 1024 * 1024, // 64 MB
             sync_on_write: true,
-            buffer_size: 256 * 1024,       // 256 KB
-            checkpoint_interval_secs: 300, // 5 minutes
+            commit_durability: Durability::Durable,
+            buffer_size: 256 * 1024,                            // 256 KB
+            checkpoint_interval_secs: 300,                      // 5 minutes
+            max_wal_bytes_before_checkpoint: 256 * 1024 * 1024, // 256 MB
             min_segments_to_keep: 3,
+            redo_workers: std::thread::available_parallelism().map_or(4, usize::from),
             group_commit_delay_ms: 5, // 5ms default delay
             group_commit_max_records: 1000,
             group_commit_max_bytes: 4 * 1024 * 1024, // 4 MB
@@ -416,6 +449,10 @@ pub struct WALManager {
     transaction_table: Arc<RwLock<HashMap<TransactionId, LSN>>>,
     /// Dirty page table: `page_id` -> `recovery_lsn`
     dirty_page_table: Arc<RwLock<HashMap<PageId, LSN>>>,
+    /// WAL bytes written since the last checkpoint began
+    bytes_since_checkpoint: Arc<AtomicU64>,
+    /// Set while a checkpoint triggered by WAL size is running
+    size_checkpoint_running: Arc<AtomicBool>,
     /// Checkpoint manager
     _checkpoint_manager: Arc<CheckpointManager>,
     /// Recovery manager
@@ -458,6 +495,8 @@ impl WALManager {
             active_txns: Arc::new(RwLock::new(HashMap::new())),
             transaction_table: Arc::new(RwLock::new(HashMap::new())),
             dirty_page_table: Arc::new(RwLock::new(HashMap::new())),
+            bytes_since_checkpoint: Arc::new(AtomicU64::new(0)),
+            size_checkpoint_running: Arc::new(AtomicBool::new(false)),
             _checkpoint_manager: checkpoint_manager,
             recovery_manager,
         };
@@ -549,8 +588,18 @@ impl WALManager {
         Ok(lsn)
     }
 
-    /// Commit a transaction
-    pub async fn commit_transaction(&self, tx_id: TransactionId) -> Result<()> {
+    /// Commit a transaction with the configured `commit_durability`
+    pub async fn commit_transaction(&self, tx_id: TransactionId) -> Result<CommitResult> {
+        self.commit_transaction_with_durability(tx_id, self._config.commit_durability)
+            .await
+    }
+
+    /// Commit a transaction, overriding the configured `commit_durability`
+    pub async fn commit_transaction_with_durability(
+        &self,
+        tx_id: TransactionId,
+        durability: Durability,
+    ) -> Result<CommitResult> {
         // Begin commit phase - update state first
         {
             let mut active_txns = self.active_txns.write().await;
@@ -568,9 +617,13 @@ impl WALManager {
 
         let record = WALRecord::new(lsn, prev_lsn, Some(tx_id), WALRecordType::Commit { tx_id });
 
-        // Write commit record and force to disk
-        self.append_log_record(record).await?;
-        self.flush_log().await?;
+        // Write commit record, forcing it to disk unless relaxed
+        self.append_log_record_with_durability(record, durability)
+            .await?;
+        let synchronous = durability == Durability::Durable;
+        if synchronous {
+            self.flush_log().await?;
+        }
 
         // Complete commit phase - update state with final LSN
         let mut active_txns = self.active_txns.write().await;
@@ -582,8 +635,11 @@ impl WALManager {
         let mut tx_table = self.transaction_table.write().await;
         tx_table.remove(&tx_id);
 
-        info!("✅ Transaction committed: {} (LSN: {})", tx_id, lsn);
-        Ok(())
+        info!(
+            "✅ Transaction committed: {} (LSN: {}, {:?})",
+            tx_id, lsn, durability
+        );
+        Ok(CommitResult { lsn, synchronous })
     }
 
     /// Abort a transaction
@@ -786,6 +842,7 @@ impl WALManager {
         };
 
         let lsn = self.allocate_lsn();
+        self.bytes_since_checkpoint.store(0, Ordering::SeqCst);
 
         // Write checkpoint begin record
         let begin_record = WALRecord::new(
@@ -814,9 +871,15 @@ impl WALManager {
     }
 
     /// Recover from crash
-    pub async fn recover(&self, pager: Arc<PageStorageManager>) -> Result<RecoveryStats> {
+    ///
+    /// `progress` is called as each recovery phase starts, advances and ends.
+    pub async fn recover(
+        &self,
+        pager: Arc<PageStorageManager>,
+        progress: Option<&(dyn Fn(&RecoveryProgress) + Send + Sync)>,
+    ) -> Result<RecoveryStats> {
         info!("🔄 Starting crash recovery...");
-        let stats = self.recovery_manager.recover(self, pager).await?;
+        let stats = self.recovery_manager.recover(self, pager, progress).await?;
         info!("✅ Recovery completed: {:?}", stats);
         Ok(stats)
     }
@@ -834,8 +897,64 @@ impl WALManager {
 
     /// Append a log record
     async fn append_log_record(&self, record: WALRecord) -> Result<()> {
-        let mut writer = self.log_writer.write().await;
-        writer.append_record(record).await
+        // Length prefix plus the serialized record, as laid out in the segment
+        let record_bytes = 4 + bincode::serialized_size(&record).unwrap_or(0);
+        self.log_writer.write().await.append_record(record).await?;
+        self.record_wal_growth(record_bytes);
+        Ok(())
+    }
+
+    /// Append a log record, overriding `sync_on_write`
+    async fn append_log_record_with_durability(
+        &self,
+        record: WALRecord,
+        durability: Durability,
+    ) -> Result<()> {
+        let record_bytes = 4 + bincode::serialized_size(&record).unwrap_or(0);
+        self.log_writer
+            .write()
+            .await
+            .append_record_with_durability(record, durability)
+            .await?;
+        self.record_wal_growth(record_bytes);
+        Ok(())
+    }
+
+    /// Count bytes appended to the WAL and start a checkpoint in the
+    /// background once `max_wal_bytes_before_checkpoint` is exceeded
+    ///
+    /// Writers never wait for the checkpoint; at most one size-triggered
+    /// checkpoint runs at a time.
+    fn record_wal_growth(&self, bytes: u64) {
+        let threshold = self._config.max_wal_bytes_before_checkpoint;
+        let written = self
+            .bytes_since_checkpoint
+            .fetch_add(bytes, Ordering::SeqCst)
+            + bytes;
+        if threshold == 0 || written < threshold {
+            return;
+        }
+        if self.size_checkpoint_running.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        info!(
+            "📏 {} WAL bytes since last checkpoint, starting checkpoint",
+            written
+        );
+        let wal = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = wal.checkpoint().await {
+                warn!("⚠️ WAL size triggered checkpoint failed: {}", e);
+            }
+            wal.size_checkpoint_running.store(false, Ordering::SeqCst);
+        });
+    }
+
+    /// WAL bytes written since the last checkpoint began
+    #[must_use]
+    pub fn bytes_since_checkpoint(&self) -> u64 {
+        self.bytes_since_checkpoint.load(Ordering::SeqCst)
     }
 
     /// Flush log to disk
@@ -1012,6 +1131,7 @@ mod tests {
             enable_checksums: true,
             sync_mode: SyncMode::None,
             direct_io: false,
+            ..Default::default()
         };
 
         let db_file = data_path.join("test.db");
@@ -1025,9 +1145,12 @@ mod tests {
             wal_dir: wal_path,
             segment_size: 1024 * 1024,
             sync_on_write: false,
+            commit_durability: Durability::Durable,
             buffer_size: 64 * 1024,
             checkpoint_interval_secs: 60,
+            max_wal_bytes_before_checkpoint: 0,
             min_segments_to_keep: 2,
+            redo_workers: 1,
             group_commit_delay_ms: 0,
             group_commit_max_records: 1000,
             group_commit_max_bytes: 4 * 1024 * 1024,
@@ -1093,6 +1216,127 @@ mod tests {
         assert!(checkpoint_lsn > 0);
     }
 
+    #[tokio::test]
+    async fn test_checkpoint_triggered_by_wal_size() {
+        let (temp, pager, _wal) = setup_test_env().await;
+        let wal_config = WALConfig {
+            wal_dir: temp.path().join("wal_by_size"),
+            sync_on_write: false,
+            checkpoint_interval_secs: 3600,
+            max_wal_bytes_before_checkpoint: 4 * 1024,
+            group_commit_delay_ms: 0,
+            ..Default::default()
+        };
+        let wal = WALManager::new(wal_config, pager).await.unwrap();
+
+        let tx_id = wal.begin_transaction().await.unwrap();
+        for i in 0..16 {
+            wal.log_update(tx_id, PageId(i), 0, vec![0; 256], vec![1; 256])
+                .await
+                .unwrap();
+        }
+        wal.commit_transaction(tx_id).await.unwrap();
+
+        // The checkpoint runs in the background, long before the interval
+        let has_checkpoint = |records: &[WALRecord]| {
+            records
+                .iter()
+                .any(|record| matches!(record.record_type, WALRecordType::CheckpointEnd))
+        };
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        while !has_checkpoint(&wal.read_log_records(1).await.unwrap()) {
+            assert!(
+                std::time::Instant::now() < deadline,
+                "no checkpoint after exceeding the WAL size threshold"
+            );
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+    }
+
+    async fn sync_count(wal: &WALManager) -> u64 {
+        wal.log_writer.read().await.sync_count()
+    }
+
+    /// LSN of the commit record of `tx_id`
+    fn commit_lsn(records: &[WALRecord], tx_id: TransactionId) -> Option<LSN> {
+        records
+            .iter()
+            .find(|record| {
+                matches!(record.record_type, WALRecordType::Commit { tx_id: id } if id == tx_id)
+            })
+            .map(|record| record.lsn)
+    }
+
+    #[tokio::test]
+    async fn test_commit_durability_overrides_default() {
+        let (_temp, _pager, wal) = setup_test_env().await;
+
+        // The configured default forces the commit to disk
+        let tx_id = wal.begin_transaction().await.unwrap();
+        let syncs = sync_count(&wal).await;
+        let result = wal.commit_transaction(tx_id).await.unwrap();
+        assert!(result.synchronous);
+        assert!(sync_count(&wal).await > syncs);
+
+        // A relaxed commit returns without syncing
+        let tx_id = wal.begin_transaction().await.unwrap();
+        let syncs = sync_count(&wal).await;
+        let result = wal
+            .commit_transaction_with_durability(tx_id, Durability::Relaxed)
+            .await
+            .unwrap();
+        assert!(!result.synchronous);
+        assert_eq!(sync_count(&wal).await, syncs);
+
+        // The next flush makes it durable
+        wal.flush_log().await.unwrap();
+        assert!(sync_count(&wal).await > syncs);
+        let records = wal.read_log_records(1).await.unwrap();
+        assert_eq!(commit_lsn(&records, tx_id), Some(result.lsn));
+    }
+
+    #[tokio::test]
+    async fn test_relaxed_commit_returns_before_group_commit_fsync() {
+        let (temp, pager, _wal) = setup_test_env().await;
+        let wal_config = WALConfig {
+            wal_dir: temp.path().join("wal_group_commit"),
+            sync_on_write: true,
+            commit_durability: Durability::Relaxed,
+            group_commit_delay_ms: 200,
+            ..Default::default()
+        };
+        let wal = WALManager::new(wal_config, pager).await.unwrap();
+
+        // A durable commit waits for its batch to be synced
+        let tx_id = wal.begin_transaction().await.unwrap();
+        let syncs = sync_count(&wal).await;
+        let result = wal
+            .commit_transaction_with_durability(tx_id, Durability::Durable)
+            .await
+            .unwrap();
+        assert!(result.synchronous);
+        assert!(sync_count(&wal).await > syncs);
+
+        // The configured relaxed commit returns while its batch is pending
+        let tx_id = wal.begin_transaction().await.unwrap();
+        let syncs = sync_count(&wal).await;
+        let result = wal.commit_transaction(tx_id).await.unwrap();
+        assert!(!result.synchronous);
+        assert_eq!(sync_count(&wal).await, syncs);
+
+        // The group commit syncs it once the batch delay has passed
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        while sync_count(&wal).await == syncs {
+            assert!(
+                std::time::Instant::now() < deadline,
+                "relaxed commit was never synced"
+            );
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        let records = wal.read_log_records(1).await.unwrap();
+        assert_eq!(commit_lsn(&records, tx_id), Some(result.lsn));
+    }
+
     #[tokio::test]
     async fn test_wal_record_serialization() {
         let record = WALRecord::new(
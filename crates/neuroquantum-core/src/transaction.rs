@@ -3,7 +3,7 @@
 //! Complete ACID-compliant transaction management with:
 //! - Write-Ahead Logging (WAL)
 //! - Two-Phase Commit Protocol
-//! - Multi-Version Concurrency Control (MVCC)
+//! - Multi-Version Concurrency Control (MVCC) with snapshot isolation
 //! - Deadlock Detection
 //! - Crash Recovery
 
@@ -12,7 +12,8 @@ use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
+use prometheus::{IntCounter, IntCounterVec, Opts};
 use serde::{Deserialize, Serialize};
 use tokio::fs::{File, OpenOptions};
 use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, SeekFrom};
@@ -21,6 +22,7 @@ use tracing::{debug, error, info, instrument, warn};
 use uuid::Uuid;
 
 use crate::error::NeuroQuantumError;
+use crate::storage::wal::{CommitResult, Durability};
 
 /// Transaction identifier
 pub type TransactionId = Uuid;
@@ -32,17 +34,28 @@ pub type LSN = u64;
 pub type ResourceId = String;
 
 /// Isolation levels for transactions
+///
+/// Each transaction picks its own level when it begins, see
+/// [`TransactionManager::begin_with_isolation`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum IsolationLevel {
-    /// Dirty reads allowed, lowest isolation
+    /// Dirty reads allowed, lowest isolation; reads take no locks and see
+    /// other transactions' uncommitted writes
     ReadUncommitted,
-    /// No dirty reads, non-repeatable reads allowed
+    /// No dirty reads, non-repeatable reads allowed; read locks are released
+    /// as soon as the read is done
     #[default]
     ReadCommitted,
-    /// Repeatable reads, phantom reads allowed
+    /// Repeatable reads, phantom reads allowed; read locks are held until
+    /// the transaction ends
     RepeatableRead,
-    /// Full serializable isolation, highest level
+    /// Full serializable isolation, highest level; read locks are held until
+    /// the transaction ends
     Serializable,
+    /// Reads see the committed state as of the transaction's start, writers
+    /// do not block readers, and concurrent writes to the same row are
+    /// resolved at commit: the first committer wins and the other aborts
+    Snapshot,
 }
 
 /// Transaction status
@@ -64,6 +77,67 @@ pub enum TransactionStatus {
     Aborted,
 }
 
+/// Why a transaction was aborted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AbortReason {
+    /// Picked as the victim to break a cycle of lock waits
+    Deadlock,
+    /// A concurrent transaction committed a row this one also wrote
+    WriteConflict,
+    /// Idle for longer than the transaction timeout
+    Timeout,
+    /// Rolled back by the client
+    UserAbort,
+    /// A write violated a constraint
+    ConstraintViolation,
+}
+
+impl AbortReason {
+    pub const ALL: [Self; 5] = [
+        Self::Deadlock,
+        Self::WriteConflict,
+        Self::Timeout,
+        Self::UserAbort,
+        Self::ConstraintViolation,
+    ];
+
+    /// Label used in metrics
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            | Self::Deadlock => "deadlock",
+            | Self::WriteConflict => "write_conflict",
+            | Self::Timeout => "timeout",
+            | Self::UserAbort => "user_abort",
+            | Self::ConstraintViolation => "constraint_violation",
+        }
+    }
+
+    /// `reason` and `retryable` labels of the abort counter
+    const fn metric_labels(self) -> [&'static str; 2] {
+        [
+            self.as_str(),
+            if self.is_retryable() { "true" } else { "false" },
+        ]
+    }
+
+    /// Whether running the transaction again can succeed
+    ///
+    /// Deadlocks and write conflicts depend on the timing of concurrent
+    /// transactions, so a retry usually goes through. The other reasons
+    /// would fail the same way again.
+    #[must_use]
+    pub const fn is_retryable(self) -> bool {
+        matches!(self, Self::Deadlock | Self::WriteConflict)
+    }
+}
+
+impl std::fmt::Display for AbortReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Lock types for concurrency control
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LockType {
@@ -145,6 +219,25 @@ pub struct Transaction {
     pub read_set: HashSet<ResourceId>,
     /// Write set for conflict detection
     pub write_set: HashSet<ResourceId>,
+    /// Row writes made through [`TransactionManager::write_row`], installed as
+    /// new versions at commit (`None` deletes the row)
+    pub pending_writes: HashMap<ResourceId, Option<Vec<u8>>>,
+    /// Active savepoints, oldest first
+    pub savepoints: Vec<Savepoint>,
+    /// Set when the transaction can no longer commit, e.g. after it was
+    /// picked as a deadlock victim; it is recorded when the transaction is
+    /// rolled back
+    pub abort_reason: Option<AbortReason>,
+}
+
+/// A named point within a transaction that it can partially roll back to
+#[derive(Debug, Clone)]
+pub struct Savepoint {
+    pub name: String,
+    /// Last LSN the transaction had written when the savepoint was created
+    pub lsn: LSN,
+    /// Row writes staged in the version store at that point
+    pub pending_writes: HashMap<ResourceId, Option<Vec<u8>>>,
 }
 
 impl Transaction {
@@ -166,6 +259,9 @@ impl Transaction {
             snapshot_version: 0,
             read_set: HashSet::new(),
             write_set: HashSet::new(),
+            pending_writes: HashMap::new(),
+            savepoints: Vec::new(),
+            abort_reason: None,
         }
     }
 
@@ -182,6 +278,58 @@ impl Transaction {
     pub fn touch(&mut self) {
         self.last_active = chrono::Utc::now();
     }
+
+    /// Create a savepoint at the transaction's current WAL position, replacing
+    /// any existing savepoint with the same name. Returns the savepoint's LSN.
+    pub fn savepoint(&mut self, name: &str) -> LSN {
+        self.touch();
+        self.savepoints.retain(|savepoint| savepoint.name != name);
+
+        let lsn = self.last_lsn.unwrap_or(0);
+        self.savepoints.push(Savepoint {
+            name: name.to_string(),
+            lsn,
+            pending_writes: self.pending_writes.clone(),
+        });
+        lsn
+    }
+
+    /// Roll back to the savepoint `name`, keeping the work done before it.
+    ///
+    /// Savepoints created after it are discarded; the savepoint itself stays
+    /// active so it can be rolled back to again. Returns the undone log records,
+    /// oldest first; the caller applies their before-images in reverse order.
+    pub fn rollback_to(&mut self, name: &str) -> Result<Vec<LogRecord>, NeuroQuantumError> {
+        let position = self.savepoint_position(name)?;
+        self.touch();
+        self.savepoints.truncate(position + 1);
+
+        let savepoint = &self.savepoints[position];
+        let (kept, undone): (Vec<_>, Vec<_>) = std::mem::take(&mut self.undo_log)
+            .into_iter()
+            .partition(|record| record.lsn <= savepoint.lsn);
+        self.undo_log = kept;
+        self.pending_writes = savepoint.pending_writes.clone();
+        Ok(undone)
+    }
+
+    /// Release the savepoint `name` and every savepoint created after it,
+    /// keeping their work
+    pub fn release(&mut self, name: &str) -> Result<(), NeuroQuantumError> {
+        let position = self.savepoint_position(name)?;
+        self.touch();
+        self.savepoints.truncate(position);
+        Ok(())
+    }
+
+    fn savepoint_position(&self, name: &str) -> Result<usize, NeuroQuantumError> {
+        self.savepoints
+            .iter()
+            .rposition(|savepoint| savepoint.name == name)
+            .ok_or_else(|| {
+                NeuroQuantumError::TransactionError(format!("Savepoint '{name}' does not exist"))
+            })
+    }
 }
 
 /// Lock manager for concurrency control with deadlock detection
@@ -293,6 +441,7 @@ impl LockManager {
 
         // Remove from waiting list - DashMap handles concurrent removal safely
         self.waiting.remove(&tx_id);
+        self.wait_for.remove(&tx_id);
 
         debug!(
             "Granted {:?} lock on {} to {:?}",
@@ -328,6 +477,8 @@ impl LockManager {
             // Detect cycle using DFS
             if self.has_cycle(&wait_for_snapshot, tx_id)? {
                 error!("Deadlock detected involving transaction {:?}", tx_id);
+                // The victim stops waiting, so the others must not see its edges
+                self.wait_for.remove(tx_id);
                 return Err(NeuroQuantumError::DeadlockDetected(format!(
                     "Deadlock detected for transaction {tx_id:?}"
                 )));
@@ -378,6 +529,30 @@ impl LockManager {
         Ok(false)
     }
 
+    /// Release the locks a transaction holds on one resource
+    pub async fn release_lock(
+        &self,
+        tx_id: &TransactionId,
+        resource_id: &str,
+    ) -> Result<(), NeuroQuantumError> {
+        let should_remove = self
+            .locks
+            .get_mut(resource_id)
+            .is_some_and(|mut resource_locks| {
+                resource_locks.retain(|lock| lock.transaction_id != *tx_id);
+                resource_locks.is_empty()
+            });
+        if should_remove {
+            self.locks.remove(resource_id);
+        }
+
+        debug!(
+            "Released lock on {} for transaction {:?}",
+            resource_id, tx_id
+        );
+        Ok(())
+    }
+
     /// Release all locks held by a transaction
     pub async fn release_locks(&self, tx_id: &TransactionId) -> Result<(), NeuroQuantumError> {
         // Collect all resource IDs first to avoid holding iterator while modifying
@@ -421,6 +596,10 @@ pub struct LogManager {
     /// Buffer for batch writes
     write_buffer: Arc<Mutex<VecDeque<LogRecord>>>,
     buffer_size: usize,
+    /// Most recent checkpoint written since the log was opened
+    last_checkpoint: Mutex<Option<CheckpointInfo>>,
+    /// Number of fsyncs issued on the log so far
+    sync_count: AtomicU64,
 }
 
 impl LogManager {
@@ -449,6 +628,8 @@ impl LogManager {
             lsn_counter: Arc::new(AtomicU64::new(1)),
             write_buffer: Arc::new(Mutex::new(VecDeque::new())),
             buffer_size: 100,
+            last_checkpoint: Mutex::new(None),
+            sync_count: AtomicU64::new(0),
         })
     }
 
@@ -472,6 +653,8 @@ impl LogManager {
             lsn_counter: Arc::new(AtomicU64::new(1)),
             write_buffer: Arc::new(Mutex::new(VecDeque::new())),
             buffer_size: 100,
+            last_checkpoint: Mutex::new(None),
+            sync_count: AtomicU64::new(0),
         }
     }
 
@@ -509,6 +692,19 @@ impl LogManager {
 
     /// Flush write buffer to disk
     async fn flush_buffer(&self) -> Result<(), NeuroQuantumError> {
+        self.write_buffer_to_file(true).await
+    }
+
+    /// Write buffered records to the log file without forcing them to disk
+    ///
+    /// The records reach disk with the next [`force_log`](Self::force_log) or
+    /// full buffer; a crash before then loses them.
+    pub async fn write_log(&self) -> Result<(), NeuroQuantumError> {
+        self.write_buffer_to_file(false).await
+    }
+
+    /// Write buffered records to the log file, syncing it if `sync` is set
+    async fn write_buffer_to_file(&self, sync: bool) -> Result<(), NeuroQuantumError> {
         let mut buffer = self.write_buffer.lock().await;
 
         if buffer.is_empty() {
@@ -537,10 +733,13 @@ impl LogManager {
         }
 
         // Sync to disk for durability
-        log_file
-            .sync_all()
-            .await
-            .map_err(|e| NeuroQuantumError::StorageError(format!("Failed to sync WAL: {e}")))?;
+        if sync {
+            log_file
+                .sync_all()
+                .await
+                .map_err(|e| NeuroQuantumError::StorageError(format!("Failed to sync WAL: {e}")))?;
+            self.sync_count.fetch_add(1, Ordering::SeqCst);
+        }
 
         Ok(())
     }
@@ -554,14 +753,24 @@ impl LogManager {
             .sync_all()
             .await
             .map_err(|e| NeuroQuantumError::StorageError(format!("Failed to force log: {e}")))?;
+        self.sync_count.fetch_add(1, Ordering::SeqCst);
 
         debug!("Forced log up to LSN {}", _lsn);
         Ok(())
     }
 
+    /// Number of fsyncs issued on the log so far
+    pub fn sync_count(&self) -> u64 {
+        self.sync_count.load(Ordering::SeqCst)
+    }
+
     /// Read log records for recovery
     pub async fn read_log(&self) -> Result<Vec<LogRecord>, NeuroQuantumError> {
         let mut log_file = self.log_file.lock().await;
+        Self::read_records(&mut log_file).await
+    }
+
+    async fn read_records(log_file: &mut File) -> Result<Vec<LogRecord>, NeuroQuantumError> {
         log_file.seek(SeekFrom::Start(0)).await.map_err(|e| {
             NeuroQuantumError::StorageError(format!("Failed to seek log file: {e}"))
         })?;
@@ -617,10 +826,19 @@ impl LogManager {
             .await?;
 
         self.force_log(lsn).await?;
+        *self.last_checkpoint.lock().await = Some(CheckpointInfo {
+            lsn,
+            timestamp: chrono::Utc::now(),
+        });
         info!("📍 Checkpoint written at LSN {}", lsn);
         Ok(lsn)
     }
 
+    /// Most recent checkpoint written since the log was opened
+    pub async fn last_checkpoint(&self) -> Option<CheckpointInfo> {
+        *self.last_checkpoint.lock().await
+    }
+
     /// Get the path to the WAL log file
     #[must_use]
     pub fn get_log_path(&self) -> &Path {
@@ -659,8 +877,12 @@ impl LogManager {
         &self,
         checkpoint_lsn: LSN,
     ) -> Result<(), NeuroQuantumError> {
+        // Hold the log file so no record is appended while it is rewritten
+        self.flush_buffer().await?;
+        let mut log_file = self.log_file.lock().await;
+
         // Read all records
-        let all_records = self.read_log().await?;
+        let all_records = Self::read_records(&mut log_file).await?;
         let total_records = all_records.len();
 
         // Keep only records after the checkpoint
@@ -716,6 +938,16 @@ impl LogManager {
                 ))
             })?;
 
+        // The open handle still refers to the replaced file
+        *log_file = OpenOptions::new()
+            .append(true)
+            .read(true)
+            .open(&self.log_path)
+            .await
+            .map_err(|e| {
+                NeuroQuantumError::StorageError(format!("Failed to reopen WAL file: {e}"))
+            })?;
+
         info!(
             "✂️ WAL truncated after checkpoint LSN {}: {} records removed, {} kept",
             checkpoint_lsn,
@@ -746,6 +978,13 @@ impl LogManager {
     }
 }
 
+/// A checkpoint written to the WAL
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckpointInfo {
+    pub lsn: LSN,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
 /// Statistics about the WAL log file
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WALLogStats {
@@ -1034,6 +1273,121 @@ impl RecoveryManager {
     }
 }
 
+/// Resource identifier of a row in the version store
+fn row_resource(table: &str, key: &str) -> ResourceId {
+    format!("row:{table}:{key}")
+}
+
+/// A committed version of a row
+#[derive(Debug, Clone)]
+struct RowVersion {
+    /// Commit timestamp of the transaction that wrote this version
+    commit_ts: u64,
+    /// Row contents, `None` if the row was deleted
+    data: Option<Vec<u8>>,
+}
+
+/// Per-row version chains for MVCC, each ordered by commit timestamp
+#[derive(Debug, Default)]
+struct VersionStore {
+    chains: DashMap<ResourceId, Vec<RowVersion>>,
+    /// Chains of rows whose committed contents live in the storage engine;
+    /// they are only kept while a snapshot or a writer still needs them
+    stored: DashSet<ResourceId>,
+}
+
+impl VersionStore {
+    /// Newest version of `resource` committed at or before `snapshot`
+    fn read(&self, resource: &str, snapshot: u64) -> Option<Vec<u8>> {
+        let chain = self.chains.get(resource)?;
+        Self::visible(&chain, snapshot)
+    }
+
+    fn visible(chain: &[RowVersion], snapshot: u64) -> Option<Vec<u8>> {
+        chain
+            .iter()
+            .rev()
+            .find(|version| version.commit_ts <= snapshot)
+            .and_then(|version| version.data.clone())
+    }
+
+    /// Commit timestamp of the newest version of `resource`
+    fn latest_commit_ts(&self, resource: &str) -> Option<u64> {
+        self.chains
+            .get(resource)
+            .and_then(|chain| chain.last().map(|version| version.commit_ts))
+    }
+
+    /// Start the chain of a row whose committed contents live outside the
+    /// store with those contents, visible to every snapshot
+    fn seed(&self, resource: &str, data: Option<Vec<u8>>) {
+        self.chains
+            .entry(resource.to_string())
+            .or_insert_with(|| vec![RowVersion { commit_ts: 0, data }]);
+        self.stored.insert(resource.to_string());
+    }
+
+    fn install(&self, resource: &str, commit_ts: u64, data: Option<Vec<u8>>) {
+        self.chains
+            .entry(resource.to_string())
+            .or_default()
+            .push(RowVersion { commit_ts, data });
+    }
+
+    /// Drop versions of `resource` that no snapshot at or after `oldest_snapshot`
+    /// can see
+    fn prune(&self, resource: &str, oldest_snapshot: u64) {
+        let remove = {
+            let Some(mut chain) = self.chains.get_mut(resource) else {
+                return;
+            };
+            // Keep the newest version visible to the oldest snapshot and everything after it
+            let visible = chain
+                .iter()
+                .rposition(|version| version.commit_ts <= oldest_snapshot);
+            if let Some(visible) = visible {
+                chain.drain(..visible);
+            }
+            chain.len() == 1 && chain[0].data.is_none() && chain[0].commit_ts <= oldest_snapshot
+        };
+        if remove {
+            self.chains.remove(resource);
+        }
+    }
+
+    /// Drop the chain of a storage engine row once no transaction in
+    /// `active` is writing it and every snapshot sees its newest version
+    fn release(&self, resource: &str, active: &HashMap<TransactionId, Transaction>) {
+        if active
+            .values()
+            .any(|tx| tx.pending_writes.contains_key(resource))
+        {
+            return;
+        }
+        let oldest_snapshot = TransactionManager::oldest_snapshot(active).unwrap_or(u64::MAX);
+        self.prune(resource, oldest_snapshot);
+        self.chains.remove_if(resource, |_, chain| {
+            chain.len() == 1 && chain[0].commit_ts <= oldest_snapshot
+        });
+        if !self.chains.contains_key(resource) {
+            self.stored.remove(resource);
+        }
+    }
+
+    /// Drop the chains of all storage engine rows no longer needed, see
+    /// [`Self::release`]
+    fn release_stored(&self, active: &HashMap<TransactionId, Transaction>) {
+        let stored: Vec<ResourceId> = self
+            .stored
+            .iter()
+            .map(|resource| resource.key().clone())
+            .collect();
+        for resource in stored {
+            self.release(&resource, active);
+        }
+    }
+}
+
 /// Main transaction manager coordinating all transaction operations
 #[derive(Clone)]
 pub struct TransactionManager {
@@ -1045,10 +1399,15 @@ pub struct TransactionManager {
     log_manager: Arc<LogManager>,
     /// Recovery manager for crash recovery
     recovery_manager: Arc<RecoveryManager>,
-    /// Global snapshot version counter for MVCC
+    /// Global snapshot version counter for MVCC; incremented by every commit
     global_version: Arc<AtomicU64>,
+    /// Committed row versions read and written through `read_row`/`write_row`,
+    /// and older versions of storage engine rows still needed by snapshots
+    version_store: Arc<VersionStore>,
     /// Transaction timeout in seconds
     default_timeout: u64,
+    /// Aborted transactions by reason
+    aborts: IntCounterVec,
 }
 
 impl Default for TransactionManager {
@@ -1068,7 +1427,9 @@ impl TransactionManager {
             log_manager: Arc::new(LogManager::new_placeholder()),
             recovery_manager: Arc::new(RecoveryManager::new_placeholder()),
             global_version: Arc::new(AtomicU64::new(1)),
+            version_store: Arc::new(VersionStore::default()),
             default_timeout: 30,
+            aborts: abort_counter(),
         }
     }
 
@@ -1086,22 +1447,36 @@ impl TransactionManager {
             log_manager,
             recovery_manager,
             global_version: Arc::new(AtomicU64::new(1)),
+            version_store: Arc::new(VersionStore::default()),
             default_timeout: 30, // 30 seconds default
+            aborts: abort_counter(),
         })
     }
 
-    /// Begin a new transaction
-    #[instrument(skip(self))]
+    /// Begin a new transaction at the given isolation level
+    ///
+    /// Equivalent to [`Self::begin_with_isolation`].
     pub async fn begin_transaction(
         &self,
         isolation_level: IsolationLevel,
+    ) -> Result<TransactionId, NeuroQuantumError> {
+        self.begin_with_isolation(isolation_level).await
+    }
+
+    /// Begin a new transaction running at `isolation_level`
+    ///
+    /// The level applies to this transaction only, so a reporting query can
+    /// run at `ReadCommitted` next to a `Serializable` transfer. It decides
+    /// what [`Self::read_row`] sees and which read locks are held, see
+    /// [`IsolationLevel`].
+    #[instrument(skip(self))]
+    pub async fn begin_with_isolation(
+        &self,
+        isolation_level: IsolationLevel,
     ) -> Result<TransactionId, NeuroQuantumError> {
         let mut tx = Transaction::new(isolation_level, self.default_timeout);
         let tx_id = tx.id;
 
-        // Assign snapshot version for MVCC
-        tx.snapshot_version = self.global_version.load(Ordering::SeqCst);
-
         // Write BEGIN log record
         let lsn = self
             .log_manager
@@ -1118,8 +1493,10 @@ impl TransactionManager {
         tx.first_lsn = Some(lsn);
         tx.last_lsn = Some(lsn);
 
-        // Add to active transactions
+        // Assign snapshot version for MVCC. Taken under the same lock commits hold
+        // while installing versions, so the snapshot never sees a partial commit.
         let mut active = self.active_transactions.write().await;
+        tx.snapshot_version = self.global_version.load(Ordering::SeqCst);
         active.insert(tx_id, tx);
 
         info!(
@@ -1129,9 +1506,24 @@ impl TransactionManager {
         Ok(tx_id)
     }
 
-    /// Commit a transaction using 2-Phase Commit
-    #[instrument(skip(self))]
+    /// Commit a transaction using 2-Phase Commit, forcing the commit to disk
     pub async fn commit(&self, tx_id: TransactionId) -> Result<(), NeuroQuantumError> {
+        self.commit_with_durability(tx_id, Durability::Durable)
+            .await
+            .map(|_| ())
+    }
+
+    /// Commit a transaction using 2-Phase Commit with the given `durability`
+    ///
+    /// A [`Durability::Relaxed`] commit writes its commit record without
+    /// forcing the log to disk, so a crash before the next forced commit or
+    /// full log buffer loses it.
+    #[instrument(skip(self))]
+    pub async fn commit_with_durability(
+        &self,
+        tx_id: TransactionId,
+        durability: Durability,
+    ) -> Result<CommitResult, NeuroQuantumError> {
         let mut active = self.active_transactions.write().await;
 
         let tx = active.get_mut(&tx_id).ok_or_else(|| {
@@ -1146,11 +1538,41 @@ impl TransactionManager {
             )));
         }
 
+        if let Some(reason) = tx.abort_reason {
+            self.abort_transaction(&mut active, tx_id, reason).await?;
+            return Err(NeuroQuantumError::transaction_aborted(
+                reason,
+                format!("Transaction {tx_id:?} cannot commit after a {reason}"),
+            ));
+        }
+
+        // First committer wins: abort if another transaction committed a newer
+        // version of a row this one wrote since its snapshot was taken
+        if tx.isolation_level == IsolationLevel::Snapshot {
+            let snapshot = tx.snapshot_version;
+            let conflict = tx.pending_writes.keys().find(|resource| {
+                self.version_store
+                    .latest_commit_ts(resource)
+                    .is_some_and(|commit_ts| commit_ts > snapshot)
+            });
+            if let Some(resource) = conflict.cloned() {
+                self.abort_transaction(&mut active, tx_id, AbortReason::WriteConflict)
+                    .await?;
+                return Err(NeuroQuantumError::transaction_aborted(
+                    AbortReason::WriteConflict,
+                    format!("{resource} was modified by a concurrent transaction"),
+                ));
+            }
+        }
+
         // Phase 1: Prepare
         tx.status = TransactionStatus::Preparing;
 
-        // Validate all locks are still held
-        if tx.locks.is_empty() && !tx.write_set.is_empty() {
+        // Validate all locks are still held (snapshot writes are not locked)
+        if tx.isolation_level != IsolationLevel::Snapshot
+            && tx.locks.is_empty()
+            && !tx.write_set.is_empty()
+        {
             return Err(NeuroQuantumError::TransactionError(
                 "Transaction lost locks before commit".to_string(),
             ));
@@ -1167,22 +1589,40 @@ impl TransactionManager {
             .write_log_record(Some(tx_id), tx.last_lsn, LogRecordType::Commit { tx_id })
             .await?;
 
-        // Force log to disk for durability
-        self.log_manager.force_log(lsn).await?;
-
-        // Update global version for MVCC
-        self.global_version.fetch_add(1, Ordering::SeqCst);
+        // Force log to disk for durability unless relaxed
+        let synchronous = durability == Durability::Durable;
+        if synchronous {
+            self.log_manager.force_log(lsn).await?;
+        } else {
+            self.log_manager.write_log().await?;
+        }
 
+        // Update global version for MVCC and install this transaction's row
+        // versions under the new commit timestamp
+        let commit_ts = self.global_version.fetch_add(1, Ordering::SeqCst) + 1;
+        let pending_writes = std::mem::take(&mut tx.pending_writes);
         tx.status = TransactionStatus::Committed;
 
+        let oldest_snapshot = active
+            .values()
+            .filter(|other| other.id != tx_id)
+            .map(|other| other.snapshot_version)
+            .min()
+            .unwrap_or(commit_ts);
+        for (resource, data) in pending_writes {
+            self.version_store.install(&resource, commit_ts, data);
+            self.version_store.prune(&resource, oldest_snapshot);
+        }
+
         // Release all locks
         self.lock_manager.release_locks(&tx_id).await?;
 
         // Remove from active transactions
         active.remove(&tx_id);
+        self.version_store.release_stored(&active);
 
-        info!("✅ Transaction {:?} committed", tx_id);
-        Ok(())
+        info!("✅ Transaction {:?} committed ({:?})", tx_id, durability);
+        Ok(CommitResult { lsn, synchronous })
     }
 
     /// Get the undo log for a transaction (for storage engine rollback)
@@ -1192,10 +1632,43 @@ impl TransactionManager {
     }
 
     /// Rollback a transaction
+    ///
+    /// Counted as a [`AbortReason::UserAbort`] unless the transaction was
+    /// already doomed, e.g. as a deadlock victim.
     #[instrument(skip(self))]
     pub async fn rollback(&self, tx_id: TransactionId) -> Result<(), NeuroQuantumError> {
         let mut active = self.active_transactions.write().await;
+        let reason = active
+            .get(&tx_id)
+            .and_then(|tx| tx.abort_reason)
+            .unwrap_or(AbortReason::UserAbort);
+        self.abort_transaction(&mut active, tx_id, reason).await?;
+
+        warn!("🔙 Transaction {:?} rolled back", tx_id);
+        Ok(())
+    }
+
+    /// Rollback a transaction, counting it as aborted for `reason`
+    #[instrument(skip(self))]
+    pub async fn rollback_with_reason(
+        &self,
+        tx_id: TransactionId,
+        reason: AbortReason,
+    ) -> Result<(), NeuroQuantumError> {
+        let mut active = self.active_transactions.write().await;
+        self.abort_transaction(&mut active, tx_id, reason).await?;
+
+        warn!("🔙 Transaction {:?} rolled back ({})", tx_id, reason);
+        Ok(())
+    }
 
+    /// Abort an active transaction while holding the active transaction lock
+    async fn abort_transaction(
+        &self,
+        active: &mut HashMap<TransactionId, Transaction>,
+        tx_id: TransactionId,
+        reason: AbortReason,
+    ) -> Result<(), NeuroQuantumError> {
         let tx = active.get_mut(&tx_id).ok_or_else(|| {
             NeuroQuantumError::TransactionError(format!("Transaction {tx_id:?} not found"))
         })?;
@@ -1234,95 +1707,414 @@ impl TransactionManager {
 
         // Remove from active transactions
         active.remove(&tx_id);
-
-        warn!("🔙 Transaction {:?} rolled back", tx_id);
+        self.abort_count(reason).inc();
+        self.version_store.release_stored(active);
         Ok(())
     }
 
-    /// Acquire a lock for a transaction
-    pub async fn acquire_lock(
+    /// Oldest snapshot still held by an active transaction
+    fn oldest_snapshot(active: &HashMap<TransactionId, Transaction>) -> Option<u64> {
+        active.values().map(|tx| tx.snapshot_version).min()
+    }
+
+    fn abort_count(&self, reason: AbortReason) -> IntCounter {
+        self.aborts.with_label_values(&reason.metric_labels())
+    }
+
+    /// Read a row through the version store.
+    ///
+    /// Returns the transaction's own uncommitted write if it has one.
+    /// Otherwise what the read sees depends on the isolation level:
+    /// - `ReadUncommitted` sees other transactions' uncommitted writes
+    /// - `ReadCommitted` sees the newest committed version without locking
+    /// - `RepeatableRead` and `Serializable` take a shared row lock held until
+    ///   the transaction ends, so the row cannot change under them
+    /// - `Snapshot` sees the newest version committed before it began
+    ///
+    /// Returns `None` if the row does not exist or was deleted.
+    pub async fn read_row(
         &self,
         tx_id: TransactionId,
-        resource: ResourceId,
-        lock_type: LockType,
-    ) -> Result<(), NeuroQuantumError> {
-        // Update transaction activity
-        {
-            let mut active = self.active_transactions.write().await;
-            if let Some(tx) = active.get_mut(&tx_id) {
-                tx.touch();
-
-                // Add to read/write set for conflict detection
-                match lock_type {
-                    | LockType::Shared | LockType::IntentionShared => {
-                        tx.read_set.insert(resource.clone());
-                    },
-                    | LockType::Exclusive | LockType::IntentionExclusive => {
-                        tx.write_set.insert(resource.clone());
-                    },
-                }
+        table: &str,
+        key: &str,
+    ) -> Result<Option<Vec<u8>>, NeuroQuantumError> {
+        let resource = row_resource(table, key);
 
-                tx.locks.insert(resource.clone());
+        let isolation_level = {
+            let active = self.active_transactions.read().await;
+            let tx = active.get(&tx_id).ok_or_else(|| {
+                NeuroQuantumError::TransactionError(format!("Transaction {tx_id:?} not found"))
+            })?;
+            if let Some(write) = tx.pending_writes.get(&resource) {
+                return Ok(write.clone());
             }
+            tx.isolation_level
+        };
+
+        if matches!(
+            isolation_level,
+            IsolationLevel::RepeatableRead | IsolationLevel::Serializable
+        ) {
+            self.acquire_lock(tx_id, resource.clone(), LockType::Shared)
+                .await?;
         }
 
-        self.lock_manager
-            .acquire_lock(tx_id, resource, lock_type)
-            .await
+        let active = self.active_transactions.read().await;
+        let tx = active.get(&tx_id).ok_or_else(|| {
+            NeuroQuantumError::TransactionError(format!("Transaction {tx_id:?} not found"))
+        })?;
+        let snapshot = match isolation_level {
+            | IsolationLevel::ReadUncommitted => {
+                let dirty = active
+                    .values()
+                    .filter(|other| other.id != tx_id)
+                    .find_map(|other| other.pending_writes.get(&resource));
+                if let Some(write) = dirty {
+                    return Ok(write.clone());
+                }
+                u64::MAX
+            },
+            | IsolationLevel::Snapshot => tx.snapshot_version,
+            | _ => u64::MAX,
+        };
+        Ok(self.version_store.read(&resource, snapshot))
     }
 
-    /// Log a data modification
-    pub async fn log_update(
+    /// Lock `resource` for a read as the transaction's isolation level requires
+    ///
+    /// `ReadUncommitted` reads take no lock. `RepeatableRead` and
+    /// `Serializable` keep the shared lock until the transaction ends, as do
+    /// `Snapshot` transactions, which only have a versioned view of rows
+    /// read through [`Self::read_row`].
+    /// `ReadCommitted` waits for the shared lock, so it never reads
+    /// uncommitted data, but only for the duration of the read: it returns
+    /// `true`, and the caller gives the lock back with
+    /// [`Self::release_read_lock`] once it has read.
+    pub async fn acquire_read_lock(
         &self,
         tx_id: TransactionId,
-        table: String,
-        key: String,
-        before_image: Option<Vec<u8>>,
-        after_image: Vec<u8>,
-    ) -> Result<LSN, NeuroQuantumError> {
-        let record_type = LogRecordType::Update {
-            tx_id,
-            table,
-            key,
-            before_image,
-            after_image,
-        };
-
-        // Get the transaction and its last_lsn
-        let last_lsn = {
+        resource: ResourceId,
+    ) -> Result<bool, NeuroQuantumError> {
+        let (isolation_level, already_locked) = {
             let active = self.active_transactions.read().await;
             let tx = active.get(&tx_id).ok_or_else(|| {
                 NeuroQuantumError::TransactionError(format!("Transaction {tx_id:?} not found"))
             })?;
-            tx.last_lsn
+            (tx.isolation_level, tx.locks.contains(&resource))
         };
 
-        // Write to WAL
-        let lsn = self
-            .log_manager
-            .write_log_record(Some(tx_id), last_lsn, record_type.clone())
-            .await?;
+        match isolation_level {
+            | IsolationLevel::ReadUncommitted => Ok(false),
+            | IsolationLevel::ReadCommitted => {
+                self.acquire_lock(tx_id, resource, LockType::Shared).await?;
+                // A lock taken earlier in the transaction must outlive this read
+                Ok(!already_locked)
+            },
+            | IsolationLevel::RepeatableRead
+            | IsolationLevel::Serializable
+            | IsolationLevel::Snapshot => {
+                self.acquire_lock(tx_id, resource, LockType::Shared).await?;
+                Ok(false)
+            },
+        }
+    }
 
-        // Add to transaction's undo log for rollback support
+    /// Give back a read lock for which [`Self::acquire_read_lock`] returned `true`
+    pub async fn release_read_lock(
+        &self,
+        tx_id: TransactionId,
+        resource: &str,
+    ) -> Result<(), NeuroQuantumError> {
         {
             let mut active = self.active_transactions.write().await;
             if let Some(tx) = active.get_mut(&tx_id) {
-                tx.last_lsn = Some(lsn);
-                tx.undo_log.push(LogRecord {
-                    lsn,
-                    prev_lsn: last_lsn,
-                    tx_id: Some(tx_id),
-                    record_type,
-                    timestamp: chrono::Utc::now(),
-                });
+                tx.locks.remove(resource);
+                tx.read_set.remove(resource);
             }
         }
-
-        Ok(lsn)
+        self.lock_manager.release_lock(&tx_id, resource).await
     }
 
-    /// Create a savepoint within a transaction
-    pub async fn create_savepoint(
+    /// Write a row through the version store; the new version becomes visible
+    /// to other transactions when this one commits.
+    ///
+    /// `Snapshot` transactions do not lock the row; write-write conflicts are
+    /// detected at commit instead. Other isolation levels take an exclusive
+    /// row lock.
+    pub async fn write_row(
+        &self,
+        tx_id: TransactionId,
+        table: &str,
+        key: &str,
+        data: Vec<u8>,
+    ) -> Result<(), NeuroQuantumError> {
+        self.stage_row_write(tx_id, table, key, Some(data)).await
+    }
+
+    /// Delete a row through the version store; see [`Self::write_row`]
+    pub async fn delete_row(
+        &self,
+        tx_id: TransactionId,
+        table: &str,
+        key: &str,
+    ) -> Result<(), NeuroQuantumError> {
+        self.stage_row_write(tx_id, table, key, None).await
+    }
+
+    async fn stage_row_write(
+        &self,
+        tx_id: TransactionId,
+        table: &str,
+        key: &str,
+        data: Option<Vec<u8>>,
+    ) -> Result<(), NeuroQuantumError> {
+        let resource = row_resource(table, key);
+
+        let isolation_level = {
+            let active = self.active_transactions.read().await;
+            let tx = active.get(&tx_id).ok_or_else(|| {
+                NeuroQuantumError::TransactionError(format!("Transaction {tx_id:?} not found"))
+            })?;
+            if tx.status != TransactionStatus::Active {
+                return Err(NeuroQuantumError::TransactionError(format!(
+                    "Transaction {:?} is not active (status: {:?})",
+                    tx_id, tx.status
+                )));
+            }
+            tx.isolation_level
+        };
+
+        if isolation_level != IsolationLevel::Snapshot {
+            self.acquire_lock(tx_id, resource.clone(), LockType::Exclusive)
+                .await?;
+        }
+
+        let mut active = self.active_transactions.write().await;
+        let tx = active.get_mut(&tx_id).ok_or_else(|| {
+            NeuroQuantumError::TransactionError(format!("Transaction {tx_id:?} not found"))
+        })?;
+        tx.touch();
+        tx.write_set.insert(resource.clone());
+        tx.pending_writes.insert(resource, data);
+        Ok(())
+    }
+
+    /// Stage a write to a row whose committed contents live in the storage
+    /// engine, which applies the write itself
+    ///
+    /// `before` is the row as last committed and `after` its new contents
+    /// (`None` for an insert and a delete respectively). The row's version
+    /// chain keeps `before` for snapshots taken before this transaction
+    /// commits, so `Snapshot` transactions keep their view of the row while
+    /// the table already holds the write.
+    ///
+    /// A `Snapshot` transaction may not write a row another transaction
+    /// committed since its snapshot was taken: the first committer wins, and
+    /// this transaction is doomed to abort with a write conflict.
+    pub async fn write_stored_row(
+        &self,
+        tx_id: TransactionId,
+        table: &str,
+        key: &str,
+        before: Option<Vec<u8>>,
+        after: Option<Vec<u8>>,
+    ) -> Result<(), NeuroQuantumError> {
+        let resource = row_resource(table, key);
+        {
+            let mut active = self.active_transactions.write().await;
+            let tx = active.get_mut(&tx_id).ok_or_else(|| {
+                NeuroQuantumError::TransactionError(format!("Transaction {tx_id:?} not found"))
+            })?;
+            let snapshot = tx.snapshot_version;
+            let conflict = tx.isolation_level == IsolationLevel::Snapshot
+                && self
+                    .version_store
+                    .latest_commit_ts(&resource)
+                    .is_some_and(|commit_ts| commit_ts > snapshot);
+            if conflict {
+                tx.abort_reason = Some(AbortReason::WriteConflict);
+                return Err(NeuroQuantumError::transaction_aborted(
+                    AbortReason::WriteConflict,
+                    format!("{resource} was modified by a concurrent transaction"),
+                ));
+            }
+        }
+
+        // Seeded once the write is staged, so the chain is not released
+        // before the storage engine applies the write
+        self.stage_row_write(tx_id, table, key, after).await?;
+        self.version_store.seed(&resource, before);
+        Ok(())
+    }
+
+    /// Record a write to a storage engine row made outside any transaction
+    ///
+    /// The write commits at once. While a transaction is active, or the row
+    /// already has a version chain, it is installed as a new version so
+    /// older snapshots keep seeing `before` and concurrent `Snapshot`
+    /// writers of the row detect the conflict.
+    pub async fn record_stored_row(
+        &self,
+        table: &str,
+        key: &str,
+        before: Option<Vec<u8>>,
+        after: Option<Vec<u8>>,
+    ) {
+        let resource = row_resource(table, key);
+        let active = self.active_transactions.write().await;
+        if active.is_empty() && !self.version_store.chains.contains_key(&resource) {
+            return;
+        }
+
+        self.version_store.seed(&resource, before);
+        let commit_ts = self.global_version.fetch_add(1, Ordering::SeqCst) + 1;
+        self.version_store.install(&resource, commit_ts, after);
+        self.version_store.release(&resource, &active);
+        drop(active);
+    }
+
+    /// The versions of `table`'s rows a `Snapshot` transaction sees where
+    /// they differ from the rows stored in the table, keyed by row key
+    ///
+    /// Includes the transaction's own writes; `None` marks a row that is
+    /// not visible to it. Returns `None` for other isolation levels, which
+    /// read the table as stored.
+    pub async fn snapshot_rows(
+        &self,
+        tx_id: TransactionId,
+        table: &str,
+    ) -> Result<Option<HashMap<String, Option<Vec<u8>>>>, NeuroQuantumError> {
+        let active = self.active_transactions.read().await;
+        let tx = active.get(&tx_id).ok_or_else(|| {
+            NeuroQuantumError::TransactionError(format!("Transaction {tx_id:?} not found"))
+        })?;
+        if tx.isolation_level != IsolationLevel::Snapshot {
+            return Ok(None);
+        }
+
+        let prefix = row_resource(table, "");
+        let mut rows: HashMap<String, Option<Vec<u8>>> = self
+            .version_store
+            .chains
+            .iter()
+            .filter_map(|chain| {
+                let key = chain.key().strip_prefix(&prefix)?.to_string();
+                Some((
+                    key,
+                    VersionStore::visible(chain.value(), tx.snapshot_version),
+                ))
+            })
+            .collect();
+        for (resource, write) in &tx.pending_writes {
+            if let Some(key) = resource.strip_prefix(&prefix) {
+                rows.insert(key.to_string(), write.clone());
+            }
+        }
+        Ok(Some(rows))
+    }
+
+    /// Acquire a lock for a transaction
+    pub async fn acquire_lock(
+        &self,
+        tx_id: TransactionId,
+        resource: ResourceId,
+        lock_type: LockType,
+    ) -> Result<(), NeuroQuantumError> {
+        // Update transaction activity
+        {
+            let mut active = self.active_transactions.write().await;
+            if let Some(tx) = active.get_mut(&tx_id) {
+                tx.touch();
+
+                // Add to read/write set for conflict detection
+                match lock_type {
+                    | LockType::Shared | LockType::IntentionShared => {
+                        tx.read_set.insert(resource.clone());
+                    },
+                    | LockType::Exclusive | LockType::IntentionExclusive => {
+                        tx.write_set.insert(resource.clone());
+                    },
+                }
+
+                tx.locks.insert(resource.clone());
+            }
+        }
+
+        match self
+            .lock_manager
+            .acquire_lock(tx_id, resource, lock_type)
+            .await
+        {
+            | Err(NeuroQuantumError::DeadlockDetected(message)) => {
+                // The caller still has to roll back to apply its undo log, so
+                // only doom the victim here; its locks are freed on rollback
+                {
+                    let mut active = self.active_transactions.write().await;
+                    if let Some(tx) = active.get_mut(&tx_id) {
+                        tx.abort_reason = Some(AbortReason::Deadlock);
+                    }
+                }
+                Err(NeuroQuantumError::transaction_aborted(
+                    AbortReason::Deadlock,
+                    message,
+                ))
+            },
+            | result => result,
+        }
+    }
+
+    /// Log a data modification
+    pub async fn log_update(
+        &self,
+        tx_id: TransactionId,
+        table: String,
+        key: String,
+        before_image: Option<Vec<u8>>,
+        after_image: Vec<u8>,
+    ) -> Result<LSN, NeuroQuantumError> {
+        let record_type = LogRecordType::Update {
+            tx_id,
+            table,
+            key,
+            before_image,
+            after_image,
+        };
+
+        // Get the transaction and its last_lsn
+        let last_lsn = {
+            let active = self.active_transactions.read().await;
+            let tx = active.get(&tx_id).ok_or_else(|| {
+                NeuroQuantumError::TransactionError(format!("Transaction {tx_id:?} not found"))
+            })?;
+            tx.last_lsn
+        };
+
+        // Write to WAL
+        let lsn = self
+            .log_manager
+            .write_log_record(Some(tx_id), last_lsn, record_type.clone())
+            .await?;
+
+        // Add to transaction's undo log for rollback support
+        {
+            let mut active = self.active_transactions.write().await;
+            if let Some(tx) = active.get_mut(&tx_id) {
+                tx.last_lsn = Some(lsn);
+                tx.undo_log.push(LogRecord {
+                    lsn,
+                    prev_lsn: last_lsn,
+                    tx_id: Some(tx_id),
+                    record_type,
+                    timestamp: chrono::Utc::now(),
+                });
+            }
+        }
+
+        Ok(lsn)
+    }
+
+    /// Create a savepoint within a transaction
+    pub async fn create_savepoint(
         &self,
         tx_id: TransactionId,
         name: String,
@@ -1333,13 +2125,9 @@ impl TransactionManager {
             NeuroQuantumError::TransactionError(format!("Transaction {tx_id:?} not found"))
         })?;
 
-        tx.touch();
-
-        // Get current LSN for this savepoint
-        let lsn = self.log_manager.lsn_counter.load(Ordering::SeqCst);
-
-        // Savepoint is tracked in-memory with its LSN
+        // Savepoint is tracked in-memory with the transaction's last LSN
         // Rollback to savepoint uses the undo log to restore state
+        let lsn = tx.savepoint(&name);
 
         debug!(
             "💾 Savepoint '{}' created for transaction {:?} at LSN {}",
@@ -1370,26 +2158,43 @@ impl TransactionManager {
             .cloned()
             .collect();
 
-        // Apply undo in reverse order
+        // The storage engine applies the before-images, see
+        // `StorageEngine::rollback_to_savepoint`
+        let mut undone_rows = HashSet::new();
         for log_record in records_to_undo.iter().rev() {
-            if let LogRecordType::Update {
-                before_image: _before_image,
-                table,
-                key,
-                ..
-            } = &log_record.record_type
-            {
+            if let LogRecordType::Update { table, key, .. } = &log_record.record_type {
                 debug!(
                     "Undoing update on {}.{} (LSN: {})",
                     table, key, log_record.lsn
                 );
-                // NOTE: Actual storage undo is handled by StorageEngine::rollback_to_savepoint()
-                // which applies before_image data to restore previous state
+                undone_rows.insert(row_resource(table, key));
             }
         }
 
-        // Remove undone records from undo log
+        // Remove undone records from undo log, and savepoints created after this one
         tx.undo_log.retain(|record| record.lsn <= savepoint_lsn);
+        tx.savepoints
+            .retain(|savepoint| savepoint.lsn <= savepoint_lsn);
+
+        // Stage the undone rows as the kept records left them
+        for resource in &undone_rows {
+            tx.pending_writes.remove(resource);
+        }
+        for log_record in &tx.undo_log {
+            if let LogRecordType::Update {
+                table,
+                key,
+                after_image,
+                ..
+            } = &log_record.record_type
+            {
+                let resource = row_resource(table, key);
+                if undone_rows.contains(&resource) {
+                    let data = (!after_image.is_empty()).then(|| after_image.clone());
+                    tx.pending_writes.insert(resource, data);
+                }
+            }
+        }
 
         info!(
             "↩️  Transaction {:?} rolled back to savepoint (LSN: {})",
@@ -1398,19 +2203,51 @@ impl TransactionManager {
         Ok(())
     }
 
-    /// Release a savepoint (no-op in this implementation as savepoints are LSN-based)
+    /// Rollback transaction to the savepoint `name`
+    ///
+    /// Returns the undone log records, oldest first, so the storage engine can
+    /// apply their before-images. Fails if the savepoint does not exist or has
+    /// been released.
+    pub async fn rollback_to_named_savepoint(
+        &self,
+        tx_id: TransactionId,
+        name: &str,
+    ) -> Result<Vec<LogRecord>, NeuroQuantumError> {
+        let mut active = self.active_transactions.write().await;
+
+        let tx = active.get_mut(&tx_id).ok_or_else(|| {
+            NeuroQuantumError::TransactionError(format!("Transaction {tx_id:?} not found"))
+        })?;
+
+        let undone = tx.rollback_to(name)?;
+
+        info!(
+            "↩️  Transaction {:?} rolled back to savepoint '{}' ({} records undone)",
+            tx_id,
+            name,
+            undone.len()
+        );
+        Ok(undone)
+    }
+
+    /// Release a savepoint and any savepoints created after it
     pub async fn release_savepoint(
         &self,
         tx_id: TransactionId,
-        _name: String,
+        name: String,
     ) -> Result<(), NeuroQuantumError> {
-        let active = self.active_transactions.read().await;
+        let mut active = self.active_transactions.write().await;
 
-        let _tx = active.get(&tx_id).ok_or_else(|| {
+        let tx = active.get_mut(&tx_id).ok_or_else(|| {
             NeuroQuantumError::TransactionError(format!("Transaction {tx_id:?} not found"))
         })?;
 
-        debug!("🗑️  Savepoint released for transaction {:?}", tx_id);
+        tx.release(&name)?;
+
+        debug!(
+            "🗑️  Savepoint '{}' released for transaction {:?}",
+            name, tx_id
+        );
         Ok(())
     }
 
@@ -1427,19 +2264,36 @@ impl TransactionManager {
 
         for tx_id in timed_out {
             warn!("⏰ Transaction {:?} timed out, rolling back", tx_id);
-            self.rollback(tx_id).await?;
+            self.rollback_with_reason(tx_id, AbortReason::Timeout)
+                .await?;
         }
 
         Ok(())
     }
 
-    /// Write a checkpoint
-    pub async fn checkpoint(&self) -> Result<(), NeuroQuantumError> {
+    /// Write a checkpoint, returning its LSN
+    pub async fn checkpoint(&self) -> Result<LSN, NeuroQuantumError> {
         let active = self.active_transactions.read().await;
         let active_tx_ids: Vec<TransactionId> = active.keys().copied().collect();
 
-        self.log_manager.write_checkpoint(active_tx_ids).await?;
-        Ok(())
+        self.log_manager.write_checkpoint(active_tx_ids).await
+    }
+
+    /// Most recent checkpoint written since the WAL was opened
+    pub async fn last_checkpoint(&self) -> Option<CheckpointInfo> {
+        self.log_manager.last_checkpoint().await
+    }
+
+    /// Oldest LSN logged by a transaction that is still active
+    ///
+    /// Records from this LSN on are needed to roll the transaction back.
+    pub async fn oldest_active_lsn(&self) -> Option<LSN> {
+        self.active_transactions
+            .read()
+            .await
+            .values()
+            .filter_map(|tx| tx.first_lsn)
+            .min()
     }
 
     /// Get transaction statistics
@@ -1450,9 +2304,26 @@ impl TransactionManager {
             active_transactions: active.len(),
             global_version: self.global_version.load(Ordering::SeqCst),
             next_lsn: self.log_manager.lsn_counter.load(Ordering::SeqCst),
+            aborts: AbortStatistics {
+                deadlock: self.abort_count(AbortReason::Deadlock).get(),
+                write_conflict: self.abort_count(AbortReason::WriteConflict).get(),
+                timeout: self.abort_count(AbortReason::Timeout).get(),
+                user_abort: self.abort_count(AbortReason::UserAbort).get(),
+                constraint_violation: self.abort_count(AbortReason::ConstraintViolation).get(),
+            },
         }
     }
 
+    /// Counter of aborted transactions labelled by `reason` and `retryable`
+    ///
+    /// Clones share their counts, so the returned collector can be
+    /// registered with a Prometheus registry such as
+    /// [`MetricsExporter::registry`](crate::monitoring::MetricsExporter::registry).
+    #[must_use]
+    pub fn abort_metrics(&self) -> IntCounterVec {
+        self.aborts.clone()
+    }
+
     /// Perform full ARIES crash recovery with storage integration
     ///
     /// This delegates to the `RecoveryManager`'s `recover_with_storage()` method,
@@ -1514,6 +2385,60 @@ pub struct TransactionStatistics {
     pub active_transactions: usize,
     pub global_version: u64,
     pub next_lsn: LSN,
+    /// Aborted transactions since startup
+    #[serde(default)]
+    pub aborts: AbortStatistics,
+}
+
+/// Aborted transactions by [`AbortReason`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbortStatistics {
+    pub deadlock: u64,
+    pub write_conflict: u64,
+    pub timeout: u64,
+    pub user_abort: u64,
+    pub constraint_violation: u64,
+}
+
+impl AbortStatistics {
+    /// Aborts for `reason`
+    #[must_use]
+    pub const fn get(&self, reason: AbortReason) -> u64 {
+        match reason {
+            | AbortReason::Deadlock => self.deadlock,
+            | AbortReason::WriteConflict => self.write_conflict,
+            | AbortReason::Timeout => self.timeout,
+            | AbortReason::UserAbort => self.user_abort,
+            | AbortReason::ConstraintViolation => self.constraint_violation,
+        }
+    }
+
+    /// Aborts for any reason
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.deadlock
+            + self.write_conflict
+            + self.timeout
+            + self.user_abort
+            + self.constraint_violation
+    }
+}
+
+#[allow(clippy::expect_used)] // The metric options are constant and valid
+fn abort_counter() -> IntCounterVec {
+    let counter = IntCounterVec::new(
+        Opts::new(
+            "neuroquantum_transaction_aborts_total",
+            "Aborted transactions by reason",
+        ),
+        &["reason", "retryable"],
+    )
+    .expect("Failed to create transaction abort counter");
+    // Export every reason from the start rather than once it first occurs
+    for reason in AbortReason::ALL {
+        counter.with_label_values(&reason.metric_labels()).reset();
+    }
+    counter
 }
 
 #[cfg(test)]
@@ -1549,39 +2474,70 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_deadlock_detection() {
+    async fn test_relaxed_commit_skips_fsync() {
         let temp_dir = TempDir::new().unwrap();
         let tx_manager = TransactionManager::new_async(temp_dir.path())
             .await
             .unwrap();
 
-        let tx1 = tx_manager
+        let tx_id = tx_manager
             .begin_transaction(IsolationLevel::ReadCommitted)
             .await
             .unwrap();
-        let tx2 = tx_manager
-            .begin_transaction(IsolationLevel::ReadCommitted)
+        let syncs = tx_manager.log_manager.sync_count();
+        let result = tx_manager
+            .commit_with_durability(tx_id, Durability::Relaxed)
             .await
             .unwrap();
+        assert!(!result.synchronous);
+        assert_eq!(tx_manager.log_manager.sync_count(), syncs);
 
-        // TX1 locks resource A
-        tx_manager
-            .acquire_lock(tx1, "A".to_string(), LockType::Exclusive)
+        let tx_id = tx_manager
+            .begin_transaction(IsolationLevel::ReadCommitted)
             .await
             .unwrap();
-
-        // TX2 locks resource B
-        tx_manager
-            .acquire_lock(tx2, "B".to_string(), LockType::Exclusive)
+        let result = tx_manager
+            .commit_with_durability(tx_id, Durability::Durable)
             .await
             .unwrap();
-
-        // This should work - no cycle yet
-        assert!(tx_manager.active_transactions.read().await.len() == 2);
+        assert!(result.synchronous);
+        assert!(tx_manager.log_manager.sync_count() > syncs);
     }
 
     #[tokio::test]
-    async fn test_wal_log_stats() {
+    async fn test_deadlock_detection() {
+        let temp_dir = TempDir::new().unwrap();
+        let tx_manager = TransactionManager::new_async(temp_dir.path())
+            .await
+            .unwrap();
+
+        let tx1 = tx_manager
+            .begin_transaction(IsolationLevel::ReadCommitted)
+            .await
+            .unwrap();
+        let tx2 = tx_manager
+            .begin_transaction(IsolationLevel::ReadCommitted)
+            .await
+            .unwrap();
+
+        // TX1 locks resource A
+        tx_manager
+            .acquire_lock(tx1, "A".to_string(), LockType::Exclusive)
+            .await
+            .unwrap();
+
+        // TX2 locks resource B
+        tx_manager
+            .acquire_lock(tx2, "B".to_string(), LockType::Exclusive)
+            .await
+            .unwrap();
+
+        // This should work - no cycle yet
+        assert!(tx_manager.active_transactions.read().await.len() == 2);
+    }
+
+    #[tokio::test]
+    async fn test_wal_log_stats() {
         let temp_dir = TempDir::new().unwrap();
         let tx_manager = TransactionManager::new_async(temp_dir.path())
             .await
@@ -1751,7 +2707,12 @@ mod tests {
         let records_before = stats_before.record_count;
 
         // Write checkpoint
-        tx_manager.checkpoint().await.unwrap();
+        assert_eq!(tx_manager.last_checkpoint().await, None);
+        let checkpoint_lsn = tx_manager.checkpoint().await.unwrap();
+        assert_eq!(
+            tx_manager.last_checkpoint().await.map(|c| c.lsn),
+            Some(checkpoint_lsn)
+        );
 
         // Force flush again
         tx_manager.log_manager.force_log(0).await.unwrap();
@@ -1782,4 +2743,597 @@ mod tests {
             records_before + 2
         );
     }
+
+    #[tokio::test]
+    async fn test_snapshot_reader_sees_stable_snapshot() {
+        let temp_dir = TempDir::new().unwrap();
+        let tx_manager = TransactionManager::new_async(temp_dir.path())
+            .await
+            .unwrap();
+
+        let setup = tx_manager
+            .begin_transaction(IsolationLevel::Snapshot)
+            .await
+            .unwrap();
+        tx_manager
+            .write_row(setup, "accounts", "1", b"100".to_vec())
+            .await
+            .unwrap();
+        tx_manager.commit(setup).await.unwrap();
+
+        let reader = tx_manager
+            .begin_transaction(IsolationLevel::Snapshot)
+            .await
+            .unwrap();
+        let read = tx_manager.read_row(reader, "accounts", "1").await.unwrap();
+        assert_eq!(read.as_deref(), Some(&b"100"[..]));
+
+        // A lock-based writer holds an exclusive row lock, but snapshot reads
+        // do not wait for it
+        let writer = tx_manager
+            .begin_transaction(IsolationLevel::ReadCommitted)
+            .await
+            .unwrap();
+        tx_manager
+            .write_row(writer, "accounts", "1", b"200".to_vec())
+            .await
+            .unwrap();
+        let read = tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            tx_manager.read_row(reader, "accounts", "1"),
+        )
+        .await
+        .expect("reader blocked by writer")
+        .unwrap();
+        assert_eq!(read.as_deref(), Some(&b"100"[..]));
+
+        // Committed writes and deletes after the snapshot stay invisible
+        tx_manager.commit(writer).await.unwrap();
+        let deleter = tx_manager
+            .begin_transaction(IsolationLevel::Snapshot)
+            .await
+            .unwrap();
+        tx_manager
+            .write_row(deleter, "accounts", "2", b"new".to_vec())
+            .await
+            .unwrap();
+        tx_manager
+            .delete_row(deleter, "accounts", "1")
+            .await
+            .unwrap();
+        tx_manager.commit(deleter).await.unwrap();
+
+        let read = tx_manager.read_row(reader, "accounts", "1").await.unwrap();
+        assert_eq!(read.as_deref(), Some(&b"100"[..]));
+        assert!(tx_manager
+            .read_row(reader, "accounts", "2")
+            .await
+            .unwrap()
+            .is_none());
+        tx_manager.commit(reader).await.unwrap();
+
+        // A transaction started afterwards sees the latest committed state
+        let later = tx_manager
+            .begin_transaction(IsolationLevel::Snapshot)
+            .await
+            .unwrap();
+        assert!(tx_manager
+            .read_row(later, "accounts", "1")
+            .await
+            .unwrap()
+            .is_none());
+        let read = tx_manager.read_row(later, "accounts", "2").await.unwrap();
+        assert_eq!(read.as_deref(), Some(&b"new"[..]));
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_write_conflict_first_committer_wins() {
+        let temp_dir = TempDir::new().unwrap();
+        let tx_manager = TransactionManager::new_async(temp_dir.path())
+            .await
+            .unwrap();
+
+        let tx1 = tx_manager
+            .begin_transaction(IsolationLevel::Snapshot)
+            .await
+            .unwrap();
+        let tx2 = tx_manager
+            .begin_transaction(IsolationLevel::Snapshot)
+            .await
+            .unwrap();
+
+        // Neither write blocks the other
+        tx_manager
+            .write_row(tx1, "accounts", "1", b"tx1".to_vec())
+            .await
+            .unwrap();
+        tx_manager
+            .write_row(tx2, "accounts", "1", b"tx2".to_vec())
+            .await
+            .unwrap();
+
+        // Each transaction reads its own write
+        let read = tx_manager.read_row(tx2, "accounts", "1").await.unwrap();
+        assert_eq!(read.as_deref(), Some(&b"tx2"[..]));
+
+        tx_manager.commit(tx1).await.unwrap();
+        let result = tx_manager.commit(tx2).await;
+        assert!(matches!(
+            result,
+            Err(NeuroQuantumError::TransactionAborted {
+                reason: AbortReason::WriteConflict,
+                retryable: true,
+                ..
+            })
+        ));
+        assert!(!tx_manager
+            .active_transactions
+            .read()
+            .await
+            .contains_key(&tx2));
+
+        let check = tx_manager
+            .begin_transaction(IsolationLevel::Snapshot)
+            .await
+            .unwrap();
+        let read = tx_manager.read_row(check, "accounts", "1").await.unwrap();
+        assert_eq!(read.as_deref(), Some(&b"tx1"[..]));
+
+        // Writes to different rows do not conflict
+        let tx3 = tx_manager
+            .begin_transaction(IsolationLevel::Snapshot)
+            .await
+            .unwrap();
+        let tx4 = tx_manager
+            .begin_transaction(IsolationLevel::Snapshot)
+            .await
+            .unwrap();
+        tx_manager
+            .write_row(tx3, "accounts", "1", b"tx3".to_vec())
+            .await
+            .unwrap();
+        tx_manager
+            .write_row(tx4, "accounts", "2", b"tx4".to_vec())
+            .await
+            .unwrap();
+        tx_manager.commit(tx3).await.unwrap();
+        tx_manager.commit(tx4).await.unwrap();
+    }
+
+    /// Commit accounts A and B holding 50 each
+    async fn seed_accounts(tx_manager: &TransactionManager) {
+        let setup = tx_manager
+            .begin_with_isolation(IsolationLevel::ReadCommitted)
+            .await
+            .unwrap();
+        for key in ["A", "B"] {
+            tx_manager
+                .write_row(setup, "accounts", key, b"50".to_vec())
+                .await
+                .unwrap();
+        }
+        tx_manager.commit(setup).await.unwrap();
+    }
+
+    async fn balance(tx_manager: &TransactionManager, tx_id: TransactionId, key: &str) -> u64 {
+        let data = tx_manager
+            .read_row(tx_id, "accounts", key)
+            .await
+            .unwrap()
+            .unwrap();
+        String::from_utf8(data).unwrap().parse().unwrap()
+    }
+
+    /// Move 25 from A to B in one transaction at `isolation_level`
+    async fn transfer(tx_manager: TransactionManager, isolation_level: IsolationLevel) {
+        let tx_id = tx_manager
+            .begin_with_isolation(isolation_level)
+            .await
+            .unwrap();
+        tx_manager
+            .write_row(tx_id, "accounts", "A", b"25".to_vec())
+            .await
+            .unwrap();
+        tx_manager
+            .write_row(tx_id, "accounts", "B", b"75".to_vec())
+            .await
+            .unwrap();
+        tx_manager.commit(tx_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_read_committed_report_sees_read_skew() {
+        let temp_dir = TempDir::new().unwrap();
+        let tx_manager = TransactionManager::new_async(temp_dir.path())
+            .await
+            .unwrap();
+        seed_accounts(&tx_manager).await;
+
+        let report = tx_manager
+            .begin_with_isolation(IsolationLevel::ReadCommitted)
+            .await
+            .unwrap();
+        let a = balance(&tx_manager, report, "A").await;
+
+        // The serializable transfer is not held up by the report's read
+        tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            transfer(tx_manager.clone(), IsolationLevel::Serializable),
+        )
+        .await
+        .expect("transfer blocked by a read committed reader");
+
+        // The report sees A before and B after the transfer
+        let b = balance(&tx_manager, report, "B").await;
+        assert_eq!((a, b), (50, 75));
+        assert_eq!(a + b, 125);
+        tx_manager.commit(report).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_serializable_report_prevents_read_skew() {
+        let temp_dir = TempDir::new().unwrap();
+        let tx_manager = TransactionManager::new_async(temp_dir.path())
+            .await
+            .unwrap();
+        seed_accounts(&tx_manager).await;
+
+        let report = tx_manager
+            .begin_with_isolation(IsolationLevel::Serializable)
+            .await
+            .unwrap();
+        let a = balance(&tx_manager, report, "A").await;
+
+        // The transfer waits for the report's lock on A
+        let transfer = tokio::spawn(transfer(tx_manager.clone(), IsolationLevel::ReadCommitted));
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        assert!(!transfer.is_finished());
+
+        let b = balance(&tx_manager, report, "B").await;
+        assert_eq!(a + b, 100);
+        tx_manager.commit(report).await.unwrap();
+
+        // Once the report is done the transfer goes through
+        transfer.await.unwrap();
+        let check = tx_manager
+            .begin_with_isolation(IsolationLevel::ReadCommitted)
+            .await
+            .unwrap();
+        assert_eq!(balance(&tx_manager, check, "A").await, 25);
+        assert_eq!(balance(&tx_manager, check, "B").await, 75);
+    }
+
+    #[tokio::test]
+    async fn test_read_uncommitted_sees_dirty_writes() {
+        let temp_dir = TempDir::new().unwrap();
+        let tx_manager = TransactionManager::new_async(temp_dir.path())
+            .await
+            .unwrap();
+        seed_accounts(&tx_manager).await;
+
+        let writer = tx_manager
+            .begin_with_isolation(IsolationLevel::Serializable)
+            .await
+            .unwrap();
+        tx_manager
+            .write_row(writer, "accounts", "A", b"0".to_vec())
+            .await
+            .unwrap();
+
+        let dirty = tx_manager
+            .begin_with_isolation(IsolationLevel::ReadUncommitted)
+            .await
+            .unwrap();
+        let committed = tx_manager
+            .begin_with_isolation(IsolationLevel::ReadCommitted)
+            .await
+            .unwrap();
+        assert_eq!(balance(&tx_manager, dirty, "A").await, 0);
+        assert_eq!(balance(&tx_manager, committed, "A").await, 50);
+
+        tx_manager.rollback(writer).await.unwrap();
+        assert_eq!(balance(&tx_manager, dirty, "A").await, 50);
+    }
+
+    #[tokio::test]
+    async fn test_read_lock_duration_follows_isolation_level() {
+        let temp_dir = TempDir::new().unwrap();
+        let tx_manager = TransactionManager::new_async(temp_dir.path())
+            .await
+            .unwrap();
+        let resource = "table:accounts".to_string();
+
+        let committed = tx_manager
+            .begin_with_isolation(IsolationLevel::ReadCommitted)
+            .await
+            .unwrap();
+        assert!(tx_manager
+            .acquire_read_lock(committed, resource.clone())
+            .await
+            .unwrap());
+        tx_manager
+            .release_read_lock(committed, &resource)
+            .await
+            .unwrap();
+
+        let repeatable = tx_manager
+            .begin_with_isolation(IsolationLevel::RepeatableRead)
+            .await
+            .unwrap();
+        assert!(!tx_manager
+            .acquire_read_lock(repeatable, resource.clone())
+            .await
+            .unwrap());
+
+        // The repeatable read lock is still held, so a writer has to wait
+        let writer = tx_manager
+            .begin_with_isolation(IsolationLevel::ReadCommitted)
+            .await
+            .unwrap();
+        let blocked = tokio::time::timeout(
+            std::time::Duration::from_millis(100),
+            tx_manager.acquire_lock(writer, resource.clone(), LockType::Exclusive),
+        )
+        .await;
+        assert!(blocked.is_err());
+
+        tx_manager.commit(repeatable).await.unwrap();
+        tx_manager
+            .acquire_lock(writer, resource, LockType::Exclusive)
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn test_transaction_savepoints() {
+        let mut tx = Transaction::new(IsolationLevel::ReadCommitted, 30);
+        let record = |lsn: LSN| LogRecord {
+            lsn,
+            prev_lsn: None,
+            tx_id: None,
+            record_type: LogRecordType::Commit { tx_id: Uuid::nil() },
+            timestamp: chrono::Utc::now(),
+        };
+
+        tx.undo_log.push(record(1));
+        tx.last_lsn = Some(1);
+        assert_eq!(tx.savepoint("outer"), 1);
+        tx.undo_log.push(record(2));
+        tx.last_lsn = Some(2);
+        assert_eq!(tx.savepoint("inner"), 2);
+        tx.undo_log.push(record(3));
+        tx.last_lsn = Some(3);
+
+        // Rolling back to the outer savepoint discards the inner one
+        let undone = tx.rollback_to("outer").unwrap();
+        assert_eq!(undone.iter().map(|r| r.lsn).collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(tx.undo_log.len(), 1);
+        assert!(tx.rollback_to("inner").is_err());
+
+        // The savepoint survives its own rollback until released
+        assert!(tx.rollback_to("outer").unwrap().is_empty());
+        tx.release("outer").unwrap();
+        assert!(tx.rollback_to("outer").is_err());
+        assert!(tx.release("outer").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rollback_to_savepoint_keeps_earlier_work() {
+        let temp_dir = TempDir::new().unwrap();
+        let tx_manager = TransactionManager::new_async(temp_dir.path())
+            .await
+            .unwrap();
+
+        let tx_id = tx_manager
+            .begin_transaction(IsolationLevel::Snapshot)
+            .await
+            .unwrap();
+        tx_manager
+            .write_row(tx_id, "orders", "1", b"kept".to_vec())
+            .await
+            .unwrap();
+        tx_manager
+            .log_update(tx_id, "orders".into(), "1".into(), None, b"kept".to_vec())
+            .await
+            .unwrap();
+
+        tx_manager
+            .create_savepoint(tx_id, "before_retry".to_string())
+            .await
+            .unwrap();
+        tx_manager
+            .write_row(tx_id, "orders", "1", b"overwritten".to_vec())
+            .await
+            .unwrap();
+        tx_manager
+            .write_row(tx_id, "orders", "2", b"discarded".to_vec())
+            .await
+            .unwrap();
+        tx_manager
+            .log_update(
+                tx_id,
+                "orders".into(),
+                "2".into(),
+                None,
+                b"discarded".to_vec(),
+            )
+            .await
+            .unwrap();
+
+        let undone = tx_manager
+            .rollback_to_named_savepoint(tx_id, "before_retry")
+            .await
+            .unwrap();
+        assert_eq!(undone.len(), 1);
+        assert_eq!(tx_manager.get_undo_log(tx_id).await.unwrap().len(), 1);
+
+        tx_manager
+            .release_savepoint(tx_id, "before_retry".to_string())
+            .await
+            .unwrap();
+        assert!(tx_manager
+            .rollback_to_named_savepoint(tx_id, "before_retry")
+            .await
+            .is_err());
+        tx_manager.commit(tx_id).await.unwrap();
+
+        // Only the work done before the savepoint was committed
+        let check = tx_manager
+            .begin_transaction(IsolationLevel::Snapshot)
+            .await
+            .unwrap();
+        let read = tx_manager.read_row(check, "orders", "1").await.unwrap();
+        assert_eq!(read.as_deref(), Some(&b"kept"[..]));
+        assert!(tx_manager
+            .read_row(check, "orders", "2")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_deadlock_victim_is_aborted_as_retryable() {
+        let temp_dir = TempDir::new().unwrap();
+        let tx_manager = TransactionManager::new_async(temp_dir.path())
+            .await
+            .unwrap();
+
+        let tx1 = tx_manager
+            .begin_transaction(IsolationLevel::ReadCommitted)
+            .await
+            .unwrap();
+        let tx2 = tx_manager
+            .begin_transaction(IsolationLevel::ReadCommitted)
+            .await
+            .unwrap();
+        tx_manager
+            .write_row(tx1, "accounts", "1", b"tx1".to_vec())
+            .await
+            .unwrap();
+        tx_manager
+            .write_row(tx2, "accounts", "2", b"tx2".to_vec())
+            .await
+            .unwrap();
+
+        // tx1 waits for the row tx2 holds...
+        let waiting = tokio::spawn({
+            let tx_manager = tx_manager.clone();
+            async move {
+                tx_manager
+                    .write_row(tx1, "accounts", "2", b"tx1".to_vec())
+                    .await
+            }
+        });
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+        // ...so tx2 closes the cycle and becomes the victim
+        let error = tx_manager
+            .write_row(tx2, "accounts", "1", b"tx2".to_vec())
+            .await
+            .unwrap_err();
+        assert!(error.is_retryable());
+        assert!(matches!(
+            error,
+            NeuroQuantumError::TransactionAborted {
+                reason: AbortReason::Deadlock,
+                ..
+            }
+        ));
+
+        // The victim cannot commit, and aborting it lets tx1 through
+        assert!(matches!(
+            tx_manager.commit(tx2).await,
+            Err(NeuroQuantumError::TransactionAborted {
+                reason: AbortReason::Deadlock,
+                retryable: true,
+                ..
+            })
+        ));
+        waiting.await.unwrap().unwrap();
+        tx_manager.commit(tx1).await.unwrap();
+
+        let aborts = tx_manager.get_statistics().await.aborts;
+        assert_eq!(aborts.deadlock, 1);
+        assert_eq!(aborts.total(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_abort_reasons_are_counted_and_exported() {
+        let temp_dir = TempDir::new().unwrap();
+        let tx_manager = TransactionManager::new_async(temp_dir.path())
+            .await
+            .unwrap();
+
+        // Write-write conflict between snapshot transactions
+        let tx1 = tx_manager
+            .begin_transaction(IsolationLevel::Snapshot)
+            .await
+            .unwrap();
+        let tx2 = tx_manager
+            .begin_transaction(IsolationLevel::Snapshot)
+            .await
+            .unwrap();
+        for tx_id in [tx1, tx2] {
+            tx_manager
+                .write_row(tx_id, "accounts", "1", b"balance".to_vec())
+                .await
+                .unwrap();
+        }
+        tx_manager.commit(tx1).await.unwrap();
+        let error = tx_manager.commit(tx2).await.unwrap_err();
+        assert!(error.is_retryable());
+
+        // Neither a client rollback nor a constraint violation is worth retrying
+        let tx3 = tx_manager
+            .begin_transaction(IsolationLevel::ReadCommitted)
+            .await
+            .unwrap();
+        tx_manager.rollback(tx3).await.unwrap();
+        let tx4 = tx_manager
+            .begin_transaction(IsolationLevel::ReadCommitted)
+            .await
+            .unwrap();
+        tx_manager
+            .rollback_with_reason(tx4, AbortReason::ConstraintViolation)
+            .await
+            .unwrap();
+        assert!(!NeuroQuantumError::transaction_aborted(
+            AbortReason::ConstraintViolation,
+            String::new()
+        )
+        .is_retryable());
+
+        let aborts = tx_manager.get_statistics().await.aborts;
+        assert_eq!(
+            aborts,
+            AbortStatistics {
+                write_conflict: 1,
+                user_abort: 1,
+                constraint_violation: 1,
+                ..AbortStatistics::default()
+            }
+        );
+        assert_eq!(aborts.get(AbortReason::WriteConflict), 1);
+
+        let registry = prometheus::Registry::new();
+        registry
+            .register(Box::new(tx_manager.abort_metrics()))
+            .unwrap();
+        let mut buffer = Vec::new();
+        prometheus::Encoder::encode(
+            &prometheus::TextEncoder::new(),
+            &registry.gather(),
+            &mut buffer,
+        )
+        .unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        for line in [
+            "{reason=\"write_conflict\",retryable=\"true\"} 1",
+            "{reason=\"user_abort\",retryable=\"false\"} 1",
+            "{reason=\"constraint_violation\",retryable=\"false\"} 1",
+            "{reason=\"deadlock\",retryable=\"true\"} 0",
+        ] {
+            assert!(output.contains(&format!("neuroquantum_transaction_aborts_total{line}")));
+        }
+    }
 }
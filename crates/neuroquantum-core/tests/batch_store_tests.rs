@@ -0,0 +1,78 @@
+//! Batch Storage Tests
+//!
+//! Tests for `store_batch` and `retrieve_batch`, comparing their results
+//! with the single-key `store_compressed` and `retrieve_compressed`.
+
+use neuroquantum_core::{NeuroQuantumDB, NeuroQuantumDBBuilder};
+
+async fn create_db() -> (NeuroQuantumDB, tempfile::TempDir) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db = NeuroQuantumDBBuilder::new()
+        .storage_path(temp_dir.path().to_path_buf())
+        .build()
+        .await
+        .unwrap();
+    (db, temp_dir)
+}
+
+fn batch_items(count: usize) -> Vec<(String, Vec<u8>)> {
+    (0..count)
+        .map(|i| {
+            let value = format!(
+                "{{\"sensor\":{i},\"reading\":{},\"unit\":\"celsius\"}}",
+                i * 7
+            );
+            (format!("sensor:{i:03}"), value.into_bytes())
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_batch_matches_individual_calls() {
+    let items = batch_items(100);
+    let keys: Vec<String> = items.iter().map(|(key, _)| key.clone()).collect();
+
+    let (mut batch_db, _batch_dir) = create_db().await;
+    batch_db.store_batch(&items).await.unwrap();
+
+    let (mut single_db, _single_dir) = create_db().await;
+    for (key, data) in &items {
+        single_db.store_compressed(key, data).await.unwrap();
+    }
+
+    let batch = batch_db.retrieve_batch(&keys).await.unwrap();
+    let from_single = single_db.retrieve_batch(&keys).await.unwrap();
+    assert_eq!(batch.len(), 100);
+    assert_eq!(batch, from_single);
+
+    for ((key, data), retrieved) in items.iter().zip(&batch) {
+        assert_eq!(retrieved.as_deref(), Some(data.as_slice()));
+        assert_eq!(&batch_db.retrieve_compressed(key).await.unwrap(), data);
+    }
+}
+
+#[tokio::test]
+async fn test_retrieve_batch_preserves_order_and_reports_missing() {
+    let items = batch_items(10);
+    let (mut db, _temp_dir) = create_db().await;
+    db.store_batch(&items).await.unwrap();
+
+    let keys = vec![
+        "sensor:009".to_string(),
+        "missing".to_string(),
+        "sensor:000".to_string(),
+        "sensor:005".to_string(),
+    ];
+    let values = db.retrieve_batch(&keys).await.unwrap();
+    assert_eq!(values[0].as_deref(), Some(items[9].1.as_slice()));
+    assert_eq!(values[1], None);
+    assert_eq!(values[2].as_deref(), Some(items[0].1.as_slice()));
+    assert_eq!(values[3].as_deref(), Some(items[5].1.as_slice()));
+}
+
+#[tokio::test]
+async fn test_empty_batch() {
+    let (mut db, _temp_dir) = create_db().await;
+    db.store_batch(&[]).await.unwrap();
+    assert!(db.retrieve_batch(&[]).await.unwrap().is_empty());
+}
@@ -76,6 +76,7 @@ fn create_chaos_test_schema(name: &str) -> TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "value".to_string(),
@@ -83,6 +84,7 @@ fn create_chaos_test_schema(name: &str) -> TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "checksum".to_string(),
@@ -90,6 +92,7 @@ fn create_chaos_test_schema(name: &str) -> TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -98,6 +101,7 @@ fn create_chaos_test_schema(name: &str) -> TableSchema {
         auto_increment_columns: std::collections::HashMap::new(),
         id_strategy: IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     }
 }
 
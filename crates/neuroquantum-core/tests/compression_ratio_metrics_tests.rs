@@ -0,0 +1,126 @@
+//! Compression Ratio Distribution Tests
+//!
+//! Tests for the rolling window of per-write compression ratios kept by
+//! `NeuroQuantumDB`, its percentile queries and its Prometheus export.
+
+use std::time::Duration;
+
+use neuroquantum_core::monitoring::{CompressionRatioConfig, CompressionRatioMetrics};
+use neuroquantum_core::{CompressionAlgorithm, NeuroQuantumDBBuilder};
+use prometheus::{Encoder, Registry, TextEncoder};
+
+fn compressible_data(seed: usize) -> Vec<u8> {
+    format!("sensor={seed} status=ok region=eu-west ")
+        .into_bytes()
+        .repeat(400)
+}
+
+fn random_data() -> Vec<u8> {
+    (0..16 * 1024).map(|_| rand::random::<u8>()).collect()
+}
+
+#[test]
+fn test_percentiles_follow_distribution() {
+    let metrics = CompressionRatioMetrics::default();
+    assert!(metrics.is_empty());
+    assert_eq!(metrics.p50(), None);
+
+    // 90 writes compressing 4:1, then 10 that didn't compress at all
+    for _ in 0..90 {
+        metrics.record(4096, 1024);
+    }
+    for _ in 0..10 {
+        metrics.record(1000, 1000);
+    }
+    metrics.record(0, 0);
+
+    assert_eq!(metrics.len(), 100);
+    assert_eq!(metrics.p50(), Some(4.0));
+    assert_eq!(metrics.p95(), Some(4.0));
+    assert_eq!(metrics.percentile(0.05), Some(1.0));
+    assert_eq!(metrics.percentile(0.10), Some(1.0));
+    assert_eq!(metrics.percentile(0.11), Some(4.0));
+
+    let summary = metrics.summary().unwrap();
+    assert_eq!(summary.samples, 100);
+    assert_eq!(summary.min, 1.0);
+    assert_eq!(summary.max, 4.0);
+    assert!((summary.mean - 3.7).abs() < 1e-9);
+}
+
+#[test]
+fn test_window_drops_old_samples() {
+    let metrics = CompressionRatioMetrics::new(CompressionRatioConfig {
+        max_samples: 10,
+        max_age: Duration::from_secs(3600),
+    })
+    .unwrap();
+
+    // The poorly compressing writes are pushed out by later ones
+    for _ in 0..10 {
+        metrics.record(100, 100);
+    }
+    for _ in 0..10 {
+        metrics.record(300, 100);
+    }
+    assert_eq!(metrics.len(), 10);
+    assert_eq!(metrics.percentile(0.0), Some(3.0));
+
+    let metrics = CompressionRatioMetrics::new(CompressionRatioConfig {
+        max_samples: 10,
+        max_age: Duration::from_millis(20),
+    })
+    .unwrap();
+    metrics.record(200, 100);
+    std::thread::sleep(Duration::from_millis(50));
+    assert!(metrics.is_empty());
+    assert!(metrics.summary().is_none());
+}
+
+#[tokio::test]
+async fn test_store_compressed_records_ratios() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut db = NeuroQuantumDBBuilder::new()
+        .storage_path(temp_dir.path().to_path_buf())
+        .build()
+        .await
+        .unwrap();
+
+    // Random data is stored as is, plus a small header
+    for i in 0..3 {
+        db.store_compressed(&format!("random_{i}"), &random_data())
+            .await
+            .unwrap();
+    }
+    for i in 0..17 {
+        db.store_compressed_with(
+            &format!("text_{i}"),
+            &compressible_data(i),
+            CompressionAlgorithm::Zstd,
+        )
+        .await
+        .unwrap();
+    }
+
+    let metrics = db.compression_ratio_metrics();
+    assert_eq!(metrics.len(), 20);
+    let lowest = metrics.percentile(0.15).unwrap();
+    assert!(lowest < 1.0 && lowest > 0.99);
+    assert!(metrics.p50().unwrap() > 10.0);
+    assert!(metrics.p95().unwrap() >= metrics.p50().unwrap());
+
+    let registry = Registry::new();
+    registry.register(Box::new(metrics.clone())).unwrap();
+    let mut buffer = Vec::new();
+    TextEncoder::new()
+        .encode(&registry.gather(), &mut buffer)
+        .unwrap();
+    let output = String::from_utf8(buffer).unwrap();
+    assert!(output.contains("neuroquantum_dna_store_compression_ratio_count 20"));
+    let window = "neuroquantum_dna_store_compression_ratio_window";
+    assert!(output.contains(&format!(
+        "{window}{{quantile=\"0.5\"}} {}",
+        metrics.p50().unwrap()
+    )));
+    assert!(output.contains(&format!("{window}{{quantile=\"0.99\"}}")));
+}
@@ -0,0 +1,196 @@
+//! Compression Algorithm Selection Tests
+//!
+//! Tests for per-call and entropy-based selection of the compression
+//! algorithm used by `store_compressed`.
+
+use neuroquantum_core::compression::{
+    estimate_entropy, predict_incompressible, INCOMPRESSIBLE_ENTROPY,
+};
+use neuroquantum_core::{
+    CompressionAlgorithm, NeuroQuantumDB, NeuroQuantumDBBuilder, UncompressedReason,
+};
+
+async fn create_db() -> (NeuroQuantumDB, tempfile::TempDir) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db = NeuroQuantumDBBuilder::new()
+        .storage_path(temp_dir.path().to_path_buf())
+        .build()
+        .await
+        .unwrap();
+    (db, temp_dir)
+}
+
+async fn stored_len(db: &NeuroQuantumDB, key: &str) -> usize {
+    db.storage_mut()
+        .await
+        .retrieve(key)
+        .await
+        .unwrap()
+        .unwrap()
+        .len()
+}
+
+fn compressible_data() -> Vec<u8> {
+    b"sensor=42 status=ok region=eu-west "
+        .repeat(400)
+        .into_iter()
+        .collect()
+}
+
+fn random_data() -> Vec<u8> {
+    (0..16 * 1024).map(|_| rand::random::<u8>()).collect()
+}
+
+#[test]
+fn test_auto_select_by_entropy() {
+    let random = random_data();
+    assert!(estimate_entropy(&random) >= INCOMPRESSIBLE_ENTROPY);
+    assert_eq!(
+        CompressionAlgorithm::auto_select(&random),
+        CompressionAlgorithm::None
+    );
+    assert_eq!(
+        CompressionAlgorithm::auto_select(&compressible_data()),
+        CompressionAlgorithm::Zstd
+    );
+    assert_eq!(
+        CompressionAlgorithm::auto_select(&b"ACGT".repeat(256)),
+        CompressionAlgorithm::Dna
+    );
+    assert_eq!(
+        CompressionAlgorithm::auto_select(b"tiny"),
+        CompressionAlgorithm::None
+    );
+}
+
+#[tokio::test]
+async fn test_auto_selection_avoids_dna_overhead_on_random_data() {
+    let (mut db, _temp_dir) = create_db().await;
+    let random = random_data();
+
+    let algorithm = db.store_compressed_auto("random", &random).await.unwrap();
+    assert_eq!(algorithm, CompressionAlgorithm::None);
+    db.store_compressed_with("random_dna", &random, CompressionAlgorithm::Dna)
+        .await
+        .unwrap();
+
+    assert_eq!(db.retrieve_compressed("random").await.unwrap(), random);
+    assert_eq!(db.retrieve_compressed("random_dna").await.unwrap(), random);
+    assert_eq!(
+        db.stored_compression("random_dna").await.unwrap(),
+        CompressionAlgorithm::Dna
+    );
+
+    // Only the algorithm header and checksum are added to incompressible data
+    let auto_len = stored_len(&db, "random").await;
+    assert!(auto_len <= random.len() + 16);
+    assert!(auto_len < stored_len(&db, "random_dna").await);
+    assert_eq!(
+        db.stored_uncompressed_reason("random").await.unwrap(),
+        Some(UncompressedReason::Requested)
+    );
+}
+
+#[test]
+fn test_predict_incompressible() {
+    assert_eq!(
+        predict_incompressible(&random_data()),
+        Some(UncompressedReason::HighEntropy)
+    );
+    assert_eq!(predict_incompressible(&compressible_data()), None);
+    assert_eq!(predict_incompressible(b"tiny"), None);
+
+    let mut gzip = vec![0x1f, 0x8b, 0x08, 0x00];
+    gzip.extend(compressible_data());
+    assert_eq!(
+        predict_incompressible(&gzip),
+        Some(UncompressedReason::AlreadyCompressed)
+    );
+
+    // Random data behind a compressible prefix is still detected
+    let mut mixed = b"header ".repeat(64);
+    mixed.extend(random_data());
+    assert_eq!(
+        predict_incompressible(&mixed),
+        Some(UncompressedReason::HighEntropy)
+    );
+}
+
+#[tokio::test]
+async fn test_store_compressed_skips_dna_for_random_data() {
+    let (mut db, _temp_dir) = create_db().await;
+    let random = random_data();
+    let data = compressible_data();
+
+    db.store_compressed("random", &random).await.unwrap();
+    db.store_compressed("compressible", &data).await.unwrap();
+
+    assert_eq!(
+        db.stored_compression("random").await.unwrap(),
+        CompressionAlgorithm::None
+    );
+    assert_eq!(
+        db.stored_uncompressed_reason("random").await.unwrap(),
+        Some(UncompressedReason::HighEntropy)
+    );
+    let random_len = stored_len(&db, "random").await;
+    assert!(random_len >= random.len() && random_len <= random.len() + 16);
+    assert_eq!(db.retrieve_compressed("random").await.unwrap(), random);
+    assert!(db.validate_data_integrity("random").await.unwrap());
+
+    assert_eq!(
+        db.stored_compression("compressible").await.unwrap(),
+        CompressionAlgorithm::Dna
+    );
+    assert_eq!(
+        db.stored_uncompressed_reason("compressible").await.unwrap(),
+        None
+    );
+    assert_eq!(db.retrieve_compressed("compressible").await.unwrap(), data);
+}
+
+#[tokio::test]
+async fn test_corrupted_uncompressed_value_is_rejected() {
+    let (mut db, _temp_dir) = create_db().await;
+    let random = random_data();
+    db.store_compressed("random", &random).await.unwrap();
+
+    let mut stored = db
+        .storage_mut()
+        .await
+        .retrieve("random")
+        .await
+        .unwrap()
+        .unwrap();
+    let last = stored.len() - 1;
+    stored[last] ^= 0xff;
+    db.storage_mut()
+        .await
+        .store("random", &stored)
+        .await
+        .unwrap();
+
+    assert!(db.retrieve_compressed("random").await.is_err());
+    assert!(!db.validate_data_integrity("random").await.unwrap());
+}
+
+#[tokio::test]
+async fn test_compressible_data_round_trips_with_every_algorithm() {
+    let (mut db, _temp_dir) = create_db().await;
+    let data = compressible_data();
+
+    let algorithm = db.store_compressed_auto("auto", &data).await.unwrap();
+    assert_eq!(algorithm, CompressionAlgorithm::Zstd);
+    assert!(stored_len(&db, "auto").await < data.len() / 10);
+    assert_eq!(db.retrieve_compressed("auto").await.unwrap(), data);
+
+    for algorithm in CompressionAlgorithm::ALL {
+        let key = format!("explicit_{algorithm}");
+        db.store_compressed_with(&key, &data, algorithm)
+            .await
+            .unwrap();
+        assert_eq!(db.stored_compression(&key).await.unwrap(), algorithm);
+        assert_eq!(db.retrieve_compressed(&key).await.unwrap(), data);
+        assert!(db.validate_data_integrity(&key).await.unwrap());
+    }
+}
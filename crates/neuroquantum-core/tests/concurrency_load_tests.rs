@@ -90,6 +90,7 @@ fn create_load_test_schema(name: &str) -> TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "version".to_string(),
@@ -97,6 +98,7 @@ fn create_load_test_schema(name: &str) -> TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "worker_id".to_string(),
@@ -104,6 +106,7 @@ fn create_load_test_schema(name: &str) -> TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "payload".to_string(),
@@ -111,6 +114,7 @@ fn create_load_test_schema(name: &str) -> TableSchema {
                 nullable: true,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -119,6 +123,7 @@ fn create_load_test_schema(name: &str) -> TableSchema {
         auto_increment_columns: std::collections::HashMap::new(),
         id_strategy: IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     }
 }
 
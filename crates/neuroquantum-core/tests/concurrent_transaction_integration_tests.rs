@@ -28,6 +28,7 @@ fn create_test_table_schema(name: &str) -> TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -35,6 +36,7 @@ fn create_test_table_schema(name: &str) -> TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "balance".to_string(),
@@ -42,6 +44,7 @@ fn create_test_table_schema(name: &str) -> TableSchema {
                 nullable: true,
                 default_value: Some(Value::Integer(0)),
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -50,6 +53,7 @@ fn create_test_table_schema(name: &str) -> TableSchema {
         auto_increment_columns: std::collections::HashMap::new(),
         id_strategy: IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     }
 }
 
@@ -0,0 +1,215 @@
+//! Integration tests for `ENCRYPTED` columns
+//!
+//! Values of encrypted columns must never reach disk in plaintext, must be
+//! redacted for ordinary readers, and must round-trip for authorized readers.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use neuroquantum_core::pqcrypto::KeyManager;
+use neuroquantum_core::storage::{
+    ColumnDefinition, ComparisonOperator, Condition, DataType, FieldAccess, Row, SelectQuery,
+    StorageEngine, TableSchema, UpdateQuery, Value, WhereClause, REDACTED_PLACEHOLDER,
+};
+use neuroquantum_core::NeuroQuantumDBBuilder;
+use tempfile::TempDir;
+
+const SECRET_SSN: &str = "078-05-1120";
+
+fn patients_schema() -> TableSchema {
+    TableSchema::new(
+        "patients",
+        "id",
+        vec![
+            ColumnDefinition::new("id", DataType::Integer),
+            ColumnDefinition::new("name", DataType::Text),
+            ColumnDefinition::new("ssn", DataType::Text).encrypted(),
+        ],
+    )
+}
+
+fn patient_row(id: i64, name: &str, ssn: &str) -> Row {
+    let mut fields = HashMap::new();
+    fields.insert("id".to_string(), Value::Integer(id));
+    fields.insert("name".to_string(), Value::text(name));
+    fields.insert("ssn".to_string(), Value::text(ssn));
+    Row {
+        id: 0,
+        fields,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    }
+}
+
+fn select_all(where_clause: Option<WhereClause>) -> SelectQuery {
+    SelectQuery {
+        table: "patients".to_string(),
+        columns: vec!["*".to_string()],
+        where_clause,
+        order_by: None,
+        limit: None,
+        offset: None,
+    }
+}
+
+async fn setup() -> (StorageEngine, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let mut storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+    storage.set_field_key_manager(Arc::new(KeyManager::new()));
+    storage.create_table(patients_schema()).await.unwrap();
+    storage
+        .insert_row("patients", patient_row(1, "Ada", SECRET_SSN))
+        .await
+        .unwrap();
+    (storage, temp_dir)
+}
+
+/// Collect the bytes of every file below `dir`
+fn read_all_files(dir: &Path) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            bytes.extend(read_all_files(&path));
+        } else {
+            bytes.extend(std::fs::read(&path).unwrap());
+        }
+    }
+    bytes
+}
+
+fn contains(haystack: &[u8], needle: &str) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle.as_bytes())
+}
+
+#[tokio::test]
+async fn test_encrypted_column_is_ciphertext_on_disk() {
+    let (mut storage, temp_dir) = setup().await;
+    storage.flush_to_disk().await.unwrap();
+
+    let on_disk = read_all_files(temp_dir.path());
+    assert!(!on_disk.is_empty());
+    assert!(
+        !contains(&on_disk, SECRET_SSN),
+        "plaintext of an ENCRYPTED column was written to disk"
+    );
+}
+
+#[tokio::test]
+async fn test_unauthorized_read_is_redacted() {
+    let (storage, _temp_dir) = setup().await;
+
+    let rows = storage.select_rows(&select_all(None)).await.unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].fields["name"], Value::text("Ada"));
+    assert_eq!(rows[0].fields["ssn"], Value::text(REDACTED_PLACEHOLDER));
+}
+
+#[tokio::test]
+async fn test_authorized_read_returns_plaintext() {
+    let (storage, _temp_dir) = setup().await;
+
+    let rows = storage
+        .select_rows_with_access(&select_all(None), FieldAccess::Decrypted)
+        .await
+        .unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].fields["ssn"], Value::text(SECRET_SSN));
+
+    // Predicates on encrypted columns see the plaintext for authorized readers
+    let by_ssn = WhereClause {
+        conditions: vec![Condition {
+            field: "ssn".to_string(),
+            operator: ComparisonOperator::Equal,
+            value: Value::text(SECRET_SSN),
+        }],
+    };
+    let rows = storage
+        .select_rows_with_access(&select_all(Some(by_ssn)), FieldAccess::Decrypted)
+        .await
+        .unwrap();
+    assert_eq!(rows.len(), 1);
+}
+
+#[tokio::test]
+async fn test_update_reencrypts_value() {
+    let (mut storage, _temp_dir) = setup().await;
+
+    let mut set_values = HashMap::new();
+    set_values.insert("ssn".to_string(), Value::text("219-09-9999"));
+    let updated = storage
+        .update_rows(&UpdateQuery {
+            table: "patients".to_string(),
+            set_values,
+            where_clause: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(updated, 1);
+
+    let rows = storage
+        .select_rows_with_access(&select_all(None), FieldAccess::Decrypted)
+        .await
+        .unwrap();
+    assert_eq!(rows[0].fields["ssn"], Value::text("219-09-9999"));
+    assert_eq!(rows[0].fields["name"], Value::text("Ada"));
+}
+
+#[tokio::test]
+async fn test_encrypted_primary_key_is_rejected() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+
+    let schema = TableSchema::new(
+        "secrets",
+        "id",
+        vec![ColumnDefinition::new("id", DataType::Integer).encrypted()],
+    );
+    assert!(storage.create_table(schema).await.is_err());
+}
+
+#[tokio::test]
+async fn test_insert_without_key_manager_fails() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+    storage.create_table(patients_schema()).await.unwrap();
+
+    let result = storage
+        .insert_row("patients", patient_row(1, "Ada", SECRET_SSN))
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_encrypted_values_survive_restart() {
+    let temp_dir = TempDir::new().unwrap();
+    let open = || {
+        NeuroQuantumDBBuilder::new()
+            .storage_path(temp_dir.path().to_path_buf())
+            .build()
+    };
+
+    let db = open().await.unwrap();
+    {
+        let mut storage = db.storage_mut().await;
+        storage.create_table(patients_schema()).await.unwrap();
+        storage
+            .insert_row("patients", patient_row(1, "Ada", SECRET_SSN))
+            .await
+            .unwrap();
+    }
+    drop(db);
+
+    let db = open().await.unwrap();
+    let rows = db
+        .storage()
+        .await
+        .select_rows_with_access(&select_all(None), FieldAccess::Decrypted)
+        .await
+        .unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].fields["ssn"], Value::text(SECRET_SSN));
+}
@@ -0,0 +1,119 @@
+//! Key Expiry Tests
+//!
+//! Tests for keys stored with a time-to-live: lazy expiry on read, the
+//! background sweeper and persistence of expiry times across restarts.
+
+use std::path::Path;
+use std::time::Duration;
+
+use neuroquantum_core::{NeuroQuantumDB, NeuroQuantumDBBuilder, NeuroQuantumError};
+
+async fn open_db(path: &Path, sweep_interval: Option<Duration>) -> NeuroQuantumDB {
+    NeuroQuantumDBBuilder::new()
+        .storage_path(path.to_path_buf())
+        .key_expiry_sweep_interval(sweep_interval)
+        .build()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_expired_key_not_found_before_sweep() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut db = open_db(temp_dir.path(), None).await;
+
+    db.store_compressed_with_ttl("session", b"token-1234", Duration::from_millis(300))
+        .await
+        .unwrap();
+    db.store_compressed("permanent", b"kept").await.unwrap();
+
+    assert_eq!(
+        db.retrieve_compressed("session").await.unwrap(),
+        b"token-1234"
+    );
+    assert!(db.storage().await.key_expiry("session").is_some());
+    assert!(db.storage().await.key_expiry("permanent").is_none());
+
+    tokio::time::sleep(Duration::from_millis(400)).await;
+
+    // Without a sweeper the entry is still stored but reads as missing
+    assert!(matches!(
+        db.retrieve_compressed("session").await,
+        Err(NeuroQuantumError::NotFound(_))
+    ));
+    assert_eq!(db.storage().await.expired_keys(), vec!["session"]);
+    assert_eq!(db.retrieve_compressed("permanent").await.unwrap(), b"kept");
+
+    assert_eq!(db.sweep_expired_keys().await.unwrap(), 1);
+    assert!(db.storage().await.key_expiry("session").is_none());
+    assert_eq!(db.sweep_expired_keys().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_sweeper_evicts_expired_keys() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut db = open_db(temp_dir.path(), Some(Duration::from_millis(50))).await;
+
+    db.store_compressed_with_ttl("cache:a", b"short lived", Duration::from_millis(100))
+        .await
+        .unwrap();
+    db.store_compressed_with_ttl("cache:b", b"long lived", Duration::from_secs(3600))
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    let storage = db.storage().await;
+    assert!(storage.key_expiry("cache:a").is_none());
+    assert!(storage.expired_keys().is_empty());
+    assert!(storage.key_expiry("cache:b").is_some());
+    drop(storage);
+
+    assert!(matches!(
+        db.retrieve_compressed("cache:a").await,
+        Err(NeuroQuantumError::NotFound(_))
+    ));
+    assert_eq!(
+        db.retrieve_compressed("cache:b").await.unwrap(),
+        b"long lived"
+    );
+}
+
+#[tokio::test]
+async fn test_ttl_survives_restart() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    {
+        let mut db = open_db(temp_dir.path(), None).await;
+        db.store_compressed_with_ttl("short", b"expires soon", Duration::from_millis(200))
+            .await
+            .unwrap();
+        db.store_compressed_with_ttl("long", b"expires later", Duration::from_secs(3600))
+            .await
+            .unwrap();
+    }
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    let db = open_db(temp_dir.path(), None).await;
+    assert!(db.storage().await.key_expiry("long").is_some());
+    assert_eq!(
+        db.retrieve_compressed("long").await.unwrap(),
+        b"expires later"
+    );
+    assert!(matches!(
+        db.retrieve_compressed("short").await,
+        Err(NeuroQuantumError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_plain_store_clears_ttl() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut db = open_db(temp_dir.path(), None).await;
+
+    db.store_compressed_with_ttl("key", b"value", Duration::from_secs(60))
+        .await
+        .unwrap();
+    db.store_compressed("key", b"value").await.unwrap();
+    assert!(db.storage().await.key_expiry("key").is_none());
+}
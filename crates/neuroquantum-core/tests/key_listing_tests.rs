@@ -0,0 +1,98 @@
+//! Key Listing Tests
+//!
+//! Tests for listing and streaming the keys of the key-value API, filtered by
+//! prefix through the ordered key index.
+
+use futures::TryStreamExt;
+use neuroquantum_core::storage::StorageEngine;
+use tempfile::TempDir;
+
+async fn engine_with_keys(keys: &[&str]) -> (TempDir, StorageEngine) {
+    let temp_dir = TempDir::new().unwrap();
+    let mut engine = StorageEngine::new(temp_dir.path()).await.unwrap();
+    for key in keys {
+        engine.store(key, key.as_bytes()).await.unwrap();
+    }
+    (temp_dir, engine)
+}
+
+#[tokio::test]
+async fn test_list_keys_by_prefix() {
+    let (_temp_dir, engine) = engine_with_keys(&[
+        "user:42",
+        "order:7",
+        "user:1",
+        "users",
+        "user:",
+        "order:10",
+        "session:abc",
+    ])
+    .await;
+
+    assert_eq!(
+        engine.list_keys(Some("user:")).unwrap(),
+        vec!["user:", "user:1", "user:42"]
+    );
+    assert_eq!(
+        engine.list_keys(Some("user")).unwrap(),
+        vec!["user:", "user:1", "user:42", "users"]
+    );
+    assert_eq!(
+        engine.list_keys(Some("order:")).unwrap(),
+        vec!["order:10", "order:7"]
+    );
+    assert!(engine.list_keys(Some("missing")).unwrap().is_empty());
+    assert_eq!(engine.list_keys(None).unwrap().len(), 7);
+    assert_eq!(
+        engine.list_keys(Some("")).unwrap(),
+        engine.list_keys(None).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_list_keys_reports_each_key_once() {
+    let (_temp_dir, mut engine) = engine_with_keys(&["a:1", "a:2"]).await;
+    engine.store("a:1", b"again").await.unwrap();
+
+    assert_eq!(engine.list_keys(Some("a:")).unwrap(), vec!["a:1", "a:2"]);
+}
+
+#[tokio::test]
+async fn test_list_keys_skips_expired_and_purged_keys() {
+    let (_temp_dir, mut engine) = engine_with_keys(&["cache:b"]).await;
+    let past = chrono::Utc::now() - chrono::Duration::seconds(1);
+    engine
+        .store_with_expiry("cache:a", b"stale", past)
+        .await
+        .unwrap();
+
+    assert_eq!(engine.list_keys(Some("cache:")).unwrap(), vec!["cache:b"]);
+    engine.purge_expired_keys().await.unwrap();
+    assert_eq!(engine.list_keys(Some("cache:")).unwrap(), vec!["cache:b"]);
+}
+
+#[tokio::test]
+async fn test_key_stream_matches_list_keys() {
+    let keys: Vec<String> = (0..50).map(|i| format!("k:{i:02}")).collect();
+    let key_refs: Vec<&str> = keys.iter().map(String::as_str).collect();
+    let (_temp_dir, engine) = engine_with_keys(&key_refs).await;
+
+    let streamed: Vec<String> = engine.key_stream(Some("k:1")).try_collect().await.unwrap();
+    assert_eq!(streamed, engine.list_keys(Some("k:1")).unwrap());
+    assert_eq!(streamed.len(), 10);
+}
+
+#[tokio::test]
+async fn test_key_index_survives_restart() {
+    let temp_dir = TempDir::new().unwrap();
+    {
+        let mut engine = StorageEngine::new(temp_dir.path()).await.unwrap();
+        engine.store("b", b"2").await.unwrap();
+        engine.store("a", b"1").await.unwrap();
+        engine.flush_to_disk().await.unwrap();
+    }
+
+    let engine = StorageEngine::new(temp_dir.path()).await.unwrap();
+    assert_eq!(engine.list_keys(None).unwrap(), vec!["a", "b"]);
+    assert_eq!(engine.retrieve("a").await.unwrap(), Some(b"1".to_vec()));
+}
@@ -0,0 +1,67 @@
+//! Keyset Pagination Tests
+//!
+//! Tests for reading tables page by page in primary key order with
+//! `select_page`, which scans the primary key order from the last-seen key
+//! and loads only the rows on the page.
+
+use neuroquantum_core::storage::test_helpers::{create_test_row, create_test_schema};
+use neuroquantum_core::storage::{StorageEngine, Value};
+use tempfile::TempDir;
+
+fn ids(rows: &[neuroquantum_core::storage::Row]) -> Vec<Value> {
+    rows.iter().map(|row| row.fields["id"].clone()).collect()
+}
+
+#[tokio::test]
+async fn test_pages_follow_primary_key_order() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut engine = StorageEngine::new(temp_dir.path()).await.unwrap();
+    engine
+        .create_table(create_test_schema("users"))
+        .await
+        .unwrap();
+    for id in [3, 1, 5, 2, 4] {
+        let row = create_test_row(id, &format!("User {id}"));
+        engine.insert_row("users", row).await.unwrap();
+    }
+
+    let (rows, next_key, stats) = engine.select_page("users", None, 2).await.unwrap();
+    assert_eq!(ids(&rows), vec![Value::Integer(1), Value::Integer(2)]);
+    assert_eq!(next_key, Some(Value::Integer(2)));
+    assert!(stats.index_scan);
+    // The page plus the row telling whether another page follows
+    assert_eq!(stats.rows_examined, 3);
+
+    let (rows, next_key, _) = engine
+        .select_page("users", next_key.as_ref(), 2)
+        .await
+        .unwrap();
+    assert_eq!(ids(&rows), vec![Value::Integer(3), Value::Integer(4)]);
+
+    let (rows, next_key, _) = engine
+        .select_page("users", next_key.as_ref(), 2)
+        .await
+        .unwrap();
+    assert_eq!(ids(&rows), vec![Value::Integer(5)]);
+    assert_eq!(next_key, None);
+}
+
+#[tokio::test]
+async fn test_empty_table_is_paged_after_restart() {
+    let temp_dir = TempDir::new().unwrap();
+    {
+        let mut engine = StorageEngine::new(temp_dir.path()).await.unwrap();
+        engine
+            .create_table(create_test_schema("empty"))
+            .await
+            .unwrap();
+        engine.flush_to_disk().await.unwrap();
+    }
+
+    // A table reloaded without rows is still read through its key order
+    let engine = StorageEngine::new(temp_dir.path()).await.unwrap();
+    let (rows, next_key, stats) = engine.select_page("empty", None, 10).await.unwrap();
+    assert!(rows.is_empty());
+    assert_eq!(next_key, None);
+    assert!(stats.index_scan);
+}
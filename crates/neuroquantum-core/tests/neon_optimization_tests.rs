@@ -5,6 +5,21 @@
 
 use neuroquantum_core::neon_optimization::{NeonOptimizer, QuantumOperation};
 
+/// Amplitudes of a 3-qubit state with real and imaginary parts
+fn sample_state() -> (Vec<f32>, Vec<f32>) {
+    (
+        vec![0.1, 0.4, -0.2, 0.3, 0.25, -0.15, 0.05, 0.35],
+        vec![0.0, 0.1, 0.2, -0.1, 0.05, 0.0, -0.3, 0.15],
+    )
+}
+
+fn assert_close(actual: &[f32], expected: &[f32]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-5, "{actual:?} != {expected:?}");
+    }
+}
+
 #[test]
 fn test_neon_optimizer_creation() {
     let _optimizer = NeonOptimizer::new().unwrap();
@@ -194,6 +209,115 @@ fn test_quantum_hadamard() {
     assert!((real_parts[1] - expected).abs() < 1e-4);
 }
 
+#[test]
+fn test_quantum_diffusion() {
+    let mut optimizer = NeonOptimizer::new().unwrap();
+
+    // Mean is 0.5, so every amplitude is reflected about 0.5
+    let mut real_parts = vec![1.0, 0.0, 0.5, 0.5];
+    let mut imag_parts = vec![0.0, 0.0, 0.0, 0.0];
+    optimizer
+        .quantum_state_operation(
+            &mut real_parts,
+            &mut imag_parts,
+            QuantumOperation::Diffusion,
+        )
+        .unwrap();
+
+    assert_close(&real_parts, &[0.0, 1.0, 0.5, 0.5]);
+    assert_close(&imag_parts, &[0.0; 4]);
+}
+
+#[test]
+fn test_fused_phase_flip_diffusion_matches_sequential() {
+    let mut optimizer = NeonOptimizer::new().unwrap();
+    let ops = [QuantumOperation::PhaseFlip, QuantumOperation::Diffusion];
+
+    let (mut seq_real, mut seq_imag) = sample_state();
+    for op in ops {
+        optimizer
+            .quantum_state_operation(&mut seq_real, &mut seq_imag, op)
+            .unwrap();
+    }
+
+    let fused = optimizer.fuse(&ops);
+    assert_eq!(fused.passes().len(), 1);
+    assert_eq!(fused.fusion_count(), 1);
+
+    let before = optimizer.get_stats().fused_operations;
+    let (mut real_parts, mut imag_parts) = sample_state();
+    optimizer
+        .apply_fused(&mut real_parts, &mut imag_parts, &fused)
+        .unwrap();
+
+    assert_close(&real_parts, &seq_real);
+    assert_close(&imag_parts, &seq_imag);
+    assert_eq!(optimizer.get_stats().fused_operations, before + 1);
+}
+
+#[test]
+fn test_fusion_stops_at_hadamard() {
+    let mut optimizer = NeonOptimizer::new().unwrap();
+    let ops = [
+        QuantumOperation::Diffusion,
+        QuantumOperation::PhaseFlip,
+        QuantumOperation::Normalize,
+        QuantumOperation::Hadamard,
+        QuantumOperation::PhaseFlip,
+        QuantumOperation::Diffusion,
+    ];
+
+    let fused = optimizer.fuse(&ops);
+    assert_eq!(
+        fused.passes(),
+        &[
+            vec![
+                QuantumOperation::Diffusion,
+                QuantumOperation::PhaseFlip,
+                QuantumOperation::Normalize,
+            ],
+            vec![QuantumOperation::Hadamard],
+            vec![QuantumOperation::PhaseFlip, QuantumOperation::Diffusion],
+        ]
+    );
+    assert_eq!(fused.num_operations(), 6);
+    assert_eq!(fused.fusion_count(), 3);
+
+    // Scale the state up so the fused normalization has work to do
+    let (mut seq_real, mut seq_imag) = sample_state();
+    seq_real.iter_mut().for_each(|r| *r *= 3.0);
+    let (mut real_parts, mut imag_parts) = (seq_real.clone(), seq_imag.clone());
+    for op in ops {
+        optimizer
+            .quantum_state_operation(&mut seq_real, &mut seq_imag, op)
+            .unwrap();
+    }
+    optimizer
+        .apply_fused(&mut real_parts, &mut imag_parts, &fused)
+        .unwrap();
+
+    assert_close(&real_parts, &seq_real);
+    assert_close(&imag_parts, &seq_imag);
+    assert_eq!(optimizer.get_stats().fused_operations, 3);
+}
+
+#[test]
+fn test_fusion_can_be_disabled() {
+    let mut optimizer = NeonOptimizer::new().unwrap();
+    assert!(optimizer.is_fusion_enabled());
+    optimizer.set_fusion_enabled(false);
+
+    let fused = optimizer.fuse(&[QuantumOperation::PhaseFlip, QuantumOperation::Diffusion]);
+    assert_eq!(fused.passes().len(), 2);
+    assert_eq!(fused.fusion_count(), 0);
+
+    let (mut real_parts, mut imag_parts) = sample_state();
+    optimizer
+        .apply_fused(&mut real_parts, &mut imag_parts, &fused)
+        .unwrap();
+    assert_eq!(optimizer.get_stats().fused_operations, 0);
+}
+
 #[test]
 fn test_parallel_search() {
     let mut optimizer = NeonOptimizer::new().unwrap();
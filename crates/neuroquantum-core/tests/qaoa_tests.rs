@@ -0,0 +1,166 @@
+//! # QAOA Solver Tests
+//!
+//! Exercises `QaoaSolver` on small max-cut instances with known optimal cuts,
+//! plus backend dispatch and fallback.
+
+use std::sync::Arc;
+
+use nalgebra::DMatrix;
+use neuroquantum_core::error::{CoreError, CoreResult};
+use neuroquantum_core::quantum::{
+    IsingModel, QUBOProblem, QaoaConfig, QaoaSolver, QuantumHardwareBackend,
+};
+
+/// Max-cut QUBO whose objective `x^T Q x` equals minus the cut weight
+fn max_cut_qubo(edges: &[(usize, usize, f64)], num_nodes: usize) -> QUBOProblem {
+    let mut q_matrix = DMatrix::zeros(num_nodes, num_nodes);
+    for &(i, j, w) in edges {
+        q_matrix[(i, i)] -= w;
+        q_matrix[(j, j)] -= w;
+        q_matrix[(i, j)] += w;
+        q_matrix[(j, i)] += w;
+    }
+    QUBOProblem {
+        q_matrix,
+        num_vars: num_nodes,
+        name: "max-cut".to_string(),
+    }
+}
+
+fn cut_value(edges: &[(usize, usize, f64)], bits: &[u8]) -> f64 {
+    edges
+        .iter()
+        .filter(|(i, j, _)| bits[*i] != bits[*j])
+        .map(|(_, _, w)| w)
+        .sum()
+}
+
+#[test]
+fn test_qaoa_finds_optimal_cut_of_square() {
+    let edges = [(0, 1, 1.0), (1, 2, 1.0), (2, 3, 1.0), (3, 0, 1.0)];
+    let problem = max_cut_qubo(&edges, 4);
+
+    let result = QaoaSolver::new().solve(&problem, 2).unwrap();
+
+    // Alternating partition cuts every edge
+    assert!(
+        result.bitstring == vec![1, 0, 1, 0] || result.bitstring == vec![0, 1, 0, 1],
+        "unexpected partition {:?}",
+        result.bitstring
+    );
+    assert!((cut_value(&edges, &result.bitstring) - 4.0).abs() < 1e-9);
+    assert!((result.objective + 4.0).abs() < 1e-9);
+    assert_eq!(result.gammas.len(), 2);
+    assert_eq!(result.betas.len(), 2);
+    assert_eq!(result.backend, "state-vector simulator");
+
+    // Optimization only accepts improvements
+    assert!(result.history.len() > 1);
+    assert!(result.history.windows(2).all(|w| w[1] <= w[0] + 1e-12));
+    assert!((result.expectation - result.history[result.history.len() - 1]).abs() < 1e-12);
+}
+
+#[test]
+fn test_qaoa_finds_optimal_weighted_cut() {
+    // Optimal cut separates {0, 3} from {1, 2, 4} with weight 13
+    let edges = [
+        (0, 1, 3.0),
+        (0, 2, 2.0),
+        (1, 3, 4.0),
+        (2, 3, 1.0),
+        (3, 4, 3.0),
+        (1, 2, 1.0),
+    ];
+    let problem = max_cut_qubo(&edges, 5);
+
+    let best = (0..1u32 << 5)
+        .map(|z| (0..5).map(|q| ((z >> q) & 1) as u8).collect::<Vec<_>>())
+        .map(|bits| cut_value(&edges, &bits))
+        .fold(f64::MIN, f64::max);
+    assert!((best - 13.0).abs() < 1e-9);
+
+    let result = QaoaSolver::with_config(QaoaConfig {
+        seed: 7,
+        ..QaoaConfig::default()
+    })
+    .solve(&problem, 3)
+    .unwrap();
+
+    assert!((cut_value(&edges, &result.bitstring) - best).abs() < 1e-9);
+    assert!((result.objective + best).abs() < 1e-9);
+}
+
+#[test]
+fn test_qaoa_rejects_zero_layers() {
+    let problem = max_cut_qubo(&[(0, 1, 1.0)], 2);
+    assert!(QaoaSolver::new().solve(&problem, 0).is_err());
+}
+
+struct FixedBackend {
+    available: bool,
+    spins: Vec<i8>,
+}
+
+impl QuantumHardwareBackend for FixedBackend {
+    fn submit_ising_problem(
+        &self,
+        ising: &IsingModel,
+        num_reads: usize,
+    ) -> CoreResult<Vec<(Vec<i8>, f64, usize)>> {
+        if self.spins.is_empty() {
+            return Err(CoreError::invalid_operation("device offline"));
+        }
+        let energy = ising.evaluate(&self.spins);
+        Ok(vec![(self.spins.clone(), energy, num_reads)])
+    }
+
+    fn is_available(&self) -> bool {
+        self.available
+    }
+
+    fn name(&self) -> &str {
+        "fixed"
+    }
+}
+
+#[test]
+fn test_qaoa_dispatches_sampling_to_available_backend() {
+    let edges = [(0, 1, 1.0), (1, 2, 1.0), (2, 3, 1.0), (3, 0, 1.0)];
+    let problem = max_cut_qubo(&edges, 4);
+
+    let backend = Arc::new(FixedBackend {
+        available: true,
+        spins: vec![-1, 1, -1, 1],
+    });
+    let result = QaoaSolver::new()
+        .with_backend(backend)
+        .solve(&problem, 1)
+        .unwrap();
+
+    assert_eq!(result.backend, "fixed");
+    assert_eq!(result.bitstring, vec![0, 1, 0, 1]);
+    assert!((result.objective + 4.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_qaoa_falls_back_to_simulation() {
+    let problem = max_cut_qubo(&[(0, 1, 1.0), (1, 2, 1.0)], 3);
+
+    for backend in [
+        FixedBackend {
+            available: false,
+            spins: vec![1, 1, 1],
+        },
+        FixedBackend {
+            available: true,
+            spins: Vec::new(),
+        },
+    ] {
+        let result = QaoaSolver::new()
+            .with_backend(Arc::new(backend))
+            .solve(&problem, 1)
+            .unwrap();
+        assert_eq!(result.backend, "state-vector simulator");
+        assert!((result.objective + 2.0).abs() < 1e-9);
+    }
+}
@@ -26,6 +26,7 @@ async fn test_simple_insert() {
             nullable: false,
             default_value: None,
             auto_increment: true,
+            ..Default::default()
         }],
         primary_key: "id".to_string(),
         created_at: chrono::Utc::now(),
@@ -33,6 +34,7 @@ async fn test_simple_insert() {
         auto_increment_columns: HashMap::new(),
         id_strategy: IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     println!("Creating table...");
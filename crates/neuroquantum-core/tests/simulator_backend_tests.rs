@@ -0,0 +1,143 @@
+//! # Simulator Backend Integration Tests
+//!
+//! Runs Grover's search and a small QUBO problem through the local
+//! [`SimulatorBackend`], checking the outputs, seeded reproducibility and the
+//! effect of injected readout noise.
+
+use nalgebra::DMatrix;
+use neuroquantum_core::quantum::backends::simulator::{SimulatorBackend, SimulatorConfig};
+use neuroquantum_core::quantum::backends::{QuantumBackendConfig, QuantumBackendInfo};
+use neuroquantum_core::quantum::grover_hardware_backends::GroverHardwareBackend;
+use neuroquantum_core::quantum::grover_quantum::{GroverQuantumBackend, QuantumOracle};
+use neuroquantum_core::quantum::qubo_hardware_backends::QUBOSolverBackend;
+use neuroquantum_core::quantum::qubo_quantum::QUBOProblem;
+
+/// Create a simple 3-variable QUBO problem with optimal energy -2
+fn create_simple_problem() -> QUBOProblem {
+    let mut q_matrix = DMatrix::zeros(3, 3);
+    q_matrix[(0, 0)] = -1.0;
+    q_matrix[(1, 1)] = -1.0;
+    q_matrix[(2, 2)] = -1.0;
+    q_matrix[(0, 1)] = 2.0;
+
+    QUBOProblem {
+        q_matrix,
+        num_vars: 3,
+        name: "Simple Test Problem".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_grover_search_finds_marked_state() {
+    let backend = SimulatorBackend::with_seed(42);
+    let oracle = QuantumOracle::new(3, vec![5]);
+
+    let result = backend.search(&oracle, 512).await.unwrap();
+    assert_eq!(result.found_indices[0], 5);
+    assert_eq!(result.backend_used, GroverQuantumBackend::Simulator);
+
+    let stats = result.measurement_stats.unwrap();
+    assert_eq!(stats.num_shots, 512);
+    assert_eq!(stats.best_state, 5);
+    assert!(stats.best_probability > 0.9);
+}
+
+#[tokio::test]
+async fn test_seeded_results_are_reproducible() {
+    let oracle = QuantumOracle::new(4, vec![3, 12]);
+    let first = SimulatorBackend::with_seed(7)
+        .search(&oracle, 256)
+        .await
+        .unwrap();
+    let second = SimulatorBackend::with_seed(7)
+        .search(&oracle, 256)
+        .await
+        .unwrap();
+    assert_eq!(
+        first.measurement_stats.unwrap().outcome_distribution,
+        second.measurement_stats.unwrap().outcome_distribution
+    );
+
+    let problem = create_simple_problem();
+    let first = SimulatorBackend::with_seed(7)
+        .solve(&problem)
+        .await
+        .unwrap();
+    let second = SimulatorBackend::with_seed(7)
+        .solve(&problem)
+        .await
+        .unwrap();
+    assert_eq!(first.variables, second.variables);
+    assert_eq!(first.energy.to_bits(), second.energy.to_bits());
+}
+
+#[tokio::test]
+async fn test_readout_noise_spreads_measurements() {
+    let oracle = QuantumOracle::new(3, vec![5]);
+    let noiseless = SimulatorBackend::with_seed(1)
+        .search(&oracle, 1000)
+        .await
+        .unwrap()
+        .measurement_stats
+        .unwrap();
+
+    let noisy = SimulatorBackend::new(SimulatorConfig {
+        seed: Some(1),
+        readout_error: 0.1,
+        ..Default::default()
+    })
+    .search(&oracle, 1000)
+    .await
+    .unwrap()
+    .measurement_stats
+    .unwrap();
+
+    // Each of the 3 bits flips with 10% probability, so ~73% stay correct
+    assert_eq!(noisy.best_state, 5);
+    assert!(noisy.best_probability < noiseless.best_probability);
+    assert!(noisy.best_probability > 0.6 && noisy.best_probability < 0.85);
+    assert!(noisy.entropy > noiseless.entropy);
+}
+
+#[tokio::test]
+async fn test_qubo_reaches_ground_state() {
+    let backend = SimulatorBackend::with_seed(42);
+    let solution = backend.solve(&create_simple_problem()).await.unwrap();
+
+    // x0 and x1 exclude each other, x2 is always set
+    assert!((solution.energy + 2.0).abs() < 1e-10);
+    assert_eq!(solution.variables[2], 1);
+    assert_eq!(solution.variables[0] + solution.variables[1], 1);
+    assert!(solution.converged);
+    assert_eq!(solution.quantum_evaluations, backend.config().num_reads);
+}
+
+#[tokio::test]
+async fn test_limits_are_enforced() {
+    let backend = SimulatorBackend::new(SimulatorConfig {
+        seed: Some(3),
+        max_qubits: 4,
+        max_variables: 2,
+        common: QuantumBackendConfig {
+            max_circuit_depth: Some(1),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    assert!(QuantumBackendInfo::is_available(&backend));
+    assert_eq!(QuantumBackendInfo::max_circuit_depth(&backend), Some(1));
+
+    let err = backend
+        .search(&QuantumOracle::new(5, vec![1]), 64)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("qubits"));
+
+    // Three qubits fit, but the Grover circuit is deeper than one layer
+    assert!(backend
+        .search(&QuantumOracle::new(3, vec![1]), 64)
+        .await
+        .is_err());
+
+    assert!(backend.solve(&create_simple_problem()).await.is_err());
+}
@@ -3,8 +3,8 @@
 //! Tests for the biologically accurate Izhikevich spiking neural network implementation.
 
 use neuroquantum_core::spiking::{
-    IzhikevichNeuron, IzhikevichNeuronType, IzhikevichParameters, STDPRule, SpikingNeuralNetwork,
-    SpikingSynapse,
+    HodgkinHuxleyNeuron, IzhikevichNeuron, IzhikevichNeuronType, IzhikevichParameters, LifNeuron,
+    NeuronModel, STDPRule, SpikingNeuralNetwork, SpikingSynapse, StdpVariant,
 };
 
 #[test]
@@ -299,3 +299,263 @@ fn test_refractory_period() {
         assert!(!neuron.is_refractory(t + 5, 2));
     }
 }
+
+/// Build and train a small recurrent network with STDP enabled
+fn trained_network() -> SpikingNeuralNetwork {
+    let network = SpikingNeuralNetwork::new();
+    network
+        .add_neuron_population(1, 3, IzhikevichNeuronType::RegularSpiking)
+        .unwrap();
+    network
+        .add_neuron(IzhikevichNeuron::new(4, IzhikevichNeuronType::FastSpiking))
+        .unwrap();
+    network.connect(1, 2, 0.6, true).unwrap();
+    network.connect(2, 3, 0.6, true).unwrap();
+    network.connect(3, 4, 0.5, true).unwrap();
+    network.connect(4, 1, 0.4, false).unwrap();
+
+    network.inject_current(1, 15.0).unwrap();
+    network.inject_current(3, 8.0).unwrap();
+    network.simulate(300).unwrap();
+    network
+}
+
+#[test]
+fn test_checkpoint_restore_reproduces_spike_trains() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("snn.ckpt");
+
+    let original = trained_network();
+    original.save(&path).unwrap();
+    let restored = SpikingNeuralNetwork::load(&path).unwrap();
+
+    let before = original.statistics().unwrap();
+    let after = restored.statistics().unwrap();
+    assert_eq!(after.neuron_count, before.neuron_count);
+    assert_eq!(after.synapse_count, before.synapse_count);
+    assert_eq!(after.total_spikes, before.total_spikes);
+    assert_eq!(after.simulation_time_ms, before.simulation_time_ms);
+    assert_eq!(
+        after.average_synaptic_weight.to_bits(),
+        before.average_synaptic_weight.to_bits()
+    );
+
+    // Drive both networks with the same stimulus
+    for network in [&original, &restored] {
+        network.inject_current(2, 12.0).unwrap();
+        network.inject_current(4, 6.0).unwrap();
+    }
+    let original_raster = original.simulate(200).unwrap();
+    let restored_raster = restored.simulate(200).unwrap();
+
+    assert!(!original_raster.is_empty(), "stimulus should evoke spikes");
+    assert_eq!(original_raster, restored_raster);
+    assert_eq!(
+        original
+            .statistics()
+            .unwrap()
+            .average_synaptic_weight
+            .to_bits(),
+        restored
+            .statistics()
+            .unwrap()
+            .average_synaptic_weight
+            .to_bits()
+    );
+}
+
+#[test]
+fn test_checkpoint_rejects_foreign_files() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("not_a_checkpoint");
+    std::fs::write(&path, b"definitely not a network").unwrap();
+
+    assert!(SpikingNeuralNetwork::load(&path).is_err());
+}
+
+/// Drive a synapse with `pairs` pre-then-post pairings (post 10 ms after pre)
+/// repeated at `frequency_hz`, returning the total weight change
+fn pairing_weight_change(variant: StdpVariant, frequency_hz: u64, pairs: u64) -> f64 {
+    let rule = STDPRule::default();
+    let mut synapse = SpikingSynapse::excitatory(1, 2, 0.5).with_stdp(variant);
+    let period = 1000 / frequency_hz;
+
+    for k in 0..pairs {
+        let pre = 1 + k * period;
+        synapse.on_pre_spike(pre, &rule);
+        synapse.on_post_spike(pre + 10, &rule);
+    }
+    synapse.weight - 0.5
+}
+
+#[test]
+fn test_pair_stdp_is_the_default_variant() {
+    let synapse = SpikingSynapse::excitatory(1, 2, 0.5);
+    assert_eq!(synapse.stdp, StdpVariant::Pair);
+    assert_eq!(StdpVariant::default(), StdpVariant::Pair);
+}
+
+#[test]
+fn test_triplet_stdp_potentiation_grows_with_frequency() {
+    let pairs = 10;
+
+    let pair_low = pairing_weight_change(StdpVariant::Pair, 1, pairs);
+    let pair_high = pairing_weight_change(StdpVariant::Pair, 50, pairs);
+    let triplet_low = pairing_weight_change(StdpVariant::Triplet, 1, pairs);
+    let triplet_high = pairing_weight_change(StdpVariant::Triplet, 50, pairs);
+
+    // Pair STDP does not gain from higher pairing frequency: the extra
+    // post-before-pre interactions at 50 Hz only add depression
+    assert!(pair_low > 0.0);
+    assert!(
+        pair_high <= pair_low,
+        "pair STDP: 50 Hz change {pair_high} should not exceed 1 Hz change {pair_low}"
+    );
+
+    // Triplet STDP potentiates much more strongly at high frequency
+    assert!(triplet_low > 0.0);
+    assert!(
+        triplet_high > 2.0 * triplet_low,
+        "triplet STDP: 50 Hz change {triplet_high} should exceed 1 Hz change {triplet_low}"
+    );
+    assert!(triplet_high > pair_high);
+}
+
+#[test]
+fn test_reward_modulated_stdp_waits_for_reward() {
+    let rule = STDPRule::default();
+    let variant = StdpVariant::RewardModulated {
+        eligibility_trace_tau: 200.0,
+    };
+    let mut synapse = SpikingSynapse::excitatory(1, 2, 0.5).with_stdp(variant);
+
+    synapse.on_pre_spike(10, &rule);
+    synapse.on_post_spike(20, &rule);
+
+    // Causal pairing builds eligibility but leaves the weight untouched
+    assert!(synapse.eligibility() > 0.0);
+    assert!((synapse.weight - 0.5).abs() < f64::EPSILON);
+
+    synapse.apply_reward(30, 2.0, &rule);
+    assert!(synapse.weight > 0.5);
+
+    // A punishment signal reverses the direction of the change
+    let mut punished = SpikingSynapse::excitatory(1, 2, 0.5).with_stdp(variant);
+    punished.on_pre_spike(10, &rule);
+    punished.on_post_spike(20, &rule);
+    punished.apply_reward(30, -2.0, &rule);
+    assert!(punished.weight < 0.5);
+}
+
+#[test]
+fn test_network_reward_only_affects_reward_modulated_synapses() {
+    let network = SpikingNeuralNetwork::new();
+    network
+        .add_neuron_population(1, 3, IzhikevichNeuronType::RegularSpiking)
+        .unwrap();
+    network
+        .connect_with_stdp(
+            1,
+            2,
+            0.5,
+            true,
+            StdpVariant::RewardModulated {
+                eligibility_trace_tau: 500.0,
+            },
+        )
+        .unwrap();
+    network.connect(1, 3, 0.5, true).unwrap();
+
+    network.inject_current(1, 15.0).unwrap();
+    network.inject_current(2, 15.0).unwrap();
+    network.inject_current(3, 15.0).unwrap();
+    network.simulate(200).unwrap();
+
+    let before = network.statistics().unwrap().average_synaptic_weight;
+    network.deliver_reward(0.0).unwrap();
+    let after_zero = network.statistics().unwrap().average_synaptic_weight;
+    assert!((before - after_zero).abs() < f64::EPSILON);
+}
+
+/// Drive a neuron with a constant current for `duration_ms` and count its spikes
+fn count_spikes(neuron: &mut impl NeuronModel, current: f64, duration_ms: u64) -> usize {
+    (0..duration_ms)
+        .filter(|_| NeuronModel::step(neuron, current, 1.0).is_some())
+        .count()
+}
+
+#[test]
+fn test_izhikevich_threshold_through_trait() {
+    let mut neuron = IzhikevichNeuron::new(1, IzhikevichNeuronType::RegularSpiking);
+    assert!(count_spikes(&mut neuron, 10.0, 300) > 0);
+
+    let mut neuron = IzhikevichNeuron::new(2, IzhikevichNeuronType::RegularSpiking);
+    assert_eq!(count_spikes(&mut neuron, 3.0, 300), 0);
+}
+
+#[test]
+fn test_lif_threshold() {
+    let mut neuron = LifNeuron::new(1);
+    let spikes = count_spikes(&mut neuron, 20.0, 300);
+    assert!(spikes > 0);
+    assert_eq!(NeuronModel::spike_count(&neuron), spikes as u64);
+
+    let mut neuron = LifNeuron::new(2);
+    assert_eq!(count_spikes(&mut neuron, 10.0, 300), 0);
+    assert!(neuron.membrane_potential() < neuron.params.v_threshold);
+}
+
+#[test]
+fn test_hodgkin_huxley_threshold() {
+    let mut neuron = HodgkinHuxleyNeuron::new(1);
+    assert!(count_spikes(&mut neuron, 10.0, 300) > 5);
+
+    let mut neuron = HodgkinHuxleyNeuron::new(2);
+    assert_eq!(count_spikes(&mut neuron, 1.0, 300), 0);
+    assert!(neuron.membrane_potential() < -55.0);
+}
+
+#[test]
+fn test_spike_times_follow_neuron_clock() {
+    let mut neuron = LifNeuron::new(7);
+    neuron.set_clock(1000);
+    let spike = (0..100)
+        .find_map(|_| NeuronModel::step(&mut neuron, 20.0, 1.0))
+        .unwrap();
+    assert_eq!(spike.neuron_id, 7);
+    assert!(spike.time > 1000);
+    assert_eq!(neuron.last_spike_time(), Some(spike.time));
+}
+
+#[test]
+fn test_network_mixes_neuron_models() {
+    let network = SpikingNeuralNetwork::new();
+    network
+        .add_neuron(IzhikevichNeuron::new(
+            1,
+            IzhikevichNeuronType::RegularSpiking,
+        ))
+        .unwrap();
+    network.add_neuron(LifNeuron::new(2)).unwrap();
+    network.add_neuron(HodgkinHuxleyNeuron::new(3)).unwrap();
+    network.connect(1, 2, 0.5, true).unwrap();
+    network.connect(2, 3, 0.5, true).unwrap();
+
+    for id in 1..=3 {
+        network.inject_current(id, 20.0).unwrap();
+    }
+    let raster = network.simulate(200).unwrap();
+
+    for id in 1..=3 {
+        assert!(
+            raster.get(&id).is_some_and(|s| !s.is_empty()),
+            "neuron {id} never fired"
+        );
+    }
+    let stats = network.statistics().unwrap();
+    assert_eq!(stats.neuron_count, 3);
+    assert_eq!(
+        stats.total_spikes,
+        raster.values().map(|s| s.len() as u64).sum::<u64>()
+    );
+}
@@ -31,6 +31,7 @@ async fn test_dna_compression_and_encryption_roundtrip() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -38,6 +39,7 @@ async fn test_dna_compression_and_encryption_roundtrip() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "email".to_string(),
@@ -45,6 +47,7 @@ async fn test_dna_compression_and_encryption_roundtrip() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -53,6 +56,7 @@ async fn test_dna_compression_and_encryption_roundtrip() {
         auto_increment_columns: std::collections::HashMap::new(),
         id_strategy: IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     storage
@@ -169,6 +173,7 @@ async fn test_compression_ratio_with_encryption() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "content".to_string(),
@@ -176,6 +181,7 @@ async fn test_compression_ratio_with_encryption() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -184,6 +190,7 @@ async fn test_compression_ratio_with_encryption() {
         auto_increment_columns: std::collections::HashMap::new(),
         id_strategy: IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     storage.create_table(schema).await.unwrap();
@@ -43,6 +43,7 @@ fn create_test_table_schema() -> TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "counter".to_string(),
@@ -50,6 +51,7 @@ fn create_test_table_schema() -> TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "data".to_string(),
@@ -57,6 +59,7 @@ fn create_test_table_schema() -> TableSchema {
                 nullable: true,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -65,6 +68,7 @@ fn create_test_table_schema() -> TableSchema {
         auto_increment_columns: std::collections::HashMap::new(),
         id_strategy: IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     }
 }
 
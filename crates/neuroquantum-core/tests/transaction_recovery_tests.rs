@@ -2,7 +2,7 @@
 
 use std::collections::HashMap;
 
-use neuroquantum_core::storage::{IdGenerationStrategy, Row, StorageEngine, Value};
+use neuroquantum_core::storage::{Durability, IdGenerationStrategy, Row, StorageEngine, Value};
 use neuroquantum_core::transaction::{IsolationLevel, LogRecordType, TransactionId};
 use tempfile::TempDir;
 
@@ -292,6 +292,63 @@ async fn test_transactional_operations_with_rollback() {
     let _ = rows.len();
 }
 
+#[tokio::test]
+async fn test_relaxed_commit_skips_fsync() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+    storage
+        .create_table(create_test_table_schema())
+        .await
+        .unwrap();
+    let insert = |id: i64| Row {
+        id: 0,
+        fields: HashMap::from([
+            ("id".to_string(), Value::Integer(id)),
+            ("name".to_string(), Value::text("Scratch")),
+            ("age".to_string(), Value::Integer(1)),
+        ]),
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    };
+    let sync_count =
+        |storage: &StorageEngine| storage.get_transaction_manager().log_manager().sync_count();
+
+    // A relaxed commit writes its commit record without syncing the log
+    let tx_id = storage.begin_transaction().await.unwrap();
+    storage
+        .insert_row_transactional(tx_id, "users", insert(1))
+        .await
+        .unwrap();
+    let syncs = sync_count(&storage);
+    let result = storage
+        .commit_transaction_with_durability(tx_id, Durability::Relaxed)
+        .await
+        .unwrap();
+    assert!(!result.synchronous);
+    assert_eq!(sync_count(&storage), syncs);
+    let log = storage
+        .get_transaction_manager()
+        .log_manager()
+        .read_log()
+        .await
+        .unwrap();
+    assert!(log.iter().any(|record| record.lsn == result.lsn
+        && matches!(record.record_type, LogRecordType::Commit { .. })));
+
+    // A durable commit forces the log to disk before returning
+    let tx_id = storage.begin_transaction().await.unwrap();
+    storage
+        .insert_row_transactional(tx_id, "users", insert(2))
+        .await
+        .unwrap();
+    let result = storage
+        .commit_transaction_with_durability(tx_id, Durability::Durable)
+        .await
+        .unwrap();
+    assert!(result.synchronous);
+    assert!(sync_count(&storage) > syncs);
+}
+
 // Helper function to create test table schema
 fn create_test_table_schema() -> neuroquantum_core::storage::TableSchema {
     neuroquantum_core::storage::TableSchema {
@@ -303,6 +360,7 @@ fn create_test_table_schema() -> neuroquantum_core::storage::TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             neuroquantum_core::storage::ColumnDefinition {
                 name: "name".to_string(),
@@ -310,6 +368,7 @@ fn create_test_table_schema() -> neuroquantum_core::storage::TableSchema {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             neuroquantum_core::storage::ColumnDefinition {
                 name: "age".to_string(),
@@ -317,6 +376,7 @@ fn create_test_table_schema() -> neuroquantum_core::storage::TableSchema {
                 nullable: true,
                 default_value: Some(neuroquantum_core::storage::Value::Integer(0)),
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -325,5 +385,6 @@ fn create_test_table_schema() -> neuroquantum_core::storage::TableSchema {
         auto_increment_columns: std::collections::HashMap::new(),
         id_strategy: IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     }
 }
@@ -33,6 +33,7 @@ async fn setup_test_data(
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -40,6 +41,7 @@ async fn setup_test_data(
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -48,6 +50,7 @@ async fn setup_test_data(
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     // Create orders table
@@ -60,6 +63,7 @@ async fn setup_test_data(
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "customer_id".to_string(),
@@ -67,6 +71,7 @@ async fn setup_test_data(
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "amount".to_string(),
@@ -74,6 +79,7 @@ async fn setup_test_data(
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -82,6 +88,7 @@ async fn setup_test_data(
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -41,6 +41,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         nullable: false,
                         default_value: None,
                         auto_increment: false,
+                        ..Default::default()
                     },
                     ColumnDefinition {
                         name: "name".to_string(),
@@ -48,6 +49,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         nullable: false,
                         default_value: None,
                         auto_increment: false,
+                        ..Default::default()
                     },
                     ColumnDefinition {
                         name: "region".to_string(),
@@ -55,6 +57,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         nullable: false,
                         default_value: None,
                         auto_increment: false,
+                        ..Default::default()
                     },
                 ],
                 primary_key: "id".to_string(),
@@ -63,6 +66,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 auto_increment_columns: HashMap::new(),
                 id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
                 foreign_keys: Vec::new(),
+                ..Default::default()
             })
             .await?;
 
@@ -77,6 +81,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         nullable: false,
                         default_value: None,
                         auto_increment: false,
+                        ..Default::default()
                     },
                     ColumnDefinition {
                         name: "customer_id".to_string(),
@@ -84,6 +89,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         nullable: false,
                         default_value: None,
                         auto_increment: false,
+                        ..Default::default()
                     },
                     ColumnDefinition {
                         name: "amount".to_string(),
@@ -91,6 +97,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         nullable: false,
                         default_value: None,
                         auto_increment: false,
+                        ..Default::default()
                     },
                 ],
                 primary_key: "id".to_string(),
@@ -99,6 +106,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 auto_increment_columns: HashMap::new(),
                 id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
                 foreign_keys: Vec::new(),
+                ..Default::default()
             })
             .await?;
     }
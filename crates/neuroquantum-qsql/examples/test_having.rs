@@ -29,6 +29,7 @@ async fn main() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "category".to_string(),
@@ -36,6 +37,7 @@ async fn main() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "amount".to_string(),
@@ -43,6 +45,7 @@ async fn main() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -51,6 +54,7 @@ async fn main() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -129,6 +129,23 @@ pub struct SelectStatement {
     /// UNION clause for compound queries (UNION / UNION ALL)
     /// Used in recursive CTEs: `anchor_query` UNION ALL `recursive_query`
     pub union_clause: Option<UnionClause>,
+    /// Optimizer hints from a `/*+ ... */` comment directly after SELECT
+    #[serde(default)]
+    pub hints: Vec<PlanHint>,
+}
+
+/// Optimizer hint overriding a cost-based planning decision
+///
+/// Syntax: `SELECT /*+ INDEX(users idx_age) HASH_JOIN */ ...`. Hints the
+/// optimizer can't apply are ignored with a warning.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlanHint {
+    /// `INDEX(table index)` - read `table` (name or alias) through `index`
+    Index { table: String, index: String },
+    /// `HASH_JOIN` - execute joins as hash joins
+    HashJoin,
+    /// `NESTED_LOOP_JOIN` - execute joins as nested loops
+    NestedLoopJoin,
 }
 
 /// UNION clause for compound queries
@@ -771,6 +788,9 @@ pub enum ColumnConstraint {
     /// `AUTO_INCREMENT` constraint (MySQL-style)
     /// Automatically generates sequential unique values for the column
     AutoIncrement,
+    /// ENCRYPTED constraint
+    /// Values are encrypted at rest and redacted for unauthorized readers
+    Encrypted,
     /// GENERATED AS IDENTITY (SQL:2003 standard)
     /// Provides ALWAYS or BY DEFAULT identity generation
     Identity {
@@ -907,7 +927,11 @@ pub struct BeginTransactionStatement {
 
 /// COMMIT statement
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct CommitStatement {}
+pub struct CommitStatement {
+    /// Optional durability, `DURABLE` or `RELAXED` (if not specified, force
+    /// the commit to disk)
+    pub durability: Option<String>,
+}
 
 /// ROLLBACK statement (without savepoint)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -0,0 +1,35 @@
+//! Cooperative query cancellation
+//!
+//! A [`CancellationToken`] is handed to the executor for the duration of a
+//! query. Cancelling it does not interrupt the executor directly; instead the
+//! executor checks the token between units of work (before execution, between
+//! join steps and for each outer row of a nested loop join) and stops with
+//! [`QSQLError::Cancelled`](crate::error::QSQLError::Cancelled).
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Shared flag requesting that a running query stop
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Create a token that has not been cancelled
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation; every clone of this token observes it
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    /// Whether cancellation has been requested
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+}
@@ -57,6 +57,9 @@ pub enum QSQLError {
 
     #[error("Prepared statement error: {message}")]
     PreparedStatementError { message: String },
+
+    #[error("Query cancelled")]
+    Cancelled,
 }
 
 // Remove Clone trait for error types that contain non-cloneable fields
@@ -106,6 +109,7 @@ impl Clone for QSQLError {
             | Self::PreparedStatementError { message } => Self::PreparedStatementError {
                 message: message.clone(),
             },
+            | Self::Cancelled => Self::Cancelled,
         }
     }
 }
@@ -7,6 +7,7 @@ use std::collections::HashMap;
 use std::fmt;
 use std::time::Duration;
 
+use neuroquantum_core::storage::IndexAccess;
 use serde::{Deserialize, Serialize};
 
 use crate::ast::{
@@ -68,6 +69,9 @@ pub struct PlanNode {
     pub plan_width: u32,
     pub actual_rows: Option<u64>,
     pub actual_time: Option<Duration>,
+    /// Table rows fetched after an index lookup, reported by EXPLAIN ANALYZE
+    #[serde(default)]
+    pub heap_fetches: Option<u64>,
     pub filter: Option<String>,
     pub index_name: Option<String>,
     pub index_cond: Option<String>,
@@ -81,6 +85,56 @@ pub struct PlanNode {
     pub quantum_advantage: Option<f32>,
 }
 
+impl PlanNode {
+    /// Create a node of the given type without estimates or children
+    #[must_use]
+    pub const fn new(node_type: NodeType) -> Self {
+        Self {
+            node_type,
+            node_id: String::new(),
+            relation_name: None,
+            alias: None,
+            startup_cost: 0.0,
+            total_cost: 0.0,
+            plan_rows: 0,
+            plan_width: 0,
+            actual_rows: None,
+            actual_time: None,
+            heap_fetches: None,
+            filter: None,
+            index_name: None,
+            index_cond: None,
+            join_type: None,
+            children: Vec::new(),
+            synaptic_pathways: Vec::new(),
+            neuromorphic_score: 0.0,
+            quantum_operations: Vec::new(),
+            quantum_advantage: None,
+        }
+    }
+
+    /// Number this node `id` and its descendants `id.1`, `id.2`, ...
+    pub fn renumber(&mut self, id: &str) {
+        for (position, child) in self.children.iter_mut().enumerate() {
+            child.renumber(&format!("{id}.{}", position + 1));
+        }
+        self.node_id = id.to_string();
+    }
+
+    /// Turn a sequential scan node into a scan of the given secondary index
+    pub fn use_index(&mut self, access: &IndexAccess) {
+        self.node_type = if access.index_only {
+            NodeType::IndexOnlyScan
+        } else {
+            NodeType::IndexScan
+        };
+        self.index_name = Some(access.index_name.clone());
+        self.index_cond = Some(access.key_columns.join(", "));
+        self.children
+            .retain(|child| child.node_type != NodeType::SeqScan);
+    }
+}
+
 /// Type of execution plan node
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum NodeType {
@@ -247,6 +301,7 @@ impl ExplainGenerator {
             plan_width: 100,
             actual_rows: None,
             actual_time: None,
+            heap_fetches: None,
             filter: select
                 .where_clause
                 .as_ref()
@@ -289,6 +344,7 @@ impl ExplainGenerator {
                 plan_width: 100,
                 actual_rows: None,
                 actual_time: None,
+                heap_fetches: None,
                 filter: Some("Filter condition".to_string()),
                 index_name: None,
                 index_cond: None,
@@ -321,6 +377,7 @@ impl ExplainGenerator {
             plan_width: 120,
             actual_rows: None,
             actual_time: None,
+            heap_fetches: None,
             filter: Some(format!(
                 "Synaptic Weight: {:.2}",
                 neuromatch.synaptic_weight
@@ -363,6 +420,7 @@ impl ExplainGenerator {
             plan_width: 80,
             actual_rows: None,
             actual_time: None,
+            heap_fetches: None,
             filter: Some("Quantum Oracle Function".to_string()),
             index_name: Some("quantum_index".to_string()),
             index_cond: None,
@@ -399,6 +457,7 @@ impl ExplainGenerator {
             plan_width: 150,
             actual_rows: None,
             actual_time: None,
+            heap_fetches: None,
             filter: None,
             index_name: None,
             index_cond: None,
@@ -414,6 +473,7 @@ impl ExplainGenerator {
                 plan_width: 75,
                 actual_rows: None,
                 actual_time: None,
+                heap_fetches: None,
                 filter: None,
                 index_name: None,
                 index_cond: None,
@@ -447,6 +507,7 @@ impl ExplainGenerator {
             plan_width: 100,
             actual_rows: None,
             actual_time: None,
+            heap_fetches: None,
             filter: None,
             index_name: None,
             index_cond: None,
@@ -620,6 +681,10 @@ impl ExplainGenerator {
 
         output.push('\n');
 
+        if let Some(ref join_type) = node.join_type {
+            output.push_str(&format!("{indent_str}  Join Type: {join_type}\n"));
+        }
+
         if let Some(ref filter) = node.filter {
             output.push_str(&format!("{indent_str}  Filter: {filter}\n"));
         }
@@ -628,6 +693,18 @@ impl ExplainGenerator {
             output.push_str(&format!("{indent_str}  Index: {index}\n"));
         }
 
+        if let Some(ref index_cond) = node.index_cond {
+            output.push_str(&format!("{indent_str}  Index Cond: {index_cond}\n"));
+        }
+
+        if let Some(actual_rows) = node.actual_rows {
+            output.push_str(&format!("{indent_str}  Actual Rows: {actual_rows}\n"));
+        }
+
+        if let Some(heap_fetches) = node.heap_fetches {
+            output.push_str(&format!("{indent_str}  Heap Fetches: {heap_fetches}\n"));
+        }
+
         if self.config.show_synaptic_pathways && !node.synaptic_pathways.is_empty() {
             output.push_str(&format!(
                 "{}  Synaptic Pathways: {}\n",
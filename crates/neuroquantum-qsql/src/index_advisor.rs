@@ -17,7 +17,7 @@
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
@@ -41,6 +41,10 @@ pub struct ColumnStats {
     pub group_by_count: u64,
     /// Most common operators used (e.g., =, >, <, LIKE)
     pub operators: HashMap<String, u64>,
+    /// Queries filtering or joining on this column that found no index on
+    /// any of their filter columns, i.e. full scans an index here would avoid
+    #[serde(default)]
+    pub avoidable_full_scans: u64,
     /// Last access timestamp (Unix ms)
     pub last_access_ms: u64,
 }
@@ -129,6 +133,8 @@ pub struct IndexRecommendation {
     pub estimated_improvement: f64,
     /// Number of queries that would benefit
     pub affected_query_count: u64,
+    /// Number of observed full scans the index would have avoided
+    pub avoidable_full_scans: u64,
     /// SQL statement to create the index
     pub create_statement: String,
     /// Reason for the recommendation
@@ -152,6 +158,9 @@ pub struct IndexAdvisorConfig {
     pub max_tracked_tables: usize,
     /// Maximum number of columns per table to track
     pub max_tracked_columns_per_table: usize,
+    /// Columns not used within this window are not recommended (`None` = no limit)
+    #[serde(default)]
+    pub observation_window: Option<Duration>,
 }
 
 impl Default for IndexAdvisorConfig {
@@ -163,6 +172,7 @@ impl Default for IndexAdvisorConfig {
             enable_tracking: true,
             max_tracked_tables: 100,
             max_tracked_columns_per_table: 50,
+            observation_window: None,
         }
     }
 }
@@ -216,12 +226,14 @@ impl IndexAdvisor {
                 // Track WHERE clause from UPDATE
                 if let Some(ref where_clause) = update.where_clause {
                     self.track_expression_columns(where_clause, &update.table_name, "WHERE");
+                    self.track_avoidable_scan(where_clause, &update.table_name);
                 }
             },
             | Statement::Delete(delete) => {
                 // Track WHERE clause from DELETE
                 if let Some(ref where_clause) = delete.where_clause {
                     self.track_expression_columns(where_clause, &delete.table_name, "WHERE");
+                    self.track_avoidable_scan(where_clause, &delete.table_name);
                 }
             },
             | _ => {}, // Other statement types don't need index recommendations
@@ -242,6 +254,7 @@ impl IndexAdvisor {
         // Track WHERE clause columns
         if let Some(ref where_clause) = select.where_clause {
             self.track_expression_columns(where_clause, &table_name, "WHERE");
+            self.track_avoidable_scan(where_clause, &table_name);
         } else {
             // No WHERE clause = potential full table scan
             self.full_scan_queries.fetch_add(1, Ordering::Relaxed);
@@ -316,6 +329,54 @@ impl IndexAdvisor {
         }
     }
 
+    /// Record a full scan the filter `expr` on `table_name` had to perform
+    ///
+    /// Counts once per query and filter column, and only when no filter column
+    /// has an index, since an index on any of them would have avoided the scan.
+    fn track_avoidable_scan(&self, expr: &Expression, table_name: &str) {
+        let mut columns = Vec::new();
+        Self::collect_columns(expr, &mut columns);
+        columns.sort_unstable();
+        columns.dedup();
+
+        let mut stats = self
+            .table_stats
+            .write()
+            .expect("table_stats RwLock poisoned");
+        let Some(table_stats) = stats.get_mut(table_name) else {
+            return;
+        };
+        if columns
+            .iter()
+            .any(|column| self.has_existing_index(table_stats, std::slice::from_ref(column)))
+        {
+            return;
+        }
+        for column in &columns {
+            if let Some(col_stats) = table_stats.columns.get_mut(column) {
+                col_stats.avoidable_full_scans += 1;
+            }
+        }
+    }
+
+    /// Collect the column identifiers of an expression
+    fn collect_columns(expr: &Expression, columns: &mut Vec<String>) {
+        match expr {
+            | Expression::Identifier(column) => columns.push(column.clone()),
+            | Expression::BinaryOp { left, right, .. } => {
+                Self::collect_columns(left, columns);
+                Self::collect_columns(right, columns);
+            },
+            | Expression::FunctionCall { args, .. } => {
+                for arg in args {
+                    Self::collect_columns(arg, columns);
+                }
+            },
+            | Expression::InList { expr, .. } => Self::collect_columns(expr, columns),
+            | _ => {},
+        }
+    }
+
     /// Track a JOIN clause
     fn track_join(&self, join: &JoinClause, primary_table: &str) {
         if let Some(ref condition) = join.condition {
@@ -434,6 +495,34 @@ impl IndexAdvisor {
         }
     }
 
+    /// Replace the known indexes of a table, e.g. with those of the storage engine
+    ///
+    /// Unlike [`register_existing_index`](Self::register_existing_index), this
+    /// also forgets indexes that have since been dropped.
+    pub fn set_existing_indexes(&self, table_name: &str, indexes: &[Vec<String>]) {
+        let mut stats = self
+            .table_stats
+            .write()
+            .expect("table_stats RwLock poisoned");
+        let table_stats = stats
+            .entry(table_name.to_string())
+            .or_insert_with(|| TableStats {
+                table_name: table_name.to_string(),
+                ..Default::default()
+            });
+        table_stats.existing_indexes = indexes.iter().map(|columns| columns.join(",")).collect();
+    }
+
+    /// Names of the tables with tracked statistics
+    pub fn tracked_tables(&self) -> Vec<String> {
+        self.table_stats
+            .read()
+            .expect("table_stats RwLock poisoned")
+            .keys()
+            .cloned()
+            .collect()
+    }
+
     /// Generate index recommendations based on collected statistics
     pub fn get_recommendations(&self) -> Vec<IndexRecommendation> {
         let stats = self
@@ -441,6 +530,12 @@ impl IndexAdvisor {
             .read()
             .expect("table_stats RwLock poisoned");
         let mut recommendations = Vec::new();
+        let window_start_ms = self.config.observation_window.map(|window| {
+            std::time::SystemTime::now()
+                .checked_sub(window)
+                .and_then(|start| start.duration_since(std::time::UNIX_EPOCH).ok())
+                .map_or(0, |d| d.as_millis() as u64)
+        });
 
         for (table_name, table_stats) in stats.iter() {
             // Skip tables with few queries
@@ -452,6 +547,9 @@ impl IndexAdvisor {
             let mut column_scores: Vec<(&String, &ColumnStats, f64)> = table_stats
                 .columns
                 .iter()
+                .filter(|(_, col_stats)| {
+                    window_start_ms.is_none_or(|start| col_stats.last_access_ms >= start)
+                })
                 .map(|(col_name, col_stats)| {
                     let score = self.calculate_column_score(col_stats, table_stats);
                     (col_name, col_stats, score)
@@ -615,6 +713,7 @@ impl IndexAdvisor {
             priority,
             estimated_improvement,
             affected_query_count,
+            avoidable_full_scans: col_stats.avoidable_full_scans,
             create_statement,
             reason,
             estimated_size_bytes,
@@ -646,6 +745,13 @@ impl IndexAdvisor {
             ));
         }
 
+        if col_stats.avoidable_full_scans > 0 {
+            reasons.push(format!(
+                "Would have avoided {} full scans",
+                col_stats.avoidable_full_scans
+            ));
+        }
+
         if table_stats.full_scan_count > 0 {
             reasons.push(format!(
                 "Table has {} full scans",
@@ -725,6 +831,13 @@ impl IndexAdvisor {
                     .unwrap_or(0)
             );
 
+            let avoidable_full_scans = composite_columns
+                .iter()
+                .filter_map(|column| table_stats.columns.get(column))
+                .map(|stats| stats.avoidable_full_scans)
+                .max()
+                .unwrap_or(0);
+
             return Some(IndexRecommendation {
                 id,
                 table_name: table_name.to_string(),
@@ -733,6 +846,7 @@ impl IndexAdvisor {
                 priority: RecommendationPriority::High,
                 estimated_improvement: 0.5,
                 affected_query_count: table_stats.query_count,
+                avoidable_full_scans,
                 create_statement,
                 reason: "Columns frequently used together in JOINs and WHERE clauses".to_string(),
                 estimated_size_bytes: table_stats.query_count * 24,
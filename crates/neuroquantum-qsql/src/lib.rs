@@ -70,16 +70,21 @@
 //! - **ARM64 optimizations**: NEON-SIMD accelerated parsing and execution
 
 pub mod ast;
+pub mod cancellation;
 pub mod error;
 pub mod executor;
 pub mod explain;
 pub mod index_advisor;
+pub mod migrations;
 pub mod natural_language;
 pub mod optimizer;
 pub mod parser;
 pub mod prepared_statements;
 pub mod query_plan;
 pub mod query_plan_cache;
+pub mod query_result_cache;
+pub mod statistics;
+pub mod table_access;
 
 // SQL Engine Integration Tests
 #[cfg(test)]
@@ -97,17 +102,20 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use anyhow::Result;
+pub use neuroquantum_core::storage::FieldAccess;
 // Import types from modules to avoid duplicates
 use optimizer::{NeuromorphicOptimizer, OptimizerConfig};
 // Re-export key types for external use (avoid conflicts)
 pub use parser::QSQLParser as Parser;
 use parser::{ParserConfig, QSQLParser as ParserQSQLParser};
 // Internal use
+pub use cancellation::CancellationToken;
 use query_plan::{ExecutionStrategy, OptimizationMetadata, QueryPlan};
-pub use query_plan::{ExecutorConfig, QueryExecutor, QueryResult};
+pub use query_plan::{ExecutorConfig, QueryCursor, QueryExecutor, QueryPage, QueryResult};
 use query_plan_cache::{CachedQueryPlan, QueryPlanCache, QueryPlanCacheConfig};
+use query_result_cache::QueryResultCache;
 use serde::{Deserialize, Serialize};
-use tracing::{debug, info, instrument, warn};
+use tracing::{debug, info, info_span, instrument, warn, Instrument};
 
 // Use the QueryPlan from query_plan module (what the executor expects)
 // pub use query_plan::QueryPlan; // Commented out to avoid duplicate definition
@@ -120,6 +128,8 @@ pub struct QSQLEngine {
     optimizer: NeuromorphicOptimizer,
     executor: QueryExecutor,
     cache: QueryPlanCache,
+    /// Results of reads, valid while the tables they read are unchanged
+    result_cache: QueryResultCache,
     metrics: QSQLMetrics,
     /// Index Advisor for automatic index recommendations
     index_advisor: index_advisor::IndexAdvisor,
@@ -135,6 +145,8 @@ pub struct QSQLMetrics {
     pub queries_executed: u64,
     pub cache_hits: u64,
     pub cache_misses: u64,
+    pub result_cache_hits: u64,
+    pub result_cache_misses: u64,
     pub neuromorphic_optimizations: u64,
     pub quantum_operations: u64,
     pub natural_language_queries: u64,
@@ -151,6 +163,7 @@ impl QSQLEngine {
             optimizer: NeuromorphicOptimizer::new()?,
             executor: QueryExecutor::new()?,
             cache: QueryPlanCache::new(),
+            result_cache: QueryResultCache::new(DEFAULT_RESULT_CACHE_SIZE),
             metrics: QSQLMetrics::default(),
             index_advisor: index_advisor::IndexAdvisor::new(),
         })
@@ -167,6 +180,7 @@ impl QSQLEngine {
             optimizer: NeuromorphicOptimizer::with_config(config.optimizer_config)?,
             executor: QueryExecutor::with_config(config.executor_config)?,
             cache: QueryPlanCache::with_config(cache_config),
+            result_cache: QueryResultCache::new(config.result_cache_size),
             metrics: QSQLMetrics::default(),
             index_advisor: index_advisor::IndexAdvisor::new(),
         })
@@ -187,6 +201,7 @@ impl QSQLEngine {
             optimizer: NeuromorphicOptimizer::new()?,
             executor,
             cache: QueryPlanCache::new(),
+            result_cache: QueryResultCache::new(DEFAULT_RESULT_CACHE_SIZE),
             metrics: QSQLMetrics::default(),
             index_advisor: index_advisor::IndexAdvisor::new(),
         })
@@ -199,6 +214,8 @@ impl QSQLEngine {
         storage_engine: Arc<tokio::sync::RwLock<neuroquantum_core::storage::StorageEngine>>,
     ) {
         self.executor.set_storage_engine(storage_engine);
+        // Versions of the previous storage engine mean nothing to the new one
+        self.result_cache.clear();
     }
 
     /// Check if the engine has a storage engine configured for production use.
@@ -207,7 +224,10 @@ impl QSQLEngine {
     }
 
     /// Execute a query with full pipeline processing
-    #[instrument(skip(self, query))]
+    ///
+    /// Parsing and execution are recorded as `qsql_parse` and `qsql_execute`
+    /// child spans carrying their duration.
+    #[instrument(name = "qsql_query", skip(self, query), fields(cache_hit = tracing::field::Empty))]
     pub async fn execute_query(&mut self, query: &str) -> Result<QueryResult, anyhow::Error> {
         let start_time = Instant::now();
 
@@ -216,18 +236,46 @@ impl QSQLEngine {
             return Err(anyhow::anyhow!("Empty query"));
         }
 
+        // Serve reads of unchanged tables from the result cache
+        let result_key = self.result_cache_key(query);
+        if let Some(key) = &result_key {
+            if let Some(mut result) = self.cached_result(key).await {
+                self.metrics.result_cache_hits += 1;
+                result.execution_time = start_time.elapsed();
+                debug!(
+                    "Query result served from cache in {:?}",
+                    start_time.elapsed()
+                );
+                return Ok(result);
+            }
+            self.metrics.result_cache_misses += 1;
+        }
+
         // Check cache first
         if let Some(cached_plan) = self.cache.get(query) {
             self.metrics.cache_hits += 1;
+            tracing::Span::current().record("cache_hit", true);
 
             // Clone the plan to avoid borrowing issues
             let plan_clone = cached_plan.plan.clone();
             let _execution_count = cached_plan.execution_count;
+            self.index_advisor.track_query(&plan_clone.statement);
+
+            // Read before executing, see `result_table_versions`
+            let table_versions = match &result_key {
+                | Some(_) => self.result_table_versions(&plan_clone.statement).await,
+                | None => None,
+            };
 
             // Use the cached plan execution method
             let exec_start = Instant::now();
-            let result = self.execute_cached_plan(&plan_clone).await?;
+            let exec_span = info_span!("qsql_execute", duration_ms = tracing::field::Empty);
+            let result = self
+                .execute_cached_plan(&plan_clone)
+                .instrument(exec_span.clone())
+                .await?;
             let exec_duration = exec_start.elapsed();
+            exec_span.record("duration_ms", exec_duration.as_secs_f64() * 1000.0);
 
             // Update cached plan statistics after execution
             if let Some(cached_plan) = self.cache.get_mut(query) {
@@ -241,18 +289,24 @@ impl QSQLEngine {
             );
             self.metrics.queries_executed += 1;
 
+            if let (Some(key), Some(versions)) = (result_key, table_versions) {
+                self.result_cache.insert(key, versions, result.clone());
+            }
+
             debug!("Query executed from cache in {:?}", start_time.elapsed());
             return Ok(result);
         }
 
         self.metrics.cache_misses += 1;
+        tracing::Span::current().record("cache_hit", false);
 
         // Parse query - convert parsing errors to anyhow errors for proper propagation
         let parse_start = Instant::now();
-        let ast = self
-            .parser
-            .parse_query(query)
+        let parse_span = info_span!("qsql_parse", duration_ms = tracing::field::Empty);
+        let ast = parse_span
+            .in_scope(|| self.parser.parse_query(query))
             .map_err(|e| anyhow::anyhow!("Parse error: {e}"))?;
+        parse_span.record("duration_ms", parse_start.elapsed().as_secs_f64() * 1000.0);
         self.metrics.average_parse_time = Self::update_average(
             self.metrics.average_parse_time,
             parse_start.elapsed(),
@@ -279,14 +333,23 @@ impl QSQLEngine {
             },
         });
 
+        // Read before executing, see `result_table_versions`
+        let table_versions = match &result_key {
+            | Some(_) => self.result_table_versions(&plan.statement).await,
+            | None => None,
+        };
+
         // Execute query
         let exec_start = Instant::now();
+        let exec_span = info_span!("qsql_execute", duration_ms = tracing::field::Empty);
         let result = self
             .executor
             .execute(&plan)
+            .instrument(exec_span.clone())
             .await
             .map_err(|e| anyhow::anyhow!("Execution error: {e}"))?;
         let exec_duration = exec_start.elapsed();
+        exec_span.record("duration_ms", exec_duration.as_secs_f64() * 1000.0);
 
         self.metrics.average_execution_time = Self::update_average(
             self.metrics.average_execution_time,
@@ -297,11 +360,108 @@ impl QSQLEngine {
 
         // Cache successful plan
         self.cache_plan(query.to_string(), plan, exec_duration);
+        if let (Some(key), Some(versions)) = (result_key, table_versions) {
+            self.result_cache.insert(key, versions, result.clone());
+        }
 
         debug!("Query executed in {:?}", start_time.elapsed());
         Ok(result)
     }
 
+    /// Execute a query, presenting values of `ENCRYPTED` columns per `access`
+    ///
+    /// [`execute_query`](Self::execute_query) always redacts encrypted values;
+    /// callers that have authorized the reader pass [`FieldAccess::Decrypted`].
+    pub async fn execute_query_with_access(
+        &mut self,
+        query: &str,
+        access: FieldAccess,
+    ) -> Result<QueryResult, anyhow::Error> {
+        self.executor.set_field_access(access);
+        let result = self.execute_query(query).await;
+        self.executor.set_field_access(FieldAccess::Redacted);
+        result
+    }
+
+    /// Execute a query that stops early once `token` is cancelled
+    ///
+    /// Cancellation is cooperative: the executor checks the token between units
+    /// of work and fails with a "Query cancelled" error. Otherwise this behaves
+    /// like [`execute_query_with_access`](Self::execute_query_with_access).
+    pub async fn execute_query_cancellable(
+        &mut self,
+        query: &str,
+        access: FieldAccess,
+        token: CancellationToken,
+    ) -> Result<QueryResult, anyhow::Error> {
+        self.executor.set_cancellation_token(Some(token));
+        let result = self.execute_query_with_access(query, access).await;
+        self.executor.set_cancellation_token(None);
+        result
+    }
+
+    /// Execute a query with positional parameters (`$1`, `$2`, ...) bound to literal values
+    ///
+    /// Parameterized queries bypass the plan cache because the bound statement
+    /// differs per invocation. Without parameters this is equivalent to
+    /// [`execute_query`](Self::execute_query).
+    #[instrument(skip(self, query, params))]
+    pub async fn execute_query_with_params(
+        &mut self,
+        query: &str,
+        params: &[Literal],
+    ) -> Result<QueryResult> {
+        if params.is_empty() {
+            return self.execute_query(query).await;
+        }
+
+        if query.trim().is_empty() {
+            return Err(anyhow::anyhow!("Empty query"));
+        }
+
+        let parse_start = Instant::now();
+        let ast = self
+            .parser
+            .parse_query(query)
+            .map_err(|e| anyhow::anyhow!("Parse error: {e}"))?;
+        self.metrics.average_parse_time = Self::update_average(
+            self.metrics.average_parse_time,
+            parse_start.elapsed(),
+            self.metrics.queries_parsed,
+        );
+        self.metrics.queries_parsed += 1;
+
+        let values: std::collections::HashMap<ParameterRef, Expression> = params
+            .iter()
+            .enumerate()
+            .map(|(idx, literal)| {
+                (
+                    ParameterRef::Positional((idx + 1) as u32),
+                    Expression::Literal(literal.clone()),
+                )
+            })
+            .collect();
+        let bound = prepared_statements::substitute_parameters(&ast, &values)
+            .map_err(|e| anyhow::anyhow!("Parameter binding error: {e}"))?;
+
+        self.index_advisor.track_query(&bound);
+
+        let exec_start = Instant::now();
+        let result = self
+            .executor
+            .execute_statement(&bound)
+            .await
+            .map_err(|e| anyhow::anyhow!("Execution error: {e}"))?;
+        self.metrics.average_execution_time = Self::update_average(
+            self.metrics.average_execution_time,
+            exec_start.elapsed(),
+            self.metrics.queries_executed,
+        );
+        self.metrics.queries_executed += 1;
+
+        Ok(result)
+    }
+
     /// Execute a natural language query
     #[instrument(skip(self, natural_query))]
     pub async fn execute_natural_query(&mut self, natural_query: &str) -> Result<QueryResult> {
@@ -315,6 +475,59 @@ impl QSQLEngine {
         self.execute_query(&qsql_query).await
     }
 
+    /// Translate a natural language query to QSQL without executing it.
+    ///
+    /// The result shows which phrases produced which clauses, a confidence
+    /// score, and alternative translations when the query is ambiguous.
+    pub fn translate_natural_query(&self, natural_query: &str) -> Result<NlTranslation> {
+        Ok(self.parser.explain_natural_language(natural_query)?)
+    }
+
+    /// Parse a SELECT to follow as a live query
+    ///
+    /// See [`QueryExecutor::live_query`] for the queries that can be followed.
+    pub fn live_query(&self, query: &str) -> Result<neuroquantum_core::storage::SelectQuery> {
+        match self.parser.parse_query(query)? {
+            | Statement::Select(select) => Ok(self.executor.live_query(&select)?),
+            | _ => Err(anyhow::anyhow!("Live queries must be SELECT statements")),
+        }
+    }
+
+    /// Open a cursor reading a SELECT page by page from one snapshot
+    ///
+    /// See [`QueryExecutor::open_cursor`] for the queries that can be paged;
+    /// for any other statement this returns `None` and the caller executes it
+    /// whole. Values of `ENCRYPTED` columns are presented per `access`. Read
+    /// pages with [`fetch_cursor_page`](Self::fetch_cursor_page) and close the
+    /// cursor with [`close_cursor`](Self::close_cursor).
+    pub async fn open_cursor(
+        &mut self,
+        query: &str,
+        access: FieldAccess,
+    ) -> Result<Option<QueryCursor>> {
+        let Statement::Select(select) = self.parser.parse_query(query)? else {
+            return Ok(None);
+        };
+        self.executor.set_field_access(access);
+        let cursor = self.executor.open_cursor(&select).await;
+        self.executor.set_field_access(FieldAccess::Redacted);
+        Ok(cursor?)
+    }
+
+    /// Read the next page of `cursor`, or `None` once every row has been read
+    pub async fn fetch_cursor_page(
+        &mut self,
+        cursor: &mut QueryCursor,
+        page_size: usize,
+    ) -> Result<Option<QueryPage>> {
+        Ok(self.executor.fetch_cursor_page(cursor, page_size).await?)
+    }
+
+    /// Close `cursor`, ending the transaction it reads in
+    pub async fn close_cursor(&mut self, cursor: QueryCursor) -> Result<()> {
+        Ok(self.executor.close_cursor(cursor).await?)
+    }
+
     /// Get current performance metrics
     pub const fn metrics(&self) -> &QSQLMetrics {
         &self.metrics
@@ -326,13 +539,17 @@ impl QSQLEngine {
     }
 
     /// Optimize synaptic pathways based on usage patterns
+    ///
+    /// Plans are strengthened as they execute; this pass weakens plans that
+    /// haven't been used within the cache's decay window and prunes those
+    /// that fall below its strength threshold.
     #[instrument(skip(self))]
     pub fn optimize_synaptic_pathways(&mut self) -> Result<()> {
-        // The new QueryPlanCache handles synaptic strengthening automatically
-        // via the record_execution method and Hebbian-inspired eviction
+        let pruned = self.cache.decay_stale_pathways();
         info!(
-            "Synaptic pathway optimization: {} cached plans, {} bytes used",
+            "Synaptic pathway optimization: {} cached plans, {} pruned, {} bytes used",
             self.cache.len(),
+            pruned,
             self.cache.current_memory_bytes()
         );
         self.metrics.neuromorphic_optimizations += 1;
@@ -358,6 +575,20 @@ impl QSQLEngine {
         self.index_advisor.get_statistics()
     }
 
+    /// Update the index advisor with the indexes in the storage engine
+    ///
+    /// Indexes created or dropped since the last refresh are taken into
+    /// account, so existing indexes are never recommended. Does nothing
+    /// without a storage engine.
+    pub async fn refresh_index_advisor_indexes(&self) {
+        let tables = self.index_advisor.tracked_tables();
+        if let Some(indexes) = self.executor.table_indexes(&tables).await {
+            for (table, columns) in indexes {
+                self.index_advisor.set_existing_indexes(&table, &columns);
+            }
+        }
+    }
+
     /// Register an existing index so it won't be recommended again
     pub fn register_existing_index(&self, table_name: &str, columns: &[String]) {
         self.index_advisor
@@ -408,6 +639,25 @@ impl QSQLEngine {
         self.cache.evict_to_target_memory(target_bytes);
     }
 
+    // =====================================================================
+    // Query Result Cache Methods
+    // =====================================================================
+
+    /// Get result cache statistics for monitoring
+    pub const fn result_cache_statistics(&self) -> &ResultCacheStatistics {
+        self.result_cache.statistics()
+    }
+
+    /// Get current number of cached query results
+    pub fn result_cache_size(&self) -> usize {
+        self.result_cache.len()
+    }
+
+    /// Clear the query result cache
+    pub fn clear_result_cache(&mut self) {
+        self.result_cache.clear();
+    }
+
     // Private helper methods
 
     async fn execute_cached_plan(&mut self, plan: &Arc<QueryPlan>) -> Result<QueryResult> {
@@ -417,6 +667,63 @@ impl QSQLEngine {
             .map_err(std::convert::Into::into)
     }
 
+    /// Result cache key of `query`, or `None` if its result must not be cached
+    ///
+    /// Results are only cached when reading from a storage engine outside an
+    /// explicit transaction, whose uncommitted writes other readers can't see.
+    fn result_cache_key(&self, query: &str) -> Option<String> {
+        let is_read = query
+            .trim_start()
+            .get(..6)
+            .is_some_and(|keyword| keyword.eq_ignore_ascii_case("SELECT"));
+        if !is_read
+            || !self.result_cache.is_enabled()
+            || !self.executor.has_storage_engine()
+            || self.executor.in_transaction()
+            || query_result_cache::calls_non_deterministic_function(query)
+        {
+            return None;
+        }
+        // Encrypted values are presented differently depending on the reader
+        let access = match self.executor.field_access() {
+            | FieldAccess::Redacted => "redacted",
+            | FieldAccess::Decrypted => "decrypted",
+        };
+        Some(format!(
+            "{access}:{}",
+            query_result_cache::normalize_query(query)
+        ))
+    }
+
+    /// Cached result for `key` if none of the tables it read changed since
+    async fn cached_result(&mut self, key: &str) -> Option<QueryResult> {
+        let tables = self.result_cache.tables(key)?;
+        let versions = self.executor.table_versions(&tables).await?;
+        self.result_cache.get(key, &versions)
+    }
+
+    /// Versions of the tables `statement` reads, if its result can be cached
+    ///
+    /// Must be read before the statement executes: a write from another
+    /// engine sharing the storage engine that lands while the statement runs
+    /// then leaves the cached result behind an older version, so it is never
+    /// served, instead of hiding it behind the version of that write.
+    async fn result_table_versions(
+        &self,
+        statement: &Statement,
+    ) -> Option<query_result_cache::TableVersions> {
+        if !matches!(statement, Statement::Select(_))
+            || table_access::names_tables_indirectly(statement)
+        {
+            return None;
+        }
+        let tables: Vec<String> = table_access::table_accesses(statement)
+            .into_iter()
+            .map(|access| access.table)
+            .collect();
+        self.executor.table_versions(&tables).await
+    }
+
     fn cache_plan(&mut self, query: String, plan: Arc<QueryPlan>, duration: Duration) {
         let cached = CachedQueryPlan::new(plan, duration);
         self.cache.insert(query, cached);
@@ -444,6 +751,7 @@ impl Default for QSQLEngine {
                     optimizer: NeuromorphicOptimizer::default(),
                     executor: QueryExecutor::default(),
                     cache: QueryPlanCache::new(),
+                    result_cache: QueryResultCache::new(DEFAULT_RESULT_CACHE_SIZE),
                     metrics: QSQLMetrics::default(),
                     index_advisor: index_advisor::IndexAdvisor::new(),
                 }
@@ -452,6 +760,9 @@ impl Default for QSQLEngine {
     }
 }
 
+/// Default number of query results kept by the result cache
+pub const DEFAULT_RESULT_CACHE_SIZE: usize = 256;
+
 /// Configuration for QSQL engine components
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QSQLConfig {
@@ -459,6 +770,8 @@ pub struct QSQLConfig {
     pub optimizer_config: OptimizerConfig,
     pub executor_config: ExecutorConfig,
     pub cache_size: usize,
+    /// Maximum number of cached query results, 0 disables result caching
+    pub result_cache_size: usize,
     pub enable_natural_language: bool,
     pub enable_quantum_optimization: bool,
     pub synaptic_learning_rate: f32,
@@ -471,6 +784,7 @@ impl Default for QSQLConfig {
             optimizer_config: OptimizerConfig::default(),
             executor_config: ExecutorConfig::default(),
             cache_size: 1000,
+            result_cache_size: DEFAULT_RESULT_CACHE_SIZE,
             enable_natural_language: true,
             enable_quantum_optimization: true,
             synaptic_learning_rate: 0.01,
@@ -489,6 +803,7 @@ impl QSQLConfig {
             optimizer_config: OptimizerConfig::default(),
             executor_config: ExecutorConfig::testing(),
             cache_size: 100,
+            result_cache_size: 16,
             enable_natural_language: true,
             enable_quantum_optimization: false,
             synaptic_learning_rate: 0.01,
@@ -503,9 +818,10 @@ pub use index_advisor::{
     ColumnStats, IndexAdvisor, IndexAdvisorConfig, IndexAdvisorStatistics, IndexRecommendation,
     IndexType, RecommendationPriority, TableStats,
 };
-pub use natural_language::NaturalLanguageProcessor;
+pub use natural_language::{NaturalLanguageProcessor, NlCandidate, NlTranslation, PhraseMapping};
 pub use prepared_statements::{
     PreparedStatement, PreparedStatementManager, PreparedStatementStats,
     PreparedStatementsStatistics,
 };
 pub use query_plan_cache::CacheStatistics;
+pub use query_result_cache::ResultCacheStatistics;
@@ -0,0 +1,274 @@
+//! Versioned schema migrations
+//!
+//! A [`Migration`] is a numbered, reversible schema change expressed as QSQL
+//! statements, typically `ALTER TABLE ... ADD/DROP/RENAME COLUMN`. The
+//! [`MigrationRunner`] applies pending migrations in version order and rolls
+//! back the latest ones, recording the applied versions in the
+//! [`MIGRATIONS_TABLE`] metadata table so progress survives restarts.
+//!
+//! Each migration runs in a transaction together with the change to
+//! [`MIGRATIONS_TABLE`]: when a statement or the bookkeeping fails, the
+//! transaction is rolled back, so row changes made by the migration are undone
+//! and it is not recorded. `ALTER TABLE` and other schema statements rewrite
+//! the table as part of the statement and take effect immediately, outside the
+//! transaction, which is why each migration should hold a single schema
+//! change.
+//!
+//! ```no_run
+//! # async fn example(engine: &mut neuroquantum_qsql::QSQLEngine) -> anyhow::Result<()> {
+//! use neuroquantum_qsql::migrations::{MigrationRunner, SqlMigration};
+//!
+//! let mut runner = MigrationRunner::new();
+//! runner.register(SqlMigration::new(
+//!     1,
+//!     "add email to users",
+//!     ["ALTER TABLE users ADD COLUMN email TEXT DEFAULT 'unknown'"],
+//!     ["ALTER TABLE users DROP COLUMN email"],
+//! ))?;
+//! runner.migrate(engine).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Context, Result};
+use tracing::{info, warn};
+
+use crate::query_plan::QueryValue;
+use crate::QSQLEngine;
+
+/// Table recording the applied migration versions
+pub const MIGRATIONS_TABLE: &str = "_schema_migrations";
+
+/// A reversible schema change
+pub trait Migration: Send + Sync {
+    /// Unique version; migrations are applied in ascending order
+    fn version(&self) -> u64;
+
+    /// Short human-readable summary, recorded with the version
+    fn description(&self) -> &str;
+
+    /// QSQL statements applying the change
+    fn up(&self) -> Vec<String>;
+
+    /// QSQL statements reverting [`up`](Self::up)
+    fn down(&self) -> Vec<String>;
+}
+
+/// A [`Migration`] given as fixed lists of statements
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqlMigration {
+    version: u64,
+    description: String,
+    up: Vec<String>,
+    down: Vec<String>,
+}
+
+impl SqlMigration {
+    pub fn new(
+        version: u64,
+        description: impl Into<String>,
+        up: impl IntoIterator<Item = impl Into<String>>,
+        down: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            version,
+            description: description.into(),
+            up: up.into_iter().map(Into::into).collect(),
+            down: down.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Migration for SqlMigration {
+    fn version(&self) -> u64 {
+        self.version
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn up(&self) -> Vec<String> {
+        self.up.clone()
+    }
+
+    fn down(&self) -> Vec<String> {
+        self.down.clone()
+    }
+}
+
+/// Applies and rolls back registered migrations
+#[derive(Default)]
+pub struct MigrationRunner {
+    migrations: BTreeMap<u64, Box<dyn Migration>>,
+}
+
+impl MigrationRunner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a migration, rejecting a version that is already registered
+    pub fn register(&mut self, migration: impl Migration + 'static) -> Result<()> {
+        let version = migration.version();
+        if self.migrations.contains_key(&version) {
+            return Err(anyhow!("Migration version {version} is registered twice"));
+        }
+        self.migrations.insert(version, Box::new(migration));
+        Ok(())
+    }
+
+    /// Versions of the registered migrations, in ascending order
+    pub fn versions(&self) -> Vec<u64> {
+        self.migrations.keys().copied().collect()
+    }
+
+    /// Versions recorded in [`MIGRATIONS_TABLE`], in ascending order
+    pub async fn applied_versions(&self, engine: &mut QSQLEngine) -> Result<Vec<u64>> {
+        Self::ensure_table(engine).await?;
+        let result = engine
+            .execute_query(&format!("SELECT version FROM {MIGRATIONS_TABLE}"))
+            .await?;
+
+        let mut versions = result
+            .rows
+            .iter()
+            .map(|row| match row.get("version") {
+                | Some(QueryValue::Integer(version)) => u64::try_from(*version).ok(),
+                | Some(QueryValue::String(version)) => version.parse().ok(),
+                | _ => None,
+            })
+            .map(|version| version.ok_or_else(|| anyhow!("Invalid row in {MIGRATIONS_TABLE}")))
+            .collect::<Result<Vec<u64>>>()?;
+        versions.sort_unstable();
+        Ok(versions)
+    }
+
+    /// Apply all pending migrations in version order
+    ///
+    /// Returns the versions applied. Stops at the first failing migration,
+    /// rolling it back and leaving the ones before it applied.
+    pub async fn migrate(&self, engine: &mut QSQLEngine) -> Result<Vec<u64>> {
+        let applied = self.applied_versions(engine).await?;
+        let mut newly_applied = Vec::new();
+
+        for (&version, migration) in &self.migrations {
+            if applied.binary_search(&version).is_ok() {
+                continue;
+            }
+            info!(
+                "⬆️  Applying migration {}: {}",
+                version,
+                migration.description()
+            );
+            let record = format!(
+                "INSERT INTO {MIGRATIONS_TABLE} (version, description, applied_at) \
+                 VALUES ({version}, '{}', '{}')",
+                escape(migration.description()),
+                chrono::Utc::now().to_rfc3339()
+            );
+            Self::run_in_transaction(engine, version, &migration.up(), &record)
+                .await
+                .with_context(|| format!("Failed to apply migration {version}"))?;
+            newly_applied.push(version);
+        }
+        Ok(newly_applied)
+    }
+
+    /// Revert the most recently applied migration
+    ///
+    /// Returns its version, or `None` when no migration is applied.
+    pub async fn rollback(&self, engine: &mut QSQLEngine) -> Result<Option<u64>> {
+        let Some(&version) = self.applied_versions(engine).await?.last() else {
+            return Ok(None);
+        };
+        let migration = self
+            .migrations
+            .get(&version)
+            .ok_or_else(|| anyhow!("Applied migration {version} is not registered"))?;
+
+        info!(
+            "⬇️  Rolling back migration {}: {}",
+            version,
+            migration.description()
+        );
+        let unrecord = format!("DELETE FROM {MIGRATIONS_TABLE} WHERE version = {version}");
+        Self::run_in_transaction(engine, version, &migration.down(), &unrecord)
+            .await
+            .with_context(|| format!("Failed to roll back migration {version}"))?;
+        Ok(Some(version))
+    }
+
+    async fn ensure_table(engine: &mut QSQLEngine) -> Result<()> {
+        engine
+            .execute_query(&format!(
+                "CREATE TABLE IF NOT EXISTS {MIGRATIONS_TABLE} (\
+                 version BIGINT PRIMARY KEY, \
+                 description TEXT NOT NULL, \
+                 applied_at TEXT NOT NULL)"
+            ))
+            .await
+            .context("Failed to create the migrations table")?;
+        Ok(())
+    }
+
+    /// Run `statements` followed by `bookkeeping` in one transaction, rolling
+    /// it back when any of them fails
+    async fn run_in_transaction(
+        engine: &mut QSQLEngine,
+        version: u64,
+        statements: &[String],
+        bookkeeping: &str,
+    ) -> Result<()> {
+        engine
+            .execute_query("BEGIN")
+            .await
+            .with_context(|| format!("Failed to begin the transaction of migration {version}"))?;
+
+        let result = async {
+            Self::run(engine, version, statements).await?;
+            engine
+                .execute_query(bookkeeping)
+                .await
+                .with_context(|| format!("Failed to update {MIGRATIONS_TABLE}"))?;
+            engine
+                .execute_query("COMMIT")
+                .await
+                .with_context(|| format!("Failed to commit migration {version}"))?;
+            Ok(())
+        }
+        .await;
+
+        if result.is_err() {
+            if let Err(e) = engine.execute_query("ROLLBACK").await {
+                warn!("❌ Failed to roll back migration {}: {:#}", version, e);
+            }
+        }
+        result
+    }
+
+    async fn run(engine: &mut QSQLEngine, version: u64, statements: &[String]) -> Result<()> {
+        for statement in statements {
+            engine
+                .execute_query(statement)
+                .await
+                .with_context(|| format!("Migration {version} failed at: {statement}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for MigrationRunner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MigrationRunner")
+            .field("versions", &self.versions())
+            .finish()
+    }
+}
+
+/// Escape `value` for a single-quoted QSQL string literal
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\'', "\\'")
+}
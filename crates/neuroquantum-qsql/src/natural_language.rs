@@ -2178,6 +2178,98 @@ pub struct ParsedQuery {
     pub qsql_translation: String,
 }
 
+/// A phrase of the natural language query and the QSQL clause it produced
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhraseMapping {
+    /// Phrase as it appears in the normalized (lowercased, trimmed) query
+    pub phrase: String,
+    /// Byte offset of the phrase in the normalized query
+    pub start_pos: usize,
+    pub end_pos: usize,
+    /// QSQL clause generated from the phrase, e.g. `WHERE age > 30`
+    pub clause: String,
+}
+
+/// One possible QSQL translation of a natural language query
+#[derive(Debug, Clone)]
+pub struct NlCandidate {
+    pub qsql: String,
+    pub intent: QueryIntent,
+    pub confidence: f32,
+    pub mappings: Vec<PhraseMapping>,
+}
+
+/// Explainable translation of a natural language query
+///
+/// Carries the chosen QSQL together with the phrases that produced each of its
+/// clauses, so the interpretation can be shown to and corrected by the user.
+#[derive(Debug, Clone)]
+pub struct NlTranslation {
+    pub natural_query: String,
+    /// Most confident translation; the query `translate_to_qsql` returns
+    pub qsql: String,
+    pub intent: QueryIntent,
+    pub confidence: f32,
+    pub mappings: Vec<PhraseMapping>,
+    /// Other plausible translations, most confident first; empty unless the
+    /// query is ambiguous
+    pub alternatives: Vec<NlCandidate>,
+}
+
+impl NlTranslation {
+    /// Whether more than one translation was plausible
+    #[must_use]
+    pub fn is_ambiguous(&self) -> bool {
+        !self.alternatives.is_empty()
+    }
+}
+
+/// Records which phrases of a query produced which QSQL clauses
+struct TranslationTrace<'a> {
+    query: &'a str,
+    mappings: Vec<PhraseMapping>,
+}
+
+impl<'a> TranslationTrace<'a> {
+    const fn new(query: &'a str) -> Self {
+        Self {
+            query,
+            mappings: Vec::new(),
+        }
+    }
+
+    fn record(&mut self, start_pos: usize, end_pos: usize, clause: impl Into<String>) {
+        if let Some(phrase) = self.query.get(start_pos..end_pos) {
+            self.mappings.push(PhraseMapping {
+                phrase: phrase.to_string(),
+                start_pos,
+                end_pos,
+                clause: clause.into(),
+            });
+        }
+    }
+
+    fn record_entity(&mut self, entity: &Entity, clause: impl Into<String>) {
+        self.record(entity.start_pos, entity.end_pos, clause);
+    }
+
+    fn into_mappings(mut self) -> Vec<PhraseMapping> {
+        self.mappings.sort_by_key(|mapping| mapping.start_pos);
+        self.mappings
+    }
+}
+
+/// Intents the processor recognizes, in tie-breaking order
+const RANKED_INTENTS: [QueryIntent; 4] = [
+    QueryIntent::Select,
+    QueryIntent::NeuroMatch,
+    QueryIntent::QuantumSearch,
+    QueryIntent::Aggregate,
+];
+
+/// Minimum pattern score for an intent to be considered
+const INTENT_THRESHOLD: f32 = 0.15;
+
 impl NaturalLanguageProcessor {
     /// Create a new natural language processor
     pub fn new() -> QSQLResult<Self> {
@@ -2243,6 +2335,86 @@ impl NaturalLanguageProcessor {
         })
     }
 
+    /// Translate natural language to QSQL, explaining how each phrase was
+    /// interpreted and listing alternative translations for ambiguous queries
+    #[instrument(skip(self))]
+    pub fn explain_translation(&self, natural_query: &str) -> QSQLResult<NlTranslation> {
+        if natural_query.trim().is_empty() {
+            return Err(NLPError::IntentRecognitionFailed {
+                text: "Empty query".to_string(),
+            }
+            .into());
+        }
+
+        let normalized = self.normalize_text(natural_query);
+        let intents = self.rank_intents(&normalized)?;
+        let entities = self.extract_entities(&normalized)?;
+        let total_score: f32 = intents.iter().map(|(_, score)| score).sum();
+
+        // Each table mentioned is a separate reading of the query
+        let mut tables: Vec<&str> = Vec::new();
+        for entity in entities
+            .iter()
+            .filter(|e| e.entity_type == EntityType::TableName)
+        {
+            if !tables.contains(&entity.value.as_str()) {
+                tables.push(&entity.value);
+            }
+        }
+        let table_readings: Vec<Vec<Entity>> = if tables.len() > 1 {
+            tables
+                .iter()
+                .map(|table| {
+                    entities
+                        .iter()
+                        .filter(|e| e.entity_type != EntityType::TableName || e.value == *table)
+                        .cloned()
+                        .collect()
+                })
+                .collect()
+        } else {
+            vec![entities.clone()]
+        };
+
+        let mut candidates: Vec<NlCandidate> = Vec::new();
+        for (intent, score) in &intents {
+            for reading in &table_readings {
+                let mut trace = TranslationTrace::new(&normalized);
+                let qsql = self.generate_traced(intent, reading, &mut trace)?;
+                if candidates.iter().any(|candidate| candidate.qsql == qsql) {
+                    continue;
+                }
+                let confidence = self.calculate_confidence(intent, reading) * score
+                    / total_score
+                    / table_readings.len() as f32;
+                candidates.push(NlCandidate {
+                    qsql,
+                    intent: intent.clone(),
+                    confidence,
+                    mappings: trace.into_mappings(),
+                });
+            }
+        }
+        // Stable sort keeps intent ranking order among equally confident candidates
+        candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+
+        let mut candidates = candidates.into_iter();
+        let best = candidates
+            .next()
+            .ok_or_else(|| NLPError::IntentRecognitionFailed {
+                text: normalized.clone(),
+            })?;
+
+        Ok(NlTranslation {
+            natural_query: natural_query.to_string(),
+            qsql: best.qsql,
+            intent: best.intent,
+            confidence: best.confidence,
+            mappings: best.mappings,
+            alternatives: candidates.collect(),
+        })
+    }
+
     /// Normalize text for processing
     fn normalize_text(&self, text: &str) -> String {
         text.to_lowercase().trim().to_string()
@@ -2250,8 +2422,14 @@ impl NaturalLanguageProcessor {
 
     /// Classify query intent using pattern matching
     fn classify_intent(&self, query: &str) -> QSQLResult<QueryIntent> {
-        let mut best_match = (QueryIntent::Select, 0.0f32);
+        let mut intents = self.rank_intents(query)?;
+        Ok(intents.swap_remove(0).0)
+    }
 
+    /// Score every intent whose patterns match the query, best first.
+    ///
+    /// Fails if no intent scores above the recognition threshold.
+    fn rank_intents(&self, query: &str) -> QSQLResult<Vec<(QueryIntent, f32)>> {
         // First check if this looks like completely invalid text
         if query.contains("not a valid database query")
             || query.contains("invalid")
@@ -2275,36 +2453,35 @@ impl NaturalLanguageProcessor {
             .into());
         }
 
-        for (intent, patterns) in &self.intent_patterns {
-            let mut intent_score = 0.0f32;
-            let mut matches_found = 0;
-
-            for pattern in patterns {
-                if pattern.is_match(query) {
-                    matches_found += 1;
-                    // Give higher score for each pattern that matches
-                    intent_score += 0.5;
-                }
-            }
+        let mut ranked = Vec::new();
+        for intent in RANKED_INTENTS {
+            let Some(patterns) = self.intent_patterns.get(&intent) else {
+                continue;
+            };
+            let matches_found = patterns
+                .iter()
+                .filter(|pattern| pattern.is_match(query))
+                .count();
 
-            // Normalize score by number of patterns for this intent
+            // Give 0.5 for each pattern that matches, normalized by the number of
+            // patterns for this intent. Raise the threshold to be more strict.
             if matches_found > 0 {
-                intent_score /= patterns.len() as f32;
-                if intent_score > best_match.1 {
-                    best_match = (intent.clone(), intent_score);
+                let intent_score = matches_found as f32 * 0.5 / patterns.len() as f32;
+                if intent_score > INTENT_THRESHOLD {
+                    ranked.push((intent, intent_score));
                 }
             }
         }
 
-        // Raise the threshold to be more strict
-        if best_match.1 > 0.15 {
-            Ok(best_match.0)
-        } else {
-            Err(NLPError::IntentRecognitionFailed {
+        if ranked.is_empty() {
+            return Err(NLPError::IntentRecognitionFailed {
                 text: query.to_string(),
             }
-            .into())
+            .into());
         }
+        // Stable sort: ties keep the order of RANKED_INTENTS
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Ok(ranked)
     }
 
     /// Extract entities from natural language query
@@ -2358,14 +2535,34 @@ impl NaturalLanguageProcessor {
         &self,
         intent: &QueryIntent,
         entities: &[Entity],
+    ) -> QSQLResult<String> {
+        self.generate_traced(intent, entities, &mut TranslationTrace::new(""))
+    }
+
+    /// Generate QSQL from intent and entities, recording which phrases of the
+    /// traced query produced each clause
+    fn generate_traced(
+        &self,
+        intent: &QueryIntent,
+        entities: &[Entity],
+        trace: &mut TranslationTrace<'_>,
     ) -> QSQLResult<String> {
         match intent {
-            | QueryIntent::Select => self.generate_select_query(entities),
-            | QueryIntent::NeuroMatch => self.generate_neuromatch_query(entities),
-            | QueryIntent::QuantumSearch => self.generate_quantum_search_query(entities),
-            | QueryIntent::Filter => self.generate_filter_query(entities),
-            | QueryIntent::Aggregate => self.generate_aggregate_query(entities),
-            | QueryIntent::Join => self.generate_join_query(entities),
+            | QueryIntent::Select => {
+                self.trace_intent_phrase(intent, trace, "SELECT");
+                self.generate_select_query(entities, trace)
+            },
+            | QueryIntent::NeuroMatch => {
+                self.trace_intent_phrase(intent, trace, "NEUROMATCH");
+                self.generate_neuromatch_query(entities, trace)
+            },
+            | QueryIntent::QuantumSearch => {
+                self.trace_intent_phrase(intent, trace, "QUANTUM_SEARCH");
+                self.generate_quantum_search_query(entities, trace)
+            },
+            | QueryIntent::Filter => self.generate_filter_query(entities, trace),
+            | QueryIntent::Aggregate => self.generate_aggregate_query(entities, trace),
+            | QueryIntent::Join => self.generate_join_query(entities, trace),
             | _ => Err(NLPError::UnsupportedConstruct {
                 construct: format!("{intent:?}"),
             }
@@ -2373,8 +2570,29 @@ impl NaturalLanguageProcessor {
         }
     }
 
+    /// Record the phrase matching the intent's primary pattern (its verb or keyword)
+    fn trace_intent_phrase(
+        &self,
+        intent: &QueryIntent,
+        trace: &mut TranslationTrace<'_>,
+        clause: &str,
+    ) {
+        let found = self
+            .intent_patterns
+            .get(intent)
+            .and_then(|patterns| patterns.first())
+            .and_then(|pattern| pattern.find(trace.query));
+        if let Some(found) = found {
+            trace.record(found.start(), found.end(), clause);
+        }
+    }
+
     /// Generate SELECT query from entities
-    fn generate_select_query(&self, entities: &[Entity]) -> QSQLResult<String> {
+    fn generate_select_query(
+        &self,
+        entities: &[Entity],
+        trace: &mut TranslationTrace<'_>,
+    ) -> QSQLResult<String> {
         let mut query = String::from("SELECT ");
 
         // Extract columns
@@ -2390,25 +2608,17 @@ impl NaturalLanguageProcessor {
                 .iter()
                 .map(|e| self.map_column_name(&e.value))
                 .collect();
+            for (entity, name) in columns.iter().zip(&column_names) {
+                trace.record_entity(entity, name.clone());
+            }
             query.push_str(&column_names.join(", "));
         }
 
         // Extract table
-        let tables: Vec<&Entity> = entities
-            .iter()
-            .filter(|e| e.entity_type == EntityType::TableName)
-            .collect();
-
-        if let Some(table) = tables.first() {
-            query.push_str(" FROM ");
-            query.push_str(&self.map_table_name(&table.value));
-        } else {
-            // Default table if none found
-            query.push_str(" FROM users");
-        }
+        self.push_table(&mut query, " FROM ", entities, "users", trace);
 
         // Add WHERE conditions
-        let conditions = self.extract_conditions(entities)?;
+        let conditions = self.extract_conditions(entities, trace)?;
         if conditions.is_empty() {
             // Look for age comparisons in the query
             let numbers: Vec<&Entity> = entities
@@ -2418,7 +2628,18 @@ impl NaturalLanguageProcessor {
 
             if !numbers.is_empty() {
                 let age_number = numbers[0].value.clone();
-                query.push_str(&format!(" WHERE age > {age_number}"));
+                let clause = format!(" WHERE age > {age_number}");
+
+                // Include a comparison phrase right before the number ("older than 30")
+                let start_pos = entities
+                    .iter()
+                    .rev()
+                    .find(|e| {
+                        e.entity_type == EntityType::Operator && e.end_pos <= numbers[0].start_pos
+                    })
+                    .map_or(numbers[0].start_pos, |e| e.start_pos);
+                trace.record(start_pos, numbers[0].end_pos, clause.trim_start());
+                query.push_str(&clause);
             }
         } else {
             query.push_str(" WHERE ");
@@ -2428,86 +2649,118 @@ impl NaturalLanguageProcessor {
         Ok(query)
     }
 
-    /// Generate NEUROMATCH query
-    fn generate_neuromatch_query(&self, entities: &[Entity]) -> QSQLResult<String> {
-        let mut query = String::from("NEUROMATCH ");
-
-        // Extract table
-        let tables: Vec<&Entity> = entities
+    /// Append `prefix` and the first table entity (or `default`) to `query`
+    fn push_table(
+        &self,
+        query: &mut String,
+        prefix: &str,
+        entities: &[Entity],
+        default: &str,
+        trace: &mut TranslationTrace<'_>,
+    ) {
+        let table = entities
             .iter()
-            .filter(|e| e.entity_type == EntityType::TableName)
-            .collect();
+            .find(|e| e.entity_type == EntityType::TableName);
 
-        if let Some(table) = tables.first() {
-            query.push_str(&self.map_table_name(&table.value));
+        query.push_str(prefix);
+        if let Some(table) = table {
+            let name = self.map_table_name(&table.value);
+            trace.record_entity(table, format!("{}{name}", prefix.trim_start()));
+            query.push_str(&name);
         } else {
-            query.push_str("memories"); // Default table for neuromatch
+            query.push_str(default);
         }
+    }
+
+    /// Generate NEUROMATCH query
+    fn generate_neuromatch_query(
+        &self,
+        entities: &[Entity],
+        trace: &mut TranslationTrace<'_>,
+    ) -> QSQLResult<String> {
+        let mut query = String::from("NEUROMATCH");
+
+        // Default table for neuromatch
+        self.push_table(&mut query, " ", entities, "memories", trace);
 
         Ok(query)
     }
 
     /// Generate `QUANTUM_SEARCH` query
-    fn generate_quantum_search_query(&self, entities: &[Entity]) -> QSQLResult<String> {
-        let mut query = String::from("QUANTUM_SEARCH ");
-
-        // Extract table
-        let tables: Vec<&Entity> = entities
-            .iter()
-            .filter(|e| e.entity_type == EntityType::TableName)
-            .collect();
+    fn generate_quantum_search_query(
+        &self,
+        entities: &[Entity],
+        trace: &mut TranslationTrace<'_>,
+    ) -> QSQLResult<String> {
+        let mut query = String::from("QUANTUM_SEARCH");
 
-        if let Some(table) = tables.first() {
-            query.push_str(&self.map_table_name(&table.value));
-        } else {
-            query.push_str("data"); // Default table for quantum search
-        }
+        // Default table for quantum search
+        self.push_table(&mut query, " ", entities, "data", trace);
 
         Ok(query)
     }
 
     /// Generate filter query
-    fn generate_filter_query(&self, entities: &[Entity]) -> QSQLResult<String> {
-        self.generate_select_query(entities)
+    fn generate_filter_query(
+        &self,
+        entities: &[Entity],
+        trace: &mut TranslationTrace<'_>,
+    ) -> QSQLResult<String> {
+        self.generate_select_query(entities, trace)
     }
 
     /// Generate aggregate query with LIMIT support
-    fn generate_aggregate_query(&self, entities: &[Entity]) -> QSQLResult<String> {
-        let mut query = String::from("SELECT COUNT(*) FROM ");
-
-        // Extract table
-        let tables: Vec<&Entity> = entities
-            .iter()
-            .filter(|e| e.entity_type == EntityType::TableName)
-            .collect();
-
-        if let Some(table) = tables.first() {
-            query.push_str(&self.map_table_name(&table.value));
-        } else {
-            query.push_str("users"); // Default table
-        }
-
+    fn generate_aggregate_query(
+        &self,
+        entities: &[Entity],
+        trace: &mut TranslationTrace<'_>,
+    ) -> QSQLResult<String> {
         // Look for "top X" patterns and add LIMIT
         let numbers: Vec<&Entity> = entities
             .iter()
             .filter(|e| e.entity_type == EntityType::Number)
             .collect();
 
-        if !numbers.is_empty() {
-            let limit_number = numbers[0].value.clone();
-            query = format!("SELECT * FROM users ORDER BY post_count DESC LIMIT {limit_number}");
+        if let Some(number) = numbers.first() {
+            let limit_number = number.value.clone();
+            let clause = format!("ORDER BY post_count DESC LIMIT {limit_number}");
+            let top = self
+                .intent_patterns
+                .get(&QueryIntent::Aggregate)
+                .and_then(|patterns| patterns.first())
+                .and_then(|pattern| pattern.find(trace.query))
+                .filter(|found| found.end() == number.end_pos);
+            match top {
+                | Some(found) => trace.record(found.start(), found.end(), clause.clone()),
+                | None => trace.record_entity(number, clause.clone()),
+            }
+            return Ok(format!("SELECT * FROM users {clause}"));
         }
 
+        self.trace_intent_phrase(&QueryIntent::Aggregate, trace, "SELECT COUNT(*)");
+        let mut query = String::from("SELECT COUNT(*)");
+
+        // Default table
+        self.push_table(&mut query, " FROM ", entities, "users", trace);
+
         Ok(query)
     }
 
     /// Generate join query
-    fn generate_join_query(&self, entities: &[Entity]) -> QSQLResult<String> {
-        self.generate_select_query(entities)
+    fn generate_join_query(
+        &self,
+        entities: &[Entity],
+        trace: &mut TranslationTrace<'_>,
+    ) -> QSQLResult<String> {
+        self.generate_select_query(entities, trace)
     }
 
     /// Extract WHERE conditions from entities
-    fn extract_conditions(&self, entities: &[Entity]) -> QSQLResult<Vec<String>> {
+    fn extract_conditions(
+        &self,
+        entities: &[Entity],
+        trace: &mut TranslationTrace<'_>,
+    ) -> QSQLResult<Vec<String>> {
         let mut conditions = Vec::new();
 
         // Look for operators and values
@@ -2524,6 +2777,11 @@ impl NaturalLanguageProcessor {
                     let value = &entities[i + 2].value;
 
                     let condition = format!("{column} {operator} {value}");
+                    trace.record(
+                        entities[i].start_pos,
+                        entities[i + 2].end_pos,
+                        format!("WHERE {condition}"),
+                    );
                     conditions.push(condition);
                     i += 3;
                 } else {
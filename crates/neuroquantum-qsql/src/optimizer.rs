@@ -11,12 +11,17 @@ use std::time::{Duration, SystemTime};
 
 use neuroquantum_core::learning::HebbianLearningEngine;
 use neuroquantum_core::plasticity::PlasticityMatrix;
+use neuroquantum_core::storage::{ColumnStatistics, TableStatistics, Value};
 use neuroquantum_core::synaptic::SynapticNetwork;
 use serde::{Deserialize, Serialize};
-use tracing::{debug, info, instrument};
+use tracing::{debug, info, instrument, warn};
 
-use crate::ast::{SelectItem, Statement};
+use crate::ast::{
+    BinaryOperator, Expression, JoinType, Literal, PlanHint, SelectItem, Statement, TableReference,
+    UnaryOperator,
+};
 use crate::error::{QSQLError, QSQLResult};
+use crate::statistics::compare_values;
 
 /// Neuromorphic query optimizer with synaptic learning
 pub struct NeuromorphicOptimizer {
@@ -117,6 +122,32 @@ pub enum ExecutionStrategy {
     HybridNeuralQuantum,
 }
 
+/// Algorithm used to execute a join between two row sets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JoinStrategy {
+    /// Evaluate the join condition for every pair of rows; works for any condition
+    NestedLoop,
+    /// Hash the smaller input on its equi-join keys and probe with the larger one
+    Hash,
+}
+
+/// Selectivity assumed for predicates the statistics cannot estimate
+const DEFAULT_SELECTIVITY: f64 = 1.0 / 3.0;
+
+/// Selectivity assumed for an equality on a column without statistics
+const DEFAULT_EQUALITY_SELECTIVITY: f64 = 0.1;
+
+/// A table joined into a query, as seen by join ordering
+#[derive(Debug, Clone)]
+pub struct JoinInput<'a> {
+    /// Alias (or name) the rest of the query uses for the table
+    pub alias: &'a str,
+    /// Estimated number of rows the table contributes
+    pub estimated_rows: f64,
+    /// The join's ON condition
+    pub condition: Option<&'a Expression>,
+}
+
 /// Synaptic pathway for data access optimization
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SynapticPathway {
@@ -675,6 +706,429 @@ impl NeuromorphicOptimizer {
         }
     }
 
+    /// Choose the join algorithm from the estimated cardinalities of both inputs.
+    ///
+    /// A nested loop evaluates `left_rows * right_rows` row pairs, a hash join
+    /// touches each row roughly once. Hash join is chosen when that product
+    /// exceeds `hash_join_threshold`, the hash join is cheaper, the join is an
+    /// INNER or outer join and the condition has at least one column-to-column equality
+    /// conjunct; any remaining conjuncts (e.g. inequalities) are checked per match.
+    #[must_use]
+    pub fn choose_join_strategy(
+        left_rows: usize,
+        right_rows: usize,
+        join_type: &JoinType,
+        condition: Option<&Expression>,
+        hash_join_threshold: usize,
+    ) -> JoinStrategy {
+        let nested_loop_cost = left_rows.saturating_mul(right_rows);
+        let hash_cost = left_rows.saturating_add(right_rows);
+
+        let use_hash = nested_loop_cost > hash_join_threshold
+            && hash_cost < nested_loop_cost
+            && Self::supports_hash_join(join_type, condition);
+
+        if use_hash {
+            JoinStrategy::Hash
+        } else {
+            JoinStrategy::NestedLoop
+        }
+    }
+
+    /// Choose the join algorithm, honoring a `HASH_JOIN` or `NESTED_LOOP_JOIN` hint.
+    ///
+    /// Falls back to [`Self::choose_join_strategy`] without a hint or when
+    /// the hinted algorithm can't execute the join.
+    #[must_use]
+    pub fn plan_join_strategy(
+        hints: &[PlanHint],
+        left_rows: usize,
+        right_rows: usize,
+        join_type: &JoinType,
+        condition: Option<&Expression>,
+        hash_join_threshold: usize,
+    ) -> JoinStrategy {
+        match Self::hinted_join_strategy(hints, join_type, condition) {
+            | Ok(Some(strategy)) => strategy,
+            | Ok(None) => Self::choose_join_strategy(
+                left_rows,
+                right_rows,
+                join_type,
+                condition,
+                hash_join_threshold,
+            ),
+            | Err(reason) => {
+                warn!("{}", reason);
+                Self::choose_join_strategy(
+                    left_rows,
+                    right_rows,
+                    join_type,
+                    condition,
+                    hash_join_threshold,
+                )
+            },
+        }
+    }
+
+    /// Join algorithm forced by the last join hint in `hints`
+    ///
+    /// Returns `Ok(None)` without a join hint and `Err` with the reason when
+    /// the hinted algorithm can't execute this join.
+    pub fn hinted_join_strategy(
+        hints: &[PlanHint],
+        join_type: &JoinType,
+        condition: Option<&Expression>,
+    ) -> Result<Option<JoinStrategy>, String> {
+        let hinted = hints.iter().rev().find_map(|hint| match hint {
+            | PlanHint::HashJoin => Some(JoinStrategy::Hash),
+            | PlanHint::NestedLoopJoin => Some(JoinStrategy::NestedLoop),
+            | PlanHint::Index { .. } => None,
+        });
+        match hinted {
+            | Some(JoinStrategy::Hash) if !Self::supports_hash_join(join_type, condition) => {
+                Err(format!(
+                    "HASH_JOIN hint ignored: {join_type:?} join has no column equality to hash on"
+                ))
+            },
+            | hinted => Ok(hinted),
+        }
+    }
+
+    /// Name of the index an `INDEX(table index)` hint forces for `relation`
+    ///
+    /// The hint's table may name the relation by its table name or alias.
+    #[must_use]
+    pub fn hinted_index<'a>(hints: &'a [PlanHint], relation: &TableReference) -> Option<&'a str> {
+        let names_relation = |table: &str| {
+            relation.name.eq_ignore_ascii_case(table)
+                || relation
+                    .alias
+                    .as_deref()
+                    .is_some_and(|alias| alias.eq_ignore_ascii_case(table))
+        };
+        hints.iter().rev().find_map(|hint| match hint {
+            | PlanHint::Index { table, index } if names_relation(table) => Some(index.as_str()),
+            | _ => None,
+        })
+    }
+
+    /// Whether a hash join can execute a join of this type and condition
+    fn supports_hash_join(join_type: &JoinType, condition: Option<&Expression>) -> bool {
+        matches!(
+            join_type,
+            JoinType::Inner | JoinType::Left | JoinType::Right | JoinType::Full
+        ) && condition.is_some_and(Self::has_equi_join_key)
+    }
+
+    /// Whether `condition` is, or is an AND containing, an equality between two columns
+    fn has_equi_join_key(condition: &Expression) -> bool {
+        match condition {
+            | Expression::BinaryOp {
+                left,
+                operator: BinaryOperator::Equal,
+                right,
+            } => matches!(
+                (left.as_ref(), right.as_ref()),
+                (Expression::Identifier(_), Expression::Identifier(_))
+            ),
+            | Expression::BinaryOp {
+                left,
+                operator: BinaryOperator::And,
+                right,
+            } => Self::has_equi_join_key(left) || Self::has_equi_join_key(right),
+            | _ => false,
+        }
+    }
+
+    /// Estimated fraction of rows of a table satisfying `predicate`.
+    ///
+    /// Equalities use the most common values of the column and spread the
+    /// remaining rows evenly over the other distinct values; range comparisons
+    /// use the histogram. Without statistics fixed default selectivities apply.
+    #[must_use]
+    pub fn estimate_selectivity(
+        statistics: Option<&TableStatistics>,
+        predicate: &Expression,
+    ) -> f64 {
+        let selectivity = match predicate {
+            | Expression::BinaryOp {
+                left,
+                operator: BinaryOperator::And,
+                right,
+            } => {
+                Self::estimate_selectivity(statistics, left)
+                    * Self::estimate_selectivity(statistics, right)
+            },
+            | Expression::BinaryOp {
+                left,
+                operator: BinaryOperator::Or,
+                right,
+            } => {
+                let left = Self::estimate_selectivity(statistics, left);
+                let right = Self::estimate_selectivity(statistics, right);
+                left + right - left * right
+            },
+            | Expression::UnaryOp {
+                operator: UnaryOperator::Not,
+                operand,
+            } => 1.0 - Self::estimate_selectivity(statistics, operand),
+            | Expression::BinaryOp {
+                left,
+                operator,
+                right,
+            } => Self::comparison_selectivity(statistics, left, operator, right),
+            | Expression::InList {
+                expr,
+                list,
+                negated,
+            } => {
+                let matching: f64 = list
+                    .iter()
+                    .map(|item| {
+                        Self::comparison_selectivity(statistics, expr, &BinaryOperator::Equal, item)
+                    })
+                    .sum();
+                let matching = matching.min(1.0);
+                if *negated {
+                    1.0 - matching
+                } else {
+                    matching
+                }
+            },
+            | Expression::IsNull { expr, negated } => {
+                match Self::column_statistics(statistics, expr) {
+                    | Some(column) if *negated => 1.0 - column.null_fraction,
+                    | Some(column) => column.null_fraction,
+                    | None => DEFAULT_SELECTIVITY,
+                }
+            },
+            | _ => DEFAULT_SELECTIVITY,
+        };
+        selectivity.clamp(0.0, 1.0)
+    }
+
+    /// The conjuncts of `predicate`, most selective first.
+    ///
+    /// Evaluating the most selective conjunct first rejects most rows after a
+    /// single comparison. Conjuncts with equal estimates keep their order.
+    #[must_use]
+    pub fn order_predicates<'a>(
+        statistics: Option<&TableStatistics>,
+        predicate: &'a Expression,
+    ) -> Vec<&'a Expression> {
+        let mut conjuncts = Vec::new();
+        Self::collect_conjuncts(predicate, &mut conjuncts);
+        let mut ranked: Vec<(f64, &Expression)> = conjuncts
+            .into_iter()
+            .map(|conjunct| (Self::estimate_selectivity(statistics, conjunct), conjunct))
+            .collect();
+        ranked.sort_by(|a, b| a.0.total_cmp(&b.0));
+        ranked.into_iter().map(|(_, conjunct)| conjunct).collect()
+    }
+
+    /// Order in which to apply a chain of INNER joins.
+    ///
+    /// Greedily joins the smallest estimated input next, but only once every
+    /// table its ON condition references (through qualified column names) has
+    /// been joined. Returns indices into `joins`.
+    #[must_use]
+    pub fn order_inner_joins(base_alias: &str, joins: &[JoinInput<'_>]) -> Vec<usize> {
+        let mut joined = vec![base_alias];
+        let mut remaining: Vec<usize> = (0..joins.len()).collect();
+        let mut order = Vec::with_capacity(joins.len());
+
+        while !remaining.is_empty() {
+            let ready = |index: &usize| {
+                let join = &joins[*index];
+                let mut qualifiers = Vec::new();
+                if let Some(condition) = join.condition {
+                    Self::collect_qualifiers(condition, &mut qualifiers);
+                }
+                qualifiers
+                    .iter()
+                    .all(|qualifier| *qualifier == join.alias || joined.contains(qualifier))
+            };
+            let next = remaining
+                .iter()
+                .copied()
+                .filter(ready)
+                .min_by(|a, b| {
+                    joins[*a]
+                        .estimated_rows
+                        .total_cmp(&joins[*b].estimated_rows)
+                })
+                .unwrap_or(remaining[0]);
+
+            remaining.retain(|index| *index != next);
+            joined.push(joins[next].alias);
+            order.push(next);
+        }
+        order
+    }
+
+    fn comparison_selectivity(
+        statistics: Option<&TableStatistics>,
+        left: &Expression,
+        operator: &BinaryOperator,
+        right: &Expression,
+    ) -> f64 {
+        // Normalize to `column <op> literal`
+        let (column, operator, literal) = match (left, right) {
+            | (Expression::Identifier(_), Expression::Literal(literal)) => {
+                (left, operator.clone(), literal)
+            },
+            | (Expression::Literal(literal), Expression::Identifier(_)) => {
+                let flipped = match operator {
+                    | BinaryOperator::LessThan => BinaryOperator::GreaterThan,
+                    | BinaryOperator::LessThanOrEqual => BinaryOperator::GreaterThanOrEqual,
+                    | BinaryOperator::GreaterThan => BinaryOperator::LessThan,
+                    | BinaryOperator::GreaterThanOrEqual => BinaryOperator::LessThanOrEqual,
+                    | other => other.clone(),
+                };
+                (right, flipped, literal)
+            },
+            | _ => return DEFAULT_SELECTIVITY,
+        };
+
+        let Some(column) = Self::column_statistics(statistics, column) else {
+            return match operator {
+                | BinaryOperator::Equal => DEFAULT_EQUALITY_SELECTIVITY,
+                | BinaryOperator::NotEqual => 1.0 - DEFAULT_EQUALITY_SELECTIVITY,
+                | _ => DEFAULT_SELECTIVITY,
+            };
+        };
+        let Some(value) = Self::literal_value(literal) else {
+            return DEFAULT_SELECTIVITY;
+        };
+
+        match operator {
+            | BinaryOperator::Equal => Self::equality_selectivity(column, &value),
+            | BinaryOperator::NotEqual => {
+                1.0 - column.null_fraction - Self::equality_selectivity(column, &value)
+            },
+            | BinaryOperator::LessThan | BinaryOperator::LessThanOrEqual => {
+                Self::less_than_selectivity(column, &value)
+            },
+            | BinaryOperator::GreaterThan | BinaryOperator::GreaterThanOrEqual => {
+                1.0 - column.null_fraction - Self::less_than_selectivity(column, &value)
+            },
+            | _ => DEFAULT_SELECTIVITY,
+        }
+    }
+
+    fn equality_selectivity(column: &ColumnStatistics, value: &Value) -> f64 {
+        if let Some((_, frequency)) = column
+            .most_common_values
+            .iter()
+            .find(|(common, _)| compare_values(common, value).is_eq())
+        {
+            return *frequency;
+        }
+        let other_distinct = column
+            .distinct_count
+            .saturating_sub(column.most_common_values.len() as u64);
+        if other_distinct == 0 {
+            return 0.0;
+        }
+        Self::histogram_fraction(column) / other_distinct as f64
+    }
+
+    /// Fraction of rows with a non-NULL value below `value`
+    fn less_than_selectivity(column: &ColumnStatistics, value: &Value) -> f64 {
+        let common: f64 = column
+            .most_common_values
+            .iter()
+            .filter(|(common, _)| compare_values(common, value).is_lt())
+            .map(|(_, frequency)| frequency)
+            .sum();
+
+        let bounds = &column.histogram_bounds;
+        let below = bounds
+            .iter()
+            .filter(|bound| compare_values(bound, value).is_lt())
+            .count();
+        let histogram = if bounds.len() < 2 {
+            if below > 0 {
+                1.0
+            } else {
+                0.0
+            }
+        } else if below == 0 {
+            0.0
+        } else if below == bounds.len() {
+            1.0
+        } else {
+            // `value` falls into bucket `below - 1`; assume it sits in the middle
+            (below as f64 - 0.5) / (bounds.len() - 1) as f64
+        };
+
+        common + histogram * Self::histogram_fraction(column)
+    }
+
+    /// Fraction of rows described by the histogram rather than the most common values
+    fn histogram_fraction(column: &ColumnStatistics) -> f64 {
+        let common: f64 = column
+            .most_common_values
+            .iter()
+            .map(|(_, frequency)| frequency)
+            .sum();
+        (1.0 - column.null_fraction - common).max(0.0)
+    }
+
+    fn column_statistics<'a>(
+        statistics: Option<&'a TableStatistics>,
+        column: &Expression,
+    ) -> Option<&'a ColumnStatistics> {
+        let Expression::Identifier(name) = column else {
+            return None;
+        };
+        let name = name
+            .rsplit_once('.')
+            .map_or(name.as_str(), |(_, column)| column);
+        statistics?.columns.get(name)
+    }
+
+    fn literal_value(literal: &Literal) -> Option<Value> {
+        match literal {
+            | Literal::String(text) => Some(Value::text(text)),
+            | Literal::Integer(value) => Some(Value::Integer(*value)),
+            | Literal::Float(value) => Some(Value::Float(*value)),
+            | Literal::Boolean(value) => Some(Value::Boolean(*value)),
+            | _ => None,
+        }
+    }
+
+    fn collect_conjuncts<'a>(predicate: &'a Expression, conjuncts: &mut Vec<&'a Expression>) {
+        match predicate {
+            | Expression::BinaryOp {
+                left,
+                operator: BinaryOperator::And,
+                right,
+            } => {
+                Self::collect_conjuncts(left, conjuncts);
+                Self::collect_conjuncts(right, conjuncts);
+            },
+            | other => conjuncts.push(other),
+        }
+    }
+
+    /// Table qualifiers of the column references in `expr`
+    fn collect_qualifiers<'a>(expr: &'a Expression, qualifiers: &mut Vec<&'a str>) {
+        match expr {
+            | Expression::Identifier(name) => {
+                if let Some((qualifier, _)) = name.split_once('.') {
+                    qualifiers.push(qualifier);
+                }
+            },
+            | Expression::BinaryOp { left, right, .. } => {
+                Self::collect_qualifiers(left, qualifiers);
+                Self::collect_qualifiers(right, qualifiers);
+            },
+            | Expression::UnaryOp { operand, .. } => Self::collect_qualifiers(operand, qualifiers),
+            | _ => {},
+        }
+    }
+
     /// Get optimization statistics
     pub const fn get_stats(&self) -> &OptimizationStats {
         &self.optimization_stats
@@ -9,15 +9,16 @@ use serde::{Deserialize, Serialize};
 use tracing::{debug, instrument, warn};
 
 use crate::ast::{
-    AdaptWeightsStatement, AlterTableOperation, AlterTableStatement, Assignment, BinaryOperator,
-    ColumnConstraint, ColumnDefinition, CommonTableExpression, CompressTableStatement,
-    CompressionAlgorithm, CreateIndexStatement, CreateTableStatement, DataType,
-    DeallocateStatement, DeleteStatement, DropIndexStatement, DropTableStatement, ExecuteStatement,
-    Expression, FromClause, InsertStatement, JoinClause, JoinType, LearnPatternStatement, Literal,
-    NeuroMatchClause, NeuroMatchStatement, OrderByItem, ParameterRef, PrepareStatement,
-    QuantumJoinStatement, QuantumSearchStatement, ReferentialAction, SelectItem, SelectStatement,
-    Statement, TableConstraint, TableReference, TruncateBehavior, TruncateTableStatement,
-    UnaryOperator, UpdateStatement, WindowFunctionType, WindowSpec, WithClause,
+    AdaptWeightsStatement, AlterTableOperation, AlterTableStatement, AnalyzeStatement, Assignment,
+    BinaryOperator, ColumnConstraint, ColumnDefinition, CommonTableExpression,
+    CompressTableStatement, CompressionAlgorithm, CreateIndexStatement, CreateTableStatement,
+    DataType, DeallocateStatement, DeleteStatement, DropIndexStatement, DropTableStatement,
+    ExecuteStatement, Expression, FromClause, InsertStatement, JoinClause, JoinType,
+    LearnPatternStatement, Literal, NeuroMatchClause, NeuroMatchStatement, OrderByItem,
+    ParameterRef, PlanHint, PrepareStatement, QuantumJoinStatement, QuantumSearchStatement,
+    ReferentialAction, SelectItem, SelectStatement, Statement, TableConstraint, TableReference,
+    TruncateBehavior, TruncateTableStatement, UnaryOperator, UpdateStatement, WindowFunctionType,
+    WindowSpec, WithClause,
 };
 use crate::error::{QSQLError, QSQLResult};
 
@@ -180,6 +181,7 @@ pub enum TokenType {
     BigSerial,
     SmallSerial,
     AutoIncrement,
+    Encrypted,
     Primary,
     Key,
     Unique,
@@ -190,6 +192,7 @@ pub enum TokenType {
     Identity,
     Foreign,
     Constraint,
+    Check,
 
     // Date/Time keywords
     Interval,
@@ -323,6 +326,8 @@ pub enum TokenType {
     // Special tokens
     Whitespace,
     Comment(String),
+    /// Text of a `/*+ ... */` optimizer hint comment
+    Hint(String),
     EOF,
 }
 
@@ -387,6 +392,18 @@ impl QSQLParser {
         }
     }
 
+    /// Translate natural language to QSQL with an explanation of how each
+    /// phrase was interpreted
+    pub fn explain_natural_language(&self, natural_query: &str) -> QSQLResult<NlTranslation> {
+        if let Some(nlp) = &self.natural_language_processor {
+            nlp.explain_translation(natural_query)
+        } else {
+            Err(QSQLError::ConfigError {
+                message: "Natural language processing not enabled".to_string(),
+            })
+        }
+    }
+
     /// Tokenize input string
     fn tokenize(&self, input: &str) -> QSQLResult<Vec<TokenType>> {
         let mut tokens = Vec::new();
@@ -396,9 +413,11 @@ impl QSQLParser {
         while position < chars.len() {
             let (token, new_pos) = self.next_token(&chars, position)?;
 
-            // Skip whitespace and comments in most cases
+            // Skip whitespace and comments in most cases; hints only count
+            // directly after SELECT and are plain comments anywhere else
             match token {
                 | TokenType::Whitespace | TokenType::Comment(_) => {},
+                | TokenType::Hint(_) if tokens.last() != Some(&TokenType::Select) => {},
                 | _ => tokens.push(token),
             }
 
@@ -443,6 +462,29 @@ impl QSQLParser {
             }
             return Ok((TokenType::Comment(comment), new_pos));
         }
+        if ch == '/' && position + 1 < chars.len() && chars[position + 1] == '*' {
+            let is_hint = chars.get(position + 2) == Some(&'+');
+            let mut new_pos = position + if is_hint { 3 } else { 2 };
+            let mut comment = String::new();
+            while new_pos < chars.len()
+                && !(chars[new_pos] == '*' && chars.get(new_pos + 1) == Some(&'/'))
+            {
+                comment.push(chars[new_pos]);
+                new_pos += 1;
+            }
+            if new_pos >= chars.len() {
+                return Err(QSQLError::ParseError {
+                    message: "Unterminated comment".to_string(),
+                    position,
+                });
+            }
+            let token = if is_hint {
+                TokenType::Hint(comment)
+            } else {
+                TokenType::Comment(comment)
+            };
+            return Ok((token, new_pos + 2));
+        }
 
         // String literals
         if ch == '\'' || ch == '"' {
@@ -728,6 +770,7 @@ impl QSQLParser {
             | Some(TokenType::Alter) => self.parse_alter_table_statement(tokens),
             | Some(TokenType::Truncate) => self.parse_truncate_table_statement(tokens),
             | Some(TokenType::Compress) => self.parse_compress_table_statement(tokens),
+            | Some(TokenType::Analyze) => self.parse_analyze_statement(tokens),
             | Some(TokenType::NeuroMatch) => self.parse_neuromatch_statement(tokens),
             | Some(TokenType::QuantumSearch) => self.parse_quantum_search_statement(tokens),
             | Some(TokenType::Learn) => self.parse_learn_pattern_statement(tokens),
@@ -791,6 +834,7 @@ impl QSQLParser {
             | TokenType::Default => Some("default".to_string()),
             | TokenType::Foreign => Some("foreign".to_string()),
             | TokenType::Constraint => Some("constraint".to_string()),
+            | TokenType::Check => Some("check".to_string()),
             | TokenType::Algorithm => Some("algorithm".to_string()),
             | TokenType::Weights => Some("weights".to_string()),
             | TokenType::Features => Some("features".to_string()),
@@ -839,6 +883,7 @@ impl QSQLParser {
             | TokenType::Default => "default".to_string(),
             | TokenType::Foreign => "foreign".to_string(),
             | TokenType::Constraint => "constraint".to_string(),
+            | TokenType::Check => "check".to_string(),
             | TokenType::Algorithm => "algorithm".to_string(),
             | TokenType::Weights => "weights".to_string(),
             | TokenType::Features => "features".to_string(),
@@ -878,6 +923,7 @@ impl QSQLParser {
         if i < tokens.len() && matches!(tokens[i], TokenType::Select) {
             i += 1;
         }
+        let hints = Self::parse_plan_hints(tokens, &mut i)?;
 
         // Skip optional DISTINCT
         if i < tokens.len() && matches!(tokens[i], TokenType::Distinct) {
@@ -1266,6 +1312,7 @@ impl QSQLParser {
                 | TokenType::Default
                 | TokenType::Foreign
                 | TokenType::Constraint
+                | TokenType::Check
                 | TokenType::Algorithm
                 | TokenType::Weights
                 | TokenType::Features
@@ -1465,6 +1512,7 @@ impl QSQLParser {
             grover_iterations,
             with_clause,
             union_clause,
+            hints,
         }))
     }
 
@@ -1493,6 +1541,7 @@ impl QSQLParser {
         if *i < tokens.len() && matches!(tokens[*i], TokenType::Select) {
             *i += 1;
         }
+        let hints = Self::parse_plan_hints(tokens, i)?;
 
         // Skip optional DISTINCT
         if *i < tokens.len() && matches!(tokens[*i], TokenType::Distinct) {
@@ -2045,9 +2094,68 @@ impl QSQLParser {
             grover_iterations,
             with_clause: None, // Subqueries don't support WITH clauses (for now)
             union_clause,
+            hints,
         })
     }
 
+    /// Parse the optimizer hints of a `/*+ ... */` comment at `i`, if any
+    ///
+    /// Recognizes `INDEX(table index)`, `HASH_JOIN` and `NESTED_LOOP_JOIN`,
+    /// separated by whitespace or commas. Unknown hints are skipped with a
+    /// warning, like any hint the optimizer can't apply.
+    fn parse_plan_hints(tokens: &[TokenType], i: &mut usize) -> QSQLResult<Vec<PlanHint>> {
+        let Some(TokenType::Hint(text)) = tokens.get(*i) else {
+            return Ok(Vec::new());
+        };
+        let position = *i;
+        *i += 1;
+
+        let mut hints = Vec::new();
+        let mut rest = text.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        while !rest.is_empty() {
+            let name_len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let name = rest[..name_len].to_uppercase();
+            rest = rest[name_len..].trim_start();
+
+            let mut arguments = Vec::new();
+            if let Some(after_paren) = rest.strip_prefix('(') {
+                let end = after_paren.find(')').ok_or_else(|| QSQLError::ParseError {
+                    message: format!("Unclosed argument list in hint {name}"),
+                    position,
+                })?;
+                arguments = after_paren[..end]
+                    .split(|c: char| c.is_whitespace() || c == ',')
+                    .filter(|argument| !argument.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                rest = &after_paren[end + 1..];
+            } else if name.is_empty() {
+                return Err(QSQLError::ParseError {
+                    message: format!("Invalid optimizer hint near '{rest}'"),
+                    position,
+                });
+            }
+
+            match (name.as_str(), arguments.as_slice()) {
+                | ("INDEX", [table, index]) => hints.push(PlanHint::Index {
+                    table: table.clone(),
+                    index: index.clone(),
+                }),
+                | ("HASH_JOIN", []) => hints.push(PlanHint::HashJoin),
+                | ("NESTED_LOOP_JOIN", []) => hints.push(PlanHint::NestedLoopJoin),
+                | _ => warn!(
+                    "Ignoring unknown optimizer hint {}({})",
+                    name,
+                    arguments.join(" ")
+                ),
+            }
+            rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        }
+        Ok(hints)
+    }
+
     /// Parse a WHERE expression for subqueries (stops at `RightParen`)
     fn parse_subquery_where_expression(
         &self,
@@ -2213,6 +2321,7 @@ impl QSQLParser {
             | TokenType::Default
             | TokenType::Foreign
             | TokenType::Constraint
+            | TokenType::Check
             | TokenType::Algorithm
             | TokenType::Weights
             | TokenType::Features
@@ -3146,6 +3255,11 @@ impl QSQLParser {
                 pos += 1;
                 Some("SERIALIZABLE".to_string())
             },
+            // Not a keyword, so tables and columns can still be named snapshot
+            | TokenType::Identifier(name) if name.eq_ignore_ascii_case("SNAPSHOT") => {
+                pos += 1;
+                Some("SNAPSHOT".to_string())
+            },
             | other => {
                 // Provide a user-friendly description of what was found
                 let token_desc = match other {
@@ -3157,7 +3271,7 @@ impl QSQLParser {
                 };
                 return Err(QSQLError::ParseError {
                     message: format!(
-                        "Invalid isolation level. Found {token_desc}, but expected READ UNCOMMITTED, READ COMMITTED, REPEATABLE READ, or SERIALIZABLE"
+                        "Invalid isolation level. Found {token_desc}, but expected READ UNCOMMITTED, READ COMMITTED, REPEATABLE READ, SERIALIZABLE, or SNAPSHOT"
                     ),
                     position: pos,
                 });
@@ -3211,9 +3325,41 @@ impl QSQLParser {
         ))
     }
 
-    /// Parse COMMIT statement
-    const fn parse_commit(&self, _tokens: &[TokenType]) -> QSQLResult<Statement> {
-        Ok(Statement::Commit(crate::ast::CommitStatement {}))
+    /// Parse COMMIT statement (with optional DURABLE or RELAXED)
+    fn parse_commit(&self, tokens: &[TokenType]) -> QSQLResult<Statement> {
+        let mut i = 0;
+
+        // Skip COMMIT keyword
+        if i < tokens.len() && matches!(tokens[i], TokenType::Commit) {
+            i += 1;
+        }
+
+        // Skip optional TRANSACTION keyword
+        if i < tokens.len() && matches!(tokens[i], TokenType::Transaction) {
+            i += 1;
+        }
+
+        // Not keywords, so tables and columns can still be named relaxed
+        let durability = match tokens.get(i) {
+            | Some(TokenType::Identifier(name))
+                if name.eq_ignore_ascii_case("DURABLE") || name.eq_ignore_ascii_case("RELAXED") =>
+            {
+                Some(name.to_uppercase())
+            },
+            | Some(TokenType::Identifier(name)) => {
+                return Err(QSQLError::ParseError {
+                    message: format!(
+                        "Invalid commit durability '{name}', expected DURABLE or RELAXED"
+                    ),
+                    position: i,
+                });
+            },
+            | _ => None,
+        };
+
+        Ok(Statement::Commit(crate::ast::CommitStatement {
+            durability,
+        }))
     }
 
     /// Parse ROLLBACK statement (with optional TO SAVEPOINT)
@@ -3507,7 +3653,7 @@ impl QSQLParser {
             // Check if this is a table constraint
             if matches!(
                 tokens[i],
-                TokenType::Primary | TokenType::Unique | TokenType::Foreign
+                TokenType::Primary | TokenType::Unique | TokenType::Foreign | TokenType::Check
             ) {
                 // Parse table constraint
                 let constraint = self.parse_table_constraint(tokens, &mut i)?;
@@ -3608,6 +3754,14 @@ impl QSQLParser {
                     *i += 1;
                     ColumnConstraint::AutoIncrement
                 },
+                | TokenType::Encrypted => {
+                    *i += 1;
+                    ColumnConstraint::Encrypted
+                },
+                | TokenType::Check => {
+                    *i += 1;
+                    ColumnConstraint::Check(self.parse_check_expression(tokens, i)?)
+                },
                 | TokenType::References => {
                     *i += 1;
                     let table = if let TokenType::Identifier(t) = &tokens[*i] {
@@ -3697,6 +3851,33 @@ impl QSQLParser {
         })
     }
 
+    /// Parse the parenthesized condition of a CHECK constraint
+    fn parse_check_expression(
+        &self,
+        tokens: &[TokenType],
+        i: &mut usize,
+    ) -> QSQLResult<Expression> {
+        if *i >= tokens.len() || !matches!(tokens[*i], TokenType::LeftParen) {
+            return Err(QSQLError::ParseError {
+                message: "Expected '(' after CHECK".to_string(),
+                position: *i,
+            });
+        }
+        *i += 1;
+
+        let expression = self.parse_expression(tokens, i)?;
+
+        if *i >= tokens.len() || !matches!(tokens[*i], TokenType::RightParen) {
+            return Err(QSQLError::ParseError {
+                message: "Expected ')' after CHECK condition".to_string(),
+                position: *i,
+            });
+        }
+        *i += 1;
+
+        Ok(expression)
+    }
+
     /// Parse referential action for foreign key constraints
     /// Parses: CASCADE | RESTRICT | SET NULL | SET DEFAULT | NO ACTION
     fn parse_referential_action(
@@ -3985,6 +4166,12 @@ impl QSQLParser {
 
                 Ok(TableConstraint::Unique(columns))
             },
+            | TokenType::Check => {
+                *i += 1;
+                Ok(TableConstraint::Check(
+                    self.parse_check_expression(tokens, i)?,
+                ))
+            },
             | TokenType::Foreign => {
                 // FOREIGN KEY (columns) REFERENCES table(columns) [ON DELETE action] [ON UPDATE action]
                 *i += 1;
@@ -4627,6 +4814,73 @@ impl QSQLParser {
         }))
     }
 
+    /// Parse ANALYZE statement
+    /// Syntax: ANALYZE [TABLE] `table_name` [(`column`, ...)]
+    fn parse_analyze_statement(&self, tokens: &[TokenType]) -> QSQLResult<Statement> {
+        let mut i = 0;
+
+        // Skip ANALYZE keyword
+        if i < tokens.len() && matches!(tokens[i], TokenType::Analyze) {
+            i += 1;
+        }
+
+        // Skip optional TABLE keyword
+        if i < tokens.len() && matches!(tokens[i], TokenType::Table) {
+            i += 1;
+        }
+
+        // Parse table name
+        let table_name = match tokens.get(i) {
+            | Some(TokenType::Identifier(name)) => {
+                i += 1;
+                name.clone()
+            },
+            | _ => {
+                return Err(QSQLError::ParseError {
+                    message: "Expected table name after ANALYZE".to_string(),
+                    position: i,
+                });
+            },
+        };
+
+        // Parse optional column list
+        let columns = if matches!(tokens.get(i), Some(TokenType::LeftParen)) {
+            i += 1;
+            let mut columns = Vec::new();
+            loop {
+                match tokens.get(i) {
+                    | Some(TokenType::Identifier(column)) => columns.push(column.clone()),
+                    | _ => {
+                        return Err(QSQLError::ParseError {
+                            message: "Expected column name in ANALYZE column list".to_string(),
+                            position: i,
+                        });
+                    },
+                }
+                i += 1;
+                match tokens.get(i) {
+                    | Some(TokenType::Comma) => i += 1,
+                    | Some(TokenType::RightParen) => break,
+                    | _ => {
+                        return Err(QSQLError::ParseError {
+                            message: "Expected ',' or ')' in ANALYZE column list".to_string(),
+                            position: i,
+                        });
+                    },
+                }
+            }
+            Some(columns)
+        } else {
+            None
+        };
+
+        Ok(Statement::Analyze(AnalyzeStatement {
+            table_name,
+            columns,
+            sample_size: None,
+        }))
+    }
+
     /// Parse COMPRESS TABLE statement
     /// Syntax: COMPRESS TABLE `table_name` USING `compression_algorithm`
     fn parse_compress_table_statement(&self, tokens: &[TokenType]) -> QSQLResult<Statement> {
@@ -5178,6 +5432,7 @@ impl QSQLParser {
         keywords.insert("SMALLSERIAL".to_string(), TokenType::SmallSerial);
         keywords.insert("AUTO_INCREMENT".to_string(), TokenType::AutoIncrement);
         keywords.insert("AUTOINCREMENT".to_string(), TokenType::AutoIncrement); // SQLite style
+        keywords.insert("ENCRYPTED".to_string(), TokenType::Encrypted);
         keywords.insert("PRIMARY".to_string(), TokenType::Primary);
         keywords.insert("KEY".to_string(), TokenType::Key);
         keywords.insert("UNIQUE".to_string(), TokenType::Unique);
@@ -5188,6 +5443,7 @@ impl QSQLParser {
         keywords.insert("IDENTITY".to_string(), TokenType::Identity);
         keywords.insert("FOREIGN".to_string(), TokenType::Foreign);
         keywords.insert("CONSTRAINT".to_string(), TokenType::Constraint);
+        keywords.insert("CHECK".to_string(), TokenType::Check);
 
         // Date/Time keywords
         keywords.insert("INTERVAL".to_string(), TokenType::Interval);
@@ -6182,6 +6438,7 @@ impl QSQLParser {
             | TokenType::Unique
             | TokenType::Foreign
             | TokenType::Constraint
+            | TokenType::Check
             | TokenType::Algorithm
             | TokenType::Weights
             | TokenType::Features
@@ -6864,4 +7121,4 @@ impl Default for QSQLParser {
 }
 
 /// Simple natural language processor for demo purposes
-use crate::natural_language::NaturalLanguageProcessor;
+use crate::natural_language::{NaturalLanguageProcessor, NlTranslation};
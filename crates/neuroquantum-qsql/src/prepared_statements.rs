@@ -376,7 +376,7 @@ fn count_parameters(statement: &Statement) -> (usize, Vec<String>) {
 }
 
 /// Substitute parameters in a statement with actual values
-fn substitute_parameters(
+pub(crate) fn substitute_parameters(
     statement: &Statement,
     params: &HashMap<ParameterRef, Expression>,
 ) -> QSQLResult<Statement> {
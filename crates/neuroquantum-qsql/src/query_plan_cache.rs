@@ -133,6 +133,10 @@ pub struct QueryPlanCacheConfig {
     pub enable_synaptic_decay: bool,
     /// Synaptic decay rate per eviction cycle (0.0-1.0)
     pub synaptic_decay_rate: f32,
+    /// Plans not accessed within this window decay on every pathway
+    /// optimization pass, see [`QueryPlanCache::decay_stale_pathways`]
+    #[serde(default = "default_synaptic_decay_window")]
+    pub synaptic_decay_window: Duration,
     /// Number of entries to evict when limit is reached
     pub eviction_batch_size: usize,
 }
@@ -145,11 +149,16 @@ impl Default for QueryPlanCacheConfig {
             min_synaptic_threshold: 0.1,
             enable_synaptic_decay: true,
             synaptic_decay_rate: 0.95,
+            synaptic_decay_window: default_synaptic_decay_window(),
             eviction_batch_size: 10,
         }
     }
 }
 
+const fn default_synaptic_decay_window() -> Duration {
+    Duration::from_secs(300)
+}
+
 impl QueryPlanCacheConfig {
     /// Create a config for testing with smaller limits
     #[cfg(test)]
@@ -161,6 +170,7 @@ impl QueryPlanCacheConfig {
             min_synaptic_threshold: 0.1,
             enable_synaptic_decay: true,
             synaptic_decay_rate: 0.9,
+            synaptic_decay_window: Duration::from_secs(1),
             eviction_batch_size: 2,
         }
     }
@@ -364,6 +374,44 @@ impl QueryPlanCache {
         );
     }
 
+    /// Weaken plans not accessed within the decay window and prune the weakest
+    ///
+    /// Stale plans have their synaptic strength multiplied by the decay rate,
+    /// so patterns that stopped occurring lose the strength they gained while
+    /// in use. Plans falling below `min_synaptic_threshold` are evicted, like
+    /// synapses pruned for disuse. Returns the number of evicted plans.
+    pub fn decay_stale_pathways(&mut self) -> usize {
+        if !self.config.enable_synaptic_decay {
+            return 0;
+        }
+
+        let mut pruned = Vec::new();
+        for (query, entry) in &mut self.entries {
+            if entry.last_accessed.elapsed() < self.config.synaptic_decay_window {
+                continue;
+            }
+            entry.synaptic_strength *= self.config.synaptic_decay_rate;
+            if entry.synaptic_strength < self.config.min_synaptic_threshold {
+                pruned.push(query.clone());
+            }
+        }
+
+        for query in &pruned {
+            if let Some(entry) = self.entries.remove(query) {
+                self.current_memory_bytes = self
+                    .current_memory_bytes
+                    .saturating_sub(entry.estimated_size_bytes);
+                self.stats.evictions += 1;
+                debug!(
+                    "Pruned stale cache entry (strength={:.3}): {}",
+                    entry.synaptic_strength,
+                    &query[..query.len().min(50)]
+                );
+            }
+        }
+        pruned.len()
+    }
+
     /// Manually trigger eviction to reduce memory usage to target
     pub fn evict_to_target_memory(&mut self, target_bytes: usize) {
         while self.current_memory_bytes > target_bytes && !self.entries.is_empty() {
@@ -0,0 +1,239 @@
+//! Query Result Cache
+//!
+//! Repeating a SELECT against unchanged data returns the [`QueryResult`] of the
+//! previous execution instead of running the query again. Entries are keyed on
+//! the normalized query text and remember the data version (see
+//! `StorageEngine::table_version`) of every table the query read. A lookup only
+//! hits while all of those versions are unchanged, so any write to a referenced
+//! table invalidates the entry.
+//!
+//! Queries whose result depends on more than the stored data, such as those
+//! calling `NOW()` or `RANDOM()`, are never cached. The cache holds at most
+//! [`QSQLConfig::result_cache_size`](crate::QSQLConfig) results and evicts the
+//! least recently used one when full.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::query_plan::QueryResult;
+
+/// Functions whose result changes between executions on the same data
+pub const NON_DETERMINISTIC_FUNCTIONS: &[&str] = &[
+    "RANDOM",
+    "RAND",
+    "NOW",
+    "CURRENT_TIMESTAMP",
+    "CURRENT_DATE",
+    "CURRENT_TIME",
+    "GETDATE",
+    "SYSDATE",
+    "CURDATE",
+    "CURTIME",
+    "DATE",
+    "LOCALTIME",
+    "LOCALTIMESTAMP",
+    "UTC_DATE",
+    "UTC_TIME",
+    "UTC_TIMESTAMP",
+    "UNIX_TIMESTAMP",
+];
+
+/// Data version of each table read by a cached query
+pub type TableVersions = Vec<(String, u64)>;
+
+/// A cached result with the table versions it was computed from
+#[derive(Debug, Clone)]
+struct CachedResult {
+    result: QueryResult,
+    table_versions: TableVersions,
+    /// Value of the cache's access counter when last inserted or hit
+    last_used: u64,
+}
+
+/// Result cache statistics for monitoring
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResultCacheStatistics {
+    /// Entries dropped because a referenced table changed
+    pub invalidations: u64,
+    /// Entries dropped to make room for new ones
+    pub evictions: u64,
+}
+
+/// Size-bounded cache of query results
+#[derive(Debug)]
+pub struct QueryResultCache {
+    entries: HashMap<String, CachedResult>,
+    /// Maximum number of cached results, 0 disables the cache
+    capacity: usize,
+    /// Incremented on every access to order entries by recency
+    clock: u64,
+    stats: ResultCacheStatistics,
+}
+
+impl QueryResultCache {
+    /// Create a cache holding at most `capacity` results
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::with_capacity(capacity.min(100)),
+            capacity,
+            clock: 0,
+            stats: ResultCacheStatistics::default(),
+        }
+    }
+
+    /// Check if results are cached at all
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    /// Tables whose versions decide whether the entry for `key` is current
+    #[must_use]
+    pub fn tables(&self, key: &str) -> Option<Vec<String>> {
+        self.entries.get(key).map(|entry| {
+            entry
+                .table_versions
+                .iter()
+                .map(|(table, _)| table.clone())
+                .collect()
+        })
+    }
+
+    /// Get the result cached for `key` if it was computed from `table_versions`
+    ///
+    /// An entry computed from other versions is stale and is removed.
+    pub fn get(&mut self, key: &str, table_versions: &[(String, u64)]) -> Option<QueryResult> {
+        let entry = self.entries.get_mut(key)?;
+        if entry.table_versions != table_versions {
+            self.entries.remove(key);
+            self.stats.invalidations += 1;
+            debug!("Result cache entry invalidated: {}", key);
+            return None;
+        }
+        self.clock += 1;
+        entry.last_used = self.clock;
+        Some(entry.result.clone())
+    }
+
+    /// Cache `result` of the query `key`, computed from `table_versions`
+    pub fn insert(&mut self, key: String, table_versions: TableVersions, result: QueryResult) {
+        if !self.is_enabled() {
+            return;
+        }
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            self.evict_least_recently_used();
+        }
+        self.clock += 1;
+        self.entries.insert(
+            key,
+            CachedResult {
+                result,
+                table_versions,
+                last_used: self.clock,
+            },
+        );
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(key, _)| key.clone());
+        if let Some(key) = oldest {
+            self.entries.remove(&key);
+            self.stats.evictions += 1;
+        }
+    }
+
+    /// Remove every cached result
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Get cache statistics
+    #[must_use]
+    pub const fn statistics(&self) -> &ResultCacheStatistics {
+        &self.stats
+    }
+
+    /// Get current number of cached results
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if no results are cached
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Normalize `query` into a cache key
+///
+/// Runs of whitespace collapse into a single space and a trailing semicolon is
+/// dropped. Quoted strings and identifiers are kept as written, so queries
+/// that only differ inside a literal get different keys.
+#[must_use]
+pub fn normalize_query(query: &str) -> String {
+    let mut normalized = String::with_capacity(query.len());
+    let mut quote = None;
+    let mut pending_space = false;
+    for c in query.trim().trim_end_matches(';').trim_end().chars() {
+        match quote {
+            | Some(q) => {
+                normalized.push(c);
+                if c == q {
+                    quote = None;
+                }
+            },
+            | None if c.is_whitespace() => pending_space = true,
+            | None => {
+                if pending_space {
+                    normalized.push(' ');
+                    pending_space = false;
+                }
+                if c == '\'' || c == '"' {
+                    quote = Some(c);
+                }
+                normalized.push(c);
+            },
+        }
+    }
+    normalized
+}
+
+/// Check if `query` may call a function from [`NON_DETERMINISTIC_FUNCTIONS`]
+///
+/// This looks at words outside string literals, so it errs on the side of not
+/// caching when a column happens to share a function's name.
+#[must_use]
+pub fn calls_non_deterministic_function(query: &str) -> bool {
+    let mut in_literal = false;
+    let mut word = String::new();
+    let mut found = false;
+    let mut check = |word: &mut String| {
+        if !word.is_empty() {
+            found |= NON_DETERMINISTIC_FUNCTIONS
+                .iter()
+                .any(|name| word.eq_ignore_ascii_case(name));
+            word.clear();
+        }
+    };
+    for c in query.chars() {
+        if in_literal {
+            in_literal = c != '\'';
+        } else if c.is_alphanumeric() || c == '_' {
+            word.push(c);
+        } else {
+            check(&mut word);
+            in_literal = c == '\'';
+        }
+    }
+    check(&mut word);
+    found
+}
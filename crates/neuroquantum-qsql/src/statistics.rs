@@ -0,0 +1,182 @@
+//! Table statistics for cost-based optimization
+//!
+//! `ANALYZE table` scans the table with a [`StatisticsCollector`] and stores the
+//! resulting [`TableStatistics`] alongside the table schema in the storage
+//! engine. The [`NeuromorphicOptimizer`](crate::optimizer::NeuromorphicOptimizer)
+//! reads them back to estimate predicate selectivity and join input sizes.
+//!
+//! For every column the collector records the NULL fraction, the number of
+//! distinct values, the most common values with their frequencies and an
+//! equi-depth histogram over the remaining values.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use neuroquantum_core::storage::{ColumnStatistics, Row, StorageEngine, TableStatistics, Value};
+
+/// Default number of most common values kept per column
+pub const DEFAULT_MOST_COMMON_VALUES: usize = 10;
+
+/// Default number of histogram buckets per column
+pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 20;
+
+/// Default row-count drift, as a fraction of the analyzed row count, after
+/// which statistics are considered stale
+pub const DEFAULT_STALENESS_THRESHOLD: f64 = 0.2;
+
+/// Builds [`TableStatistics`] from a full scan of a table
+#[derive(Debug, Clone)]
+pub struct StatisticsCollector {
+    most_common_values: usize,
+    histogram_buckets: usize,
+}
+
+impl Default for StatisticsCollector {
+    fn default() -> Self {
+        Self {
+            most_common_values: DEFAULT_MOST_COMMON_VALUES,
+            histogram_buckets: DEFAULT_HISTOGRAM_BUCKETS,
+        }
+    }
+}
+
+impl StatisticsCollector {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collector keeping at most `most_common_values` frequent values and
+    /// `histogram_buckets` histogram buckets per column
+    #[must_use]
+    pub fn with_limits(most_common_values: usize, histogram_buckets: usize) -> Self {
+        Self {
+            most_common_values,
+            histogram_buckets: histogram_buckets.max(1),
+        }
+    }
+
+    /// Statistics for `columns` over `rows`; rows missing a column count as NULL
+    #[must_use]
+    pub fn collect(&self, rows: &[Row], columns: &[String]) -> TableStatistics {
+        let columns = columns
+            .iter()
+            .map(|column| (column.clone(), self.column_statistics(rows, column)))
+            .collect::<HashMap<_, _>>();
+
+        TableStatistics {
+            row_count: rows.len() as u64,
+            columns,
+            analyzed_at: chrono::Utc::now(),
+        }
+    }
+
+    fn column_statistics(&self, rows: &[Row], column: &str) -> ColumnStatistics {
+        let mut values: Vec<&Value> = rows
+            .iter()
+            .filter_map(|row| row.fields.get(column))
+            .filter(|value| !matches!(value, Value::Null))
+            .collect();
+        values.sort_by(|a, b| compare_values(a, b));
+
+        let total = rows.len().max(1) as f64;
+        let null_fraction = (rows.len() - values.len()) as f64 / total;
+
+        // Run-length encode the sorted values into (value, count) groups
+        let mut groups: Vec<(&Value, usize)> = Vec::new();
+        for &value in &values {
+            match groups.last_mut() {
+                | Some((last, count)) if compare_values(last, value).is_eq() => {
+                    *count += 1;
+                },
+                | _ => groups.push((value, 1)),
+            }
+        }
+        let distinct_count = groups.len() as u64;
+
+        // Values occurring more than once, most frequent first
+        let mut frequent: Vec<(&Value, usize)> = groups
+            .iter()
+            .copied()
+            .filter(|(_, count)| *count > 1)
+            .collect();
+        frequent.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| compare_values(a.0, b.0)));
+        frequent.truncate(self.most_common_values);
+
+        let most_common_values = frequent
+            .iter()
+            .map(|(value, count)| ((*value).clone(), *count as f64 / total))
+            .collect();
+
+        // Histogram over the values not covered by the most common values
+        let remaining: Vec<&Value> = values
+            .iter()
+            .copied()
+            .filter(|value| {
+                !frequent
+                    .iter()
+                    .any(|(common, _)| compare_values(common, value).is_eq())
+            })
+            .collect();
+        let histogram_bounds = self.histogram_bounds(&remaining);
+
+        ColumnStatistics {
+            null_fraction,
+            distinct_count,
+            most_common_values,
+            histogram_bounds,
+        }
+    }
+
+    /// Equi-depth bucket bounds over sorted `values`
+    fn histogram_bounds(&self, values: &[&Value]) -> Vec<Value> {
+        if values.len() < 2 {
+            return values.iter().map(|value| (*value).clone()).collect();
+        }
+        let buckets = self.histogram_buckets.min(values.len() - 1);
+        let last = values.len() - 1;
+        (0..=buckets)
+            .map(|bucket| values[bucket * last / buckets].clone())
+            .collect()
+    }
+}
+
+/// Statistics stored for `table`, unless its row count has drifted by more
+/// than [`DEFAULT_STALENESS_THRESHOLD`] since it was analyzed
+#[must_use]
+pub fn fresh_statistics<'a>(
+    storage: &'a StorageEngine,
+    table: &str,
+) -> Option<&'a TableStatistics> {
+    let statistics = storage.get_table_statistics(table)?;
+    let row_count = storage.get_table_row_count(table)? as u64;
+    (!statistics.is_stale(row_count, DEFAULT_STALENESS_THRESHOLD)).then_some(statistics)
+}
+
+/// Total order over storage values: numbers compare numerically, other values
+/// within their own type, and values of different types by type
+#[must_use]
+pub fn compare_values(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        | (Value::Integer(a), Value::Integer(b)) => a.cmp(b),
+        | (Value::Float(a), Value::Float(b)) => a.total_cmp(b),
+        | (Value::Integer(a), Value::Float(b)) => (*a as f64).total_cmp(b),
+        | (Value::Float(a), Value::Integer(b)) => a.total_cmp(&(*b as f64)),
+        | (Value::Text(a), Value::Text(b)) => a.cmp(b),
+        | (Value::Boolean(a), Value::Boolean(b)) => a.cmp(b),
+        | (Value::Timestamp(a), Value::Timestamp(b)) => a.cmp(b),
+        | (Value::Binary(a), Value::Binary(b)) => a.cmp(b),
+        | _ => type_rank(a).cmp(&type_rank(b)),
+    }
+}
+
+const fn type_rank(value: &Value) -> u8 {
+    match value {
+        | Value::Null => 0,
+        | Value::Boolean(_) => 1,
+        | Value::Integer(_) | Value::Float(_) => 2,
+        | Value::Text(_) => 3,
+        | Value::Timestamp(_) => 4,
+        | Value::Binary(_) => 5,
+    }
+}
@@ -0,0 +1,266 @@
+//! Tables accessed by a statement
+//!
+//! [`table_accesses`] walks a parsed [`Statement`] and reports every table it
+//! reads or writes, including tables referenced from joins, derived tables,
+//! CTEs, UNIONs and subqueries. Callers use it to authorize a statement before
+//! it is executed, e.g. against per-table API key scopes.
+//!
+//! Statements that only name an index or a prepared statement (`DROP INDEX`,
+//! `SYNAPTIC_OPTIMIZE`, `EXECUTE`, ...) do not report the underlying tables;
+//! use [`names_tables_indirectly`] to detect them.
+
+use crate::ast::{Expression, FromClause, SelectItem, SelectStatement, Statement};
+
+/// How a statement uses a table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AccessKind {
+    /// Rows are only read
+    Read,
+    /// Rows or the table definition are modified
+    Write,
+}
+
+/// A table read or written by a statement
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableAccess {
+    pub table: String,
+    pub kind: AccessKind,
+}
+
+/// Every table `statement` reads or writes, without duplicates
+#[must_use]
+pub fn table_accesses(statement: &Statement) -> Vec<TableAccess> {
+    let mut collector = Collector::default();
+    collector.statement(statement);
+    collector.accesses
+}
+
+/// Whether `statement` refers to tables only through another object (an index
+/// or prepared statement), so [`table_accesses`] cannot list them
+#[must_use]
+pub fn names_tables_indirectly(statement: &Statement) -> bool {
+    match statement {
+        | Statement::DropIndex(_) | Statement::SynapticOptimize(_) | Statement::Execute(_) => true,
+        | Statement::Explain(explain) => names_tables_indirectly(&explain.statement),
+        | Statement::SuperpositionQuery(query) => {
+            query.parallel_queries.iter().any(names_tables_indirectly)
+        },
+        | _ => false,
+    }
+}
+
+#[derive(Default)]
+struct Collector {
+    accesses: Vec<TableAccess>,
+    /// CTE names in scope; references to them are not tables
+    ctes: Vec<String>,
+}
+
+impl Collector {
+    fn record(&mut self, table: &str, kind: AccessKind) {
+        if table.is_empty() || self.ctes.iter().any(|cte| cte.eq_ignore_ascii_case(table)) {
+            return;
+        }
+        let access = TableAccess {
+            table: table.to_string(),
+            kind,
+        };
+        if !self.accesses.contains(&access) {
+            self.accesses.push(access);
+        }
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        match statement {
+            | Statement::Select(select) => self.select(select),
+            | Statement::Insert(insert) => {
+                self.record(&insert.table_name, AccessKind::Write);
+                for expr in insert.values.iter().flatten() {
+                    self.expression(expr);
+                }
+            },
+            | Statement::Update(update) => {
+                self.record(&update.table_name, AccessKind::Write);
+                for assignment in &update.assignments {
+                    self.expression(&assignment.value);
+                }
+                self.optional_expression(update.where_clause.as_ref());
+            },
+            | Statement::Delete(delete) => {
+                self.record(&delete.table_name, AccessKind::Write);
+                self.optional_expression(delete.where_clause.as_ref());
+            },
+            | Statement::CreateTable(create) => self.record(&create.table_name, AccessKind::Write),
+            | Statement::DropTable(drop) => self.record(&drop.table_name, AccessKind::Write),
+            | Statement::AlterTable(alter) => self.record(&alter.table_name, AccessKind::Write),
+            | Statement::CreateIndex(index) => self.record(&index.table_name, AccessKind::Write),
+            | Statement::TruncateTable(truncate) => {
+                self.record(&truncate.table_name, AccessKind::Write);
+            },
+            | Statement::CompressTable(compress) => {
+                self.record(&compress.table_name, AccessKind::Write);
+            },
+            | Statement::NeuroMatch(neuro_match) => {
+                self.record(&neuro_match.target_table, AccessKind::Read);
+                self.expression(&neuro_match.pattern_expression);
+            },
+            | Statement::LearnPattern(learn) => {
+                self.record(&learn.target_table, AccessKind::Write);
+                self.optional_expression(learn.pattern_expression.as_ref());
+            },
+            | Statement::AdaptWeights(adapt) => {
+                self.record(&adapt.target_table, AccessKind::Write);
+                self.optional_expression(adapt.weight_expression.as_ref());
+            },
+            | Statement::QuantumSearch(search) => {
+                self.record(&search.target_table, AccessKind::Read);
+                self.expression(&search.search_expression);
+            },
+            | Statement::SuperpositionQuery(query) => {
+                for parallel in &query.parallel_queries {
+                    self.statement(parallel);
+                }
+            },
+            | Statement::QuantumJoin(join) => {
+                self.record(&join.left_table, AccessKind::Read);
+                self.record(&join.right_table, AccessKind::Read);
+                self.optional_expression(join.on_condition.as_ref());
+            },
+            | Statement::Explain(explain) => self.statement(&explain.statement),
+            | Statement::Analyze(analyze) => self.record(&analyze.table_name, AccessKind::Read),
+            | Statement::Prepare(prepare) => self.statement(&prepare.statement),
+            | Statement::DropIndex(_)
+            | Statement::SynapticOptimize(_)
+            | Statement::BeginTransaction(_)
+            | Statement::Commit(_)
+            | Statement::Rollback(_)
+            | Statement::Savepoint(_)
+            | Statement::RollbackToSavepoint(_)
+            | Statement::ReleaseSavepoint(_)
+            | Statement::Execute(_)
+            | Statement::Deallocate(_) => {},
+        }
+    }
+
+    fn select(&mut self, select: &SelectStatement) {
+        let scope = self.ctes.len();
+        if let Some(with) = &select.with_clause {
+            for cte in &with.ctes {
+                // A recursive CTE may reference itself
+                if with.recursive {
+                    self.ctes.push(cte.name.clone());
+                }
+                self.select(&cte.query);
+                if !with.recursive {
+                    self.ctes.push(cte.name.clone());
+                }
+            }
+        }
+
+        for item in &select.select_list {
+            if let SelectItem::Expression { expr, .. } = item {
+                self.expression(expr);
+            }
+        }
+        if let Some(from) = &select.from {
+            self.from(from);
+        }
+        self.optional_expression(select.where_clause.as_ref());
+        for expr in &select.group_by {
+            self.expression(expr);
+        }
+        self.optional_expression(select.having.as_ref());
+        for item in &select.order_by {
+            self.expression(&item.expression);
+        }
+        if let Some(union) = &select.union_clause {
+            self.select(&union.select);
+        }
+
+        self.ctes.truncate(scope);
+    }
+
+    fn from(&mut self, from: &FromClause) {
+        let relations = from
+            .relations
+            .iter()
+            .chain(from.joins.iter().map(|join| &join.relation));
+        for relation in relations {
+            match &relation.subquery {
+                | Some(subquery) => self.select(subquery),
+                | None => self.record(&relation.name, AccessKind::Read),
+            }
+        }
+        for join in &from.joins {
+            self.optional_expression(join.condition.as_ref());
+        }
+    }
+
+    fn optional_expression(&mut self, expr: Option<&Expression>) {
+        if let Some(expr) = expr {
+            self.expression(expr);
+        }
+    }
+
+    fn expression(&mut self, expr: &Expression) {
+        match expr {
+            | Expression::BinaryOp { left, right, .. } => {
+                self.expression(left);
+                self.expression(right);
+            },
+            | Expression::UnaryOp { operand, .. } => self.expression(operand),
+            | Expression::FunctionCall { args, .. }
+            | Expression::QuantumSuperposition { states: args } => {
+                for arg in args {
+                    self.expression(arg);
+                }
+            },
+            | Expression::WindowFunction {
+                args, over_clause, ..
+            } => {
+                let order_by = over_clause.order_by.iter().map(|item| &item.expression);
+                for expr in args.iter().chain(&over_clause.partition_by).chain(order_by) {
+                    self.expression(expr);
+                }
+            },
+            | Expression::Subquery(statement) => self.statement(statement),
+            | Expression::SynapticActivation { source: inner, .. }
+            | Expression::SynapticMatch { pattern: inner, .. }
+            | Expression::PlasticityFunction { input: inner, .. }
+            | Expression::QuantumMeasurement { target: inner, .. }
+            | Expression::AmplitudeAmplification { target: inner, .. }
+            | Expression::Extract { source: inner, .. }
+            | Expression::IsNull { expr: inner, .. } => self.expression(inner),
+            | Expression::InList { expr, list, .. } => {
+                self.expression(expr);
+                for item in list {
+                    self.expression(item);
+                }
+            },
+            | Expression::InSubquery { expr, subquery, .. } => {
+                self.expression(expr);
+                self.select(subquery);
+            },
+            | Expression::Exists { subquery, .. } | Expression::ScalarSubquery { subquery } => {
+                self.select(subquery);
+            },
+            | Expression::Case {
+                when_clauses,
+                else_result,
+            } => {
+                for (condition, result) in when_clauses {
+                    self.expression(condition);
+                    self.expression(result);
+                }
+                if let Some(else_result) = else_result {
+                    self.expression(else_result);
+                }
+            },
+            | Expression::Literal(_)
+            | Expression::Identifier(_)
+            | Expression::NeuroPattern { .. }
+            | Expression::Parameter(_)
+            | Expression::Default => {},
+        }
+    }
+}
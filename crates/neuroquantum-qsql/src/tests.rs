@@ -1162,6 +1162,7 @@ mod optimizer_tests {
             grover_iterations: None,
             with_clause: None,
             union_clause: None,
+            hints: Vec::new(),
         });
 
         let optimized = optimizer.optimize(statement);
@@ -1231,6 +1232,7 @@ mod optimizer_tests {
             grover_iterations: None,
             with_clause: None,
             union_clause: None,
+            hints: Vec::new(),
         });
 
         // Optimizer should handle the statement
@@ -1268,6 +1270,7 @@ mod optimizer_tests {
             grover_iterations: None,
             with_clause: None,
             union_clause: None,
+            hints: Vec::new(),
         });
 
         // Test that optimizer can handle the statement
@@ -1316,6 +1319,7 @@ mod executor_tests {
                 grover_iterations: None,
                 with_clause: None,
                 union_clause: None,
+                hints: Vec::new(),
             })),
             execution_strategy: ExecutionStrategy::Sequential,
             synaptic_pathways: vec![],
@@ -1365,6 +1369,7 @@ mod executor_tests {
                 grover_iterations: None,
                 with_clause: None,
                 union_clause: None,
+                hints: Vec::new(),
             })),
             execution_strategy: ExecutionStrategy::Sequential,
             synaptic_pathways: vec![],
@@ -1414,6 +1419,7 @@ mod executor_tests {
                 grover_iterations: None,
                 with_clause: None,
                 union_clause: None,
+                hints: Vec::new(),
             })),
             execution_strategy: ExecutionStrategy::Sequential,
             synaptic_pathways: vec![],
@@ -1873,6 +1879,7 @@ mod property_tests {
                 grover_iterations: None,
                 with_clause: None,
                 union_clause: None,
+                hints: Vec::new(),
             })),
             execution_strategy: ExecutionStrategy::Sequential,
             synaptic_pathways: vec![],
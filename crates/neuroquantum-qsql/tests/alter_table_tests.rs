@@ -35,6 +35,7 @@ async fn setup_test_env() -> (
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -42,6 +43,7 @@ async fn setup_test_env() -> (
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "age".to_string(),
@@ -49,6 +51,7 @@ async fn setup_test_env() -> (
                 nullable: true,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -57,6 +60,7 @@ async fn setup_test_env() -> (
         auto_increment_columns: std::collections::HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -368,3 +372,49 @@ async fn test_alter_table_add_duplicate_column() {
     let result = executor.execute_statement(&statement).await;
     assert!(result.is_err(), "Should fail for duplicate column");
 }
+
+#[tokio::test]
+async fn test_alter_table_add_not_null_column_requires_default() {
+    let (_temp_dir, storage_arc, mut executor) = setup_test_env().await;
+    let parser = Parser::new();
+
+    // Existing rows would have no value for the column
+    let sql = "ALTER TABLE users ADD COLUMN email TEXT NOT NULL";
+    let statement = parser.parse(sql).unwrap();
+    let result = executor.execute_statement(&statement).await;
+    assert!(result.is_err(), "Should require a default");
+
+    let storage_guard = storage_arc.read().await;
+    let schema = storage_guard.get_table_schema("users").unwrap();
+    assert!(!schema.columns.iter().any(|c| c.name == "email"));
+    drop(storage_guard);
+
+    let sql = "ALTER TABLE users ADD COLUMN email TEXT NOT NULL DEFAULT 'none'";
+    let statement = parser.parse(sql).unwrap();
+    let result = executor.execute_statement(&statement).await;
+    assert!(result.is_ok(), "ALTER TABLE ADD COLUMN failed: {result:?}");
+}
+
+#[tokio::test]
+async fn test_alter_table_leaves_other_tables_untouched() {
+    let (_temp_dir, _storage_arc, mut executor) = setup_test_env().await;
+    let parser = Parser::new();
+
+    for sql in [
+        "CREATE TABLE orders (id INTEGER PRIMARY KEY, amount INTEGER)",
+        "INSERT INTO orders (id, amount) VALUES (1, 100)",
+        "ALTER TABLE users ADD COLUMN email TEXT DEFAULT 'unknown'",
+    ] {
+        let statement = parser.parse(sql).unwrap();
+        executor.execute_statement(&statement).await.unwrap();
+    }
+
+    let statement = parser.parse("SELECT * FROM users").unwrap();
+    let result = executor.execute_statement(&statement).await.unwrap();
+    assert_eq!(result.rows.len(), 3, "Only the users rows are rewritten");
+
+    let statement = parser.parse("SELECT * FROM orders").unwrap();
+    let result = executor.execute_statement(&statement).await.unwrap();
+    assert_eq!(result.rows.len(), 1);
+    assert!(!result.rows[0].contains_key("email"));
+}
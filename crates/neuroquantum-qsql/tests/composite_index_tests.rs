@@ -0,0 +1,315 @@
+//! Tests for multi-column secondary indexes created with `CREATE INDEX`
+//!
+//! `orders` spreads rows over four regions with distinct amounts, so a
+//! `(region, amount)` index answers `region = ? AND amount > ?` with a prefix
+//! range scan. A query reading only indexed columns is answered from the
+//! index entries without fetching table rows.
+
+use std::sync::Arc;
+
+use neuroquantum_core::storage::{
+    ComparisonOperator, Condition, SelectQuery, StorageEngine, Value, WhereClause,
+};
+use neuroquantum_qsql::query_plan::{QueryResult, QueryValue};
+use neuroquantum_qsql::{ExecutorConfig, Parser, QueryExecutor};
+use tempfile::TempDir;
+
+const REGIONS: [&str; 4] = ["eu", "us", "apac", "latam"];
+const INDEX: &str = "idx_orders_region_amount";
+
+async fn setup_orders() -> (
+    TempDir,
+    Arc<tokio::sync::RwLock<StorageEngine>>,
+    QueryExecutor,
+) {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+    let storage_arc = Arc::new(tokio::sync::RwLock::new(storage));
+    let mut executor =
+        QueryExecutor::with_storage(ExecutorConfig::default(), storage_arc.clone()).unwrap();
+
+    execute(
+        &mut executor,
+        "CREATE TABLE orders (id INTEGER PRIMARY KEY, region TEXT, amount INTEGER)",
+    )
+    .await
+    .unwrap();
+    for id in 1..=40 {
+        let region = REGIONS[(id % 4) as usize];
+        let amount = id * 10;
+        execute(
+            &mut executor,
+            &format!("INSERT INTO orders (id, region, amount) VALUES ({id}, '{region}', {amount})"),
+        )
+        .await
+        .unwrap();
+    }
+
+    (temp_dir, storage_arc, executor)
+}
+
+async fn execute(
+    executor: &mut QueryExecutor,
+    sql: &str,
+) -> neuroquantum_qsql::error::QSQLResult<QueryResult> {
+    let statement = Parser::new().parse(sql).unwrap();
+    executor.execute_statement(&statement).await
+}
+
+fn ids(result: &QueryResult) -> Vec<i64> {
+    let mut ids: Vec<i64> = result
+        .rows
+        .iter()
+        .map(|row| match row.get("id") {
+            | Some(QueryValue::Integer(id)) => *id,
+            | other => panic!("unexpected id {other:?}"),
+        })
+        .collect();
+    ids.sort_unstable();
+    ids
+}
+
+fn region_amount_query(region: &str, operator: ComparisonOperator, amount: i64) -> SelectQuery {
+    SelectQuery {
+        table: "orders".to_string(),
+        columns: vec!["*".to_string()],
+        where_clause: Some(WhereClause {
+            conditions: vec![
+                Condition {
+                    field: "amount".to_string(),
+                    operator,
+                    value: Value::Integer(amount),
+                },
+                Condition {
+                    field: "region".to_string(),
+                    operator: ComparisonOperator::Equal,
+                    value: Value::text(region),
+                },
+            ],
+        }),
+        order_by: None,
+        limit: None,
+        offset: None,
+    }
+}
+
+#[tokio::test]
+async fn test_composite_index_prefix_lookup() {
+    let (_temp_dir, storage_arc, mut executor) = setup_orders().await;
+    execute(
+        &mut executor,
+        &format!("CREATE INDEX {INDEX} ON orders (region, amount)"),
+    )
+    .await
+    .unwrap();
+
+    let definition = storage_arc
+        .read()
+        .await
+        .get_index_definition(INDEX)
+        .cloned()
+        .unwrap();
+    assert_eq!(definition.columns, vec!["region", "amount"]);
+    assert!(!definition.unique);
+
+    // Only the five matching 'eu' rows are examined
+    {
+        let storage = storage_arc.read().await;
+        let query = region_amount_query("eu", ComparisonOperator::GreaterThan, 200);
+        let (rows, stats) = storage.select_rows_with_stats(&query).await.unwrap();
+        assert_eq!(rows.len(), 5);
+        assert!(stats.index_scan);
+        assert!(stats.indexes_used.contains(&INDEX.to_string()));
+        assert_eq!(stats.rows_examined, 5);
+
+        let query = region_amount_query("eu", ComparisonOperator::LessThanOrEqual, 200);
+        let (rows, stats) = storage.select_rows_with_stats(&query).await.unwrap();
+        assert_eq!(rows.len(), 5);
+        assert_eq!(stats.rows_examined, 5);
+    }
+
+    let result = execute(
+        &mut executor,
+        "SELECT * FROM orders WHERE region = 'eu' AND amount > 200",
+    )
+    .await
+    .unwrap();
+    assert_eq!(ids(&result), vec![24, 28, 32, 36, 40]);
+
+    // A condition on the second column alone cannot use the index
+    let storage = storage_arc.read().await;
+    let mut query = region_amount_query("eu", ComparisonOperator::GreaterThan, 200);
+    query.where_clause.as_mut().unwrap().conditions.pop();
+    let (rows, stats) = storage.select_rows_with_stats(&query).await.unwrap();
+    assert_eq!(rows.len(), 20);
+    assert!(!stats.index_scan);
+}
+
+#[tokio::test]
+async fn test_composite_index_follows_row_changes() {
+    let (temp_dir, storage_arc, mut executor) = setup_orders().await;
+    execute(
+        &mut executor,
+        &format!("CREATE INDEX {INDEX} ON orders (region, amount)"),
+    )
+    .await
+    .unwrap();
+
+    execute(
+        &mut executor,
+        "INSERT INTO orders (id, region, amount) VALUES (41, 'eu', 1000)",
+    )
+    .await
+    .unwrap();
+    execute(
+        &mut executor,
+        "UPDATE orders SET region = 'eu' WHERE id = 39",
+    )
+    .await
+    .unwrap();
+    execute(&mut executor, "DELETE FROM orders WHERE id = 40")
+        .await
+        .unwrap();
+
+    let result = execute(
+        &mut executor,
+        "SELECT * FROM orders WHERE region = 'eu' AND amount >= 360",
+    )
+    .await
+    .unwrap();
+    assert_eq!(ids(&result), vec![36, 39, 41]);
+
+    // The index is rebuilt when the database is reopened
+    storage_arc.write().await.flush_to_disk().await.unwrap();
+    let reopened = StorageEngine::new(temp_dir.path()).await.unwrap();
+    assert_eq!(reopened.get_table_indexes("orders").len(), 1);
+    let query = region_amount_query("eu", ComparisonOperator::GreaterThanOrEqual, 360);
+    let (rows, stats) = reopened.select_rows_with_stats(&query).await.unwrap();
+    assert_eq!(rows.len(), 3);
+    assert!(stats.index_scan);
+}
+
+#[tokio::test]
+async fn test_unique_composite_index_and_drop() {
+    let (_temp_dir, storage_arc, mut executor) = setup_orders().await;
+    execute(
+        &mut executor,
+        "CREATE UNIQUE INDEX idx_orders_unique ON orders (region, amount)",
+    )
+    .await
+    .unwrap();
+
+    let duplicate = execute(
+        &mut executor,
+        "INSERT INTO orders (id, region, amount) VALUES (41, 'eu', 400)",
+    )
+    .await;
+    assert!(duplicate.is_err());
+    execute(
+        &mut executor,
+        "INSERT INTO orders (id, region, amount) VALUES (41, 'us', 400)",
+    )
+    .await
+    .unwrap();
+
+    // Existing duplicates prevent creating a unique index
+    let result = execute(
+        &mut executor,
+        "CREATE UNIQUE INDEX idx_orders_amount ON orders (amount)",
+    )
+    .await;
+    assert!(result.is_err());
+    assert!(execute(
+        &mut executor,
+        "CREATE INDEX idx_orders_missing ON orders (region, missing)",
+    )
+    .await
+    .is_err());
+
+    execute(
+        &mut executor,
+        "CREATE UNIQUE INDEX IF NOT EXISTS idx_orders_unique ON orders (region, amount)",
+    )
+    .await
+    .unwrap();
+    execute(&mut executor, "DROP INDEX idx_orders_unique")
+        .await
+        .unwrap();
+    assert!(storage_arc
+        .read()
+        .await
+        .get_table_indexes("orders")
+        .is_empty());
+    assert!(execute(&mut executor, "DROP INDEX idx_orders_unique")
+        .await
+        .is_err());
+    execute(&mut executor, "DROP INDEX IF EXISTS idx_orders_unique")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_covering_index_skips_row_fetches() {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+    let storage_arc = Arc::new(tokio::sync::RwLock::new(storage));
+    let mut executor =
+        QueryExecutor::with_storage(ExecutorConfig::default(), storage_arc.clone()).unwrap();
+
+    execute(
+        &mut executor,
+        "CREATE TABLE t (id INTEGER PRIMARY KEY, name TEXT, note TEXT)",
+    )
+    .await
+    .unwrap();
+    for id in 1..=5 {
+        execute(
+            &mut executor,
+            &format!("INSERT INTO t (id, name, note) VALUES ({id}, 'name {id}', 'note {id}')"),
+        )
+        .await
+        .unwrap();
+    }
+    execute(&mut executor, "CREATE INDEX idx_t_id_name ON t (id, name)")
+        .await
+        .unwrap();
+
+    let result = execute(&mut executor, "SELECT name FROM t WHERE id = 3")
+        .await
+        .unwrap();
+    assert_eq!(result.rows.len(), 1);
+    assert_eq!(
+        result.rows[0].get("name"),
+        Some(&QueryValue::String("name 3".to_string()))
+    );
+
+    let plan = |result: QueryResult| -> serde_json::Value {
+        let Some(QueryValue::String(json)) = result.rows[0].get("QUERY PLAN") else {
+            panic!("expected a JSON plan");
+        };
+        serde_json::from_str::<serde_json::Value>(json).unwrap()["plan_nodes"][0].clone()
+    };
+
+    let result = execute(
+        &mut executor,
+        "EXPLAIN (ANALYZE, FORMAT JSON) SELECT name FROM t WHERE id = 3",
+    )
+    .await
+    .unwrap();
+    let node = plan(result);
+    assert_eq!(node["node_type"], "IndexOnlyScan");
+    assert_eq!(node["index_name"], "idx_t_id_name");
+    assert_eq!(node["actual_rows"], 1);
+    assert_eq!(node["heap_fetches"], 0);
+
+    // Selecting a column outside the index needs the table row
+    let result = execute(
+        &mut executor,
+        "EXPLAIN (ANALYZE, FORMAT JSON) SELECT note FROM t WHERE id = 3",
+    )
+    .await
+    .unwrap();
+    let node = plan(result);
+    assert_eq!(node["node_type"], "IndexScan");
+    assert_eq!(node["heap_fetches"], 1);
+}
@@ -35,6 +35,7 @@ async fn setup_test_env() -> (
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "message".to_string(),
@@ -42,6 +43,7 @@ async fn setup_test_env() -> (
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "severity".to_string(),
@@ -49,6 +51,7 @@ async fn setup_test_env() -> (
                 nullable: true,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -57,6 +60,7 @@ async fn setup_test_env() -> (
         auto_increment_columns: std::collections::HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -285,6 +289,7 @@ async fn test_compress_table_empty_table() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "message".to_string(),
@@ -292,6 +297,7 @@ async fn test_compress_table_empty_table() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -300,6 +306,7 @@ async fn test_compress_table_empty_table() {
         auto_increment_columns: std::collections::HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
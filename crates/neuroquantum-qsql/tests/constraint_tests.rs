@@ -0,0 +1,167 @@
+//! Tests for NOT NULL, UNIQUE and CHECK constraint enforcement
+//!
+//! Violations are rejected on INSERT and UPDATE with an error naming the
+//! violated constraint, and leave the table unchanged.
+
+use std::sync::Arc;
+
+use neuroquantum_core::storage::StorageEngine;
+use neuroquantum_qsql::QSQLEngine;
+use tempfile::TempDir;
+use tokio::sync::RwLock;
+
+async fn setup() -> (TempDir, Arc<RwLock<StorageEngine>>, QSQLEngine) {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+    let storage_arc = Arc::new(RwLock::new(storage));
+    let mut engine = QSQLEngine::with_storage(storage_arc.clone()).unwrap();
+    for sql in [
+        "CREATE TABLE products (\
+         id INTEGER PRIMARY KEY, \
+         sku TEXT NOT NULL UNIQUE, \
+         name TEXT NOT NULL, \
+         price INTEGER CHECK (price > 0), \
+         stock INTEGER, \
+         CHECK (stock <= 100))",
+        "INSERT INTO products (id, sku, name, price, stock) VALUES (1, 'A-1', 'Anvil', 50, 3)",
+    ] {
+        engine.execute_query(sql).await.unwrap();
+    }
+    (temp_dir, storage_arc, engine)
+}
+
+async fn row_count(engine: &mut QSQLEngine) -> usize {
+    engine
+        .execute_query("SELECT id FROM products")
+        .await
+        .unwrap()
+        .rows
+        .len()
+}
+
+#[tokio::test]
+async fn test_null_into_not_null_column_is_rejected() {
+    let (_dir, _storage, mut engine) = setup().await;
+
+    let err = engine
+        .execute_query("INSERT INTO products (id, sku, name, price) VALUES (2, 'B-2', NULL, 10)")
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("NOT NULL constraint violated: column 'name' of table 'products'"),
+        "unexpected error: {err}"
+    );
+
+    // Leaving the column out is the same violation
+    let err = engine
+        .execute_query("INSERT INTO products (id, sku, price) VALUES (2, 'B-2', 10)")
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("NOT NULL constraint violated: column 'name'"),
+        "unexpected error: {err}"
+    );
+
+    let err = engine
+        .execute_query("UPDATE products SET name = NULL WHERE id = 1")
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("NOT NULL constraint violated: column 'name'"),
+        "unexpected error: {err}"
+    );
+    assert_eq!(row_count(&mut engine).await, 1);
+}
+
+#[tokio::test]
+async fn test_duplicate_into_unique_column_is_rejected() {
+    let (_dir, storage, mut engine) = setup().await;
+
+    // The constraint is backed by a unique index
+    let index = storage
+        .read()
+        .await
+        .get_table_indexes("products")
+        .into_iter()
+        .find(|index| index.name == "products_sku_key")
+        .cloned()
+        .expect("UNIQUE should create an index");
+    assert!(index.unique);
+    assert_eq!(index.columns, vec!["sku".to_string()]);
+
+    let err = engine
+        .execute_query("INSERT INTO products (id, sku, name, price) VALUES (2, 'A-1', 'Axe', 20)")
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("UNIQUE constraint 'products_sku_key' violated: duplicate value for (sku)"),
+        "unexpected error: {err}"
+    );
+    assert_eq!(row_count(&mut engine).await, 1);
+
+    engine
+        .execute_query("INSERT INTO products (id, sku, name, price) VALUES (2, 'B-2', 'Axe', 20)")
+        .await
+        .unwrap();
+    let err = engine
+        .execute_query("UPDATE products SET sku = 'A-1' WHERE id = 2")
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("UNIQUE constraint 'products_sku_key' violated"),
+        "unexpected error: {err}"
+    );
+}
+
+#[tokio::test]
+async fn test_check_violation_is_rejected() {
+    let (_dir, _storage, mut engine) = setup().await;
+
+    let err = engine
+        .execute_query("INSERT INTO products (id, sku, name, price) VALUES (2, 'B-2', 'Axe', 0)")
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("CHECK constraint 'products_price_check' violated: price > 0"),
+        "unexpected error: {err}"
+    );
+
+    let err = engine
+        .execute_query("UPDATE products SET stock = 500 WHERE id = 1")
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("CHECK constraint 'products_stock_check' violated: stock <= 100"),
+        "unexpected error: {err}"
+    );
+    assert_eq!(row_count(&mut engine).await, 1);
+
+    // NULL satisfies a CHECK constraint
+    engine
+        .execute_query("INSERT INTO products (id, sku, name, price) VALUES (2, 'B-2', 'Axe', NULL)")
+        .await
+        .unwrap();
+    assert_eq!(row_count(&mut engine).await, 2);
+}
+
+#[tokio::test]
+async fn test_unsupported_check_is_rejected_at_create() {
+    let (_dir, _storage, mut engine) = setup().await;
+
+    let err = engine
+        .execute_query("CREATE TABLE orders (id INTEGER PRIMARY KEY, a INTEGER, CHECK (a > id))")
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("Unsupported CHECK constraint"),
+        "unexpected error: {err}"
+    );
+}
@@ -128,6 +128,7 @@ async fn test_execute_insert_with_missing_column_defaults() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -135,6 +136,7 @@ async fn test_execute_insert_with_missing_column_defaults() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "status".to_string(),
@@ -142,6 +144,7 @@ async fn test_execute_insert_with_missing_column_defaults() {
                 nullable: true,
                 default_value: Some(Value::text("active")),
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "age".to_string(),
@@ -149,6 +152,7 @@ async fn test_execute_insert_with_missing_column_defaults() {
                 nullable: true,
                 default_value: Some(Value::Integer(0)),
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -157,6 +161,7 @@ async fn test_execute_insert_with_missing_column_defaults() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -231,6 +236,7 @@ async fn test_execute_insert_with_explicit_default() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -238,6 +244,7 @@ async fn test_execute_insert_with_explicit_default() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "price".to_string(),
@@ -245,6 +252,7 @@ async fn test_execute_insert_with_explicit_default() {
                 nullable: true,
                 default_value: Some(Value::Float(9.99)),
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "category".to_string(),
@@ -252,6 +260,7 @@ async fn test_execute_insert_with_explicit_default() {
                 nullable: true,
                 default_value: Some(Value::text("general")),
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -260,6 +269,7 @@ async fn test_execute_insert_with_explicit_default() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -338,6 +348,7 @@ async fn test_insert_null_for_nullable_without_default() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -345,6 +356,7 @@ async fn test_insert_null_for_nullable_without_default() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "description".to_string(),
@@ -352,6 +364,7 @@ async fn test_insert_null_for_nullable_without_default() {
                 nullable: true,
                 default_value: None, // No default, but nullable
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -360,6 +373,7 @@ async fn test_insert_null_for_nullable_without_default() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -419,6 +433,7 @@ async fn test_multiple_inserts_with_defaults() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -426,6 +441,7 @@ async fn test_multiple_inserts_with_defaults() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "department".to_string(),
@@ -433,6 +449,7 @@ async fn test_multiple_inserts_with_defaults() {
                 nullable: true,
                 default_value: Some(Value::text("General")),
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "salary".to_string(),
@@ -440,6 +457,7 @@ async fn test_multiple_inserts_with_defaults() {
                 nullable: true,
                 default_value: Some(Value::Integer(50000)),
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -448,6 +466,7 @@ async fn test_multiple_inserts_with_defaults() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
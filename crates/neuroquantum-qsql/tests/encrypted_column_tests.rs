@@ -0,0 +1,79 @@
+//! Tests for `ENCRYPTED` column declarations in CREATE TABLE
+
+use std::sync::Arc;
+
+use neuroquantum_core::pqcrypto::KeyManager;
+use neuroquantum_core::storage::{StorageEngine, REDACTED_PLACEHOLDER};
+use neuroquantum_qsql::query_plan::QueryValue;
+use neuroquantum_qsql::{FieldAccess, Parser, QueryExecutor};
+use tokio::sync::RwLock;
+
+async fn setup() -> (QueryExecutor, Arc<RwLock<StorageEngine>>, tempfile::TempDir) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+    storage.set_field_key_manager(Arc::new(KeyManager::new()));
+    let storage_arc = Arc::new(RwLock::new(storage));
+
+    let mut executor = QueryExecutor::new().unwrap();
+    executor.set_storage_engine(storage_arc.clone());
+
+    let parser = Parser::new();
+    for sql in [
+        "CREATE TABLE customers (id INTEGER PRIMARY KEY, name TEXT, card TEXT ENCRYPTED)",
+        "INSERT INTO customers (id, name, card) VALUES (1, 'Ada', '4111-1111-1111-1111')",
+    ] {
+        let statement = parser.parse(sql).unwrap();
+        executor.execute_statement(&statement).await.unwrap();
+    }
+
+    (executor, storage_arc, temp_dir)
+}
+
+#[tokio::test]
+async fn test_encrypted_constraint_is_recorded_in_schema() {
+    let (_executor, storage, _temp_dir) = setup().await;
+
+    let storage = storage.read().await;
+    let schema = storage.get_table_schema("customers").unwrap();
+    let flags: Vec<(&str, bool)> = schema
+        .columns
+        .iter()
+        .map(|c| (c.name.as_str(), c.encrypted))
+        .collect();
+    assert_eq!(flags, vec![("id", false), ("name", false), ("card", true)]);
+}
+
+#[tokio::test]
+async fn test_select_redacts_by_default_and_decrypts_when_authorized() {
+    let (mut executor, _storage, _temp_dir) = setup().await;
+    let statement = Parser::new()
+        .parse("SELECT name, card FROM customers")
+        .unwrap();
+
+    let redacted = executor.execute_statement(&statement).await.unwrap();
+    assert_eq!(
+        redacted.rows[0].get("card"),
+        Some(&QueryValue::String(REDACTED_PLACEHOLDER.to_string()))
+    );
+
+    executor.set_field_access(FieldAccess::Decrypted);
+    let decrypted = executor.execute_statement(&statement).await.unwrap();
+    assert_eq!(
+        decrypted.rows[0].get("card"),
+        Some(&QueryValue::String("4111-1111-1111-1111".to_string()))
+    );
+    assert_eq!(
+        decrypted.rows[0].get("name"),
+        Some(&QueryValue::String("Ada".to_string()))
+    );
+}
+
+#[tokio::test]
+async fn test_alter_table_cannot_add_encrypted_column() {
+    let (mut executor, _storage, _temp_dir) = setup().await;
+    let statement = Parser::new()
+        .parse("ALTER TABLE customers ADD COLUMN notes TEXT ENCRYPTED")
+        .unwrap();
+
+    assert!(executor.execute_statement(&statement).await.is_err());
+}
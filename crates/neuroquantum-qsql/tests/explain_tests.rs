@@ -49,6 +49,7 @@ fn test_explain_generator() {
         grover_iterations: None,
         with_clause: None,
         union_clause: None,
+        hints: Vec::new(),
     };
 
     let query_plan = QueryPlan {
@@ -103,6 +104,7 @@ fn test_explain_text_format() {
         grover_iterations: None,
         with_clause: None,
         union_clause: None,
+        hints: Vec::new(),
     };
 
     let query_plan = QueryPlan {
@@ -237,6 +239,7 @@ fn test_explain_json_format() {
         grover_iterations: None,
         with_clause: None,
         union_clause: None,
+        hints: Vec::new(),
     };
 
     let query_plan = QueryPlan {
@@ -335,6 +335,8 @@ mod storage_integration_tests {
     use std::sync::Arc;
 
     use neuroquantum_core::storage::StorageEngine;
+    use neuroquantum_core::transaction::TransactionManager;
+    use neuroquantum_qsql::query_plan::QueryValue;
     use neuroquantum_qsql::{ExecutorConfig, Parser, QueryExecutor};
     use tempfile::TempDir;
     use tokio::sync::RwLock;
@@ -577,4 +579,167 @@ mod storage_integration_tests {
             result.err()
         );
     }
+
+    /// Executor with a transaction manager, and `customers` and `orders`
+    /// tables where orders reference customers with `on_delete`
+    async fn setup_orders(
+        on_delete: &str,
+    ) -> (TempDir, Arc<RwLock<StorageEngine>>, QueryExecutor, Parser) {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let wal_path = temp_dir.path().join("wal");
+        tokio::fs::create_dir_all(&wal_path).await.unwrap();
+        let storage = StorageEngine::new(temp_dir.path())
+            .await
+            .expect("Failed to create storage");
+        let storage_arc = Arc::new(RwLock::new(storage));
+        let tx_manager = TransactionManager::new_async(&wal_path)
+            .await
+            .expect("Failed to create transaction manager");
+
+        let mut executor =
+            QueryExecutor::with_storage(ExecutorConfig::default(), storage_arc.clone())
+                .expect("Failed to create executor");
+        executor.set_transaction_manager(Arc::new(tx_manager));
+        let parser = Parser::new();
+
+        for sql in [
+            "CREATE TABLE customers (id INTEGER PRIMARY KEY, name TEXT)".to_string(),
+            format!(
+                "CREATE TABLE orders (id INTEGER PRIMARY KEY, \
+                 customer_id INTEGER REFERENCES customers(id) ON DELETE {on_delete})"
+            ),
+            "INSERT INTO customers (id, name) VALUES (1, 'Alice')".to_string(),
+            "INSERT INTO customers (id, name) VALUES (2, 'Bob')".to_string(),
+            "INSERT INTO orders (id, customer_id) VALUES (1, 1)".to_string(),
+            "INSERT INTO orders (id, customer_id) VALUES (2, 1)".to_string(),
+            "INSERT INTO orders (id, customer_id) VALUES (3, 2)".to_string(),
+        ] {
+            run(&mut executor, &parser, &sql)
+                .await
+                .unwrap_or_else(|e| panic!("{sql} failed: {e}"));
+        }
+        (temp_dir, storage_arc, executor, parser)
+    }
+
+    async fn run(
+        executor: &mut QueryExecutor,
+        parser: &Parser,
+        sql: &str,
+    ) -> neuroquantum_qsql::QSQLResult<neuroquantum_qsql::QueryResult> {
+        executor.execute_statement(&parser.parse(sql)?).await
+    }
+
+    /// `customer_id` of each order, by order id
+    async fn order_customers(
+        executor: &mut QueryExecutor,
+        parser: &Parser,
+    ) -> Vec<(i64, Option<i64>)> {
+        let result = run(executor, parser, "SELECT id, customer_id FROM orders")
+            .await
+            .expect("Failed to select orders");
+        let mut orders: Vec<_> = result
+            .rows
+            .iter()
+            .map(|row| {
+                let Some(QueryValue::Integer(id)) = row.get("id") else {
+                    panic!("order without id: {row:?}");
+                };
+                let customer_id = match row.get("customer_id") {
+                    | Some(QueryValue::Integer(customer_id)) => Some(*customer_id),
+                    | _ => None,
+                };
+                (*id, customer_id)
+            })
+            .collect();
+        orders.sort_unstable();
+        orders
+    }
+
+    #[tokio::test]
+    async fn test_foreign_key_column_is_indexed() {
+        let (_temp_dir, storage, _executor, _parser) = setup_orders("CASCADE").await;
+
+        let storage = storage.read().await;
+        let index = storage
+            .get_index_definition("orders_customer_id_fkey")
+            .expect("Foreign key columns should be indexed");
+        assert_eq!(index.columns, vec!["customer_id".to_string()]);
+        assert!(!index.unique);
+    }
+
+    #[tokio::test]
+    async fn test_foreign_key_delete_restrict_in_transaction() {
+        let (_temp_dir, _storage, mut executor, parser) = setup_orders("RESTRICT").await;
+
+        run(&mut executor, &parser, "BEGIN").await.unwrap();
+        let err = run(&mut executor, &parser, "DELETE FROM customers WHERE id = 1")
+            .await
+            .expect_err("Deleting a referenced customer should be rejected");
+        assert!(
+            err.to_string().contains("Foreign key violation"),
+            "unexpected error: {err}"
+        );
+        run(&mut executor, &parser, "ROLLBACK").await.unwrap();
+
+        assert_eq!(
+            order_customers(&mut executor, &parser).await,
+            vec![(1, Some(1)), (2, Some(1)), (3, Some(2))]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_foreign_key_delete_cascade_in_transaction() {
+        let (_temp_dir, _storage, mut executor, parser) = setup_orders("CASCADE").await;
+
+        run(&mut executor, &parser, "BEGIN").await.unwrap();
+        run(&mut executor, &parser, "DELETE FROM customers WHERE id = 1")
+            .await
+            .expect("Delete with CASCADE should succeed");
+        run(&mut executor, &parser, "COMMIT").await.unwrap();
+
+        assert_eq!(
+            order_customers(&mut executor, &parser).await,
+            vec![(3, Some(2))]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_foreign_key_delete_set_null() {
+        let (_temp_dir, _storage, mut executor, parser) = setup_orders("SET NULL").await;
+
+        run(&mut executor, &parser, "DELETE FROM customers WHERE id = 1")
+            .await
+            .expect("Delete with SET NULL should succeed");
+
+        assert_eq!(
+            order_customers(&mut executor, &parser).await,
+            vec![(1, None), (2, None), (3, Some(2))]
+        );
+
+        // Cleared references no longer match the old value through the index
+        let result = run(
+            &mut executor,
+            &parser,
+            "SELECT id FROM orders WHERE customer_id = 1",
+        )
+        .await
+        .unwrap();
+        assert!(result.rows.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_foreign_key_delete_set_null_in_transaction() {
+        let (_temp_dir, _storage, mut executor, parser) = setup_orders("SET NULL").await;
+
+        run(&mut executor, &parser, "BEGIN").await.unwrap();
+        run(&mut executor, &parser, "DELETE FROM customers WHERE id = 2")
+            .await
+            .expect("Delete with SET NULL should succeed");
+        run(&mut executor, &parser, "COMMIT").await.unwrap();
+
+        assert_eq!(
+            order_customers(&mut executor, &parser).await,
+            vec![(1, Some(1)), (2, Some(1)), (3, None)]
+        );
+    }
 }
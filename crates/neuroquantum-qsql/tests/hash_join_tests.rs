@@ -7,6 +7,8 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use neuroquantum_core::storage::{ColumnDefinition, DataType, StorageEngine, TableSchema};
+use neuroquantum_qsql::ast::{JoinType, Statement};
+use neuroquantum_qsql::optimizer::{JoinStrategy, NeuromorphicOptimizer};
 use neuroquantum_qsql::query_plan::QueryValue;
 use neuroquantum_qsql::{ExecutorConfig, Parser, QueryExecutor};
 use tempfile::TempDir;
@@ -29,6 +31,7 @@ async fn setup_test_tables() -> (TempDir, Arc<tokio::sync::RwLock<StorageEngine>
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -36,6 +39,7 @@ async fn setup_test_tables() -> (TempDir, Arc<tokio::sync::RwLock<StorageEngine>
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -44,6 +48,7 @@ async fn setup_test_tables() -> (TempDir, Arc<tokio::sync::RwLock<StorageEngine>
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     // Create orders table
@@ -56,6 +61,7 @@ async fn setup_test_tables() -> (TempDir, Arc<tokio::sync::RwLock<StorageEngine>
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "user_id".to_string(),
@@ -63,6 +69,7 @@ async fn setup_test_tables() -> (TempDir, Arc<tokio::sync::RwLock<StorageEngine>
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "amount".to_string(),
@@ -70,6 +77,7 @@ async fn setup_test_tables() -> (TempDir, Arc<tokio::sync::RwLock<StorageEngine>
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "order_id".to_string(),
@@ -78,6 +86,7 @@ async fn setup_test_tables() -> (TempDir, Arc<tokio::sync::RwLock<StorageEngine>
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -462,3 +471,182 @@ async fn test_hash_join_duplicate_keys() {
 
     assert_eq!(order_ids, vec![101, 102, 103]);
 }
+
+/// Run `sql` over a fixed three-user / three-order data set using `threshold`
+async fn run_join(sql: &str, threshold: usize) -> Vec<HashMap<String, QueryValue>> {
+    let (_temp_dir, storage_arc) = setup_test_tables().await;
+    let config = ExecutorConfig {
+        hash_join_threshold: threshold,
+        ..Default::default()
+    };
+    let mut executor = QueryExecutor::with_storage(config, storage_arc).unwrap();
+    let parser = Parser::new();
+
+    let inserts = [
+        "INSERT INTO users (id, name) VALUES (1, 'Alice')",
+        "INSERT INTO users (id, name) VALUES (2, 'Bob')",
+        "INSERT INTO users (id, name) VALUES (3, 'Charlie')",
+        "INSERT INTO orders (order_id, user_id, amount) VALUES (101, 1, 50.0)",
+        "INSERT INTO orders (order_id, user_id, amount) VALUES (102, 1, 150.0)",
+        "INSERT INTO orders (order_id, user_id, amount) VALUES (103, 2, 20.0)",
+    ];
+    for sql in inserts {
+        let statement = parser.parse(sql).unwrap();
+        executor.execute_statement(&statement).await.unwrap();
+    }
+
+    let statement = parser.parse(sql).unwrap();
+    executor.execute_statement(&statement).await.unwrap().rows
+}
+
+/// `(name, amount)` of each joined row, sorted
+fn name_amount_pairs(rows: &[HashMap<String, QueryValue>]) -> Vec<(String, Option<i64>)> {
+    let mut pairs: Vec<_> = rows
+        .iter()
+        .map(|row| {
+            let Some(QueryValue::String(name)) = row.get("users.name") else {
+                panic!("missing users.name in {row:?}");
+            };
+            let amount = match row.get("orders.amount") {
+                | Some(QueryValue::Float(amount)) => Some(*amount as i64),
+                | Some(QueryValue::Null) => None,
+                | other => panic!("unexpected orders.amount {other:?}"),
+            };
+            (name.clone(), amount)
+        })
+        .collect();
+    pairs.sort();
+    pairs
+}
+
+/// The optimizer picks the join strategy from the input cardinalities
+#[test]
+fn test_optimizer_join_strategy_selection() {
+    let equi = Parser::new()
+        .parse("SELECT * FROM users JOIN orders ON users.id = orders.user_id")
+        .unwrap();
+    let Statement::Select(select) = equi else {
+        panic!("expected SELECT");
+    };
+    let condition = select.from.as_ref().unwrap().joins[0].condition.as_ref();
+
+    let choose = |left, right, join_type, threshold| {
+        NeuromorphicOptimizer::choose_join_strategy(left, right, &join_type, condition, threshold)
+    };
+    assert_eq!(choose(3, 3, JoinType::Inner, 0), JoinStrategy::Hash);
+    assert_eq!(choose(3, 3, JoinType::Left, 0), JoinStrategy::Hash);
+    // Below the threshold
+    assert_eq!(
+        choose(3, 3, JoinType::Inner, 1000),
+        JoinStrategy::NestedLoop
+    );
+    // A single-row input is cheaper to scan than to hash
+    assert_eq!(
+        choose(1, 5000, JoinType::Inner, 0),
+        JoinStrategy::NestedLoop
+    );
+    assert_eq!(choose(3, 3, JoinType::Cross, 0), JoinStrategy::NestedLoop);
+
+    // No column equality to hash on
+    let inequality = Parser::new()
+        .parse("SELECT * FROM users JOIN orders ON users.id < orders.user_id")
+        .unwrap();
+    let Statement::Select(select) = inequality else {
+        panic!("expected SELECT");
+    };
+    let condition = select.from.as_ref().unwrap().joins[0].condition.as_ref();
+    assert_eq!(
+        NeuromorphicOptimizer::choose_join_strategy(3, 3, &JoinType::Inner, condition, 0),
+        JoinStrategy::NestedLoop
+    );
+}
+
+/// Inner and left joins return the same rows with either strategy
+#[tokio::test]
+async fn test_join_results_match_across_strategies() {
+    // threshold 0 selects hash join for these 3 x 3 inputs, usize::MAX nested loop
+    for threshold in [0, usize::MAX] {
+        let rows = run_join(
+            "SELECT * FROM users INNER JOIN orders ON users.id = orders.user_id",
+            threshold,
+        )
+        .await;
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            name_amount_pairs(&rows),
+            vec![
+                ("Alice".to_string(), Some(50)),
+                ("Alice".to_string(), Some(150)),
+                ("Bob".to_string(), Some(20)),
+            ]
+        );
+        assert!(rows.iter().all(|row| {
+            row.contains_key("users.id")
+                && row.contains_key("orders.order_id")
+                && row.contains_key("orders.user_id")
+        }));
+
+        let rows = run_join(
+            "SELECT * FROM users LEFT JOIN orders ON users.id = orders.user_id",
+            threshold,
+        )
+        .await;
+        assert_eq!(rows.len(), 4);
+        assert_eq!(
+            name_amount_pairs(&rows),
+            vec![
+                ("Alice".to_string(), Some(50)),
+                ("Alice".to_string(), Some(150)),
+                ("Bob".to_string(), Some(20)),
+                ("Charlie".to_string(), None),
+            ]
+        );
+    }
+}
+
+/// Inequality conditions filter joined rows; left joins keep unmatched rows
+#[tokio::test]
+async fn test_join_with_inequality_condition() {
+    for threshold in [0, usize::MAX] {
+        let rows = run_join(
+            "SELECT * FROM users INNER JOIN orders \
+             ON users.id = orders.user_id AND orders.amount > 30",
+            threshold,
+        )
+        .await;
+        assert_eq!(
+            name_amount_pairs(&rows),
+            vec![
+                ("Alice".to_string(), Some(50)),
+                ("Alice".to_string(), Some(150)),
+            ]
+        );
+
+        let rows = run_join(
+            "SELECT * FROM users LEFT JOIN orders \
+             ON users.id = orders.user_id AND orders.amount > 30",
+            threshold,
+        )
+        .await;
+        assert_eq!(
+            name_amount_pairs(&rows),
+            vec![
+                ("Alice".to_string(), Some(50)),
+                ("Alice".to_string(), Some(150)),
+                ("Bob".to_string(), None),
+                ("Charlie".to_string(), None),
+            ]
+        );
+    }
+
+    // Pure inequality join: every order paired with users whose id is lower
+    let rows = run_join(
+        "SELECT * FROM users INNER JOIN orders ON users.id < orders.user_id",
+        0,
+    )
+    .await;
+    assert_eq!(
+        name_amount_pairs(&rows),
+        vec![("Alice".to_string(), Some(20))]
+    );
+}
@@ -23,6 +23,7 @@ async fn setup_orders_table(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "category".to_string(),
@@ -30,6 +31,7 @@ async fn setup_orders_table(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "amount".to_string(),
@@ -37,6 +39,7 @@ async fn setup_orders_table(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -45,6 +48,7 @@ async fn setup_orders_table(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     let mut storage_guard = storage_arc.write().await;
@@ -7,8 +7,13 @@
 //! - Existing index handling
 //! - Recommendation priority calculation
 
+use std::sync::Arc;
+
+use neuroquantum_core::storage::StorageEngine;
 use neuroquantum_qsql::ast::*;
 use neuroquantum_qsql::index_advisor::{IndexAdvisor, IndexAdvisorConfig, RecommendationPriority};
+use neuroquantum_qsql::QSQLEngine;
+use tempfile::TempDir;
 
 fn create_test_select(table: &str, where_column: Option<&str>) -> Statement {
     let where_clause = where_column.map(|col| Expression::BinaryOp {
@@ -42,6 +47,7 @@ fn create_test_select(table: &str, where_column: Option<&str>) -> Statement {
         grover_iterations: None,
         with_clause: None,
         union_clause: None,
+        hints: Vec::new(),
     })
 }
 
@@ -215,3 +221,99 @@ fn test_tracking_disabled() {
     // Tracking is disabled, so nothing should be recorded
     assert_eq!(stats.tables_tracked, 0);
 }
+
+#[test]
+fn test_recommends_index_for_dominant_predicate() {
+    let advisor = IndexAdvisor::new();
+    for _ in 0..40 {
+        advisor.track_query(&create_test_select("users", Some("age")));
+    }
+    for _ in 0..5 {
+        advisor.track_query(&create_test_select("users", Some("name")));
+    }
+
+    let recommendations = advisor.get_recommendations();
+    let top = &recommendations[0];
+    assert_eq!(top.columns, vec!["age".to_string()]);
+    assert_eq!(top.avoidable_full_scans, 40);
+    assert!(top.create_statement.ends_with("ON users (age)"));
+    assert!(top.reason.contains("Would have avoided 40 full scans"));
+
+    // Once indexed, age filters no longer scan and age isn't recommended
+    advisor.set_existing_indexes("users", &[vec!["age".to_string()]]);
+    advisor.track_query(&create_test_select("users", Some("age")));
+    let table_stats = advisor.get_table_stats("users").unwrap();
+    assert_eq!(table_stats.columns["age"].avoidable_full_scans, 40);
+    assert!(!advisor
+        .get_recommendations()
+        .iter()
+        .any(|r| r.columns == vec!["age".to_string()]));
+}
+
+#[test]
+fn test_observation_window_ignores_old_patterns() {
+    let advisor = IndexAdvisor::with_config(IndexAdvisorConfig {
+        observation_window: Some(std::time::Duration::from_millis(50)),
+        ..Default::default()
+    });
+    for _ in 0..20 {
+        advisor.track_query(&create_test_select("users", Some("age")));
+    }
+    assert!(!advisor.get_recommendations().is_empty());
+
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    assert!(advisor.get_recommendations().is_empty());
+}
+
+#[tokio::test]
+async fn test_engine_skips_indexes_existing_in_storage() {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+    let mut engine = QSQLEngine::with_storage(Arc::new(tokio::sync::RwLock::new(storage))).unwrap();
+    for sql in [
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, age INTEGER)",
+        "CREATE INDEX idx_users_name ON users (name)",
+    ] {
+        engine.execute_query(sql).await.unwrap();
+    }
+    for i in 0..12 {
+        engine
+            .execute_query(&format!(
+                "INSERT INTO users (id, name, age) VALUES ({i}, 'user {i}', {})",
+                20 + i
+            ))
+            .await
+            .unwrap();
+    }
+    for i in 0..12 {
+        for sql in [
+            format!("SELECT * FROM users WHERE age = {}", 20 + i),
+            format!("SELECT * FROM users WHERE name = 'user {i}'"),
+        ] {
+            engine.execute_query(&sql).await.unwrap();
+        }
+    }
+
+    engine.refresh_index_advisor_indexes().await;
+    let recommendations = engine.get_index_recommendations();
+    let age = recommendations
+        .iter()
+        .find(|r| r.columns == vec!["age".to_string()])
+        .expect("expected an index recommendation on age");
+    assert_eq!(age.table_name, "users");
+    assert_eq!(age.avoidable_full_scans, 12);
+    assert!(!recommendations
+        .iter()
+        .any(|r| r.columns == vec!["name".to_string()]));
+
+    // After dropping the index, name is a candidate again
+    engine
+        .execute_query("DROP INDEX idx_users_name")
+        .await
+        .unwrap();
+    engine.refresh_index_advisor_indexes().await;
+    assert!(engine
+        .get_index_recommendations()
+        .iter()
+        .any(|r| r.columns == vec!["name".to_string()]));
+}
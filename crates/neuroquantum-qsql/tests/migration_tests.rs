@@ -0,0 +1,249 @@
+//! Tests for versioned schema migrations
+//!
+//! Migrations run `ALTER TABLE` through a `QSQLEngine` and are recorded in
+//! the `_schema_migrations` table of the same storage engine, each in a
+//! transaction of its own.
+
+use std::sync::Arc;
+
+use neuroquantum_core::storage::StorageEngine;
+use neuroquantum_qsql::migrations::{MigrationRunner, SqlMigration};
+use neuroquantum_qsql::query_plan::QueryValue;
+use neuroquantum_qsql::QSQLEngine;
+use tempfile::TempDir;
+use tokio::sync::RwLock;
+
+async fn setup() -> (TempDir, Arc<RwLock<StorageEngine>>, QSQLEngine) {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+    let storage_arc = Arc::new(RwLock::new(storage));
+    let mut engine = QSQLEngine::with_storage(storage_arc.clone()).unwrap();
+    for sql in [
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)",
+        "INSERT INTO users (id, name) VALUES (1, 'Alice')",
+        "INSERT INTO users (id, name) VALUES (2, 'Bob')",
+    ] {
+        engine.execute_query(sql).await.unwrap();
+    }
+    (temp_dir, storage_arc, engine)
+}
+
+/// Adds `email`, renames `name` and then renames `email`, so the last
+/// migration only succeeds after the first
+fn runner() -> MigrationRunner {
+    let mut runner = MigrationRunner::new();
+    runner
+        .register(SqlMigration::new(
+            3,
+            "rename email to contact",
+            ["ALTER TABLE users RENAME COLUMN email TO contact"],
+            ["ALTER TABLE users RENAME COLUMN contact TO email"],
+        ))
+        .unwrap();
+    runner
+        .register(SqlMigration::new(
+            1,
+            "add email",
+            ["ALTER TABLE users ADD COLUMN email TEXT NOT NULL DEFAULT 'unknown'"],
+            ["ALTER TABLE users DROP COLUMN email"],
+        ))
+        .unwrap();
+    runner
+        .register(SqlMigration::new(
+            2,
+            "rename name to full_name",
+            ["ALTER TABLE users RENAME COLUMN name TO full_name"],
+            ["ALTER TABLE users RENAME COLUMN full_name TO name"],
+        ))
+        .unwrap();
+    runner
+}
+
+async fn columns(storage: &RwLock<StorageEngine>, table: &str) -> Vec<String> {
+    let storage = storage.read().await;
+    let schema = storage.get_table_schema(table).unwrap();
+    schema.columns.iter().map(|c| c.name.clone()).collect()
+}
+
+/// `(id, column)` of every user, ordered by id
+async fn users(engine: &mut QSQLEngine, column: &str) -> Vec<(QueryValue, QueryValue)> {
+    let result = engine
+        .execute_query(&format!("SELECT id, {column} FROM users ORDER BY id"))
+        .await
+        .unwrap();
+    result
+        .rows
+        .iter()
+        .map(|row| (row["id"].clone(), row[column].clone()))
+        .collect()
+}
+
+fn text(value: &str) -> QueryValue {
+    QueryValue::String(value.to_string())
+}
+
+#[tokio::test]
+async fn test_migrations_apply_in_order_and_roll_back() {
+    let (_temp_dir, storage, mut engine) = setup().await;
+    let runner = runner();
+    assert_eq!(runner.versions(), vec![1, 2, 3]);
+
+    assert_eq!(runner.migrate(&mut engine).await.unwrap(), vec![1, 2, 3]);
+    assert_eq!(
+        runner.applied_versions(&mut engine).await.unwrap(),
+        vec![1, 2, 3]
+    );
+    assert_eq!(
+        columns(&storage, "users").await,
+        vec!["id", "full_name", "contact"]
+    );
+    assert_eq!(
+        users(&mut engine, "full_name").await,
+        vec![
+            (QueryValue::Integer(1), text("Alice")),
+            (QueryValue::Integer(2), text("Bob")),
+        ]
+    );
+    assert_eq!(
+        users(&mut engine, "contact").await,
+        vec![
+            (QueryValue::Integer(1), text("unknown")),
+            (QueryValue::Integer(2), text("unknown")),
+        ]
+    );
+
+    // Everything is applied already
+    assert!(runner.migrate(&mut engine).await.unwrap().is_empty());
+
+    assert_eq!(runner.rollback(&mut engine).await.unwrap(), Some(3));
+    assert_eq!(
+        runner.applied_versions(&mut engine).await.unwrap(),
+        vec![1, 2]
+    );
+    assert_eq!(
+        columns(&storage, "users").await,
+        vec!["id", "full_name", "email"]
+    );
+    assert_eq!(
+        users(&mut engine, "email").await,
+        vec![
+            (QueryValue::Integer(1), text("unknown")),
+            (QueryValue::Integer(2), text("unknown")),
+        ]
+    );
+
+    assert_eq!(runner.rollback(&mut engine).await.unwrap(), Some(2));
+    assert_eq!(runner.rollback(&mut engine).await.unwrap(), Some(1));
+    assert_eq!(runner.rollback(&mut engine).await.unwrap(), None);
+    assert_eq!(columns(&storage, "users").await, vec!["id", "name"]);
+    assert_eq!(
+        users(&mut engine, "name").await,
+        vec![
+            (QueryValue::Integer(1), text("Alice")),
+            (QueryValue::Integer(2), text("Bob")),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_applied_versions_are_shared_through_storage() {
+    let (_temp_dir, storage, mut engine) = setup().await;
+    runner().migrate(&mut engine).await.unwrap();
+
+    let mut other_engine = QSQLEngine::with_storage(storage).unwrap();
+    let runner = runner();
+    assert_eq!(
+        runner.applied_versions(&mut other_engine).await.unwrap(),
+        vec![1, 2, 3]
+    );
+    assert!(runner.migrate(&mut other_engine).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_failed_migration_is_not_recorded() {
+    let (_temp_dir, storage, mut engine) = setup().await;
+    let mut runner = MigrationRunner::new();
+    runner
+        .register(SqlMigration::new(
+            1,
+            "add required age",
+            ["ALTER TABLE users ADD COLUMN age INTEGER NOT NULL"],
+            ["ALTER TABLE users DROP COLUMN age"],
+        ))
+        .unwrap();
+
+    let err = runner.migrate(&mut engine).await.unwrap_err();
+    assert!(format!("{err:#}").contains("needs a DEFAULT"), "{err:#}");
+    assert!(runner
+        .applied_versions(&mut engine)
+        .await
+        .unwrap()
+        .is_empty());
+    assert_eq!(columns(&storage, "users").await, vec!["id", "name"]);
+}
+
+#[tokio::test]
+async fn test_failed_migration_rolls_back_its_changes() {
+    let (_temp_dir, _storage, mut engine) = setup().await;
+    let changes = [
+        "INSERT INTO users (id, name) VALUES (3, 'Carol')",
+        "UPDATE users SET name = 'Alicia' WHERE id = 1",
+    ];
+    let mut runner = MigrationRunner::new();
+    runner
+        .register(SqlMigration::new(
+            1,
+            "seed users",
+            changes
+                .into_iter()
+                .chain(["INSERT INTO missing_table (id) VALUES (1)"]),
+            ["DELETE FROM users WHERE id = 3"],
+        ))
+        .unwrap();
+
+    let err = runner.migrate(&mut engine).await.unwrap_err();
+    assert!(format!("{err:#}").contains("missing_table"), "{err:#}");
+    assert!(runner
+        .applied_versions(&mut engine)
+        .await
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        users(&mut engine, "name").await,
+        vec![
+            (QueryValue::Integer(1), text("Alice")),
+            (QueryValue::Integer(2), text("Bob")),
+        ]
+    );
+
+    // The failed migration left no transaction open
+    let mut runner = MigrationRunner::new();
+    runner
+        .register(SqlMigration::new(
+            1,
+            "seed users",
+            changes,
+            ["DELETE FROM users WHERE id = 3"],
+        ))
+        .unwrap();
+    assert_eq!(runner.migrate(&mut engine).await.unwrap(), vec![1]);
+    assert_eq!(
+        users(&mut engine, "name").await,
+        vec![
+            (QueryValue::Integer(1), text("Alicia")),
+            (QueryValue::Integer(2), text("Bob")),
+            (QueryValue::Integer(3), text("Carol")),
+        ]
+    );
+}
+
+#[test]
+fn test_duplicate_version_is_rejected() {
+    let mut runner = MigrationRunner::new();
+    runner
+        .register(SqlMigration::new(1, "first", ["SELECT 1"], ["SELECT 1"]))
+        .unwrap();
+    assert!(runner
+        .register(SqlMigration::new(1, "second", ["SELECT 1"], ["SELECT 1"]))
+        .is_err());
+}
@@ -68,6 +68,7 @@ async fn test_execute_multi_row_insert() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -75,6 +76,7 @@ async fn test_execute_multi_row_insert() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "email".to_string(),
@@ -82,6 +84,7 @@ async fn test_execute_multi_row_insert() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "age".to_string(),
@@ -89,6 +92,7 @@ async fn test_execute_multi_row_insert() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -97,6 +101,7 @@ async fn test_execute_multi_row_insert() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -182,6 +187,7 @@ async fn test_multi_row_insert_auto_increment() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -189,6 +195,7 @@ async fn test_multi_row_insert_auto_increment() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "price".to_string(),
@@ -196,6 +203,7 @@ async fn test_multi_row_insert_auto_increment() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -204,6 +212,7 @@ async fn test_multi_row_insert_auto_increment() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -269,6 +278,7 @@ async fn test_multi_row_insert_transaction() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "customer".to_string(),
@@ -276,6 +286,7 @@ async fn test_multi_row_insert_transaction() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "total".to_string(),
@@ -283,6 +294,7 @@ async fn test_multi_row_insert_transaction() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -291,6 +303,7 @@ async fn test_multi_row_insert_transaction() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -356,6 +369,7 @@ async fn test_large_batch_insert() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "message".to_string(),
@@ -363,6 +377,7 @@ async fn test_large_batch_insert() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -371,6 +386,7 @@ async fn test_large_batch_insert() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -228,6 +228,61 @@ fn test_quantum_translation() {
     assert!(result.unwrap().contains("QUANTUM_SEARCH"));
 }
 
+#[test]
+fn test_translation_explains_phrases() {
+    let nlp = NaturalLanguageProcessor::new().unwrap();
+    let translation = nlp.explain_translation("Show users older than 30").unwrap();
+
+    assert_eq!(translation.qsql, "SELECT * FROM users WHERE age > 30");
+    assert_eq!(
+        translation.qsql,
+        nlp.translate_to_qsql("Show users older than 30").unwrap()
+    );
+    assert_eq!(translation.intent, QueryIntent::Select);
+    assert!(translation.confidence > 0.5);
+    assert!(!translation.is_ambiguous());
+
+    let mappings: Vec<(&str, &str)> = translation
+        .mappings
+        .iter()
+        .map(|m| (m.phrase.as_str(), m.clause.as_str()))
+        .collect();
+    assert_eq!(
+        mappings,
+        vec![
+            ("show", "SELECT"),
+            ("users", "FROM users"),
+            ("older than 30", "WHERE age > 30"),
+        ]
+    );
+}
+
+#[test]
+fn test_ambiguous_translation_has_alternatives() {
+    let nlp = NaturalLanguageProcessor::new().unwrap();
+
+    // "find" reads as a plain SELECT, "similar" as a NEUROMATCH
+    let translation = nlp.explain_translation("find similar users").unwrap();
+    assert!(translation.is_ambiguous());
+    let mut candidates: Vec<&str> = translation
+        .alternatives
+        .iter()
+        .map(|c| c.qsql.as_str())
+        .collect();
+    candidates.push(&translation.qsql);
+    assert!(candidates.contains(&"SELECT * FROM users"));
+    assert!(candidates.contains(&"NEUROMATCH users"));
+    for alternative in &translation.alternatives {
+        assert!(alternative.confidence <= translation.confidence);
+    }
+
+    // Two tables give one candidate per table
+    let translation = nlp.explain_translation("show users and posts").unwrap();
+    assert_eq!(translation.qsql, "SELECT * FROM users");
+    assert_eq!(translation.alternatives.len(), 1);
+    assert_eq!(translation.alternatives[0].qsql, "SELECT * FROM posts");
+}
+
 // NOTE: test_intent_classification_legacy and test_entity_extraction_legacy were removed
 // because they call private methods (classify_intent, extract_entities).
 // These internal implementation details are tested indirectly through translate_to_qsql.
@@ -36,6 +36,7 @@ async fn test_neuromatch_returns_results() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "title".to_string(),
@@ -43,6 +44,7 @@ async fn test_neuromatch_returns_results() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "author".to_string(),
@@ -50,6 +52,7 @@ async fn test_neuromatch_returns_results() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "genre".to_string(),
@@ -57,6 +60,7 @@ async fn test_neuromatch_returns_results() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "description".to_string(),
@@ -64,6 +68,7 @@ async fn test_neuromatch_returns_results() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -72,6 +77,7 @@ async fn test_neuromatch_returns_results() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
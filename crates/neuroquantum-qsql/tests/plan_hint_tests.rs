@@ -0,0 +1,243 @@
+//! Tests for optimizer hints given as `/*+ ... */` comments after SELECT
+//!
+//! `users` has indexes on `(age)` and `(age, name)`; the optimizer prefers
+//! the composite index for queries fixing both columns, so an `INDEX` hint
+//! naming `idx_users_age` is visible in the plan. Join hints override the
+//! choice between nested loop and hash join.
+
+use std::sync::Arc;
+
+use neuroquantum_core::storage::StorageEngine;
+use neuroquantum_qsql::ast::{PlanHint, Statement};
+use neuroquantum_qsql::query_plan::{QueryResult, QueryValue};
+use neuroquantum_qsql::{ExecutorConfig, Parser, QueryExecutor};
+use serde_json::Value;
+use tempfile::TempDir;
+
+async fn setup(config: ExecutorConfig) -> (TempDir, QueryExecutor) {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+    let storage_arc = Arc::new(tokio::sync::RwLock::new(storage));
+    let mut executor = QueryExecutor::with_storage(config, storage_arc).unwrap();
+
+    for sql in [
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, age INTEGER)",
+        "CREATE TABLE orders (id INTEGER PRIMARY KEY, user_id INTEGER, amount INTEGER)",
+    ] {
+        execute(&mut executor, sql).await.unwrap();
+    }
+    for id in 1..=20 {
+        let age = 20 + id % 5;
+        execute(
+            &mut executor,
+            &format!("INSERT INTO users (id, name, age) VALUES ({id}, 'user {id}', {age})"),
+        )
+        .await
+        .unwrap();
+        execute(
+            &mut executor,
+            &format!(
+                "INSERT INTO orders (id, user_id, amount) VALUES ({id}, {}, {})",
+                id % 7 + 1,
+                id * 10
+            ),
+        )
+        .await
+        .unwrap();
+    }
+    for sql in [
+        "CREATE INDEX idx_users_age ON users (age)",
+        "CREATE INDEX idx_users_age_name ON users (age, name)",
+    ] {
+        execute(&mut executor, sql).await.unwrap();
+    }
+
+    (temp_dir, executor)
+}
+
+async fn execute(
+    executor: &mut QueryExecutor,
+    sql: &str,
+) -> neuroquantum_qsql::error::QSQLResult<QueryResult> {
+    let statement = Parser::new().parse(sql).unwrap();
+    executor.execute_statement(&statement).await
+}
+
+/// Plan of an `EXPLAIN (FORMAT JSON)` query
+async fn explain(executor: &mut QueryExecutor, sql: &str) -> Value {
+    let result = execute(executor, &format!("EXPLAIN (FORMAT JSON) {sql}"))
+        .await
+        .unwrap();
+    let Some(QueryValue::String(json)) = result.rows[0].get("QUERY PLAN") else {
+        panic!("expected a JSON plan");
+    };
+    serde_json::from_str(json).unwrap()
+}
+
+fn ids(result: &QueryResult) -> Vec<i64> {
+    let mut ids: Vec<i64> = result
+        .rows
+        .iter()
+        .map(|row| match row.get("id") {
+            | Some(QueryValue::Integer(id)) => *id,
+            | other => panic!("unexpected id {other:?}"),
+        })
+        .collect();
+    ids.sort_unstable();
+    ids
+}
+
+#[test]
+fn test_parse_plan_hints() {
+    let parser = Parser::new();
+    let statement = parser
+        .parse("SELECT /*+ INDEX(users idx_users_age), HASH_JOIN */ * FROM users")
+        .unwrap();
+    let Statement::Select(select) = statement else {
+        panic!("expected SELECT");
+    };
+    assert_eq!(
+        select.hints,
+        vec![
+            PlanHint::Index {
+                table: "users".to_string(),
+                index: "idx_users_age".to_string(),
+            },
+            PlanHint::HashJoin,
+        ]
+    );
+
+    // Unknown hints are skipped, plain block comments are no hints at all
+    let statement = parser
+        .parse("SELECT /*+ PARALLEL(4) nested_loop_join */ * FROM users")
+        .unwrap();
+    let Statement::Select(select) = statement else {
+        panic!("expected SELECT");
+    };
+    assert_eq!(select.hints, vec![PlanHint::NestedLoopJoin]);
+
+    let statement = parser
+        .parse("SELECT /* HASH_JOIN */ * FROM users /*+ HASH_JOIN */")
+        .unwrap();
+    let Statement::Select(select) = statement else {
+        panic!("expected SELECT");
+    };
+    assert!(select.hints.is_empty());
+
+    assert!(parser.parse("SELECT /*+ HASH_JOIN * FROM users").is_err());
+}
+
+#[tokio::test]
+async fn test_index_hint_forces_index_scan() {
+    let (_temp_dir, mut executor) = setup(ExecutorConfig::default()).await;
+    let query = "SELECT * FROM users WHERE age = 23 AND name = 'user 3'";
+
+    // Without a hint the index fixing both columns wins
+    let plan = explain(&mut executor, query).await;
+    assert_eq!(plan["plan_nodes"][0]["node_type"], "IndexScan");
+    assert_eq!(plan["plan_nodes"][0]["index_name"], "idx_users_age_name");
+
+    let hinted = "SELECT /*+ INDEX(users idx_users_age) */ * FROM users \
+                  WHERE age = 23 AND name = 'user 3'";
+    let plan = explain(&mut executor, hinted).await;
+    let node = &plan["plan_nodes"][0];
+    assert_eq!(node["node_type"], "IndexScan");
+    assert_eq!(node["index_name"], "idx_users_age");
+    assert_eq!(node["index_cond"], "age");
+    assert!(!plan["warnings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|warning| warning.as_str().unwrap().contains("hint ignored")));
+
+    let expected = ids(&execute(&mut executor, query).await.unwrap());
+    assert_eq!(expected, vec![3]);
+    assert_eq!(
+        ids(&execute(&mut executor, hinted).await.unwrap()),
+        expected
+    );
+}
+
+#[tokio::test]
+async fn test_inapplicable_index_hint_is_ignored_with_warning() {
+    let (_temp_dir, mut executor) = setup(ExecutorConfig::default()).await;
+
+    // No index on `name` alone, so the hint can't serve a `name` lookup
+    let sql = "SELECT /*+ INDEX(users idx_users_age) */ * FROM users WHERE name = 'user 3'";
+    let plan = explain(&mut executor, sql).await;
+    assert_eq!(plan["plan_nodes"][0]["node_type"], "SeqScan");
+    assert!(plan["warnings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|warning| warning
+            == "INDEX(users idx_users_age) hint ignored: the index can't answer this query"));
+    assert_eq!(ids(&execute(&mut executor, sql).await.unwrap()), vec![3]);
+
+    let sql = "SELECT /*+ INDEX(users idx_missing) */ * FROM users WHERE age = 21";
+    let plan = explain(&mut executor, sql).await;
+    assert_eq!(plan["plan_nodes"][0]["index_name"], "idx_users_age");
+    assert!(plan["warnings"]
+        .to_string()
+        .contains("INDEX(users idx_missing) hint ignored"));
+    assert_eq!(
+        ids(&execute(&mut executor, sql).await.unwrap()),
+        vec![1, 6, 11, 16]
+    );
+}
+
+#[tokio::test]
+async fn test_join_hint_overrides_cost_based_strategy() {
+    let (_temp_dir, mut executor) = setup(ExecutorConfig::default()).await;
+    let join = "* FROM users u INNER JOIN orders o ON u.id = o.user_id";
+
+    // 20 x 20 rows stay below the default hash join threshold
+    let plan = explain(&mut executor, &format!("SELECT {join}")).await;
+    let node = &plan["plan_nodes"][0];
+    assert_eq!(node["node_type"], "NestedLoop");
+    assert_eq!(node["join_type"], "Inner");
+    assert_eq!(node["children"][0]["relation_name"], "users");
+    assert_eq!(node["children"][1]["relation_name"], "orders");
+    assert_eq!(node["children"][1]["node_id"], "1.2");
+
+    let plan = explain(&mut executor, &format!("SELECT /*+ HASH_JOIN */ {join}")).await;
+    assert_eq!(plan["plan_nodes"][0]["node_type"], "HashJoin");
+
+    let unhinted = execute(&mut executor, &format!("SELECT {join}"))
+        .await
+        .unwrap();
+    let hinted = execute(&mut executor, &format!("SELECT /*+ HASH_JOIN */ {join}"))
+        .await
+        .unwrap();
+    assert_eq!(unhinted.rows.len(), 20);
+    assert_eq!(hinted.rows.len(), unhinted.rows.len());
+
+    // A hash join needs a column equality to hash on
+    let plan = explain(
+        &mut executor,
+        "SELECT /*+ HASH_JOIN */ * FROM users u INNER JOIN orders o ON u.id < o.user_id",
+    )
+    .await;
+    assert_eq!(plan["plan_nodes"][0]["node_type"], "NestedLoop");
+    assert!(plan["warnings"]
+        .to_string()
+        .contains("HASH_JOIN hint ignored"));
+}
+
+#[tokio::test]
+async fn test_nested_loop_hint_overrides_hash_join() {
+    let config = ExecutorConfig {
+        hash_join_threshold: 2,
+        ..Default::default()
+    };
+    let (_temp_dir, mut executor) = setup(config).await;
+    let join = "* FROM users u INNER JOIN orders o ON u.id = o.user_id";
+
+    let plan = explain(&mut executor, &format!("SELECT {join}")).await;
+    assert_eq!(plan["plan_nodes"][0]["node_type"], "HashJoin");
+
+    let sql = format!("SELECT /*+ NESTED_LOOP_JOIN */ {join}");
+    let plan = explain(&mut executor, &sql).await;
+    assert_eq!(plan["plan_nodes"][0]["node_type"], "NestedLoop");
+    assert_eq!(execute(&mut executor, &sql).await.unwrap().rows.len(), 20);
+}
@@ -44,6 +44,7 @@ fn test_prepare_statement() {
         grover_iterations: None,
         with_clause: None,
         union_clause: None,
+        hints: Vec::new(),
     };
 
     let prepare_stmt = PrepareStatement {
@@ -95,6 +96,7 @@ fn test_execute_with_positional_parameters() {
         grover_iterations: None,
         with_clause: None,
         union_clause: None,
+        hints: Vec::new(),
     };
 
     let prepare_stmt = PrepareStatement {
@@ -162,6 +164,7 @@ fn test_execute_with_named_parameters() {
         grover_iterations: None,
         with_clause: None,
         union_clause: None,
+        hints: Vec::new(),
     };
 
     let prepare_stmt = PrepareStatement {
@@ -224,6 +227,7 @@ fn test_deallocate() {
         grover_iterations: None,
         with_clause: None,
         union_clause: None,
+        hints: Vec::new(),
     };
 
     let prepare_stmt = PrepareStatement {
@@ -260,6 +264,7 @@ fn test_deallocate_all() {
             grover_iterations: None,
             with_clause: None,
             union_clause: None,
+            hints: Vec::new(),
         };
 
         let prepare_stmt = PrepareStatement {
@@ -297,6 +302,7 @@ fn test_duplicate_prepare_fails() {
         grover_iterations: None,
         with_clause: None,
         union_clause: None,
+        hints: Vec::new(),
     };
 
     let prepare_stmt = PrepareStatement {
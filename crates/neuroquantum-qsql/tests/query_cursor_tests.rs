@@ -0,0 +1,100 @@
+//! Tests for reading a SELECT page by page through a query cursor
+//!
+//! A cursor reads every page from the snapshot it was opened with, so
+//! writes committed between pages neither appear in nor disturb the result.
+
+use std::sync::Arc;
+
+use neuroquantum_core::storage::StorageEngine;
+use neuroquantum_qsql::query_plan::QueryValue;
+use neuroquantum_qsql::{FieldAccess, QSQLEngine, QueryPage};
+use tempfile::TempDir;
+use tokio::sync::RwLock;
+
+async fn setup() -> (TempDir, QSQLEngine) {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+    let mut engine = QSQLEngine::with_storage(Arc::new(RwLock::new(storage))).unwrap();
+    engine
+        .execute_query("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)")
+        .await
+        .unwrap();
+    for (id, name) in [(1, "Ada"), (2, "Grace"), (3, "Edsger"), (4, "Barbara")] {
+        engine
+            .execute_query(&format!(
+                "INSERT INTO users (id, name) VALUES ({id}, '{name}')"
+            ))
+            .await
+            .unwrap();
+    }
+    (temp_dir, engine)
+}
+
+fn names(page: &QueryPage) -> Vec<String> {
+    page.rows
+        .iter()
+        .filter_map(|row| match row.get("name") {
+            | Some(QueryValue::String(name)) => Some(name.clone()),
+            | _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_cursor_pages_read_one_snapshot() {
+    let (_temp_dir, mut engine) = setup().await;
+    let mut cursor = engine
+        .open_cursor("SELECT id, name FROM users", FieldAccess::Redacted)
+        .await
+        .unwrap()
+        .expect("a plain SELECT is read through a cursor");
+
+    let page = engine
+        .fetch_cursor_page(&mut cursor, 2)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(names(&page), vec!["Ada", "Grace"]);
+
+    // Writes between pages land after the cursor's snapshot
+    for statement in [
+        "INSERT INTO users (id, name) VALUES (5, 'Alan')",
+        "UPDATE users SET name = 'Dijkstra' WHERE id = 3",
+        "DELETE FROM users WHERE id = 4",
+    ] {
+        engine.execute_query(statement).await.unwrap();
+    }
+
+    let page = engine
+        .fetch_cursor_page(&mut cursor, 2)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(names(&page), vec!["Edsger", "Barbara"]);
+    assert!(engine
+        .fetch_cursor_page(&mut cursor, 2)
+        .await
+        .unwrap()
+        .is_none_or(|page| page.rows.is_empty()));
+    engine.close_cursor(cursor).await.unwrap();
+
+    // A new query sees the committed writes
+    let result = engine
+        .execute_query("SELECT id, name FROM users")
+        .await
+        .unwrap();
+    assert_eq!(result.rows.len(), 4);
+}
+
+#[tokio::test]
+async fn test_statements_without_a_cursor() {
+    let (_temp_dir, mut engine) = setup().await;
+    let cursor = engine
+        .open_cursor(
+            "INSERT INTO users (id, name) VALUES (9, 'Niklaus')",
+            FieldAccess::Redacted,
+        )
+        .await
+        .unwrap();
+    assert!(cursor.is_none());
+}
@@ -27,6 +27,7 @@ fn create_test_plan() -> Arc<QueryPlan> {
             grover_iterations: None,
             with_clause: None,
             union_clause: None,
+            hints: Vec::new(),
         })),
         execution_strategy: ExecutionStrategy::Sequential,
         synaptic_pathways: vec![],
@@ -213,3 +214,44 @@ fn test_eviction_priority_calculation() {
     // Higher strength and usage should mean higher priority (less likely to evict)
     assert!(strengthened_priority > initial_priority);
 }
+
+#[test]
+fn test_stale_plans_decay_and_are_pruned() {
+    let config = QueryPlanCacheConfig {
+        min_synaptic_threshold: 0.1,
+        synaptic_decay_rate: 0.5,
+        synaptic_decay_window: Duration::from_millis(50),
+        ..Default::default()
+    };
+    let mut cache = QueryPlanCache::with_config(config);
+    for query in ["STALE_QUERY", "FRESH_QUERY"] {
+        let cached = CachedQueryPlan::new(create_test_plan(), Duration::from_millis(10));
+        cache.insert(query.to_string(), cached);
+    }
+    let strength = |cache: &QueryPlanCache, query: &str| {
+        cache
+            .iter()
+            .find(|(cached, _)| *cached == query)
+            .map(|(_, plan)| plan.synaptic_strength)
+    };
+
+    // Nothing is stale yet
+    assert_eq!(cache.decay_stale_pathways(), 0);
+    assert_eq!(strength(&cache, "STALE_QUERY"), Some(0.5));
+
+    for expected in [0.25, 0.125] {
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(cache.get("FRESH_QUERY").is_some());
+        assert_eq!(cache.decay_stale_pathways(), 0);
+        assert_eq!(strength(&cache, "STALE_QUERY"), Some(expected));
+        assert_eq!(strength(&cache, "FRESH_QUERY"), Some(0.5));
+    }
+
+    // Below the threshold the stale plan is pruned
+    std::thread::sleep(Duration::from_millis(60));
+    assert!(cache.get("FRESH_QUERY").is_some());
+    assert_eq!(cache.decay_stale_pathways(), 1);
+    assert!(!cache.contains("STALE_QUERY"));
+    assert!(cache.contains("FRESH_QUERY"));
+    assert_eq!(cache.statistics().evictions, 1);
+}
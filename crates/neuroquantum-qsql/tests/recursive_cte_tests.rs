@@ -55,6 +55,7 @@ async fn test_recursive_cte_employee_hierarchy() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -62,6 +63,7 @@ async fn test_recursive_cte_employee_hierarchy() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "manager_id".to_string(),
@@ -69,6 +71,7 @@ async fn test_recursive_cte_employee_hierarchy() {
                 nullable: true,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -77,6 +80,7 @@ async fn test_recursive_cte_employee_hierarchy() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -159,6 +163,7 @@ async fn test_recursive_cte_union_semantics() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "parent_id".to_string(),
@@ -166,6 +171,7 @@ async fn test_recursive_cte_union_semantics() {
                 nullable: true,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -174,6 +180,7 @@ async fn test_recursive_cte_union_semantics() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -242,6 +249,7 @@ async fn test_recursive_cte_depth_limit() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "next_id".to_string(),
@@ -249,6 +257,7 @@ async fn test_recursive_cte_depth_limit() {
                 nullable: true,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -257,6 +266,7 @@ async fn test_recursive_cte_depth_limit() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -311,6 +321,7 @@ async fn test_recursive_cte_with_multiple_ctes() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -318,6 +329,7 @@ async fn test_recursive_cte_with_multiple_ctes() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "parent_id".to_string(),
@@ -325,6 +337,7 @@ async fn test_recursive_cte_with_multiple_ctes() {
                 nullable: true,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -333,6 +346,7 @@ async fn test_recursive_cte_with_multiple_ctes() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -390,6 +404,7 @@ async fn test_recursive_cte_graph_traversal() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "to_node".to_string(),
@@ -397,6 +412,7 @@ async fn test_recursive_cte_graph_traversal() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "from_node".to_string(),
@@ -405,6 +421,7 @@ async fn test_recursive_cte_graph_traversal() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
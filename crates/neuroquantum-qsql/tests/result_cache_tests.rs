@@ -0,0 +1,149 @@
+//! Tests for the query result cache
+//!
+//! Reads are served from the cache while the tables they read are unchanged;
+//! any write to such a table, through QSQL or the storage engine directly,
+//! makes the next read execute again.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use neuroquantum_core::storage::{Row, StorageEngine, Value};
+use neuroquantum_qsql::query_plan::QueryValue;
+use neuroquantum_qsql::{QSQLConfig, QSQLEngine};
+use tempfile::TempDir;
+use tokio::sync::RwLock;
+
+async fn setup() -> (TempDir, Arc<RwLock<StorageEngine>>, QSQLEngine) {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+    let storage_arc = Arc::new(RwLock::new(storage));
+    let mut engine = QSQLEngine::with_storage(storage_arc.clone()).unwrap();
+    engine
+        .execute_query("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)")
+        .await
+        .unwrap();
+    engine
+        .execute_query("INSERT INTO users (id, name) VALUES (1, 'Alice')")
+        .await
+        .unwrap();
+    engine
+        .execute_query("CREATE TABLE orders (id INTEGER PRIMARY KEY, amount INTEGER)")
+        .await
+        .unwrap();
+    (temp_dir, storage_arc, engine)
+}
+
+fn user_row(id: i64, name: &str) -> Row {
+    let fields = HashMap::from([
+        ("id".to_string(), Value::Integer(id)),
+        ("name".to_string(), Value::text(name)),
+    ]);
+    Row {
+        id: 0,
+        fields,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    }
+}
+
+fn names(result: &neuroquantum_qsql::QueryResult) -> Vec<String> {
+    let mut names: Vec<String> = result
+        .rows
+        .iter()
+        .filter_map(|row| match row.get("name") {
+            | Some(QueryValue::String(name)) => Some(name.clone()),
+            | _ => None,
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+#[tokio::test]
+async fn test_repeated_read_hits_result_cache() {
+    let (_temp_dir, _storage_arc, mut engine) = setup().await;
+
+    let first = engine.execute_query("SELECT * FROM users").await.unwrap();
+    assert_eq!(engine.metrics().result_cache_hits, 0);
+    assert_eq!(engine.result_cache_size(), 1);
+
+    // Whitespace differences normalize to the same cache key
+    let second = engine
+        .execute_query("SELECT *   FROM users;")
+        .await
+        .unwrap();
+    assert_eq!(engine.metrics().result_cache_hits, 1);
+    assert_eq!(names(&first), names(&second));
+
+    // A write to an unrelated table leaves the cached result valid
+    engine
+        .execute_query("INSERT INTO orders (id, amount) VALUES (1, 10)")
+        .await
+        .unwrap();
+    engine.execute_query("SELECT * FROM users").await.unwrap();
+    assert_eq!(engine.metrics().result_cache_hits, 2);
+}
+
+#[tokio::test]
+async fn test_write_invalidates_result_cache() {
+    let (_temp_dir, storage_arc, mut engine) = setup().await;
+
+    let before = engine.execute_query("SELECT * FROM users").await.unwrap();
+    assert_eq!(names(&before), vec!["Alice"]);
+
+    engine
+        .execute_query("INSERT INTO users (id, name) VALUES (2, 'Bob')")
+        .await
+        .unwrap();
+    let after_insert = engine.execute_query("SELECT * FROM users").await.unwrap();
+    assert_eq!(names(&after_insert), vec!["Alice", "Bob"]);
+    assert_eq!(engine.metrics().result_cache_hits, 0);
+    assert_eq!(engine.result_cache_statistics().invalidations, 1);
+
+    // Writes that bypass QSQL invalidate the cache as well
+    storage_arc
+        .write()
+        .await
+        .insert_row("users", user_row(3, "Carol"))
+        .await
+        .unwrap();
+    let after_direct_write = engine.execute_query("SELECT * FROM users").await.unwrap();
+    assert_eq!(names(&after_direct_write), vec!["Alice", "Bob", "Carol"]);
+    assert_eq!(engine.metrics().result_cache_hits, 0);
+
+    engine.execute_query("SELECT * FROM users").await.unwrap();
+    assert_eq!(engine.metrics().result_cache_hits, 1);
+}
+
+#[tokio::test]
+async fn test_result_cache_skips_non_deterministic_and_disabled() {
+    let (_temp_dir, _storage_arc, mut engine) = setup().await;
+
+    engine
+        .execute_query("SELECT id, NOW() FROM users")
+        .await
+        .unwrap();
+    engine
+        .execute_query("SELECT id, NOW() FROM users")
+        .await
+        .unwrap();
+    assert_eq!(engine.metrics().result_cache_hits, 0);
+    assert_eq!(engine.result_cache_size(), 0);
+
+    let temp_dir = TempDir::new().unwrap();
+    let storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+    let mut disabled = QSQLEngine::with_config(QSQLConfig {
+        result_cache_size: 0,
+        ..QSQLConfig::default()
+    })
+    .unwrap();
+    disabled.set_storage_engine(Arc::new(RwLock::new(storage)));
+    disabled
+        .execute_query("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)")
+        .await
+        .unwrap();
+    disabled.execute_query("SELECT * FROM users").await.unwrap();
+    disabled.execute_query("SELECT * FROM users").await.unwrap();
+    assert_eq!(disabled.metrics().result_cache_hits, 0);
+    assert_eq!(disabled.result_cache_size(), 0);
+}
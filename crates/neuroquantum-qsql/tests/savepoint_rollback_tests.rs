@@ -10,6 +10,7 @@ use neuroquantum_core::storage::{
     ColumnDefinition, DataType, IdGenerationStrategy, StorageEngine, TableSchema,
 };
 use neuroquantum_core::transaction::TransactionManager;
+use neuroquantum_qsql::query_plan::QueryValue;
 use neuroquantum_qsql::{ExecutorConfig, Parser, QueryExecutor};
 use tempfile::TempDir;
 
@@ -55,6 +56,7 @@ async fn create_test_table(storage_arc: &Arc<tokio::sync::RwLock<StorageEngine>>
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "value".to_string(),
@@ -62,6 +64,7 @@ async fn create_test_table(storage_arc: &Arc<tokio::sync::RwLock<StorageEngine>>
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -70,6 +73,7 @@ async fn create_test_table(storage_arc: &Arc<tokio::sync::RwLock<StorageEngine>>
         auto_increment_columns: HashMap::new(),
         id_strategy: IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     let mut storage = storage_arc.write().await;
@@ -326,3 +330,58 @@ async fn test_rollback_to_savepoint_with_operations() {
 
     println!("✅ ROLLBACK TO SAVEPOINT with operations test: SUCCESS");
 }
+
+#[tokio::test]
+async fn test_rollback_to_savepoint_restores_rows() {
+    let (_temp_dir, storage_arc, _tx_manager, mut executor) = setup_test_environment().await;
+    create_test_table(&storage_arc).await;
+
+    let parser = Parser::new();
+    for sql in [
+        "INSERT INTO test_table (id, value) VALUES (1, 'first')",
+        "INSERT INTO test_table (id, value) VALUES (2, 'second')",
+        "CREATE INDEX idx_test_value ON test_table (value)",
+        "BEGIN",
+        "SAVEPOINT sp1",
+        "UPDATE test_table SET value = 'changed' WHERE id = 1",
+        "DELETE FROM test_table WHERE id = 2",
+        "INSERT INTO test_table (id, value) VALUES (3, 'third')",
+    ] {
+        let statement = parser.parse(sql).unwrap();
+        executor.execute_statement(&statement).await.unwrap();
+    }
+
+    // The update, the delete and the insert are undone
+    let rollback_to_stmt = parser.parse("ROLLBACK TO SAVEPOINT sp1").unwrap();
+    let result = executor.execute_statement(&rollback_to_stmt).await.unwrap();
+    assert_eq!(result.rows_affected, 3);
+
+    let commit_stmt = parser.parse("COMMIT").unwrap();
+    executor.execute_statement(&commit_stmt).await.unwrap();
+
+    let select_stmt = parser
+        .parse("SELECT id, value FROM test_table ORDER BY id")
+        .unwrap();
+    let result = executor.execute_statement(&select_stmt).await.unwrap();
+    let values: Vec<_> = result.rows.iter().map(|row| row.get("value")).collect();
+    assert_eq!(
+        values,
+        vec![
+            Some(&QueryValue::String("first".to_string())),
+            Some(&QueryValue::String("second".to_string()))
+        ]
+    );
+
+    // The index no longer holds the undone update and has the deleted row again
+    let select_stmt = parser
+        .parse("SELECT value FROM test_table WHERE value = 'changed'")
+        .unwrap();
+    let result = executor.execute_statement(&select_stmt).await.unwrap();
+    assert!(result.rows.is_empty());
+
+    let select_stmt = parser
+        .parse("SELECT id FROM test_table WHERE value = 'second'")
+        .unwrap();
+    let result = executor.execute_statement(&select_stmt).await.unwrap();
+    assert_eq!(result.rows.len(), 1);
+}
@@ -0,0 +1,246 @@
+//! Tests for ANALYZE and statistics-driven optimization
+//!
+//! `accounts` has a skewed `status` column (90% 'active', 10% 'banned') and a
+//! uniform `region` column (four values), so the most selective predicate
+//! depends on the value being compared.
+
+use std::sync::Arc;
+
+use neuroquantum_core::storage::{create_test_row, StorageEngine, Value};
+use neuroquantum_qsql::ast::{Expression, Statement};
+use neuroquantum_qsql::optimizer::{JoinInput, NeuromorphicOptimizer};
+use neuroquantum_qsql::query_plan::QueryValue;
+use neuroquantum_qsql::statistics::{fresh_statistics, StatisticsCollector};
+use neuroquantum_qsql::{ExecutorConfig, Parser, QueryExecutor};
+use tempfile::TempDir;
+
+const REGIONS: [&str; 4] = ["eu", "us", "apac", "latam"];
+
+async fn setup_accounts(
+    rows: i64,
+) -> (
+    TempDir,
+    Arc<tokio::sync::RwLock<StorageEngine>>,
+    QueryExecutor,
+) {
+    let temp_dir = TempDir::new().unwrap();
+    let storage = StorageEngine::new(temp_dir.path()).await.unwrap();
+    let storage_arc = Arc::new(tokio::sync::RwLock::new(storage));
+    let mut executor =
+        QueryExecutor::with_storage(ExecutorConfig::default(), storage_arc.clone()).unwrap();
+
+    execute(
+        &mut executor,
+        "CREATE TABLE accounts (id INTEGER PRIMARY KEY, status TEXT, region TEXT)",
+    )
+    .await;
+    insert_accounts(&mut executor, 1..=rows).await;
+
+    (temp_dir, storage_arc, executor)
+}
+
+async fn insert_accounts(executor: &mut QueryExecutor, ids: std::ops::RangeInclusive<i64>) {
+    for id in ids {
+        let status = if id % 10 == 0 { "banned" } else { "active" };
+        let region = REGIONS[(id % 4) as usize];
+        execute(
+            executor,
+            &format!(
+                "INSERT INTO accounts (id, status, region) VALUES ({id}, '{status}', '{region}')"
+            ),
+        )
+        .await;
+    }
+}
+
+async fn execute(
+    executor: &mut QueryExecutor,
+    sql: &str,
+) -> neuroquantum_qsql::query_plan::QueryResult {
+    let statement = Parser::new().parse(sql).unwrap();
+    executor.execute_statement(&statement).await.unwrap()
+}
+
+fn where_clause(sql: &str) -> Expression {
+    let Statement::Select(select) = Parser::new().parse(sql).unwrap() else {
+        panic!("expected SELECT");
+    };
+    select.where_clause.unwrap()
+}
+
+fn compared_column(predicate: &Expression) -> &str {
+    let Expression::BinaryOp { left, .. } = predicate else {
+        panic!("expected comparison, got {predicate:?}");
+    };
+    let Expression::Identifier(column) = left.as_ref() else {
+        panic!("expected column, got {left:?}");
+    };
+    column
+}
+
+#[tokio::test]
+async fn test_analyze_collects_column_statistics() {
+    let (_temp_dir, storage_arc, mut executor) = setup_accounts(40).await;
+
+    let result = execute(&mut executor, "ANALYZE accounts").await;
+    assert_eq!(result.rows.len(), 3);
+    let status_row = result
+        .rows
+        .iter()
+        .find(|row| row.get("column_name") == Some(&QueryValue::String("status".to_string())))
+        .unwrap();
+    assert_eq!(status_row.get("row_count"), Some(&QueryValue::Integer(40)));
+    assert_eq!(
+        status_row.get("distinct_values"),
+        Some(&QueryValue::Integer(2))
+    );
+
+    let storage = storage_arc.read().await;
+    let statistics = storage.get_table_statistics("accounts").unwrap();
+    assert_eq!(statistics.row_count, 40);
+    let status = &statistics.columns["status"];
+    assert_eq!(status.null_fraction, 0.0);
+    assert_eq!(
+        status.most_common_values,
+        vec![(Value::text("active"), 0.9), (Value::text("banned"), 0.1)]
+    );
+    let id = &statistics.columns["id"];
+    assert_eq!(id.distinct_count, 40);
+    assert!(id.most_common_values.is_empty());
+    assert_eq!(id.histogram_bounds.first(), Some(&Value::Integer(1)));
+    assert_eq!(id.histogram_bounds.last(), Some(&Value::Integer(40)));
+    drop(storage);
+
+    let result = execute(&mut executor, "ANALYZE TABLE accounts (status, region)").await;
+    assert_eq!(result.rows.len(), 2);
+    let storage = storage_arc.read().await;
+    let statistics = storage.get_table_statistics("accounts").unwrap();
+    assert!(!statistics.columns.contains_key("id"));
+}
+
+#[tokio::test]
+async fn test_optimizer_orders_predicates_by_selectivity() {
+    let (_temp_dir, storage_arc, mut executor) = setup_accounts(40).await;
+    execute(&mut executor, "ANALYZE accounts").await;
+    let storage = storage_arc.read().await;
+    let statistics = fresh_statistics(&storage, "accounts");
+    assert!(statistics.is_some());
+
+    // 'active' matches 90% of rows, a single region 25%
+    let predicate =
+        where_clause("SELECT * FROM accounts WHERE status = 'active' AND region = 'eu'");
+    let ordered = NeuromorphicOptimizer::order_predicates(statistics, &predicate);
+    assert_eq!(compared_column(ordered[0]), "region");
+
+    // 'banned' matches only 10% of rows
+    let predicate =
+        where_clause("SELECT * FROM accounts WHERE region = 'eu' AND status = 'banned'");
+    let ordered = NeuromorphicOptimizer::order_predicates(statistics, &predicate);
+    assert_eq!(compared_column(ordered[0]), "status");
+    let selectivity = NeuromorphicOptimizer::estimate_selectivity(statistics, ordered[0]);
+    assert!((selectivity - 0.1).abs() < 1e-9);
+
+    // Without statistics the query order is kept
+    let ordered = NeuromorphicOptimizer::order_predicates(None, &predicate);
+    assert_eq!(compared_column(ordered[0]), "region");
+    drop(storage);
+
+    // Reordered conditions still return the right rows
+    let result = execute(
+        &mut executor,
+        "SELECT * FROM accounts WHERE status = 'active' AND region = 'eu'",
+    )
+    .await;
+    assert_eq!(result.rows.len(), 8);
+}
+
+#[tokio::test]
+async fn test_statistics_become_stale_after_row_count_drift() {
+    let (temp_dir, storage_arc, mut executor) = setup_accounts(40).await;
+    execute(&mut executor, "ANALYZE accounts").await;
+
+    // 10% growth stays within the threshold
+    insert_accounts(&mut executor, 41..=44).await;
+    assert!(fresh_statistics(&*storage_arc.read().await, "accounts").is_some());
+
+    // 30% growth does not
+    insert_accounts(&mut executor, 45..=52).await;
+    {
+        let storage = storage_arc.read().await;
+        let statistics = storage.get_table_statistics("accounts").unwrap();
+        assert!(statistics.is_stale(52, 0.2));
+        assert!(fresh_statistics(&storage, "accounts").is_none());
+    }
+
+    // Statistics are persisted with the schema
+    storage_arc.write().await.flush_to_disk().await.unwrap();
+    let reopened = StorageEngine::new(temp_dir.path()).await.unwrap();
+    assert_eq!(
+        reopened.get_table_statistics("accounts").unwrap().row_count,
+        40
+    );
+}
+
+#[test]
+fn test_collector_histogram_for_unique_values() {
+    let rows: Vec<_> = (1..=100).map(|id| create_test_row(id, "row")).collect();
+    let statistics = StatisticsCollector::with_limits(10, 4).collect(&rows, &["id".to_string()]);
+    let id = &statistics.columns["id"];
+    assert_eq!(id.distinct_count, 100);
+    assert_eq!(
+        id.histogram_bounds,
+        vec![
+            Value::Integer(1),
+            Value::Integer(25),
+            Value::Integer(50),
+            Value::Integer(75),
+            Value::Integer(100),
+        ]
+    );
+
+    let predicate = where_clause("SELECT * FROM t WHERE id < 50");
+    let selectivity = NeuromorphicOptimizer::estimate_selectivity(Some(&statistics), &predicate);
+    assert!((0.3..=0.7).contains(&selectivity), "{selectivity}");
+}
+
+#[test]
+fn test_inner_joins_ordered_by_estimated_rows() {
+    let small_to_large = where_clause("SELECT * FROM t WHERE large.id = small.large_id");
+    let joins = [
+        JoinInput {
+            alias: "large",
+            estimated_rows: 10_000.0,
+            condition: None,
+        },
+        JoinInput {
+            alias: "small",
+            estimated_rows: 10.0,
+            condition: Some(&small_to_large),
+        },
+        JoinInput {
+            alias: "medium",
+            estimated_rows: 500.0,
+            condition: None,
+        },
+    ];
+
+    // `small` goes first; nothing forces `large` before `medium`
+    let independent = [
+        JoinInput {
+            condition: None,
+            ..joins[1].clone()
+        },
+        joins[0].clone(),
+        joins[2].clone(),
+    ];
+    assert_eq!(
+        NeuromorphicOptimizer::order_inner_joins("base", &independent),
+        vec![0, 2, 1]
+    );
+
+    // `small` references `large`, so `large` must be joined before it
+    assert_eq!(
+        NeuromorphicOptimizer::order_inner_joins("base", &joins),
+        vec![2, 0, 1]
+    );
+}
@@ -33,6 +33,7 @@ async fn test_insert_with_dna_compression() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -40,6 +41,7 @@ async fn test_insert_with_dna_compression() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "email".to_string(),
@@ -47,6 +49,7 @@ async fn test_insert_with_dna_compression() {
                 nullable: true,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -55,6 +58,7 @@ async fn test_insert_with_dna_compression() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -127,6 +131,7 @@ async fn test_select_with_dna_decompression() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -134,6 +139,7 @@ async fn test_select_with_dna_decompression() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "price".to_string(),
@@ -141,6 +147,7 @@ async fn test_select_with_dna_decompression() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -149,6 +156,7 @@ async fn test_select_with_dna_decompression() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -208,6 +216,7 @@ async fn test_update_with_dna_recompression() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "salary".to_string(),
@@ -215,6 +224,7 @@ async fn test_update_with_dna_recompression() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -223,6 +233,7 @@ async fn test_update_with_dna_recompression() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -328,6 +339,7 @@ async fn test_delete_with_dna_cleanup() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "message".to_string(),
@@ -335,6 +347,7 @@ async fn test_delete_with_dna_cleanup() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -343,6 +356,7 @@ async fn test_delete_with_dna_cleanup() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -428,6 +442,7 @@ async fn test_drop_table_removes_table() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "data".to_string(),
@@ -435,6 +450,7 @@ async fn test_drop_table_removes_table() {
                 nullable: true,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -443,6 +459,7 @@ async fn test_drop_table_removes_table() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -570,6 +587,7 @@ async fn test_drop_table_cleans_up_files() {
             nullable: false,
             default_value: None,
             auto_increment: true,
+            ..Default::default()
         }],
         primary_key: "id".to_string(),
         created_at: chrono::Utc::now(),
@@ -577,6 +595,7 @@ async fn test_drop_table_cleans_up_files() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -630,6 +649,7 @@ async fn test_cte_with_storage() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -637,6 +657,7 @@ async fn test_cte_with_storage() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "status".to_string(),
@@ -644,6 +665,7 @@ async fn test_cte_with_storage() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "age".to_string(),
@@ -651,6 +673,7 @@ async fn test_cte_with_storage() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -659,6 +682,7 @@ async fn test_cte_with_storage() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -761,6 +785,7 @@ async fn test_update_without_where_clause_affects_all_rows() {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -768,6 +793,7 @@ async fn test_update_without_where_clause_affects_all_rows() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "status".to_string(),
@@ -775,6 +801,7 @@ async fn test_update_without_where_clause_affects_all_rows() {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -783,6 +810,7 @@ async fn test_update_without_where_clause_affects_all_rows() {
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
@@ -27,6 +27,7 @@ async fn setup_test_tables(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>)
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -34,6 +35,7 @@ async fn setup_test_tables(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>)
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "age".to_string(),
@@ -41,6 +43,7 @@ async fn setup_test_tables(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>)
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "department_id".to_string(),
@@ -48,6 +51,7 @@ async fn setup_test_tables(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>)
                 nullable: true,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -56,6 +60,7 @@ async fn setup_test_tables(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>)
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     // Create departments table
@@ -68,6 +73,7 @@ async fn setup_test_tables(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>)
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -75,6 +81,7 @@ async fn setup_test_tables(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>)
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "active".to_string(),
@@ -82,6 +89,7 @@ async fn setup_test_tables(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>)
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -90,6 +98,7 @@ async fn setup_test_tables(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>)
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     // Create orders table
@@ -102,6 +111,7 @@ async fn setup_test_tables(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>)
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "user_id".to_string(),
@@ -109,6 +119,7 @@ async fn setup_test_tables(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>)
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "amount".to_string(),
@@ -116,6 +127,7 @@ async fn setup_test_tables(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>)
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -124,6 +136,7 @@ async fn setup_test_tables(storage_arc: Arc<tokio::sync::RwLock<StorageEngine>>)
         auto_increment_columns: HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     let mut storage_guard = storage_arc.write().await;
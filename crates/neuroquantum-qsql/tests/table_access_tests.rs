@@ -0,0 +1,70 @@
+//! Tests for collecting the tables a statement reads and writes
+
+use neuroquantum_qsql::table_access::{
+    names_tables_indirectly, table_accesses, AccessKind, TableAccess,
+};
+use neuroquantum_qsql::Parser;
+
+fn accesses(sql: &str) -> Vec<TableAccess> {
+    let statement = Parser::new().parse_query(sql).unwrap();
+    table_accesses(&statement)
+}
+
+fn read(table: &str) -> TableAccess {
+    TableAccess {
+        table: table.to_string(),
+        kind: AccessKind::Read,
+    }
+}
+
+fn write(table: &str) -> TableAccess {
+    TableAccess {
+        table: table.to_string(),
+        kind: AccessKind::Write,
+    }
+}
+
+#[test]
+fn test_select_reports_joined_and_subquery_tables() {
+    assert_eq!(accesses("SELECT * FROM users"), vec![read("users")]);
+
+    let found = accesses(
+        "SELECT u.name FROM users u JOIN orders o ON u.id = o.user_id \
+         WHERE u.id IN (SELECT user_id FROM audit)",
+    );
+    assert_eq!(found.len(), 3);
+    for table in ["users", "orders", "audit"] {
+        assert!(found.contains(&read(table)), "{table} missing");
+    }
+}
+
+#[test]
+fn test_cte_names_are_not_tables() {
+    let found = accesses("WITH recent AS (SELECT * FROM orders) SELECT * FROM recent");
+    assert_eq!(found, vec![read("orders")]);
+}
+
+#[test]
+fn test_writes_are_distinguished_from_reads() {
+    assert_eq!(
+        accesses("INSERT INTO users (id, name) VALUES (1, 'Ada')"),
+        vec![write("users")]
+    );
+    assert_eq!(
+        accesses("DELETE FROM users WHERE id IN (SELECT user_id FROM banned)"),
+        vec![write("users"), read("banned")]
+    );
+    assert_eq!(accesses("DROP TABLE users"), vec![write("users")]);
+}
+
+#[test]
+fn test_indirect_references_are_flagged() {
+    let statement = Parser::new()
+        .parse_query("DROP INDEX idx_users_name")
+        .unwrap();
+    assert!(names_tables_indirectly(&statement));
+    assert!(table_accesses(&statement).is_empty());
+
+    let statement = Parser::new().parse_query("SELECT * FROM users").unwrap();
+    assert!(!names_tables_indirectly(&statement));
+}
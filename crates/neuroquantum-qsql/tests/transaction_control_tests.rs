@@ -25,6 +25,15 @@ fn test_parse_transaction_statements() {
     assert!(result.is_ok(), "Failed to parse COMMIT");
     matches!(result.unwrap(), Statement::Commit(_));
 
+    // Test COMMIT with a durability
+    match parser.parse("COMMIT RELAXED").unwrap() {
+        | Statement::Commit(commit) => {
+            assert_eq!(commit.durability.as_deref(), Some("RELAXED"));
+        },
+        | other => panic!("Expected Commit, got {other:?}"),
+    }
+    assert!(parser.parse("COMMIT EVENTUALLY").is_err());
+
     // Test ROLLBACK
     let result = parser.parse("ROLLBACK");
     assert!(result.is_ok(), "Failed to parse ROLLBACK");
@@ -7,6 +7,7 @@ use std::sync::Arc;
 
 use neuroquantum_core::storage::{ColumnDefinition, DataType, StorageEngine, TableSchema, Value};
 use neuroquantum_core::transaction::TransactionManager;
+use neuroquantum_qsql::query_plan::{QueryResult, QueryValue};
 use neuroquantum_qsql::{ExecutorConfig, Parser, QueryExecutor};
 use tempfile::TempDir;
 
@@ -52,6 +53,7 @@ async fn create_test_table(storage_arc: &Arc<tokio::sync::RwLock<StorageEngine>>
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -59,6 +61,7 @@ async fn create_test_table(storage_arc: &Arc<tokio::sync::RwLock<StorageEngine>>
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "balance".to_string(),
@@ -66,6 +69,7 @@ async fn create_test_table(storage_arc: &Arc<tokio::sync::RwLock<StorageEngine>>
                 nullable: false,
                 default_value: Some(Value::Integer(0)),
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -74,6 +78,7 @@ async fn create_test_table(storage_arc: &Arc<tokio::sync::RwLock<StorageEngine>>
         auto_increment_columns: std::collections::HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     let mut storage_guard = storage_arc.write().await;
@@ -143,6 +148,50 @@ async fn test_begin_rollback_basic() {
     println!("✅ BEGIN/ROLLBACK basic test: SUCCESS");
 }
 
+#[tokio::test]
+async fn test_commit_relaxed_skips_fsync() {
+    let (_temp_dir, storage_arc, _tx_manager, mut executor) = setup_test_environment().await;
+    create_test_table(&storage_arc).await;
+
+    let parser = Parser::new();
+    let sync_count = || async {
+        storage_arc
+            .read()
+            .await
+            .get_transaction_manager()
+            .log_manager()
+            .sync_count()
+    };
+
+    for statement in [
+        "BEGIN",
+        "INSERT INTO users (id, name, balance) VALUES (1, 'Alice', 100)",
+    ] {
+        let statement = parser.parse(statement).unwrap();
+        executor.execute_statement(&statement).await.unwrap();
+    }
+    let syncs = sync_count().await;
+    let commit_stmt = parser.parse("COMMIT RELAXED").unwrap();
+    executor.execute_statement(&commit_stmt).await.unwrap();
+    assert_eq!(sync_count().await, syncs);
+
+    // The commit is visible even before it reaches disk
+    let select_stmt = parser.parse("SELECT * FROM users WHERE id = 1").unwrap();
+    let result = executor.execute_statement(&select_stmt).await.unwrap();
+    assert_eq!(result.rows.len(), 1);
+
+    // A plain COMMIT is durable
+    for statement in [
+        "BEGIN",
+        "INSERT INTO users (id, name, balance) VALUES (2, 'Bob', 200)",
+        "COMMIT",
+    ] {
+        let statement = parser.parse(statement).unwrap();
+        executor.execute_statement(&statement).await.unwrap();
+    }
+    assert!(sync_count().await > syncs);
+}
+
 #[tokio::test]
 async fn test_start_transaction_commit() {
     let (_temp_dir, storage_arc, _tx_manager, mut executor) = setup_test_environment().await;
@@ -368,3 +417,140 @@ async fn test_release_savepoint() {
 
     println!("✅ RELEASE SAVEPOINT test: SUCCESS");
 }
+
+/// Parse and execute one statement
+async fn run(executor: &mut QueryExecutor, sql: &str) -> Result<QueryResult, String> {
+    let statement = Parser::new().parse(sql).map_err(|e| e.to_string())?;
+    executor
+        .execute_statement(&statement)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Balances of all users, ordered by id
+async fn balances(executor: &mut QueryExecutor) -> Vec<QueryValue> {
+    let result = run(executor, "SELECT id, balance FROM users ORDER BY id")
+        .await
+        .unwrap();
+    result
+        .rows
+        .iter()
+        .map(|row| row.get("balance").cloned().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_snapshot_transaction_reads_stable_snapshot() {
+    let (_temp_dir, storage_arc, _tx_manager, mut reader) = setup_test_environment().await;
+    create_test_table(&storage_arc).await;
+    let mut writer =
+        QueryExecutor::with_storage(ExecutorConfig::default(), storage_arc.clone()).unwrap();
+
+    run(
+        &mut writer,
+        "INSERT INTO users (id, name, balance) VALUES (1, 'Alice', 100)",
+    )
+    .await
+    .unwrap();
+    run(
+        &mut writer,
+        "INSERT INTO users (id, name, balance) VALUES (2, 'Bob', 200)",
+    )
+    .await
+    .unwrap();
+
+    run(&mut reader, "BEGIN ISOLATION LEVEL SNAPSHOT")
+        .await
+        .unwrap();
+    assert_eq!(
+        balances(&mut reader).await,
+        vec![QueryValue::Integer(100), QueryValue::Integer(200)]
+    );
+
+    // Changes committed by another session after the snapshot stay invisible
+    run(&mut writer, "UPDATE users SET balance = 50 WHERE id = 1")
+        .await
+        .unwrap();
+    run(&mut writer, "DELETE FROM users WHERE id = 2")
+        .await
+        .unwrap();
+    run(
+        &mut writer,
+        "INSERT INTO users (id, name, balance) VALUES (3, 'Carol', 300)",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        balances(&mut reader).await,
+        vec![QueryValue::Integer(100), QueryValue::Integer(200)]
+    );
+
+    // The snapshot's own writes are visible to it, but not to others
+    run(
+        &mut reader,
+        "INSERT INTO users (id, name, balance) VALUES (4, 'Dave', 400)",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        balances(&mut reader).await,
+        vec![
+            QueryValue::Integer(100),
+            QueryValue::Integer(200),
+            QueryValue::Integer(400)
+        ]
+    );
+    assert_eq!(
+        balances(&mut writer).await,
+        vec![QueryValue::Integer(50), QueryValue::Integer(300)]
+    );
+
+    run(&mut reader, "COMMIT").await.unwrap();
+    assert_eq!(
+        balances(&mut reader).await,
+        vec![
+            QueryValue::Integer(50),
+            QueryValue::Integer(300),
+            QueryValue::Integer(400)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_snapshot_write_conflict_first_committer_wins() {
+    let (_temp_dir, storage_arc, _tx_manager, mut first) = setup_test_environment().await;
+    create_test_table(&storage_arc).await;
+    let mut second =
+        QueryExecutor::with_storage(ExecutorConfig::default(), storage_arc.clone()).unwrap();
+
+    run(
+        &mut first,
+        "INSERT INTO users (id, name, balance) VALUES (1, 'Alice', 100)",
+    )
+    .await
+    .unwrap();
+
+    run(&mut first, "BEGIN ISOLATION LEVEL SNAPSHOT")
+        .await
+        .unwrap();
+    run(&mut second, "BEGIN ISOLATION LEVEL SNAPSHOT")
+        .await
+        .unwrap();
+
+    run(&mut first, "UPDATE users SET balance = 150 WHERE id = 1")
+        .await
+        .unwrap();
+    run(&mut first, "COMMIT").await.unwrap();
+
+    // The second transaction's snapshot predates the committed update
+    let error = run(&mut second, "UPDATE users SET balance = 0 WHERE id = 1")
+        .await
+        .unwrap_err();
+    assert!(
+        error.contains("modified by a concurrent transaction"),
+        "unexpected error: {error}"
+    );
+    assert!(run(&mut second, "COMMIT").await.is_err());
+
+    assert_eq!(balances(&mut first).await, vec![QueryValue::Integer(150)]);
+}
@@ -30,6 +30,7 @@ async fn setup_test_db() -> (TempDir, Arc<tokio::sync::RwLock<StorageEngine>>) {
                 nullable: false,
                 default_value: None,
                 auto_increment: true,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "name".to_string(),
@@ -37,6 +38,7 @@ async fn setup_test_db() -> (TempDir, Arc<tokio::sync::RwLock<StorageEngine>>) {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "department".to_string(),
@@ -44,6 +46,7 @@ async fn setup_test_db() -> (TempDir, Arc<tokio::sync::RwLock<StorageEngine>>) {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
             ColumnDefinition {
                 name: "salary".to_string(),
@@ -51,6 +54,7 @@ async fn setup_test_db() -> (TempDir, Arc<tokio::sync::RwLock<StorageEngine>>) {
                 nullable: false,
                 default_value: None,
                 auto_increment: false,
+                ..Default::default()
             },
         ],
         primary_key: "id".to_string(),
@@ -59,6 +63,7 @@ async fn setup_test_db() -> (TempDir, Arc<tokio::sync::RwLock<StorageEngine>>) {
         auto_increment_columns: std::collections::HashMap::new(),
         id_strategy: neuroquantum_core::storage::IdGenerationStrategy::AutoIncrement,
         foreign_keys: Vec::new(),
+        ..Default::default()
     };
 
     {
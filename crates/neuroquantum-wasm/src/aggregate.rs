@@ -0,0 +1,250 @@
+//! Aggregate functions for WASM `SELECT` queries
+//!
+//! Supports `COUNT(*)`, `COUNT(col)`, `SUM`, `AVG`, `MIN` and `MAX`, each
+//! optionally named with `AS alias`. A query with aggregates returns a single
+//! row holding one value per aggregate. Following SQL, `COUNT(col)` and the
+//! other functions skip nulls and missing values, and all but `COUNT` yield
+//! null over an empty input.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use serde_json::{Number, Value};
+
+type Row = HashMap<String, Value>;
+
+/// An aggregate function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregateFunction {
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
+impl AggregateFunction {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            | "COUNT" => Some(Self::Count),
+            | "SUM" => Some(Self::Sum),
+            | "AVG" => Some(Self::Avg),
+            | "MIN" => Some(Self::Min),
+            | "MAX" => Some(Self::Max),
+            | _ => None,
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            | Self::Count => "COUNT",
+            | Self::Sum => "SUM",
+            | Self::Avg => "AVG",
+            | Self::Min => "MIN",
+            | Self::Max => "MAX",
+        }
+    }
+}
+
+/// An aggregate in a select list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Aggregate {
+    pub function: AggregateFunction,
+    /// Aggregated column, `None` for `COUNT(*)`
+    pub column: Option<String>,
+    /// Name of the result column: the alias, or the expression as written
+    pub label: String,
+}
+
+/// Parse the select list of a query
+///
+/// Returns `None` when the list contains no aggregates, and an error when
+/// it mixes aggregates with plain columns, as there is no `GROUP BY`.
+pub fn parse_select_list(list: &str) -> Result<Option<Vec<Aggregate>>, String> {
+    let items: Vec<Option<Aggregate>> =
+        list.split(',').map(parse_item).collect::<Result<_, _>>()?;
+
+    if items.iter().all(Option::is_none) {
+        return Ok(None);
+    }
+    items
+        .into_iter()
+        .map(|item| item.ok_or_else(|| "Cannot mix aggregates and plain columns".to_string()))
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
+/// Parse `FUNC(arg) [AS alias]`, or `None` for anything but an aggregate
+fn parse_item(item: &str) -> Result<Option<Aggregate>, String> {
+    let item = item.trim();
+    let (expr, alias) = match item.to_ascii_uppercase().find(" AS ") {
+        | Some(pos) => (item[..pos].trim(), Some(item[pos + 4..].trim())),
+        | None => (item, None),
+    };
+
+    let Some(open) = expr.find('(') else {
+        return Ok(None);
+    };
+    let Some(function) = AggregateFunction::from_name(expr[..open].trim()) else {
+        return Ok(None);
+    };
+    let arg = expr[open + 1..]
+        .strip_suffix(')')
+        .ok_or_else(|| format!("Missing ')' in {expr}"))?
+        .trim();
+
+    let column = match arg {
+        | "" => return Err(format!("{} requires an argument", function.name())),
+        | "*" if function == AggregateFunction::Count => None,
+        | "*" => return Err(format!("{}(*) is not supported", function.name())),
+        | column => Some(column.to_string()),
+    };
+
+    Ok(Some(Aggregate {
+        function,
+        column,
+        label: alias.unwrap_or(expr).to_string(),
+    }))
+}
+
+/// Compute the aggregates over `rows` into a single result row
+pub fn evaluate(aggregates: &[Aggregate], rows: &[Row]) -> Result<Row, String> {
+    let mut accumulator = Accumulator::new(aggregates.to_vec());
+    accumulator.add(rows);
+    accumulator.finish()
+}
+
+/// Aggregates of a query, fed their input rows in batches
+#[derive(Debug)]
+pub struct Accumulator<'a> {
+    aggregates: Vec<Aggregate>,
+    rows: usize,
+    /// Non-null values of each aggregate's column, empty for `COUNT(*)`
+    values: Vec<Vec<&'a Value>>,
+}
+
+impl<'a> Accumulator<'a> {
+    pub fn new(aggregates: Vec<Aggregate>) -> Self {
+        let values = vec![Vec::new(); aggregates.len()];
+        Self {
+            aggregates,
+            rows: 0,
+            values,
+        }
+    }
+
+    /// Labels of the aggregates, the columns of the result row
+    pub fn labels(&self) -> Vec<String> {
+        self.aggregates.iter().map(|a| a.label.clone()).collect()
+    }
+
+    pub fn add(&mut self, rows: &'a [Row]) {
+        self.rows += rows.len();
+        for (aggregate, values) in self.aggregates.iter().zip(&mut self.values) {
+            let Some(column) = &aggregate.column else {
+                continue;
+            };
+            values.extend(
+                rows.iter()
+                    .filter_map(|row| column_value(row, column))
+                    .filter(|value| !value.is_null()),
+            );
+        }
+    }
+
+    /// Compute the result row from the rows added so far
+    pub fn finish(self) -> Result<Row, String> {
+        let rows = self.rows;
+        self.aggregates
+            .iter()
+            .zip(&self.values)
+            .map(|(aggregate, values)| {
+                Ok((
+                    aggregate.label.clone(),
+                    evaluate_one(aggregate, rows, values)?,
+                ))
+            })
+            .collect()
+    }
+}
+
+fn evaluate_one(aggregate: &Aggregate, rows: usize, values: &[&Value]) -> Result<Value, String> {
+    let Some(column) = &aggregate.column else {
+        return Ok(Value::from(rows));
+    };
+
+    match aggregate.function {
+        | AggregateFunction::Count => Ok(Value::from(values.len())),
+        | AggregateFunction::Sum => sum(values, column),
+        | AggregateFunction::Avg => {
+            if values.is_empty() {
+                return Ok(Value::Null);
+            }
+            let total = numbers(values, column, "AVG")?.iter().sum::<f64>();
+            Ok(Number::from_f64(total / values.len() as f64).map_or(Value::Null, Value::Number))
+        },
+        | AggregateFunction::Min => extreme(values, column, Ordering::Less),
+        | AggregateFunction::Max => extreme(values, column, Ordering::Greater),
+    }
+}
+
+/// Value of `column` in `row`, matching the name case-insensitively
+fn column_value<'a>(row: &'a Row, column: &str) -> Option<&'a Value> {
+    row.get(column).or_else(|| {
+        row.iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(column))
+            .map(|(_, value)| value)
+    })
+}
+
+fn numbers(values: &[&Value], column: &str, function: &str) -> Result<Vec<f64>, String> {
+    values
+        .iter()
+        .map(|value| {
+            value
+                .as_f64()
+                .ok_or_else(|| format!("{function}({column}) requires a numeric column"))
+        })
+        .collect()
+}
+
+/// Sum as an integer while all values are integers and it fits, otherwise as a float
+fn sum(values: &[&Value], column: &str) -> Result<Value, String> {
+    if values.is_empty() {
+        return Ok(Value::Null);
+    }
+    let integers: Option<i64> = values
+        .iter()
+        .try_fold(0i64, |total, value| total.checked_add(value.as_i64()?));
+    if let Some(total) = integers {
+        return Ok(Value::from(total));
+    }
+    let total = numbers(values, column, "SUM")?.iter().sum::<f64>();
+    Ok(Number::from_f64(total).map_or(Value::Null, Value::Number))
+}
+
+/// Smallest (`Ordering::Less`) or largest (`Ordering::Greater`) value
+fn extreme(values: &[&Value], column: &str, wanted: Ordering) -> Result<Value, String> {
+    let mut best: Option<&Value> = None;
+    for &value in values {
+        best = match best {
+            | Some(current) if compare(value, current, column)? != wanted => Some(current),
+            | _ => Some(value),
+        };
+    }
+    Ok(best.cloned().unwrap_or(Value::Null))
+}
+
+fn compare(a: &Value, b: &Value, column: &str) -> Result<Ordering, String> {
+    match (a, b) {
+        | (Value::Number(x), Value::Number(y)) => match (x.as_i64(), y.as_i64()) {
+            | (Some(x), Some(y)) => Ok(x.cmp(&y)),
+            | _ => Ok(x
+                .as_f64()
+                .unwrap_or(f64::NAN)
+                .total_cmp(&y.as_f64().unwrap_or(f64::NAN))),
+        },
+        | (Value::String(x), Value::String(y)) => Ok(x.cmp(y)),
+        | _ => Err(format!("Cannot compare values of {column}: {a} and {b}")),
+    }
+}
@@ -24,7 +24,7 @@
     clippy::needless_pass_by_ref_mut
 )]
 //!
-//! - SQL query execution in the browser
+//! - SQL query execution in the browser, including aggregates
 //! - In-memory storage for WASM
 //! - DNA compression/decompression
 //! - JavaScript-friendly API with Promises
@@ -44,16 +44,19 @@
 //!   await db.execute("INSERT INTO users (id, name) VALUES (1, 'Alice')");
 //!   
 //!   const results = await db.query("SELECT * FROM users");
-//!   console.log(results);
+//!   console.log(results.columns, results.rows);
 //! }
 //! ```
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
-use js_sys::{Array, Object, Reflect};
+use js_sys::{Array, Object, Promise, Reflect};
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::AbortSignal;
 
+pub mod aggregate;
 pub mod dna_compression;
 
 // Re-export the WASM DNA compressor for direct usage
@@ -88,11 +91,85 @@ pub struct QueryResultRow {
     pub values: HashMap<String, serde_json::Value>,
 }
 
+/// Rows of a table or query result, keyed by column name
+pub type Rows = Vec<HashMap<String, serde_json::Value>>;
+
+/// Tables and declared columns as of `beginTransaction`
+struct TableSnapshot {
+    tables: HashMap<String, Rows>,
+    columns: HashMap<String, Vec<String>>,
+}
+
+/// Rows scanned or converted between checks of a query's `AbortSignal`
+const QUERY_YIELD_INTERVAL: usize = 1024;
+
+/// Version of the document written by `exportJson`
+const EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// Document written by `exportJson` and read by `importJson`
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DatabaseDump {
+    version: u32,
+    tables: HashMap<String, Rows>,
+    /// Declared columns per table, absent in older exports
+    #[serde(default)]
+    columns: HashMap<String, Vec<String>>,
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_QUERY_ROW: &str = r#"
+/** A result row, keyed by column name */
+export type QueryRow = Record<string, string | number | boolean | null>;
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Rows of a [`QueryResult`], typed as `QueryRow[]` in TypeScript
+    #[wasm_bindgen(typescript_type = "QueryRow[]")]
+    pub type QueryRows;
+}
+
+/// Result of a `query`
+///
+/// `columns` lists the result columns in order, even when no rows match.
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct QueryResult {
+    columns: Vec<String>,
+    rows: Array,
+}
+
+#[wasm_bindgen]
+impl QueryResult {
+    /// Names of the result columns
+    #[wasm_bindgen(getter)]
+    pub fn columns(&self) -> Vec<String> {
+        self.columns.clone()
+    }
+
+    /// Result rows, keyed by column name
+    #[wasm_bindgen(getter)]
+    pub fn rows(&self) -> QueryRows {
+        self.rows.clone().unchecked_into()
+    }
+
+    /// Number of result rows
+    #[wasm_bindgen(getter, js_name = rowCount)]
+    pub fn row_count(&self) -> u32 {
+        self.rows.length()
+    }
+}
+
 /// Main `NeuroQuantumDB` WebAssembly interface
 #[wasm_bindgen]
 pub struct NeuroQuantumDB {
     // In-memory tables for browser usage
-    tables: HashMap<String, Vec<HashMap<String, serde_json::Value>>>,
+    tables: HashMap<String, Rows>,
+    // Columns declared by CREATE TABLE
+    columns: HashMap<String, Vec<String>>,
+    // Tables and columns as of `beginTransaction`, restored on rollback
+    snapshot: Option<TableSnapshot>,
 }
 
 #[wasm_bindgen]
@@ -104,6 +181,8 @@ impl NeuroQuantumDB {
 
         Ok(Self {
             tables: HashMap::new(),
+            columns: HashMap::new(),
+            snapshot: None,
         })
     }
 
@@ -120,28 +199,108 @@ impl NeuroQuantumDB {
         }
     }
 
-    /// Execute a SQL query (SELECT) and return results as JSON
+    /// Execute a SQL query (SELECT) and return its columns and rows
+    ///
+    /// With a `signal`, the query yields to the event loop every
+    /// `QUERY_YIELD_INTERVAL` rows, while scanning the table and again while
+    /// converting the result, and is rejected with the signal's abort reason
+    /// once it is aborted, like `fetch`.
     #[wasm_bindgen]
-    pub async fn query(&self, sql: &str) -> Result<JsValue, JsValue> {
+    pub async fn query(
+        &self,
+        sql: &str,
+        signal: Option<AbortSignal>,
+    ) -> Result<QueryResult, JsValue> {
         console_log(&format!("Querying SQL: {sql}"));
-
-        match self.query_internal(sql) {
-            | Ok(results) => {
-                // Convert results to JavaScript array
-                let array = Array::new();
-                for row in results {
-                    let obj = Object::new();
-                    for (key, value) in row {
-                        let js_val = serde_wasm_bindgen::to_value(&value)
-                            .map_err(|e| JsValue::from_str(&format!("Serialization error: {e}")))?;
-                        Reflect::set(&obj, &JsValue::from_str(&key), &js_val)?;
-                    }
-                    array.push(&obj);
+        check_aborted(signal.as_ref())?;
+
+        let mut scan = self
+            .select_scan(sql)
+            .map_err(|e| JsValue::from_str(&format!("Query error: {e}")))?;
+        while scan.step() {
+            if signal.is_some() {
+                yield_now().await?;
+                check_aborted(signal.as_ref())?;
+            }
+        }
+        let (columns, results) = scan
+            .finish()
+            .map_err(|e| JsValue::from_str(&format!("Query error: {e}")))?;
+
+        // Convert results to JavaScript objects, keys in column order
+        let array = Array::new();
+        for (index, row) in results.into_iter().enumerate() {
+            if signal.is_some() && index > 0 && index % QUERY_YIELD_INTERVAL == 0 {
+                yield_now().await?;
+                check_aborted(signal.as_ref())?;
+            }
+            let obj = Object::new();
+            for column in &columns {
+                if let Some(value) = row.get(column) {
+                    let js_val = serde_wasm_bindgen::to_value(value)
+                        .map_err(|e| JsValue::from_str(&format!("Serialization error: {e}")))?;
+                    Reflect::set(&obj, &JsValue::from_str(column), &js_val)?;
                 }
-                Ok(array.into())
-            },
-            | Err(e) => Err(JsValue::from_str(&format!("Query error: {e}"))),
+            }
+            array.push(&obj);
         }
+        Ok(QueryResult {
+            columns,
+            rows: array,
+        })
+    }
+
+    /// Start a transaction
+    ///
+    /// Statements executed until [`commit`](Self::commit) take effect
+    /// together, or not at all after [`rollback`](Self::rollback).
+    /// Transactions can't be nested.
+    #[wasm_bindgen(js_name = beginTransaction)]
+    pub fn begin_transaction(&mut self) -> Result<(), JsValue> {
+        self.begin_transaction_internal()
+            .map_err(|e| JsValue::from_str(&format!("Transaction error: {e}")))
+    }
+
+    /// Commit the current transaction, keeping its changes
+    #[wasm_bindgen]
+    pub fn commit(&mut self) -> Result<(), JsValue> {
+        self.commit_internal()
+            .map_err(|e| JsValue::from_str(&format!("Transaction error: {e}")))
+    }
+
+    /// Roll back the current transaction, discarding its changes
+    #[wasm_bindgen]
+    pub fn rollback(&mut self) -> Result<(), JsValue> {
+        self.rollback_internal()
+            .map_err(|e| JsValue::from_str(&format!("Transaction error: {e}")))
+    }
+
+    /// Check if a transaction is open
+    #[wasm_bindgen(getter, js_name = inTransaction)]
+    pub fn in_transaction(&self) -> bool {
+        self.snapshot.is_some()
+    }
+
+    /// Export all tables as a JSON document
+    ///
+    /// The result can be stored, e.g. in IndexedDB, and loaded again with
+    /// [`import_json`](Self::import_json).
+    #[wasm_bindgen(js_name = exportJson)]
+    pub fn export_json(&self) -> Result<JsValue, JsValue> {
+        self.dump()
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|e| JsValue::from_str(&format!("Export error: {e}")))
+    }
+
+    /// Replace all tables with those of a document from [`export_json`](Self::export_json)
+    ///
+    /// Malformed documents are rejected and leave the database unchanged.
+    #[wasm_bindgen(js_name = importJson)]
+    pub fn import_json(&mut self, data: JsValue) -> Result<(), JsValue> {
+        let dump: DatabaseDump = serde_wasm_bindgen::from_value(data)
+            .map_err(|e| JsValue::from_str(&format!("Import error: {e}")))?;
+        self.restore(dump)
+            .map_err(|e| JsValue::from_str(&format!("Import error: {e}")))
     }
 
     /// Compress a DNA sequence using quaternary encoding
@@ -199,6 +358,7 @@ impl NeuroQuantumDB {
     pub fn clear(&mut self) {
         console_log("Clearing all database data");
         self.tables.clear();
+        self.columns.clear();
     }
 
     /// Get the number of tables in the database
@@ -223,6 +383,8 @@ impl NeuroQuantumDB {
         // Parse CREATE TABLE
         if sql_upper.starts_with("CREATE TABLE") {
             let table_name = self.parse_table_name(&sql_upper, "CREATE TABLE")?;
+            self.columns
+                .insert(table_name.clone(), Self::parse_column_names(sql));
             self.tables.insert(table_name, Vec::new());
             return Ok(0);
         }
@@ -246,10 +408,22 @@ impl NeuroQuantumDB {
     }
 
     /// Internal query logic
-    pub fn query_internal(
-        &self,
-        sql: &str,
-    ) -> Result<Vec<HashMap<String, serde_json::Value>>, String> {
+    pub fn query_internal(&self, sql: &str) -> Result<Rows, String> {
+        self.select_internal(sql).map(|(_, rows)| rows)
+    }
+
+    /// Internal query logic, also returning the result columns in order
+    ///
+    /// `SELECT *` yields the columns declared by CREATE TABLE, followed by
+    /// any other columns rows were inserted with.
+    pub fn select_internal(&self, sql: &str) -> Result<(Vec<String>, Rows), String> {
+        let mut scan = self.select_scan(sql)?;
+        while scan.step() {}
+        scan.finish()
+    }
+
+    /// Parse a `SELECT` into a scan over its table
+    fn select_scan(&self, sql: &str) -> Result<SelectScan<'_>, String> {
         let sql_upper = sql.trim().to_uppercase();
 
         if !sql_upper.starts_with("SELECT") {
@@ -270,7 +444,22 @@ impl NeuroQuantumDB {
                 .get(&table_name)
                 .ok_or(format!("Table '{table_name}' not found"))?;
 
-            return Ok(table.clone());
+            // Aggregates reduce the selected rows to a single row
+            let output = match aggregate::parse_select_list(&sql[6..from_idx])? {
+                | Some(aggregates) => {
+                    ScanOutput::Aggregates(aggregate::Accumulator::new(aggregates))
+                },
+                | None => ScanOutput::Rows {
+                    rows: Vec::with_capacity(table.len()),
+                    undeclared: BTreeSet::new(),
+                },
+            };
+
+            return Ok(SelectScan {
+                remaining: table,
+                columns: self.columns.get(&table_name).cloned().unwrap_or_default(),
+                output,
+            });
         }
 
         Err("Invalid SELECT query".to_string())
@@ -287,6 +476,108 @@ impl NeuroQuantumDB {
         Ok(table_name)
     }
 
+    /// Export all tables as a JSON string
+    pub fn export_json_internal(&self) -> Result<String, String> {
+        serde_json::to_string(&self.dump()).map_err(|e| e.to_string())
+    }
+
+    /// Replace all tables with those of a JSON string from
+    /// [`export_json_internal`](Self::export_json_internal)
+    pub fn import_json_internal(&mut self, json: &str) -> Result<(), String> {
+        let dump: DatabaseDump = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        self.restore(dump)
+    }
+
+    fn dump(&self) -> DatabaseDump {
+        DatabaseDump {
+            version: EXPORT_FORMAT_VERSION,
+            tables: self.tables.clone(),
+            columns: self.columns.clone(),
+        }
+    }
+
+    fn restore(&mut self, dump: DatabaseDump) -> Result<(), String> {
+        if dump.version != EXPORT_FORMAT_VERSION {
+            return Err(format!(
+                "Unsupported export format version {}",
+                dump.version
+            ));
+        }
+        console_log(&format!("Importing {} tables", dump.tables.len()));
+        self.tables = dump.tables;
+        self.columns = dump.columns;
+        Ok(())
+    }
+
+    /// Snapshot the tables to start a transaction
+    pub fn begin_transaction_internal(&mut self) -> Result<(), String> {
+        if self.snapshot.is_some() {
+            return Err("A transaction is already in progress".to_string());
+        }
+        console_log("Beginning transaction");
+        self.snapshot = Some(TableSnapshot {
+            tables: self.tables.clone(),
+            columns: self.columns.clone(),
+        });
+        Ok(())
+    }
+
+    /// Discard the snapshot, keeping the changes made since it was taken
+    pub fn commit_internal(&mut self) -> Result<(), String> {
+        self.snapshot.take().ok_or("No transaction in progress")?;
+        console_log("Committed transaction");
+        Ok(())
+    }
+
+    /// Restore the tables from the snapshot
+    pub fn rollback_internal(&mut self) -> Result<(), String> {
+        let snapshot = self.snapshot.take().ok_or("No transaction in progress")?;
+        self.tables = snapshot.tables;
+        self.columns = snapshot.columns;
+        console_log("Rolled back transaction");
+        Ok(())
+    }
+
+    /// Column names of `CREATE TABLE name (col TYPE, ...)`, in order
+    fn parse_column_names(sql: &str) -> Vec<String> {
+        let (Some(start), Some(end)) = (sql.find('('), sql.rfind(')')) else {
+            return Vec::new();
+        };
+        if end <= start {
+            return Vec::new();
+        }
+
+        // Split on top-level commas only, as in DECIMAL(10, 2)
+        let mut definitions = Vec::new();
+        let mut depth = 0usize;
+        let mut current = String::new();
+        for c in sql[start + 1..end].chars() {
+            match c {
+                | '(' => depth += 1,
+                | ')' => depth = depth.saturating_sub(1),
+                | ',' if depth == 0 => {
+                    definitions.push(std::mem::take(&mut current));
+                    continue;
+                },
+                | _ => {},
+            }
+            current.push(c);
+        }
+        definitions.push(current);
+
+        definitions
+            .iter()
+            .filter_map(|definition| definition.split_whitespace().next())
+            .filter(|name| {
+                !matches!(
+                    name.to_uppercase().as_str(),
+                    "PRIMARY" | "FOREIGN" | "UNIQUE" | "CHECK" | "CONSTRAINT"
+                )
+            })
+            .map(|name| name.trim_matches('"').to_string())
+            .collect()
+    }
+
     /// Execute INSERT statement
     fn execute_insert(&mut self, sql: &str) -> Result<u32, String> {
         // Simple INSERT parser: INSERT INTO table (col1, col2) VALUES (val1, val2)
@@ -364,6 +655,63 @@ impl NeuroQuantumDB {
     }
 }
 
+/// A `SELECT` over one table, run in batches of `QUERY_YIELD_INTERVAL` rows
+struct SelectScan<'a> {
+    /// Rows not scanned yet
+    remaining: &'a [HashMap<String, serde_json::Value>],
+    /// Columns declared by CREATE TABLE
+    columns: Vec<String>,
+    output: ScanOutput<'a>,
+}
+
+enum ScanOutput<'a> {
+    Rows {
+        rows: Rows,
+        /// Columns rows were inserted with but not declared
+        undeclared: BTreeSet<&'a String>,
+    },
+    Aggregates(aggregate::Accumulator<'a>),
+}
+
+impl SelectScan<'_> {
+    /// Scan the next batch of rows, returning whether any rows remain
+    fn step(&mut self) -> bool {
+        let (batch, remaining) = self
+            .remaining
+            .split_at(self.remaining.len().min(QUERY_YIELD_INTERVAL));
+        self.remaining = remaining;
+
+        match &mut self.output {
+            | ScanOutput::Rows { rows, undeclared } => {
+                undeclared.extend(
+                    batch
+                        .iter()
+                        .flat_map(HashMap::keys)
+                        .filter(|key| !self.columns.contains(*key)),
+                );
+                rows.extend_from_slice(batch);
+            },
+            | ScanOutput::Aggregates(accumulator) => accumulator.add(batch),
+        }
+        !self.remaining.is_empty()
+    }
+
+    /// The result columns and rows
+    fn finish(self) -> Result<(Vec<String>, Rows), String> {
+        match self.output {
+            | ScanOutput::Rows { rows, undeclared } => {
+                let mut columns = self.columns;
+                columns.extend(undeclared.into_iter().cloned());
+                Ok((columns, rows))
+            },
+            | ScanOutput::Aggregates(accumulator) => {
+                let columns = accumulator.labels();
+                Ok((columns, vec![accumulator.finish()?]))
+            },
+        }
+    }
+}
+
 impl Default for NeuroQuantumDB {
     /// Creates a default instance of `NeuroQuantumDB`.
     ///
@@ -388,3 +736,30 @@ extern "C" {
 fn console_log(s: &str) {
     log(s);
 }
+
+/// Reject with the abort reason if `signal` has been aborted
+fn check_aborted(signal: Option<&AbortSignal>) -> Result<(), JsValue> {
+    match signal {
+        | Some(signal) if signal.aborted() => {
+            console_log("Query aborted");
+            Err(signal.reason())
+        },
+        | _ => Ok(()),
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &js_sys::Function, timeout: i32);
+}
+
+/// Let the event loop run, including timers and event handlers such as an
+/// abort, before continuing
+///
+/// This waits for a timer rather than a resolved promise, as promise
+/// callbacks run before the event loop gets to any timers or events.
+async fn yield_now() -> Result<(), JsValue> {
+    let promise = Promise::new(&mut |resolve, _reject| set_timeout(&resolve, 0));
+    JsFuture::from(promise).await.map(|_| ())
+}
@@ -7,6 +7,7 @@
 //! - Query operations
 
 use neuroquantum_wasm::NeuroQuantumDB;
+use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
 
 wasm_bindgen_test_configure!(run_in_browser);
@@ -48,3 +49,319 @@ fn test_query() {
     let rows = results.unwrap();
     assert_eq!(rows.len(), 1);
 }
+
+#[wasm_bindgen_test]
+fn test_transaction_rollback_discards_insert() {
+    let mut db = NeuroQuantumDB::new().unwrap();
+    db.execute_internal("CREATE TABLE USERS (id INTEGER, name TEXT)")
+        .unwrap();
+    db.execute_internal("INSERT INTO USERS (id, name) VALUES (1, 'Alice')")
+        .unwrap();
+
+    db.begin_transaction_internal().unwrap();
+    assert!(db.in_transaction());
+    db.execute_internal("INSERT INTO USERS (id, name) VALUES (2, 'Bob')")
+        .unwrap();
+    db.execute_internal("CREATE TABLE ORDERS (id INTEGER)")
+        .unwrap();
+    assert_eq!(db.query_internal("SELECT * FROM USERS").unwrap().len(), 2);
+
+    db.rollback_internal().unwrap();
+    assert!(!db.in_transaction());
+    let rows = db.query_internal("SELECT * FROM USERS").unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["name"], "Alice");
+    assert!(!db.has_table("ORDERS"));
+}
+
+#[wasm_bindgen_test]
+fn test_transaction_commit_keeps_insert() {
+    let mut db = NeuroQuantumDB::new().unwrap();
+    db.execute_internal("CREATE TABLE USERS (id INTEGER, name TEXT)")
+        .unwrap();
+
+    db.begin_transaction_internal().unwrap();
+    db.execute_internal("INSERT INTO USERS (id, name) VALUES (1, 'Alice')")
+        .unwrap();
+    db.execute_internal("INSERT INTO USERS (id, name) VALUES (2, 'Bob')")
+        .unwrap();
+    db.commit_internal().unwrap();
+
+    assert!(!db.in_transaction());
+    assert_eq!(db.query_internal("SELECT * FROM USERS").unwrap().len(), 2);
+
+    // Nothing is left to roll back to
+    assert!(db.rollback_internal().is_err());
+    assert_eq!(db.query_internal("SELECT * FROM USERS").unwrap().len(), 2);
+}
+
+#[wasm_bindgen_test]
+fn test_nested_transaction_is_rejected() {
+    let mut db = NeuroQuantumDB::new().unwrap();
+    db.begin_transaction_internal().unwrap();
+    assert!(db.begin_transaction_internal().is_err());
+    assert!(db.begin_transaction().is_err());
+
+    // The open transaction is unaffected
+    assert!(db.in_transaction());
+    db.commit().unwrap();
+    assert!(db.commit().is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_export_import_round_trip() {
+    let mut db = NeuroQuantumDB::new().unwrap();
+    db.execute_internal("CREATE TABLE USERS (id INTEGER, name TEXT)")
+        .unwrap();
+    db.execute_internal("CREATE TABLE EMPTY (id INTEGER)")
+        .unwrap();
+    for (id, name) in [(1, "Alice"), (2, "Bob"), (3, "Carol")] {
+        db.execute_internal(&format!(
+            "INSERT INTO USERS (id, name) VALUES ({id}, '{name}')"
+        ))
+        .unwrap();
+    }
+    let original = db.query_internal("SELECT * FROM USERS").unwrap();
+
+    let dump = db.export_json_internal().unwrap();
+    db.clear();
+    assert_eq!(db.table_count(), 0);
+
+    db.import_json_internal(&dump).unwrap();
+    assert_eq!(db.table_count(), 2);
+    assert!(db.has_table("EMPTY"));
+    assert_eq!(db.query_internal("SELECT * FROM USERS").unwrap(), original);
+
+    // The JavaScript API produces the same document
+    let exported = db.export_json().unwrap();
+    db.clear();
+    db.import_json(exported).unwrap();
+    assert_eq!(db.query_internal("SELECT * FROM USERS").unwrap(), original);
+}
+
+#[wasm_bindgen_test]
+fn test_import_rejects_malformed_input() {
+    let mut db = NeuroQuantumDB::new().unwrap();
+    db.execute_internal("CREATE TABLE USERS (id INTEGER)")
+        .unwrap();
+
+    for json in [
+        "not json",
+        r#"{"tables": {}}"#,
+        r#"{"version": 2, "tables": {}}"#,
+        r#"{"version": 1, "tables": {"USERS": {"id": 1}}}"#,
+        r#"{"version": 1, "tables": {"USERS": [1, 2]}}"#,
+        r#"{"version": 1, "tables": {}, "extra": true}"#,
+    ] {
+        assert!(db.import_json_internal(json).is_err(), "accepted {json}");
+    }
+    assert!(db
+        .import_json(wasm_bindgen::JsValue::from_str("tables"))
+        .is_err());
+
+    // Rejected imports leave the database as it was
+    assert!(db.has_table("USERS"));
+    assert_eq!(db.table_count(), 1);
+}
+
+fn orders_db() -> NeuroQuantumDB {
+    let mut db = NeuroQuantumDB::new().unwrap();
+    db.execute_internal("CREATE TABLE ORDERS (id INTEGER, customer TEXT, amount INTEGER)")
+        .unwrap();
+    for (id, customer, amount) in [(1, "Alice", 30), (2, "Bob", 12), (3, "Alice", 45)] {
+        db.execute_internal(&format!(
+            "INSERT INTO ORDERS (id, customer, amount) VALUES ({id}, '{customer}', {amount})"
+        ))
+        .unwrap();
+    }
+    // No amount given for this order
+    db.execute_internal("INSERT INTO ORDERS (id, customer) VALUES (4, 'Carol')")
+        .unwrap();
+    db
+}
+
+#[wasm_bindgen_test]
+fn test_count_aggregates() {
+    let db = orders_db();
+
+    let rows = db
+        .query_internal("SELECT COUNT(*), COUNT(amount) AS priced FROM ORDERS")
+        .unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["COUNT(*)"], 4);
+    assert_eq!(rows[0]["priced"], 3);
+
+    // Over no rows COUNT is zero and the other aggregates are null
+    let mut db = NeuroQuantumDB::new().unwrap();
+    db.execute_internal("CREATE TABLE EMPTY (id INTEGER)")
+        .unwrap();
+    let rows = db
+        .query_internal("SELECT count(*), sum(id) FROM EMPTY")
+        .unwrap();
+    assert_eq!(rows[0]["count(*)"], 0);
+    assert!(rows[0]["sum(id)"].is_null());
+}
+
+#[wasm_bindgen_test]
+fn test_sum_avg_min_max_aggregates() {
+    let db = orders_db();
+
+    let rows = db
+        .query_internal(
+            "SELECT SUM(amount) AS total, AVG(amount) AS average, MIN(amount), MAX(customer) \
+             FROM ORDERS",
+        )
+        .unwrap();
+    let row = &rows[0];
+    assert_eq!(row["total"], 87);
+    assert!((row["average"].as_f64().unwrap() - 29.0).abs() < 1e-9);
+    assert_eq!(row["MIN(amount)"], 12);
+    assert_eq!(row["MAX(customer)"], "Carol");
+}
+
+#[wasm_bindgen_test]
+fn test_invalid_aggregates_are_rejected() {
+    let db = orders_db();
+
+    for sql in [
+        "SELECT SUM(customer) FROM ORDERS",
+        "SELECT AVG(customer) FROM ORDERS",
+        "SELECT SUM(*) FROM ORDERS",
+        "SELECT COUNT() FROM ORDERS",
+        "SELECT customer, COUNT(*) FROM ORDERS",
+    ] {
+        assert!(db.query_internal(sql).is_err(), "accepted {sql}");
+    }
+}
+
+fn large_db(rows: usize) -> NeuroQuantumDB {
+    let mut db = NeuroQuantumDB::new().unwrap();
+    db.execute_internal("CREATE TABLE EVENTS (id INTEGER, kind TEXT)")
+        .unwrap();
+    for id in 0..rows {
+        db.execute_internal(&format!(
+            "INSERT INTO EVENTS (id, kind) VALUES ({id}, 'click')"
+        ))
+        .unwrap();
+    }
+    db
+}
+
+#[wasm_bindgen::prelude::wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &js_sys::Function, timeout: i32);
+}
+
+/// Abort `controller` with `reason` from a timer, as in
+/// `setTimeout(() => controller.abort(reason), 0)`
+fn abort_on_timer(controller: web_sys::AbortController, reason: &'static str) {
+    let abort = wasm_bindgen::closure::Closure::once_into_js(move || {
+        controller.abort_with_reason(&wasm_bindgen::JsValue::from_str(reason));
+    });
+    set_timeout(abort.unchecked_ref(), 0);
+}
+
+#[wasm_bindgen_test]
+async fn test_query_is_cancelled_by_abort_signal() {
+    let db = large_db(5000);
+    let controller = web_sys::AbortController::new().unwrap();
+    let signal = controller.signal();
+    abort_on_timer(controller, "user cancelled");
+
+    let err = db
+        .query("SELECT * FROM EVENTS", Some(signal.clone()))
+        .await
+        .unwrap_err();
+    assert!(signal.aborted());
+    assert_eq!(err.as_string().as_deref(), Some("user cancelled"));
+}
+
+#[wasm_bindgen_test]
+async fn test_aggregate_query_is_cancelled_by_abort_signal() {
+    // The result is a single row, so only the table scan can observe the abort
+    let db = large_db(5000);
+    let controller = web_sys::AbortController::new().unwrap();
+    let signal = controller.signal();
+    abort_on_timer(controller, "user cancelled");
+
+    let err = db
+        .query("SELECT COUNT(*) FROM EVENTS", Some(signal.clone()))
+        .await
+        .unwrap_err();
+    assert!(signal.aborted());
+    assert_eq!(err.as_string().as_deref(), Some("user cancelled"));
+}
+
+#[wasm_bindgen_test]
+async fn test_query_with_signal_completes_when_not_aborted() {
+    let db = large_db(2500);
+    let controller = web_sys::AbortController::new().unwrap();
+
+    let rows = db
+        .query("SELECT * FROM EVENTS", Some(controller.signal()))
+        .await
+        .unwrap();
+    assert_eq!(rows.row_count(), 2500);
+
+    // A signal aborted up front rejects before any work is done
+    controller.abort();
+    let err = db
+        .query("SELECT COUNT(*) FROM EVENTS", Some(controller.signal()))
+        .await
+        .unwrap_err();
+    assert_eq!(err, controller.signal().reason());
+}
+
+#[wasm_bindgen_test]
+async fn test_empty_result_exposes_columns() {
+    let mut db = NeuroQuantumDB::new().unwrap();
+    db.execute_internal(
+        "CREATE TABLE PRICES (id INTEGER PRIMARY KEY, price DECIMAL(10, 2), label TEXT)",
+    )
+    .unwrap();
+
+    let result = db.query("SELECT * FROM PRICES", None).await.unwrap();
+    assert_eq!(result.columns(), vec!["id", "price", "label"]);
+    assert_eq!(result.row_count(), 0);
+    assert_eq!(js_sys::Array::from(&result.rows()).length(), 0);
+
+    db.execute_internal("INSERT INTO PRICES (id, label, note) VALUES (1, 'tea', 'new')")
+        .unwrap();
+    let result = db.query("SELECT * FROM PRICES", None).await.unwrap();
+    assert_eq!(result.columns(), vec!["id", "price", "label", "note"]);
+    assert_eq!(result.row_count(), 1);
+
+    let result = db
+        .query("SELECT COUNT(*) AS n, MAX(id) FROM PRICES", None)
+        .await
+        .unwrap();
+    assert_eq!(result.columns(), vec!["n", "MAX(id)"]);
+}
+
+#[wasm_bindgen_test]
+fn test_columns_survive_rollback_and_export() {
+    let mut db = NeuroQuantumDB::new().unwrap();
+    db.execute_internal("CREATE TABLE USERS (id INTEGER, name TEXT)")
+        .unwrap();
+
+    db.begin_transaction_internal().unwrap();
+    db.execute_internal("CREATE TABLE USERS (id INTEGER)")
+        .unwrap();
+    db.rollback_internal().unwrap();
+    let (columns, _) = db.select_internal("SELECT * FROM USERS").unwrap();
+    assert_eq!(columns, vec!["id", "name"]);
+
+    let dump = db.export_json_internal().unwrap();
+    db.clear();
+    db.import_json_internal(&dump).unwrap();
+    let (columns, rows) = db.select_internal("SELECT * FROM USERS").unwrap();
+    assert_eq!(columns, vec!["id", "name"]);
+    assert!(rows.is_empty());
+
+    // Exports from before column tracking still load
+    db.import_json_internal(r#"{"version": 1, "tables": {"USERS": [{"id": 1}]}}"#)
+        .unwrap();
+    let (columns, _) = db.select_internal("SELECT * FROM USERS").unwrap();
+    assert_eq!(columns, vec!["id"]);
+}
@@ -28,10 +28,10 @@ fuzz_target!(|data: &[u8]| {
         return;
     }
 
-    // Create SIMD encoder/decoder with auto-detection
-    let encoder = SimdEncoder::new();
-    let decoder = SimdDecoder::new();
-    let pattern_matcher = SimdPatternMatcher::new();
+    // Create SIMD encoder/decoder, honoring NEUROQUANTUM_SIMD
+    let encoder = SimdEncoder::new().unwrap();
+    let decoder = SimdDecoder::new().unwrap();
+    let pattern_matcher = SimdPatternMatcher::new().unwrap();
 
     // Test bytes to bases encoding
     let result = encoder.batch_encode(data);